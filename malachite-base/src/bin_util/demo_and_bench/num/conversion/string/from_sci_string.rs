@@ -105,7 +105,7 @@ fn benchmark_primitive_int_from_sci_string<T: PrimitiveInt>(
         limit,
         file_name,
         &string_len_bucketer(),
-        &mut [("Malachite", &mut |s| no_out!(T::from_sci_string(&s)))],
+        &mut [("Malachite", &mut |s| no_out!(T::from_sci_string(&s).ok()))],
     );
 }
 
@@ -127,7 +127,7 @@ fn benchmark_primitive_int_from_sci_string_with_options<T: PrimitiveInt>(
         file_name,
         &pair_1_string_len_bucketer("s"),
         &mut [("Malachite", &mut |(s, options)| {
-            no_out!(T::from_sci_string_with_options(&s, options))
+            no_out!(T::from_sci_string_with_options(&s, options).ok())
         })],
     );
 }
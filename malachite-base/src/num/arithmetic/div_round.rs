@@ -22,6 +22,28 @@ fn div_round_unsigned<T: PrimitiveUnsigned>(x: T, other: T, rm: RoundingMode) ->
                 (quotient, Ordering::Less)
             }
         }
+        RoundingMode::NearestTiesAway => {
+            let shifted_other = other >> 1;
+            if remainder > shifted_other || remainder == shifted_other && other.even() {
+                (quotient + T::ONE, Ordering::Greater)
+            } else {
+                (quotient, Ordering::Less)
+            }
+        }
+        RoundingMode::NearestTiesTowardZero => {
+            if remainder > other >> 1 {
+                (quotient + T::ONE, Ordering::Greater)
+            } else {
+                (quotient, Ordering::Less)
+            }
+        }
+        RoundingMode::Odd => {
+            if quotient.odd() {
+                (quotient, Ordering::Less)
+            } else {
+                (quotient + T::ONE, Ordering::Greater)
+            }
+        }
         RoundingMode::Exact => {
             panic!("Division is not exact: {x} / {other}");
         }
@@ -61,6 +83,13 @@ macro_rules! impl_div_round_unsigned {
             /// \end{cases}
             /// $$
             ///
+            /// $g(x, y, \mathrm{NearestTiesAway})$ and $g(x, y, \mathrm{NearestTiesTowardZero})$
+            /// are defined like $g(x, y, \mathrm{Nearest})$, except that ties are rounded away from
+            /// $0$ and towards $0$, respectively. $g(x, y, \mathrm{Odd})$ is $g(x, y,
+            /// \mathrm{Down})$ if no rounding is necessary, and otherwise whichever of $g(x, y,
+            /// \mathrm{Floor})$ and $g(x, y, \mathrm{Ceiling})$ corresponds to an odd rounded
+            /// quotient.
+            ///
             /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
             ///
             /// Then
@@ -168,6 +197,13 @@ macro_rules! impl_div_round_signed {
             /// \end{cases}
             /// $$
             ///
+            /// $g(x, y, \mathrm{NearestTiesAway})$ and $g(x, y, \mathrm{NearestTiesTowardZero})$
+            /// are defined like $g(x, y, \mathrm{Nearest})$, except that ties are rounded away from
+            /// $0$ and towards $0$, respectively. $g(x, y, \mathrm{Odd})$ is $g(x, y,
+            /// \mathrm{Down})$ if no rounding is necessary, and otherwise whichever of $g(x, y,
+            /// \mathrm{Floor})$ and $g(x, y, \mathrm{Ceiling})$ corresponds to an odd rounded
+            /// quotient.
+            ///
             /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
             ///
             /// Then
@@ -13,9 +13,11 @@ fn round_to_multiple_unsigned<T: PrimitiveUnsigned>(
     match (x, other) {
         (x, y) if x == y => (x, Ordering::Equal),
         (x, y) if y == T::ZERO => match rm {
-            RoundingMode::Down | RoundingMode::Floor | RoundingMode::Nearest => {
-                (T::ZERO, Ordering::Less)
-            }
+            RoundingMode::Down
+            | RoundingMode::Floor
+            | RoundingMode::Nearest
+            | RoundingMode::NearestTiesAway
+            | RoundingMode::NearestTiesTowardZero => (T::ZERO, Ordering::Less),
             _ => panic!("Cannot round {x} to zero using RoundingMode {rm}"),
         },
         (x, y) => {
@@ -50,6 +52,24 @@ fn round_to_multiple_unsigned<T: PrimitiveUnsigned>(
                             }
                         }
                     }
+                    RoundingMode::NearestTiesAway | RoundingMode::NearestTiesTowardZero => {
+                        match r.cmp(&(y >> 1)) {
+                            Ordering::Less => (floor, Ordering::Less),
+                            Ordering::Equal
+                                if y.odd() || rm == RoundingMode::NearestTiesTowardZero =>
+                            {
+                                (floor, Ordering::Less)
+                            }
+                            _ => (floor.checked_add(y).unwrap(), Ordering::Greater),
+                        }
+                    }
+                    RoundingMode::Odd => {
+                        if (x / y).odd() {
+                            (floor, Ordering::Less)
+                        } else {
+                            (floor.checked_add(y).unwrap(), Ordering::Greater)
+                        }
+                    }
                     RoundingMode::Exact => {
                         panic!("Cannot round {x} to {y} using RoundingMode {rm}")
                     }
@@ -92,6 +112,13 @@ macro_rules! impl_round_to_multiple_unsigned {
             /// \end{cases}
             /// $$
             ///
+            /// $f(x, y, \mathrm{NearestTiesAway})$ and $f(x, y, \mathrm{NearestTiesTowardZero})$
+            /// are defined like $f(x, y, \mathrm{Nearest})$, except that ties are rounded away from
+            /// $0$ and towards $0$, respectively. $f(x, y, \mathrm{Odd})$ is $f(x, y,
+            /// \mathrm{Down})$ if no rounding is necessary, and otherwise whichever of $f(x, y,
+            /// \mathrm{Floor})$ and $f(x, y, \mathrm{Ceiling})$ corresponds to an odd rounded
+            /// quotient.
+            ///
             /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \N$.
             ///
             /// The following two expressions are equivalent:
@@ -216,6 +243,13 @@ macro_rules! impl_round_to_multiple_signed {
             /// \end{cases}
             /// $$
             ///
+            /// $f(x, y, \mathrm{NearestTiesAway})$ and $f(x, y, \mathrm{NearestTiesTowardZero})$
+            /// are defined like $f(x, y, \mathrm{Nearest})$, except that ties are rounded away from
+            /// $0$ and towards $0$, respectively. $f(x, y, \mathrm{Odd})$ is $f(x, y,
+            /// \mathrm{Down})$ if no rounding is necessary, and otherwise whichever of $f(x, y,
+            /// \mathrm{Floor})$ and $f(x, y, \mathrm{Ceiling})$ corresponds to an odd rounded
+            /// quotient.
+            ///
             /// $f(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
             ///
             /// The following two expressions are equivalent:
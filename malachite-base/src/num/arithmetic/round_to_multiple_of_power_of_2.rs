@@ -48,6 +48,13 @@ macro_rules! impl_round_to_multiple_of_power_of_2 {
             /// \end{cases}
             /// $$
             ///
+            /// $f(x, k, \mathrm{NearestTiesAway})$ and $f(x, k, \mathrm{NearestTiesTowardZero})$
+            /// are defined like $f(x, k, \mathrm{Nearest})$, except that ties are rounded away from
+            /// $0$ and towards $0$, respectively. $f(x, k, \mathrm{Odd})$ is $f(x, k,
+            /// \mathrm{Down})$ if no rounding is necessary, and otherwise whichever of $f(x, k,
+            /// \mathrm{Floor})$ and $f(x, k, \mathrm{Ceiling})$ corresponds to an odd rounded
+            /// quotient.
+            ///
             /// $f(x, k, \mathrm{Exact}) = 2^k q$, but panics if $q \notin \Z$.
             ///
             /// The following two expressions are equivalent:
@@ -32,7 +32,9 @@ fn shr_round_unsigned_unsigned<
                 },
             )
         }
-        RoundingMode::Up | RoundingMode::Ceiling if bits >= width => (T::ONE, Ordering::Greater),
+        RoundingMode::Up | RoundingMode::Ceiling | RoundingMode::Odd if bits >= width => {
+            (T::ONE, Ordering::Greater)
+        }
         RoundingMode::Up | RoundingMode::Ceiling => {
             let shifted = x >> bits;
             if shifted << bits == x {
@@ -41,7 +43,9 @@ fn shr_round_unsigned_unsigned<
                 (shifted + T::ONE, Ordering::Greater)
             }
         }
-        RoundingMode::Nearest if bits == width && x > T::power_of_2(T::WIDTH - 1) => {
+        RoundingMode::Nearest | RoundingMode::NearestTiesTowardZero
+            if bits == width && x > T::power_of_2(T::WIDTH - 1) =>
+        {
             (T::ONE, Ordering::Greater)
         }
         RoundingMode::Nearest if bits >= width => (T::ZERO, Ordering::Less),
@@ -72,6 +76,45 @@ fn shr_round_unsigned_unsigned<
                 }
             }
         }
+        RoundingMode::NearestTiesAway if bits == width && x >= T::power_of_2(T::WIDTH - 1) => {
+            (T::ONE, Ordering::Greater)
+        }
+        RoundingMode::NearestTiesAway | RoundingMode::NearestTiesTowardZero if bits >= width => {
+            (T::ZERO, Ordering::Less)
+        }
+        RoundingMode::NearestTiesAway | RoundingMode::NearestTiesTowardZero => {
+            let bm1 = bits - U::ONE;
+            let mostly_shifted = x >> bm1;
+            let bm1_zeros = mostly_shifted << bm1 == x;
+            let shifted = mostly_shifted >> 1;
+            if mostly_shifted.even() {
+                // round down
+                (
+                    shifted,
+                    if bm1_zeros {
+                        Ordering::Equal
+                    } else {
+                        Ordering::Less
+                    },
+                )
+            } else if !bm1_zeros || rm == RoundingMode::NearestTiesAway {
+                // round up
+                (shifted + T::ONE, Ordering::Greater)
+            } else {
+                // result is half-integer; round towards zero
+                (shifted, Ordering::Less)
+            }
+        }
+        RoundingMode::Odd => {
+            let shifted = x >> bits;
+            if shifted << bits == x {
+                (shifted, Ordering::Equal)
+            } else if shifted.odd() {
+                (shifted, Ordering::Less)
+            } else {
+                (shifted + T::ONE, Ordering::Greater)
+            }
+        }
         RoundingMode::Exact if bits >= width => {
             panic!("Right shift is not exact: {x} >> {bits}");
         }
@@ -86,7 +129,7 @@ fn shr_round_unsigned_unsigned<
 }
 
 fn shr_round_assign_unsigned_unsigned<
-    T: PrimitiveUnsigned + Shl<U, Output = T> + ShrAssign<U>,
+    T: PrimitiveUnsigned + Shl<U, Output = T> + Shr<U, Output = T> + ShrAssign<U>,
     U: PrimitiveUnsigned,
 >(
     x: &mut T,
@@ -161,6 +204,11 @@ fn shr_round_assign_unsigned_unsigned<
                 }
             }
         }
+        RoundingMode::NearestTiesAway | RoundingMode::NearestTiesTowardZero | RoundingMode::Odd => {
+            let o;
+            (*x, o) = shr_round_unsigned_unsigned(*x, bits, rm);
+            o
+        }
         RoundingMode::Exact if bits >= width => {
             panic!("Right shift is not exact: {} >>= {}", *x, bits);
         }
@@ -213,6 +261,13 @@ macro_rules! impl_shr_round_unsigned_unsigned {
                     /// \end{cases}
                     /// $$
                     ///
+                    /// $g(x, k, \mathrm{NearestTiesAway})$ and $g(x, k,
+                    /// \mathrm{NearestTiesTowardZero})$ are defined like $g(x, k,
+                    /// \mathrm{Nearest})$, except that ties are rounded away from $0$ and towards
+                    /// $0$, respectively. $g(x, k, \mathrm{Odd})$ is $g(x, k, \mathrm{Down})$ if no
+                    /// rounding is necessary, and otherwise whichever of $g(x, k, \mathrm{Floor})$
+                    /// and $g(x, k, \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+                    ///
                     /// $g(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
                     ///
                     /// Then
@@ -333,6 +388,13 @@ macro_rules! impl_shr_round_signed_unsigned {
                     /// \end{cases}
                     /// $$
                     ///
+                    /// $g(x, k, \mathrm{NearestTiesAway})$ and $g(x, k,
+                    /// \mathrm{NearestTiesTowardZero})$ are defined like $g(x, k,
+                    /// \mathrm{Nearest})$, except that ties are rounded away from $0$ and towards
+                    /// $0$, respectively. $g(x, k, \mathrm{Odd})$ is $g(x, k, \mathrm{Down})$ if no
+                    /// rounding is necessary, and otherwise whichever of $g(x, k, \mathrm{Floor})$
+                    /// and $g(x, k, \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+                    ///
                     /// $g(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
                     ///
                     /// Then
@@ -477,6 +539,13 @@ macro_rules! impl_shr_round_primitive_signed {
                     /// \end{cases}
                     /// $$
                     ///
+                    /// $g(x, k, \mathrm{NearestTiesAway})$ and $g(x, k,
+                    /// \mathrm{NearestTiesTowardZero})$ are defined like $g(x, k,
+                    /// \mathrm{Nearest})$, except that ties are rounded away from $0$ and towards
+                    /// $0$, respectively. $g(x, k, \mathrm{Odd})$ is $g(x, k, \mathrm{Down})$ if no
+                    /// rounding is necessary, and otherwise whichever of $g(x, k, \mathrm{Floor})$
+                    /// and $g(x, k, \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+                    ///
                     /// $g(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
                     ///
                     /// Then
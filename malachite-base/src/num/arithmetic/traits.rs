@@ -1184,6 +1184,19 @@ pub trait ReciprocalAssign {
     fn reciprocal_assign(&mut self);
 }
 
+/// Removes all factors of $p$ from a number, returning the remaining number and the multiplicity
+/// of $p$.
+pub trait RemoveFactor<RHS = Self> {
+    type Output;
+
+    fn remove_factor(self, p: RHS) -> (Self::Output, u64);
+}
+
+/// Removes all factors of $p$ from a number in place, returning the multiplicity of $p$.
+pub trait RemoveFactorAssign<RHS = Self> {
+    fn remove_factor_assign(&mut self, p: RHS) -> u64;
+}
+
 /// Finds the floor of the $n$th root of a number.
 pub trait FloorRoot<POW> {
     type Output;
@@ -1534,6 +1547,12 @@ pub trait SubMulAssign<Y = Self, Z = Self> {
     fn sub_mul_assign(&mut self, y: Y, z: Z);
 }
 
+/// Finds the multiplicity of $p$ as a factor of a number; that is, the largest $k$ such that $p^k$
+/// divides the number.
+pub trait Valuation<RHS = Self> {
+    fn valuation(self, p: RHS) -> u64;
+}
+
 /// Takes the absolute value of a number, wrapping around at the boundary of the type.
 pub trait WrappingAbs {
     type Output;
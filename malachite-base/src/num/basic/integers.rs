@@ -29,7 +29,7 @@ use crate::num::comparison::traits::{EqAbs, OrdAbs, PartialOrdAbs};
 use crate::num::conversion::traits::{
     ConvertibleFrom, ExactFrom, ExactInto, FromSciString, FromStringBase, IsInteger,
     OverflowingFrom, OverflowingInto, RoundingFrom, RoundingInto, SaturatingFrom, SaturatingInto,
-    ToSci, ToStringBase, WrappingFrom, WrappingInto,
+    ToFormattedString, ToSci, ToStringBase, WrappingFrom, WrappingInto,
 };
 use crate::num::float::NiceFloat;
 use crate::num::logic::traits::{
@@ -305,6 +305,7 @@ pub trait PrimitiveInt:
     + SaturatingSubAssign<Self>
     + SaturatingSubMul<Self, Self, Output = Self>
     + SaturatingSubMulAssign<Self, Self>
+    + Send
     + Shl<i128, Output = Self>
     + Shl<i16, Output = Self>
     + Shl<i32, Output = Self>
@@ -397,6 +398,8 @@ pub trait PrimitiveInt:
     + SubMul<Self, Self, Output = Self>
     + SubMulAssign<Self, Self>
     + Sum<Self>
+    + Sync
+    + ToFormattedString
     + ToSci
     + ToStringBase
     + TrailingZeros
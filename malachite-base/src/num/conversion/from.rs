@@ -399,9 +399,12 @@ fn primitive_float_rounding_from_unsigned<T: PrimitiveFloat, U: PrimitiveUnsigne
             RoundingMode::Exact => {
                 panic!("Value cannot be represented exactly as an {}", T::NAME)
             }
-            RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                (T::MAX_FINITE, Ordering::Less)
-            }
+            RoundingMode::Floor
+            | RoundingMode::Down
+            | RoundingMode::Nearest
+            | RoundingMode::NearestTiesAway
+            | RoundingMode::NearestTiesTowardZero
+            | RoundingMode::Odd => (T::MAX_FINITE, Ordering::Less),
             _ => (T::INFINITY, Ordering::Greater),
         }
     }
@@ -418,9 +421,11 @@ fn unsigned_rounding_from_primitive_float<T: PrimitiveUnsigned, U: PrimitiveFloa
                 RoundingMode::Exact => {
                     panic!("Value cannot be represented exactly as a {}", T::NAME)
                 }
-                RoundingMode::Down | RoundingMode::Floor | RoundingMode::Nearest => {
-                    (T::MAX, Ordering::Less)
-                }
+                RoundingMode::Down
+                | RoundingMode::Floor
+                | RoundingMode::Nearest
+                | RoundingMode::NearestTiesAway
+                | RoundingMode::NearestTiesTowardZero => (T::MAX, Ordering::Less),
                 _ => panic!("Cannot round away from positive infinity"),
             }
         } else {
@@ -428,9 +433,11 @@ fn unsigned_rounding_from_primitive_float<T: PrimitiveUnsigned, U: PrimitiveFloa
                 RoundingMode::Exact => {
                     panic!("Value cannot be represented exactly as a {}", T::NAME)
                 }
-                RoundingMode::Down | RoundingMode::Ceiling | RoundingMode::Nearest => {
-                    (T::ZERO, Ordering::Greater)
-                }
+                RoundingMode::Down
+                | RoundingMode::Ceiling
+                | RoundingMode::Nearest
+                | RoundingMode::NearestTiesAway
+                | RoundingMode::NearestTiesTowardZero => (T::ZERO, Ordering::Greater),
                 _ => panic!("Cannot round away from negative infinity"),
             }
         };
@@ -443,9 +450,11 @@ fn unsigned_rounding_from_primitive_float<T: PrimitiveUnsigned, U: PrimitiveFloa
             RoundingMode::Exact => {
                 panic!("Value cannot be represented exactly as a {}", T::NAME)
             }
-            RoundingMode::Ceiling | RoundingMode::Down | RoundingMode::Nearest => {
-                (T::ZERO, Ordering::Greater)
-            }
+            RoundingMode::Ceiling
+            | RoundingMode::Down
+            | RoundingMode::Nearest
+            | RoundingMode::NearestTiesAway
+            | RoundingMode::NearestTiesTowardZero => (T::ZERO, Ordering::Greater),
             _ => panic!("Value is less than 0 and rounding mode is {rm}"),
         };
     }
@@ -468,9 +477,11 @@ fn unsigned_rounding_from_primitive_float<T: PrimitiveUnsigned, U: PrimitiveFloa
             RoundingMode::Exact => {
                 panic!("Value cannot be represented exactly as a {}", T::NAME)
             }
-            RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                (T::MAX, Ordering::Less)
-            }
+            RoundingMode::Floor
+            | RoundingMode::Down
+            | RoundingMode::Nearest
+            | RoundingMode::NearestTiesAway
+            | RoundingMode::NearestTiesTowardZero => (T::MAX, Ordering::Less),
             _ => panic!(
                 "Value is greater than {}::MAX and rounding mode is {}",
                 T::NAME,
@@ -570,6 +581,12 @@ macro_rules! impl_from_float_unsigned {
                     ///   least-significant bit in its representation is selected. If the value is
                     ///   larger than the maximum finite float (which can only happen when
                     ///   converting a `u128` to an `f32`), the maximum finite float is returned.
+                    /// - If the rounding mode is `NearestTiesAway` or `NearestTiesTowardZero`, the
+                    ///   rounding proceeds as with `Nearest`, except that a value exactly between
+                    ///   two floats is rounded away from or towards zero, respectively.
+                    /// - If the rounding mode is `Odd`, the float with the one least-significant
+                    ///   bit in its representation is selected, unless the value is exactly
+                    ///   representable.
                     ///
                     /// # Worst-case complexity
                     /// Constant time and additional memory.
@@ -612,6 +629,11 @@ macro_rules! impl_from_float_unsigned {
                     ///   the float is greater than the maximum representable unsigned value, the
                     ///   maximum unsigned value is returned. If the float is negative, zero is
                     ///   returned.
+                    /// - If the rounding mode is `NearestTiesAway` or `NearestTiesTowardZero`, the
+                    ///   rounding proceeds as with `Nearest`, except that a value exactly between
+                    ///   two numbers is rounded away from or towards zero, respectively.
+                    /// - If the rounding mode is `Odd`, the odd one of the two nearest numbers is
+                    ///   returned, unless the float is an integer.
                     ///
                     /// # Worst-case complexity
                     /// Constant time and additional memory.
@@ -748,9 +770,11 @@ fn signed_rounding_from_primitive_float<
                 RoundingMode::Exact => {
                     panic!("Value cannot be represented exactly as a {}", S::NAME)
                 }
-                RoundingMode::Down | RoundingMode::Floor | RoundingMode::Nearest => {
-                    (S::MAX, Ordering::Less)
-                }
+                RoundingMode::Down
+                | RoundingMode::Floor
+                | RoundingMode::Nearest
+                | RoundingMode::NearestTiesAway
+                | RoundingMode::NearestTiesTowardZero => (S::MAX, Ordering::Less),
                 _ => panic!("Cannot round away from extreme value"),
             }
         } else {
@@ -758,9 +782,11 @@ fn signed_rounding_from_primitive_float<
                 RoundingMode::Exact => {
                     panic!("Value cannot be represented exactly as a {}", S::NAME)
                 }
-                RoundingMode::Down | RoundingMode::Nearest | RoundingMode::Ceiling => {
-                    (S::MIN, Ordering::Greater)
-                }
+                RoundingMode::Down
+                | RoundingMode::Nearest
+                | RoundingMode::NearestTiesAway
+                | RoundingMode::NearestTiesTowardZero
+                | RoundingMode::Ceiling => (S::MIN, Ordering::Greater),
                 _ => panic!("Cannot round away from extreme value"),
             }
         };
@@ -777,9 +803,11 @@ fn signed_rounding_from_primitive_float<
                 RoundingMode::Exact => {
                     panic!("Value cannot be represented exactly as an {}", S::NAME)
                 }
-                RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                    (S::MAX, Ordering::Less)
-                }
+                RoundingMode::Floor
+                | RoundingMode::Down
+                | RoundingMode::Nearest
+                | RoundingMode::NearestTiesAway
+                | RoundingMode::NearestTiesTowardZero => (S::MAX, Ordering::Less),
                 _ => panic!(
                     "Value is greater than {}::MAX and rounding mode is {}",
                     S::NAME,
@@ -801,9 +829,11 @@ fn signed_rounding_from_primitive_float<
                 RoundingMode::Exact => {
                     panic!("Value cannot be represented exactly as an {}", S::NAME)
                 }
-                RoundingMode::Ceiling | RoundingMode::Down | RoundingMode::Nearest => {
-                    (S::MIN, Ordering::Greater)
-                }
+                RoundingMode::Ceiling
+                | RoundingMode::Down
+                | RoundingMode::Nearest
+                | RoundingMode::NearestTiesAway
+                | RoundingMode::NearestTiesTowardZero => (S::MIN, Ordering::Greater),
                 _ => panic!(
                     "Value is smaller than {}::MIN and rounding mode is {}",
                     S::NAME,
@@ -928,6 +958,12 @@ macro_rules! impl_from_float_signed {
                     /// - If the rounding mode is `Nearest`, then the nearest float is returned.
                     ///   If the value is exactly between two floats, the float with the zero
                     ///   least-significant bit in its representation is selected.
+                    /// - If the rounding mode is `NearestTiesAway` or `NearestTiesTowardZero`, the
+                    ///   rounding proceeds as with `Nearest`, except that a value exactly between
+                    ///   two floats is rounded away from or towards zero, respectively.
+                    /// - If the rounding mode is `Odd`, the float with the one least-significant
+                    ///   bit in its representation is selected, unless the value is exactly
+                    ///   representable.
                     ///
                     /// # Worst-case complexity
                     /// Constant time and additional memory.
@@ -972,6 +1008,11 @@ macro_rules! impl_from_float_signed {
                     ///   the float is greater than the maximum representable signed value, the
                     ///   maximum signed value is returned. If the float is smaller than the
                     ///   minimum representable signed value, the minimum signed value is returned.
+                    /// - If the rounding mode is `NearestTiesAway` or `NearestTiesTowardZero`, the
+                    ///   rounding proceeds as with `Nearest`, except that a value exactly between
+                    ///   two numbers is rounded away from or towards zero, respectively.
+                    /// - If the rounding mode is `Odd`, the odd one of the two nearest numbers is
+                    ///   returned, unless the float is an integer.
                     ///
                     /// # Worst-case complexity
                     /// Constant time and additional memory.
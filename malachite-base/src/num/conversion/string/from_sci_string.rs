@@ -2,6 +2,7 @@ use crate::num::arithmetic::traits::Parity;
 use crate::num::basic::integers::PrimitiveInt;
 use crate::num::conversion::string::from_string::digit_from_display_byte;
 use crate::num::conversion::string::options::FromSciStringOptions;
+use crate::num::conversion::string::parse_error::{ParseNumberError, ParseNumberErrorKind};
 use crate::num::conversion::traits::FromSciString;
 use crate::rounding_modes::RoundingMode;
 use std::cmp::Ordering;
@@ -13,101 +14,49 @@ pub fn parse_exponent(s: &[u8]) -> Option<i64> {
 }
 
 #[doc(hidden)]
-pub fn validate_helper(s: &[u8], base: u8) -> Option<()> {
-    for &c in s {
-        if digit_from_display_byte(c)? >= base {
-            return None;
-        }
-    }
-    Some(())
+pub fn is_zero_helper(s: &[u8]) -> bool {
+    s.iter().all(|&c| c == b'0')
 }
 
+// `s` must only contain valid digits in the given base.
 #[doc(hidden)]
-pub fn is_zero_helper(s: &[u8], base: u8) -> Option<bool> {
-    let mut all_zeros = true;
-    for &c in s {
-        let d = digit_from_display_byte(c)?;
-        if d >= base {
-            return None;
-        }
-        if d != 0 {
-            all_zeros = false;
-        }
-    }
-    Some(all_zeros)
-}
-
-#[doc(hidden)]
-pub fn cmp_half_helper(s: &[u8], base: u8) -> Option<Ordering> {
+pub fn cmp_half_helper(s: &[u8], base: u8) -> Ordering {
     if s.is_empty() {
-        return Some(Ordering::Less);
+        return Ordering::Less;
     }
     let h = base >> 1;
-    let mut done = false;
-    let mut result;
+    let mut digits = s.iter().map(|&c| digit_from_display_byte(c).unwrap());
     if base.even() {
         // 1/2 is 0.h
-        result = Ordering::Equal;
-        let mut first = true;
-        for &c in s {
-            let d = digit_from_display_byte(c)?;
-            if d >= base {
-                return None;
-            }
-            if done {
-                continue;
-            }
-            if first {
-                let half_c = d.cmp(&h);
-                if half_c != Ordering::Equal {
-                    result = half_c;
-                    done = true;
-                }
-                first = false;
-            } else if d != 0 {
-                result = Ordering::Greater;
-                done = true;
-            }
+        let half_c = digits.next().unwrap().cmp(&h);
+        if half_c != Ordering::Equal {
+            half_c
+        } else if digits.all(|d| d == 0) {
+            Ordering::Equal
+        } else {
+            Ordering::Greater
         }
     } else {
         // 1/2 is 0.hhh...
-        result = Ordering::Less;
-        for &c in s {
-            let d = digit_from_display_byte(c)?;
-            if done {
-                continue;
-            }
-            let half_c = d.cmp(&h);
-            if half_c != Ordering::Equal {
-                result = half_c;
-                done = true;
-            }
-        }
+        digits
+            .map(|d| d.cmp(&h))
+            .find(|&c| c != Ordering::Equal)
+            .unwrap_or(Ordering::Less)
     }
-    Some(result)
 }
 
 fn parse_int<T: PrimitiveInt>(cs: &[u8], base: u8) -> Option<T> {
     // if T is unsigned, from_string_base won't handle -0
-    let mut test_neg_zero = false;
     if T::MIN == T::ZERO {
-        if let Some(&b'-') = cs.first() {
-            test_neg_zero = true;
-        }
-    }
-    if test_neg_zero {
-        if cs.len() == 1 {
-            return None;
-        }
-        for &c in &cs[1..] {
-            if c != b'0' {
-                return None;
-            }
+        if let Some((&b'-', ds)) = cs.split_first() {
+            return if is_zero_helper(ds) {
+                Some(T::ZERO)
+            } else {
+                None
+            };
         }
-        Some(T::ZERO)
-    } else {
-        T::from_string_base(base, std::str::from_utf8(cs).ok()?)
     }
+    T::from_string_base(base, std::str::from_utf8(cs).ok()?).ok()
 }
 
 fn up_1<T: PrimitiveInt>(x: T, neg: bool) -> Option<T> {
@@ -118,143 +67,189 @@ fn up_1<T: PrimitiveInt>(x: T, neg: bool) -> Option<T> {
     }
 }
 
+// Checks that `s[start..end]` is a nonempty string of digits in the given base, with an optional
+// leading sign if `sign_allowed` is true and at most one point if `point_allowed` is true.
+fn check_sci_digits(
+    s: &[u8],
+    start: usize,
+    end: usize,
+    base: u8,
+    sign_allowed: bool,
+    point_allowed: bool,
+) -> Result<(), ParseNumberError> {
+    let mut i = start;
+    if sign_allowed && i < end && (s[i] == b'+' || s[i] == b'-') {
+        i += 1;
+    }
+    let mut seen_digit = false;
+    let mut seen_point = false;
+    for (j, &c) in s.iter().enumerate().take(end).skip(i) {
+        if c == b'.' && point_allowed && !seen_point {
+            seen_point = true;
+        } else if digit_from_display_byte(c).map_or(false, |d| d < base) {
+            seen_digit = true;
+        } else {
+            return Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit(j)));
+        }
+    }
+    if seen_digit {
+        Ok(())
+    } else {
+        Err(ParseNumberError::new(ParseNumberErrorKind::Empty))
+    }
+}
+
+// Splits a string, possibly in scientific notation, into its significand digits, with an optional
+// leading sign but without the point, and the exponent that the significand must be multiplied by.
+// Every digit of the result is valid in the base. If the string is not syntactically valid, or if
+// the exponent does not fit in an `i64`, an error describing the problem is returned.
 #[doc(hidden)]
-pub fn preprocess_sci_string(s: &str, options: FromSciStringOptions) -> Option<(Vec<u8>, i64)> {
-    let mut s = s.as_bytes().to_vec();
-    let mut exponent = 0;
+pub fn preprocess_sci_string(
+    s: &str,
+    options: FromSciStringOptions,
+) -> Result<(Vec<u8>, i64), ParseNumberError> {
+    let s = s.as_bytes();
+    let len = s.len();
+    // Find the end of the significand and the start of the exponent, if any. An invalid sign is
+    // reported after any invalid digits before it.
+    let mut significand_end = len;
+    let mut exponent_start = None;
+    let mut invalid_sign = None;
     if options.base < 15 {
-        for (i, &c) in s.iter().enumerate().rev() {
-            if c == b'e' || c == b'E' {
-                if i == 0 || i == s.len() - 1 {
-                    return None;
-                }
-                exponent = parse_exponent(&s[i + 1..])?;
-                s.truncate(i);
-                break;
-            }
+        if let Some(i) = s.iter().rposition(|&c| c == b'e' || c == b'E') {
+            significand_end = i;
+            exponent_start = Some(i + 1);
         }
-    } else {
-        for (i, &c) in s.iter().enumerate().rev() {
-            if c == b'+' || c == b'-' {
-                if i == 0 {
-                    break;
-                }
-                if i == 1 || i == s.len() - 1 {
-                    return None;
-                }
-                let exp_indicator = s[i - 1];
-                if exp_indicator != b'e' && exp_indicator != b'E' {
-                    return None;
-                }
-                exponent = parse_exponent(&s[i..])?;
-                s.truncate(i - 1);
-                break;
+    } else if let Some(i) = s.iter().rposition(|&c| c == b'+' || c == b'-') {
+        if i != 0 {
+            if s[i - 1] == b'e' || s[i - 1] == b'E' {
+                significand_end = i - 1;
+                exponent_start = Some(i);
+            } else {
+                significand_end = i;
+                invalid_sign = Some(i);
             }
         }
     }
-    let mut point_index = None;
-    for (i, &c) in s.iter().enumerate() {
-        if c == b'.' {
-            point_index = Some(i);
-            break;
-        }
+    check_sci_digits(s, 0, significand_end, options.base, true, true)?;
+    if let Some(i) = invalid_sign {
+        return Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit(i)));
     }
-    if let Some(point_index) = point_index {
-        let len = s.len();
-        if point_index != len - 1 {
-            let next_char = s[point_index + 1];
-            if next_char == b'+' || next_char == b'-' {
-                return None;
-            }
-            exponent = exponent.checked_sub(i64::try_from(len - point_index - 1).ok()?)?;
-            s.copy_within(point_index + 1..len, point_index);
-        }
-        s.pop();
+    let overflow = || ParseNumberError::new(ParseNumberErrorKind::Overflow);
+    let mut exponent = if let Some(i) = exponent_start {
+        check_sci_digits(s, i, len, 10, true, false)?;
+        parse_exponent(&s[i..]).ok_or_else(overflow)?
+    } else {
+        0
+    };
+    let mut significand = s[..significand_end].to_vec();
+    if let Some(point_index) = significand.iter().position(|&c| c == b'.') {
+        let fraction_len = significand.len() - point_index - 1;
+        exponent = exponent
+            .checked_sub(i64::try_from(fraction_len).map_err(|_| overflow())?)
+            .ok_or_else(overflow)?;
+        significand.remove(point_index);
     }
-    Some((s, exponent))
+    Ok((significand, exponent))
 }
 
 fn from_sci_string_with_options_primitive_int<T: PrimitiveInt>(
     s: &str,
     options: FromSciStringOptions,
-) -> Option<T> {
+) -> Result<T, ParseNumberError> {
     let (s, exponent) = preprocess_sci_string(s, options)?;
+    let first = s[0];
+    let neg = first == b'-';
+    let sign = neg || first == b'+';
+    // The digits are valid, so parsing or rounding can only fail if the value is negative but `T`
+    // is unsigned, or if the value is out of range
+    let range_error = || {
+        ParseNumberError::new(if neg && T::MIN == T::ZERO {
+            ParseNumberErrorKind::SignNotAllowed
+        } else {
+            ParseNumberErrorKind::Overflow
+        })
+    };
     if exponent >= 0 {
-        let x = parse_int::<T>(&s, options.base)?;
-        x.checked_mul(T::wrapping_from(options.base).checked_pow(exponent.unsigned_abs())?)
+        let x = parse_int::<T>(&s, options.base).ok_or_else(range_error)?;
+        T::wrapping_from(options.base)
+            .checked_pow(exponent.unsigned_abs())
+            .and_then(|pow| x.checked_mul(pow))
+            .ok_or_else(|| ParseNumberError::new(ParseNumberErrorKind::Overflow))
     } else {
-        let neg_exponent = usize::try_from(exponent.unsigned_abs()).ok()?;
+        let neg_exponent = usize::try_from(exponent.unsigned_abs())
+            .map_err(|_| ParseNumberError::new(ParseNumberErrorKind::Overflow))?;
         let len = s.len();
-        if len == 0 {
-            return None;
-        }
-        let first = s[0];
-        let neg = first == b'-';
-        let sign = neg || first == b'+';
         let rm = if neg {
             -options.rounding_mode
         } else {
             options.rounding_mode
         };
         let sig_len = if sign { len - 1 } else { len };
-        if sig_len == 0 {
-            return None;
-        }
+        let inexact = || ParseNumberError::new(ParseNumberErrorKind::Inexact);
         if neg_exponent > sig_len {
             let s = if sign { &s[1..] } else { &s[..] };
-            return match rm {
-                RoundingMode::Down | RoundingMode::Floor | RoundingMode::Nearest => {
-                    validate_helper(s, options.base)?;
-                    Some(T::ZERO)
-                }
-                RoundingMode::Up | RoundingMode::Ceiling => {
-                    if is_zero_helper(s, options.base)? {
-                        Some(T::ZERO)
-                    } else {
-                        up_1(T::ZERO, neg)
+            return if is_zero_helper(s) {
+                Ok(T::ZERO)
+            } else {
+                match rm {
+                    RoundingMode::Down
+                    | RoundingMode::Floor
+                    | RoundingMode::Nearest
+                    | RoundingMode::NearestTiesAway
+                    | RoundingMode::NearestTiesTowardZero => Ok(T::ZERO),
+                    RoundingMode::Up | RoundingMode::Ceiling | RoundingMode::Odd => {
+                        up_1(T::ZERO, neg).ok_or_else(range_error)
                     }
+                    RoundingMode::Exact => Err(inexact()),
                 }
-                RoundingMode::Exact => None,
             };
         }
         let (before_e, after_e) = s.split_at(len - neg_exponent);
+        if is_zero_helper(after_e) {
+            return match before_e {
+                &[] | &[b'-'] | &[b'+'] => Ok(T::ZERO),
+                before_e => parse_int(before_e, options.base).ok_or_else(range_error),
+            };
+        }
+        if rm == RoundingMode::Exact {
+            return Err(inexact());
+        }
         let x = match before_e {
             &[] | &[b'-'] | &[b'+'] => T::ZERO,
-            before_e => parse_int(before_e, options.base)?,
+            before_e => parse_int(before_e, options.base).ok_or_else(range_error)?,
         };
-        if after_e.is_empty() {
-            return Some(x);
-        }
+        let up = |x: T| up_1(x, neg).ok_or_else(range_error);
         match rm {
-            RoundingMode::Down | RoundingMode::Floor => {
-                validate_helper(after_e, options.base)?;
-                Some(x)
-            }
-            RoundingMode::Up | RoundingMode::Ceiling => {
-                if is_zero_helper(after_e, options.base)? {
-                    Some(x)
-                } else {
-                    up_1(x, neg)
-                }
-            }
-            RoundingMode::Exact => {
-                if is_zero_helper(after_e, options.base)? {
-                    Some(x)
-                } else {
-                    None
-                }
-            }
-            RoundingMode::Nearest => match cmp_half_helper(after_e, options.base)? {
-                Ordering::Less => Some(x),
-                Ordering::Greater => up_1(x, neg),
+            RoundingMode::Down | RoundingMode::Floor => Ok(x),
+            RoundingMode::Up | RoundingMode::Ceiling => up(x),
+            RoundingMode::Nearest => match cmp_half_helper(after_e, options.base) {
+                Ordering::Less => Ok(x),
+                Ordering::Greater => up(x),
                 Ordering::Equal => {
                     if x.even() {
-                        Some(x)
+                        Ok(x)
                     } else {
-                        up_1(x, neg)
+                        up(x)
                     }
                 }
             },
+            RoundingMode::NearestTiesAway => match cmp_half_helper(after_e, options.base) {
+                Ordering::Less => Ok(x),
+                Ordering::Greater | Ordering::Equal => up(x),
+            },
+            RoundingMode::NearestTiesTowardZero => match cmp_half_helper(after_e, options.base) {
+                Ordering::Less | Ordering::Equal => Ok(x),
+                Ordering::Greater => up(x),
+            },
+            RoundingMode::Odd => {
+                if x.odd() {
+                    Ok(x)
+                } else {
+                    up(x)
+                }
+            }
+            RoundingMode::Exact => unreachable!(),
         }
     }
 }
@@ -284,9 +279,10 @@ macro_rules! impl_from_sci_string {
             /// non-integer, the rounding mode specified in `options` is used to round to an
             /// integer.
             ///
-            /// If the string is unparseable or parses to an out-of-range integer, `None` is
-            /// returned. `None` is also returned if the rounding mode in options is `Exact`, but
-            /// rounding is necessary.
+            /// If the string is unparseable or parses to an out-of-range integer, a
+            /// [`ParseNumberError`] describing the problem is returned. An error of kind
+            /// [`Inexact`](ParseNumberErrorKind::Inexact) is returned if the rounding mode in
+            /// options is `Exact`, but rounding is necessary.
             ///
             /// # Worst-case complexity
             /// $T(n) = O(n)$
@@ -298,7 +294,10 @@ macro_rules! impl_from_sci_string {
             /// # Examples
             /// See [here](super::from_sci_string).
             #[inline]
-            fn from_sci_string_with_options(s: &str, options: FromSciStringOptions) -> Option<$t> {
+            fn from_sci_string_with_options(
+                s: &str,
+                options: FromSciStringOptions,
+            ) -> Result<$t, ParseNumberError> {
                 from_sci_string_with_options_primitive_int(s, options)
             }
         }
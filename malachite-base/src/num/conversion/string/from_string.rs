@@ -1,3 +1,4 @@
+use crate::num::conversion::string::parse_error::{ParseNumberError, ParseNumberErrorKind};
 use crate::num::conversion::traits::FromStringBase;
 
 /// Produces a digit from a byte corresponding to a numeric or alphabetic (lower- or uppercase)
@@ -33,14 +34,66 @@ pub const fn digit_from_display_byte(b: u8) -> Option<u8> {
     }
 }
 
+// Determines why a string, consisting of an optional sign followed by digits in a given base,
+// could not be parsed. If the string is syntactically valid, the value must have been out of
+// range.
+#[doc(hidden)]
+pub fn string_base_error(
+    s: &str,
+    base: u8,
+    minus_allowed: bool,
+    plus_allowed: bool,
+) -> ParseNumberError {
+    if !(2..=36).contains(&base) {
+        return ParseNumberError::new(ParseNumberErrorKind::InvalidBase(base));
+    }
+    let bytes = s.as_bytes();
+    let start = match bytes.first() {
+        Some(b'-') if minus_allowed => 1,
+        Some(b'+') if plus_allowed => 1,
+        Some(b'-' | b'+') => return ParseNumberError::new(ParseNumberErrorKind::SignNotAllowed),
+        _ => 0,
+    };
+    if bytes.len() == start {
+        return ParseNumberError::new(ParseNumberErrorKind::Empty);
+    }
+    for (i, &b) in bytes.iter().enumerate().skip(start) {
+        if digit_from_display_byte(b).map_or(true, |d| d >= base) {
+            return ParseNumberError::new(ParseNumberErrorKind::InvalidDigit(i));
+        }
+    }
+    ParseNumberError::new(ParseNumberErrorKind::Overflow)
+}
+
 macro_rules! impl_from_string_base {
     ($t:ident) => {
         impl FromStringBase for $t {
+            /// Converts a string, in a specified base, to a primitive integer.
+            ///
+            /// The string must be nonempty and only contain digits smaller than the base, with an
+            /// optional leading `'+'`, or a `'-'` if the type is signed. If the base is not
+            /// between 2 and 36, inclusive, the string is invalid, or its value is out of range,
+            /// a [`ParseNumberError`] describing the problem is returned.
+            ///
             /// This is a wrapper over the `from_str_radix` functions in the standard library, for
             /// example [this one](u32::from_str_radix).
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+            ///
+            /// # Examples
+            /// See [here](super::from_string).
             #[inline]
-            fn from_string_base(base: u8, s: &str) -> Option<Self> {
-                $t::from_str_radix(s, u32::from(base)).ok()
+            fn from_string_base(base: u8, s: &str) -> Result<Self, ParseNumberError> {
+                if !(2..=36).contains(&base) {
+                    return Err(ParseNumberError::new(ParseNumberErrorKind::InvalidBase(base)));
+                }
+                $t::from_str_radix(s, u32::from(base))
+                    .map_err(|_| string_base_error(s, base, $t::MIN != 0, true))
             }
         }
     };
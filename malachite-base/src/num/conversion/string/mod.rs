@@ -3,39 +3,119 @@
 ///
 /// # from_sci_string
 /// ```
+/// use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
 /// use malachite_base::num::conversion::traits::FromSciString;
 ///
-/// assert_eq!(u8::from_sci_string("123"), Some(123));
-/// assert_eq!(u8::from_sci_string("123.5"), Some(124));
-/// assert_eq!(u8::from_sci_string("256"), None);
-/// assert_eq!(u64::from_sci_string("1.23e10"), Some(12300000000));
+/// assert_eq!(u8::from_sci_string("123"), Ok(123));
+/// assert_eq!(u8::from_sci_string("123.5"), Ok(124));
+/// assert_eq!(
+///     u8::from_sci_string("256").unwrap_err().kind(),
+///     ParseNumberErrorKind::Overflow
+/// );
+/// assert_eq!(
+///     u8::from_sci_string("1.2x").unwrap_err().kind(),
+///     ParseNumberErrorKind::InvalidDigit(3)
+/// );
+/// assert_eq!(u64::from_sci_string("1.23e10"), Ok(12300000000));
 /// ```
 ///
 /// # from_sci_string_with_options
 /// ```
 /// use malachite_base::num::conversion::string::options::FromSciStringOptions;
+/// use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
 /// use malachite_base::num::conversion::traits::FromSciString;
 /// use malachite_base::rounding_modes::RoundingMode;
 ///
 /// let mut options = FromSciStringOptions::default();
-/// assert_eq!(u8::from_sci_string_with_options("123.5", options), Some(124));
+/// assert_eq!(u8::from_sci_string_with_options("123.5", options), Ok(124));
 ///
 /// options.set_rounding_mode(RoundingMode::Floor);
-/// assert_eq!(u8::from_sci_string_with_options("123.5", options), Some(123));
+/// assert_eq!(u8::from_sci_string_with_options("123.5", options), Ok(123));
+///
+/// options.set_rounding_mode(RoundingMode::Exact);
+/// assert_eq!(
+///     u8::from_sci_string_with_options("123.5", options).unwrap_err().kind(),
+///     ParseNumberErrorKind::Inexact
+/// );
 ///
 /// options = FromSciStringOptions::default();
 /// options.set_base(16);
-/// assert_eq!(u8::from_sci_string_with_options("ff", options), Some(255));
+/// assert_eq!(u8::from_sci_string_with_options("ff", options), Ok(255));
 /// ```
 pub mod from_sci_string;
 /// [`FromStringBase`](super::traits::FromStringBase), a trait for converting strings in a
 /// specified base to numbers.
+///
+/// # from_string_base
+/// ```
+/// use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
+/// use malachite_base::num::conversion::traits::FromStringBase;
+///
+/// assert_eq!(u8::from_string_base(10, "123"), Ok(123));
+/// assert_eq!(i16::from_string_base(16, "-ff"), Ok(-255));
+/// assert_eq!(u32::from_string_base(36, "+Zz"), Ok(1295));
+///
+/// assert_eq!(u8::from_string_base(10, "").unwrap_err().kind(), ParseNumberErrorKind::Empty);
+/// assert_eq!(
+///     u8::from_string_base(2, "102").unwrap_err().kind(),
+///     ParseNumberErrorKind::InvalidDigit(2)
+/// );
+/// assert_eq!(
+///     u8::from_string_base(1, "0").unwrap_err().kind(),
+///     ParseNumberErrorKind::InvalidBase(1)
+/// );
+/// assert_eq!(
+///     u8::from_string_base(10, "-1").unwrap_err().kind(),
+///     ParseNumberErrorKind::SignNotAllowed
+/// );
+/// assert_eq!(
+///     i8::from_string_base(10, "-129").unwrap_err().kind(),
+///     ParseNumberErrorKind::Overflow
+/// );
+/// ```
 pub mod from_string;
-/// [`ToSciOptions`](options::ToSciOptions) and
-/// [`FromSciSringOptions`](options::FromSciStringOptions), `struct`s for specifying parameters
-/// when using the [`FromSciString`](super::traits::FromSciString) and
-/// [`ToSci`](super::traits::ToSci) traits.
+/// [`ToSciOptions`](options::ToSciOptions),
+/// [`FromSciSringOptions`](options::FromSciStringOptions), and
+/// [`FormatOptions`](options::FormatOptions), `struct`s for specifying parameters when using the
+/// [`FromSciString`](super::traits::FromSciString), [`ToSci`](super::traits::ToSci), and
+/// [`ToFormattedString`](super::traits::ToFormattedString) traits.
 pub mod options;
+/// [`ParseNumberError`](parse_error::ParseNumberError), the error returned when a string can't be
+/// parsed as a number.
+pub mod parse_error;
+/// [`ToFormattedString`](super::traits::ToFormattedString), a trait for converting a number to a
+/// string with grouped digits and custom separators.
+///
+/// # to_formatted_string
+/// ```
+/// use malachite_base::num::conversion::string::options::{DigitGrouping, FormatOptions};
+/// use malachite_base::num::conversion::traits::ToFormattedString;
+///
+/// let mut options = FormatOptions::default();
+/// assert_eq!(1234567u32.to_formatted_string(options), "1234567");
+///
+/// options.set_digit_grouping(DigitGrouping::Uniform(3));
+/// assert_eq!(1234567u32.to_formatted_string(options), "1,234,567");
+/// assert_eq!((-1234567i32).to_formatted_string(options), "-1,234,567");
+/// assert_eq!(123u32.to_formatted_string(options), "123");
+///
+/// options.set_digit_grouping(DigitGrouping::Indian);
+/// assert_eq!(12345678u32.to_formatted_string(options), "1,23,45,678");
+///
+/// options.set_group_separator('.');
+/// options.set_decimal_separator(',');
+/// options.set_digit_grouping(DigitGrouping::Uniform(3));
+/// options.set_fraction_digits(2);
+/// assert_eq!(1234567u32.to_formatted_string(options), "1.234.567,00");
+///
+/// let mut options = FormatOptions::default();
+/// options.set_base(16);
+/// options.set_uppercase();
+/// options.set_digit_grouping(DigitGrouping::Uniform(4));
+/// options.set_group_separator('_');
+/// assert_eq!(u64::MAX.to_formatted_string(options), "FFFF_FFFF_FFFF_FFFF");
+/// ```
+pub mod to_formatted_string;
 /// [`ToSci`](super::traits::ToSci), a trait for converting a number to string, possibly using
 /// scientific notation.
 ///
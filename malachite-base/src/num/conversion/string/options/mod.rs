@@ -1,3 +1,4 @@
+use crate::num::arithmetic::traits::Parity;
 use crate::rounding_modes::RoundingMode;
 
 /// A `struct` determining how much "detail" should be used when creating a scientific-notation
@@ -292,6 +293,243 @@ impl FromSciStringOptions {
     }
 }
 
+/// An `enum` determining how the digits before the decimal (or other-base) point should be
+/// grouped when a number is formatted using
+/// [`ToFormattedString`](crate::num::conversion::traits::ToFormattedString).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DigitGrouping {
+    /// Indicates that the digits should not be grouped.
+    None,
+    /// Indicates that the digits should be split into groups of a fixed size, counting from the
+    /// point. `Uniform(3)` groups decimal digits by thousands, as in `"1,234,567"`, and
+    /// `Uniform(4)` is commonly used for hexadecimal digits. The group size cannot be zero.
+    Uniform(u64),
+    /// Indicates that the digits should be grouped as in the Indian numbering system: the three
+    /// digits closest to the point form a group, and the remaining digits are grouped in pairs, as
+    /// in `"1,23,45,678"` (1 crore, 23 lakh, 45 thousand, 678).
+    Indian,
+}
+
+impl Default for DigitGrouping {
+    fn default() -> DigitGrouping {
+        DigitGrouping::None
+    }
+}
+
+impl DigitGrouping {
+    // Returns whether a group separator should be placed before a digit that has `digits_after`
+    // digits between it and the point.
+    pub(crate) fn separator_before(self, digits_after: u64) -> bool {
+        match self {
+            DigitGrouping::None => false,
+            DigitGrouping::Uniform(size) => digits_after % size == 0,
+            DigitGrouping::Indian => {
+                digits_after == 3 || digits_after > 3 && (digits_after - 3).even()
+            }
+        }
+    }
+}
+
+/// A `struct` determining how a number should be formatted by
+/// [`ToFormattedString`](crate::num::conversion::traits::ToFormattedString).
+///
+/// - The base must be between 2 and 36, inclusive. The characters representing the digits are
+///   `'0'` through `'9'` and either `'a'` through `'z'` or `'A'` through `'Z'`, depending on
+///   whether the `lowercase` field is set. The default base is 10.
+///
+/// - The lowercase setting determines whether digits in bases greater than 10 are lowercase or
+///   uppercase. The default is `true`.
+///
+/// - The digit grouping determines how the digits before the point are split into groups. The
+///   digits after the point are never grouped. The default is [`DigitGrouping::None`].
+///
+/// - The group separator is placed between groups of digits. The default is `','`.
+///
+/// - The decimal separator is placed between the digits before and after the point. The default
+///   is `'.'`.
+///
+/// - The fraction digits determine how many digits should be shown after the point. If they are
+///   unspecified, the number is shown exactly: integers have no digits after the point, and other
+///   numbers are shown with as many digits as their expansion in the base requires. If the fraction
+///   digits are specified, exactly that many digits are shown, and the number is rounded if
+///   necessary. The default is unspecified.
+///
+/// - The non-terminating fraction digits determine what happens when the fraction digits are
+///   unspecified but the number's expansion in the base doesn't terminate, as with $1/3$ in base
+///   10, so that it can't be shown exactly. If they are specified, the number is rounded to that
+///   many digits after the point; otherwise, formatting the number panics. The default is
+///   [`NON_TERMINATING_FRACTION_DIGITS`](FormatOptions::NON_TERMINATING_FRACTION_DIGITS).
+///
+/// - The rounding mode determines how the number should be rounded, in case it can't be
+///   represented exactly with the number of digits that are shown. The default rounding mode is
+///   `Nearest`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FormatOptions {
+    pub(crate) base: u8,
+    pub(crate) lowercase: bool,
+    pub(crate) digit_grouping: DigitGrouping,
+    pub(crate) group_separator: char,
+    pub(crate) decimal_separator: char,
+    pub(crate) fraction_digits: Option<u64>,
+    pub(crate) non_terminating_fraction_digits: Option<u64>,
+    pub(crate) rounding_mode: RoundingMode,
+}
+
+impl Default for FormatOptions {
+    fn default() -> FormatOptions {
+        FormatOptions {
+            base: 10,
+            lowercase: true,
+            digit_grouping: DigitGrouping::None,
+            group_separator: ',',
+            decimal_separator: '.',
+            fraction_digits: None,
+            non_terminating_fraction_digits: Some(FormatOptions::NON_TERMINATING_FRACTION_DIGITS),
+            rounding_mode: RoundingMode::Nearest,
+        }
+    }
+}
+
+impl FormatOptions {
+    /// The default number of digits shown after the point when the fraction digits are unspecified
+    /// but the number's expansion in the base doesn't terminate, as with $1/3$ in base 10.
+    pub const NON_TERMINATING_FRACTION_DIGITS: u64 = 20;
+
+    /// Returns the base to be used in the conversion. It is always between 2 and 36, inclusive.
+    #[inline]
+    pub const fn get_base(&self) -> u8 {
+        self.base
+    }
+
+    /// Returns whether the digits should be lowercase.
+    #[inline]
+    pub const fn get_lowercase(&self) -> bool {
+        self.lowercase
+    }
+
+    /// Returns the digit grouping to be used in the conversion.
+    #[inline]
+    pub const fn get_digit_grouping(&self) -> DigitGrouping {
+        self.digit_grouping
+    }
+
+    /// Returns the separator to be placed between groups of digits.
+    #[inline]
+    pub const fn get_group_separator(&self) -> char {
+        self.group_separator
+    }
+
+    /// Returns the separator to be placed between the digits before and after the point.
+    #[inline]
+    pub const fn get_decimal_separator(&self) -> char {
+        self.decimal_separator
+    }
+
+    /// Returns the number of digits to be shown after the point, or `None` if the number should
+    /// be shown exactly.
+    #[inline]
+    pub const fn get_fraction_digits(&self) -> Option<u64> {
+        self.fraction_digits
+    }
+
+    /// Returns the number of digits to be shown after the point when the fraction digits are
+    /// unspecified but the number's expansion in the base doesn't terminate, or `None` if
+    /// formatting such a number should panic.
+    #[inline]
+    pub const fn get_non_terminating_fraction_digits(&self) -> Option<u64> {
+        self.non_terminating_fraction_digits
+    }
+
+    /// Returns the rounding mode to be used in the conversion.
+    #[inline]
+    pub const fn get_rounding_mode(&self) -> RoundingMode {
+        self.rounding_mode
+    }
+
+    /// Sets the base to be used in the conversion.
+    ///
+    /// # Panics
+    /// Panics if `base` is less than 2 or greater than 36.
+    #[inline]
+    pub fn set_base(&mut self, base: u8) {
+        assert!(base >= 2);
+        assert!(base <= 36);
+        self.base = base;
+    }
+
+    /// Specifies that digits in bases greater than ten should be output with lowercase letters.
+    #[inline]
+    pub fn set_lowercase(&mut self) {
+        self.lowercase = true;
+    }
+
+    /// Specifies that digits in bases greater than ten should be output with uppercase letters.
+    #[inline]
+    pub fn set_uppercase(&mut self) {
+        self.lowercase = false;
+    }
+
+    /// Sets the digit grouping to be used in the conversion.
+    ///
+    /// # Panics
+    /// Panics if `digit_grouping` is `Uniform(0)`.
+    #[inline]
+    pub fn set_digit_grouping(&mut self, digit_grouping: DigitGrouping) {
+        assert_ne!(digit_grouping, DigitGrouping::Uniform(0));
+        self.digit_grouping = digit_grouping;
+    }
+
+    /// Sets the separator to be placed between groups of digits.
+    #[inline]
+    pub fn set_group_separator(&mut self, group_separator: char) {
+        self.group_separator = group_separator;
+    }
+
+    /// Sets the separator to be placed between the digits before and after the point.
+    #[inline]
+    pub fn set_decimal_separator(&mut self, decimal_separator: char) {
+        self.decimal_separator = decimal_separator;
+    }
+
+    /// Specifies that exactly `fraction_digits` digits should be shown after the point, rounding
+    /// the number if necessary. If `fraction_digits` is zero, no point is shown.
+    #[inline]
+    pub fn set_fraction_digits(&mut self, fraction_digits: u64) {
+        self.fraction_digits = Some(fraction_digits);
+    }
+
+    /// Specifies that the number should be shown exactly, with as many digits after the point as
+    /// its expansion in the base requires. Formatting a number whose expansion doesn't terminate
+    /// then panics; to round such numbers instead, use
+    /// [`set_fraction_digits_exact_or`](FormatOptions::set_fraction_digits_exact_or).
+    #[inline]
+    pub fn set_fraction_digits_exact(&mut self) {
+        self.fraction_digits = None;
+        self.non_terminating_fraction_digits = None;
+    }
+
+    /// Specifies that the number should be shown exactly, with as many digits after the point as
+    /// its expansion in the base requires, unless the expansion doesn't terminate; then exactly
+    /// `non_terminating_fraction_digits` digits are shown, and the number is rounded using the
+    /// rounding mode.
+    #[inline]
+    pub fn set_fraction_digits_exact_or(&mut self, non_terminating_fraction_digits: u64) {
+        self.fraction_digits = None;
+        self.non_terminating_fraction_digits = Some(non_terminating_fraction_digits);
+    }
+
+    /// Sets the rounding mode to be used in the conversion.
+    #[inline]
+    pub fn set_rounding_mode(&mut self, rm: RoundingMode) {
+        self.rounding_mode = rm;
+    }
+
+    #[cfg(feature = "test_build")]
+    pub fn is_valid(&self) -> bool {
+        (2..=36).contains(&self.base) && self.digit_grouping != DigitGrouping::Uniform(0)
+    }
+}
+
 /// Iterators that generate [`SciSizeOptions`], [`ToSciOptions`], and [`FromSciStringOptions`]
 /// without repetition.
 pub mod exhaustive;
@@ -0,0 +1,182 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// The reason that a string could not be parsed as a number.
+///
+/// This `enum` is returned by [`ParseNumberError::kind`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ParseNumberErrorKind {
+    /// The string, or a part of it that must contain digits (such as an exponent or a
+    /// denominator), is empty.
+    Empty,
+    /// The string contains a byte that is not allowed at the given byte offset: for example, a
+    /// character that is not a digit in the base being used.
+    InvalidDigit(usize),
+    /// The base is not supported. Most functions support the bases from 2 to 36, inclusive.
+    InvalidBase(u8),
+    /// The string contains a sign that is not allowed: for example, a `'-'` in front of a string
+    /// representing a nonzero unsigned value.
+    SignNotAllowed,
+    /// The string represents a value that is out of range for the target type, or has an exponent
+    /// that is too large.
+    Overflow,
+    /// The string represents a value that the target type cannot represent exactly, and the
+    /// rounding mode is [`Exact`](crate::rounding_modes::RoundingMode::Exact) or no rounding is
+    /// possible.
+    Inexact,
+    /// The string represents a fraction whose denominator is zero.
+    ZeroDenominator,
+}
+
+/// An error returned when a string cannot be parsed as a number.
+///
+/// This is the error type of [`FromStringBase`](crate::num::conversion::traits::FromStringBase)
+/// and [`FromSciString`](crate::num::conversion::traits::FromSciString), and of the
+/// [`FromStr`](std::str::FromStr) implementations of Malachite's number types.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::conversion::string::parse_error::{
+///     ParseNumberError, ParseNumberErrorKind,
+/// };
+/// use malachite_base::num::conversion::traits::FromStringBase;
+///
+/// let e = u8::from_string_base(10, "12x").unwrap_err();
+/// assert_eq!(e.kind(), ParseNumberErrorKind::InvalidDigit(2));
+/// assert_eq!(e.to_string(), "invalid digit at byte offset 2");
+///
+/// assert_eq!(
+///     u8::from_string_base(10, "256"),
+///     Err(ParseNumberError::new(ParseNumberErrorKind::Overflow))
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ParseNumberError {
+    kind: ParseNumberErrorKind,
+}
+
+impl ParseNumberError {
+    /// Creates a [`ParseNumberError`] of the specified kind.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::parse_error::{
+    ///     ParseNumberError, ParseNumberErrorKind,
+    /// };
+    ///
+    /// let e = ParseNumberError::new(ParseNumberErrorKind::Empty);
+    /// assert_eq!(e.kind(), ParseNumberErrorKind::Empty);
+    /// ```
+    pub const fn new(kind: ParseNumberErrorKind) -> ParseNumberError {
+        ParseNumberError { kind }
+    }
+
+    /// Returns the kind of a [`ParseNumberError`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
+    /// use malachite_base::num::conversion::traits::FromStringBase;
+    ///
+    /// assert_eq!(
+    ///     u8::from_string_base(10, "-1").unwrap_err().kind(),
+    ///     ParseNumberErrorKind::SignNotAllowed
+    /// );
+    /// ```
+    pub const fn kind(&self) -> ParseNumberErrorKind {
+        self.kind
+    }
+
+    /// Adds an offset to the byte offset of an
+    /// [`InvalidDigit`](ParseNumberErrorKind::InvalidDigit) error. Errors of other kinds are
+    /// returned unchanged.
+    ///
+    /// This is useful when a number is parsed from a substring, and the error should refer to a
+    /// position in the full string.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
+    /// use malachite_base::num::conversion::traits::FromStringBase;
+    ///
+    /// let s = "x = 12y";
+    /// let e = u8::from_string_base(10, &s[4..]).unwrap_err().offset_by(4);
+    /// assert_eq!(e.kind(), ParseNumberErrorKind::InvalidDigit(6));
+    /// ```
+    #[must_use]
+    pub const fn offset_by(self, offset: usize) -> ParseNumberError {
+        match self.kind {
+            ParseNumberErrorKind::InvalidDigit(i) => ParseNumberError {
+                kind: ParseNumberErrorKind::InvalidDigit(i + offset),
+            },
+            _ => self,
+        }
+    }
+}
+
+impl From<ParseNumberErrorKind> for ParseNumberError {
+    /// Creates a [`ParseNumberError`] of the specified kind.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::parse_error::{
+    ///     ParseNumberError, ParseNumberErrorKind,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     ParseNumberError::from(ParseNumberErrorKind::Overflow).kind(),
+    ///     ParseNumberErrorKind::Overflow
+    /// );
+    /// ```
+    #[inline]
+    fn from(kind: ParseNumberErrorKind) -> ParseNumberError {
+        ParseNumberError::new(kind)
+    }
+}
+
+impl Display for ParseNumberError {
+    /// Writes a description of a [`ParseNumberError`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::parse_error::{
+    ///     ParseNumberError, ParseNumberErrorKind,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     ParseNumberError::new(ParseNumberErrorKind::InvalidBase(37)).to_string(),
+    ///     "invalid base 37"
+    /// );
+    /// assert_eq!(
+    ///     ParseNumberError::new(ParseNumberErrorKind::Inexact).to_string(),
+    ///     "number cannot be represented exactly"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.kind {
+            ParseNumberErrorKind::Empty => f.write_str("no digits to parse"),
+            ParseNumberErrorKind::InvalidDigit(i) => {
+                write!(f, "invalid digit at byte offset {i}")
+            }
+            ParseNumberErrorKind::InvalidBase(base) => write!(f, "invalid base {base}"),
+            ParseNumberErrorKind::SignNotAllowed => f.write_str("sign not allowed"),
+            ParseNumberErrorKind::Overflow => f.write_str("number out of range"),
+            ParseNumberErrorKind::Inexact => f.write_str("number cannot be represented exactly"),
+            ParseNumberErrorKind::ZeroDenominator => f.write_str("denominator is zero"),
+        }
+    }
+}
+
+impl Error for ParseNumberError {}
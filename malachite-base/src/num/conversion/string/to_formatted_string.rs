@@ -0,0 +1,137 @@
+use crate::num::arithmetic::traits::UnsignedAbs;
+use crate::num::basic::signeds::PrimitiveSigned;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::string::options::FormatOptions;
+use crate::num::conversion::traits::{ExactFrom, ToFormattedString, ToStringBase};
+
+/// Assembles a formatted number from its sign and the digits before and after the point.
+///
+/// The digits must be given as lowercase ASCII characters, as produced by
+/// [`ToStringBase::to_string_base`]; they are converted to uppercase if the options require it.
+/// Group separators are inserted into the integer digits according to the options' digit
+/// grouping. If the options specify a number of fraction digits, `frac_digits` is padded with
+/// zeros to that length; it must not be longer. If the resulting fraction is empty, no decimal
+/// separator is written.
+///
+/// # Worst-case complexity
+/// $T(n) = O(n)$
+///
+/// $M(n) = O(n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `int_digits.len() + frac_digits.len()`,
+/// plus the number of fraction digits in `options`.
+#[doc(hidden)]
+pub fn format_digits(
+    negative: bool,
+    int_digits: &str,
+    frac_digits: &str,
+    options: FormatOptions,
+) -> String {
+    let mut s = String::new();
+    if negative {
+        s.push('-');
+    }
+    let int_len = u64::exact_from(int_digits.len());
+    for (i, c) in int_digits.chars().enumerate() {
+        let digits_after = int_len - u64::exact_from(i);
+        if i != 0 && options.digit_grouping.separator_before(digits_after) {
+            s.push(options.group_separator);
+        }
+        s.push(if options.lowercase {
+            c
+        } else {
+            c.to_ascii_uppercase()
+        });
+    }
+    let frac_len = u64::exact_from(frac_digits.len());
+    let padding = options.fraction_digits.map_or(0, |n| {
+        assert!(frac_len <= n);
+        n - frac_len
+    });
+    if frac_len != 0 || padding != 0 {
+        s.push(options.decimal_separator);
+        for c in frac_digits.chars() {
+            s.push(if options.lowercase {
+                c
+            } else {
+                c.to_ascii_uppercase()
+            });
+        }
+        for _ in 0..padding {
+            s.push('0');
+        }
+    }
+    s
+}
+
+macro_rules! impl_to_formatted_string_unsigned {
+    ($t:ident) => {
+        impl ToFormattedString for $t {
+            /// Converts an unsigned number to a string, using the specified
+            /// [`FormatOptions`](super::options::FormatOptions).
+            ///
+            /// Since the number is an integer, it is never rounded; if the options specify a
+            /// number of fraction digits, that many zeros are written after the point.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`,
+            /// plus the number of fraction digits in `options`.
+            ///
+            /// # Examples
+            /// See [here](super::to_formatted_string#to_formatted_string).
+            #[inline]
+            fn to_formatted_string(&self, options: FormatOptions) -> String {
+                to_formatted_string_unsigned(*self, options)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_to_formatted_string_unsigned);
+
+fn to_formatted_string_unsigned<T: PrimitiveUnsigned>(x: T, options: FormatOptions) -> String {
+    format_digits(false, &x.to_string_base(options.base), "", options)
+}
+
+macro_rules! impl_to_formatted_string_signed {
+    ($t:ident) => {
+        impl ToFormattedString for $t {
+            /// Converts a signed number to a string, using the specified
+            /// [`FormatOptions`](super::options::FormatOptions).
+            ///
+            /// Since the number is an integer, it is never rounded; if the options specify a
+            /// number of fraction digits, that many zeros are written after the point.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`,
+            /// plus the number of fraction digits in `options`.
+            ///
+            /// # Examples
+            /// See [here](super::to_formatted_string#to_formatted_string).
+            #[inline]
+            fn to_formatted_string(&self, options: FormatOptions) -> String {
+                to_formatted_string_signed(*self, options)
+            }
+        }
+    };
+}
+apply_to_signeds!(impl_to_formatted_string_signed);
+
+fn to_formatted_string_signed<T: PrimitiveSigned>(x: T, options: FormatOptions) -> String
+where
+    <T as UnsignedAbs>::Output: PrimitiveUnsigned,
+{
+    format_digits(
+        x < T::ZERO,
+        &x.unsigned_abs().to_string_base(options.base),
+        "",
+        options,
+    )
+}
@@ -10,7 +10,9 @@ use crate::num::conversion::string::to_string::{
 use crate::num::conversion::traits::{ExactFrom, ToSci};
 use crate::rounding_modes::RoundingMode;
 use crate::slices::slice_trailing_zeros;
+use std::cmp::max;
 use std::fmt::{Display, Formatter, Write};
+use std::str::FromStr;
 
 /// A `struct` that can be used to format a number in scientific notation.
 pub struct SciWrapper<'a, T: ToSci> {
@@ -38,6 +40,66 @@ pub fn write_exponent<T: PrimitiveInt>(
     write!(f, "{exp}")
 }
 
+/// Returns the [`ToSciOptions`] used to format a number with [`LowerExp`](std::fmt::LowerExp) or
+/// [`UpperExp`](std::fmt::UpperExp).
+///
+/// If the formatter specifies a precision $p$, the number is rounded to $p + 1$ significant
+/// digits, and trailing zeros are kept. Otherwise, if `exact` is `true`, the number is written in
+/// full, and if `exact` is `false`, the default options are used.
+#[doc(hidden)]
+pub fn exp_options(f: &Formatter, exact: bool) -> ToSciOptions {
+    let mut options = ToSciOptions::default();
+    if let Some(precision) = f.precision() {
+        options.set_precision(u64::exact_from(precision) + 1);
+        options.set_include_trailing_zeros(true);
+    } else if exact {
+        options.set_size_complete();
+    }
+    options
+}
+
+/// Writes the absolute value of a number, given as a base-10 string produced by [`ToSci`], in the
+/// form used by [`LowerExp`](std::fmt::LowerExp) and [`UpperExp`](std::fmt::UpperExp): a single
+/// digit before the decimal point, followed by an exponent that is always present.
+///
+/// If the formatter specifies a precision $p$, the significand is padded with zeros to $p + 1$
+/// digits; otherwise, trailing zeros are removed. The output is padded according to the width,
+/// fill, alignment, sign, and zero-padding flags of the formatter.
+#[doc(hidden)]
+pub fn pad_exp(
+    f: &mut Formatter,
+    non_negative: bool,
+    s: &str,
+    e_lowercase: bool,
+) -> std::fmt::Result {
+    let (significand, exp) = match s.find(['e', 'E']) {
+        Some(i) => (&s[..i], i64::from_str(&s[i + 1..]).unwrap()),
+        None => (s, 0),
+    };
+    let point_index = significand.find('.').unwrap_or(significand.len());
+    let mut digits: Vec<u8> = significand.bytes().filter(|&b| b != b'.').collect();
+    let leading_zeros = digits.iter().take_while(|&&b| b == b'0').count();
+    let exp = if leading_zeros == digits.len() {
+        digits.truncate(1);
+        0
+    } else {
+        digits.drain(..leading_zeros);
+        exp + i64::exact_from(point_index) - i64::exact_from(leading_zeros) - 1
+    };
+    if let Some(precision) = f.precision() {
+        digits.resize(precision + 1, b'0');
+    } else {
+        let trailing_zeros = digits.iter().rev().take_while(|&&b| b == b'0').count();
+        digits.truncate(max(digits.len() - trailing_zeros, 1));
+    }
+    if digits.len() > 1 {
+        digits.insert(1, b'.');
+    }
+    digits.push(if e_lowercase { b'e' } else { b'E' });
+    digits.extend_from_slice(exp.to_string().as_bytes());
+    f.pad_integral(non_negative, "", std::str::from_utf8(&digits).unwrap())
+}
+
 fn write_helper<T>(x: T, f: &mut Formatter, options: ToSciOptions) -> std::fmt::Result
 where
     BaseFmtWrapper<T>: Display,
@@ -1,4 +1,5 @@
-use crate::num::conversion::string::options::{FromSciStringOptions, ToSciOptions};
+use crate::num::conversion::string::options::{FormatOptions, FromSciStringOptions, ToSciOptions};
+use crate::num::conversion::string::parse_error::ParseNumberError;
 use crate::num::conversion::string::to_sci::SciWrapper;
 use crate::rounding_modes::RoundingMode;
 use std::cmp::Ordering;
@@ -76,7 +77,9 @@ pub trait PowerOf2Digits<T>: Sized {
 
 /// Converts a string slice in a given base to a value.
 pub trait FromStringBase: Sized {
-    fn from_string_base(base: u8, s: &str) -> Option<Self>;
+    /// Converts a string slice in a given base to a value. If the string can't be parsed, a
+    /// [`ParseNumberError`] describing the problem is returned.
+    fn from_string_base(base: u8, s: &str) -> std::result::Result<Self, ParseNumberError>;
 }
 
 /// Converts a number to a string using a specified base.
@@ -112,15 +115,28 @@ pub trait ToSci: Sized {
     }
 }
 
+/// Converts a number to a string with grouped digits, custom separators, and, optionally, a fixed
+/// number of digits after the point.
+pub trait ToFormattedString {
+    /// Converts a number to a string, using the specified
+    /// [`FormatOptions`](super::string::options::FormatOptions).
+    fn to_formatted_string(&self, options: FormatOptions) -> String;
+}
+
 /// Converts a `&str`, possibly in scientific notation, to a number.
 pub trait FromSciString: Sized {
-    /// Converts a `&str`, possibly in scientific notation, to a number.
-    fn from_sci_string_with_options(s: &str, options: FromSciStringOptions) -> Option<Self>;
+    /// Converts a `&str`, possibly in scientific notation, to a number. If the string can't be
+    /// parsed, a [`ParseNumberError`] describing the problem is returned.
+    fn from_sci_string_with_options(
+        s: &str,
+        options: FromSciStringOptions,
+    ) -> std::result::Result<Self, ParseNumberError>;
 
     /// Converts a `&str`, possibly in scientific notation, to a number, using the default
-    /// [`FromSciStringOptions`](super::string::options::FromSciStringOptions).
+    /// [`FromSciStringOptions`](super::string::options::FromSciStringOptions). If the string
+    /// can't be parsed, a [`ParseNumberError`] describing the problem is returned.
     #[inline]
-    fn from_sci_string(s: &str) -> Option<Self> {
+    fn from_sci_string(s: &str) -> std::result::Result<Self, ParseNumberError> {
         Self::from_sci_string_with_options(s, FromSciStringOptions::default())
     }
 }
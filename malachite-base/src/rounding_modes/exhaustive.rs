@@ -6,7 +6,7 @@ pub type ExhaustiveRoundingModes = Cloned<Iter<'static, RoundingMode>>;
 
 /// Generates all [`RoundingMode`]s.
 ///
-/// The output length is 6.
+/// The output length is 9.
 ///
 /// # Complexity per iteration
 /// Constant time and additional memory.
@@ -25,6 +25,9 @@ pub type ExhaustiveRoundingModes = Cloned<Iter<'static, RoundingMode>>;
 ///         RoundingMode::Floor,
 ///         RoundingMode::Ceiling,
 ///         RoundingMode::Nearest,
+///         RoundingMode::NearestTiesAway,
+///         RoundingMode::NearestTiesTowardZero,
+///         RoundingMode::Odd,
 ///         RoundingMode::Exact,
 ///     ]
 /// );
@@ -28,6 +28,15 @@ impl FromStr for RoundingMode {
     /// assert_eq!(RoundingMode::from_str("Floor"), Ok(RoundingMode::Floor));
     /// assert_eq!(RoundingMode::from_str("Ceiling"), Ok(RoundingMode::Ceiling));
     /// assert_eq!(RoundingMode::from_str("Nearest"), Ok(RoundingMode::Nearest));
+    /// assert_eq!(
+    ///     RoundingMode::from_str("NearestTiesAway"),
+    ///     Ok(RoundingMode::NearestTiesAway)
+    /// );
+    /// assert_eq!(
+    ///     RoundingMode::from_str("NearestTiesTowardZero"),
+    ///     Ok(RoundingMode::NearestTiesTowardZero)
+    /// );
+    /// assert_eq!(RoundingMode::from_str("Odd"), Ok(RoundingMode::Odd));
     /// assert_eq!(RoundingMode::from_str("Exact"), Ok(RoundingMode::Exact));
     /// assert_eq!(RoundingMode::from_str("abc"), Err("abc".to_string()));
     /// ```
@@ -39,6 +48,9 @@ impl FromStr for RoundingMode {
             "Floor" => Ok(RoundingMode::Floor),
             "Ceiling" => Ok(RoundingMode::Ceiling),
             "Nearest" => Ok(RoundingMode::Nearest),
+            "NearestTiesAway" => Ok(RoundingMode::NearestTiesAway),
+            "NearestTiesTowardZero" => Ok(RoundingMode::NearestTiesTowardZero),
+            "Odd" => Ok(RoundingMode::Odd),
             "Exact" => Ok(RoundingMode::Exact),
             _ => Err(src.to_string()),
         }
@@ -11,17 +11,17 @@ use crate::named::Named;
 /// Here are some examples of how floating-point values would be rounded to integer values using
 /// the different `RoundingMode`s.
 ///
-/// | x    | `Floor` | `Ceiling` | `Down` | `Up` | `Nearest` | `Exact`    |
-/// |------|---------|-----------|--------|------|-----------|------------|
-/// |  3.0 |       3 |         3 |      3 |    3 |         3 |          3 |
-/// |  3.2 |       3 |         4 |      3 |    4 |         3 | `panic!()` |
-/// |  3.8 |       3 |         4 |      3 |    4 |         4 | `panic!()` |
-/// |  3.5 |       3 |         4 |      3 |    4 |         4 | `panic!()` |
-/// |  4.5 |       4 |         5 |      4 |    5 |         4 | `panic!()` |
-/// | -3.2 |      -4 |        -3 |     -3 |   -4 |        -3 | `panic!()` |
-/// | -3.8 |      -4 |        -3 |     -3 |   -4 |        -4 | `panic!()` |
-/// | -3.5 |      -4 |        -3 |     -3 |   -4 |        -4 | `panic!()` |
-/// | -4.5 |      -5 |        -4 |     -4 |   -5 |        -4 | `panic!()` |
+/// | x    | `Floor` | `Ceiling` | `Down` | `Up` | `Nearest` | `NearestTiesAway` | `NearestTiesTowardZero` | `Odd` | `Exact`    |
+/// |------|---------|-----------|--------|------|-----------|-------------------|-------------------------|-------|------------|
+/// |  3.0 |       3 |         3 |      3 |    3 |         3 |                 3 |                       3 |     3 |          3 |
+/// |  3.2 |       3 |         4 |      3 |    4 |         3 |                 3 |                       3 |     3 | `panic!()` |
+/// |  3.8 |       3 |         4 |      3 |    4 |         4 |                 4 |                       4 |     3 | `panic!()` |
+/// |  3.5 |       3 |         4 |      3 |    4 |         4 |                 4 |                       3 |     3 | `panic!()` |
+/// |  4.5 |       4 |         5 |      4 |    5 |         4 |                 5 |                       4 |     5 | `panic!()` |
+/// | -3.2 |      -4 |        -3 |     -3 |   -4 |        -3 |                -3 |                      -3 |    -3 | `panic!()` |
+/// | -3.8 |      -4 |        -3 |     -3 |   -4 |        -4 |                -4 |                      -4 |    -3 | `panic!()` |
+/// | -3.5 |      -4 |        -3 |     -3 |   -4 |        -4 |                -4 |                      -3 |    -3 | `panic!()` |
+/// | -4.5 |      -5 |        -4 |     -4 |   -5 |        -4 |                -5 |                      -4 |    -5 | `panic!()` |
 ///
 /// Sometimes a `RoundingMode` is used in an unusual context, such as rounding an integer to a
 /// floating-point number, in which case further explanation of its behavior is provided at the
@@ -58,19 +58,56 @@ pub enum RoundingMode {
     /// nearest even integer. This is also called _bankers' rounding_ and is often used as a
     /// default.
     Nearest,
+    /// Applies the function
+    /// $$
+    ///   x \mapsto \\begin{cases}
+    ///       \operatorname{sgn}(x) \lfloor |x| \rfloor & |x| - \lfloor |x| \rfloor < \frac{1}{2} \\\\
+    ///       \operatorname{sgn}(x) \lceil |x| \rceil & |x| - \lfloor |x| \rfloor \geq \frac{1}{2}.
+    ///   \\end{cases}
+    /// $$
+    /// In other words, it rounds to the nearest integer, and when there's a tie, it rounds away
+    /// from $0$. This is IEEE 754's `roundTiesToAway`, and is the rounding taught in schools.
+    NearestTiesAway,
+    /// Applies the function
+    /// $$
+    ///   x \mapsto \\begin{cases}
+    ///       \operatorname{sgn}(x) \lfloor |x| \rfloor & |x| - \lfloor |x| \rfloor \leq \frac{1}{2} \\\\
+    ///       \operatorname{sgn}(x) \lceil |x| \rceil & |x| - \lfloor |x| \rfloor > \frac{1}{2}.
+    ///   \\end{cases}
+    /// $$
+    /// In other words, it rounds to the nearest integer, and when there's a tie, it rounds towards
+    /// $0$.
+    NearestTiesTowardZero,
+    /// Applies the function
+    /// $$
+    ///   x \mapsto \\begin{cases}
+    ///       x & x \in \Z \\\\
+    ///       \lfloor x \rfloor & x \notin \Z \\ \text{and} \\ \lfloor x \rfloor \\ \text{is odd} \\\\
+    ///       \lceil x \rceil & x \notin \Z \\ \text{and} \\ \lceil x \rceil \\ \text{is odd.}
+    ///   \\end{cases}
+    /// $$
+    /// In other words, an exact value is left unchanged, and an inexact value is rounded to
+    /// whichever neighbor is odd. When rounding to a floating-point number, the neighbor with an
+    /// odd significand is chosen. Rounding to odd at a few extra bits of precision and then
+    /// rounding again, in any other mode, gives the same result as rounding once, so this mode is
+    /// useful for intermediate results.
+    Odd,
     /// Panics if the value is not already rounded.
     Exact,
 }
 
 impl_named!(RoundingMode);
 
-/// A list of all six rounding modes.
-pub const ROUNDING_MODES: [RoundingMode; 6] = [
+/// A list of all nine rounding modes.
+pub const ROUNDING_MODES: [RoundingMode; 9] = [
     RoundingMode::Down,
     RoundingMode::Up,
     RoundingMode::Floor,
     RoundingMode::Ceiling,
     RoundingMode::Nearest,
+    RoundingMode::NearestTiesAway,
+    RoundingMode::NearestTiesTowardZero,
+    RoundingMode::Odd,
     RoundingMode::Exact,
 ];
 
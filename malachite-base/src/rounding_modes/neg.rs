@@ -20,6 +20,8 @@ use std::ops::Neg;
 /// assert_eq!(-RoundingMode::Floor, RoundingMode::Ceiling);
 /// assert_eq!(-RoundingMode::Ceiling, RoundingMode::Floor);
 /// assert_eq!(-RoundingMode::Nearest, RoundingMode::Nearest);
+/// assert_eq!(-RoundingMode::NearestTiesAway, RoundingMode::NearestTiesAway);
+/// assert_eq!(-RoundingMode::Odd, RoundingMode::Odd);
 /// assert_eq!(-RoundingMode::Exact, RoundingMode::Exact);
 /// ```
 impl Neg for RoundingMode {
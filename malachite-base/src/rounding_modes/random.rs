@@ -24,7 +24,18 @@ pub type RandomRoundingModes = Cloned<RandomValuesFromSlice<'static, RoundingMod
 ///
 /// assert_eq!(
 ///     random_rounding_modes(EXAMPLE_SEED).take(10).collect_vec(),
-///     &[Up, Exact, Ceiling, Up, Floor, Nearest, Exact, Up, Floor, Exact]
+///     &[
+///         Up,
+///         Odd,
+///         NearestTiesAway,
+///         Nearest,
+///         NearestTiesTowardZero,
+///         Nearest,
+///         Floor,
+///         Exact,
+///         Up,
+///         Odd
+///     ]
 /// )
 /// ```
 #[inline]
@@ -506,9 +506,12 @@ pub fn exhaustive_primitive_float_unsigned_rounding_mode_triple_gen_var_2<T: Pri
 pub(crate) fn float_rounding_mode_filter_var_1<T: PrimitiveFloat>(p: &(T, RoundingMode)) -> bool {
     let &(f, rm) = p;
     match rm {
-        RoundingMode::Floor | RoundingMode::Up => f >= T::ZERO,
+        RoundingMode::Floor | RoundingMode::Up | RoundingMode::Odd => f >= T::ZERO,
         RoundingMode::Ceiling | RoundingMode::Down => f > T::NEGATIVE_ONE,
-        RoundingMode::Nearest => f >= T::NEGATIVE_ONE / T::TWO,
+        RoundingMode::Nearest | RoundingMode::NearestTiesTowardZero => {
+            f >= T::NEGATIVE_ONE / T::TWO
+        }
+        RoundingMode::NearestTiesAway => f > T::NEGATIVE_ONE / T::TWO,
         RoundingMode::Exact => f >= T::ZERO && f.is_integer(),
     }
 }
@@ -547,10 +550,13 @@ pub fn exhaustive_primitive_float_rounding_mode_pair_gen_var_3<
             exhaustive_rounding_modes(),
         )
         .filter(move |&(f, rm)| match rm {
-            RoundingMode::Up => f >= f_min && f <= f_max,
+            RoundingMode::Up | RoundingMode::Odd => f >= f_min && f <= f_max,
             RoundingMode::Ceiling => f <= f_max,
             RoundingMode::Floor => f >= f_min,
-            RoundingMode::Down | RoundingMode::Nearest => true,
+            RoundingMode::Down
+            | RoundingMode::Nearest
+            | RoundingMode::NearestTiesAway
+            | RoundingMode::NearestTiesTowardZero => true,
             RoundingMode::Exact => U::convertible_from(f),
         }),
     )
@@ -1231,7 +1237,11 @@ fn round_to_multiple_unsigned_helper<T: PrimitiveUnsigned>(x: T, y: T, rm: Round
     if x == y {
         true
     } else if y == T::ZERO {
-        rm == RoundingMode::Down || rm == RoundingMode::Floor || rm == RoundingMode::Nearest
+        rm == RoundingMode::Down
+            || rm == RoundingMode::Floor
+            || rm == RoundingMode::Nearest
+            || rm == RoundingMode::NearestTiesAway
+            || rm == RoundingMode::NearestTiesTowardZero
     } else {
         x.div_round(y, rm).0.checked_mul(y).is_some()
     }
@@ -3130,7 +3140,12 @@ pub(crate) fn round_to_multiple_unsigned_filter_map<T: PrimitiveUnsigned>(
     if x == y {
         Some((x, y, rm))
     } else if y == T::ZERO {
-        if rm == RoundingMode::Floor || rm == RoundingMode::Down || rm == RoundingMode::Nearest {
+        if rm == RoundingMode::Floor
+            || rm == RoundingMode::Down
+            || rm == RoundingMode::Nearest
+            || rm == RoundingMode::NearestTiesAway
+            || rm == RoundingMode::NearestTiesTowardZero
+        {
             Some((x, y, rm))
         } else {
             None
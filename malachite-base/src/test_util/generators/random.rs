@@ -993,10 +993,13 @@ pub fn random_primitive_float_rounding_mode_pair_gen_var_3<
             &random_rounding_modes,
         )
         .filter(move |&(f, rm)| match rm {
-            RoundingMode::Up => f >= f_min && f <= f_max,
+            RoundingMode::Up | RoundingMode::Odd => f >= f_min && f <= f_max,
             RoundingMode::Ceiling => f <= f_max,
             RoundingMode::Floor => f >= f_min,
-            RoundingMode::Down | RoundingMode::Nearest => true,
+            RoundingMode::Down
+            | RoundingMode::Nearest
+            | RoundingMode::NearestTiesAway
+            | RoundingMode::NearestTiesTowardZero => true,
             RoundingMode::Exact => U::convertible_from(f),
         }),
     )
@@ -1 +1 @@
-pub const ROUNDING_MODE_CHARS: &str = "CDEFNUacegilnoprstwx";
+pub const ROUNDING_MODE_CHARS: &str = "ACDEFNOTUZacdegilnoprstwxy";
@@ -330,6 +330,7 @@ pub mod num {
                 pub mod from_sci_string_options;
                 pub mod to_sci_options;
             }
+            pub mod to_formatted_string;
             pub mod to_sci;
             pub mod to_string;
         }
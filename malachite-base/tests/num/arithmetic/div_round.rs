@@ -27,6 +27,15 @@ fn test_div_round_unsigned() {
     test::<u8>(0, 1, RoundingMode::Up, 0, Ordering::Equal);
     test::<u8>(0, 1, RoundingMode::Ceiling, 0, Ordering::Equal);
     test::<u8>(0, 1, RoundingMode::Nearest, 0, Ordering::Equal);
+    test::<u8>(0, 1, RoundingMode::NearestTiesAway, 0, Ordering::Equal);
+    test::<u8>(
+        0,
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Equal,
+    );
+    test::<u8>(0, 1, RoundingMode::Odd, 0, Ordering::Equal);
     test::<u8>(0, 1, RoundingMode::Exact, 0, Ordering::Equal);
 
     test::<u16>(0, 123, RoundingMode::Down, 0, Ordering::Equal);
@@ -34,6 +43,15 @@ fn test_div_round_unsigned() {
     test::<u16>(0, 123, RoundingMode::Up, 0, Ordering::Equal);
     test::<u16>(0, 123, RoundingMode::Ceiling, 0, Ordering::Equal);
     test::<u16>(0, 123, RoundingMode::Nearest, 0, Ordering::Equal);
+    test::<u16>(0, 123, RoundingMode::NearestTiesAway, 0, Ordering::Equal);
+    test::<u16>(
+        0,
+        123,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Equal,
+    );
+    test::<u16>(0, 123, RoundingMode::Odd, 0, Ordering::Equal);
     test::<u16>(0, 123, RoundingMode::Exact, 0, Ordering::Equal);
 
     test::<u32>(1, 1, RoundingMode::Down, 1, Ordering::Equal);
@@ -41,6 +59,15 @@ fn test_div_round_unsigned() {
     test::<u32>(1, 1, RoundingMode::Up, 1, Ordering::Equal);
     test::<u32>(1, 1, RoundingMode::Ceiling, 1, Ordering::Equal);
     test::<u32>(1, 1, RoundingMode::Nearest, 1, Ordering::Equal);
+    test::<u32>(1, 1, RoundingMode::NearestTiesAway, 1, Ordering::Equal);
+    test::<u32>(
+        1,
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        1,
+        Ordering::Equal,
+    );
+    test::<u32>(1, 1, RoundingMode::Odd, 1, Ordering::Equal);
     test::<u32>(1, 1, RoundingMode::Exact, 1, Ordering::Equal);
 
     test::<u64>(123, 1, RoundingMode::Down, 123, Ordering::Equal);
@@ -48,6 +75,15 @@ fn test_div_round_unsigned() {
     test::<u64>(123, 1, RoundingMode::Up, 123, Ordering::Equal);
     test::<u64>(123, 1, RoundingMode::Ceiling, 123, Ordering::Equal);
     test::<u64>(123, 1, RoundingMode::Nearest, 123, Ordering::Equal);
+    test::<u64>(123, 1, RoundingMode::NearestTiesAway, 123, Ordering::Equal);
+    test::<u64>(
+        123,
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Equal,
+    );
+    test::<u64>(123, 1, RoundingMode::Odd, 123, Ordering::Equal);
     test::<u64>(123, 1, RoundingMode::Exact, 123, Ordering::Equal);
 
     test::<u128>(123, 2, RoundingMode::Down, 61, Ordering::Less);
@@ -55,18 +91,45 @@ fn test_div_round_unsigned() {
     test::<u128>(123, 2, RoundingMode::Up, 62, Ordering::Greater);
     test::<u128>(123, 2, RoundingMode::Ceiling, 62, Ordering::Greater);
     test::<u128>(123, 2, RoundingMode::Nearest, 62, Ordering::Greater);
+    test::<u128>(123, 2, RoundingMode::NearestTiesAway, 62, Ordering::Greater);
+    test::<u128>(
+        123,
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        61,
+        Ordering::Less,
+    );
+    test::<u128>(123, 2, RoundingMode::Odd, 61, Ordering::Less);
 
     test::<usize>(125, 2, RoundingMode::Down, 62, Ordering::Less);
     test::<usize>(125, 2, RoundingMode::Floor, 62, Ordering::Less);
     test::<usize>(125, 2, RoundingMode::Up, 63, Ordering::Greater);
     test::<usize>(125, 2, RoundingMode::Ceiling, 63, Ordering::Greater);
     test::<usize>(125, 2, RoundingMode::Nearest, 62, Ordering::Less);
+    test::<usize>(125, 2, RoundingMode::NearestTiesAway, 63, Ordering::Greater);
+    test::<usize>(
+        125,
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        62,
+        Ordering::Less,
+    );
+    test::<usize>(125, 2, RoundingMode::Odd, 63, Ordering::Greater);
 
     test::<u8>(123, 123, RoundingMode::Down, 1, Ordering::Equal);
     test::<u8>(123, 123, RoundingMode::Floor, 1, Ordering::Equal);
     test::<u8>(123, 123, RoundingMode::Up, 1, Ordering::Equal);
     test::<u8>(123, 123, RoundingMode::Ceiling, 1, Ordering::Equal);
     test::<u8>(123, 123, RoundingMode::Nearest, 1, Ordering::Equal);
+    test::<u8>(123, 123, RoundingMode::NearestTiesAway, 1, Ordering::Equal);
+    test::<u8>(
+        123,
+        123,
+        RoundingMode::NearestTiesTowardZero,
+        1,
+        Ordering::Equal,
+    );
+    test::<u8>(123, 123, RoundingMode::Odd, 1, Ordering::Equal);
     test::<u8>(123, 123, RoundingMode::Exact, 1, Ordering::Equal);
 
     test::<u16>(123, 456, RoundingMode::Down, 0, Ordering::Less);
@@ -74,6 +137,15 @@ fn test_div_round_unsigned() {
     test::<u16>(123, 456, RoundingMode::Up, 1, Ordering::Greater);
     test::<u16>(123, 456, RoundingMode::Ceiling, 1, Ordering::Greater);
     test::<u16>(123, 456, RoundingMode::Nearest, 0, Ordering::Less);
+    test::<u16>(123, 456, RoundingMode::NearestTiesAway, 0, Ordering::Less);
+    test::<u16>(
+        123,
+        456,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Less,
+    );
+    test::<u16>(123, 456, RoundingMode::Odd, 1, Ordering::Greater);
 
     test::<u64>(
         1000000000000,
@@ -378,6 +450,15 @@ fn test_div_round_signed() {
     test::<i8>(0, 1, RoundingMode::Up, 0, Ordering::Equal);
     test::<i8>(0, 1, RoundingMode::Ceiling, 0, Ordering::Equal);
     test::<i8>(0, 1, RoundingMode::Nearest, 0, Ordering::Equal);
+    test::<i8>(0, 1, RoundingMode::NearestTiesAway, 0, Ordering::Equal);
+    test::<i8>(
+        0,
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Equal,
+    );
+    test::<i8>(0, 1, RoundingMode::Odd, 0, Ordering::Equal);
     test::<i8>(0, 1, RoundingMode::Exact, 0, Ordering::Equal);
 
     test::<i16>(0, 123, RoundingMode::Down, 0, Ordering::Equal);
@@ -385,6 +466,15 @@ fn test_div_round_signed() {
     test::<i16>(0, 123, RoundingMode::Up, 0, Ordering::Equal);
     test::<i16>(0, 123, RoundingMode::Ceiling, 0, Ordering::Equal);
     test::<i16>(0, 123, RoundingMode::Nearest, 0, Ordering::Equal);
+    test::<i16>(0, 123, RoundingMode::NearestTiesAway, 0, Ordering::Equal);
+    test::<i16>(
+        0,
+        123,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Equal,
+    );
+    test::<i16>(0, 123, RoundingMode::Odd, 0, Ordering::Equal);
     test::<i16>(0, 123, RoundingMode::Exact, 0, Ordering::Equal);
 
     test::<i32>(1, 1, RoundingMode::Down, 1, Ordering::Equal);
@@ -392,6 +482,15 @@ fn test_div_round_signed() {
     test::<i32>(1, 1, RoundingMode::Up, 1, Ordering::Equal);
     test::<i32>(1, 1, RoundingMode::Ceiling, 1, Ordering::Equal);
     test::<i32>(1, 1, RoundingMode::Nearest, 1, Ordering::Equal);
+    test::<i32>(1, 1, RoundingMode::NearestTiesAway, 1, Ordering::Equal);
+    test::<i32>(
+        1,
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        1,
+        Ordering::Equal,
+    );
+    test::<i32>(1, 1, RoundingMode::Odd, 1, Ordering::Equal);
     test::<i32>(1, 1, RoundingMode::Exact, 1, Ordering::Equal);
 
     test::<i64>(123, 1, RoundingMode::Down, 123, Ordering::Equal);
@@ -399,6 +498,15 @@ fn test_div_round_signed() {
     test::<i64>(123, 1, RoundingMode::Up, 123, Ordering::Equal);
     test::<i64>(123, 1, RoundingMode::Ceiling, 123, Ordering::Equal);
     test::<i64>(123, 1, RoundingMode::Nearest, 123, Ordering::Equal);
+    test::<i64>(123, 1, RoundingMode::NearestTiesAway, 123, Ordering::Equal);
+    test::<i64>(
+        123,
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Equal,
+    );
+    test::<i64>(123, 1, RoundingMode::Odd, 123, Ordering::Equal);
     test::<i64>(123, 1, RoundingMode::Exact, 123, Ordering::Equal);
 
     test::<i128>(123, 2, RoundingMode::Down, 61, Ordering::Less);
@@ -406,18 +514,45 @@ fn test_div_round_signed() {
     test::<i128>(123, 2, RoundingMode::Up, 62, Ordering::Greater);
     test::<i128>(123, 2, RoundingMode::Ceiling, 62, Ordering::Greater);
     test::<i128>(123, 2, RoundingMode::Nearest, 62, Ordering::Greater);
+    test::<i128>(123, 2, RoundingMode::NearestTiesAway, 62, Ordering::Greater);
+    test::<i128>(
+        123,
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        61,
+        Ordering::Less,
+    );
+    test::<i128>(123, 2, RoundingMode::Odd, 61, Ordering::Less);
 
     test::<isize>(125, 2, RoundingMode::Down, 62, Ordering::Less);
     test::<isize>(125, 2, RoundingMode::Floor, 62, Ordering::Less);
     test::<isize>(125, 2, RoundingMode::Up, 63, Ordering::Greater);
     test::<isize>(125, 2, RoundingMode::Ceiling, 63, Ordering::Greater);
     test::<isize>(125, 2, RoundingMode::Nearest, 62, Ordering::Less);
+    test::<isize>(125, 2, RoundingMode::NearestTiesAway, 63, Ordering::Greater);
+    test::<isize>(
+        125,
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        62,
+        Ordering::Less,
+    );
+    test::<isize>(125, 2, RoundingMode::Odd, 63, Ordering::Greater);
 
     test::<i8>(123, 123, RoundingMode::Down, 1, Ordering::Equal);
     test::<i8>(123, 123, RoundingMode::Floor, 1, Ordering::Equal);
     test::<i8>(123, 123, RoundingMode::Up, 1, Ordering::Equal);
     test::<i8>(123, 123, RoundingMode::Ceiling, 1, Ordering::Equal);
     test::<i8>(123, 123, RoundingMode::Nearest, 1, Ordering::Equal);
+    test::<i8>(123, 123, RoundingMode::NearestTiesAway, 1, Ordering::Equal);
+    test::<i8>(
+        123,
+        123,
+        RoundingMode::NearestTiesTowardZero,
+        1,
+        Ordering::Equal,
+    );
+    test::<i8>(123, 123, RoundingMode::Odd, 1, Ordering::Equal);
     test::<i8>(123, 123, RoundingMode::Exact, 1, Ordering::Equal);
 
     test::<i16>(123, 456, RoundingMode::Down, 0, Ordering::Less);
@@ -425,6 +560,15 @@ fn test_div_round_signed() {
     test::<i16>(123, 456, RoundingMode::Up, 1, Ordering::Greater);
     test::<i16>(123, 456, RoundingMode::Ceiling, 1, Ordering::Greater);
     test::<i16>(123, 456, RoundingMode::Nearest, 0, Ordering::Less);
+    test::<i16>(123, 456, RoundingMode::NearestTiesAway, 0, Ordering::Less);
+    test::<i16>(
+        123,
+        456,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Less,
+    );
+    test::<i16>(123, 456, RoundingMode::Odd, 1, Ordering::Greater);
 
     test::<i64>(
         1000000000000,
@@ -719,6 +863,15 @@ fn test_div_round_signed() {
     test::<i8>(0, -1, RoundingMode::Up, 0, Ordering::Equal);
     test::<i8>(0, -1, RoundingMode::Ceiling, 0, Ordering::Equal);
     test::<i8>(0, -1, RoundingMode::Nearest, 0, Ordering::Equal);
+    test::<i8>(0, -1, RoundingMode::NearestTiesAway, 0, Ordering::Equal);
+    test::<i8>(
+        0,
+        -1,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Equal,
+    );
+    test::<i8>(0, -1, RoundingMode::Odd, 0, Ordering::Equal);
     test::<i8>(0, -1, RoundingMode::Exact, 0, Ordering::Equal);
 
     test::<i16>(0, -123, RoundingMode::Down, 0, Ordering::Equal);
@@ -726,6 +879,15 @@ fn test_div_round_signed() {
     test::<i16>(0, -123, RoundingMode::Up, 0, Ordering::Equal);
     test::<i16>(0, -123, RoundingMode::Ceiling, 0, Ordering::Equal);
     test::<i16>(0, -123, RoundingMode::Nearest, 0, Ordering::Equal);
+    test::<i16>(0, -123, RoundingMode::NearestTiesAway, 0, Ordering::Equal);
+    test::<i16>(
+        0,
+        -123,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Equal,
+    );
+    test::<i16>(0, -123, RoundingMode::Odd, 0, Ordering::Equal);
     test::<i16>(0, -123, RoundingMode::Exact, 0, Ordering::Equal);
 
     test::<i32>(1, -1, RoundingMode::Down, -1, Ordering::Equal);
@@ -733,6 +895,15 @@ fn test_div_round_signed() {
     test::<i32>(1, -1, RoundingMode::Up, -1, Ordering::Equal);
     test::<i32>(1, -1, RoundingMode::Ceiling, -1, Ordering::Equal);
     test::<i32>(1, -1, RoundingMode::Nearest, -1, Ordering::Equal);
+    test::<i32>(1, -1, RoundingMode::NearestTiesAway, -1, Ordering::Equal);
+    test::<i32>(
+        1,
+        -1,
+        RoundingMode::NearestTiesTowardZero,
+        -1,
+        Ordering::Equal,
+    );
+    test::<i32>(1, -1, RoundingMode::Odd, -1, Ordering::Equal);
     test::<i32>(1, -1, RoundingMode::Exact, -1, Ordering::Equal);
 
     test::<i64>(123, -1, RoundingMode::Down, -123, Ordering::Equal);
@@ -740,6 +911,21 @@ fn test_div_round_signed() {
     test::<i64>(123, -1, RoundingMode::Up, -123, Ordering::Equal);
     test::<i64>(123, -1, RoundingMode::Ceiling, -123, Ordering::Equal);
     test::<i64>(123, -1, RoundingMode::Nearest, -123, Ordering::Equal);
+    test::<i64>(
+        123,
+        -1,
+        RoundingMode::NearestTiesAway,
+        -123,
+        Ordering::Equal,
+    );
+    test::<i64>(
+        123,
+        -1,
+        RoundingMode::NearestTiesTowardZero,
+        -123,
+        Ordering::Equal,
+    );
+    test::<i64>(123, -1, RoundingMode::Odd, -123, Ordering::Equal);
     test::<i64>(123, -1, RoundingMode::Exact, -123, Ordering::Equal);
 
     test::<i128>(123, -2, RoundingMode::Down, -61, Ordering::Greater);
@@ -747,18 +933,51 @@ fn test_div_round_signed() {
     test::<i128>(123, -2, RoundingMode::Up, -62, Ordering::Less);
     test::<i128>(123, -2, RoundingMode::Ceiling, -61, Ordering::Greater);
     test::<i128>(123, -2, RoundingMode::Nearest, -62, Ordering::Less);
+    test::<i128>(123, -2, RoundingMode::NearestTiesAway, -62, Ordering::Less);
+    test::<i128>(
+        123,
+        -2,
+        RoundingMode::NearestTiesTowardZero,
+        -61,
+        Ordering::Greater,
+    );
+    test::<i128>(123, -2, RoundingMode::Odd, -61, Ordering::Greater);
 
     test::<isize>(125, -2, RoundingMode::Down, -62, Ordering::Greater);
     test::<isize>(125, -2, RoundingMode::Floor, -63, Ordering::Less);
     test::<isize>(125, -2, RoundingMode::Up, -63, Ordering::Less);
     test::<isize>(125, -2, RoundingMode::Ceiling, -62, Ordering::Greater);
     test::<isize>(125, -2, RoundingMode::Nearest, -62, Ordering::Greater);
+    test::<isize>(125, -2, RoundingMode::NearestTiesAway, -63, Ordering::Less);
+    test::<isize>(
+        125,
+        -2,
+        RoundingMode::NearestTiesTowardZero,
+        -62,
+        Ordering::Greater,
+    );
+    test::<isize>(125, -2, RoundingMode::Odd, -63, Ordering::Less);
 
     test::<i8>(123, -123, RoundingMode::Down, -1, Ordering::Equal);
     test::<i8>(123, -123, RoundingMode::Floor, -1, Ordering::Equal);
     test::<i8>(123, -123, RoundingMode::Up, -1, Ordering::Equal);
     test::<i8>(123, -123, RoundingMode::Ceiling, -1, Ordering::Equal);
     test::<i8>(123, -123, RoundingMode::Nearest, -1, Ordering::Equal);
+    test::<i8>(
+        123,
+        -123,
+        RoundingMode::NearestTiesAway,
+        -1,
+        Ordering::Equal,
+    );
+    test::<i8>(
+        123,
+        -123,
+        RoundingMode::NearestTiesTowardZero,
+        -1,
+        Ordering::Equal,
+    );
+    test::<i8>(123, -123, RoundingMode::Odd, -1, Ordering::Equal);
     test::<i8>(123, -123, RoundingMode::Exact, -1, Ordering::Equal);
 
     test::<i16>(123, -456, RoundingMode::Down, 0, Ordering::Greater);
@@ -766,6 +985,21 @@ fn test_div_round_signed() {
     test::<i16>(123, -456, RoundingMode::Up, -1, Ordering::Less);
     test::<i16>(123, -456, RoundingMode::Ceiling, 0, Ordering::Greater);
     test::<i16>(123, -456, RoundingMode::Nearest, 0, Ordering::Greater);
+    test::<i16>(
+        123,
+        -456,
+        RoundingMode::NearestTiesAway,
+        0,
+        Ordering::Greater,
+    );
+    test::<i16>(
+        123,
+        -456,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Greater,
+    );
+    test::<i16>(123, -456, RoundingMode::Odd, -1, Ordering::Less);
 
     test::<i64>(
         1000000000000,
@@ -1060,6 +1294,15 @@ fn test_div_round_signed() {
     test::<i8>(-1, 1, RoundingMode::Up, -1, Ordering::Equal);
     test::<i8>(-1, 1, RoundingMode::Ceiling, -1, Ordering::Equal);
     test::<i8>(-1, 1, RoundingMode::Nearest, -1, Ordering::Equal);
+    test::<i8>(-1, 1, RoundingMode::NearestTiesAway, -1, Ordering::Equal);
+    test::<i8>(
+        -1,
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        -1,
+        Ordering::Equal,
+    );
+    test::<i8>(-1, 1, RoundingMode::Odd, -1, Ordering::Equal);
     test::<i8>(-1, 1, RoundingMode::Exact, -1, Ordering::Equal);
 
     test::<i16>(-123, 1, RoundingMode::Down, -123, Ordering::Equal);
@@ -1067,6 +1310,21 @@ fn test_div_round_signed() {
     test::<i16>(-123, 1, RoundingMode::Up, -123, Ordering::Equal);
     test::<i16>(-123, 1, RoundingMode::Ceiling, -123, Ordering::Equal);
     test::<i16>(-123, 1, RoundingMode::Nearest, -123, Ordering::Equal);
+    test::<i16>(
+        -123,
+        1,
+        RoundingMode::NearestTiesAway,
+        -123,
+        Ordering::Equal,
+    );
+    test::<i16>(
+        -123,
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        -123,
+        Ordering::Equal,
+    );
+    test::<i16>(-123, 1, RoundingMode::Odd, -123, Ordering::Equal);
     test::<i16>(-123, 1, RoundingMode::Exact, -123, Ordering::Equal);
 
     test::<i32>(-123, 2, RoundingMode::Down, -61, Ordering::Greater);
@@ -1074,18 +1332,51 @@ fn test_div_round_signed() {
     test::<i32>(-123, 2, RoundingMode::Up, -62, Ordering::Less);
     test::<i32>(-123, 2, RoundingMode::Ceiling, -61, Ordering::Greater);
     test::<i32>(-123, 2, RoundingMode::Nearest, -62, Ordering::Less);
+    test::<i32>(-123, 2, RoundingMode::NearestTiesAway, -62, Ordering::Less);
+    test::<i32>(
+        -123,
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        -61,
+        Ordering::Greater,
+    );
+    test::<i32>(-123, 2, RoundingMode::Odd, -61, Ordering::Greater);
 
     test::<i64>(-125, 2, RoundingMode::Down, -62, Ordering::Greater);
     test::<i64>(-125, 2, RoundingMode::Floor, -63, Ordering::Less);
     test::<i64>(-125, 2, RoundingMode::Up, -63, Ordering::Less);
     test::<i64>(-125, 2, RoundingMode::Ceiling, -62, Ordering::Greater);
     test::<i64>(-125, 2, RoundingMode::Nearest, -62, Ordering::Greater);
+    test::<i64>(-125, 2, RoundingMode::NearestTiesAway, -63, Ordering::Less);
+    test::<i64>(
+        -125,
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        -62,
+        Ordering::Greater,
+    );
+    test::<i64>(-125, 2, RoundingMode::Odd, -63, Ordering::Less);
 
     test::<i128>(-123, 123, RoundingMode::Down, -1, Ordering::Equal);
     test::<i128>(-123, 123, RoundingMode::Floor, -1, Ordering::Equal);
     test::<i128>(-123, 123, RoundingMode::Up, -1, Ordering::Equal);
     test::<i128>(-123, 123, RoundingMode::Ceiling, -1, Ordering::Equal);
     test::<i128>(-123, 123, RoundingMode::Nearest, -1, Ordering::Equal);
+    test::<i128>(
+        -123,
+        123,
+        RoundingMode::NearestTiesAway,
+        -1,
+        Ordering::Equal,
+    );
+    test::<i128>(
+        -123,
+        123,
+        RoundingMode::NearestTiesTowardZero,
+        -1,
+        Ordering::Equal,
+    );
+    test::<i128>(-123, 123, RoundingMode::Odd, -1, Ordering::Equal);
     test::<i128>(-123, 123, RoundingMode::Exact, -1, Ordering::Equal);
 
     test::<isize>(-123, 456, RoundingMode::Down, 0, Ordering::Greater);
@@ -1093,6 +1384,21 @@ fn test_div_round_signed() {
     test::<isize>(-123, 456, RoundingMode::Up, -1, Ordering::Less);
     test::<isize>(-123, 456, RoundingMode::Ceiling, 0, Ordering::Greater);
     test::<isize>(-123, 456, RoundingMode::Nearest, 0, Ordering::Greater);
+    test::<isize>(
+        -123,
+        456,
+        RoundingMode::NearestTiesAway,
+        0,
+        Ordering::Greater,
+    );
+    test::<isize>(
+        -123,
+        456,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Greater,
+    );
+    test::<isize>(-123, 456, RoundingMode::Odd, -1, Ordering::Less);
 
     test::<i64>(
         -1000000000000,
@@ -1387,6 +1693,15 @@ fn test_div_round_signed() {
     test::<i8>(-1, -1, RoundingMode::Up, 1, Ordering::Equal);
     test::<i8>(-1, -1, RoundingMode::Ceiling, 1, Ordering::Equal);
     test::<i8>(-1, -1, RoundingMode::Nearest, 1, Ordering::Equal);
+    test::<i8>(-1, -1, RoundingMode::NearestTiesAway, 1, Ordering::Equal);
+    test::<i8>(
+        -1,
+        -1,
+        RoundingMode::NearestTiesTowardZero,
+        1,
+        Ordering::Equal,
+    );
+    test::<i8>(-1, -1, RoundingMode::Odd, 1, Ordering::Equal);
     test::<i8>(-1, -1, RoundingMode::Exact, 1, Ordering::Equal);
 
     test::<i16>(-123, -1, RoundingMode::Down, 123, Ordering::Equal);
@@ -1394,6 +1709,21 @@ fn test_div_round_signed() {
     test::<i16>(-123, -1, RoundingMode::Up, 123, Ordering::Equal);
     test::<i16>(-123, -1, RoundingMode::Ceiling, 123, Ordering::Equal);
     test::<i16>(-123, -1, RoundingMode::Nearest, 123, Ordering::Equal);
+    test::<i16>(
+        -123,
+        -1,
+        RoundingMode::NearestTiesAway,
+        123,
+        Ordering::Equal,
+    );
+    test::<i16>(
+        -123,
+        -1,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Equal,
+    );
+    test::<i16>(-123, -1, RoundingMode::Odd, 123, Ordering::Equal);
     test::<i16>(-123, -1, RoundingMode::Exact, 123, Ordering::Equal);
 
     test::<i32>(-123, -2, RoundingMode::Down, 61, Ordering::Less);
@@ -1401,18 +1731,63 @@ fn test_div_round_signed() {
     test::<i32>(-123, -2, RoundingMode::Up, 62, Ordering::Greater);
     test::<i32>(-123, -2, RoundingMode::Ceiling, 62, Ordering::Greater);
     test::<i32>(-123, -2, RoundingMode::Nearest, 62, Ordering::Greater);
+    test::<i32>(
+        -123,
+        -2,
+        RoundingMode::NearestTiesAway,
+        62,
+        Ordering::Greater,
+    );
+    test::<i32>(
+        -123,
+        -2,
+        RoundingMode::NearestTiesTowardZero,
+        61,
+        Ordering::Less,
+    );
+    test::<i32>(-123, -2, RoundingMode::Odd, 61, Ordering::Less);
 
     test::<i64>(-125, -2, RoundingMode::Down, 62, Ordering::Less);
     test::<i64>(-125, -2, RoundingMode::Floor, 62, Ordering::Less);
     test::<i64>(-125, -2, RoundingMode::Up, 63, Ordering::Greater);
     test::<i64>(-125, -2, RoundingMode::Ceiling, 63, Ordering::Greater);
     test::<i64>(-125, -2, RoundingMode::Nearest, 62, Ordering::Less);
+    test::<i64>(
+        -125,
+        -2,
+        RoundingMode::NearestTiesAway,
+        63,
+        Ordering::Greater,
+    );
+    test::<i64>(
+        -125,
+        -2,
+        RoundingMode::NearestTiesTowardZero,
+        62,
+        Ordering::Less,
+    );
+    test::<i64>(-125, -2, RoundingMode::Odd, 63, Ordering::Greater);
 
     test::<i128>(-123, -123, RoundingMode::Down, 1, Ordering::Equal);
     test::<i128>(-123, -123, RoundingMode::Floor, 1, Ordering::Equal);
     test::<i128>(-123, -123, RoundingMode::Up, 1, Ordering::Equal);
     test::<i128>(-123, -123, RoundingMode::Ceiling, 1, Ordering::Equal);
     test::<i128>(-123, -123, RoundingMode::Nearest, 1, Ordering::Equal);
+    test::<i128>(
+        -123,
+        -123,
+        RoundingMode::NearestTiesAway,
+        1,
+        Ordering::Equal,
+    );
+    test::<i128>(
+        -123,
+        -123,
+        RoundingMode::NearestTiesTowardZero,
+        1,
+        Ordering::Equal,
+    );
+    test::<i128>(-123, -123, RoundingMode::Odd, 1, Ordering::Equal);
     test::<i128>(-123, -123, RoundingMode::Exact, 1, Ordering::Equal);
 
     test::<isize>(-123, -456, RoundingMode::Down, 0, Ordering::Less);
@@ -1420,6 +1795,15 @@ fn test_div_round_signed() {
     test::<isize>(-123, -456, RoundingMode::Up, 1, Ordering::Greater);
     test::<isize>(-123, -456, RoundingMode::Ceiling, 1, Ordering::Greater);
     test::<isize>(-123, -456, RoundingMode::Nearest, 0, Ordering::Less);
+    test::<isize>(-123, -456, RoundingMode::NearestTiesAway, 0, Ordering::Less);
+    test::<isize>(
+        -123,
+        -456,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Less,
+    );
+    test::<isize>(-123, -456, RoundingMode::Odd, 1, Ordering::Greater);
 
     test::<i64>(
         -1000000000000,
@@ -1714,6 +2098,21 @@ fn test_div_round_signed() {
     test::<i8>(-128, 1, RoundingMode::Floor, -128, Ordering::Equal);
     test::<i8>(-128, 1, RoundingMode::Ceiling, -128, Ordering::Equal);
     test::<i8>(-128, 1, RoundingMode::Nearest, -128, Ordering::Equal);
+    test::<i8>(
+        -128,
+        1,
+        RoundingMode::NearestTiesAway,
+        -128,
+        Ordering::Equal,
+    );
+    test::<i8>(
+        -128,
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        -128,
+        Ordering::Equal,
+    );
+    test::<i8>(-128, 1, RoundingMode::Odd, -128, Ordering::Equal);
     test::<i8>(-128, 1, RoundingMode::Exact, -128, Ordering::Equal);
 }
 
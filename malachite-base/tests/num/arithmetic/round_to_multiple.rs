@@ -27,6 +27,15 @@ fn test_round_to_multiple_unsigned() {
     test::<u8>(0, 1, RoundingMode::Up, 0, Ordering::Equal);
     test::<u8>(0, 1, RoundingMode::Ceiling, 0, Ordering::Equal);
     test::<u8>(0, 1, RoundingMode::Nearest, 0, Ordering::Equal);
+    test::<u8>(0, 1, RoundingMode::NearestTiesAway, 0, Ordering::Equal);
+    test::<u8>(
+        0,
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Equal,
+    );
+    test::<u8>(0, 1, RoundingMode::Odd, 0, Ordering::Equal);
     test::<u8>(0, 1, RoundingMode::Exact, 0, Ordering::Equal);
 
     test::<u16>(0, 123, RoundingMode::Down, 0, Ordering::Equal);
@@ -34,6 +43,15 @@ fn test_round_to_multiple_unsigned() {
     test::<u16>(0, 123, RoundingMode::Up, 0, Ordering::Equal);
     test::<u16>(0, 123, RoundingMode::Ceiling, 0, Ordering::Equal);
     test::<u16>(0, 123, RoundingMode::Nearest, 0, Ordering::Equal);
+    test::<u16>(0, 123, RoundingMode::NearestTiesAway, 0, Ordering::Equal);
+    test::<u16>(
+        0,
+        123,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Equal,
+    );
+    test::<u16>(0, 123, RoundingMode::Odd, 0, Ordering::Equal);
     test::<u16>(0, 123, RoundingMode::Exact, 0, Ordering::Equal);
 
     test::<u32>(1, 1, RoundingMode::Down, 1, Ordering::Equal);
@@ -41,6 +59,15 @@ fn test_round_to_multiple_unsigned() {
     test::<u32>(1, 1, RoundingMode::Up, 1, Ordering::Equal);
     test::<u32>(1, 1, RoundingMode::Ceiling, 1, Ordering::Equal);
     test::<u32>(1, 1, RoundingMode::Nearest, 1, Ordering::Equal);
+    test::<u32>(1, 1, RoundingMode::NearestTiesAway, 1, Ordering::Equal);
+    test::<u32>(
+        1,
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        1,
+        Ordering::Equal,
+    );
+    test::<u32>(1, 1, RoundingMode::Odd, 1, Ordering::Equal);
     test::<u32>(1, 1, RoundingMode::Exact, 1, Ordering::Equal);
 
     test::<u64>(123, 1, RoundingMode::Down, 123, Ordering::Equal);
@@ -48,6 +75,15 @@ fn test_round_to_multiple_unsigned() {
     test::<u64>(123, 1, RoundingMode::Up, 123, Ordering::Equal);
     test::<u64>(123, 1, RoundingMode::Ceiling, 123, Ordering::Equal);
     test::<u64>(123, 1, RoundingMode::Nearest, 123, Ordering::Equal);
+    test::<u64>(123, 1, RoundingMode::NearestTiesAway, 123, Ordering::Equal);
+    test::<u64>(
+        123,
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Equal,
+    );
+    test::<u64>(123, 1, RoundingMode::Odd, 123, Ordering::Equal);
     test::<u64>(123, 1, RoundingMode::Exact, 123, Ordering::Equal);
 
     test::<u128>(123, 2, RoundingMode::Down, 122, Ordering::Less);
@@ -55,18 +91,63 @@ fn test_round_to_multiple_unsigned() {
     test::<u128>(123, 2, RoundingMode::Up, 124, Ordering::Greater);
     test::<u128>(123, 2, RoundingMode::Ceiling, 124, Ordering::Greater);
     test::<u128>(123, 2, RoundingMode::Nearest, 124, Ordering::Greater);
+    test::<u128>(
+        123,
+        2,
+        RoundingMode::NearestTiesAway,
+        124,
+        Ordering::Greater,
+    );
+    test::<u128>(
+        123,
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        122,
+        Ordering::Less,
+    );
+    test::<u128>(123, 2, RoundingMode::Odd, 122, Ordering::Less);
 
     test::<usize>(125, 2, RoundingMode::Down, 124, Ordering::Less);
     test::<usize>(125, 2, RoundingMode::Floor, 124, Ordering::Less);
     test::<usize>(125, 2, RoundingMode::Up, 126, Ordering::Greater);
     test::<usize>(125, 2, RoundingMode::Ceiling, 126, Ordering::Greater);
     test::<usize>(125, 2, RoundingMode::Nearest, 124, Ordering::Less);
+    test::<usize>(
+        125,
+        2,
+        RoundingMode::NearestTiesAway,
+        126,
+        Ordering::Greater,
+    );
+    test::<usize>(
+        125,
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        124,
+        Ordering::Less,
+    );
+    test::<usize>(125, 2, RoundingMode::Odd, 126, Ordering::Greater);
 
     test::<u8>(123, 123, RoundingMode::Down, 123, Ordering::Equal);
     test::<u8>(123, 123, RoundingMode::Floor, 123, Ordering::Equal);
     test::<u8>(123, 123, RoundingMode::Up, 123, Ordering::Equal);
     test::<u8>(123, 123, RoundingMode::Ceiling, 123, Ordering::Equal);
     test::<u8>(123, 123, RoundingMode::Nearest, 123, Ordering::Equal);
+    test::<u8>(
+        123,
+        123,
+        RoundingMode::NearestTiesAway,
+        123,
+        Ordering::Equal,
+    );
+    test::<u8>(
+        123,
+        123,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Equal,
+    );
+    test::<u8>(123, 123, RoundingMode::Odd, 123, Ordering::Equal);
     test::<u8>(123, 123, RoundingMode::Exact, 123, Ordering::Equal);
 
     test::<u16>(123, 456, RoundingMode::Down, 0, Ordering::Less);
@@ -74,6 +155,15 @@ fn test_round_to_multiple_unsigned() {
     test::<u16>(123, 456, RoundingMode::Up, 456, Ordering::Greater);
     test::<u16>(123, 456, RoundingMode::Ceiling, 456, Ordering::Greater);
     test::<u16>(123, 456, RoundingMode::Nearest, 0, Ordering::Less);
+    test::<u16>(123, 456, RoundingMode::NearestTiesAway, 0, Ordering::Less);
+    test::<u16>(
+        123,
+        456,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Less,
+    );
+    test::<u16>(123, 456, RoundingMode::Odd, 456, Ordering::Greater);
 
     test::<u64>(
         1000000000000,
@@ -389,6 +479,15 @@ fn test_round_to_multiple_signed() {
     test::<i8>(0, 1, RoundingMode::Up, 0, Ordering::Equal);
     test::<i8>(0, 1, RoundingMode::Ceiling, 0, Ordering::Equal);
     test::<i8>(0, 1, RoundingMode::Nearest, 0, Ordering::Equal);
+    test::<i8>(0, 1, RoundingMode::NearestTiesAway, 0, Ordering::Equal);
+    test::<i8>(
+        0,
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Equal,
+    );
+    test::<i8>(0, 1, RoundingMode::Odd, 0, Ordering::Equal);
     test::<i8>(0, 1, RoundingMode::Exact, 0, Ordering::Equal);
 
     test::<i16>(0, 123, RoundingMode::Down, 0, Ordering::Equal);
@@ -396,6 +495,15 @@ fn test_round_to_multiple_signed() {
     test::<i16>(0, 123, RoundingMode::Up, 0, Ordering::Equal);
     test::<i16>(0, 123, RoundingMode::Ceiling, 0, Ordering::Equal);
     test::<i16>(0, 123, RoundingMode::Nearest, 0, Ordering::Equal);
+    test::<i16>(0, 123, RoundingMode::NearestTiesAway, 0, Ordering::Equal);
+    test::<i16>(
+        0,
+        123,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Equal,
+    );
+    test::<i16>(0, 123, RoundingMode::Odd, 0, Ordering::Equal);
     test::<i16>(0, 123, RoundingMode::Exact, 0, Ordering::Equal);
 
     test::<i32>(1, 1, RoundingMode::Down, 1, Ordering::Equal);
@@ -403,6 +511,15 @@ fn test_round_to_multiple_signed() {
     test::<i32>(1, 1, RoundingMode::Up, 1, Ordering::Equal);
     test::<i32>(1, 1, RoundingMode::Ceiling, 1, Ordering::Equal);
     test::<i32>(1, 1, RoundingMode::Nearest, 1, Ordering::Equal);
+    test::<i32>(1, 1, RoundingMode::NearestTiesAway, 1, Ordering::Equal);
+    test::<i32>(
+        1,
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        1,
+        Ordering::Equal,
+    );
+    test::<i32>(1, 1, RoundingMode::Odd, 1, Ordering::Equal);
     test::<i32>(1, 1, RoundingMode::Exact, 1, Ordering::Equal);
 
     test::<i64>(123, 1, RoundingMode::Down, 123, Ordering::Equal);
@@ -410,6 +527,15 @@ fn test_round_to_multiple_signed() {
     test::<i64>(123, 1, RoundingMode::Up, 123, Ordering::Equal);
     test::<i64>(123, 1, RoundingMode::Ceiling, 123, Ordering::Equal);
     test::<i64>(123, 1, RoundingMode::Nearest, 123, Ordering::Equal);
+    test::<i64>(123, 1, RoundingMode::NearestTiesAway, 123, Ordering::Equal);
+    test::<i64>(
+        123,
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Equal,
+    );
+    test::<i64>(123, 1, RoundingMode::Odd, 123, Ordering::Equal);
     test::<i64>(123, 1, RoundingMode::Exact, 123, Ordering::Equal);
 
     test::<i128>(123, 2, RoundingMode::Down, 122, Ordering::Less);
@@ -417,18 +543,63 @@ fn test_round_to_multiple_signed() {
     test::<i128>(123, 2, RoundingMode::Up, 124, Ordering::Greater);
     test::<i128>(123, 2, RoundingMode::Ceiling, 124, Ordering::Greater);
     test::<i128>(123, 2, RoundingMode::Nearest, 124, Ordering::Greater);
+    test::<i128>(
+        123,
+        2,
+        RoundingMode::NearestTiesAway,
+        124,
+        Ordering::Greater,
+    );
+    test::<i128>(
+        123,
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        122,
+        Ordering::Less,
+    );
+    test::<i128>(123, 2, RoundingMode::Odd, 122, Ordering::Less);
 
     test::<isize>(125, 2, RoundingMode::Down, 124, Ordering::Less);
     test::<isize>(125, 2, RoundingMode::Floor, 124, Ordering::Less);
     test::<isize>(125, 2, RoundingMode::Up, 126, Ordering::Greater);
     test::<isize>(125, 2, RoundingMode::Ceiling, 126, Ordering::Greater);
     test::<isize>(125, 2, RoundingMode::Nearest, 124, Ordering::Less);
+    test::<isize>(
+        125,
+        2,
+        RoundingMode::NearestTiesAway,
+        126,
+        Ordering::Greater,
+    );
+    test::<isize>(
+        125,
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        124,
+        Ordering::Less,
+    );
+    test::<isize>(125, 2, RoundingMode::Odd, 126, Ordering::Greater);
 
     test::<i8>(123, 123, RoundingMode::Down, 123, Ordering::Equal);
     test::<i8>(123, 123, RoundingMode::Floor, 123, Ordering::Equal);
     test::<i8>(123, 123, RoundingMode::Up, 123, Ordering::Equal);
     test::<i8>(123, 123, RoundingMode::Ceiling, 123, Ordering::Equal);
     test::<i8>(123, 123, RoundingMode::Nearest, 123, Ordering::Equal);
+    test::<i8>(
+        123,
+        123,
+        RoundingMode::NearestTiesAway,
+        123,
+        Ordering::Equal,
+    );
+    test::<i8>(
+        123,
+        123,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Equal,
+    );
+    test::<i8>(123, 123, RoundingMode::Odd, 123, Ordering::Equal);
     test::<i8>(123, 123, RoundingMode::Exact, 123, Ordering::Equal);
 
     test::<i16>(123, 456, RoundingMode::Down, 0, Ordering::Less);
@@ -436,6 +607,15 @@ fn test_round_to_multiple_signed() {
     test::<i16>(123, 456, RoundingMode::Up, 456, Ordering::Greater);
     test::<i16>(123, 456, RoundingMode::Ceiling, 456, Ordering::Greater);
     test::<i16>(123, 456, RoundingMode::Nearest, 0, Ordering::Less);
+    test::<i16>(123, 456, RoundingMode::NearestTiesAway, 0, Ordering::Less);
+    test::<i16>(
+        123,
+        456,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Less,
+    );
+    test::<i16>(123, 456, RoundingMode::Odd, 456, Ordering::Greater);
 
     test::<i64>(
         1000000000000,
@@ -730,6 +910,15 @@ fn test_round_to_multiple_signed() {
     test::<i8>(0, -1, RoundingMode::Up, 0, Ordering::Equal);
     test::<i8>(0, -1, RoundingMode::Ceiling, 0, Ordering::Equal);
     test::<i8>(0, -1, RoundingMode::Nearest, 0, Ordering::Equal);
+    test::<i8>(0, -1, RoundingMode::NearestTiesAway, 0, Ordering::Equal);
+    test::<i8>(
+        0,
+        -1,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Equal,
+    );
+    test::<i8>(0, -1, RoundingMode::Odd, 0, Ordering::Equal);
     test::<i8>(0, -1, RoundingMode::Exact, 0, Ordering::Equal);
 
     test::<i16>(0, -123, RoundingMode::Down, 0, Ordering::Equal);
@@ -737,6 +926,15 @@ fn test_round_to_multiple_signed() {
     test::<i16>(0, -123, RoundingMode::Up, 0, Ordering::Equal);
     test::<i16>(0, -123, RoundingMode::Ceiling, 0, Ordering::Equal);
     test::<i16>(0, -123, RoundingMode::Nearest, 0, Ordering::Equal);
+    test::<i16>(0, -123, RoundingMode::NearestTiesAway, 0, Ordering::Equal);
+    test::<i16>(
+        0,
+        -123,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Equal,
+    );
+    test::<i16>(0, -123, RoundingMode::Odd, 0, Ordering::Equal);
     test::<i16>(0, -123, RoundingMode::Exact, 0, Ordering::Equal);
 
     test::<i32>(1, -1, RoundingMode::Down, 1, Ordering::Equal);
@@ -744,6 +942,15 @@ fn test_round_to_multiple_signed() {
     test::<i32>(1, -1, RoundingMode::Up, 1, Ordering::Equal);
     test::<i32>(1, -1, RoundingMode::Ceiling, 1, Ordering::Equal);
     test::<i32>(1, -1, RoundingMode::Nearest, 1, Ordering::Equal);
+    test::<i32>(1, -1, RoundingMode::NearestTiesAway, 1, Ordering::Equal);
+    test::<i32>(
+        1,
+        -1,
+        RoundingMode::NearestTiesTowardZero,
+        1,
+        Ordering::Equal,
+    );
+    test::<i32>(1, -1, RoundingMode::Odd, 1, Ordering::Equal);
     test::<i32>(1, -1, RoundingMode::Exact, 1, Ordering::Equal);
 
     test::<i64>(123, -1, RoundingMode::Down, 123, Ordering::Equal);
@@ -751,6 +958,15 @@ fn test_round_to_multiple_signed() {
     test::<i64>(123, -1, RoundingMode::Up, 123, Ordering::Equal);
     test::<i64>(123, -1, RoundingMode::Ceiling, 123, Ordering::Equal);
     test::<i64>(123, -1, RoundingMode::Nearest, 123, Ordering::Equal);
+    test::<i64>(123, -1, RoundingMode::NearestTiesAway, 123, Ordering::Equal);
+    test::<i64>(
+        123,
+        -1,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Equal,
+    );
+    test::<i64>(123, -1, RoundingMode::Odd, 123, Ordering::Equal);
     test::<i64>(123, -1, RoundingMode::Exact, 123, Ordering::Equal);
 
     test::<i128>(123, -2, RoundingMode::Down, 122, Ordering::Less);
@@ -758,18 +974,63 @@ fn test_round_to_multiple_signed() {
     test::<i128>(123, -2, RoundingMode::Up, 124, Ordering::Greater);
     test::<i128>(123, -2, RoundingMode::Ceiling, 124, Ordering::Greater);
     test::<i128>(123, -2, RoundingMode::Nearest, 124, Ordering::Greater);
+    test::<i128>(
+        123,
+        -2,
+        RoundingMode::NearestTiesAway,
+        124,
+        Ordering::Greater,
+    );
+    test::<i128>(
+        123,
+        -2,
+        RoundingMode::NearestTiesTowardZero,
+        122,
+        Ordering::Less,
+    );
+    test::<i128>(123, -2, RoundingMode::Odd, 122, Ordering::Less);
 
     test::<isize>(125, -2, RoundingMode::Down, 124, Ordering::Less);
     test::<isize>(125, -2, RoundingMode::Floor, 124, Ordering::Less);
     test::<isize>(125, -2, RoundingMode::Up, 126, Ordering::Greater);
     test::<isize>(125, -2, RoundingMode::Ceiling, 126, Ordering::Greater);
     test::<isize>(125, -2, RoundingMode::Nearest, 124, Ordering::Less);
+    test::<isize>(
+        125,
+        -2,
+        RoundingMode::NearestTiesAway,
+        126,
+        Ordering::Greater,
+    );
+    test::<isize>(
+        125,
+        -2,
+        RoundingMode::NearestTiesTowardZero,
+        124,
+        Ordering::Less,
+    );
+    test::<isize>(125, -2, RoundingMode::Odd, 126, Ordering::Greater);
 
     test::<i8>(123, -123, RoundingMode::Down, 123, Ordering::Equal);
     test::<i8>(123, -123, RoundingMode::Floor, 123, Ordering::Equal);
     test::<i8>(123, -123, RoundingMode::Up, 123, Ordering::Equal);
     test::<i8>(123, -123, RoundingMode::Ceiling, 123, Ordering::Equal);
     test::<i8>(123, -123, RoundingMode::Nearest, 123, Ordering::Equal);
+    test::<i8>(
+        123,
+        -123,
+        RoundingMode::NearestTiesAway,
+        123,
+        Ordering::Equal,
+    );
+    test::<i8>(
+        123,
+        -123,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Equal,
+    );
+    test::<i8>(123, -123, RoundingMode::Odd, 123, Ordering::Equal);
     test::<i8>(123, -123, RoundingMode::Exact, 123, Ordering::Equal);
 
     test::<i16>(123, -456, RoundingMode::Down, 0, Ordering::Less);
@@ -777,6 +1038,15 @@ fn test_round_to_multiple_signed() {
     test::<i16>(123, -456, RoundingMode::Up, 456, Ordering::Greater);
     test::<i16>(123, -456, RoundingMode::Ceiling, 456, Ordering::Greater);
     test::<i16>(123, -456, RoundingMode::Nearest, 0, Ordering::Less);
+    test::<i16>(123, -456, RoundingMode::NearestTiesAway, 0, Ordering::Less);
+    test::<i16>(
+        123,
+        -456,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Less,
+    );
+    test::<i16>(123, -456, RoundingMode::Odd, 456, Ordering::Greater);
 
     test::<i64>(
         1000000000000,
@@ -1071,6 +1341,15 @@ fn test_round_to_multiple_signed() {
     test::<i32>(-1, 1, RoundingMode::Up, -1, Ordering::Equal);
     test::<i32>(-1, 1, RoundingMode::Ceiling, -1, Ordering::Equal);
     test::<i32>(-1, 1, RoundingMode::Nearest, -1, Ordering::Equal);
+    test::<i32>(-1, 1, RoundingMode::NearestTiesAway, -1, Ordering::Equal);
+    test::<i32>(
+        -1,
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        -1,
+        Ordering::Equal,
+    );
+    test::<i32>(-1, 1, RoundingMode::Odd, -1, Ordering::Equal);
     test::<i32>(-1, 1, RoundingMode::Exact, -1, Ordering::Equal);
 
     test::<i64>(-123, 1, RoundingMode::Down, -123, Ordering::Equal);
@@ -1078,6 +1357,21 @@ fn test_round_to_multiple_signed() {
     test::<i64>(-123, 1, RoundingMode::Up, -123, Ordering::Equal);
     test::<i64>(-123, 1, RoundingMode::Ceiling, -123, Ordering::Equal);
     test::<i64>(-123, 1, RoundingMode::Nearest, -123, Ordering::Equal);
+    test::<i64>(
+        -123,
+        1,
+        RoundingMode::NearestTiesAway,
+        -123,
+        Ordering::Equal,
+    );
+    test::<i64>(
+        -123,
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        -123,
+        Ordering::Equal,
+    );
+    test::<i64>(-123, 1, RoundingMode::Odd, -123, Ordering::Equal);
     test::<i64>(-123, 1, RoundingMode::Exact, -123, Ordering::Equal);
 
     test::<i128>(-123, 2, RoundingMode::Down, -122, Ordering::Greater);
@@ -1085,18 +1379,51 @@ fn test_round_to_multiple_signed() {
     test::<i128>(-123, 2, RoundingMode::Up, -124, Ordering::Less);
     test::<i128>(-123, 2, RoundingMode::Ceiling, -122, Ordering::Greater);
     test::<i128>(-123, 2, RoundingMode::Nearest, -124, Ordering::Less);
+    test::<i128>(-123, 2, RoundingMode::NearestTiesAway, -124, Ordering::Less);
+    test::<i128>(
+        -123,
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        -122,
+        Ordering::Greater,
+    );
+    test::<i128>(-123, 2, RoundingMode::Odd, -122, Ordering::Greater);
 
     test::<isize>(-125, 2, RoundingMode::Down, -124, Ordering::Greater);
     test::<isize>(-125, 2, RoundingMode::Floor, -126, Ordering::Less);
     test::<isize>(-125, 2, RoundingMode::Up, -126, Ordering::Less);
     test::<isize>(-125, 2, RoundingMode::Ceiling, -124, Ordering::Greater);
     test::<isize>(-125, 2, RoundingMode::Nearest, -124, Ordering::Greater);
+    test::<isize>(-125, 2, RoundingMode::NearestTiesAway, -126, Ordering::Less);
+    test::<isize>(
+        -125,
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        -124,
+        Ordering::Greater,
+    );
+    test::<isize>(-125, 2, RoundingMode::Odd, -126, Ordering::Less);
 
     test::<i8>(-123, 123, RoundingMode::Down, -123, Ordering::Equal);
     test::<i8>(-123, 123, RoundingMode::Floor, -123, Ordering::Equal);
     test::<i8>(-123, 123, RoundingMode::Up, -123, Ordering::Equal);
     test::<i8>(-123, 123, RoundingMode::Ceiling, -123, Ordering::Equal);
     test::<i8>(-123, 123, RoundingMode::Nearest, -123, Ordering::Equal);
+    test::<i8>(
+        -123,
+        123,
+        RoundingMode::NearestTiesAway,
+        -123,
+        Ordering::Equal,
+    );
+    test::<i8>(
+        -123,
+        123,
+        RoundingMode::NearestTiesTowardZero,
+        -123,
+        Ordering::Equal,
+    );
+    test::<i8>(-123, 123, RoundingMode::Odd, -123, Ordering::Equal);
     test::<i8>(-123, 123, RoundingMode::Exact, -123, Ordering::Equal);
 
     test::<i16>(-123, 456, RoundingMode::Down, 0, Ordering::Greater);
@@ -1104,6 +1431,21 @@ fn test_round_to_multiple_signed() {
     test::<i16>(-123, 456, RoundingMode::Up, -456, Ordering::Less);
     test::<i16>(-123, 456, RoundingMode::Ceiling, 0, Ordering::Greater);
     test::<i16>(-123, 456, RoundingMode::Nearest, 0, Ordering::Greater);
+    test::<i16>(
+        -123,
+        456,
+        RoundingMode::NearestTiesAway,
+        0,
+        Ordering::Greater,
+    );
+    test::<i16>(
+        -123,
+        456,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Greater,
+    );
+    test::<i16>(-123, 456, RoundingMode::Odd, -456, Ordering::Less);
 
     test::<i64>(
         -1000000000000,
@@ -1398,6 +1740,15 @@ fn test_round_to_multiple_signed() {
     test::<i32>(-1, -1, RoundingMode::Up, -1, Ordering::Equal);
     test::<i32>(-1, -1, RoundingMode::Ceiling, -1, Ordering::Equal);
     test::<i32>(-1, -1, RoundingMode::Nearest, -1, Ordering::Equal);
+    test::<i32>(-1, -1, RoundingMode::NearestTiesAway, -1, Ordering::Equal);
+    test::<i32>(
+        -1,
+        -1,
+        RoundingMode::NearestTiesTowardZero,
+        -1,
+        Ordering::Equal,
+    );
+    test::<i32>(-1, -1, RoundingMode::Odd, -1, Ordering::Equal);
     test::<i32>(-1, -1, RoundingMode::Exact, -1, Ordering::Equal);
 
     test::<i64>(-123, -1, RoundingMode::Down, -123, Ordering::Equal);
@@ -1405,6 +1756,21 @@ fn test_round_to_multiple_signed() {
     test::<i64>(-123, -1, RoundingMode::Up, -123, Ordering::Equal);
     test::<i64>(-123, -1, RoundingMode::Ceiling, -123, Ordering::Equal);
     test::<i64>(-123, -1, RoundingMode::Nearest, -123, Ordering::Equal);
+    test::<i64>(
+        -123,
+        -1,
+        RoundingMode::NearestTiesAway,
+        -123,
+        Ordering::Equal,
+    );
+    test::<i64>(
+        -123,
+        -1,
+        RoundingMode::NearestTiesTowardZero,
+        -123,
+        Ordering::Equal,
+    );
+    test::<i64>(-123, -1, RoundingMode::Odd, -123, Ordering::Equal);
     test::<i64>(-123, -1, RoundingMode::Exact, -123, Ordering::Equal);
 
     test::<i128>(-123, -2, RoundingMode::Down, -122, Ordering::Greater);
@@ -1412,18 +1778,63 @@ fn test_round_to_multiple_signed() {
     test::<i128>(-123, -2, RoundingMode::Up, -124, Ordering::Less);
     test::<i128>(-123, -2, RoundingMode::Ceiling, -122, Ordering::Greater);
     test::<i128>(-123, -2, RoundingMode::Nearest, -124, Ordering::Less);
+    test::<i128>(
+        -123,
+        -2,
+        RoundingMode::NearestTiesAway,
+        -124,
+        Ordering::Less,
+    );
+    test::<i128>(
+        -123,
+        -2,
+        RoundingMode::NearestTiesTowardZero,
+        -122,
+        Ordering::Greater,
+    );
+    test::<i128>(-123, -2, RoundingMode::Odd, -122, Ordering::Greater);
 
     test::<isize>(-125, -2, RoundingMode::Down, -124, Ordering::Greater);
     test::<isize>(-125, -2, RoundingMode::Floor, -126, Ordering::Less);
     test::<isize>(-125, -2, RoundingMode::Up, -126, Ordering::Less);
     test::<isize>(-125, -2, RoundingMode::Ceiling, -124, Ordering::Greater);
     test::<isize>(-125, -2, RoundingMode::Nearest, -124, Ordering::Greater);
+    test::<isize>(
+        -125,
+        -2,
+        RoundingMode::NearestTiesAway,
+        -126,
+        Ordering::Less,
+    );
+    test::<isize>(
+        -125,
+        -2,
+        RoundingMode::NearestTiesTowardZero,
+        -124,
+        Ordering::Greater,
+    );
+    test::<isize>(-125, -2, RoundingMode::Odd, -126, Ordering::Less);
 
     test::<i8>(-123, -123, RoundingMode::Down, -123, Ordering::Equal);
     test::<i8>(-123, -123, RoundingMode::Floor, -123, Ordering::Equal);
     test::<i8>(-123, -123, RoundingMode::Up, -123, Ordering::Equal);
     test::<i8>(-123, -123, RoundingMode::Ceiling, -123, Ordering::Equal);
     test::<i8>(-123, -123, RoundingMode::Nearest, -123, Ordering::Equal);
+    test::<i8>(
+        -123,
+        -123,
+        RoundingMode::NearestTiesAway,
+        -123,
+        Ordering::Equal,
+    );
+    test::<i8>(
+        -123,
+        -123,
+        RoundingMode::NearestTiesTowardZero,
+        -123,
+        Ordering::Equal,
+    );
+    test::<i8>(-123, -123, RoundingMode::Odd, -123, Ordering::Equal);
     test::<i8>(-123, -123, RoundingMode::Exact, -123, Ordering::Equal);
 
     test::<i16>(-123, -456, RoundingMode::Down, 0, Ordering::Greater);
@@ -1431,6 +1842,21 @@ fn test_round_to_multiple_signed() {
     test::<i16>(-123, -456, RoundingMode::Up, -456, Ordering::Less);
     test::<i16>(-123, -456, RoundingMode::Ceiling, 0, Ordering::Greater);
     test::<i16>(-123, -456, RoundingMode::Nearest, 0, Ordering::Greater);
+    test::<i16>(
+        -123,
+        -456,
+        RoundingMode::NearestTiesAway,
+        0,
+        Ordering::Greater,
+    );
+    test::<i16>(
+        -123,
+        -456,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Greater,
+    );
+    test::<i16>(-123, -456, RoundingMode::Odd, -456, Ordering::Less);
 
     test::<i64>(
         -1000000000000,
@@ -1725,6 +2151,21 @@ fn test_round_to_multiple_signed() {
     test::<i8>(-128, 1, RoundingMode::Floor, -128, Ordering::Equal);
     test::<i8>(-128, 1, RoundingMode::Ceiling, -128, Ordering::Equal);
     test::<i8>(-128, 1, RoundingMode::Nearest, -128, Ordering::Equal);
+    test::<i8>(
+        -128,
+        1,
+        RoundingMode::NearestTiesAway,
+        -128,
+        Ordering::Equal,
+    );
+    test::<i8>(
+        -128,
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        -128,
+        Ordering::Equal,
+    );
+    test::<i8>(-128, 1, RoundingMode::Odd, -128, Ordering::Equal);
     test::<i8>(-128, 1, RoundingMode::Exact, -128, Ordering::Equal);
 
     test::<i8>(-128, -1, RoundingMode::Down, -128, Ordering::Equal);
@@ -1732,6 +2173,21 @@ fn test_round_to_multiple_signed() {
     test::<i8>(-128, -1, RoundingMode::Floor, -128, Ordering::Equal);
     test::<i8>(-128, -1, RoundingMode::Ceiling, -128, Ordering::Equal);
     test::<i8>(-128, -1, RoundingMode::Nearest, -128, Ordering::Equal);
+    test::<i8>(
+        -128,
+        -1,
+        RoundingMode::NearestTiesAway,
+        -128,
+        Ordering::Equal,
+    );
+    test::<i8>(
+        -128,
+        -1,
+        RoundingMode::NearestTiesTowardZero,
+        -128,
+        Ordering::Equal,
+    );
+    test::<i8>(-128, -1, RoundingMode::Odd, -128, Ordering::Equal);
     test::<i8>(-128, -1, RoundingMode::Exact, -128, Ordering::Equal);
 
     test::<i8>(-128, -128, RoundingMode::Down, -128, Ordering::Equal);
@@ -1739,6 +2195,21 @@ fn test_round_to_multiple_signed() {
     test::<i8>(-128, -128, RoundingMode::Floor, -128, Ordering::Equal);
     test::<i8>(-128, -128, RoundingMode::Ceiling, -128, Ordering::Equal);
     test::<i8>(-128, -128, RoundingMode::Nearest, -128, Ordering::Equal);
+    test::<i8>(
+        -128,
+        -128,
+        RoundingMode::NearestTiesAway,
+        -128,
+        Ordering::Equal,
+    );
+    test::<i8>(
+        -128,
+        -128,
+        RoundingMode::NearestTiesTowardZero,
+        -128,
+        Ordering::Equal,
+    );
+    test::<i8>(-128, -128, RoundingMode::Odd, -128, Ordering::Equal);
     test::<i8>(-128, -128, RoundingMode::Exact, -128, Ordering::Equal);
 
     test::<i8>(0, 0, RoundingMode::Floor, 0, Ordering::Equal);
@@ -1840,7 +2311,10 @@ fn round_to_multiple_properties_helper_unsigned<T: PrimitiveUnsigned>() {
                 assert!(rounded >= x)
             }
             RoundingMode::Exact => assert_eq!(rounded, x),
-            RoundingMode::Nearest => {
+            RoundingMode::Odd => assert!(rounded == x || rounded.div_exact(y).odd()),
+            RoundingMode::Nearest
+            | RoundingMode::NearestTiesAway
+            | RoundingMode::NearestTiesTowardZero => {
                 if y == T::ZERO {
                     assert_eq!(rounded, T::ZERO);
                 } else {
@@ -1858,7 +2332,11 @@ fn round_to_multiple_properties_helper_unsigned<T: PrimitiveUnsigned>() {
                     if let (Some(closest), Some(second_closest)) = (closest, second_closest) {
                         assert!(closest <= second_closest);
                         if closest == second_closest {
-                            assert!(rounded.div_exact(y).even());
+                            match rm {
+                                RoundingMode::Nearest => assert!(rounded.div_exact(y).even()),
+                                RoundingMode::NearestTiesAway => assert!(rounded >= x),
+                                _ => assert!(rounded <= x),
+                            }
                         }
                     }
                 }
@@ -1940,7 +2418,10 @@ fn round_to_multiple_properties_helper_signed<
             RoundingMode::Down => assert!(rounded.le_abs(&x)),
             RoundingMode::Up => assert!(rounded.ge_abs(&x)),
             RoundingMode::Exact => assert_eq!(rounded, x),
-            RoundingMode::Nearest => {
+            RoundingMode::Odd => assert!(rounded == x || rounded.div_exact(y).odd()),
+            RoundingMode::Nearest
+            | RoundingMode::NearestTiesAway
+            | RoundingMode::NearestTiesTowardZero => {
                 if y == S::ZERO {
                     assert_eq!(rounded, S::ZERO);
                 } else {
@@ -1963,7 +2444,11 @@ fn round_to_multiple_properties_helper_signed<
                     if let (Some(closest), Some(second_closest)) = (closest, second_closest) {
                         assert!(closest <= second_closest);
                         if closest == second_closest {
-                            assert!(rounded.div_exact(y).even());
+                            match rm {
+                                RoundingMode::Nearest => assert!(rounded.div_exact(y).even()),
+                                RoundingMode::NearestTiesAway => assert!(rounded.ge_abs(&x)),
+                                _ => assert!(rounded.le_abs(&x)),
+                            }
                         }
                     }
                 }
@@ -30,6 +30,15 @@ fn test_round_to_multiple_of_power_of_2() {
     test::<u32>(10, 2, RoundingMode::Down, 8, Ordering::Less);
     test::<u64>(10, 2, RoundingMode::Up, 12, Ordering::Greater);
     test::<u128>(10, 2, RoundingMode::Nearest, 8, Ordering::Less);
+    test::<u128>(10, 2, RoundingMode::NearestTiesAway, 12, Ordering::Greater);
+    test::<u128>(
+        10,
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        8,
+        Ordering::Less,
+    );
+    test::<u128>(10, 2, RoundingMode::Odd, 12, Ordering::Greater);
     test::<usize>(12, 2, RoundingMode::Exact, 12, Ordering::Equal);
 
     test::<i8>(-10, 2, RoundingMode::Floor, -12, Ordering::Less);
@@ -37,6 +46,15 @@ fn test_round_to_multiple_of_power_of_2() {
     test::<i32>(-10, 2, RoundingMode::Down, -8, Ordering::Greater);
     test::<i64>(-10, 2, RoundingMode::Up, -12, Ordering::Less);
     test::<i128>(-10, 2, RoundingMode::Nearest, -8, Ordering::Greater);
+    test::<i128>(-10, 2, RoundingMode::NearestTiesAway, -12, Ordering::Less);
+    test::<i128>(
+        -10,
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        -8,
+        Ordering::Greater,
+    );
+    test::<i128>(-10, 2, RoundingMode::Odd, -12, Ordering::Less);
     test::<isize>(-12, 2, RoundingMode::Exact, -12, Ordering::Equal);
 
     test::<u8>(0xff, 4, RoundingMode::Down, 0xf0, Ordering::Less);
@@ -44,18 +62,65 @@ fn test_round_to_multiple_of_power_of_2() {
     test::<u8>(0xef, 4, RoundingMode::Up, 0xf0, Ordering::Greater);
     test::<u8>(0xef, 4, RoundingMode::Ceiling, 0xf0, Ordering::Greater);
     test::<u8>(0xe8, 4, RoundingMode::Nearest, 0xe0, Ordering::Less);
+    test::<u8>(
+        0xe8,
+        4,
+        RoundingMode::NearestTiesAway,
+        0xf0,
+        Ordering::Greater,
+    );
+    test::<u8>(
+        0xe8,
+        4,
+        RoundingMode::NearestTiesTowardZero,
+        0xe0,
+        Ordering::Less,
+    );
+    test::<u8>(0xe8, 4, RoundingMode::Odd, 0xf0, Ordering::Greater);
     test::<u8>(1, 8, RoundingMode::Nearest, 0, Ordering::Less);
+    test::<u8>(1, 8, RoundingMode::NearestTiesAway, 0, Ordering::Less);
+    test::<u8>(1, 8, RoundingMode::NearestTiesTowardZero, 0, Ordering::Less);
 
     test::<i8>(0x7f, 4, RoundingMode::Down, 0x70, Ordering::Less);
     test::<i8>(0x7f, 4, RoundingMode::Floor, 0x70, Ordering::Less);
     test::<i8>(0x6f, 4, RoundingMode::Up, 0x70, Ordering::Greater);
     test::<i8>(0x6f, 4, RoundingMode::Ceiling, 0x70, Ordering::Greater);
     test::<i8>(0x68, 4, RoundingMode::Nearest, 0x60, Ordering::Less);
+    test::<i8>(
+        0x68,
+        4,
+        RoundingMode::NearestTiesAway,
+        0x70,
+        Ordering::Greater,
+    );
+    test::<i8>(
+        0x68,
+        4,
+        RoundingMode::NearestTiesTowardZero,
+        0x60,
+        Ordering::Less,
+    );
+    test::<i8>(0x68, 4, RoundingMode::Odd, 0x70, Ordering::Greater);
     test::<i8>(-0x7f, 4, RoundingMode::Down, -0x70, Ordering::Greater);
     test::<i8>(-0x7f, 4, RoundingMode::Floor, -0x80, Ordering::Less);
     test::<i8>(-0x7f, 4, RoundingMode::Up, -0x80, Ordering::Less);
     test::<i8>(-0x7f, 4, RoundingMode::Ceiling, -0x70, Ordering::Greater);
     test::<i8>(-0x78, 4, RoundingMode::Nearest, -0x80, Ordering::Less);
+    test::<i8>(
+        -0x78,
+        4,
+        RoundingMode::NearestTiesAway,
+        -0x80,
+        Ordering::Less,
+    );
+    test::<i8>(
+        -0x78,
+        4,
+        RoundingMode::NearestTiesTowardZero,
+        -0x70,
+        Ordering::Greater,
+    );
+    test::<i8>(-0x78, 4, RoundingMode::Odd, -0x70, Ordering::Greater);
 }
 
 fn round_to_multiple_of_power_of_2_fail_helper<T: PrimitiveInt>() {
@@ -124,7 +189,10 @@ fn round_to_multiple_of_power_of_2_properties_helper_unsigned<T: PrimitiveUnsign
                 assert!(rounded >= n)
             }
             RoundingMode::Exact => assert_eq!(rounded, n),
-            RoundingMode::Nearest => {
+            RoundingMode::Odd => assert!(rounded == n || rounded.get_bit(pow)),
+            RoundingMode::Nearest
+            | RoundingMode::NearestTiesAway
+            | RoundingMode::NearestTiesTowardZero => {
                 if let Some(k) = T::ONE.arithmetic_checked_shl(pow) {
                     let mut closest = None;
                     let mut second_closest = None;
@@ -140,7 +208,11 @@ fn round_to_multiple_of_power_of_2_properties_helper_unsigned<T: PrimitiveUnsign
                     if let (Some(closest), Some(second_closest)) = (closest, second_closest) {
                         assert!(closest <= second_closest);
                         if closest == second_closest {
-                            assert!(!rounded.get_bit(pow));
+                            match rm {
+                                RoundingMode::Nearest => assert!(!rounded.get_bit(pow)),
+                                RoundingMode::NearestTiesAway => assert!(rounded >= n),
+                                _ => assert!(rounded <= n),
+                            }
                         }
                     }
                 }
@@ -285,7 +357,10 @@ fn round_to_multiple_of_power_of_2_properties_helper_signed<
             RoundingMode::Down => assert!(rounded.le_abs(&n)),
             RoundingMode::Up => assert!(rounded.ge_abs(&n)),
             RoundingMode::Exact => assert_eq!(rounded, n),
-            RoundingMode::Nearest => {
+            RoundingMode::Odd => assert!(rounded == n || rounded.get_bit(pow)),
+            RoundingMode::Nearest
+            | RoundingMode::NearestTiesAway
+            | RoundingMode::NearestTiesTowardZero => {
                 if let Some(k) = S::ONE.arithmetic_checked_shl(pow) {
                     let mut closest = None;
                     let mut second_closest = None;
@@ -301,7 +376,11 @@ fn round_to_multiple_of_power_of_2_properties_helper_signed<
                     if let (Some(closest), Some(second_closest)) = (closest, second_closest) {
                         assert!(closest <= second_closest);
                         if closest == second_closest {
-                            assert!(!rounded.get_bit(pow));
+                            match rm {
+                                RoundingMode::Nearest => assert!(!rounded.get_bit(pow)),
+                                RoundingMode::NearestTiesAway => assert!(rounded.ge_abs(&n)),
+                                _ => assert!(rounded.le_abs(&n)),
+                            }
                         }
                     }
                 }
@@ -33,6 +33,15 @@ fn test_shl_round() {
     test::<u8, i8>(0, 0, RoundingMode::Floor, 0, Ordering::Equal);
     test::<u8, i8>(0, 0, RoundingMode::Ceiling, 0, Ordering::Equal);
     test::<u8, i8>(0, 0, RoundingMode::Nearest, 0, Ordering::Equal);
+    test::<u8, i8>(0, 0, RoundingMode::NearestTiesAway, 0, Ordering::Equal);
+    test::<u8, i8>(
+        0,
+        0,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Equal,
+    );
+    test::<u8, i8>(0, 0, RoundingMode::Odd, 0, Ordering::Equal);
     test::<u8, i8>(0, 0, RoundingMode::Exact, 0, Ordering::Equal);
 
     test::<u8, i16>(0, -10, RoundingMode::Down, 0, Ordering::Equal);
@@ -40,6 +49,15 @@ fn test_shl_round() {
     test::<u8, i16>(0, -10, RoundingMode::Floor, 0, Ordering::Equal);
     test::<u8, i16>(0, -10, RoundingMode::Ceiling, 0, Ordering::Equal);
     test::<u8, i16>(0, -10, RoundingMode::Nearest, 0, Ordering::Equal);
+    test::<u8, i16>(0, -10, RoundingMode::NearestTiesAway, 0, Ordering::Equal);
+    test::<u8, i16>(
+        0,
+        -10,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Equal,
+    );
+    test::<u8, i16>(0, -10, RoundingMode::Odd, 0, Ordering::Equal);
     test::<u8, i16>(0, -10, RoundingMode::Exact, 0, Ordering::Equal);
 
     test::<i8, i32>(123, 0, RoundingMode::Down, 123, Ordering::Equal);
@@ -47,6 +65,15 @@ fn test_shl_round() {
     test::<i8, i32>(123, 0, RoundingMode::Floor, 123, Ordering::Equal);
     test::<i8, i32>(123, 0, RoundingMode::Ceiling, 123, Ordering::Equal);
     test::<i8, i32>(123, 0, RoundingMode::Nearest, 123, Ordering::Equal);
+    test::<i8, i32>(123, 0, RoundingMode::NearestTiesAway, 123, Ordering::Equal);
+    test::<i8, i32>(
+        123,
+        0,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Equal,
+    );
+    test::<i8, i32>(123, 0, RoundingMode::Odd, 123, Ordering::Equal);
     test::<i8, i32>(123, 0, RoundingMode::Exact, 123, Ordering::Equal);
 
     test::<u8, i64>(245, -1, RoundingMode::Down, 122, Ordering::Less);
@@ -54,12 +81,36 @@ fn test_shl_round() {
     test::<u8, i64>(245, -1, RoundingMode::Floor, 122, Ordering::Less);
     test::<u8, i64>(245, -1, RoundingMode::Ceiling, 123, Ordering::Greater);
     test::<u8, i64>(245, -1, RoundingMode::Nearest, 122, Ordering::Less);
+    test::<u8, i64>(
+        245,
+        -1,
+        RoundingMode::NearestTiesAway,
+        123,
+        Ordering::Greater,
+    );
+    test::<u8, i64>(
+        245,
+        -1,
+        RoundingMode::NearestTiesTowardZero,
+        122,
+        Ordering::Less,
+    );
+    test::<u8, i64>(245, -1, RoundingMode::Odd, 123, Ordering::Greater);
 
     test::<u8, i128>(246, -1, RoundingMode::Down, 123, Ordering::Equal);
     test::<u8, i128>(246, -1, RoundingMode::Up, 123, Ordering::Equal);
     test::<u8, i128>(246, -1, RoundingMode::Floor, 123, Ordering::Equal);
     test::<u8, i128>(246, -1, RoundingMode::Ceiling, 123, Ordering::Equal);
     test::<u8, i128>(246, -1, RoundingMode::Nearest, 123, Ordering::Equal);
+    test::<u8, i128>(246, -1, RoundingMode::NearestTiesAway, 123, Ordering::Equal);
+    test::<u8, i128>(
+        246,
+        -1,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Equal,
+    );
+    test::<u8, i128>(246, -1, RoundingMode::Odd, 123, Ordering::Equal);
     test::<u8, i128>(246, -1, RoundingMode::Exact, 123, Ordering::Equal);
 
     test::<u8, isize>(247, -1, RoundingMode::Down, 123, Ordering::Less);
@@ -67,18 +118,57 @@ fn test_shl_round() {
     test::<u8, isize>(247, -1, RoundingMode::Floor, 123, Ordering::Less);
     test::<u8, isize>(247, -1, RoundingMode::Ceiling, 124, Ordering::Greater);
     test::<u8, isize>(247, -1, RoundingMode::Nearest, 124, Ordering::Greater);
+    test::<u8, isize>(
+        247,
+        -1,
+        RoundingMode::NearestTiesAway,
+        124,
+        Ordering::Greater,
+    );
+    test::<u8, isize>(
+        247,
+        -1,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Less,
+    );
+    test::<u8, isize>(247, -1, RoundingMode::Odd, 123, Ordering::Less);
 
     test::<i16, i8>(491, -2, RoundingMode::Down, 122, Ordering::Less);
     test::<i16, i8>(491, -2, RoundingMode::Up, 123, Ordering::Greater);
     test::<i16, i8>(491, -2, RoundingMode::Floor, 122, Ordering::Less);
     test::<i16, i8>(491, -2, RoundingMode::Ceiling, 123, Ordering::Greater);
     test::<i16, i8>(491, -2, RoundingMode::Nearest, 123, Ordering::Greater);
+    test::<i16, i8>(
+        491,
+        -2,
+        RoundingMode::NearestTiesAway,
+        123,
+        Ordering::Greater,
+    );
+    test::<i16, i8>(
+        491,
+        -2,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Greater,
+    );
+    test::<i16, i8>(491, -2, RoundingMode::Odd, 123, Ordering::Greater);
 
     test::<u16, i16>(492, -2, RoundingMode::Down, 123, Ordering::Equal);
     test::<u16, i16>(492, -2, RoundingMode::Up, 123, Ordering::Equal);
     test::<u16, i16>(492, -2, RoundingMode::Floor, 123, Ordering::Equal);
     test::<u16, i16>(492, -2, RoundingMode::Ceiling, 123, Ordering::Equal);
     test::<u16, i16>(492, -2, RoundingMode::Nearest, 123, Ordering::Equal);
+    test::<u16, i16>(492, -2, RoundingMode::NearestTiesAway, 123, Ordering::Equal);
+    test::<u16, i16>(
+        492,
+        -2,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Equal,
+    );
+    test::<u16, i16>(492, -2, RoundingMode::Odd, 123, Ordering::Equal);
     test::<u16, i16>(492, -2, RoundingMode::Exact, 123, Ordering::Equal);
 
     test::<i16, i32>(493, -2, RoundingMode::Down, 123, Ordering::Less);
@@ -86,6 +176,15 @@ fn test_shl_round() {
     test::<i16, i32>(493, -2, RoundingMode::Floor, 123, Ordering::Less);
     test::<i16, i32>(493, -2, RoundingMode::Ceiling, 124, Ordering::Greater);
     test::<i16, i32>(493, -2, RoundingMode::Nearest, 123, Ordering::Less);
+    test::<i16, i32>(493, -2, RoundingMode::NearestTiesAway, 123, Ordering::Less);
+    test::<i16, i32>(
+        493,
+        -2,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Less,
+    );
+    test::<i16, i32>(493, -2, RoundingMode::Odd, 123, Ordering::Less);
 
     test::<u32, i8>(4127195135, -25, RoundingMode::Down, 122, Ordering::Less);
     test::<u32, i8>(4127195135, -25, RoundingMode::Up, 123, Ordering::Greater);
@@ -110,6 +209,21 @@ fn test_shl_round() {
     test::<u32, i16>(4127195136, -25, RoundingMode::Floor, 123, Ordering::Equal);
     test::<u32, i16>(4127195136, -25, RoundingMode::Ceiling, 123, Ordering::Equal);
     test::<u32, i16>(4127195136, -25, RoundingMode::Nearest, 123, Ordering::Equal);
+    test::<u32, i16>(
+        4127195136,
+        -25,
+        RoundingMode::NearestTiesAway,
+        123,
+        Ordering::Equal,
+    );
+    test::<u32, i16>(
+        4127195136,
+        -25,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Equal,
+    );
+    test::<u32, i16>(4127195136, -25, RoundingMode::Odd, 123, Ordering::Equal);
     test::<u32, i16>(4127195136, -25, RoundingMode::Exact, 123, Ordering::Equal);
 
     test::<u32, i32>(4127195137, -25, RoundingMode::Down, 123, Ordering::Less);
@@ -123,6 +237,21 @@ fn test_shl_round() {
         Ordering::Greater,
     );
     test::<u32, i32>(4127195137, -25, RoundingMode::Nearest, 123, Ordering::Less);
+    test::<u32, i32>(
+        4127195137,
+        -25,
+        RoundingMode::NearestTiesAway,
+        123,
+        Ordering::Less,
+    );
+    test::<u32, i32>(
+        4127195137,
+        -25,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Less,
+    );
+    test::<u32, i32>(4127195137, -25, RoundingMode::Odd, 123, Ordering::Less);
 
     test::<i64, i8>(8254390271, -26, RoundingMode::Down, 122, Ordering::Less);
     test::<i64, i8>(8254390271, -26, RoundingMode::Up, 123, Ordering::Greater);
@@ -147,6 +276,21 @@ fn test_shl_round() {
     test::<u64, i16>(8254390272, -26, RoundingMode::Floor, 123, Ordering::Equal);
     test::<u64, i16>(8254390272, -26, RoundingMode::Ceiling, 123, Ordering::Equal);
     test::<u64, i16>(8254390272, -26, RoundingMode::Nearest, 123, Ordering::Equal);
+    test::<u64, i16>(
+        8254390272,
+        -26,
+        RoundingMode::NearestTiesAway,
+        123,
+        Ordering::Equal,
+    );
+    test::<u64, i16>(
+        8254390272,
+        -26,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Equal,
+    );
+    test::<u64, i16>(8254390272, -26, RoundingMode::Odd, 123, Ordering::Equal);
     test::<u64, i16>(8254390272, -26, RoundingMode::Exact, 123, Ordering::Equal);
 
     test::<i64, i32>(8254390273, -26, RoundingMode::Down, 123, Ordering::Less);
@@ -160,6 +304,21 @@ fn test_shl_round() {
         Ordering::Greater,
     );
     test::<i64, i32>(8254390273, -26, RoundingMode::Nearest, 123, Ordering::Less);
+    test::<i64, i32>(
+        8254390273,
+        -26,
+        RoundingMode::NearestTiesAway,
+        123,
+        Ordering::Less,
+    );
+    test::<i64, i32>(
+        8254390273,
+        -26,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Less,
+    );
+    test::<i64, i32>(8254390273, -26, RoundingMode::Odd, 123, Ordering::Less);
 
     test::<i64, i64>(
         0xffffffff,
@@ -482,6 +641,21 @@ fn test_shl_round() {
     test::<u128, i128>(980657949, -72, RoundingMode::Floor, 0, Ordering::Less);
     test::<u128, i128>(980657949, -72, RoundingMode::Ceiling, 1, Ordering::Greater);
     test::<u128, i128>(980657949, -72, RoundingMode::Nearest, 0, Ordering::Less);
+    test::<u128, i128>(
+        980657949,
+        -72,
+        RoundingMode::NearestTiesAway,
+        0,
+        Ordering::Less,
+    );
+    test::<u128, i128>(
+        980657949,
+        -72,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Less,
+    );
+    test::<u128, i128>(980657949, -72, RoundingMode::Odd, 1, Ordering::Greater);
 
     test::<i128, isize>(0xffffffff, -31, RoundingMode::Down, 1, Ordering::Less);
     test::<i128, isize>(0xffffffff, -31, RoundingMode::Up, 2, Ordering::Greater);
@@ -577,6 +751,21 @@ fn test_shl_round() {
     test::<i8, i8>(-123, 0, RoundingMode::Floor, -123, Ordering::Equal);
     test::<i8, i8>(-123, 0, RoundingMode::Ceiling, -123, Ordering::Equal);
     test::<i8, i8>(-123, 0, RoundingMode::Nearest, -123, Ordering::Equal);
+    test::<i8, i8>(
+        -123,
+        0,
+        RoundingMode::NearestTiesAway,
+        -123,
+        Ordering::Equal,
+    );
+    test::<i8, i8>(
+        -123,
+        0,
+        RoundingMode::NearestTiesTowardZero,
+        -123,
+        Ordering::Equal,
+    );
+    test::<i8, i8>(-123, 0, RoundingMode::Odd, -123, Ordering::Equal);
     test::<i8, i8>(-123, 0, RoundingMode::Exact, -123, Ordering::Equal);
 
     test::<i16, i8>(-245, -1, RoundingMode::Down, -122, Ordering::Greater);
@@ -584,12 +773,42 @@ fn test_shl_round() {
     test::<i16, i8>(-245, -1, RoundingMode::Floor, -123, Ordering::Less);
     test::<i16, i8>(-245, -1, RoundingMode::Ceiling, -122, Ordering::Greater);
     test::<i16, i8>(-245, -1, RoundingMode::Nearest, -122, Ordering::Greater);
+    test::<i16, i8>(
+        -245,
+        -1,
+        RoundingMode::NearestTiesAway,
+        -123,
+        Ordering::Less,
+    );
+    test::<i16, i8>(
+        -245,
+        -1,
+        RoundingMode::NearestTiesTowardZero,
+        -122,
+        Ordering::Greater,
+    );
+    test::<i16, i8>(-245, -1, RoundingMode::Odd, -123, Ordering::Less);
 
     test::<i16, i16>(-246, -1, RoundingMode::Down, -123, Ordering::Equal);
     test::<i16, i16>(-246, -1, RoundingMode::Up, -123, Ordering::Equal);
     test::<i16, i16>(-246, -1, RoundingMode::Floor, -123, Ordering::Equal);
     test::<i16, i16>(-246, -1, RoundingMode::Ceiling, -123, Ordering::Equal);
     test::<i16, i16>(-246, -1, RoundingMode::Nearest, -123, Ordering::Equal);
+    test::<i16, i16>(
+        -246,
+        -1,
+        RoundingMode::NearestTiesAway,
+        -123,
+        Ordering::Equal,
+    );
+    test::<i16, i16>(
+        -246,
+        -1,
+        RoundingMode::NearestTiesTowardZero,
+        -123,
+        Ordering::Equal,
+    );
+    test::<i16, i16>(-246, -1, RoundingMode::Odd, -123, Ordering::Equal);
     test::<i16, i16>(-246, -1, RoundingMode::Exact, -123, Ordering::Equal);
 
     test::<i16, i32>(-247, -1, RoundingMode::Down, -123, Ordering::Greater);
@@ -597,18 +816,63 @@ fn test_shl_round() {
     test::<i16, i32>(-247, -1, RoundingMode::Floor, -124, Ordering::Less);
     test::<i16, i32>(-247, -1, RoundingMode::Ceiling, -123, Ordering::Greater);
     test::<i16, i32>(-247, -1, RoundingMode::Nearest, -124, Ordering::Less);
+    test::<i16, i32>(
+        -247,
+        -1,
+        RoundingMode::NearestTiesAway,
+        -124,
+        Ordering::Less,
+    );
+    test::<i16, i32>(
+        -247,
+        -1,
+        RoundingMode::NearestTiesTowardZero,
+        -123,
+        Ordering::Greater,
+    );
+    test::<i16, i32>(-247, -1, RoundingMode::Odd, -123, Ordering::Greater);
 
     test::<i16, i64>(-491, -2, RoundingMode::Down, -122, Ordering::Greater);
     test::<i16, i64>(-491, -2, RoundingMode::Up, -123, Ordering::Less);
     test::<i16, i64>(-491, -2, RoundingMode::Floor, -123, Ordering::Less);
     test::<i16, i64>(-491, -2, RoundingMode::Ceiling, -122, Ordering::Greater);
     test::<i16, i64>(-491, -2, RoundingMode::Nearest, -123, Ordering::Less);
+    test::<i16, i64>(
+        -491,
+        -2,
+        RoundingMode::NearestTiesAway,
+        -123,
+        Ordering::Less,
+    );
+    test::<i16, i64>(
+        -491,
+        -2,
+        RoundingMode::NearestTiesTowardZero,
+        -123,
+        Ordering::Less,
+    );
+    test::<i16, i64>(-491, -2, RoundingMode::Odd, -123, Ordering::Less);
 
     test::<i16, i128>(-492, -2, RoundingMode::Down, -123, Ordering::Equal);
     test::<i16, i128>(-492, -2, RoundingMode::Up, -123, Ordering::Equal);
     test::<i16, i128>(-492, -2, RoundingMode::Floor, -123, Ordering::Equal);
     test::<i16, i128>(-492, -2, RoundingMode::Ceiling, -123, Ordering::Equal);
     test::<i16, i128>(-492, -2, RoundingMode::Nearest, -123, Ordering::Equal);
+    test::<i16, i128>(
+        -492,
+        -2,
+        RoundingMode::NearestTiesAway,
+        -123,
+        Ordering::Equal,
+    );
+    test::<i16, i128>(
+        -492,
+        -2,
+        RoundingMode::NearestTiesTowardZero,
+        -123,
+        Ordering::Equal,
+    );
+    test::<i16, i128>(-492, -2, RoundingMode::Odd, -123, Ordering::Equal);
     test::<i16, i128>(-492, -2, RoundingMode::Exact, -123, Ordering::Equal);
 
     test::<i16, isize>(-493, -2, RoundingMode::Down, -123, Ordering::Greater);
@@ -616,6 +880,21 @@ fn test_shl_round() {
     test::<i16, isize>(-493, -2, RoundingMode::Floor, -124, Ordering::Less);
     test::<i16, isize>(-493, -2, RoundingMode::Ceiling, -123, Ordering::Greater);
     test::<i16, isize>(-493, -2, RoundingMode::Nearest, -123, Ordering::Greater);
+    test::<i16, isize>(
+        -493,
+        -2,
+        RoundingMode::NearestTiesAway,
+        -123,
+        Ordering::Greater,
+    );
+    test::<i16, isize>(
+        -493,
+        -2,
+        RoundingMode::NearestTiesTowardZero,
+        -123,
+        Ordering::Greater,
+    );
+    test::<i16, isize>(-493, -2, RoundingMode::Odd, -123, Ordering::Greater);
 
     test::<i64, i8>(
         -4127195135,
@@ -1287,6 +1566,21 @@ fn test_shl_round() {
     test::<i64, i16>(-980657949, -72, RoundingMode::Floor, -1, Ordering::Less);
     test::<i64, i16>(-980657949, -72, RoundingMode::Ceiling, 0, Ordering::Greater);
     test::<i64, i16>(-980657949, -72, RoundingMode::Nearest, 0, Ordering::Greater);
+    test::<i64, i16>(
+        -980657949,
+        -72,
+        RoundingMode::NearestTiesAway,
+        0,
+        Ordering::Greater,
+    );
+    test::<i64, i16>(
+        -980657949,
+        -72,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Greater,
+    );
+    test::<i64, i16>(-980657949, -72, RoundingMode::Odd, -1, Ordering::Less);
 
     test::<i64, i32>(-0xffffffff, -31, RoundingMode::Down, -1, Ordering::Greater);
     test::<i64, i32>(-0xffffffff, -31, RoundingMode::Up, -2, Ordering::Less);
@@ -38,6 +38,15 @@ fn test_shr_round() {
     test::<u8, u8>(0, 0, RoundingMode::Floor, 0, Ordering::Equal);
     test::<u8, u8>(0, 0, RoundingMode::Ceiling, 0, Ordering::Equal);
     test::<u8, u8>(0, 0, RoundingMode::Nearest, 0, Ordering::Equal);
+    test::<u8, u8>(0, 0, RoundingMode::NearestTiesAway, 0, Ordering::Equal);
+    test::<u8, u8>(
+        0,
+        0,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Equal,
+    );
+    test::<u8, u8>(0, 0, RoundingMode::Odd, 0, Ordering::Equal);
     test::<u8, u8>(0, 0, RoundingMode::Exact, 0, Ordering::Equal);
 
     test::<u8, i16>(0, 10, RoundingMode::Down, 0, Ordering::Equal);
@@ -45,6 +54,15 @@ fn test_shr_round() {
     test::<u8, i16>(0, 10, RoundingMode::Floor, 0, Ordering::Equal);
     test::<u8, i16>(0, 10, RoundingMode::Ceiling, 0, Ordering::Equal);
     test::<u8, i16>(0, 10, RoundingMode::Nearest, 0, Ordering::Equal);
+    test::<u8, i16>(0, 10, RoundingMode::NearestTiesAway, 0, Ordering::Equal);
+    test::<u8, i16>(
+        0,
+        10,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Equal,
+    );
+    test::<u8, i16>(0, 10, RoundingMode::Odd, 0, Ordering::Equal);
     test::<u8, i16>(0, 10, RoundingMode::Exact, 0, Ordering::Equal);
 
     test::<i8, u32>(123, 0, RoundingMode::Down, 123, Ordering::Equal);
@@ -52,6 +70,15 @@ fn test_shr_round() {
     test::<i8, u32>(123, 0, RoundingMode::Floor, 123, Ordering::Equal);
     test::<i8, u32>(123, 0, RoundingMode::Ceiling, 123, Ordering::Equal);
     test::<i8, u32>(123, 0, RoundingMode::Nearest, 123, Ordering::Equal);
+    test::<i8, u32>(123, 0, RoundingMode::NearestTiesAway, 123, Ordering::Equal);
+    test::<i8, u32>(
+        123,
+        0,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Equal,
+    );
+    test::<i8, u32>(123, 0, RoundingMode::Odd, 123, Ordering::Equal);
     test::<i8, u32>(123, 0, RoundingMode::Exact, 123, Ordering::Equal);
 
     test::<u8, u64>(245, 1, RoundingMode::Down, 122, Ordering::Less);
@@ -59,12 +86,36 @@ fn test_shr_round() {
     test::<u8, u64>(245, 1, RoundingMode::Floor, 122, Ordering::Less);
     test::<u8, u64>(245, 1, RoundingMode::Ceiling, 123, Ordering::Greater);
     test::<u8, u64>(245, 1, RoundingMode::Nearest, 122, Ordering::Less);
+    test::<u8, u64>(
+        245,
+        1,
+        RoundingMode::NearestTiesAway,
+        123,
+        Ordering::Greater,
+    );
+    test::<u8, u64>(
+        245,
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        122,
+        Ordering::Less,
+    );
+    test::<u8, u64>(245, 1, RoundingMode::Odd, 123, Ordering::Greater);
 
     test::<u8, u128>(246, 1, RoundingMode::Down, 123, Ordering::Equal);
     test::<u8, u128>(246, 1, RoundingMode::Up, 123, Ordering::Equal);
     test::<u8, u128>(246, 1, RoundingMode::Floor, 123, Ordering::Equal);
     test::<u8, u128>(246, 1, RoundingMode::Ceiling, 123, Ordering::Equal);
     test::<u8, u128>(246, 1, RoundingMode::Nearest, 123, Ordering::Equal);
+    test::<u8, u128>(246, 1, RoundingMode::NearestTiesAway, 123, Ordering::Equal);
+    test::<u8, u128>(
+        246,
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Equal,
+    );
+    test::<u8, u128>(246, 1, RoundingMode::Odd, 123, Ordering::Equal);
     test::<u8, u128>(246, 1, RoundingMode::Exact, 123, Ordering::Equal);
 
     test::<u8, usize>(247, 1, RoundingMode::Down, 123, Ordering::Less);
@@ -72,18 +123,57 @@ fn test_shr_round() {
     test::<u8, usize>(247, 1, RoundingMode::Floor, 123, Ordering::Less);
     test::<u8, usize>(247, 1, RoundingMode::Ceiling, 124, Ordering::Greater);
     test::<u8, usize>(247, 1, RoundingMode::Nearest, 124, Ordering::Greater);
+    test::<u8, usize>(
+        247,
+        1,
+        RoundingMode::NearestTiesAway,
+        124,
+        Ordering::Greater,
+    );
+    test::<u8, usize>(
+        247,
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Less,
+    );
+    test::<u8, usize>(247, 1, RoundingMode::Odd, 123, Ordering::Less);
 
     test::<i16, i8>(491, 2, RoundingMode::Down, 122, Ordering::Less);
     test::<i16, i8>(491, 2, RoundingMode::Up, 123, Ordering::Greater);
     test::<i16, i8>(491, 2, RoundingMode::Floor, 122, Ordering::Less);
     test::<i16, i8>(491, 2, RoundingMode::Ceiling, 123, Ordering::Greater);
     test::<i16, i8>(491, 2, RoundingMode::Nearest, 123, Ordering::Greater);
+    test::<i16, i8>(
+        491,
+        2,
+        RoundingMode::NearestTiesAway,
+        123,
+        Ordering::Greater,
+    );
+    test::<i16, i8>(
+        491,
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Greater,
+    );
+    test::<i16, i8>(491, 2, RoundingMode::Odd, 123, Ordering::Greater);
 
     test::<u16, i16>(492, 2, RoundingMode::Down, 123, Ordering::Equal);
     test::<u16, i16>(492, 2, RoundingMode::Up, 123, Ordering::Equal);
     test::<u16, i16>(492, 2, RoundingMode::Floor, 123, Ordering::Equal);
     test::<u16, i16>(492, 2, RoundingMode::Ceiling, 123, Ordering::Equal);
     test::<u16, i16>(492, 2, RoundingMode::Nearest, 123, Ordering::Equal);
+    test::<u16, i16>(492, 2, RoundingMode::NearestTiesAway, 123, Ordering::Equal);
+    test::<u16, i16>(
+        492,
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Equal,
+    );
+    test::<u16, i16>(492, 2, RoundingMode::Odd, 123, Ordering::Equal);
     test::<u16, i16>(492, 2, RoundingMode::Exact, 123, Ordering::Equal);
 
     test::<i16, u32>(493, 2, RoundingMode::Down, 123, Ordering::Less);
@@ -91,6 +181,15 @@ fn test_shr_round() {
     test::<i16, u32>(493, 2, RoundingMode::Floor, 123, Ordering::Less);
     test::<i16, u32>(493, 2, RoundingMode::Ceiling, 124, Ordering::Greater);
     test::<i16, u32>(493, 2, RoundingMode::Nearest, 123, Ordering::Less);
+    test::<i16, u32>(493, 2, RoundingMode::NearestTiesAway, 123, Ordering::Less);
+    test::<i16, u32>(
+        493,
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Less,
+    );
+    test::<i16, u32>(493, 2, RoundingMode::Odd, 123, Ordering::Less);
 
     test::<u32, i8>(4127195135, 25, RoundingMode::Down, 122, Ordering::Less);
     test::<u32, i8>(4127195135, 25, RoundingMode::Up, 123, Ordering::Greater);
@@ -115,6 +214,21 @@ fn test_shr_round() {
     test::<u32, u16>(4127195136, 25, RoundingMode::Floor, 123, Ordering::Equal);
     test::<u32, u16>(4127195136, 25, RoundingMode::Ceiling, 123, Ordering::Equal);
     test::<u32, u16>(4127195136, 25, RoundingMode::Nearest, 123, Ordering::Equal);
+    test::<u32, u16>(
+        4127195136,
+        25,
+        RoundingMode::NearestTiesAway,
+        123,
+        Ordering::Equal,
+    );
+    test::<u32, u16>(
+        4127195136,
+        25,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Equal,
+    );
+    test::<u32, u16>(4127195136, 25, RoundingMode::Odd, 123, Ordering::Equal);
     test::<u32, u16>(4127195136, 25, RoundingMode::Exact, 123, Ordering::Equal);
 
     test::<u32, i32>(4127195137, 25, RoundingMode::Down, 123, Ordering::Less);
@@ -128,6 +242,21 @@ fn test_shr_round() {
         Ordering::Greater,
     );
     test::<u32, i32>(4127195137, 25, RoundingMode::Nearest, 123, Ordering::Less);
+    test::<u32, i32>(
+        4127195137,
+        25,
+        RoundingMode::NearestTiesAway,
+        123,
+        Ordering::Less,
+    );
+    test::<u32, i32>(
+        4127195137,
+        25,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Less,
+    );
+    test::<u32, i32>(4127195137, 25, RoundingMode::Odd, 123, Ordering::Less);
 
     test::<i64, u8>(8254390271, 26, RoundingMode::Down, 122, Ordering::Less);
     test::<i64, u8>(8254390271, 26, RoundingMode::Up, 123, Ordering::Greater);
@@ -152,6 +281,21 @@ fn test_shr_round() {
     test::<u64, i16>(8254390272, 26, RoundingMode::Floor, 123, Ordering::Equal);
     test::<u64, i16>(8254390272, 26, RoundingMode::Ceiling, 123, Ordering::Equal);
     test::<u64, i16>(8254390272, 26, RoundingMode::Nearest, 123, Ordering::Equal);
+    test::<u64, i16>(
+        8254390272,
+        26,
+        RoundingMode::NearestTiesAway,
+        123,
+        Ordering::Equal,
+    );
+    test::<u64, i16>(
+        8254390272,
+        26,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Equal,
+    );
+    test::<u64, i16>(8254390272, 26, RoundingMode::Odd, 123, Ordering::Equal);
     test::<u64, i16>(8254390272, 26, RoundingMode::Exact, 123, Ordering::Equal);
 
     test::<i64, u32>(8254390273, 26, RoundingMode::Down, 123, Ordering::Less);
@@ -165,6 +309,21 @@ fn test_shr_round() {
         Ordering::Greater,
     );
     test::<i64, u32>(8254390273, 26, RoundingMode::Nearest, 123, Ordering::Less);
+    test::<i64, u32>(
+        8254390273,
+        26,
+        RoundingMode::NearestTiesAway,
+        123,
+        Ordering::Less,
+    );
+    test::<i64, u32>(
+        8254390273,
+        26,
+        RoundingMode::NearestTiesTowardZero,
+        123,
+        Ordering::Less,
+    );
+    test::<i64, u32>(8254390273, 26, RoundingMode::Odd, 123, Ordering::Less);
 
     test::<i64, i64>(
         0xffffffff,
@@ -487,6 +646,21 @@ fn test_shr_round() {
     test::<u128, i128>(980657949, 72, RoundingMode::Floor, 0, Ordering::Less);
     test::<u128, i128>(980657949, 72, RoundingMode::Ceiling, 1, Ordering::Greater);
     test::<u128, i128>(980657949, 72, RoundingMode::Nearest, 0, Ordering::Less);
+    test::<u128, i128>(
+        980657949,
+        72,
+        RoundingMode::NearestTiesAway,
+        0,
+        Ordering::Less,
+    );
+    test::<u128, i128>(
+        980657949,
+        72,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Less,
+    );
+    test::<u128, i128>(980657949, 72, RoundingMode::Odd, 1, Ordering::Greater);
 
     test::<i128, isize>(0xffffffff, 31, RoundingMode::Down, 1, Ordering::Less);
     test::<i128, isize>(0xffffffff, 31, RoundingMode::Up, 2, Ordering::Greater);
@@ -576,6 +750,21 @@ fn test_shr_round() {
     test::<i8, u8>(-123, 0, RoundingMode::Floor, -123, Ordering::Equal);
     test::<i8, u8>(-123, 0, RoundingMode::Ceiling, -123, Ordering::Equal);
     test::<i8, u8>(-123, 0, RoundingMode::Nearest, -123, Ordering::Equal);
+    test::<i8, u8>(
+        -123,
+        0,
+        RoundingMode::NearestTiesAway,
+        -123,
+        Ordering::Equal,
+    );
+    test::<i8, u8>(
+        -123,
+        0,
+        RoundingMode::NearestTiesTowardZero,
+        -123,
+        Ordering::Equal,
+    );
+    test::<i8, u8>(-123, 0, RoundingMode::Odd, -123, Ordering::Equal);
     test::<i8, u8>(-123, 0, RoundingMode::Exact, -123, Ordering::Equal);
 
     test::<i16, i8>(-245, 1, RoundingMode::Down, -122, Ordering::Greater);
@@ -583,12 +772,36 @@ fn test_shr_round() {
     test::<i16, i8>(-245, 1, RoundingMode::Floor, -123, Ordering::Less);
     test::<i16, i8>(-245, 1, RoundingMode::Ceiling, -122, Ordering::Greater);
     test::<i16, i8>(-245, 1, RoundingMode::Nearest, -122, Ordering::Greater);
+    test::<i16, i8>(-245, 1, RoundingMode::NearestTiesAway, -123, Ordering::Less);
+    test::<i16, i8>(
+        -245,
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        -122,
+        Ordering::Greater,
+    );
+    test::<i16, i8>(-245, 1, RoundingMode::Odd, -123, Ordering::Less);
 
     test::<i16, u16>(-246, 1, RoundingMode::Down, -123, Ordering::Equal);
     test::<i16, u16>(-246, 1, RoundingMode::Up, -123, Ordering::Equal);
     test::<i16, u16>(-246, 1, RoundingMode::Floor, -123, Ordering::Equal);
     test::<i16, u16>(-246, 1, RoundingMode::Ceiling, -123, Ordering::Equal);
     test::<i16, u16>(-246, 1, RoundingMode::Nearest, -123, Ordering::Equal);
+    test::<i16, u16>(
+        -246,
+        1,
+        RoundingMode::NearestTiesAway,
+        -123,
+        Ordering::Equal,
+    );
+    test::<i16, u16>(
+        -246,
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        -123,
+        Ordering::Equal,
+    );
+    test::<i16, u16>(-246, 1, RoundingMode::Odd, -123, Ordering::Equal);
     test::<i16, u16>(-246, 1, RoundingMode::Exact, -123, Ordering::Equal);
 
     test::<i16, i32>(-247, 1, RoundingMode::Down, -123, Ordering::Greater);
@@ -596,18 +809,51 @@ fn test_shr_round() {
     test::<i16, i32>(-247, 1, RoundingMode::Floor, -124, Ordering::Less);
     test::<i16, i32>(-247, 1, RoundingMode::Ceiling, -123, Ordering::Greater);
     test::<i16, i32>(-247, 1, RoundingMode::Nearest, -124, Ordering::Less);
+    test::<i16, i32>(-247, 1, RoundingMode::NearestTiesAway, -124, Ordering::Less);
+    test::<i16, i32>(
+        -247,
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        -123,
+        Ordering::Greater,
+    );
+    test::<i16, i32>(-247, 1, RoundingMode::Odd, -123, Ordering::Greater);
 
     test::<i16, u64>(-491, 2, RoundingMode::Down, -122, Ordering::Greater);
     test::<i16, u64>(-491, 2, RoundingMode::Up, -123, Ordering::Less);
     test::<i16, u64>(-491, 2, RoundingMode::Floor, -123, Ordering::Less);
     test::<i16, u64>(-491, 2, RoundingMode::Ceiling, -122, Ordering::Greater);
     test::<i16, u64>(-491, 2, RoundingMode::Nearest, -123, Ordering::Less);
+    test::<i16, u64>(-491, 2, RoundingMode::NearestTiesAway, -123, Ordering::Less);
+    test::<i16, u64>(
+        -491,
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        -123,
+        Ordering::Less,
+    );
+    test::<i16, u64>(-491, 2, RoundingMode::Odd, -123, Ordering::Less);
 
     test::<i16, i128>(-492, 2, RoundingMode::Down, -123, Ordering::Equal);
     test::<i16, i128>(-492, 2, RoundingMode::Up, -123, Ordering::Equal);
     test::<i16, i128>(-492, 2, RoundingMode::Floor, -123, Ordering::Equal);
     test::<i16, i128>(-492, 2, RoundingMode::Ceiling, -123, Ordering::Equal);
     test::<i16, i128>(-492, 2, RoundingMode::Nearest, -123, Ordering::Equal);
+    test::<i16, i128>(
+        -492,
+        2,
+        RoundingMode::NearestTiesAway,
+        -123,
+        Ordering::Equal,
+    );
+    test::<i16, i128>(
+        -492,
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        -123,
+        Ordering::Equal,
+    );
+    test::<i16, i128>(-492, 2, RoundingMode::Odd, -123, Ordering::Equal);
     test::<i16, i128>(-492, 2, RoundingMode::Exact, -123, Ordering::Equal);
 
     test::<i16, usize>(-493, 2, RoundingMode::Down, -123, Ordering::Greater);
@@ -615,6 +861,21 @@ fn test_shr_round() {
     test::<i16, usize>(-493, 2, RoundingMode::Floor, -124, Ordering::Less);
     test::<i16, usize>(-493, 2, RoundingMode::Ceiling, -123, Ordering::Greater);
     test::<i16, usize>(-493, 2, RoundingMode::Nearest, -123, Ordering::Greater);
+    test::<i16, usize>(
+        -493,
+        2,
+        RoundingMode::NearestTiesAway,
+        -123,
+        Ordering::Greater,
+    );
+    test::<i16, usize>(
+        -493,
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        -123,
+        Ordering::Greater,
+    );
+    test::<i16, usize>(-493, 2, RoundingMode::Odd, -123, Ordering::Greater);
 
     test::<i64, i8>(-4127195135, 25, RoundingMode::Down, -122, Ordering::Greater);
     test::<i64, i8>(-4127195135, 25, RoundingMode::Up, -123, Ordering::Less);
@@ -627,6 +888,21 @@ fn test_shr_round() {
         Ordering::Greater,
     );
     test::<i64, i8>(-4127195135, 25, RoundingMode::Nearest, -123, Ordering::Less);
+    test::<i64, i8>(
+        -4127195135,
+        25,
+        RoundingMode::NearestTiesAway,
+        -123,
+        Ordering::Less,
+    );
+    test::<i64, i8>(
+        -4127195135,
+        25,
+        RoundingMode::NearestTiesTowardZero,
+        -123,
+        Ordering::Less,
+    );
+    test::<i64, i8>(-4127195135, 25, RoundingMode::Odd, -123, Ordering::Less);
 
     test::<i64, u16>(-4127195136, 25, RoundingMode::Down, -123, Ordering::Equal);
     test::<i64, u16>(-4127195136, 25, RoundingMode::Up, -123, Ordering::Equal);
@@ -676,6 +952,21 @@ fn test_shr_round() {
         Ordering::Greater,
     );
     test::<i64, u64>(-8254390271, 26, RoundingMode::Nearest, -123, Ordering::Less);
+    test::<i64, u64>(
+        -8254390271,
+        26,
+        RoundingMode::NearestTiesAway,
+        -123,
+        Ordering::Less,
+    );
+    test::<i64, u64>(
+        -8254390271,
+        26,
+        RoundingMode::NearestTiesTowardZero,
+        -123,
+        Ordering::Less,
+    );
+    test::<i64, u64>(-8254390271, 26, RoundingMode::Odd, -123, Ordering::Less);
 
     test::<i64, i128>(-8254390272, 26, RoundingMode::Down, -123, Ordering::Equal);
     test::<i64, i128>(-8254390272, 26, RoundingMode::Up, -123, Ordering::Equal);
@@ -1250,6 +1541,21 @@ fn test_shr_round() {
     test::<i64, u16>(-980657949, 72, RoundingMode::Floor, -1, Ordering::Less);
     test::<i64, u16>(-980657949, 72, RoundingMode::Ceiling, 0, Ordering::Greater);
     test::<i64, u16>(-980657949, 72, RoundingMode::Nearest, 0, Ordering::Greater);
+    test::<i64, u16>(
+        -980657949,
+        72,
+        RoundingMode::NearestTiesAway,
+        0,
+        Ordering::Greater,
+    );
+    test::<i64, u16>(
+        -980657949,
+        72,
+        RoundingMode::NearestTiesTowardZero,
+        0,
+        Ordering::Greater,
+    );
+    test::<i64, u16>(-980657949, 72, RoundingMode::Odd, -1, Ordering::Less);
 
     test::<i64, i32>(-0xffffffff, 31, RoundingMode::Down, -1, Ordering::Greater);
     test::<i64, i32>(-0xffffffff, 31, RoundingMode::Up, -2, Ordering::Less);
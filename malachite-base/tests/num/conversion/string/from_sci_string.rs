@@ -2,6 +2,7 @@ use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::signeds::PrimitiveSigned;
 use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
 use malachite_base::num::conversion::string::options::FromSciStringOptions;
+use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
 use malachite_base::rounding_modes::RoundingMode;
 use malachite_base::test_util::generators::{
     signed_gen, string_from_sci_string_options_pair_gen,
@@ -11,9 +12,9 @@ use malachite_base::test_util::generators::{
 #[test]
 pub fn test_from_sci_string() {
     fn test<T: PrimitiveInt>(s: &str, out: Option<T>) {
-        assert_eq!(T::from_sci_string(s), out);
+        assert_eq!(T::from_sci_string(s).ok(), out);
         assert_eq!(
-            T::from_sci_string_with_options(s, FromSciStringOptions::default()),
+            T::from_sci_string_with_options(s, FromSciStringOptions::default()).ok(),
             out
         );
     }
@@ -196,7 +197,7 @@ pub fn test_from_sci_string() {
 #[test]
 pub fn test_from_sci_string_with_options() {
     fn test<T: PrimitiveInt>(s: &str, options: FromSciStringOptions, out: Option<T>) {
-        assert_eq!(T::from_sci_string_with_options(s, options), out);
+        assert_eq!(T::from_sci_string_with_options(s, options).ok(), out);
     }
     // For tests with the default options, see `test_from_sci_string`
 
@@ -730,8 +731,44 @@ pub fn test_from_sci_string_with_options() {
     test::<i8>("-1.9999999999999999999999999999", options, None);
 }
 
+#[test]
+pub fn test_from_sci_string_error() {
+    fn test<T: PrimitiveInt>(s: &str, options: FromSciStringOptions, kind: ParseNumberErrorKind) {
+        assert_eq!(
+            T::from_sci_string_with_options(s, options)
+                .unwrap_err()
+                .kind(),
+            kind
+        );
+    }
+    let mut options = FromSciStringOptions::default();
+    test::<u8>("", options, ParseNumberErrorKind::Empty);
+    test::<u8>("-", options, ParseNumberErrorKind::Empty);
+    test::<u8>("1e", options, ParseNumberErrorKind::Empty);
+    test::<u8>("12x", options, ParseNumberErrorKind::InvalidDigit(2));
+    test::<u8>("1.2.3", options, ParseNumberErrorKind::InvalidDigit(3));
+    test::<u8>("1e1.5", options, ParseNumberErrorKind::InvalidDigit(3));
+    test::<u8>("--1", options, ParseNumberErrorKind::InvalidDigit(1));
+    test::<u8>("-1", options, ParseNumberErrorKind::SignNotAllowed);
+    test::<u8>("256", options, ParseNumberErrorKind::Overflow);
+    test::<i8>("-1e3", options, ParseNumberErrorKind::Overflow);
+    test::<u8>(
+        "1e10000000000000000000000",
+        options,
+        ParseNumberErrorKind::Overflow,
+    );
+
+    options.set_base(2);
+    test::<u8>("102", options, ParseNumberErrorKind::InvalidDigit(2));
+
+    options = FromSciStringOptions::default();
+    options.set_rounding_mode(RoundingMode::Exact);
+    test::<u8>("1.5", options, ParseNumberErrorKind::Inexact);
+    test::<i8>("-1.5e-1", options, ParseNumberErrorKind::Inexact);
+}
+
 fn from_sci_string_helper_helper<T: PrimitiveInt>(s: &str) {
-    if let Some(x) = T::from_sci_string(s) {
+    if let Ok(x) = T::from_sci_string(s) {
         assert!(!s.ends_with('+'));
         assert!(!s.ends_with('-'));
         assert!(!s.contains("++"));
@@ -787,7 +824,7 @@ fn from_sci_string_with_options_helper_helper<T: PrimitiveInt>(
     s: &str,
     options: FromSciStringOptions,
 ) {
-    if let Some(x) = T::from_sci_string_with_options(s, options) {
+    if let Ok(x) = T::from_sci_string_with_options(s, options) {
         assert!(!s.ends_with('+'));
         assert!(!s.ends_with('-'));
         assert!(!s.contains("++"));
@@ -1,4 +1,6 @@
+use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::conversion::string::from_string::digit_from_display_byte;
+use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
 use malachite_base::num::conversion::string::to_string::{
     digit_to_display_byte_lower, digit_to_display_byte_upper,
 };
@@ -39,6 +41,35 @@ fn test_digit_from_display_byte() {
     test_err(b'!');
 }
 
+#[test]
+fn test_from_string_base() {
+    fn test_ok<T: PrimitiveInt>(base: u8, s: &str, out: T) {
+        assert_eq!(T::from_string_base(base, s).unwrap(), out);
+    }
+    test_ok::<u8>(10, "0", 0);
+    test_ok::<u8>(10, "+255", 255);
+    test_ok::<u8>(16, "fF", 255);
+    test_ok::<i8>(2, "-10000000", -128);
+    test_ok::<u64>(36, "z", 35);
+
+    fn test_err<T: PrimitiveInt>(base: u8, s: &str, kind: ParseNumberErrorKind) {
+        assert_eq!(T::from_string_base(base, s).unwrap_err().kind(), kind);
+    }
+    test_err::<u8>(10, "", ParseNumberErrorKind::Empty);
+    test_err::<i8>(10, "-", ParseNumberErrorKind::Empty);
+    test_err::<u8>(10, "+", ParseNumberErrorKind::Empty);
+    test_err::<u8>(10, "-1", ParseNumberErrorKind::SignNotAllowed);
+    test_err::<u8>(10, "-0", ParseNumberErrorKind::SignNotAllowed);
+    test_err::<i8>(10, "+-1", ParseNumberErrorKind::InvalidDigit(1));
+    test_err::<u8>(10, "1a", ParseNumberErrorKind::InvalidDigit(1));
+    test_err::<u8>(2, "1012", ParseNumberErrorKind::InvalidDigit(3));
+    test_err::<u8>(10, " 1", ParseNumberErrorKind::InvalidDigit(0));
+    test_err::<u8>(10, "256", ParseNumberErrorKind::Overflow);
+    test_err::<i8>(10, "-129", ParseNumberErrorKind::Overflow);
+    test_err::<u8>(1, "0", ParseNumberErrorKind::InvalidBase(1));
+    test_err::<u8>(37, "0", ParseNumberErrorKind::InvalidBase(37));
+}
+
 #[test]
 fn digit_from_display_byte_properties() {
     unsigned_gen().test_properties(|b| {
@@ -0,0 +1,190 @@
+use malachite_base::num::arithmetic::traits::UnsignedAbs;
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::string::options::{DigitGrouping, FormatOptions};
+use malachite_base::num::conversion::traits::ToFormattedString;
+use malachite_base::test_util::generators::{
+    signed_unsigned_pair_gen_var_5, unsigned_pair_gen_var_8,
+};
+use std::panic::catch_unwind;
+
+#[test]
+pub fn test_to_formatted_string() {
+    fn test<T: Copy + ToFormattedString>(x: T, options: FormatOptions, out: &str) {
+        assert_eq!(x.to_formatted_string(options), out);
+    }
+    let mut options = FormatOptions::default();
+    assert_eq!(
+        options.get_non_terminating_fraction_digits(),
+        Some(FormatOptions::NON_TERMINATING_FRACTION_DIGITS)
+    );
+    test(0u8, options, "0");
+    test(123u8, options, "123");
+    test(1234567u32, options, "1234567");
+    test(-1234567i32, options, "-1234567");
+
+    options.set_digit_grouping(DigitGrouping::Uniform(3));
+    test(0u8, options, "0");
+    test(123u8, options, "123");
+    test(1234u16, options, "1,234");
+    test(123456u32, options, "123,456");
+    test(1234567u32, options, "1,234,567");
+    test(-123i8, options, "-123");
+    test(-1234i16, options, "-1,234");
+    test(-123456i32, options, "-123,456");
+    test(i64::MIN, options, "-9,223,372,036,854,775,808");
+    test(
+        u128::MAX,
+        options,
+        "340,282,366,920,938,463,463,374,607,431,768,211,455",
+    );
+
+    options.set_digit_grouping(DigitGrouping::Indian);
+    test(123u8, options, "123");
+    test(1234u16, options, "1,234");
+    test(12345u16, options, "12,345");
+    test(123456u32, options, "1,23,456");
+    test(12345678u32, options, "1,23,45,678");
+    test(-123456789i32, options, "-12,34,56,789");
+
+    options.set_digit_grouping(DigitGrouping::Uniform(3));
+    options.set_group_separator('.');
+    options.set_decimal_separator(',');
+    test(1234567u32, options, "1.234.567");
+    options.set_fraction_digits(2);
+    test(1234567u32, options, "1.234.567,00");
+    test(-1i8, options, "-1,00");
+    options.set_fraction_digits(0);
+    test(1234567u32, options, "1.234.567");
+    options.set_fraction_digits_exact();
+    assert_eq!(options.get_fraction_digits(), None);
+    assert_eq!(options.get_non_terminating_fraction_digits(), None);
+    test(1234567u32, options, "1.234.567");
+    options.set_fraction_digits_exact_or(3);
+    assert_eq!(options.get_fraction_digits(), None);
+    assert_eq!(options.get_non_terminating_fraction_digits(), Some(3));
+    test(1234567u32, options, "1.234.567");
+
+    let mut options = FormatOptions::default();
+    options.set_base(16);
+    options.set_digit_grouping(DigitGrouping::Uniform(4));
+    options.set_group_separator('_');
+    test(u64::MAX, options, "ffff_ffff_ffff_ffff");
+    test(0xabcdeu32, options, "a_bcde");
+    test(-0xabcdei32, options, "-a_bcde");
+    options.set_uppercase();
+    test(u64::MAX, options, "FFFF_FFFF_FFFF_FFFF");
+
+    options.set_base(2);
+    options.set_digit_grouping(DigitGrouping::Uniform(1));
+    options.set_group_separator(' ');
+    test(10u8, options, "1 0 1 0");
+
+    options.set_base(36);
+    options.set_digit_grouping(DigitGrouping::None);
+    options.set_fraction_digits(3);
+    options.set_decimal_separator('·');
+    test(-1295i16, options, "-ZZ·000");
+}
+
+#[test]
+fn set_digit_grouping_fail() {
+    assert_panic!(FormatOptions::default().set_digit_grouping(DigitGrouping::Uniform(0)));
+}
+
+fn strip_separators(s: &str) -> String {
+    s.chars().filter(|&c| c != '_').collect()
+}
+
+fn check_groups(s: &str, grouping: DigitGrouping) {
+    let groups: Vec<&str> = s.split('_').collect();
+    let (last, rest) = groups.split_last().unwrap();
+    match grouping {
+        DigitGrouping::None => assert_eq!(groups.len(), 1),
+        DigitGrouping::Uniform(size) => {
+            let size = usize::try_from(size).unwrap();
+            if !rest.is_empty() {
+                assert_eq!(last.len(), size);
+            }
+            for (i, group) in rest.iter().enumerate() {
+                if i == 0 {
+                    assert!((1..=size).contains(&group.len()));
+                } else {
+                    assert_eq!(group.len(), size);
+                }
+            }
+        }
+        DigitGrouping::Indian => {
+            if !rest.is_empty() {
+                assert_eq!(last.len(), 3);
+            }
+            for (i, group) in rest.iter().enumerate() {
+                if i == 0 {
+                    assert!((1..=2).contains(&group.len()));
+                } else {
+                    assert_eq!(group.len(), 2);
+                }
+            }
+        }
+    }
+}
+
+const GROUPINGS: [DigitGrouping; 5] = [
+    DigitGrouping::None,
+    DigitGrouping::Uniform(1),
+    DigitGrouping::Uniform(3),
+    DigitGrouping::Uniform(4),
+    DigitGrouping::Indian,
+];
+
+fn to_formatted_string_helper_unsigned<T: PrimitiveUnsigned>() {
+    unsigned_pair_gen_var_8::<T, u8>().test_properties(|(x, base)| {
+        let mut options = FormatOptions::default();
+        options.set_base(base);
+        let s = x.to_string_base(base);
+        assert_eq!(x.to_formatted_string(options), s);
+        options.set_group_separator('_');
+        for grouping in GROUPINGS {
+            options.set_digit_grouping(grouping);
+            let s_grouped = x.to_formatted_string(options);
+            assert_eq!(strip_separators(&s_grouped), s);
+            check_groups(&s_grouped, grouping);
+        }
+        options.set_digit_grouping(DigitGrouping::None);
+        options.set_uppercase();
+        options.set_fraction_digits(2);
+        assert_eq!(
+            x.to_formatted_string(options),
+            x.to_string_base_upper(base) + ".00"
+        );
+    });
+}
+
+fn to_formatted_string_helper_signed<T: PrimitiveSigned>()
+where
+    <T as UnsignedAbs>::Output: PrimitiveUnsigned,
+{
+    signed_unsigned_pair_gen_var_5::<T, u8>().test_properties(|(x, base)| {
+        let mut options = FormatOptions::default();
+        options.set_base(base);
+        let s = x.to_formatted_string(options);
+        assert_eq!(s.starts_with('-'), x < T::ZERO);
+        assert_eq!(
+            &s[usize::from(x < T::ZERO)..],
+            x.unsigned_abs().to_formatted_string(options)
+        );
+        options.set_group_separator('_');
+        for grouping in GROUPINGS {
+            options.set_digit_grouping(grouping);
+            let s_grouped = x.to_formatted_string(options);
+            assert_eq!(strip_separators(&s_grouped), s);
+            check_groups(s_grouped.trim_start_matches('-'), grouping);
+        }
+    });
+}
+
+#[test]
+fn to_formatted_string_properties() {
+    apply_fn_to_unsigneds!(to_formatted_string_helper_unsigned);
+    apply_fn_to_signeds!(to_formatted_string_helper_signed);
+}
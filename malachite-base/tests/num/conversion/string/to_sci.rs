@@ -816,7 +816,7 @@ fn to_sci_helper_unsigned<T: PrimitiveUnsigned>() {
         assert!(!s.contains('+'));
         assert!(!s.contains('E'));
         assert!(s.chars().filter(|&c| c == '.').count() <= 1);
-        if let Some(x_from) = T::from_sci_string(&s) {
+        if let Ok(x_from) = T::from_sci_string(&s) {
             if x == T::ZERO {
                 assert_eq!(x_from, T::ZERO);
             } else {
@@ -869,7 +869,7 @@ where
         assert!(s.chars().filter(|&c| c == '.').count() <= 1);
         assert!(s.chars().filter(|&c| c == '-').count() <= 1);
         assert!(s.chars().filter(|&c| c == '+').count() <= 1);
-        if let Some(x_from) = T::from_sci_string(&s) {
+        if let Ok(x_from) = T::from_sci_string(&s) {
             if x == T::ZERO {
                 assert_eq!(x_from, T::ZERO);
             } else {
@@ -930,7 +930,7 @@ fn to_sci_with_options_helper_unsigned<T: PrimitiveUnsigned>() {
         assert!(s.chars().filter(|&c| c == '+').count() <= 1);
         let mut from_options = FromSciStringOptions::default();
         from_options.set_base(options.get_base());
-        if let Some(x_from) = T::from_sci_string_with_options(&s, from_options) {
+        if let Ok(x_from) = T::from_sci_string_with_options(&s, from_options) {
             if x == T::ZERO {
                 assert_eq!(x_from, T::ZERO);
             } else {
@@ -1010,7 +1010,7 @@ where
         assert!(s.chars().filter(|&c| c == '+').count() <= 1);
         let mut from_options = FromSciStringOptions::default();
         from_options.set_base(options.get_base());
-        if let Some(x_from) = T::from_sci_string_with_options(&s, from_options) {
+        if let Ok(x_from) = T::from_sci_string_with_options(&s, from_options) {
             if x == T::ZERO {
                 assert_eq!(x_from, T::ZERO);
             } else {
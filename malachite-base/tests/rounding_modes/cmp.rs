@@ -7,7 +7,17 @@ use std::cmp::Ordering;
 
 #[test]
 fn test_cmp() {
-    test_cmp_helper::<RoundingMode>(&["Down", "Up", "Floor", "Ceiling", "Nearest", "Exact"]);
+    test_cmp_helper::<RoundingMode>(&[
+        "Down",
+        "Up",
+        "Floor",
+        "Ceiling",
+        "Nearest",
+        "NearestTiesAway",
+        "NearestTiesTowardZero",
+        "Odd",
+        "Exact",
+    ]);
 }
 
 #[test]
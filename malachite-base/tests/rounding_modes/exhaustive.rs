@@ -12,6 +12,9 @@ fn test_exhaustive_rounding_modes() {
             RoundingMode::Floor,
             RoundingMode::Ceiling,
             RoundingMode::Nearest,
+            RoundingMode::NearestTiesAway,
+            RoundingMode::NearestTiesTowardZero,
+            RoundingMode::Odd,
             RoundingMode::Exact,
         ]
     );
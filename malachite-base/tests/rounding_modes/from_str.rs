@@ -13,11 +13,18 @@ fn test_from_str() {
     test("Floor", Ok(RoundingMode::Floor));
     test("Ceiling", Ok(RoundingMode::Ceiling));
     test("Nearest", Ok(RoundingMode::Nearest));
+    test("NearestTiesAway", Ok(RoundingMode::NearestTiesAway));
+    test(
+        "NearestTiesTowardZero",
+        Ok(RoundingMode::NearestTiesTowardZero),
+    );
+    test("Odd", Ok(RoundingMode::Odd));
     test("Exact", Ok(RoundingMode::Exact));
 
     test("", Err("".to_string()));
     test("abc", Err("abc".to_string()));
     test("Uptown", Err("Uptown".to_string()));
+    test("NearestTies", Err("NearestTies".to_string()));
 }
 
 #[allow(clippy::needless_pass_by_value)]
@@ -14,6 +14,12 @@ fn test_neg() {
     test(RoundingMode::Floor, RoundingMode::Ceiling);
     test(RoundingMode::Ceiling, RoundingMode::Floor);
     test(RoundingMode::Nearest, RoundingMode::Nearest);
+    test(RoundingMode::NearestTiesAway, RoundingMode::NearestTiesAway);
+    test(
+        RoundingMode::NearestTiesTowardZero,
+        RoundingMode::NearestTiesTowardZero,
+    );
+    test(RoundingMode::Odd, RoundingMode::Odd);
     test(RoundingMode::Exact, RoundingMode::Exact);
 }
 
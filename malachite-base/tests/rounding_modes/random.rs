@@ -1,7 +1,9 @@
 use itertools::Itertools;
 use malachite_base::random::EXAMPLE_SEED;
 use malachite_base::rounding_modes::random::random_rounding_modes;
-use malachite_base::rounding_modes::RoundingMode::{Ceiling, Down, Exact, Floor, Nearest, Up};
+use malachite_base::rounding_modes::RoundingMode::{
+    Ceiling, Down, Exact, Floor, Nearest, NearestTiesAway, NearestTiesTowardZero, Odd, Up,
+};
 use malachite_base::test_util::stats::common_values_map::common_values_map;
 
 #[test]
@@ -13,16 +15,37 @@ fn test_random_rounding_modes() {
         (values.as_slice(), common_values.as_slice()),
         (
             &[
-                Up, Exact, Ceiling, Up, Floor, Nearest, Exact, Up, Floor, Exact, Nearest, Down,
-                Exact, Down, Floor, Exact, Floor, Down, Nearest, Down
+                Up,
+                Odd,
+                NearestTiesAway,
+                Nearest,
+                NearestTiesTowardZero,
+                Nearest,
+                Floor,
+                Exact,
+                Up,
+                Odd,
+                NearestTiesAway,
+                Down,
+                Floor,
+                NearestTiesTowardZero,
+                Ceiling,
+                NearestTiesAway,
+                Floor,
+                Floor,
+                NearestTiesAway,
+                NearestTiesAway
             ][..],
             &[
-                (Ceiling, 167408),
-                (Down, 167104),
-                (Nearest, 166935),
-                (Exact, 166549),
-                (Floor, 166068),
-                (Up, 165936)
+                (NearestTiesAway, 111545),
+                (Exact, 111262),
+                (Floor, 111248),
+                (Up, 111191),
+                (Nearest, 111185),
+                (Down, 111145),
+                (NearestTiesTowardZero, 111064),
+                (Odd, 110767),
+                (Ceiling, 110593)
             ][..]
         )
     );
@@ -14,6 +14,9 @@ fn test_to_string() {
     test(RoundingMode::Floor, "Floor");
     test(RoundingMode::Ceiling, "Ceiling");
     test(RoundingMode::Nearest, "Nearest");
+    test(RoundingMode::NearestTiesAway, "NearestTiesAway");
+    test(RoundingMode::NearestTiesTowardZero, "NearestTiesTowardZero");
+    test(RoundingMode::Odd, "Odd");
     test(RoundingMode::Exact, "Exact");
 }
 
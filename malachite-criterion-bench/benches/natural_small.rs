@@ -0,0 +1,91 @@
+extern crate criterion;
+extern crate malachite_base;
+extern crate malachite_nz;
+extern crate num;
+extern crate rug;
+
+use criterion::*;
+use malachite_base::num::random::random_primitive_ints;
+use malachite_base::random::EXAMPLE_SEED;
+use malachite_nz::natural::random::get_random_natural_with_bits;
+use malachite_nz::natural::Natural;
+use num::BigUint;
+use std::str::FromStr;
+
+// Benchmarks operations on `Natural`s of at most a few limbs, whose limbs are stored inline rather
+// than on the heap. To compare against a different `Natural` layout, run with
+// `--save-baseline <name>` on one layout and `--baseline <name>` on the other.
+
+pub fn natural_to_biguint(n: &Natural) -> BigUint {
+    BigUint::from_str(n.to_string().as_ref()).unwrap()
+}
+
+pub fn natural_to_rug_integer(n: &Natural) -> rug::Integer {
+    rug::Integer::from_str(n.to_string().as_ref()).unwrap()
+}
+
+const SIZES: [u64; 6] = [64, 96, 128, 192, 256, 512];
+
+fn bench_small_add(c: &mut Criterion) {
+    let mut group = c.benchmark_group("small Natural + Natural");
+    for &i in SIZES.iter() {
+        let x = get_random_natural_with_bits(&mut random_primitive_ints(EXAMPLE_SEED.fork("a")), i);
+        let y = get_random_natural_with_bits(&mut random_primitive_ints(EXAMPLE_SEED.fork("b")), i);
+        let x_num = natural_to_biguint(&x);
+        let y_num = natural_to_biguint(&y);
+        let x_rug = natural_to_rug_integer(&x);
+        let y_rug = natural_to_rug_integer(&y);
+        group.bench_function(BenchmarkId::new("malachite", i), |b| b.iter(|| &x + &y));
+        group.bench_function(BenchmarkId::new("num", i), |b| b.iter(|| &x_num + &y_num));
+        group.bench_function(BenchmarkId::new("rug", i), |b| {
+            b.iter(|| rug::Integer::from(&x_rug + &y_rug))
+        });
+    }
+    group.finish();
+}
+
+fn bench_small_mul(c: &mut Criterion) {
+    let mut group = c.benchmark_group("small Natural * Natural");
+    for &i in SIZES.iter() {
+        let x = get_random_natural_with_bits(&mut random_primitive_ints(EXAMPLE_SEED.fork("a")), i);
+        let y = get_random_natural_with_bits(&mut random_primitive_ints(EXAMPLE_SEED.fork("b")), i);
+        let x_num = natural_to_biguint(&x);
+        let y_num = natural_to_biguint(&y);
+        let x_rug = natural_to_rug_integer(&x);
+        let y_rug = natural_to_rug_integer(&y);
+        group.bench_function(BenchmarkId::new("malachite", i), |b| b.iter(|| &x * &y));
+        group.bench_function(BenchmarkId::new("num", i), |b| b.iter(|| &x_num * &y_num));
+        group.bench_function(BenchmarkId::new("rug", i), |b| {
+            b.iter(|| rug::Integer::from(&x_rug * &y_rug))
+        });
+    }
+    group.finish();
+}
+
+fn bench_small_add_assign(c: &mut Criterion) {
+    let mut group = c.benchmark_group("small Natural += Natural");
+    for &i in SIZES.iter() {
+        let x = get_random_natural_with_bits(&mut random_primitive_ints(EXAMPLE_SEED.fork("a")), i);
+        let y = get_random_natural_with_bits(&mut random_primitive_ints(EXAMPLE_SEED.fork("b")), i);
+        group.bench_function(BenchmarkId::new("malachite", i), |b| {
+            b.iter_with_setup(|| x.clone(), |mut x| x += &y)
+        });
+    }
+    group.finish();
+}
+
+fn bench_small_clone(c: &mut Criterion) {
+    let mut group = c.benchmark_group("small Natural clone");
+    for &i in SIZES.iter() {
+        let x = get_random_natural_with_bits(&mut random_primitive_ints(EXAMPLE_SEED.fork("a")), i);
+        group.bench_function(BenchmarkId::new("malachite", i), |b| b.iter(|| x.clone()));
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().significance_level(0.1);
+    targets = bench_small_add, bench_small_mul, bench_small_add_assign, bench_small_clone
+}
+criterion_main!(benches);
@@ -0,0 +1,134 @@
+use crate::Float;
+use crate::InnerFloat::{Finite, Infinity, Zero};
+
+impl Float {
+    /// Returns a [`Float`] with the absolute value of `self` and the sign of `other`, taking `self`
+    /// by value.
+    ///
+    /// NaN has no sign, so if `self` is NaN then NaN is returned, and if `other` is NaN then it is
+    /// treated as positive.
+    ///
+    /// $$
+    /// f(x, y) = \\begin{cases}
+    ///     -|x| & \text{if} \\quad y < 0 \\ \text{or} \\ y = -0.0, \\\\
+    ///     |x| & \text{otherwise}.
+    /// \\end{cases}
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!(Float::NAN.copysign(&Float::NEGATIVE_ONE).is_nan());
+    /// assert_eq!(Float::INFINITY.copysign(&Float::NEGATIVE_ONE), Float::NEGATIVE_INFINITY);
+    /// assert_eq!(
+    ///     ComparableFloat(Float::ZERO.copysign(&Float::NEGATIVE_ZERO)),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    /// assert_eq!(Float::ONE.copysign(&Float::NEGATIVE_INFINITY), Float::NEGATIVE_ONE);
+    /// assert_eq!(Float::NEGATIVE_ONE.copysign(&Float::ZERO), Float::ONE);
+    /// assert_eq!(Float::NEGATIVE_ONE.copysign(&Float::NAN), Float::ONE);
+    /// ```
+    #[inline]
+    pub fn copysign(mut self, other: &Float) -> Float {
+        self.copysign_assign(other);
+        self
+    }
+
+    /// Returns a [`Float`] with the absolute value of `self` and the sign of `other`, taking `self`
+    /// by reference.
+    ///
+    /// NaN has no sign, so if `self` is NaN then NaN is returned, and if `other` is NaN then it is
+    /// treated as positive.
+    ///
+    /// $$
+    /// f(x, y) = \\begin{cases}
+    ///     -|x| & \text{if} \\quad y < 0 \\ \text{or} \\ y = -0.0, \\\\
+    ///     |x| & \text{otherwise}.
+    /// \\end{cases}
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!(Float::NAN.copysign_ref(&Float::NEGATIVE_ONE).is_nan());
+    /// assert_eq!(Float::INFINITY.copysign_ref(&Float::NEGATIVE_ONE), Float::NEGATIVE_INFINITY);
+    /// assert_eq!(
+    ///     ComparableFloat(Float::ZERO.copysign_ref(&Float::NEGATIVE_ZERO)),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    /// assert_eq!(Float::ONE.copysign_ref(&Float::NEGATIVE_INFINITY), Float::NEGATIVE_ONE);
+    /// assert_eq!(Float::NEGATIVE_ONE.copysign_ref(&Float::ZERO), Float::ONE);
+    /// assert_eq!(Float::NEGATIVE_ONE.copysign_ref(&Float::NAN), Float::ONE);
+    /// ```
+    #[inline]
+    pub fn copysign_ref(&self, other: &Float) -> Float {
+        let mut x = self.clone();
+        x.copysign_assign(other);
+        x
+    }
+
+    /// Replaces `self` with a [`Float`] with the absolute value of `self` and the sign of `other`.
+    ///
+    /// NaN has no sign, so if `self` is NaN then it is unchanged, and if `other` is NaN then it is
+    /// treated as positive.
+    ///
+    /// $$
+    /// x \gets \\begin{cases}
+    ///     -|x| & \text{if} \\quad y < 0 \\ \text{or} \\ y = -0.0, \\\\
+    ///     |x| & \text{otherwise}.
+    /// \\end{cases}
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NegativeInfinity, NegativeOne, NegativeZero, One, Zero
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x.copysign_assign(&Float::NEGATIVE_ONE);
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::ZERO;
+    /// x.copysign_assign(&Float::NEGATIVE_ZERO);
+    /// assert_eq!(ComparableFloat(x), ComparableFloat(Float::NEGATIVE_ZERO));
+    ///
+    /// let mut x = Float::ONE;
+    /// x.copysign_assign(&Float::NEGATIVE_INFINITY);
+    /// assert_eq!(x, Float::NEGATIVE_ONE);
+    ///
+    /// let mut x = Float::NEGATIVE_ONE;
+    /// x.copysign_assign(&Float::ZERO);
+    /// assert_eq!(x, Float::ONE);
+    /// ```
+    pub fn copysign_assign(&mut self, other: &Float) {
+        match self {
+            Float(Infinity { sign }) | Float(Zero { sign }) | Float(Finite { sign, .. }) => {
+                *sign = !other.is_sign_negative();
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,280 @@
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{
+    float_either_infinity, float_either_zero, float_infinity, float_nan, float_zero,
+    significand_bits, Float,
+};
+use malachite_base::num::arithmetic::traits::{Abs, SqrtRem, Square};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use std::cmp::{max, min, Ordering};
+
+// Computes sqrt(x ^ 2 + y ^ 2), where x = x_significand * 2 ^ (x_exponent - x_bits) and similarly
+// for y, and x_exponent >= y_exponent.
+//
+// The result is computed with at least `prec` + 2 correct bits. If it is inexact, a sticky bit is
+// appended, so that rounding the result to `prec` bits gives the same result as rounding the exact
+// value.
+fn hypot_finite(
+    x_significand: &Natural,
+    x_exponent: i64,
+    y_significand: &Natural,
+    y_exponent: i64,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let x_bits = significand_bits(x_significand);
+    let y_bits = significand_bits(y_significand);
+    let exponent_diff = u64::exact_from(x_exponent.saturating_sub(y_exponent));
+    let working_prec = max(prec, x_bits) + 2;
+    if exponent_diff >= (working_prec + 1) >> 1 {
+        // |y| < 2 ^ y_exponent and |x| >= 2 ^ (x_exponent - 1), so the result lies strictly between
+        // |x| and |x| + y ^ 2 / (2 * |x|) < |x| + 2 ^ (2 * y_exponent - x_exponent). That interval
+        // contains no rounding boundaries at `working_prec` bits, so |x| with a sticky bit rounds
+        // the same way.
+        let shift = working_prec - x_bits;
+        let mut n = x_significand << shift;
+        n.set_bit(0);
+        return Float::from_natural_times_power_of_2_prec_round(
+            n,
+            x_exponent - i64::exact_from(x_bits + shift),
+            prec,
+            rm,
+        );
+    }
+    let x_low = x_exponent - i64::exact_from(x_bits);
+    let y_low = y_exponent - i64::exact_from(y_bits);
+    let low = min(x_low, y_low);
+    let n = (x_significand << u64::exact_from(x_low - low)).square()
+        + (y_significand << u64::exact_from(y_low - low)).square();
+    let k = (prec + 2).saturating_sub(n.significant_bits() >> 1);
+    let (mut root, rem) = (n << (k << 1)).sqrt_rem();
+    let mut pow = low - i64::exact_from(k);
+    if rem != 0u32 {
+        root <<= 1;
+        root.set_bit(0);
+        pow -= 1;
+    }
+    Float::from_natural_times_power_of_2_prec_round(root, pow, prec, rm)
+}
+
+impl Float {
+    /// Computes $\sqrt{x^2+y^2}$, the length of the hypotenuse of a right triangle with legs $x$
+    /// and $y$, rounding the result to the specified precision and with the specified rounding
+    /// mode. Both [`Float`]s are taken by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded result is less than, equal to, or greater than the exact result.
+    /// Although `NaN` is not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Ordering::Equal`.
+    ///
+    /// The result is correctly rounded, and the intermediate value $x^2+y^2$ never overflows or
+    /// underflows.
+    ///
+    /// $$
+    /// f(x,y,p,m) = \sqrt{x^2+y^2}+\epsilon.
+    /// $$
+    /// - If $\sqrt{x^2+y^2}$ is 0 or infinite, $\epsilon=0$.
+    /// - If $m$ is not `Nearest`, `NearestTiesAway`, or `NearestTiesTowardZero`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p+1}$.
+    /// - Otherwise, $|\epsilon| \leq 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$.
+    ///
+    /// Special cases:
+    /// - $f(\pm\infty,y,p,m)=f(x,\pm\infty,p,m)=\infty$, even if the other argument is NaN
+    /// - $f(\text{NaN},y,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$ otherwise
+    /// - $f(\pm0.0,\pm0.0,p,m)=0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::hypot_prec`] instead. If you
+    /// know that your target precision is the maximum of the precisions of the two inputs, consider
+    /// using [`Float::hypot_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (h, o) = Float::from(3).hypot_prec_round(&Float::from(4), 10, RoundingMode::Exact);
+    /// assert_eq!(h.to_string(), "5.0");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (h, o) = Float::from(1).hypot_prec_round(&Float::from(1), 20, RoundingMode::Floor);
+    /// assert_eq!(h.to_string(), "1.414213");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (h, o) = Float::from(1).hypot_prec_round(&Float::from(1), 20, RoundingMode::Ceiling);
+    /// assert_eq!(h.to_string(), "1.414215");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (h, o) = Float::from(-1e100).hypot_prec_round(&Float::from(1), 10, RoundingMode::Up);
+    /// assert_eq!(h.to_string(), "1.001e100");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    pub fn hypot_prec_round(
+        &self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match (self, other) {
+            (float_either_infinity!(), _) | (_, float_either_infinity!()) => {
+                (float_infinity!(), Ordering::Equal)
+            }
+            (float_nan!(), _) | (_, float_nan!()) => (float_nan!(), Ordering::Equal),
+            (float_either_zero!(), float_either_zero!()) => (float_zero!(), Ordering::Equal),
+            (float_either_zero!(), x) | (x, float_either_zero!()) => {
+                let mut h = x.abs();
+                let o = h.set_prec_round(prec, rm);
+                (h, o)
+            }
+            (
+                Float(Finite {
+                    exponent: x_exp,
+                    significand: x,
+                    ..
+                }),
+                Float(Finite {
+                    exponent: y_exp,
+                    significand: y,
+                    ..
+                }),
+            ) => {
+                if x_exp >= y_exp {
+                    hypot_finite(x, *x_exp, y, *y_exp, prec, rm)
+                } else {
+                    hypot_finite(y, *y_exp, x, *x_exp, prec, rm)
+                }
+            }
+        }
+    }
+
+    /// Computes $\sqrt{x^2+y^2}$, rounding the result to the nearest value of the specified
+    /// precision. Both [`Float`]s are taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded result is less than, equal to, or greater than the exact
+    /// result. Although `NaN` is not comparable to any [`Float`], whenever this function returns a
+    /// `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = \sqrt{x^2+y^2}+\epsilon,
+    /// $$
+    /// where $|\epsilon| \leq 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$, or $\epsilon=0$ if
+    /// $\sqrt{x^2+y^2}$ is 0 or infinite.
+    ///
+    /// See [`Float::hypot_prec_round`] for special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (h, o) = Float::from(1).hypot_prec(&Float::from(1), 20);
+    /// assert_eq!(h.to_string(), "1.414213");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (h, o) = Float::from(5).hypot_prec(&Float::from(-12), 4);
+    /// assert_eq!(h.to_string(), "13.0");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    #[inline]
+    pub fn hypot_prec(&self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.hypot_prec_round(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Computes $\sqrt{x^2+y^2}$, rounding the result with the specified rounding mode. Both
+    /// [`Float`]s are taken by reference. The precision of the output is the maximum of the
+    /// precisions of the inputs, where NaNs, infinities, and zeros are considered to have a
+    /// precision of 1. An [`Ordering`] is also returned, indicating whether the rounded result is
+    /// less than, equal to, or greater than the exact result. Although `NaN` is not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`Float::hypot_prec_round`] for details and special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the output
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (h, o) = Float::from(1.0f64).hypot_round(&Float::from(1.0f64), RoundingMode::Down);
+    /// assert_eq!(h.to_string(), "1.4142135623730949");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (h, o) = Float::from(1.0f64).hypot_round(&Float::from(1.0f64), RoundingMode::Up);
+    /// assert_eq!(h.to_string(), "1.4142135623730951");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn hypot_round(&self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.get_prec().unwrap_or(1), other.get_prec().unwrap_or(1));
+        self.hypot_prec_round(other, prec, rm)
+    }
+
+    /// Computes $\sqrt{x^2+y^2}$, rounding the result to the nearest value. Both [`Float`]s are
+    /// taken by reference. The precision of the output is the maximum of the precisions of the
+    /// inputs, where NaNs, infinities, and zeros are considered to have precision 1.
+    ///
+    /// See [`Float::hypot_prec_round`] for details and special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::from(3).hypot(&Float::from(-4)).to_string(), "5.0");
+    /// assert_eq!(Float::from(1.0f64).hypot(&Float::from(2.0f64)).to_string(), "2.2360679774997898");
+    /// assert_eq!(Float::ZERO.hypot(&Float::from(-1.5)).to_string(), "1.5");
+    /// assert_eq!(Float::NAN.hypot(&Float::INFINITY), Float::INFINITY);
+    /// assert!(Float::NAN.hypot(&Float::ZERO).is_nan());
+    /// ```
+    #[inline]
+    pub fn hypot(&self, other: &Float) -> Float {
+        self.hypot_round(other, RoundingMode::Nearest).0
+    }
+}
@@ -1,5 +1,10 @@
 /// Absolute value of [`Float`](super::Float)s.
 pub mod abs;
+/// Copying the sign of one [`Float`](super::Float) to another.
+pub mod copysign;
+/// The length of the hypotenuse of a right triangle, $\sqrt{x^2+y^2}$, for
+/// [`Float`](super::Float)s.
+pub mod hypot;
 /// An implementations of [`IsPowerOf2`](malachite_base::num::arithmetic::traits::IsPowerOf2), a
 /// trait for determining whether a number is an integer power of 2.
 pub mod is_power_of_2;
@@ -0,0 +1,217 @@
+use crate::ball::Ball;
+use std::cmp::max;
+use std::ops::{Add, AddAssign};
+
+impl Ball {
+    /// Adds two [`Ball`]s, taking both by reference. The midpoint of the result is rounded to the
+    /// specified precision, and the rounding error is added to the radius.
+    ///
+    /// The result contains $x + y$ for every $x$ in `self` and $y$ in `other`. If either input is
+    /// indeterminate, so is the result.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let third = Ball::from_rational_prec(&Rational::from_signeds(1, 3), 100);
+    /// let sum = third.add_prec(&third, 10);
+    /// assert_eq!(sum.mid().to_string(), "0.667");
+    /// assert!(sum.contains_rational(&Rational::from_signeds(2, 3)));
+    /// ```
+    pub fn add_prec(&self, other: &Ball, prec: u64) -> Ball {
+        match (
+            self.to_rational_mid_and_rad(),
+            other.to_rational_mid_and_rad(),
+        ) {
+            (Some((x_mid, x_rad)), Some((y_mid, y_rad))) => {
+                Ball::from_mid_and_error(&(x_mid + y_mid), &(x_rad + y_rad), prec)
+            }
+            _ => Ball::INDETERMINATE,
+        }
+    }
+}
+
+impl Add<Ball> for Ball {
+    type Output = Ball;
+
+    /// Adds two [`Ball`]s, taking both by value.
+    ///
+    /// The midpoint of the result is rounded to the larger of the precisions of the two inputs'
+    /// midpoints. See [`Ball::add_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    ///
+    /// assert_eq!((Ball::ONE + Ball::ONE).to_string(), "[2.0 +/- 0.0]");
+    /// ```
+    #[inline]
+    fn add(self, other: Ball) -> Ball {
+        &self + &other
+    }
+}
+
+impl<'a> Add<&'a Ball> for Ball {
+    type Output = Ball;
+
+    /// Adds two [`Ball`]s, taking the first by value and the second by reference.
+    ///
+    /// The midpoint of the result is rounded to the larger of the precisions of the two inputs'
+    /// midpoints. See [`Ball::add_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    ///
+    /// assert_eq!((Ball::ONE + &Ball::ONE).to_string(), "[2.0 +/- 0.0]");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a Ball) -> Ball {
+        &self + other
+    }
+}
+
+impl<'a> Add<Ball> for &'a Ball {
+    type Output = Ball;
+
+    /// Adds two [`Ball`]s, taking the first by reference and the second by value.
+    ///
+    /// The midpoint of the result is rounded to the larger of the precisions of the two inputs'
+    /// midpoints. See [`Ball::add_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    ///
+    /// assert_eq!((&Ball::ONE + Ball::ONE).to_string(), "[2.0 +/- 0.0]");
+    /// ```
+    #[inline]
+    fn add(self, other: Ball) -> Ball {
+        self + &other
+    }
+}
+
+impl<'a, 'b> Add<&'a Ball> for &'b Ball {
+    type Output = Ball;
+
+    /// Adds two [`Ball`]s, taking both by reference.
+    ///
+    /// The midpoint of the result is rounded to the larger of the precisions of the two inputs'
+    /// midpoints. See [`Ball::add_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    ///
+    /// assert_eq!((&Ball::ONE + &Ball::ONE).to_string(), "[2.0 +/- 0.0]");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a Ball) -> Ball {
+        self.add_prec(other, max(self.mid_prec(), other.mid_prec()))
+    }
+}
+
+impl AddAssign<Ball> for Ball {
+    /// Adds a [`Ball`] to a [`Ball`] in place, taking the [`Ball`] on the right-hand side by value.
+    ///
+    /// See [`Ball::add_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    ///
+    /// let mut x = Ball::ONE;
+    /// x += Ball::ONE;
+    /// assert_eq!(x.to_string(), "[2.0 +/- 0.0]");
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: Ball) {
+        *self = &*self + &other;
+    }
+}
+
+impl<'a> AddAssign<&'a Ball> for Ball {
+    /// Adds a [`Ball`] to a [`Ball`] in place, taking the [`Ball`] on the right-hand side by
+    /// reference.
+    ///
+    /// See [`Ball::add_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    ///
+    /// let mut x = Ball::ONE;
+    /// x += &Ball::ONE;
+    /// assert_eq!(x.to_string(), "[2.0 +/- 0.0]");
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: &'a Ball) {
+        *self = &*self + other;
+    }
+}
@@ -0,0 +1,77 @@
+use crate::ball::arithmetic::log::series_helper;
+use crate::ball::Ball;
+use malachite_q::Rational;
+
+// Returns a `Ball` containing $\ln 2 = 2 \operatorname{artanh}(1/3)$, with radius at most about
+// $2^{-p}$, where $p$ is `prec`.
+pub(crate) fn ln_2_ball(prec: u64) -> Ball {
+    let working_prec = prec + 8;
+    let third = Ball::from_rational_prec(&Rational::from_unsigneds(1u32, 3), working_prec);
+    series_helper(&third, working_prec, false).mul_power_of_2(1)
+}
+
+// Returns a `Ball` containing $\pi = 16 \arctan(1/5) - 4 \arctan(1/239)$ (Machin's formula), with
+// radius at most about $2^{-p}$, where $p$ is `prec`.
+pub(crate) fn pi_ball(prec: u64) -> Ball {
+    let working_prec = prec + 8;
+    let fifth = Ball::from_rational_prec(&Rational::from_unsigneds(1u32, 5), working_prec);
+    let x = Ball::from_rational_prec(&Rational::from_unsigneds(1u32, 239), working_prec);
+    series_helper(&fifth, working_prec, true)
+        .mul_power_of_2(4)
+        .sub_prec(
+            &series_helper(&x, working_prec, true).mul_power_of_2(2),
+            working_prec,
+        )
+}
+
+impl Ball {
+    /// Returns a [`Ball`] containing $\pi$, whose midpoint has the specified precision.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// let pi = Ball::pi_prec(100);
+    /// assert_eq!(pi.mid().to_string(), "3.141592653589793238462643383279");
+    /// assert!(pi.rad() < &Float::from(1.0e-29));
+    /// ```
+    pub fn pi_prec(prec: u64) -> Ball {
+        pi_ball(prec + 8).round_mid(prec)
+    }
+
+    /// Returns a [`Ball`] containing $\ln 2$, whose midpoint has the specified precision.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// let ln_2 = Ball::ln_2_prec(100);
+    /// assert_eq!(ln_2.mid().to_string(), "0.693147180559945309417232121458");
+    /// assert!(ln_2.rad() < &Float::from(1.0e-29));
+    /// ```
+    pub fn ln_2_prec(prec: u64) -> Ball {
+        ln_2_ball(prec + 8).round_mid(prec)
+    }
+}
@@ -0,0 +1,233 @@
+use crate::ball::Ball;
+use malachite_base::num::arithmetic::traits::Abs;
+use std::cmp::max;
+use std::ops::{Div, DivAssign};
+
+impl Ball {
+    /// Divides a [`Ball`] by another, taking both by reference. The midpoint of the result is
+    /// rounded to the specified precision, and the rounding error is added to the radius.
+    ///
+    /// The result contains $x/y$ for every $x$ in `self` and $y$ in `other`. If either input is
+    /// indeterminate, or if `other` contains zero, the result is indeterminate.
+    ///
+    /// If the inputs have midpoints $m_x$ and $m_y$ and radii $r_x$ and $r_y$, the radius of the
+    /// result, before rounding, is
+    /// $$
+    /// \frac{|m_x|r_y + |m_y|r_x}{|m_y|(|m_y| - r_y)}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let quotient = Ball::ONE.div_prec(&Ball::from(Float::from(3)), 10);
+    /// assert_eq!(quotient.mid().to_string(), "0.3335");
+    /// assert!(quotient.contains_rational(&Rational::from_signeds(1, 3)));
+    ///
+    /// assert!(!Ball::ONE.div_prec(&Ball::ZERO, 10).is_finite());
+    /// ```
+    pub fn div_prec(&self, other: &Ball, prec: u64) -> Ball {
+        match (
+            self.to_rational_mid_and_rad(),
+            other.to_rational_mid_and_rad(),
+        ) {
+            (Some((x_mid, x_rad)), Some((y_mid, y_rad))) => {
+                let y_mid_abs = (&y_mid).abs();
+                if y_mid_abs <= y_rad {
+                    return Ball::INDETERMINATE;
+                }
+                let err = ((&x_mid).abs() * &y_rad + &y_mid_abs * x_rad)
+                    / (&y_mid_abs * (&y_mid_abs - y_rad));
+                Ball::from_mid_and_error(&(x_mid / y_mid), &err, prec)
+            }
+            _ => Ball::INDETERMINATE,
+        }
+    }
+}
+
+impl Div<Ball> for Ball {
+    type Output = Ball;
+
+    /// Divides two [`Ball`]s, taking both by value.
+    ///
+    /// The midpoint of the result is rounded to the larger of the precisions of the two inputs'
+    /// midpoints. See [`Ball::div_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    ///
+    /// assert_eq!((Ball::ONE / Ball::ONE).to_string(), "[1.0 +/- 0.0]");
+    /// ```
+    #[inline]
+    fn div(self, other: Ball) -> Ball {
+        &self / &other
+    }
+}
+
+impl<'a> Div<&'a Ball> for Ball {
+    type Output = Ball;
+
+    /// Divides two [`Ball`]s, taking the first by value and the second by reference.
+    ///
+    /// The midpoint of the result is rounded to the larger of the precisions of the two inputs'
+    /// midpoints. See [`Ball::div_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    ///
+    /// assert_eq!((Ball::ONE / &Ball::ONE).to_string(), "[1.0 +/- 0.0]");
+    /// ```
+    #[inline]
+    fn div(self, other: &'a Ball) -> Ball {
+        &self / other
+    }
+}
+
+impl<'a> Div<Ball> for &'a Ball {
+    type Output = Ball;
+
+    /// Divides two [`Ball`]s, taking the first by reference and the second by value.
+    ///
+    /// The midpoint of the result is rounded to the larger of the precisions of the two inputs'
+    /// midpoints. See [`Ball::div_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    ///
+    /// assert_eq!((&Ball::ONE / Ball::ONE).to_string(), "[1.0 +/- 0.0]");
+    /// ```
+    #[inline]
+    fn div(self, other: Ball) -> Ball {
+        self / &other
+    }
+}
+
+impl<'a, 'b> Div<&'a Ball> for &'b Ball {
+    type Output = Ball;
+
+    /// Divides two [`Ball`]s, taking both by reference.
+    ///
+    /// The midpoint of the result is rounded to the larger of the precisions of the two inputs'
+    /// midpoints. See [`Ball::div_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    ///
+    /// assert_eq!((&Ball::ONE / &Ball::ONE).to_string(), "[1.0 +/- 0.0]");
+    /// ```
+    #[inline]
+    fn div(self, other: &'a Ball) -> Ball {
+        self.div_prec(other, max(self.mid_prec(), other.mid_prec()))
+    }
+}
+
+impl DivAssign<Ball> for Ball {
+    /// Divides a [`Ball`] by a [`Ball`] in place, taking the [`Ball`] on the right-hand side by value.
+    ///
+    /// See [`Ball::div_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    ///
+    /// let mut x = Ball::ONE;
+    /// x /= Ball::ONE;
+    /// assert_eq!(x.to_string(), "[1.0 +/- 0.0]");
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: Ball) {
+        *self = &*self / &other;
+    }
+}
+
+impl<'a> DivAssign<&'a Ball> for Ball {
+    /// Divides a [`Ball`] by a [`Ball`] in place, taking the [`Ball`] on the right-hand side by
+    /// reference.
+    ///
+    /// See [`Ball::div_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    ///
+    /// let mut x = Ball::ONE;
+    /// x /= &Ball::ONE;
+    /// assert_eq!(x.to_string(), "[1.0 +/- 0.0]");
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: &'a Ball) {
+        *self = &*self / other;
+    }
+}
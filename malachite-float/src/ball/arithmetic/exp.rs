@@ -0,0 +1,103 @@
+use crate::ball::Ball;
+use crate::Float;
+use malachite_base::num::arithmetic::traits::{Ceiling, CeilingLogBase2, Pow, PowerOf2};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_q::Rational;
+use std::cmp::max;
+
+// Beyond this binary exponent, the exponential of a number is not computed.
+const MAX_EXP_ARGUMENT_LOG: i64 = 50;
+
+// Beyond this binary exponent, the effect of a `Ball`'s radius on its exponential is not computed.
+const MAX_EXP_RADIUS_LOG: i64 = 12;
+
+// Returns a `Ball` containing $e^x$, for a `Rational` $x$. The radius of the result is at most
+// about $2^{-p}$ times $e^x$, where $p$ is `prec`.
+//
+// The argument is reduced to $t = x/2^s$ with $|t| < 2^{-8}$, the Taylor series of $e^t$ is summed,
+// and the result is squared $s$ times.
+pub(crate) fn exp_point(x: &Rational, prec: u64) -> Ball {
+    if *x == 0u32 {
+        return Ball::ONE;
+    }
+    let log = x.floor_log_base_2_abs();
+    if log >= MAX_EXP_ARGUMENT_LOG {
+        return Ball::INDETERMINATE;
+    }
+    let s = u64::exact_from(max(0, log + 9));
+    let terms = (prec >> 3) + 2;
+    let working_prec = prec + s + (terms.ceiling_log_base_2() << 1) + 16;
+    let t = Ball::from_rational_prec(&(x >> s), working_prec);
+    let mut sum = Ball::ONE;
+    let mut term = Ball::ONE;
+    for k in 1..terms {
+        term = term
+            .mul_prec(&t, working_prec)
+            .div_prec(&Ball::from(Float::from(k)), working_prec);
+        sum = sum.add_prec(&term, working_prec);
+    }
+    // Since |t| < 2^-8, the tail of the series is at most 2 * |t|^terms / terms! < 2^(1 - 8 *
+    // terms).
+    let mut result = sum.add_error(&Rational::power_of_2(1 - (i64::exact_from(terms) << 3)));
+    for _ in 0..s {
+        result = result.square_prec(working_prec);
+    }
+    result
+}
+
+impl Ball {
+    /// Computes the exponential function of a [`Ball`], taking it by reference. The midpoint of
+    /// the result is rounded to the specified precision, and the error is added to the radius.
+    ///
+    /// The result contains $e^x$ for every $x$ in `self`. If `self` is indeterminate or extremely
+    /// large, the result is indeterminate.
+    ///
+    /// If the input has midpoint $m$ and radius $r$, the radius of the result is increased by an
+    /// upper bound of $e^m(e^r-1)$ to account for the input's radius.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// let e = Ball::ONE.exp_prec(100);
+    /// assert_eq!(e.mid().to_string(), "2.718281828459045235360287471351");
+    /// assert!(e.rad() < &Float::from(1.0e-29));
+    ///
+    /// assert_eq!(Ball::ZERO.exp_prec(10).to_string(), "[1.0 +/- 0.0]");
+    /// ```
+    pub fn exp_prec(&self, prec: u64) -> Ball {
+        let (mid, rad) = if let Some(p) = self.to_rational_mid_and_rad() {
+            p
+        } else {
+            return Ball::INDETERMINATE;
+        };
+        let exp = exp_point(&mid, prec + 8);
+        if rad == 0u32 || !exp.is_finite() {
+            return exp.round_mid(prec);
+        }
+        // e^r - 1 <= r * e^r < 3r if r <= 1, and e^r - 1 < 3^ceiling(r) otherwise.
+        let factor = if rad <= 1u32 {
+            rad * Rational::from(3)
+        } else if rad.floor_log_base_2_abs() < MAX_EXP_RADIUS_LOG {
+            Rational::from(3).pow(u64::exact_from(&rad.ceiling()))
+        } else {
+            return Ball::INDETERMINATE;
+        };
+        let err = exp.upper_abs().unwrap() * factor;
+        exp.add_error(&err).round_mid(prec)
+    }
+}
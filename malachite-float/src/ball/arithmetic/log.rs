@@ -0,0 +1,133 @@
+use crate::ball::arithmetic::constants::ln_2_ball;
+use crate::ball::Ball;
+use crate::Float;
+use malachite_base::num::arithmetic::traits::{CeilingLogBase2, Parity, PowerOf2};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_q::Rational;
+
+// The number of square roots taken to bring the argument of the atanh series close to zero.
+const LOG_SQRT_STEPS: u64 = 8;
+
+// Returns a `Ball` containing $\operatorname{artanh} z = \sum_{k=0}^\infty z^{2k+1}/(2k+1)$, for a
+// `Ball` $z$ whose elements have absolute value at most $1/2$. The series is summed until its tail
+// is less than $2^{-p}$, where $p$ is `prec`.
+pub(crate) fn atanh_series(z: &Ball, prec: u64) -> Ball {
+    series_helper(z, prec, false)
+}
+
+// Sums $\sum_{k=0}^\infty s^k z^{2k+1}/(2k+1)$, where $s$ is -1 if `alternating` and 1 otherwise,
+// for a `Ball` $z$ whose elements have absolute value at most $1/2$. This is $\arctan z$ or
+// $\operatorname{artanh} z$. The tail is bounded by $2|z|^{2k+1}$, where $k$ is the index of the
+// first omitted term.
+pub(crate) fn series_helper(z: &Ball, prec: u64, alternating: bool) -> Ball {
+    let upper = z.upper_abs().unwrap();
+    if upper == 0u32 {
+        return Ball::ZERO;
+    }
+    // |z| <= 2^c
+    let c = upper.ceiling_log_base_2();
+    assert!(c < 0);
+    let c = c.unsigned_abs();
+    let z_squared = z.square_prec(prec);
+    let mut power = z.clone();
+    let mut sum = z.clone();
+    let mut k = 1u64;
+    // Stop once 2 * |z|^(2k + 1) <= 2^-prec.
+    while c * ((k << 1) + 1) < prec + 1 {
+        power = power.mul_prec(&z_squared, prec);
+        let term = power.div_prec(&Ball::from(Float::from((k << 1) + 1)), prec);
+        sum = if alternating && k.odd() {
+            sum.sub_prec(&term, prec)
+        } else {
+            sum.add_prec(&term, prec)
+        };
+        k += 1;
+    }
+    sum.add_error(&Rational::power_of_2(
+        1 - i64::exact_from(c * ((k << 1) + 1)),
+    ))
+}
+
+// Returns a `Ball` containing $\ln x$, for a positive `Rational` $x$. The radius of the result is
+// at most about $2^{-p}$, or $2^{-p}$ times $|\ln x|$ if that is larger, where $p$ is `prec`.
+//
+// Writing $x = 2^ef$ with $1 \leq f < 2$, $\ln x = e \ln 2 + 2^{k+1} \operatorname{artanh}((g -
+// 1)/(g + 1))$, where $g = f^{1/2^k}$.
+pub(crate) fn log_point(x: &Rational, prec: u64) -> Ball {
+    assert!(*x > 0u32);
+    if *x == 1u32 {
+        return Ball::ZERO;
+    }
+    let e = x.floor_log_base_2_abs();
+    let working_prec = prec + LOG_SQRT_STEPS + e.unsigned_abs().significant_bits() + 16;
+    let mut g = Ball::from_rational_prec(&(x >> e), working_prec);
+    for _ in 0..LOG_SQRT_STEPS {
+        g = g.sqrt_prec(working_prec);
+    }
+    let z = g
+        .sub_prec(&Ball::ONE, working_prec)
+        .div_prec(&g.add_prec(&Ball::ONE, working_prec), working_prec);
+    let log_f = atanh_series(&z, working_prec).mul_power_of_2(i64::exact_from(LOG_SQRT_STEPS) + 1);
+    if e == 0 {
+        log_f
+    } else {
+        ln_2_ball(working_prec)
+            .mul_prec(&Ball::from(Float::from(e)), working_prec)
+            .add_prec(&log_f, working_prec)
+    }
+}
+
+impl Ball {
+    /// Computes the natural logarithm of a [`Ball`], taking it by reference. The midpoint of the
+    /// result is rounded to the specified precision, and the error is added to the radius.
+    ///
+    /// The result contains $\ln x$ for every $x$ in `self`. If `self` contains a nonpositive
+    /// number, or is indeterminate, the result is indeterminate.
+    ///
+    /// If the input has midpoint $m$ and radius $r$, the radius of the result is increased by
+    /// $r/(m-r)$ to account for the input's radius.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// let ln_10 = Ball::from(Float::from(10)).log_prec(100);
+    /// assert_eq!(ln_10.mid().to_string(), "2.302585092994045684017991454684");
+    /// assert!(ln_10.rad() < &Float::from(1.0e-29));
+    ///
+    /// assert_eq!(Ball::ONE.log_prec(10).to_string(), "[0.0 +/- 0.0]");
+    /// assert!(!Ball::ZERO.log_prec(10).is_finite());
+    /// ```
+    pub fn log_prec(&self, prec: u64) -> Ball {
+        let (mid, rad) = if let Some(p) = self.to_rational_mid_and_rad() {
+            p
+        } else {
+            return Ball::INDETERMINATE;
+        };
+        if mid <= rad {
+            return Ball::INDETERMINATE;
+        }
+        let log = log_point(&mid, prec + 8);
+        if rad == 0u32 {
+            return log.round_mid(prec);
+        }
+        // |ln x - ln m| <= r / (m - r)
+        let err = &rad / (mid - &rad);
+        log.add_error(&err).round_mid(prec)
+    }
+}
@@ -0,0 +1,22 @@
+/// Addition of [`Ball`](super::Ball)s.
+pub mod add;
+/// Functions for computing the constants $\pi$ and $\ln 2$ as [`Ball`](super::Ball)s.
+pub mod constants;
+/// Division of [`Ball`](super::Ball)s.
+pub mod div;
+/// The exponential function of [`Ball`](super::Ball)s.
+pub mod exp;
+/// The natural logarithm of [`Ball`](super::Ball)s.
+pub mod log;
+/// Multiplication of [`Ball`](super::Ball)s.
+pub mod mul;
+/// Negation of [`Ball`](super::Ball)s.
+pub mod neg;
+/// The square root of [`Ball`](super::Ball)s.
+pub mod sqrt;
+/// Squaring of [`Ball`](super::Ball)s.
+pub mod square;
+/// Subtraction of [`Ball`](super::Ball)s.
+pub mod sub;
+/// Trigonometric functions of [`Ball`](super::Ball)s: sine, cosine, and arctangent.
+pub mod trig;
@@ -0,0 +1,81 @@
+use crate::ball::Ball;
+use malachite_base::num::arithmetic::traits::NegAssign;
+use std::ops::Neg;
+
+impl Neg for Ball {
+    type Output = Ball;
+
+    /// Negates a [`Ball`], taking it by value.
+    ///
+    /// Negation is exact: the midpoint is negated and the radius is unchanged.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Ball::new(Float::from(1.5), Float::from(0.25));
+    /// assert_eq!((-x).to_string(), "[-1.5 +/- 0.25]");
+    /// ```
+    #[inline]
+    fn neg(mut self) -> Ball {
+        self.neg_assign();
+        self
+    }
+}
+
+impl<'a> Neg for &'a Ball {
+    type Output = Ball;
+
+    /// Negates a [`Ball`], taking it by reference.
+    ///
+    /// Negation is exact: the midpoint is negated and the radius is unchanged.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Ball::new(Float::from(1.5), Float::from(0.25));
+    /// assert_eq!((-&x).to_string(), "[-1.5 +/- 0.25]");
+    /// ```
+    #[inline]
+    fn neg(self) -> Ball {
+        -self.clone()
+    }
+}
+
+impl NegAssign for Ball {
+    /// Negates a [`Ball`] in place.
+    ///
+    /// Negation is exact: the midpoint is negated and the radius is unchanged.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::NegAssign;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Ball::new(Float::from(1.5), Float::from(0.25));
+    /// x.neg_assign();
+    /// assert_eq!(x.to_string(), "[-1.5 +/- 0.25]");
+    /// ```
+    fn neg_assign(&mut self) {
+        if !self.mid.is_zero() {
+            self.mid.neg_assign();
+        }
+    }
+}
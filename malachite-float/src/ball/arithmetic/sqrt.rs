@@ -0,0 +1,83 @@
+use crate::ball::Ball;
+use malachite_base::num::arithmetic::traits::{CheckedSqrt, FloorSqrt};
+use malachite_base::num::basic::traits::{OneHalf, Zero};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+// Returns a `Ball` containing the square root of a positive `Rational`. The radius of the result
+// is at most $2^{-p}$ times the square root, where $p$ is `prec`.
+pub(crate) fn sqrt_point(x: &Rational, prec: u64) -> Ball {
+    if let Some(sqrt) = x.checked_sqrt() {
+        return Ball::from_rational_prec(&sqrt, prec);
+    }
+    // Choose e so that floor(x * 4^e) has about 2 * prec + 4 bits. Then sqrt(x) * 2^e lies in
+    // [q, q + 1), where q is the floor of the square root of floor(x * 4^e).
+    let e = i64::exact_from(prec) + 2 - (x.floor_log_base_2_abs() >> 1);
+    let n = Natural::rounding_from(x << (e << 1), RoundingMode::Floor).0;
+    let q = Rational::from(n.floor_sqrt());
+    Ball::from_mid_and_error(
+        &((q + Rational::ONE_HALF) >> e),
+        &(Rational::ONE_HALF >> e),
+        prec,
+    )
+}
+
+impl Ball {
+    /// Computes the square root of a [`Ball`], taking it by reference. The midpoint of the result
+    /// is rounded to the specified precision, and the error is added to the radius.
+    ///
+    /// The result contains $\sqrt{x}$ for every $x$ in `self`. If `self` contains a negative
+    /// number, or is indeterminate, the result is indeterminate.
+    ///
+    /// If the input has midpoint $m$ and radius $r$, the radius of the result is increased by
+    /// $r/\sqrt{m}$ to account for the input's radius.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let sqrt_2 = Ball::from(Float::from(2)).sqrt_prec(100);
+    /// assert_eq!(sqrt_2.mid().to_string(), "1.414213562373095048801688724209");
+    /// assert!(sqrt_2.rad() < &Float::from(1.0e-29));
+    ///
+    /// let two = sqrt_2.square_prec(100);
+    /// assert!(two.contains_rational(&Rational::from(2)));
+    ///
+    /// assert_eq!(Ball::from(Float::from(4)).sqrt_prec(10).to_string(), "[2.0 +/- 0.0]");
+    /// assert!(!Ball::from(Float::from(-4)).sqrt_prec(10).is_finite());
+    /// ```
+    pub fn sqrt_prec(&self, prec: u64) -> Ball {
+        let (mid, rad) = if let Some(p) = self.to_rational_mid_and_rad() {
+            p
+        } else {
+            return Ball::INDETERMINATE;
+        };
+        if mid < rad {
+            return Ball::INDETERMINATE;
+        } else if mid == 0u32 {
+            return Ball::ZERO;
+        }
+        let sqrt = sqrt_point(&mid, prec + 8);
+        if rad == 0u32 {
+            return sqrt.round_mid(prec);
+        }
+        // |sqrt(x) - sqrt(m)| = |x - m| / (sqrt(x) + sqrt(m)) <= r / sqrt(m)
+        let err = rad / sqrt.lower_abs().unwrap();
+        sqrt.add_error(&err).round_mid(prec)
+    }
+}
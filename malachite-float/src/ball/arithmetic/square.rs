@@ -0,0 +1,99 @@
+use crate::ball::Ball;
+use malachite_base::num::arithmetic::traits::{Abs, Square};
+
+impl Ball {
+    /// Squares a [`Ball`], taking it by reference. The midpoint of the result is rounded to the
+    /// specified precision, and the rounding error is added to the radius.
+    ///
+    /// The result contains $x^2$ for every $x$ in `self`. This gives a tighter bound than
+    /// multiplying a [`Ball`] by itself, since the two factors are known to be equal. If the input
+    /// has midpoint $m$ and radius $r$, the radius of the result, before rounding, is $2|m|r+r^2$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let third = Ball::from_rational_prec(&Rational::from_signeds(1, 3), 100);
+    /// let square = third.square_prec(10);
+    /// assert_eq!(square.mid().to_string(), "0.1111");
+    /// assert!(square.contains_rational(&Rational::from_signeds(1, 9)));
+    /// ```
+    pub fn square_prec(&self, prec: u64) -> Ball {
+        if let Some((mid, rad)) = self.to_rational_mid_and_rad() {
+            let err = ((&mid).abs() << 1u64) * &rad + (&rad).square();
+            Ball::from_mid_and_error(&mid.square(), &err, prec)
+        } else {
+            Ball::INDETERMINATE
+        }
+    }
+}
+
+impl Square for Ball {
+    type Output = Ball;
+
+    /// Squares a [`Ball`], taking it by value.
+    ///
+    /// The midpoint of the result is rounded to the precision of the input's midpoint. See
+    /// [`Ball::square_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Square;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Ball::from(Float::from(3)).square().to_string(), "[8.0 +/- 1.0]");
+    /// ```
+    #[inline]
+    fn square(self) -> Ball {
+        (&self).square()
+    }
+}
+
+impl<'a> Square for &'a Ball {
+    type Output = Ball;
+
+    /// Squares a [`Ball`], taking it by reference.
+    ///
+    /// The midpoint of the result is rounded to the precision of the input's midpoint. See
+    /// [`Ball::square_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Square;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!((&Ball::from(Float::from(3))).square().to_string(), "[8.0 +/- 1.0]");
+    /// ```
+    #[inline]
+    fn square(self) -> Ball {
+        self.square_prec(self.mid_prec())
+    }
+}
@@ -0,0 +1,265 @@
+use crate::ball::arithmetic::constants::pi_ball;
+use crate::ball::arithmetic::log::series_helper;
+use crate::ball::Ball;
+use crate::Float;
+use malachite_base::num::arithmetic::traits::{ModPowerOf2, Parity, Square};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+use std::cmp::max;
+
+// The number of argument-halving steps taken before summing the arctangent series.
+const ATAN_HALVING_STEPS: u64 = 8;
+
+// Returns a `Ball` containing $\arctan z = \sum_{k=0}^\infty (-1)^k z^{2k+1}/(2k+1)$, for a `Ball`
+// $z$ whose elements have absolute value at most $1/2$. The series is summed until its tail is
+// less than $2^{-p}$, where $p$ is `prec`.
+pub(crate) fn atan_series(z: &Ball, prec: u64) -> Ball {
+    series_helper(z, prec, true)
+}
+
+// Returns `Ball`s containing $\sin x$ and $\cos x$, for a `Rational` $x$. The radii of the results
+// are at most about $2^{-p}$, where $p$ is `prec`.
+//
+// The argument is reduced to $r = x - k\pi/2$ with $|r| \leq \pi/4$, approximately, and then the
+// Taylor series of $\sin r$ and $\cos r$ are summed.
+pub(crate) fn sin_cos_point(x: &Rational, prec: u64) -> (Ball, Ball) {
+    if *x == 0u32 {
+        return (Ball::ZERO, Ball::ONE);
+    }
+    let working_prec = prec + u64::exact_from(max(0, x.floor_log_base_2_abs())) + 16;
+    let x = Ball::from_rational_prec(x, working_prec);
+    let half_pi = pi_ball(working_prec).mul_power_of_2(-1);
+    let k = Integer::rounding_from(
+        Rational::exact_from(x.mid()) / Rational::exact_from(half_pi.mid()),
+        RoundingMode::Nearest,
+    )
+    .0;
+    let quadrant = u64::exact_from(&(&k).mod_power_of_2(2));
+    let r = if k == 0u32 {
+        x
+    } else {
+        x.sub_prec(
+            &half_pi.mul_prec(&Ball::from(Float::from(k)), working_prec),
+            working_prec,
+        )
+    };
+    // Sum r^n / n! for n < N, where N! >= 2^(working_prec + 1). Since |r| <= 1, the tails of both
+    // series are at most 2 / N! <= 2^-working_prec.
+    let mut sin = Ball::ZERO;
+    let mut cos = Ball::ONE;
+    let mut term = Ball::ONE;
+    let mut factorial = Natural::ONE;
+    let mut n = 1u64;
+    while factorial.significant_bits() < working_prec + 2 {
+        term = term
+            .mul_prec(&r, working_prec)
+            .div_prec(&Ball::from(Float::from(n)), working_prec);
+        let sum = if n.odd() { &mut sin } else { &mut cos };
+        *sum = if n.mod_power_of_2(2) < 2 {
+            sum.add_prec(&term, working_prec)
+        } else {
+            sum.sub_prec(&term, working_prec)
+        };
+        factorial *= Natural::from(n);
+        n += 1;
+    }
+    let err = Rational::from_naturals(Natural::from(2u32), factorial);
+    let sin = sin.add_error(&err);
+    let cos = cos.add_error(&err);
+    match quadrant {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    }
+}
+
+// Returns a `Ball` containing $\arctan x$, for a `Rational` $x$. The radius of the result is at
+// most about $2^{-p}$, where $p$ is `prec`.
+//
+// If $|x| > 1$, $\arctan x = \pm\pi/2 - \arctan 1/x$ is used. Then the argument is halved several
+// times using $\arctan y = 2 \arctan \left ( y/(1 + \sqrt{1 + y^2}) \right )$, and the Taylor series
+// is summed.
+pub(crate) fn atan_point(x: &Rational, prec: u64) -> Ball {
+    if *x == 0u32 {
+        return Ball::ZERO;
+    }
+    let working_prec = prec + ATAN_HALVING_STEPS + 16;
+    let invert = *x > 1u32 || *x < -1i32;
+    let mut y = if invert {
+        Ball::from_rational_prec(&(Rational::ONE / x), working_prec)
+    } else {
+        Ball::from_rational_prec(x, working_prec)
+    };
+    for _ in 0..ATAN_HALVING_STEPS {
+        let denominator = y
+            .square_prec(working_prec)
+            .add_prec(&Ball::ONE, working_prec)
+            .sqrt_prec(working_prec)
+            .add_prec(&Ball::ONE, working_prec);
+        y = y.div_prec(&denominator, working_prec);
+    }
+    let atan = atan_series(&y, working_prec).mul_power_of_2(i64::exact_from(ATAN_HALVING_STEPS));
+    if invert {
+        let half_pi = pi_ball(working_prec).mul_power_of_2(-1);
+        if *x > 0u32 {
+            half_pi.sub_prec(&atan, working_prec)
+        } else {
+            (-half_pi).sub_prec(&atan, working_prec)
+        }
+    } else {
+        atan
+    }
+}
+
+impl Ball {
+    /// Computes the sine of a [`Ball`], taking it by reference. The midpoint of the result is
+    /// rounded to the specified precision, and the error is added to the radius.
+    ///
+    /// The result contains $\sin x$ for every $x$ in `self`. If `self` is indeterminate, so is
+    /// the result. Since the sine function is 1-Lipschitz, the radius of the result is increased
+    /// by the radius of the input.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// let sin_1 = Ball::ONE.sin_prec(100);
+    /// assert_eq!(sin_1.mid().to_string(), "0.84147098480789650665250232163");
+    /// assert!(sin_1.rad() < &Float::from(1.0e-29));
+    /// ```
+    pub fn sin_prec(&self, prec: u64) -> Ball {
+        self.sin_cos_prec(prec).0
+    }
+
+    /// Computes the cosine of a [`Ball`], taking it by reference. The midpoint of the result is
+    /// rounded to the specified precision, and the error is added to the radius.
+    ///
+    /// The result contains $\cos x$ for every $x$ in `self`. If `self` is indeterminate, so is
+    /// the result. Since the cosine function is 1-Lipschitz, the radius of the result is increased
+    /// by the radius of the input.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// let cos_1 = Ball::ONE.cos_prec(100);
+    /// assert_eq!(cos_1.mid().to_string(), "0.5403023058681397174009366074433");
+    /// assert!(cos_1.rad() < &Float::from(1.0e-29));
+    /// ```
+    pub fn cos_prec(&self, prec: u64) -> Ball {
+        self.sin_cos_prec(prec).1
+    }
+
+    /// Computes the sine and cosine of a [`Ball`], taking it by reference. This is faster than
+    /// computing them separately.
+    ///
+    /// See [`Ball::sin_prec`] and [`Ball::cos_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let pi = Ball::pi_prec(100);
+    /// let (sin, cos) = pi.sin_cos_prec(100);
+    /// assert!(sin.contains_rational(&Rational::from(0)));
+    /// assert!(cos.contains_rational(&Rational::from(-1)));
+    /// ```
+    pub fn sin_cos_prec(&self, prec: u64) -> (Ball, Ball) {
+        let (mid, rad) = if let Some(p) = self.to_rational_mid_and_rad() {
+            p
+        } else {
+            return (Ball::INDETERMINATE, Ball::INDETERMINATE);
+        };
+        let (sin, cos) = sin_cos_point(&mid, prec + 8);
+        (
+            sin.add_error(&rad).round_mid(prec),
+            cos.add_error(&rad).round_mid(prec),
+        )
+    }
+
+    /// Computes the arctangent of a [`Ball`], taking it by reference. The midpoint of the result
+    /// is rounded to the specified precision, and the error is added to the radius.
+    ///
+    /// The result contains $\arctan x$ for every $x$ in `self`. If `self` is indeterminate, so is
+    /// the result. If the input has radius $r$ and its elements have absolute values of at least
+    /// $a$, the radius of the result is increased by $r/(1+a^2)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// let quarter_pi = Ball::ONE.atan_prec(100);
+    /// assert_eq!(quarter_pi.mid().to_string(), "0.78539816339744830961566084582");
+    /// assert!(quarter_pi.rad() < &Float::from(1.0e-29));
+    /// ```
+    pub fn atan_prec(&self, prec: u64) -> Ball {
+        let (mid, rad) = if let Some(p) = self.to_rational_mid_and_rad() {
+            p
+        } else {
+            return Ball::INDETERMINATE;
+        };
+        let atan = atan_point(&mid, prec + 8);
+        if rad == 0u32 {
+            return atan.round_mid(prec);
+        }
+        let err = &rad / (self.lower_abs().unwrap().square() + Rational::ONE);
+        atan.add_error(&err).round_mid(prec)
+    }
+}
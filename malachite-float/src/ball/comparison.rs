@@ -0,0 +1,126 @@
+use crate::ball::Ball;
+use malachite_base::num::arithmetic::traits::Abs;
+use malachite_q::Rational;
+
+impl Ball {
+    /// Determines whether a [`Ball`] contains a [`Rational`].
+    ///
+    /// The indeterminate [`Ball`] contains every [`Rational`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::new(Float::from(1.5), Float::from(0.25));
+    /// assert!(x.contains_rational(&Rational::from_signeds(3, 2)));
+    /// assert!(x.contains_rational(&Rational::from_signeds(7, 4)));
+    /// assert!(!x.contains_rational(&Rational::from(2)));
+    /// ```
+    pub fn contains_rational(&self, x: &Rational) -> bool {
+        if let Some((mid, rad)) = self.to_rational_mid_and_rad() {
+            (mid - x).abs() <= rad
+        } else {
+            true
+        }
+    }
+
+    /// Determines whether a [`Ball`] contains another [`Ball`].
+    ///
+    /// The indeterminate [`Ball`] contains every [`Ball`], and is contained only by itself.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Ball::new(Float::from(1.5), Float::from(0.25));
+    /// assert!(x.contains(&Ball::new(Float::from(1.5), Float::from(0.125))));
+    /// assert!(x.contains(&Ball::new(Float::from(1.625), Float::from(0.125))));
+    /// assert!(!x.contains(&Ball::new(Float::from(1.75), Float::from(0.125))));
+    /// assert!(!x.contains(&Ball::INDETERMINATE));
+    /// ```
+    pub fn contains(&self, other: &Ball) -> bool {
+        match (
+            self.to_rational_mid_and_rad(),
+            other.to_rational_mid_and_rad(),
+        ) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some((x_mid, x_rad)), Some((y_mid, y_rad))) => {
+                x_rad >= y_rad && (x_mid - y_mid).abs() <= x_rad - y_rad
+            }
+        }
+    }
+
+    /// Determines whether two [`Ball`]s overlap; that is, whether they have at least one element
+    /// in common.
+    ///
+    /// The indeterminate [`Ball`] overlaps every [`Ball`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Ball::new(Float::from(1.5), Float::from(0.25));
+    /// assert!(x.overlaps(&Ball::new(Float::from(2), Float::from(0.25))));
+    /// assert!(!x.overlaps(&Ball::new(Float::from(2), Float::from(0.125))));
+    /// assert!(x.overlaps(&Ball::INDETERMINATE));
+    /// ```
+    pub fn overlaps(&self, other: &Ball) -> bool {
+        match (
+            self.to_rational_mid_and_rad(),
+            other.to_rational_mid_and_rad(),
+        ) {
+            (Some((x_mid, x_rad)), Some((y_mid, y_rad))) => (x_mid - y_mid).abs() <= x_rad + y_rad,
+            _ => true,
+        }
+    }
+
+    /// Determines whether a [`Ball`] contains zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Ball::new(Float::from(0.25), Float::from(0.5)).contains_zero());
+    /// assert!(!Ball::new(Float::from(1.5), Float::from(0.5)).contains_zero());
+    /// ```
+    pub fn contains_zero(&self) -> bool {
+        self.lower_abs().map_or(true, |x| x == 0u32)
+    }
+}
@@ -0,0 +1,260 @@
+use crate::ball::Ball;
+use crate::Float;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter, Result};
+
+// The number of extra bits of working precision used in the first step of Ziv's strategy.
+pub(crate) const ZIV_EXTRA_PREC: u64 = 32;
+
+impl From<Float> for Ball {
+    /// Converts a [`Float`] to an exact [`Ball`], taking the [`Float`] by value.
+    ///
+    /// If the [`Float`] is NaN or infinite, the indeterminate [`Ball`] is returned. Negative zero
+    /// is converted to positive zero.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Infinity;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Ball::from(Float::from(1.5)).to_string(), "[1.5 +/- 0.0]");
+    /// assert!(!Ball::from(Float::INFINITY).is_finite());
+    /// ```
+    fn from(x: Float) -> Ball {
+        if x.is_finite() {
+            Ball {
+                mid: x.abs_negative_zero(),
+                rad: Float::ZERO,
+            }
+        } else {
+            Ball::INDETERMINATE
+        }
+    }
+}
+
+impl<'a> From<&'a Float> for Ball {
+    /// Converts a [`Float`] to an exact [`Ball`], taking the [`Float`] by reference.
+    ///
+    /// If the [`Float`] is NaN or infinite, the indeterminate [`Ball`] is returned. Negative zero
+    /// is converted to positive zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Infinity;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Ball::from(&Float::from(1.5)).to_string(), "[1.5 +/- 0.0]");
+    /// assert!(!Ball::from(&Float::INFINITY).is_finite());
+    /// ```
+    #[inline]
+    fn from(x: &'a Float) -> Ball {
+        Ball::from(x.clone())
+    }
+}
+
+impl Ball {
+    /// Returns the endpoints of a [`Ball`], as [`Rational`]s. If the [`Ball`] has midpoint $m$
+    /// and radius $r$, the endpoints are $m-r$ and $m+r$.
+    ///
+    /// If the [`Ball`] is indeterminate, `None` is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// let (a, b) = Ball::new(Float::from(1.5), Float::from(0.25))
+    ///     .to_rational_interval()
+    ///     .unwrap();
+    /// assert_eq!(a.to_string(), "5/4");
+    /// assert_eq!(b.to_string(), "7/4");
+    ///
+    /// assert!(Ball::INDETERMINATE.to_rational_interval().is_none());
+    /// ```
+    pub fn to_rational_interval(&self) -> Option<(Rational, Rational)> {
+        self.to_rational_mid_and_rad()
+            .map(|(mid, rad)| (&mid - &rad, mid + rad))
+    }
+
+    /// Rounds every element of a [`Ball`] to a [`Float`] with the specified precision, using the
+    /// specified rounding mode. If every element rounds to the same [`Float`], and in the same
+    /// direction, that [`Float`] is returned, along with an [`Ordering`] indicating whether it is
+    /// less than, equal to, or greater than the elements. Otherwise, `None` is returned.
+    ///
+    /// If the [`Ball`] is indeterminate, `None` is returned. If the rounding mode is
+    /// [`RoundingMode::Exact`], `None` is returned unless the [`Ball`] is exact and its midpoint
+    /// can be represented with the specified precision.
+    ///
+    /// This function is the building block for computing correctly-rounded results: an
+    /// approximation is computed with increasing precision until its enclosing [`Ball`] is narrow
+    /// enough for this function to succeed.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::ball::Ball;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Ball::pi_prec(100).to_float_prec_round(10, RoundingMode::Floor).unwrap();
+    /// assert_eq!(x.to_string(), "3.141");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Ball::pi_prec(100).to_float_prec_round(10, RoundingMode::Ceiling).unwrap();
+    /// assert_eq!(x.to_string(), "3.145");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// assert!(Ball::pi_prec(10).to_float_prec_round(100, RoundingMode::Nearest).is_none());
+    /// ```
+    pub fn to_float_prec_round(&self, prec: u64, rm: RoundingMode) -> Option<(Float, Ordering)> {
+        let (mid, rad) = self.to_rational_mid_and_rad()?;
+        if rad == 0u32 {
+            if rm == RoundingMode::Exact {
+                let (x, o) = Float::from_rational_prec_round(mid, prec, RoundingMode::Nearest);
+                return if o == Ordering::Equal {
+                    Some((x, o))
+                } else {
+                    None
+                };
+            }
+            return Some(Float::from_rational_prec_round(mid, prec, rm));
+        } else if rm == RoundingMode::Exact {
+            return None;
+        }
+        let (lower, lower_o) = Float::from_rational_prec_round(&mid - &rad, prec, rm);
+        let (upper, upper_o) = Float::from_rational_prec_round(mid + rad, prec, rm);
+        if lower == upper && lower_o == upper_o {
+            Some((lower, lower_o))
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Ball {
+    /// Converts a [`Ball`] to a [`String`], in the form `[m +/- r]`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Ball::ONE.to_string(), "[1.0 +/- 0.0]");
+    /// assert_eq!(Ball::new(Float::from(1.5), Float::from(0.25)).to_string(), "[1.5 +/- 0.25]");
+    /// assert_eq!(Ball::INDETERMINATE.to_string(), "[NaN +/- Infinity]");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "[{} +/- {}]", self.mid, self.rad)
+    }
+}
+
+impl Debug for Ball {
+    /// Converts a [`Ball`] to a [`String`], in the form `[m +/- r]`.
+    ///
+    /// This is the same as the [`Display::fmt`] implementation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    ///
+    /// assert_eq!(format!("{:?}", Ball::ONE), "[1.0 +/- 0.0]");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+// Tries to round every element of a `Ball` to the same `Float`, as one step of Ziv's strategy for
+// correct rounding.
+//
+// If `rm` is `Exact`, the elements are rounded to nearest instead, and the function panics if the
+// rounding is inexact.
+pub(crate) fn ziv_try_round(x: &Ball, prec: u64, rm: RoundingMode) -> Option<(Float, Ordering)> {
+    let inner_rm = if rm == RoundingMode::Exact {
+        RoundingMode::Nearest
+    } else {
+        rm
+    };
+    let result = x.to_float_prec_round(prec, inner_rm)?;
+    assert!(
+        rm != RoundingMode::Exact || result.1 == Ordering::Equal,
+        "Result cannot be represented exactly with precision {prec}"
+    );
+    Some(result)
+}
+
+// Returns the working precision to use after `prec` in Ziv's strategy.
+pub(crate) const fn ziv_next_prec(prec: u64) -> u64 {
+    prec + (prec >> 1)
+}
+
+// Rounds a real number to a `Float` with the specified precision and rounding mode, using Ziv's
+// strategy: `f` is called with increasing working precisions, and must return a `Ball` containing
+// the number, until one of the `Ball`s is narrow enough to determine the correctly-rounded result.
+//
+// `f` must eventually return an exact `Ball` if the number can be represented with precision
+// `prec`, or if it is halfway between two such `Float`s and `rm` is `Nearest`. Otherwise the loop
+// will not terminate.
+pub(crate) fn ziv_round<F: FnMut(u64) -> Ball>(
+    prec: u64,
+    rm: RoundingMode,
+    mut f: F,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    let mut working_prec = prec + ZIV_EXTRA_PREC;
+    loop {
+        if let Some(result) = ziv_try_round(&f(working_prec), prec, rm) {
+            return result;
+        }
+        working_prec = ziv_next_prec(working_prec);
+    }
+}
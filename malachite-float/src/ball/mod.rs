@@ -0,0 +1,333 @@
+use crate::Float;
+use malachite_base::num::arithmetic::traits::Abs;
+use malachite_base::num::basic::traits::{Infinity, NaN, One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+
+/// The precision, in bits, of a [`Ball`]'s radius.
+///
+/// Radii only need to be accurate enough to give a useful bound, so they are always stored with
+/// this small precision, and are always rounded up.
+pub const RADIUS_PREC: u64 = 30;
+
+/// A ball of real numbers, consisting of a [`Float`] midpoint and a nonnegative [`Float`] radius.
+///
+/// A `Ball` $[m \pm r]$ represents the closed interval $[m-r, m+r]$. It is used for validated
+/// numerics: every operation on `Ball`s returns a `Ball` that is guaranteed to contain the exact
+/// result of applying the operation to any points of the input `Ball`s. The midpoint is computed
+/// with a caller-specified precision, and the error incurred by rounding it is added to the
+/// radius.
+///
+/// The design follows [Arb](https://arblib.org/)'s `arb_t`. The radius has a fixed, low precision
+/// (see [`RADIUS_PREC`]), and is always rounded up.
+///
+/// A `Ball` is either
+/// - finite: its midpoint is a finite [`Float`] and its radius is a finite, nonnegative [`Float`];
+///   or
+/// - indeterminate: its midpoint is NaN and its radius is infinite. An indeterminate `Ball`
+///   contains every real number. It is returned when a result can't be bounded; for example, when
+///   dividing by a `Ball` that contains zero.
+///
+/// A `Ball` whose radius is zero is called exact.
+#[derive(Clone)]
+pub struct Ball {
+    pub(crate) mid: Float,
+    pub(crate) rad: Float,
+}
+
+impl Ball {
+    /// The indeterminate `Ball`, which contains every real number.
+    pub const INDETERMINATE: Ball = Ball {
+        mid: Float::NAN,
+        rad: Float::INFINITY,
+    };
+
+    /// Creates a `Ball` from a midpoint and a radius.
+    ///
+    /// The radius is rounded up to [`RADIUS_PREC`] bits. If either the midpoint or the radius is
+    /// not finite, the indeterminate `Ball` is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `rad.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rad` is negative.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Ball::new(Float::from(1.5), Float::from(0.25));
+    /// assert_eq!(x.to_string(), "[1.5 +/- 0.25]");
+    /// ```
+    pub fn new(mid: Float, mut rad: Float) -> Ball {
+        assert!(
+            rad.is_nan() || rad >= 0u32,
+            "Radius cannot be negative: {rad}"
+        );
+        if !mid.is_finite() || !rad.is_finite() {
+            return Ball::INDETERMINATE;
+        }
+        rad.set_prec_round(RADIUS_PREC, RoundingMode::Ceiling);
+        Ball {
+            mid: mid.abs_negative_zero(),
+            rad: rad.abs_negative_zero(),
+        }
+    }
+
+    /// Creates a `Ball` containing a [`Rational`]. The midpoint is the [`Rational`] rounded to the
+    /// nearest [`Float`] with the specified precision, and the radius is the rounding error.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(&Rational::from_signeds(1, 3), 10);
+    /// assert_eq!(x.to_string(), "[0.3335 +/- 0.0001627604167]");
+    /// assert!(x.contains_rational(&Rational::from_signeds(1, 3)));
+    /// ```
+    pub fn from_rational_prec(x: &Rational, prec: u64) -> Ball {
+        Ball::from_mid_and_error(x, &Rational::ZERO, prec)
+    }
+
+    /// Creates the smallest `Ball` whose midpoint has the specified precision and which contains
+    /// the closed interval $[a, b]$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(a.significant_bits(), b.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if $a > b$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_interval(&Rational::from(1), &Rational::from(2), 10);
+    /// assert_eq!(x.to_string(), "[1.5 +/- 0.5]");
+    /// ```
+    pub fn from_rational_interval(a: &Rational, b: &Rational, prec: u64) -> Ball {
+        assert!(a <= b);
+        let mid = (a + b) >> 1u64;
+        let rad = (b - a) >> 1u64;
+        Ball::from_mid_and_error(&mid, &rad, prec)
+    }
+
+    // Creates a `Ball` containing every real within `err` of `mid`, rounding `mid` to `prec` bits.
+    pub(crate) fn from_mid_and_error(mid: &Rational, err: &Rational, prec: u64) -> Ball {
+        let (m, o) = Float::from_rational_prec_round_ref(mid, prec, RoundingMode::Nearest);
+        let rad = if o == Ordering::Equal {
+            upper_radius(err)
+        } else {
+            upper_radius(&((Rational::exact_from(&m) - mid).abs() + err))
+        };
+        Ball { mid: m, rad }
+    }
+
+    /// Returns a reference to a `Ball`'s midpoint.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Ball::new(Float::from(1.5), Float::from(0.25));
+    /// assert_eq!(x.mid().to_string(), "1.5");
+    /// ```
+    pub const fn mid(&self) -> &Float {
+        &self.mid
+    }
+
+    /// Returns a reference to a `Ball`'s radius.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Ball::new(Float::from(1.5), Float::from(0.25));
+    /// assert_eq!(x.rad().to_string(), "0.25");
+    /// ```
+    pub const fn rad(&self) -> &Float {
+        &self.rad
+    }
+
+    /// Takes a `Ball` by value and returns its midpoint and radius.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// let (mid, rad) = Ball::new(Float::from(1.5), Float::from(0.25)).into_mid_and_rad();
+    /// assert_eq!(mid.to_string(), "1.5");
+    /// assert_eq!(rad.to_string(), "0.25");
+    /// ```
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_mid_and_rad(self) -> (Float, Float) {
+        (self.mid, self.rad)
+    }
+
+    /// Determines whether a `Ball` is finite; that is, whether it is not indeterminate.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    ///
+    /// assert!(Ball::ONE.is_finite());
+    /// assert!(!Ball::INDETERMINATE.is_finite());
+    /// ```
+    pub const fn is_finite(&self) -> bool {
+        self.rad.is_finite()
+    }
+
+    /// Determines whether a `Ball` is exact; that is, whether its radius is zero.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!(Ball::ONE.is_exact());
+    /// assert!(!Ball::from_rational_prec(&Rational::from_signeds(1, 3), 10).is_exact());
+    /// ```
+    pub const fn is_exact(&self) -> bool {
+        self.rad.is_zero()
+    }
+
+    // Returns the midpoint and radius as `Rational`s, or `None` if the `Ball` is indeterminate.
+    pub(crate) fn to_rational_mid_and_rad(&self) -> Option<(Rational, Rational)> {
+        if self.is_finite() {
+            Some((
+                Rational::exact_from(&self.mid),
+                Rational::exact_from(&self.rad),
+            ))
+        } else {
+            None
+        }
+    }
+
+    // Returns an upper bound for the absolute values of the `Ball`'s elements, or `None` if the
+    // `Ball` is indeterminate.
+    pub(crate) fn upper_abs(&self) -> Option<Rational> {
+        self.to_rational_mid_and_rad().map(|(m, r)| m.abs() + r)
+    }
+
+    // Returns a lower bound for the absolute values of the `Ball`'s elements, or `None` if the
+    // `Ball` is indeterminate. The bound is zero if the `Ball` contains zero.
+    pub(crate) fn lower_abs(&self) -> Option<Rational> {
+        self.to_rational_mid_and_rad().map(|(m, r)| {
+            let m = m.abs();
+            if m > r {
+                m - r
+            } else {
+                Rational::ZERO
+            }
+        })
+    }
+
+    // Returns a `Ball` containing every real within `err` of an element of `self`.
+    pub(crate) fn add_error(&self, err: &Rational) -> Ball {
+        if let Some((_, rad)) = self.to_rational_mid_and_rad() {
+            Ball {
+                mid: self.mid.clone(),
+                rad: upper_radius(&(rad + err)),
+            }
+        } else {
+            Ball::INDETERMINATE
+        }
+    }
+
+    // Rounds the midpoint of a `Ball` to the specified precision, adding the rounding error to the
+    // radius.
+    pub(crate) fn round_mid(&self, prec: u64) -> Ball {
+        if let Some((mid, rad)) = self.to_rational_mid_and_rad() {
+            Ball::from_mid_and_error(&mid, &rad, prec)
+        } else {
+            Ball::INDETERMINATE
+        }
+    }
+
+    // Multiplies a `Ball` by $2^k$. This is exact.
+    pub(crate) fn mul_power_of_2(&self, pow: i64) -> Ball {
+        if let Some((mid, rad)) = self.to_rational_mid_and_rad() {
+            Ball::from_mid_and_error(&(mid << pow), &(rad << pow), self.mid_prec())
+        } else {
+            Ball::INDETERMINATE
+        }
+    }
+
+    // Returns the precision of the midpoint, or 1 if the midpoint is zero.
+    pub(crate) fn mid_prec(&self) -> u64 {
+        self.mid.get_prec().unwrap_or(1)
+    }
+}
+
+// Rounds a nonnegative `Rational` up to a `Float` with `RADIUS_PREC` bits.
+pub(crate) fn upper_radius(x: &Rational) -> Float {
+    Float::from_rational_prec_round_ref(x, RADIUS_PREC, RoundingMode::Ceiling).0
+}
+
+impl Zero for Ball {
+    /// The constant 0, as an exact `Ball`.
+    const ZERO: Ball = Ball {
+        mid: Float::ZERO,
+        rad: Float::ZERO,
+    };
+}
+
+impl One for Ball {
+    /// The constant 1, as an exact `Ball`.
+    const ONE: Ball = Ball {
+        mid: Float::ONE,
+        rad: Float::ZERO,
+    };
+}
+
+/// Arithmetic and elementary functions of [`Ball`]s.
+pub mod arithmetic;
+/// Queries about the containment and overlap of [`Ball`]s.
+pub mod comparison;
+/// Conversions between [`Ball`]s and other types, including [`String`]s.
+pub mod conversion;
@@ -9,5 +9,6 @@ pub mod complexity;
 pub mod constants;
 /// Getting and setting the components of a [`Float`](super::Float).
 pub mod get_and_set;
-/// Getting [`Float`](super::Float)'s ulp (unit in the last place).
+/// Getting [`Float`](super::Float)'s ulp (unit in the last place), and stepping to adjacent
+/// [`Float`](super::Float)s.
 pub mod ulp;
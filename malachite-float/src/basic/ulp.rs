@@ -1,11 +1,14 @@
 use crate::InnerFloat::Finite;
 use crate::{significand_bits, Float};
-use malachite_base::num::arithmetic::traits::{DivisibleByPowerOf2, NegAssign, PowerOf2};
+use malachite_base::num::arithmetic::traits::{
+    DivisibleByPowerOf2, IsPowerOf2, NegAssign, PowerOf2,
+};
 use malachite_base::num::basic::integers::PrimitiveInt;
-use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::basic::traits::{NaN, Zero};
 use malachite_base::num::conversion::traits::{ExactFrom, WrappingFrom};
 use malachite_base::num::logic::traits::SignificantBits;
 use malachite_nz::platform::Limb;
+use std::cmp::Ordering;
 
 impl Float {
     /// Gets a [`Float`]'s ulp (unit in last place, or unit of least precision).
@@ -245,4 +248,188 @@ impl Float {
             panic!("Cannot decrement float that is non-finite or zero");
         }
     }
+
+    /// Replaces a [`Float`] with the next-largest [`Float`] with the same precision.
+    ///
+    /// Unlike [`Float::increment`], this function never changes the precision: if the [`Float`]
+    /// is the largest [`Float`] of its precision in its binade, the result is the next power of 2.
+    /// If the [`Float`] is negative and its absolute value is a power of 2, the result has a
+    /// smaller exponent. This is the analog of MPFR's `mpfr_nextabove`, except that since
+    /// [`Float`] exponents are unbounded, there is no next-largest [`Float`] for zero or for
+    /// infinities.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is NaN, infinite, or zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowerOf2;
+    /// use malachite_base::num::basic::traits::{NegativeOne, One};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::ONE;
+    /// x.next_higher();
+    /// assert_eq!(x.to_string(), "2.0");
+    /// assert_eq!(x.get_prec(), Some(1));
+    ///
+    /// let mut x = Float::one_prec(100);
+    /// x.next_higher();
+    /// assert_eq!(x.to_string(), "1.000000000000000000000000000002");
+    ///
+    /// let mut x = Float::from(std::f64::consts::PI);
+    /// x.next_higher();
+    /// assert_eq!(x.to_string(), "3.1415926535897936");
+    ///
+    /// let mut x = Float::from(255);
+    /// x.next_higher();
+    /// assert_eq!(x.to_string(), "256.0");
+    /// assert_eq!(x.get_prec(), Some(8));
+    ///
+    /// let mut x = Float::NEGATIVE_ONE;
+    /// x.next_higher();
+    /// assert_eq!(x.to_string(), "-0.5");
+    /// assert_eq!(x.get_prec(), Some(1));
+    /// ```
+    pub fn next_higher(&mut self) {
+        if self.is_sign_negative() {
+            self.neg_assign();
+            self.next_lower();
+            self.neg_assign();
+        } else if let Float(Finite { precision, .. }) = self {
+            let precision = *precision;
+            self.increment();
+            // If the increment carried into the next binade, the result is a power of 2, which is
+            // exactly representable with the original precision.
+            self.set_prec(precision);
+        } else {
+            panic!("Cannot take the next higher value of a float that is non-finite or zero");
+        }
+    }
+
+    /// Replaces a [`Float`] with the next-smallest [`Float`] with the same precision.
+    ///
+    /// Unlike [`Float::decrement`], this function never changes the precision: if the [`Float`]
+    /// is positive and its absolute value is a power of 2, the result is the largest [`Float`] of
+    /// its precision in the binade below. This is the analog of MPFR's `mpfr_nextbelow`, except
+    /// that since [`Float`] exponents are unbounded, there is no next-smallest [`Float`] for zero
+    /// or for infinities.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is NaN, infinite, or zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowerOf2;
+    /// use malachite_base::num::basic::traits::{NegativeOne, One};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::ONE;
+    /// x.next_lower();
+    /// assert_eq!(x.to_string(), "0.5");
+    /// assert_eq!(x.get_prec(), Some(1));
+    ///
+    /// let mut x = Float::one_prec(100);
+    /// x.next_lower();
+    /// assert_eq!(x.to_string(), "0.999999999999999999999999999999");
+    ///
+    /// let mut x = Float::from(std::f64::consts::PI);
+    /// x.next_lower();
+    /// assert_eq!(x.to_string(), "3.1415926535897927");
+    ///
+    /// let mut x = Float::power_of_2(8u64);
+    /// x.next_lower();
+    /// assert_eq!(x.to_string(), "1.0e2");
+    ///
+    /// let mut x = Float::power_of_2(-100i64);
+    /// x.next_lower();
+    /// assert_eq!(x.to_string(), "4.0e-31");
+    ///
+    /// let mut x = Float::NEGATIVE_ONE;
+    /// x.next_lower();
+    /// assert_eq!(x.to_string(), "-2.0");
+    /// ```
+    pub fn next_lower(&mut self) {
+        if self.is_sign_negative() {
+            self.neg_assign();
+            self.next_higher();
+            self.neg_assign();
+        } else if let Float(Finite { precision, .. }) = self {
+            let precision = *precision;
+            if self.is_power_of_2() {
+                // Decrementing would lose a bit of precision, so decrement with one extra bit
+                // instead; the result then has the original precision.
+                self.set_prec(precision + 1);
+            }
+            self.decrement();
+        } else {
+            panic!("Cannot take the next lower value of a float that is non-finite or zero");
+        }
+    }
+
+    /// Replaces a [`Float`] with the next [`Float`] with the same precision in the direction of
+    /// another [`Float`].
+    ///
+    /// If either [`Float`] is NaN, `self` becomes NaN. If the two [`Float`]s are equal (including
+    /// the case where they are zeros of different signs), `self` is unchanged. Otherwise, this
+    /// function is equivalent to [`Float::next_higher`] or [`Float::next_lower`]. This is the
+    /// analog of MPFR's `mpfr_nexttoward`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `self` is infinite or zero, and `other` is neither NaN nor equal to `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero, One, Zero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let mut x = Float::ONE;
+    /// x.next_toward(&Float::INFINITY);
+    /// assert_eq!(x.to_string(), "2.0");
+    ///
+    /// let mut x = Float::from(std::f64::consts::PI);
+    /// x.next_toward(&Float::ZERO);
+    /// assert_eq!(x.to_string(), "3.1415926535897927");
+    ///
+    /// let mut x = Float::ONE;
+    /// x.next_toward(&Float::ONE);
+    /// assert_eq!(x.to_string(), "1.0");
+    ///
+    /// let mut x = Float::ZERO;
+    /// x.next_toward(&Float::NEGATIVE_ZERO);
+    /// assert_eq!(ComparableFloat(x), ComparableFloat(Float::ZERO));
+    ///
+    /// let mut x = Float::ONE;
+    /// x.next_toward(&Float::NAN);
+    /// assert!(x.is_nan());
+    /// ```
+    pub fn next_toward(&mut self, other: &Float) {
+        match (*self).partial_cmp(other) {
+            None => *self = Float::NAN,
+            Some(Ordering::Less) => self.next_higher(),
+            Some(Ordering::Greater) => self.next_lower(),
+            Some(Ordering::Equal) => {}
+        }
+    }
 }
@@ -247,3 +247,55 @@ impl PartialOrd for ComparableFloat {
         Some(self.as_ref().cmp(&other.as_ref()))
     }
 }
+
+impl Float {
+    /// Compares two [`Float`]s using a total order, in the spirit of the IEEE 754-2019
+    /// `totalOrder` predicate.
+    ///
+    /// This is how [`Float`]s are ordered, least to greatest:
+    ///   - Negative infinity
+    ///   - Negative nonzero finite floats
+    ///   - Negative zero
+    ///   - Positive zero
+    ///   - Positive nonzero finite floats
+    ///   - Positive infinity
+    ///   - NaN
+    ///
+    /// Since [`Float`] NaNs have no sign, NaN is ordered above everything else, like a positive
+    /// NaN is in `totalOrder`. Two [`Float`]s with different precisions but representing the same
+    /// value are not equal; the one with the greater precision has the greater absolute value.
+    ///
+    /// Apart from the placement of NaN, this order is the same as the order of
+    /// [`ComparableFloat`]s, which can be used when a [`Float`] needs to be used as a key.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeOne, NegativeZero, One, OneHalf, Zero
+    /// };
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// assert_eq!(Float::NAN.total_cmp(&Float::NAN), Ordering::Equal);
+    /// assert_eq!(Float::NAN.total_cmp(&Float::INFINITY), Ordering::Greater);
+    /// assert_eq!(Float::NEGATIVE_ZERO.total_cmp(&Float::ZERO), Ordering::Less);
+    /// assert_eq!(Float::ONE.total_cmp(&Float::one_prec(100)), Ordering::Less);
+    /// assert_eq!(Float::NEGATIVE_ONE.total_cmp(&Float::ONE_HALF), Ordering::Less);
+    /// ```
+    pub fn total_cmp(&self, other: &Float) -> Ordering {
+        match (self, other) {
+            (float_nan!(), float_nan!()) => Ordering::Equal,
+            (float_nan!(), _) => Ordering::Greater,
+            (_, float_nan!()) => Ordering::Less,
+            _ => ComparableFloatRef(self).cmp(&ComparableFloatRef(other)),
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use crate::Float;
+use crate::InnerFloat::NaN;
+use std::cmp::Ordering;
+
+impl Float {
+    /// Returns the smaller of two [`Float`]s, following the IEEE 754-2019 `minimum` operation.
+    /// Both [`Float`]s are taken by value.
+    ///
+    /// If either [`Float`] is NaN, NaN is returned. Negative zero is considered to be smaller than
+    /// positive zero. If the two [`Float`]s are equal but have different precisions, `self` is
+    /// returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{
+    ///     NaN, NegativeInfinity, NegativeZero, One, OneHalf, Zero
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert_eq!(Float::ONE.minimum(Float::ONE_HALF), Float::ONE_HALF);
+    /// assert_eq!(Float::ONE.minimum(Float::NEGATIVE_INFINITY), Float::NEGATIVE_INFINITY);
+    /// assert_eq!(
+    ///     ComparableFloat(Float::ZERO.minimum(Float::NEGATIVE_ZERO)),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    /// assert!(Float::ONE.minimum(Float::NAN).is_nan());
+    /// ```
+    pub fn minimum(self, other: Float) -> Float {
+        match self.partial_cmp(&other) {
+            None => float_nan!(),
+            Some(Ordering::Less) => self,
+            Some(Ordering::Greater) => other,
+            Some(Ordering::Equal) => {
+                if self.is_sign_positive() && other.is_sign_negative() {
+                    other
+                } else {
+                    self
+                }
+            }
+        }
+    }
+
+    /// Returns the larger of two [`Float`]s, following the IEEE 754-2019 `maximum` operation.
+    /// Both [`Float`]s are taken by value.
+    ///
+    /// If either [`Float`] is NaN, NaN is returned. Positive zero is considered to be larger than
+    /// negative zero. If the two [`Float`]s are equal but have different precisions, `self` is
+    /// returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero, One, OneHalf, Zero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert_eq!(Float::ONE.maximum(Float::ONE_HALF), Float::ONE);
+    /// assert_eq!(Float::ONE.maximum(Float::INFINITY), Float::INFINITY);
+    /// assert_eq!(
+    ///     ComparableFloat(Float::NEGATIVE_ZERO.maximum(Float::ZERO)),
+    ///     ComparableFloat(Float::ZERO)
+    /// );
+    /// assert!(Float::ONE.maximum(Float::NAN).is_nan());
+    /// ```
+    pub fn maximum(self, other: Float) -> Float {
+        match self.partial_cmp(&other) {
+            None => float_nan!(),
+            Some(Ordering::Less) => other,
+            Some(Ordering::Greater) => self,
+            Some(Ordering::Equal) => {
+                if self.is_sign_negative() && other.is_sign_positive() {
+                    other
+                } else {
+                    self
+                }
+            }
+        }
+    }
+
+    /// Returns the smaller of two [`Float`]s, following the IEEE 754-2019 `minimumNumber`
+    /// operation. Both [`Float`]s are taken by value.
+    ///
+    /// This function is the same as [`Float::minimum`], except that if exactly one of the
+    /// [`Float`]s is NaN, the other is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NaN, NegativeZero, One, OneHalf, Zero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert_eq!(Float::ONE.minimum_number(Float::ONE_HALF), Float::ONE_HALF);
+    /// assert_eq!(
+    ///     ComparableFloat(Float::ZERO.minimum_number(Float::NEGATIVE_ZERO)),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    /// assert_eq!(Float::ONE.minimum_number(Float::NAN), Float::ONE);
+    /// assert_eq!(Float::NAN.minimum_number(Float::ONE), Float::ONE);
+    /// assert!(Float::NAN.minimum_number(Float::NAN).is_nan());
+    /// ```
+    pub fn minimum_number(self, other: Float) -> Float {
+        if self.is_nan() {
+            other
+        } else if other.is_nan() {
+            self
+        } else {
+            self.minimum(other)
+        }
+    }
+
+    /// Returns the larger of two [`Float`]s, following the IEEE 754-2019 `maximumNumber`
+    /// operation. Both [`Float`]s are taken by value.
+    ///
+    /// This function is the same as [`Float::maximum`], except that if exactly one of the
+    /// [`Float`]s is NaN, the other is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NaN, NegativeZero, One, OneHalf, Zero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert_eq!(Float::ONE.maximum_number(Float::ONE_HALF), Float::ONE);
+    /// assert_eq!(
+    ///     ComparableFloat(Float::NEGATIVE_ZERO.maximum_number(Float::ZERO)),
+    ///     ComparableFloat(Float::ZERO)
+    /// );
+    /// assert_eq!(Float::ONE.maximum_number(Float::NAN), Float::ONE);
+    /// assert_eq!(Float::NAN.maximum_number(Float::ONE), Float::ONE);
+    /// assert!(Float::NAN.maximum_number(Float::NAN).is_nan());
+    /// ```
+    pub fn maximum_number(self, other: Float) -> Float {
+        if self.is_nan() {
+            other
+        } else if other.is_nan() {
+            self
+        } else {
+            self.maximum(other)
+        }
+    }
+}
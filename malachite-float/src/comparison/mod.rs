@@ -8,6 +8,9 @@ pub mod cmp_abs;
 pub mod eq;
 /// Hashing of [`Float`](crate::Float)s.
 pub mod hash;
+/// The IEEE 754-2019 `minimum`, `maximum`, `minimumNumber`, and `maximumNumber` operations on
+/// [`Float`](crate::Float)s.
+pub mod min_max;
 /// Implementations of [`PartialOrdAbs`](`malachite_base::num::comparison::traits::PartialOrdAbs`)
 /// (a trait for comparing the absolute values of numbers by order) for [`Float`](crate::Float)s
 /// and [`Integer`](malachite_nz::integer::Integer)s.
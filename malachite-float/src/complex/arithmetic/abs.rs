@@ -0,0 +1,212 @@
+use crate::ball::conversion::ziv_round;
+use crate::ball::Ball;
+use crate::complex::Complex;
+use crate::Float;
+use malachite_base::num::arithmetic::traits::Abs;
+use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+
+// Returns a `Ball` containing the argument of $x + yi$, in $(-\pi, \pi]$. The argument of zero is
+// zero.
+pub(crate) fn arg_ball(x: &Rational, y: &Rational, prec: u64) -> Ball {
+    if *y == 0u32 {
+        if *x >= 0u32 {
+            Ball::ZERO
+        } else {
+            Ball::pi_prec(prec)
+        }
+    } else if *x == 0u32 {
+        let half_pi = Ball::pi_prec(prec).mul_power_of_2(-1);
+        if *y > 0u32 {
+            half_pi
+        } else {
+            -half_pi
+        }
+    } else {
+        let atan = Ball::from_rational_prec(&(y / x), prec).atan_prec(prec);
+        if *x > 0u32 {
+            atan
+        } else if *y > 0u32 {
+            atan.add_prec(&Ball::pi_prec(prec), prec)
+        } else {
+            atan.sub_prec(&Ball::pi_prec(prec), prec)
+        }
+    }
+}
+
+impl Complex {
+    /// Computes the absolute value of a [`Complex`] number, taking it by reference. The result is
+    /// rounded to the specified precision, using the specified rounding mode. An [`Ordering`] is
+    /// also returned, indicating whether the rounded absolute value is less than, equal to, or
+    /// greater than the exact absolute value.
+    ///
+    /// $$
+    /// f(a+bi) = \sqrt{a^2+b^2}.
+    /// $$
+    ///
+    /// If either part of the input is infinite, the result is infinity. Otherwise, if either part
+    /// is NaN, the result is NaN.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result can't be represented
+    /// exactly with the specified precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let z = Complex::new(Float::from(3), Float::from(4));
+    /// let (abs, o) = z.abs_prec_round(10, RoundingMode::Exact);
+    /// assert_eq!(abs.to_string(), "5.0");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let z = Complex::new(Float::from(1), Float::from(1));
+    /// let (abs, o) = z.abs_prec_round(10, RoundingMode::Floor);
+    /// assert_eq!(abs.to_string(), "1.414");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    pub fn abs_prec_round(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        let z = if let Some(z) = self.to_complex_rational() {
+            z
+        } else if self.re.is_infinite() || self.im.is_infinite() {
+            return (Float::INFINITY, Ordering::Equal);
+        } else {
+            return (Float::NAN, Ordering::Equal);
+        };
+        if z.is_real() {
+            return Float::from_rational_prec_round(z.into_re_im().0.abs(), prec, rm);
+        }
+        let norm = z.norm();
+        ziv_round(prec, rm, |working_prec| {
+            Ball::from_rational_prec(&norm, working_prec).sqrt_prec(working_prec)
+        })
+    }
+
+    /// Computes the absolute value of a [`Complex`] number, taking it by reference. The result is
+    /// rounded to the specified precision, to nearest. An [`Ordering`] is also returned,
+    /// indicating whether the rounded absolute value is less than, equal to, or greater than the
+    /// exact absolute value.
+    ///
+    /// If either part of the input is infinite, the result is infinity. Otherwise, if either part
+    /// is NaN, the result is NaN. See [`Complex::abs_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let z = Complex::new(Float::from(1), Float::from(2));
+    /// assert_eq!(z.abs_prec(100).0.to_string(), "2.236067977499789696409173668732");
+    /// ```
+    #[inline]
+    pub fn abs_prec(&self, prec: u64) -> (Float, Ordering) {
+        self.abs_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the argument of a [`Complex`] number, taking it by reference. The result is
+    /// rounded to the specified precision, using the specified rounding mode. An [`Ordering`] is
+    /// also returned, indicating whether the rounded argument is less than, equal to, or greater
+    /// than the exact argument.
+    ///
+    /// The argument of $a+bi$ is the angle $\theta \in (-\pi, \pi]$ such that $a+bi = r e^{i
+    /// \theta}$ for some $r \geq 0$. The argument of zero is zero.
+    ///
+    /// If either part of the input is not finite, the result is NaN: for example, the argument of
+    /// $\infty + 0i$ is NaN, not 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result can't be represented
+    /// exactly with the specified precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let z = Complex::new(Float::from(-1), Float::from(0));
+    /// let (arg, o) = z.arg_prec_round(10, RoundingMode::Floor);
+    /// assert_eq!(arg.to_string(), "3.141");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let z = Complex::new(Float::from(1), Float::from(-1));
+    /// let (arg, o) = z.arg_prec_round(10, RoundingMode::Ceiling);
+    /// assert_eq!(arg.to_string(), "-0.785");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    pub fn arg_prec_round(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        let (x, y) = if let Some(z) = self.to_complex_rational() {
+            z.into_re_im()
+        } else {
+            return (Float::NAN, Ordering::Equal);
+        };
+        ziv_round(prec, rm, |working_prec| arg_ball(&x, &y, working_prec))
+    }
+
+    /// Computes the argument of a [`Complex`] number, taking it by reference. The result is
+    /// rounded to the specified precision, to nearest. An [`Ordering`] is also returned,
+    /// indicating whether the rounded argument is less than, equal to, or greater than the exact
+    /// argument.
+    ///
+    /// If either part of the input is not finite, the result is NaN. See
+    /// [`Complex::arg_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let z = Complex::new(Float::from(0), Float::from(1));
+    /// assert_eq!(z.arg_prec(100).0.to_string(), "1.57079632679489661923132169164");
+    /// assert_eq!(Complex::ZERO.arg_prec(100).0.to_string(), "0.0");
+    /// ```
+    #[inline]
+    pub fn arg_prec(&self, prec: u64) -> (Float, Ordering) {
+        self.arg_prec_round(prec, RoundingMode::Nearest)
+    }
+}
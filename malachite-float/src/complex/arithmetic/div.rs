@@ -0,0 +1,332 @@
+use crate::complex::extended::Extended;
+use crate::complex::Complex;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_q::complex::ComplexRational;
+use std::cmp::{max, Ordering};
+use std::ops::{Div, DivAssign};
+
+// Divides a `Complex` number by another, where at least one of them has a part that is not finite
+// or the divisor is zero, following the example implementation of division in C99 Annex G (G.5.1).
+// The scaling that the example does to avoid overflow isn't needed, since the arithmetic is exact.
+fn div_non_finite(x: &Complex, y: &Complex) -> (Extended, Extended) {
+    let (a, b) = x.to_extended();
+    let (c, d) = y.to_extended();
+    let denominator = &(&c * &c) + &(&d * &d);
+    let re = &(&(&a * &c) + &(&b * &d)) / &denominator;
+    let im = &(&(&b * &c) - &(&a * &d)) / &denominator;
+    if !re.is_nan() || !im.is_nan() {
+        (re, im)
+    } else if denominator == Extended::ZERO && (!a.is_nan() || !b.is_nan()) {
+        // A nonzero number divided by zero
+        (&Extended::INFINITY * &a, &Extended::INFINITY * &b)
+    } else if (a.is_infinite() || b.is_infinite()) && c.is_finite() && d.is_finite() {
+        // An infinity divided by a finite number
+        let a = a.to_unit();
+        let b = b.to_unit();
+        (
+            &Extended::INFINITY * &(&(&a * &c) + &(&b * &d)),
+            &Extended::INFINITY * &(&(&b * &c) - &(&a * &d)),
+        )
+    } else if (c.is_infinite() || d.is_infinite()) && a.is_finite() && b.is_finite() {
+        // A finite number divided by an infinity
+        let c = c.to_unit();
+        let d = d.to_unit();
+        (
+            &Extended::ZERO * &(&(&a * &c) + &(&b * &d)),
+            &Extended::ZERO * &(&(&b * &c) - &(&a * &d)),
+        )
+    } else {
+        (re, im)
+    }
+}
+
+impl Complex {
+    /// Divides a [`Complex`] number by another, taking both by reference. Each part of the result
+    /// is rounded to the corresponding precision, using the corresponding rounding mode. A pair of
+    /// [`Ordering`]s is also returned, indicating whether each part of the rounded result is less
+    /// than, equal to, or greater than the corresponding part of the exact result.
+    ///
+    /// $$
+    /// f(x, y) = x/y.
+    /// $$
+    ///
+    /// If either input has a part that is not finite, or if `other` is zero, the result is computed
+    /// as in C99 Annex G: each part is computed from the parts of the inputs using the rules for
+    /// infinities and NaN, and if this makes both parts NaN, an infinite number divided by a finite
+    /// one is infinite, a finite number divided by an infinite one is 0, and a nonzero number
+    /// divided by zero is infinite. A zero divisor is treated as positive. For example, $1/(\infty
+    /// + 0i) = 0$ and $(1 + i)/0 = \infty + \infty i$, while both parts of $0/0$ are NaN.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if either rounding mode is `Exact` but the
+    /// corresponding part of the result can't be represented exactly with the corresponding
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::{max, Ordering};
+    ///
+    /// let x = Complex::new(Float::from(1.5), Float::from(-2));
+    /// let y = Complex::new(Float::from(0.25), Float::from(3));
+    /// let (z, o) = x.div_prec_round(&y, (5, 1), (RoundingMode::Floor, RoundingMode::Ceiling));
+    /// assert_eq!(z.to_string(), "(-0.62 -0.5)");
+    /// assert_eq!(o, (Ordering::Less, Ordering::Greater));
+    /// ```
+    pub fn div_prec_round(
+        &self,
+        other: &Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        match (self.to_complex_rational(), other.to_complex_rational()) {
+            (Some(x), Some(y)) if y != ComplexRational::ZERO => {
+                Complex::from_complex_rational_prec_round(x / y, prec, rm)
+            }
+            _ => {
+                let (re, im) = div_non_finite(self, other);
+                Extended::into_complex_prec_round(re, im, prec, rm)
+            }
+        }
+    }
+
+    /// Divides a [`Complex`] number by another, taking both by reference. Both parts of the result
+    /// are rounded to the specified precision, to nearest. A pair of [`Ordering`]s is also
+    /// returned, indicating whether each part of the rounded result is less than, equal to, or
+    /// greater than the corresponding part of the exact result.
+    ///
+    /// Infinities, NaNs, and zero divisors are handled as in C99 Annex G. See
+    /// [`Complex::div_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::new(Float::from(1.5), Float::from(-2));
+    /// let y = Complex::new(Float::from(0.25), Float::from(3));
+    /// assert_eq!(x.div_prec(&y, 10).0.to_string(), "(-0.621 -0.552)");
+    /// ```
+    #[inline]
+    pub fn div_prec(&self, other: &Complex, prec: u64) -> (Complex, (Ordering, Ordering)) {
+        self.div_prec_round(
+            other,
+            (prec, prec),
+            (RoundingMode::Nearest, RoundingMode::Nearest),
+        )
+    }
+}
+
+impl Div<Complex> for Complex {
+    type Output = Complex;
+
+    /// Divides a [`Complex`] number by another, taking both by value.
+    ///
+    /// Both parts of the result are rounded to nearest, with the largest precision of the four
+    /// parts of the inputs. Infinities, NaNs, and zero divisors are handled as in C99 Annex G. See
+    /// [`Complex::div_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::new(Float::from(1.5), Float::from(-2));
+    /// let y = Complex::new(Float::from(0.25), Float::from(3));
+    /// assert_eq!((x / y).to_string(), "(-0.6206896551724138 -0.5517241379310345)");
+    /// ```
+    #[inline]
+    fn div(self, other: Complex) -> Complex {
+        &self / &other
+    }
+}
+
+impl<'a> Div<&'a Complex> for Complex {
+    type Output = Complex;
+
+    /// Divides a [`Complex`] number by another, taking the first by value and the second by
+    /// reference.
+    ///
+    /// Both parts of the result are rounded to nearest, with the largest precision of the four
+    /// parts of the inputs. Infinities, NaNs, and zero divisors are handled as in C99 Annex G. See
+    /// [`Complex::div_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::new(Float::from(1.5), Float::from(-2));
+    /// let y = Complex::new(Float::from(0.25), Float::from(3));
+    /// assert_eq!((x / &y).to_string(), "(-0.6206896551724138 -0.5517241379310345)");
+    /// ```
+    #[inline]
+    fn div(self, other: &'a Complex) -> Complex {
+        &self / other
+    }
+}
+
+impl<'a> Div<Complex> for &'a Complex {
+    type Output = Complex;
+
+    /// Divides a [`Complex`] number by another, taking the first by reference and the second by
+    /// value.
+    ///
+    /// Both parts of the result are rounded to nearest, with the largest precision of the four
+    /// parts of the inputs. Infinities, NaNs, and zero divisors are handled as in C99 Annex G. See
+    /// [`Complex::div_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::new(Float::from(1.5), Float::from(-2));
+    /// let y = Complex::new(Float::from(0.25), Float::from(3));
+    /// assert_eq!((&x / y).to_string(), "(-0.6206896551724138 -0.5517241379310345)");
+    /// ```
+    #[inline]
+    fn div(self, other: Complex) -> Complex {
+        self / &other
+    }
+}
+
+impl<'a, 'b> Div<&'a Complex> for &'b Complex {
+    type Output = Complex;
+
+    /// Divides a [`Complex`] number by another, taking both by reference.
+    ///
+    /// Both parts of the result are rounded to nearest, with the largest precision of the four
+    /// parts of the inputs. Infinities, NaNs, and zero divisors are handled as in C99 Annex G. See
+    /// [`Complex::div_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::new(Float::from(1.5), Float::from(-2));
+    /// let y = Complex::new(Float::from(0.25), Float::from(3));
+    /// assert_eq!((&x / &y).to_string(), "(-0.6206896551724138 -0.5517241379310345)");
+    /// ```
+    fn div(self, other: &'a Complex) -> Complex {
+        self.div_prec(other, max(self.max_prec(), other.max_prec()))
+            .0
+    }
+}
+
+impl DivAssign<Complex> for Complex {
+    /// Divides a [`Complex`] number by a [`Complex`] number in place, taking the [`Complex`] number
+    /// on the right-hand side by value.
+    ///
+    /// Infinities, NaNs, and zero divisors are handled as in C99 Annex G. See
+    /// [`Complex::div_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Complex::new(Float::from(1.5), Float::from(-2));
+    /// let y = Complex::new(Float::from(0.25), Float::from(3));
+    /// x /= y;
+    /// assert_eq!(x.to_string(), "(-0.6206896551724138 -0.5517241379310345)");
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: Complex) {
+        *self = &*self / &other;
+    }
+}
+
+impl<'a> DivAssign<&'a Complex> for Complex {
+    /// Divides a [`Complex`] number by a [`Complex`] number in place, taking the [`Complex`] number
+    /// on the right-hand side by reference.
+    ///
+    /// Infinities, NaNs, and zero divisors are handled as in C99 Annex G. See
+    /// [`Complex::div_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Complex::new(Float::from(1.5), Float::from(-2));
+    /// let y = Complex::new(Float::from(0.25), Float::from(3));
+    /// x /= &y;
+    /// assert_eq!(x.to_string(), "(-0.6206896551724138 -0.5517241379310345)");
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: &'a Complex) {
+        *self = &*self / other;
+    }
+}
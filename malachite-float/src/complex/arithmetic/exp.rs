@@ -0,0 +1,144 @@
+use crate::ball::conversion::ziv_round;
+use crate::ball::Ball;
+use crate::complex::extended::Extended;
+use crate::complex::{ziv_round_complex, Complex, NAN_RESULT};
+use malachite_base::rounding_modes::RoundingMode;
+use std::cmp::Ordering;
+
+// If the absolute value of the real part of the input is at least 2 to this power, the exponential
+// is not computed.
+pub(crate) const MAX_EXP_ARGUMENT_LOG: i64 = 50;
+
+// Computes $e^{x+yi}$, where $x$ or $y$ is not finite, following C99 Annex G (G.6.3.1).
+pub(crate) fn exp_non_finite(x: &Extended, y: &Extended) -> (Extended, Extended) {
+    match (x, y) {
+        (Extended::Infinity(true), Extended::Finite(y)) if *y == 0u32 => {
+            (Extended::INFINITY, Extended::ZERO)
+        }
+        (Extended::Infinity(true), Extended::Finite(y)) => {
+            // $\infty(\cos y + i \sin y)$. Since $y$ is a nonzero rational number, neither $\cos y$
+            // nor $\sin y$ is zero, so their signs are found by rounding them to one bit.
+            let sin_cos =
+                |working_prec| Ball::from_rational_prec(y, working_prec).sin_cos_prec(working_prec);
+            let sin = ziv_round(1, RoundingMode::Nearest, |working_prec| {
+                sin_cos(working_prec).0
+            })
+            .0;
+            let cos = ziv_round(1, RoundingMode::Nearest, |working_prec| {
+                sin_cos(working_prec).1
+            })
+            .0;
+            (
+                &Extended::INFINITY * &Extended::from_float(&cos),
+                &Extended::INFINITY * &Extended::from_float(&sin),
+            )
+        }
+        (Extended::Infinity(true), _) => (Extended::INFINITY, Extended::NaN),
+        (Extended::Infinity(false), _) => (Extended::ZERO, Extended::ZERO),
+        (Extended::NaN, Extended::Finite(y)) if *y == 0u32 => (Extended::NaN, Extended::ZERO),
+        _ => (Extended::NaN, Extended::NaN),
+    }
+}
+
+impl Complex {
+    /// Computes the exponential function of a [`Complex`] number, taking it by reference. Each part
+    /// of the result is rounded to the corresponding precision, using the corresponding rounding
+    /// mode. A pair of [`Ordering`]s is also returned, indicating whether each part of the rounded
+    /// result is less than, equal to, or greater than the corresponding part of the exact result.
+    ///
+    /// $$
+    /// f(a+bi) = e^a(\cos b + i \sin b).
+    /// $$
+    ///
+    /// If either part of the input is not finite, the result is computed as in C99 Annex G and MPC:
+    /// for example, $e^{-\infty + i} = 0$, $e^{\infty + 0i} = \infty$, and $e^{\infty + 2i} =
+    /// -\infty + \infty i$, while both parts of $e^{1 + \infty i}$ are NaN. If the input is finite
+    /// but the absolute value of its real part is at least $2^{50}$, both parts of the result are
+    /// NaN.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if either rounding mode is `Exact` but the
+    /// corresponding part of the result can't be represented exactly with the corresponding
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let z = Complex::new(Float::from(1), Float::from(0));
+    /// let (w, o) = z.exp_prec_round((10, 10), (RoundingMode::Floor, RoundingMode::Ceiling));
+    /// assert_eq!(w.to_string(), "(2.715 0.0)");
+    /// assert_eq!(o, (Ordering::Less, Ordering::Equal));
+    /// ```
+    pub fn exp_prec_round(
+        &self,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        let (x, y) = if let Some(z) = self.to_complex_rational() {
+            z.into_re_im()
+        } else {
+            let (x, y) = self.to_extended();
+            let (re, im) = exp_non_finite(&x, &y);
+            return Extended::into_complex_prec_round(re, im, prec, rm);
+        };
+        if x != 0u32 && x.floor_log_base_2_abs() >= MAX_EXP_ARGUMENT_LOG {
+            return NAN_RESULT;
+        }
+        ziv_round_complex(prec, rm, |working_prec| {
+            let exp = Ball::from_rational_prec(&x, working_prec).exp_prec(working_prec);
+            let (sin, cos) = Ball::from_rational_prec(&y, working_prec).sin_cos_prec(working_prec);
+            (
+                exp.mul_prec(&cos, working_prec),
+                exp.mul_prec(&sin, working_prec),
+            )
+        })
+    }
+
+    /// Computes the exponential function of a [`Complex`] number, taking it by reference. Both
+    /// parts of the result are rounded to the specified precision, to nearest. A pair of
+    /// [`Ordering`]s is also returned, indicating whether each part of the rounded result is less
+    /// than, equal to, or greater than the corresponding part of the exact result.
+    ///
+    /// Infinities and NaNs are handled as in C99 Annex G. If the input is finite but the absolute
+    /// value of its real part is at least $2^{50}$, both parts of the result are NaN. See
+    /// [`Complex::exp_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let z = Complex::new(Float::from(0), Float::from(1));
+    /// assert_eq!(
+    ///     z.exp_prec(100).0.to_string(),
+    ///     "(0.5403023058681397174009366074433 0.84147098480789650665250232163)"
+    /// );
+    /// ```
+    #[inline]
+    pub fn exp_prec(&self, prec: u64) -> (Complex, (Ordering, Ordering)) {
+        self.exp_prec_round((prec, prec), (RoundingMode::Nearest, RoundingMode::Nearest))
+    }
+}
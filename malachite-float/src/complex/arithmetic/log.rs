@@ -0,0 +1,156 @@
+use crate::ball::Ball;
+use crate::complex::arithmetic::abs::arg_ball;
+use crate::complex::{ziv_round_complex, Complex, NAN_RESULT};
+use crate::Float;
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+use malachite_base::rounding_modes::RoundingMode;
+use std::cmp::Ordering;
+
+// Computes the logarithm of a `Complex` number with a part that is not finite, following C99
+// Annex G (G.6.3.2). If either part is infinite, the real part of the logarithm is $\infty$ and
+// the imaginary part is the limit of the argument, an odd multiple of $\pi/4$ or 0.
+fn log_non_finite(
+    z: &Complex,
+    prec: (u64, u64),
+    rm: (RoundingMode, RoundingMode),
+) -> (Complex, (Ordering, Ordering)) {
+    if !z.re.is_infinite() && !z.im.is_infinite() {
+        return NAN_RESULT;
+    }
+    let (arg, arg_o) = if z.re.is_nan() || z.im.is_nan() {
+        (Float::NAN, Ordering::Equal)
+    } else {
+        // The direction in which `z` is infinite, reflected into the upper half-plane
+        let direction = |x: &Float| -> i32 {
+            if !x.is_infinite() {
+                0
+            } else if *x > 0u32 {
+                1
+            } else {
+                -1
+            }
+        };
+        let direction = Complex::new(
+            Float::from(direction(&z.re)),
+            Float::from(direction(&z.im).abs()),
+        );
+        if z.im < 0u32 {
+            let (arg, o) = direction.arg_prec_round(prec.1, -rm.1);
+            if arg == 0u32 {
+                (arg, o)
+            } else {
+                (-arg, o.reverse())
+            }
+        } else {
+            direction.arg_prec_round(prec.1, rm.1)
+        }
+    };
+    (Complex::new(Float::INFINITY, arg), (Ordering::Equal, arg_o))
+}
+
+impl Complex {
+    /// Computes the principal natural logarithm of a [`Complex`] number, taking it by reference.
+    /// Each part of the result is rounded to the corresponding precision, using the corresponding
+    /// rounding mode. A pair of [`Ordering`]s is also returned, indicating whether each part of the
+    /// rounded result is less than, equal to, or greater than the corresponding part of the exact
+    /// result.
+    ///
+    /// $$
+    /// f(z) = \ln |z| + i \arg z.
+    /// $$
+    ///
+    /// The imaginary part of the result is in $(-\pi, \pi]$. The logarithm of zero is $-\infty +
+    /// 0i$. If either part of the input is infinite, the real part of the result is $\infty$ and
+    /// the imaginary part is the limit of the argument, as in C99 Annex G and MPC: for example,
+    /// $\ln(-\infty + i) = \infty + \pi i$ and $\ln(\infty + \infty i) = \infty + \pi i/4$. The
+    /// imaginary part is NaN if the other part of the input is NaN. If one part of the input is NaN
+    /// and the other is finite, both parts of the result are NaN.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if either rounding mode is `Exact` but the
+    /// corresponding part of the result can't be represented exactly with the corresponding
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let z = Complex::new(Float::from(-1), Float::from(0));
+    /// let (w, o) = z.log_prec_round((10, 10), (RoundingMode::Floor, RoundingMode::Ceiling));
+    /// assert_eq!(w.to_string(), "(0.0 3.145)");
+    /// assert_eq!(o, (Ordering::Equal, Ordering::Greater));
+    /// ```
+    pub fn log_prec_round(
+        &self,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        let z = if let Some(z) = self.to_complex_rational() {
+            z
+        } else {
+            return log_non_finite(self, prec, rm);
+        };
+        let norm = z.norm();
+        if norm == 0u32 {
+            return (
+                Complex::new(Float::NEGATIVE_INFINITY, Float::ZERO),
+                (Ordering::Equal, Ordering::Equal),
+            );
+        }
+        let (x, y) = z.into_re_im();
+        ziv_round_complex(prec, rm, |working_prec| {
+            (
+                Ball::from_rational_prec(&norm, working_prec)
+                    .log_prec(working_prec)
+                    .mul_power_of_2(-1),
+                arg_ball(&x, &y, working_prec),
+            )
+        })
+    }
+
+    /// Computes the principal natural logarithm of a [`Complex`] number, taking it by reference.
+    /// Both parts of the result are rounded to the specified precision, to nearest. A pair of
+    /// [`Ordering`]s is also returned, indicating whether each part of the rounded result is less
+    /// than, equal to, or greater than the corresponding part of the exact result.
+    ///
+    /// Infinities and NaNs are handled as in C99 Annex G. See [`Complex::log_prec_round`] for
+    /// details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let z = Complex::new(Float::from(1), Float::from(1));
+    /// assert_eq!(
+    ///     z.log_prec(100).0.to_string(),
+    ///     "(0.346573590279972654708616060729 0.78539816339744830961566084582)"
+    /// );
+    /// ```
+    #[inline]
+    pub fn log_prec(&self, prec: u64) -> (Complex, (Ordering, Ordering)) {
+        self.log_prec_round((prec, prec), (RoundingMode::Nearest, RoundingMode::Nearest))
+    }
+}
@@ -0,0 +1,22 @@
+/// Absolute values and arguments of [`Complex`](super::Complex) numbers.
+pub mod abs;
+/// Addition of [`Complex`](super::Complex) numbers.
+pub mod add;
+/// Division of [`Complex`](super::Complex) numbers.
+pub mod div;
+/// Exponentials of [`Complex`](super::Complex) numbers.
+pub mod exp;
+/// Logarithms of [`Complex`](super::Complex) numbers.
+pub mod log;
+/// Multiplication of [`Complex`](super::Complex) numbers.
+pub mod mul;
+/// Negation and conjugation of [`Complex`](super::Complex) numbers.
+pub mod neg;
+/// Powers of [`Complex`](super::Complex) numbers.
+pub mod pow;
+/// Square roots of [`Complex`](super::Complex) numbers.
+pub mod sqrt;
+/// Subtraction of [`Complex`](super::Complex) numbers.
+pub mod sub;
+/// Trigonometric functions of [`Complex`](super::Complex) numbers.
+pub mod trig;
@@ -0,0 +1,325 @@
+use crate::complex::extended::Extended;
+use crate::complex::Complex;
+use malachite_base::rounding_modes::RoundingMode;
+use std::cmp::{max, Ordering};
+use std::ops::{Mul, MulAssign};
+
+// Multiplies two `Complex` numbers, at least one of which has a part that is not finite, following
+// the example implementation of multiplication in C99 Annex G (G.5.1).
+pub(crate) fn mul_non_finite(x: &Complex, y: &Complex) -> (Extended, Extended) {
+    let (mut a, mut b) = x.to_extended();
+    let (mut c, mut d) = y.to_extended();
+    let re = &(&a * &c) - &(&b * &d);
+    let im = &(&a * &d) + &(&b * &c);
+    if !re.is_nan() || !im.is_nan() {
+        return (re, im);
+    }
+    // Recover an infinite result that the formulas above turned into NaNs
+    let mut recalculate = false;
+    if a.is_infinite() || b.is_infinite() {
+        a = a.to_unit();
+        b = b.to_unit();
+        c = c.nan_to_zero();
+        d = d.nan_to_zero();
+        recalculate = true;
+    }
+    if c.is_infinite() || d.is_infinite() {
+        c = c.to_unit();
+        d = d.to_unit();
+        a = a.nan_to_zero();
+        b = b.nan_to_zero();
+        recalculate = true;
+    }
+    if recalculate {
+        (
+            &Extended::INFINITY * &(&(&a * &c) - &(&b * &d)),
+            &Extended::INFINITY * &(&(&a * &d) + &(&b * &c)),
+        )
+    } else {
+        (re, im)
+    }
+}
+
+impl Complex {
+    /// Multiplies two [`Complex`] numbers, taking both by reference. Each part of the result is
+    /// rounded to the corresponding precision, using the corresponding rounding mode. A pair of
+    /// [`Ordering`]s is also returned, indicating whether each part of the rounded result is less
+    /// than, equal to, or greater than the corresponding part of the exact result.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// If either input has a part that is not finite, the result is computed as in C99 Annex G:
+    /// each part is computed from the parts of the inputs using the rules for infinities and NaN,
+    /// and if this makes both parts NaN while an input has an infinite part, the result is
+    /// infinite. For example, $(\infty + 0i)(1 + i) = \infty + \infty i$ and $i(\infty + \infty i)
+    /// = -\infty + \infty i$, while both parts of $(\infty + 0i) \cdot 0$ are NaN.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if either rounding mode is `Exact` but the
+    /// corresponding part of the result can't be represented exactly with the corresponding
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::{max, Ordering};
+    ///
+    /// let x = Complex::new(Float::from(1.5), Float::from(-2));
+    /// let y = Complex::new(Float::from(0.25), Float::from(3));
+    /// let (z, o) = x.mul_prec_round(&y, (5, 1), (RoundingMode::Floor, RoundingMode::Ceiling));
+    /// assert_eq!(z.to_string(), "(6.2 4.0)");
+    /// assert_eq!(o, (Ordering::Less, Ordering::Equal));
+    /// ```
+    pub fn mul_prec_round(
+        &self,
+        other: &Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        if let (Some(x), Some(y)) = (self.to_complex_rational(), other.to_complex_rational()) {
+            Complex::from_complex_rational_prec_round(x * y, prec, rm)
+        } else {
+            let (re, im) = mul_non_finite(self, other);
+            Extended::into_complex_prec_round(re, im, prec, rm)
+        }
+    }
+
+    /// Multiplies two [`Complex`] numbers, taking both by reference. Both parts of the result are
+    /// rounded to the specified precision, to nearest. A pair of [`Ordering`]s is also returned,
+    /// indicating whether each part of the rounded result is less than, equal to, or greater than
+    /// the corresponding part of the exact result.
+    ///
+    /// Infinities and NaNs are handled as in C99 Annex G. See [`Complex::mul_prec_round`] for
+    /// details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::new(Float::from(1.5), Float::from(-2));
+    /// let y = Complex::new(Float::from(0.25), Float::from(3));
+    /// assert_eq!(x.mul_prec(&y, 10).0.to_string(), "(6.375 4.0)");
+    /// ```
+    #[inline]
+    pub fn mul_prec(&self, other: &Complex, prec: u64) -> (Complex, (Ordering, Ordering)) {
+        self.mul_prec_round(
+            other,
+            (prec, prec),
+            (RoundingMode::Nearest, RoundingMode::Nearest),
+        )
+    }
+}
+
+impl Mul<Complex> for Complex {
+    type Output = Complex;
+
+    /// Multiplies two [`Complex`] numbers, taking both by value.
+    ///
+    /// Both parts of the result are rounded to nearest, with the largest precision of the four
+    /// parts of the inputs. Infinities and NaNs are handled as in C99 Annex G. See
+    /// [`Complex::mul_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::new(Float::from(1.5), Float::from(-2));
+    /// let y = Complex::new(Float::from(0.25), Float::from(3));
+    /// assert_eq!((x * y).to_string(), "(6.375 4.0)");
+    /// ```
+    #[inline]
+    fn mul(self, other: Complex) -> Complex {
+        &self * &other
+    }
+}
+
+impl<'a> Mul<&'a Complex> for Complex {
+    type Output = Complex;
+
+    /// Multiplies two [`Complex`] numbers, taking the first by value and the second by reference.
+    ///
+    /// Both parts of the result are rounded to nearest, with the largest precision of the four
+    /// parts of the inputs. Infinities and NaNs are handled as in C99 Annex G. See
+    /// [`Complex::mul_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::new(Float::from(1.5), Float::from(-2));
+    /// let y = Complex::new(Float::from(0.25), Float::from(3));
+    /// assert_eq!((x * &y).to_string(), "(6.375 4.0)");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Complex) -> Complex {
+        &self * other
+    }
+}
+
+impl<'a> Mul<Complex> for &'a Complex {
+    type Output = Complex;
+
+    /// Multiplies two [`Complex`] numbers, taking the first by reference and the second by value.
+    ///
+    /// Both parts of the result are rounded to nearest, with the largest precision of the four
+    /// parts of the inputs. Infinities and NaNs are handled as in C99 Annex G. See
+    /// [`Complex::mul_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::new(Float::from(1.5), Float::from(-2));
+    /// let y = Complex::new(Float::from(0.25), Float::from(3));
+    /// assert_eq!((&x * y).to_string(), "(6.375 4.0)");
+    /// ```
+    #[inline]
+    fn mul(self, other: Complex) -> Complex {
+        self * &other
+    }
+}
+
+impl<'a, 'b> Mul<&'a Complex> for &'b Complex {
+    type Output = Complex;
+
+    /// Multiplies two [`Complex`] numbers, taking both by reference.
+    ///
+    /// Both parts of the result are rounded to nearest, with the largest precision of the four
+    /// parts of the inputs. Infinities and NaNs are handled as in C99 Annex G. See
+    /// [`Complex::mul_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::new(Float::from(1.5), Float::from(-2));
+    /// let y = Complex::new(Float::from(0.25), Float::from(3));
+    /// assert_eq!((&x * &y).to_string(), "(6.375 4.0)");
+    /// ```
+    fn mul(self, other: &'a Complex) -> Complex {
+        self.mul_prec(other, max(self.max_prec(), other.max_prec()))
+            .0
+    }
+}
+
+impl MulAssign<Complex> for Complex {
+    /// Multiplies a [`Complex`] number by a [`Complex`] number in place, taking the [`Complex`]
+    /// number on the right-hand side by value.
+    ///
+    /// Infinities and NaNs are handled as in C99 Annex G. See [`Complex::mul_prec_round`] for
+    /// details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Complex::new(Float::from(1.5), Float::from(-2));
+    /// let y = Complex::new(Float::from(0.25), Float::from(3));
+    /// x *= y;
+    /// assert_eq!(x.to_string(), "(6.375 4.0)");
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: Complex) {
+        *self = &*self * &other;
+    }
+}
+
+impl<'a> MulAssign<&'a Complex> for Complex {
+    /// Multiplies a [`Complex`] number by a [`Complex`] number in place, taking the [`Complex`]
+    /// number on the right-hand side by reference.
+    ///
+    /// Infinities and NaNs are handled as in C99 Annex G. See [`Complex::mul_prec_round`] for
+    /// details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Complex::new(Float::from(1.5), Float::from(-2));
+    /// let y = Complex::new(Float::from(0.25), Float::from(3));
+    /// x *= &y;
+    /// assert_eq!(x.to_string(), "(6.375 4.0)");
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: &'a Complex) {
+        *self = &*self * other;
+    }
+}
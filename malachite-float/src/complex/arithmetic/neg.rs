@@ -0,0 +1,124 @@
+use crate::complex::Complex;
+use malachite_base::num::arithmetic::traits::NegAssign;
+use std::ops::Neg;
+
+impl Complex {
+    /// Returns the complex conjugate of a [`Complex`] number, taking it by reference.
+    ///
+    /// $$
+    /// f(a+bi) = a-bi.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let z = Complex::new(Float::from(1.5), Float::from(-2));
+    /// assert_eq!(z.conj().to_string(), "(1.5 2.0)");
+    /// ```
+    pub fn conj(&self) -> Complex {
+        Complex {
+            re: self.re.clone(),
+            im: -&self.im,
+        }
+    }
+}
+
+impl Neg for Complex {
+    type Output = Complex;
+
+    /// Negates a [`Complex`] number, taking it by value.
+    ///
+    /// Both parts are negated, including NaNs, infinities and zeros.
+    ///
+    /// $$
+    /// f(x) = -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let z = Complex::new(Float::from(1.5), Float::from(-2));
+    /// assert_eq!((-z).to_string(), "(-1.5 2.0)");
+    /// ```
+    #[inline]
+    fn neg(mut self) -> Complex {
+        self.neg_assign();
+        self
+    }
+}
+
+impl<'a> Neg for &'a Complex {
+    type Output = Complex;
+
+    /// Negates a [`Complex`] number, taking it by reference.
+    ///
+    /// Both parts are negated, including NaNs, infinities and zeros.
+    ///
+    /// $$
+    /// f(x) = -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let z = Complex::new(Float::from(1.5), Float::from(-2));
+    /// assert_eq!((-&z).to_string(), "(-1.5 2.0)");
+    /// ```
+    fn neg(self) -> Complex {
+        Complex {
+            re: -&self.re,
+            im: -&self.im,
+        }
+    }
+}
+
+impl NegAssign for Complex {
+    /// Negates a [`Complex`] number in place.
+    ///
+    /// Both parts are negated, including NaNs, infinities and zeros.
+    ///
+    /// $$
+    /// x \gets -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::NegAssign;
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let mut z = Complex::new(Float::from(1.5), Float::from(-2));
+    /// z.neg_assign();
+    /// assert_eq!(z.to_string(), "(-1.5 2.0)");
+    /// ```
+    fn neg_assign(&mut self) {
+        self.re.neg_assign();
+        self.im.neg_assign();
+    }
+}
@@ -0,0 +1,387 @@
+use crate::ball::conversion::{ziv_next_prec, ziv_try_round, ZIV_EXTRA_PREC};
+use crate::ball::Ball;
+use crate::complex::arithmetic::abs::arg_ball;
+use crate::complex::arithmetic::exp::exp_non_finite;
+use crate::complex::arithmetic::mul::mul_non_finite;
+use crate::complex::extended::Extended;
+use crate::complex::{Complex, NAN_RESULT};
+use crate::Float;
+use malachite_base::num::arithmetic::traits::{
+    CheckedRoot, CheckedSqrt, NegAssign, Parity, Pow, Square,
+};
+use malachite_base::num::basic::traits::{NaN, NegativeOne, One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_q::complex::ComplexRational;
+use malachite_q::Rational;
+use std::cmp::{max, Ordering};
+
+// Powers with rational exponents whose numerators and denominators have absolute values at most
+// this are computed exactly, when the result is a `ComplexRational`.
+const MAX_EXACT_POW_EXPONENT: u64 = 1 << 12;
+
+// Some powers, like (-4)^(1/4) = 1 + i, have parts that are exactly representable, but which can't
+// be identified using `Ball`s. Once the working precision exceeds the larger of the two output
+// precisions by this factor, plus `POW_EXTRA_PREC`, a part that still can't be rounded is checked
+// for exactness by `round_exact_part`.
+const POW_PREC_FACTOR: u64 = 4;
+
+const POW_EXTRA_PREC: u64 = 256;
+
+// Returns the principal square root of `z`, if it is a `ComplexRational`.
+fn exact_sqrt(z: &ComplexRational) -> Option<ComplexRational> {
+    let abs = z.norm().checked_sqrt()?;
+    let re = ((&abs + z.re()) >> 1u64).checked_sqrt()?;
+    let mut im = ((abs - z.re()) >> 1u64).checked_sqrt()?;
+    if *z.im() < 0u32 {
+        im.neg_assign();
+    }
+    Some(ComplexRational::new(re, im))
+}
+
+// Returns $p$ and $q$ such that `w` is $p/q$ in lowest terms, if $|p|, q \leq$
+// `MAX_EXACT_POW_EXPONENT`.
+fn small_fraction(w: &Rational) -> Option<(i64, u64)> {
+    let p = u64::try_from(w.numerator_ref()).ok()?;
+    let q = u64::try_from(w.denominator_ref()).ok()?;
+    if p > MAX_EXACT_POW_EXPONENT || q > MAX_EXACT_POW_EXPONENT {
+        return None;
+    }
+    let p = i64::exact_from(p);
+    Some((if *w < 0u32 { -p } else { p }, q))
+}
+
+// Returns the principal value of `z^w`, where `z` is nonzero, if it can be computed exactly: that
+// is, if `w` is a fraction $p/q$ with small $p$ and $q$, and $z^{1/q}$ is a `ComplexRational`. This
+// is only checked if $q$ is 1 or 2, or if `z` is a positive real number.
+fn exact_pow(z: &ComplexRational, w: &Rational) -> Option<ComplexRational> {
+    let (p, q) = small_fraction(w)?;
+    let root = if q == 1 {
+        z.clone()
+    } else if z.is_real() && *z.re() > 0u32 {
+        ComplexRational::from(z.re().checked_root(q)?)
+    } else if q == 2 {
+        exact_sqrt(z)?
+    } else {
+        return None;
+    };
+    Some(root.pow(p))
+}
+
+// Returns the only number that can keep a `Ball` from being rounded to precision `prec`, once the
+// `Ball` is much narrower than the spacing of `Float`s with precision `prec + 1`: zero, if the
+// `Ball` contains zero, and otherwise the `Float` with precision `prec + 1` closest to the `Ball`'s
+// midpoint, since every `Float` with precision `prec`, and every midpoint between two of them, has
+// precision `prec + 1`. Returns `None` if the `Ball` contains no such number.
+fn rounding_boundary(x: &Ball, prec: u64) -> Option<Rational> {
+    let (lower, upper) = x.to_rational_interval()?;
+    if lower <= 0u32 && upper >= 0u32 {
+        return Some(Rational::ZERO);
+    }
+    let boundary =
+        Rational::exact_from(&Float::from_rational_prec((&lower + &upper) >> 1u64, prec + 1).0);
+    if lower <= boundary && boundary <= upper {
+        Some(boundary)
+    } else {
+        None
+    }
+}
+
+// Computes $(c + \tau)^q$ in $\mathbb{Q}[\tau]/(\tau^2 + u)$, returning $a$ and $b$ such that
+// $(c + \tau)^q = a + b\tau$.
+fn pow_mod_quadratic(c: &Rational, u: &Rational, mut q: u64) -> (Rational, Rational) {
+    let mul = |(a, b): &(Rational, Rational), (c, d): &(Rational, Rational)| {
+        (a * c - b * d * u, a * d + b * c)
+    };
+    let mut result = (Rational::ONE, Rational::ZERO);
+    let mut power = (c.clone(), Rational::ONE);
+    loop {
+        if q.odd() {
+            result = mul(&result, &power);
+        }
+        q >>= 1;
+        if q == 0 {
+            return result;
+        }
+        power = mul(&power, &power);
+    }
+}
+
+// Determines whether $\sqrt{u}$ is in $[a, b]$.
+fn sqrt_in_interval(u: &Rational, a: &Rational, b: &Rational) -> bool {
+    *b >= 0u32 && b.square() >= *u && (*a <= 0u32 || a.square() <= *u)
+}
+
+// Determines whether the real part of $\zeta$ is $c$, where $\zeta$ is a $q$th root of $s \neq 0$
+// and `re` and `im` contain the real and imaginary parts of $\zeta$.
+//
+// Every $q$th root of $s$ has absolute value $\sqrt{\rho}$, where $\rho = |s|^{2/q}$, and the
+// distance between two of them is at least $2\sqrt{\rho} \sin(\pi/q) \geq 4\sqrt{\rho}/q$. If
+// the real part of $\zeta$ is $c$, $\zeta = c \pm ti$, where $t = \sqrt{\rho - c^2}$. When $\rho$
+// is rational, whether $c + ti$ or $c - ti$ is a $q$th root of $s$ can be decided with rational
+// arithmetic, and if the box containing $\zeta$ is smaller than the distance between roots, the
+// root that lies in the box is $\zeta$.
+//
+// Returns `None` if $\rho$ is irrational, and otherwise whether the real part of $\zeta$ was
+// shown to be $c$; if it wasn't, narrower `Ball`s may still show it.
+fn is_real_part_of_root(
+    s: &ComplexRational,
+    q: u64,
+    c: &Rational,
+    re: &Ball,
+    im: &Ball,
+) -> Option<bool> {
+    let rho = s.norm().checked_root(q)?;
+    let (re_lower, re_upper) = re.to_rational_interval()?;
+    let (im_lower, im_upper) = im.to_rational_interval()?;
+    let diameter_squared = (re_upper - re_lower).square() + (&im_upper - &im_lower).square();
+    let u = &rho - c.square();
+    if u < 0u32 || diameter_squared * Rational::from(q).square() >= rho << 4u64 {
+        return Some(false);
+    }
+    // $(c \pm ti)^q = a \pm bti$, so $c \pm ti$ is a root of $s$ if $a$ is the real part of $s$ and
+    // $\pm bt$ is the imaginary part.
+    let (a, b) = pow_mod_quadratic(c, &u, q);
+    if a != *s.re() {
+        return Some(false);
+    }
+    let positive_t_in_box = || sqrt_in_interval(&u, &im_lower, &im_upper);
+    let negative_t_in_box = || sqrt_in_interval(&u, &-&im_upper, &-&im_lower);
+    Some(if *s.im() == 0u32 {
+        (b == 0u32 || u == 0u32) && (positive_t_in_box() || negative_t_in_box())
+    } else if b != 0u32 && &u * (&b).square() == s.im().square() {
+        if (*s.im() > 0u32) == (b > 0u32) {
+            positive_t_in_box()
+        } else {
+            negative_t_in_box()
+        }
+    } else {
+        false
+    })
+}
+
+// Handles a part of $z^w$ that can't be rounded once the working precision exceeds its limit.
+// `fraction` is $(p, q)$ if $w = p/q$ (see `small_fraction`), and `re` and `im` contain the real
+// and imaginary parts of $z^w$. If `imaginary` is true, the imaginary part is handled, as the real
+// part of $-iz^w$.
+//
+// The part is exactly representable or halfway between two representable numbers, or else it
+// would have been rounded. If that value can be found and verified, it is rounded; if it can be
+// shown not to be the part, `None` is returned, so that the working precision is increased
+// further. Otherwise, including when the `Ball`s are indeterminate because the result is too large,
+// the part is NaN.
+fn round_exact_part(
+    z: &ComplexRational,
+    fraction: Option<(i64, u64)>,
+    imaginary: bool,
+    re: &Ball,
+    im: &Ball,
+    prec: u64,
+    rm: RoundingMode,
+) -> Option<(Float, Ordering)> {
+    let (p, q) = match fraction {
+        Some(fraction) if re.is_finite() && im.is_finite() => fraction,
+        _ => return Some((Float::NAN, Ordering::Equal)),
+    };
+    let mut s = z.pow(p);
+    let (re, im) = if imaginary {
+        s *= ComplexRational::new(Rational::ZERO, Rational::NEGATIVE_ONE).pow(q);
+        (im.clone(), -re.clone())
+    } else {
+        (re.clone(), im.clone())
+    };
+    let c = rounding_boundary(&re, prec)?;
+    match is_real_part_of_root(&s, q, &c, &re, &im) {
+        Some(true) => Some(Float::from_rational_prec_round(c, prec, rm)),
+        Some(false) => None,
+        None => Some((Float::NAN, Ordering::Equal)),
+    }
+}
+
+impl Complex {
+    /// Raises a [`Complex`] number to a [`Complex`] power, taking both by reference. Each part of
+    /// the result is rounded to the corresponding precision, using the corresponding rounding
+    /// mode. A pair of [`Ordering`]s is also returned, indicating whether each part of the rounded
+    /// result is less than, equal to, or greater than the corresponding part of the exact result.
+    ///
+    /// The principal value is computed:
+    /// $$
+    /// f(z, w) = z^w = e^{w \ln z},
+    /// $$
+    /// where $\ln$ is the principal natural logarithm (see [`Complex::log_prec_round`]). $z^0$ is 1
+    /// for every $z$, including zero, infinities, and NaN. If $w \neq 0$, $0^w$ is 0 if the real
+    /// part of $w$ is positive, and NaN otherwise.
+    ///
+    /// If $w$ is a real fraction $p/q$ with $|p|, q \leq 2^{12}$, the power is computed exactly and
+    /// then rounded whenever this is possible: when $q = 1$, when $q = 2$ and $\sqrt{z}$ has
+    /// rational parts, or when $z$ is a positive real number with a rational $q$th root. This is
+    /// how results like $(-4)^{1/2} = 2i$ are found. Otherwise, the result is computed with
+    /// increasing precision until it can be correctly rounded. In the rare case that a part of the
+    /// result is exactly representable, or halfway between two representable numbers, such as the
+    /// parts of $(-4)^{1/4} = 1 + i$, it can't be told apart from nearby numbers this way. Once the
+    /// working precision reaches four times the output precision, such a part is checked exactly
+    /// instead: if $w = p/q$ as above and $|z|^{2p/q}$ is rational, the candidate value $c$ is
+    /// verified by checking, with rational arithmetic, that $c \pm \sqrt{|z|^{2p/q} - c^2}\,i$ is a
+    /// $q$th root of $z^p$ and is the one that was approximated. If the part can't be verified in
+    /// this way, it is NaN rather than possibly incorrectly rounded.
+    ///
+    /// If either part of either input is not finite, the result is computed as $e^{w \ln z}$, with
+    /// the rules for infinities and NaNs of [`Complex::log_prec_round`],
+    /// [`Complex::mul_prec_round`], and [`Complex::exp_prec_round`], as in MPC: for example,
+    /// $2^{-\infty + 0i} = 0$, and $(\infty + 0i)^2 = \infty + \text{NaN}i$. Both parts of the
+    /// result are NaN if it is too large to be computed.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log (nm) \log\log (nm) + n^2 \log n \log\log n)$
+    ///
+    /// $M(n, m) = O(nm \log (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`, and $m$ is the absolute value of the exponent's
+    /// numerator, if the power is computed exactly, or 1 otherwise.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if either rounding mode is `Exact` but the
+    /// corresponding part of the result is found to be not exactly representable with the
+    /// corresponding precision. A part that is NaN because it couldn't be verified doesn't cause a
+    /// panic.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let z = Complex::new(Float::from(1), Float::from(1));
+    /// let w = Complex::new(Float::from(8), Float::from(0));
+    /// let (p, o) = z.pow_prec_round(&w, (10, 10), (RoundingMode::Exact, RoundingMode::Exact));
+    /// assert_eq!(p.to_string(), "(16.0 0.0)");
+    /// assert_eq!(o, (Ordering::Equal, Ordering::Equal));
+    ///
+    /// let z = Complex::new(Float::from(-4), Float::from(0));
+    /// let w = Complex::new(Float::from(0.5), Float::from(0));
+    /// let (p, o) = z.pow_prec_round(&w, (10, 10), (RoundingMode::Floor, RoundingMode::Floor));
+    /// assert_eq!(p.to_string(), "(0.0 2.0)");
+    /// assert_eq!(o, (Ordering::Equal, Ordering::Equal));
+    /// ```
+    pub fn pow_prec_round(
+        &self,
+        other: &Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        if other.re.is_zero() && other.im.is_zero() {
+            return Complex::from_complex_rational_prec_round(ComplexRational::ONE, prec, rm);
+        } else if self.re.is_zero() && self.im.is_zero() {
+            return if other.re > 0u32 && !other.im.is_nan() {
+                Complex::from_complex_rational_prec_round(ComplexRational::ZERO, prec, rm)
+            } else {
+                NAN_RESULT
+            };
+        }
+        let (z, w) =
+            if let (Some(z), Some(w)) = (self.to_complex_rational(), other.to_complex_rational()) {
+                (z, w)
+            } else {
+                // Only the signs of the parts of ln z matter, since the parts of w ln z are
+                // infinite or NaN.
+                let (re, im) = mul_non_finite(&self.log_prec(1).0, other);
+                let (re, im) = exp_non_finite(&re, &im);
+                return Extended::into_complex_prec_round(re, im, prec, rm);
+            };
+        if w.is_real() {
+            if let Some(p) = exact_pow(&z, w.re()) {
+                return Complex::from_complex_rational_prec_round(p, prec, rm);
+            }
+        }
+        let fraction = if w.is_real() {
+            small_fraction(w.re())
+        } else {
+            None
+        };
+        let norm = z.norm();
+        let (x, y) = (z.re(), z.im());
+        let (u, v) = (w.re(), w.im());
+        let max_prec = max(prec.0, prec.1) * POW_PREC_FACTOR + POW_EXTRA_PREC;
+        let mut working_prec = max(prec.0, prec.1) + ZIV_EXTRA_PREC;
+        let mut re = None;
+        let mut im = None;
+        loop {
+            // w ln z = (u + vi)(ln |z| + i arg z) = a + bi
+            let log_abs = Ball::from_rational_prec(&norm, working_prec)
+                .log_prec(working_prec)
+                .mul_power_of_2(-1);
+            let arg = arg_ball(x, y, working_prec);
+            let u = Ball::from_rational_prec(u, working_prec);
+            let v = Ball::from_rational_prec(v, working_prec);
+            let a = u
+                .mul_prec(&log_abs, working_prec)
+                .sub_prec(&v.mul_prec(&arg, working_prec), working_prec);
+            let b = u
+                .mul_prec(&arg, working_prec)
+                .add_prec(&v.mul_prec(&log_abs, working_prec), working_prec);
+            let exp = a.exp_prec(working_prec);
+            let (sin, cos) = b.sin_cos_prec(working_prec);
+            let re_ball = exp.mul_prec(&cos, working_prec);
+            let im_ball = exp.mul_prec(&sin, working_prec);
+            if re.is_none() {
+                re = ziv_try_round(&re_ball, prec.0, rm.0);
+            }
+            if im.is_none() {
+                im = ziv_try_round(&im_ball, prec.1, rm.1);
+            }
+            if working_prec > max_prec {
+                if re.is_none() {
+                    re = round_exact_part(&z, fraction, false, &re_ball, &im_ball, prec.0, rm.0);
+                }
+                if im.is_none() {
+                    im = round_exact_part(&z, fraction, true, &re_ball, &im_ball, prec.1, rm.1);
+                }
+            }
+            if let (Some((re, re_o)), Some((im, im_o))) = (&re, &im) {
+                return (Complex::new(re.clone(), im.clone()), (*re_o, *im_o));
+            }
+            working_prec = ziv_next_prec(working_prec);
+        }
+    }
+
+    /// Raises a [`Complex`] number to a [`Complex`] power, taking both by reference. Both parts of
+    /// the result are rounded to the specified precision, to nearest. A pair of [`Ordering`]s is
+    /// also returned, indicating whether each part of the rounded result is less than, equal to,
+    /// or greater than the corresponding part of the exact result.
+    ///
+    /// Infinities and NaNs are handled as in C99 Annex G, and both parts of the result are NaN if
+    /// it is too large to be computed. See [`Complex::pow_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log (nm) \log\log (nm) + n^2 \log n \log\log n)$
+    ///
+    /// $M(n, m) = O(nm \log (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`, and $m$ is the absolute value of the exponent's
+    /// numerator, if the power is computed exactly, or 1 otherwise.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    ///
+    /// // i^i = e^(-pi/2)
+    /// assert_eq!(
+    ///     Complex::I.pow_prec(&Complex::I, 100).0.to_string(),
+    ///     "(0.2078795763507619085469556198349 0.0)"
+    /// );
+    /// ```
+    #[inline]
+    pub fn pow_prec(&self, other: &Complex, prec: u64) -> (Complex, (Ordering, Ordering)) {
+        self.pow_prec_round(
+            other,
+            (prec, prec),
+            (RoundingMode::Nearest, RoundingMode::Nearest),
+        )
+    }
+}
@@ -0,0 +1,123 @@
+use crate::ball::Ball;
+use crate::complex::{ziv_round_complex, Complex, NAN_RESULT};
+use malachite_base::num::arithmetic::traits::Abs;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::rounding_modes::RoundingMode;
+use std::cmp::Ordering;
+
+impl Complex {
+    /// Computes the principal square root of a [`Complex`] number, taking it by reference. Each
+    /// part of the result is rounded to the corresponding precision, using the corresponding
+    /// rounding mode. A pair of [`Ordering`]s is also returned, indicating whether each part of the
+    /// rounded result is less than, equal to, or greater than the corresponding part of the exact
+    /// result.
+    ///
+    /// $$
+    /// f(z) = \sqrt{z}.
+    /// $$
+    ///
+    /// The principal square root has a nonnegative real part, and its imaginary part has the same
+    /// sign as the imaginary part of the input. If either part of the input is not finite, both
+    /// parts of the result are NaN. Infinities are not propagated as they are in C99 Annex G and
+    /// MPC: for example, $\sqrt{\infty + 0i}$ is NaN, not $\infty$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if either rounding mode is `Exact` but the
+    /// corresponding part of the result can't be represented exactly with the corresponding
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let z = Complex::new(Float::from(-4), Float::from(0));
+    /// let (w, o) = z.sqrt_prec_round((10, 10), (RoundingMode::Floor, RoundingMode::Ceiling));
+    /// assert_eq!(w.to_string(), "(0.0 2.0)");
+    /// assert_eq!(o, (Ordering::Equal, Ordering::Equal));
+    /// ```
+    pub fn sqrt_prec_round(
+        &self,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        let z = if let Some(z) = self.to_complex_rational() {
+            z
+        } else {
+            return NAN_RESULT;
+        };
+        let norm = z.norm();
+        let (x, y) = z.into_re_im();
+        ziv_round_complex(prec, rm, |working_prec| {
+            if norm == 0u32 {
+                return (Ball::ZERO, Ball::ZERO);
+            }
+            let abs = Ball::from_rational_prec(&norm, working_prec).sqrt_prec(working_prec);
+            let x_ball = Ball::from_rational_prec(&x, working_prec);
+            // For a + bi = sqrt(x + yi), a^2 = (|z| + x) / 2, b^2 = (|z| - x) / 2, and 2ab = y. The
+            // larger of |a| and |b| is computed directly, to avoid cancellation.
+            if x >= 0u32 {
+                let a = abs
+                    .add_prec(&x_ball, working_prec)
+                    .mul_power_of_2(-1)
+                    .sqrt_prec(working_prec);
+                let b = Ball::from_rational_prec(&y, working_prec)
+                    .div_prec(&a.mul_power_of_2(1), working_prec);
+                (a, b)
+            } else {
+                let b_abs = abs
+                    .sub_prec(&x_ball, working_prec)
+                    .mul_power_of_2(-1)
+                    .sqrt_prec(working_prec);
+                let a = Ball::from_rational_prec(&(&y).abs(), working_prec)
+                    .div_prec(&b_abs.mul_power_of_2(1), working_prec);
+                (a, if y >= 0u32 { b_abs } else { -b_abs })
+            }
+        })
+    }
+
+    /// Computes the principal square root of a [`Complex`] number, taking it by reference. Both
+    /// parts of the result are rounded to the specified precision, to nearest. A pair of
+    /// [`Ordering`]s is also returned, indicating whether each part of the rounded result is less
+    /// than, equal to, or greater than the corresponding part of the exact result.
+    ///
+    /// If either part of the input is not finite, both parts of the result are NaN. See
+    /// [`Complex::sqrt_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let z = Complex::new(Float::from(0), Float::from(2));
+    /// assert_eq!(
+    ///     z.sqrt_prec(100).0.to_string(),
+    ///     "(1.0 1.0)"
+    /// );
+    /// ```
+    #[inline]
+    pub fn sqrt_prec(&self, prec: u64) -> (Complex, (Ordering, Ordering)) {
+        self.sqrt_prec_round((prec, prec), (RoundingMode::Nearest, RoundingMode::Nearest))
+    }
+}
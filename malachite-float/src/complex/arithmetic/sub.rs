@@ -0,0 +1,290 @@
+use crate::complex::extended::Extended;
+use crate::complex::Complex;
+use malachite_base::rounding_modes::RoundingMode;
+use std::cmp::{max, Ordering};
+use std::ops::{Sub, SubAssign};
+
+impl Complex {
+    /// Subtracts a [`Complex`] number by another, taking both by reference. Each part of the result
+    /// is rounded to the corresponding precision, using the corresponding rounding mode. A pair of
+    /// [`Ordering`]s is also returned, indicating whether each part of the rounded result is less
+    /// than, equal to, or greater than the corresponding part of the exact result.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// Infinities and NaNs in either input are propagated part by part, as in C99 Annex G and MPC:
+    /// for example, $(\infty + 0i) - 1 = \infty + 0i$, and the real part of $(\infty + 0i) -
+    /// (\infty + 0i)$ is NaN.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if either rounding mode is `Exact` but the
+    /// corresponding part of the result can't be represented exactly with the corresponding
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::{max, Ordering};
+    ///
+    /// let x = Complex::new(Float::from(1.5), Float::from(-2));
+    /// let y = Complex::new(Float::from(0.25), Float::from(3));
+    /// let (z, o) = x.sub_prec_round(&y, (5, 1), (RoundingMode::Floor, RoundingMode::Ceiling));
+    /// assert_eq!(z.to_string(), "(1.25 -4.0)");
+    /// assert_eq!(o, (Ordering::Equal, Ordering::Greater));
+    /// ```
+    pub fn sub_prec_round(
+        &self,
+        other: &Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        if let (Some(x), Some(y)) = (self.to_complex_rational(), other.to_complex_rational()) {
+            Complex::from_complex_rational_prec_round(x - y, prec, rm)
+        } else {
+            let (a, b) = self.to_extended();
+            let (c, d) = other.to_extended();
+            Extended::into_complex_prec_round(&a - &c, &b - &d, prec, rm)
+        }
+    }
+
+    /// Subtracts a [`Complex`] number by another, taking both by reference. Both parts of the
+    /// result are rounded to the specified precision, to nearest. A pair of [`Ordering`]s is also
+    /// returned, indicating whether each part of the rounded result is less than, equal to, or
+    /// greater than the corresponding part of the exact result.
+    ///
+    /// Infinities and NaNs are propagated part by part. See [`Complex::sub_prec_round`] for
+    /// details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::new(Float::from(1.5), Float::from(-2));
+    /// let y = Complex::new(Float::from(0.25), Float::from(3));
+    /// assert_eq!(x.sub_prec(&y, 10).0.to_string(), "(1.25 -5.0)");
+    /// ```
+    #[inline]
+    pub fn sub_prec(&self, other: &Complex, prec: u64) -> (Complex, (Ordering, Ordering)) {
+        self.sub_prec_round(
+            other,
+            (prec, prec),
+            (RoundingMode::Nearest, RoundingMode::Nearest),
+        )
+    }
+}
+
+impl Sub<Complex> for Complex {
+    type Output = Complex;
+
+    /// Subtracts a [`Complex`] number by another, taking both by value.
+    ///
+    /// Both parts of the result are rounded to nearest, with the largest precision of the four
+    /// parts of the inputs. Infinities and NaNs are propagated part by part. See
+    /// [`Complex::sub_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::new(Float::from(1.5), Float::from(-2));
+    /// let y = Complex::new(Float::from(0.25), Float::from(3));
+    /// assert_eq!((x - y).to_string(), "(1.25 -5.0)");
+    /// ```
+    #[inline]
+    fn sub(self, other: Complex) -> Complex {
+        &self - &other
+    }
+}
+
+impl<'a> Sub<&'a Complex> for Complex {
+    type Output = Complex;
+
+    /// Subtracts a [`Complex`] number by another, taking the first by value and the second by
+    /// reference.
+    ///
+    /// Both parts of the result are rounded to nearest, with the largest precision of the four
+    /// parts of the inputs. Infinities and NaNs are propagated part by part. See
+    /// [`Complex::sub_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::new(Float::from(1.5), Float::from(-2));
+    /// let y = Complex::new(Float::from(0.25), Float::from(3));
+    /// assert_eq!((x - &y).to_string(), "(1.25 -5.0)");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'a Complex) -> Complex {
+        &self - other
+    }
+}
+
+impl<'a> Sub<Complex> for &'a Complex {
+    type Output = Complex;
+
+    /// Subtracts a [`Complex`] number by another, taking the first by reference and the second by
+    /// value.
+    ///
+    /// Both parts of the result are rounded to nearest, with the largest precision of the four
+    /// parts of the inputs. Infinities and NaNs are propagated part by part. See
+    /// [`Complex::sub_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::new(Float::from(1.5), Float::from(-2));
+    /// let y = Complex::new(Float::from(0.25), Float::from(3));
+    /// assert_eq!((&x - y).to_string(), "(1.25 -5.0)");
+    /// ```
+    #[inline]
+    fn sub(self, other: Complex) -> Complex {
+        self - &other
+    }
+}
+
+impl<'a, 'b> Sub<&'a Complex> for &'b Complex {
+    type Output = Complex;
+
+    /// Subtracts a [`Complex`] number by another, taking both by reference.
+    ///
+    /// Both parts of the result are rounded to nearest, with the largest precision of the four
+    /// parts of the inputs. Infinities and NaNs are propagated part by part. See
+    /// [`Complex::sub_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::new(Float::from(1.5), Float::from(-2));
+    /// let y = Complex::new(Float::from(0.25), Float::from(3));
+    /// assert_eq!((&x - &y).to_string(), "(1.25 -5.0)");
+    /// ```
+    fn sub(self, other: &'a Complex) -> Complex {
+        self.sub_prec(other, max(self.max_prec(), other.max_prec()))
+            .0
+    }
+}
+
+impl SubAssign<Complex> for Complex {
+    /// Subtracts a [`Complex`] number by a [`Complex`] number in place, taking the [`Complex`]
+    /// number on the right-hand side by value.
+    ///
+    /// Infinities and NaNs are propagated part by part. See [`Complex::sub_prec_round`] for
+    /// details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Complex::new(Float::from(1.5), Float::from(-2));
+    /// let y = Complex::new(Float::from(0.25), Float::from(3));
+    /// x -= y;
+    /// assert_eq!(x.to_string(), "(1.25 -5.0)");
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: Complex) {
+        *self = &*self - &other;
+    }
+}
+
+impl<'a> SubAssign<&'a Complex> for Complex {
+    /// Subtracts a [`Complex`] number by a [`Complex`] number in place, taking the [`Complex`]
+    /// number on the right-hand side by reference.
+    ///
+    /// Infinities and NaNs are propagated part by part. See [`Complex::sub_prec_round`] for
+    /// details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Complex::new(Float::from(1.5), Float::from(-2));
+    /// let y = Complex::new(Float::from(0.25), Float::from(3));
+    /// x -= &y;
+    /// assert_eq!(x.to_string(), "(1.25 -5.0)");
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: &'a Complex) {
+        *self = &*self - other;
+    }
+}
@@ -0,0 +1,316 @@
+use crate::ball::Ball;
+use crate::complex::arithmetic::exp::MAX_EXP_ARGUMENT_LOG;
+use crate::complex::{ziv_round_complex, Complex, NAN_RESULT};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+
+// Returns `Ball`s containing $\sin x$, $\cos x$, $\sinh y$, and $\cosh y$.
+fn sin_cos_sinh_cosh(x: &Rational, y: &Rational, prec: u64) -> (Ball, Ball, Ball, Ball) {
+    let (sin, cos) = Ball::from_rational_prec(x, prec).sin_cos_prec(prec);
+    let y = Ball::from_rational_prec(y, prec);
+    let exp = y.exp_prec(prec);
+    let exp_neg = (-y).exp_prec(prec);
+    let sinh = exp.sub_prec(&exp_neg, prec).mul_power_of_2(-1);
+    let cosh = exp.add_prec(&exp_neg, prec).mul_power_of_2(-1);
+    (sin, cos, sinh, cosh)
+}
+
+// Returns the parts of a `Complex` as `Rational`s, or `None` if either part is not finite or if
+// the absolute value of the imaginary part, multiplied by `scale`, is too large for its hyperbolic
+// functions to be computed.
+fn trig_args(z: &Complex, scale: u64) -> Option<(Rational, Rational)> {
+    let (x, y) = z.to_complex_rational()?.into_re_im();
+    let (x, y) = (x * Rational::from(scale), y * Rational::from(scale));
+    if y != 0u32 && y.floor_log_base_2_abs() >= MAX_EXP_ARGUMENT_LOG {
+        None
+    } else {
+        Some((x, y))
+    }
+}
+
+impl Complex {
+    /// Computes the sine of a [`Complex`] number, taking it by reference. Each part of the
+    /// result is rounded to the corresponding precision, using the corresponding rounding mode. A
+    /// pair of [`Ordering`]s is also returned, indicating whether each part of the rounded result
+    /// is less than, equal to, or greater than the corresponding part of the exact result.
+    ///
+    /// $$
+    /// f(a+bi) = \sin a \cosh b + i \cos a \sinh b.
+    /// $$
+    ///
+    /// If either part of the input is not finite, or if the absolute value of the imaginary part is
+    /// at least $2^{50}$, both parts of the result are NaN. Infinities are not propagated as they
+    /// are in C99 Annex G and MPC: for example, $\sin(\infty i)$ is NaN, not $\infty i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if either rounding mode is `Exact` but the
+    /// corresponding part of the result can't be represented exactly with the corresponding
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let z = Complex::new(Float::from(1), Float::from(0));
+    /// let (w, o) = z.sin_prec_round((10, 10), (RoundingMode::Floor, RoundingMode::Ceiling));
+    /// assert_eq!(w.to_string(), "(0.841 0.0)");
+    /// assert_eq!(o, (Ordering::Less, Ordering::Equal));
+    /// ```
+    pub fn sin_prec_round(
+        &self,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        let (x, y) = if let Some(p) = trig_args(self, 1) {
+            p
+        } else {
+            return NAN_RESULT;
+        };
+        ziv_round_complex(prec, rm, |working_prec| {
+            let (sin, cos, sinh, cosh) = sin_cos_sinh_cosh(&x, &y, working_prec);
+            (
+                sin.mul_prec(&cosh, working_prec),
+                cos.mul_prec(&sinh, working_prec),
+            )
+        })
+    }
+
+    /// Computes the sine of a [`Complex`] number, taking it by reference. Both parts of the
+    /// result are rounded to the specified precision, to nearest. A pair of [`Ordering`]s is also
+    /// returned, indicating whether each part of the rounded result is less than, equal to, or
+    /// greater than the corresponding part of the exact result.
+    ///
+    /// If either part of the input is not finite, or if the absolute value of the imaginary part is
+    /// at least $2^{50}$, both parts of the result are NaN. See [`Complex::sin_prec_round`] for
+    /// details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let z = Complex::new(Float::from(1), Float::from(1));
+    /// assert_eq!(
+    ///     z.sin_prec(100).0.to_string(),
+    ///     "(1.298457581415977294826042365808 0.634963914784736108255082202991)"
+    /// );
+    /// ```
+    #[inline]
+    pub fn sin_prec(&self, prec: u64) -> (Complex, (Ordering, Ordering)) {
+        self.sin_prec_round((prec, prec), (RoundingMode::Nearest, RoundingMode::Nearest))
+    }
+}
+
+impl Complex {
+    /// Computes the cosine of a [`Complex`] number, taking it by reference. Each part of the
+    /// result is rounded to the corresponding precision, using the corresponding rounding mode. A
+    /// pair of [`Ordering`]s is also returned, indicating whether each part of the rounded result
+    /// is less than, equal to, or greater than the corresponding part of the exact result.
+    ///
+    /// $$
+    /// f(a+bi) = \cos a \cosh b - i \sin a \sinh b.
+    /// $$
+    ///
+    /// If either part of the input is not finite, or if the absolute value of the imaginary part is
+    /// at least $2^{50}$, both parts of the result are NaN. Infinities are not propagated as they
+    /// are in C99 Annex G and MPC: for example, $\cos(\infty i)$ is NaN, not $\infty$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if either rounding mode is `Exact` but the
+    /// corresponding part of the result can't be represented exactly with the corresponding
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let z = Complex::new(Float::from(0), Float::from(1));
+    /// let (w, o) = z.cos_prec_round((10, 10), (RoundingMode::Floor, RoundingMode::Ceiling));
+    /// assert_eq!(w.to_string(), "(1.543 0.0)");
+    /// assert_eq!(o, (Ordering::Less, Ordering::Equal));
+    /// ```
+    pub fn cos_prec_round(
+        &self,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        let (x, y) = if let Some(p) = trig_args(self, 1) {
+            p
+        } else {
+            return NAN_RESULT;
+        };
+        ziv_round_complex(prec, rm, |working_prec| {
+            let (sin, cos, sinh, cosh) = sin_cos_sinh_cosh(&x, &y, working_prec);
+            (
+                cos.mul_prec(&cosh, working_prec),
+                -sin.mul_prec(&sinh, working_prec),
+            )
+        })
+    }
+
+    /// Computes the cosine of a [`Complex`] number, taking it by reference. Both parts of the
+    /// result are rounded to the specified precision, to nearest. A pair of [`Ordering`]s is also
+    /// returned, indicating whether each part of the rounded result is less than, equal to, or
+    /// greater than the corresponding part of the exact result.
+    ///
+    /// If either part of the input is not finite, or if the absolute value of the imaginary part is
+    /// at least $2^{50}$, both parts of the result are NaN. See [`Complex::cos_prec_round`] for
+    /// details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let z = Complex::new(Float::from(1), Float::from(1));
+    /// assert_eq!(
+    ///     z.cos_prec(100).0.to_string(),
+    ///     "(0.833730025131149048883885394335 -0.988897705762865096382129540893)"
+    /// );
+    /// ```
+    #[inline]
+    pub fn cos_prec(&self, prec: u64) -> (Complex, (Ordering, Ordering)) {
+        self.cos_prec_round((prec, prec), (RoundingMode::Nearest, RoundingMode::Nearest))
+    }
+}
+
+impl Complex {
+    /// Computes the tangent of a [`Complex`] number, taking it by reference. Each part of the
+    /// result is rounded to the corresponding precision, using the corresponding rounding mode. A
+    /// pair of [`Ordering`]s is also returned, indicating whether each part of the rounded result
+    /// is less than, equal to, or greater than the corresponding part of the exact result.
+    ///
+    /// $$
+    /// f(a+bi) = \frac{\sin 2a + i \sinh 2b}{\cos 2a + \cosh 2b}.
+    /// $$
+    ///
+    /// If either part of the input is not finite, or if the absolute value of the imaginary part is
+    /// at least $2^{49}$, both parts of the result are NaN. Infinities are not propagated as they
+    /// are in C99 Annex G and MPC: for example, $\tan(\infty i)$ is NaN, not $i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if either rounding mode is `Exact` but the
+    /// corresponding part of the result can't be represented exactly with the corresponding
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let z = Complex::new(Float::from(0), Float::from(1));
+    /// let (w, o) = z.tan_prec_round((10, 10), (RoundingMode::Floor, RoundingMode::Ceiling));
+    /// assert_eq!(w.to_string(), "(0.0 0.762)");
+    /// assert_eq!(o, (Ordering::Equal, Ordering::Greater));
+    /// ```
+    pub fn tan_prec_round(
+        &self,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        let (x, y) = if let Some(p) = trig_args(self, 2) {
+            p
+        } else {
+            return NAN_RESULT;
+        };
+        ziv_round_complex(prec, rm, |working_prec| {
+            let (sin, cos, sinh, cosh) = sin_cos_sinh_cosh(&x, &y, working_prec);
+            let denominator = cos.add_prec(&cosh, working_prec);
+            (
+                sin.div_prec(&denominator, working_prec),
+                sinh.div_prec(&denominator, working_prec),
+            )
+        })
+    }
+
+    /// Computes the tangent of a [`Complex`] number, taking it by reference. Both parts of the
+    /// result are rounded to the specified precision, to nearest. A pair of [`Ordering`]s is also
+    /// returned, indicating whether each part of the rounded result is less than, equal to, or
+    /// greater than the corresponding part of the exact result.
+    ///
+    /// If either part of the input is not finite, or if the absolute value of the imaginary part is
+    /// at least $2^{49}$, both parts of the result are NaN. See [`Complex::tan_prec_round`] for
+    /// details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let z = Complex::new(Float::from(1), Float::from(1));
+    /// assert_eq!(
+    ///     z.tan_prec(100).0.to_string(),
+    ///     "(0.2717525853195117165288437224987 1.083923327338694543475752061211)"
+    /// );
+    /// ```
+    #[inline]
+    pub fn tan_prec(&self, prec: u64) -> (Complex, (Ordering, Ordering)) {
+        self.tan_prec_round((prec, prec), (RoundingMode::Nearest, RoundingMode::Nearest))
+    }
+}
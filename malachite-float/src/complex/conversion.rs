@@ -0,0 +1,308 @@
+use crate::complex::Complex;
+use crate::Float;
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+use malachite_base::num::conversion::traits::FromSciString;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_q::complex::ComplexRational;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter, Result};
+
+impl From<Float> for Complex {
+    /// Converts a [`Float`] to a [`Complex`] number with a zero imaginary part, taking the
+    /// [`Float`] by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Complex::from(Float::from(1.5)).to_string(), "(1.5 0.0)");
+    /// ```
+    #[inline]
+    fn from(x: Float) -> Complex {
+        Complex {
+            re: x,
+            im: Float::ZERO,
+        }
+    }
+}
+
+impl<'a> From<&'a Float> for Complex {
+    /// Converts a [`Float`] to a [`Complex`] number with a zero imaginary part, taking the
+    /// [`Float`] by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Complex::from(&Float::from(1.5)).to_string(), "(1.5 0.0)");
+    /// ```
+    #[inline]
+    fn from(x: &'a Float) -> Complex {
+        Complex::from(x.clone())
+    }
+}
+
+impl Complex {
+    /// Converts a [`ComplexRational`] to a [`Complex`] number, taking the [`ComplexRational`] by
+    /// value. Each part of the result is rounded to the corresponding precision, using the
+    /// corresponding rounding mode. A pair of [`Ordering`]s is also returned, indicating whether
+    /// each part of the result is less than, equal to, or greater than the corresponding part of
+    /// the input.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(z.significant_bits(), prec.0,
+    /// prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if either rounding mode is `Exact` but the
+    /// corresponding part of the input can't be represented exactly with the corresponding
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::complex::Complex;
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::cmp::Ordering;
+    /// use std::str::FromStr;
+    ///
+    /// let (z, o) = Complex::from_complex_rational_prec_round(
+    ///     ComplexRational::from_str("(1/3 -1/2)").unwrap(),
+    ///     (10, 10),
+    ///     (RoundingMode::Floor, RoundingMode::Exact),
+    /// );
+    /// assert_eq!(z.to_string(), "(0.333 -0.5)");
+    /// assert_eq!(o, (Ordering::Less, Ordering::Equal));
+    /// ```
+    pub fn from_complex_rational_prec_round(
+        z: ComplexRational,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        let (x, y) = z.into_re_im();
+        let (re, re_o) = Float::from_rational_prec_round(x, prec.0, rm.0);
+        let (im, im_o) = Float::from_rational_prec_round(y, prec.1, rm.1);
+        (Complex { re, im }, (re_o, im_o))
+    }
+
+    /// Converts a [`ComplexRational`] to a [`Complex`] number, taking the [`ComplexRational`] by
+    /// reference. Each part of the result is rounded to the corresponding precision, using the
+    /// corresponding rounding mode. A pair of [`Ordering`]s is also returned, indicating whether
+    /// each part of the result is less than, equal to, or greater than the corresponding part of
+    /// the input.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(z.significant_bits(), prec.0,
+    /// prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if either rounding mode is `Exact` but the
+    /// corresponding part of the input can't be represented exactly with the corresponding
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::complex::Complex;
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::cmp::Ordering;
+    /// use std::str::FromStr;
+    ///
+    /// let (z, o) = Complex::from_complex_rational_prec_round_ref(
+    ///     &ComplexRational::from_str("(1/3 -1/2)").unwrap(),
+    ///     (10, 10),
+    ///     (RoundingMode::Ceiling, RoundingMode::Exact),
+    /// );
+    /// assert_eq!(z.to_string(), "(0.3335 -0.5)");
+    /// assert_eq!(o, (Ordering::Greater, Ordering::Equal));
+    /// ```
+    pub fn from_complex_rational_prec_round_ref(
+        z: &ComplexRational,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        let (re, re_o) = Float::from_rational_prec_round_ref(z.re(), prec.0, rm.0);
+        let (im, im_o) = Float::from_rational_prec_round_ref(z.im(), prec.1, rm.1);
+        (Complex { re, im }, (re_o, im_o))
+    }
+
+    /// Converts a string to a [`Complex`] number. Each part of the result is rounded to the
+    /// corresponding precision, using the corresponding rounding mode. A pair of [`Ordering`]s is
+    /// also returned, indicating whether each part of the result is less than, equal to, or
+    /// greater than the corresponding part of the number represented by the string.
+    ///
+    /// The string should have the form `(re im)`, where `re` and `im` are separated by a single
+    /// space, or consist of a single part `re`, in which case the imaginary part is zero. This is
+    /// the format produced by [`Complex`]'s [`Display`] implementation. Each part may be `NaN`,
+    /// `Infinity`, `-Infinity`, or a number in scientific notation, such as `-1.5e-3`; see
+    /// [`FromSciString`]. A zero part that begins with `-` is negative zero.
+    ///
+    /// If the string does not have this form, `None` is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(s.len(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if either rounding mode is `Exact` but the
+    /// corresponding part of the parsed number can't be represented exactly with the
+    /// corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::complex::Complex;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (z, o) = Complex::from_sci_string_prec_round(
+    ///     "(0.1 -2.5e3)",
+    ///     (10, 20),
+    ///     (RoundingMode::Nearest, RoundingMode::Exact),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(z.to_string(), "(0.09998 -2500.0)");
+    /// assert_eq!(o, (Ordering::Less, Ordering::Equal));
+    ///
+    /// let (z, _) = Complex::from_sci_string_prec_round(
+    ///     "(NaN -0.0)",
+    ///     (10, 10),
+    ///     (RoundingMode::Nearest, RoundingMode::Nearest),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(z.to_string(), "(NaN -0.0)");
+    ///
+    /// assert!(Complex::from_sci_string_prec_round(
+    ///     "(1,2)",
+    ///     (10, 10),
+    ///     (RoundingMode::Nearest, RoundingMode::Nearest)
+    /// )
+    /// .is_none());
+    /// ```
+    pub fn from_sci_string_prec_round(
+        s: &str,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> Option<(Complex, (Ordering, Ordering))> {
+        let (re, im) = if let Some(s) = s.strip_prefix('(') {
+            s.strip_suffix(')')?.split_once(' ')?
+        } else {
+            (s, "0")
+        };
+        let (re, re_o) = float_from_sci_string_prec_round(re, prec.0, rm.0)?;
+        let (im, im_o) = float_from_sci_string_prec_round(im, prec.1, rm.1)?;
+        Some((Complex { re, im }, (re_o, im_o)))
+    }
+
+    /// Returns the number of significant bits of a [`Complex`] number; that is, the sum of the
+    /// significant bits of its parts. Zeros and non-finite parts have one significant bit.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let z = Complex::new(Float::from(1.5), Float::from(-2));
+    /// assert_eq!(z.significant_bits(), 55);
+    /// ```
+    pub fn significant_bits(&self) -> u64 {
+        self.re.get_prec().unwrap_or(1) + self.im.get_prec().unwrap_or(1)
+    }
+}
+
+// Parses a part of a `Complex` number.
+fn float_from_sci_string_prec_round(
+    s: &str,
+    prec: u64,
+    rm: RoundingMode,
+) -> Option<(Float, Ordering)> {
+    match s {
+        "NaN" => Some((Float::NAN, Ordering::Equal)),
+        "Infinity" => Some((Float::INFINITY, Ordering::Equal)),
+        "-Infinity" => Some((Float::NEGATIVE_INFINITY, Ordering::Equal)),
+        s => {
+            let x = Rational::from_sci_string(s).ok()?;
+            if x == 0u32 && s.starts_with('-') {
+                Some((Float::NEGATIVE_ZERO, Ordering::Equal))
+            } else {
+                Some(Float::from_rational_prec_round(x, prec, rm))
+            }
+        }
+    }
+}
+
+impl Display for Complex {
+    /// Converts a [`Complex`] number to a [`String`], in the form `(re im)`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NaN, One};
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Complex::ONE.to_string(), "(1.0 0.0)");
+    /// assert_eq!(Complex::I.to_string(), "(0.0 1.0)");
+    /// assert_eq!(Complex::NAN.to_string(), "(NaN NaN)");
+    /// assert_eq!(Complex::new(Float::from(1.5), Float::from(-2)).to_string(), "(1.5 -2.0)");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "({} {})", self.re, self.im)
+    }
+}
+
+impl Debug for Complex {
+    /// Converts a [`Complex`] number to a [`String`], in the form `(re im)`.
+    ///
+    /// This is the same as the [`Display::fmt`] implementation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    ///
+    /// assert_eq!(format!("{:?}", Complex::I), "(0.0 1.0)");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
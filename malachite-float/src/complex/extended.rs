@@ -0,0 +1,187 @@
+use crate::complex::Complex;
+use crate::Float;
+use malachite_base::num::arithmetic::traits::Sign;
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeOne, One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+// An extended real number: a `Rational`, an infinity, or NaN.
+//
+// When an input of a `Complex` function is not finite, the function follows the rules of C99
+// Annex G, which are stated in terms of floating-point operations on the parts of the inputs.
+// Those operations are carried out on `Extended`s instead, exactly, with IEEE 754's rules for
+// infinities and NaN. Zeros are unsigned: where IEEE 754 would use the sign of a zero, it is taken
+// to be positive.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Extended {
+    Finite(Rational),
+    // `true` for positive infinity
+    Infinity(bool),
+    NaN,
+}
+
+impl Extended {
+    pub(crate) const ZERO: Extended = Extended::Finite(Rational::ZERO);
+
+    pub(crate) const INFINITY: Extended = Extended::Infinity(true);
+
+    pub(crate) fn from_float(x: &Float) -> Extended {
+        if x.is_nan() {
+            Extended::NaN
+        } else if x.is_infinite() {
+            Extended::Infinity(x.is_sign_positive())
+        } else {
+            Extended::Finite(Rational::exact_from(x))
+        }
+    }
+
+    pub(crate) const fn is_nan(&self) -> bool {
+        matches!(self, Extended::NaN)
+    }
+
+    pub(crate) const fn is_infinite(&self) -> bool {
+        matches!(self, Extended::Infinity(_))
+    }
+
+    pub(crate) const fn is_finite(&self) -> bool {
+        matches!(self, Extended::Finite(_))
+    }
+
+    // Returns the sign of `self`, or `None` if `self` is NaN. Zero has sign `Equal`.
+    fn sign(&self) -> Option<Ordering> {
+        match self {
+            Extended::Finite(x) => Some(x.sign()),
+            Extended::Infinity(true) => Some(Ordering::Greater),
+            Extended::Infinity(false) => Some(Ordering::Less),
+            Extended::NaN => None,
+        }
+    }
+
+    // Replaces an infinity by 1 or -1 and any other value, including NaN, by zero. Annex G does
+    // this to an operand with an infinite part before recomputing a result that would otherwise be
+    // NaN.
+    pub(crate) const fn to_unit(&self) -> Extended {
+        match self {
+            Extended::Infinity(true) => Extended::Finite(Rational::ONE),
+            Extended::Infinity(false) => Extended::Finite(Rational::NEGATIVE_ONE),
+            _ => Extended::ZERO,
+        }
+    }
+
+    // Replaces NaN by zero, and leaves any other value unchanged.
+    pub(crate) fn nan_to_zero(self) -> Extended {
+        if self.is_nan() {
+            Extended::ZERO
+        } else {
+            self
+        }
+    }
+
+    // Converts an `Extended` to a `Float`. A finite value is rounded to the specified precision,
+    // using the specified rounding mode.
+    pub(crate) fn into_float_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        match self {
+            Extended::Finite(x) => Float::from_rational_prec_round(x, prec, rm),
+            Extended::Infinity(true) => (Float::INFINITY, Ordering::Equal),
+            Extended::Infinity(false) => (Float::NEGATIVE_INFINITY, Ordering::Equal),
+            Extended::NaN => (Float::NAN, Ordering::Equal),
+        }
+    }
+
+    // Converts a pair of `Extended`s to a `Complex`, in the manner of `into_float_prec_round`.
+    pub(crate) fn into_complex_prec_round(
+        re: Extended,
+        im: Extended,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        let (re, re_o) = re.into_float_prec_round(prec.0, rm.0);
+        let (im, im_o) = im.into_float_prec_round(prec.1, rm.1);
+        (Complex::new(re, im), (re_o, im_o))
+    }
+}
+
+impl Complex {
+    // Returns the parts of a `Complex` as `Extended`s.
+    pub(crate) fn to_extended(&self) -> (Extended, Extended) {
+        (
+            Extended::from_float(&self.re),
+            Extended::from_float(&self.im),
+        )
+    }
+}
+
+impl<'a> Neg for &'a Extended {
+    type Output = Extended;
+
+    fn neg(self) -> Extended {
+        match self {
+            Extended::Finite(x) => Extended::Finite(-x),
+            Extended::Infinity(positive) => Extended::Infinity(!positive),
+            Extended::NaN => Extended::NaN,
+        }
+    }
+}
+
+impl<'a, 'b> Add<&'a Extended> for &'b Extended {
+    type Output = Extended;
+
+    fn add(self, other: &'a Extended) -> Extended {
+        match (self, other) {
+            (Extended::Finite(x), Extended::Finite(y)) => Extended::Finite(x + y),
+            (Extended::Infinity(x), Extended::Infinity(y)) if x != y => Extended::NaN,
+            (Extended::Infinity(x), Extended::Finite(_) | Extended::Infinity(_))
+            | (Extended::Finite(_), Extended::Infinity(x)) => Extended::Infinity(*x),
+            _ => Extended::NaN,
+        }
+    }
+}
+
+impl<'a, 'b> Sub<&'a Extended> for &'b Extended {
+    type Output = Extended;
+
+    fn sub(self, other: &'a Extended) -> Extended {
+        self + &-other
+    }
+}
+
+impl<'a, 'b> Mul<&'a Extended> for &'b Extended {
+    type Output = Extended;
+
+    fn mul(self, other: &'a Extended) -> Extended {
+        match (self, other) {
+            (Extended::Finite(x), Extended::Finite(y)) => Extended::Finite(x * y),
+            _ => match (self.sign(), other.sign()) {
+                (Some(x), Some(y)) if x != Ordering::Equal && y != Ordering::Equal => {
+                    Extended::Infinity(x == y)
+                }
+                _ => Extended::NaN,
+            },
+        }
+    }
+}
+
+impl<'a, 'b> Div<&'a Extended> for &'b Extended {
+    type Output = Extended;
+
+    fn div(self, other: &'a Extended) -> Extended {
+        match (self, other) {
+            (Extended::Finite(x), Extended::Finite(y)) => {
+                if *y != 0u32 {
+                    Extended::Finite(x / y)
+                } else if *x == 0u32 {
+                    Extended::NaN
+                } else {
+                    // The zero divisor is taken to be positive
+                    Extended::Infinity(*x > 0u32)
+                }
+            }
+            (Extended::Finite(_), Extended::Infinity(_)) => Extended::ZERO,
+            (Extended::Infinity(x), Extended::Finite(y)) => Extended::Infinity(*x == (*y >= 0u32)),
+            _ => Extended::NaN,
+        }
+    }
+}
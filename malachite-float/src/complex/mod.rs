@@ -0,0 +1,218 @@
+use crate::ball::conversion::{ziv_next_prec, ziv_try_round, ZIV_EXTRA_PREC};
+use crate::ball::Ball;
+use crate::Float;
+use malachite_base::num::basic::traits::{NaN, One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_q::complex::ComplexRational;
+use malachite_q::Rational;
+use std::cmp::{max, Ordering};
+
+/// A complex number whose real and imaginary parts are [`Float`]s.
+///
+/// The `Complex` type is designed to be similar to the `mpc_t` type in
+/// [MPC](https://www.multiprecision.org/mpc/). The real and imaginary parts have their own
+/// precisions, and functions that produce a `Complex` take a pair of precisions and a pair of
+/// [`RoundingMode`]s, one for each part. They also return a pair of [`Ordering`]s, indicating
+/// whether each part of the result is less than, equal to, or greater than the corresponding part
+/// of the exact result.
+///
+/// Every function is correctly rounded: each part of the result is the exact result's part,
+/// rounded to the requested precision using the requested [`RoundingMode`]. Unlike MPC, these
+/// functions don't track the signs of zeros: zeros in inputs are treated as positive, and zero
+/// parts of results are positive.
+///
+/// Addition, subtraction, multiplication, division, exponentiation, the exponential function and
+/// the logarithm handle infinities and NaNs as C99 Annex G and MPC do, propagating infinities
+/// through each part of the result: for example, $(\infty + 0i) + 1 = \infty + 0i$ and
+/// $1/(\infty + 0i) = 0$. For the other functions, if either part of an input is not finite, both
+/// parts of the result are NaN, except where otherwise noted.
+#[derive(Clone, PartialEq)]
+pub struct Complex {
+    pub(crate) re: Float,
+    pub(crate) im: Float,
+}
+
+impl Complex {
+    /// The imaginary unit $i$.
+    pub const I: Complex = Complex {
+        re: Float::ZERO,
+        im: Float::ONE,
+    };
+
+    /// Creates a `Complex` from its real and imaginary parts.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let z = Complex::new(Float::from(1.5), Float::from(-2));
+    /// assert_eq!(z.to_string(), "(1.5 -2.0)");
+    /// ```
+    pub const fn new(re: Float, im: Float) -> Complex {
+        Complex { re, im }
+    }
+
+    /// Returns a reference to the real part of a `Complex`.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let z = Complex::new(Float::from(1.5), Float::from(-2));
+    /// assert_eq!(z.re().to_string(), "1.5");
+    /// ```
+    pub const fn re(&self) -> &Float {
+        &self.re
+    }
+
+    /// Returns a reference to the imaginary part of a `Complex`.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let z = Complex::new(Float::from(1.5), Float::from(-2));
+    /// assert_eq!(z.im().to_string(), "-2.0");
+    /// ```
+    pub const fn im(&self) -> &Float {
+        &self.im
+    }
+
+    /// Takes a `Complex` by value and returns its real and imaginary parts.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let (re, im) = Complex::new(Float::from(1.5), Float::from(-2)).into_re_im();
+    /// assert_eq!(re.to_string(), "1.5");
+    /// assert_eq!(im.to_string(), "-2.0");
+    /// ```
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_re_im(self) -> (Float, Float) {
+        (self.re, self.im)
+    }
+
+    /// Determines whether both parts of a `Complex` are finite.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, One};
+    /// use malachite_float::complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Complex::ONE.is_finite());
+    /// assert!(!Complex::NAN.is_finite());
+    /// assert!(!Complex::new(Float::ONE, Float::INFINITY).is_finite());
+    /// ```
+    pub const fn is_finite(&self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+
+    // Returns the exact value of a `Complex` as a `ComplexRational`, or `None` if either part is
+    // not finite.
+    pub(crate) fn to_complex_rational(&self) -> Option<ComplexRational> {
+        if self.is_finite() {
+            Some(ComplexRational::new(
+                Rational::exact_from(&self.re),
+                Rational::exact_from(&self.im),
+            ))
+        } else {
+            None
+        }
+    }
+
+    // Returns the largest precision of the two parts of a `Complex`, treating zeros and
+    // non-finite parts as having precision 1.
+    pub(crate) fn max_prec(&self) -> u64 {
+        max(
+            self.re.get_prec().unwrap_or(1),
+            self.im.get_prec().unwrap_or(1),
+        )
+    }
+}
+
+// The result returned by functions whose inputs are not finite.
+pub(crate) const NAN_RESULT: (Complex, (Ordering, Ordering)) =
+    (Complex::NAN, (Ordering::Equal, Ordering::Equal));
+
+// Rounds a complex number to a `Complex` using Ziv's strategy. `f` is called with increasing
+// working precisions, and must return a pair of `Ball`s containing the real and imaginary parts of
+// the number, until both `Ball`s are narrow enough to determine the correctly-rounded parts.
+//
+// Each part must satisfy the same conditions as the argument of `ziv_round`.
+pub(crate) fn ziv_round_complex<F: FnMut(u64) -> (Ball, Ball)>(
+    prec: (u64, u64),
+    rm: (RoundingMode, RoundingMode),
+    mut f: F,
+) -> (Complex, (Ordering, Ordering)) {
+    assert_ne!(prec.0, 0);
+    assert_ne!(prec.1, 0);
+    let mut working_prec = max(prec.0, prec.1) + ZIV_EXTRA_PREC;
+    let mut re = None;
+    let mut im = None;
+    loop {
+        let (re_ball, im_ball) = f(working_prec);
+        if re.is_none() {
+            re = ziv_try_round(&re_ball, prec.0, rm.0);
+        }
+        if im.is_none() {
+            im = ziv_try_round(&im_ball, prec.1, rm.1);
+        }
+        if let (Some((re, re_o)), Some((im, im_o))) = (&re, &im) {
+            return (Complex::new(re.clone(), im.clone()), (*re_o, *im_o));
+        }
+        working_prec = ziv_next_prec(working_prec);
+    }
+}
+
+/// The constant 0.
+impl Zero for Complex {
+    const ZERO: Complex = Complex {
+        re: Float::ZERO,
+        im: Float::ZERO,
+    };
+}
+
+/// The constant 1.
+impl One for Complex {
+    const ONE: Complex = Complex {
+        re: Float::ONE,
+        im: Float::ZERO,
+    };
+}
+
+/// The `Complex` whose parts are both NaN.
+impl NaN for Complex {
+    const NAN: Complex = Complex {
+        re: Float::NAN,
+        im: Float::NAN,
+    };
+}
+
+/// Arithmetic and elementary functions of [`Complex`] numbers.
+pub mod arithmetic;
+/// Conversions between [`Complex`] numbers and other types, including [`String`]s.
+pub mod conversion;
+// Extended real numbers, used when an input of a [`Complex`] function is not finite.
+pub(crate) mod extended;
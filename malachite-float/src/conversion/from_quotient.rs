@@ -0,0 +1,156 @@
+use crate::Float;
+use malachite_base::num::arithmetic::traits::{DivRem, DivisibleByPowerOf2, ShrRound, UnsignedAbs};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use std::cmp::Ordering;
+
+impl Float {
+    /// Converts the quotient of two [`Integer`]s to a [`Float`], taking both by reference. If the
+    /// [`Float`] is nonzero, it has the specified precision. If rounding is needed, the specified
+    /// rounding mode is used. An [`Ordering`] is also returned, indicating whether the returned
+    /// value is less than, equal to, or greater than the exact quotient.
+    ///
+    /// The result is the same as that of [`Float::from_rational_prec_round`] applied to $x/y$, but
+    /// no gcd is computed. Only `prec + 2` bits of the quotient are generated; whether anything
+    /// remains is recorded in the lowest of them, which is enough to round correctly in every
+    /// rounding mode.
+    ///
+    /// If you're only using [`RoundingMode::Nearest`], try using [`Float::from_quotient_prec`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(y.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or `y` is zero, or if `rm` is `Exact` but the quotient cannot be
+    /// represented with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from_quotient_prec_round(
+    ///     &Integer::from(1),
+    ///     &Integer::from(3),
+    ///     10,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "0.333");
+    /// assert_eq!(x.get_prec(), Some(10));
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from_quotient_prec_round(
+    ///     &Integer::from(1),
+    ///     &Integer::from(3),
+    ///     10,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "0.3335");
+    /// assert_eq!(x.get_prec(), Some(10));
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from_quotient_prec_round(
+    ///     &Integer::from(-6),
+    ///     &Integer::from(8),
+    ///     10,
+    ///     RoundingMode::Exact,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.75");
+    /// assert_eq!(x.get_prec(), Some(10));
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    pub fn from_quotient_prec_round(
+        x: &Integer,
+        y: &Integer,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        assert_ne!(*y, 0, "division by zero");
+        if *x == 0 {
+            return (Float::ZERO, Ordering::Equal);
+        }
+        let sign = (*x > 0) == (*y > 0);
+        // |x / y| is between 2^(bits(x) - bits(y) - 1) and 2^(bits(x) - bits(y) + 1), so the
+        // truncated quotient below has at least prec + 2 bits.
+        let pow = i64::exact_from(prec) + 2 - i64::exact_from(x.significant_bits())
+            + i64::exact_from(y.significant_bits());
+        let (n, mut inexact) = if pow >= 0 {
+            (x << pow, false)
+        } else {
+            let bits = pow.unsigned_abs();
+            (
+                x.shr_round(bits, RoundingMode::Down).0,
+                !x.divisible_by_power_of_2(bits),
+            )
+        };
+        let (q, r) = n.div_rem(y);
+        inexact |= r != 0;
+        let mut q = q.unsigned_abs();
+        if inexact {
+            q.set_bit(0);
+        }
+        let (f, o) = Float::from_natural_times_power_of_2_prec_round(
+            q,
+            -pow,
+            prec,
+            if sign { rm } else { -rm },
+        );
+        if sign {
+            (f, o)
+        } else {
+            (-f, o.reverse())
+        }
+    }
+
+    /// Converts the quotient of two [`Integer`]s to a [`Float`], taking both by reference. If the
+    /// [`Float`] is nonzero, it has the specified precision. An [`Ordering`] is also returned,
+    /// indicating whether the returned value is less than, equal to, or greater than the exact
+    /// quotient.
+    ///
+    /// Rounding may occur, in which case [`RoundingMode::Nearest`] is used by default. To specify
+    /// a rounding mode as well as a precision, try [`Float::from_quotient_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(y.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or `y` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from_quotient_prec(&Integer::from(1), &Integer::from(3), 100);
+    /// assert_eq!(x.to_string(), "0.3333333333333333333333333333335");
+    /// assert_eq!(x.get_prec(), Some(100));
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from_quotient_prec(&Integer::from(-1), &Integer::from(3), 10);
+    /// assert_eq!(x.to_string(), "-0.3335");
+    /// assert_eq!(x.get_prec(), Some(10));
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn from_quotient_prec(x: &Integer, y: &Integer, prec: u64) -> (Float, Ordering) {
+        Float::from_quotient_prec_round(x, y, prec, RoundingMode::Nearest)
+    }
+}
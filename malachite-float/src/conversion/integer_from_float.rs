@@ -75,10 +75,12 @@ impl RoundingFrom<Float> for Integer {
                 let abs_rm = if sign { rm } else { -rm };
                 let (abs_i, abs_o) = if exponent < 0 {
                     match abs_rm {
-                        RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                            (Natural::ZERO, Ordering::Less)
-                        }
-                        RoundingMode::Ceiling | RoundingMode::Up => {
+                        RoundingMode::Floor
+                        | RoundingMode::Down
+                        | RoundingMode::Nearest
+                        | RoundingMode::NearestTiesAway
+                        | RoundingMode::NearestTiesTowardZero => (Natural::ZERO, Ordering::Less),
+                        RoundingMode::Ceiling | RoundingMode::Up | RoundingMode::Odd => {
                             (Natural::ONE, Ordering::Greater)
                         }
                         RoundingMode::Exact => {
@@ -174,10 +176,14 @@ impl<'a> RoundingFrom<&'a Float> for Integer {
                     let abs_rm = if *sign { rm } else { -rm };
                     let (abs_i, abs_o) = if *exponent < 0 {
                         match abs_rm {
-                            RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
+                            RoundingMode::Floor
+                            | RoundingMode::Down
+                            | RoundingMode::Nearest
+                            | RoundingMode::NearestTiesAway
+                            | RoundingMode::NearestTiesTowardZero => {
                                 (Natural::ZERO, Ordering::Less)
                             }
-                            RoundingMode::Ceiling | RoundingMode::Up => {
+                            RoundingMode::Ceiling | RoundingMode::Up | RoundingMode::Odd => {
                                 (Natural::ONE, Ordering::Greater)
                             }
                             RoundingMode::Exact => {
@@ -203,6 +203,9 @@ pub mod from_primitive_float;
 /// assert_eq!(o, Ordering::Greater);
 /// ```
 pub mod from_primitive_int;
+/// Functions for converting the quotient of two [`Integer`](malachite_nz::integer::Integer)s to a
+/// [`Float`](crate::Float) without computing a gcd.
+pub mod from_quotient;
 /// Implementations of the [`From`] trait for converting a [`Rational`](malachite_q::Rational) to a
 /// [`Float`](crate::Float).
 pub mod from_rational;
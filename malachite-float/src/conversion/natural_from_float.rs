@@ -14,8 +14,8 @@ impl RoundingFrom<Float> for Natural {
     /// is less than, equal to, or greater than the original value.
     ///
     /// If the [`Float`] is negative (including negative infinity), then it will be rounded to zero
-    /// when the [`RoundingMode`] is `Ceiling`, `Down`, or `Nearest`. Otherwise, this function will
-    /// panic.
+    /// when the [`RoundingMode`] is `Ceiling`, `Down`, `Nearest`, `NearestTiesAway`, or
+    /// `NearestTiesTowardZero`. Otherwise, this function will panic.
     ///
     /// If the [`Float`] is NaN or positive infinity, the function will panic regardless of the
     /// rounding mode.
@@ -29,8 +29,8 @@ impl RoundingFrom<Float> for Natural {
     ///
     /// # Panics
     /// Panics if the [`Float`] is not an integer and `rm` is `Exact`, or if the [`Float`] is less
-    /// than zero and `rm` is not `Down`, `Ceiling`, or `Nearest`, or if the [`Float`] is NaN or
-    /// positive infinity.
+    /// than zero and `rm` is not `Down`, `Ceiling`, `Nearest`, `NearestTiesAway`, or
+    /// `NearestTiesTowardZero`, or if the [`Float`] is NaN or positive infinity.
     ///
     /// # Examples
     /// ```
@@ -73,9 +73,11 @@ impl RoundingFrom<Float> for Natural {
         match f {
             float_either_zero!() => (Natural::ZERO, Ordering::Equal),
             float_negative_infinity!() => match rm {
-                RoundingMode::Ceiling | RoundingMode::Down | RoundingMode::Nearest => {
-                    (Natural::ZERO, Ordering::Greater)
-                }
+                RoundingMode::Ceiling
+                | RoundingMode::Down
+                | RoundingMode::Nearest
+                | RoundingMode::NearestTiesAway
+                | RoundingMode::NearestTiesTowardZero => (Natural::ZERO, Ordering::Greater),
                 _ => panic!("Can't convert -Infinity to Natural using {rm}"),
             },
             Float(Finite {
@@ -86,17 +88,21 @@ impl RoundingFrom<Float> for Natural {
             }) => {
                 if !sign {
                     match rm {
-                        RoundingMode::Ceiling | RoundingMode::Down | RoundingMode::Nearest => {
-                            (Natural::ZERO, Ordering::Greater)
-                        }
+                        RoundingMode::Ceiling
+                        | RoundingMode::Down
+                        | RoundingMode::Nearest
+                        | RoundingMode::NearestTiesAway
+                        | RoundingMode::NearestTiesTowardZero => (Natural::ZERO, Ordering::Greater),
                         _ => panic!("Cannot convert negative number to Natural using {rm}"),
                     }
                 } else if exponent < 0 {
                     match rm {
-                        RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                            (Natural::ZERO, Ordering::Less)
-                        }
-                        RoundingMode::Ceiling | RoundingMode::Up => {
+                        RoundingMode::Floor
+                        | RoundingMode::Down
+                        | RoundingMode::Nearest
+                        | RoundingMode::NearestTiesAway
+                        | RoundingMode::NearestTiesTowardZero => (Natural::ZERO, Ordering::Less),
+                        RoundingMode::Ceiling | RoundingMode::Up | RoundingMode::Odd => {
                             (Natural::ONE, Ordering::Greater)
                         }
                         RoundingMode::Exact => panic!("Cannot convert Float to Natural using {rm}"),
@@ -122,8 +128,8 @@ impl<'a> RoundingFrom<&'a Float> for Natural {
     /// value is less than, equal to, or greater than the original value.
     ///
     /// If the [`Float`] is negative (including negative infinity), then it will be rounded to zero
-    /// when the [`RoundingMode`] is `Ceiling`, `Down`, or `Nearest`. Otherwise, this function will
-    /// panic.
+    /// when the [`RoundingMode`] is `Ceiling`, `Down`, `Nearest`, `NearestTiesAway`, or
+    /// `NearestTiesTowardZero`. Otherwise, this function will panic.
     ///
     /// If the [`Float`] is NaN or positive infinity, the function will panic regardless of the
     /// rounding mode.
@@ -137,8 +143,8 @@ impl<'a> RoundingFrom<&'a Float> for Natural {
     ///
     /// # Panics
     /// Panics if the [`Float`] is not an integer and `rm` is `Exact`, or if the [`Float`] is less
-    /// than zero and `rm` is not `Down`, `Ceiling`, or `Nearest`, or if the [`Float`] is NaN or
-    /// positive infinity.
+    /// than zero and `rm` is not `Down`, `Ceiling`, `Nearest`, `NearestTiesAway`, or
+    /// `NearestTiesTowardZero`, or if the [`Float`] is NaN or positive infinity.
     ///
     /// # Examples
     /// ```
@@ -182,9 +188,11 @@ impl<'a> RoundingFrom<&'a Float> for Natural {
         match f {
             float_either_zero!() => (Natural::ZERO, Ordering::Equal),
             float_negative_infinity!() => match rm {
-                RoundingMode::Ceiling | RoundingMode::Down | RoundingMode::Nearest => {
-                    (Natural::ZERO, Ordering::Greater)
-                }
+                RoundingMode::Ceiling
+                | RoundingMode::Down
+                | RoundingMode::Nearest
+                | RoundingMode::NearestTiesAway
+                | RoundingMode::NearestTiesTowardZero => (Natural::ZERO, Ordering::Greater),
                 _ => panic!("Can't convert -Infinity to Natural using {rm}"),
             },
             Float(Finite {
@@ -195,17 +203,21 @@ impl<'a> RoundingFrom<&'a Float> for Natural {
             }) => {
                 if !sign {
                     match rm {
-                        RoundingMode::Ceiling | RoundingMode::Down | RoundingMode::Nearest => {
-                            (Natural::ZERO, Ordering::Greater)
-                        }
+                        RoundingMode::Ceiling
+                        | RoundingMode::Down
+                        | RoundingMode::Nearest
+                        | RoundingMode::NearestTiesAway
+                        | RoundingMode::NearestTiesTowardZero => (Natural::ZERO, Ordering::Greater),
                         _ => panic!("Cannot convert -Infinity to Natural using {rm}"),
                     }
                 } else if *exponent < 0 {
                     match rm {
-                        RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                            (Natural::ZERO, Ordering::Less)
-                        }
-                        RoundingMode::Ceiling | RoundingMode::Up => {
+                        RoundingMode::Floor
+                        | RoundingMode::Down
+                        | RoundingMode::Nearest
+                        | RoundingMode::NearestTiesAway
+                        | RoundingMode::NearestTiesTowardZero => (Natural::ZERO, Ordering::Less),
+                        RoundingMode::Ceiling | RoundingMode::Up | RoundingMode::Odd => {
                             (Natural::ONE, Ordering::Greater)
                         }
                         RoundingMode::Exact => panic!("Cannot convert Float to Natural using {rm}"),
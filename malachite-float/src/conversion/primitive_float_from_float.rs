@@ -31,23 +31,33 @@ fn primitive_float_rounding_from_float<T: PrimitiveFloat>(
                 if exponent < T::MIN_EXPONENT {
                     match abs_rm {
                         RoundingMode::Floor | RoundingMode::Down => (T::ZERO, Ordering::Less),
-                        RoundingMode::Ceiling | RoundingMode::Up => {
+                        RoundingMode::Ceiling | RoundingMode::Up | RoundingMode::Odd => {
                             (T::MIN_POSITIVE_SUBNORMAL, Ordering::Greater)
                         }
-                        RoundingMode::Nearest => {
+                        RoundingMode::Nearest | RoundingMode::NearestTiesTowardZero => {
                             if exponent == T::MIN_EXPONENT - 1 && !significand.is_power_of_2() {
                                 (T::MIN_POSITIVE_SUBNORMAL, Ordering::Greater)
                             } else {
                                 (T::ZERO, Ordering::Less)
                             }
                         }
+                        RoundingMode::NearestTiesAway => {
+                            if exponent == T::MIN_EXPONENT - 1 {
+                                (T::MIN_POSITIVE_SUBNORMAL, Ordering::Greater)
+                            } else {
+                                (T::ZERO, Ordering::Less)
+                            }
+                        }
                         RoundingMode::Exact => panic!("Float too small for exact conversion"),
                     }
                 } else if exponent > T::MAX_EXPONENT {
                     match abs_rm {
-                        RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                            (T::MAX_FINITE, Ordering::Less)
-                        }
+                        RoundingMode::Floor
+                        | RoundingMode::Down
+                        | RoundingMode::Nearest
+                        | RoundingMode::NearestTiesAway
+                        | RoundingMode::NearestTiesTowardZero
+                        | RoundingMode::Odd => (T::MAX_FINITE, Ordering::Less),
                         RoundingMode::Ceiling | RoundingMode::Up => {
                             (T::INFINITY, Ordering::Greater)
                         }
@@ -64,7 +74,10 @@ fn primitive_float_rounding_from_float<T: PrimitiveFloat>(
                             match abs_rm {
                                 RoundingMode::Floor
                                 | RoundingMode::Down
-                                | RoundingMode::Nearest => (T::MAX_FINITE, Ordering::Less),
+                                | RoundingMode::Nearest
+                                | RoundingMode::NearestTiesAway
+                                | RoundingMode::NearestTiesTowardZero
+                                | RoundingMode::Odd => (T::MAX_FINITE, Ordering::Less),
                                 RoundingMode::Ceiling | RoundingMode::Up => {
                                     (T::INFINITY, Ordering::Greater)
                                 }
@@ -126,23 +139,33 @@ fn primitive_float_rounding_from_float_ref<T: PrimitiveFloat>(
                 if exponent < T::MIN_EXPONENT {
                     match abs_rm {
                         RoundingMode::Floor | RoundingMode::Down => (T::ZERO, Ordering::Less),
-                        RoundingMode::Ceiling | RoundingMode::Up => {
+                        RoundingMode::Ceiling | RoundingMode::Up | RoundingMode::Odd => {
                             (T::MIN_POSITIVE_SUBNORMAL, Ordering::Greater)
                         }
-                        RoundingMode::Nearest => {
+                        RoundingMode::Nearest | RoundingMode::NearestTiesTowardZero => {
                             if exponent == T::MIN_EXPONENT - 1 && !significand.is_power_of_2() {
                                 (T::MIN_POSITIVE_SUBNORMAL, Ordering::Greater)
                             } else {
                                 (T::ZERO, Ordering::Less)
                             }
                         }
+                        RoundingMode::NearestTiesAway => {
+                            if exponent == T::MIN_EXPONENT - 1 {
+                                (T::MIN_POSITIVE_SUBNORMAL, Ordering::Greater)
+                            } else {
+                                (T::ZERO, Ordering::Less)
+                            }
+                        }
                         RoundingMode::Exact => panic!("Float too small for exact conversion"),
                     }
                 } else if exponent > T::MAX_EXPONENT {
                     match abs_rm {
-                        RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                            (T::MAX_FINITE, Ordering::Less)
-                        }
+                        RoundingMode::Floor
+                        | RoundingMode::Down
+                        | RoundingMode::Nearest
+                        | RoundingMode::NearestTiesAway
+                        | RoundingMode::NearestTiesTowardZero
+                        | RoundingMode::Odd => (T::MAX_FINITE, Ordering::Less),
                         RoundingMode::Ceiling | RoundingMode::Up => {
                             (T::INFINITY, Ordering::Greater)
                         }
@@ -159,7 +182,10 @@ fn primitive_float_rounding_from_float_ref<T: PrimitiveFloat>(
                             match abs_rm {
                                 RoundingMode::Floor
                                 | RoundingMode::Down
-                                | RoundingMode::Nearest => (T::MAX_FINITE, Ordering::Less),
+                                | RoundingMode::Nearest
+                                | RoundingMode::NearestTiesAway
+                                | RoundingMode::NearestTiesTowardZero
+                                | RoundingMode::Odd => (T::MAX_FINITE, Ordering::Less),
                                 RoundingMode::Ceiling | RoundingMode::Up => {
                                     (T::INFINITY, Ordering::Greater)
                                 }
@@ -19,15 +19,19 @@ where
     match f {
         float_nan!() => panic!("Can't convert NaN to {}", T::NAME),
         float_infinity!() => match rm {
-            RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                (T::MAX, Ordering::Less)
-            }
+            RoundingMode::Floor
+            | RoundingMode::Down
+            | RoundingMode::Nearest
+            | RoundingMode::NearestTiesAway
+            | RoundingMode::NearestTiesTowardZero => (T::MAX, Ordering::Less),
             _ => panic!("Can't convert Infinity to {} using {}", T::NAME, rm),
         },
         float_negative_infinity!() => match rm {
-            RoundingMode::Ceiling | RoundingMode::Down | RoundingMode::Nearest => {
-                (T::ZERO, Ordering::Greater)
-            }
+            RoundingMode::Ceiling
+            | RoundingMode::Down
+            | RoundingMode::Nearest
+            | RoundingMode::NearestTiesAway
+            | RoundingMode::NearestTiesTowardZero => (T::ZERO, Ordering::Greater),
             _ => panic!("Can't convert -Infinity to {} using {}", T::NAME, rm),
         },
         float_either_zero!() => (T::ZERO, Ordering::Equal),
@@ -39,26 +43,34 @@ where
         }) => {
             if !sign {
                 match rm {
-                    RoundingMode::Ceiling | RoundingMode::Down | RoundingMode::Nearest => {
-                        (T::ZERO, Ordering::Greater)
-                    }
+                    RoundingMode::Ceiling
+                    | RoundingMode::Down
+                    | RoundingMode::Nearest
+                    | RoundingMode::NearestTiesAway
+                    | RoundingMode::NearestTiesTowardZero => (T::ZERO, Ordering::Greater),
                     _ => panic!("Cannot convert negative Float to {} using {}", T::NAME, rm),
                 }
             } else if exponent < 0 {
                 match rm {
-                    RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                        (T::ZERO, Ordering::Less)
+                    RoundingMode::Floor
+                    | RoundingMode::Down
+                    | RoundingMode::Nearest
+                    | RoundingMode::NearestTiesAway
+                    | RoundingMode::NearestTiesTowardZero => (T::ZERO, Ordering::Less),
+                    RoundingMode::Ceiling | RoundingMode::Up | RoundingMode::Odd => {
+                        (T::ONE, Ordering::Greater)
                     }
-                    RoundingMode::Ceiling | RoundingMode::Up => (T::ONE, Ordering::Greater),
                     RoundingMode::Exact => {
                         panic!("Cannot convert Float to {} using {}", T::NAME, rm)
                     }
                 }
             } else if exponent > i64::wrapping_from(T::WIDTH) {
                 match rm {
-                    RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                        (T::MAX, Ordering::Less)
-                    }
+                    RoundingMode::Floor
+                    | RoundingMode::Down
+                    | RoundingMode::Nearest
+                    | RoundingMode::NearestTiesAway
+                    | RoundingMode::NearestTiesTowardZero => (T::MAX, Ordering::Less),
                     _ => panic!("Cannot convert large Float to {} using {}", T::NAME, rm),
                 }
             } else {
@@ -73,9 +85,11 @@ where
                     (n, o)
                 } else {
                     match rm {
-                        RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                            (T::MAX, Ordering::Less)
-                        }
+                        RoundingMode::Floor
+                        | RoundingMode::Down
+                        | RoundingMode::Nearest
+                        | RoundingMode::NearestTiesAway
+                        | RoundingMode::NearestTiesTowardZero => (T::MAX, Ordering::Less),
                         _ => panic!("Cannot convert large Float to {} using {}", T::NAME, rm),
                     }
                 };
@@ -96,15 +110,19 @@ where
     match f {
         float_nan!() => panic!("Can't convert NaN to {}", T::NAME),
         float_infinity!() => match rm {
-            RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                (T::MAX, Ordering::Less)
-            }
+            RoundingMode::Floor
+            | RoundingMode::Down
+            | RoundingMode::Nearest
+            | RoundingMode::NearestTiesAway
+            | RoundingMode::NearestTiesTowardZero => (T::MAX, Ordering::Less),
             _ => panic!("Can't convert Infinity to {} using {}", T::NAME, rm),
         },
         float_negative_infinity!() => match rm {
-            RoundingMode::Ceiling | RoundingMode::Down | RoundingMode::Nearest => {
-                (T::ZERO, Ordering::Greater)
-            }
+            RoundingMode::Ceiling
+            | RoundingMode::Down
+            | RoundingMode::Nearest
+            | RoundingMode::NearestTiesAway
+            | RoundingMode::NearestTiesTowardZero => (T::ZERO, Ordering::Greater),
             _ => panic!("Can't convert -Infinity to {} using {}", T::NAME, rm),
         },
         float_either_zero!() => (T::ZERO, Ordering::Equal),
@@ -116,26 +134,34 @@ where
         }) => {
             if !sign {
                 match rm {
-                    RoundingMode::Ceiling | RoundingMode::Down | RoundingMode::Nearest => {
-                        (T::ZERO, Ordering::Greater)
-                    }
+                    RoundingMode::Ceiling
+                    | RoundingMode::Down
+                    | RoundingMode::Nearest
+                    | RoundingMode::NearestTiesAway
+                    | RoundingMode::NearestTiesTowardZero => (T::ZERO, Ordering::Greater),
                     _ => panic!("Cannot convert negative Float to {} using {}", T::NAME, rm),
                 }
             } else if *exponent < 0 {
                 match rm {
-                    RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                        (T::ZERO, Ordering::Less)
+                    RoundingMode::Floor
+                    | RoundingMode::Down
+                    | RoundingMode::Nearest
+                    | RoundingMode::NearestTiesAway
+                    | RoundingMode::NearestTiesTowardZero => (T::ZERO, Ordering::Less),
+                    RoundingMode::Ceiling | RoundingMode::Up | RoundingMode::Odd => {
+                        (T::ONE, Ordering::Greater)
                     }
-                    RoundingMode::Ceiling | RoundingMode::Up => (T::ONE, Ordering::Greater),
                     RoundingMode::Exact => {
                         panic!("Cannot convert Float to {} using {}", T::NAME, rm)
                     }
                 }
             } else if *exponent > i64::wrapping_from(T::WIDTH) {
                 match rm {
-                    RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                        (T::MAX, Ordering::Less)
-                    }
+                    RoundingMode::Floor
+                    | RoundingMode::Down
+                    | RoundingMode::Nearest
+                    | RoundingMode::NearestTiesAway
+                    | RoundingMode::NearestTiesTowardZero => (T::MAX, Ordering::Less),
                     _ => panic!("Cannot convert large Float to {} using {}", T::NAME, rm),
                 }
             } else {
@@ -150,9 +176,11 @@ where
                     (n, o)
                 } else {
                     match rm {
-                        RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                            (T::MAX, Ordering::Less)
-                        }
+                        RoundingMode::Floor
+                        | RoundingMode::Down
+                        | RoundingMode::Nearest
+                        | RoundingMode::NearestTiesAway
+                        | RoundingMode::NearestTiesTowardZero => (T::MAX, Ordering::Less),
                         _ => panic!("Cannot convert large Float to {} using {}", T::NAME, rm),
                     }
                 };
@@ -264,13 +292,13 @@ macro_rules! impl_unsigned_from {
             /// than the original value.
             ///
             /// If the [`Float`] is negative (including negative infinity), then it will be rounded
-            /// to zero when the [`RoundingMode`] is `Ceiling`, `Down`, or `Nearest`. Otherwise,
-            /// this function will panic.
+            /// to zero when the [`RoundingMode`] is `Ceiling`, `Down`, `Nearest`,
+            /// `NearestTiesAway`, or `NearestTiesTowardZero`. Otherwise, this function will panic.
             ///
             /// If the [`Float`] is greater than the maximum representable value of the unsigned
             /// type (including infinity), then it will be rounded to the maximum value when the
-            /// [`RoundingMode`] is `Floor`, `Down`, or `Nearest`. Otherwise, this function will
-            /// panic.
+            /// [`RoundingMode`] is `Floor`, `Down`, `Nearest`, `NearestTiesAway`, or
+            /// `NearestTiesTowardZero`. Otherwise, this function will panic.
             ///
             /// If the [`Float`] is NaN, the function will panic regardless of the rounding mode.
             ///
@@ -279,9 +307,10 @@ macro_rules! impl_unsigned_from {
             ///
             /// # Panics
             /// Panics if the [`Float`] is not an integer and `rm` is `Exact`, or if the [`Float`]
-            /// is less than zero and `rm` is not `Down`, `Ceiling`, or `Nearest`, if the [`Float`]
-            /// is greater than the maximum representable value of the unsigned type and `rm` is
-            /// not `Down`, `Floor`, or `Nearest`, or if the [`Float`] is NaN.
+            /// is less than zero and `rm` is not `Down`, `Ceiling`, `Nearest`, `NearestTiesAway`,
+            /// or `NearestTiesTowardZero`, if the [`Float`] is greater than the maximum
+            /// representable value of the unsigned type and `rm` is not `Down`, `Floor`, `Nearest`,
+            /// `NearestTiesAway`, or `NearestTiesTowardZero`, or if the [`Float`] is NaN.
             ///
             /// # Examples
             /// See [here](super::primitive_int_from_float#rounding_from).
@@ -298,13 +327,13 @@ macro_rules! impl_unsigned_from {
             /// than the original value.
             ///
             /// If the [`Float`] is negative (including negative infinity), then it will be rounded
-            /// to zero when the [`RoundingMode`] is `Ceiling`, `Down`, or `Nearest`. Otherwise,
-            /// this function will panic.
+            /// to zero when the [`RoundingMode`] is `Ceiling`, `Down`, `Nearest`,
+            /// `NearestTiesAway`, or `NearestTiesTowardZero`. Otherwise, this function will panic.
             ///
             /// If the [`Float`] is greater than the maximum representable value of the unsigned
             /// type (including infinity), then it will be rounded to the maximum value when the
-            /// [`RoundingMode`] is `Floor`, `Down`, or `Nearest`. Otherwise, this function will
-            /// panic.
+            /// [`RoundingMode`] is `Floor`, `Down`, `Nearest`, `NearestTiesAway`, or
+            /// `NearestTiesTowardZero`. Otherwise, this function will panic.
             ///
             /// If the [`Float`] is NaN, the function will panic regardless of the rounding mode.
             ///
@@ -313,9 +342,10 @@ macro_rules! impl_unsigned_from {
             ///
             /// # Panics
             /// Panics if the [`Float`] is not an integer and `rm` is `Exact`, or if the [`Float`]
-            /// is less than zero and `rm` is not `Down`, `Ceiling`, or `Nearest`, if the [`Float`]
-            /// is greater than the maximum representable value of the unsigned type and `rm` is
-            /// not `Down`, `Floor`, or `Nearest`, or if the [`Float`] is NaN.
+            /// is less than zero and `rm` is not `Down`, `Ceiling`, `Nearest`, `NearestTiesAway`,
+            /// or `NearestTiesTowardZero`, if the [`Float`] is greater than the maximum
+            /// representable value of the unsigned type and `rm` is not `Down`, `Floor`, `Nearest`,
+            /// `NearestTiesAway`, or `NearestTiesTowardZero`, or if the [`Float`] is NaN.
             ///
             /// # Examples
             /// See [here](super::primitive_int_from_float#rounding_from).
@@ -395,15 +425,19 @@ where
     match f {
         float_nan!() => panic!("Can't convert NaN to {}", T::NAME),
         float_infinity!() => match rm {
-            RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                (T::MAX, Ordering::Less)
-            }
+            RoundingMode::Floor
+            | RoundingMode::Down
+            | RoundingMode::Nearest
+            | RoundingMode::NearestTiesAway
+            | RoundingMode::NearestTiesTowardZero => (T::MAX, Ordering::Less),
             _ => panic!("Can't convert Infinity to {} using {}", T::NAME, rm),
         },
         float_negative_infinity!() => match rm {
-            RoundingMode::Ceiling | RoundingMode::Down | RoundingMode::Nearest => {
-                (T::MIN, Ordering::Greater)
-            }
+            RoundingMode::Ceiling
+            | RoundingMode::Down
+            | RoundingMode::Nearest
+            | RoundingMode::NearestTiesAway
+            | RoundingMode::NearestTiesTowardZero => (T::MIN, Ordering::Greater),
             _ => panic!("Can't convert -Infinity to {} using {}", T::NAME, rm),
         },
         float_either_zero!() => (T::ZERO, Ordering::Equal),
@@ -416,19 +450,25 @@ where
             if sign {
                 if exponent < 0 {
                     match rm {
-                        RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                            (T::ZERO, Ordering::Less)
+                        RoundingMode::Floor
+                        | RoundingMode::Down
+                        | RoundingMode::Nearest
+                        | RoundingMode::NearestTiesAway
+                        | RoundingMode::NearestTiesTowardZero => (T::ZERO, Ordering::Less),
+                        RoundingMode::Ceiling | RoundingMode::Up | RoundingMode::Odd => {
+                            (T::ONE, Ordering::Greater)
                         }
-                        RoundingMode::Ceiling | RoundingMode::Up => (T::ONE, Ordering::Greater),
                         RoundingMode::Exact => {
                             panic!("Cannot convert Float to Integer using {rm}")
                         }
                     }
                 } else if exponent >= i64::wrapping_from(T::WIDTH) {
                     match rm {
-                        RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                            (T::MAX, Ordering::Less)
-                        }
+                        RoundingMode::Floor
+                        | RoundingMode::Down
+                        | RoundingMode::Nearest
+                        | RoundingMode::NearestTiesAway
+                        | RoundingMode::NearestTiesTowardZero => (T::MAX, Ordering::Less),
                         _ => {
                             panic!("Cannot convert Float to Integer using {rm}")
                         }
@@ -445,9 +485,11 @@ where
                         (n, o)
                     } else {
                         match rm {
-                            RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                                (T::MAX, Ordering::Less)
-                            }
+                            RoundingMode::Floor
+                            | RoundingMode::Down
+                            | RoundingMode::Nearest
+                            | RoundingMode::NearestTiesAway
+                            | RoundingMode::NearestTiesTowardZero => (T::MAX, Ordering::Less),
                             _ => {
                                 panic!("Cannot convert large Float to {} using {}", T::NAME, rm)
                             }
@@ -457,10 +499,14 @@ where
                 }
             } else if exponent < 0 {
                 match rm {
-                    RoundingMode::Ceiling | RoundingMode::Down | RoundingMode::Nearest => {
-                        (T::ZERO, Ordering::Greater)
+                    RoundingMode::Ceiling
+                    | RoundingMode::Down
+                    | RoundingMode::Nearest
+                    | RoundingMode::NearestTiesAway
+                    | RoundingMode::NearestTiesTowardZero => (T::ZERO, Ordering::Greater),
+                    RoundingMode::Floor | RoundingMode::Up | RoundingMode::Odd => {
+                        (T::NEGATIVE_ONE, Ordering::Less)
                     }
-                    RoundingMode::Floor | RoundingMode::Up => (T::NEGATIVE_ONE, Ordering::Less),
                     RoundingMode::Exact => {
                         panic!("Cannot convert Float to Integer using {rm}")
                     }
@@ -469,9 +515,11 @@ where
                 // This doesn't catch the case where -2^(W+1) < x < -2^W, but that's ok
                 // because the next else block handles it.
                 match rm {
-                    RoundingMode::Ceiling | RoundingMode::Down | RoundingMode::Nearest => {
-                        (T::MIN, Ordering::Greater)
-                    }
+                    RoundingMode::Ceiling
+                    | RoundingMode::Down
+                    | RoundingMode::Nearest
+                    | RoundingMode::NearestTiesAway
+                    | RoundingMode::NearestTiesTowardZero => (T::MIN, Ordering::Greater),
                     _ => {
                         panic!("Cannot convert Float to Integer using {rm}")
                     }
@@ -488,9 +536,11 @@ where
                     (n, o.reverse())
                 } else {
                     match rm {
-                        RoundingMode::Ceiling | RoundingMode::Down | RoundingMode::Nearest => {
-                            (T::MIN, Ordering::Greater)
-                        }
+                        RoundingMode::Ceiling
+                        | RoundingMode::Down
+                        | RoundingMode::Nearest
+                        | RoundingMode::NearestTiesAway
+                        | RoundingMode::NearestTiesTowardZero => (T::MIN, Ordering::Greater),
                         _ => panic!(
                             "Cannot convert large negative Float to {} using {}",
                             T::NAME,
@@ -512,15 +562,19 @@ where
     match f {
         float_nan!() => panic!("Can't convert NaN to {}", T::NAME),
         float_infinity!() => match rm {
-            RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                (T::MAX, Ordering::Less)
-            }
+            RoundingMode::Floor
+            | RoundingMode::Down
+            | RoundingMode::Nearest
+            | RoundingMode::NearestTiesAway
+            | RoundingMode::NearestTiesTowardZero => (T::MAX, Ordering::Less),
             _ => panic!("Can't convert Infinity to {} using {}", T::NAME, rm),
         },
         float_negative_infinity!() => match rm {
-            RoundingMode::Ceiling | RoundingMode::Down | RoundingMode::Nearest => {
-                (T::MIN, Ordering::Greater)
-            }
+            RoundingMode::Ceiling
+            | RoundingMode::Down
+            | RoundingMode::Nearest
+            | RoundingMode::NearestTiesAway
+            | RoundingMode::NearestTiesTowardZero => (T::MIN, Ordering::Greater),
             _ => panic!("Can't convert -Infinity to {} using {}", T::NAME, rm),
         },
         float_either_zero!() => (T::ZERO, Ordering::Equal),
@@ -533,19 +587,25 @@ where
             if *sign {
                 if *exponent < 0 {
                     match rm {
-                        RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                            (T::ZERO, Ordering::Less)
+                        RoundingMode::Floor
+                        | RoundingMode::Down
+                        | RoundingMode::Nearest
+                        | RoundingMode::NearestTiesAway
+                        | RoundingMode::NearestTiesTowardZero => (T::ZERO, Ordering::Less),
+                        RoundingMode::Ceiling | RoundingMode::Up | RoundingMode::Odd => {
+                            (T::ONE, Ordering::Greater)
                         }
-                        RoundingMode::Ceiling | RoundingMode::Up => (T::ONE, Ordering::Greater),
                         RoundingMode::Exact => {
                             panic!("Cannot convert Float to Integer using {rm}")
                         }
                     }
                 } else if *exponent >= i64::wrapping_from(T::WIDTH) {
                     match rm {
-                        RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                            (T::MAX, Ordering::Less)
-                        }
+                        RoundingMode::Floor
+                        | RoundingMode::Down
+                        | RoundingMode::Nearest
+                        | RoundingMode::NearestTiesAway
+                        | RoundingMode::NearestTiesTowardZero => (T::MAX, Ordering::Less),
                         _ => {
                             panic!("Cannot convert Float to Integer using {rm}")
                         }
@@ -562,9 +622,11 @@ where
                         (n, o)
                     } else {
                         match rm {
-                            RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                                (T::MAX, Ordering::Less)
-                            }
+                            RoundingMode::Floor
+                            | RoundingMode::Down
+                            | RoundingMode::Nearest
+                            | RoundingMode::NearestTiesAway
+                            | RoundingMode::NearestTiesTowardZero => (T::MAX, Ordering::Less),
                             _ => {
                                 panic!("Cannot convert large Float to {} using {}", T::NAME, rm)
                             }
@@ -574,10 +636,14 @@ where
                 }
             } else if *exponent < 0 {
                 match rm {
-                    RoundingMode::Ceiling | RoundingMode::Down | RoundingMode::Nearest => {
-                        (T::ZERO, Ordering::Greater)
+                    RoundingMode::Ceiling
+                    | RoundingMode::Down
+                    | RoundingMode::Nearest
+                    | RoundingMode::NearestTiesAway
+                    | RoundingMode::NearestTiesTowardZero => (T::ZERO, Ordering::Greater),
+                    RoundingMode::Floor | RoundingMode::Up | RoundingMode::Odd => {
+                        (T::NEGATIVE_ONE, Ordering::Less)
                     }
-                    RoundingMode::Floor | RoundingMode::Up => (T::NEGATIVE_ONE, Ordering::Less),
                     RoundingMode::Exact => {
                         panic!("Cannot convert Float to Integer using {rm}")
                     }
@@ -586,9 +652,11 @@ where
                 // This doesn't catch the case where -2^(W+1) < x < -2^W, but that's ok
                 // because the next else block handles it.
                 match rm {
-                    RoundingMode::Ceiling | RoundingMode::Down | RoundingMode::Nearest => {
-                        (T::MIN, Ordering::Greater)
-                    }
+                    RoundingMode::Ceiling
+                    | RoundingMode::Down
+                    | RoundingMode::Nearest
+                    | RoundingMode::NearestTiesAway
+                    | RoundingMode::NearestTiesTowardZero => (T::MIN, Ordering::Greater),
                     _ => {
                         panic!("Cannot convert Float to Integer using {rm}")
                     }
@@ -605,9 +673,11 @@ where
                     (n, o.reverse())
                 } else {
                     match rm {
-                        RoundingMode::Ceiling | RoundingMode::Down | RoundingMode::Nearest => {
-                            (T::MIN, Ordering::Greater)
-                        }
+                        RoundingMode::Ceiling
+                        | RoundingMode::Down
+                        | RoundingMode::Nearest
+                        | RoundingMode::NearestTiesAway
+                        | RoundingMode::NearestTiesTowardZero => (T::MIN, Ordering::Greater),
                         _ => panic!(
                             "Cannot convert large negative Float to {} using {}",
                             T::NAME,
@@ -736,13 +806,13 @@ macro_rules! impl_signed_from {
             ///
             /// If the [`Float`] is less than the minimum representable value of the signed type
             /// (including negative infinity), then it will be rounded to zero when the
-            /// [`RoundingMode`] is `Ceiling`, `Down`, or `Nearest`. Otherwise, this function will
-            /// panic.
+            /// [`RoundingMode`] is `Ceiling`, `Down`, `Nearest`, `NearestTiesAway`, or
+            /// `NearestTiesTowardZero`. Otherwise, this function will panic.
             ///
             /// If the [`Float`] is greater than the maximum representable value of the signed type
             /// (including infinity), then it will be rounded to the maximum value when the
-            /// [`RoundingMode`] is `Floor`, `Down`, or `Nearest`. Otherwise, this function will
-            /// panic.
+            /// [`RoundingMode`] is `Floor`, `Down`, `Nearest`, `NearestTiesAway`, or
+            /// `NearestTiesTowardZero`. Otherwise, this function will panic.
             ///
             /// If the [`Float`] is NaN, the function will panic regardless of the rounding mode.
             ///
@@ -752,9 +822,10 @@ macro_rules! impl_signed_from {
             /// # Panics
             /// Panics if the [`Float`] is not an integer and `rm` is `Exact`, or if the [`Float`]
             /// is smaller than the minimum representable value of the signed type and `rm` is not
-            /// `Down`, `Ceiling`, or `Nearest`, if the [`Float`] is greater than the maximum
-            /// representable value of the signed type and `rm` is not `Down`, `Floor`, or
-            /// `Nearest`, or if the [`Float`] is NaN.
+            /// `Down`, `Ceiling`, `Nearest`, `NearestTiesAway`, or `NearestTiesTowardZero`, if the
+            /// [`Float`] is greater than the maximum representable value of the signed type and
+            /// `rm` is not `Down`, `Floor`, `Nearest`, `NearestTiesAway`, or
+            /// `NearestTiesTowardZero`, or if the [`Float`] is NaN.
             ///
             /// # Examples
             /// See [here](super::primitive_int_from_float#rounding_from).
@@ -772,13 +843,13 @@ macro_rules! impl_signed_from {
             ///
             /// If the [`Float`] is less than the minimum representable value of the signed type
             /// (including negative infinity), then it will be rounded to zero when the
-            /// [`RoundingMode`] is `Ceiling`, `Down`, or `Nearest`. Otherwise, this function will
-            /// panic.
+            /// [`RoundingMode`] is `Ceiling`, `Down`, `Nearest`, `NearestTiesAway`, or
+            /// `NearestTiesTowardZero`. Otherwise, this function will panic.
             ///
             /// If the [`Float`] is greater than the maximum representable value of the signed type
             /// (including infinity), then it will be rounded to the maximum value when the
-            /// [`RoundingMode`] is `Floor`, `Down`, or `Nearest`. Otherwise, this function will
-            /// panic.
+            /// [`RoundingMode`] is `Floor`, `Down`, `Nearest`, `NearestTiesAway`, or
+            /// `NearestTiesTowardZero`. Otherwise, this function will panic.
             ///
             /// If the [`Float`] is NaN, the function will panic regardless of the rounding mode.
             ///
@@ -788,9 +859,10 @@ macro_rules! impl_signed_from {
             /// # Panics
             /// Panics if the [`Float`] is not an integer and `rm` is `Exact`, or if the [`Float`]
             /// is smaller than the minimum representable value of the signed type and `rm` is not
-            /// `Down`, `Ceiling`, or `Nearest`, if the [`Float`] is greater than the maximum
-            /// representable value of the signed type and `rm` is not `Down`, `Floor`, or
-            /// `Nearest`, or if the [`Float`] is NaN.
+            /// `Down`, `Ceiling`, `Nearest`, `NearestTiesAway`, or `NearestTiesTowardZero`, if the
+            /// [`Float`] is greater than the maximum representable value of the signed type and
+            /// `rm` is not `Down`, `Floor`, `Nearest`, `NearestTiesAway`, or
+            /// `NearestTiesTowardZero`, or if the [`Float`] is NaN.
             ///
             /// # Examples
             /// See [here](super::primitive_int_from_float#rounding_from).
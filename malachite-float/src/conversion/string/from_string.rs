@@ -1,49 +1,113 @@
 use crate::Float;
 use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+use malachite_base::num::conversion::string::from_string::string_base_error;
 use malachite_base::num::conversion::string::options::FromSciStringOptions;
+use malachite_base::num::conversion::string::parse_error::{
+    ParseNumberError, ParseNumberErrorKind,
+};
 use malachite_base::num::conversion::traits::{FromSciString, FromStringBase};
 use malachite_base::rounding_modes::RoundingMode;
 use malachite_q::Rational;
+use std::cmp::Ordering;
 use std::str::FromStr;
 
-fn from_hex_string(s: &str) -> Float {
+fn from_hex_string(s: &str) -> Result<Float, ParseNumberError> {
     match s {
-        "NaN" => Float::NAN,
-        "Infinity" => Float::INFINITY,
-        "-Infinity" => Float::NEGATIVE_INFINITY,
-        "0x0.0" => Float::ZERO,
-        "-0x0.0" => Float::NEGATIVE_ZERO,
-        s => {
-            let (s, sign) = if let Some(s) = s.strip_prefix('-') {
-                (s, false)
-            } else {
-                (s, true)
-            };
-            let s = s.strip_prefix("0x").unwrap();
-            let hash_index = s.find('#').unwrap();
-            let precision = u64::from_str(&s[hash_index + 1..]).unwrap();
-            let mut options = FromSciStringOptions::default();
-            options.set_base(16);
-            let x = Float::from_rational_prec_round(
-                Rational::from_sci_string_with_options(&s[..hash_index], options).unwrap(),
-                precision,
-                RoundingMode::Exact,
-            )
-            .0;
-            if sign {
-                x
-            } else {
-                -x
-            }
+        "NaN" => return Ok(Float::NAN),
+        "Infinity" => return Ok(Float::INFINITY),
+        "-Infinity" => return Ok(Float::NEGATIVE_INFINITY),
+        "0x0.0" => return Ok(Float::ZERO),
+        "-0x0.0" => return Ok(Float::NEGATIVE_ZERO),
+        _ => {}
+    }
+    let (abs_s, sign) = if let Some(abs_s) = s.strip_prefix('-') {
+        (abs_s, false)
+    } else {
+        (s, true)
+    };
+    let offset = s.len() - abs_s.len();
+    let invalid = |i| ParseNumberError::new(ParseNumberErrorKind::InvalidDigit(i));
+    if abs_s.is_empty() {
+        return Err(ParseNumberError::new(ParseNumberErrorKind::Empty));
+    }
+    let significand = match abs_s.strip_prefix("0x") {
+        Some(significand) => significand,
+        None if abs_s.starts_with('0') => return Err(invalid(offset + 1)),
+        None => return Err(invalid(offset)),
+    };
+    let offset = offset + 2;
+    let hash_index = match significand.find('#') {
+        Some(i) => i,
+        None => return Err(invalid(offset + significand.len())),
+    };
+    let (significand, precision) = (&significand[..hash_index], &significand[hash_index + 1..]);
+    if significand.starts_with(['+', '-']) {
+        return Err(invalid(offset));
+    }
+    let mut options = FromSciStringOptions::default();
+    options.set_base(16);
+    let x = Rational::from_sci_string_with_options(significand, options)
+        .map_err(|e| e.offset_by(offset))?;
+    let precision_offset = offset + hash_index + 1;
+    let precision = match u64::from_str(precision) {
+        Ok(0) => return Err(invalid(precision_offset)),
+        Ok(precision) => precision,
+        Err(_) => {
+            return Err(string_base_error(precision, 10, false, false).offset_by(precision_offset))
         }
+    };
+    let (x, o) = Float::from_rational_prec_round(x, precision, RoundingMode::Floor);
+    if o != Ordering::Equal {
+        return Err(ParseNumberError::new(ParseNumberErrorKind::Inexact));
     }
+    Ok(if sign { x } else { -x })
 }
 
 impl FromStringBase for Float {
-    fn from_string_base(base: u8, s: &str) -> Option<Self> {
+    /// Converts a string, in base 16, to a [`Float`].
+    ///
+    /// The string must have the format produced by formatting a
+    /// [`ComparableFloat`](crate::ComparableFloat) with `{:#x}`: `"NaN"`, `"Infinity"`,
+    /// `"-Infinity"`, `"0x0.0"`, `"-0x0.0"`, or an optional `'-'` followed by `"0x"`, a
+    /// hexadecimal significand, `'#'`, and a positive decimal precision. The significand must be
+    /// exactly representable with the given precision.
+    ///
+    /// If the string is invalid, a [`ParseNumberError`] describing the problem is returned. Bases
+    /// other than 16 yield an error of kind [`InvalidBase`](ParseNumberErrorKind::InvalidBase),
+    /// and significands that cannot be represented with the given precision yield an error of
+    /// kind [`Inexact`](ParseNumberErrorKind::Inexact).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
+    /// use malachite_base::num::conversion::traits::FromStringBase;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::from_string_base(16, "0x1.8#2").unwrap(), 1.5);
+    /// assert_eq!(Float::from_string_base(16, "-0x3.0#2").unwrap(), -3.0);
+    /// assert!(Float::from_string_base(16, "NaN").unwrap().is_nan());
+    ///
+    /// let kind = |base, s| Float::from_string_base(base, s).unwrap_err().kind();
+    /// assert_eq!(kind(10, "0x1.8#2"), ParseNumberErrorKind::InvalidBase(10));
+    /// assert_eq!(kind(16, "1.8#2"), ParseNumberErrorKind::InvalidDigit(0));
+    /// assert_eq!(kind(16, "0x1.8"), ParseNumberErrorKind::InvalidDigit(5));
+    /// assert_eq!(kind(16, "0x1.8#"), ParseNumberErrorKind::Empty);
+    /// assert_eq!(kind(16, "0x1.g#2"), ParseNumberErrorKind::InvalidDigit(4));
+    /// assert_eq!(kind(16, "0x1.8#1"), ParseNumberErrorKind::Inexact);
+    /// ```
+    fn from_string_base(base: u8, s: &str) -> Result<Float, ParseNumberError> {
         if base != 16 {
-            panic!();
+            return Err(ParseNumberError::new(ParseNumberErrorKind::InvalidBase(
+                base,
+            )));
         }
-        Some(from_hex_string(s))
+        from_hex_string(s)
     }
 }
@@ -1,2 +1,3 @@
 pub mod from_string;
+pub mod to_formatted_string;
 pub mod to_string;
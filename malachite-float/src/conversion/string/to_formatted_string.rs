@@ -0,0 +1,64 @@
+use crate::Float;
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use malachite_base::num::conversion::string::options::FormatOptions;
+use malachite_base::num::conversion::string::to_formatted_string::format_digits;
+use malachite_base::num::conversion::traits::{ExactFrom, ToFormattedString};
+use malachite_q::Rational;
+
+impl ToFormattedString for Float {
+    /// Converts a [`Float`] to a [`String`], using the specified [`FormatOptions`].
+    ///
+    /// A finite [`Float`] is formatted like the [`Rational`] that it represents: the digits before
+    /// the point are grouped and separated according to the options, and if the options specify a
+    /// number of fraction digits, the value is rounded to that many digits after the point, using
+    /// the options' rounding mode. Otherwise, the value is written exactly, unless its expansion in
+    /// the base doesn't terminate, which can only happen if the base is odd; then it is rounded to
+    /// the options' non-terminating fraction digits, which by default are
+    /// [`FormatOptions::NON_TERMINATING_FRACTION_DIGITS`]. Zeros keep their sign.
+    /// NaN and infinities are written as `"NaN"`, `"Infinity"`, and `"-Infinity"`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.get_exponent()` plus
+    /// `self.get_prec()`, plus the number of fraction digits in `options`.
+    ///
+    /// # Panics
+    /// Panics if the rounding mode is `Exact` and the [`Float`] cannot be represented exactly with
+    /// the number of fraction digits that are shown, or if the fraction digits are unspecified,
+    /// the expansion of the [`Float`] doesn't terminate, and the non-terminating fraction digits are
+    /// unspecified too.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NegativeZero};
+    /// use malachite_base::num::conversion::string::options::{DigitGrouping, FormatOptions};
+    /// use malachite_base::num::conversion::traits::ToFormattedString;
+    /// use malachite_float::Float;
+    ///
+    /// let mut options = FormatOptions::default();
+    /// options.set_digit_grouping(DigitGrouping::Uniform(3));
+    /// assert_eq!(Float::from(1234567.25).to_formatted_string(options), "1,234,567.25");
+    /// assert_eq!(Float::from(-1234567.25).to_formatted_string(options), "-1,234,567.25");
+    /// assert_eq!(Float::INFINITY.to_formatted_string(options), "Infinity");
+    ///
+    /// options.set_fraction_digits(1);
+    /// assert_eq!(Float::from(1234567.25).to_formatted_string(options), "1,234,567.2");
+    /// assert_eq!(Float::NEGATIVE_ZERO.to_formatted_string(options), "-0.0");
+    ///
+    /// let mut options = FormatOptions::default();
+    /// options.set_base(3);
+    /// assert_eq!(Float::from(0.5).to_formatted_string(options), "0.11111111111111111111");
+    /// ```
+    fn to_formatted_string(&self, options: FormatOptions) -> String {
+        match self {
+            Float(NaN) => "NaN".to_string(),
+            Float(Infinity { sign: true }) => "Infinity".to_string(),
+            Float(Infinity { sign: false }) => "-Infinity".to_string(),
+            Float(Zero { sign }) => format_digits(!*sign, "0", "", options),
+            Float(Finite { .. }) => Rational::exact_from(self).to_formatted_string(options),
+        }
+    }
+}
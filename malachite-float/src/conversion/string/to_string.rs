@@ -2,49 +2,179 @@ use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
 use crate::{ComparableFloat, ComparableFloatRef, Float};
 use malachite_base::num::arithmetic::traits::{Abs, ModPowerOf2, ShrRound};
 use malachite_base::num::conversion::string::options::ToSciOptions;
+use malachite_base::num::conversion::string::to_sci::{exp_options, pad_exp};
 use malachite_base::num::conversion::traits::{ExactFrom, ToSci};
 use malachite_base::rounding_modes::RoundingMode;
 use malachite_q::Rational;
-use std::fmt::{Debug, Display, Formatter, LowerHex, Result, Write};
+use std::fmt::{Alignment, Debug, Display, Formatter, LowerExp, LowerHex, Result, UpperExp, Write};
+
+// Returns the shortest base-10 string, in the format produced by `ToSci`, that distinguishes the
+// absolute value of a finite, nonzero `Float` from its neighbors.
+fn shortest_abs_sci_string(x: &Float) -> String {
+    let x = x.abs();
+    let mut lower = x.clone();
+    let mut higher = x.clone();
+    lower.decrement();
+    higher.increment();
+    let x_q = Rational::exact_from(x);
+    let lower_q = Rational::exact_from(lower);
+    let higher_q = Rational::exact_from(higher);
+    let mut options = ToSciOptions::default();
+    for precision in 1.. {
+        options.set_precision(precision);
+        let s = x_q.to_sci_with_options(options).to_string();
+        let s_lower = lower_q.to_sci_with_options(options).to_string();
+        let s_higher = higher_q.to_sci_with_options(options).to_string();
+        if s != s_lower && s != s_higher {
+            return s;
+        }
+    }
+    panic!();
+}
+
+// Pads `NaN` or an infinity. Zero-padding is not applied to these values; the fill character is
+// used instead.
+fn pad_non_finite(f: &mut Formatter, s: &str) -> Result {
+    let padding = f.width().map_or(0, |width| width.saturating_sub(s.len()));
+    let (before, after) = match f.align() {
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding >> 1, (padding + 1) >> 1),
+        _ => (padding, 0),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(s)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+fn fmt_infinity(f: &mut Formatter, sign: bool) -> Result {
+    pad_non_finite(
+        f,
+        match (sign, f.sign_plus()) {
+            (true, false) => "Infinity",
+            (true, true) => "+Infinity",
+            (false, _) => "-Infinity",
+        },
+    )
+}
 
 impl Display for Float {
     fn fmt(&self, f: &mut Formatter) -> Result {
         match self {
-            float_nan!() => write!(f, "NaN"),
-            float_infinity!() => write!(f, "Infinity"),
-            float_negative_infinity!() => write!(f, "-Infinity"),
-            float_zero!() => write!(f, "0.0"),
-            float_negative_zero!() => write!(f, "-0.0"),
+            float_nan!() => pad_non_finite(f, "NaN"),
+            float_infinity!() => fmt_infinity(f, true),
+            float_negative_infinity!() => fmt_infinity(f, false),
+            float_zero!() | float_negative_zero!() => {
+                let s = match f.precision() {
+                    Some(0) => "0".to_string(),
+                    Some(precision) => format!("0.{}", "0".repeat(precision)),
+                    None => "0.0".to_string(),
+                };
+                f.pad_integral(!self.is_sign_negative(), "", &s)
+            }
             _ => {
-                let mut lower = self.clone();
-                let mut higher = self.clone();
-                lower.decrement();
-                higher.increment();
-                let self_q = Rational::exact_from(self);
-                let lower_q = Rational::exact_from(lower);
-                let higher_q = Rational::exact_from(higher);
-                let mut options = ToSciOptions::default();
-                for precision in 1.. {
-                    options.set_precision(precision);
-                    let s = self_q.to_sci_with_options(options).to_string();
-                    let s_lower = lower_q.to_sci_with_options(options).to_string();
-                    let s_higher = higher_q.to_sci_with_options(options).to_string();
-                    if s != s_lower && s != s_higher {
-                        return if s.contains('.') {
-                            write!(f, "{s}")
-                        } else if let Some(i) = s.find('e') {
-                            write!(f, "{}.0e{}", &s[..i], &s[i + 1..])
-                        } else {
-                            write!(f, "{s}.0")
-                        };
+                let s = if let Some(precision) = f.precision() {
+                    let mut options = ToSciOptions::default();
+                    options.set_scale(u64::exact_from(precision));
+                    options.set_include_trailing_zeros(true);
+                    options.set_neg_exp_threshold(i64::MIN);
+                    Rational::exact_from(self)
+                        .abs()
+                        .to_sci_with_options(options)
+                        .to_string()
+                } else {
+                    let s = shortest_abs_sci_string(self);
+                    if s.contains('.') {
+                        s
+                    } else if let Some(i) = s.find('e') {
+                        format!("{}.0e{}", &s[..i], &s[i + 1..])
+                    } else {
+                        format!("{s}.0")
                     }
-                }
-                panic!();
+                };
+                f.pad_integral(!self.is_sign_negative(), "", &s)
             }
         }
     }
 }
 
+fn fmt_exp(x: &Float, f: &mut Formatter, e_lowercase: bool) -> Result {
+    match x {
+        float_nan!() => pad_non_finite(f, "NaN"),
+        float_infinity!() => fmt_infinity(f, true),
+        float_negative_infinity!() => fmt_infinity(f, false),
+        float_zero!() | float_negative_zero!() => {
+            pad_exp(f, !x.is_sign_negative(), "0", e_lowercase)
+        }
+        _ => {
+            let s = if f.precision().is_some() {
+                Rational::exact_from(x)
+                    .abs()
+                    .to_sci_with_options(exp_options(f, false))
+                    .to_string()
+            } else {
+                shortest_abs_sci_string(x)
+            };
+            pad_exp(f, !x.is_sign_negative(), &s, e_lowercase)
+        }
+    }
+}
+
+impl LowerExp for Float {
+    /// Formats a [`Float`] in scientific notation, using a lowercase `'e'`.
+    ///
+    /// If a precision is specified, the significand is rounded to that many digits after the
+    /// decimal point, using [`Nearest`](RoundingMode::Nearest) rounding. Otherwise, the shortest
+    /// significand that distinguishes the [`Float`] from its neighbors is used, as in the
+    /// [`Display`] implementation. The width, fill, alignment, sign, and zero-padding flags work
+    /// as they do for primitive floats.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NegativeZero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(format!("{:e}", Float::from(1.5)), "1.5e0");
+    /// assert_eq!(format!("{:e}", Float::from(-1024)), "-1.024e3");
+    /// assert_eq!(format!("{:.3e}", Float::from(0.1)), "1.000e-1");
+    /// assert_eq!(format!("{:e}", Float::NEGATIVE_ZERO), "-0e0");
+    /// assert_eq!(format!("{:+e}", Float::INFINITY), "+Infinity");
+    /// assert_eq!(format!("{:>10.2e}", Float::from(1.5)), "    1.50e0");
+    /// assert_eq!(format!("{:010.2e}", Float::from(-1.5)), "-0001.50e0");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        fmt_exp(self, f, true)
+    }
+}
+
+impl UpperExp for Float {
+    /// Formats a [`Float`] in scientific notation, using an uppercase `'E'`.
+    ///
+    /// If a precision is specified, the significand is rounded to that many digits after the
+    /// decimal point, using [`Nearest`](RoundingMode::Nearest) rounding. Otherwise, the shortest
+    /// significand that distinguishes the [`Float`] from its neighbors is used, as in the
+    /// [`Display`] implementation. The width, fill, alignment, sign, and zero-padding flags work
+    /// as they do for primitive floats.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(format!("{:E}", Float::from(-1024)), "-1.024E3");
+    /// assert_eq!(format!("{:.3E}", Float::from(0.1)), "1.000E-1");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        fmt_exp(self, f, false)
+    }
+}
+
 impl Debug for Float {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> Result {
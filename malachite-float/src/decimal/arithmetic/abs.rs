@@ -0,0 +1,85 @@
+use crate::decimal::Decimal;
+use malachite_base::num::arithmetic::traits::{Abs, AbsAssign};
+
+impl Abs for Decimal {
+    type Output = Decimal;
+
+    /// Takes the absolute value of a [`Decimal`], taking it by value. The exponent is unchanged.
+    ///
+    /// $$
+    /// f(x) = |x|.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Abs;
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// assert_eq!("1.50".parse::<Decimal>().unwrap().abs().to_string(), "1.50");
+    /// assert_eq!("-0.001".parse::<Decimal>().unwrap().abs().to_string(), "0.001");
+    /// ```
+    #[inline]
+    fn abs(mut self) -> Decimal {
+        self.abs_assign();
+        self
+    }
+}
+
+impl<'a> Abs for &'a Decimal {
+    type Output = Decimal;
+
+    /// Takes the absolute value of a [`Decimal`], taking it by reference. The exponent is
+    /// unchanged.
+    ///
+    /// $$
+    /// f(x) = |x|.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.coefficient().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Abs;
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// assert_eq!((&"1.50".parse::<Decimal>().unwrap()).abs().to_string(), "1.50");
+    /// assert_eq!((&"-0.001".parse::<Decimal>().unwrap()).abs().to_string(), "0.001");
+    /// ```
+    fn abs(self) -> Decimal {
+        Decimal::new((&self.coefficient).abs(), self.exponent)
+    }
+}
+
+impl AbsAssign for Decimal {
+    /// Replaces a [`Decimal`] with its absolute value. The exponent is unchanged.
+    ///
+    /// $$
+    /// x \gets |x|.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AbsAssign;
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// let mut x: Decimal = "-1.50".parse().unwrap();
+    /// x.abs_assign();
+    /// assert_eq!(x.to_string(), "1.50");
+    /// ```
+    #[inline]
+    fn abs_assign(&mut self) {
+        self.coefficient.abs_assign();
+    }
+}
@@ -0,0 +1,167 @@
+use crate::decimal::{sub_exponents, Decimal};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+
+impl Decimal {
+    /// Divides two [`Decimal`]s, rounding the result so that its coefficient has at most `prec`
+    /// digits. Both [`Decimal`]s are taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded result is less than, equal to, or greater than the exact
+    /// result.
+    ///
+    /// Since the quotient of two [`Decimal`]s is not always a [`Decimal`], there is no `Div`
+    /// implementation; a precision or scale must always be given.
+    ///
+    /// If the result is exact, trailing zeros are removed from its coefficient, but its exponent is
+    /// not increased beyond `self.exponent() - other.exponent()`. In particular, a zero result has
+    /// that exponent.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x/y+\varepsilon,
+    /// $$
+    /// where $|\varepsilon| < 10^{\lfloor\log_{10} |x/y|\rfloor-p+1}$, or $\varepsilon = 0$ if
+    /// $x/y$ is 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.precision(),
+    /// other.precision(), |self.exponent() - other.exponent()|, prec)`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, if `prec` is zero, if `rm` is `Exact` but the result cannot be
+    /// represented exactly with the given precision, or if `self` is zero and
+    /// `self.exponent() - other.exponent()` does not fit in an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::decimal::Decimal;
+    /// use std::cmp::Ordering;
+    ///
+    /// let x: Decimal = "1".parse().unwrap();
+    /// let y: Decimal = "3".parse().unwrap();
+    /// let (z, o) = x.div_prec_round(&y, 5, RoundingMode::Floor);
+    /// assert_eq!(z.to_string(), "0.33333");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (z, o) = x.div_prec_round(&y, 5, RoundingMode::Ceiling);
+    /// assert_eq!(z.to_string(), "0.33334");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (z, o) = x.div_prec_round(&"0.8".parse().unwrap(), 5, RoundingMode::Exact);
+    /// assert_eq!(z.to_string(), "1.25");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    pub fn div_prec_round(
+        &self,
+        other: &Decimal,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Decimal, Ordering) {
+        assert!(!other.is_zero(), "division by zero");
+        if self.is_zero() {
+            assert_ne!(prec, 0);
+            return (
+                Decimal::new(Integer::ZERO, sub_exponents(self.exponent, other.exponent)),
+                Ordering::Equal,
+            );
+        }
+        let (mut q, o) = Decimal::from_rational_prec_round(
+            Rational::from(self) / Rational::from(other),
+            prec,
+            rm,
+        );
+        if o == Ordering::Equal {
+            // If the ideal exponent is out of range, saturating still gives the right bound, since
+            // the exponent of `q` is in range
+            q.remove_trailing_zeros(self.exponent.saturating_sub(other.exponent));
+        }
+        (q, o)
+    }
+
+    /// Divides two [`Decimal`]s, rounding the result to the nearest value whose coefficient has at
+    /// most `prec` digits. Both [`Decimal`]s are taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result.
+    ///
+    /// If the result is equidistant from two such values, the one with an even last digit is
+    /// chosen. See [`Decimal::div_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.precision(),
+    /// other.precision(), |self.exponent() - other.exponent()|, prec)`.
+    ///
+    /// # Panics
+    /// Panics if `other` or `prec` is zero, or if `self` is zero and
+    /// `self.exponent() - other.exponent()` does not fit in an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    /// use std::cmp::Ordering;
+    ///
+    /// let x: Decimal = "2".parse().unwrap();
+    /// let y: Decimal = "3".parse().unwrap();
+    /// let (z, o) = x.div_prec(&y, 5);
+    /// assert_eq!(z.to_string(), "0.66667");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn div_prec(&self, other: &Decimal, prec: u64) -> (Decimal, Ordering) {
+        self.div_prec_round(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Divides two [`Decimal`]s, rounding the result so that it has the given scale; that is, so
+    /// that its exponent is `-scale`. Both [`Decimal`]s are taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded result is less than, equal to, or greater
+    /// than the exact result.
+    ///
+    /// $$
+    /// f(x,y,s,m) = x/y+\varepsilon,
+    /// $$
+    /// where $|\varepsilon| < 10^{-s}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.precision(),
+    /// other.precision(), |self.exponent() - other.exponent()|, |scale|)`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, if `scale` is `i64::MIN`, or if `rm` is `Exact` but the result
+    /// cannot be represented exactly with the given scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::decimal::Decimal;
+    /// use std::cmp::Ordering;
+    ///
+    /// // Split $100 three ways
+    /// let x: Decimal = "100.00".parse().unwrap();
+    /// let y: Decimal = "3".parse().unwrap();
+    /// let (z, o) = x.div_scale_round(&y, 2, RoundingMode::Down);
+    /// assert_eq!(z.to_string(), "33.33");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    pub fn div_scale_round(
+        &self,
+        other: &Decimal,
+        scale: i64,
+        rm: RoundingMode,
+    ) -> (Decimal, Ordering) {
+        assert!(!other.is_zero(), "division by zero");
+        Decimal::from_rational_scale_round(Rational::from(self) / Rational::from(other), scale, rm)
+    }
+}
@@ -0,0 +1,16 @@
+/// Absolute value of [`Decimal`](super::Decimal)s.
+pub mod abs;
+/// Addition of [`Decimal`](super::Decimal)s.
+pub mod add;
+/// Division of [`Decimal`](super::Decimal)s.
+pub mod div;
+/// Multiplication of [`Decimal`](super::Decimal)s.
+pub mod mul;
+/// Negation of [`Decimal`](super::Decimal)s.
+pub mod neg;
+/// Rescaling, quantizing, and rounding [`Decimal`](super::Decimal)s to a precision.
+pub mod round;
+/// Square roots of [`Decimal`](super::Decimal)s.
+pub mod sqrt;
+/// Subtraction of [`Decimal`](super::Decimal)s.
+pub mod sub;
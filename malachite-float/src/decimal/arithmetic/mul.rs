@@ -0,0 +1,356 @@
+use crate::decimal::{add_exponents, negate_exponent, Decimal};
+use malachite_base::rounding_modes::RoundingMode;
+use std::cmp::Ordering;
+use std::ops::{Mul, MulAssign};
+
+impl Decimal {
+    /// Multiplies two [`Decimal`]s, rounding the result so that its coefficient has at most `prec`
+    /// digits. Both [`Decimal`]s are taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded result is less than, equal to, or greater than the exact
+    /// result.
+    ///
+    /// $$
+    /// f(x,y,p,m) = xy+\varepsilon,
+    /// $$
+    /// where $|\varepsilon| < 10^{\lfloor\log_{10} |xy|\rfloor-p+1}$, or
+    /// $\varepsilon = 0$ if $xy$ is 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.precision(),
+    /// other.precision(), |self.exponent() - other.exponent()|)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, if the sum of the exponents of the inputs does not fit in an
+    /// [`i64`], or if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::decimal::Decimal;
+    /// use std::cmp::Ordering;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "3.1416".parse().unwrap();
+    /// let (z, o) = x.mul_prec_round(&y, 3, RoundingMode::Floor);
+    /// assert_eq!(z.to_string(), "3.92");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (z, o) = x.mul_prec_round(&y, 3, RoundingMode::Ceiling);
+    /// assert_eq!(z.to_string(), "3.93");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    pub fn mul_prec_round(
+        &self,
+        other: &Decimal,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Decimal, Ordering) {
+        (self * other).round_to_precision(prec, rm)
+    }
+
+    /// Multiplies two [`Decimal`]s, rounding the result to the nearest value whose coefficient has
+    /// at most `prec` digits. Both [`Decimal`]s are taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than
+    /// the exact result.
+    ///
+    /// If the result is equidistant from two such values, the one with an even last digit is
+    /// chosen. See [`Decimal::mul_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.precision(),
+    /// other.precision(), |self.exponent() - other.exponent()|)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the sum of the exponents of the inputs does not fit in an
+    /// [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    /// use std::cmp::Ordering;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "3.1416".parse().unwrap();
+    /// let (z, o) = x.mul_prec(&y, 3);
+    /// assert_eq!(z.to_string(), "3.93");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn mul_prec(&self, other: &Decimal, prec: u64) -> (Decimal, Ordering) {
+        self.mul_prec_round(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Multiplies two [`Decimal`]s, rounding the result so that it has the given scale; that is, so
+    /// that its exponent is `-scale`. Both [`Decimal`]s are taken by reference. An [`Ordering`]
+    /// is also returned, indicating whether the rounded result is less than, equal to, or greater
+    /// than the exact result.
+    ///
+    /// $$
+    /// f(x,y,s,m) = xy+\varepsilon,
+    /// $$
+    /// where $|\varepsilon| < 10^{-s}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.precision(),
+    /// other.precision(), |self.exponent() - other.exponent()|, |self.exponent() + scale|)`.
+    ///
+    /// # Panics
+    /// Panics if `scale` is `i64::MIN`, if the sum of the exponents of the inputs does not fit in
+    /// an [`i64`], or if `rm` is `Exact` but the result cannot be represented exactly with the
+    /// given scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::decimal::Decimal;
+    /// use std::cmp::Ordering;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "3.1416".parse().unwrap();
+    /// let (z, o) = x.mul_scale_round(&y, 1, RoundingMode::Down);
+    /// assert_eq!(z.to_string(), "3.9");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (z, o) = x.mul_scale_round(&y, 5, RoundingMode::Exact);
+    /// assert_eq!(z.to_string(), "3.92700");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    #[inline]
+    pub fn mul_scale_round(
+        &self,
+        other: &Decimal,
+        scale: i64,
+        rm: RoundingMode,
+    ) -> (Decimal, Ordering) {
+        (self * other).rescale(negate_exponent(scale), rm)
+    }
+}
+
+impl Mul<Decimal> for Decimal {
+    type Output = Decimal;
+
+    /// Multiplies two [`Decimal`]s exactly, taking both by value.
+    ///
+    /// The exponent of the result is the sum of the exponents of the inputs.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.coefficient().significant_bits(), other.coefficient().significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if the sum of the exponents of the inputs does not fit in an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "3.1416".parse().unwrap();
+    /// assert_eq!((x * y).to_string(), "3.927000");
+    /// ```
+    #[inline]
+    fn mul(self, other: Decimal) -> Decimal {
+        &self * &other
+    }
+}
+
+impl<'a> Mul<&'a Decimal> for Decimal {
+    type Output = Decimal;
+
+    /// Multiplies two [`Decimal`]s exactly, taking the first by value and the second by reference.
+    ///
+    /// The exponent of the result is the sum of the exponents of the inputs.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.coefficient().significant_bits(), other.coefficient().significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if the sum of the exponents of the inputs does not fit in an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "3.1416".parse().unwrap();
+    /// assert_eq!((x * &y).to_string(), "3.927000");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Decimal) -> Decimal {
+        &self * other
+    }
+}
+
+impl<'a> Mul<Decimal> for &'a Decimal {
+    type Output = Decimal;
+
+    /// Multiplies two [`Decimal`]s exactly, taking the first by reference and the second by value.
+    ///
+    /// The exponent of the result is the sum of the exponents of the inputs.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.coefficient().significant_bits(), other.coefficient().significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if the sum of the exponents of the inputs does not fit in an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "3.1416".parse().unwrap();
+    /// assert_eq!((&x * y).to_string(), "3.927000");
+    /// ```
+    #[inline]
+    fn mul(self, other: Decimal) -> Decimal {
+        self * &other
+    }
+}
+
+impl<'a, 'b> Mul<&'a Decimal> for &'b Decimal {
+    type Output = Decimal;
+
+    /// Multiplies two [`Decimal`]s exactly, taking both by reference.
+    ///
+    /// The exponent of the result is the sum of the exponents of the inputs.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.coefficient().significant_bits(), other.coefficient().significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if the sum of the exponents of the inputs does not fit in an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "3.1416".parse().unwrap();
+    /// assert_eq!((&x * &y).to_string(), "3.927000");
+    /// ```
+    fn mul(self, other: &'a Decimal) -> Decimal {
+        Decimal::new(
+            &self.coefficient * &other.coefficient,
+            add_exponents(self.exponent, other.exponent),
+        )
+    }
+}
+
+impl MulAssign<Decimal> for Decimal {
+    /// Multiplies a [`Decimal`] by another exactly, in place, taking the [`Decimal`] on the
+    /// right-hand side by value.
+    ///
+    /// The exponent of the result is the sum of the exponents of the inputs.
+    ///
+    /// $$
+    /// x \gets xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.coefficient().significant_bits(), other.coefficient().significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if the sum of the exponents of the inputs does not fit in an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// let mut x: Decimal = "1.25".parse().unwrap();
+    /// x *= "3.1416".parse::<Decimal>().unwrap();
+    /// assert_eq!(x.to_string(), "3.927000");
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: Decimal) {
+        *self = &*self * &other;
+    }
+}
+
+impl<'a> MulAssign<&'a Decimal> for Decimal {
+    /// Multiplies a [`Decimal`] by another exactly, in place, taking the [`Decimal`] on the
+    /// right-hand side by reference.
+    ///
+    /// The exponent of the result is the sum of the exponents of the inputs.
+    ///
+    /// $$
+    /// x \gets xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.coefficient().significant_bits(), other.coefficient().significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if the sum of the exponents of the inputs does not fit in an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// let mut x: Decimal = "1.25".parse().unwrap();
+    /// x *= &"3.1416".parse::<Decimal>().unwrap();
+    /// assert_eq!(x.to_string(), "3.927000");
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: &'a Decimal) {
+        *self = &*self * other;
+    }
+}
@@ -0,0 +1,83 @@
+use crate::decimal::Decimal;
+use malachite_base::num::arithmetic::traits::NegAssign;
+use std::ops::Neg;
+
+impl Neg for Decimal {
+    type Output = Decimal;
+
+    /// Negates a [`Decimal`], taking it by value. The exponent is unchanged.
+    ///
+    /// $$
+    /// f(x) = -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// assert_eq!((-"1.50".parse::<Decimal>().unwrap()).to_string(), "-1.50");
+    /// assert_eq!((-"-0.001".parse::<Decimal>().unwrap()).to_string(), "0.001");
+    /// ```
+    #[inline]
+    fn neg(mut self) -> Decimal {
+        self.neg_assign();
+        self
+    }
+}
+
+impl<'a> Neg for &'a Decimal {
+    type Output = Decimal;
+
+    /// Negates a [`Decimal`], taking it by reference. The exponent is unchanged.
+    ///
+    /// $$
+    /// f(x) = -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.coefficient().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// assert_eq!((-&"1.50".parse::<Decimal>().unwrap()).to_string(), "-1.50");
+    /// assert_eq!((-&"-0.001".parse::<Decimal>().unwrap()).to_string(), "0.001");
+    /// ```
+    fn neg(self) -> Decimal {
+        Decimal::new(-&self.coefficient, self.exponent)
+    }
+}
+
+impl NegAssign for Decimal {
+    /// Negates a [`Decimal`] in place. The exponent is unchanged.
+    ///
+    /// $$
+    /// x \gets -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::NegAssign;
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// let mut x: Decimal = "1.50".parse().unwrap();
+    /// x.neg_assign();
+    /// assert_eq!(x.to_string(), "-1.50");
+    /// ```
+    #[inline]
+    fn neg_assign(&mut self) {
+        self.coefficient.neg_assign();
+    }
+}
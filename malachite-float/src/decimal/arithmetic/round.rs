@@ -0,0 +1,192 @@
+use crate::decimal::{add_exponents, digit_count, power_of_10, round_with_half, Decimal};
+use malachite_base::num::arithmetic::traits::DivRound;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+
+// Divides `c` by 10 ^ `pow` and rounds the result to an `Integer`. An `Ordering` is also returned,
+// indicating whether the rounded result is less than, equal to, or greater than the exact
+// quotient. If the quotient has absolute value less than 1/10, 10 ^ `pow` is never computed.
+pub(crate) fn div_round_power_of_10(
+    c: &Integer,
+    pow: u64,
+    rm: RoundingMode,
+) -> (Integer, Ordering) {
+    if *c == 0u32 || pow == 0 {
+        return (c.clone(), Ordering::Equal);
+    }
+    if pow > digit_count(c.unsigned_abs_ref()) {
+        let positive = *c > 0u32;
+        let (q, o) = round_with_half(
+            Natural::ZERO,
+            false,
+            Ordering::Less,
+            if positive { rm } else { -rm },
+        );
+        if positive {
+            (Integer::from(q), o)
+        } else {
+            (-Integer::from(q), o.reverse())
+        }
+    } else {
+        c.div_round(Integer::from(power_of_10(pow)), rm)
+    }
+}
+
+impl Decimal {
+    /// Changes the exponent of a [`Decimal`], rounding its value if necessary. The [`Decimal`] is
+    /// taken by reference. An [`Ordering`] is also returned, indicating whether the rounded result
+    /// is less than, equal to, or greater than the original value.
+    ///
+    /// If the new exponent is less than or equal to the old one, the result is exact. Otherwise,
+    /// the coefficient is divided by a power of 10 and rounded using `rm`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.coefficient().significant_bits(), |self.exponent() - exponent|)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the value cannot be represented exactly with the given
+    /// exponent.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::decimal::Decimal;
+    /// use std::cmp::Ordering;
+    ///
+    /// let x: Decimal = "2.345".parse().unwrap();
+    /// let (y, o) = x.rescale(-2, RoundingMode::Nearest);
+    /// assert_eq!(y.to_string(), "2.34");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (y, o) = x.rescale(-2, RoundingMode::NearestTiesAway);
+    /// assert_eq!(y.to_string(), "2.35");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (y, o) = x.rescale(-5, RoundingMode::Exact);
+    /// assert_eq!(y.to_string(), "2.34500");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (y, o) = x.rescale(0, RoundingMode::Ceiling);
+    /// assert_eq!(y.to_string(), "3");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    pub fn rescale(&self, exponent: i64, rm: RoundingMode) -> (Decimal, Ordering) {
+        let diff = self.exponent.abs_diff(exponent);
+        if exponent <= self.exponent {
+            (
+                Decimal::new(
+                    &self.coefficient * Integer::from(power_of_10(diff)),
+                    exponent,
+                ),
+                Ordering::Equal,
+            )
+        } else {
+            let (c, o) = div_round_power_of_10(&self.coefficient, diff, rm);
+            (Decimal::new(c, exponent), o)
+        }
+    }
+
+    /// Rounds a [`Decimal`] so that it has the same exponent as another [`Decimal`]. Both
+    /// [`Decimal`]s are taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded result is less than, equal to, or greater than the original value.
+    ///
+    /// This is the IEEE 754 `quantize` operation. It is equivalent to
+    /// `self.rescale(other.exponent(), rm)`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.coefficient().significant_bits(), |self.exponent() - other.exponent()|)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the value cannot be represented exactly with the exponent of
+    /// `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::decimal::Decimal;
+    /// use std::cmp::Ordering;
+    ///
+    /// let price: Decimal = "19.995".parse().unwrap();
+    /// let cent: Decimal = "0.01".parse().unwrap();
+    /// let (y, o) = price.quantize(&cent, RoundingMode::NearestTiesAway);
+    /// assert_eq!(y.to_string(), "20.00");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (y, o) = price.quantize(&cent, RoundingMode::Down);
+    /// assert_eq!(y.to_string(), "19.99");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn quantize(&self, other: &Decimal, rm: RoundingMode) -> (Decimal, Ordering) {
+        self.rescale(other.exponent, rm)
+    }
+
+    /// Rounds a [`Decimal`] so that its coefficient has at most `prec` digits. The [`Decimal`] is
+    /// taken by reference. An [`Ordering`] is also returned, indicating whether the rounded result
+    /// is less than, equal to, or greater than the original value.
+    ///
+    /// If the coefficient already has `prec` digits or fewer, the [`Decimal`] is returned
+    /// unchanged. Otherwise the exponent is increased until the rounded coefficient fits.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.coefficient().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, if `rm` is `Exact` but the value cannot be represented exactly
+    /// with the given precision, or if the exponent of the result does not fit in an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::decimal::Decimal;
+    /// use std::cmp::Ordering;
+    ///
+    /// let x: Decimal = "9.9951".parse().unwrap();
+    /// let (y, o) = x.round_to_precision(3, RoundingMode::Nearest);
+    /// assert_eq!(y.to_string(), "10.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (y, o) = x.round_to_precision(3, RoundingMode::Floor);
+    /// assert_eq!(y.to_string(), "9.99");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (y, o) = x.round_to_precision(10, RoundingMode::Exact);
+    /// assert_eq!(y.to_string(), "9.9951");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    pub fn round_to_precision(&self, prec: u64, rm: RoundingMode) -> (Decimal, Ordering) {
+        assert_ne!(prec, 0);
+        let digits = self.precision();
+        if digits <= prec {
+            return (self.clone(), Ordering::Equal);
+        }
+        let pow = digits - prec;
+        let (mut c, o) = div_round_power_of_10(&self.coefficient, pow, rm);
+        let mut exponent = add_exponents(self.exponent, i64::exact_from(pow));
+        if digit_count(c.unsigned_abs_ref()) > prec {
+            // The coefficient was rounded up to 10 ^ prec, so dividing by 10 is exact
+            c /= Integer::from(10u32);
+            exponent = add_exponents(exponent, 1);
+        }
+        (Decimal::new(c, exponent), o)
+    }
+}
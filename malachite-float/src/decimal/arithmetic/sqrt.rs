@@ -0,0 +1,181 @@
+use crate::decimal::{digit_count, negate_exponent, power_of_10, round_with_half, Decimal};
+use malachite_base::num::arithmetic::traits::{FloorLogBase, FloorSqrt, Square};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+
+// Computes the square root of a nonnegative `Rational` x, rounded to a multiple of 10 ^ `exponent`.
+fn sqrt_rational_to_exponent(x: &Rational, exponent: i64, rm: RoundingMode) -> (Decimal, Ordering) {
+    let pow = Rational::from(power_of_10(exponent.unsigned_abs() << 1));
+    // The result is sqrt(n) * 10 ^ exponent
+    let n = if exponent <= 0 { x * pow } else { x / pow };
+    let r = Natural::rounding_from(&n, RoundingMode::Floor)
+        .0
+        .floor_sqrt();
+    let exact = (&r).square() == n;
+    // Compare sqrt(n) with r + 1/2 by comparing 4n with (2r + 1) ^ 2
+    let half = (n << 2u64).cmp(&Rational::from(
+        ((&r << 1u64) + Natural::from(1u32)).square(),
+    ));
+    let (c, o) = round_with_half(r, exact, half, rm);
+    (Decimal::new(Integer::from(c), exponent), o)
+}
+
+impl Decimal {
+    /// Computes the square root of a [`Decimal`], rounding the result so that its coefficient has
+    /// at most `prec` digits. The [`Decimal`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result.
+    ///
+    /// If the result is exact, trailing zeros are removed from its coefficient, but its exponent is
+    /// not increased beyond half the exponent of the input, rounded down. In particular, the
+    /// square root of zero is zero with that exponent.
+    ///
+    /// $$
+    /// f(x,p,m) = \sqrt{x}+\varepsilon,
+    /// $$
+    /// where $|\varepsilon| < 10^{\lfloor\log_{10} \sqrt{x}\rfloor-p+1}$, or $\varepsilon = 0$ if
+    /// $x$ is 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.precision(),
+    /// |self.exponent()|, prec)`.
+    ///
+    /// # Panics
+    /// Panics if `self` is negative, if `prec` is zero, or if `rm` is `Exact` but the result cannot
+    /// be represented exactly with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::decimal::Decimal;
+    /// use std::cmp::Ordering;
+    ///
+    /// let x: Decimal = "2".parse().unwrap();
+    /// let (y, o) = x.sqrt_prec_round(10, RoundingMode::Floor);
+    /// assert_eq!(y.to_string(), "1.414213562");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (y, o) = x.sqrt_prec_round(10, RoundingMode::Ceiling);
+    /// assert_eq!(y.to_string(), "1.414213563");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let x: Decimal = "2.25".parse().unwrap();
+    /// let (y, o) = x.sqrt_prec_round(10, RoundingMode::Exact);
+    /// assert_eq!(y.to_string(), "1.5");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    pub fn sqrt_prec_round(&self, prec: u64, rm: RoundingMode) -> (Decimal, Ordering) {
+        assert_ne!(prec, 0);
+        assert!(
+            self.coefficient >= 0u32,
+            "Cannot take the square root of a negative Decimal"
+        );
+        if self.is_zero() {
+            return (
+                Decimal::new(Integer::ZERO, self.exponent >> 1),
+                Ordering::Equal,
+            );
+        }
+        let x = Rational::from(self);
+        // floor(log_10(sqrt(x))) = floor(floor(log_10(x)) / 2)
+        let exponent =
+            ((&x).floor_log_base(&Rational::from(10u32)) >> 1) - i64::exact_from(prec - 1);
+        let (mut y, o) = sqrt_rational_to_exponent(&x, exponent, rm);
+        if digit_count(y.coefficient.unsigned_abs_ref()) > prec {
+            // The coefficient was rounded up to 10 ^ prec, so dividing by 10 is exact
+            y.coefficient /= Integer::from(10u32);
+            y.exponent += 1;
+        }
+        if o == Ordering::Equal {
+            y.remove_trailing_zeros(self.exponent >> 1);
+        }
+        (y, o)
+    }
+
+    /// Computes the square root of a [`Decimal`], rounding the result to the nearest value whose
+    /// coefficient has at most `prec` digits. The [`Decimal`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded result is less than, equal
+    /// to, or greater than the exact result.
+    ///
+    /// If the result is equidistant from two such values, the one with an even last digit is
+    /// chosen. See [`Decimal::sqrt_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.precision(),
+    /// |self.exponent()|, prec)`.
+    ///
+    /// # Panics
+    /// Panics if `self` is negative or `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (y, o) = "10".parse::<Decimal>().unwrap().sqrt_prec(5);
+    /// assert_eq!(y.to_string(), "3.1623");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn sqrt_prec(&self, prec: u64) -> (Decimal, Ordering) {
+        self.sqrt_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the square root of a [`Decimal`], rounding the result so that it has the given
+    /// scale; that is, so that its exponent is `-scale`. The [`Decimal`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded result is less than, equal
+    /// to, or greater than the exact result.
+    ///
+    /// $$
+    /// f(x,s,m) = \sqrt{x}+\varepsilon,
+    /// $$
+    /// where $|\varepsilon| < 10^{-s}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.precision(),
+    /// |self.exponent()|, |scale|)`.
+    ///
+    /// # Panics
+    /// Panics if `self` is negative, if `scale` is `i64::MIN`, or if `rm` is `Exact` but the
+    /// result cannot be represented exactly with the given scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::decimal::Decimal;
+    /// use std::cmp::Ordering;
+    ///
+    /// let x: Decimal = "2".parse().unwrap();
+    /// let (y, o) = x.sqrt_scale_round(4, RoundingMode::Nearest);
+    /// assert_eq!(y.to_string(), "1.4142");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (y, o) = "2.25".parse::<Decimal>().unwrap().sqrt_scale_round(3, RoundingMode::Exact);
+    /// assert_eq!(y.to_string(), "1.500");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    pub fn sqrt_scale_round(&self, scale: i64, rm: RoundingMode) -> (Decimal, Ordering) {
+        assert!(
+            self.coefficient >= 0u32,
+            "Cannot take the square root of a negative Decimal"
+        );
+        sqrt_rational_to_exponent(&Rational::from(self), negate_exponent(scale), rm)
+    }
+}
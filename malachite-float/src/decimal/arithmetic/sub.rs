@@ -0,0 +1,347 @@
+use crate::decimal::{negate_exponent, power_of_10, Decimal};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use std::cmp::Ordering;
+use std::ops::{Sub, SubAssign};
+
+impl Decimal {
+    /// Subtracts two [`Decimal`]s, rounding the result so that its coefficient has at most `prec`
+    /// digits. Both [`Decimal`]s are taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded result is less than, equal to, or greater than the exact
+    /// result.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x-y+\varepsilon,
+    /// $$
+    /// where $|\varepsilon| < 10^{\lfloor\log_{10} |x-y|\rfloor-p+1}$, or
+    /// $\varepsilon = 0$ if $x-y$ is 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.precision(),
+    /// other.precision(), |self.exponent() - other.exponent()|)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented
+    /// exactly with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::decimal::Decimal;
+    /// use std::cmp::Ordering;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "3.1416".parse().unwrap();
+    /// let (z, o) = x.sub_prec_round(&y, 3, RoundingMode::Floor);
+    /// assert_eq!(z.to_string(), "-1.90");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (z, o) = x.sub_prec_round(&y, 3, RoundingMode::Ceiling);
+    /// assert_eq!(z.to_string(), "-1.89");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    pub fn sub_prec_round(
+        &self,
+        other: &Decimal,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Decimal, Ordering) {
+        (self - other).round_to_precision(prec, rm)
+    }
+
+    /// Subtracts two [`Decimal`]s, rounding the result to the nearest value whose coefficient has
+    /// at most `prec` digits. Both [`Decimal`]s are taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than
+    /// the exact result.
+    ///
+    /// If the result is equidistant from two such values, the one with an even last digit is
+    /// chosen. See [`Decimal::sub_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.precision(),
+    /// other.precision(), |self.exponent() - other.exponent()|)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    /// use std::cmp::Ordering;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "3.1416".parse().unwrap();
+    /// let (z, o) = x.sub_prec(&y, 3);
+    /// assert_eq!(z.to_string(), "-1.89");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn sub_prec(&self, other: &Decimal, prec: u64) -> (Decimal, Ordering) {
+        self.sub_prec_round(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Subtracts two [`Decimal`]s, rounding the result so that it has the given scale; that is, so
+    /// that its exponent is `-scale`. Both [`Decimal`]s are taken by reference. An [`Ordering`]
+    /// is also returned, indicating whether the rounded result is less than, equal to, or greater
+    /// than the exact result.
+    ///
+    /// $$
+    /// f(x,y,s,m) = x-y+\varepsilon,
+    /// $$
+    /// where $|\varepsilon| < 10^{-s}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.precision(),
+    /// other.precision(), |self.exponent() - other.exponent()|, |self.exponent() + scale|)`.
+    ///
+    /// # Panics
+    /// Panics if `scale` is `i64::MIN`, or if `rm` is `Exact` but the result cannot be represented
+    /// exactly with the given scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::decimal::Decimal;
+    /// use std::cmp::Ordering;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "3.1416".parse().unwrap();
+    /// let (z, o) = x.sub_scale_round(&y, 1, RoundingMode::Down);
+    /// assert_eq!(z.to_string(), "-1.8");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (z, o) = x.sub_scale_round(&y, 5, RoundingMode::Exact);
+    /// assert_eq!(z.to_string(), "-1.89160");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    #[inline]
+    pub fn sub_scale_round(
+        &self,
+        other: &Decimal,
+        scale: i64,
+        rm: RoundingMode,
+    ) -> (Decimal, Ordering) {
+        (self - other).rescale(negate_exponent(scale), rm)
+    }
+}
+
+impl Sub<Decimal> for Decimal {
+    type Output = Decimal;
+
+    /// Subtracts two [`Decimal`]s exactly, taking both by value.
+    ///
+    /// The exponent of the result is the smaller of the exponents of the inputs.
+    ///
+    /// $$
+    /// f(x, y) = x-y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.precision(),
+    /// other.precision(), |self.exponent() - other.exponent()|)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "3.1416".parse().unwrap();
+    /// assert_eq!((x - y).to_string(), "-1.8916");
+    /// ```
+    #[inline]
+    fn sub(self, other: Decimal) -> Decimal {
+        &self - &other
+    }
+}
+
+impl<'a> Sub<&'a Decimal> for Decimal {
+    type Output = Decimal;
+
+    /// Subtracts two [`Decimal`]s exactly, taking the first by value and the second by reference.
+    ///
+    /// The exponent of the result is the smaller of the exponents of the inputs.
+    ///
+    /// $$
+    /// f(x, y) = x-y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.precision(),
+    /// other.precision(), |self.exponent() - other.exponent()|)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "3.1416".parse().unwrap();
+    /// assert_eq!((x - &y).to_string(), "-1.8916");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'a Decimal) -> Decimal {
+        &self - other
+    }
+}
+
+impl<'a> Sub<Decimal> for &'a Decimal {
+    type Output = Decimal;
+
+    /// Subtracts two [`Decimal`]s exactly, taking the first by reference and the second by value.
+    ///
+    /// The exponent of the result is the smaller of the exponents of the inputs.
+    ///
+    /// $$
+    /// f(x, y) = x-y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.precision(),
+    /// other.precision(), |self.exponent() - other.exponent()|)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "3.1416".parse().unwrap();
+    /// assert_eq!((&x - y).to_string(), "-1.8916");
+    /// ```
+    #[inline]
+    fn sub(self, other: Decimal) -> Decimal {
+        self - &other
+    }
+}
+
+impl<'a, 'b> Sub<&'a Decimal> for &'b Decimal {
+    type Output = Decimal;
+
+    /// Subtracts two [`Decimal`]s exactly, taking both by reference.
+    ///
+    /// The exponent of the result is the smaller of the exponents of the inputs.
+    ///
+    /// $$
+    /// f(x, y) = x-y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.precision(),
+    /// other.precision(), |self.exponent() - other.exponent()|)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "3.1416".parse().unwrap();
+    /// assert_eq!((&x - &y).to_string(), "-1.8916");
+    /// ```
+    fn sub(self, other: &'a Decimal) -> Decimal {
+        match self.exponent.cmp(&other.exponent) {
+            Ordering::Equal => Decimal::new(&self.coefficient - &other.coefficient, self.exponent),
+            Ordering::Less => Decimal::new(
+                &self.coefficient
+                    - &other.coefficient
+                        * Integer::from(power_of_10(self.exponent.abs_diff(other.exponent))),
+                self.exponent,
+            ),
+            Ordering::Greater => Decimal::new(
+                &self.coefficient
+                    * Integer::from(power_of_10(self.exponent.abs_diff(other.exponent)))
+                    - &other.coefficient,
+                other.exponent,
+            ),
+        }
+    }
+}
+
+impl SubAssign<Decimal> for Decimal {
+    /// Subtracts a [`Decimal`] by another exactly, in place, taking the [`Decimal`] on the
+    /// right-hand side by value.
+    ///
+    /// The exponent of the result is the smaller of the exponents of the inputs.
+    ///
+    /// $$
+    /// x \gets x-y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.precision(),
+    /// other.precision(), |self.exponent() - other.exponent()|)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// let mut x: Decimal = "1.25".parse().unwrap();
+    /// x -= "3.1416".parse::<Decimal>().unwrap();
+    /// assert_eq!(x.to_string(), "-1.8916");
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: Decimal) {
+        *self = &*self - &other;
+    }
+}
+
+impl<'a> SubAssign<&'a Decimal> for Decimal {
+    /// Subtracts a [`Decimal`] by another exactly, in place, taking the [`Decimal`] on the
+    /// right-hand side by reference.
+    ///
+    /// The exponent of the result is the smaller of the exponents of the inputs.
+    ///
+    /// $$
+    /// x \gets x-y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.precision(),
+    /// other.precision(), |self.exponent() - other.exponent()|)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// let mut x: Decimal = "1.25".parse().unwrap();
+    /// x -= &"3.1416".parse::<Decimal>().unwrap();
+    /// assert_eq!(x.to_string(), "-1.8916");
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: &'a Decimal) {
+        *self = &*self - other;
+    }
+}
@@ -0,0 +1,122 @@
+use crate::decimal::{digit_count, power_of_10, Decimal};
+use malachite_base::num::arithmetic::traits::Sign;
+use malachite_base::num::comparison::traits::OrdAbs;
+use malachite_nz::integer::Integer;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+impl PartialEq for Decimal {
+    /// Determines whether two [`Decimal`]s are equal.
+    ///
+    /// Two [`Decimal`]s are equal if they represent the same number, even if their coefficients
+    /// and exponents are different.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.precision(),
+    /// other.precision())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.5".parse().unwrap();
+    /// assert!(x == "1.50".parse::<Decimal>().unwrap());
+    /// assert!(x == "0.15e1".parse::<Decimal>().unwrap());
+    /// assert!(x != "1.51".parse::<Decimal>().unwrap());
+    /// assert!("0".parse::<Decimal>().unwrap() == "0.000".parse::<Decimal>().unwrap());
+    /// ```
+    #[inline]
+    fn eq(&self, other: &Decimal) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    /// Compares two [`Decimal`]s.
+    ///
+    /// See the documentation for the [`Ord`] implementation.
+    #[inline]
+    fn partial_cmp(&self, other: &Decimal) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    /// Compares two [`Decimal`]s by value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.precision(),
+    /// other.precision())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.5".parse().unwrap();
+    /// assert!(x < "1.51".parse::<Decimal>().unwrap());
+    /// assert!(x > "-2".parse::<Decimal>().unwrap());
+    /// assert!(x > "1.2e-300".parse::<Decimal>().unwrap());
+    /// assert!(x <= "1.500".parse::<Decimal>().unwrap());
+    /// ```
+    fn cmp(&self, other: &Decimal) -> Ordering {
+        let sign_cmp = self.coefficient.sign().cmp(&other.coefficient.sign());
+        if sign_cmp != Ordering::Equal || self.is_zero() {
+            return sign_cmp;
+        }
+        if self.exponent == other.exponent {
+            return self.coefficient.cmp(&other.coefficient);
+        }
+        // Both `Decimal`s are nonzero and have the same sign. First compare the positions of their
+        // most significant digits, which avoids large powers of 10 when the exponents are far
+        // apart.
+        let self_digits = digit_count(self.coefficient.unsigned_abs_ref());
+        let other_digits = digit_count(other.coefficient.unsigned_abs_ref());
+        let self_magnitude = i128::from(self.exponent) + i128::from(self_digits);
+        let other_magnitude = i128::from(other.exponent) + i128::from(other_digits);
+        let abs_cmp = if self_magnitude != other_magnitude {
+            self_magnitude.cmp(&other_magnitude)
+        } else {
+            // The exponents differ by less than the number of digits of either coefficient
+            let pow = Integer::from(power_of_10(self.exponent.abs_diff(other.exponent)));
+            if self.exponent > other.exponent {
+                (&self.coefficient * pow).cmp_abs(&other.coefficient)
+            } else {
+                self.coefficient.cmp_abs(&(&other.coefficient * pow))
+            }
+        };
+        if self.coefficient > 0u32 {
+            abs_cmp
+        } else {
+            abs_cmp.reverse()
+        }
+    }
+}
+
+impl Hash for Decimal {
+    /// Computes a hash of a [`Decimal`].
+    ///
+    /// [`Decimal`]s that are equal have the same hash, even if their coefficients and exponents are
+    /// different.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.precision()`.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let normalized = self.normalize();
+        normalized.coefficient.hash(state);
+        normalized.exponent.hash(state);
+    }
+}
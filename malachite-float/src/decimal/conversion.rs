@@ -0,0 +1,645 @@
+use crate::decimal::{digit_count, negate_exponent, power_of_10, Decimal};
+use malachite_base::num::arithmetic::traits::{Abs, DivisibleBy, FloorLogBase, Pow};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::string::from_sci_string::preprocess_sci_string;
+use malachite_base::num::conversion::string::options::{FromSciStringOptions, ToSciOptions};
+use malachite_base::num::conversion::string::parse_error::{
+    ParseNumberError, ParseNumberErrorKind,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, FromSciString, RoundingFrom, ToSci};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::conversion::string::from_sci_string::FromSciStringHelper;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+use std::cmp::{max, Ordering};
+use std::fmt::{Debug, Display, Formatter, Result, Write};
+use std::str::FromStr;
+
+impl Decimal {
+    /// Converts a [`Rational`] to a [`Decimal`], rounding the result so that its coefficient has
+    /// exactly `prec` digits. The [`Rational`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// original value.
+    ///
+    /// Zero is converted to zero with exponent 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but `x` cannot be represented exactly with
+    /// the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::decimal::Decimal;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) =
+    ///     Decimal::from_rational_prec_round(Rational::from_signeds(2, 3), 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.66666");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Decimal::from_rational_prec_round(
+    ///     Rational::from_signeds(-2, 3),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.66667");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Decimal::from_rational_prec_round(Rational::from_signeds(1, 4), 3, RoundingMode::Exact);
+    /// assert_eq!(x.to_string(), "0.250");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    pub fn from_rational_prec_round(
+        x: Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Decimal, Ordering) {
+        assert_ne!(prec, 0);
+        if x == 0u32 {
+            return (Decimal::ZERO, Ordering::Equal);
+        }
+        let exponent =
+            (&x).abs().floor_log_base(&Rational::from(10u32)) - i64::exact_from(prec - 1);
+        let (mut y, o) = Decimal::from_rational_scale_round(x, -exponent, rm);
+        if digit_count(y.coefficient.unsigned_abs_ref()) > prec {
+            // The coefficient was rounded up to 10 ^ prec, so dividing by 10 is exact
+            y.coefficient /= Integer::from(10u32);
+            y.exponent += 1;
+        }
+        (y, o)
+    }
+
+    /// Converts a [`Rational`] to a [`Decimal`] with the given scale; that is, with exponent
+    /// `-scale`. The [`Rational`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded result is less than, equal to, or greater than the original value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(),
+    /// |scale|)`.
+    ///
+    /// # Panics
+    /// Panics if `scale` is `i64::MIN`, or if `rm` is `Exact` but `x` cannot be represented
+    /// exactly with the given scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::decimal::Decimal;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) =
+    ///     Decimal::from_rational_scale_round(Rational::from_signeds(2, 3), 2, RoundingMode::Up);
+    /// assert_eq!(x.to_string(), "0.67");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Decimal::from_rational_scale_round(
+    ///     Rational::from(12345),
+    ///     -2,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "1.23e4");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    pub fn from_rational_scale_round(
+        x: Rational,
+        scale: i64,
+        rm: RoundingMode,
+    ) -> (Decimal, Ordering) {
+        let exponent = negate_exponent(scale);
+        let pow = Rational::from(power_of_10(scale.unsigned_abs()));
+        let scaled = if scale >= 0 { x * pow } else { x / pow };
+        let (coefficient, o) = Integer::rounding_from(scaled, rm);
+        (Decimal::new(coefficient, exponent), o)
+    }
+}
+
+impl From<Integer> for Decimal {
+    /// Converts an [`Integer`] to a [`Decimal`] with exponent 0, taking the [`Integer`] by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Decimal::from(Integer::from(-1200)).to_string(), "-1200");
+    /// ```
+    #[inline]
+    fn from(x: Integer) -> Decimal {
+        Decimal::new(x, 0)
+    }
+}
+
+impl<'a> From<&'a Integer> for Decimal {
+    /// Converts an [`Integer`] to a [`Decimal`] with exponent 0, taking the [`Integer`] by
+    /// reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Decimal::from(&Integer::from(-1200)).to_string(), "-1200");
+    /// ```
+    #[inline]
+    fn from(x: &'a Integer) -> Decimal {
+        Decimal::new(x.clone(), 0)
+    }
+}
+
+impl From<Natural> for Decimal {
+    /// Converts a [`Natural`] to a [`Decimal`] with exponent 0, taking the [`Natural`] by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Decimal::from(Natural::from(1200u32)).to_string(), "1200");
+    /// ```
+    #[inline]
+    fn from(x: Natural) -> Decimal {
+        Decimal::new(Integer::from(x), 0)
+    }
+}
+
+impl<'a> From<&'a Natural> for Decimal {
+    /// Converts a [`Natural`] to a [`Decimal`] with exponent 0, taking the [`Natural`] by
+    /// reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Decimal::from(&Natural::from(1200u32)).to_string(), "1200");
+    /// ```
+    #[inline]
+    fn from(x: &'a Natural) -> Decimal {
+        Decimal::new(Integer::from(x), 0)
+    }
+}
+
+macro_rules! impl_from_primitive_int {
+    ($t: ident) => {
+        impl From<$t> for Decimal {
+            /// Converts a primitive integer to a [`Decimal`] with exponent 0.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::conversion#from).
+            #[inline]
+            fn from(x: $t) -> Decimal {
+                Decimal::new(Integer::from(x), 0)
+            }
+        }
+    };
+}
+apply_to_primitive_ints!(impl_from_primitive_int);
+
+impl From<Decimal> for Rational {
+    /// Converts a [`Decimal`] to a [`Rational`], taking the [`Decimal`] by value. The conversion
+    /// is exact.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.precision(),
+    /// |x.exponent()|)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::from("1.50".parse::<Decimal>().unwrap()).to_string(), "3/2");
+    /// assert_eq!(Rational::from("-1.2e3".parse::<Decimal>().unwrap()).to_string(), "-1200");
+    /// ```
+    #[inline]
+    fn from(x: Decimal) -> Rational {
+        Rational::from(&x)
+    }
+}
+
+impl<'a> From<&'a Decimal> for Rational {
+    /// Converts a [`Decimal`] to a [`Rational`], taking the [`Decimal`] by reference. The
+    /// conversion is exact.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.precision(),
+    /// |x.exponent()|)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::from(&"1.50".parse::<Decimal>().unwrap()).to_string(), "3/2");
+    /// assert_eq!(Rational::from(&"-1.2e3".parse::<Decimal>().unwrap()).to_string(), "-1200");
+    /// ```
+    fn from(x: &'a Decimal) -> Rational {
+        let pow = Integer::from(power_of_10(x.exponent.unsigned_abs()));
+        if x.exponent >= 0 {
+            Rational::from(&x.coefficient * pow)
+        } else {
+            Rational::from_integers_ref(&x.coefficient, &pow)
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DecimalFromRationalError;
+
+impl TryFrom<Rational> for Decimal {
+    type Error = DecimalFromRationalError;
+
+    /// Converts a [`Rational`] to a [`Decimal`], taking the [`Rational`] by value. If the
+    /// [`Rational`] can't be represented exactly, an error is returned.
+    ///
+    /// A [`Rational`] can be represented exactly if and only if its denominator has no prime
+    /// factors other than 2 and 5. The result has the largest exponent that is at most 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::conversion::DecimalFromRationalError;
+    /// use malachite_float::decimal::Decimal;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Decimal::try_from(Rational::from_signeds(3, 8)).unwrap().to_string(),
+    ///     "0.375"
+    /// );
+    /// assert_eq!(Decimal::try_from(Rational::from(1200)).unwrap().to_string(), "1200");
+    /// assert_eq!(
+    ///     Decimal::try_from(Rational::from_signeds(1, 3)),
+    ///     Err(DecimalFromRationalError)
+    /// );
+    /// ```
+    #[inline]
+    fn try_from(x: Rational) -> std::result::Result<Decimal, Self::Error> {
+        Decimal::try_from(&x)
+    }
+}
+
+impl<'a> TryFrom<&'a Rational> for Decimal {
+    type Error = DecimalFromRationalError;
+
+    /// Converts a [`Rational`] to a [`Decimal`], taking the [`Rational`] by reference. If the
+    /// [`Rational`] can't be represented exactly, an error is returned.
+    ///
+    /// A [`Rational`] can be represented exactly if and only if its denominator has no prime
+    /// factors other than 2 and 5. The result has the largest exponent that is at most 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::conversion::DecimalFromRationalError;
+    /// use malachite_float::decimal::Decimal;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Decimal::try_from(&Rational::from_signeds(-3, 8)).unwrap().to_string(),
+    ///     "-0.375"
+    /// );
+    /// assert_eq!(
+    ///     Decimal::try_from(&Rational::from_signeds(1, 3)),
+    ///     Err(DecimalFromRationalError)
+    /// );
+    /// ```
+    fn try_from(x: &'a Rational) -> std::result::Result<Decimal, Self::Error> {
+        let mut d = x.denominator_ref().clone();
+        let twos = d.trailing_zeros().unwrap();
+        d >>= twos;
+        let five = Natural::from(5u32);
+        let mut fives = 0;
+        while (&d).divisible_by(&five) {
+            d /= &five;
+            fives += 1;
+        }
+        if d != 1u32 {
+            return Err(DecimalFromRationalError);
+        }
+        // numerator / (2 ^ twos * 5 ^ fives) = numerator * 2 ^ (k - twos) * 5 ^ (k - fives) / 10 ^ k
+        let k = max(twos, fives);
+        let mut coefficient = Integer::from(x.numerator_ref());
+        if x < &0u32 {
+            coefficient = -coefficient;
+        }
+        coefficient <<= k - twos;
+        coefficient *= Integer::from(five.pow(k - fives));
+        Ok(Decimal::new(coefficient, -i64::exact_from(k)))
+    }
+}
+
+impl FromSciString for Decimal {
+    /// Converts a string, possibly in scientific notation, to a [`Decimal`].
+    ///
+    /// Use [`FromSciStringOptions`] to specify the base (from 2 to 36, inclusive). The rounding
+    /// mode option is ignored.
+    ///
+    /// In base 10, the coefficient and exponent of the result are exactly the ones written in the
+    /// string: `"1.50"` becomes $150 \times 10^{-2}$ and `"1.2e3"` becomes $12 \times 10^2$. In
+    /// other bases, the string is first parsed as a [`Rational`], and an error of kind
+    /// [`Inexact`](ParseNumberErrorKind::Inexact) is returned if the value can't be represented
+    /// exactly as a [`Decimal`]; otherwise, the result is the same as that of
+    /// [`Decimal::try_from`].
+    ///
+    /// The syntax is the same as for [`Rational::from_sci_string_with_options`]. If the string is
+    /// unparseable, a [`ParseNumberError`] describing the problem is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::options::FromSciStringOptions;
+    /// use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
+    /// use malachite_base::num::conversion::traits::FromSciString;
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// let x = Decimal::from_sci_string("1.50").unwrap();
+    /// assert_eq!(*x.coefficient(), 150);
+    /// assert_eq!(x.exponent(), -2);
+    ///
+    /// let x = Decimal::from_sci_string("-1.2e3").unwrap();
+    /// assert_eq!(*x.coefficient(), -12);
+    /// assert_eq!(x.exponent(), 2);
+    ///
+    /// assert_eq!(
+    ///     Decimal::from_sci_string("1.2.3").unwrap_err().kind(),
+    ///     ParseNumberErrorKind::InvalidDigit(3)
+    /// );
+    ///
+    /// let mut options = FromSciStringOptions::default();
+    /// options.set_base(2);
+    /// assert_eq!(
+    ///     Decimal::from_sci_string_with_options("0.011", options).unwrap().to_string(),
+    ///     "0.375"
+    /// );
+    /// options.set_base(3);
+    /// assert_eq!(
+    ///     Decimal::from_sci_string_with_options("0.1", options).unwrap_err().kind(),
+    ///     ParseNumberErrorKind::Inexact
+    /// );
+    /// ```
+    fn from_sci_string_with_options(
+        s: &str,
+        options: FromSciStringOptions,
+    ) -> std::result::Result<Decimal, ParseNumberError> {
+        if options.get_base() == 10 {
+            let (cs, exponent) = preprocess_sci_string(s, options)?;
+            // The digits are valid, so parsing can't fail
+            Ok(Decimal::new(Integer::parse_int(&cs, 10).unwrap(), exponent))
+        } else {
+            Decimal::try_from(Rational::from_sci_string_with_options(s, options)?)
+                .map_err(|_| ParseNumberError::new(ParseNumberErrorKind::Inexact))
+        }
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = ParseNumberError;
+
+    /// Converts a string to a [`Decimal`].
+    ///
+    /// This is equivalent to [`Decimal::from_sci_string`] in base 10: the coefficient and exponent
+    /// of the result are exactly the ones written in the string. If the string does not represent
+    /// a valid [`Decimal`], a [`ParseNumberError`] describing the problem is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Decimal::from_str("0.10").unwrap().to_string(), "0.10");
+    /// assert_eq!(Decimal::from_str("-12e-1").unwrap().to_string(), "-1.2");
+    /// assert!(Decimal::from_str("1/2").is_err());
+    /// ```
+    #[inline]
+    fn from_str(s: &str) -> std::result::Result<Decimal, ParseNumberError> {
+        Decimal::from_sci_string(s)
+    }
+}
+
+impl ToSci for Decimal {
+    /// Determines whether a [`Decimal`] can be converted to a string using
+    /// [`to_sci`](malachite_base::num::conversion::traits::ToSci::to_sci) and a particular set of
+    /// options.
+    ///
+    /// This is the same as for the [`Rational`] with the same value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.precision(),
+    /// |self.exponent()|)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::options::ToSciOptions;
+    /// use malachite_base::num::conversion::traits::ToSci;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let mut options = ToSciOptions::default();
+    /// options.set_precision(2);
+    /// assert!(x.fmt_sci_valid(options));
+    /// options.set_rounding_mode(RoundingMode::Exact);
+    /// assert!(!x.fmt_sci_valid(options));
+    /// ```
+    #[inline]
+    fn fmt_sci_valid(&self, options: ToSciOptions) -> bool {
+        Rational::from(self).fmt_sci_valid(options)
+    }
+
+    /// Converts a [`Decimal`] to a string using a specified base, possibly formatting the number
+    /// using scientific notation.
+    ///
+    /// The number is formatted in the same way as the [`Rational`] with the same value; see
+    /// [`ToSciOptions`] for details on the available options. The [`Display`] implementation uses
+    /// this function with a precision equal to the precision of the [`Decimal`], so that trailing
+    /// zeros are preserved.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.precision(),
+    /// |self.exponent()|)`.
+    ///
+    /// # Panics
+    /// Panics if `options.rounding_mode` is `Exact`, but the size options are such that the input
+    /// must be rounded.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::options::ToSciOptions;
+    /// use malachite_base::num::conversion::traits::ToSci;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let mut options = ToSciOptions::default();
+    /// options.set_precision(2);
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "1.2");
+    /// options.set_rounding_mode(RoundingMode::Up);
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "1.3");
+    /// ```
+    #[inline]
+    fn fmt_sci(&self, f: &mut Formatter, options: ToSciOptions) -> Result {
+        Rational::from(self).fmt_sci(f, options)
+    }
+}
+
+impl Display for Decimal {
+    /// Converts a [`Decimal`] to a [`String`].
+    ///
+    /// All the digits of the coefficient are shown, including trailing zeros, so that the string
+    /// can be parsed back into a [`Decimal`] with the same coefficient and exponent. Small and
+    /// large numbers are written in scientific notation, in the same way as by [`ToSci`]: for
+    /// example, $15 \times 10^{-1}$ is written as `"1.5"`, $150 \times 10^{-2}$ as `"1.50"`, and
+    /// $15 \times 10^3$ as `"1.5e4"`. A zero coefficient is written as `"0"`, followed by a
+    /// decimal point and $-e$ zeros if the exponent $e$ is negative, or by `"e"` and $e$ if it is
+    /// positive.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.precision(),
+    /// |self.exponent()|)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Decimal::new(Integer::from(15), -1).to_string(), "1.5");
+    /// assert_eq!(Decimal::new(Integer::from(150), -2).to_string(), "1.50");
+    /// assert_eq!(Decimal::new(Integer::from(-15), 3).to_string(), "-1.5e4");
+    /// assert_eq!(Decimal::new(Integer::from(15), -10).to_string(), "1.5e-9");
+    /// assert_eq!(Decimal::new(Integer::from(0), -2).to_string(), "0.00");
+    /// assert_eq!(Decimal::new(Integer::from(0), 2).to_string(), "0e2");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if self.is_zero() {
+            f.write_char('0')?;
+            match self.exponent.cmp(&0) {
+                Ordering::Equal => Ok(()),
+                Ordering::Less => {
+                    f.write_char('.')?;
+                    for _ in 0..self.exponent.unsigned_abs() {
+                        f.write_char('0')?;
+                    }
+                    Ok(())
+                }
+                Ordering::Greater => write!(f, "e{}", self.exponent),
+            }
+        } else {
+            let mut options = ToSciOptions::default();
+            options.set_precision(self.precision());
+            options.set_include_trailing_zeros(true);
+            options.set_rounding_mode(RoundingMode::Exact);
+            self.fmt_sci(f, options)
+        }
+    }
+}
+
+impl Debug for Decimal {
+    /// Converts a [`Decimal`] to a [`String`].
+    ///
+    /// This is the same implementation as for [`Display`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.precision(),
+    /// |self.exponent()|)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_float::decimal::Decimal;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Decimal::new(Integer::from(150), -2).to_debug_string(), "1.50");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,314 @@
+use malachite_base::num::arithmetic::traits::{DivRound, FloorLogBase, Parity, Pow};
+use malachite_base::num::basic::traits::{NegativeOne, One, Two, Zero};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+
+/// An arbitrary-precision decimal floating-point number.
+///
+/// A `Decimal` is a coefficient, which is an [`Integer`], multiplied by a power of 10: the value of
+/// a `Decimal` with coefficient $c$ and exponent $e$ is $c \times 10^e$. Unlike a
+/// [`Float`](crate::Float), a `Decimal` can represent numbers like 0.1 exactly, which makes it
+/// suitable for financial calculations.
+///
+/// The same number may have many representations; for example, 1.5 may be represented as $15
+/// \times 10^{-1}$ or $150 \times 10^{-2}$. The representation is preserved by addition,
+/// subtraction, and multiplication, and it determines how the number is displayed, so that `1.50`
+/// stays `1.50`. However, equality, comparison, and hashing depend only on the value, so
+/// `Decimal`s that represent the same number are equal.
+///
+/// The scale of a `Decimal` is the negative of its exponent, which, if it is nonnegative, is the
+/// number of digits after the decimal point. The precision of a `Decimal` is the number of digits
+/// in its coefficient.
+///
+/// Addition, subtraction, multiplication, and negation are exact. Every other function that
+/// produces a `Decimal` takes either a precision, which is the maximum number of digits in the
+/// result's coefficient, or a scale, together with a [`RoundingMode`], and returns an [`Ordering`]
+/// indicating whether the rounded result is less than, equal to, or greater than the exact result.
+#[derive(Clone)]
+pub struct Decimal {
+    pub(crate) coefficient: Integer,
+    pub(crate) exponent: i64,
+}
+
+impl Decimal {
+    /// Creates a `Decimal` from a coefficient and an exponent. The value of the result is
+    /// $c \times 10^e$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Decimal::new(Integer::from(150), -2).to_string(), "1.50");
+    /// assert_eq!(Decimal::new(Integer::from(-3), 0).to_string(), "-3");
+    /// assert_eq!(Decimal::new(Integer::from(12), 3).to_string(), "1.2e4");
+    /// ```
+    pub const fn new(coefficient: Integer, exponent: i64) -> Decimal {
+        Decimal {
+            coefficient,
+            exponent,
+        }
+    }
+
+    /// Returns a reference to the coefficient of a `Decimal`.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.50".parse().unwrap();
+    /// assert_eq!(*x.coefficient(), 150);
+    /// ```
+    pub const fn coefficient(&self) -> &Integer {
+        &self.coefficient
+    }
+
+    /// Returns the exponent of a `Decimal`.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.50".parse().unwrap();
+    /// assert_eq!(x.exponent(), -2);
+    /// ```
+    pub const fn exponent(&self) -> i64 {
+        self.exponent
+    }
+
+    /// Returns the scale of a `Decimal`, which is the negative of its exponent. If the scale is
+    /// nonnegative, it is the number of digits after the decimal point.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if the exponent is `i64::MIN`, since the scale then does not fit in an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.50".parse().unwrap();
+    /// assert_eq!(x.scale(), 2);
+    /// ```
+    pub const fn scale(&self) -> i64 {
+        negate_exponent(self.exponent)
+    }
+
+    /// Takes a `Decimal` by value and returns its coefficient and exponent.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// let (c, e) = "-1.50".parse::<Decimal>().unwrap().into_coefficient_and_exponent();
+    /// assert_eq!(c, -150);
+    /// assert_eq!(e, -2);
+    /// ```
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_coefficient_and_exponent(self) -> (Integer, i64) {
+        (self.coefficient, self.exponent)
+    }
+
+    /// Returns the precision of a `Decimal`, which is the number of digits in its coefficient. The
+    /// precision of zero is 1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.coefficient().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// assert_eq!("1.50".parse::<Decimal>().unwrap().precision(), 3);
+    /// assert_eq!("-0.001".parse::<Decimal>().unwrap().precision(), 1);
+    /// assert_eq!("0.000".parse::<Decimal>().unwrap().precision(), 1);
+    /// ```
+    pub fn precision(&self) -> u64 {
+        digit_count(self.coefficient.unsigned_abs_ref())
+    }
+
+    /// Determines whether a `Decimal` is zero.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// assert!("0.00".parse::<Decimal>().unwrap().is_zero());
+    /// assert!(!"0.01".parse::<Decimal>().unwrap().is_zero());
+    /// ```
+    pub fn is_zero(&self) -> bool {
+        self.coefficient == 0u32
+    }
+
+    /// Returns the representation of a `Decimal`'s value with the largest possible exponent, by
+    /// removing trailing zeros from the coefficient. Zero is normalized to have exponent 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.coefficient().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::decimal::Decimal;
+    ///
+    /// assert_eq!("1.500".parse::<Decimal>().unwrap().normalize().to_string(), "1.5");
+    /// assert_eq!("1200".parse::<Decimal>().unwrap().normalize().to_string(), "1.2e3");
+    /// assert_eq!("0.000".parse::<Decimal>().unwrap().normalize().to_string(), "0");
+    /// ```
+    pub fn normalize(&self) -> Decimal {
+        if self.is_zero() {
+            return Decimal::ZERO;
+        }
+        let mut x = self.clone();
+        x.remove_trailing_zeros(i64::MAX);
+        x
+    }
+
+    // Removes trailing zeros from the coefficient, increasing the exponent, but without increasing
+    // the exponent past `max_exponent`. The coefficient must be nonzero.
+    pub(crate) fn remove_trailing_zeros(&mut self, max_exponent: i64) {
+        let ten = Integer::from(10u32);
+        while self.exponent < max_exponent {
+            let (q, o) = (&self.coefficient).div_round(&ten, RoundingMode::Down);
+            if o != Ordering::Equal {
+                break;
+            }
+            self.coefficient = q;
+            self.exponent += 1;
+        }
+    }
+}
+
+// Returns the sum of two exponents, panicking if it does not fit in an `i64`.
+pub(crate) fn add_exponents(x: i64, y: i64) -> i64 {
+    x.checked_add(y).expect("Decimal exponent overflow")
+}
+
+// Returns the difference of two exponents, panicking if it does not fit in an `i64`.
+pub(crate) fn sub_exponents(x: i64, y: i64) -> i64 {
+    x.checked_sub(y).expect("Decimal exponent overflow")
+}
+
+// Converts a scale to an exponent or an exponent to a scale, panicking if the result does not fit
+// in an `i64`; that is, if the input is `i64::MIN`.
+pub(crate) const fn negate_exponent(x: i64) -> i64 {
+    if let Some(y) = x.checked_neg() {
+        y
+    } else {
+        panic!("Decimal exponent overflow")
+    }
+}
+
+// Returns 10 ^ pow.
+pub(crate) fn power_of_10(pow: u64) -> Natural {
+    Natural::from(10u32).pow(pow)
+}
+
+// Returns the number of decimal digits of `x`, or 1 if `x` is zero.
+pub(crate) fn digit_count(x: &Natural) -> u64 {
+    if *x == 0u32 {
+        1
+    } else {
+        x.floor_log_base(&Natural::from(10u32)) + 1
+    }
+}
+
+// Rounds a nonnegative number t to an integer, where r = floor(t), `exact` indicates whether
+// t = r, and `half` is the result of comparing t to r + 1/2. An `Ordering` is also returned,
+// indicating whether the rounded result is less than, equal to, or greater than t.
+pub(crate) fn round_with_half(
+    r: Natural,
+    exact: bool,
+    half: Ordering,
+    rm: RoundingMode,
+) -> (Natural, Ordering) {
+    if exact {
+        return (r, Ordering::Equal);
+    }
+    let up = match rm {
+        RoundingMode::Floor | RoundingMode::Down => false,
+        RoundingMode::Ceiling | RoundingMode::Up => true,
+        RoundingMode::Odd => r.even(),
+        RoundingMode::Nearest => match half {
+            Ordering::Less => false,
+            Ordering::Greater => true,
+            Ordering::Equal => r.odd(),
+        },
+        RoundingMode::NearestTiesAway => half != Ordering::Less,
+        RoundingMode::NearestTiesTowardZero => half == Ordering::Greater,
+        RoundingMode::Exact => panic!("Inexact Decimal rounding"),
+    };
+    if up {
+        (r + Natural::ONE, Ordering::Greater)
+    } else {
+        (r, Ordering::Less)
+    }
+}
+
+/// The constant 0, with exponent 0.
+impl Zero for Decimal {
+    const ZERO: Decimal = Decimal {
+        coefficient: Integer::ZERO,
+        exponent: 0,
+    };
+}
+
+/// The constant 1, with exponent 0.
+impl One for Decimal {
+    const ONE: Decimal = Decimal {
+        coefficient: Integer::ONE,
+        exponent: 0,
+    };
+}
+
+/// The constant 2, with exponent 0.
+impl Two for Decimal {
+    const TWO: Decimal = Decimal {
+        coefficient: Integer::TWO,
+        exponent: 0,
+    };
+}
+
+/// The constant -1, with exponent 0.
+impl NegativeOne for Decimal {
+    const NEGATIVE_ONE: Decimal = Decimal {
+        coefficient: Integer::NEGATIVE_ONE,
+        exponent: 0,
+    };
+}
+
+/// Arithmetic on [`Decimal`]s, rescaling, and rounding.
+pub mod arithmetic;
+/// Equality, comparison, and hashing of [`Decimal`]s.
+pub mod comparison;
+/// Conversions between [`Decimal`]s and other types, including [`Rational`](malachite_q::Rational)s
+/// and [`String`]s.
+pub mod conversion;
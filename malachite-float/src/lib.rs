@@ -261,14 +261,20 @@ impl<'a> Deref for ComparableFloatRef<'a> {
 
 /// Traits for arithmetic.
 pub mod arithmetic;
+/// [`Ball`](ball::Ball)s: [`Float`] midpoints with error radii, for validated numerics.
+pub mod ball;
 #[macro_use]
 /// Basic traits for working with [`Float`]s.
 pub mod basic;
 /// Traits for comparing [`Float`]s for equality or order.
 pub mod comparison;
+/// [`Complex`](complex::Complex) numbers, with [`Float`] real and imaginary parts.
+pub mod complex;
 /// Traits for converting to and from [`Float`]s, including converting [`Float`]s to and from
 /// strings.
 pub mod conversion;
+/// [`Decimal`](decimal::Decimal)s: arbitrary-precision decimal floating-point numbers.
+pub mod decimal;
 /// Iterators that generate [`Float`]s without repetition.
 pub mod exhaustive;
 /// Iterators that generate [`Float`]s randomly.
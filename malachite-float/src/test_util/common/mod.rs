@@ -29,7 +29,10 @@ pub const fn rug_round_try_from_rounding_mode(rm: RoundingMode) -> Result<Round,
         RoundingMode::Down => Ok(Round::Zero),
         RoundingMode::Up => Ok(Round::AwayZero),
         RoundingMode::Nearest => Ok(Round::Nearest),
-        RoundingMode::Exact => Err(()),
+        RoundingMode::NearestTiesAway
+        | RoundingMode::NearestTiesTowardZero
+        | RoundingMode::Odd
+        | RoundingMode::Exact => Err(()),
     }
 }
 
@@ -1,4 +1,4 @@
-use crate::test_util::common::rug_round_exact_from_rounding_mode;
+use crate::test_util::common::rug_round_try_from_rounding_mode;
 use crate::Float;
 use malachite_base::num::basic::floats::PrimitiveFloat;
 use malachite_base::num::basic::integers::PrimitiveInt;
@@ -71,14 +71,14 @@ pub fn float_primitive_float_pair_rm<T: PrimitiveFloat>(
 pub fn float_unsigned_rounding_mode_triple_rm<T: PrimitiveUnsigned>(
     xs: It<(Float, T, RoundingMode)>,
 ) -> It<((rug::Float, T, rug::float::Round), (Float, T, RoundingMode))> {
-    Box::new(xs.map(|(x, p, rm)| {
-        (
+    Box::new(xs.filter_map(|(x, p, rm)| {
+        Some((
             (
                 rug::Float::exact_from(&x),
                 p,
-                rug_round_exact_from_rounding_mode(rm),
+                rug_round_try_from_rounding_mode(rm).ok()?,
             ),
             (x, p, rm),
-        )
+        ))
     }))
 }
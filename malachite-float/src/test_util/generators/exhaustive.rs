@@ -3,7 +3,9 @@ use crate::exhaustive::{
     exhaustive_non_negative_finite_floats, exhaustive_nonzero_finite_floats,
     exhaustive_positive_finite_floats,
 };
-use crate::test_util::extra_variadic::{exhaustive_triples_from_single, exhaustive_triples_xxy};
+use crate::test_util::extra_variadic::{
+    exhaustive_quadruples_xxyz, exhaustive_triples_from_single, exhaustive_triples_xxy,
+};
 use crate::{significand_bits, Float};
 use malachite_base::num::arithmetic::traits::IsPowerOf2;
 use malachite_base::num::basic::floats::PrimitiveFloat;
@@ -127,6 +129,20 @@ pub fn exhaustive_float_float_rational_triple_gen() -> It<(Float, Float, Rationa
     ))
 }
 
+// -- (Float, Float, PrimitiveUnsigned, RoundingMode) --
+
+pub fn exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_1(
+) -> It<(Float, Float, u64, RoundingMode)> {
+    Box::new(
+        exhaustive_quadruples_xxyz(
+            exhaustive_floats(),
+            exhaustive_positive_primitive_ints(),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&(_, _, _, rm)| rm != RoundingMode::Exact),
+    )
+}
+
 // -- (Float, Integer) --
 
 pub fn exhaustive_float_integer_pair_gen() -> It<(Float, Integer)> {
@@ -287,10 +303,12 @@ pub fn exhaustive_float_rational_rational_triple_gen() -> It<(Float, Rational, R
 
 pub(crate) fn natural_rounding_from_float_valid(f: &Float, rm: RoundingMode) -> bool {
     match rm {
-        RoundingMode::Down | RoundingMode::Ceiling | RoundingMode::Nearest => {
-            f.is_finite() || *f == Float::NEGATIVE_INFINITY
-        }
-        RoundingMode::Up | RoundingMode::Floor => {
+        RoundingMode::Down
+        | RoundingMode::Ceiling
+        | RoundingMode::Nearest
+        | RoundingMode::NearestTiesAway
+        | RoundingMode::NearestTiesTowardZero => f.is_finite() || *f == Float::NEGATIVE_INFINITY,
+        RoundingMode::Up | RoundingMode::Floor | RoundingMode::Odd => {
             f.is_finite() && (f.is_sign_positive() || f.is_negative_zero())
         }
         RoundingMode::Exact => Natural::convertible_from(f),
@@ -345,8 +363,13 @@ where
     match rm {
         RoundingMode::Floor => f.is_sign_positive() || f.is_negative_zero(),
         RoundingMode::Ceiling => *f <= T::MAX,
-        RoundingMode::Down | RoundingMode::Nearest => !f.is_nan(),
-        RoundingMode::Up => (f.is_sign_positive() || f.is_negative_zero()) && *f <= T::MAX,
+        RoundingMode::Down
+        | RoundingMode::Nearest
+        | RoundingMode::NearestTiesAway
+        | RoundingMode::NearestTiesTowardZero => !f.is_nan(),
+        RoundingMode::Up | RoundingMode::Odd => {
+            (f.is_sign_positive() || f.is_negative_zero()) && *f <= T::MAX
+        }
         RoundingMode::Exact => T::convertible_from(f),
     }
 }
@@ -376,8 +399,11 @@ where
     match rm {
         RoundingMode::Floor => *f >= T::MIN,
         RoundingMode::Ceiling => *f <= T::MAX,
-        RoundingMode::Down | RoundingMode::Nearest => !f.is_nan(),
-        RoundingMode::Up => *f >= T::MIN && *f <= T::MAX,
+        RoundingMode::Down
+        | RoundingMode::Nearest
+        | RoundingMode::NearestTiesAway
+        | RoundingMode::NearestTiesTowardZero => !f.is_nan(),
+        RoundingMode::Up | RoundingMode::Odd => *f >= T::MIN && *f <= T::MAX,
         RoundingMode::Exact => T::convertible_from(f),
     }
 }
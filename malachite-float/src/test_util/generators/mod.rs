@@ -185,6 +185,19 @@ pub fn float_float_rational_triple_gen() -> Generator<(Float, Float, Rational)>
     )
 }
 
+// -- (Float, Float, PrimitiveUnsigned, RoundingMode) --
+
+// All `(Float, Float, u64, RoundingMode)` where the `u64` is positive and the `RoundingMode` is not
+// `Exact`.
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_1(
+) -> Generator<(Float, Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_1,
+        &random_float_float_unsigned_rounding_mode_quadruple_gen_var_1,
+        &special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_1,
+    )
+}
+
 // -- (Float, Integer) --
 
 pub fn float_integer_pair_gen() -> Generator<(Float, Integer)> {
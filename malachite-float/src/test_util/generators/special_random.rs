@@ -3,7 +3,8 @@ use crate::random::{
     striped_random_nonzero_finite_floats, striped_random_positive_finite_floats,
 };
 use crate::test_util::extra_variadic::{
-    random_triples, random_triples_from_single, random_triples_xxy, random_triples_xyy,
+    random_quadruples_xxyz, random_triples, random_triples_from_single, random_triples_xxy,
+    random_triples_xyy,
 };
 use crate::test_util::generators::{
     from_primitive_float_prec_round_valid, integer_rounding_from_float_valid,
@@ -346,6 +347,40 @@ pub fn special_random_float_float_rational_triple_gen(
     ))
 }
 
+// -- (Float, Float, PrimitiveUnsigned, RoundingMode) --
+
+pub fn special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_1(
+    config: &GenConfig,
+) -> It<(Float, Float, u64, RoundingMode)> {
+    Box::new(
+        random_quadruples_xxyz(
+            EXAMPLE_SEED,
+            &|seed| {
+                striped_random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_stripe_n", 32),
+                    config.get_or("mean_stripe_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(_, _, _, rm)| rm != RoundingMode::Exact),
+    )
+}
+
 // -- (Float, Integer) --
 
 pub fn special_random_float_integer_pair_gen(config: &GenConfig) -> It<(Float, Integer)> {
@@ -0,0 +1,126 @@
+use malachite_base::num::arithmetic::traits::Abs;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::float::NiceFloat;
+use malachite_base::test_util::generators::primitive_float_pair_gen;
+use malachite_float::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_float::test_util::generators::{float_gen, float_pair_gen};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+
+#[test]
+fn test_copysign() {
+    let test = |s, s_hex, t, t_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let result = x.clone().copysign(&y);
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+        assert_eq!(to_hex_string(&result), out_hex);
+
+        let result_alt = x.copysign_ref(&y);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+
+        let mut result_alt = x.clone();
+        result_alt.copysign_assign(&y);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+
+        if !y.is_nan() {
+            assert_eq!(
+                ComparableFloat(Float::from(
+                    &rug::Float::exact_from(&x).copysign(&rug::Float::exact_from(&y))
+                )),
+                ComparableFloat(result)
+            );
+        }
+    };
+    test("NaN", "NaN", "1.0", "0x1.0#1", "NaN", "NaN");
+    test("NaN", "NaN", "-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "Infinity",
+        "Infinity",
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+    );
+    test("0.0", "0x0.0", "-Infinity", "-Infinity", "-0.0", "-0x0.0");
+    test("-0.0", "-0x0.0", "Infinity", "Infinity", "0.0", "0x0.0");
+    test("1.0", "0x1.0#1", "NaN", "NaN", "1.0", "0x1.0#1");
+    test("-1.0", "-0x1.0#1", "NaN", "NaN", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "2.0", "0x2.0#1", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "-2.0", "-0x2.0#1", "-1.0", "-0x1.0#1");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-0.0",
+        "-0x0.0",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "0.5",
+        "0x0.8#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+    );
+}
+
+#[test]
+fn copysign_properties() {
+    float_pair_gen().test_properties(|(x, y)| {
+        let result = x.clone().copysign(&y);
+        assert!(result.is_valid());
+
+        let result_alt = x.copysign_ref(&y);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+
+        let mut result_alt = x.clone();
+        result_alt.copysign_assign(&y);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+
+        if !x.is_nan() {
+            assert_eq!(
+                ComparableFloatRef(&result.clone().abs()),
+                ComparableFloatRef(&x.clone().abs())
+            );
+            assert_eq!(result.is_sign_negative(), y.is_sign_negative());
+        }
+        assert_eq!(
+            ComparableFloat(result.clone().copysign(&x)),
+            ComparableFloat(x.clone().copysign(&x))
+        );
+        assert_eq!(ComparableFloat(result), ComparableFloat((-x).copysign(&y)));
+    });
+
+    float_gen().test_properties(|x| {
+        assert_eq!(
+            ComparableFloat(x.clone().copysign(&x)),
+            ComparableFloat(x.clone())
+        );
+    });
+
+    primitive_float_pair_gen::<f64>().test_properties(|(x, y)| {
+        if !x.is_nan() && !y.is_nan() {
+            assert_eq!(
+                NiceFloat(f64::exact_from(Float::from(x).copysign(&Float::from(y)))),
+                NiceFloat(x.copysign(y))
+            );
+        }
+    });
+}
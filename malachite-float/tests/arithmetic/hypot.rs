@@ -0,0 +1,317 @@
+use malachite_base::num::arithmetic::traits::Abs;
+use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_float_unsigned_rounding_mode_quadruple_gen_var_1, float_gen, float_pair_gen,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use std::cmp::{max, Ordering};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_hypot() {
+    let test = |s, s_hex, t, t_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let h = x.hypot(&y);
+        assert!(h.is_valid());
+        assert_eq!(h.to_string(), out);
+        assert_eq!(to_hex_string(&h), out_hex);
+
+        let rug_h = rug::Float::with_val(
+            u32::exact_from(max(x.get_prec().unwrap_or(1), y.get_prec().unwrap_or(1))),
+            rug::Float::exact_from(&x).hypot_ref(&rug::Float::exact_from(&y)),
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_h)),
+            ComparableFloatRef(&h)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN", "NaN", "NaN");
+    test("NaN", "NaN", "1.0", "0x1.0#1", "NaN", "NaN");
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+    );
+    test("0.0", "0x0.0", "-0.0", "-0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-1.5", "-0x1.8#2", "1.5", "0x1.8#2");
+    test("3.0", "0x3.0#2", "4.0", "0x4.0#1", "4.0", "0x4.0#2");
+    test("-3.0", "-0x3.0#2", "-4.0", "-0x4.0#1", "4.0", "0x4.0#2");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test(
+        "1.0",
+        "0x1.0000000000000#53",
+        "1.0",
+        "0x1.0000000000000#53",
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "4.154354402313313",
+        "0x4.2783c525ede70#53",
+    );
+    test(
+        "1.0e100",
+        "0x1.249ad2594c37dE+83#53",
+        "1.0",
+        "0x1.0#1",
+        "1.0e100",
+        "0x1.249ad2594c37dE+83#53",
+    );
+}
+
+#[test]
+fn test_hypot_prec_round() {
+    let test = |s, s_hex, t, t_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (h, o) = x.hypot_prec_round(&y, prec, rm);
+        assert!(h.is_valid());
+        assert_eq!(h.to_string(), out);
+        assert_eq!(to_hex_string(&h), out_hex);
+        assert_eq!(o, o_out);
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_h, rug_o) = rug::Float::with_val_round(
+                u32::exact_from(prec),
+                rug::Float::exact_from(&x).hypot_ref(&rug::Float::exact_from(&y)),
+                rug_rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_h)),
+                ComparableFloatRef(&h)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "3.0",
+        "0x3.0#2",
+        "4.0",
+        "0x4.0#1",
+        10,
+        RoundingMode::Exact,
+        "5.0",
+        "0x5.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        20,
+        RoundingMode::Floor,
+        "1.414213",
+        "0x1.6a09e#20",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        20,
+        RoundingMode::Ceiling,
+        "1.414215",
+        "0x1.6a0a0#20",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        20,
+        RoundingMode::Nearest,
+        "1.414213",
+        "0x1.6a09e#20",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        20,
+        RoundingMode::Odd,
+        "1.414213",
+        "0x1.6a09e#20",
+        Ordering::Less,
+    );
+    test(
+        "-1.0e100",
+        "-0x1.249ad2594c37dE+83#53",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "1.001e100",
+        "0x1.250E+83#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0e100",
+        "-0x1.249ad2594c37dE+83#53",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "9.996e99",
+        "0x1.248E+83#10",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-3.0",
+        "-0x3.0#2",
+        1,
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn hypot_prec_round_fail() {
+    assert_panic!(Float::from(1).hypot_prec_round(&Float::from(1), 0, RoundingMode::Floor));
+    assert_panic!(Float::from(1).hypot_prec_round(&Float::from(1), 10, RoundingMode::Exact));
+}
+
+#[test]
+fn hypot_prec_round_properties() {
+    float_float_unsigned_rounding_mode_quadruple_gen_var_1().test_properties(|(x, y, prec, rm)| {
+        let (h, o) = x.hypot_prec_round(&y, prec, rm);
+        assert!(h.is_valid());
+
+        let (h_alt, o_alt) = y.hypot_prec_round(&x, prec, rm);
+        assert_eq!(ComparableFloatRef(&h_alt), ComparableFloatRef(&h));
+        assert_eq!(o_alt, o);
+
+        let (h_alt, o_alt) = (-&x).hypot_prec_round(&-&y, prec, rm);
+        assert_eq!(ComparableFloatRef(&h_alt), ComparableFloatRef(&h));
+        assert_eq!(o_alt, o);
+
+        if h.is_normal() {
+            assert_eq!(h.get_prec(), Some(prec));
+            assert!(h > 0u32);
+        }
+        if x.is_finite() && y.is_finite() {
+            // h and the exact result are nonnegative, so comparing their squares is enough
+            let h_squared = Rational::exact_from(&h) * Rational::exact_from(&h);
+            let exact_squared = Rational::exact_from(&x) * Rational::exact_from(&x)
+                + Rational::exact_from(&y) * Rational::exact_from(&y);
+            assert_eq!(h_squared.cmp(&exact_squared), o);
+        }
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_h, rug_o) = rug::Float::with_val_round(
+                u32::exact_from(prec),
+                rug::Float::exact_from(&x).hypot_ref(&rug::Float::exact_from(&y)),
+                rug_rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_h)),
+                ComparableFloatRef(&h)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[test]
+fn hypot_properties() {
+    float_pair_gen().test_properties(|(x, y)| {
+        let h = x.hypot(&y);
+        assert!(h.is_valid());
+        assert_eq!(ComparableFloat(y.hypot(&x)), ComparableFloat(h.clone()));
+        assert_eq!(
+            ComparableFloat(x.clone().abs().hypot(&y.clone().abs())),
+            ComparableFloat(h.clone())
+        );
+        assert!(!h.is_sign_negative());
+
+        let prec = max(x.get_prec().unwrap_or(1), y.get_prec().unwrap_or(1));
+        let (h_alt, _) = x.hypot_round(&y, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&h_alt), ComparableFloatRef(&h));
+        let (h_alt, _) = x.hypot_prec(&y, prec);
+        assert_eq!(ComparableFloatRef(&h_alt), ComparableFloatRef(&h));
+
+        let rug_h = rug::Float::with_val(
+            u32::exact_from(prec),
+            rug::Float::exact_from(&x).hypot_ref(&rug::Float::exact_from(&y)),
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_h)),
+            ComparableFloatRef(&h)
+        );
+    });
+
+    float_gen().test_properties(|x| {
+        assert_eq!(
+            ComparableFloat(x.hypot(&Float::INFINITY)),
+            ComparableFloat(Float::INFINITY)
+        );
+        if !x.is_nan() {
+            assert_eq!(
+                ComparableFloat(x.hypot(&Float::ZERO)),
+                ComparableFloat(x.clone().abs())
+            );
+        } else {
+            assert!(x.hypot(&Float::ZERO).is_nan());
+            assert!(x.hypot(&Float::NAN).is_nan());
+        }
+    });
+}
@@ -0,0 +1,186 @@
+use malachite_base::num::arithmetic::traits::{Abs, NegAssign, Square};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_float::ball::Ball;
+use malachite_float::test_util::generators::{float_gen, float_pair_gen};
+use malachite_float::Float;
+use malachite_q::Rational;
+
+fn ball(mid: f64, rad: f64) -> Ball {
+    Ball::new(Float::from(mid), Float::from(rad))
+}
+
+// Returns the endpoints of a `Ball` built from a finite `Float`, widened by a small radius, along
+// with the `Ball` itself. Returns `None` if the `Float` is not finite.
+fn widen(x: &Float) -> Option<(Ball, Rational)> {
+    if !x.is_finite() {
+        return None;
+    }
+    let q = Rational::exact_from(x);
+    Some((Ball::new(x.clone(), Float::from(0.125)), q))
+}
+
+#[test]
+fn test_add_sub_mul_div() {
+    let test = |x: Ball, y: Ball, sum: &str, difference: &str, product: &str, quotient: &str| {
+        let s = x.add_prec(&y, 20);
+        assert_eq!(s.to_string(), sum);
+        assert_eq!((&x + &y).to_string(), (x.clone() + y.clone()).to_string());
+        let mut s_alt = x.clone();
+        s_alt += &y;
+        assert_eq!(s_alt.to_string(), (&x + &y).to_string());
+
+        assert_eq!(x.sub_prec(&y, 20).to_string(), difference);
+        let mut d_alt = x.clone();
+        d_alt -= y.clone();
+        assert_eq!(d_alt.to_string(), (&x - &y).to_string());
+
+        assert_eq!(x.mul_prec(&y, 20).to_string(), product);
+        let mut p_alt = x.clone();
+        p_alt *= &y;
+        assert_eq!(p_alt.to_string(), (&x * &y).to_string());
+
+        assert_eq!(x.div_prec(&y, 20).to_string(), quotient);
+        let mut q_alt = x.clone();
+        q_alt /= y.clone();
+        assert_eq!(q_alt.to_string(), (&x / &y).to_string());
+    };
+    test(
+        Ball::ONE,
+        Ball::ONE,
+        "[2.0 +/- 0.0]",
+        "[0.0 +/- 0.0]",
+        "[1.0 +/- 0.0]",
+        "[1.0 +/- 0.0]",
+    );
+    test(
+        ball(1.5, 0.25),
+        ball(2.0, 0.5),
+        "[3.5 +/- 0.75]",
+        "[-0.5 +/- 0.75]",
+        "[3.0 +/- 1.375]",
+        "[0.75 +/- 0.416666667]",
+    );
+    test(
+        Ball::ONE,
+        ball(0.0, 0.5),
+        "[1.0 +/- 0.5]",
+        "[1.0 +/- 0.5]",
+        "[0.0 +/- 0.5]",
+        "[NaN +/- Infinity]",
+    );
+    test(
+        Ball::INDETERMINATE,
+        Ball::ONE,
+        "[NaN +/- Infinity]",
+        "[NaN +/- Infinity]",
+        "[NaN +/- Infinity]",
+        "[NaN +/- Infinity]",
+    );
+}
+
+#[test]
+fn test_neg_and_square() {
+    let test = |x: Ball, neg: &str, square: &str| {
+        assert_eq!((-x.clone()).to_string(), neg);
+        assert_eq!((-&x).to_string(), neg);
+        let mut neg_alt = x.clone();
+        neg_alt.neg_assign();
+        assert_eq!(neg_alt.to_string(), neg);
+
+        assert_eq!(x.square_prec(20).to_string(), square);
+        assert_eq!((&x).square().to_string(), x.clone().square().to_string());
+    };
+    test(Ball::ZERO, "[0.0 +/- 0.0]", "[0.0 +/- 0.0]");
+    test(ball(1.5, 0.25), "[-1.5 +/- 0.25]", "[2.25 +/- 0.8125]");
+    test(ball(0.5, 1.0), "[-0.5 +/- 1.0]", "[0.25 +/- 2.0]");
+    test(
+        Ball::INDETERMINATE,
+        "[NaN +/- Infinity]",
+        "[NaN +/- Infinity]",
+    );
+}
+
+#[test]
+fn test_elementary_functions() {
+    let two = Ball::from(Float::from(2));
+    let sqrt_2 = two.sqrt_prec(200);
+    assert!(sqrt_2
+        .square_prec(200)
+        .contains_rational(&Rational::from(2)));
+    assert!(sqrt_2.rad() < &Float::from(1.0e-59));
+
+    let e = Ball::ONE.exp_prec(200);
+    let ln_e = e.log_prec(200);
+    assert!(ln_e.contains_rational(&Rational::ONE));
+    assert!(ln_e.rad() < &Float::from(1.0e-58));
+
+    let ln_2 = Ball::ln_2_prec(200);
+    assert!(ln_2.overlaps(&two.log_prec(200)));
+    assert!(ln_2.exp_prec(200).contains_rational(&Rational::from(2)));
+
+    let pi = Ball::pi_prec(200);
+    assert!(pi.rad() < &Float::from(1.0e-59));
+    let (sin, cos) = pi
+        .mul_prec(&Ball::from(Float::from(0.5)), 200)
+        .sin_cos_prec(200);
+    assert!(sin.contains_rational(&Rational::ONE));
+    assert!(cos.contains_rational(&Rational::ZERO));
+    assert!(Ball::ONE
+        .atan_prec(200)
+        .mul_prec(&Ball::from(Float::from(4)), 200)
+        .overlaps(&pi));
+
+    let x = Ball::from(Float::from(1000));
+    let (sin, cos) = x.sin_cos_prec(100);
+    assert!(sin
+        .square_prec(100)
+        .add_prec(&cos.square_prec(100), 100)
+        .contains_rational(&Rational::ONE));
+
+    assert!(!Ball::from(Float::from(-1)).sqrt_prec(10).is_finite());
+    assert!(!ball(0.5, 1.0).log_prec(10).is_finite());
+    assert!(!Ball::from(Float::from(1.0e30)).exp_prec(10).is_finite());
+    assert!(!Ball::INDETERMINATE.sin_prec(10).is_finite());
+}
+
+#[test]
+fn add_sub_mul_properties() {
+    float_pair_gen().test_properties(|(x, y)| {
+        let (Some((bx, qx)), Some((by, qy))) = (widen(&x), widen(&y)) else {
+            return;
+        };
+        for prec in [1, 10, 100] {
+            let sum = bx.add_prec(&by, prec);
+            assert!(sum.contains_rational(&(&qx + &qy)));
+            let difference = bx.sub_prec(&by, prec);
+            assert!(difference.contains_rational(&(&qx - &qy)));
+            let product = bx.mul_prec(&by, prec);
+            assert!(product.contains_rational(&(&qx * &qy)));
+            if !by.contains_zero() {
+                let quotient = bx.div_prec(&by, prec);
+                assert!(quotient.contains_rational(&(&qx / &qy)));
+            }
+        }
+    });
+}
+
+#[test]
+fn sqrt_exp_properties() {
+    float_gen().test_properties(|x| {
+        let Some((bx, qx)) = widen(&x) else {
+            return;
+        };
+        let square = bx.square_prec(50);
+        assert!(square.contains_rational(&(&qx * &qx)));
+        let sqrt = square.sqrt_prec(50);
+        if sqrt.is_finite() {
+            assert!(sqrt.contains_rational(&(&qx).abs()));
+        }
+        if qx == 0u32 || qx.floor_log_base_2_abs() < 10 {
+            let exp = bx.exp_prec(50);
+            assert!(exp.is_finite());
+            assert!(exp.log_prec(50).contains_rational(&qx));
+        }
+    });
+}
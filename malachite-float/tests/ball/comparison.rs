@@ -0,0 +1,61 @@
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_float::ball::Ball;
+use malachite_float::test_util::generators::float_pair_gen;
+use malachite_float::Float;
+use malachite_q::Rational;
+
+fn ball(mid: f64, rad: f64) -> Ball {
+    Ball::new(Float::from(mid), Float::from(rad))
+}
+
+#[test]
+fn test_contains_rational() {
+    let x = ball(1.5, 0.25);
+    assert!(x.contains_rational(&Rational::from_signeds(5, 4)));
+    assert!(x.contains_rational(&Rational::from_signeds(7, 4)));
+    assert!(x.contains_rational(&Rational::from_signeds(3, 2)));
+    assert!(!x.contains_rational(&Rational::ONE));
+    assert!(!x.contains_rational(&Rational::from(2)));
+    assert!(Ball::ZERO.contains_rational(&Rational::ZERO));
+    assert!(!Ball::ZERO.contains_rational(&Rational::ONE));
+    assert!(Ball::INDETERMINATE.contains_rational(&Rational::from(1000)));
+}
+
+#[test]
+fn test_contains_overlaps_contains_zero() {
+    let test = |x: Ball, y: Ball, contains: bool, overlaps: bool| {
+        assert_eq!(x.contains(&y), contains);
+        assert_eq!(x.overlaps(&y), overlaps);
+        assert_eq!(y.overlaps(&x), overlaps);
+    };
+    test(ball(1.5, 0.25), ball(1.5, 0.25), true, true);
+    test(ball(1.5, 0.5), ball(1.25, 0.25), true, true);
+    test(ball(1.25, 0.25), ball(1.5, 0.5), false, true);
+    test(ball(1.0, 0.5), ball(2.0, 0.5), false, true);
+    test(ball(1.0, 0.5), ball(2.5, 0.5), false, false);
+    test(Ball::INDETERMINATE, ball(2.5, 0.5), true, true);
+    test(ball(2.5, 0.5), Ball::INDETERMINATE, false, true);
+
+    assert!(Ball::ZERO.contains_zero());
+    assert!(ball(1.0, 1.0).contains_zero());
+    assert!(!ball(1.0, 0.5).contains_zero());
+    assert!(Ball::INDETERMINATE.contains_zero());
+}
+
+#[test]
+fn contains_overlaps_properties() {
+    float_pair_gen().test_properties(|(x, y)| {
+        if !x.is_finite() || !y.is_finite() {
+            return;
+        }
+        let bx = Ball::from(&x);
+        let wide = Ball::new(x.clone(), Float::ONE);
+        assert!(bx.contains(&bx));
+        assert!(wide.contains(&bx));
+        assert!(wide.overlaps(&bx));
+        let by = Ball::from(&y);
+        assert_eq!(bx.overlaps(&by), x == y);
+        assert_eq!(bx.contains(&by), x == y);
+        assert!(Ball::INDETERMINATE.contains(&bx));
+    });
+}
@@ -0,0 +1,128 @@
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero, One};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::ball::{Ball, RADIUS_PREC};
+use malachite_float::test_util::generators::float_gen;
+use malachite_float::Float;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+
+#[test]
+fn test_from_float() {
+    let test = |x: Float, out: &str| {
+        let b = Ball::from(&x);
+        assert_eq!(b.to_string(), out);
+        assert_eq!(format!("{b:?}"), out);
+        assert_eq!(Ball::from(x).to_string(), out);
+    };
+    test(Float::ONE, "[1.0 +/- 0.0]");
+    test(Float::from(-1.5), "[-1.5 +/- 0.0]");
+    test(Float::NEGATIVE_ZERO, "[0.0 +/- 0.0]");
+    test(Float::NAN, "[NaN +/- Infinity]");
+    test(Float::INFINITY, "[NaN +/- Infinity]");
+}
+
+#[test]
+fn test_new() {
+    let b = Ball::new(Float::from(1.5), Float::from(1.0e-10));
+    assert_eq!(b.mid().to_string(), "1.5");
+    assert_eq!(b.rad().get_prec(), Some(RADIUS_PREC));
+    assert!(Rational::try_from(b.rad()).unwrap() >= Rational::try_from(1.0e-10).unwrap());
+    assert!(!Ball::new(Float::NAN, Float::ONE).is_finite());
+    assert!(!Ball::new(Float::ONE, Float::INFINITY).is_finite());
+}
+
+#[test]
+#[should_panic]
+fn new_fail() {
+    Ball::new(Float::ONE, Float::from(-1));
+}
+
+#[test]
+fn test_from_rational() {
+    let test = |x: Rational, prec: u64, out: &str| {
+        let b = Ball::from_rational_prec(&x, prec);
+        assert_eq!(b.to_string(), out);
+        assert!(b.contains_rational(&x));
+    };
+    test(Rational::ONE, 1, "[1.0 +/- 0.0]");
+    test(
+        Rational::from_signeds(1, 3),
+        10,
+        "[0.3335 +/- 0.0001627604167]",
+    );
+    test(
+        Rational::from_signeds(-22, 7),
+        20,
+        "[-3.142857 +/- 5.44956753e-7]",
+    );
+
+    let b = Ball::from_rational_interval(&Rational::from(-1), &Rational::from_signeds(1, 3), 10);
+    assert!(b.contains_rational(&Rational::from(-1)));
+    assert!(b.contains_rational(&Rational::from_signeds(1, 3)));
+}
+
+#[test]
+fn test_to_rational_interval() {
+    let (a, b) = Ball::new(Float::from(1.5), Float::from(0.25))
+        .to_rational_interval()
+        .unwrap();
+    assert_eq!(a, Rational::from_signeds(5, 4));
+    assert_eq!(b, Rational::from_signeds(7, 4));
+    assert!(Ball::INDETERMINATE.to_rational_interval().is_none());
+}
+
+#[test]
+fn test_to_float_prec_round() {
+    let test = |x: &Ball, prec: u64, rm: RoundingMode, out: Option<(&str, Ordering)>| {
+        assert_eq!(
+            x.to_float_prec_round(prec, rm)
+                .map(|(f, o)| (f.to_string(), o)),
+            out.map(|(s, o)| (s.to_string(), o))
+        );
+    };
+    let pi = Ball::pi_prec(100);
+    test(
+        &pi,
+        10,
+        RoundingMode::Floor,
+        Some(("3.141", Ordering::Less)),
+    );
+    test(
+        &pi,
+        10,
+        RoundingMode::Ceiling,
+        Some(("3.145", Ordering::Greater)),
+    );
+    test(
+        &pi,
+        10,
+        RoundingMode::Nearest,
+        Some(("3.141", Ordering::Less)),
+    );
+    test(&pi, 10, RoundingMode::Exact, None);
+    test(&Ball::pi_prec(10), 100, RoundingMode::Nearest, None);
+    test(
+        &Ball::ONE,
+        10,
+        RoundingMode::Exact,
+        Some(("1.0", Ordering::Equal)),
+    );
+    test(&Ball::INDETERMINATE, 10, RoundingMode::Nearest, None);
+}
+
+#[test]
+fn to_float_prec_round_properties() {
+    float_gen().test_properties(|x| {
+        let b = Ball::from(&x);
+        if !x.is_finite() {
+            assert!(b.to_float_prec_round(10, RoundingMode::Nearest).is_none());
+            return;
+        }
+        let prec = x.get_prec().unwrap_or(1);
+        let (y, o) = b.to_float_prec_round(prec, RoundingMode::Exact).unwrap();
+        assert_eq!(y, x);
+        assert_eq!(o, Ordering::Equal);
+        let (a, b) = b.to_rational_interval().unwrap();
+        assert_eq!(a, b);
+    });
+}
@@ -7,7 +7,7 @@ use malachite_base::num::logic::traits::SignificantBits;
 use malachite_base::rounding_modes::RoundingMode;
 use malachite_base::strings::ToDebugString;
 use malachite_float::test_util::common::{
-    parse_hex_string, rug_round_exact_from_rounding_mode, to_hex_string,
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
 };
 use malachite_float::test_util::generators::{
     float_gen, float_unsigned_pair_gen_var_1, float_unsigned_rounding_mode_triple_gen_var_1,
@@ -288,15 +288,9 @@ fn test_set_prec_round() {
         assert_eq!(x.to_string(), out);
         assert_eq!(to_hex_string(&x), out_hex);
 
-        if rm != RoundingMode::Exact {
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
             let mut rug_x = rug::Float::exact_from(&old_x);
-            assert_eq!(
-                rug_x.set_prec_round(
-                    u32::exact_from(prec),
-                    rug_round_exact_from_rounding_mode(rm)
-                ),
-                o
-            );
+            assert_eq!(rug_x.set_prec_round(u32::exact_from(prec), rug_rm), o);
             assert_eq!(ComparableFloat(x), ComparableFloat(Float::from(&rug_x)));
         }
     };
@@ -544,6 +538,69 @@ fn test_set_prec_round() {
         "0x3.24#10",
         Ordering::Less,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::NearestTiesAway,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::NearestTiesTowardZero,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Odd,
+        "3.145",
+        "0x3.25#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.5",
+        "0x2.8#3",
+        2,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#2",
+        Ordering::Less,
+    );
+    test(
+        "2.5",
+        "0x2.8#3",
+        2,
+        RoundingMode::NearestTiesAway,
+        "3.0",
+        "0x3.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "2.5",
+        "0x2.8#3",
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        "2.0",
+        "0x2.0#2",
+        Ordering::Less,
+    );
+    test(
+        "2.5",
+        "0x2.8#3",
+        2,
+        RoundingMode::Odd,
+        "3.0",
+        "0x3.0#2",
+        Ordering::Greater,
+    );
 
     test(
         "-1.0",
@@ -698,6 +755,33 @@ fn test_set_prec_round() {
         "-0x3.24#10",
         Ordering::Greater,
     );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Odd,
+        "-3.145",
+        "-0x3.25#10",
+        Ordering::Less,
+    );
+    test(
+        "-2.5",
+        "-0x2.8#3",
+        2,
+        RoundingMode::NearestTiesAway,
+        "-3.0",
+        "-0x3.0#2",
+        Ordering::Less,
+    );
+    test(
+        "-2.5",
+        "-0x2.8#3",
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        "-2.0",
+        "-0x2.0#2",
+        Ordering::Greater,
+    );
 }
 
 #[test]
@@ -724,12 +808,9 @@ fn set_prec_round_properties() {
 
         if rm == RoundingMode::Exact {
             assert_eq!(o, Ordering::Equal);
-        } else {
+        } else if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
             let mut rug_x = rug::Float::exact_from(&old_x);
-            assert_eq!(
-                rug_x.set_prec_round(u32::exact_from(p), rug_round_exact_from_rounding_mode(rm)),
-                o
-            );
+            assert_eq!(rug_x.set_prec_round(u32::exact_from(p), rug_rm), o);
             assert_eq!(
                 ComparableFloatRef(&x),
                 ComparableFloatRef(&Float::from(&rug_x))
@@ -5,9 +5,10 @@ use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Negati
 use malachite_base::num::conversion::traits::{ExactFrom, SciMantissaAndExponent};
 use malachite_base::test_util::generators::primitive_float_gen_var_12;
 use malachite_float::test_util::common::{parse_hex_string, to_hex_string};
-use malachite_float::test_util::generators::{float_gen, float_gen_var_3};
+use malachite_float::test_util::generators::{float_gen, float_gen_var_3, float_pair_gen};
 use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
 use malachite_q::Rational;
+use std::cmp::Ordering;
 use std::panic::catch_unwind;
 
 #[allow(clippy::redundant_closure_for_method_calls)]
@@ -340,3 +341,345 @@ fn decrement_properties() {
         }
     });
 }
+
+#[test]
+fn test_next_higher() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let mut x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        x.next_higher();
+        assert!(x.is_valid());
+
+        assert_eq!(x.to_string(), out);
+        assert_eq!(to_hex_string(&x), out_hex);
+
+        let mut rug_x = rug::Float::exact_from(&parse_hex_string(s_hex));
+        rug_x.next_up();
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_x)),
+            ComparableFloatRef(&x)
+        );
+    };
+    test("1.0", "0x1.0#1", "2.0", "0x2.0#1");
+    test("2.0", "0x2.0#1", "4.0", "0x4.0#1");
+    test("0.5", "0x0.8#1", "1.0", "0x1.0#1");
+    test("3.0", "0x3.0#2", "4.0", "0x4.0#2");
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        "1.000000000000000000000000000002",
+        "0x1.0000000000000000000000002#100",
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "1.4142135623730954",
+        "0x1.6a09e667f3bce#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897936",
+        "0x3.243f6a8885a32#53",
+    );
+
+    test("-1.0", "-0x1.0#1", "-0.5", "-0x0.8#1");
+    test("-2.0", "-0x2.0#1", "-1.0", "-0x1.0#1");
+    test("-0.5", "-0x0.8#1", "-0.2", "-0x0.4#1");
+    test("-3.0", "-0x3.0#2", "-2.0", "-0x2.0#2");
+    test(
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        "-0.999999999999999999999999999999",
+        "-0x0.fffffffffffffffffffffffff#100",
+    );
+    test(
+        "-0.33333333333333331",
+        "-0x0.55555555555554#53",
+        "-0.33333333333333326",
+        "-0x0.55555555555550#53",
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "-1.4142135623730949",
+        "-0x1.6a09e667f3bcc#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-3.1415926535897927",
+        "-0x3.243f6a8885a2e#53",
+    );
+}
+
+#[test]
+fn next_higher_fail() {
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.next_higher();
+    });
+    assert_panic!({
+        let mut x = Float::INFINITY;
+        x.next_higher();
+    });
+    assert_panic!({
+        let mut x = Float::NEGATIVE_INFINITY;
+        x.next_higher();
+    });
+    assert_panic!({
+        let mut x = Float::ZERO;
+        x.next_higher();
+    });
+    assert_panic!({
+        let mut x = Float::NEGATIVE_ZERO;
+        x.next_higher();
+    });
+}
+
+#[test]
+fn next_higher_properties() {
+    float_gen_var_3().test_properties(|mut x| {
+        let old_x = x.clone();
+        x.next_higher();
+        assert!(x.is_valid());
+        assert_eq!(x.get_prec(), old_x.get_prec());
+        assert!(x > old_x);
+
+        let mut rug_x = rug::Float::exact_from(&old_x);
+        rug_x.next_up();
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_x)),
+            ComparableFloatRef(&x)
+        );
+
+        let mut y = x.clone();
+        y.next_lower();
+        assert_eq!(ComparableFloatRef(&y), ComparableFloatRef(&old_x));
+
+        let mut y = -&old_x;
+        y.next_lower();
+        assert_eq!(ComparableFloat(y), ComparableFloat(-x));
+    });
+}
+
+#[test]
+fn test_next_lower() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let mut x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        x.next_lower();
+        assert!(x.is_valid());
+
+        assert_eq!(x.to_string(), out);
+        assert_eq!(to_hex_string(&x), out_hex);
+
+        let mut rug_x = rug::Float::exact_from(&parse_hex_string(s_hex));
+        rug_x.next_down();
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_x)),
+            ComparableFloatRef(&x)
+        );
+    };
+    test("1.0", "0x1.0#1", "0.5", "0x0.8#1");
+    test("2.0", "0x2.0#1", "1.0", "0x1.0#1");
+    test("0.5", "0x0.8#1", "0.2", "0x0.4#1");
+    test("3.0", "0x3.0#2", "2.0", "0x2.0#2");
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        "0.999999999999999999999999999999",
+        "0x0.fffffffffffffffffffffffff#100",
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "0.33333333333333326",
+        "0x0.55555555555550#53",
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "1.4142135623730949",
+        "0x1.6a09e667f3bcc#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897927",
+        "0x3.243f6a8885a2e#53",
+    );
+
+    test("-1.0", "-0x1.0#1", "-2.0", "-0x2.0#1");
+    test("-2.0", "-0x2.0#1", "-4.0", "-0x4.0#1");
+    test("-0.5", "-0x0.8#1", "-1.0", "-0x1.0#1");
+    test("-3.0", "-0x3.0#2", "-4.0", "-0x4.0#2");
+    test(
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        "-1.000000000000000000000000000002",
+        "-0x1.0000000000000000000000002#100",
+    );
+    test(
+        "-0.33333333333333331",
+        "-0x0.55555555555554#53",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "-1.4142135623730954",
+        "-0x1.6a09e667f3bce#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-3.1415926535897936",
+        "-0x3.243f6a8885a32#53",
+    );
+}
+
+#[test]
+fn next_lower_fail() {
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.next_lower();
+    });
+    assert_panic!({
+        let mut x = Float::INFINITY;
+        x.next_lower();
+    });
+    assert_panic!({
+        let mut x = Float::NEGATIVE_INFINITY;
+        x.next_lower();
+    });
+    assert_panic!({
+        let mut x = Float::ZERO;
+        x.next_lower();
+    });
+    assert_panic!({
+        let mut x = Float::NEGATIVE_ZERO;
+        x.next_lower();
+    });
+}
+
+#[test]
+fn next_lower_properties() {
+    float_gen_var_3().test_properties(|mut x| {
+        let old_x = x.clone();
+        x.next_lower();
+        assert!(x.is_valid());
+        assert_eq!(x.get_prec(), old_x.get_prec());
+        assert!(x < old_x);
+
+        let mut rug_x = rug::Float::exact_from(&old_x);
+        rug_x.next_down();
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_x)),
+            ComparableFloatRef(&x)
+        );
+
+        let mut y = x.clone();
+        y.next_higher();
+        assert_eq!(ComparableFloatRef(&y), ComparableFloatRef(&old_x));
+
+        let mut y = -&old_x;
+        y.next_higher();
+        assert_eq!(ComparableFloat(y), ComparableFloat(-x));
+    });
+}
+
+#[test]
+fn test_next_toward() {
+    let test = |s, s_hex, t, t_hex, out: &str, out_hex: &str| {
+        let mut x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        x.next_toward(&y);
+        assert!(x.is_valid());
+
+        assert_eq!(x.to_string(), out);
+        assert_eq!(to_hex_string(&x), out_hex);
+    };
+    test("1.0", "0x1.0#1", "NaN", "NaN", "NaN", "NaN");
+    test("1.0", "0x1.0#1", "Infinity", "Infinity", "2.0", "0x2.0#1");
+    test("1.0", "0x1.0#1", "-Infinity", "-Infinity", "0.5", "0x0.8#1");
+    test("1.0", "0x1.0#1", "0.0", "0x0.0", "0.5", "0x0.8#1");
+    test("1.0", "0x1.0#1", "1.0", "0x1.00000#20", "1.0", "0x1.0#1");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.0",
+        "0x3.0#2",
+        "3.1415926535897927",
+        "0x3.243f6a8885a2e#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "4.0",
+        "0x4.0#1",
+        "3.1415926535897936",
+        "0x3.243f6a8885a32#53",
+    );
+    test("-1.0", "-0x1.0#1", "0.0", "0x0.0", "-0.5", "-0x0.8#1");
+    test("-1.0", "-0x1.0#1", "-3.0", "-0x3.0#2", "-2.0", "-0x2.0#1");
+    test("NaN", "NaN", "1.0", "0x1.0#1", "NaN", "NaN");
+    test("0.0", "0x0.0", "0.0", "0x0.0", "0.0", "0x0.0");
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", "Infinity", "Infinity",
+    );
+}
+
+#[test]
+fn next_toward_fail() {
+    assert_panic!({
+        let mut x = Float::ZERO;
+        x.next_toward(&Float::INFINITY);
+    });
+    assert_panic!({
+        let mut x = Float::INFINITY;
+        x.next_toward(&Float::ZERO);
+    });
+}
+
+#[test]
+fn next_toward_properties() {
+    float_pair_gen().test_properties(|(mut x, y)| {
+        let ordering = x.partial_cmp(&y);
+        if x.is_finite() && x != 0u32
+            || ordering != Some(Ordering::Less) && ordering != Some(Ordering::Greater)
+        {
+            let old_x = x.clone();
+            x.next_toward(&y);
+            assert!(x.is_valid());
+            match ordering {
+                None => assert!(x.is_nan()),
+                Some(Ordering::Equal) => {
+                    assert_eq!(ComparableFloatRef(&x), ComparableFloatRef(&old_x));
+                }
+                Some(Ordering::Less) => {
+                    let mut z = old_x.clone();
+                    z.next_higher();
+                    assert_eq!(ComparableFloatRef(&x), ComparableFloatRef(&z));
+                }
+                Some(Ordering::Greater) => {
+                    let mut z = old_x.clone();
+                    z.next_lower();
+                    assert_eq!(ComparableFloatRef(&x), ComparableFloatRef(&z));
+                }
+            }
+        }
+    });
+}
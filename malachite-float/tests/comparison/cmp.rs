@@ -1,4 +1,4 @@
-use malachite_base::num::basic::traits::{Infinity, NegativeInfinity};
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
 use malachite_base::num::conversion::traits::ExactFrom;
 use malachite_base::num::float::NiceFloat;
 use malachite_base::test_util::generators::primitive_float_pair_gen;
@@ -169,3 +169,55 @@ fn comparable_float_cmp_properties() {
         );
     });
 }
+
+#[test]
+fn test_total_cmp() {
+    // Move NaN from the middle of the `ComparableFloat` order to the end
+    let mut strings = ORDERED_FLOAT_HEX_STRINGS.to_vec();
+    strings.retain(|&s| s != "NaN");
+    strings.push("NaN");
+    let xs = read_hex_strings(&strings);
+    let ys = read_hex_strings(&strings);
+    for (i, x) in xs.iter().enumerate() {
+        for (j, y) in ys.iter().enumerate() {
+            assert_eq!(i.cmp(&j), x.total_cmp(y));
+        }
+    }
+}
+
+#[test]
+fn total_cmp_properties() {
+    float_pair_gen().test_properties(|(x, y)| {
+        let ord = x.total_cmp(&y);
+        assert_eq!(y.total_cmp(&x).reverse(), ord);
+        assert_eq!(
+            ComparableFloatRef(&x) == ComparableFloatRef(&y),
+            ord == Ordering::Equal
+        );
+        if !x.is_nan() && !y.is_nan() {
+            assert_eq!(ComparableFloatRef(&x).cmp(&ComparableFloatRef(&y)), ord);
+            assert_eq!((-y).total_cmp(&-x), ord);
+        }
+    });
+
+    float_gen().test_properties(|x| {
+        assert_eq!(x.total_cmp(&x), Ordering::Equal);
+        assert_ne!(x.total_cmp(&Float::NAN), Ordering::Greater);
+        if !x.is_nan() {
+            assert_ne!(x.total_cmp(&Float::INFINITY), Ordering::Greater);
+            assert_ne!(x.total_cmp(&Float::NEGATIVE_INFINITY), Ordering::Less);
+        }
+    });
+
+    float_triple_gen().test_properties(|(x, y, z)| {
+        if x.total_cmp(&y) == Ordering::Less && y.total_cmp(&z) == Ordering::Less {
+            assert_eq!(x.total_cmp(&z), Ordering::Less);
+        }
+    });
+
+    primitive_float_pair_gen::<f64>().test_properties(|(x, y)| {
+        if !x.is_nan() && !y.is_nan() {
+            assert_eq!(Float::from(x).total_cmp(&Float::from(y)), x.total_cmp(&y));
+        }
+    });
+}
@@ -0,0 +1,170 @@
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::test_util::generators::primitive_float_pair_gen;
+use malachite_float::test_util::common::parse_hex_string;
+use malachite_float::test_util::generators::{float_gen, float_pair_gen, float_triple_gen};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+
+#[test]
+fn test_min_max() {
+    let test = |s_hex, t_hex, min: &str, max: &str, min_number: &str, max_number: &str| {
+        let x = parse_hex_string(s_hex);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(x.clone().minimum(y.clone()).to_string(), min);
+        assert_eq!(x.clone().maximum(y.clone()).to_string(), max);
+        assert_eq!(x.clone().minimum_number(y.clone()).to_string(), min_number);
+        assert_eq!(x.maximum_number(y).to_string(), max_number);
+    };
+    test("NaN", "NaN", "NaN", "NaN", "NaN", "NaN");
+    test("NaN", "0x1.0#1", "NaN", "NaN", "1.0", "1.0");
+    test("-0x1.0#1", "NaN", "NaN", "NaN", "-1.0", "-1.0");
+    test("0x0.0", "-0x0.0", "-0.0", "0.0", "-0.0", "0.0");
+    test("-0x0.0", "0x0.0", "-0.0", "0.0", "-0.0", "0.0");
+    test("0x0.0", "0x0.0", "0.0", "0.0", "0.0", "0.0");
+    test("-0x0.0", "-0x0.0", "-0.0", "-0.0", "-0.0", "-0.0");
+    test(
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "-Infinity",
+        "Infinity",
+    );
+    test("0x1.0#1", "0x0.8#1", "0.5", "1.0", "0.5", "1.0");
+    test("-0x1.0#1", "0x0.8#1", "-1.0", "0.5", "-1.0", "0.5");
+    test(
+        "0x3.243f6a8885a30#53",
+        "0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "3.1415926535897931",
+        "2.7182818284590451",
+        "3.1415926535897931",
+    );
+
+    // Equal values with different precisions: `self` is returned
+    let x = parse_hex_string("0x1.0#1");
+    let y = parse_hex_string("0x1.0000000000000000000000000#100");
+    assert_eq!(x.clone().minimum(y.clone()).get_prec(), Some(1));
+    assert_eq!(y.clone().minimum(x.clone()).get_prec(), Some(100));
+    assert_eq!(x.clone().maximum(y.clone()).get_prec(), Some(1));
+    assert_eq!(y.maximum(x).get_prec(), Some(100));
+}
+
+#[test]
+fn min_max_properties() {
+    float_pair_gen().test_properties(|(x, y)| {
+        let min = x.clone().minimum(y.clone());
+        let max = x.clone().maximum(y.clone());
+        let min_number = x.clone().minimum_number(y.clone());
+        let max_number = x.clone().maximum_number(y.clone());
+        assert!(min.is_valid());
+        assert!(max.is_valid());
+        assert!(min_number.is_valid());
+        assert!(max_number.is_valid());
+
+        if x.is_nan() || y.is_nan() {
+            assert!(min.is_nan());
+            assert!(max.is_nan());
+            if x.is_nan() {
+                assert_eq!(ComparableFloatRef(&min_number), ComparableFloatRef(&y));
+                assert_eq!(ComparableFloatRef(&max_number), ComparableFloatRef(&y));
+            } else {
+                assert_eq!(ComparableFloatRef(&min_number), ComparableFloatRef(&x));
+                assert_eq!(ComparableFloatRef(&max_number), ComparableFloatRef(&x));
+            }
+        } else {
+            assert_eq!(ComparableFloatRef(&min_number), ComparableFloatRef(&min));
+            assert_eq!(ComparableFloatRef(&max_number), ComparableFloatRef(&max));
+            assert!(min <= x && min <= y);
+            assert!(max >= x && max >= y);
+            assert!(
+                ComparableFloatRef(&min) == ComparableFloatRef(&x)
+                    || ComparableFloatRef(&min) == ComparableFloatRef(&y)
+            );
+            assert!(
+                ComparableFloatRef(&max) == ComparableFloatRef(&x)
+                    || ComparableFloatRef(&max) == ComparableFloatRef(&y)
+            );
+            if min.is_sign_positive() {
+                assert!(x.is_sign_positive() && y.is_sign_positive());
+            }
+            if max.is_sign_negative() {
+                assert!(x.is_sign_negative() && y.is_sign_negative());
+            }
+            assert_eq!(
+                ComparableFloat(-(-&x).maximum(-&y)),
+                ComparableFloat(min.clone())
+            );
+            if x != y {
+                assert_eq!(
+                    ComparableFloat(y.clone().minimum(x.clone())),
+                    ComparableFloat(min)
+                );
+                assert_eq!(ComparableFloat(y.maximum(x)), ComparableFloat(max));
+            }
+        }
+    });
+
+    float_gen().test_properties(|x| {
+        assert_eq!(
+            ComparableFloat(x.clone().minimum(x.clone())),
+            ComparableFloat(x.clone())
+        );
+        assert_eq!(
+            ComparableFloat(x.clone().maximum(x.clone())),
+            ComparableFloat(x.clone())
+        );
+        assert!(x.clone().minimum(Float::NAN).is_nan());
+        assert!(x.clone().maximum(Float::NAN).is_nan());
+        assert_eq!(
+            ComparableFloat(x.clone().minimum_number(Float::NAN)),
+            ComparableFloat(x.clone())
+        );
+        assert_eq!(
+            ComparableFloat(x.clone().maximum_number(Float::NAN)),
+            ComparableFloat(x.clone())
+        );
+        if !x.is_nan() {
+            assert_eq!(
+                ComparableFloat(x.clone().minimum(Float::INFINITY)),
+                ComparableFloat(x.clone())
+            );
+            assert_eq!(
+                ComparableFloat(x.clone().maximum(Float::NEGATIVE_INFINITY)),
+                ComparableFloat(x)
+            );
+        }
+    });
+
+    float_triple_gen().test_properties(|(x, y, z)| {
+        assert_eq!(
+            ComparableFloat(x.clone().minimum(y.clone()).minimum(z.clone())),
+            ComparableFloat(x.clone().minimum(y.clone().minimum(z.clone())))
+        );
+        assert_eq!(
+            ComparableFloat(x.clone().maximum(y.clone()).maximum(z.clone())),
+            ComparableFloat(x.maximum(y.maximum(z)))
+        );
+    });
+
+    primitive_float_pair_gen::<f64>().test_properties(|(x, y)| {
+        if !x.is_nan() && !y.is_nan() {
+            assert_eq!(
+                NiceFloat(f64::try_from(Float::from(x).minimum(Float::from(y))).unwrap()),
+                NiceFloat(if x < y || x == y && x.is_sign_negative() {
+                    x
+                } else {
+                    y
+                })
+            );
+            assert_eq!(
+                NiceFloat(f64::try_from(Float::from(x).maximum(Float::from(y))).unwrap()),
+                NiceFloat(if x > y || x == y && x.is_sign_positive() {
+                    x
+                } else {
+                    y
+                })
+            );
+        }
+    });
+}
@@ -0,0 +1,352 @@
+use malachite_base::num::arithmetic::traits::NegAssign;
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeOne, One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::complex::Complex;
+use malachite_float::test_util::generators::float_pair_gen;
+use malachite_float::Float;
+use malachite_q::complex::ComplexRational;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+
+fn complex(re: f64, im: f64) -> Complex {
+    Complex::new(Float::from(re), Float::from(im))
+}
+
+#[test]
+fn test_add_sub_mul_div() {
+    let test =
+        |x: Complex, y: Complex, sum: &str, difference: &str, product: &str, quotient: &str| {
+            assert_eq!(x.add_prec(&y, 20).0.to_string(), sum);
+            let mut s_alt = x.clone();
+            s_alt += &y;
+            assert_eq!(s_alt.to_string(), (&x + &y).to_string());
+
+            assert_eq!(x.sub_prec(&y, 20).0.to_string(), difference);
+            let mut d_alt = x.clone();
+            d_alt -= y.clone();
+            assert_eq!(d_alt.to_string(), (&x - &y).to_string());
+
+            assert_eq!(x.mul_prec(&y, 20).0.to_string(), product);
+            let mut p_alt = x.clone();
+            p_alt *= &y;
+            assert_eq!(p_alt.to_string(), (&x * &y).to_string());
+
+            assert_eq!(x.div_prec(&y, 20).0.to_string(), quotient);
+            let mut q_alt = x.clone();
+            q_alt /= y.clone();
+            assert_eq!(q_alt.to_string(), (&x / &y).to_string());
+        };
+    test(
+        Complex::ONE,
+        Complex::I,
+        "(1.0 1.0)",
+        "(1.0 -1.0)",
+        "(0.0 1.0)",
+        "(0.0 -1.0)",
+    );
+    test(
+        complex(1.5, -2.0),
+        complex(0.25, 3.0),
+        "(1.75 1.0)",
+        "(1.25 -5.0)",
+        "(6.375 4.0)",
+        "(-0.620689 -0.551724)",
+    );
+    test(
+        complex(1.0, 1.0),
+        Complex::ZERO,
+        "(1.0 1.0)",
+        "(1.0 1.0)",
+        "(0.0 0.0)",
+        "(Infinity Infinity)",
+    );
+    test(
+        Complex::NAN,
+        Complex::ONE,
+        "(NaN NaN)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+    );
+}
+
+#[test]
+fn test_prec_round() {
+    let x = complex(1.5, -2.0);
+    let y = complex(0.25, 3.0);
+    let (z, o) = x.mul_prec_round(&y, (4, 4), (RoundingMode::Down, RoundingMode::Up));
+    assert_eq!(z.to_string(), "(6.0 4.0)");
+    assert_eq!(o, (Ordering::Less, Ordering::Equal));
+    let (z, o) = x.div_prec_round(&y, (4, 4), (RoundingMode::Ceiling, RoundingMode::Floor));
+    assert_eq!(z.to_string(), "(-0.56 -0.56)");
+    assert_eq!(o, (Ordering::Greater, Ordering::Less));
+}
+
+#[test]
+#[should_panic]
+fn mul_prec_round_fail() {
+    complex(1.5, -2.0).mul_prec_round(
+        &complex(0.25, 3.0),
+        (2, 2),
+        (RoundingMode::Exact, RoundingMode::Exact),
+    );
+}
+
+#[test]
+fn test_neg_conj() {
+    let x = complex(1.5, -2.0);
+    assert_eq!((-&x).to_string(), "(-1.5 2.0)");
+    assert_eq!(x.conj().to_string(), "(1.5 2.0)");
+    let mut y = x.clone();
+    y.neg_assign();
+    assert_eq!(y, -x);
+}
+
+#[test]
+fn test_abs_arg() {
+    let test = |x: Complex, abs: &str, arg: &str| {
+        assert_eq!(x.abs_prec(20).0.to_string(), abs);
+        assert_eq!(x.arg_prec(20).0.to_string(), arg);
+    };
+    test(complex(3.0, 4.0), "5.0", "0.927296");
+    test(complex(-1.0, 0.0), "1.0", "3.141594");
+    test(complex(0.0, -2.0), "2.0", "-1.570797");
+    test(Complex::ZERO, "0.0", "0.0");
+    test(Complex::NAN, "NaN", "NaN");
+}
+
+#[test]
+fn test_sqrt_exp_log() {
+    let test = |x: Complex, sqrt: &str, exp: &str, log: &str| {
+        assert_eq!(x.sqrt_prec(20).0.to_string(), sqrt);
+        assert_eq!(x.exp_prec(20).0.to_string(), exp);
+        assert_eq!(x.log_prec(20).0.to_string(), log);
+    };
+    test(Complex::ZERO, "(0.0 0.0)", "(1.0 0.0)", "(-Infinity 0.0)");
+    test(Complex::ONE, "(1.0 0.0)", "(2.718281 0.0)", "(0.0 0.0)");
+    test(
+        complex(-4.0, 0.0),
+        "(0.0 2.0)",
+        "(0.01831564 0.0)",
+        "(1.386293 3.141594)",
+    );
+    test(
+        complex(3.0, 4.0),
+        "(2.0 1.0)",
+        "(-13.12878 -15.20079)",
+        "(1.609438 0.927296)",
+    );
+    test(Complex::NAN, "(NaN NaN)", "(NaN NaN)", "(NaN NaN)");
+}
+
+#[test]
+fn test_trig() {
+    let test = |x: Complex, sin: &str, cos: &str, tan: &str| {
+        assert_eq!(x.sin_prec(20).0.to_string(), sin);
+        assert_eq!(x.cos_prec(20).0.to_string(), cos);
+        assert_eq!(x.tan_prec(20).0.to_string(), tan);
+    };
+    test(Complex::ZERO, "(0.0 0.0)", "(1.0 0.0)", "(0.0 0.0)");
+    test(
+        Complex::I,
+        "(0.0 1.175201)",
+        "(1.543081 0.0)",
+        "(0.0 0.761594)",
+    );
+    test(
+        complex(1.0, 1.0),
+        "(1.298458 0.634964)",
+        "(0.83373 -0.988897)",
+        "(0.2717524 1.083923)",
+    );
+}
+
+#[test]
+fn test_pow() {
+    let test = |x: Complex, y: Complex, out: &str| {
+        assert_eq!(x.pow_prec(&y, 20).0.to_string(), out);
+    };
+    test(Complex::I, Complex::I, "(0.2078795 0.0)");
+    test(complex(1.0, 1.0), complex(2.0, 0.0), "(0.0 2.0)");
+    test(complex(1.0, 1.0), complex(-2.0, 0.0), "(0.0 -0.5)");
+    test(Complex::ZERO, Complex::ZERO, "(1.0 0.0)");
+    test(Complex::ZERO, complex(1.0, 0.0), "(0.0 0.0)");
+    test(Complex::ZERO, complex(-1.0, 0.0), "(NaN NaN)");
+    test(complex(4.0, 0.0), complex(0.5, 0.0), "(2.0 0.0)");
+    test(complex(-4.0, 0.0), complex(0.5, 0.0), "(0.0 2.0)");
+    test(complex(-4.0, 0.0), complex(1.5, 0.0), "(0.0 -8.0)");
+    test(complex(3.0, 4.0), complex(0.5, 0.0), "(2.0 1.0)");
+    test(complex(8.0, 0.0), complex(-2.0, 0.0), "(0.015625 0.0)");
+
+    let (p, o) = complex(-4.0, 0.0).pow_prec_round(
+        &complex(0.5, 0.0),
+        (20, 20),
+        (RoundingMode::Exact, RoundingMode::Exact),
+    );
+    assert_eq!(p.to_string(), "(0.0 2.0)");
+    assert_eq!(o, (Ordering::Equal, Ordering::Equal));
+
+    // Both parts of (-4)^(1/4) = 1 + i are exact, but can only be rounded once they are verified
+    let (p, o) = complex(-4.0, 0.0).pow_prec_round(
+        &complex(0.25, 0.0),
+        (20, 20),
+        (RoundingMode::Exact, RoundingMode::Exact),
+    );
+    assert_eq!(p.to_string(), "(1.0 1.0)");
+    assert_eq!(o, (Ordering::Equal, Ordering::Equal));
+    // (-4)^(3/4) = -2 + 2i
+    let (p, o) = complex(-4.0, 0.0).pow_prec_round(
+        &complex(0.75, 0.0),
+        (20, 20),
+        (RoundingMode::Floor, RoundingMode::Ceiling),
+    );
+    assert_eq!(p.to_string(), "(-2.0 2.0)");
+    assert_eq!(o, (Ordering::Equal, Ordering::Equal));
+    // (-1)^(1/2 + i) = e^(-pi)i, but the real part can't be verified to be 0
+    let (p, o) = complex(-1.0, 0.0).pow_prec(&complex(0.5, 1.0), 20);
+    assert_eq!(p.to_string(), "(NaN 0.0432139)");
+    assert_eq!(o.0, Ordering::Equal);
+}
+
+#[test]
+fn test_non_finite() {
+    let c = Complex::new;
+    let test_binary = |x: Complex, y: Complex, op: &str, out: &str| {
+        let (result, o) = match op {
+            "+" => x.add_prec(&y, 20),
+            "-" => x.sub_prec(&y, 20),
+            "*" => x.mul_prec(&y, 20),
+            "/" => x.div_prec(&y, 20),
+            _ => x.pow_prec(&y, 20),
+        };
+        assert_eq!(result.to_string(), out);
+        assert_eq!(o, (Ordering::Equal, Ordering::Equal));
+    };
+    let inf = || c(Float::INFINITY, Float::ZERO);
+    test_binary(inf(), Complex::ONE, "+", "(Infinity 0.0)");
+    test_binary(inf(), -inf(), "+", "(NaN 0.0)");
+    test_binary(
+        c(Float::ONE, Float::INFINITY),
+        c(Float::NAN, Float::ONE),
+        "+",
+        "(NaN Infinity)",
+    );
+    test_binary(inf(), Complex::ONE, "-", "(Infinity 0.0)");
+    test_binary(complex(1.0, 2.0), inf(), "-", "(-Infinity 2.0)");
+    test_binary(inf(), inf(), "-", "(NaN 0.0)");
+
+    test_binary(inf(), complex(1.0, 1.0), "*", "(Infinity Infinity)");
+    test_binary(inf(), Complex::ONE, "*", "(Infinity NaN)");
+    test_binary(
+        Complex::I,
+        c(Float::INFINITY, Float::INFINITY),
+        "*",
+        "(-Infinity Infinity)",
+    );
+    test_binary(inf(), Complex::ZERO, "*", "(NaN NaN)");
+    test_binary(Complex::NAN, inf(), "*", "(NaN NaN)");
+
+    test_binary(Complex::ONE, inf(), "/", "(0.0 0.0)");
+    test_binary(complex(1.0, 1.0), Complex::ZERO, "/", "(Infinity Infinity)");
+    test_binary(complex(-1.0, 0.0), Complex::ZERO, "/", "(-Infinity NaN)");
+    test_binary(Complex::ZERO, Complex::ZERO, "/", "(NaN NaN)");
+    test_binary(inf(), complex(1.0, 1.0), "/", "(Infinity -Infinity)");
+    test_binary(Complex::NAN, inf(), "/", "(NaN NaN)");
+
+    test_binary(complex(2.0, 0.0), -inf(), "^", "(0.0 0.0)");
+    test_binary(inf(), complex(2.0, 0.0), "^", "(Infinity NaN)");
+    test_binary(Complex::NAN, Complex::ZERO, "^", "(1.0 0.0)");
+    test_binary(Complex::ZERO, inf(), "^", "(0.0 0.0)");
+    test_binary(Complex::ZERO, -inf(), "^", "(NaN NaN)");
+    test_binary(
+        complex(1.0, 2.0),
+        c(Float::NAN, Float::ONE),
+        "^",
+        "(NaN NaN)",
+    );
+    assert_eq!(
+        (&inf() * &complex(1.0, 2.0)).to_string(),
+        "(Infinity Infinity)"
+    );
+    assert_eq!((&Complex::ONE / &inf()).to_string(), "(0.0 0.0)");
+
+    let test_unary = |x: Complex, exp: &str, log: &str| {
+        let (result, o) = x.exp_prec(20);
+        assert_eq!(result.to_string(), exp);
+        assert_eq!(o, (Ordering::Equal, Ordering::Equal));
+        assert_eq!(x.log_prec(20).0.to_string(), log);
+    };
+    test_unary(
+        c(Float::NEGATIVE_INFINITY, Float::ONE),
+        "(0.0 0.0)",
+        "(Infinity 3.141594)",
+    );
+    test_unary(
+        c(Float::NEGATIVE_INFINITY, Float::NEGATIVE_ONE),
+        "(0.0 0.0)",
+        "(Infinity -3.141594)",
+    );
+    test_unary(inf(), "(Infinity 0.0)", "(Infinity 0.0)");
+    test_unary(
+        c(Float::INFINITY, Float::from(2)),
+        "(-Infinity Infinity)",
+        "(Infinity 0.0)",
+    );
+    test_unary(
+        c(Float::INFINITY, Float::INFINITY),
+        "(Infinity NaN)",
+        "(Infinity 0.7853985)",
+    );
+    test_unary(
+        c(Float::ONE, Float::NEGATIVE_INFINITY),
+        "(NaN NaN)",
+        "(Infinity -1.570797)",
+    );
+    test_unary(c(Float::NAN, Float::ZERO), "(NaN 0.0)", "(NaN NaN)");
+    test_unary(
+        c(Float::INFINITY, Float::NAN),
+        "(Infinity NaN)",
+        "(Infinity NaN)",
+    );
+    test_unary(c(Float::NAN, Float::ONE), "(NaN NaN)", "(NaN NaN)");
+
+    // Functions that don't follow C99 Annex G
+    for x in [inf(), c(Float::ONE, Float::NEGATIVE_INFINITY), c(Float::NAN, Float::ONE)] {
+        for (result, o) in [x.sqrt_prec(20), x.sin_prec(20), x.cos_prec(20), x.tan_prec(20)] {
+            assert_eq!(result.to_string(), "(NaN NaN)");
+            assert_eq!(o, (Ordering::Equal, Ordering::Equal));
+        }
+        assert!(x.arg_prec(20).0.is_nan());
+    }
+    assert_eq!(inf().abs_prec(20).0.to_string(), "Infinity");
+    assert_eq!(
+        c(Float::NAN, Float::INFINITY).abs_prec(20).0.to_string(),
+        "Infinity"
+    );
+    assert_eq!(c(Float::NAN, Float::ONE).abs_prec(20).0.to_string(), "NaN");
+}
+
+#[test]
+fn add_mul_properties() {
+    float_pair_gen().test_properties(|(a, b)| {
+        let x = Complex::new(a.clone(), b.clone());
+        let y = Complex::new(b.clone(), a.clone());
+        let sum = x.add_prec(&y, 64).0;
+        let product = x.mul_prec(&y, 64).0;
+        assert_eq!(sum.to_string(), y.add_prec(&x, 64).0.to_string());
+        assert_eq!(product.to_string(), y.mul_prec(&x, 64).0.to_string());
+        if a.is_finite() && b.is_finite() {
+            let p = ComplexRational::new(Rational::exact_from(&a), Rational::exact_from(&b));
+            let q = ComplexRational::new(Rational::exact_from(&b), Rational::exact_from(&a));
+            let (expected, _) = Complex::from_complex_rational_prec_round(
+                p * q,
+                (64, 64),
+                (RoundingMode::Nearest, RoundingMode::Nearest),
+            );
+            assert_eq!(product.to_string(), expected.to_string());
+        } else {
+            assert!(!sum.is_finite());
+        }
+    });
+}
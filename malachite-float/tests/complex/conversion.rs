@@ -0,0 +1,89 @@
+use malachite_base::num::basic::traits::{NaN, One, Zero};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::complex::Complex;
+use malachite_float::Float;
+use malachite_q::complex::ComplexRational;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+#[test]
+fn test_from_float() {
+    assert_eq!(Complex::from(Float::from(1.5)).to_string(), "(1.5 0.0)");
+    assert_eq!(Complex::from(&Float::NAN).to_string(), "(NaN 0.0)");
+    assert_eq!(Complex::ZERO.to_string(), "(0.0 0.0)");
+    assert_eq!(Complex::ONE.to_string(), "(1.0 0.0)");
+    assert_eq!(Complex::I.to_string(), "(0.0 1.0)");
+    assert_eq!(format!("{:?}", Complex::NAN), "(NaN NaN)");
+}
+
+#[test]
+fn test_from_complex_rational_prec_round() {
+    let test = |s: &str, prec: (u64, u64), rm: (RoundingMode, RoundingMode), out: &str, o| {
+        let z = ComplexRational::from_str(s).unwrap();
+        let (x, o_alt) = Complex::from_complex_rational_prec_round_ref(&z, prec, rm);
+        assert_eq!(x.to_string(), out);
+        assert_eq!(o_alt, o);
+        let (x_alt, o_alt) = Complex::from_complex_rational_prec_round(z, prec, rm);
+        assert_eq!(x_alt, x);
+        assert_eq!(o_alt, o);
+    };
+    test(
+        "(1/3 -1/3)",
+        (10, 10),
+        (RoundingMode::Floor, RoundingMode::Floor),
+        "(0.333 -0.3335)",
+        (Ordering::Less, Ordering::Less),
+    );
+    test(
+        "(1/2 3)",
+        (1, 2),
+        (RoundingMode::Exact, RoundingMode::Exact),
+        "(0.5 3.0)",
+        (Ordering::Equal, Ordering::Equal),
+    );
+    test(
+        "0",
+        (1, 1),
+        (RoundingMode::Exact, RoundingMode::Exact),
+        "(0.0 0.0)",
+        (Ordering::Equal, Ordering::Equal),
+    );
+}
+
+#[test]
+#[should_panic]
+fn from_complex_rational_prec_round_fail() {
+    Complex::from_complex_rational_prec_round(
+        ComplexRational::from(Rational::from_signeds(1, 3)),
+        (10, 10),
+        (RoundingMode::Exact, RoundingMode::Exact),
+    );
+}
+
+#[test]
+fn test_from_sci_string_prec_round() {
+    let test = |s: &str, out: Option<&str>| {
+        assert_eq!(
+            Complex::from_sci_string_prec_round(
+                s,
+                (20, 20),
+                (RoundingMode::Nearest, RoundingMode::Nearest)
+            )
+            .map(|(z, _)| z.to_string())
+            .as_deref(),
+            out
+        );
+    };
+    test("(1 2)", Some("(1.0 2.0)"));
+    test("(-1.5e2 0.25)", Some("(-150.0 0.25)"));
+    test("3", Some("(3.0 0.0)"));
+    test("(Infinity -Infinity)", Some("(Infinity -Infinity)"));
+    test("(NaN -0.0)", Some("(NaN -0.0)"));
+    test("(1 2", None);
+    test("(1,2)", None);
+    test("(1 2 3)", None);
+    test("(x 2)", None);
+    test("", None);
+}
+
@@ -0,0 +1,196 @@
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::to_hex_string;
+use malachite_float::test_util::generators::rational_unsigned_rounding_mode_triple_gen_var_1;
+use malachite_float::{ComparableFloatRef, Float};
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+#[test]
+fn test_from_quotient_prec_round() {
+    let test = |s, t, prec, rm, out, out_hex, out_o| {
+        let x = Integer::from_str(s).unwrap();
+        let y = Integer::from_str(t).unwrap();
+        let (f, o) = Float::from_quotient_prec_round(&x, &y, prec, rm);
+        assert!(f.is_valid());
+        assert_eq!(f.to_string(), out);
+        assert_eq!(to_hex_string(&f), out_hex);
+        assert_eq!(o, out_o);
+    };
+    test(
+        "0",
+        "5",
+        1,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-5",
+        10,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "6",
+        "4",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "6",
+        "4",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "6",
+        "4",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "6",
+        "4",
+        2,
+        RoundingMode::Exact,
+        "1.5",
+        "0x1.8#2",
+        Ordering::Equal,
+    );
+    test(
+        "-6",
+        "4",
+        2,
+        RoundingMode::Exact,
+        "-1.5",
+        "-0x1.8#2",
+        Ordering::Equal,
+    );
+    test(
+        "1",
+        "3",
+        10,
+        RoundingMode::Floor,
+        "0.333",
+        "0x0.554#10",
+        Ordering::Less,
+    );
+    test(
+        "1",
+        "3",
+        10,
+        RoundingMode::Ceiling,
+        "0.3335",
+        "0x0.556#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1",
+        "3",
+        10,
+        RoundingMode::Floor,
+        "-0.3335",
+        "-0x0.556#10",
+        Ordering::Less,
+    );
+    test(
+        "-1",
+        "-3",
+        10,
+        RoundingMode::Down,
+        "0.333",
+        "0x0.554#10",
+        Ordering::Less,
+    );
+    test(
+        "2",
+        "-6",
+        10,
+        RoundingMode::Up,
+        "-0.3335",
+        "-0x0.556#10",
+        Ordering::Less,
+    );
+    test(
+        "22",
+        "7",
+        100,
+        RoundingMode::Nearest,
+        "3.142857142857142857142857142858",
+        "0x3.2492492492492492492492494#100",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn from_quotient_prec_round_fail_1() {
+    Float::from_quotient_prec_round(&Integer::ONE, &Integer::ONE, 0, RoundingMode::Floor);
+}
+
+#[test]
+#[should_panic]
+fn from_quotient_prec_round_fail_2() {
+    Float::from_quotient_prec_round(&Integer::ONE, &Integer::ZERO, 10, RoundingMode::Floor);
+}
+
+#[test]
+#[should_panic]
+fn from_quotient_prec_round_fail_3() {
+    Float::from_quotient_prec_round(&Integer::ONE, &Integer::from(3), 10, RoundingMode::Exact);
+}
+
+#[test]
+fn test_from_quotient_prec() {
+    let x = Integer::from(10).pow(100) + Integer::ONE;
+    let y = Integer::from(-7).pow(101);
+    let (f, o) = Float::from_quotient_prec(&x, &y, 200);
+    let (f_alt, o_alt) = Float::from_rational_prec(Rational::from_integers(x, y), 200);
+    assert_eq!(ComparableFloatRef(&f), ComparableFloatRef(&f_alt));
+    assert_eq!(o, o_alt);
+}
+
+#[test]
+fn from_quotient_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_1().test_properties(|(x, prec, rm)| {
+        let (n, d) = x.to_numerator_and_denominator();
+        let n = if x >= 0 { Integer::from(n) } else { -n };
+        let d = Integer::from(d);
+        let (f, o) = Float::from_rational_prec_round_ref(&x, prec, rm);
+
+        let (f_alt, o_alt) = Float::from_quotient_prec_round(&n, &d, prec, rm);
+        assert!(f_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&f_alt), ComparableFloatRef(&f));
+        assert_eq!(o_alt, o);
+
+        // The quotient doesn't need to be in lowest terms
+        let k = Integer::from(-3);
+        let (f_alt, o_alt) = Float::from_quotient_prec_round(&(&n * &k), &(&d * &k), prec, rm);
+        assert!(f_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&f_alt), ComparableFloatRef(&f));
+        assert_eq!(o_alt, o);
+
+        if rm == RoundingMode::Nearest {
+            let (f_alt, o_alt) = Float::from_quotient_prec(&n, &d, prec);
+            assert_eq!(ComparableFloatRef(&f_alt), ComparableFloatRef(&f));
+            assert_eq!(o_alt, o);
+        }
+    });
+}
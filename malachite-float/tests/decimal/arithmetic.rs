@@ -0,0 +1,382 @@
+use malachite_base::num::arithmetic::traits::{Abs, AbsAssign, NegAssign, Pow, Square};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::decimal::Decimal;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::{
+    integer_integer_signed_triple_gen, integer_signed_pair_gen,
+};
+use malachite_q::Rational;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+fn dec(s: &str) -> Decimal {
+    Decimal::from_str(s).unwrap()
+}
+
+fn ulp(exponent: i64) -> Rational {
+    Rational::from(10u32).pow(exponent)
+}
+
+#[test]
+fn test_add_sub_mul_neg_abs() {
+    let test = |x: &str, y: &str, sum: &str, difference: &str, product: &str| {
+        let x = dec(x);
+        let y = dec(y);
+
+        let s = &x + &y;
+        assert_eq!(s.to_string(), sum);
+        assert_eq!((x.clone() + y.clone()).to_string(), sum);
+        assert_eq!((&x + y.clone()).to_string(), sum);
+        assert_eq!((x.clone() + &y).to_string(), sum);
+        let mut s_alt = x.clone();
+        s_alt += &y;
+        assert_eq!(s_alt.to_string(), sum);
+        let mut s_alt = x.clone();
+        s_alt += y.clone();
+        assert_eq!(s_alt.to_string(), sum);
+
+        let d = &x - &y;
+        assert_eq!(d.to_string(), difference);
+        assert_eq!((x.clone() - y.clone()).to_string(), difference);
+        let mut d_alt = x.clone();
+        d_alt -= &y;
+        assert_eq!(d_alt.to_string(), difference);
+
+        let p = &x * &y;
+        assert_eq!(p.to_string(), product);
+        assert_eq!((x.clone() * y.clone()).to_string(), product);
+        let mut p_alt = x.clone();
+        p_alt *= y.clone();
+        assert_eq!(p_alt.to_string(), product);
+    };
+    test("1.25", "3.1416", "4.3916", "-1.8916", "3.927000");
+    test("0.10", "0.2", "0.30", "-0.10", "0.020");
+    test("1.2e3", "5", "1205", "1195", "6.0e3");
+    test("-1.50", "1.5", "0.00", "-3.00", "-2.250");
+    test("0", "0.000", "0.000", "0.000", "0.000");
+
+    let x = dec("-1.50");
+    assert_eq!((-&x).to_string(), "1.50");
+    assert_eq!((&x).abs().to_string(), "1.50");
+    let mut y = x.clone();
+    y.neg_assign();
+    assert_eq!(y.to_string(), "1.50");
+    let mut y = x;
+    y.abs_assign();
+    assert_eq!(y.to_string(), "1.50");
+}
+
+#[test]
+fn test_prec_and_scale_arithmetic() {
+    let x = dec("1.25");
+    let y = dec("3.1416");
+    assert_eq!(
+        x.add_prec_round(&y, 3, RoundingMode::Floor),
+        (dec("4.39"), Ordering::Less)
+    );
+    assert_eq!(x.add_prec(&y, 3).0.to_string(), "4.39");
+    assert_eq!(
+        x.sub_scale_round(&y, 2, RoundingMode::Up),
+        (dec("-1.90"), Ordering::Less)
+    );
+    let (z, o) = x.mul_prec_round(&y, 2, RoundingMode::Nearest);
+    assert_eq!(z.to_string(), "3.9");
+    assert_eq!(o, Ordering::Less);
+    let (z, o) = x.mul_scale_round(&y, 0, RoundingMode::Ceiling);
+    assert_eq!(z.to_string(), "4");
+    assert_eq!(o, Ordering::Greater);
+}
+
+#[test]
+fn test_div() {
+    let test = |x: &str, y: &str, prec: u64, rm: RoundingMode, out: &str, o: Ordering| {
+        let (z, o_alt) = dec(x).div_prec_round(&dec(y), prec, rm);
+        assert_eq!(z.to_string(), out);
+        assert_eq!(o_alt, o);
+    };
+    test("1", "3", 5, RoundingMode::Floor, "0.33333", Ordering::Less);
+    test(
+        "-1",
+        "3",
+        5,
+        RoundingMode::Floor,
+        "-0.33334",
+        Ordering::Less,
+    );
+    test("2", "3", 1, RoundingMode::Nearest, "0.7", Ordering::Greater);
+    test("1", "0.8", 5, RoundingMode::Exact, "1.25", Ordering::Equal);
+    test(
+        "1.00",
+        "4",
+        10,
+        RoundingMode::Exact,
+        "0.25",
+        Ordering::Equal,
+    );
+    test("100", "4", 10, RoundingMode::Exact, "25", Ordering::Equal);
+    test(
+        "1.2e3",
+        "3",
+        10,
+        RoundingMode::Exact,
+        "4e2",
+        Ordering::Equal,
+    );
+    test("0.00", "7", 3, RoundingMode::Exact, "0.00", Ordering::Equal);
+    test("1", "4", 1, RoundingMode::Nearest, "0.2", Ordering::Less);
+    test(
+        "1",
+        "4",
+        1,
+        RoundingMode::NearestTiesAway,
+        "0.3",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn div_prec_round_fail() {
+    dec("1").div_prec_round(&dec("0.00"), 5, RoundingMode::Floor);
+}
+
+#[test]
+fn test_sqrt() {
+    let test = |x: &str, prec: u64, rm: RoundingMode, out: &str, o: Ordering| {
+        let (z, o_alt) = dec(x).sqrt_prec_round(prec, rm);
+        assert_eq!(z.to_string(), out);
+        assert_eq!(o_alt, o);
+    };
+    test("2", 10, RoundingMode::Floor, "1.414213562", Ordering::Less);
+    test(
+        "2",
+        10,
+        RoundingMode::Ceiling,
+        "1.414213563",
+        Ordering::Greater,
+    );
+    test("0.0004", 5, RoundingMode::Exact, "0.02", Ordering::Equal);
+    test("100", 5, RoundingMode::Exact, "10", Ordering::Equal);
+    test("0.00", 3, RoundingMode::Exact, "0.0", Ordering::Equal);
+    test("99.9", 2, RoundingMode::Nearest, "10", Ordering::Greater);
+    test("1e-9", 3, RoundingMode::Down, "0.0000316", Ordering::Less);
+}
+
+#[test]
+#[should_panic]
+fn sqrt_prec_round_fail() {
+    dec("-1").sqrt_prec_round(5, RoundingMode::Floor);
+}
+
+#[test]
+fn test_rescale_quantize_round_to_precision() {
+    let x = dec("-2.345");
+    assert_eq!(
+        x.rescale(-2, RoundingMode::Floor),
+        (dec("-2.35"), Ordering::Less)
+    );
+    assert_eq!(
+        x.rescale(-2, RoundingMode::Nearest),
+        (dec("-2.34"), Ordering::Greater)
+    );
+    assert_eq!(
+        x.rescale(-2, RoundingMode::NearestTiesTowardZero),
+        (dec("-2.34"), Ordering::Greater)
+    );
+    assert_eq!(x.rescale(5, RoundingMode::Floor).0.to_string(), "-1e5");
+    assert_eq!(x.rescale(5, RoundingMode::Ceiling).0.to_string(), "0e5");
+    assert_eq!(
+        x.quantize(&dec("1e1"), RoundingMode::Odd),
+        (dec("-1e1"), Ordering::Less)
+    );
+    assert_eq!(
+        x.round_to_precision(1, RoundingMode::Up),
+        (dec("-3"), Ordering::Less)
+    );
+}
+
+#[test]
+#[should_panic]
+fn rescale_fail() {
+    dec("2.345").rescale(-2, RoundingMode::Exact);
+}
+
+#[test]
+fn test_extreme_exponents() {
+    let x = Decimal::new(Integer::from(2), i64::MAX);
+    let y = Decimal::new(Integer::from(-3), i64::MIN);
+    let z = &x * &y;
+    assert_eq!(*z.coefficient(), -6);
+    assert_eq!(z.exponent(), -1);
+
+    let (q, o) = Decimal::new(Integer::ZERO, -1).div_prec_round(
+        &Decimal::new(Integer::ONE, i64::MIN),
+        5,
+        RoundingMode::Exact,
+    );
+    assert_eq!(q.exponent(), i64::MAX);
+    assert!(q.is_zero());
+    assert_eq!(o, Ordering::Equal);
+
+    let (r, o) =
+        Decimal::new(Integer::from(123), i64::MAX - 2).round_to_precision(1, RoundingMode::Down);
+    assert_eq!(*r.coefficient(), 1);
+    assert_eq!(r.exponent(), i64::MAX);
+    assert_eq!(o, Ordering::Less);
+
+    assert_eq!(x.scale(), -i64::MAX);
+    assert_eq!(Decimal::new(Integer::ONE, i64::MIN + 1).scale(), i64::MAX);
+}
+
+#[test]
+#[should_panic]
+fn mul_exponent_overflow_fail() {
+    let _ = Decimal::new(Integer::from(2), i64::MAX) * Decimal::new(Integer::from(3), 1);
+}
+
+#[test]
+#[should_panic]
+fn div_prec_round_exponent_overflow_fail() {
+    Decimal::new(Integer::ZERO, 0).div_prec_round(
+        &Decimal::new(Integer::ONE, i64::MIN),
+        5,
+        RoundingMode::Exact,
+    );
+}
+
+#[test]
+#[should_panic]
+fn round_to_precision_exponent_overflow_fail() {
+    Decimal::new(Integer::from(123), i64::MAX - 1).round_to_precision(1, RoundingMode::Down);
+}
+
+#[test]
+#[should_panic]
+fn scale_fail() {
+    Decimal::new(Integer::ONE, i64::MIN).scale();
+}
+
+#[test]
+#[should_panic]
+fn div_scale_round_fail() {
+    dec("1").div_scale_round(&dec("3"), i64::MIN, RoundingMode::Floor);
+}
+
+#[test]
+fn add_sub_mul_properties() {
+    integer_integer_signed_triple_gen::<i8>().test_properties(|(a, b, e)| {
+        let x = Decimal::new(a, i64::from(e));
+        let y = Decimal::new(b, i64::from(e) / 3);
+        let qx = Rational::from(&x);
+        let qy = Rational::from(&y);
+
+        let s = &x + &y;
+        assert_eq!(Rational::from(&s), &qx + &qy);
+        assert_eq!(s.exponent(), x.exponent().min(y.exponent()));
+        assert_eq!(&y + &x, s);
+
+        let d = &x - &y;
+        assert_eq!(Rational::from(&d), &qx - &qy);
+        assert_eq!(d, -(&y - &x));
+
+        let p = &x * &y;
+        assert_eq!(Rational::from(&p), &qx * &qy);
+        assert_eq!(p.exponent(), x.exponent() + y.exponent());
+    });
+}
+
+#[test]
+fn div_properties() {
+    integer_integer_signed_triple_gen::<i8>().test_properties(|(a, b, e)| {
+        if b == 0u32 {
+            return;
+        }
+        let x = Decimal::new(a, i64::from(e));
+        let y = Decimal::new(b, i64::from(e) / 3);
+        let q = Rational::from(&x) / Rational::from(&y);
+        let prec = 1 + u64::from(e.unsigned_abs() % 20);
+        for rm in exhaustive_rounding_modes() {
+            if rm == RoundingMode::Exact {
+                continue;
+            }
+            let (z, o) = x.div_prec_round(&y, prec, rm);
+            assert!(z.precision() <= prec);
+            let qz = Rational::from(&z);
+            assert_eq!(qz.cmp(&q), o);
+            assert_eq!(z, Decimal::from_rational_prec_round(q.clone(), prec, rm).0);
+            if o == Ordering::Equal {
+                assert!(z.exponent() <= x.exponent() - y.exponent() || z.precision() == prec);
+                assert_eq!(x.div_prec_round(&y, prec, RoundingMode::Exact), (z, o));
+            }
+
+            let (z, o) = x.div_scale_round(&y, i64::from(e), rm);
+            assert_eq!(z.exponent(), -i64::from(e));
+            assert_eq!(Rational::from(&z).cmp(&q), o);
+            assert!((Rational::from(&z) - &q).abs() < ulp(-i64::from(e)));
+        }
+    });
+}
+
+#[test]
+fn sqrt_properties() {
+    integer_signed_pair_gen::<i8>().test_properties(|(c, e)| {
+        let x = Decimal::new(c.abs(), i64::from(e));
+        let qx = Rational::from(&x);
+        let prec = 1 + u64::from(e.unsigned_abs() % 20);
+        for rm in exhaustive_rounding_modes() {
+            if rm == RoundingMode::Exact {
+                continue;
+            }
+            let (z, o) = x.sqrt_prec_round(prec, rm);
+            assert!(z.precision() <= prec);
+            let qz = Rational::from(&z);
+            assert_eq!((&qz).square().cmp(&qx), o);
+            if x.is_zero() {
+                assert_eq!(o, Ordering::Equal);
+                continue;
+            }
+            let lo = if o == Ordering::Greater {
+                &qz - ulp(z.exponent())
+            } else {
+                qz.clone()
+            };
+            let hi = if o == Ordering::Less {
+                &qz + ulp(z.exponent())
+            } else {
+                qz.clone()
+            };
+            assert!((&lo).square() <= qx);
+            assert!((&hi).square() >= qx);
+            if o == Ordering::Equal {
+                assert_eq!(x.sqrt_prec_round(prec, RoundingMode::Exact), (z, o));
+            }
+        }
+    });
+}
+
+#[test]
+fn rescale_properties() {
+    integer_integer_signed_triple_gen::<i8>().test_properties(|(c, _, e)| {
+        let x = Decimal::new(c, 0);
+        let qx = Rational::from(&x);
+        let exponent = i64::from(e);
+        for rm in exhaustive_rounding_modes() {
+            if rm == RoundingMode::Exact {
+                continue;
+            }
+            let (z, o) = x.rescale(exponent, rm);
+            assert_eq!(z.exponent(), exponent);
+            assert_eq!(Rational::from(&z).cmp(&qx), o);
+            assert!((Rational::from(&z) - &qx).abs() < ulp(exponent));
+            if exponent <= 0 {
+                assert_eq!(o, Ordering::Equal);
+            }
+            assert_eq!(
+                x.quantize(&Decimal::new(Integer::ZERO, exponent), rm),
+                (z, o)
+            );
+        }
+    });
+}
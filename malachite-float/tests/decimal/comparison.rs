@@ -0,0 +1,69 @@
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::test_util::hash::hash;
+use malachite_float::decimal::Decimal;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_integer_signed_triple_gen;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+fn dec(s: &str) -> Decimal {
+    Decimal::from_str(s).unwrap()
+}
+
+#[test]
+fn test_eq_cmp_hash() {
+    let test = |x: &str, y: &str, out: Ordering| {
+        let x = dec(x);
+        let y = dec(y);
+        assert_eq!(x.cmp(&y), out);
+        assert_eq!(y.cmp(&x), out.reverse());
+        assert_eq!(x.partial_cmp(&y), Some(out));
+        assert_eq!(x == y, out == Ordering::Equal);
+        if out == Ordering::Equal {
+            assert_eq!(hash(&x), hash(&y));
+        }
+    };
+    test("0", "0", Ordering::Equal);
+    test("0", "0.000", Ordering::Equal);
+    test("0e10", "-0.0", Ordering::Equal);
+    test("1.5", "1.50", Ordering::Equal);
+    test("1.5", "0.15e1", Ordering::Equal);
+    test("1200", "1.2e3", Ordering::Equal);
+    test("1.5", "1.51", Ordering::Less);
+    test("-1.5", "-1.51", Ordering::Greater);
+    test("-1", "0.001", Ordering::Less);
+    test("1e100", "99999", Ordering::Greater);
+    test("-1e-100", "-0.1", Ordering::Greater);
+    test("0", "1e-100", Ordering::Less);
+}
+
+#[test]
+fn test_normalize() {
+    let test = |s: &str, coefficient: i64, exponent: i64| {
+        let x = dec(s).normalize();
+        assert_eq!(*x.coefficient(), coefficient);
+        assert_eq!(x.exponent(), exponent);
+    };
+    test("0.000", 0, 0);
+    test("1.500", 15, -1);
+    test("-1200", -12, 2);
+    test("7", 7, 0);
+}
+
+#[test]
+fn cmp_properties() {
+    integer_integer_signed_triple_gen::<i8>().test_properties(|(a, b, e)| {
+        let x = Decimal::new(a, i64::from(e));
+        let y = Decimal::new(b, i64::from(e) / 3);
+        let ord = x.cmp(&y);
+        assert_eq!(Rational::from(&x).cmp(&Rational::from(&y)), ord);
+        assert_eq!(y.cmp(&x), ord.reverse());
+        assert_eq!(x, x.normalize());
+        assert_eq!(hash(&x), hash(&x.normalize()));
+        let z = Decimal::new(x.coefficient() * Integer::from(100), x.exponent() - 2);
+        assert_eq!(x, z);
+        assert_eq!(hash(&x), hash(&z));
+        assert_eq!(x.is_zero(), x == Decimal::ZERO);
+    });
+}
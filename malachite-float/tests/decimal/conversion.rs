@@ -0,0 +1,295 @@
+use malachite_base::num::arithmetic::traits::{Abs, Pow};
+use malachite_base::num::basic::traits::{NegativeOne, One, Two, Zero};
+use malachite_base::num::comparison::traits::PartialOrdAbs;
+use malachite_base::num::conversion::string::options::{FromSciStringOptions, ToSciOptions};
+use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
+use malachite_base::num::conversion::traits::{FromSciString, ToSci};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::decimal::conversion::DecimalFromRationalError;
+use malachite_float::decimal::Decimal;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_signed_pair_gen;
+use malachite_q::test_util::generators::{
+    rational_signed_rounding_mode_triple_gen_var_1, rational_unsigned_pair_gen_var_3,
+};
+use malachite_q::Rational;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+fn ulp(exponent: i64) -> Rational {
+    Rational::from(10u32).pow(exponent)
+}
+
+#[test]
+fn test_from_str_and_display() {
+    let test = |s: &str, coefficient: i64, exponent: i64, out: &str| {
+        let x = Decimal::from_str(s).unwrap();
+        assert_eq!(*x.coefficient(), coefficient);
+        assert_eq!(x.exponent(), exponent);
+        assert_eq!(x.to_string(), out);
+        assert_eq!(format!("{x:?}"), out);
+        let y = Decimal::from_str(out).unwrap();
+        assert_eq!(*y.coefficient(), coefficient);
+        assert_eq!(y.exponent(), exponent);
+    };
+    test("0", 0, 0, "0");
+    test("0.000", 0, -3, "0.000");
+    test("0e5", 0, 5, "0e5");
+    test("1", 1, 0, "1");
+    test("-1.50", -150, -2, "-1.50");
+    test("+12.5", 125, -1, "12.5");
+    test("1.2e3", 12, 2, "1.2e3");
+    test("1200", 1200, 0, "1200");
+    test("0.001", 1, -3, "0.001");
+    test("1.0e-10", 10, -11, "1.0e-10");
+}
+
+#[test]
+fn test_from_str_fail() {
+    for s in ["", "-", ".", "1.2.3", "1e", "1/2", "abc", "NaN", "1 2"] {
+        assert!(Decimal::from_str(s).is_err(), "{s}");
+    }
+    let test = |s, kind| {
+        assert_eq!(Decimal::from_str(s).unwrap_err().kind(), kind);
+    };
+    test("", ParseNumberErrorKind::Empty);
+    test("1e", ParseNumberErrorKind::Empty);
+    test("1.2.3", ParseNumberErrorKind::InvalidDigit(3));
+    test("1/2", ParseNumberErrorKind::InvalidDigit(1));
+    test("1e100000000000000000000", ParseNumberErrorKind::Overflow);
+}
+
+#[test]
+fn test_from_sci_string_with_options() {
+    let mut options = FromSciStringOptions::default();
+    options.set_base(16);
+    assert_eq!(
+        Decimal::from_sci_string_with_options("0.8", options)
+            .unwrap()
+            .to_string(),
+        "0.5"
+    );
+    assert_eq!(
+        Decimal::from_sci_string_with_options("ff", options)
+            .unwrap()
+            .to_string(),
+        "255"
+    );
+    assert_eq!(
+        Decimal::from_sci_string_with_options("0.1", options)
+            .unwrap()
+            .to_string(),
+        "0.0625"
+    );
+    options.set_base(3);
+    assert_eq!(
+        Decimal::from_sci_string_with_options("0.1", options)
+            .unwrap_err()
+            .kind(),
+        ParseNumberErrorKind::Inexact
+    );
+    assert_eq!(
+        Decimal::from_sci_string_with_options("0.3", options)
+            .unwrap_err()
+            .kind(),
+        ParseNumberErrorKind::InvalidDigit(2)
+    );
+}
+
+#[test]
+fn test_to_sci() {
+    let x = Decimal::from_str("123.456").unwrap();
+    assert_eq!(x.to_sci().to_string(), "123.456");
+    let mut options = ToSciOptions::default();
+    options.set_scale(1);
+    assert_eq!(x.to_sci_with_options(options).to_string(), "123.5");
+    options.set_rounding_mode(RoundingMode::Floor);
+    assert_eq!(x.to_sci_with_options(options).to_string(), "123.4");
+}
+
+#[test]
+fn test_constants_and_from() {
+    assert_eq!(Decimal::ZERO.to_string(), "0");
+    assert_eq!(Decimal::ONE.to_string(), "1");
+    assert_eq!(Decimal::TWO.to_string(), "2");
+    assert_eq!(Decimal::NEGATIVE_ONE.to_string(), "-1");
+    assert_eq!(Decimal::from(-5i8).to_string(), "-5");
+    assert_eq!(Decimal::from(u64::MAX).to_string(), "18446744073709551615");
+    assert_eq!(Decimal::from(Natural::from(10u32)).to_string(), "10");
+    assert_eq!(Decimal::from(&Integer::from(-10)).to_string(), "-10");
+}
+
+#[test]
+fn test_rational_conversions() {
+    let test = |x: Rational, out: Option<&str>| {
+        let d = Decimal::try_from(&x);
+        assert_eq!(
+            d.as_ref().map(ToString::to_string).ok(),
+            out.map(ToString::to_string)
+        );
+        if let Ok(d) = d {
+            assert_eq!(Decimal::try_from(x.clone()), Ok(d.clone()));
+            assert_eq!(Rational::from(&d), x);
+        } else {
+            assert_eq!(d, Err(DecimalFromRationalError));
+        }
+    };
+    test(Rational::ZERO, Some("0"));
+    test(Rational::from(100), Some("100"));
+    test(Rational::from_signeds(-1, 2), Some("-0.5"));
+    test(Rational::from_signeds(1, 80), Some("0.0125"));
+    test(Rational::from_signeds(7, 625), Some("0.0112"));
+    test(Rational::from_signeds(1, 3), None);
+    test(Rational::from_signeds(1, 30), None);
+}
+
+#[test]
+fn test_from_rational_prec_round() {
+    let test = |x: Rational, prec: u64, rm: RoundingMode, out: &str, o: Ordering| {
+        let (d, o_alt) = Decimal::from_rational_prec_round(x, prec, rm);
+        assert_eq!(d.to_string(), out);
+        assert_eq!(o_alt, o);
+    };
+    let third = Rational::from_signeds(1, 3);
+    test(third.clone(), 1, RoundingMode::Floor, "0.3", Ordering::Less);
+    test(
+        third.clone(),
+        3,
+        RoundingMode::Up,
+        "0.334",
+        Ordering::Greater,
+    );
+    test(-&third, 3, RoundingMode::Floor, "-0.334", Ordering::Less);
+    test(
+        -third,
+        3,
+        RoundingMode::Ceiling,
+        "-0.333",
+        Ordering::Greater,
+    );
+    test(
+        Rational::from_signeds(999, 100),
+        2,
+        RoundingMode::Nearest,
+        "10",
+        Ordering::Greater,
+    );
+    test(
+        Rational::from_signeds(25, 100),
+        1,
+        RoundingMode::Nearest,
+        "0.2",
+        Ordering::Less,
+    );
+    test(
+        Rational::from_signeds(25, 100),
+        1,
+        RoundingMode::NearestTiesAway,
+        "0.3",
+        Ordering::Greater,
+    );
+    test(
+        Rational::from_signeds(35, 100),
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        "0.3",
+        Ordering::Less,
+    );
+    test(
+        Rational::from_signeds(25, 100),
+        1,
+        RoundingMode::Odd,
+        "0.3",
+        Ordering::Greater,
+    );
+    test(
+        Rational::from(12345),
+        2,
+        RoundingMode::Down,
+        "1.2e4",
+        Ordering::Less,
+    );
+    test(Rational::ZERO, 5, RoundingMode::Exact, "0", Ordering::Equal);
+}
+
+#[test]
+#[should_panic]
+fn from_rational_prec_round_fail_1() {
+    Decimal::from_rational_prec_round(Rational::ONE, 0, RoundingMode::Floor);
+}
+
+#[test]
+#[should_panic]
+fn from_rational_prec_round_fail_2() {
+    Decimal::from_rational_prec_round(Rational::from_signeds(1, 3), 10, RoundingMode::Exact);
+}
+
+#[test]
+fn rational_conversion_properties() {
+    integer_signed_pair_gen::<i8>().test_properties(|(c, e)| {
+        let d = Decimal::new(c, i64::from(e));
+        let q = Rational::from(&d);
+        assert_eq!(Rational::from(d.clone()), q);
+        let d_alt = Decimal::try_from(&q).unwrap();
+        assert_eq!(d_alt, d);
+        assert!(d_alt.exponent() <= 0);
+        assert!(d_alt.exponent() >= d.exponent().min(0));
+
+        let s = d.to_string();
+        let d_alt = Decimal::from_str(&s).unwrap();
+        assert_eq!(d_alt.coefficient(), d.coefficient());
+        assert_eq!(d_alt.exponent(), d.exponent());
+    });
+}
+
+#[test]
+fn from_rational_prec_round_properties() {
+    rational_unsigned_pair_gen_var_3::<u64>().test_properties(|(x, prec)| {
+        let prec = prec % 100 + 1;
+        let mut floor = None;
+        for rm in exhaustive_rounding_modes() {
+            if rm == RoundingMode::Exact {
+                continue;
+            }
+            let (d, o) = Decimal::from_rational_prec_round(x.clone(), prec, rm);
+            assert!(d.precision() <= prec);
+            let q = Rational::from(&d);
+            assert_eq!(q.cmp(&x), o);
+            if x != 0u32 {
+                assert_eq!(d.precision(), prec);
+                assert!((&q - &x).abs() < ulp(d.exponent()));
+            }
+            match rm {
+                RoundingMode::Floor => assert_ne!(o, Ordering::Greater),
+                RoundingMode::Ceiling => assert_ne!(o, Ordering::Less),
+                RoundingMode::Down => assert!(q.le_abs(&x)),
+                RoundingMode::Up => assert!(q.ge_abs(&x)),
+                _ => {}
+            }
+            if rm == RoundingMode::Floor {
+                floor = Some(o);
+            }
+        }
+        if floor == Some(Ordering::Equal) {
+            let (d, o) = Decimal::from_rational_prec_round(x.clone(), prec, RoundingMode::Exact);
+            assert_eq!(o, Ordering::Equal);
+            assert_eq!(Rational::from(d), x);
+        }
+    });
+}
+
+#[test]
+fn from_rational_scale_round_properties() {
+    rational_signed_rounding_mode_triple_gen_var_1().test_properties(|(x, scale, rm)| {
+        if rm == RoundingMode::Exact {
+            return;
+        }
+        let (d, o) = Decimal::from_rational_scale_round(x.clone(), scale, rm);
+        assert_eq!(d.exponent(), -scale);
+        let q = Rational::from(&d);
+        assert_eq!(q.cmp(&x), o);
+        assert!((&q - &x).abs() < ulp(-scale));
+    });
+}
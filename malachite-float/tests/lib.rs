@@ -43,12 +43,19 @@ extern crate malachite_base;
 
 pub mod arithmetic {
     pub mod abs;
+    pub mod copysign;
+    pub mod hypot;
     pub mod is_power_of_2;
     pub mod neg;
     pub mod power_of_2;
     pub mod sign;
 }
 
+pub mod ball {
+    pub mod arithmetic;
+    pub mod comparison;
+    pub mod conversion;
+}
 pub mod basic {
     pub mod classification;
     pub mod complexity;
@@ -63,6 +70,7 @@ pub mod comparison {
     pub mod cmp_abs;
     pub mod eq;
     pub mod hash;
+    pub mod min_max;
     pub mod partial_cmp_abs_integer;
     pub mod partial_cmp_abs_natural;
     pub mod partial_cmp_abs_primitive_float;
@@ -79,12 +87,17 @@ pub mod comparison {
     pub mod partial_eq_primitive_int;
     pub mod partial_eq_rational;
 }
+pub mod complex {
+    pub mod arithmetic;
+    pub mod conversion;
+}
 pub mod conversion {
     pub mod clone;
     pub mod from_integer;
     pub mod from_natural;
     pub mod from_primitive_float;
     pub mod from_primitive_int;
+    pub mod from_quotient;
     pub mod from_rational;
     pub mod integer_from_float;
     pub mod mantissa_and_exponent;
@@ -93,6 +106,11 @@ pub mod conversion {
     pub mod primitive_int_from_float;
     pub mod rational_from_float;
 }
+pub mod decimal {
+    pub mod arithmetic;
+    pub mod comparison;
+    pub mod conversion;
+}
 pub mod exhaustive {
     pub mod exhaustive_finite_floats;
     pub mod exhaustive_floats;
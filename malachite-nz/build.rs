@@ -0,0 +1,39 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+#[allow(dead_code)]
+#[path = "src/tuning.rs"]
+mod tuning;
+
+// By default, the thresholds that choose between algorithms (for example, between Toom-Cook and FFT
+// multiplication) are taken from `src/platform_64.rs` or `src/platform_32.rs`. If the environment
+// variable `MALACHITE_NZ_PLATFORM` is set to the path of another platform file, such as one
+// generated for the current machine by `cargo run --release --features bin_build -- -g tuneup`,
+// that file is used instead. The build fails if the file's thresholds are invalid; see
+// `src/tuning.rs`.
+fn main() {
+    println!("cargo:rerun-if-env-changed=MALACHITE_NZ_PLATFORM");
+    println!("cargo:rustc-check-cfg=cfg(malachite_nz_tuned_platform)");
+    if let Some(path) = env::var_os("MALACHITE_NZ_PLATFORM") {
+        let path = Path::new(&path);
+        println!("cargo:rerun-if-changed={}", path.display());
+        let source = fs::read_to_string(path).unwrap_or_else(|e| {
+            panic!(
+                "Could not read MALACHITE_NZ_PLATFORM file {}: {e}",
+                path.display()
+            )
+        });
+        if let Err(e) =
+            tuning::parse_thresholds(&source).and_then(|t| tuning::validate_thresholds(&t))
+        {
+            panic!(
+                "Invalid thresholds in MALACHITE_NZ_PLATFORM file {}: {e}",
+                path.display()
+            );
+        }
+        let out = Path::new(&env::var_os("OUT_DIR").unwrap()).join("platform.rs");
+        fs::write(out, source).unwrap();
+        println!("cargo:rustc-cfg=malachite_nz_tuned_platform");
+    }
+}
@@ -57,6 +57,8 @@ use crate::bin_util::generate::digits_data::generate_string_data;
 #[cfg(feature = "bin_build")]
 use crate::bin_util::generate::factorial_data::generate_factorial_data;
 #[cfg(feature = "bin_build")]
+use crate::bin_util::generate::tuneup::generate_tuned_platform;
+#[cfg(feature = "bin_build")]
 use malachite_base::test_util::runner::cmd::read_command_line_arguments;
 #[cfg(feature = "bin_build")]
 use malachite_base::test_util::runner::Runner;
@@ -70,6 +72,8 @@ use malachite_base::test_util::runner::Runner;
 //     benchmark_limbs_to_digits_small_base_algorithms
 //
 // cargo run --features bin_build -- -g digits_data
+//
+// cargo run --release --features bin_build -- -g tuneup > platform_tuned.rs
 #[cfg(feature = "bin_build")]
 fn main() {
     let args = read_command_line_arguments("malachite-nz test utils");
@@ -90,6 +94,7 @@ fn main() {
         match codegen_key.as_str() {
             "digits_data" => generate_string_data(),
             "factorial_data" => generate_factorial_data(),
+            "tuneup" => generate_tuned_platform(),
             _ => panic!("Invalid codegen key: {codegen_key}"),
         }
     }
@@ -87,7 +87,9 @@ fn benchmark_integer_from_sci_string(
         limit,
         file_name,
         &string_len_bucketer(),
-        &mut [("Malachite", &mut |s| no_out!(Integer::from_sci_string(&s)))],
+        &mut [("Malachite", &mut |s| {
+            no_out!(Integer::from_sci_string(&s).ok())
+        })],
     );
 }
 
@@ -106,7 +108,7 @@ fn benchmark_integer_from_sci_string_with_options(
         file_name,
         &pair_1_string_len_bucketer("s"),
         &mut [("Malachite", &mut |(s, options)| {
-            no_out!(Integer::from_sci_string_with_options(&s, options))
+            no_out!(Integer::from_sci_string_with_options(&s, options).ok())
         })],
     );
 }
@@ -1,2 +1,3 @@
 pub mod digits_data;
 pub mod factorial_data;
+pub mod tuneup;
@@ -0,0 +1,308 @@
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::logic::traits::BitAccess;
+use malachite_base::num::random::random_primitive_ints;
+use malachite_base::random::EXAMPLE_SEED;
+use malachite_nz::natural::arithmetic::div_mod::{
+    limbs_div_mod_barrett, limbs_div_mod_barrett_scratch_len, limbs_div_mod_divide_and_conquer,
+    limbs_div_mod_schoolbook, limbs_two_limb_inverse_helper,
+};
+use malachite_nz::natural::arithmetic::mul::fft::{
+    limbs_mul_greater_to_out_fft, limbs_mul_greater_to_out_fft_scratch_len,
+};
+use malachite_nz::natural::arithmetic::mul::limbs_mul_greater_to_out_basecase;
+use malachite_nz::natural::arithmetic::mul::toom::{
+    limbs_mul_greater_to_out_toom_22, limbs_mul_greater_to_out_toom_22_input_sizes_valid,
+    limbs_mul_greater_to_out_toom_22_scratch_len, limbs_mul_greater_to_out_toom_33,
+    limbs_mul_greater_to_out_toom_33_input_sizes_valid,
+    limbs_mul_greater_to_out_toom_33_scratch_len, limbs_mul_greater_to_out_toom_44,
+    limbs_mul_greater_to_out_toom_44_input_sizes_valid,
+    limbs_mul_greater_to_out_toom_44_scratch_len, limbs_mul_greater_to_out_toom_6h,
+    limbs_mul_greater_to_out_toom_6h_input_sizes_valid,
+    limbs_mul_greater_to_out_toom_6h_scratch_len, limbs_mul_greater_to_out_toom_8h,
+    limbs_mul_greater_to_out_toom_8h_input_sizes_valid,
+    limbs_mul_greater_to_out_toom_8h_scratch_len,
+};
+use malachite_nz::platform::Limb;
+use malachite_nz::tuning::{make_thresholds_valid, validate_thresholds};
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+#[cfg(not(feature = "32_bit_limbs"))]
+const DEFAULT_PLATFORM: &str = include_str!("../../platform_64.rs");
+#[cfg(feature = "32_bit_limbs")]
+const DEFAULT_PLATFORM: &str = include_str!("../../platform_32.rs");
+
+// Each measurement repeats a function until at least this much time has elapsed.
+const MIN_MEASUREMENT_TIME: Duration = Duration::from_millis(2);
+
+// The time per call is the minimum over this many measurements, to filter out noise.
+const MEASUREMENT_COUNT: usize = 5;
+
+// A crossover is only accepted if the faster algorithm stays faster for this many consecutive
+// sizes.
+const CONFIRMATION_COUNT: usize = 3;
+
+// Returns the time taken by one call to `f`, in seconds.
+fn time_per_call<F: FnMut()>(mut f: F) -> f64 {
+    let mut best = f64::INFINITY;
+    for _ in 0..MEASUREMENT_COUNT {
+        let mut calls = 0u32;
+        let start = Instant::now();
+        let mut elapsed;
+        loop {
+            f();
+            calls += 1;
+            elapsed = start.elapsed();
+            if elapsed >= MIN_MEASUREMENT_TIME {
+                break;
+            }
+        }
+        best = best.min(elapsed.as_secs_f64() / f64::from(calls));
+    }
+    best
+}
+
+fn random_limbs(len: usize, seed_index: u64) -> Vec<Limb> {
+    random_primitive_ints(EXAMPLE_SEED.fork(&seed_index.to_string()))
+        .take(len)
+        .collect()
+}
+
+// Returns the smallest size in the sequence produced by stepping from `min` to `max` at which
+// `measure` reports that the large-size algorithm is faster than the small-size one, and remains
+// faster for the next `CONFIRMATION_COUNT` sizes. `measure` returns `None` if a size is not valid
+// for both algorithms. If no crossover is found, `max` is returned.
+fn find_threshold<F: FnMut(usize) -> Option<(f64, f64)>>(
+    name: &str,
+    min: usize,
+    max: usize,
+    mut measure: F,
+) -> usize {
+    let mut candidate = None;
+    let mut confirmations = 0;
+    let mut size = min;
+    while size <= max {
+        if let Some((small_time, large_time)) = measure(size) {
+            eprintln!("{name}: size {size}: {small_time:.3e}s vs. {large_time:.3e}s");
+            if large_time < small_time {
+                if candidate.is_none() {
+                    candidate = Some(size);
+                }
+                confirmations += 1;
+                if confirmations > CONFIRMATION_COUNT {
+                    break;
+                }
+            } else {
+                candidate = None;
+                confirmations = 0;
+            }
+        }
+        size += (size >> 4).max(1);
+    }
+    let threshold = candidate.unwrap_or(max);
+    eprintln!("{name} = {threshold}");
+    threshold
+}
+
+type MulFn = fn(&mut [Limb], &[Limb], &[Limb], &mut [Limb]);
+type ScratchLenFn = fn(usize, usize) -> usize;
+type ValidFn = fn(usize, usize) -> bool;
+
+fn limbs_mul_basecase_with_scratch(out: &mut [Limb], xs: &[Limb], ys: &[Limb], _: &mut [Limb]) {
+    limbs_mul_greater_to_out_basecase(out, xs, ys);
+}
+
+const fn no_scratch(_: usize, _: usize) -> usize {
+    0
+}
+
+const fn always_valid(_: usize, _: usize) -> bool {
+    true
+}
+
+// Compares two multiplication algorithms on random operands of equal length.
+fn tune_mul(
+    name: &str,
+    min: usize,
+    max: usize,
+    small: (MulFn, ScratchLenFn, ValidFn),
+    large: (MulFn, ScratchLenFn, ValidFn),
+) -> usize {
+    find_threshold(name, min, max, |n| {
+        let (small_mul, small_scratch_len, small_valid) = small;
+        let (large_mul, large_scratch_len, large_valid) = large;
+        if !small_valid(n, n) || !large_valid(n, n) {
+            return None;
+        }
+        let xs = random_limbs(n, 0);
+        let ys = random_limbs(n, 1);
+        let mut out = vec![0; n << 1];
+        let mut scratch = vec![0; small_scratch_len(n, n)];
+        let small_time = time_per_call(|| small_mul(&mut out, &xs, &ys, &mut scratch));
+        let mut scratch = vec![0; large_scratch_len(n, n)];
+        let large_time = time_per_call(|| large_mul(&mut out, &xs, &ys, &mut scratch));
+        Some((small_time, large_time))
+    })
+}
+
+// Generates a normalized divisor of length `d_len` and a numerator of length `2 * d_len`.
+fn division_operands(d_len: usize) -> (Vec<Limb>, Vec<Limb>, Limb) {
+    let ns = random_limbs(d_len << 1, 2);
+    let mut ds = random_limbs(d_len, 3);
+    ds[d_len - 1].set_bit(Limb::WIDTH - 1);
+    let d_inv = limbs_two_limb_inverse_helper(ds[d_len - 1], ds[d_len - 2]);
+    (ns, ds, d_inv)
+}
+
+fn tune_dc_div_qr(min: usize, max: usize) -> usize {
+    find_threshold("DC_DIV_QR_THRESHOLD", min, max, |d_len| {
+        if d_len < 6 {
+            return None;
+        }
+        let (ns, ds, d_inv) = division_operands(d_len);
+        let mut qs = vec![0; d_len];
+        let mut ns_copy = ns.clone();
+        let small_time = time_per_call(|| {
+            ns_copy.copy_from_slice(&ns);
+            limbs_div_mod_schoolbook(&mut qs, &mut ns_copy, &ds, d_inv);
+        });
+        let large_time = time_per_call(|| {
+            ns_copy.copy_from_slice(&ns);
+            limbs_div_mod_divide_and_conquer(&mut qs, &mut ns_copy, &ds, d_inv);
+        });
+        Some((small_time, large_time))
+    })
+}
+
+fn tune_mu_div_qr(min: usize, max: usize) -> usize {
+    find_threshold("MU_DIV_QR_THRESHOLD", min, max, |d_len| {
+        let (ns, ds, d_inv) = division_operands(d_len);
+        let mut qs = vec![0; d_len];
+        let mut ns_copy = ns.clone();
+        let small_time = time_per_call(|| {
+            ns_copy.copy_from_slice(&ns);
+            limbs_div_mod_divide_and_conquer(&mut qs, &mut ns_copy, &ds, d_inv);
+        });
+        let mut rs = vec![0; d_len];
+        let mut scratch = vec![0; limbs_div_mod_barrett_scratch_len(ns.len(), d_len)];
+        let large_time = time_per_call(|| {
+            limbs_div_mod_barrett(&mut qs, &mut rs, &ns, &ds, &mut scratch);
+        });
+        Some((small_time, large_time))
+    })
+}
+
+// Replaces the values of the tuned constants in the default platform file.
+fn substitute_thresholds(thresholds: &BTreeMap<&str, usize>) -> String {
+    let mut out = String::new();
+    let mut replaced = 0;
+    for line in DEFAULT_PLATFORM.lines() {
+        let tuned = line
+            .strip_prefix("pub const ")
+            .and_then(|rest| rest.split_once(": usize = "))
+            .and_then(|(name, rest)| {
+                thresholds.get(name).map(|&value| {
+                    let old = &rest[..rest.find(';').unwrap()];
+                    format!("pub const {name}: usize = {value}; // default: {old}")
+                })
+            });
+        if let Some(tuned) = tuned {
+            out.push_str(&tuned);
+            replaced += 1;
+        } else {
+            out.push_str(line);
+        }
+        out.push('\n');
+    }
+    assert_eq!(replaced, thresholds.len());
+    out
+}
+
+// Times competing limb algorithms on the current machine, in the manner of GMP's `tuneup`, and
+// prints an alternative platform file in which the multiplication and division thresholds are
+// replaced by the measured crossover points. Progress is reported on stderr.
+//
+// Each threshold is measured with all other thresholds at their current values, so running the
+// tuner a second time, with the output of the first run in place, may refine the results. Measured
+// thresholds that are out of order, or below the minimum input length of their algorithm, are
+// raised; see `malachite_nz::tuning`.
+//
+// To use the output, save it to a file and set the environment variable `MALACHITE_NZ_PLATFORM`
+// to its path when building `malachite-nz`:
+//
+// ```text
+// cargo run --release --features bin_build -- -g tuneup > platform_tuned.rs
+// MALACHITE_NZ_PLATFORM=$PWD/platform_tuned.rs cargo build --release
+// ```
+//
+// The file must have been generated with the same limb width (that is, with or without the
+// `32_bit_limbs` feature) as the build that uses it.
+pub(crate) fn generate_tuned_platform() {
+    let mut thresholds = BTreeMap::new();
+    let basecase: (MulFn, ScratchLenFn, ValidFn) =
+        (limbs_mul_basecase_with_scratch, no_scratch, always_valid);
+    let toom_22: (MulFn, ScratchLenFn, ValidFn) = (
+        limbs_mul_greater_to_out_toom_22,
+        limbs_mul_greater_to_out_toom_22_scratch_len,
+        limbs_mul_greater_to_out_toom_22_input_sizes_valid,
+    );
+    let toom_33: (MulFn, ScratchLenFn, ValidFn) = (
+        limbs_mul_greater_to_out_toom_33,
+        limbs_mul_greater_to_out_toom_33_scratch_len,
+        limbs_mul_greater_to_out_toom_33_input_sizes_valid,
+    );
+    let toom_44: (MulFn, ScratchLenFn, ValidFn) = (
+        limbs_mul_greater_to_out_toom_44,
+        limbs_mul_greater_to_out_toom_44_scratch_len,
+        limbs_mul_greater_to_out_toom_44_input_sizes_valid,
+    );
+    let toom_6h: (MulFn, ScratchLenFn, ValidFn) = (
+        limbs_mul_greater_to_out_toom_6h,
+        limbs_mul_greater_to_out_toom_6h_scratch_len,
+        limbs_mul_greater_to_out_toom_6h_input_sizes_valid,
+    );
+    let toom_8h: (MulFn, ScratchLenFn, ValidFn) = (
+        limbs_mul_greater_to_out_toom_8h,
+        limbs_mul_greater_to_out_toom_8h_scratch_len,
+        limbs_mul_greater_to_out_toom_8h_input_sizes_valid,
+    );
+    let fft: (MulFn, ScratchLenFn, ValidFn) = (
+        limbs_mul_greater_to_out_fft,
+        limbs_mul_greater_to_out_fft_scratch_len,
+        always_valid,
+    );
+    thresholds.insert(
+        "MUL_TOOM22_THRESHOLD",
+        tune_mul("MUL_TOOM22_THRESHOLD", 4, 300, basecase, toom_22),
+    );
+    thresholds.insert(
+        "MUL_TOOM33_THRESHOLD",
+        tune_mul("MUL_TOOM33_THRESHOLD", 10, 600, toom_22, toom_33),
+    );
+    thresholds.insert(
+        "MUL_TOOM44_THRESHOLD",
+        tune_mul("MUL_TOOM44_THRESHOLD", 20, 1500, toom_33, toom_44),
+    );
+    thresholds.insert(
+        "MUL_TOOM6H_THRESHOLD",
+        tune_mul("MUL_TOOM6H_THRESHOLD", 50, 2000, toom_44, toom_6h),
+    );
+    thresholds.insert(
+        "MUL_TOOM8H_THRESHOLD",
+        tune_mul("MUL_TOOM8H_THRESHOLD", 100, 3000, toom_6h, toom_8h),
+    );
+    thresholds.insert(
+        "MUL_FFT_THRESHOLD",
+        tune_mul("MUL_FFT_THRESHOLD", 200, 20000, toom_8h, fft),
+    );
+    thresholds.insert("DC_DIV_QR_THRESHOLD", tune_dc_div_qr(6, 1000));
+    thresholds.insert("MU_DIV_QR_THRESHOLD", tune_mu_div_qr(100, 10000));
+    make_thresholds_valid(&mut thresholds);
+    validate_thresholds(&thresholds).unwrap();
+    println!(
+        "// This file was generated by `cargo run --release --features bin_build -- -g tuneup`."
+    );
+    println!("// To use it, set `MALACHITE_NZ_PLATFORM` to its path when building malachite-nz.");
+    println!("// Tuned for {}-bit limbs.", Limb::WIDTH);
+    print!("{}", substitute_thresholds(&thresholds));
+}
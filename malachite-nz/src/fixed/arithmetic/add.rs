@@ -0,0 +1,352 @@
+use crate::fixed::{Int, Uint};
+use crate::natural::arithmetic::add::limbs_slice_add_same_length_in_place_left;
+use crate::natural::arithmetic::sub::limbs_sub_same_length_in_place_left;
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::arithmetic::traits::{
+    CheckedAdd, CheckedSub, OverflowingAdd, OverflowingAddAssign, OverflowingSub,
+    OverflowingSubAssign, SaturatingAdd, SaturatingAddAssign, SaturatingSub, SaturatingSubAssign,
+    WrappingAdd, WrappingAddAssign, WrappingSub, WrappingSubAssign,
+};
+use malachite_base::num::basic::traits::Zero;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+impl<const LIMBS: usize> OverflowingAddAssign<Uint<LIMBS>> for Uint<LIMBS> {
+    /// Adds a [`Uint`] to a [`Uint`] in place.
+    ///
+    /// Returns a boolean indicating whether an arithmetic overflow occurred. If an overflow
+    /// occurred, then the wrapped value is assigned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::add#overflowing).
+    #[inline]
+    fn overflowing_add_assign(&mut self, other: Uint<LIMBS>) -> bool {
+        limbs_slice_add_same_length_in_place_left(&mut self.0, &other.0)
+    }
+}
+
+impl<const LIMBS: usize> OverflowingSubAssign<Uint<LIMBS>> for Uint<LIMBS> {
+    /// Subtracts a [`Uint`] from a [`Uint`] in place.
+    ///
+    /// Returns a boolean indicating whether an arithmetic overflow occurred. If an overflow
+    /// occurred, then the wrapped value is assigned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::add#overflowing).
+    #[inline]
+    fn overflowing_sub_assign(&mut self, other: Uint<LIMBS>) -> bool {
+        limbs_sub_same_length_in_place_left(&mut self.0, &other.0)
+    }
+}
+
+impl<const LIMBS: usize> OverflowingAddAssign<Int<LIMBS>> for Int<LIMBS> {
+    /// Adds an [`Int`] to an [`Int`] in place.
+    ///
+    /// Returns a boolean indicating whether an arithmetic overflow occurred. If an overflow
+    /// occurred, then the wrapped value is assigned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::add#overflowing).
+    fn overflowing_add_assign(&mut self, other: Int<LIMBS>) -> bool {
+        let x_negative = self.is_negative();
+        let y_negative = other.is_negative();
+        self.0.overflowing_add_assign(other.0);
+        x_negative == y_negative && self.is_negative() != x_negative
+    }
+}
+
+impl<const LIMBS: usize> OverflowingSubAssign<Int<LIMBS>> for Int<LIMBS> {
+    /// Subtracts an [`Int`] from an [`Int`] in place.
+    ///
+    /// Returns a boolean indicating whether an arithmetic overflow occurred. If an overflow
+    /// occurred, then the wrapped value is assigned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::add#overflowing).
+    fn overflowing_sub_assign(&mut self, other: Int<LIMBS>) -> bool {
+        let x_negative = self.is_negative();
+        let y_negative = other.is_negative();
+        self.0.overflowing_sub_assign(other.0);
+        x_negative != y_negative && self.is_negative() != x_negative
+    }
+}
+
+impl_via_overflowing!(
+    Uint,
+    "sum",
+    "add",
+    "$T(n) = O(n)$",
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    OverflowingAdd,
+    overflowing_add,
+    overflowing_add_assign,
+    WrappingAdd,
+    wrapping_add,
+    WrappingAddAssign,
+    wrapping_add_assign,
+    CheckedAdd,
+    checked_add
+);
+impl_via_overflowing!(
+    Uint,
+    "difference",
+    "add",
+    "$T(n) = O(n)$",
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    OverflowingSub,
+    overflowing_sub,
+    overflowing_sub_assign,
+    WrappingSub,
+    wrapping_sub,
+    WrappingSubAssign,
+    wrapping_sub_assign,
+    CheckedSub,
+    checked_sub
+);
+impl_via_overflowing!(
+    Int,
+    "sum",
+    "add",
+    "$T(n) = O(n)$",
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    OverflowingAdd,
+    overflowing_add,
+    overflowing_add_assign,
+    WrappingAdd,
+    wrapping_add,
+    WrappingAddAssign,
+    wrapping_add_assign,
+    CheckedAdd,
+    checked_add
+);
+impl_via_overflowing!(
+    Int,
+    "difference",
+    "add",
+    "$T(n) = O(n)$",
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    OverflowingSub,
+    overflowing_sub,
+    overflowing_sub_assign,
+    WrappingSub,
+    wrapping_sub,
+    WrappingSubAssign,
+    wrapping_sub_assign,
+    CheckedSub,
+    checked_sub
+);
+
+impl<const LIMBS: usize> SaturatingAdd<Uint<LIMBS>> for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Adds two [`Uint`]s, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::add#saturating).
+    #[inline]
+    fn saturating_add(mut self, other: Uint<LIMBS>) -> Uint<LIMBS> {
+        self.saturating_add_assign(other);
+        self
+    }
+}
+
+impl<const LIMBS: usize> SaturatingAddAssign<Uint<LIMBS>> for Uint<LIMBS> {
+    /// Adds a [`Uint`] to a [`Uint`] in place, saturating at the numeric bounds instead of
+    /// overflowing.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::add#saturating).
+    #[inline]
+    fn saturating_add_assign(&mut self, other: Uint<LIMBS>) {
+        if self.overflowing_add_assign(other) {
+            *self = Uint::MAX;
+        }
+    }
+}
+
+impl<const LIMBS: usize> SaturatingSub<Uint<LIMBS>> for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Subtracts a [`Uint`] from a [`Uint`], saturating at the numeric bounds instead of
+    /// overflowing.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::add#saturating).
+    #[inline]
+    fn saturating_sub(mut self, other: Uint<LIMBS>) -> Uint<LIMBS> {
+        self.saturating_sub_assign(other);
+        self
+    }
+}
+
+impl<const LIMBS: usize> SaturatingSubAssign<Uint<LIMBS>> for Uint<LIMBS> {
+    /// Subtracts a [`Uint`] from a [`Uint`] in place, saturating at the numeric bounds instead
+    /// of overflowing.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::add#saturating).
+    #[inline]
+    fn saturating_sub_assign(&mut self, other: Uint<LIMBS>) {
+        if self.overflowing_sub_assign(other) {
+            *self = Uint::ZERO;
+        }
+    }
+}
+
+impl<const LIMBS: usize> SaturatingAdd<Int<LIMBS>> for Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    /// Adds two [`Int`]s, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::add#saturating).
+    #[inline]
+    fn saturating_add(mut self, other: Int<LIMBS>) -> Int<LIMBS> {
+        self.saturating_add_assign(other);
+        self
+    }
+}
+
+impl<const LIMBS: usize> SaturatingAddAssign<Int<LIMBS>> for Int<LIMBS> {
+    /// Adds an [`Int`] to an [`Int`] in place, saturating at the numeric bounds instead of
+    /// overflowing.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::add#saturating).
+    fn saturating_add_assign(&mut self, other: Int<LIMBS>) {
+        if self.overflowing_add_assign(other) {
+            *self = if other.is_negative() {
+                Int::MIN
+            } else {
+                Int::MAX
+            };
+        }
+    }
+}
+
+impl<const LIMBS: usize> SaturatingSub<Int<LIMBS>> for Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    /// Subtracts an [`Int`] from an [`Int`], saturating at the numeric bounds instead of
+    /// overflowing.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::add#saturating).
+    #[inline]
+    fn saturating_sub(mut self, other: Int<LIMBS>) -> Int<LIMBS> {
+        self.saturating_sub_assign(other);
+        self
+    }
+}
+
+impl<const LIMBS: usize> SaturatingSubAssign<Int<LIMBS>> for Int<LIMBS> {
+    /// Subtracts an [`Int`] from an [`Int`] in place, saturating at the numeric bounds instead of
+    /// overflowing.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::add#saturating).
+    fn saturating_sub_assign(&mut self, other: Int<LIMBS>) {
+        if self.overflowing_sub_assign(other) {
+            *self = if other.is_negative() {
+                Int::MAX
+            } else {
+                Int::MIN
+            };
+        }
+    }
+}
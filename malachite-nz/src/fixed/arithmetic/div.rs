@@ -0,0 +1,831 @@
+use crate::fixed::{Int, Uint};
+use crate::natural::arithmetic::add::limbs_slice_add_same_length_in_place_left;
+use crate::natural::arithmetic::div_mod::{
+    limbs_div_limb_in_place_mod, limbs_div_mod_three_limb_by_two_limb,
+    limbs_two_limb_inverse_helper,
+};
+use crate::natural::arithmetic::sub_mul::limbs_sub_mul_limb_same_length_in_place_left;
+use crate::platform::{DoubleLimb, Limb};
+use malachite_base::comparison::traits::Min;
+use malachite_base::num::arithmetic::traits::{
+    CheckedDiv, DivAssignMod, DivAssignRem, DivMod, DivRem, OverflowingDiv, OverflowingDivAssign,
+    UnsignedAbs, WrappingAddAssign, WrappingDiv, WrappingDivAssign, WrappingNegAssign,
+    WrappingSubAssign,
+};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_base::num::conversion::traits::{JoinHalves, SplitInHalf};
+use malachite_base::num::logic::traits::LeadingZeros;
+use std::ops::{Div, DivAssign, Rem, RemAssign};
+
+// A nonzero divisor, given as limbs in ascending order with its high zero limbs trimmed off,
+// together with the data needed to divide by it one limb at a time.
+//
+// The divisor is never normalized in place, which would need an extra limb of storage. Instead,
+// each quotient limb is estimated from the top three limbs of the shifted partial remainder and
+// the top two limbs of the shifted divisor, and is then corrected against the unshifted divisor.
+pub(crate) struct FixedDivisor<'a> {
+    ds: &'a [Limb],
+    shift: u64,
+    d_1: Limb,
+    d_0: Limb,
+    d_inv: Limb,
+}
+
+impl<'a> FixedDivisor<'a> {
+    // Returns `None` if `ds` is zero.
+    //
+    // # Worst-case complexity
+    // $T(n) = O(n)$
+    //
+    // $M(n) = O(1)$
+    //
+    // where $T$ is time, $M$ is additional memory, and $n$ is `ds.len()`.
+    pub(crate) fn new(ds: &'a [Limb]) -> Option<FixedDivisor<'a>> {
+        let d_len = ds.iter().rposition(|&d| d != 0)? + 1;
+        let ds = &ds[..d_len];
+        if d_len == 1 {
+            return Some(FixedDivisor {
+                ds,
+                shift: 0,
+                d_1: 0,
+                d_0: ds[0],
+                d_inv: 0,
+            });
+        }
+        let shift = LeadingZeros::leading_zeros(ds[d_len - 1]);
+        let d_1 = shl_pair(ds[d_len - 1], ds[d_len - 2], shift);
+        let d_0 = shl_pair(
+            ds[d_len - 2],
+            if d_len > 2 { ds[d_len - 3] } else { 0 },
+            shift,
+        );
+        Some(FixedDivisor {
+            ds,
+            shift,
+            d_1,
+            d_0,
+            d_inv: limbs_two_limb_inverse_helper(d_1, d_0),
+        })
+    }
+
+    // The significant limbs of the divisor.
+    #[inline]
+    pub(crate) const fn limbs(&self) -> &'a [Limb] {
+        self.ds
+    }
+
+    // The number of significant limbs of the divisor.
+    #[inline]
+    pub(crate) const fn len(&self) -> usize {
+        self.ds.len()
+    }
+
+    // Given a partial remainder `rs`, which has `self.len()` limbs and is less than the divisor,
+    // and a limb `n`, replaces `rs` with the remainder of `rs * B + n` and returns the quotient
+    // limb.
+    //
+    // # Worst-case complexity
+    // $T(n) = O(n)$
+    //
+    // $M(n) = O(1)$
+    //
+    // where $T$ is time, $M$ is additional memory, and $n$ is `self.len()`.
+    pub(crate) fn step(&self, rs: &mut [Limb], n: Limb) -> Limb {
+        let ds = self.ds;
+        let d_len = ds.len();
+        if d_len == 1 {
+            let (q, r) = DoubleLimb::join_halves(rs[0], n).div_rem(DoubleLimb::from(ds[0]));
+            rs[0] = r.lower_half();
+            return q.lower_half();
+        }
+        // The partial remainder is now `hi` followed by `rs`, and is less than `B * d`.
+        let hi = rs[d_len - 1];
+        rs.copy_within(0..d_len - 1, 1);
+        rs[0] = n;
+        let shift = self.shift;
+        let n_2 = shl_pair(hi, rs[d_len - 1], shift);
+        let n_1 = shl_pair(rs[d_len - 1], rs[d_len - 2], shift);
+        let n_0 = shl_pair(
+            rs[d_len - 2],
+            if d_len > 2 { rs[d_len - 3] } else { 0 },
+            shift,
+        );
+        let mut q = if n_2 == self.d_1 && n_1 == self.d_0 {
+            Limb::MAX
+        } else {
+            limbs_div_mod_three_limb_by_two_limb(n_2, n_1, n_0, self.d_1, self.d_0, self.d_inv).0
+        };
+        // The estimate is never too small, so the partial remainder can only go negative.
+        let mut top = hi.wrapping_sub(limbs_sub_mul_limb_same_length_in_place_left(rs, ds, q));
+        while top != 0 {
+            q -= 1;
+            if limbs_slice_add_same_length_in_place_left(rs, ds) {
+                top.wrapping_add_assign(1);
+            }
+        }
+        q
+    }
+}
+
+// Returns the high limb of `[hi, lo]` shifted left by `shift` bits, where `shift` is less than
+// `Limb::WIDTH`.
+#[inline]
+const fn shl_pair(hi: Limb, lo: Limb, shift: u64) -> Limb {
+    if shift == 0 {
+        hi
+    } else {
+        (hi << shift) | (lo >> (Limb::WIDTH - shift))
+    }
+}
+
+// Interpreting two arrays of `Limb`s as the limbs (in ascending order) of two fixed-width
+// unsigned integers, writes the limbs of the quotient to `ns` and returns the limbs of the
+// remainder. No memory is allocated.
+//
+// # Worst-case complexity
+// $T(n) = O(n^2)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+//
+// # Panics
+// Panics if `ds` is zero.
+fn limbs_div_mod_fixed<const LIMBS: usize>(
+    ns: &mut [Limb; LIMBS],
+    ds: &[Limb; LIMBS],
+) -> [Limb; LIMBS] {
+    let d = FixedDivisor::new(ds).unwrap_or_else(|| panic!("division by zero"));
+    let mut rs = [0; LIMBS];
+    if d.len() == 1 && LIMBS > 1 {
+        rs[0] = limbs_div_limb_in_place_mod(ns, ds[0]);
+        return rs;
+    }
+    let rs_lo = &mut rs[..d.len()];
+    for n in ns.iter_mut().rev() {
+        *n = d.step(rs_lo, *n);
+    }
+    rs
+}
+
+impl<const LIMBS: usize> DivAssignMod<Uint<LIMBS>> for Uint<LIMBS> {
+    type ModOutput = Uint<LIMBS>;
+
+    /// Divides a [`Uint`] by a [`Uint`] in place, taking the [`Uint`] on the right-hand side by
+    /// value and returning the remainder. The quotient is rounded towards negative infinity.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div#div_mod).
+    #[inline]
+    fn div_assign_mod(&mut self, other: Uint<LIMBS>) -> Uint<LIMBS> {
+        Uint(limbs_div_mod_fixed(&mut self.0, &other.0))
+    }
+}
+
+impl<const LIMBS: usize> DivMod<Uint<LIMBS>> for Uint<LIMBS> {
+    type DivOutput = Uint<LIMBS>;
+    type ModOutput = Uint<LIMBS>;
+
+    /// Divides a [`Uint`] by a [`Uint`], returning the quotient and remainder. The quotient is
+    /// rounded towards negative infinity.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div#div_mod).
+    #[inline]
+    fn div_mod(mut self, other: Uint<LIMBS>) -> (Uint<LIMBS>, Uint<LIMBS>) {
+        let r = self.div_assign_mod(other);
+        (self, r)
+    }
+}
+
+impl<const LIMBS: usize> DivAssignRem<Uint<LIMBS>> for Uint<LIMBS> {
+    type RemOutput = Uint<LIMBS>;
+
+    /// Divides a [`Uint`] by a [`Uint`] in place, returning the remainder. The quotient is
+    /// rounded towards zero.
+    ///
+    /// For [`Uint`]s, `div_assign_rem` is equivalent to
+    /// [`div_assign_mod`](DivAssignMod::div_assign_mod).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div#div_mod).
+    #[inline]
+    fn div_assign_rem(&mut self, other: Uint<LIMBS>) -> Uint<LIMBS> {
+        self.div_assign_mod(other)
+    }
+}
+
+impl<const LIMBS: usize> DivRem<Uint<LIMBS>> for Uint<LIMBS> {
+    type DivOutput = Uint<LIMBS>;
+    type RemOutput = Uint<LIMBS>;
+
+    /// Divides a [`Uint`] by a [`Uint`], returning the quotient and remainder. The quotient is
+    /// rounded towards zero.
+    ///
+    /// For [`Uint`]s, `div_rem` is equivalent to [`div_mod`](DivMod::div_mod).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div#div_mod).
+    #[inline]
+    fn div_rem(self, other: Uint<LIMBS>) -> (Uint<LIMBS>, Uint<LIMBS>) {
+        self.div_mod(other)
+    }
+}
+
+impl<const LIMBS: usize> DivAssign<Uint<LIMBS>> for Uint<LIMBS> {
+    /// Divides a [`Uint`] by a [`Uint`] in place. The quotient is rounded towards zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    #[inline]
+    fn div_assign(&mut self, other: Uint<LIMBS>) {
+        self.div_assign_mod(other);
+    }
+}
+
+impl<const LIMBS: usize> Div<Uint<LIMBS>> for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Divides a [`Uint`] by a [`Uint`]. The quotient is rounded towards zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    #[inline]
+    fn div(mut self, other: Uint<LIMBS>) -> Uint<LIMBS> {
+        self /= other;
+        self
+    }
+}
+
+impl<const LIMBS: usize> RemAssign<Uint<LIMBS>> for Uint<LIMBS> {
+    /// Divides a [`Uint`] by a [`Uint`] in place, replacing it with the remainder.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    #[inline]
+    fn rem_assign(&mut self, other: Uint<LIMBS>) {
+        *self = self.div_assign_mod(other);
+    }
+}
+
+impl<const LIMBS: usize> Rem<Uint<LIMBS>> for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Finds the remainder when a [`Uint`] is divided by a [`Uint`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    #[inline]
+    fn rem(mut self, other: Uint<LIMBS>) -> Uint<LIMBS> {
+        self %= other;
+        self
+    }
+}
+
+impl<const LIMBS: usize> CheckedDiv<Uint<LIMBS>> for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Divides a [`Uint`] by a [`Uint`], returning `None` if `other` is zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    #[inline]
+    fn checked_div(self, other: Uint<LIMBS>) -> Option<Uint<LIMBS>> {
+        if other == Uint::ZERO {
+            None
+        } else {
+            Some(self / other)
+        }
+    }
+}
+
+impl<const LIMBS: usize> OverflowingDivAssign<Uint<LIMBS>> for Uint<LIMBS> {
+    /// Divides a [`Uint`] by a [`Uint`] in place. Unsigned division never overflows, so this
+    /// always returns `false`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    #[inline]
+    fn overflowing_div_assign(&mut self, other: Uint<LIMBS>) -> bool {
+        *self /= other;
+        false
+    }
+}
+
+impl<const LIMBS: usize> OverflowingDiv<Uint<LIMBS>> for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Divides a [`Uint`] by a [`Uint`]. Unsigned division never overflows, so the returned
+    /// boolean is always `false`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    #[inline]
+    fn overflowing_div(self, other: Uint<LIMBS>) -> (Uint<LIMBS>, bool) {
+        (self / other, false)
+    }
+}
+
+impl<const LIMBS: usize> WrappingDivAssign<Uint<LIMBS>> for Uint<LIMBS> {
+    /// Divides a [`Uint`] by a [`Uint`] in place. Unsigned division never overflows, so this is
+    /// equivalent to `/=`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    #[inline]
+    fn wrapping_div_assign(&mut self, other: Uint<LIMBS>) {
+        *self /= other;
+    }
+}
+
+impl<const LIMBS: usize> WrappingDiv<Uint<LIMBS>> for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Divides a [`Uint`] by a [`Uint`]. Unsigned division never overflows, so this is equivalent
+    /// to `/`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    #[inline]
+    fn wrapping_div(self, other: Uint<LIMBS>) -> Uint<LIMBS> {
+        self / other
+    }
+}
+
+impl<const LIMBS: usize> Int<LIMBS> {
+    // Returns the quotient, rounded towards zero and wrapped, and the remainder, which has the
+    // sign of `self`. Also returns whether the quotient overflowed, which only happens for
+    // $-2^{W-1} / -1$.
+    fn overflowing_div_rem(self, other: Int<LIMBS>) -> (Int<LIMBS>, Int<LIMBS>, bool) {
+        let (mut q, mut r) = self.unsigned_abs().div_mod(other.unsigned_abs());
+        if self.is_negative() != other.is_negative() {
+            q.wrapping_neg_assign();
+        }
+        if self.is_negative() {
+            r.wrapping_neg_assign();
+        }
+        (
+            Int(q),
+            Int(r),
+            self == Int::MIN && other == Int::NEGATIVE_ONE,
+        )
+    }
+}
+
+impl<const LIMBS: usize> DivRem<Int<LIMBS>> for Int<LIMBS> {
+    type DivOutput = Int<LIMBS>;
+    type RemOutput = Int<LIMBS>;
+
+    /// Divides an [`Int`] by an [`Int`], returning the quotient and remainder. The quotient is
+    /// rounded towards zero and the remainder has the same sign as the first input.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `self` is $-2^{W-1}$ and `other` is $-1$.
+    ///
+    /// # Examples
+    /// See [here](super::div#div_mod).
+    fn div_rem(self, other: Int<LIMBS>) -> (Int<LIMBS>, Int<LIMBS>) {
+        let (q, r, overflow) = self.overflowing_div_rem(other);
+        if overflow {
+            panic!("Int quotient overflow");
+        }
+        (q, r)
+    }
+}
+
+impl<const LIMBS: usize> DivAssignRem<Int<LIMBS>> for Int<LIMBS> {
+    type RemOutput = Int<LIMBS>;
+
+    /// Divides an [`Int`] by an [`Int`] in place, returning the remainder. The quotient is
+    /// rounded towards zero and the remainder has the same sign as the first input.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `self` is $-2^{W-1}$ and `other` is $-1$.
+    ///
+    /// # Examples
+    /// See [here](super::div#div_mod).
+    #[inline]
+    fn div_assign_rem(&mut self, other: Int<LIMBS>) -> Int<LIMBS> {
+        let (q, r) = self.div_rem(other);
+        *self = q;
+        r
+    }
+}
+
+impl<const LIMBS: usize> DivMod<Int<LIMBS>> for Int<LIMBS> {
+    type DivOutput = Int<LIMBS>;
+    type ModOutput = Int<LIMBS>;
+
+    /// Divides an [`Int`] by an [`Int`], returning the quotient and remainder. The quotient is
+    /// rounded towards negative infinity and the remainder has the same sign as the second input.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `self` is $-2^{W-1}$ and `other` is $-1$.
+    ///
+    /// # Examples
+    /// See [here](super::div#div_mod).
+    fn div_mod(self, other: Int<LIMBS>) -> (Int<LIMBS>, Int<LIMBS>) {
+        let (mut q, mut r) = self.div_rem(other);
+        if r != Int::ZERO && r.is_negative() != other.is_negative() {
+            q.0.wrapping_sub_assign(Uint::ONE);
+            r.0.wrapping_add_assign(other.0);
+        }
+        (q, r)
+    }
+}
+
+impl<const LIMBS: usize> DivAssignMod<Int<LIMBS>> for Int<LIMBS> {
+    type ModOutput = Int<LIMBS>;
+
+    /// Divides an [`Int`] by an [`Int`] in place, returning the remainder. The quotient is
+    /// rounded towards negative infinity and the remainder has the same sign as the second input.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `self` is $-2^{W-1}$ and `other` is $-1$.
+    ///
+    /// # Examples
+    /// See [here](super::div#div_mod).
+    #[inline]
+    fn div_assign_mod(&mut self, other: Int<LIMBS>) -> Int<LIMBS> {
+        let (q, r) = self.div_mod(other);
+        *self = q;
+        r
+    }
+}
+
+impl<const LIMBS: usize> Div<Int<LIMBS>> for Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    /// Divides an [`Int`] by an [`Int`]. The quotient is rounded towards zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `self` is $-2^{W-1}$ and `other` is $-1$.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    #[inline]
+    fn div(self, other: Int<LIMBS>) -> Int<LIMBS> {
+        self.div_rem(other).0
+    }
+}
+
+impl<const LIMBS: usize> DivAssign<Int<LIMBS>> for Int<LIMBS> {
+    /// Divides an [`Int`] by an [`Int`] in place. The quotient is rounded towards zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `self` is $-2^{W-1}$ and `other` is $-1$.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    #[inline]
+    fn div_assign(&mut self, other: Int<LIMBS>) {
+        *self = *self / other;
+    }
+}
+
+impl<const LIMBS: usize> Rem<Int<LIMBS>> for Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    /// Finds the remainder when an [`Int`] is divided by an [`Int`]. The remainder has the same
+    /// sign as the first input.
+    ///
+    /// Unlike `/`, this does not panic when `self` is $-2^{W-1}$ and `other` is $-1$; the
+    /// remainder is 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    #[inline]
+    fn rem(self, other: Int<LIMBS>) -> Int<LIMBS> {
+        self.overflowing_div_rem(other).1
+    }
+}
+
+impl<const LIMBS: usize> RemAssign<Int<LIMBS>> for Int<LIMBS> {
+    /// Divides an [`Int`] by an [`Int`] in place, replacing it with the remainder. The remainder
+    /// has the same sign as the first input.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    #[inline]
+    fn rem_assign(&mut self, other: Int<LIMBS>) {
+        *self = *self % other;
+    }
+}
+
+impl<const LIMBS: usize> CheckedDiv<Int<LIMBS>> for Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    /// Divides an [`Int`] by an [`Int`], returning `None` if `other` is zero or if the quotient
+    /// is not representable.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    #[inline]
+    fn checked_div(self, other: Int<LIMBS>) -> Option<Int<LIMBS>> {
+        if other == Int::ZERO {
+            return None;
+        }
+        let (q, _, overflow) = self.overflowing_div_rem(other);
+        if overflow {
+            None
+        } else {
+            Some(q)
+        }
+    }
+}
+
+impl<const LIMBS: usize> OverflowingDiv<Int<LIMBS>> for Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    /// Divides an [`Int`] by an [`Int`].
+    ///
+    /// Returns a tuple containing the quotient and a boolean indicating whether an arithmetic
+    /// overflow occurred, which is the case only when `self` is $-2^{W-1}$ and `other` is $-1$.
+    /// In that case $-2^{W-1}$ is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    #[inline]
+    fn overflowing_div(self, other: Int<LIMBS>) -> (Int<LIMBS>, bool) {
+        let (q, _, overflow) = self.overflowing_div_rem(other);
+        (q, overflow)
+    }
+}
+
+impl<const LIMBS: usize> OverflowingDivAssign<Int<LIMBS>> for Int<LIMBS> {
+    /// Divides an [`Int`] by an [`Int`] in place.
+    ///
+    /// Returns a boolean indicating whether an arithmetic overflow occurred, which is the case
+    /// only when `self` is $-2^{W-1}$ and `other` is $-1$. In that case the value is left
+    /// unchanged.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    #[inline]
+    fn overflowing_div_assign(&mut self, other: Int<LIMBS>) -> bool {
+        let (q, overflow) = self.overflowing_div(other);
+        *self = q;
+        overflow
+    }
+}
+
+impl<const LIMBS: usize> WrappingDiv<Int<LIMBS>> for Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    /// Divides an [`Int`] by an [`Int`], wrapping around at the boundary of the type.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    #[inline]
+    fn wrapping_div(self, other: Int<LIMBS>) -> Int<LIMBS> {
+        self.overflowing_div(other).0
+    }
+}
+
+impl<const LIMBS: usize> WrappingDivAssign<Int<LIMBS>> for Int<LIMBS> {
+    /// Divides an [`Int`] by an [`Int`] in place, wrapping around at the boundary of the type.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    #[inline]
+    fn wrapping_div_assign(&mut self, other: Int<LIMBS>) {
+        *self = self.wrapping_div(other);
+    }
+}
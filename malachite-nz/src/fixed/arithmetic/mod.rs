@@ -0,0 +1,360 @@
+// Given an implementation of `OverflowingXAssign` for a fixed-width type, implements `X`,
+// `XAssign`, `OverflowingX`, `WrappingX`, `WrappingXAssign`, and `CheckedX`. The standard
+// operators panic if the result is not representable.
+macro_rules! impl_via_overflowing {
+    (
+        $t: ident,
+        $name: expr,
+        $module: expr,
+        $time: expr,
+        $std: ident,
+        $std_fn: ident,
+        $std_assign: ident,
+        $std_assign_fn: ident,
+        $overflowing: ident,
+        $overflowing_fn: ident,
+        $overflowing_assign_fn: ident,
+        $wrapping: ident,
+        $wrapping_fn: ident,
+        $wrapping_assign: ident,
+        $wrapping_assign_fn: ident,
+        $checked: ident,
+        $checked_fn: ident
+    ) => {
+        impl<const LIMBS: usize> $overflowing<$t<LIMBS>> for $t<LIMBS> {
+            type Output = $t<LIMBS>;
+
+            #[doc = concat!("Computes the ", $name, " of two [`", stringify!($t), "`]s.")]
+            ///
+            /// Returns a tuple containing the result and a boolean indicating whether an
+            /// arithmetic overflow occurred. If an overflow occurred, then the wrapped value is
+            /// returned.
+            ///
+            /// # Worst-case complexity
+            #[doc = $time]
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+            ///
+            /// # Examples
+            #[doc = concat!("See [here](crate::fixed::arithmetic::", $module, "#overflowing).")]
+            #[inline]
+            fn $overflowing_fn(mut self, other: $t<LIMBS>) -> ($t<LIMBS>, bool) {
+                let overflow = self.$overflowing_assign_fn(other);
+                (self, overflow)
+            }
+        }
+
+        impl<const LIMBS: usize> $wrapping<$t<LIMBS>> for $t<LIMBS> {
+            type Output = $t<LIMBS>;
+
+            #[doc = concat!("Computes the ", $name, " of two [`", stringify!($t), "`]s,")]
+            /// wrapping around at the boundary of the type.
+            ///
+            /// # Worst-case complexity
+            #[doc = $time]
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+            ///
+            /// # Examples
+            #[doc = concat!("See [here](crate::fixed::arithmetic::", $module, "#wrapping).")]
+            #[inline]
+            fn $wrapping_fn(mut self, other: $t<LIMBS>) -> $t<LIMBS> {
+                self.$overflowing_assign_fn(other);
+                self
+            }
+        }
+
+        impl<const LIMBS: usize> $wrapping_assign<$t<LIMBS>> for $t<LIMBS> {
+            #[doc = concat!("Computes the ", $name, " of two [`", stringify!($t), "`]s")]
+            /// in place, wrapping around at the boundary of the type.
+            ///
+            /// # Worst-case complexity
+            #[doc = $time]
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+            ///
+            /// # Examples
+            #[doc = concat!("See [here](crate::fixed::arithmetic::", $module, "#wrapping).")]
+            #[inline]
+            fn $wrapping_assign_fn(&mut self, other: $t<LIMBS>) {
+                self.$overflowing_assign_fn(other);
+            }
+        }
+
+        impl<const LIMBS: usize> $checked<$t<LIMBS>> for $t<LIMBS> {
+            type Output = $t<LIMBS>;
+
+            #[doc = concat!("Computes the ", $name, " of two [`", stringify!($t), "`]s,")]
+            /// returning `None` if there is no valid result.
+            ///
+            /// # Worst-case complexity
+            #[doc = $time]
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+            ///
+            /// # Examples
+            #[doc = concat!("See [here](crate::fixed::arithmetic::", $module, "#checked).")]
+            #[inline]
+            fn $checked_fn(mut self, other: $t<LIMBS>) -> Option<$t<LIMBS>> {
+                if self.$overflowing_assign_fn(other) {
+                    None
+                } else {
+                    Some(self)
+                }
+            }
+        }
+
+        impl<const LIMBS: usize> $std<$t<LIMBS>> for $t<LIMBS> {
+            type Output = $t<LIMBS>;
+
+            #[doc = concat!("Computes the ", $name, " of two [`", stringify!($t), "`]s.")]
+            ///
+            /// # Worst-case complexity
+            #[doc = $time]
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+            ///
+            /// # Panics
+            /// Panics if the result is not representable.
+            ///
+            /// # Examples
+            #[doc = concat!("See [here](crate::fixed::arithmetic::", $module, "#operators).")]
+            #[inline]
+            fn $std_fn(mut self, other: $t<LIMBS>) -> $t<LIMBS> {
+                self.$std_assign_fn(other);
+                self
+            }
+        }
+
+        impl<const LIMBS: usize> $std_assign<$t<LIMBS>> for $t<LIMBS> {
+            #[doc = concat!("Computes the ", $name, " of two [`", stringify!($t), "`]s")]
+            /// in place.
+            ///
+            /// # Worst-case complexity
+            #[doc = $time]
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+            ///
+            /// # Panics
+            /// Panics if the result is not representable.
+            ///
+            /// # Examples
+            #[doc = concat!("See [here](crate::fixed::arithmetic::", $module, "#operators).")]
+            fn $std_assign_fn(&mut self, other: $t<LIMBS>) {
+                if self.$overflowing_assign_fn(other) {
+                    panic!("{} {} overflow", stringify!($t), $name);
+                }
+            }
+        }
+    };
+}
+
+/// Addition and subtraction of [`Uint`](crate::fixed::Uint)s and [`Int`](crate::fixed::Int)s,
+/// with standard, wrapping, overflowing, checked, and saturating semantics.
+///
+/// # operators
+/// ```
+/// use malachite_base::num::basic::traits::{One, Two};
+/// use malachite_nz::fixed::{I256, U256};
+///
+/// assert_eq!(U256::from(3u32) + U256::TWO, U256::from(5u32));
+/// assert_eq!(U256::from(3u32) - U256::TWO, U256::ONE);
+/// assert_eq!(I256::TWO - I256::from(5), I256::from(-3));
+///
+/// let mut x = U256::ONE;
+/// x += U256::TWO;
+/// x -= U256::ONE;
+/// assert_eq!(x, U256::TWO);
+/// ```
+///
+/// # overflowing
+/// ```
+/// use malachite_base::comparison::traits::{Max, Min};
+/// use malachite_base::num::arithmetic::traits::{OverflowingAdd, OverflowingSub};
+/// use malachite_base::num::basic::traits::{One, Zero};
+/// use malachite_nz::fixed::{I256, U256};
+///
+/// assert_eq!(U256::MAX.overflowing_add(U256::ONE), (U256::ZERO, true));
+/// assert_eq!(U256::ZERO.overflowing_sub(U256::ONE), (U256::MAX, true));
+/// assert_eq!(I256::MAX.overflowing_add(I256::ONE), (I256::MIN, true));
+/// assert_eq!(I256::ZERO.overflowing_sub(I256::ONE), (I256::from(-1), false));
+/// ```
+///
+/// # wrapping
+/// ```
+/// use malachite_base::comparison::traits::{Max, Min};
+/// use malachite_base::num::arithmetic::traits::{WrappingAdd, WrappingSub};
+/// use malachite_base::num::basic::traits::{One, Two};
+/// use malachite_nz::fixed::{I256, U256};
+///
+/// assert_eq!(U256::MAX.wrapping_add(U256::TWO), U256::ONE);
+/// assert_eq!(U256::ONE.wrapping_sub(U256::TWO), U256::MAX);
+/// assert_eq!(I256::MIN.wrapping_sub(I256::ONE), I256::MAX);
+/// ```
+///
+/// # checked
+/// ```
+/// use malachite_base::comparison::traits::Max;
+/// use malachite_base::num::arithmetic::traits::{CheckedAdd, CheckedSub};
+/// use malachite_base::num::basic::traits::{One, Two};
+/// use malachite_nz::fixed::{I256, U256};
+///
+/// assert_eq!(U256::MAX.checked_add(U256::ONE), None);
+/// assert_eq!(U256::TWO.checked_sub(U256::ONE), Some(U256::ONE));
+/// assert_eq!(I256::ONE.checked_sub(I256::TWO), Some(I256::from(-1)));
+/// ```
+///
+/// # saturating
+/// ```
+/// use malachite_base::comparison::traits::{Max, Min};
+/// use malachite_base::num::arithmetic::traits::{SaturatingAdd, SaturatingSub};
+/// use malachite_base::num::basic::traits::{One, Two, Zero};
+/// use malachite_nz::fixed::{I256, U256};
+///
+/// assert_eq!(U256::MAX.saturating_add(U256::ONE), U256::MAX);
+/// assert_eq!(U256::ONE.saturating_sub(U256::TWO), U256::ZERO);
+/// assert_eq!(I256::MIN.saturating_sub(I256::ONE), I256::MIN);
+/// ```
+pub mod add;
+/// Division of [`Uint`](crate::fixed::Uint)s and [`Int`](crate::fixed::Int)s.
+///
+/// # div_mod
+/// ```
+/// use malachite_base::num::arithmetic::traits::{DivMod, DivRem};
+/// use malachite_nz::fixed::{I256, U256};
+///
+/// assert_eq!(U256::from(23u32).div_mod(U256::from(10u32)), (U256::from(2u32), U256::from(3u32)));
+/// assert_eq!(I256::from(-23).div_mod(I256::from(10)), (I256::from(-3), I256::from(7)));
+/// assert_eq!(I256::from(-23).div_rem(I256::from(10)), (I256::from(-2), I256::from(-3)));
+/// ```
+///
+/// # div
+/// ```
+/// use malachite_base::comparison::traits::{Max, Min};
+/// use malachite_base::num::arithmetic::traits::{CheckedDiv, OverflowingDiv};
+/// use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+/// use malachite_nz::fixed::{I256, U256};
+///
+/// assert_eq!(U256::MAX / U256::MAX, U256::ONE);
+/// assert_eq!(U256::from(23u32) % U256::from(10u32), U256::from(3u32));
+/// assert_eq!(I256::from(-23) / I256::from(10), I256::from(-2));
+/// assert_eq!(U256::MAX.checked_div(U256::ZERO), None);
+/// assert_eq!(I256::MIN.overflowing_div(I256::NEGATIVE_ONE), (I256::MIN, true));
+/// ```
+pub mod div;
+/// Modular arithmetic on [`Uint`](crate::fixed::Uint)s.
+///
+/// # mod_add
+/// ```
+/// use malachite_base::num::arithmetic::traits::{ModAdd, ModNeg, ModSub};
+/// use malachite_nz::fixed::U256;
+///
+/// let m = U256::from(10u32);
+/// assert_eq!(U256::from(7u32).mod_add(U256::from(5u32), m), U256::from(2u32));
+/// assert_eq!(U256::from(3u32).mod_sub(U256::from(5u32), m), U256::from(8u32));
+/// assert_eq!(U256::from(3u32).mod_neg(m), U256::from(7u32));
+/// ```
+///
+/// # mod_mul
+/// ```
+/// use malachite_base::num::arithmetic::traits::{ModMul, ModPow};
+/// use malachite_nz::fixed::U256;
+///
+/// let m = U256::from(497u32);
+/// assert_eq!(U256::from(123u32).mod_mul(U256::from(456u32), m), U256::from(424u32));
+/// assert_eq!(U256::from(4u32).mod_pow(U256::from(13u32), m), U256::from(445u32));
+/// ```
+pub mod mod_pow;
+/// Multiplication of [`Uint`](crate::fixed::Uint)s and [`Int`](crate::fixed::Int)s, with
+/// standard, wrapping, overflowing, checked, and saturating semantics.
+///
+/// # operators
+/// ```
+/// use malachite_base::num::basic::traits::Two;
+/// use malachite_nz::fixed::{I256, U256};
+///
+/// assert_eq!(U256::from(3u32) * U256::TWO, U256::from(6u32));
+/// assert_eq!(I256::from(-3) * I256::TWO, I256::from(-6));
+///
+/// let mut x = U256::from(3u32);
+/// x *= U256::from(10u32);
+/// assert_eq!(x, U256::from(30u32));
+/// ```
+///
+/// # overflowing
+/// ```
+/// use malachite_base::comparison::traits::Min;
+/// use malachite_base::num::arithmetic::traits::OverflowingMul;
+/// use malachite_base::num::basic::traits::{NegativeOne, One, Two, Zero};
+/// use malachite_nz::fixed::{I256, U256};
+///
+/// assert_eq!(U256::from(3u32).overflowing_mul(U256::TWO), (U256::from(6u32), false));
+/// assert_eq!((U256::ONE << 255).overflowing_mul(U256::TWO), (U256::ZERO, true));
+/// assert_eq!(I256::MIN.overflowing_mul(I256::NEGATIVE_ONE), (I256::MIN, true));
+/// ```
+///
+/// # wrapping
+/// ```
+/// use malachite_base::comparison::traits::Max;
+/// use malachite_base::num::arithmetic::traits::WrappingMul;
+/// use malachite_base::num::basic::traits::One;
+/// use malachite_nz::fixed::U256;
+///
+/// assert_eq!(U256::MAX.wrapping_mul(U256::MAX), U256::ONE);
+/// ```
+///
+/// # checked
+/// ```
+/// use malachite_base::comparison::traits::Max;
+/// use malachite_base::num::arithmetic::traits::CheckedMul;
+/// use malachite_base::num::basic::traits::Two;
+/// use malachite_nz::fixed::U256;
+///
+/// assert_eq!(U256::MAX.checked_mul(U256::TWO), None);
+/// assert_eq!(U256::TWO.checked_mul(U256::TWO), Some(U256::from(4u32)));
+/// ```
+///
+/// # saturating
+/// ```
+/// use malachite_base::comparison::traits::{Max, Min};
+/// use malachite_base::num::arithmetic::traits::SaturatingMul;
+/// use malachite_base::num::basic::traits::Two;
+/// use malachite_nz::fixed::{I256, U256};
+///
+/// assert_eq!(U256::MAX.saturating_mul(U256::TWO), U256::MAX);
+/// assert_eq!(I256::MIN.saturating_mul(I256::TWO), I256::MIN);
+/// assert_eq!(I256::MIN.saturating_mul(I256::from(-2)), I256::MAX);
+/// ```
+pub mod mul;
+/// Negation and absolute values of [`Uint`](crate::fixed::Uint)s and
+/// [`Int`](crate::fixed::Int)s.
+///
+/// # neg
+/// ```
+/// use malachite_base::comparison::traits::{Max, Min};
+/// use malachite_base::num::arithmetic::traits::{
+///     CheckedNeg, OverflowingAbs, UnsignedAbs, WrappingNeg,
+/// };
+/// use malachite_base::num::basic::traits::{One, Zero};
+/// use malachite_nz::fixed::{I256, U256};
+///
+/// assert_eq!(-I256::from(5), I256::from(-5));
+/// assert_eq!(U256::ONE.wrapping_neg(), U256::MAX);
+/// assert_eq!(U256::ZERO.checked_neg(), Some(U256::ZERO));
+/// assert_eq!(U256::ONE.checked_neg(), None);
+/// assert_eq!(I256::MIN.overflowing_abs(), (I256::MIN, true));
+/// assert_eq!(I256::from(-5).unsigned_abs(), U256::from(5u32));
+/// ```
+pub mod neg;
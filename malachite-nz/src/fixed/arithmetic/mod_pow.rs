@@ -0,0 +1,301 @@
+use crate::fixed::arithmetic::div::FixedDivisor;
+use crate::fixed::Uint;
+use crate::natural::arithmetic::add::limbs_slice_add_same_length_in_place_left;
+use crate::natural::arithmetic::mul::limb::limbs_mul_limb_to_out;
+use crate::natural::arithmetic::sub::limbs_sub_same_length_in_place_left;
+use crate::natural::comparison::cmp::limbs_cmp_same_length;
+use malachite_base::num::arithmetic::traits::{
+    ModAdd, ModAddAssign, ModMul, ModMulAssign, ModNeg, ModNegAssign, ModPow, ModPowAssign, ModSub,
+    ModSubAssign, OverflowingAddAssign, OverflowingSubAssign, WrappingAddAssign, WrappingSubAssign,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+use std::cmp::Ordering;
+
+impl<const LIMBS: usize> ModAdd<Uint<LIMBS>, Uint<LIMBS>> for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Adds two [`Uint`]s modulo a third [`Uint`] $m$. Assumes the inputs are already reduced
+    /// modulo $m$.
+    ///
+    /// $f(x, y, m) = z$, where $x, y, z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::mod_pow#mod_add).
+    #[inline]
+    fn mod_add(mut self, other: Uint<LIMBS>, m: Uint<LIMBS>) -> Uint<LIMBS> {
+        self.mod_add_assign(other, m);
+        self
+    }
+}
+
+impl<const LIMBS: usize> ModAddAssign<Uint<LIMBS>, Uint<LIMBS>> for Uint<LIMBS> {
+    /// Adds two [`Uint`]s modulo a third [`Uint`] $m$, in place. Assumes the inputs are already
+    /// reduced modulo $m$.
+    ///
+    /// $x \gets z$, where $x, y, z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::mod_pow#mod_add).
+    #[inline]
+    fn mod_add_assign(&mut self, other: Uint<LIMBS>, m: Uint<LIMBS>) {
+        if self.overflowing_add_assign(other) || *self >= m {
+            self.wrapping_sub_assign(m);
+        }
+    }
+}
+
+impl<const LIMBS: usize> ModSub<Uint<LIMBS>, Uint<LIMBS>> for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Subtracts two [`Uint`]s modulo a third [`Uint`] $m$. Assumes the inputs are already
+    /// reduced modulo $m$.
+    ///
+    /// $f(x, y, m) = z$, where $x, y, z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::mod_pow#mod_add).
+    #[inline]
+    fn mod_sub(mut self, other: Uint<LIMBS>, m: Uint<LIMBS>) -> Uint<LIMBS> {
+        self.mod_sub_assign(other, m);
+        self
+    }
+}
+
+impl<const LIMBS: usize> ModSubAssign<Uint<LIMBS>, Uint<LIMBS>> for Uint<LIMBS> {
+    /// Subtracts two [`Uint`]s modulo a third [`Uint`] $m$, in place. Assumes the inputs are
+    /// already reduced modulo $m$.
+    ///
+    /// $x \gets z$, where $x, y, z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::mod_pow#mod_add).
+    #[inline]
+    fn mod_sub_assign(&mut self, other: Uint<LIMBS>, m: Uint<LIMBS>) {
+        if self.overflowing_sub_assign(other) {
+            self.wrapping_add_assign(m);
+        }
+    }
+}
+
+impl<const LIMBS: usize> ModNeg<Uint<LIMBS>> for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Negates a [`Uint`] modulo another [`Uint`] $m$. Assumes the input is already reduced
+    /// modulo $m$.
+    ///
+    /// $f(x, m) = y$, where $x, y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::mod_pow#mod_add).
+    #[inline]
+    fn mod_neg(mut self, m: Uint<LIMBS>) -> Uint<LIMBS> {
+        self.mod_neg_assign(m);
+        self
+    }
+}
+
+impl<const LIMBS: usize> ModNegAssign<Uint<LIMBS>> for Uint<LIMBS> {
+    /// Negates a [`Uint`] modulo another [`Uint`] $m$, in place. Assumes the input is already
+    /// reduced modulo $m$.
+    ///
+    /// $x \gets y$, where $x, y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::mod_pow#mod_add).
+    #[inline]
+    fn mod_neg_assign(&mut self, m: Uint<LIMBS>) {
+        if *self != Uint::ZERO {
+            let mut neg = m;
+            neg.wrapping_sub_assign(*self);
+            *self = neg;
+        }
+    }
+}
+
+impl<const LIMBS: usize> ModMul<Uint<LIMBS>, Uint<LIMBS>> for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Multiplies two [`Uint`]s modulo a third [`Uint`] $m$. Assumes the inputs are already
+    /// reduced modulo $m$.
+    ///
+    /// $f(x, y, m) = z$, where $x, y, z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::mod_pow#mod_mul).
+    #[inline]
+    fn mod_mul(mut self, other: Uint<LIMBS>, m: Uint<LIMBS>) -> Uint<LIMBS> {
+        self.mod_mul_assign(other, m);
+        self
+    }
+}
+
+impl<const LIMBS: usize> ModMulAssign<Uint<LIMBS>, Uint<LIMBS>> for Uint<LIMBS> {
+    /// Multiplies two [`Uint`]s modulo a third [`Uint`] $m$, in place. Assumes the inputs are
+    /// already reduced modulo $m$.
+    ///
+    /// $x \gets z$, where $x, y, z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// The product is accumulated one limb of `other` at a time and reduced after every limb, so
+    /// that no double-width intermediate value is needed.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::mod_pow#mod_mul).
+    fn mod_mul_assign(&mut self, other: Uint<LIMBS>, m: Uint<LIMBS>) {
+        let d = FixedDivisor::new(&m.0).unwrap_or_else(|| panic!("division by zero"));
+        let ds = d.limbs();
+        let len = ds.len();
+        let xs = &self.0[..len];
+        let mut rs = [0; LIMBS];
+        let mut ts = [0; LIMBS];
+        let rs = &mut rs[..len];
+        let ts = &mut ts[..len];
+        for &y in other.0[..other.significant_limb_count()].iter().rev() {
+            // r <- r * B mod m
+            d.step(rs, 0);
+            if y == 0 {
+                continue;
+            }
+            // t <- x * y mod m. Since x < m, the top `len` limbs of x * y are less than m.
+            let t_hi = limbs_mul_limb_to_out(ts, xs, y);
+            let t_lo = ts[0];
+            ts.copy_within(1.., 0);
+            ts[len - 1] = t_hi;
+            d.step(ts, t_lo);
+            // r <- r + t mod m
+            if limbs_slice_add_same_length_in_place_left(rs, ts)
+                || limbs_cmp_same_length(rs, ds) != Ordering::Less
+            {
+                limbs_sub_same_length_in_place_left(rs, ds);
+            }
+        }
+        self.0 = [0; LIMBS];
+        self.0[..len].copy_from_slice(rs);
+    }
+}
+
+impl<const LIMBS: usize> ModPow<Uint<LIMBS>, Uint<LIMBS>> for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Raises a [`Uint`] to a [`Uint`] power modulo a third [`Uint`] $m$. Assumes the input is
+    /// already reduced modulo $m$.
+    ///
+    /// $f(x, n, m) = y$, where $x, y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::mod_pow#mod_mul).
+    #[inline]
+    fn mod_pow(mut self, exp: Uint<LIMBS>, m: Uint<LIMBS>) -> Uint<LIMBS> {
+        self.mod_pow_assign(exp, m);
+        self
+    }
+}
+
+impl<const LIMBS: usize> ModPowAssign<Uint<LIMBS>, Uint<LIMBS>> for Uint<LIMBS> {
+    /// Raises a [`Uint`] to a [`Uint`] power modulo a third [`Uint`] $m$, in place. Assumes the
+    /// input is already reduced modulo $m$.
+    ///
+    /// $x \gets y$, where $x, y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::mod_pow#mod_mul).
+    fn mod_pow_assign(&mut self, exp: Uint<LIMBS>, m: Uint<LIMBS>) {
+        if m == Uint::ZERO {
+            panic!("division by zero");
+        }
+        let x = *self;
+        *self = if m == Uint::ONE {
+            Uint::ZERO
+        } else {
+            Uint::ONE
+        };
+        for i in (0..exp.significant_bits()).rev() {
+            self.mod_mul_assign(*self, m);
+            if exp.get_bit(i) {
+                self.mod_mul_assign(x, m);
+            }
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use crate::fixed::{Int, Uint};
+use crate::natural::arithmetic::add_mul::limbs_slice_add_mul_limb_same_length_in_place_left;
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::arithmetic::traits::{
+    CheckedMul, OverflowingMul, OverflowingMulAssign, SaturatingMul, SaturatingMulAssign,
+    UnsignedAbs, WrappingMul, WrappingMulAssign, WrappingNegAssign,
+};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use std::ops::{Mul, MulAssign};
+
+impl<const LIMBS: usize> OverflowingMulAssign<Uint<LIMBS>> for Uint<LIMBS> {
+    /// Multiplies a [`Uint`] by a [`Uint`] in place.
+    ///
+    /// Returns a boolean indicating whether an arithmetic overflow occurred. If an overflow
+    /// occurred, then the wrapped value is assigned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::mul#overflowing).
+    fn overflowing_mul_assign(&mut self, other: Uint<LIMBS>) -> bool {
+        let mut out = [0; LIMBS];
+        let mut overflow = false;
+        for (i, &y) in other.0.iter().enumerate() {
+            if y == 0 {
+                continue;
+            }
+            // Any nonzero product x_j * y_i with i + j >= LIMBS lands outside the result.
+            if self.0[LIMBS - i..].iter().any(|&x| x != 0) {
+                overflow = true;
+            }
+            if limbs_slice_add_mul_limb_same_length_in_place_left(
+                &mut out[i..],
+                &self.0[..LIMBS - i],
+                y,
+            ) != 0
+            {
+                overflow = true;
+            }
+        }
+        self.0 = out;
+        overflow
+    }
+}
+
+impl<const LIMBS: usize> OverflowingMulAssign<Int<LIMBS>> for Int<LIMBS> {
+    /// Multiplies an [`Int`] by an [`Int`] in place.
+    ///
+    /// Returns a boolean indicating whether an arithmetic overflow occurred. If an overflow
+    /// occurred, then the wrapped value is assigned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::mul#overflowing).
+    fn overflowing_mul_assign(&mut self, other: Int<LIMBS>) -> bool {
+        let negative = self.is_negative() != other.is_negative();
+        let (mut product, mut overflow) = self.unsigned_abs().overflowing_mul(other.unsigned_abs());
+        if negative {
+            overflow |= product > Int::<LIMBS>::MIN.0;
+            product.wrapping_neg_assign();
+        } else {
+            overflow |= product.0[LIMBS - 1].get_highest_bit();
+        }
+        self.0 = product;
+        overflow
+    }
+}
+
+impl_via_overflowing!(
+    Uint,
+    "product",
+    "mul",
+    "$T(n) = O(n^2)$",
+    Mul,
+    mul,
+    MulAssign,
+    mul_assign,
+    OverflowingMul,
+    overflowing_mul,
+    overflowing_mul_assign,
+    WrappingMul,
+    wrapping_mul,
+    WrappingMulAssign,
+    wrapping_mul_assign,
+    CheckedMul,
+    checked_mul
+);
+impl_via_overflowing!(
+    Int,
+    "product",
+    "mul",
+    "$T(n) = O(n^2)$",
+    Mul,
+    mul,
+    MulAssign,
+    mul_assign,
+    OverflowingMul,
+    overflowing_mul,
+    overflowing_mul_assign,
+    WrappingMul,
+    wrapping_mul,
+    WrappingMulAssign,
+    wrapping_mul_assign,
+    CheckedMul,
+    checked_mul
+);
+
+impl<const LIMBS: usize> SaturatingMul<Uint<LIMBS>> for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Multiplies two [`Uint`]s, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::mul#saturating).
+    #[inline]
+    fn saturating_mul(mut self, other: Uint<LIMBS>) -> Uint<LIMBS> {
+        self.saturating_mul_assign(other);
+        self
+    }
+}
+
+impl<const LIMBS: usize> SaturatingMulAssign<Uint<LIMBS>> for Uint<LIMBS> {
+    /// Multiplies a [`Uint`] by a [`Uint`] in place, saturating at the numeric bounds instead of
+    /// overflowing.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::mul#saturating).
+    #[inline]
+    fn saturating_mul_assign(&mut self, other: Uint<LIMBS>) {
+        if self.overflowing_mul_assign(other) {
+            *self = Uint::MAX;
+        }
+    }
+}
+
+impl<const LIMBS: usize> SaturatingMul<Int<LIMBS>> for Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    /// Multiplies two [`Int`]s, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::mul#saturating).
+    #[inline]
+    fn saturating_mul(mut self, other: Int<LIMBS>) -> Int<LIMBS> {
+        self.saturating_mul_assign(other);
+        self
+    }
+}
+
+impl<const LIMBS: usize> SaturatingMulAssign<Int<LIMBS>> for Int<LIMBS> {
+    /// Multiplies an [`Int`] by an [`Int`] in place, saturating at the numeric bounds instead of
+    /// overflowing.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::mul#saturating).
+    fn saturating_mul_assign(&mut self, other: Int<LIMBS>) {
+        let negative = self.is_negative() != other.is_negative();
+        if self.overflowing_mul_assign(other) {
+            *self = if negative { Int::MIN } else { Int::MAX };
+        }
+    }
+}
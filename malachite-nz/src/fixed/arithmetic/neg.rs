@@ -0,0 +1,548 @@
+use crate::fixed::{Int, Uint};
+use crate::integer::conversion::to_twos_complement_limbs::limbs_twos_complement_in_place;
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::arithmetic::traits::{
+    Abs, AbsAssign, CheckedAbs, CheckedNeg, NegAssign, OverflowingAbs, OverflowingAbsAssign,
+    OverflowingNeg, OverflowingNegAssign, SaturatingAbs, SaturatingAbsAssign, SaturatingNeg,
+    SaturatingNegAssign, UnsignedAbs, WrappingAbs, WrappingAbsAssign, WrappingNeg,
+    WrappingNegAssign,
+};
+use std::ops::Neg;
+
+impl<const LIMBS: usize> OverflowingNegAssign for Uint<LIMBS> {
+    /// Negates a [`Uint`] in place.
+    ///
+    /// Returns a boolean indicating whether an arithmetic overflow occurred, which is the case
+    /// whenever the [`Uint`] is nonzero. If an overflow occurred, then the wrapped value, $2^W-x$,
+    /// is assigned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::neg#neg).
+    #[inline]
+    fn overflowing_neg_assign(&mut self) -> bool {
+        !limbs_twos_complement_in_place(&mut self.0)
+    }
+}
+
+impl<const LIMBS: usize> OverflowingNeg for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Negates a [`Uint`].
+    ///
+    /// Returns a tuple containing the result and a boolean indicating whether an arithmetic
+    /// overflow occurred, which is the case whenever the [`Uint`] is nonzero. If an overflow
+    /// occurred, then the wrapped value, $2^W-x$, is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::neg#neg).
+    #[inline]
+    fn overflowing_neg(mut self) -> (Uint<LIMBS>, bool) {
+        let overflow = self.overflowing_neg_assign();
+        (self, overflow)
+    }
+}
+
+impl<const LIMBS: usize> WrappingNegAssign for Uint<LIMBS> {
+    /// Negates a [`Uint`] in place, wrapping around at the boundary of the type.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::neg#neg).
+    #[inline]
+    fn wrapping_neg_assign(&mut self) {
+        limbs_twos_complement_in_place(&mut self.0);
+    }
+}
+
+impl<const LIMBS: usize> WrappingNeg for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Negates a [`Uint`], wrapping around at the boundary of the type.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::neg#neg).
+    #[inline]
+    fn wrapping_neg(mut self) -> Uint<LIMBS> {
+        self.wrapping_neg_assign();
+        self
+    }
+}
+
+impl<const LIMBS: usize> CheckedNeg for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Negates a [`Uint`], returning `None` if the result is not representable; that is, if the
+    /// [`Uint`] is nonzero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::neg#neg).
+    #[inline]
+    fn checked_neg(self) -> Option<Uint<LIMBS>> {
+        if self.0.iter().all(|&x| x == 0) {
+            Some(self)
+        } else {
+            None
+        }
+    }
+}
+
+impl<const LIMBS: usize> OverflowingNegAssign for Int<LIMBS> {
+    /// Negates an [`Int`] in place.
+    ///
+    /// Returns a boolean indicating whether an arithmetic overflow occurred, which is the case
+    /// only when the [`Int`] is $-2^{W-1}$. In that case the value is left unchanged.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::neg#neg).
+    #[inline]
+    fn overflowing_neg_assign(&mut self) -> bool {
+        let overflow = *self == Int::MIN;
+        self.0.wrapping_neg_assign();
+        overflow
+    }
+}
+
+impl<const LIMBS: usize> OverflowingNeg for Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    /// Negates an [`Int`].
+    ///
+    /// Returns a tuple containing the result and a boolean indicating whether an arithmetic
+    /// overflow occurred, which is the case only when the [`Int`] is $-2^{W-1}$. In that case
+    /// $-2^{W-1}$ is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::neg#neg).
+    #[inline]
+    fn overflowing_neg(mut self) -> (Int<LIMBS>, bool) {
+        let overflow = self.overflowing_neg_assign();
+        (self, overflow)
+    }
+}
+
+impl<const LIMBS: usize> WrappingNegAssign for Int<LIMBS> {
+    /// Negates an [`Int`] in place, wrapping around at the boundary of the type.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::neg#neg).
+    #[inline]
+    fn wrapping_neg_assign(&mut self) {
+        self.0.wrapping_neg_assign();
+    }
+}
+
+impl<const LIMBS: usize> WrappingNeg for Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    /// Negates an [`Int`], wrapping around at the boundary of the type.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::neg#neg).
+    #[inline]
+    fn wrapping_neg(mut self) -> Int<LIMBS> {
+        self.wrapping_neg_assign();
+        self
+    }
+}
+
+impl<const LIMBS: usize> CheckedNeg for Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    /// Negates an [`Int`], returning `None` if the result is not representable; that is, if the
+    /// [`Int`] is $-2^{W-1}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::neg#neg).
+    #[inline]
+    fn checked_neg(self) -> Option<Int<LIMBS>> {
+        let (neg, overflow) = self.overflowing_neg();
+        if overflow {
+            None
+        } else {
+            Some(neg)
+        }
+    }
+}
+
+impl<const LIMBS: usize> SaturatingNegAssign for Int<LIMBS> {
+    /// Negates an [`Int`] in place, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::neg#neg).
+    #[inline]
+    fn saturating_neg_assign(&mut self) {
+        if self.overflowing_neg_assign() {
+            *self = Int::MAX;
+        }
+    }
+}
+
+impl<const LIMBS: usize> SaturatingNeg for Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    /// Negates an [`Int`], saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::neg#neg).
+    #[inline]
+    fn saturating_neg(mut self) -> Int<LIMBS> {
+        self.saturating_neg_assign();
+        self
+    }
+}
+
+impl<const LIMBS: usize> NegAssign for Int<LIMBS> {
+    /// Negates an [`Int`] in place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if the [`Int`] is $-2^{W-1}$.
+    ///
+    /// # Examples
+    /// See [here](super::neg#neg).
+    #[inline]
+    fn neg_assign(&mut self) {
+        if self.overflowing_neg_assign() {
+            panic!("Int negation overflow");
+        }
+    }
+}
+
+impl<const LIMBS: usize> Neg for Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    /// Negates an [`Int`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if the [`Int`] is $-2^{W-1}$.
+    ///
+    /// # Examples
+    /// See [here](super::neg#neg).
+    #[inline]
+    fn neg(mut self) -> Int<LIMBS> {
+        self.neg_assign();
+        self
+    }
+}
+
+impl<const LIMBS: usize> OverflowingAbsAssign for Int<LIMBS> {
+    /// Replaces an [`Int`] with its absolute value.
+    ///
+    /// Returns a boolean indicating whether an arithmetic overflow occurred, which is the case
+    /// only when the [`Int`] is $-2^{W-1}$. In that case the value is left unchanged.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::neg#neg).
+    #[inline]
+    fn overflowing_abs_assign(&mut self) -> bool {
+        self.is_negative() && self.overflowing_neg_assign()
+    }
+}
+
+impl<const LIMBS: usize> OverflowingAbs for Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    /// Takes the absolute value of an [`Int`].
+    ///
+    /// Returns a tuple containing the result and a boolean indicating whether an arithmetic
+    /// overflow occurred, which is the case only when the [`Int`] is $-2^{W-1}$. In that case
+    /// $-2^{W-1}$ is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::neg#neg).
+    #[inline]
+    fn overflowing_abs(mut self) -> (Int<LIMBS>, bool) {
+        let overflow = self.overflowing_abs_assign();
+        (self, overflow)
+    }
+}
+
+impl<const LIMBS: usize> WrappingAbsAssign for Int<LIMBS> {
+    /// Replaces an [`Int`] with its absolute value, wrapping around at the boundary of the type.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::neg#neg).
+    #[inline]
+    fn wrapping_abs_assign(&mut self) {
+        self.overflowing_abs_assign();
+    }
+}
+
+impl<const LIMBS: usize> WrappingAbs for Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    /// Takes the absolute value of an [`Int`], wrapping around at the boundary of the type.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::neg#neg).
+    #[inline]
+    fn wrapping_abs(mut self) -> Int<LIMBS> {
+        self.wrapping_abs_assign();
+        self
+    }
+}
+
+impl<const LIMBS: usize> CheckedAbs for Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    /// Takes the absolute value of an [`Int`], returning `None` if the result is not
+    /// representable; that is, if the [`Int`] is $-2^{W-1}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::neg#neg).
+    #[inline]
+    fn checked_abs(self) -> Option<Int<LIMBS>> {
+        let (abs, overflow) = self.overflowing_abs();
+        if overflow {
+            None
+        } else {
+            Some(abs)
+        }
+    }
+}
+
+impl<const LIMBS: usize> SaturatingAbsAssign for Int<LIMBS> {
+    /// Replaces an [`Int`] with its absolute value, saturating at the numeric bounds instead of
+    /// overflowing.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::neg#neg).
+    #[inline]
+    fn saturating_abs_assign(&mut self) {
+        if self.overflowing_abs_assign() {
+            *self = Int::MAX;
+        }
+    }
+}
+
+impl<const LIMBS: usize> SaturatingAbs for Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    /// Takes the absolute value of an [`Int`], saturating at the numeric bounds instead of
+    /// overflowing.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::neg#neg).
+    #[inline]
+    fn saturating_abs(mut self) -> Int<LIMBS> {
+        self.saturating_abs_assign();
+        self
+    }
+}
+
+impl<const LIMBS: usize> AbsAssign for Int<LIMBS> {
+    /// Replaces an [`Int`] with its absolute value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if the [`Int`] is $-2^{W-1}$.
+    ///
+    /// # Examples
+    /// See [here](super::neg#neg).
+    #[inline]
+    fn abs_assign(&mut self) {
+        if self.overflowing_abs_assign() {
+            panic!("Int absolute value overflow");
+        }
+    }
+}
+
+impl<const LIMBS: usize> Abs for Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    /// Takes the absolute value of an [`Int`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if the [`Int`] is $-2^{W-1}$.
+    ///
+    /// # Examples
+    /// See [here](super::neg#neg).
+    #[inline]
+    fn abs(mut self) -> Int<LIMBS> {
+        self.abs_assign();
+        self
+    }
+}
+
+impl<const LIMBS: usize> UnsignedAbs for Int<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Takes the absolute value of an [`Int`], returning it as a [`Uint`] of the same width. This
+    /// never overflows.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::neg#neg).
+    #[inline]
+    fn unsigned_abs(self) -> Uint<LIMBS> {
+        let mut x = self.0;
+        if self.is_negative() {
+            x.wrapping_neg_assign();
+        }
+        x
+    }
+}
@@ -0,0 +1,79 @@
+use crate::fixed::{Int, Uint};
+use crate::natural::comparison::cmp::limbs_cmp_same_length;
+use std::cmp::Ordering;
+
+impl<const LIMBS: usize> PartialOrd for Uint<LIMBS> {
+    /// Compares two [`Uint`]s.
+    ///
+    /// See the documentation for the [`Ord`] implementation.
+    #[inline]
+    fn partial_cmp(&self, other: &Uint<LIMBS>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const LIMBS: usize> Ord for Uint<LIMBS> {
+    /// Compares two [`Uint`]s.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::Max;
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_nz::fixed::U256;
+    ///
+    /// assert!(U256::ZERO < U256::ONE);
+    /// assert!(U256::MAX > U256::ONE << 128);
+    /// assert!(U256::from(123u32) >= U256::from(123u32));
+    /// ```
+    #[inline]
+    fn cmp(&self, other: &Uint<LIMBS>) -> Ordering {
+        limbs_cmp_same_length(&self.0, &other.0)
+    }
+}
+
+impl<const LIMBS: usize> PartialOrd for Int<LIMBS> {
+    /// Compares two [`Int`]s.
+    ///
+    /// See the documentation for the [`Ord`] implementation.
+    #[inline]
+    fn partial_cmp(&self, other: &Int<LIMBS>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const LIMBS: usize> Ord for Int<LIMBS> {
+    /// Compares two [`Int`]s.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::{Max, Min};
+    /// use malachite_base::num::basic::traits::{NegativeOne, Zero};
+    /// use malachite_nz::fixed::I256;
+    ///
+    /// assert!(I256::NEGATIVE_ONE < I256::ZERO);
+    /// assert!(I256::MIN < I256::NEGATIVE_ONE);
+    /// assert!(I256::MAX > I256::from(-123));
+    /// ```
+    fn cmp(&self, other: &Int<LIMBS>) -> Ordering {
+        // Two's complement values of the same sign compare like their unsigned bit patterns.
+        match (self.is_negative(), other.is_negative()) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            _ => self.0.cmp(&other.0),
+        }
+    }
+}
@@ -0,0 +1,818 @@
+use crate::fixed::Uint;
+use crate::natural::arithmetic::div_exact::limbs_modular_invert_limb;
+use crate::natural::Natural;
+use crate::platform::{DoubleLimb, Limb, SignedLimb};
+use malachite_base::num::arithmetic::traits::{
+    Parity, Pow, PowAssign, PowerOf2, Square, SquareAssign, WrappingNegAssign,
+};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::{SplitInHalf, WrappingFrom};
+#[cfg(feature = "test_build")]
+use std::cell::RefCell;
+use std::fmt::{self, Debug, Formatter};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::slice;
+
+// The functions in this module must not branch on, or index memory by, anything derived from a
+// residue or an exponent. Loop counts may only depend on `LIMBS`. Conditional operations are done
+// by computing every alternative and combining them with masks, which are either 0 or
+// `Limb::MAX`.
+
+#[cfg(feature = "test_build")]
+thread_local! {
+    static TRACE: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+// Records an event in the trace that tests use to check that the sequence of operations does not
+// depend on secret values. Does nothing unless the `test_build` feature is enabled.
+#[inline]
+#[allow(clippy::missing_const_for_fn, unused_variables)]
+fn trace(event: &'static str) {
+    #[cfg(feature = "test_build")]
+    TRACE.with(|trace| trace.borrow_mut().push(event));
+}
+
+/// Returns and clears the events recorded by the constant-time functions on the current thread.
+///
+/// Two computations whose control flow and memory accesses do not depend on secret inputs produce
+/// identical traces.
+#[cfg(feature = "test_build")]
+#[doc(hidden)]
+pub fn take_trace() -> Vec<&'static str> {
+    TRACE.with(|trace| std::mem::take(&mut *trace.borrow_mut()))
+}
+
+const WINDOW_SIZE: u64 = 4;
+
+// Returns `Limb::MAX` if `bit` is 1 and 0 if `bit` is 0.
+#[inline]
+const fn mask_from_bit(bit: Limb) -> Limb {
+    bit.wrapping_neg()
+}
+
+// Returns 1 if `x` is nonzero and 0 otherwise.
+#[inline]
+const fn nonzero_bit(x: Limb) -> Limb {
+    (x | x.wrapping_neg()) >> (Limb::WIDTH - 1)
+}
+
+// Returns `Limb::MAX` if `x == y` and 0 otherwise.
+#[inline]
+const fn eq_mask(x: Limb, y: Limb) -> Limb {
+    mask_from_bit(nonzero_bit(x ^ y) ^ 1)
+}
+
+// Returns `xs` if `mask` is `Limb::MAX` and `ys` if it is 0.
+fn select<const LIMBS: usize>(mask: Limb, xs: &[Limb; LIMBS], ys: &[Limb; LIMBS]) -> [Limb; LIMBS] {
+    let mut out = [0; LIMBS];
+    for ((z, &x), &y) in out.iter_mut().zip(xs.iter()).zip(ys.iter()) {
+        *z = (x & mask) | (y & !mask);
+    }
+    out
+}
+
+// Swaps `xs` and `ys` if `mask` is `Limb::MAX`, and leaves them alone if it is 0.
+fn swap(mask: Limb, xs: &mut [Limb], ys: &mut [Limb]) {
+    for (x, y) in xs.iter_mut().zip(ys.iter_mut()) {
+        let t = (*x ^ *y) & mask;
+        *x ^= t;
+        *y ^= t;
+    }
+}
+
+// Adds `ys & mask` to `xs` and returns the carry, which is 0 or 1.
+fn add_masked(xs: &mut [Limb], ys: &[Limb], mask: Limb) -> Limb {
+    let mut carry = 0;
+    for (x, &y) in xs.iter_mut().zip(ys.iter()) {
+        let (sum, carry_1) = x.overflowing_add(y & mask);
+        let (sum, carry_2) = sum.overflowing_add(carry);
+        *x = sum;
+        carry = Limb::from(carry_1 | carry_2);
+    }
+    carry
+}
+
+// Subtracts `ys` from `xs` and returns the borrow, which is 0 or 1.
+fn sub_in_place(xs: &mut [Limb], ys: &[Limb]) -> Limb {
+    let mut borrow = 0;
+    for (x, &y) in xs.iter_mut().zip(ys.iter()) {
+        let (difference, borrow_1) = x.overflowing_sub(y);
+        let (difference, borrow_2) = difference.overflowing_sub(borrow);
+        *x = difference;
+        borrow = Limb::from(borrow_1 | borrow_2);
+    }
+    borrow
+}
+
+// Negates the two's complement number `xs` if `mask` is `Limb::MAX`, and returns the carry out of
+// the top limb, so that a longer number can be negated a piece at a time.
+fn negate_masked(xs: &mut [Limb], mask: Limb) -> Limb {
+    let mut carry = mask & 1;
+    for x in xs.iter_mut() {
+        let (sum, carry_out) = (*x ^ mask).overflowing_add(carry);
+        *x = sum;
+        carry = Limb::from(carry_out);
+    }
+    carry
+}
+
+// Shifts `xs` right by one bit. The bit shifted into the top is `high_bit`, which is 0 or 1.
+fn shr_1(xs: &mut [Limb], high_bit: Limb) {
+    let len = xs.len();
+    for i in 0..len - 1 {
+        xs[i] = (xs[i] >> 1) | (xs[i + 1] << (Limb::WIDTH - 1));
+    }
+    xs[len - 1] = (xs[len - 1] >> 1) | (high_bit << (Limb::WIDTH - 1));
+}
+
+// Computes `xs - m` if `high` is 1 or `xs >= m`, and `xs` otherwise. `high` is an extra limb on
+// top of `xs`, which is 0 or 1, and $xs + high B^n$ must be less than $2m$.
+fn reduce_once<const LIMBS: usize>(
+    xs: &[Limb; LIMBS],
+    high: Limb,
+    ms: &[Limb; LIMBS],
+) -> [Limb; LIMBS] {
+    let mut difference = *xs;
+    let borrow = sub_in_place(&mut difference, ms);
+    // If `high` is 1, the subtraction's borrow cancels it.
+    select(mask_from_bit(borrow & (high ^ 1)), xs, &difference)
+}
+
+/// An odd modulus $m$, fitting in a [`Uint`], for modular arithmetic that does not leak secret
+/// values through timing.
+///
+/// The [`ModPow`](malachite_base::num::arithmetic::traits::ModPow),
+/// [`ModMul`](malachite_base::num::arithmetic::traits::ModMul), and
+/// [`ModInverse`](malachite_base::num::arithmetic::traits::ModInverse) implementations for
+/// [`Natural`] branch on the values of their inputs and skip leading zeros, so the time they take
+/// depends on those values. The [`ConstantTimeResidue`]s created from a `ConstantTimeModulus` are
+/// stored in Montgomery form in a fixed number of limbs, and their arithmetic functions never
+/// branch on, or index memory by, the values of residues or exponents. The modulus itself is not
+/// considered secret.
+///
+/// Exponentiation uses a fixed window of 4 bits and reads every precomputed power on every step.
+/// Inversion uses Bernstein and Yang's safegcd, running a number of division steps that only
+/// depends on `LIMBS`.
+///
+/// These guarantees concern the code in this module; they cannot account for what the compiler or
+/// processor do with it.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::arithmetic::traits::Pow;
+/// use malachite_nz::fixed::constant_time::ConstantTimeModulus;
+/// use malachite_nz::fixed::U256;
+///
+/// let m = ConstantTimeModulus::new(U256::from(1000000007u32));
+/// let x = m.residue(U256::from(123u32));
+/// let y = m.residue(U256::from(456u32));
+/// assert_eq!(U256::from(x * y + x), U256::from(56211u32));
+/// assert_eq!(U256::from(x.pow(U256::from(1000000006u32))), U256::from(1u32));
+/// assert_eq!(U256::from(x.invert().unwrap() * x), U256::from(1u32));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ConstantTimeModulus<const LIMBS: usize> {
+    m: Uint<LIMBS>,
+    // $-m^{-1} \mod B$
+    m_inv: Limb,
+    // $R \mod m$ and $R^2 \mod m$, where $R = 2^W$.
+    one: Uint<LIMBS>,
+    r_squared: Uint<LIMBS>,
+}
+
+/// An element of $\Z/m\Z$, created from a [`ConstantTimeModulus`], whose arithmetic does not
+/// branch on or index memory by its value.
+///
+/// A `ConstantTimeResidue` supports `+`, `-`, `*`, and unary `-`, along with
+/// [`Square`](malachite_base::num::arithmetic::traits::Square),
+/// [`Pow`](malachite_base::num::arithmetic::traits::Pow) with a [`Uint`] exponent, and
+/// [`invert`](ConstantTimeResidue::invert). Combining residues from different moduli panics. To
+/// get the value back, convert the residue to a [`Uint`].
+///
+/// Comparing residues with `==` takes the same time whether or not they are equal. Formatting a
+/// residue with [`Debug`] does not run in constant time.
+///
+/// # Examples
+/// ```
+/// use malachite_nz::fixed::constant_time::ConstantTimeModulus;
+/// use malachite_nz::fixed::U256;
+///
+/// let m = ConstantTimeModulus::new(U256::from(11u32));
+/// let x = m.residue(U256::from(7u32));
+/// assert_eq!(U256::from(x + m.residue(U256::from(5u32))), U256::from(1u32));
+/// assert_eq!(U256::from(-x), U256::from(4u32));
+/// ```
+#[derive(Clone, Copy)]
+pub struct ConstantTimeResidue<'a, const LIMBS: usize> {
+    modulus: &'a ConstantTimeModulus<LIMBS>,
+    // $xR \mod m$
+    xs: [Limb; LIMBS],
+}
+
+impl<const LIMBS: usize> ConstantTimeModulus<LIMBS> {
+    /// Creates a [`ConstantTimeModulus`] from an odd [`Uint`] $m$.
+    ///
+    /// The precomputation branches on $m$, which is not considered secret.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if $m$ is even.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::fixed::constant_time::ConstantTimeModulus;
+    /// use malachite_nz::fixed::U256;
+    ///
+    /// let m = ConstantTimeModulus::new(U256::from(11u32));
+    /// assert_eq!(m.modulus(), U256::from(11u32));
+    /// ```
+    pub fn new(m: Uint<LIMBS>) -> ConstantTimeModulus<LIMBS> {
+        assert!(m.0[0].odd(), "constant-time modulus must be odd");
+        let mut m_inv = limbs_modular_invert_limb(m.0[0]);
+        m_inv.wrapping_neg_assign();
+        let m_natural = Natural::from(m);
+        let reduced_power =
+            |pow: u64| Uint::try_from(&(Natural::power_of_2(pow) % &m_natural)).unwrap();
+        ConstantTimeModulus {
+            m,
+            m_inv,
+            one: reduced_power(Uint::<LIMBS>::WIDTH),
+            r_squared: reduced_power(Uint::<LIMBS>::WIDTH << 1),
+        }
+    }
+
+    /// Returns the modulus $m$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::fixed::constant_time::ConstantTimeModulus;
+    /// use malachite_nz::fixed::U256;
+    ///
+    /// assert_eq!(ConstantTimeModulus::new(U256::from(11u32)).modulus(), U256::from(11u32));
+    /// ```
+    #[inline]
+    pub const fn modulus(&self) -> Uint<LIMBS> {
+        self.m
+    }
+
+    /// Creates the [`ConstantTimeResidue`] of a [`Uint`] modulo $m$. The [`Uint`] does not need to
+    /// be reduced.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::fixed::constant_time::ConstantTimeModulus;
+    /// use malachite_nz::fixed::U256;
+    ///
+    /// let m = ConstantTimeModulus::new(U256::from(11u32));
+    /// assert_eq!(U256::from(m.residue(U256::from(123u32))), U256::from(2u32));
+    /// ```
+    pub fn residue(&self, x: Uint<LIMBS>) -> ConstantTimeResidue<'_, LIMBS> {
+        ConstantTimeResidue {
+            modulus: self,
+            xs: self.mul_limbs(&x.0, &self.r_squared.0),
+        }
+    }
+
+    /// Returns the [`ConstantTimeResidue`] 0.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::fixed::constant_time::ConstantTimeModulus;
+    /// use malachite_nz::fixed::U256;
+    ///
+    /// let m = ConstantTimeModulus::new(U256::from(11u32));
+    /// assert_eq!(U256::from(m.zero()), U256::ZERO);
+    /// ```
+    #[inline]
+    pub const fn zero(&self) -> ConstantTimeResidue<'_, LIMBS> {
+        ConstantTimeResidue {
+            modulus: self,
+            xs: [0; LIMBS],
+        }
+    }
+
+    /// Returns the [`ConstantTimeResidue`] 1. If $m$ is 1, this is the same as 0.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::fixed::constant_time::ConstantTimeModulus;
+    /// use malachite_nz::fixed::U256;
+    ///
+    /// let m = ConstantTimeModulus::new(U256::from(11u32));
+    /// assert_eq!(U256::from(m.one()), U256::ONE);
+    /// ```
+    #[inline]
+    pub const fn one(&self) -> ConstantTimeResidue<'_, LIMBS> {
+        ConstantTimeResidue {
+            modulus: self,
+            xs: self.one.0,
+        }
+    }
+
+    fn check_same(&self, other: &ConstantTimeModulus<LIMBS>) {
+        assert_eq!(self.m, other.m, "residues have different moduli");
+    }
+
+    // Computes $xyR^{-1} \mod m$, where $x < R$ and $y < m$, using coarsely integrated operand
+    // scanning.
+    //
+    // # Worst-case complexity
+    // $T(n) = O(n^2)$
+    //
+    // $M(n) = O(1)$
+    //
+    // where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    //
+    // This is Algorithm 2 (CIOS) from Koç, Acar, and Kaliski, _Analyzing and Comparing Montgomery
+    // Multiplication Algorithms_.
+    fn mul_limbs(&self, xs: &[Limb; LIMBS], ys: &[Limb; LIMBS]) -> [Limb; LIMBS] {
+        trace("mul");
+        let ms = &self.m.0;
+        let mut ts = [0; LIMBS];
+        let mut t_high = 0;
+        for &x in xs {
+            // ts += x * ys
+            let mut carry = 0;
+            for (t, &y) in ts.iter_mut().zip(ys.iter()) {
+                let (hi, lo) = (DoubleLimb::from(*t)
+                    + DoubleLimb::from(x) * DoubleLimb::from(y)
+                    + DoubleLimb::from(carry))
+                .split_in_half();
+                *t = lo;
+                carry = hi;
+            }
+            let (t_higher, t_high_lo) =
+                (DoubleLimb::from(t_high) + DoubleLimb::from(carry)).split_in_half();
+            // ts = (ts + u * ms) / B, where u is chosen so that the division is exact
+            let u = ts[0].wrapping_mul(self.m_inv);
+            let (mut carry, _) = (DoubleLimb::from(ts[0])
+                + DoubleLimb::from(u) * DoubleLimb::from(ms[0]))
+            .split_in_half();
+            for j in 1..LIMBS {
+                let (hi, lo) = (DoubleLimb::from(ts[j])
+                    + DoubleLimb::from(u) * DoubleLimb::from(ms[j])
+                    + DoubleLimb::from(carry))
+                .split_in_half();
+                ts[j - 1] = lo;
+                carry = hi;
+            }
+            let (hi, lo) = (DoubleLimb::from(t_high_lo) + DoubleLimb::from(carry)).split_in_half();
+            ts[LIMBS - 1] = lo;
+            t_high = t_higher + hi;
+        }
+        reduce_once(&ts, t_high, ms)
+    }
+
+    fn add_limbs(&self, xs: &[Limb; LIMBS], ys: &[Limb; LIMBS]) -> [Limb; LIMBS] {
+        trace("add");
+        let mut sum = *xs;
+        let carry = add_masked(&mut sum, ys, Limb::MAX);
+        reduce_once(&sum, carry, &self.m.0)
+    }
+
+    fn sub_limbs(&self, xs: &[Limb; LIMBS], ys: &[Limb; LIMBS]) -> [Limb; LIMBS] {
+        trace("sub");
+        let mut difference = *xs;
+        let borrow = sub_in_place(&mut difference, ys);
+        add_masked(&mut difference, &self.m.0, mask_from_bit(borrow));
+        difference
+    }
+
+    // Computes $x/2 \mod m$.
+    fn halve_limbs(&self, xs: &mut [Limb; LIMBS]) {
+        trace("halve");
+        let odd_mask = mask_from_bit(xs[0] & 1);
+        let carry = add_masked(xs, &self.m.0, odd_mask);
+        shr_1(xs, carry);
+    }
+
+    // Computes $x^{-1} \mod m$ for a plain (not Montgomery) $x < m$, with a flag that is 1 if the
+    // inverse exists and 0 otherwise. If the inverse does not exist, the first value is
+    // meaningless.
+    //
+    // The state of the algorithm is $(\delta, f, g, d, e)$, starting at $(1, m, x, 0, 1)$. $f$ and
+    // $g$ are signed and kept in `LIMBS + 1` limbs, as a `[Limb; LIMBS]` and a separate top limb;
+    // $d$ and $e$ are kept reduced modulo $m$, and
+    // satisfy $f \equiv dx$ and $g \equiv ex \pmod m$. Each division step is
+    // - $(1 - \delta, g, (g - f)/2, e, (e - d)/2)$ if $\delta > 0$ and $g$ is odd,
+    // - $(1 + \delta, f, (g + f)/2, d, (e + d)/2)$ if $\delta \leq 0$ and $g$ is odd, and
+    // - $(1 + \delta, f, g/2, d, e/2)$ if $g$ is even.
+    //
+    // After enough steps $g$ is 0 and $f = \pm\gcd(m, x)$. 0 is not invertible, even when $m$ is 1.
+    //
+    // # Worst-case complexity
+    // $T(n) = O(n^2)$
+    //
+    // $M(n) = O(n)$
+    //
+    // where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    //
+    // This is the constant-time inversion from Bernstein and Yang, _Fast constant-time gcd
+    // computation and modular inversion_.
+    fn invert_limbs(&self, xs: &[Limb; LIMBS]) -> ([Limb; LIMBS], Limb) {
+        let bits = Uint::<LIMBS>::WIDTH;
+        // Theorem 11.2 of the paper bounds the number of division steps needed when $f$ and $g$
+        // have at most `bits` bits.
+        let iterations = if bits < 46 {
+            (49 * bits + 80) / 17
+        } else {
+            (49 * bits + 57) / 17
+        };
+        let mut delta: SignedLimb = 1;
+        let mut fs = self.m.0;
+        let mut f_high: Limb = 0;
+        let mut gs = *xs;
+        let mut g_high: Limb = 0;
+        let mut ds = [0; LIMBS];
+        let mut es = [0; LIMBS];
+        es[0] = 1;
+        // If $m$ is 1, $e$ must start at 1 mod 1.
+        es = reduce_once(&es, 0, &self.m.0);
+        for _ in 0..iterations {
+            trace("divstep");
+            let g_odd = gs[0] & 1;
+            let delta_positive = Limb::wrapping_from(delta.wrapping_neg()) >> (Limb::WIDTH - 1);
+            let swap_mask = mask_from_bit(delta_positive & g_odd);
+            // If swapping, $(\delta, f, g, d, e) \gets (-\delta, g, -f, e, -d)$.
+            let delta_mask = SignedLimb::wrapping_from(swap_mask);
+            delta = (delta ^ delta_mask).wrapping_sub(delta_mask);
+            swap(swap_mask, &mut fs, &mut gs);
+            swap(swap_mask, slice::from_mut(&mut f_high), slice::from_mut(&mut g_high));
+            let carry = negate_masked(&mut gs, swap_mask);
+            g_high = (g_high ^ swap_mask).wrapping_add(carry);
+            swap(swap_mask, &mut ds, &mut es);
+            let negative_es = self.sub_limbs(&[0; LIMBS], &es);
+            es = select(swap_mask, &negative_es, &es);
+            // If $g$ was odd, add $f$ to $g$ and $d$ to $e$. Then halve $g$ and $e$.
+            let odd_mask = mask_from_bit(g_odd);
+            let carry = add_masked(&mut gs, &fs, odd_mask);
+            g_high = g_high.wrapping_add(f_high & odd_mask).wrapping_add(carry);
+            let mut masked_ds = ds;
+            for d in &mut masked_ds {
+                *d &= odd_mask;
+            }
+            es = self.add_limbs(&es, &masked_ds);
+            let g_sign = g_high >> (Limb::WIDTH - 1);
+            shr_1(&mut gs, g_high & 1);
+            g_high = (g_high >> 1) | (g_sign << (Limb::WIDTH - 1));
+            self.halve_limbs(&mut es);
+            delta = delta.wrapping_add(1);
+        }
+        // $f$ is $\pm 1$ exactly when the inverse exists.
+        let f_negative = f_high >> (Limb::WIDTH - 1);
+        let mut not_one = (fs[0] ^ 1) | f_high;
+        let mut not_negative_one = !fs[0] | !f_high;
+        let mut x_nonzero = 0;
+        for &f in &fs[1..] {
+            not_one |= f;
+            not_negative_one |= !f;
+        }
+        for &x in xs {
+            x_nonzero |= x;
+        }
+        let invertible = (nonzero_bit(not_one) ^ 1 | nonzero_bit(not_negative_one) ^ 1)
+            & nonzero_bit(x_nonzero);
+        let negative_ds = self.sub_limbs(&[0; LIMBS], &ds);
+        (
+            select(mask_from_bit(f_negative), &negative_ds, &ds),
+            invertible,
+        )
+    }
+}
+
+impl<'a, const LIMBS: usize> ConstantTimeResidue<'a, LIMBS> {
+    /// Returns the [`ConstantTimeModulus`] that this [`ConstantTimeResidue`] was created from.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::fixed::constant_time::ConstantTimeModulus;
+    /// use malachite_nz::fixed::U256;
+    ///
+    /// let m = ConstantTimeModulus::new(U256::from(11u32));
+    /// assert_eq!(m.residue(U256::from(3u32)).modulus().modulus(), U256::from(11u32));
+    /// ```
+    #[inline]
+    pub const fn modulus(&self) -> &'a ConstantTimeModulus<LIMBS> {
+        self.modulus
+    }
+
+    /// Computes the multiplicative inverse of a [`ConstantTimeResidue`].
+    ///
+    /// Returns `None` if the residue is not coprime to $m$; in particular, 0 is never invertible,
+    /// even when $m$ is 1, as with [`Residue::invert`](crate::natural::modular::Residue::invert).
+    /// The computation takes the same time either way, but whether the inverse
+    /// exists is revealed by the return value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::fixed::constant_time::ConstantTimeModulus;
+    /// use malachite_nz::fixed::U256;
+    ///
+    /// let m = ConstantTimeModulus::new(U256::from(15u32));
+    /// assert_eq!(
+    ///     m.residue(U256::from(2u32)).invert().map(U256::from),
+    ///     Some(U256::from(8u32))
+    /// );
+    /// assert!(m.residue(U256::from(5u32)).invert().is_none());
+    /// ```
+    pub fn invert(&self) -> Option<ConstantTimeResidue<'a, LIMBS>> {
+        let modulus = self.modulus;
+        // The Montgomery form of $x$ is $a = xR$. Inverting $a$ gives $x^{-1}R^{-1}$, and two
+        // multiplications by $R^2$ turn that into $x^{-1}R$.
+        let (inverse, invertible) = modulus.invert_limbs(&self.xs);
+        let inverse = modulus.mul_limbs(&inverse, &modulus.r_squared.0);
+        let inverse = modulus.mul_limbs(&inverse, &modulus.r_squared.0);
+        if invertible == 1 {
+            Some(ConstantTimeResidue {
+                modulus,
+                xs: inverse,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, const LIMBS: usize> From<ConstantTimeResidue<'a, LIMBS>> for Uint<LIMBS> {
+    /// Converts a [`ConstantTimeResidue`] to a [`Uint`] in $[0, m)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::fixed::constant_time::ConstantTimeModulus;
+    /// use malachite_nz::fixed::U256;
+    ///
+    /// let m = ConstantTimeModulus::new(U256::from(11u32));
+    /// assert_eq!(U256::from(m.residue(U256::from(14u32))), U256::from(3u32));
+    /// ```
+    fn from(x: ConstantTimeResidue<'a, LIMBS>) -> Uint<LIMBS> {
+        Uint(x.modulus.mul_limbs(&x.xs, &Uint::<LIMBS>::ONE.0))
+    }
+}
+
+impl<'a, const LIMBS: usize> PartialEq for ConstantTimeResidue<'a, LIMBS> {
+    /// Determines whether two [`ConstantTimeResidue`]s are equal, examining every limb of both.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Panics
+    /// Panics if the residues were created from different moduli.
+    fn eq(&self, other: &ConstantTimeResidue<'a, LIMBS>) -> bool {
+        self.modulus.check_same(other.modulus);
+        let mut difference = 0;
+        for (&x, &y) in self.xs.iter().zip(other.xs.iter()) {
+            difference |= x ^ y;
+        }
+        nonzero_bit(difference) == 0
+    }
+}
+
+impl<'a, const LIMBS: usize> Eq for ConstantTimeResidue<'a, LIMBS> {}
+
+impl<'a, const LIMBS: usize> Debug for ConstantTimeResidue<'a, LIMBS> {
+    /// Writes the value of a [`ConstantTimeResidue`], in $[0, m)$, to a [`Formatter`]. This does
+    /// not run in constant time.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(&Uint::from(*self), f)
+    }
+}
+
+macro_rules! impl_constant_time_op {
+    (
+        $trait: ident,
+        $fn: ident,
+        $assign_trait: ident,
+        $assign_fn: ident,
+        $limbs_fn: ident,
+        $doc: expr
+    ) => {
+        impl<'a, const LIMBS: usize> $trait for ConstantTimeResidue<'a, LIMBS> {
+            type Output = ConstantTimeResidue<'a, LIMBS>;
+
+            #[doc = $doc]
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+            ///
+            /// # Panics
+            /// Panics if the residues were created from different moduli.
+            #[inline]
+            fn $fn(self, other: ConstantTimeResidue<'a, LIMBS>) -> ConstantTimeResidue<'a, LIMBS> {
+                self.modulus.check_same(other.modulus);
+                ConstantTimeResidue {
+                    modulus: self.modulus,
+                    xs: self.modulus.$limbs_fn(&self.xs, &other.xs),
+                }
+            }
+        }
+
+        impl<'a, const LIMBS: usize> $assign_trait for ConstantTimeResidue<'a, LIMBS> {
+            #[doc = $doc]
+            /// The result is assigned to the left-hand side.
+            ///
+            /// See the implementation of the non-assigning operator for details.
+            #[inline]
+            fn $assign_fn(&mut self, other: ConstantTimeResidue<'a, LIMBS>) {
+                *self = $trait::$fn(*self, other);
+            }
+        }
+    };
+}
+impl_constant_time_op!(
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    add_limbs,
+    "Adds two [`ConstantTimeResidue`]s."
+);
+impl_constant_time_op!(
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    sub_limbs,
+    "Subtracts a [`ConstantTimeResidue`] from another."
+);
+impl_constant_time_op!(
+    Mul,
+    mul,
+    MulAssign,
+    mul_assign,
+    mul_limbs,
+    "Multiplies two [`ConstantTimeResidue`]s."
+);
+
+impl<'a, const LIMBS: usize> Neg for ConstantTimeResidue<'a, LIMBS> {
+    type Output = ConstantTimeResidue<'a, LIMBS>;
+
+    /// Negates a [`ConstantTimeResidue`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    #[inline]
+    fn neg(self) -> ConstantTimeResidue<'a, LIMBS> {
+        ConstantTimeResidue {
+            modulus: self.modulus,
+            xs: self.modulus.sub_limbs(&[0; LIMBS], &self.xs),
+        }
+    }
+}
+
+impl<'a, const LIMBS: usize> Square for ConstantTimeResidue<'a, LIMBS> {
+    type Output = ConstantTimeResidue<'a, LIMBS>;
+
+    /// Squares a [`ConstantTimeResidue`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Square;
+    /// use malachite_nz::fixed::constant_time::ConstantTimeModulus;
+    /// use malachite_nz::fixed::U256;
+    ///
+    /// let m = ConstantTimeModulus::new(U256::from(11u32));
+    /// assert_eq!(U256::from(m.residue(U256::from(5u32)).square()), U256::from(3u32));
+    /// ```
+    #[inline]
+    fn square(self) -> ConstantTimeResidue<'a, LIMBS> {
+        self * self
+    }
+}
+
+impl<'a, const LIMBS: usize> SquareAssign for ConstantTimeResidue<'a, LIMBS> {
+    /// Squares a [`ConstantTimeResidue`] in place.
+    ///
+    /// See the implementation of [`Square`] for details.
+    #[inline]
+    fn square_assign(&mut self) {
+        *self = self.square();
+    }
+}
+
+impl<'a, const LIMBS: usize> Pow<Uint<LIMBS>> for ConstantTimeResidue<'a, LIMBS> {
+    type Output = ConstantTimeResidue<'a, LIMBS>;
+
+    /// Raises a [`ConstantTimeResidue`] to a [`Uint`] power. $0^0$ is 1.
+    ///
+    /// The exponent is treated as secret: it is processed in fixed windows of 4 bits, all $W$
+    /// bits are processed regardless of the exponent's value, and each window reads every one of
+    /// the 16 precomputed powers, keeping the one it needs with a mask.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::fixed::constant_time::ConstantTimeModulus;
+    /// use malachite_nz::fixed::U256;
+    ///
+    /// let m = ConstantTimeModulus::new(U256::from(497u32));
+    /// let x = m.residue(U256::from(4u32));
+    /// assert_eq!(U256::from(x.pow(U256::from(13u32))), U256::from(445u32));
+    /// ```
+    fn pow(self, exp: Uint<LIMBS>) -> ConstantTimeResidue<'a, LIMBS> {
+        let modulus = self.modulus;
+        let table_len = usize::power_of_2(WINDOW_SIZE);
+        let mut table = vec![modulus.one.0; table_len];
+        for i in 1..table_len {
+            table[i] = modulus.mul_limbs(&table[i - 1], &self.xs);
+        }
+        let mut out = modulus.one.0;
+        let window_mask = Limb::power_of_2(WINDOW_SIZE) - 1;
+        for window in (0..Uint::<LIMBS>::WIDTH / WINDOW_SIZE).rev() {
+            for _ in 0..WINDOW_SIZE {
+                out = modulus.mul_limbs(&out, &out);
+            }
+            let bit_index = window * WINDOW_SIZE;
+            let bits = (exp.0[usize::wrapping_from(bit_index >> Limb::LOG_WIDTH)]
+                >> (bit_index & Limb::WIDTH_MASK))
+                & window_mask;
+            let mut power = [0; LIMBS];
+            for (i, entry) in table.iter().enumerate() {
+                trace("lookup");
+                let mask = eq_mask(Limb::wrapping_from(i), bits);
+                for (p, &e) in power.iter_mut().zip(entry.iter()) {
+                    *p |= e & mask;
+                }
+            }
+            out = modulus.mul_limbs(&out, &power);
+        }
+        ConstantTimeResidue { modulus, xs: out }
+    }
+}
+
+impl<'a, const LIMBS: usize> PowAssign<Uint<LIMBS>> for ConstantTimeResidue<'a, LIMBS> {
+    /// Raises a [`ConstantTimeResidue`] to a [`Uint`] power in place. $0^0$ is 1.
+    ///
+    /// See the implementation of [`Pow`] for details.
+    #[inline]
+    fn pow_assign(&mut self, exp: Uint<LIMBS>) {
+        *self = self.pow(exp);
+    }
+}
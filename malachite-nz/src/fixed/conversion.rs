@@ -0,0 +1,554 @@
+use crate::fixed::{Int, Uint};
+use crate::integer::Integer;
+use crate::natural::InnerNatural::{Large, Small};
+use crate::natural::Natural;
+use crate::platform::{Limb, SignedLimb};
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::arithmetic::traits::WrappingNegAssign;
+use malachite_base::num::conversion::string::parse_error::{
+    ParseNumberError, ParseNumberErrorKind,
+};
+use malachite_base::num::conversion::traits::{
+    ConvertibleFrom, OverflowingFrom, SaturatingFrom, WrappingFrom,
+};
+use std::fmt::{Binary, Debug, Display, Formatter, LowerHex, Octal, UpperHex};
+use std::slice;
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UintFromNaturalError;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct IntFromIntegerError;
+
+// Returns the limbs of a `Natural`, in ascending order, without allocating.
+fn natural_limbs(x: &Natural) -> &[Limb] {
+    match *x {
+        Natural(Small(ref small)) => slice::from_ref(small),
+        Natural(Large(ref limbs)) => limbs,
+    }
+}
+
+// Copies the lowest `LIMBS` limbs of `xs` into an array, padding with zeros. Returns the array and
+// whether any nonzero limbs were left over.
+fn limbs_to_array<const LIMBS: usize>(xs: &[Limb]) -> ([Limb; LIMBS], bool) {
+    let mut out = [0; LIMBS];
+    if xs.len() <= LIMBS {
+        out[..xs.len()].copy_from_slice(xs);
+        (out, false)
+    } else {
+        out.copy_from_slice(&xs[..LIMBS]);
+        (out, xs[LIMBS..].iter().any(|&x| x != 0))
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($t: ident) => {
+        impl<const LIMBS: usize> From<$t> for Uint<LIMBS> {
+            /// Converts an unsigned primitive integer to a [`Uint`].
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+            ///
+            /// # Examples
+            /// See [here](super::conversion#from).
+            #[inline]
+            fn from(x: $t) -> Uint<LIMBS> {
+                let mut xs = [0; LIMBS];
+                xs[0] = Limb::from(x);
+                Uint(xs)
+            }
+        }
+    };
+}
+impl_from_unsigned!(u8);
+impl_from_unsigned!(u16);
+impl_from_unsigned!(u32);
+#[cfg(not(feature = "32_bit_limbs"))]
+impl_from_unsigned!(u64);
+
+macro_rules! impl_from_signed {
+    ($t: ident) => {
+        impl<const LIMBS: usize> From<$t> for Int<LIMBS> {
+            /// Converts a signed primitive integer to an [`Int`].
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+            ///
+            /// # Examples
+            /// See [here](super::conversion#from).
+            #[inline]
+            fn from(x: $t) -> Int<LIMBS> {
+                let mut xs = [if x < 0 { Limb::MAX } else { 0 }; LIMBS];
+                xs[0] = Limb::wrapping_from(SignedLimb::from(x));
+                Int(Uint(xs))
+            }
+        }
+    };
+}
+impl_from_signed!(i8);
+impl_from_signed!(i16);
+impl_from_signed!(i32);
+#[cfg(not(feature = "32_bit_limbs"))]
+impl_from_signed!(i64);
+
+impl<const LIMBS: usize> From<Uint<LIMBS>> for Natural {
+    /// Converts a [`Uint`] to a [`Natural`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::conversion#from).
+    #[inline]
+    fn from(x: Uint<LIMBS>) -> Natural {
+        Natural::from_limbs_asc(&x.0)
+    }
+}
+
+impl<const LIMBS: usize> From<Int<LIMBS>> for Integer {
+    /// Converts an [`Int`] to an [`Integer`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::conversion#from).
+    #[inline]
+    fn from(x: Int<LIMBS>) -> Integer {
+        Integer::from_twos_complement_limbs_asc(&x.0 .0)
+    }
+}
+
+impl<'a, const LIMBS: usize> TryFrom<&'a Natural> for Uint<LIMBS> {
+    type Error = UintFromNaturalError;
+
+    /// Converts a [`Natural`] to a [`Uint`], returning an error if the [`Natural`] is too large.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::conversion#try_from).
+    fn try_from(value: &Natural) -> Result<Uint<LIMBS>, Self::Error> {
+        let (xs, overflow) = limbs_to_array(natural_limbs(value));
+        if overflow {
+            Err(UintFromNaturalError)
+        } else {
+            Ok(Uint(xs))
+        }
+    }
+}
+
+impl<'a, const LIMBS: usize> WrappingFrom<&'a Natural> for Uint<LIMBS> {
+    /// Converts a [`Natural`] to a [`Uint`], wrapping modulo $2^W$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::conversion#wrapping_from).
+    #[inline]
+    fn wrapping_from(value: &Natural) -> Uint<LIMBS> {
+        Uint(limbs_to_array(natural_limbs(value)).0)
+    }
+}
+
+impl<'a, const LIMBS: usize> SaturatingFrom<&'a Natural> for Uint<LIMBS> {
+    /// Converts a [`Natural`] to a [`Uint`].
+    ///
+    /// If the [`Natural`] is too large to fit in a [`Uint`], the maximum representable value is
+    /// returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::conversion#saturating_from).
+    #[inline]
+    fn saturating_from(value: &Natural) -> Uint<LIMBS> {
+        Uint::try_from(value).unwrap_or(Uint::MAX)
+    }
+}
+
+impl<'a, const LIMBS: usize> OverflowingFrom<&'a Natural> for Uint<LIMBS> {
+    /// Converts a [`Natural`] to a [`Uint`], wrapping modulo $2^W$.
+    ///
+    /// The returned boolean value indicates whether wrapping occurred.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::conversion#overflowing_from).
+    #[inline]
+    fn overflowing_from(value: &Natural) -> (Uint<LIMBS>, bool) {
+        let (xs, overflow) = limbs_to_array(natural_limbs(value));
+        (Uint(xs), overflow)
+    }
+}
+
+impl<'a, const LIMBS: usize> ConvertibleFrom<&'a Natural> for Uint<LIMBS> {
+    /// Determines whether a [`Natural`] can be converted to a [`Uint`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::conversion#convertible_from).
+    #[inline]
+    fn convertible_from(value: &Natural) -> bool {
+        let xs = natural_limbs(value);
+        xs.len() <= LIMBS || xs[LIMBS..].iter().all(|&x| x == 0)
+    }
+}
+
+impl<'a, const LIMBS: usize> OverflowingFrom<&'a Integer> for Int<LIMBS> {
+    /// Converts an [`Integer`] to an [`Int`], wrapping modulo $2^W$.
+    ///
+    /// The returned boolean value indicates whether wrapping occurred.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::conversion#overflowing_from).
+    fn overflowing_from(value: &Integer) -> (Int<LIMBS>, bool) {
+        let (xs, overflow) = limbs_to_array(natural_limbs(&value.abs));
+        let mut x = Int(Uint(xs));
+        if !value.sign {
+            x.wrapping_neg_assign();
+        }
+        // Besides dropping high limbs, the conversion overflows if the sign bit of the result
+        // doesn't match the sign of the `Integer`.
+        (x, overflow || x.is_negative() == value.sign)
+    }
+}
+
+impl<'a, const LIMBS: usize> TryFrom<&'a Integer> for Int<LIMBS> {
+    type Error = IntFromIntegerError;
+
+    /// Converts an [`Integer`] to an [`Int`], returning an error if the [`Integer`] is out of
+    /// range.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::conversion#try_from).
+    #[inline]
+    fn try_from(value: &Integer) -> Result<Int<LIMBS>, Self::Error> {
+        let (x, overflow) = Int::overflowing_from(value);
+        if overflow {
+            Err(IntFromIntegerError)
+        } else {
+            Ok(x)
+        }
+    }
+}
+
+impl<'a, const LIMBS: usize> WrappingFrom<&'a Integer> for Int<LIMBS> {
+    /// Converts an [`Integer`] to an [`Int`], wrapping modulo $2^W$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::conversion#wrapping_from).
+    #[inline]
+    fn wrapping_from(value: &Integer) -> Int<LIMBS> {
+        Int::overflowing_from(value).0
+    }
+}
+
+impl<'a, const LIMBS: usize> SaturatingFrom<&'a Integer> for Int<LIMBS> {
+    /// Converts an [`Integer`] to an [`Int`].
+    ///
+    /// If the [`Integer`] is out of range, the closest representable value is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::conversion#saturating_from).
+    #[inline]
+    fn saturating_from(value: &Integer) -> Int<LIMBS> {
+        match Int::try_from(value) {
+            Ok(x) => x,
+            Err(_) if value.sign => Int::MAX,
+            Err(_) => Int::MIN,
+        }
+    }
+}
+
+impl<'a, const LIMBS: usize> ConvertibleFrom<&'a Integer> for Int<LIMBS> {
+    /// Determines whether an [`Integer`] can be converted to an [`Int`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::conversion#convertible_from).
+    #[inline]
+    fn convertible_from(value: &Integer) -> bool {
+        !Int::<LIMBS>::overflowing_from(value).1
+    }
+}
+
+impl<const LIMBS: usize> WrappingFrom<Uint<LIMBS>> for Int<LIMBS> {
+    /// Reinterprets the bits of a [`Uint`] as an [`Int`] of the same width.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// See [here](super::conversion#wrapping_from).
+    #[inline]
+    fn wrapping_from(value: Uint<LIMBS>) -> Int<LIMBS> {
+        Int(value)
+    }
+}
+
+impl<const LIMBS: usize> WrappingFrom<Int<LIMBS>> for Uint<LIMBS> {
+    /// Reinterprets the bits of an [`Int`] as a [`Uint`] of the same width.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// See [here](super::conversion#wrapping_from).
+    #[inline]
+    fn wrapping_from(value: Int<LIMBS>) -> Uint<LIMBS> {
+        value.0
+    }
+}
+
+impl<const LIMBS: usize> ConvertibleFrom<Uint<LIMBS>> for Int<LIMBS> {
+    /// Determines whether a [`Uint`] can be converted to an [`Int`] of the same width; that is,
+    /// whether it is less than $2^{W-1}$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// See [here](super::conversion#convertible_from).
+    #[inline]
+    fn convertible_from(value: Uint<LIMBS>) -> bool {
+        !Int(value).is_negative()
+    }
+}
+
+impl<const LIMBS: usize> ConvertibleFrom<Int<LIMBS>> for Uint<LIMBS> {
+    /// Determines whether an [`Int`] can be converted to a [`Uint`] of the same width; that is,
+    /// whether it is non-negative.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// See [here](super::conversion#convertible_from).
+    #[inline]
+    fn convertible_from(value: Int<LIMBS>) -> bool {
+        !value.is_negative()
+    }
+}
+
+impl<const LIMBS: usize> FromStr for Uint<LIMBS> {
+    type Err = ParseNumberError;
+
+    /// Converts a string to a [`Uint`].
+    ///
+    /// The string must consist of decimal digits and represent a value less than $2^W$. Otherwise
+    /// a [`ParseNumberError`] is returned; its kind is
+    /// [`Overflow`](ParseNumberErrorKind::Overflow) if the value is out of range.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// See [here](super::conversion#from_str).
+    #[inline]
+    fn from_str(s: &str) -> Result<Uint<LIMBS>, ParseNumberError> {
+        Uint::try_from(&Natural::from_str(s)?)
+            .map_err(|_| ParseNumberError::new(ParseNumberErrorKind::Overflow))
+    }
+}
+
+impl<const LIMBS: usize> FromStr for Int<LIMBS> {
+    type Err = ParseNumberError;
+
+    /// Converts a string to an [`Int`].
+    ///
+    /// The string must consist of decimal digits, optionally preceded by a `-`, and represent a
+    /// value in $[-2^{W-1}, 2^{W-1})$. Otherwise a [`ParseNumberError`] is returned; its kind is
+    /// [`Overflow`](ParseNumberErrorKind::Overflow) if the value is out of range.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// See [here](super::conversion#from_str).
+    #[inline]
+    fn from_str(s: &str) -> Result<Int<LIMBS>, ParseNumberError> {
+        Int::try_from(&Integer::from_str(s)?)
+            .map_err(|_| ParseNumberError::new(ParseNumberErrorKind::Overflow))
+    }
+}
+
+macro_rules! impl_fmt {
+    ($trait: ident) => {
+        impl<const LIMBS: usize> $trait for Uint<LIMBS> {
+            /// Formats a [`Uint`] in the same way as the [`Natural`] with the same value.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n (\log n)^2 \log\log n)$
+            ///
+            /// $M(n) = O(n \log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+            ///
+            /// # Examples
+            /// See [here](super::conversion#to_string).
+            #[inline]
+            fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+                $trait::fmt(&Natural::from(*self), f)
+            }
+        }
+    };
+}
+impl_fmt!(Display);
+impl_fmt!(Debug);
+impl_fmt!(Binary);
+impl_fmt!(Octal);
+impl_fmt!(LowerHex);
+impl_fmt!(UpperHex);
+
+impl<const LIMBS: usize> Display for Int<LIMBS> {
+    /// Converts an [`Int`] to a [`String`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::conversion#to_string).
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        Display::fmt(&Integer::from(*self), f)
+    }
+}
+
+impl<const LIMBS: usize> Debug for Int<LIMBS> {
+    /// Converts an [`Int`] to a [`String`].
+    ///
+    /// This is the same as the [`Display::fmt`] implementation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::conversion#to_string).
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+macro_rules! impl_fmt_twos_complement {
+    ($trait: ident) => {
+        impl<const LIMBS: usize> $trait for Int<LIMBS> {
+            /// Formats the two's complement representation of an [`Int`], in the same way as the
+            /// primitive signed integers do. Negative values are formatted as their $W$-bit two's
+            /// complement, with no sign.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+            ///
+            /// # Examples
+            /// See [here](super::conversion#to_string).
+            #[inline]
+            fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+                $trait::fmt(&self.0, f)
+            }
+        }
+    };
+}
+impl_fmt_twos_complement!(Binary);
+impl_fmt_twos_complement!(Octal);
+impl_fmt_twos_complement!(LowerHex);
+impl_fmt_twos_complement!(UpperHex);
@@ -0,0 +1,519 @@
+use crate::fixed::{Int, Uint};
+use crate::natural::arithmetic::shl::limbs_slice_shl_in_place;
+use crate::natural::arithmetic::shr::limbs_slice_shr_in_place;
+use crate::natural::logic::not::limbs_not_in_place;
+use crate::platform::Limb;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::{BitAccess, LeadingZeros, SignificantBits};
+use std::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, ShlAssign, Shr,
+    ShrAssign,
+};
+
+// Shifts an array of `Limb`s, in ascending order, left by `bits` bits. Bits shifted past the end
+// of the array are lost.
+fn limbs_shl_fixed<const LIMBS: usize>(xs: &mut [Limb; LIMBS], bits: u64) {
+    if bits >= Uint::<LIMBS>::WIDTH {
+        *xs = [0; LIMBS];
+        return;
+    }
+    let limbs = usize::exact_from(bits >> Limb::LOG_WIDTH);
+    let bits = bits & Limb::WIDTH_MASK;
+    if limbs != 0 {
+        xs.copy_within(..LIMBS - limbs, limbs);
+        xs[..limbs].fill(0);
+    }
+    if bits != 0 {
+        limbs_slice_shl_in_place(&mut xs[limbs..], bits);
+    }
+}
+
+// Shifts an array of `Limb`s, in ascending order, right by `bits` bits, filling the vacated high
+// bits with the bits of `fill`, which is either 0 or `Limb::MAX`.
+fn limbs_shr_fixed<const LIMBS: usize>(xs: &mut [Limb; LIMBS], bits: u64, fill: Limb) {
+    if bits >= Uint::<LIMBS>::WIDTH {
+        *xs = [fill; LIMBS];
+        return;
+    }
+    let limbs = usize::exact_from(bits >> Limb::LOG_WIDTH);
+    let bits = bits & Limb::WIDTH_MASK;
+    let len = LIMBS - limbs;
+    if limbs != 0 {
+        xs.copy_within(limbs.., 0);
+        xs[len..].fill(fill);
+    }
+    if bits != 0 {
+        limbs_slice_shr_in_place(&mut xs[..len], bits);
+        xs[len - 1] |= fill << (Limb::WIDTH - bits);
+    }
+}
+
+impl<const LIMBS: usize> Not for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Returns the bitwise negation of a [`Uint`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::Max;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::fixed::U256;
+    ///
+    /// assert_eq!(!U256::ZERO, U256::MAX);
+    /// ```
+    #[inline]
+    fn not(mut self) -> Uint<LIMBS> {
+        limbs_not_in_place(&mut self.0);
+        self
+    }
+}
+
+impl<const LIMBS: usize> Not for Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    /// Returns the bitwise negation of an [`Int`].
+    ///
+    /// $f(x) = -x - 1$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeOne, Zero};
+    /// use malachite_nz::fixed::I256;
+    ///
+    /// assert_eq!(!I256::ZERO, I256::NEGATIVE_ONE);
+    /// assert_eq!(!I256::from(5), I256::from(-6));
+    /// ```
+    #[inline]
+    fn not(self) -> Int<LIMBS> {
+        Int(!self.0)
+    }
+}
+
+macro_rules! impl_bitwise {
+    (
+        $std: ident,
+        $std_fn: ident,
+        $std_assign: ident,
+        $std_assign_fn: ident,
+        $op: tt,
+        $name: expr
+    ) => {
+        impl<const LIMBS: usize> $std_assign<Uint<LIMBS>> for Uint<LIMBS> {
+            #[doc = concat!("Takes the bitwise ", $name, " of two [`Uint`]s, in place.")]
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+            ///
+            /// # Examples
+            /// See [here](super::logic#bitwise).
+            #[inline]
+            fn $std_assign_fn(&mut self, other: Uint<LIMBS>) {
+                for (x, y) in self.0.iter_mut().zip(other.0.iter()) {
+                    *x $op *y;
+                }
+            }
+        }
+
+        impl<const LIMBS: usize> $std<Uint<LIMBS>> for Uint<LIMBS> {
+            type Output = Uint<LIMBS>;
+
+            #[doc = concat!("Takes the bitwise ", $name, " of two [`Uint`]s.")]
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+            ///
+            /// # Examples
+            /// See [here](super::logic#bitwise).
+            #[inline]
+            fn $std_fn(mut self, other: Uint<LIMBS>) -> Uint<LIMBS> {
+                self.$std_assign_fn(other);
+                self
+            }
+        }
+
+        impl<const LIMBS: usize> $std_assign<Int<LIMBS>> for Int<LIMBS> {
+            #[doc = concat!("Takes the bitwise ", $name, " of two [`Int`]s, in place.")]
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+            ///
+            /// # Examples
+            /// See [here](super::logic#bitwise).
+            #[inline]
+            fn $std_assign_fn(&mut self, other: Int<LIMBS>) {
+                self.0.$std_assign_fn(other.0);
+            }
+        }
+
+        impl<const LIMBS: usize> $std<Int<LIMBS>> for Int<LIMBS> {
+            type Output = Int<LIMBS>;
+
+            #[doc = concat!("Takes the bitwise ", $name, " of two [`Int`]s.")]
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+            ///
+            /// # Examples
+            /// See [here](super::logic#bitwise).
+            #[inline]
+            fn $std_fn(self, other: Int<LIMBS>) -> Int<LIMBS> {
+                Int(self.0.$std_fn(other.0))
+            }
+        }
+    };
+}
+impl_bitwise!(BitAnd, bitand, BitAndAssign, bitand_assign, &=, "and");
+impl_bitwise!(BitOr, bitor, BitOrAssign, bitor_assign, |=, "or");
+impl_bitwise!(BitXor, bitxor, BitXorAssign, bitxor_assign, ^=, "xor");
+
+impl<const LIMBS: usize> ShlAssign<u64> for Uint<LIMBS> {
+    /// Left-shifts a [`Uint`] (multiplies it by a power of 2) in place. Bits shifted past the
+    /// width of the type are discarded, so shifting by at least $W$ bits produces 0.
+    ///
+    /// $x \gets 2^b x \mod 2^W$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::logic#shl).
+    #[inline]
+    fn shl_assign(&mut self, bits: u64) {
+        limbs_shl_fixed(&mut self.0, bits);
+    }
+}
+
+impl<const LIMBS: usize> Shl<u64> for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Left-shifts a [`Uint`] (multiplies it by a power of 2). Bits shifted past the width of the
+    /// type are discarded, so shifting by at least $W$ bits produces 0.
+    ///
+    /// $f(x, b) = 2^b x \mod 2^W$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::logic#shl).
+    #[inline]
+    fn shl(mut self, bits: u64) -> Uint<LIMBS> {
+        self <<= bits;
+        self
+    }
+}
+
+impl<const LIMBS: usize> ShlAssign<u64> for Int<LIMBS> {
+    /// Left-shifts an [`Int`] (multiplies it by a power of 2) in place, wrapping around at the
+    /// boundary of the type. Shifting by at least $W$ bits produces 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::logic#shl).
+    #[inline]
+    fn shl_assign(&mut self, bits: u64) {
+        self.0 <<= bits;
+    }
+}
+
+impl<const LIMBS: usize> Shl<u64> for Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    /// Left-shifts an [`Int`] (multiplies it by a power of 2), wrapping around at the boundary of
+    /// the type. Shifting by at least $W$ bits produces 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::logic#shl).
+    #[inline]
+    fn shl(self, bits: u64) -> Int<LIMBS> {
+        Int(self.0 << bits)
+    }
+}
+
+impl<const LIMBS: usize> ShrAssign<u64> for Uint<LIMBS> {
+    /// Right-shifts a [`Uint`] (divides it by a power of 2 and takes the floor) in place.
+    ///
+    /// $x \gets \lfloor x/2^b \rfloor$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::logic#shr).
+    #[inline]
+    fn shr_assign(&mut self, bits: u64) {
+        limbs_shr_fixed(&mut self.0, bits, 0);
+    }
+}
+
+impl<const LIMBS: usize> Shr<u64> for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Right-shifts a [`Uint`] (divides it by a power of 2 and takes the floor).
+    ///
+    /// $f(x, b) = \lfloor x/2^b \rfloor$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::logic#shr).
+    #[inline]
+    fn shr(mut self, bits: u64) -> Uint<LIMBS> {
+        self >>= bits;
+        self
+    }
+}
+
+impl<const LIMBS: usize> ShrAssign<u64> for Int<LIMBS> {
+    /// Right-shifts an [`Int`] (divides it by a power of 2 and takes the floor) in place. The
+    /// shift is arithmetic, so negative values stay negative.
+    ///
+    /// $x \gets \lfloor x/2^b \rfloor$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::logic#shr).
+    #[inline]
+    fn shr_assign(&mut self, bits: u64) {
+        let fill = if self.is_negative() { Limb::MAX } else { 0 };
+        limbs_shr_fixed(&mut self.0 .0, bits, fill);
+    }
+}
+
+impl<const LIMBS: usize> Shr<u64> for Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    /// Right-shifts an [`Int`] (divides it by a power of 2 and takes the floor). The shift is
+    /// arithmetic, so negative values stay negative.
+    ///
+    /// $f(x, b) = \lfloor x/2^b \rfloor$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::logic#shr).
+    #[inline]
+    fn shr(mut self, bits: u64) -> Int<LIMBS> {
+        self >>= bits;
+        self
+    }
+}
+
+impl<const LIMBS: usize> SignificantBits for Uint<LIMBS> {
+    /// Returns the number of significant bits of a [`Uint`].
+    ///
+    /// $$
+    /// f(n) = \\begin{cases}
+    ///     0 & \text{if} \\quad n = 0, \\\\
+    ///     \lfloor \log_2 n \rfloor + 1 & \text{if} \\quad n > 0.
+    /// \\end{cases}
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `LIMBS`.
+    ///
+    /// # Examples
+    /// See [here](super::logic#significant_bits).
+    fn significant_bits(self) -> u64 {
+        let len = self.significant_limb_count();
+        if len == 0 {
+            0
+        } else {
+            u64::exact_from(len) * Limb::WIDTH - LeadingZeros::leading_zeros(self.0[len - 1])
+        }
+    }
+}
+
+impl<const LIMBS: usize> BitAccess for Uint<LIMBS> {
+    /// Determines whether the $i$th bit of a [`Uint`] is 0 or 1. Getting bits beyond the type's
+    /// width is allowed; those bits are false.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// See [here](super::logic#bit_access).
+    #[inline]
+    fn get_bit(&self, index: u64) -> bool {
+        index < Uint::<LIMBS>::WIDTH
+            && self.0[usize::exact_from(index >> Limb::LOG_WIDTH)].get_bit(index & Limb::WIDTH_MASK)
+    }
+
+    /// Sets the $i$th bit of a [`Uint`] to 1.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if $i \geq W$.
+    ///
+    /// # Examples
+    /// See [here](super::logic#bit_access).
+    #[inline]
+    fn set_bit(&mut self, index: u64) {
+        if index >= Uint::<LIMBS>::WIDTH {
+            panic!(
+                "Cannot set bit {} in non-negative value of width {}",
+                index,
+                Uint::<LIMBS>::WIDTH
+            );
+        }
+        self.0[usize::exact_from(index >> Limb::LOG_WIDTH)].set_bit(index & Limb::WIDTH_MASK);
+    }
+
+    /// Sets the $i$th bit of a [`Uint`] to 0. Clearing bits beyond the type's width is allowed;
+    /// since those bits are already false, clearing them does nothing.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// See [here](super::logic#bit_access).
+    #[inline]
+    fn clear_bit(&mut self, index: u64) {
+        if index < Uint::<LIMBS>::WIDTH {
+            self.0[usize::exact_from(index >> Limb::LOG_WIDTH)].clear_bit(index & Limb::WIDTH_MASK);
+        }
+    }
+}
+
+impl<const LIMBS: usize> BitAccess for Int<LIMBS> {
+    /// Determines whether the $i$th bit of an [`Int`]'s two's complement representation is 0 or
+    /// 1. Getting bits beyond the type's width is allowed; those bits are equal to the sign bit.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// See [here](super::logic#bit_access).
+    #[inline]
+    fn get_bit(&self, index: u64) -> bool {
+        if index < Int::<LIMBS>::WIDTH {
+            self.0.get_bit(index)
+        } else {
+            self.is_negative()
+        }
+    }
+
+    /// Sets the $i$th bit of an [`Int`]'s two's complement representation to 1.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if $i \geq W$ and the [`Int`] is non-negative.
+    ///
+    /// # Examples
+    /// See [here](super::logic#bit_access).
+    #[inline]
+    fn set_bit(&mut self, index: u64) {
+        if index < Int::<LIMBS>::WIDTH {
+            self.0.set_bit(index);
+        } else if !self.is_negative() {
+            panic!(
+                "Cannot set bit {} in non-negative value of width {}",
+                index,
+                Int::<LIMBS>::WIDTH
+            );
+        }
+    }
+
+    /// Sets the $i$th bit of an [`Int`]'s two's complement representation to 0.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if $i \geq W$ and the [`Int`] is negative.
+    ///
+    /// # Examples
+    /// See [here](super::logic#bit_access).
+    #[inline]
+    fn clear_bit(&mut self, index: u64) {
+        if index < Int::<LIMBS>::WIDTH {
+            self.0.clear_bit(index);
+        } else if self.is_negative() {
+            panic!(
+                "Cannot clear bit {} in negative value of width {}",
+                index,
+                Int::<LIMBS>::WIDTH
+            );
+        }
+    }
+}
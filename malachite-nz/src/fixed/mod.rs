@@ -0,0 +1,439 @@
+use crate::platform::Limb;
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{NegativeOne, One, Two, Zero};
+
+/// A fixed-width unsigned integer made up of `LIMBS` [`Limb`](crate#limbs)s.
+///
+/// The value is stored inline, in an array of `LIMBS` [`Limb`](crate#limbs)s in ascending order,
+/// so a `Uint` never allocates. Its width, $W$, is `LIMBS` times the width of a
+/// [`Limb`](crate#limbs), and it can represent any integer in $[0, 2^W)$. `LIMBS` must be
+/// positive.
+///
+/// A `Uint` supports the same families of arithmetic functions as the primitive unsigned types:
+/// [`WrappingAdd`](malachite_base::num::arithmetic::traits::WrappingAdd),
+/// [`OverflowingMul`](malachite_base::num::arithmetic::traits::OverflowingMul),
+/// [`CheckedSub`](malachite_base::num::arithmetic::traits::CheckedSub),
+/// [`SaturatingAdd`](malachite_base::num::arithmetic::traits::SaturatingAdd), and so on. The
+/// operators `+`, `-`, and `*` panic if the result is not representable. The computations are
+/// done by the same `limbs_*` functions that [`Natural`](crate::natural::Natural) uses.
+///
+/// [`U256`] and [`U512`] are 256- and 512-bit `Uint`s.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::arithmetic::traits::{OverflowingMul, WrappingAdd};
+/// use malachite_base::comparison::traits::Max;
+/// use malachite_base::num::basic::traits::{One, Two, Zero};
+/// use malachite_nz::fixed::U256;
+///
+/// assert_eq!(U256::MAX.wrapping_add(U256::ONE), U256::ZERO);
+/// assert_eq!((U256::TWO << 254).overflowing_mul(U256::TWO), (U256::ZERO, true));
+/// assert_eq!(U256::MAX.to_string().len(), 78);
+/// ```
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct Uint<const LIMBS: usize>(pub(crate) [Limb; LIMBS]);
+
+/// A fixed-width signed integer made up of `LIMBS` [`Limb`](crate#limbs)s.
+///
+/// The value is stored inline, in two's complement, in an array of `LIMBS`
+/// [`Limb`](crate#limbs)s in ascending order, so an `Int` never allocates. Its width, $W$, is
+/// `LIMBS` times the width of a [`Limb`](crate#limbs), and it can represent any integer in
+/// $[-2^{W-1}, 2^{W-1})$. `LIMBS` must be positive.
+///
+/// An `Int` supports the same families of arithmetic functions as the primitive signed types. The
+/// operators `+`, `-`, `*`, `/`, and unary `-` panic if the result is not representable.
+///
+/// [`I256`] and [`I512`] are 256- and 512-bit `Int`s.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::arithmetic::traits::{OverflowingNeg, SaturatingSub};
+/// use malachite_base::comparison::traits::{Max, Min};
+/// use malachite_base::num::basic::traits::One;
+/// use malachite_nz::fixed::I256;
+///
+/// assert_eq!(I256::MIN.overflowing_neg(), (I256::MIN, true));
+/// assert_eq!(I256::MIN.saturating_sub(I256::ONE), I256::MIN);
+/// assert_eq!((-I256::MAX).to_string().len(), 78);
+/// ```
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct Int<const LIMBS: usize>(pub(crate) Uint<LIMBS>);
+
+/// A 256-bit unsigned integer.
+pub type U256 = Uint<{ (256 / Limb::WIDTH) as usize }>;
+
+/// A 512-bit unsigned integer.
+pub type U512 = Uint<{ (512 / Limb::WIDTH) as usize }>;
+
+/// A 256-bit signed integer.
+pub type I256 = Int<{ (256 / Limb::WIDTH) as usize }>;
+
+/// A 512-bit signed integer.
+pub type I512 = Int<{ (512 / Limb::WIDTH) as usize }>;
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// The width of a `Uint`, in bits.
+    pub const WIDTH: u64 = LIMBS as u64 * Limb::WIDTH;
+
+    /// Creates a `Uint` from its [`Limb`](crate#limbs)s, in ascending order.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::fixed::Uint;
+    ///
+    /// assert_eq!(Uint::from_limbs_asc([3, 0]).to_string(), "3");
+    /// assert_eq!(Uint::from_limbs_asc([0, 1]), Uint::from(1u32) << Uint::<2>::WIDTH / 2);
+    /// ```
+    #[inline]
+    pub const fn from_limbs_asc(xs: [Limb; LIMBS]) -> Uint<LIMBS> {
+        Uint(xs)
+    }
+
+    /// Returns the [`Limb`](crate#limbs)s of a `Uint`, in ascending order.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::fixed::Uint;
+    ///
+    /// assert_eq!((Uint::<2>::ONE << Uint::<2>::WIDTH / 2).to_limbs_asc(), [0, 1]);
+    /// ```
+    #[inline]
+    pub const fn to_limbs_asc(self) -> [Limb; LIMBS] {
+        self.0
+    }
+
+    // Returns the number of limbs of a `Uint`, not counting leading zeros.
+    pub(crate) fn significant_limb_count(&self) -> usize {
+        self.0.iter().rposition(|&x| x != 0).map_or(0, |i| i + 1)
+    }
+}
+
+impl<const LIMBS: usize> Int<LIMBS> {
+    /// The width of an `Int`, in bits.
+    pub const WIDTH: u64 = LIMBS as u64 * Limb::WIDTH;
+
+    /// Creates an `Int` from the [`Limb`](crate#limbs)s of its two's complement representation,
+    /// in ascending order.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::fixed::Int;
+    /// use malachite_nz::platform::Limb;
+    ///
+    /// assert_eq!(Int::from_twos_complement_limbs_asc([3, 0]).to_string(), "3");
+    /// assert_eq!(Int::from_twos_complement_limbs_asc([Limb::MAX; 2]).to_string(), "-1");
+    /// ```
+    #[inline]
+    pub const fn from_twos_complement_limbs_asc(xs: [Limb; LIMBS]) -> Int<LIMBS> {
+        Int(Uint(xs))
+    }
+
+    /// Returns the [`Limb`](crate#limbs)s of the two's complement representation of an `Int`, in
+    /// ascending order.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeOne, One};
+    /// use malachite_nz::fixed::Int;
+    /// use malachite_nz::platform::Limb;
+    ///
+    /// assert_eq!(Int::<2>::ONE.to_twos_complement_limbs_asc(), [1, 0]);
+    /// assert_eq!(Int::<2>::NEGATIVE_ONE.to_twos_complement_limbs_asc(), [Limb::MAX; 2]);
+    /// ```
+    #[inline]
+    pub const fn to_twos_complement_limbs_asc(self) -> [Limb; LIMBS] {
+        self.0 .0
+    }
+
+    /// Determines whether an `Int` is negative.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeOne, Zero};
+    /// use malachite_nz::fixed::I256;
+    ///
+    /// assert!(I256::NEGATIVE_ONE.is_negative());
+    /// assert!(!I256::ZERO.is_negative());
+    /// ```
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        self.0 .0[LIMBS - 1] >> (Limb::WIDTH - 1) != 0
+    }
+}
+
+/// The constant 0.
+impl<const LIMBS: usize> Zero for Uint<LIMBS> {
+    const ZERO: Uint<LIMBS> = Uint([0; LIMBS]);
+}
+
+/// The constant 1.
+impl<const LIMBS: usize> One for Uint<LIMBS> {
+    const ONE: Uint<LIMBS> = {
+        let mut xs = [0; LIMBS];
+        xs[0] = 1;
+        Uint(xs)
+    };
+}
+
+/// The constant 2.
+impl<const LIMBS: usize> Two for Uint<LIMBS> {
+    const TWO: Uint<LIMBS> = {
+        let mut xs = [0; LIMBS];
+        xs[0] = 2;
+        Uint(xs)
+    };
+}
+
+/// The minimum value of a [`Uint`], 0.
+impl<const LIMBS: usize> Min for Uint<LIMBS> {
+    const MIN: Uint<LIMBS> = Uint::ZERO;
+}
+
+/// The maximum value of a [`Uint`], $2^W-1$.
+impl<const LIMBS: usize> Max for Uint<LIMBS> {
+    const MAX: Uint<LIMBS> = Uint([Limb::MAX; LIMBS]);
+}
+
+impl<const LIMBS: usize> Default for Uint<LIMBS> {
+    /// The default value of a [`Uint`], 0.
+    fn default() -> Uint<LIMBS> {
+        Uint::ZERO
+    }
+}
+
+/// The constant 0.
+impl<const LIMBS: usize> Zero for Int<LIMBS> {
+    const ZERO: Int<LIMBS> = Int(Uint::ZERO);
+}
+
+/// The constant 1.
+impl<const LIMBS: usize> One for Int<LIMBS> {
+    const ONE: Int<LIMBS> = Int(Uint::ONE);
+}
+
+/// The constant 2.
+impl<const LIMBS: usize> Two for Int<LIMBS> {
+    const TWO: Int<LIMBS> = Int(Uint::TWO);
+}
+
+/// The constant -1.
+impl<const LIMBS: usize> NegativeOne for Int<LIMBS> {
+    const NEGATIVE_ONE: Int<LIMBS> = Int(Uint::MAX);
+}
+
+/// The minimum value of an [`Int`], $-2^{W-1}$.
+impl<const LIMBS: usize> Min for Int<LIMBS> {
+    const MIN: Int<LIMBS> = {
+        let mut xs = [0; LIMBS];
+        xs[LIMBS - 1] = 1 << (Limb::WIDTH - 1);
+        Int(Uint(xs))
+    };
+}
+
+/// The maximum value of an [`Int`], $2^{W-1}-1$.
+impl<const LIMBS: usize> Max for Int<LIMBS> {
+    const MAX: Int<LIMBS> = {
+        let mut xs = [Limb::MAX; LIMBS];
+        xs[LIMBS - 1] = Limb::MAX >> 1;
+        Int(Uint(xs))
+    };
+}
+
+impl<const LIMBS: usize> Default for Int<LIMBS> {
+    /// The default value of an [`Int`], 0.
+    fn default() -> Int<LIMBS> {
+        Int::ZERO
+    }
+}
+
+/// Traits for arithmetic.
+pub mod arithmetic;
+/// Traits for comparing [`Uint`]s and [`Int`]s.
+pub mod comparison;
+/// [`ConstantTimeModulus`](constant_time::ConstantTimeModulus) and
+/// [`ConstantTimeResidue`](constant_time::ConstantTimeResidue), for modular arithmetic whose
+/// timing does not depend on secret values.
+pub mod constant_time;
+/// Traits for converting [`Uint`]s and [`Int`]s to and from [`Natural`](crate::natural::Natural)s,
+/// [`Integer`](crate::integer::Integer)s, primitive integers, and [`String`]s.
+///
+/// # from
+/// ```
+/// use malachite_base::comparison::traits::Max;
+/// use malachite_nz::fixed::{I256, U256};
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(U256::from(123u32).to_string(), "123");
+/// assert_eq!(I256::from(-123).to_string(), "-123");
+/// assert_eq!(Natural::from(U256::MAX), (Natural::from(1u32) << 256) - Natural::from(1u32));
+/// assert_eq!(Integer::from(I256::from(-5)), -5);
+/// ```
+///
+/// # try_from
+/// ```
+/// use malachite_nz::fixed::{I256, U256};
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(U256::try_from(&Natural::from(123u32)), Ok(U256::from(123u32)));
+/// assert!(U256::try_from(&(Natural::from(1u32) << 256)).is_err());
+/// assert_eq!(I256::try_from(&Integer::from(-123)), Ok(I256::from(-123)));
+/// assert!(I256::try_from(&(Integer::from(1) << 255)).is_err());
+/// assert!(I256::try_from(&-(Integer::from(1) << 255u64)).is_ok());
+/// ```
+///
+/// # wrapping_from
+/// ```
+/// use malachite_base::comparison::traits::Max;
+/// use malachite_base::num::basic::traits::{NegativeOne, Zero};
+/// use malachite_base::num::conversion::traits::WrappingFrom;
+/// use malachite_nz::fixed::{I256, U256};
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(U256::wrapping_from(&(Natural::from(1u32) << 256)), U256::ZERO);
+/// assert_eq!(I256::wrapping_from(U256::MAX), I256::NEGATIVE_ONE);
+/// assert_eq!(U256::wrapping_from(I256::NEGATIVE_ONE), U256::MAX);
+/// ```
+///
+/// # saturating_from
+/// ```
+/// use malachite_base::comparison::traits::{Max, Min};
+/// use malachite_base::num::conversion::traits::SaturatingFrom;
+/// use malachite_nz::fixed::{I256, U256};
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(U256::saturating_from(&(Natural::from(1u32) << 300)), U256::MAX);
+/// assert_eq!(I256::saturating_from(&-(Integer::from(1) << 300u64)), I256::MIN);
+/// ```
+///
+/// # overflowing_from
+/// ```
+/// use malachite_base::num::basic::traits::One;
+/// use malachite_base::num::conversion::traits::OverflowingFrom;
+/// use malachite_nz::fixed::{I256, U256};
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(
+///     U256::overflowing_from(&((Natural::from(1u32) << 256) + Natural::from(1u32))),
+///     (U256::ONE, true)
+/// );
+/// assert_eq!(I256::overflowing_from(&Integer::from(-7)), (I256::from(-7), false));
+/// ```
+///
+/// # convertible_from
+/// ```
+/// use malachite_base::comparison::traits::Max;
+/// use malachite_base::num::conversion::traits::ConvertibleFrom;
+/// use malachite_nz::fixed::{I256, U256};
+/// use malachite_nz::natural::Natural;
+///
+/// assert!(U256::convertible_from(&Natural::from(10u32)));
+/// assert!(!U256::convertible_from(&(Natural::from(1u32) << 256)));
+/// assert!(!I256::convertible_from(U256::MAX));
+/// assert!(!U256::convertible_from(I256::from(-1)));
+/// ```
+///
+/// # from_str
+/// ```
+/// use malachite_nz::fixed::{I256, U256};
+/// use std::str::FromStr;
+///
+/// assert_eq!(U256::from_str("123"), Ok(U256::from(123u32)));
+/// assert_eq!(I256::from_str("-123"), Ok(I256::from(-123)));
+/// assert!(U256::from_str("-1").is_err());
+/// assert!(U256::from_str(&"9".repeat(78)).is_err());
+/// ```
+///
+/// # to_string
+/// ```
+/// use malachite_nz::fixed::{I256, U256};
+///
+/// assert_eq!(U256::from(255u32).to_string(), "255");
+/// assert_eq!(format!("{:x}", U256::from(255u32)), "ff");
+/// assert_eq!(format!("{:#b}", U256::from(5u32)), "0b101");
+/// assert_eq!(I256::from(-255).to_string(), "-255");
+/// assert_eq!(format!("{:x}", I256::from(-1)), "f".repeat(64));
+/// ```
+pub mod conversion;
+/// Traits for logic and bit manipulation.
+///
+/// # bitwise
+/// ```
+/// use malachite_base::num::basic::traits::NegativeOne;
+/// use malachite_nz::fixed::{I256, U256};
+///
+/// assert_eq!(U256::from(12u32) & U256::from(10u32), U256::from(8u32));
+/// assert_eq!(U256::from(12u32) | U256::from(10u32), U256::from(14u32));
+/// assert_eq!(U256::from(12u32) ^ U256::from(10u32), U256::from(6u32));
+/// assert_eq!(I256::from(-12) & I256::NEGATIVE_ONE, I256::from(-12));
+/// ```
+///
+/// # shl
+/// ```
+/// use malachite_base::num::basic::traits::{One, Zero};
+/// use malachite_nz::fixed::{I256, U256};
+///
+/// assert_eq!(U256::ONE << 3, U256::from(8u32));
+/// assert_eq!(U256::ONE << 256, U256::ZERO);
+/// assert_eq!(I256::from(-3) << 2, I256::from(-12));
+/// ```
+///
+/// # shr
+/// ```
+/// use malachite_base::comparison::traits::Max;
+/// use malachite_base::num::basic::traits::{NegativeOne, One};
+/// use malachite_nz::fixed::{I256, U256};
+///
+/// assert_eq!(U256::MAX >> 255, U256::ONE);
+/// assert_eq!(I256::from(-13) >> 2, I256::from(-4));
+/// assert_eq!(I256::from(-13) >> 1000, I256::NEGATIVE_ONE);
+/// ```
+///
+/// # significant_bits
+/// ```
+/// use malachite_base::comparison::traits::Max;
+/// use malachite_base::num::logic::traits::SignificantBits;
+/// use malachite_base::num::basic::traits::Zero;
+/// use malachite_nz::fixed::U256;
+///
+/// assert_eq!(U256::ZERO.significant_bits(), 0);
+/// assert_eq!(U256::from(100u32).significant_bits(), 7);
+/// assert_eq!(U256::MAX.significant_bits(), 256);
+/// ```
+///
+/// # bit_access
+/// ```
+/// use malachite_base::num::logic::traits::BitAccess;
+/// use malachite_base::num::basic::traits::{One, Zero};
+/// use malachite_nz::fixed::{I256, U256};
+///
+/// let mut x = U256::ZERO;
+/// x.set_bit(200);
+/// assert_eq!(x, U256::ONE << 200);
+/// assert!(x.get_bit(200));
+/// x.clear_bit(200);
+/// assert_eq!(x, U256::ZERO);
+///
+/// assert!(I256::from(-1).get_bit(1000));
+/// ```
+pub mod logic;
@@ -45,7 +45,11 @@ impl Natural {
                 false
             }
             (Natural(Large(ref mut xs)), Natural(Large(ref mut ys)), z) => {
-                let (right, sign) = limbs_overflowing_sub_mul_limb_in_place_either(xs, ys, z);
+                let (right, sign) = limbs_overflowing_sub_mul_limb_in_place_either(
+                    xs.as_mut_vec(),
+                    ys.as_mut_vec(),
+                    z,
+                );
                 if right {
                     b.trim();
                     *self = b;
@@ -80,7 +84,7 @@ impl Natural {
                 false
             }
             (Natural(Large(ref mut xs)), Natural(Large(ref ys)), z) => {
-                let sign = limbs_overflowing_sub_mul_limb_in_place_left(xs, ys, z);
+                let sign = limbs_overflowing_sub_mul_limb_in_place_left(xs.as_mut_vec(), ys, z);
                 self.trim();
                 sign
             }
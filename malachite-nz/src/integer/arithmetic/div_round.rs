@@ -42,6 +42,12 @@ impl DivRound<Integer> for Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $g(x, y, \mathrm{NearestTiesAway})$ and $g(x, y, \mathrm{NearestTiesTowardZero})$ are
+    /// defined like $g(x, y, \mathrm{Nearest})$, except that ties are rounded away from $0$ and
+    /// towards $0$, respectively. $g(x, y, \mathrm{Odd})$ is $g(x, y, \mathrm{Down})$ if no
+    /// rounding is necessary, and otherwise whichever of $g(x, y, \mathrm{Floor})$ and $g(x, y,
+    /// \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+    ///
     /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
     ///
     /// Then
@@ -185,6 +191,12 @@ impl<'a> DivRound<&'a Integer> for Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $g(x, y, \mathrm{NearestTiesAway})$ and $g(x, y, \mathrm{NearestTiesTowardZero})$ are
+    /// defined like $g(x, y, \mathrm{Nearest})$, except that ties are rounded away from $0$ and
+    /// towards $0$, respectively. $g(x, y, \mathrm{Odd})$ is $g(x, y, \mathrm{Down})$ if no
+    /// rounding is necessary, and otherwise whichever of $g(x, y, \mathrm{Floor})$ and $g(x, y,
+    /// \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+    ///
     /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
     ///
     /// Then
@@ -327,6 +339,12 @@ impl<'a> DivRound<Integer> for &'a Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $g(x, y, \mathrm{NearestTiesAway})$ and $g(x, y, \mathrm{NearestTiesTowardZero})$ are
+    /// defined like $g(x, y, \mathrm{Nearest})$, except that ties are rounded away from $0$ and
+    /// towards $0$, respectively. $g(x, y, \mathrm{Odd})$ is $g(x, y, \mathrm{Down})$ if no
+    /// rounding is necessary, and otherwise whichever of $g(x, y, \mathrm{Floor})$ and $g(x, y,
+    /// \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+    ///
     /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
     ///
     /// Then
@@ -472,6 +490,12 @@ impl<'a, 'b> DivRound<&'b Integer> for &'a Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $g(x, y, \mathrm{NearestTiesAway})$ and $g(x, y, \mathrm{NearestTiesTowardZero})$ are
+    /// defined like $g(x, y, \mathrm{Nearest})$, except that ties are rounded away from $0$ and
+    /// towards $0$, respectively. $g(x, y, \mathrm{Odd})$ is $g(x, y, \mathrm{Down})$ if no
+    /// rounding is necessary, and otherwise whichever of $g(x, y, \mathrm{Floor})$ and $g(x, y,
+    /// \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+    ///
     /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
     ///
     /// Then
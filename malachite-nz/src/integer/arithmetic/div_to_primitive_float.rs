@@ -0,0 +1,107 @@
+use crate::integer::Integer;
+use crate::natural::arithmetic::div_to_primitive_float::div_to_primitive_float_round;
+use malachite_base::num::arithmetic::traits::NegAssign;
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::rounding_modes::RoundingMode;
+use std::cmp::Ordering;
+
+fn div_to_primitive_float_round_integer<T: PrimitiveFloat>(
+    x: &Integer,
+    y: &Integer,
+    mut rm: RoundingMode,
+) -> (T, Ordering) {
+    let sign = x.sign == y.sign || *x == 0;
+    if !sign {
+        rm.neg_assign();
+    }
+    let (f, o) = div_to_primitive_float_round::<T>(&x.abs, &y.abs, rm);
+    if sign {
+        (f, o)
+    } else {
+        (-f, o.reverse())
+    }
+}
+
+impl Integer {
+    /// Divides an [`Integer`] by another [`Integer`] and converts the quotient to an [`f32`],
+    /// taking both by reference and using a specified
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode). An [`Ordering`] is also
+    /// returned, indicating whether the returned value is less than, equal to, or greater than the
+    /// exact quotient.
+    ///
+    /// No gcd is computed, and only the quotient bits needed for correct rounding are generated.
+    /// See [`Natural::div_to_f64_round`](crate::natural::Natural::div_to_f64_round) for how
+    /// overflow and underflow are handled; negative quotients are handled symmetrically.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `other.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `rm` is `Exact` and the quotient cannot be represented
+    /// exactly.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::float::NiceFloat;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_nz::integer::Integer;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (f, o) = Integer::from(-2).div_to_f32_round(&Integer::from(3), RoundingMode::Floor);
+    /// assert_eq!(NiceFloat(f), NiceFloat(-0.6666667));
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (f, o) = Integer::from(-2).div_to_f32_round(&Integer::from(-3), RoundingMode::Floor);
+    /// assert_eq!(NiceFloat(f), NiceFloat(0.6666666));
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn div_to_f32_round(&self, other: &Integer, rm: RoundingMode) -> (f32, Ordering) {
+        div_to_primitive_float_round_integer(self, other, rm)
+    }
+
+    /// Divides an [`Integer`] by another [`Integer`] and converts the quotient to an [`f64`],
+    /// taking both by reference and using a specified
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode). An [`Ordering`] is also
+    /// returned, indicating whether the returned value is less than, equal to, or greater than the
+    /// exact quotient.
+    ///
+    /// No gcd is computed, and only the quotient bits needed for correct rounding are generated.
+    /// See [`Natural::div_to_f64_round`](crate::natural::Natural::div_to_f64_round) for how
+    /// overflow and underflow are handled; negative quotients are handled symmetrically.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `other.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `rm` is `Exact` and the quotient cannot be represented
+    /// exactly.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::float::NiceFloat;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_nz::integer::Integer;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (f, o) = Integer::from(-2).div_to_f64_round(&Integer::from(3), RoundingMode::Down);
+    /// assert_eq!(NiceFloat(f), NiceFloat(-0.6666666666666666));
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (f, o) = Integer::from(6).div_to_f64_round(&Integer::from(-8), RoundingMode::Exact);
+    /// assert_eq!(NiceFloat(f), NiceFloat(-0.75));
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    #[inline]
+    pub fn div_to_f64_round(&self, other: &Integer, rm: RoundingMode) -> (f64, Ordering) {
+        div_to_primitive_float_round_integer(self, other, rm)
+    }
+}
@@ -33,6 +33,9 @@ pub mod div_mod;
 /// dividing two numbers according to a specified
 /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode).
 pub mod div_round;
+/// Functions for dividing two [`Integer`](crate::integer::Integer)s and converting the quotient
+/// to a primitive float, without computing a gcd.
+pub mod div_to_primitive_float;
 /// Implementations of [`DivisibleBy`](malachite_base::num::arithmetic::traits::DivisibleBy), a
 /// trait for determining whether one number is divisible by another.
 pub mod divisible_by;
@@ -92,6 +95,11 @@ pub mod pow;
 /// Implementations of [`PowerOf2`](malachite_base::num::arithmetic::traits::PowerOf2), a trait for
 /// computing a power of 2.
 pub mod power_of_2;
+/// Implementations of [`RemoveFactor`](malachite_base::num::arithmetic::traits::RemoveFactor),
+/// [`RemoveFactorAssign`](malachite_base::num::arithmetic::traits::RemoveFactorAssign), and
+/// [`Valuation`](malachite_base::num::arithmetic::traits::Valuation), traits for finding and
+/// removing the factors of a number that are powers of another number.
+pub mod remove_factor;
 /// Implementations of traits for taking the $n$th root of a number.
 ///
 /// The traits are [`FloorRoot`](malachite_base::num::arithmetic::traits::FloorRoot),
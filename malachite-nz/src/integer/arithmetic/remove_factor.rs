@@ -0,0 +1,393 @@
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{RemoveFactor, RemoveFactorAssign, Valuation};
+
+impl RemoveFactor<Natural> for Integer {
+    type Output = Integer;
+
+    /// Removes all factors of $p$ from an [`Integer`], returning the remaining [`Integer`] and the
+    /// multiplicity of $p$. Both the [`Integer`] and the [`Natural`] are taken by value.
+    ///
+    /// $f(x, p) = (x/p^k, k)$, where $k$ is the largest integer such that $p^k \mid x$. The sign
+    /// of the result is the sign of $x$.
+    ///
+    /// $p$ does not have to be prime.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::RemoveFactor;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(-360)
+    ///         .remove_factor(Natural::from(2u32))
+    ///         .to_debug_string(),
+    ///     "(-45, 3)"
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-360)
+    ///         .remove_factor(Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "(-360, 0)"
+    /// );
+    /// ```
+    #[inline]
+    fn remove_factor(mut self, p: Natural) -> (Integer, u64) {
+        let multiplicity = self.abs.remove_factor_assign(&p);
+        (self, multiplicity)
+    }
+}
+
+impl<'a> RemoveFactor<&'a Natural> for Integer {
+    type Output = Integer;
+
+    /// Removes all factors of $p$ from an [`Integer`], returning the remaining [`Integer`] and the
+    /// multiplicity of $p$. The [`Integer`] is taken by value and the [`Natural`] by reference.
+    ///
+    /// $f(x, p) = (x/p^k, k)$, where $k$ is the largest integer such that $p^k \mid x$. The sign
+    /// of the result is the sign of $x$.
+    ///
+    /// $p$ does not have to be prime.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::RemoveFactor;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(-360)
+    ///         .remove_factor(&Natural::from(2u32))
+    ///         .to_debug_string(),
+    ///     "(-45, 3)"
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-360)
+    ///         .remove_factor(&Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "(-360, 0)"
+    /// );
+    /// ```
+    #[inline]
+    fn remove_factor(mut self, p: &'a Natural) -> (Integer, u64) {
+        let multiplicity = self.abs.remove_factor_assign(p);
+        (self, multiplicity)
+    }
+}
+
+impl<'a> RemoveFactor<Natural> for &'a Integer {
+    type Output = Integer;
+
+    /// Removes all factors of $p$ from an [`Integer`], returning the remaining [`Integer`] and the
+    /// multiplicity of $p$. The [`Integer`] is taken by reference and the [`Natural`] by value.
+    ///
+    /// $f(x, p) = (x/p^k, k)$, where $k$ is the largest integer such that $p^k \mid x$. The sign
+    /// of the result is the sign of $x$.
+    ///
+    /// $p$ does not have to be prime.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::RemoveFactor;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(-360))
+    ///         .remove_factor(Natural::from(2u32))
+    ///         .to_debug_string(),
+    ///     "(-45, 3)"
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-360))
+    ///         .remove_factor(Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "(-360, 0)"
+    /// );
+    /// ```
+    #[inline]
+    fn remove_factor(self, p: Natural) -> (Integer, u64) {
+        let (abs, multiplicity) = (&self.abs).remove_factor(p);
+        (Integer::from_sign_and_abs(self.sign, abs), multiplicity)
+    }
+}
+
+impl<'a, 'b> RemoveFactor<&'b Natural> for &'a Integer {
+    type Output = Integer;
+
+    /// Removes all factors of $p$ from an [`Integer`], returning the remaining [`Integer`] and the
+    /// multiplicity of $p$. Both the [`Integer`] and the [`Natural`] are taken by reference.
+    ///
+    /// $f(x, p) = (x/p^k, k)$, where $k$ is the largest integer such that $p^k \mid x$. The sign
+    /// of the result is the sign of $x$.
+    ///
+    /// $p$ does not have to be prime.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::RemoveFactor;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(-360))
+    ///         .remove_factor(&Natural::from(2u32))
+    ///         .to_debug_string(),
+    ///     "(-45, 3)"
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-360))
+    ///         .remove_factor(&Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "(-360, 0)"
+    /// );
+    /// ```
+    #[inline]
+    fn remove_factor(self, p: &'b Natural) -> (Integer, u64) {
+        let (abs, multiplicity) = (&self.abs).remove_factor(p);
+        (Integer::from_sign_and_abs(self.sign, abs), multiplicity)
+    }
+}
+
+impl RemoveFactorAssign<Natural> for Integer {
+    /// Removes all factors of $p$ from an [`Integer`] in place, returning the multiplicity of
+    /// $p$. The [`Natural`] is taken by value.
+    ///
+    /// $x \gets x/p^k$ and $f(x, p) = k$, where $k$ is the largest integer such that
+    /// $p^k \mid x$.
+    ///
+    /// $p$ does not have to be prime.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::RemoveFactorAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-360);
+    /// assert_eq!(x.remove_factor_assign(Natural::from(3u32)), 2);
+    /// assert_eq!(x, -40);
+    /// ```
+    #[inline]
+    fn remove_factor_assign(&mut self, p: Natural) -> u64 {
+        self.abs.remove_factor_assign(p)
+    }
+}
+
+impl<'a> RemoveFactorAssign<&'a Natural> for Integer {
+    /// Removes all factors of $p$ from an [`Integer`] in place, returning the multiplicity of
+    /// $p$. The [`Natural`] is taken by reference.
+    ///
+    /// $x \gets x/p^k$ and $f(x, p) = k$, where $k$ is the largest integer such that
+    /// $p^k \mid x$.
+    ///
+    /// $p$ does not have to be prime.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::RemoveFactorAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-360);
+    /// assert_eq!(x.remove_factor_assign(&Natural::from(3u32)), 2);
+    /// assert_eq!(x, -40);
+    /// ```
+    #[inline]
+    fn remove_factor_assign(&mut self, p: &'a Natural) -> u64 {
+        self.abs.remove_factor_assign(p)
+    }
+}
+
+impl Valuation<Natural> for Integer {
+    /// Returns the multiplicity of $p$ as a factor of an [`Integer`]. Both the [`Integer`] and the [`Natural`] are taken by value.
+    ///
+    /// $f(x, p) = k$, where $k$ is the largest integer such that $p^k \mid x$. When $p$ is prime,
+    /// this is the $p$-adic valuation of $x$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Valuation;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-360).valuation(Natural::from(2u32)), 3);
+    /// assert_eq!(Integer::from(-360).valuation(Natural::from(5u32)), 1);
+    /// assert_eq!(Integer::from(-360).valuation(Natural::from(7u32)), 0);
+    /// ```
+    #[inline]
+    fn valuation(self, p: Natural) -> u64 {
+        self.abs.valuation(p)
+    }
+}
+
+impl<'a> Valuation<&'a Natural> for Integer {
+    /// Returns the multiplicity of $p$ as a factor of an [`Integer`]. The [`Integer`] is taken by value and the [`Natural`] by reference.
+    ///
+    /// $f(x, p) = k$, where $k$ is the largest integer such that $p^k \mid x$. When $p$ is prime,
+    /// this is the $p$-adic valuation of $x$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Valuation;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-360).valuation(&Natural::from(2u32)), 3);
+    /// assert_eq!(Integer::from(-360).valuation(&Natural::from(5u32)), 1);
+    /// assert_eq!(Integer::from(-360).valuation(&Natural::from(7u32)), 0);
+    /// ```
+    #[inline]
+    fn valuation(self, p: &'a Natural) -> u64 {
+        self.abs.valuation(p)
+    }
+}
+
+impl<'a> Valuation<Natural> for &'a Integer {
+    /// Returns the multiplicity of $p$ as a factor of an [`Integer`]. The [`Integer`] is taken by reference and the [`Natural`] by value.
+    ///
+    /// $f(x, p) = k$, where $k$ is the largest integer such that $p^k \mid x$. When $p$ is prime,
+    /// this is the $p$-adic valuation of $x$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Valuation;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-360)).valuation(Natural::from(2u32)), 3);
+    /// assert_eq!((&Integer::from(-360)).valuation(Natural::from(5u32)), 1);
+    /// assert_eq!((&Integer::from(-360)).valuation(Natural::from(7u32)), 0);
+    /// ```
+    #[inline]
+    fn valuation(self, p: Natural) -> u64 {
+        (&self.abs).valuation(p)
+    }
+}
+
+impl<'a, 'b> Valuation<&'b Natural> for &'a Integer {
+    /// Returns the multiplicity of $p$ as a factor of an [`Integer`]. Both the [`Integer`] and the [`Natural`] are taken by reference.
+    ///
+    /// $f(x, p) = k$, where $k$ is the largest integer such that $p^k \mid x$. When $p$ is prime,
+    /// this is the $p$-adic valuation of $x$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Valuation;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-360)).valuation(&Natural::from(2u32)), 3);
+    /// assert_eq!((&Integer::from(-360)).valuation(&Natural::from(5u32)), 1);
+    /// assert_eq!((&Integer::from(-360)).valuation(&Natural::from(7u32)), 0);
+    /// ```
+    #[inline]
+    fn valuation(self, p: &'b Natural) -> u64 {
+        (&self.abs).valuation(p)
+    }
+}
@@ -36,6 +36,12 @@ impl RoundToMultiple<Integer> for Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $f(x, y, \mathrm{NearestTiesAway})$ and $f(x, y, \mathrm{NearestTiesTowardZero})$ are
+    /// defined like $f(x, y, \mathrm{Nearest})$, except that ties are rounded away from $0$ and
+    /// towards $0$, respectively. $f(x, y, \mathrm{Odd})$ is $f(x, y, \mathrm{Down})$ if no
+    /// rounding is necessary, and otherwise whichever of $f(x, y, \mathrm{Floor})$ and $f(x, y,
+    /// \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+    ///
     /// $f(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
     ///
     /// The following two expressions are equivalent:
@@ -181,6 +187,12 @@ impl<'a> RoundToMultiple<&'a Integer> for Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $f(x, y, \mathrm{NearestTiesAway})$ and $f(x, y, \mathrm{NearestTiesTowardZero})$ are
+    /// defined like $f(x, y, \mathrm{Nearest})$, except that ties are rounded away from $0$ and
+    /// towards $0$, respectively. $f(x, y, \mathrm{Odd})$ is $f(x, y, \mathrm{Down})$ if no
+    /// rounding is necessary, and otherwise whichever of $f(x, y, \mathrm{Floor})$ and $f(x, y,
+    /// \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+    ///
     /// $f(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
     ///
     /// The following two expressions are equivalent:
@@ -326,6 +338,12 @@ impl<'a> RoundToMultiple<Integer> for &'a Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $f(x, y, \mathrm{NearestTiesAway})$ and $f(x, y, \mathrm{NearestTiesTowardZero})$ are
+    /// defined like $f(x, y, \mathrm{Nearest})$, except that ties are rounded away from $0$ and
+    /// towards $0$, respectively. $f(x, y, \mathrm{Odd})$ is $f(x, y, \mathrm{Down})$ if no
+    /// rounding is necessary, and otherwise whichever of $f(x, y, \mathrm{Floor})$ and $f(x, y,
+    /// \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+    ///
     /// $f(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
     ///
     /// The following two expressions are equivalent:
@@ -473,6 +491,12 @@ impl<'a, 'b> RoundToMultiple<&'b Integer> for &'a Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $f(x, y, \mathrm{NearestTiesAway})$ and $f(x, y, \mathrm{NearestTiesTowardZero})$ are
+    /// defined like $f(x, y, \mathrm{Nearest})$, except that ties are rounded away from $0$ and
+    /// towards $0$, respectively. $f(x, y, \mathrm{Odd})$ is $f(x, y, \mathrm{Down})$ if no
+    /// rounding is necessary, and otherwise whichever of $f(x, y, \mathrm{Floor})$ and $f(x, y,
+    /// \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+    ///
     /// $f(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
     ///
     /// The following two expressions are equivalent:
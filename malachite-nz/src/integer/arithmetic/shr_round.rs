@@ -99,6 +99,13 @@ macro_rules! impl_shr_round_unsigned {
             /// \end{cases}
             /// $$
             ///
+            /// $f(x, k, \mathrm{NearestTiesAway})$ and $f(x, k, \mathrm{NearestTiesTowardZero})$
+            /// are defined like $f(x, k, \mathrm{Nearest})$, except that ties are rounded away from
+            /// $0$ and towards $0$, respectively. $f(x, k, \mathrm{Odd})$ is $f(x, k,
+            /// \mathrm{Down})$ if no rounding is necessary, and otherwise whichever of $f(x, k,
+            /// \mathrm{Floor})$ and $f(x, k, \mathrm{Ceiling})$ corresponds to an odd rounded
+            /// quotient.
+            ///
             /// $f(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
             ///
             /// Then
@@ -162,6 +169,13 @@ macro_rules! impl_shr_round_unsigned {
             /// \end{cases}
             /// $$
             ///
+            /// $f(x, k, \mathrm{NearestTiesAway})$ and $f(x, k, \mathrm{NearestTiesTowardZero})$
+            /// are defined like $f(x, k, \mathrm{Nearest})$, except that ties are rounded away from
+            /// $0$ and towards $0$, respectively. $f(x, k, \mathrm{Odd})$ is $f(x, k,
+            /// \mathrm{Down})$ if no rounding is necessary, and otherwise whichever of $f(x, k,
+            /// \mathrm{Floor})$ and $f(x, k, \mathrm{Ceiling})$ corresponds to an odd rounded
+            /// quotient.
+            ///
             /// $f(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
             ///
             /// Then
@@ -289,6 +303,13 @@ macro_rules! impl_shr_round_signed {
             /// \end{cases}
             /// $$
             ///
+            /// $f(x, k, \mathrm{NearestTiesAway})$ and $f(x, k, \mathrm{NearestTiesTowardZero})$
+            /// are defined like $f(x, k, \mathrm{Nearest})$, except that ties are rounded away from
+            /// $0$ and towards $0$, respectively. $f(x, k, \mathrm{Odd})$ is $f(x, k,
+            /// \mathrm{Down})$ if no rounding is necessary, and otherwise whichever of $f(x, k,
+            /// \mathrm{Floor})$ and $f(x, k, \mathrm{Ceiling})$ corresponds to an odd rounded
+            /// quotient.
+            ///
             /// $f(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
             ///
             /// Then
@@ -354,6 +375,13 @@ macro_rules! impl_shr_round_signed {
             /// \end{cases}
             /// $$
             ///
+            /// $f(x, k, \mathrm{NearestTiesAway})$ and $f(x, k, \mathrm{NearestTiesTowardZero})$
+            /// are defined like $f(x, k, \mathrm{Nearest})$, except that ties are rounded away from
+            /// $0$ and towards $0$, respectively. $f(x, k, \mathrm{Odd})$ is $f(x, k,
+            /// \mathrm{Down})$ if no rounding is necessary, and otherwise whichever of $f(x, k,
+            /// \mathrm{Floor})$ and $f(x, k, \mathrm{Ceiling})$ corresponds to an odd rounded
+            /// quotient.
+            ///
             /// $f(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
             ///
             /// Then
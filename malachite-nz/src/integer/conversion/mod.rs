@@ -360,6 +360,7 @@ pub mod primitive_float_from_integer;
 pub mod primitive_int_from_integer;
 /// Implementations of traits for serialization and deserialization using
 /// [serde](https://serde.rs/).
+#[cfg(feature = "serde")]
 pub mod serde;
 /// Implementations of traits for converting [`Integer`](crate::integer::Integer)s to and from
 /// [`String`]s.
@@ -1,43 +1,133 @@
-use crate::integer::{Integer, SerdeInteger};
-use crate::natural::Natural;
-use malachite_base::num::conversion::traits::FromStringBase;
-use std::convert::TryFrom;
+use crate::integer::Integer;
+use crate::natural::conversion::serde::{natural_from_hex_string, natural_from_le_bytes};
+use malachite_base::num::conversion::traits::PowerOf2Digits;
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::Formatter;
 
-impl From<Integer> for SerdeInteger {
-    #[inline]
-    fn from(x: Integer) -> SerdeInteger {
-        SerdeInteger(format!("{x:#x}"))
+// Parses the human-readable form of an `Integer`: `"0x"` or `"-0x"` followed by hexadecimal
+// digits.
+fn integer_from_hex_string(s: &str) -> Result<Integer, String> {
+    if let Some(abs) = s.strip_prefix('-') {
+        if abs.starts_with("0x") {
+            let abs =
+                natural_from_hex_string(abs).map_err(|_| format!("Unrecognized digits in {s}"))?;
+            Ok(Integer::from_sign_and_abs(false, abs))
+        } else {
+            Err(format!("String '{s}' starts with '-' but not with '-0x'"))
+        }
+    } else if s.starts_with("0x") {
+        Ok(Integer::from(natural_from_hex_string(s)?))
+    } else {
+        Err(format!("String '{s}' does not start with '0x' or '-0x'"))
+    }
+}
+
+// Converts the compact binary form of an `Integer`, a sign byte followed by the bytes of its
+// absolute value in little-endian order, back to an `Integer`. The sign byte is 0 for
+// non-negative `Integer`s and 1 for negative ones.
+fn integer_from_sign_and_le_bytes(bytes: &[u8]) -> Result<Integer, String> {
+    match bytes.split_first() {
+        Some((&0, abs)) => Ok(Integer::from(natural_from_le_bytes(abs))),
+        Some((&1, abs)) => Ok(Integer::from_sign_and_abs(
+            false,
+            natural_from_le_bytes(abs),
+        )),
+        Some((&sign, _)) => Err(format!("Invalid sign byte {sign}")),
+        None => Err("Missing sign byte".to_string()),
+    }
+}
+
+struct IntegerVisitor;
+
+impl<'de> Visitor<'de> for IntegerVisitor {
+    type Value = Integer;
+
+    fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.write_str(
+            "a hexadecimal string starting with \"0x\" or \"-0x\", or a sign byte followed by a \
+            little-endian byte array",
+        )
+    }
+
+    fn visit_str<E: Error>(self, s: &str) -> Result<Integer, E> {
+        integer_from_hex_string(s).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: Error>(self, bytes: &[u8]) -> Result<Integer, E> {
+        integer_from_sign_and_le_bytes(bytes).map_err(E::custom)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Integer, A::Error> {
+        let mut bytes = Vec::new();
+        while let Some(b) = seq.next_element()? {
+            bytes.push(b);
+        }
+        integer_from_sign_and_le_bytes(&bytes).map_err(A::Error::custom)
+    }
+}
+
+impl Serialize for Integer {
+    /// Serializes an [`Integer`].
+    ///
+    /// Human-readable formats, such as JSON, get a hexadecimal string starting with `"0x"` or
+    /// `"-0x"`. Binary formats, such as bincode, get a byte array consisting of a sign byte,
+    /// which is 0 for non-negative [`Integer`]s and 1 for negative ones, followed by the bytes of
+    /// the absolute value in little-endian order, with no trailing zeros.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_string(&Integer::from(-1000000000000i64)).unwrap(),
+    ///     "\"-0xe8d4a51000\""
+    /// );
+    /// ```
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&format!("{self:#x}"))
+        } else {
+            let mut bytes = vec![u8::from(!self.sign)];
+            bytes.extend(PowerOf2Digits::<u8>::to_power_of_2_digits_asc(&self.abs, 8));
+            serializer.serialize_bytes(&bytes)
+        }
     }
 }
 
-impl TryFrom<SerdeInteger> for Integer {
-    type Error = String;
-
-    #[inline]
-    fn try_from(s: SerdeInteger) -> Result<Integer, String> {
-        if s.0.starts_with('-') {
-            if s.0.starts_with("-0x") {
-                Ok(Integer::from_sign_and_abs(
-                    false,
-                    Natural::from_string_base(16, &s.0[3..])
-                        .ok_or_else(|| format!("Unrecognized digits in {}", s.0))?,
-                ))
-            } else {
-                Err(format!(
-                    "String '{}' starts with '-' but not with '-0x'",
-                    s.0
-                ))
-            }
-        } else if s.0.starts_with("0x") {
-            Ok(Integer::from(
-                Natural::from_string_base(16, &s.0[2..])
-                    .ok_or_else(|| format!("Unrecognized digits in {}", s.0))?,
-            ))
+impl<'de> Deserialize<'de> for Integer {
+    /// Deserializes an [`Integer`], in the form produced by its [`Serialize`] implementation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the length of the serialized
+    /// data.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(
+    ///     serde_json::from_str::<Integer>("\"-0xe8d4a51000\"").unwrap(),
+    ///     -1000000000000i64
+    /// );
+    /// assert!(serde_json::from_str::<Integer>("\"-e8d4a51000\"").is_err());
+    /// ```
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Integer, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(IntegerVisitor)
         } else {
-            Err(format!(
-                "String '{}' does not start with '0x' or '-0x'",
-                s.0
-            ))
+            deserializer.deserialize_bytes(IntegerVisitor)
         }
     }
 }
@@ -0,0 +1,57 @@
+use crate::integer::Integer;
+use crate::natural::conversion::string::from_literal::natural_from_unsigned_literal;
+use malachite_base::num::conversion::string::parse_error::ParseNumberError;
+
+impl Integer {
+    /// Converts a string in the style of a Rust or C integer literal to an [`Integer`].
+    ///
+    /// The string may begin with a `'+'` or `'-'`, followed by an optional radix prefix: `"0x"`
+    /// for hexadecimal, `"0o"` for octal, or `"0b"` for binary. The letter in the prefix may be
+    /// lowercase or uppercase. Without a prefix, the string is parsed as decimal. The digits may
+    /// be separated by any number of underscores, which are ignored; an underscore may also
+    /// follow the prefix, but it may not begin an unprefixed string. Hexadecimal digits may be
+    /// lowercase or uppercase. Type suffixes such as `i64` and C-style octal literals with a
+    /// leading `0` are not supported.
+    ///
+    /// Strings with a power-of-2 radix are converted directly to limbs, without any
+    /// multiplications.
+    ///
+    /// If the string is invalid, a [`ParseNumberError`] describing the problem is returned. The
+    /// byte offset of an invalid digit refers to the original string, including any sign, prefix,
+    /// and underscores.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from_literal("-1_000_000").unwrap(), -1000000);
+    /// assert_eq!(Integer::from_literal("+0xdead_BEEF").unwrap(), 3735928559u32);
+    /// assert_eq!(Integer::from_literal("-0o755").unwrap(), -493);
+    /// assert_eq!(Integer::from_literal("-0B1010_1010").unwrap(), -170);
+    ///
+    /// let kind = |s| Integer::from_literal(s).unwrap_err().kind();
+    /// assert_eq!(kind("-"), ParseNumberErrorKind::Empty);
+    /// assert_eq!(kind("--1"), ParseNumberErrorKind::InvalidDigit(1));
+    /// assert_eq!(kind("-0x-1"), ParseNumberErrorKind::InvalidDigit(3));
+    /// assert_eq!(kind("-0o78"), ParseNumberErrorKind::InvalidDigit(4));
+    /// ```
+    pub fn from_literal(s: &str) -> Result<Integer, ParseNumberError> {
+        match s.as_bytes().first() {
+            Some(b'+') => natural_from_unsigned_literal(&s[1..])
+                .map(Integer::from)
+                .map_err(|e| e.offset_by(1)),
+            Some(b'-') => natural_from_unsigned_literal(&s[1..])
+                .map(|x| -x)
+                .map_err(|e| e.offset_by(1)),
+            _ => natural_from_unsigned_literal(s).map(Integer::from),
+        }
+    }
+}
@@ -4,6 +4,7 @@ use crate::natural::conversion::string::from_sci_string::{
 };
 use malachite_base::num::basic::traits::One;
 use malachite_base::num::conversion::string::options::FromSciStringOptions;
+use malachite_base::num::conversion::string::parse_error::ParseNumberError;
 use malachite_base::num::conversion::traits::{FromSciString, FromStringBase};
 
 impl FromSciStringHelper for Integer {
@@ -16,7 +17,7 @@ impl FromSciStringHelper for Integer {
                 _ => {}
             }
         }
-        Integer::from_string_base(base, std::str::from_utf8(cs).ok()?)
+        Integer::from_string_base(base, std::str::from_utf8(cs).ok()?).ok()
     }
 
     fn up_1(self, neg: bool) -> Option<Integer> {
@@ -49,8 +50,10 @@ impl FromSciString for Integer {
     /// exponents, but they may be used on their own. If the string represents a non-integer, the
     /// rounding mode specified in `options` is used to round to an integer.
     ///
-    /// If the string is unparseable, `None` is returned. `None` is also returned if the rounding
-    /// mode in options is `Exact`, but rounding is necessary.
+    /// If the string is unparseable, a [`ParseNumberError`] describing the problem is returned. An
+    /// error of kind
+    /// [`Inexact`](malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind::Inexact)
+    /// is returned if the rounding mode in options is `Exact`, but rounding is necessary.
     ///
     /// # Worst-case complexity
     /// $T(n, m) = O(m^n n \log m (\log n + \log\log m))$
@@ -62,6 +65,7 @@ impl FromSciString for Integer {
     /// # Examples
     /// ```
     /// use malachite_base::num::conversion::string::options::FromSciStringOptions;
+    /// use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
     /// use malachite_base::num::conversion::traits::FromSciString;
     /// use malachite_base::rounding_modes::RoundingMode;
     /// use malachite_nz::integer::Integer;
@@ -80,9 +84,21 @@ impl FromSciString for Integer {
     /// options = FromSciStringOptions::default();
     /// options.set_base(16);
     /// assert_eq!(Integer::from_sci_string_with_options("ff", options).unwrap(), 255);
+    ///
+    /// assert_eq!(
+    ///     Integer::from_sci_string("12.3.4").unwrap_err().kind(),
+    ///     ParseNumberErrorKind::InvalidDigit(4)
+    /// );
+    /// assert_eq!(
+    ///     Integer::from_sci_string("1e").unwrap_err().kind(),
+    ///     ParseNumberErrorKind::Empty
+    /// );
     /// ```
     #[inline]
-    fn from_sci_string_with_options(s: &str, options: FromSciStringOptions) -> Option<Integer> {
+    fn from_sci_string_with_options(
+        s: &str,
+        options: FromSciStringOptions,
+    ) -> Result<Integer, ParseNumberError> {
         from_sci_string_with_options_helper(s, options)
     }
 }
@@ -1,18 +1,20 @@
 use crate::integer::Integer;
 use crate::natural::Natural;
+use malachite_base::num::conversion::string::from_string::string_base_error;
+use malachite_base::num::conversion::string::parse_error::ParseNumberError;
 use malachite_base::num::conversion::traits::FromStringBase;
 use std::ops::Neg;
 use std::str::FromStr;
 
 impl FromStr for Integer {
-    type Err = ();
+    type Err = ParseNumberError;
 
     /// Converts an string to an [`Integer`].
     ///
     /// If the string does not represent a valid [`Integer`], an `Err` is returned. To be valid,
     /// the string must be nonempty and only contain the [`char`]s `'0'` through `'9'`, with an
     /// optional leading `'-'`. Leading zeros are allowed, as is the string `"-0"`. The string
-    /// `"-"` is not.
+    /// `"-"` is not. The [`ParseNumberError`] describes why the string is invalid.
     ///
     /// # Worst-case complexity
     /// $T(n) = O(n (\log n)^2 \log\log n)$
@@ -23,6 +25,7 @@ impl FromStr for Integer {
     ///
     /// # Examples
     /// ```
+    /// use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
     /// use malachite_nz::integer::Integer;
     /// use std::str::FromStr;
     ///
@@ -35,10 +38,14 @@ impl FromStr for Integer {
     ///
     /// assert!(Integer::from_str("").is_err());
     /// assert!(Integer::from_str("a").is_err());
+    /// assert_eq!(
+    ///     Integer::from_str("-12a4").unwrap_err().kind(),
+    ///     ParseNumberErrorKind::InvalidDigit(3)
+    /// );
     /// ```
     #[inline]
-    fn from_str(s: &str) -> Result<Integer, ()> {
-        Integer::from_string_base(10, s).ok_or(())
+    fn from_str(s: &str) -> Result<Integer, ParseNumberError> {
+        Integer::from_string_base(10, s)
     }
 }
 
@@ -49,7 +56,8 @@ impl FromStringBase for Integer {
     /// the string must be nonempty and only contain the [`char`]s `'0'` through `'9'`, `'a'`
     /// through `'z'`, and `'A'` through `'Z'`, with an optional leading `'-'`; and only characters
     /// that represent digits smaller than the base are allowed. Leading zeros are allowed, as is
-    /// the string `"-0"`. The string `"-"` is not.
+    /// the string `"-0"`. The string `"-"` is not. If `base` is less than 2 or greater than 36, an
+    /// error of kind [`InvalidBase`](ParseNumberErrorKind::InvalidBase) is returned.
     ///
     /// # Worst-case complexity
     /// $T(n) = O(n (\log n)^2 \log\log n)$
@@ -58,11 +66,9 @@ impl FromStringBase for Integer {
     ///
     /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
     ///
-    /// # Panics
-    /// Panics if `base` is less than 2 or greater than 36.
-    ///
     /// # Examples
     /// ```
+    /// use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
     /// use malachite_base::num::conversion::traits::{Digits, FromStringBase};
     /// use malachite_nz::integer::Integer;
     ///
@@ -89,15 +95,21 @@ impl FromStringBase for Integer {
     ///     -3735928559i64
     /// );
     ///
-    /// assert!(Integer::from_string_base(10, "").is_none());
-    /// assert!(Integer::from_string_base(10, "a").is_none());
-    /// assert!(Integer::from_string_base(2, "2").is_none());
-    /// assert!(Integer::from_string_base(2, "-2").is_none());
+    /// let kind = |base, s| Integer::from_string_base(base, s).unwrap_err().kind();
+    /// assert_eq!(kind(10, ""), ParseNumberErrorKind::Empty);
+    /// assert_eq!(kind(10, "-"), ParseNumberErrorKind::Empty);
+    /// assert_eq!(kind(10, "a"), ParseNumberErrorKind::InvalidDigit(0));
+    /// assert_eq!(kind(2, "2"), ParseNumberErrorKind::InvalidDigit(0));
+    /// assert_eq!(kind(2, "-2"), ParseNumberErrorKind::InvalidDigit(1));
+    /// assert_eq!(kind(10, "--2"), ParseNumberErrorKind::InvalidDigit(1));
+    /// assert_eq!(kind(1, "0"), ParseNumberErrorKind::InvalidBase(1));
     /// ```
     #[inline]
-    fn from_string_base(base: u8, s: &str) -> Option<Integer> {
+    fn from_string_base(base: u8, s: &str) -> Result<Integer, ParseNumberError> {
         if let Some(abs_string) = s.strip_prefix('-') {
-            Natural::from_string_base(base, abs_string).map(Neg::neg)
+            Natural::from_string_base(base, abs_string)
+                .map(Neg::neg)
+                .map_err(|_| string_base_error(s, base, true, false))
         } else {
             Natural::from_string_base(base, s).map(Integer::from)
         }
@@ -1,3 +1,6 @@
+/// [`Integer::from_literal`](crate::integer::Integer::from_literal), a function for parsing
+/// strings in the style of Rust or C integer literals.
+pub mod from_literal;
 /// Implementations of [`FromSciString`](malachite_base::num::conversion::traits::FromSciString).
 /// This is a trait for converting strings, possibly using scientific notation, to numbers.
 pub mod from_sci_string;
@@ -5,6 +8,10 @@ pub mod from_sci_string;
 /// [`FromStringBase`](malachite_base::num::conversion::traits::FromStringBase), a trait for
 /// converting strings in a specified base to numbers.
 pub mod from_string;
+/// Implementations of
+/// [`ToFormattedString`](malachite_base::num::conversion::traits::ToFormattedString), a trait for
+/// converting a number to a string with grouped digits and custom separators.
+pub mod to_formatted_string;
 /// Implementations of [`ToSci`](malachite_base::num::conversion::traits::ToSci), a trait for
 /// converting a number to string, possibly using scientific notation.
 pub mod to_sci;
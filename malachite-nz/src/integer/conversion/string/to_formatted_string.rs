@@ -0,0 +1,53 @@
+use crate::integer::Integer;
+use malachite_base::num::conversion::string::options::FormatOptions;
+use malachite_base::num::conversion::string::to_formatted_string::format_digits;
+use malachite_base::num::conversion::traits::{ToFormattedString, ToStringBase};
+
+impl ToFormattedString for Integer {
+    /// Converts an [`Integer`] to a [`String`], using the specified [`FormatOptions`].
+    ///
+    /// The digits before the point are grouped and separated according to the options, and a
+    /// negative [`Integer`] is preceded by a `'-'`. Since an [`Integer`] is never rounded, if the
+    /// options specify a number of fraction digits, that many zeros are written after the point.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`, plus
+    /// the number of fraction digits in `options`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::options::{DigitGrouping, FormatOptions};
+    /// use malachite_base::num::conversion::traits::ToFormattedString;
+    /// use malachite_nz::integer::Integer;
+    /// use std::str::FromStr;
+    ///
+    /// let x = Integer::from_str("-1234567890123").unwrap();
+    /// let mut options = FormatOptions::default();
+    /// assert_eq!(x.to_formatted_string(options), "-1234567890123");
+    ///
+    /// options.set_digit_grouping(DigitGrouping::Uniform(3));
+    /// assert_eq!(x.to_formatted_string(options), "-1,234,567,890,123");
+    /// assert_eq!(Integer::from(-123).to_formatted_string(options), "-123");
+    ///
+    /// options.set_digit_grouping(DigitGrouping::Indian);
+    /// assert_eq!(x.to_formatted_string(options), "-12,34,56,78,90,123");
+    ///
+    /// options.set_digit_grouping(DigitGrouping::Uniform(3));
+    /// options.set_group_separator('.');
+    /// options.set_decimal_separator(',');
+    /// options.set_fraction_digits(2);
+    /// assert_eq!(x.to_formatted_string(options), "-1.234.567.890.123,00");
+    /// ```
+    fn to_formatted_string(&self, options: FormatOptions) -> String {
+        format_digits(
+            *self < 0,
+            &self.unsigned_abs_ref().to_string_base(options.get_base()),
+            "",
+            options,
+        )
+    }
+}
@@ -1,7 +1,8 @@
 use crate::integer::Integer;
 use malachite_base::num::conversion::string::options::ToSciOptions;
+use malachite_base::num::conversion::string::to_sci::{exp_options, pad_exp};
 use malachite_base::num::conversion::traits::ToSci;
-use std::fmt::{Formatter, Write};
+use std::fmt::{Formatter, LowerExp, UpperExp, Write};
 
 impl ToSci for Integer {
     /// Determines whether an [`Integer`] can be converted to a string using
@@ -104,3 +105,71 @@ impl ToSci for Integer {
         }
     }
 }
+
+impl LowerExp for Integer {
+    /// Formats an [`Integer`] in scientific notation, using a lowercase `'e'`.
+    ///
+    /// If a precision is specified, the significand is rounded to that many digits after the
+    /// decimal point, using
+    /// [`Nearest`](malachite_base::rounding_modes::RoundingMode::Nearest) rounding. Otherwise,
+    /// the number is written exactly, with trailing zeros removed from the significand. The width,
+    /// fill, alignment, sign, and zero-padding flags work as they do for primitive integers.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(format!("{:e}", Integer::from(123456)), "1.23456e5");
+    /// assert_eq!(format!("{:e}", Integer::from(-123456)), "-1.23456e5");
+    /// assert_eq!(format!("{:.2e}", Integer::from(-123456)), "-1.23e5");
+    /// assert_eq!(format!("{:+e}", Integer::from(1000)), "+1e3");
+    /// assert_eq!(format!("{:>10e}", Integer::from(-1000)), "      -1e3");
+    /// assert_eq!(format!("{:010e}", Integer::from(-1000)), "-0000001e3");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let s = self
+            .unsigned_abs_ref()
+            .to_sci_with_options(exp_options(f, true))
+            .to_string();
+        pad_exp(f, *self >= 0, &s, true)
+    }
+}
+
+impl UpperExp for Integer {
+    /// Formats an [`Integer`] in scientific notation, using an uppercase `'E'`.
+    ///
+    /// If a precision is specified, the significand is rounded to that many digits after the
+    /// decimal point, using
+    /// [`Nearest`](malachite_base::rounding_modes::RoundingMode::Nearest) rounding. Otherwise,
+    /// the number is written exactly, with trailing zeros removed from the significand. The width,
+    /// fill, alignment, sign, and zero-padding flags work as they do for primitive integers.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(format!("{:E}", Integer::from(-123456)), "-1.23456E5");
+    /// assert_eq!(format!("{:.3E}", Integer::from(-123456)), "-1.235E5");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let s = self
+            .unsigned_abs_ref()
+            .to_sci_with_options(exp_options(f, true))
+            .to_string();
+        pad_exp(f, *self >= 0, &s, false)
+    }
+}
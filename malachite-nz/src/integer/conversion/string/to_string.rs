@@ -5,7 +5,7 @@ use malachite_base::num::conversion::string::to_string::{
 };
 use malachite_base::num::conversion::traits::{Digits, ToStringBase};
 use malachite_base::vecs::vec_pad_left;
-use std::fmt::{Binary, Debug, Display, Formatter, LowerHex, Octal, Result, UpperHex, Write};
+use std::fmt::{Binary, Debug, Display, Formatter, LowerHex, Octal, Result, UpperHex};
 
 impl<'a> Display for BaseFmtWrapper<&'a Integer> {
     /// Writes a wrapped [`Integer`] to a string using a specified base.
@@ -36,30 +36,13 @@ impl<'a> Display for BaseFmtWrapper<&'a Integer> {
     /// assert_eq!(format!("{:#010}", x), "-000GJDGXS");
     /// ```
     fn fmt(&self, f: &mut Formatter) -> Result {
-        if !self.x.sign {
-            f.write_char('-')?;
-            if let Some(width) = f.width() {
-                return if f.alternate() {
-                    write!(
-                        f,
-                        "{:#0width$}",
-                        &BaseFmtWrapper::new(self.x.unsigned_abs_ref(), self.base),
-                        width = width.saturating_sub(1)
-                    )
-                } else {
-                    write!(
-                        f,
-                        "{:0width$}",
-                        &BaseFmtWrapper::new(self.x.unsigned_abs_ref(), self.base),
-                        width = width.saturating_sub(1)
-                    )
-                };
-            }
-        }
-        Display::fmt(
-            &BaseFmtWrapper::new(self.x.unsigned_abs_ref(), self.base),
-            f,
-        )
+        let abs = self.x.unsigned_abs_ref();
+        let digits = if f.alternate() {
+            abs.to_string_base_upper(self.base)
+        } else {
+            abs.to_string_base(self.base)
+        };
+        f.pad_integral(self.x.sign, "", &digits)
     }
 }
 
@@ -220,20 +203,15 @@ impl Display for Integer {
     ///     "-1000000000000"
     /// );
     /// assert_eq!(format!("{:05}", Integer::from(-123)), "-0123");
+    ///
+    /// assert_eq!(format!("{:>8}", Integer::from(-123)), "    -123");
+    /// assert_eq!(format!("{:*<8}", Integer::from(-123)), "-123****");
+    /// assert_eq!(format!("{:^8}", Integer::from(-123)), "  -123  ");
+    /// assert_eq!(format!("{:+}", Integer::from(123)), "+123");
+    /// assert_eq!(format!("{:+06}", Integer::from(123)), "+00123");
     /// ```
     fn fmt(&self, f: &mut Formatter) -> Result {
-        if *self < 0 {
-            f.write_char('-')?;
-            if let Some(width) = f.width() {
-                return write!(
-                    f,
-                    "{:0width$}",
-                    self.unsigned_abs_ref(),
-                    width = width.saturating_sub(1)
-                );
-            }
-        }
-        Display::fmt(self.unsigned_abs_ref(), f)
+        f.pad_integral(*self >= 0, "", &self.unsigned_abs_ref().to_string())
     }
 }
 
@@ -332,29 +310,11 @@ impl Binary for Integer {
     ///     "-0b1110100011010100101001010001000000000000"
     /// );
     /// assert_eq!(format!("{:#011b}", Integer::from(-123)), "-0b01111011");
+    /// assert_eq!(format!("{:>#13b}", Integer::from(-123)), "   -0b1111011");
+    /// assert_eq!(format!("{:+#b}", Integer::from(123)), "+0b1111011");
     /// ```
     fn fmt(&self, f: &mut Formatter) -> Result {
-        if *self < 0 {
-            f.write_char('-')?;
-            if let Some(width) = f.width() {
-                return if f.alternate() {
-                    write!(
-                        f,
-                        "{:#0width$b}",
-                        self.unsigned_abs_ref(),
-                        width = width.saturating_sub(1)
-                    )
-                } else {
-                    write!(
-                        f,
-                        "{:0width$b}",
-                        self.unsigned_abs_ref(),
-                        width = width.saturating_sub(1)
-                    )
-                };
-            }
-        }
-        Binary::fmt(self.unsigned_abs_ref(), f)
+        f.pad_integral(*self >= 0, "0b", &format!("{:b}", self.unsigned_abs_ref()))
     }
 }
 
@@ -410,27 +370,7 @@ impl Octal for Integer {
     /// assert_eq!(format!("{:#07o}", Integer::from(-123)), "-0o0173");
     /// ```
     fn fmt(&self, f: &mut Formatter) -> Result {
-        if *self < 0 {
-            f.write_char('-')?;
-            if let Some(width) = f.width() {
-                return if f.alternate() {
-                    write!(
-                        f,
-                        "{:#0width$o}",
-                        self.unsigned_abs_ref(),
-                        width = width.saturating_sub(1)
-                    )
-                } else {
-                    write!(
-                        f,
-                        "{:0width$o}",
-                        self.unsigned_abs_ref(),
-                        width = width.saturating_sub(1)
-                    )
-                };
-            }
-        }
-        Octal::fmt(self.unsigned_abs_ref(), f)
+        f.pad_integral(*self >= 0, "0o", &format!("{:o}", self.unsigned_abs_ref()))
     }
 }
 
@@ -486,27 +426,7 @@ impl LowerHex for Integer {
     /// assert_eq!(format!("{:#07x}", Integer::from(-123)), "-0x007b");
     /// ```
     fn fmt(&self, f: &mut Formatter) -> Result {
-        if *self < 0 {
-            f.write_char('-')?;
-            if let Some(width) = f.width() {
-                return if f.alternate() {
-                    write!(
-                        f,
-                        "{:#0width$x}",
-                        self.unsigned_abs_ref(),
-                        width = width.saturating_sub(1)
-                    )
-                } else {
-                    write!(
-                        f,
-                        "{:0width$x}",
-                        self.unsigned_abs_ref(),
-                        width = width.saturating_sub(1)
-                    )
-                };
-            }
-        }
-        LowerHex::fmt(self.unsigned_abs_ref(), f)
+        f.pad_integral(*self >= 0, "0x", &format!("{:x}", self.unsigned_abs_ref()))
     }
 }
 
@@ -562,26 +482,6 @@ impl UpperHex for Integer {
     /// assert_eq!(format!("{:#07X}", Integer::from(-123)), "-0x007B");
     /// ```
     fn fmt(&self, f: &mut Formatter) -> Result {
-        if *self < 0 {
-            f.write_char('-')?;
-            if let Some(width) = f.width() {
-                return if f.alternate() {
-                    write!(
-                        f,
-                        "{:#0width$X}",
-                        self.unsigned_abs_ref(),
-                        width = width.saturating_sub(1)
-                    )
-                } else {
-                    write!(
-                        f,
-                        "{:0width$X}",
-                        self.unsigned_abs_ref(),
-                        width = width.saturating_sub(1)
-                    )
-                };
-            }
-        }
-        UpperHex::fmt(self.unsigned_abs_ref(), f)
+        f.pad_integral(*self >= 0, "0x", &format!("{:X}", self.unsigned_abs_ref()))
     }
 }
@@ -1,5 +1,6 @@
 use crate::integer::Integer;
 use crate::natural::arithmetic::add::{limbs_add_limb_to_out, limbs_slice_add_limb_in_place};
+use crate::natural::limb_vec::LimbVec;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::Limb;
@@ -730,7 +731,7 @@ impl Natural {
     fn and_pos_limb_neg(&self, other: Limb) -> Natural {
         Natural(match *self {
             Natural(Small(small)) => Small(small & other),
-            Natural(Large(ref limbs)) => Large(limbs_pos_and_limb_neg(limbs, other)),
+            Natural(Large(ref limbs)) => Large(limbs_pos_and_limb_neg(limbs, other).into()),
         })
     }
 
@@ -740,12 +741,14 @@ impl Natural {
             Natural(Small(ref mut small)) => {
                 let result = small.wrapping_neg() & other;
                 if result == 0 {
-                    *self = Natural(Large(vec![0, 1]));
+                    *self = Natural(Large(LimbVec::from_slice(&[0, 1])));
                 } else {
                     *small = result.wrapping_neg();
                 }
             }
-            Natural(Large(ref mut limbs)) => limbs_vec_neg_and_limb_neg_in_place(limbs, other),
+            Natural(Large(ref mut limbs)) => {
+                limbs_vec_neg_and_limb_neg_in_place(limbs.as_mut_vec(), other)
+            }
         }
     }
 
@@ -772,7 +775,7 @@ impl Natural {
                 *self = Natural(Small(xs[0].wrapping_neg() & *y))
             }
             (Natural(Large(ref mut xs)), Natural(Large(ref ys))) => {
-                limbs_vec_and_pos_neg_in_place_right(ys, xs);
+                limbs_vec_and_pos_neg_in_place_right(ys, xs.as_mut_vec());
                 self.trim();
             }
         }
@@ -795,12 +798,12 @@ impl Natural {
             Natural(Small(small)) => {
                 let result = small.wrapping_neg() & other;
                 if result == 0 {
-                    Large(vec![0, 1])
+                    Large(LimbVec::from_slice(&[0, 1]))
                 } else {
                     Small(result.wrapping_neg())
                 }
             }
-            Natural(Large(ref limbs)) => Large(limbs_neg_and_limb_neg(limbs, other)),
+            Natural(Large(ref limbs)) => Large(limbs_neg_and_limb_neg(limbs, other).into()),
         })
     }
 
@@ -809,7 +812,7 @@ impl Natural {
             (Natural(Small(x)), _) => *self = other.and_neg_limb_neg(x.wrapping_neg()),
             (_, Natural(Small(y))) => self.and_assign_neg_limb_neg(y.wrapping_neg()),
             (Natural(Large(ref mut xs)), Natural(Large(ref mut ys))) => {
-                if limbs_vec_and_neg_neg_in_place_either(xs, ys) {
+                if limbs_vec_and_neg_neg_in_place_either(xs.as_mut_vec(), ys.as_mut_vec()) {
                     *self = other;
                 }
                 self.trim();
@@ -822,7 +825,7 @@ impl Natural {
             (Natural(Small(x)), _) => *self = other.and_neg_limb_neg(x.wrapping_neg()),
             (_, Natural(Small(y))) => self.and_assign_neg_limb_neg(y.wrapping_neg()),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys))) => {
-                limbs_vec_and_neg_neg_in_place_left(xs, ys);
+                limbs_vec_and_neg_neg_in_place_left(xs.as_mut_vec(), ys);
                 self.trim();
             }
         }
@@ -1,6 +1,7 @@
 use crate::integer::Integer;
 use crate::natural::arithmetic::add::limbs_slice_add_limb_in_place;
 use crate::natural::arithmetic::sub::limbs_sub_limb_in_place;
+use crate::natural::limb_vec::LimbVec;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::Limb;
@@ -191,7 +192,7 @@ impl Natural {
                 let mut cleared_small = small.wrapping_neg();
                 cleared_small.clear_bit(index);
                 if cleared_small == 0 {
-                    *self = Natural(Large(vec![0, 1]));
+                    *self = Natural(Large(LimbVec::from_slice(&[0, 1])));
                 } else {
                     *small = cleared_small.wrapping_neg();
                 }
@@ -201,7 +202,7 @@ impl Natural {
                 limbs_vec_clear_bit_neg(limbs, index);
             }
             Natural(Large(ref mut limbs)) => {
-                limbs_vec_clear_bit_neg(limbs, index);
+                limbs_vec_clear_bit_neg(limbs.as_mut_vec(), index);
             }
         }
     }
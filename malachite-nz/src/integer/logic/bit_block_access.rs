@@ -199,7 +199,7 @@ impl Natural {
     fn neg_get_bits_owned(self, start: u64, end: u64) -> Natural {
         Natural::from_owned_limbs_asc(match self {
             Natural(Small(small)) => limbs_neg_limb_get_bits(small, start, end),
-            Natural(Large(limbs)) => limbs_vec_neg_get_bits(limbs, start, end),
+            Natural(Large(limbs)) => limbs_vec_neg_get_bits(limbs.into_vec(), start, end),
         })
     }
 
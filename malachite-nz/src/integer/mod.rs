@@ -5,23 +5,15 @@ use malachite_base::num::basic::traits::{NegativeOne, One, Two, Zero};
 /// An integer.
 ///
 /// Any `Integer` whose absolute value is small enough to fit into a [`Limb`](crate#limbs) is
-/// represented inline. Only integers outside this range incur the costs of heap-allocation.
+/// represented inline, as are the limbs of any `Integer` whose absolute value fits into two
+/// [`Limb`](crate#limbs)s. Only integers outside this range incur the costs of heap-allocation.
 #[derive(Clone, Hash, Eq, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-#[cfg_attr(
-    feature = "serde",
-    serde(try_from = "SerdeInteger", into = "SerdeInteger")
-)]
 pub struct Integer {
     // whether the `Integer` is non-negative
     pub(crate) sign: bool,
     pub(crate) abs: Natural,
 }
 
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "serde", serde(transparent))]
-pub(crate) struct SerdeInteger(String);
-
 impl Integer {
     // Returns true iff `self` is valid.
     //
@@ -17,7 +17,9 @@
 //!     [`ModPow`](malachite_base::num::arithmetic::traits::ModPow), and of traits for arithmetic
 //!     modulo a power of 2, like
 //!     [`ModPowerOf2Add`](malachite_base::num::arithmetic::traits::ModPowerOf2Add) and
-//!     [`ModPowerOf2Pow`](malachite_base::num::arithmetic::traits::ModPowerOf2Pow);
+//!     [`ModPowerOf2Pow`](malachite_base::num::arithmetic::traits::ModPowerOf2Pow), and a
+//!     [`Modulus`](natural::modular::Modulus) type for doing many operations modulo the same
+//!     [`Natural`](natural::Natural);
 //!   - Various functions for logic and bit manipulation, like [`BitAnd`](std::ops::BitAnd) and
 //!     [`BitAccess`](malachite_base::num::logic::traits::BitAccess).
 //! - The implementations of these functions use high-performance algorithms that work efficiently
@@ -28,10 +30,17 @@
 //!   [Schönhage-Strassen (FFT) multiplication](https://en.wikipedia.org/wiki/Schonhage-Strassen_algorithm),
 //!   depending on the input size.
 //! - Small numbers are also handled efficiently. Any [`Natural`](natural::Natural) smaller than
-//!   $2^{64}$ does not use any allocated memory, and working with such numbers is almost as fast
+//!   $2^{128}$ does not use any allocated memory, and working with such numbers is almost as fast
 //!   as working with primitive integers. As a result, Malachite does not provide implementations
 //!   for _e.g._ adding a [`Natural`](natural::Natural) to a [`u64`], since the [`u64`] can be
-//!   converted to a [`Natural`](natural::Natural) very cheaply.
+//!   converted to a [`Natural`](natural::Natural) very cheaply. The limbs of such a
+//!   [`Natural`](natural::Natural) are stored where a pointer to the heap would otherwise be, so a
+//!   [`Natural`](natural::Natural) is still only 24 bytes long on 64-bit platforms.
+//! - When a bound on the size of the numbers is known ahead of time, the fixed-width
+//!   [`Uint`](fixed::Uint) and [`Int`](fixed::Int) types, such as [`U256`](fixed::U256) and
+//!   [`I512`](fixed::I512), can be used instead. They never allocate, and they overflow like
+//!   primitive integers do, with wrapping, overflowing, checked, and saturating variants of the
+//!   arithmetic functions.
 //! - Malachite handles memory intelligently. Consider the problem of adding a 1000-bit
 //!   [`Natural`](natural::Natural) and a 500-bit [`Natural`](natural::Natural). If we only have
 //!   references to the [`Natural`](natural::Natural)s, then we must allocate new memory for the
@@ -54,6 +63,13 @@
 //!   for `&x + &y`, but then that partial sum is taken by _value_ using the `Natural + &Natural`
 //!   implementation described above; so those $n$ bits are reused for the final sum.
 //!
+//!   Products are different: in `&a * &b + &c * &d`, the product `&c * &d` is a temporary value
+//!   that is allocated and then freed. The [`lazy`](natural::lazy) module avoids this. The
+//!   expression `a.lazy() * &b + c.lazy() * &d` is a [`LazyExpr`](natural::lazy::LazyExpr) that
+//!   computes nothing until it's evaluated, and then writes the result directly into its
+//!   destination, computing the products in memory taken from a
+//!   [`Scratch`](natural::scratch::Scratch).
+//!
 //! # Limbs
 //! Large [`Natural`](natural::Natural)s and [`Integer`](integer::Integer)s store their data as
 //! [`Vec`]s of some primitive type. The elements of these [`Vec`]s are called "limbs" in GMP
@@ -96,6 +112,12 @@
 //! # Features
 //! - `32_bit_limbs`: Sets the type of [`Limb`](crate#limbs) to [`u32`] instead of the default,
 //!   [`u64`].
+//! - `parallel`: Uses a thread pool (via [rayon](https://crates.io/crates/rayon)) to speed up
+//!   multiplication and radix conversion of very large numbers. The FFT transforms and pointwise
+//!   products, the three half-length products at the top level of large balanced Toom
+//!   multiplications, the chunks of unbalanced Toom multiplications, the two halves of large limb
+//!   products, and the two halves of divide-and-conquer radix conversions are computed on multiple
+//!   threads. The results are identical to those computed without this feature.
 //! - `test_build`: A large proportion of the code in this crate is only used for testing. For a
 //!   typical user, building this code would result in an unnecessarily long compilation time and
 //!   an unnecessarily large binary. Some of it is also used for testing `malachite-q`, so it can't
@@ -146,9 +168,10 @@ extern crate itertools;
 #[macro_use]
 extern crate malachite_base;
 #[cfg(feature = "serde")]
-#[macro_use]
 extern crate serde;
 
+#[cfg(feature = "parallel")]
+extern crate rayon;
 #[cfg(feature = "test_build")]
 extern crate num;
 #[cfg(feature = "test_build")]
@@ -162,21 +185,42 @@ pub use crate::platform_64 as platform;
 pub use platform_32 as platform;
 
 #[doc(hidden)]
-#[cfg(feature = "32_bit_limbs")]
+#[cfg(all(feature = "32_bit_limbs", not(malachite_nz_tuned_platform)))]
 pub mod platform_32;
 #[doc(hidden)]
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(all(not(feature = "32_bit_limbs"), not(malachite_nz_tuned_platform)))]
 pub mod platform_64;
 
+// A platform file generated by `cargo run --release --features bin_build -- -g tuneup`, selected
+// by setting `MALACHITE_NZ_PLATFORM` at build time; see `build.rs`.
+#[doc(hidden)]
+#[cfg(all(feature = "32_bit_limbs", malachite_nz_tuned_platform))]
+pub mod platform_32 {
+    include!(concat!(env!("OUT_DIR"), "/platform.rs"));
+}
+#[doc(hidden)]
+#[cfg(all(not(feature = "32_bit_limbs"), malachite_nz_tuned_platform))]
+pub mod platform_64 {
+    include!(concat!(env!("OUT_DIR"), "/platform.rs"));
+}
+
 #[cfg(feature = "doc-images")]
 extern crate embed_doc_image;
 
+pub(crate) mod parallel;
+
+#[doc(hidden)]
+pub mod tuning;
+
 /// [`Natural`](natural::Natural), a type representing arbitrarily large non-negative integers.
 #[macro_use]
 pub mod natural;
 /// [`Integer`](integer::Integer), a type representing integers with arbitrarily large absolute
 /// values.
 pub mod integer;
+/// [`Uint`](fixed::Uint) and [`Int`](fixed::Int), fixed-width integer types whose limbs are stored
+/// inline.
+pub mod fixed;
 
 #[cfg(feature = "test_build")]
 pub mod test_util;
@@ -1,10 +1,12 @@
 use crate::natural::arithmetic::shl::{limbs_shl, limbs_vec_shl_in_place};
+use crate::natural::limb_vec::{LimbVec, INLINE_LIMB_COUNT};
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::Limb;
 use malachite_base::num::arithmetic::traits::OverflowingAddAssign;
 use malachite_base::num::basic::traits::Zero;
 use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use std::cmp::max;
 use std::iter::Sum;
 use std::ops::{Add, AddAssign};
 
@@ -193,6 +195,32 @@ pub_crate_test! {limbs_add(xs: &[Limb], ys: &[Limb]) -> Vec<Limb> {
     }
 }}
 
+// Interpreting two slices of `Limb`s as the limbs (in ascending order) of two `Natural`s, returns
+// the limbs of the sum of the `Natural`s, stored inline if there are few enough of them.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `max(xs.len(), ys.len())`.
+fn limbs_add_to_limb_vec(xs: &[Limb], ys: &[Limb]) -> LimbVec {
+    let len = max(xs.len(), ys.len());
+    if len <= INLINE_LIMB_COUNT {
+        let mut carry = false;
+        let mut sum = LimbVec::from_inline_fn(|out| {
+            carry = limbs_add_to_out(out, xs, ys);
+            len
+        });
+        if carry {
+            sum.push(1);
+        }
+        sum
+    } else {
+        limbs_add(xs, ys).into()
+    }
+}
+
 // Interpreting two equal-length slices of `Limb`s as the limbs (in ascending order) of two
 // `Natural`s, writes the `xs.len()` least-significant limbs of the sum of the `Natural`s to an
 // output slice. The output must be at least as long as one of the input slices. Returns whether
@@ -523,9 +551,17 @@ impl Natural {
             (x, 0) => x.clone(),
             (Natural(Small(small)), other) => match small.overflowing_add(other) {
                 (sum, false) => Natural::from(sum),
-                (sum, true) => Natural(Large(vec![sum, 1])),
+                (sum, true) => Natural(Large(LimbVec::from_slice(&[sum, 1]))),
             },
-            (Natural(Large(ref limbs)), other) => Natural(Large(limbs_add_limb(limbs, other))),
+            (Natural(Large(ref limbs)), other) => {
+                if limbs.is_inline() {
+                    let mut sum = self.clone();
+                    sum.add_assign_limb(other);
+                    sum
+                } else {
+                    Natural(Large(limbs_add_limb(limbs, other).into()))
+                }
+            }
         }
     }
 
@@ -536,13 +572,15 @@ impl Natural {
             (&mut Natural(Small(ref mut small)), other) => {
                 let (sum, overflow) = small.overflowing_add(other);
                 if overflow {
-                    *self = Natural(Large(vec![sum, 1]));
+                    *self = Natural(Large(LimbVec::from_slice(&[sum, 1])));
                 } else {
                     *small = sum;
                 }
             }
             (&mut Natural(Large(ref mut limbs)), other) => {
-                limbs_vec_add_limb_in_place(limbs, other);
+                if limbs_slice_add_limb_in_place(limbs, other) {
+                    limbs.push(1);
+                }
             }
         }
     }
@@ -711,7 +749,9 @@ impl<'a, 'b> Add<&'a Natural> for &'b Natural {
         match (self, other) {
             (x, &Natural(Small(y))) => x.add_limb_ref(y),
             (&Natural(Small(x)), y) => y.add_limb_ref(x),
-            (&Natural(Large(ref xs)), &Natural(Large(ref ys))) => Natural(Large(limbs_add(xs, ys))),
+            (&Natural(Large(ref xs)), &Natural(Large(ref ys))) => {
+                Natural(Large(limbs_add_to_limb_vec(xs, ys)))
+            }
         }
     }
 }
@@ -750,7 +790,9 @@ impl AddAssign<Natural> for Natural {
             (x, &mut Natural(Small(y))) => x.add_assign_limb(y),
             (&mut Natural(Small(x)), y) => *self = y.add_limb_ref(x),
             (&mut Natural(Large(ref mut xs)), &mut Natural(Large(ref mut ys))) => {
-                if limbs_vec_add_in_place_either(xs, ys) {
+                if xs.is_inline() && ys.is_inline() {
+                    *xs = limbs_add_to_limb_vec(xs, ys);
+                } else if limbs_vec_add_in_place_either(xs.as_mut_vec(), ys.as_mut_vec()) {
                     *self = other;
                 }
             }
@@ -792,7 +834,11 @@ impl<'a> AddAssign<&'a Natural> for Natural {
             (x, &Natural(Small(y))) => x.add_assign_limb(y),
             (&mut Natural(Small(x)), y) => *self = y.add_limb_ref(x),
             (&mut Natural(Large(ref mut xs)), &Natural(Large(ref ys))) => {
-                limbs_vec_add_in_place_left(xs, ys);
+                if xs.is_inline() {
+                    *xs = limbs_add_to_limb_vec(xs, ys);
+                } else {
+                    limbs_vec_add_in_place_left(xs.as_mut_vec(), ys);
+                }
             }
         }
     }
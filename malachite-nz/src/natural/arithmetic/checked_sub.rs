@@ -1,7 +1,8 @@
 use crate::natural::arithmetic::sub::{
-    limbs_sub, limbs_sub_greater_in_place_left, limbs_sub_limb, limbs_sub_limb_in_place,
-    limbs_vec_sub_in_place_right,
+    limbs_sub, limbs_sub_greater_in_place_left, limbs_sub_greater_to_out, limbs_sub_limb,
+    limbs_sub_limb_in_place, limbs_vec_sub_in_place_right,
 };
+use crate::natural::limb_vec::{LimbVec, INLINE_LIMB_COUNT};
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::Limb;
@@ -24,6 +25,10 @@ impl Natural {
             (Natural(Large(ref limbs)), other) => {
                 if *self < other {
                     None
+                } else if limbs.is_inline() {
+                    let mut diff = self.clone();
+                    assert!(!diff.sub_assign_limb_no_panic(other));
+                    Some(diff)
                 } else {
                     Some(Natural::from_owned_limbs_asc(
                         limbs_sub_limb(limbs, other).0,
@@ -107,7 +112,18 @@ impl Natural {
             }),
             (_, Natural(Small(_))) => true,
             (&mut Natural(Large(ref mut xs)), &Natural(Large(ref ys))) => {
-                let borrow = xs.len() > ys.len() || limbs_vec_sub_in_place_right(ys, xs);
+                let borrow = if xs.len() > ys.len() {
+                    true
+                } else if xs.is_inline() && ys.len() <= INLINE_LIMB_COUNT {
+                    let mut borrow = false;
+                    *xs = LimbVec::from_inline_fn(|out| {
+                        borrow = limbs_sub_greater_to_out(out, ys, xs);
+                        ys.len()
+                    });
+                    borrow
+                } else {
+                    limbs_vec_sub_in_place_right(ys, xs.as_mut_vec())
+                };
                 if !borrow {
                     self.trim();
                 }
@@ -311,6 +327,10 @@ impl<'a, 'b> CheckedSub<&'a Natural> for &'b Natural {
             (&Natural(Large(ref xs)), &Natural(Large(ref ys))) => {
                 if self < other {
                     None
+                } else if xs.is_inline() {
+                    let mut diff = self.clone();
+                    assert!(!diff.sub_assign_ref_no_panic(other));
+                    Some(diff)
                 } else {
                     Some(Natural::from_owned_limbs_asc(limbs_sub(xs, ys).0))
                 }
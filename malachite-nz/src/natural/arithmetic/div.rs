@@ -2212,7 +2212,7 @@ impl DivAssign<Natural> for Natural {
                 } else {
                     let mut qs = vec![0; ns_len - ds_len + 1];
                     limbs_div_to_out(&mut qs, ns, ds);
-                    swap(&mut qs, ns);
+                    swap(&mut qs, ns.as_mut_vec());
                     self.trim();
                 }
             }
@@ -2268,7 +2268,7 @@ impl<'a> DivAssign<&'a Natural> for Natural {
                 } else {
                     let mut qs = vec![0; ns_len - ds_len + 1];
                     limbs_div_to_out_val_ref(&mut qs, ns, ds);
-                    swap(&mut qs, ns);
+                    swap(&mut qs, ns.as_mut_vec());
                     self.trim();
                 }
             }
@@ -1044,7 +1044,9 @@ pub_crate_test! {limbs_modular_div_schoolbook(
         qs = &mut qs[limit..];
         let q = d_inv.wrapping_mul(ns[0]);
         let (ns_lo, ns_hi) = ns.split_at_mut(d_len);
-        let hi = carry + limbs_slice_add_mul_limb_same_length_in_place_left(ns_lo, ds, q);
+        let hi = carry.wrapping_add(limbs_slice_add_mul_limb_same_length_in_place_left(
+            ns_lo, ds, q,
+        ));
         qs[0] = q;
         ns_hi[0].wrapping_add_assign(hi);
         ns = &mut ns[1..];
@@ -1090,7 +1092,9 @@ pub fn limbs_modular_div_schoolbook_in_place(mut ns: &mut [Limb], ds: &[Limb], d
         ns = &mut ns[limit..];
         let q = d_inv.wrapping_mul(ns[0]);
         let (ns_lo, ns_hi) = ns.split_at_mut(d_len);
-        let hi = carry + limbs_slice_add_mul_limb_same_length_in_place_left(ns_lo, ds, q);
+        let hi = carry.wrapping_add(limbs_slice_add_mul_limb_same_length_in_place_left(
+            ns_lo, ds, q,
+        ));
         ns_lo[0] = q;
         ns_hi[0].wrapping_add_assign(hi);
         ns = &mut ns[1..];
@@ -2129,7 +2133,7 @@ impl DivExactAssign<Natural> for Natural {
                 } else {
                     let mut qs = vec![0; ns_len - ds_len + 1];
                     limbs_div_exact_to_out(&mut qs, ns, ds);
-                    swap(&mut qs, ns);
+                    swap(&mut qs, ns.as_mut_vec());
                     self.trim();
                 }
             }
@@ -2195,7 +2199,7 @@ impl<'a> DivExactAssign<&'a Natural> for Natural {
                 } else {
                     let mut qs = vec![0; ns_len - ds_len + 1];
                     limbs_div_exact_to_out_val_ref(&mut qs, ns, ds);
-                    swap(&mut qs, ns);
+                    swap(&mut qs, ns.as_mut_vec());
                     self.trim();
                 }
             }
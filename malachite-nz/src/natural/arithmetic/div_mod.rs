@@ -2051,7 +2051,7 @@ impl<'a> DivMod<Natural> for &'a Natural {
                     (Natural::ZERO, self.clone())
                 } else {
                     let (qs, mut rs) = limbs_div_mod(ns, ds);
-                    swap(&mut rs, ds);
+                    swap(&mut rs, ds.as_mut_vec());
                     other.trim();
                     (Natural::from_owned_limbs_asc(qs), other)
                 }
@@ -2194,8 +2194,8 @@ impl DivAssignMod<Natural> for Natural {
                     r
                 } else {
                     let (mut qs, mut rs) = limbs_div_mod(ns, ds);
-                    swap(&mut qs, ns);
-                    swap(&mut rs, ds);
+                    swap(&mut qs, ns.as_mut_vec());
+                    swap(&mut rs, ds.as_mut_vec());
                     self.trim();
                     other.trim();
                     other
@@ -2271,7 +2271,7 @@ impl<'a> DivAssignMod<&'a Natural> for Natural {
                     r
                 } else {
                     let (mut qs, rs) = limbs_div_mod(ns, ds);
-                    swap(&mut qs, ns);
+                    swap(&mut qs, ns.as_mut_vec());
                     self.trim();
                     Natural::from_owned_limbs_asc(rs)
                 }
@@ -30,16 +30,26 @@ pub_test! {limbs_limb_div_round_limbs(n: Limb, ds: &[Limb], rm: RoundingMode)
     } else {
         match rm {
             RoundingMode::Down | RoundingMode::Floor => Some((0, Ordering::Less)),
-            RoundingMode::Up | RoundingMode::Ceiling => Some((1, Ordering::Greater)),
+            RoundingMode::Up | RoundingMode::Ceiling | RoundingMode::Odd => {
+                Some((1, Ordering::Greater))
+            }
             RoundingMode::Exact => None,
             // 1 if 2 * n > Natural::from_limbs_asc(ds); otherwise, 0
-            RoundingMode::Nearest => Some(
+            RoundingMode::Nearest | RoundingMode::NearestTiesTowardZero => Some(
                 if ds.len() == 2 && ds[1] == 1 && n.get_highest_bit() && (n << 1) > ds[0] {
                     (1, Ordering::Greater)
                 } else {
                     (0, Ordering::Less)
                 },
             ),
+            // 1 if 2 * n >= Natural::from_limbs_asc(ds); otherwise, 0
+            RoundingMode::NearestTiesAway => Some(
+                if ds.len() == 2 && ds[1] == 1 && n.get_highest_bit() && (n << 1) >= ds[0] {
+                    (1, Ordering::Greater)
+                } else {
+                    (0, Ordering::Less)
+                },
+            ),
         }
     }
 }}
@@ -51,10 +61,28 @@ pub(crate) fn double_cmp(x: &Natural, y: &Natural) -> Ordering {
         .then_with(|| x.cmp_normalized(y))
 }
 
+// Returns whether a quotient should be rounded up, given the result of comparing twice the
+// remainder with the divisor. `rm` must be one of the nearest rounding modes.
+pub(crate) const fn nearest_rounds_up(compare: Ordering, q_odd: bool, rm: RoundingMode) -> bool {
+    match compare {
+        Ordering::Less => false,
+        Ordering::Greater => true,
+        Ordering::Equal => match rm {
+            RoundingMode::NearestTiesAway => true,
+            RoundingMode::NearestTiesTowardZero => false,
+            _ => q_odd,
+        },
+    }
+}
+
 // assumes r != 0
-fn div_round_nearest(q: Natural, r: &Natural, d: &Natural) -> (Natural, Ordering) {
-    let compare = double_cmp(r, d);
-    if compare == Ordering::Greater || compare == Ordering::Equal && q.odd() {
+fn div_round_nearest(
+    q: Natural,
+    r: &Natural,
+    d: &Natural,
+    rm: RoundingMode,
+) -> (Natural, Ordering) {
+    if nearest_rounds_up(double_cmp(r, d), q.odd(), rm) {
         (q.add_limb(1), Ordering::Greater)
     } else {
         (q, Ordering::Less)
@@ -62,9 +90,13 @@ fn div_round_nearest(q: Natural, r: &Natural, d: &Natural) -> (Natural, Ordering
 }
 
 // assumes r != 0
-fn div_round_assign_nearest(q: &mut Natural, r: &Natural, d: &Natural) -> Ordering {
-    let compare = double_cmp(r, d);
-    if compare == Ordering::Greater || compare == Ordering::Equal && q.odd() {
+fn div_round_assign_nearest(
+    q: &mut Natural,
+    r: &Natural,
+    d: &Natural,
+    rm: RoundingMode,
+) -> Ordering {
+    if nearest_rounds_up(double_cmp(r, d), q.odd(), rm) {
         *q += Natural::ONE;
         Ordering::Greater
     } else {
@@ -103,6 +135,12 @@ impl DivRound<Natural> for Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $g(x, y, \mathrm{NearestTiesAway})$ and $g(x, y, \mathrm{NearestTiesTowardZero})$ are
+    /// defined like $g(x, y, \mathrm{Nearest})$, except that ties are rounded away from $0$ and
+    /// towards $0$, respectively. $g(x, y, \mathrm{Odd})$ is $g(x, y, \mathrm{Down})$ if no
+    /// rounding is necessary, and otherwise whichever of $g(x, y, \mathrm{Floor})$ and $g(x, y,
+    /// \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+    ///
     /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
     ///
     /// Then
@@ -201,6 +239,12 @@ impl<'a> DivRound<&'a Natural> for Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $g(x, y, \mathrm{NearestTiesAway})$ and $g(x, y, \mathrm{NearestTiesTowardZero})$ are
+    /// defined like $g(x, y, \mathrm{Nearest})$, except that ties are rounded away from $0$ and
+    /// towards $0$, respectively. $g(x, y, \mathrm{Odd})$ is $g(x, y, \mathrm{Down})$ if no
+    /// rounding is necessary, and otherwise whichever of $g(x, y, \mathrm{Floor})$ and $g(x, y,
+    /// \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+    ///
     /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
     ///
     /// Then
@@ -299,6 +343,12 @@ impl<'a> DivRound<Natural> for &'a Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $g(x, y, \mathrm{NearestTiesAway})$ and $g(x, y, \mathrm{NearestTiesTowardZero})$ are
+    /// defined like $g(x, y, \mathrm{Nearest})$, except that ties are rounded away from $0$ and
+    /// towards $0$, respectively. $g(x, y, \mathrm{Odd})$ is $g(x, y, \mathrm{Down})$ if no
+    /// rounding is necessary, and otherwise whichever of $g(x, y, \mathrm{Floor})$ and $g(x, y,
+    /// \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+    ///
     /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
     ///
     /// Then
@@ -367,7 +417,16 @@ impl<'a> DivRound<Natural> for &'a Natural {
                 RoundingMode::Floor | RoundingMode::Down => (q, Ordering::Less),
                 RoundingMode::Ceiling | RoundingMode::Up => (q.add_limb(1), Ordering::Greater),
                 RoundingMode::Exact => panic!("Division is not exact"),
-                RoundingMode::Nearest => div_round_nearest(q, &r, &other),
+                RoundingMode::Nearest
+                | RoundingMode::NearestTiesAway
+                | RoundingMode::NearestTiesTowardZero => div_round_nearest(q, &r, &other, rm),
+                RoundingMode::Odd => {
+                    if q.odd() {
+                        (q, Ordering::Less)
+                    } else {
+                        (q.add_limb(1), Ordering::Greater)
+                    }
+                }
             }
         }
     }
@@ -404,6 +463,12 @@ impl<'a, 'b> DivRound<&'b Natural> for &'a Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $g(x, y, \mathrm{NearestTiesAway})$ and $g(x, y, \mathrm{NearestTiesTowardZero})$ are
+    /// defined like $g(x, y, \mathrm{Nearest})$, except that ties are rounded away from $0$ and
+    /// towards $0$, respectively. $g(x, y, \mathrm{Odd})$ is $g(x, y, \mathrm{Down})$ if no
+    /// rounding is necessary, and otherwise whichever of $g(x, y, \mathrm{Floor})$ and $g(x, y,
+    /// \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+    ///
     /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
     ///
     /// Then
@@ -472,7 +537,16 @@ impl<'a, 'b> DivRound<&'b Natural> for &'a Natural {
                 RoundingMode::Floor | RoundingMode::Down => (q, Ordering::Less),
                 RoundingMode::Ceiling | RoundingMode::Up => (q.add_limb(1), Ordering::Greater),
                 RoundingMode::Exact => panic!("Division is not exact: {self} / {other}"),
-                RoundingMode::Nearest => div_round_nearest(q, &r, other),
+                RoundingMode::Nearest
+                | RoundingMode::NearestTiesAway
+                | RoundingMode::NearestTiesTowardZero => div_round_nearest(q, &r, other, rm),
+                RoundingMode::Odd => {
+                    if q.odd() {
+                        (q, Ordering::Less)
+                    } else {
+                        (q.add_limb(1), Ordering::Greater)
+                    }
+                }
             }
         }
     }
@@ -561,7 +635,19 @@ impl DivRoundAssign<Natural> for Natural {
                     Ordering::Greater
                 }
                 RoundingMode::Exact => panic!("Division is not exact"),
-                RoundingMode::Nearest => div_round_assign_nearest(self, &r, &other),
+                RoundingMode::Nearest
+                | RoundingMode::NearestTiesAway
+                | RoundingMode::NearestTiesTowardZero => {
+                    div_round_assign_nearest(self, &r, &other, rm)
+                }
+                RoundingMode::Odd => {
+                    if self.odd() {
+                        Ordering::Less
+                    } else {
+                        *self += Natural::ONE;
+                        Ordering::Greater
+                    }
+                }
             }
         }
     }
@@ -656,7 +742,19 @@ impl<'a> DivRoundAssign<&'a Natural> for Natural {
                     Ordering::Greater
                 }
                 RoundingMode::Exact => panic!("Division is not exact"),
-                RoundingMode::Nearest => div_round_assign_nearest(self, &r, other),
+                RoundingMode::Nearest
+                | RoundingMode::NearestTiesAway
+                | RoundingMode::NearestTiesTowardZero => {
+                    div_round_assign_nearest(self, &r, other, rm)
+                }
+                RoundingMode::Odd => {
+                    if self.odd() {
+                        Ordering::Less
+                    } else {
+                        *self += Natural::ONE;
+                        Ordering::Greater
+                    }
+                }
             }
         }
     }
@@ -0,0 +1,210 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{DivMod, DivisibleByPowerOf2, ShrRound};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::conversion::traits::{ExactFrom, WrappingFrom};
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+use malachite_base::rounding_modes::RoundingMode;
+use std::cmp::Ordering;
+
+// Returns $xy^{-1}2^p$, rounded to an integer according to `rm`, along with an `Ordering`
+// comparing the result to the exact value. Only the integer part of the quotient and two more bits
+// are computed; if anything nonzero remains, the lowest of these bits is set. This sticky bit
+// preserves enough information for the final shift to round correctly in every rounding mode.
+pub(crate) fn div_shl_round(
+    x: &Natural,
+    y: &Natural,
+    pow: i64,
+    rm: RoundingMode,
+) -> (Natural, Ordering) {
+    let pow = pow + 2;
+    let (mut q, inexact) = if pow >= 0 {
+        let (q, r) = (x << pow).div_mod(y);
+        (q, r != 0)
+    } else {
+        let bits = pow.unsigned_abs();
+        let (q, r) = (x >> bits).div_mod(y);
+        (q, r != 0 || !x.divisible_by_power_of_2(bits))
+    };
+    if inexact {
+        q.set_bit(0);
+    }
+    q.shr_round(2, rm)
+}
+
+fn overflow<T: PrimitiveFloat>(rm: RoundingMode) -> (T, Ordering) {
+    match rm {
+        RoundingMode::Exact => panic!("Value cannot be represented exactly as a float"),
+        RoundingMode::Floor
+        | RoundingMode::Down
+        | RoundingMode::Nearest
+        | RoundingMode::NearestTiesAway
+        | RoundingMode::NearestTiesTowardZero
+        | RoundingMode::Odd => (T::MAX_FINITE, Ordering::Less),
+        _ => (T::INFINITY, Ordering::Greater),
+    }
+}
+
+pub(crate) fn div_to_primitive_float_round<T: PrimitiveFloat>(
+    x: &Natural,
+    y: &Natural,
+    rm: RoundingMode,
+) -> (T, Ordering) {
+    assert_ne!(*y, 0, "division by zero");
+    if *x == 0 {
+        return (T::ZERO, Ordering::Equal);
+    }
+    let mut exponent =
+        i64::exact_from(x.significant_bits()) - i64::exact_from(y.significant_bits());
+    if x.cmp_normalized(y) == Ordering::Less {
+        exponent -= 1;
+    }
+    if exponent > T::MAX_EXPONENT {
+        overflow(rm)
+    } else if exponent >= T::MIN_NORMAL_EXPONENT {
+        let (mut mantissa, o) =
+            div_shl_round(x, y, i64::wrapping_from(T::MANTISSA_WIDTH) - exponent, rm);
+        if mantissa.significant_bits() > T::MANTISSA_WIDTH + 1 {
+            if exponent == T::MAX_EXPONENT {
+                return overflow(rm);
+            }
+            mantissa >>= 1; // lsb is zero
+            exponent += 1;
+        }
+        mantissa.clear_bit(T::MANTISSA_WIDTH);
+        (
+            T::from_raw_mantissa_and_exponent(
+                u64::exact_from(&mantissa),
+                u64::exact_from(exponent + T::MAX_EXPONENT),
+            ),
+            o,
+        )
+    } else {
+        // The quotient is subnormal or underflows, so it is rounded to a multiple of the smallest
+        // positive subnormal float
+        let (mantissa, o) = div_shl_round(x, y, -T::MIN_EXPONENT, rm);
+        (
+            if mantissa.significant_bits() > T::MANTISSA_WIDTH {
+                T::MIN_POSITIVE_NORMAL
+            } else {
+                T::from_raw_mantissa_and_exponent(u64::exact_from(&mantissa), 0)
+            },
+            o,
+        )
+    }
+}
+
+impl Natural {
+    /// Divides a [`Natural`] by another [`Natural`] and converts the quotient to an [`f32`],
+    /// taking both by reference and using a specified
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode). An [`Ordering`] is also
+    /// returned, indicating whether the returned value is less than, equal to, or greater than the
+    /// exact quotient.
+    ///
+    /// The result is the same as that of building the rational number $x/y$ and converting it to
+    /// a float, but no gcd is computed and only the quotient bits needed for correct rounding are
+    /// generated. See [`div_to_f64_round`](Natural::div_to_f64_round) for how overflow and
+    /// underflow are handled.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `other.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `rm` is `Exact` and the quotient cannot be represented
+    /// exactly.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::float::NiceFloat;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_nz::natural::Natural;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (f, o) = Natural::from(2u32).div_to_f32_round(&Natural::from(3u32), RoundingMode::Floor);
+    /// assert_eq!(NiceFloat(f), NiceFloat(0.6666666));
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (f, o) = Natural::from(2u32).div_to_f32_round(&Natural::from(3u32), RoundingMode::Ceiling);
+    /// assert_eq!(NiceFloat(f), NiceFloat(0.6666667));
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn div_to_f32_round(&self, other: &Natural, rm: RoundingMode) -> (f32, Ordering) {
+        div_to_primitive_float_round(self, other, rm)
+    }
+
+    /// Divides a [`Natural`] by another [`Natural`] and converts the quotient to an [`f64`],
+    /// taking both by reference and using a specified
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode). An [`Ordering`] is also
+    /// returned, indicating whether the returned value is less than, equal to, or greater than the
+    /// exact quotient.
+    ///
+    /// The result is the same as that of building the rational number $x/y$ and converting it to
+    /// a float, but no gcd is computed and only the quotient bits needed for correct rounding are
+    /// generated.
+    ///
+    /// - If the rounding mode is `Floor` or `Down`, the largest float less than or equal to the
+    ///   quotient is returned. If the quotient is greater than the maximum finite float, then the
+    ///   maximum finite float is returned.
+    /// - If the rounding mode is `Ceiling` or `Up`, the smallest float greater than or equal to
+    ///   the quotient is returned. If the quotient is greater than the maximum finite float, then
+    ///   positive infinity is returned.
+    /// - If the rounding mode is `Nearest`, then the nearest float is returned. If the quotient is
+    ///   exactly between two floats, the float with the zero least-significant bit in its
+    ///   representation is selected. If the quotient is greater than the maximum finite float,
+    ///   then the maximum finite float is returned.
+    ///
+    /// Quotients that are too small to be represented are rounded to zero or to the smallest
+    /// positive subnormal float.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `other.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `rm` is `Exact` and the quotient cannot be represented
+    /// exactly.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::float::NiceFloat;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_nz::natural::Natural;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (f, o) = Natural::from(2u32).div_to_f64_round(&Natural::from(3u32), RoundingMode::Down);
+    /// assert_eq!(NiceFloat(f), NiceFloat(0.6666666666666666));
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (f, o) =
+    ///     Natural::from(2u32).div_to_f64_round(&Natural::from(3u32), RoundingMode::Nearest);
+    /// assert_eq!(NiceFloat(f), NiceFloat(0.6666666666666666));
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (f, o) = Natural::from(3u32).div_to_f64_round(&Natural::from(4u32), RoundingMode::Exact);
+    /// assert_eq!(NiceFloat(f), NiceFloat(0.75));
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (f, o) = Natural::from(10u32)
+    ///     .pow(400)
+    ///     .div_to_f64_round(&Natural::from(7u32).pow(300), RoundingMode::Nearest);
+    /// assert_eq!(NiceFloat(f), NiceFloat(2.9552076050124082e146));
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (f, o) =
+    ///     Natural::from(1u32).div_to_f64_round(&Natural::from(10u32).pow(400), RoundingMode::Up);
+    /// assert_eq!(NiceFloat(f), NiceFloat(5.0e-324));
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn div_to_f64_round(&self, other: &Natural, rm: RoundingMode) -> (f64, Ordering) {
+        div_to_primitive_float_round(self, other, rm)
+    }
+}
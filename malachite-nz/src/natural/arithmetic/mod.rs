@@ -44,6 +44,9 @@ pub mod div_mod;
 /// dividing two numbers according to a specified
 /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode).
 pub mod div_round;
+/// Functions for dividing two [`Natural`](crate::natural::Natural)s and converting the quotient
+/// to a primitive float, without computing a gcd.
+pub mod div_to_primitive_float;
 /// Implementations of [`DivisibleBy`](malachite_base::num::arithmetic::traits::DivisibleBy), a
 /// trait for determining whether one number is divisible by another.
 pub mod divisible_by;
@@ -511,6 +514,11 @@ pub mod power_of_2;
 /// An implementation of [`Primorial`](malachite_base::num::arithmetic::traits::Primorial), a trait
 /// for computing the primorial of a number.
 pub mod primorial;
+/// Implementations of [`RemoveFactor`](malachite_base::num::arithmetic::traits::RemoveFactor),
+/// [`RemoveFactorAssign`](malachite_base::num::arithmetic::traits::RemoveFactorAssign), and
+/// [`Valuation`](malachite_base::num::arithmetic::traits::Valuation), traits for finding and
+/// removing the factors of a number that are powers of another number.
+pub mod remove_factor;
 /// Implementations of traits for taking the $n$th root of a number.
 ///
 /// The traits are [`FloorRoot`](malachite_base::num::arithmetic::traits::FloorRoot),
@@ -2034,7 +2034,7 @@ impl<'a> RemAssign<&'a Natural> for Natural {
                 if ns.len() >= ds.len() {
                     let mut rs = vec![0; ds.len()];
                     limbs_mod_to_out(&mut rs, ns, ds);
-                    swap(&mut rs, ns);
+                    swap(&mut rs, ns.as_mut_vec());
                     self.trim();
                 }
             }
@@ -233,7 +233,7 @@ impl ModPowerOf2Assign for Natural {
         match *self {
             Natural(Small(ref mut small)) => small.mod_power_of_2_assign(pow),
             Natural(Large(ref mut limbs)) => {
-                limbs_vec_mod_power_of_2_in_place(limbs, pow);
+                limbs_vec_mod_power_of_2_in_place(limbs.as_mut_vec(), pow);
                 self.trim();
             }
         }
@@ -467,7 +467,7 @@ impl ModPowerOf2SquareAssign for Natural {
                 })
             }
             Natural(Large(ref mut xs)) => {
-                *xs = limbs_mod_power_of_2_square(xs, pow);
+                *xs = limbs_mod_power_of_2_square(xs.as_mut_vec(), pow).into();
                 self.trim();
             }
         }
@@ -190,7 +190,7 @@ impl Natural {
                 if overflow {
                     let mut out = limbs_low_mask(pow);
                     out[0] = diff;
-                    Natural(Large(out))
+                    Natural(Large(out.into()))
                 } else {
                     Natural(Small(diff))
                 }
@@ -214,7 +214,7 @@ impl Natural {
                 if overflow {
                     let mut out = limbs_low_mask(pow);
                     out[0] = diff;
-                    Natural(Large(out))
+                    Natural(Large(out.into()))
                 } else {
                     Natural(Small(diff))
                 }
@@ -237,7 +237,7 @@ impl Natural {
                 if overflow {
                     let mut out = limbs_low_mask(pow);
                     out[0] = diff;
-                    *self = Natural(Large(out));
+                    *self = Natural(Large(out.into()));
                 } else {
                     *small = diff;
                 }
@@ -262,13 +262,13 @@ impl Natural {
                 if overflow {
                     let mut out = limbs_low_mask(pow);
                     out[0] = diff;
-                    *self = Natural(Large(out))
+                    *self = Natural(Large(out.into()))
                 } else {
                     *small = diff
                 }
             }
             (&mut Natural(Large(ref mut limbs)), other, _) => {
-                limbs_mod_power_of_2_limb_sub_limbs_in_place(other, limbs, pow);
+                limbs_mod_power_of_2_limb_sub_limbs_in_place(other, limbs.as_mut_vec(), pow);
                 self.trim();
             }
         }
@@ -369,7 +369,7 @@ impl<'a> ModPowerOf2Sub<Natural> for &'a Natural {
                 other
             }
             (&Natural(Large(ref xs)), &mut Natural(Large(ref mut ys))) => {
-                limbs_mod_power_of_2_sub_in_place_right(xs, ys, pow);
+                limbs_mod_power_of_2_sub_in_place_right(xs, ys.as_mut_vec(), pow);
                 other.trim();
                 other
             }
@@ -448,7 +448,7 @@ impl ModPowerOf2SubAssign<Natural> for Natural {
                 *self = other;
             }
             (&mut Natural(Large(ref mut xs)), Natural(Large(ref mut ys))) => {
-                if limbs_mod_power_of_2_sub_in_place_either(xs, ys, pow) {
+                if limbs_mod_power_of_2_sub_in_place_either(xs.as_mut_vec(), ys.as_mut_vec(), pow) {
                     swap(xs, ys)
                 }
                 self.trim();
@@ -490,7 +490,7 @@ impl<'a> ModPowerOf2SubAssign<&'a Natural> for Natural {
             (x, &Natural(Small(y))) => x.mod_power_of_2_sub_assign_limb(y, pow),
             (&mut Natural(Small(x)), y) => *self = y.mod_power_of_2_right_sub_limb_ref(x, pow),
             (&mut Natural(Large(ref mut xs)), &Natural(Large(ref ys))) => {
-                limbs_mod_power_of_2_sub_in_place_left(xs, ys, pow);
+                limbs_mod_power_of_2_sub_in_place_left(xs.as_mut_vec(), ys, pow);
                 self.trim();
             }
         }
@@ -13,6 +13,7 @@ use crate::natural::arithmetic::sub::{
     limbs_sub_limb_in_place, limbs_sub_same_length_in_place_left,
     limbs_sub_same_length_in_place_right, limbs_sub_same_length_to_out,
 };
+use crate::parallel::{for_each_with_scratch, join, PARALLEL, PARALLEL_FFT_THRESHOLD};
 use crate::platform::{Limb, SignedLimb, FFT_TAB, MULMOD_TAB};
 use malachite_base::fail_on_untested_path;
 use malachite_base::num::arithmetic::traits::{
@@ -2186,6 +2187,123 @@ pub_test! {limbs_mul_greater_to_out_fft_with_cutoff_scratch_len(
     }
 }}
 
+// Returns the rows of a matrix Fourier transform that need to be convolved: the first
+// `(trunc - two_n) / sqrt` rows of the second half of `xss`, in bit-reversed order, followed by all
+// the rows of the first half.
+fn limbs_fft_mfa_rows<'a, 'b>(
+    xss: &'b mut [&'a mut [Limb]],
+    sqrt: usize,
+    trunc: usize,
+) -> Vec<&'b mut [&'a mut [Limb]]> {
+    let two_n = xss.len() >> 1;
+    let len = two_n / sqrt;
+    let depth = len.ceiling_log_base_2();
+    let (xss_lo, xss_hi) = xss.split_at_mut(two_n);
+    let mut xss_hi: Vec<Option<&mut [&'a mut [Limb]]>> =
+        xss_hi.chunks_mut(sqrt).map(Some).collect();
+    let mut rows = Vec::with_capacity(len << 1);
+    for s in 0..(trunc - two_n) / sqrt {
+        rows.push(xss_hi[n_revbin(s, depth)].take().unwrap());
+    }
+    rows.extend(xss_lo.chunks_mut(sqrt));
+    rows
+}
+
+// Convolves one row of a matrix Fourier transform: transforms the row of `xss` (and the row of
+// `yss`, unless squaring), normalizes the coefficients, multiplies them pointwise using
+// `pointwise`, and transforms the row of `xss` back.
+#[allow(clippy::mut_mut)]
+fn limbs_fft_mfa_convolve_row<'a, F: Fn(&mut [Limb], Option<&[Limb]>, &mut [Limb])>(
+    xss: &mut [&'a mut [Limb]],
+    mut yss: Option<&mut [&'a mut [Limb]]>,
+    w: usize,
+    ts: &mut &'a mut [Limb],
+    us: &mut &'a mut [Limb],
+    scratch: &mut [Limb],
+    pointwise: &F,
+) {
+    limbs_fft_radix2(xss, w, ts, us);
+    if let Some(yss) = yss.as_deref_mut() {
+        limbs_fft_radix2(yss, w, ts, us);
+    }
+    if let Some(yss) = yss {
+        for (xs, ys) in xss.iter_mut().zip(yss.iter_mut()) {
+            limbs_fft_normmod_2expp1(xs);
+            limbs_fft_normmod_2expp1(ys);
+            pointwise(xs, Some(ys), scratch);
+        }
+    } else {
+        for xs in &mut *xss {
+            limbs_fft_normmod_2expp1(xs);
+            pointwise(xs, None, scratch);
+        }
+    }
+    limbs_ifft_radix2(xss, w, ts, us);
+}
+
+// Convolves the relevant rows of a matrix Fourier transform; see `limbs_fft_mfa_convolve_row`. The
+// rows are independent, so if `parallel` is `true` they are convolved on multiple threads. In that
+// case each row gets its own pair of temporary coefficients from `row_temps` instead of sharing
+// `ts` and `us`, and each thread gets its own copy of `scratch`.
+#[allow(clippy::mut_mut)]
+fn limbs_fft_mfa_convolve_rows<'a, F: Fn(&mut [Limb], Option<&[Limb]>, &mut [Limb]) + Sync>(
+    parallel: bool,
+    xss: &mut [&'a mut [Limb]],
+    yss: Option<&mut [&'a mut [Limb]]>,
+    w: usize,
+    sqrt: usize,
+    trunc: usize,
+    ts: &mut &'a mut [Limb],
+    us: &mut &'a mut [Limb],
+    row_temps: &'a mut [Limb],
+    scratch: &mut [Limb],
+    pointwise: F,
+) {
+    let wy = w * ((xss.len() >> 1) / sqrt);
+    let rows: Vec<(&mut [&'a mut [Limb]], Option<&mut [&'a mut [Limb]]>)> = match yss {
+        Some(yss) => limbs_fft_mfa_rows(xss, sqrt, trunc)
+            .into_iter()
+            .zip(limbs_fft_mfa_rows(yss, sqrt, trunc).into_iter().map(Some))
+            .collect(),
+        None => limbs_fft_mfa_rows(xss, sqrt, trunc)
+            .into_iter()
+            .map(|xss_row| (xss_row, None))
+            .collect(),
+    };
+    if parallel {
+        let size = ts.len();
+        assert!(row_temps.len() >= rows.len() * (size << 1));
+        let mut rows: Vec<_> = rows
+            .into_iter()
+            .zip(row_temps.chunks_mut(size << 1))
+            .map(|((xss_row, yss_row), temps)| {
+                let (ts, us) = temps.split_at_mut(size);
+                (xss_row, yss_row, ts, us)
+            })
+            .collect();
+        for_each_with_scratch(
+            true,
+            &mut rows,
+            scratch,
+            |(xss_row, yss_row, ts, us), scratch| {
+                limbs_fft_mfa_convolve_row(
+                    xss_row,
+                    yss_row.as_deref_mut(),
+                    wy,
+                    ts,
+                    us,
+                    scratch,
+                    &pointwise,
+                );
+            },
+        );
+    } else {
+        for (xss_row, yss_row) in rows {
+            limbs_fft_mfa_convolve_row(xss_row, yss_row, wy, ts, us, scratch, &pointwise);
+        }
+    }
+}
+
 // This is equivalent to `flint_mpn_mul_fft_main` from `fft/mul_fft_main.c`, FLINT 2.8.0, where
 // i1 != i2.
 pub_test! {limbs_mul_greater_to_out_fft_with_cutoff(
@@ -2251,6 +2369,10 @@ pub_test! {limbs_mul_greater_to_out_fft_with_cutoff(
         let size = len + 1;
         let mut j1 = ((xs_len << Limb::LOG_WIDTH) - 1) / bits + 1;
         let mut j2 = ((ys_len << Limb::LOG_WIDTH) - 1) / bits + 1;
+        let parallel = PARALLEL && xs_len + ys_len >= PARALLEL_FFT_THRESHOLD;
+        // The transform of `ys` gets its own temporary coefficients, so that it can run
+        // concurrently with the transform of `xs`.
+        let mut y_temps = vec![0; 3 * size];
         let four_n = n << 2;
         let (scratch, combine_scratch) = scratch.split_at_mut(((n * size) << 3) + 3 * size);
         let (mut yss_scratch, mut xss_scratch) = scratch.split_at_mut((n * size) << 2);
@@ -2276,20 +2398,32 @@ pub_test! {limbs_mul_greater_to_out_fft_with_cutoff(
         }
         // trunc must be divisible by 2
         trunc = (trunc + 1) >> 1 << 1;
-        j1 = limbs_fft_split_bits(&mut xss, xs, bits);
-        for xs in &mut xss[j1..] {
-            slice_set_zero(xs);
-        }
-        limbs_fft_truncate_sqrt(&mut xss, w, &mut ts, &mut us, ss, trunc);
-        j2 = limbs_fft_split_bits(&mut yss, ys, bits);
-        for xs in &mut yss[j2..] {
-            slice_set_zero(xs);
-        }
-        limbs_fft_truncate_sqrt(&mut yss, w, &mut ts, &mut us, ss, trunc);
+        let (mut y_ts, y_temps_hi) = y_temps.split_at_mut(size);
+        let (mut y_us, y_ss) = y_temps_hi.split_at_mut(size);
+        (j1, j2) = join(
+            parallel,
+            || {
+                let j1 = limbs_fft_split_bits(&mut xss, xs, bits);
+                for xs in &mut xss[j1..] {
+                    slice_set_zero(xs);
+                }
+                limbs_fft_truncate_sqrt(&mut xss, w, &mut ts, &mut us, ss, trunc);
+                j1
+            },
+            || {
+                let j2 = limbs_fft_split_bits(&mut yss, ys, bits);
+                for xs in &mut yss[j2..] {
+                    slice_set_zero(xs);
+                }
+                limbs_fft_truncate_sqrt(&mut yss, w, &mut y_ts, &mut y_us, y_ss, trunc);
+                j2
+            },
+        );
         let n_2 = (b + U_WIDTH - 1) >> Limb::LOG_WIDTH;
         assert_eq!(n_2, len);
         let k = (n_2 << Limb::LOG_WIDTH) - b;
-        for (xs, ys) in xss.iter_mut().zip(yss.iter_mut()).take(trunc) {
+        let mut pairs: Vec<_> = xss.iter_mut().zip(yss.iter_mut()).take(trunc).collect();
+        for_each_with_scratch(parallel, &mut pairs, combine_scratch, |(xs, ys), scratch| {
             limbs_fft_normmod_2expp1(xs);
             limbs_fft_normmod_2expp1(ys);
             let (xs_last, xs_init) = xs.split_last_mut().unwrap();
@@ -2302,15 +2436,15 @@ pub_test! {limbs_mul_greater_to_out_fft_with_cutoff(
                 ys_init,
                 c == 1,
                 k,
-                combine_scratch,
+                scratch,
             ));
-        }
+        });
         limbs_ifft_truncate_sqrt(&mut xss, w, &mut ts, &mut us, &mut ss, trunc);
         let depth = depth + 2;
-        for xs in &mut xss[..trunc] {
+        for_each_with_scratch(parallel, &mut xss[..trunc], &mut [], |xs, _| {
             limbs_fft_div_2expmod_2expp1_in_place(xs, depth);
             limbs_fft_normmod_2expp1(xs);
-        }
+        });
         slice_set_zero(out);
         limbs_fft_combine_bits(out, &mut xss[..j1 + j2 - 1], bits, len, combine_scratch);
     } else {
@@ -2326,6 +2460,12 @@ pub_test! {limbs_mul_greater_to_out_fft_with_cutoff(
         let size = limbs + 1;
         let mut j1 = ((xs_len << Limb::LOG_WIDTH) - 1) / bits + 1;
         let mut j2 = ((ys_len << Limb::LOG_WIDTH) - 1) / bits + 1;
+        let parallel = PARALLEL && xs_len + ys_len >= PARALLEL_FFT_THRESHOLD;
+        // The transform of `ys` gets its own temporary coefficients, so that it can run
+        // concurrently with the transform of `xs`. When the rows are convolved in parallel, each
+        // row also gets its own pair of temporary coefficients.
+        let mut y_temps = vec![0; 3 * size];
+        let mut row_temps = vec![0; if parallel { ((n << 2) / sqrt) * (size << 1) } else { 0 }];
         let (scratch, misc_scratch) = scratch.split_at_mut(((n * size) << 3) + 3 * size);
         let (mut yss_scratch, mut xss_scratch) = scratch.split_at_mut((n * size) << 2);
         let four_n = n << 2;
@@ -2348,63 +2488,56 @@ pub_test! {limbs_mul_greater_to_out_fft_with_cutoff(
         // trunc must be divisible by 2*sqrt
         let two_sqrt = sqrt << 1;
         trunc = two_sqrt * ((trunc + two_sqrt - 1) / two_sqrt);
-        j1 = limbs_fft_split_bits(&mut xss, xs, bits);
-        for ps in &mut xss[j1..] {
-            slice_set_zero(ps);
-        }
-        limbs_fft_mfa_truncate_sqrt_outer(&mut xss, w, &mut ts, &mut us, &mut ss, sqrt, trunc);
-        j2 = limbs_fft_split_bits(&mut yss, ys, bits);
-        for qs in &mut yss[j2..] {
-            slice_set_zero(qs);
-        }
-        limbs_fft_mfa_truncate_sqrt_outer(&mut yss, w, &mut ts, &mut us, &mut ss, sqrt, trunc);
-        let two_n = four_n >> 1;
-        let len = two_n / sqrt;
-        let depth = len.ceiling_log_base_2();
-        // convolutions on relevant rows
-        let xss_hi = &mut xss[two_n..];
-        let yss_hi = &mut yss[two_n..];
-        let wy = w * len;
+        let (mut y_ts, y_temps_hi) = y_temps.split_at_mut(size);
+        let (mut y_us, mut y_ss) = y_temps_hi.split_at_mut(size);
+        (j1, j2) = join(
+            parallel,
+            || {
+                let j1 = limbs_fft_split_bits(&mut xss, xs, bits);
+                for ps in &mut xss[j1..] {
+                    slice_set_zero(ps);
+                }
+                limbs_fft_mfa_truncate_sqrt_outer(
+                    &mut xss, w, &mut ts, &mut us, &mut ss, sqrt, trunc,
+                );
+                j1
+            },
+            || {
+                let j2 = limbs_fft_split_bits(&mut yss, ys, bits);
+                for qs in &mut yss[j2..] {
+                    slice_set_zero(qs);
+                }
+                limbs_fft_mfa_truncate_sqrt_outer(
+                    &mut yss, w, &mut y_ts, &mut y_us, &mut y_ss, sqrt, trunc,
+                );
+                j2
+            },
+        );
+        // convolutions on relevant rows and on rows
         if bits >> Limb::LOG_WIDTH <= cutoff {
             let n_2 = (nw + U_WIDTH - 1) >> Limb::LOG_WIDTH;
-            for s in 0..(trunc - two_n) / sqrt {
-                let start = sqrt * n_revbin(s, depth);
-                let xss_hi = &mut xss_hi[start..][..sqrt];
-                let yss_hi = &mut yss_hi[start..][..sqrt];
-                limbs_fft_radix2(xss_hi, wy, &mut ts, &mut us);
-                limbs_fft_radix2(yss_hi, wy, &mut ts, &mut us);
-                for (xs, ys) in xss_hi.iter_mut().zip(yss_hi.iter_mut()) {
-                    limbs_fft_normmod_2expp1(xs);
-                    limbs_fft_normmod_2expp1(ys);
-                    let k = (n_2 << Limb::LOG_WIDTH) - nw;
+            let k = (n_2 << Limb::LOG_WIDTH) - nw;
+            limbs_fft_mfa_convolve_rows(
+                parallel,
+                &mut xss,
+                Some(&mut yss),
+                w,
+                sqrt,
+                trunc,
+                &mut ts,
+                &mut us,
+                &mut row_temps,
+                misc_scratch,
+                |xs, ys, scratch| {
                     xs[limbs] = Limb::from(limbs_fft_mulmod_2expp1_basecase_same(
                         &mut xs[..n_2],
-                        &ys[..n_2],
+                        &ys.unwrap()[..n_2],
                         false,
                         k,
-                        misc_scratch,
+                        scratch,
                     ));
-                }
-                limbs_ifft_radix2(xss_hi, wy, &mut ts, &mut us);
-            }
-            // convolutions on rows
-            for (xss_chunk, yss_chunk) in xss.chunks_mut(sqrt).zip(yss.chunks_mut(sqrt)).take(len) {
-                limbs_fft_radix2(xss_chunk, wy, &mut ts, &mut us);
-                limbs_fft_radix2(yss_chunk, wy, &mut ts, &mut us);
-                for (xs, ys) in xss_chunk.iter_mut().zip(yss_chunk.iter_mut()) {
-                    limbs_fft_normmod_2expp1(xs);
-                    limbs_fft_normmod_2expp1(ys);
-                    let k = (n_2 << Limb::LOG_WIDTH) - nw;
-                    xs[limbs] = Limb::from(limbs_fft_mulmod_2expp1_basecase_same(
-                        &mut xs[..n_2],
-                        &ys[..n_2],
-                        false,
-                        k,
-                        misc_scratch,
-                    ));
-                }
-                limbs_ifft_radix2(xss_chunk, wy, &mut ts, &mut us);
-            }
+                },
+            );
         } else {
             let depth2 = nw.ceiling_log_base_2();
             let off = if depth2 < 12 {
@@ -2418,61 +2551,40 @@ pub_test! {limbs_mul_greater_to_out_fft_with_cutoff(
             let size = ((n3 * w2) >> Limb::LOG_WIDTH) + 1;
             let two_n3 = n3 << 1;
             let yss_scratch_len = (n3 + n3 * size) << 1;
-            let (scratch, combine_scratch) =
-                misc_scratch.split_at_mut((yss_scratch_len << 1) + 3 * size);
-            let (mut yss_scratch, mut xss_scratch) = scratch.split_at_mut(yss_scratch_len);
-            let mut xss2: Vec<&mut [Limb]> = Vec::with_capacity(two_n3);
-            for _ in 0..two_n3 {
-                let (lo, hi) = xss_scratch.split_at_mut(size);
-                xss2.push(lo);
-                xss_scratch = hi;
-            }
-            let (xss0, scratch_hi) = xss_scratch.split_at_mut(two_n3);
-            let (mut ts2, scratch_hi) = scratch_hi.split_at_mut(size);
-            let (mut us2, mut ss2) = scratch_hi.split_at_mut(size);
-            let mut yss2: Vec<&mut [Limb]> = Vec::with_capacity(two_n3);
-            for _ in 0..two_n3 {
-                let (lo, hi) = yss_scratch.split_at_mut(size);
-                yss2.push(lo);
-                yss_scratch = hi;
-            }
-            let yss0 = yss_scratch;
-            for s in 0..(trunc - two_n) / sqrt {
-                let start = sqrt * n_revbin(s, depth);
-                let xss_hi = &mut xss_hi[start..][..sqrt];
-                let yss_hi = &mut yss_hi[start..][..sqrt];
-                limbs_fft_radix2(xss_hi, wy, &mut ts, &mut us);
-                limbs_fft_radix2(yss_hi, wy, &mut ts, &mut us);
-                for (xs, ys) in xss_hi.iter_mut().zip(yss_hi.iter_mut()) {
-                    limbs_fft_normmod_2expp1(xs);
-                    limbs_fft_normmod_2expp1(ys);
-                    limbs_fft_mulmod_2expp1(
-                        xs,
-                        ys,
-                        n,
-                        w,
-                        &mut xss2,
-                        xss0,
-                        &mut yss2,
-                        yss0,
-                        &mut ts2,
-                        &mut us2,
-                        &mut ss2,
-                        combine_scratch,
-                    );
-                }
-                limbs_ifft_radix2(xss_hi, wy, &mut ts, &mut us);
-            }
-            // convolutions on rows
-            for (xss_chunk, yss_chunk) in xss.chunks_mut(sqrt).zip(yss.chunks_mut(sqrt)).take(len) {
-                limbs_fft_radix2(xss_chunk, wy, &mut ts, &mut us);
-                limbs_fft_radix2(yss_chunk, wy, &mut ts, &mut us);
-                for (xs, ys) in xss_chunk.iter_mut().zip(yss_chunk.iter_mut()) {
-                    limbs_fft_normmod_2expp1(xs);
-                    limbs_fft_normmod_2expp1(ys);
+            limbs_fft_mfa_convolve_rows(
+                parallel,
+                &mut xss,
+                Some(&mut yss),
+                w,
+                sqrt,
+                trunc,
+                &mut ts,
+                &mut us,
+                &mut row_temps,
+                misc_scratch,
+                |xs, ys, scratch| {
+                    let (scratch, combine_scratch) =
+                        scratch.split_at_mut((yss_scratch_len << 1) + 3 * size);
+                    let (mut yss_scratch, mut xss_scratch) = scratch.split_at_mut(yss_scratch_len);
+                    let mut xss2: Vec<&mut [Limb]> = Vec::with_capacity(two_n3);
+                    for _ in 0..two_n3 {
+                        let (lo, hi) = xss_scratch.split_at_mut(size);
+                        xss2.push(lo);
+                        xss_scratch = hi;
+                    }
+                    let (xss0, scratch_hi) = xss_scratch.split_at_mut(two_n3);
+                    let (mut ts2, scratch_hi) = scratch_hi.split_at_mut(size);
+                    let (mut us2, mut ss2) = scratch_hi.split_at_mut(size);
+                    let mut yss2: Vec<&mut [Limb]> = Vec::with_capacity(two_n3);
+                    for _ in 0..two_n3 {
+                        let (lo, hi) = yss_scratch.split_at_mut(size);
+                        yss2.push(lo);
+                        yss_scratch = hi;
+                    }
+                    let yss0 = yss_scratch;
                     limbs_fft_mulmod_2expp1(
                         xs,
-                        ys,
+                        ys.unwrap(),
                         n,
                         w,
                         &mut xss2,
@@ -2484,9 +2596,8 @@ pub_test! {limbs_mul_greater_to_out_fft_with_cutoff(
                         &mut ss2,
                         combine_scratch,
                     );
-                }
-                limbs_ifft_radix2(xss_chunk, wy, &mut ts, &mut us);
-            }
+                },
+            );
         }
         limbs_ifft_mfa_truncate_sqrt_outer(&mut xss, n, w, &mut ts, &mut us, &mut ss, sqrt, trunc);
         let out = &mut out[..xs_len + ys_len];
@@ -2646,6 +2757,7 @@ pub_test! {limbs_square_to_out_fft_with_cutoff(
         let len = b >> Limb::LOG_WIDTH;
         let size = len + 1;
         let mut j1 = ((xs_len << Limb::LOG_WIDTH) - 1) / bits + 1;
+        let parallel = PARALLEL && xs_len << 1 >= PARALLEL_FFT_THRESHOLD;
         let (mut xss_scratch, combine_scratch) = scratch.split_at_mut(((n * size) << 2) + 3 * size);
         let four_n = n << 2;
         let mut xss: Vec<&mut [Limb]> = Vec::with_capacity(four_n);
@@ -2668,22 +2780,18 @@ pub_test! {limbs_square_to_out_fft_with_cutoff(
             slice_set_zero(xs);
         }
         limbs_fft_truncate_sqrt(&mut xss, w, &mut ts, &mut us, ss, trunc);
-        for xs in &mut xss[..trunc] {
+        for_each_with_scratch(parallel, &mut xss[..trunc], combine_scratch, |xs, scratch| {
             limbs_fft_normmod_2expp1(xs);
             let (xs_last, xs_init) = xs.split_last_mut().unwrap();
             assert_eq!(*xs_last, 0);
-            *xs_last = Limb::from(limbs_fft_mulmod_2expp1_basecase_same2(
-                xs_init,
-                b,
-                combine_scratch,
-            ));
-        }
+            *xs_last = Limb::from(limbs_fft_mulmod_2expp1_basecase_same2(xs_init, b, scratch));
+        });
         limbs_ifft_truncate_sqrt(&mut xss, w, &mut ts, &mut us, &mut ss, trunc);
         let depth = depth + 2;
-        for xs in &mut xss[0..trunc] {
+        for_each_with_scratch(parallel, &mut xss[..trunc], &mut [], |xs, _| {
             limbs_fft_div_2expmod_2expp1_in_place(xs, depth);
             limbs_fft_normmod_2expp1(xs);
-        }
+        });
         slice_set_zero(out);
         limbs_fft_combine_bits(out, &mut xss[..(j1 << 1) - 1], bits, len, combine_scratch);
     } else {
@@ -2698,6 +2806,10 @@ pub_test! {limbs_square_to_out_fft_with_cutoff(
         let limbs = nw >> Limb::LOG_WIDTH;
         let size = limbs + 1;
         let mut j1 = ((xs_len << Limb::LOG_WIDTH) - 1) / bits + 1;
+        let parallel = PARALLEL && xs_len << 1 >= PARALLEL_FFT_THRESHOLD;
+        // When the rows are convolved in parallel, each row gets its own pair of temporary
+        // coefficients.
+        let mut row_temps = vec![0; if parallel { ((n << 2) / sqrt) * (size << 1) } else { 0 }];
         let (mut xss_scratch, misc_scratch) = scratch.split_at_mut(((n * size) << 2) + 3 * size);
         let four_n = n << 2;
         let mut xss: Vec<&mut [Limb]> = Vec::with_capacity(four_n);
@@ -2718,34 +2830,23 @@ pub_test! {limbs_square_to_out_fft_with_cutoff(
             slice_set_zero(ps);
         }
         limbs_fft_mfa_truncate_sqrt_outer(&mut xss, w, &mut ts, &mut us, &mut ss, sqrt, trunc);
-        let two_n = four_n >> 1;
-        let len = two_n / sqrt;
-        let depth = len.ceiling_log_base_2();
-        // convolutions on relevant rows
-        let xss_hi = &mut xss[two_n..];
-        let wy = w * len;
+        // convolutions on relevant rows and on rows
         if nw >> Limb::LOG_WIDTH <= cutoff {
-            for s in 0..(trunc - two_n) / sqrt {
-                let start = sqrt * n_revbin(s, depth);
-                let xss_hi = &mut xss_hi[start..][..sqrt];
-                limbs_fft_radix2(xss_hi, wy, &mut ts, &mut us);
-                for xs in &mut *xss_hi {
-                    limbs_fft_normmod_2expp1(xs);
-                    xs[limbs] =
-                        Limb::from(limbs_fft_mulmod_2expp1_basecase_same2(xs, nw, misc_scratch));
-                }
-                limbs_ifft_radix2(xss_hi, wy, &mut ts, &mut us);
-            }
-            // convolutions on rows
-            for xss_chunk in xss.chunks_mut(sqrt).take(len) {
-                limbs_fft_radix2(xss_chunk, wy, &mut ts, &mut us);
-                for xs in &mut *xss_chunk {
-                    limbs_fft_normmod_2expp1(xs);
-                    xs[limbs] =
-                        Limb::from(limbs_fft_mulmod_2expp1_basecase_same2(xs, nw, misc_scratch));
-                }
-                limbs_ifft_radix2(xss_chunk, wy, &mut ts, &mut us);
-            }
+            limbs_fft_mfa_convolve_rows(
+                parallel,
+                &mut xss,
+                None,
+                w,
+                sqrt,
+                trunc,
+                &mut ts,
+                &mut us,
+                &mut row_temps,
+                misc_scratch,
+                |xs, _, scratch| {
+                    xs[limbs] = Limb::from(limbs_fft_mulmod_2expp1_basecase_same2(xs, nw, scratch));
+                },
+            );
         } else {
             let depth2 = nw.ceiling_log_base_2();
             let off = if depth2 < 12 {
@@ -2758,23 +2859,29 @@ pub_test! {limbs_square_to_out_fft_with_cutoff(
             let n3 = usize::power_of_2(depth2);
             let size = ((n3 * w3) >> Limb::LOG_WIDTH) + 1;
             let two_n3 = n3 << 1;
-            let (mut xss_scratch, combine_scratch) =
-                misc_scratch.split_at_mut(((n * size) << 2) + 3 * size);
-            let mut xss2: Vec<&mut [Limb]> = Vec::with_capacity(two_n3);
-            for _ in 0..two_n3 {
-                let (lo, hi) = xss_scratch.split_at_mut(size);
-                xss2.push(lo);
-                xss_scratch = hi;
-            }
-            let (xss0, scratch_hi) = xss_scratch.split_at_mut(two_n3);
-            let (mut ts2, scratch_hi) = scratch_hi.split_at_mut(size);
-            let (mut us2, mut ss2) = scratch_hi.split_at_mut(size);
-            for s in 0..(trunc - two_n) / sqrt {
-                let start = sqrt * n_revbin(s, depth);
-                let xss_hi = &mut xss_hi[start..][..sqrt];
-                limbs_fft_radix2(xss_hi, wy, &mut ts, &mut us);
-                for xs in &mut *xss_hi {
-                    limbs_fft_normmod_2expp1(xs);
+            limbs_fft_mfa_convolve_rows(
+                parallel,
+                &mut xss,
+                None,
+                w,
+                sqrt,
+                trunc,
+                &mut ts,
+                &mut us,
+                &mut row_temps,
+                misc_scratch,
+                |xs, _, scratch| {
+                    let (mut xss_scratch, combine_scratch) =
+                        scratch.split_at_mut(((n * size) << 2) + 3 * size);
+                    let mut xss2: Vec<&mut [Limb]> = Vec::with_capacity(two_n3);
+                    for _ in 0..two_n3 {
+                        let (lo, hi) = xss_scratch.split_at_mut(size);
+                        xss2.push(lo);
+                        xss_scratch = hi;
+                    }
+                    let (xss0, scratch_hi) = xss_scratch.split_at_mut(two_n3);
+                    let (mut ts2, scratch_hi) = scratch_hi.split_at_mut(size);
+                    let (mut us2, mut ss2) = scratch_hi.split_at_mut(size);
                     limbs_fft_mulmod_2expp1_same(
                         xs,
                         n,
@@ -2786,28 +2893,8 @@ pub_test! {limbs_square_to_out_fft_with_cutoff(
                         &mut ss2,
                         combine_scratch,
                     );
-                }
-                limbs_ifft_radix2(xss_hi, wy, &mut ts, &mut us);
-            }
-            // convolutions on rows
-            for xss_chunk in xss.chunks_mut(sqrt).take(len) {
-                limbs_fft_radix2(xss_chunk, wy, &mut ts, &mut us);
-                for xs in &mut *xss_chunk {
-                    limbs_fft_normmod_2expp1(xs);
-                    limbs_fft_mulmod_2expp1_same(
-                        xs,
-                        n,
-                        w,
-                        &mut xss2,
-                        xss0,
-                        &mut ts2,
-                        &mut us2,
-                        &mut ss2,
-                        combine_scratch,
-                    );
-                }
-                limbs_ifft_radix2(xss_chunk, wy, &mut ts, &mut us);
-            }
+                },
+            );
         }
         limbs_ifft_mfa_truncate_sqrt_outer(&mut xss, n, w, &mut ts, &mut us, &mut ss, sqrt, trunc);
         let out = &mut out[..xs_len << 1];
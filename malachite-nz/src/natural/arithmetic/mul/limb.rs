@@ -1,3 +1,4 @@
+use crate::natural::limb_vec::LimbVec;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::{DoubleLimb, Limb};
@@ -153,11 +154,14 @@ impl Natural {
                 if upper == 0 {
                     *small = lower;
                 } else {
-                    *self = Natural(Large(vec![lower, upper]));
+                    *self = Natural(Large(LimbVec::from_slice(&[lower, upper])));
                 }
             }
             (&mut Natural(Large(ref mut limbs)), other) => {
-                limbs_vec_mul_limb_in_place(limbs, other);
+                let carry = limbs_slice_mul_limb_in_place(limbs, other);
+                if carry != 0 {
+                    limbs.push(carry);
+                }
             }
         }
     }
@@ -172,10 +176,18 @@ impl Natural {
                 if upper == 0 {
                     Small(lower)
                 } else {
-                    Large(vec![lower, upper])
+                    Large(LimbVec::from_slice(&[lower, upper]))
                 }
             }),
-            (Natural(Large(ref limbs)), other) => Natural(Large(limbs_mul_limb(limbs, other))),
+            (Natural(Large(ref limbs)), other) => {
+                if limbs.is_inline() {
+                    let mut product = self.clone();
+                    product.mul_assign_limb(other);
+                    product
+                } else {
+                    Natural(Large(limbs_mul_limb(limbs, other).into()))
+                }
+            }
         }
     }
 }
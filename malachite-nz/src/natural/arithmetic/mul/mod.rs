@@ -1,4 +1,6 @@
-use crate::natural::arithmetic::add::limbs_slice_add_greater_in_place_left;
+use crate::natural::arithmetic::add::{
+    limbs_add_greater_to_out, limbs_slice_add_greater_in_place_left,
+};
 use crate::natural::arithmetic::add_mul::limbs_slice_add_mul_limb_same_length_in_place_left;
 use crate::natural::arithmetic::mul::fft::{
     limbs_mul_greater_to_out_fft, limbs_mul_greater_to_out_fft_scratch_len,
@@ -17,8 +19,14 @@ use crate::natural::arithmetic::mul::toom::{
     limbs_mul_greater_to_out_toom_6h, limbs_mul_greater_to_out_toom_6h_scratch_len,
     limbs_mul_greater_to_out_toom_8h, limbs_mul_greater_to_out_toom_8h_scratch_len,
 };
+use crate::natural::arithmetic::sub::limbs_sub_greater_in_place_left;
+use crate::natural::limb_vec::{LimbVec, INLINE_LIMB_COUNT};
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
+use crate::parallel::{
+    for_each_with_scratch, join, PARALLEL, PARALLEL_FFT_THRESHOLD, PARALLEL_KARATSUBA_THRESHOLD,
+    PARALLEL_TOOM_THRESHOLD,
+};
 use crate::platform::{
     Limb, MUL_FFT_THRESHOLD, MUL_TOOM22_THRESHOLD, MUL_TOOM32_TO_TOOM43_THRESHOLD,
     MUL_TOOM32_TO_TOOM53_THRESHOLD, MUL_TOOM33_THRESHOLD, MUL_TOOM42_TO_TOOM53_THRESHOLD,
@@ -27,6 +35,7 @@ use crate::platform::{
 };
 use malachite_base::num::basic::traits::One;
 use malachite_base::num::basic::traits::Zero;
+use malachite_base::slices::slice_test_zero;
 use std::cmp::max;
 use std::iter::Product;
 use std::ops::{Mul, MulAssign};
@@ -131,7 +140,9 @@ pub_crate_test! {limbs_mul_same_length_to_out(
     let len = xs.len();
     assert_eq!(ys.len(), len);
     assert_ne!(len, 0);
-    if len < MUL_TOOM22_THRESHOLD {
+    if PARALLEL && len >= PARALLEL_KARATSUBA_THRESHOLD && len << 1 < PARALLEL_FFT_THRESHOLD {
+        limbs_mul_same_length_to_out_parallel_karatsuba(out, xs, ys);
+    } else if len < MUL_TOOM22_THRESHOLD {
         limbs_mul_greater_to_out_basecase(out, xs, ys);
     } else if len < MUL_TOOM33_THRESHOLD {
         limbs_mul_greater_to_out_toom_22(out, xs, ys, scratch);
@@ -148,6 +159,77 @@ pub_crate_test! {limbs_mul_same_length_to_out(
     }
 }}
 
+// Interpreting two equal-length slices of `Limb`s as the limbs (in ascending order) of two
+// `Natural`s, writes the `2 * xs.len()` least-significant limbs of the product of the `Natural`s
+// to an output slice, using one level of Karatsuba's algorithm. With $x = x_1B^h + x_0$ and
+// $y = y_1B^h + y_0$, where $B$ is the limb base and $h$ is half the length, the products $x_0y_0$,
+// $x_1y_1$, and $(x_0 + x_1)(y_0 + y_1)$ are computed on separate threads, and then
+// $$
+// xy = x_1y_1B^{2h} + ((x_0 + x_1)(y_0 + y_1) - x_0y_0 - x_1y_1)B^h + x_0y_0.
+// $$
+// The result is the same whether or not the `parallel` feature is enabled.
+//
+// # Worst-case complexity
+// $T(n) = O(n \log n \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+//
+// # Panics
+// Panics if `out` is too short, `xs` and `ys` have different lengths, or `xs` has fewer than 2
+// limbs.
+pub_test! {limbs_mul_same_length_to_out_parallel_karatsuba(
+    out: &mut [Limb],
+    xs: &[Limb],
+    ys: &[Limb]
+) {
+    let len = xs.len();
+    assert_eq!(ys.len(), len);
+    assert!(len > 1);
+    let out = &mut out[..len << 1];
+    let half = len >> 1;
+    let hi_len = len - half;
+    let (xs_lo, xs_hi) = xs.split_at(half);
+    let (ys_lo, ys_hi) = ys.split_at(half);
+    let sum = |lo: &[Limb], hi: &[Limb]| {
+        let mut sum = vec![0; hi_len + 1];
+        sum[hi_len] = Limb::from(limbs_add_greater_to_out(&mut sum, hi, lo));
+        sum
+    };
+    let xs_sum = sum(xs_lo, xs_hi);
+    let ys_sum = sum(ys_lo, ys_hi);
+    let mul = |out: &mut [Limb], xs: &[Limb], ys: &[Limb]| {
+        let mut scratch = vec![0; limbs_mul_same_length_to_out_scratch_len(xs.len())];
+        limbs_mul_same_length_to_out(out, xs, ys, &mut scratch);
+    };
+    let (out_lo, out_hi) = out.split_at_mut(half << 1);
+    let (_, mut middle) = join(
+        true,
+        || {
+            join(
+                true,
+                || mul(out_lo, xs_lo, ys_lo),
+                || mul(out_hi, xs_hi, ys_hi),
+            )
+        },
+        || {
+            let mut middle = vec![0; (hi_len + 1) << 1];
+            mul(&mut middle, &xs_sum, &ys_sum);
+            middle
+        },
+    );
+    assert!(!limbs_sub_greater_in_place_left(&mut middle, out_lo));
+    assert!(!limbs_sub_greater_in_place_left(&mut middle, out_hi));
+    // x_0y_1 + x_1y_0 < 2B^len
+    let (middle, middle_hi) = middle.split_at(len + 1);
+    assert!(slice_test_zero(middle_hi));
+    assert!(!limbs_slice_add_greater_in_place_left(
+        &mut out[half..],
+        middle
+    ));
+}}
+
 // This is equivalent to `TOOM44_OK` from `mpn/generic/mul.c`, GMP 6.2.1.
 const fn toom44_ok(xs_len: usize, ys_len: usize) -> bool {
     12 + 3 * xs_len < ys_len << 2
@@ -271,6 +353,15 @@ pub_crate_test! {limbs_mul_greater_to_out(
     assert!(out.len() >= xs_len + ys_len);
     if xs_len == ys_len {
         limbs_mul_same_length_to_out(out, xs, ys, scratch);
+    } else if PARALLEL
+        && ys_len >= MUL_TOOM22_THRESHOLD
+        && 3 * ys_len < MUL_FFT_THRESHOLD
+        && xs_len >= ys_len << 2
+        && xs_len + ys_len >= PARALLEL_TOOM_THRESHOLD
+    {
+        // Very unbalanced operands that avoid the FFT code are multiplied chunk by chunk, so the
+        // chunks can be multiplied on multiple threads.
+        limbs_mul_greater_to_out_parallel_chunks(out, xs, ys);
     } else if ys_len < MUL_TOOM22_THRESHOLD {
         // Plain schoolbook multiplication. Unless xs_len is very large, or else if
         // `limbs_mul_same_length_to_out` applies, perform basecase multiply directly.
@@ -392,6 +483,69 @@ pub_crate_test! {limbs_mul_greater_to_out(
     out[xs_len + ys_len - 1]
 }}
 
+// The maximum number of chunks that `limbs_mul_greater_to_out_parallel_chunks` splits `xs` into.
+const PARALLEL_MAX_CHUNKS: usize = 64;
+
+// Interpreting two slices of `Limb`s as the limbs (in ascending order) of two `Natural`s, writes
+// the `xs.len() + ys.len()` least-significant limbs of the product of the `Natural`s to an output
+// slice. `xs` must be at least 4 times as long as `ys`. `xs` is split into chunks at least twice as
+// long as `ys`, each chunk is multiplied by `ys` on a separate thread, and the partial products are
+// added together.
+//
+// # Worst-case complexity
+// $T(n, m) = O(n \log m \log\log m)$
+//
+// $M(n, m) = O(n + m \log m)$
+//
+// where $T$ is time, $M$ is additional memory, $n$ is `xs.len()`, and $m$ is `ys.len()`.
+//
+// # Panics
+// Panics if `out` is too short, `xs` is less than 4 times as long as `ys`, or `ys` is empty.
+pub_test! {limbs_mul_greater_to_out_parallel_chunks(out: &mut [Limb], xs: &[Limb], ys: &[Limb]) {
+    let xs_len = xs.len();
+    let ys_len = ys.len();
+    assert_ne!(ys_len, 0);
+    assert!(xs_len >= ys_len << 2);
+    let chunk_len = max(
+        ys_len << 1,
+        (xs_len + PARALLEL_MAX_CHUNKS - 1) / PARALLEL_MAX_CHUNKS,
+    );
+    let last_len = (xs_len - 1) % chunk_len + 1;
+    let mut products: Vec<(&[Limb], Vec<Limb>)> = xs
+        .chunks(chunk_len)
+        .map(|chunk| (chunk, vec![0; chunk.len() + ys_len]))
+        .collect();
+    let mut mul_scratch = vec![
+        0;
+        max(
+            limbs_mul_to_out_scratch_len(chunk_len, ys_len),
+            limbs_mul_to_out_scratch_len(last_len, ys_len)
+        )
+    ];
+    for_each_with_scratch(
+        true,
+        &mut products,
+        &mut mul_scratch,
+        |(chunk, product), scratch| {
+            limbs_mul_to_out(product, chunk, ys, scratch);
+        },
+    );
+    let out = &mut out[..xs_len + ys_len];
+    let (first, rest) = products.split_first().unwrap();
+    out[..first.1.len()].copy_from_slice(&first.1);
+    let mut offset = chunk_len;
+    for (chunk, product) in rest {
+        // The low `ys_len` limbs of this product overlap the previous one.
+        let out = &mut out[offset..offset + product.len()];
+        out[ys_len..].copy_from_slice(&product[ys_len..]);
+        assert!(!limbs_slice_add_greater_in_place_left(
+            out,
+            &product[..ys_len]
+        ));
+        offset += chunk.len();
+    }
+}}
+
 pub_crate_test! {limbs_mul_to_out_scratch_len(xs_len: usize, ys_len: usize) -> usize {
     if xs_len >= ys_len {
         limbs_mul_greater_to_out_scratch_len(xs_len, ys_len)
@@ -468,6 +622,35 @@ pub_crate_test! {limbs_mul_greater_to_out_basecase(out: &mut [Limb], xs: &[Limb]
     }
 }}
 
+// Interpreting two slices of `Limb`s as the limbs (in ascending order) of two `Natural`s, returns
+// the limbs of the product of the `Natural`s, stored inline if there are few enough of them. The
+// highest limb of the result may be zero.
+//
+// # Worst-case complexity
+// $T(n) = O(n \log n \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len() + ys.len()`.
+//
+// # Panics
+// Panics if either slice is empty.
+fn limbs_mul_to_limb_vec(xs: &[Limb], ys: &[Limb]) -> LimbVec {
+    let len = xs.len() + ys.len();
+    if len <= INLINE_LIMB_COUNT {
+        LimbVec::from_inline_fn(|out| {
+            if xs.len() >= ys.len() {
+                limbs_mul_greater_to_out_basecase(out, xs, ys);
+            } else {
+                limbs_mul_greater_to_out_basecase(out, ys, xs);
+            }
+            len
+        })
+    } else {
+        limbs_mul(xs, ys).into()
+    }
+}
+
 impl Mul<Natural> for Natural {
     type Output = Natural;
 
@@ -622,7 +805,9 @@ impl<'a, 'b> Mul<&'a Natural> for &'b Natural {
             (Natural(Small(x)), y) => y.mul_limb_ref(*x),
             (x, Natural(Small(y))) => x.mul_limb_ref(*y),
             (Natural(Large(ref xs)), Natural(Large(ref ys))) => {
-                Natural::from_owned_limbs_asc(limbs_mul(xs, ys))
+                let mut product = Natural(Large(limbs_mul_to_limb_vec(xs, ys)));
+                product.trim();
+                product
             }
         }
     }
@@ -665,7 +850,7 @@ impl MulAssign<Natural> for Natural {
             }
             (_, Natural(Small(y))) => self.mul_assign_limb(*y),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys))) => {
-                *xs = limbs_mul(xs, ys);
+                *xs = limbs_mul_to_limb_vec(xs, ys);
                 self.trim();
             }
         }
@@ -706,7 +891,7 @@ impl<'a> MulAssign<&'a Natural> for Natural {
             (Natural(Small(x)), _) => *self = other.mul_limb_ref(*x),
             (_, Natural(Small(y))) => self.mul_assign_limb(*y),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys))) => {
-                *xs = limbs_mul(xs, ys);
+                *xs = limbs_mul_to_limb_vec(xs, ys);
                 self.trim();
             }
         }
@@ -2,6 +2,7 @@ use crate::natural::arithmetic::mul::limb::limbs_slice_mul_limb_in_place;
 use crate::natural::arithmetic::mul::{
     limbs_mul_limb_to_out, limbs_mul_to_out, limbs_mul_to_out_scratch_len, MUL_TOOM22_THRESHOLD,
 };
+use crate::parallel::{join, PARALLEL, PARALLEL_PRODUCT_THRESHOLD};
 use crate::platform::Limb;
 
 const RECURSIVE_PROD_THRESHOLD: usize = MUL_TOOM22_THRESHOLD;
@@ -41,8 +42,22 @@ pub fn limbs_product(out: &mut [Limb], factors: &mut [Limb]) -> usize {
         let half_len = factors_len >> 1;
         let (factors, xs) = factors.split_at_mut(half_len);
         let mut ys = vec![0; xs.len()];
-        let ys_len = limbs_product(&mut ys, xs);
-        let xs_len = limbs_product(xs, &mut factors[..half_len]);
+        let (xs_len, ys_len) = if PARALLEL && factors_len >= PARALLEL_PRODUCT_THRESHOLD {
+            // The low half can't be written to `xs` while `xs` is being used to compute the high
+            // half, so it gets its own buffer.
+            let mut zs = vec![0; xs.len()];
+            let (ys_len, zs_len) = join(
+                true,
+                || limbs_product(&mut ys, xs),
+                || limbs_product(&mut zs, factors),
+            );
+            xs[..zs_len].copy_from_slice(&zs[..zs_len]);
+            (zs_len, ys_len)
+        } else {
+            let ys_len = limbs_product(&mut ys, xs);
+            let xs_len = limbs_product(xs, factors);
+            (xs_len, ys_len)
+        };
         let size = xs_len + ys_len;
         assert!(out.len() >= size);
         let mut mul_scratch = vec![0; limbs_mul_to_out_scratch_len(xs_len, ys_len)];
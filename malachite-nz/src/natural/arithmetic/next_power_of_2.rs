@@ -1,3 +1,4 @@
+use crate::natural::limb_vec::LimbVec;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::Limb;
@@ -166,10 +167,10 @@ impl<'a> NextPowerOf2 for &'a Natural {
                 if let Some(result) = small.checked_next_power_of_two() {
                     Small(result)
                 } else {
-                    Large(vec![0, 1])
+                    Large(LimbVec::from_slice(&[0, 1]))
                 }
             }
-            Natural(Large(ref limbs)) => Large(limbs_next_power_of_2(limbs)),
+            Natural(Large(ref limbs)) => Large(limbs_next_power_of_2(limbs).into()),
         })
     }
 }
@@ -210,11 +211,11 @@ impl NextPowerOf2Assign for Natural {
                 if let Some(pow) = small.checked_next_power_of_two() {
                     *small = pow;
                 } else {
-                    *self = Natural(Large(vec![0, 1]));
+                    *self = Natural(Large(LimbVec::from_slice(&[0, 1])));
                 }
             }
             Natural(Large(ref mut limbs)) => {
-                limbs_vec_next_power_of_2_in_place(limbs);
+                limbs_vec_next_power_of_2_in_place(limbs.as_mut_vec());
             }
         }
     }
@@ -599,13 +599,13 @@ impl<'a> Pow<u64> for &'a Natural {
                 if small.significant_bits() * exp <= Limb::WIDTH {
                     Natural(Small(small.checked_pow(u32::wrapping_from(exp)).unwrap()))
                 } else {
-                    let mut out = Natural(Large(limbs_pow(&[*small], exp)));
+                    let mut out = Natural(Large(limbs_pow(&[*small], exp).into()));
                     out.demote_if_small();
                     out
                 }
             }
             (Natural(Large(ref limbs)), exp) => {
-                let mut out = Natural(Large(limbs_pow(limbs, exp)));
+                let mut out = Natural(Large(limbs_pow(limbs, exp).into()));
                 out.demote_if_small();
                 out
             }
@@ -649,12 +649,12 @@ impl PowAssign<u64> for Natural {
                 if small.significant_bits() * exp <= Limb::WIDTH {
                     *small = small.checked_pow(u32::wrapping_from(exp)).unwrap();
                 } else {
-                    *self = Natural(Large(limbs_pow(&[*small], exp)));
+                    *self = Natural(Large(limbs_pow(&[*small], exp).into()));
                     self.demote_if_small();
                 }
             }
             (Natural(Large(ref mut limbs)), exp) => {
-                *self = Natural(Large(limbs_pow(limbs, exp)));
+                *self = Natural(Large(limbs_pow(limbs, exp).into()));
                 self.demote_if_small();
             }
         }
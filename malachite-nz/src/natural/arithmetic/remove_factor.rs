@@ -0,0 +1,464 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    CheckedLogBase2, DivExactAssign, DivisibleBy, RemoveFactor, RemoveFactorAssign, Square,
+    Valuation,
+};
+use malachite_base::num::logic::traits::SignificantBits;
+
+// Removes all factors of `p` from `x` and returns their multiplicity.
+//
+// If `p` is a power of 2, the factors are removed with a shift. Otherwise, `x` is divided by $p$,
+// $p^2$, $p^4$, and so on for as long as these powers divide it, and then by the same powers in
+// reverse order, as GMP's `mpz_remove` does. This takes $O(\log k)$ divisibility checks, where $k$
+// is the multiplicity.
+//
+// # Worst-case complexity
+// $T(n) = O(n (\log n)^2 \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+//
+// # Panics
+// Panics if `x` is zero or `p` is less than 2.
+fn remove_factor_helper(x: &mut Natural, p: &Natural) -> u64 {
+    assert_ne!(*x, 0u32, "Cannot remove factors from zero");
+    assert!(*p > 1u32, "The factor must be at least 2");
+    if let Some(log) = p.checked_log_base_2() {
+        let multiplicity = x.trailing_zeros().unwrap() / log;
+        *x >>= multiplicity * log;
+        return multiplicity;
+    }
+    if !(&*x).divisible_by(p) {
+        return 0;
+    }
+    x.div_exact_assign(p);
+    let mut multiplicity = 1;
+    // powers[i] is p ^ (2 ^ i), and x has been divided by p ^ (2 ^ (i + 1) - 1)
+    let mut powers = vec![p.clone()];
+    loop {
+        let power = powers.last().unwrap();
+        // The square has at least 2 * bits - 1 bits, so if that exceeds the bits of x it can't
+        // divide x
+        if (power.significant_bits() << 1) - 1 > x.significant_bits() {
+            break;
+        }
+        let square = power.square();
+        if !(&*x).divisible_by(&square) {
+            break;
+        }
+        x.div_exact_assign(&square);
+        multiplicity += 1 << powers.len();
+        powers.push(square);
+    }
+    // The remaining multiplicity is less than 2 ^ powers.len()
+    for (i, power) in powers.iter().enumerate().rev() {
+        if (&*x).divisible_by(power) {
+            x.div_exact_assign(power);
+            multiplicity += 1 << i;
+        }
+    }
+    multiplicity
+}
+
+impl RemoveFactor<Natural> for Natural {
+    type Output = Natural;
+
+    /// Removes all factors of $p$ from a [`Natural`], returning the remaining [`Natural`] and the
+    /// multiplicity of $p$. Both [`Natural`]s are taken by value.
+    ///
+    /// $f(x, p) = (x/p^k, k)$, where $k$ is the largest integer such that $p^k \mid x$.
+    ///
+    /// $p$ does not have to be prime.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::RemoveFactor;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(360u32)
+    ///         .remove_factor(Natural::from(2u32))
+    ///         .to_debug_string(),
+    ///     "(45, 3)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(360u32)
+    ///         .remove_factor(Natural::from(6u32))
+    ///         .to_debug_string(),
+    ///     "(10, 2)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(360u32)
+    ///         .remove_factor(Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "(360, 0)"
+    /// );
+    /// ```
+    #[inline]
+    fn remove_factor(mut self, p: Natural) -> (Natural, u64) {
+        let multiplicity = remove_factor_helper(&mut self, &p);
+        (self, multiplicity)
+    }
+}
+
+impl<'a> RemoveFactor<&'a Natural> for Natural {
+    type Output = Natural;
+
+    /// Removes all factors of $p$ from a [`Natural`], returning the remaining [`Natural`] and the
+    /// multiplicity of $p$. The first [`Natural`] is taken by value and the second by reference.
+    ///
+    /// $f(x, p) = (x/p^k, k)$, where $k$ is the largest integer such that $p^k \mid x$.
+    ///
+    /// $p$ does not have to be prime.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::RemoveFactor;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(360u32)
+    ///         .remove_factor(&Natural::from(2u32))
+    ///         .to_debug_string(),
+    ///     "(45, 3)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(360u32)
+    ///         .remove_factor(&Natural::from(6u32))
+    ///         .to_debug_string(),
+    ///     "(10, 2)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(360u32)
+    ///         .remove_factor(&Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "(360, 0)"
+    /// );
+    /// ```
+    #[inline]
+    fn remove_factor(mut self, p: &'a Natural) -> (Natural, u64) {
+        let multiplicity = remove_factor_helper(&mut self, p);
+        (self, multiplicity)
+    }
+}
+
+impl<'a> RemoveFactor<Natural> for &'a Natural {
+    type Output = Natural;
+
+    /// Removes all factors of $p$ from a [`Natural`], returning the remaining [`Natural`] and the
+    /// multiplicity of $p$. The first [`Natural`] is taken by reference and the second by value.
+    ///
+    /// $f(x, p) = (x/p^k, k)$, where $k$ is the largest integer such that $p^k \mid x$.
+    ///
+    /// $p$ does not have to be prime.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::RemoveFactor;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(360u32))
+    ///         .remove_factor(Natural::from(2u32))
+    ///         .to_debug_string(),
+    ///     "(45, 3)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(360u32))
+    ///         .remove_factor(Natural::from(6u32))
+    ///         .to_debug_string(),
+    ///     "(10, 2)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(360u32))
+    ///         .remove_factor(Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "(360, 0)"
+    /// );
+    /// ```
+    #[inline]
+    fn remove_factor(self, p: Natural) -> (Natural, u64) {
+        self.clone().remove_factor(&p)
+    }
+}
+
+impl<'a, 'b> RemoveFactor<&'b Natural> for &'a Natural {
+    type Output = Natural;
+
+    /// Removes all factors of $p$ from a [`Natural`], returning the remaining [`Natural`] and the
+    /// multiplicity of $p$. Both [`Natural`]s are taken by reference.
+    ///
+    /// $f(x, p) = (x/p^k, k)$, where $k$ is the largest integer such that $p^k \mid x$.
+    ///
+    /// $p$ does not have to be prime.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::RemoveFactor;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(360u32))
+    ///         .remove_factor(&Natural::from(2u32))
+    ///         .to_debug_string(),
+    ///     "(45, 3)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(360u32))
+    ///         .remove_factor(&Natural::from(6u32))
+    ///         .to_debug_string(),
+    ///     "(10, 2)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(360u32))
+    ///         .remove_factor(&Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "(360, 0)"
+    /// );
+    /// ```
+    #[inline]
+    fn remove_factor(self, p: &'b Natural) -> (Natural, u64) {
+        self.clone().remove_factor(p)
+    }
+}
+
+impl RemoveFactorAssign<Natural> for Natural {
+    /// Removes all factors of $p$ from a [`Natural`] in place, returning the multiplicity of $p$.
+    /// The [`Natural`] on the right-hand side is taken by value.
+    ///
+    /// $x \gets x/p^k$ and $f(x, p) = k$, where $k$ is the largest integer such that
+    /// $p^k \mid x$.
+    ///
+    /// $p$ does not have to be prime.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::RemoveFactorAssign;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Natural::from(360u32);
+    /// assert_eq!(x.remove_factor_assign(Natural::from(3u32)), 2);
+    /// assert_eq!(x, 40);
+    /// ```
+    #[inline]
+    fn remove_factor_assign(&mut self, p: Natural) -> u64 {
+        remove_factor_helper(self, &p)
+    }
+}
+
+impl<'a> RemoveFactorAssign<&'a Natural> for Natural {
+    /// Removes all factors of $p$ from a [`Natural`] in place, returning the multiplicity of $p$.
+    /// The [`Natural`] on the right-hand side is taken by reference.
+    ///
+    /// $x \gets x/p^k$ and $f(x, p) = k$, where $k$ is the largest integer such that
+    /// $p^k \mid x$.
+    ///
+    /// $p$ does not have to be prime.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::RemoveFactorAssign;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Natural::from(360u32);
+    /// assert_eq!(x.remove_factor_assign(&Natural::from(3u32)), 2);
+    /// assert_eq!(x, 40);
+    /// ```
+    #[inline]
+    fn remove_factor_assign(&mut self, p: &'a Natural) -> u64 {
+        remove_factor_helper(self, p)
+    }
+}
+
+impl Valuation<Natural> for Natural {
+    /// Returns the multiplicity of $p$ as a factor of a [`Natural`]. Both [`Natural`]s are taken
+    /// by value.
+    ///
+    /// $f(x, p) = k$, where $k$ is the largest integer such that $p^k \mid x$. When $p$ is prime,
+    /// this is the $p$-adic valuation of $x$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Valuation;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(360u32).valuation(Natural::from(2u32)), 3);
+    /// assert_eq!(Natural::from(360u32).valuation(Natural::from(5u32)), 1);
+    /// assert_eq!(Natural::from(360u32).valuation(Natural::from(7u32)), 0);
+    /// ```
+    #[inline]
+    fn valuation(mut self, p: Natural) -> u64 {
+        remove_factor_helper(&mut self, &p)
+    }
+}
+
+impl<'a> Valuation<&'a Natural> for Natural {
+    /// Returns the multiplicity of $p$ as a factor of a [`Natural`]. The first [`Natural`] is
+    /// taken by value and the second by reference.
+    ///
+    /// $f(x, p) = k$, where $k$ is the largest integer such that $p^k \mid x$. When $p$ is prime,
+    /// this is the $p$-adic valuation of $x$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Valuation;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(360u32).valuation(&Natural::from(2u32)), 3);
+    /// assert_eq!(Natural::from(360u32).valuation(&Natural::from(5u32)), 1);
+    /// assert_eq!(Natural::from(360u32).valuation(&Natural::from(7u32)), 0);
+    /// ```
+    #[inline]
+    fn valuation(mut self, p: &'a Natural) -> u64 {
+        remove_factor_helper(&mut self, p)
+    }
+}
+
+impl<'a> Valuation<Natural> for &'a Natural {
+    /// Returns the multiplicity of $p$ as a factor of a [`Natural`]. The first [`Natural`] is
+    /// taken by reference and the second by value.
+    ///
+    /// $f(x, p) = k$, where $k$ is the largest integer such that $p^k \mid x$. When $p$ is prime,
+    /// this is the $p$-adic valuation of $x$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Valuation;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Natural::from(360u32)).valuation(Natural::from(2u32)), 3);
+    /// assert_eq!((&Natural::from(360u32)).valuation(Natural::from(5u32)), 1);
+    /// assert_eq!((&Natural::from(360u32)).valuation(Natural::from(7u32)), 0);
+    /// ```
+    #[inline]
+    fn valuation(self, p: Natural) -> u64 {
+        remove_factor_helper(&mut self.clone(), &p)
+    }
+}
+
+impl<'a, 'b> Valuation<&'b Natural> for &'a Natural {
+    /// Returns the multiplicity of $p$ as a factor of a [`Natural`]. Both [`Natural`]s are taken
+    /// by reference.
+    ///
+    /// $f(x, p) = k$, where $k$ is the largest integer such that $p^k \mid x$. When $p$ is prime,
+    /// this is the $p$-adic valuation of $x$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Valuation;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Natural::from(360u32)).valuation(&Natural::from(2u32)), 3);
+    /// assert_eq!((&Natural::from(360u32)).valuation(&Natural::from(5u32)), 1);
+    /// assert_eq!((&Natural::from(360u32)).valuation(&Natural::from(7u32)), 0);
+    /// ```
+    #[inline]
+    fn valuation(self, p: &'b Natural) -> u64 {
+        remove_factor_helper(&mut self.clone(), p)
+    }
+}
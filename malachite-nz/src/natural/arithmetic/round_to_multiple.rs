@@ -1,5 +1,5 @@
 use crate::natural::Natural;
-use malachite_base::num::arithmetic::traits::{RoundToMultiple, RoundToMultipleAssign};
+use malachite_base::num::arithmetic::traits::{Parity, RoundToMultiple, RoundToMultipleAssign};
 use malachite_base::num::basic::traits::Zero;
 use malachite_base::rounding_modes::RoundingMode;
 use std::cmp::Ordering;
@@ -31,6 +31,12 @@ impl RoundToMultiple<Natural> for Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $f(x, y, \mathrm{NearestTiesAway})$ and $f(x, y, \mathrm{NearestTiesTowardZero})$ are
+    /// defined like $f(x, y, \mathrm{Nearest})$, except that ties are rounded away from $0$ and
+    /// towards $0$, respectively. $f(x, y, \mathrm{Odd})$ is $f(x, y, \mathrm{Down})$ if no
+    /// rounding is necessary, and otherwise whichever of $f(x, y, \mathrm{Floor})$ and $f(x, y,
+    /// \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+    ///
     /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \N$.
     ///
     /// The following two expressions are equivalent:
@@ -134,6 +140,12 @@ impl<'a> RoundToMultiple<&'a Natural> for Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $f(x, y, \mathrm{NearestTiesAway})$ and $f(x, y, \mathrm{NearestTiesTowardZero})$ are
+    /// defined like $f(x, y, \mathrm{Nearest})$, except that ties are rounded away from $0$ and
+    /// towards $0$, respectively. $f(x, y, \mathrm{Odd})$ is $f(x, y, \mathrm{Down})$ if no
+    /// rounding is necessary, and otherwise whichever of $f(x, y, \mathrm{Floor})$ and $f(x, y,
+    /// \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+    ///
     /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \N$.
     ///
     /// The following two expressions are equivalent:
@@ -237,6 +249,12 @@ impl<'a> RoundToMultiple<Natural> for &'a Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $f(x, y, \mathrm{NearestTiesAway})$ and $f(x, y, \mathrm{NearestTiesTowardZero})$ are
+    /// defined like $f(x, y, \mathrm{Nearest})$, except that ties are rounded away from $0$ and
+    /// towards $0$, respectively. $f(x, y, \mathrm{Odd})$ is $f(x, y, \mathrm{Down})$ if no
+    /// rounding is necessary, and otherwise whichever of $f(x, y, \mathrm{Floor})$ and $f(x, y,
+    /// \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+    ///
     /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \N$.
     ///
     /// The following two expressions are equivalent:
@@ -310,9 +328,11 @@ impl<'a> RoundToMultiple<Natural> for &'a Natural {
         match (self, other) {
             (x, y) if *x == y => (y, Ordering::Equal),
             (x, Natural::ZERO) => match rm {
-                RoundingMode::Down | RoundingMode::Floor | RoundingMode::Nearest => {
-                    (Natural::ZERO, Ordering::Less)
-                }
+                RoundingMode::Down
+                | RoundingMode::Floor
+                | RoundingMode::Nearest
+                | RoundingMode::NearestTiesAway
+                | RoundingMode::NearestTiesTowardZero => (Natural::ZERO, Ordering::Less),
                 _ => panic!("Cannot round {x} to zero using RoundingMode {rm}"),
             },
             (x, y) => {
@@ -343,6 +363,23 @@ impl<'a> RoundToMultiple<Natural> for &'a Natural {
                                 }
                             }
                         }
+                        RoundingMode::NearestTiesAway | RoundingMode::NearestTiesTowardZero => {
+                            let compare = (r << 1u64).cmp(&y);
+                            if compare == Ordering::Greater
+                                || compare == Ordering::Equal && rm == RoundingMode::NearestTiesAway
+                            {
+                                (floor + y, Ordering::Greater)
+                            } else {
+                                (floor, Ordering::Less)
+                            }
+                        }
+                        RoundingMode::Odd => {
+                            if (&floor / &y).odd() {
+                                (floor, Ordering::Less)
+                            } else {
+                                (floor + y, Ordering::Greater)
+                            }
+                        }
                         RoundingMode::Exact => {
                             panic!("Cannot round {x} to {y} using RoundingMode {rm}")
                         }
@@ -380,6 +417,12 @@ impl<'a, 'b> RoundToMultiple<&'b Natural> for &'a Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $f(x, y, \mathrm{NearestTiesAway})$ and $f(x, y, \mathrm{NearestTiesTowardZero})$ are
+    /// defined like $f(x, y, \mathrm{Nearest})$, except that ties are rounded away from $0$ and
+    /// towards $0$, respectively. $f(x, y, \mathrm{Odd})$ is $f(x, y, \mathrm{Down})$ if no
+    /// rounding is necessary, and otherwise whichever of $f(x, y, \mathrm{Floor})$ and $f(x, y,
+    /// \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+    ///
     /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \N$.
     ///
     /// The following two expressions are equivalent:
@@ -453,9 +496,11 @@ impl<'a, 'b> RoundToMultiple<&'b Natural> for &'a Natural {
         match (self, other) {
             (x, y) if x == y => (x.clone(), Ordering::Equal),
             (x, &Natural::ZERO) => match rm {
-                RoundingMode::Down | RoundingMode::Floor | RoundingMode::Nearest => {
-                    (Natural::ZERO, Ordering::Less)
-                }
+                RoundingMode::Down
+                | RoundingMode::Floor
+                | RoundingMode::Nearest
+                | RoundingMode::NearestTiesAway
+                | RoundingMode::NearestTiesTowardZero => (Natural::ZERO, Ordering::Less),
                 _ => panic!("Cannot round {x} to zero using RoundingMode {rm}"),
             },
             (x, y) => {
@@ -486,6 +531,23 @@ impl<'a, 'b> RoundToMultiple<&'b Natural> for &'a Natural {
                                 }
                             }
                         }
+                        RoundingMode::NearestTiesAway | RoundingMode::NearestTiesTowardZero => {
+                            let compare = (r << 1u64).cmp(y);
+                            if compare == Ordering::Greater
+                                || compare == Ordering::Equal && rm == RoundingMode::NearestTiesAway
+                            {
+                                (floor + y, Ordering::Greater)
+                            } else {
+                                (floor, Ordering::Less)
+                            }
+                        }
+                        RoundingMode::Odd => {
+                            if (&floor / y).odd() {
+                                (floor, Ordering::Less)
+                            } else {
+                                (floor + y, Ordering::Greater)
+                            }
+                        }
                         RoundingMode::Exact => {
                             panic!("Cannot round {x} to {y} using RoundingMode {rm}")
                         }
@@ -588,7 +650,11 @@ impl RoundToMultipleAssign<Natural> for Natural {
         match (&mut *self, other) {
             (x, y) if *x == y => Ordering::Equal,
             (x, Natural::ZERO) => match rm {
-                RoundingMode::Down | RoundingMode::Floor | RoundingMode::Nearest => {
+                RoundingMode::Down
+                | RoundingMode::Floor
+                | RoundingMode::Nearest
+                | RoundingMode::NearestTiesAway
+                | RoundingMode::NearestTiesTowardZero => {
                     *self = Natural::ZERO;
                     Ordering::Less
                 }
@@ -629,6 +695,25 @@ impl RoundToMultipleAssign<Natural> for Natural {
                                 }
                             }
                         }
+                        RoundingMode::NearestTiesAway | RoundingMode::NearestTiesTowardZero => {
+                            let compare = (r << 1u64).cmp(&y);
+                            if compare == Ordering::Greater
+                                || compare == Ordering::Equal && rm == RoundingMode::NearestTiesAway
+                            {
+                                *x += y;
+                                Ordering::Greater
+                            } else {
+                                Ordering::Less
+                            }
+                        }
+                        RoundingMode::Odd => {
+                            if (&*x / &y).odd() {
+                                Ordering::Less
+                            } else {
+                                *x += y;
+                                Ordering::Greater
+                            }
+                        }
                         RoundingMode::Exact => {
                             panic!("Cannot round {x} to {y} using RoundingMode {rm}")
                         }
@@ -731,7 +816,11 @@ impl<'a> RoundToMultipleAssign<&'a Natural> for Natural {
         match (&mut *self, other) {
             (x, y) if *x == *y => Ordering::Equal,
             (x, &Natural::ZERO) => match rm {
-                RoundingMode::Down | RoundingMode::Floor | RoundingMode::Nearest => {
+                RoundingMode::Down
+                | RoundingMode::Floor
+                | RoundingMode::Nearest
+                | RoundingMode::NearestTiesAway
+                | RoundingMode::NearestTiesTowardZero => {
                     *self = Natural::ZERO;
                     Ordering::Less
                 }
@@ -772,6 +861,25 @@ impl<'a> RoundToMultipleAssign<&'a Natural> for Natural {
                                 }
                             }
                         }
+                        RoundingMode::NearestTiesAway | RoundingMode::NearestTiesTowardZero => {
+                            let compare = (r << 1u64).cmp(y);
+                            if compare == Ordering::Greater
+                                || compare == Ordering::Equal && rm == RoundingMode::NearestTiesAway
+                            {
+                                *x += y;
+                                Ordering::Greater
+                            } else {
+                                Ordering::Less
+                            }
+                        }
+                        RoundingMode::Odd => {
+                            if (&*x / y).odd() {
+                                Ordering::Less
+                            } else {
+                                *x += y;
+                                Ordering::Greater
+                            }
+                        }
                         RoundingMode::Exact => {
                             panic!("Cannot round {x} to {y} using RoundingMode {rm}")
                         }
@@ -161,6 +161,72 @@ pub_test! {
     }
 }}
 
+// Interpreting a slice of `Limb`s as the limbs (in ascending order) of a `Natural`, returns the
+// limbs of the `Natural` rounded to the nearest multiple of 2<sup>`pow`</sup>. If the original
+// value is exactly between two multiples, it is rounded up. The limbs should not all be zero.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `max(xs.len(), pow / Limb::WIDTH)`.
+pub_test! {limbs_round_to_multiple_of_power_of_2_nearest_ties_away(
+    xs: &[Limb],
+    pow: u64
+) -> (Vec<Limb>, Ordering) {
+    if pow == 0 {
+        (xs.to_vec(), Ordering::Equal)
+    } else if !limbs_get_bit(xs, pow - 1) {
+        limbs_round_to_multiple_of_power_of_2_down(xs, pow)
+    } else {
+        limbs_round_to_multiple_of_power_of_2_up(xs, pow)
+    }
+}}
+
+// Interpreting a slice of `Limb`s as the limbs (in ascending order) of a `Natural`, returns the
+// limbs of the `Natural` rounded to the nearest multiple of 2<sup>`pow`</sup>. If the original
+// value is exactly between two multiples, it is rounded down. The limbs should not all be zero.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `max(xs.len(), pow / Limb::WIDTH)`.
+pub_test! {limbs_round_to_multiple_of_power_of_2_nearest_ties_toward_zero(
+    xs: &[Limb],
+    pow: u64
+) -> (Vec<Limb>, Ordering) {
+    if pow == 0 {
+        (xs.to_vec(), Ordering::Equal)
+    } else if limbs_get_bit(xs, pow - 1) && !limbs_divisible_by_power_of_2(xs, pow - 1) {
+        limbs_round_to_multiple_of_power_of_2_up(xs, pow)
+    } else {
+        limbs_round_to_multiple_of_power_of_2_down(xs, pow)
+    }
+}}
+
+// Interpreting a slice of `Limb`s as the limbs (in ascending order) of a `Natural`, returns the
+// limbs of the `Natural` rounded to a multiple of 2<sup>`pow`</sup>. If the original value is not
+// already a multiple, it is rounded to the neighboring multiple whose `pow`th bit is one. The
+// limbs should not all be zero.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `max(xs.len(), pow / Limb::WIDTH)`.
+pub_test! {
+    limbs_round_to_multiple_of_power_of_2_odd(xs: &[Limb], pow: u64) -> (Vec<Limb>, Ordering) {
+    if limbs_get_bit(xs, pow) || limbs_divisible_by_power_of_2(xs, pow) {
+        limbs_round_to_multiple_of_power_of_2_down(xs, pow)
+    } else {
+        limbs_round_to_multiple_of_power_of_2_up(xs, pow)
+    }
+}}
+
 // Interpreting a slice of `Limb`s as the limbs (in ascending order) of a `Natural`, returns the
 // limbs of the `Natural` rounded to a multiple of 2<sup>`pow`</sup>, using a specified rounding
 // format. If the original value is not already a multiple of the power of 2, and the
@@ -185,6 +251,13 @@ pub_test! {limbs_round_to_multiple_of_power_of_2(
             Some(limbs_round_to_multiple_of_power_of_2_up(xs, pow))
         }
         RoundingMode::Nearest => Some(limbs_round_to_multiple_of_power_of_2_nearest(xs, pow)),
+        RoundingMode::NearestTiesAway => {
+            Some(limbs_round_to_multiple_of_power_of_2_nearest_ties_away(xs, pow))
+        }
+        RoundingMode::NearestTiesTowardZero => {
+            Some(limbs_round_to_multiple_of_power_of_2_nearest_ties_toward_zero(xs, pow))
+        }
+        RoundingMode::Odd => Some(limbs_round_to_multiple_of_power_of_2_odd(xs, pow)),
         RoundingMode::Exact => {
             if limbs_divisible_by_power_of_2(xs, pow) {
                 Some((xs.to_vec(), Ordering::Equal))
@@ -337,6 +410,74 @@ pub_test! {limbs_round_to_multiple_of_power_of_2_nearest_in_place(
     }
 }}
 
+// Interpreting a `Vec` of `Limb`s as the limbs (in ascending order) of a `Natural`, writes the
+// limbs of the `Natural`, rounded to the nearest multiple of 2<sup>`pow`</sup>, to the input
+// `Vec`. If the original value is exactly between two multiples, it is rounded up. The limbs
+// should not all be zero.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `max(xs.len(), pow / Limb::WIDTH)`.
+pub_test! {limbs_round_to_multiple_of_power_of_2_nearest_ties_away_in_place(
+    xs: &mut Vec<Limb>,
+    pow: u64
+) -> Ordering {
+    if pow == 0 {
+        Ordering::Equal
+    } else if !limbs_get_bit(xs, pow - 1) {
+        limbs_round_to_multiple_of_power_of_2_down_in_place(xs, pow)
+    } else {
+        limbs_round_to_multiple_of_power_of_2_up_in_place(xs, pow)
+    }
+}}
+
+// Interpreting a `Vec` of `Limb`s as the limbs (in ascending order) of a `Natural`, writes the
+// limbs of the `Natural`, rounded to the nearest multiple of 2<sup>`pow`</sup>, to the input
+// `Vec`. If the original value is exactly between two multiples, it is rounded down. The limbs
+// should not all be zero.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `max(xs.len(), pow / Limb::WIDTH)`.
+pub_test! {limbs_round_to_multiple_of_power_of_2_nearest_ties_toward_zero_in_place(
+    xs: &mut Vec<Limb>,
+    pow: u64
+) -> Ordering {
+    if pow == 0 {
+        Ordering::Equal
+    } else if limbs_get_bit(xs, pow - 1) && !limbs_divisible_by_power_of_2(xs, pow - 1) {
+        limbs_round_to_multiple_of_power_of_2_up_in_place(xs, pow)
+    } else {
+        limbs_round_to_multiple_of_power_of_2_down_in_place(xs, pow)
+    }
+}}
+
+// Interpreting a `Vec` of `Limb`s as the limbs (in ascending order) of a `Natural`, writes the
+// limbs of the `Natural`, rounded to a multiple of 2<sup>`pow`</sup>, to the input `Vec`. If the
+// original value is not already a multiple, it is rounded to the neighboring multiple whose
+// `pow`th bit is one. The limbs should not all be zero.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `max(xs.len(), pow / Limb::WIDTH)`.
+pub_test! {
+    limbs_round_to_multiple_of_power_of_2_odd_in_place(xs: &mut Vec<Limb>, pow: u64) -> Ordering {
+    if limbs_get_bit(xs, pow) || limbs_divisible_by_power_of_2(xs, pow) {
+        limbs_round_to_multiple_of_power_of_2_down_in_place(xs, pow)
+    } else {
+        limbs_round_to_multiple_of_power_of_2_up_in_place(xs, pow)
+    }
+}}
+
 // Interpreting a slice of `Limb`s as the limbs (in ascending order) of a `Natural`, writes the
 // limbs of the `Natural` rounded to the nearest multiple of 2<sup>`pow`</sup> to the input `Vec`,
 // using a specified rounding format. If the original value is not already a multiple of the power
@@ -364,6 +505,13 @@ pub_test! {limbs_round_to_multiple_of_power_of_2_in_place(
         RoundingMode::Nearest => Some(limbs_round_to_multiple_of_power_of_2_nearest_in_place(
             xs, pow,
         )),
+        RoundingMode::NearestTiesAway => Some(
+            limbs_round_to_multiple_of_power_of_2_nearest_ties_away_in_place(xs, pow),
+        ),
+        RoundingMode::NearestTiesTowardZero => Some(
+            limbs_round_to_multiple_of_power_of_2_nearest_ties_toward_zero_in_place(xs, pow),
+        ),
+        RoundingMode::Odd => Some(limbs_round_to_multiple_of_power_of_2_odd_in_place(xs, pow)),
         RoundingMode::Exact => {
             if limbs_divisible_by_power_of_2(xs, pow) {
                 Some(Ordering::Equal)
@@ -402,6 +550,12 @@ impl RoundToMultipleOfPowerOf2<u64> for Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $f(x, k, \mathrm{NearestTiesAway})$ and $f(x, k, \mathrm{NearestTiesTowardZero})$ are
+    /// defined like $f(x, k, \mathrm{Nearest})$, except that ties are rounded away from $0$ and
+    /// towards $0$, respectively. $f(x, k, \mathrm{Odd})$ is $f(x, k, \mathrm{Down})$ if no
+    /// rounding is necessary, and otherwise whichever of $f(x, k, \mathrm{Floor})$ and $f(x, k,
+    /// \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+    ///
     /// $f(x, k, \mathrm{Exact}) = 2^k q$, but panics if $q \notin \Z$.
     ///
     /// The following two expressions are equivalent:
@@ -498,6 +652,12 @@ impl<'a> RoundToMultipleOfPowerOf2<u64> for &'a Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $f(x, k, \mathrm{NearestTiesAway})$ and $f(x, k, \mathrm{NearestTiesTowardZero})$ are
+    /// defined like $f(x, k, \mathrm{Nearest})$, except that ties are rounded away from $0$ and
+    /// towards $0$, respectively. $f(x, k, \mathrm{Odd})$ is $f(x, k, \mathrm{Down})$ if no
+    /// rounding is necessary, and otherwise whichever of $f(x, k, \mathrm{Floor})$ and $f(x, k,
+    /// \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+    ///
     /// $f(x, k, \mathrm{Exact}) = 2^k q$, but panics if $q \notin \Z$.
     ///
     /// The following two expressions are equivalent:
@@ -657,7 +817,9 @@ impl RoundToMultipleOfPowerOf2Assign<u64> for Natural {
                 o
             }
             (Natural(Large(ref mut limbs)), pow) => {
-                if let Some(o) = limbs_round_to_multiple_of_power_of_2_in_place(limbs, pow, rm) {
+                if let Some(o) =
+                    limbs_round_to_multiple_of_power_of_2_in_place(limbs.as_mut_vec(), pow, rm)
+                {
                     self.trim();
                     o
                 } else {
@@ -174,11 +174,11 @@ where
             Natural(if let Some(shifted) = small.arithmetic_checked_shl(bits) {
                 Small(shifted)
             } else {
-                Large(limbs_shl(&[*small], u64::exact_from(bits)))
+                Large(limbs_shl(&[*small], u64::exact_from(bits)).into())
             })
         }
         (Natural(Large(ref limbs)), bits) => {
-            Natural(Large(limbs_shl(limbs, u64::exact_from(bits))))
+            Natural(Large(limbs_shl(limbs, u64::exact_from(bits)).into()))
         }
     }
 }
@@ -195,11 +195,11 @@ where
             if let Some(shifted) = small.arithmetic_checked_shl(bits) {
                 *small = shifted;
             } else {
-                *x = Natural(Large(limbs_shl(&[*small], u64::exact_from(bits))));
+                *x = Natural(Large(limbs_shl(&[*small], u64::exact_from(bits)).into()));
             }
         }
         (Natural(Large(ref mut limbs)), bits) => {
-            limbs_vec_shl_in_place(limbs, u64::exact_from(bits));
+            limbs_vec_shl_in_place(limbs.as_mut_vec(), u64::exact_from(bits));
         }
     }
 }
@@ -76,6 +76,13 @@ macro_rules! impl_natural_shl_round_signed {
             /// \end{cases}
             /// $$
             ///
+            /// $g(x, k, \mathrm{NearestTiesAway})$ and $g(x, k, \mathrm{NearestTiesTowardZero})$
+            /// are defined like $g(x, k, \mathrm{Nearest})$, except that ties are rounded away from
+            /// $0$ and towards $0$, respectively. $g(x, k, \mathrm{Odd})$ is $g(x, k,
+            /// \mathrm{Down})$ if no rounding is necessary, and otherwise whichever of $g(x, k,
+            /// \mathrm{Floor})$ and $g(x, k, \mathrm{Ceiling})$ corresponds to an odd rounded
+            /// quotient.
+            ///
             /// $g(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
             ///
             /// Then
@@ -139,6 +146,13 @@ macro_rules! impl_natural_shl_round_signed {
             /// \end{cases}
             /// $$
             ///
+            /// $g(x, k, \mathrm{NearestTiesAway})$ and $g(x, k, \mathrm{NearestTiesTowardZero})$
+            /// are defined like $g(x, k, \mathrm{Nearest})$, except that ties are rounded away from
+            /// $0$ and towards $0$, respectively. $g(x, k, \mathrm{Odd})$ is $g(x, k,
+            /// \mathrm{Down})$ if no rounding is necessary, and otherwise whichever of $g(x, k,
+            /// \mathrm{Floor})$ and $g(x, k, \mathrm{Ceiling})$ corresponds to an odd rounded
+            /// quotient.
+            ///
             /// $g(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
             ///
             /// Then
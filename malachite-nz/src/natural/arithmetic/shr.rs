@@ -163,7 +163,7 @@ where
             *small >>= bits;
         }
         (Natural(Large(ref mut limbs)), bits) => {
-            limbs_vec_shr_in_place(limbs, u64::exact_from(bits));
+            limbs_vec_shr_in_place(limbs.as_mut_vec(), u64::exact_from(bits));
             x.trim();
         }
     }
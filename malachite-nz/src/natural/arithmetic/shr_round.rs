@@ -147,6 +147,84 @@ pub_test! {limbs_shr_exact(xs: &[Limb], bits: u64) -> Option<Vec<Limb>> {
     }
 }}
 
+// Interpreting a slice of `Limb`s as the limbs (in ascending order) of a `Natural`, returns the
+// limbs of the `Natural` right-shifted by a `Limb`, rounding to the `Natural` nearest to the
+// actual value of `self` divided by 2<sup>`bits`</sup>. If the actual value is exactly between two
+// integers, it is rounded up.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(m) = O(m)$
+//
+// where $T$ is time, $M$ is additional memory, $n$ is `xs.len()`, and $m$ is
+// `max(1, xs.len() - bits / Limb::WIDTH)`.
+pub_test! {limbs_shr_round_nearest_ties_away(xs: &[Limb], bits: u64) -> (Vec<Limb>, Ordering) {
+    if bits == 0 {
+        (xs.to_vec(), Ordering::Equal)
+    } else if !limbs_get_bit(xs, bits - 1) {
+        let d = slice_test_zero(xs) || limbs_divisible_by_power_of_2(xs, bits - 1);
+        (
+            limbs_shr(xs, bits),
+            if d { Ordering::Equal } else { Ordering::Less },
+        )
+    } else {
+        limbs_shr_round_up(xs, bits)
+    }
+}}
+
+// Interpreting a slice of `Limb`s as the limbs (in ascending order) of a `Natural`, returns the
+// limbs of the `Natural` right-shifted by a `Limb`, rounding to the `Natural` nearest to the
+// actual value of `self` divided by 2<sup>`bits`</sup>. If the actual value is exactly between two
+// integers, it is rounded down.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(m) = O(m)$
+//
+// where $T$ is time, $M$ is additional memory, $n$ is `xs.len()`, and $m$ is
+// `max(1, xs.len() - bits / Limb::WIDTH)`.
+pub_test! {
+    limbs_shr_round_nearest_ties_toward_zero(xs: &[Limb], bits: u64) -> (Vec<Limb>, Ordering) {
+    if bits == 0 {
+        (xs.to_vec(), Ordering::Equal)
+    } else {
+        let d = slice_test_zero(xs) || limbs_divisible_by_power_of_2(xs, bits - 1);
+        if !limbs_get_bit(xs, bits - 1) {
+            (
+                limbs_shr(xs, bits),
+                if d { Ordering::Equal } else { Ordering::Less },
+            )
+        } else if d {
+            (limbs_shr(xs, bits), Ordering::Less)
+        } else {
+            limbs_shr_round_up(xs, bits)
+        }
+    }
+}}
+
+// Interpreting a slice of `Limb`s as the limbs (in ascending order) of a `Natural`, returns the
+// limbs of the `Natural` right-shifted by a `Limb`. If the shift is inexact, the result is rounded
+// to whichever of its two neighbors is odd. The limbs should not all be zero.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(m) = O(m)$
+//
+// where $T$ is time, $M$ is additional memory, $n$ is `xs.len()`, and $m$ is
+// `max(1, xs.len() - bits / Limb::WIDTH)`.
+pub_test! {limbs_shr_round_odd(xs: &[Limb], bits: u64) -> (Vec<Limb>, Ordering) {
+    if limbs_divisible_by_power_of_2(xs, bits) {
+        (limbs_shr(xs, bits), Ordering::Equal)
+    } else if limbs_get_bit(xs, bits) {
+        (limbs_shr(xs, bits), Ordering::Less)
+    } else {
+        limbs_shr_round_up(xs, bits)
+    }
+}}
+
 // Interpreting a slice of `Limb`s as the limbs (in ascending order) of a `Natural`, returns the
 // limbs of the `Natural` right-shifted by a `Limb`, rounded using a specified rounding format. The
 // limbs should not all be zero.
@@ -171,6 +249,11 @@ pub_test! {
         )),
         RoundingMode::Up | RoundingMode::Ceiling => Some(limbs_shr_round_up(xs, bits)),
         RoundingMode::Nearest => Some(limbs_shr_round_nearest(xs, bits)),
+        RoundingMode::NearestTiesAway => Some(limbs_shr_round_nearest_ties_away(xs, bits)),
+        RoundingMode::NearestTiesTowardZero => {
+            Some(limbs_shr_round_nearest_ties_toward_zero(xs, bits))
+        }
+        RoundingMode::Odd => Some(limbs_shr_round_odd(xs, bits)),
         RoundingMode::Exact => limbs_shr_exact(xs, bits).map(|ss| (ss, Ordering::Equal)),
     }
 }}
@@ -277,6 +360,92 @@ pub_test! {limbs_vec_shr_round_nearest_in_place(xs: &mut Vec<Limb>, bits: u64) -
     }
 }}
 
+// Interpreting a `Vec` of `Limb`s as the limbs (in ascending order) of a `Natural`, writes the
+// limbs of the `Natural` right-shifted by a `Limb` to the input `Vec`, rounding to the `Natural`
+// nearest to the actual value of `self` divided by 2<sup>`bits`</sup>. If the actual value is
+// exactly between two integers, it is rounded up.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory and $n$ is `xs.len()`.
+pub_test! {
+    limbs_vec_shr_round_nearest_ties_away_in_place(xs: &mut Vec<Limb>, bits: u64) -> Ordering {
+    if bits == 0 {
+        Ordering::Equal
+    } else if !limbs_get_bit(xs, bits - 1) {
+        let d = slice_test_zero(xs) || limbs_divisible_by_power_of_2(xs, bits - 1);
+        limbs_vec_shr_in_place(xs, bits);
+        if d {
+            Ordering::Equal
+        } else {
+            Ordering::Less
+        }
+    } else {
+        limbs_vec_shr_round_up_in_place(xs, bits)
+    }
+}}
+
+// Interpreting a `Vec` of `Limb`s as the limbs (in ascending order) of a `Natural`, writes the
+// limbs of the `Natural` right-shifted by a `Limb` to the input `Vec`, rounding to the `Natural`
+// nearest to the actual value of `self` divided by 2<sup>`bits`</sup>. If the actual value is
+// exactly between two integers, it is rounded down.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory and $n$ is `xs.len()`.
+pub_test! {limbs_vec_shr_round_nearest_ties_toward_zero_in_place(
+    xs: &mut Vec<Limb>,
+    bits: u64
+) -> Ordering {
+    if bits == 0 {
+        Ordering::Equal
+    } else {
+        let d = slice_test_zero(xs) || limbs_divisible_by_power_of_2(xs, bits - 1);
+        if !limbs_get_bit(xs, bits - 1) {
+            limbs_vec_shr_in_place(xs, bits);
+            if d {
+                Ordering::Equal
+            } else {
+                Ordering::Less
+            }
+        } else if d {
+            limbs_vec_shr_in_place(xs, bits);
+            Ordering::Less
+        } else {
+            limbs_vec_shr_round_up_in_place(xs, bits)
+        }
+    }
+}}
+
+// Interpreting a `Vec` of `Limb`s as the limbs (in ascending order) of a `Natural`, writes the
+// limbs of the `Natural` right-shifted by a `Limb` to the input `Vec`. If the shift is inexact,
+// the result is rounded to whichever of its two neighbors is odd. The limbs should not all be
+// zero.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory and $n$ is `xs.len()`.
+pub_test! {limbs_vec_shr_round_odd_in_place(xs: &mut Vec<Limb>, bits: u64) -> Ordering {
+    if limbs_divisible_by_power_of_2(xs, bits) {
+        limbs_vec_shr_in_place(xs, bits);
+        Ordering::Equal
+    } else if limbs_get_bit(xs, bits) {
+        limbs_vec_shr_in_place(xs, bits);
+        Ordering::Less
+    } else {
+        limbs_vec_shr_round_up_in_place(xs, bits)
+    }
+}}
+
 // Interpreting a `Vec` of `Limb`s as the limbs (in ascending order) of a `Natural`, writes the
 // limbs of the `Natural` right-shifted by a `Limb` to the input `Vec`, if the shift is exact
 // (doesn't remove any `true` bits). Returns whether the shift was exact. The limbs should not all
@@ -331,6 +500,14 @@ pub_test! {limbs_vec_shr_round_in_place(
             (true, limbs_vec_shr_round_up_in_place(xs, bits))
         }
         RoundingMode::Nearest => (true, limbs_vec_shr_round_nearest_in_place(xs, bits)),
+        RoundingMode::NearestTiesAway => {
+            (true, limbs_vec_shr_round_nearest_ties_away_in_place(xs, bits))
+        }
+        RoundingMode::NearestTiesTowardZero => (
+            true,
+            limbs_vec_shr_round_nearest_ties_toward_zero_in_place(xs, bits),
+        ),
+        RoundingMode::Odd => (true, limbs_vec_shr_round_odd_in_place(xs, bits)),
         RoundingMode::Exact => (limbs_vec_shr_exact_in_place(xs, bits), Ordering::Equal),
     }
 }}
@@ -375,7 +552,8 @@ where
         (_, bits) if bits == T::ZERO => Ordering::Equal,
         (Natural(Small(ref mut small)), bits) => small.shr_round_assign(bits, rm),
         (Natural(Large(ref mut limbs)), bits) => {
-            let (b, o) = limbs_vec_shr_round_in_place(limbs, u64::exact_from(bits), rm);
+            let (b, o) =
+                limbs_vec_shr_round_in_place(limbs.as_mut_vec(), u64::exact_from(bits), rm);
             if !b {
                 panic!("Right shift is not exact.");
             }
@@ -421,6 +599,13 @@ macro_rules! impl_natural_shr_round_unsigned {
             /// \end{cases}
             /// $$
             ///
+            /// $f(x, k, \mathrm{NearestTiesAway})$ and $f(x, k, \mathrm{NearestTiesTowardZero})$
+            /// are defined like $f(x, k, \mathrm{Nearest})$, except that ties are rounded away from
+            /// $0$ and towards $0$, respectively. $f(x, k, \mathrm{Odd})$ is $f(x, k,
+            /// \mathrm{Down})$ if no rounding is necessary, and otherwise whichever of $f(x, k,
+            /// \mathrm{Floor})$ and $f(x, k, \mathrm{Ceiling})$ corresponds to an odd rounded
+            /// quotient.
+            ///
             /// $f(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
             ///
             /// Then
@@ -481,6 +666,13 @@ macro_rules! impl_natural_shr_round_unsigned {
             /// \end{cases}
             /// $$
             ///
+            /// $f(x, k, \mathrm{NearestTiesAway})$ and $f(x, k, \mathrm{NearestTiesTowardZero})$
+            /// are defined like $f(x, k, \mathrm{Nearest})$, except that ties are rounded away from
+            /// $0$ and towards $0$, respectively. $f(x, k, \mathrm{Odd})$ is $f(x, k,
+            /// \mathrm{Down})$ if no rounding is necessary, and otherwise whichever of $f(x, k,
+            /// \mathrm{Floor})$ and $f(x, k, \mathrm{Ceiling})$ corresponds to an odd rounded
+            /// quotient.
+            ///
             /// $f(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
             ///
             /// Then
@@ -609,6 +801,13 @@ macro_rules! impl_natural_shr_round_signed {
             /// \end{cases}
             /// $$
             ///
+            /// $f(x, k, \mathrm{NearestTiesAway})$ and $f(x, k, \mathrm{NearestTiesTowardZero})$
+            /// are defined like $f(x, k, \mathrm{Nearest})$, except that ties are rounded away from
+            /// $0$ and towards $0$, respectively. $f(x, k, \mathrm{Odd})$ is $f(x, k,
+            /// \mathrm{Down})$ if no rounding is necessary, and otherwise whichever of $f(x, k,
+            /// \mathrm{Floor})$ and $f(x, k, \mathrm{Ceiling})$ corresponds to an odd rounded
+            /// quotient.
+            ///
             /// $f(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
             ///
             /// Then
@@ -671,6 +870,13 @@ macro_rules! impl_natural_shr_round_signed {
             /// \end{cases}
             /// $$
             ///
+            /// $f(x, k, \mathrm{NearestTiesAway})$ and $f(x, k, \mathrm{NearestTiesTowardZero})$
+            /// are defined like $f(x, k, \mathrm{Nearest})$, except that ties are rounded away from
+            /// $0$ and towards $0$, respectively. $f(x, k, \mathrm{Odd})$ is $f(x, k,
+            /// \mathrm{Down})$ if no rounding is necessary, and otherwise whichever of $f(x, k,
+            /// \mathrm{Floor})$ and $f(x, k, \mathrm{Ceiling})$ corresponds to an odd rounded
+            /// quotient.
+            ///
             /// $f(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
             ///
             /// Then
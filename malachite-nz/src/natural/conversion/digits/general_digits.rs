@@ -12,6 +12,7 @@ use crate::natural::arithmetic::square::{limbs_square_to_out, limbs_square_to_ou
 use crate::natural::comparison::cmp::limbs_cmp_same_length;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
+use crate::parallel::{join, PARALLEL, PARALLEL_DIGITS_THRESHOLD};
 use crate::platform::{
     Limb, BASES, FROM_DIGITS_DIVIDE_AND_CONQUER_THRESHOLD, MP_BASES_BIG_BASE_10,
     MP_BASES_BIG_BASE_INVERTED_10, MP_BASES_CHARS_PER_LIMB_10, MP_BASES_NORMALIZATION_STEPS_10,
@@ -734,24 +735,59 @@ fn limbs_to_digits_small_base_divide_and_conquer<T: PrimitiveUnsigned>(
                 len -= powers[i].digits_in_base;
             }
             let (scratch_lo, scratch_hi) = scratch.split_at_mut(q_len);
-            let next_index = limbs_to_digits_small_base_divide_and_conquer(
-                out,
-                len,
-                scratch_lo,
-                base,
-                powers,
-                i - 1,
-                scratch_hi,
-            );
-            limbs_to_digits_small_base_divide_and_conquer(
-                &mut out[next_index..],
-                power.digits_in_base,
-                &mut xs[..total_len],
-                base,
-                powers,
-                i - 1,
-                scratch,
-            ) + next_index
+            if PARALLEL && xs_len >= PARALLEL_DIGITS_THRESHOLD {
+                // The number of high digits isn't known in advance, so the low digits are written
+                // to a separate buffer and copied afterwards.
+                let mut lo_digits = vec![T::ZERO; power.digits_in_base];
+                let mut lo_scratch =
+                    vec![0; limbs_to_digits_small_base_divide_and_conquer_scratch_len(total_len)];
+                let (next_index, lo_len) = join(
+                    true,
+                    || {
+                        limbs_to_digits_small_base_divide_and_conquer(
+                            out,
+                            len,
+                            scratch_lo,
+                            base,
+                            powers,
+                            i - 1,
+                            scratch_hi,
+                        )
+                    },
+                    || {
+                        limbs_to_digits_small_base_divide_and_conquer(
+                            &mut lo_digits,
+                            power.digits_in_base,
+                            &mut xs[..total_len],
+                            base,
+                            powers,
+                            i - 1,
+                            &mut lo_scratch,
+                        )
+                    },
+                );
+                out[next_index..next_index + lo_len].copy_from_slice(&lo_digits[..lo_len]);
+                next_index + lo_len
+            } else {
+                let next_index = limbs_to_digits_small_base_divide_and_conquer(
+                    out,
+                    len,
+                    scratch_lo,
+                    base,
+                    powers,
+                    i - 1,
+                    scratch_hi,
+                );
+                limbs_to_digits_small_base_divide_and_conquer(
+                    &mut out[next_index..],
+                    power.digits_in_base,
+                    &mut xs[..total_len],
+                    base,
+                    powers,
+                    i - 1,
+                    scratch,
+                ) + next_index
+            }
         }
     }
 }
@@ -1396,36 +1432,60 @@ where
     let len_hi = xs_len - len_lo;
     let (xs_lo, xs_hi) = xs.split_at(len_hi);
     assert!(len_lo >= len_hi);
-    let out_len_hi = if len_hi < SET_STR_DC_THRESHOLD {
-        limbs_from_digits_small_base_basecase(scratch, xs_lo, base)
-    } else {
-        limbs_from_digits_small_base_divide_and_conquer(scratch, xs_lo, base, powers, i - 1, out)
-    }?;
     let shift = power.shift;
     let adjusted_power_len = power.power.len() + shift;
-    if out_len_hi == 0 {
-        // Zero +1 limb here, to avoid reading an allocated but uninitialized limb in
-        // limbs_slice_add_limb_in_place below.
-        slice_set_zero(&mut out[..adjusted_power_len + 1]);
-    } else {
-        let (out_lo, out_hi) = out.split_at_mut(shift);
-        let mut mul_scratch = vec![0; limbs_mul_to_out_scratch_len(power.power.len(), out_len_hi)];
-        limbs_mul_to_out(out_hi, power.power, &scratch[..out_len_hi], &mut mul_scratch);
-        slice_set_zero(out_lo);
-    }
-    let out_len_lo = if len_lo < SET_STR_DC_THRESHOLD {
-        limbs_from_digits_small_base_basecase(scratch, xs_hi, base)
+    let from_digits_hi = |out: &mut [Limb], scratch: &mut [Limb]| {
+        if len_hi < SET_STR_DC_THRESHOLD {
+            limbs_from_digits_small_base_basecase(out, xs_lo, base)
+        } else {
+            limbs_from_digits_small_base_divide_and_conquer(out, xs_lo, base, powers, i - 1, scratch)
+        }
+    };
+    let from_digits_lo = |scratch: &mut [Limb]| {
+        if len_lo < SET_STR_DC_THRESHOLD {
+            limbs_from_digits_small_base_basecase(scratch, xs_hi, base)
+        } else {
+            let (scratch_lo, scratch_hi) = scratch.split_at_mut(adjusted_power_len + 1);
+            limbs_from_digits_small_base_divide_and_conquer(
+                scratch_lo,
+                xs_hi,
+                base,
+                powers,
+                i - 1,
+                scratch_hi,
+            )
+        }
+    };
+    let mul_hi = |out: &mut [Limb], hi: &[Limb]| {
+        if hi.is_empty() {
+            // Zero +1 limb here, to avoid reading an allocated but uninitialized limb in
+            // limbs_slice_add_limb_in_place below.
+            slice_set_zero(&mut out[..adjusted_power_len + 1]);
+        } else {
+            let (out_lo, out_hi) = out.split_at_mut(shift);
+            let mut mul_scratch = vec![0; limbs_mul_to_out_scratch_len(power.power.len(), hi.len())];
+            limbs_mul_to_out(out_hi, power.power, hi, &mut mul_scratch);
+            slice_set_zero(out_lo);
+        }
+    };
+    let (out_len_hi, out_len_lo) = if PARALLEL && xs_len >= PARALLEL_DIGITS_THRESHOLD {
+        // The high half gets its own buffers, so that it can be computed at the same time as the
+        // low half.
+        let mut hi_out = vec![0; scratch.len()];
+        let mut hi_scratch = vec![0; out.len()];
+        let (out_len_hi, out_len_lo) = join(
+            true,
+            || from_digits_hi(&mut hi_out, &mut hi_scratch),
+            || from_digits_lo(scratch),
+        );
+        let out_len_hi = out_len_hi?;
+        mul_hi(out, &hi_out[..out_len_hi]);
+        (out_len_hi, out_len_lo?)
     } else {
-        let (scratch_lo, scratch_hi) = scratch.split_at_mut(adjusted_power_len + 1);
-        limbs_from_digits_small_base_divide_and_conquer(
-            scratch_lo,
-            xs_hi,
-            base,
-            powers,
-            i - 1,
-            scratch_hi,
-        )
-    }?;
+        let out_len_hi = from_digits_hi(scratch, out)?;
+        mul_hi(out, &scratch[..out_len_hi]);
+        (out_len_hi, from_digits_lo(scratch)?)
+    };
     if out_len_lo != 0 {
         let (out_lo, out_hi) = out.split_at_mut(out_len_lo);
         if limbs_slice_add_same_length_in_place_left(out_lo, &scratch[..out_len_lo]) {
@@ -40,6 +40,8 @@ macro_rules! float_impls {
                     if rm == RoundingMode::Down
                         || rm == RoundingMode::Ceiling
                         || rm == RoundingMode::Nearest
+                        || rm == RoundingMode::NearestTiesAway
+                        || rm == RoundingMode::NearestTiesTowardZero
                     {
                         (Natural::ZERO, Ordering::Greater)
                     } else {
@@ -115,7 +115,14 @@ impl Natural {
         let significant_bits;
         let mut exact = true;
         let mut half_compare = Ordering::Less; // (mantissa - floor(mantissa)).cmp(&0.5)
+        let rm_is_nearest = matches!(
+            rm,
+            RoundingMode::Nearest
+                | RoundingMode::NearestTiesAway
+                | RoundingMode::NearestTiesTowardZero
+        );
         let mut highest_discarded_limb = 0;
+        let mut lower_discarded_limbs_zero = true;
         match self {
             Natural(Small(x)) => {
                 most_significant_limbs[0] = *x;
@@ -138,6 +145,7 @@ impl Natural {
                         }
                         exact = false;
                         highest_discarded_limb = xs[len - 4];
+                        lower_discarded_limbs_zero = slice_test_zero(&xs[..len - 4]);
                     }
                     significant_bits =
                         most_significant_limbs[2].significant_bits() + (Limb::WIDTH << 1);
@@ -168,7 +176,7 @@ impl Natural {
                     if rm == RoundingMode::Exact {
                         return None;
                     }
-                    if rm == RoundingMode::Nearest {
+                    if rm_is_nearest {
                         // If `exact` is true here, that means all lower limbs are 0
                         half_compare = if exact && one_index == shift - 1 {
                             Ordering::Equal
@@ -191,10 +199,14 @@ impl Natural {
                 }
             }
             Ordering::Equal => {
-                if !exact && rm == RoundingMode::Nearest {
+                if !exact && rm_is_nearest {
                     // len is at least 4, since the only way `exact` is false at this point is if
                     // xs[..len - 3] is nonzero
-                    half_compare = highest_discarded_limb.cmp(&Limb::power_of_2(Limb::WIDTH - 1));
+                    half_compare =
+                        match highest_discarded_limb.cmp(&Limb::power_of_2(Limb::WIDTH - 1)) {
+                            Ordering::Equal if !lower_discarded_limbs_zero => Ordering::Greater,
+                            c => c,
+                        };
                 }
             }
         }
@@ -203,11 +215,17 @@ impl Natural {
         let mantissa =
             T::from_raw_mantissa_and_exponent(raw_mantissa, u64::wrapping_from(T::MAX_EXPONENT));
         let increment = !exact
-            && (rm == RoundingMode::Up
-                || rm == RoundingMode::Ceiling
-                || rm == RoundingMode::Nearest
-                    && (half_compare == Ordering::Greater
-                        || half_compare == Ordering::Equal && raw_mantissa.odd()));
+            && match rm {
+                RoundingMode::Up | RoundingMode::Ceiling => true,
+                RoundingMode::Nearest => {
+                    half_compare == Ordering::Greater
+                        || half_compare == Ordering::Equal && raw_mantissa.odd()
+                }
+                RoundingMode::NearestTiesAway => half_compare != Ordering::Less,
+                RoundingMode::NearestTiesTowardZero => half_compare == Ordering::Greater,
+                RoundingMode::Odd => raw_mantissa.even(),
+                RoundingMode::Down | RoundingMode::Floor | RoundingMode::Exact => false,
+            };
         Some(if increment {
             let next_mantissa = mantissa.next_higher();
             if next_mantissa == T::TWO {
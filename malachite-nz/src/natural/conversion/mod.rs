@@ -458,6 +458,7 @@ pub mod primitive_float_from_natural;
 pub mod primitive_int_from_natural;
 /// Implementations of traits for serialization and deserialization using
 /// [serde](https://serde.rs/).
+#[cfg(feature = "serde")]
 pub mod serde;
 /// Implementations of traits for converting [`Natural`](crate::natural::Natural)s to and from
 /// [`String`]s.
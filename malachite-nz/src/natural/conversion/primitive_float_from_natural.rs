@@ -59,9 +59,12 @@ macro_rules! float_impls {
                             RoundingMode::Exact => {
                                 panic!("Value cannot be represented exactly as an {}", $f::NAME)
                             }
-                            RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                                ($f::MAX_FINITE, Ordering::Less)
-                            }
+                            RoundingMode::Floor
+                            | RoundingMode::Down
+                            | RoundingMode::Nearest
+                            | RoundingMode::NearestTiesAway
+                            | RoundingMode::NearestTiesTowardZero
+                            | RoundingMode::Odd => ($f::MAX_FINITE, Ordering::Less),
                             _ => ($f::INFINITY, Ordering::Greater),
                         }
                     }
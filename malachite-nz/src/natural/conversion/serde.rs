@@ -1,24 +1,109 @@
-use crate::natural::{Natural, SerdeNatural};
-use malachite_base::num::conversion::traits::FromStringBase;
-use std::convert::TryFrom;
-
-impl From<Natural> for SerdeNatural {
-    #[inline]
-    fn from(x: Natural) -> SerdeNatural {
-        SerdeNatural(format!("{x:#x}"))
+use crate::natural::Natural;
+use malachite_base::num::conversion::traits::{FromStringBase, PowerOf2Digits};
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::Formatter;
+
+// Parses the human-readable form of a `Natural`: `"0x"` followed by hexadecimal digits.
+pub(crate) fn natural_from_hex_string(s: &str) -> Result<Natural, String> {
+    if let Some(digits) = s.strip_prefix("0x") {
+        Natural::from_string_base(16, digits).map_err(|_| format!("Unrecognized digits in {s}"))
+    } else {
+        Err(format!("String '{s}' does not start with '0x'"))
     }
 }
 
-impl TryFrom<SerdeNatural> for Natural {
-    type Error = String;
+// Converts the compact binary form of a `Natural`, its bytes in little-endian order, back to a
+// `Natural`. Trailing zero bytes are allowed.
+pub(crate) fn natural_from_le_bytes(bytes: &[u8]) -> Natural {
+    Natural::from_power_of_2_digits_asc(8, bytes.iter().copied()).unwrap()
+}
+
+struct NaturalVisitor;
+
+impl<'de> Visitor<'de> for NaturalVisitor {
+    type Value = Natural;
+
+    fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.write_str("a hexadecimal string starting with \"0x\" or a little-endian byte array")
+    }
+
+    fn visit_str<E: Error>(self, s: &str) -> Result<Natural, E> {
+        natural_from_hex_string(s).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: Error>(self, bytes: &[u8]) -> Result<Natural, E> {
+        Ok(natural_from_le_bytes(bytes))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Natural, A::Error> {
+        let mut bytes = Vec::new();
+        while let Some(b) = seq.next_element()? {
+            bytes.push(b);
+        }
+        Ok(natural_from_le_bytes(&bytes))
+    }
+}
+
+impl Serialize for Natural {
+    /// Serializes a [`Natural`].
+    ///
+    /// Human-readable formats, such as JSON, get a hexadecimal string starting with `"0x"`.
+    /// Binary formats, such as bincode, get the [`Natural`]'s bytes in little-endian order, with
+    /// no trailing zeros; zero is an empty byte array. The byte representation doesn't depend on
+    /// the limb size Malachite was compiled with.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_string(&Natural::from(1000000000000u64)).unwrap(),
+    ///     "\"0xe8d4a51000\""
+    /// );
+    /// ```
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&format!("{self:#x}"))
+        } else {
+            serializer.serialize_bytes(&self.to_power_of_2_digits_asc(8))
+        }
+    }
+}
 
-    #[inline]
-    fn try_from(s: SerdeNatural) -> Result<Natural, String> {
-        if s.0.starts_with("0x") {
-            Natural::from_string_base(16, &s.0[2..])
-                .ok_or_else(|| format!("Unrecognized digits in {}", s.0))
+impl<'de> Deserialize<'de> for Natural {
+    /// Deserializes a [`Natural`], in the form produced by its [`Serialize`] implementation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the length of the serialized
+    /// data.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     serde_json::from_str::<Natural>("\"0xe8d4a51000\"").unwrap(),
+    ///     1000000000000u64
+    /// );
+    /// assert!(serde_json::from_str::<Natural>("\"e8d4a51000\"").is_err());
+    /// ```
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Natural, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(NaturalVisitor)
         } else {
-            Err(format!("String '{}' does not start with '0x'", s.0))
+            deserializer.deserialize_bytes(NaturalVisitor)
         }
     }
 }
@@ -0,0 +1,108 @@
+use crate::natural::Natural;
+use malachite_base::num::conversion::string::parse_error::{
+    ParseNumberError, ParseNumberErrorKind,
+};
+use malachite_base::num::conversion::traits::FromStringBase;
+
+// Maps the byte offset of an `InvalidDigit` error in a string with its underscores removed back to
+// the corresponding offset in the original string.
+fn restore_underscore_offset(e: ParseNumberError, s: &[u8]) -> ParseNumberError {
+    if let ParseNumberErrorKind::InvalidDigit(i) = e.kind() {
+        let j = s
+            .iter()
+            .enumerate()
+            .filter(|&(_, &b)| b != b'_')
+            .nth(i)
+            .map_or(s.len(), |(j, _)| j);
+        ParseNumberError::new(ParseNumberErrorKind::InvalidDigit(j))
+    } else {
+        e
+    }
+}
+
+// Parses an unsigned integer literal: an optional radix prefix (`0x`, `0o`, or `0b`, with either
+// case for the letter) followed by digits, possibly separated by underscores. Underscores may not
+// begin an unprefixed literal.
+#[doc(hidden)]
+pub fn natural_from_unsigned_literal(s: &str) -> Result<Natural, ParseNumberError> {
+    let bytes = s.as_bytes();
+    let (base, offset) = match bytes {
+        [b'0', b'x' | b'X', ..] => (16, 2),
+        [b'0', b'o' | b'O', ..] => (8, 2),
+        [b'0', b'b' | b'B', ..] => (2, 2),
+        _ => (10, 0),
+    };
+    let digits = &bytes[offset..];
+    match digits.first() {
+        Some(b'_') if offset == 0 => {
+            return Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit(0)))
+        }
+        // `from_string_base` may accept a sign in front of short power-of-2 strings
+        Some(b'+' | b'-') => {
+            return Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit(
+                offset,
+            )))
+        }
+        _ => {}
+    }
+    if digits.contains(&b'_') {
+        // Removing ASCII bytes from a UTF-8 string leaves valid UTF-8
+        let stripped =
+            String::from_utf8(digits.iter().copied().filter(|&b| b != b'_').collect()).unwrap();
+        Natural::from_string_base(base, &stripped)
+            .map_err(|e| restore_underscore_offset(e, digits).offset_by(offset))
+    } else {
+        Natural::from_string_base(base, &s[offset..]).map_err(|e| e.offset_by(offset))
+    }
+}
+
+impl Natural {
+    /// Converts a string in the style of a Rust or C integer literal to a [`Natural`].
+    ///
+    /// The string may begin with a `'+'`, followed by an optional radix prefix: `"0x"` for
+    /// hexadecimal, `"0o"` for octal, or `"0b"` for binary. The letter in the prefix may be
+    /// lowercase or uppercase. Without a prefix, the string is parsed as decimal. The digits may
+    /// be separated by any number of underscores, which are ignored; an underscore may also
+    /// follow the prefix, but it may not begin an unprefixed string. Hexadecimal digits may be
+    /// lowercase or uppercase. Type suffixes such as `u64` and C-style octal literals with a
+    /// leading `0` are not supported.
+    ///
+    /// Strings with a power-of-2 radix are converted directly to limbs, without any
+    /// multiplications.
+    ///
+    /// If the string is invalid, a [`ParseNumberError`] describing the problem is returned. The
+    /// byte offset of an invalid digit refers to the original string, including any sign, prefix,
+    /// and underscores.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from_literal("1_000_000").unwrap(), 1000000);
+    /// assert_eq!(Natural::from_literal("+0xdead_BEEF").unwrap(), 3735928559u32);
+    /// assert_eq!(Natural::from_literal("0o755").unwrap(), 493);
+    /// assert_eq!(Natural::from_literal("0b_1010_1010").unwrap(), 170);
+    ///
+    /// let kind = |s| Natural::from_literal(s).unwrap_err().kind();
+    /// assert_eq!(kind("0x"), ParseNumberErrorKind::Empty);
+    /// assert_eq!(kind("-1"), ParseNumberErrorKind::SignNotAllowed);
+    /// assert_eq!(kind("_1"), ParseNumberErrorKind::InvalidDigit(0));
+    /// assert_eq!(kind("0b1_012"), ParseNumberErrorKind::InvalidDigit(6));
+    /// assert_eq!(kind("0xffu8"), ParseNumberErrorKind::InvalidDigit(4));
+    /// ```
+    pub fn from_literal(s: &str) -> Result<Natural, ParseNumberError> {
+        match s.as_bytes().first() {
+            Some(b'+') => natural_from_unsigned_literal(&s[1..]).map_err(|e| e.offset_by(1)),
+            Some(b'-') => Err(ParseNumberError::new(ParseNumberErrorKind::SignNotAllowed)),
+            _ => natural_from_unsigned_literal(s),
+        }
+    }
+}
@@ -2,9 +2,12 @@ use crate::natural::Natural;
 use malachite_base::num::arithmetic::traits::{CheckedSub, Parity, Pow};
 use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::string::from_sci_string::{
-    cmp_half_helper, is_zero_helper, preprocess_sci_string, validate_helper,
+    cmp_half_helper, is_zero_helper, preprocess_sci_string,
 };
 use malachite_base::num::conversion::string::options::FromSciStringOptions;
+use malachite_base::num::conversion::string::parse_error::{
+    ParseNumberError, ParseNumberErrorKind,
+};
 use malachite_base::num::conversion::traits::{FromSciString, FromStringBase};
 use malachite_base::rounding_modes::RoundingMode;
 use std::cmp::Ordering;
@@ -43,7 +46,7 @@ impl FromSciStringHelper for Natural {
                     _ => {}
                 }
             }
-            Natural::from_string_base(base, std::str::from_utf8(cs).ok()?)
+            Natural::from_string_base(base, std::str::from_utf8(cs).ok()?).ok()
         }
     }
 
@@ -61,20 +64,21 @@ pub(crate) fn from_sci_string_with_options_helper<
 >(
     s: &str,
     options: FromSciStringOptions,
-) -> Option<T>
+) -> Result<T, ParseNumberError>
 where
     for<'a> &'a T: Parity,
 {
     let (s, exponent) = preprocess_sci_string(s, options)?;
+    // The digits are valid, so parsing or rounding can only fail if the value is negative but `T`
+    // is unsigned
+    let sign_error = || ParseNumberError::new(ParseNumberErrorKind::SignNotAllowed);
     if exponent >= 0 {
-        let x = T::parse_int(&s, options.get_base())?;
-        Some(x * T::from(options.get_base()).pow(exponent.unsigned_abs()))
+        let x = T::parse_int(&s, options.get_base()).ok_or_else(sign_error)?;
+        Ok(x * T::from(options.get_base()).pow(exponent.unsigned_abs()))
     } else {
-        let neg_exponent = usize::try_from(exponent.unsigned_abs()).ok()?;
+        let neg_exponent = usize::try_from(exponent.unsigned_abs())
+            .map_err(|_| ParseNumberError::new(ParseNumberErrorKind::Overflow))?;
         let len = s.len();
-        if len == 0 {
-            return None;
-        }
         let first = s[0];
         let neg = first == b'-';
         let sign = neg || first == b'+';
@@ -84,64 +88,72 @@ where
             options.get_rounding_mode()
         };
         let sig_len = if sign { len - 1 } else { len };
-        if sig_len == 0 {
-            return None;
-        }
+        let inexact = || ParseNumberError::new(ParseNumberErrorKind::Inexact);
         if neg_exponent > sig_len {
             let s = if sign { &s[1..] } else { &s[..] };
-            return match rm {
-                RoundingMode::Down | RoundingMode::Floor | RoundingMode::Nearest => {
-                    validate_helper(s, options.get_base())?;
-                    Some(T::ZERO)
-                }
-                RoundingMode::Up | RoundingMode::Ceiling => {
-                    if is_zero_helper(s, options.get_base())? {
-                        Some(T::ZERO)
-                    } else {
-                        T::ZERO.up_1(neg)
+            return if is_zero_helper(s) {
+                Ok(T::ZERO)
+            } else {
+                match rm {
+                    RoundingMode::Down
+                    | RoundingMode::Floor
+                    | RoundingMode::Nearest
+                    | RoundingMode::NearestTiesAway
+                    | RoundingMode::NearestTiesTowardZero => Ok(T::ZERO),
+                    RoundingMode::Up | RoundingMode::Ceiling | RoundingMode::Odd => {
+                        T::ZERO.up_1(neg).ok_or_else(sign_error)
                     }
+                    RoundingMode::Exact => Err(inexact()),
                 }
-                RoundingMode::Exact => None,
             };
         }
         let (before_e, after_e) = s.split_at(len - neg_exponent);
+        if is_zero_helper(after_e) {
+            return match before_e {
+                &[] | &[b'-'] | &[b'+'] => Ok(T::ZERO),
+                before_e => T::parse_int(before_e, options.get_base()).ok_or_else(sign_error),
+            };
+        }
+        if rm == RoundingMode::Exact {
+            return Err(inexact());
+        }
         let x = match before_e {
             &[] | &[b'-'] | &[b'+'] => T::ZERO,
-            before_e => T::parse_int(before_e, options.get_base())?,
+            before_e => T::parse_int(before_e, options.get_base()).ok_or_else(sign_error)?,
         };
-        if after_e.is_empty() {
-            return Some(x);
-        }
+        let up = |x: T| x.up_1(neg).ok_or_else(sign_error);
         match rm {
-            RoundingMode::Down | RoundingMode::Floor => {
-                validate_helper(after_e, options.get_base())?;
-                Some(x)
-            }
-            RoundingMode::Up | RoundingMode::Ceiling => {
-                if is_zero_helper(after_e, options.get_base())? {
-                    Some(x)
-                } else {
-                    x.up_1(neg)
-                }
-            }
-            RoundingMode::Exact => {
-                if is_zero_helper(after_e, options.get_base())? {
-                    Some(x)
-                } else {
-                    None
-                }
-            }
-            RoundingMode::Nearest => match cmp_half_helper(after_e, options.get_base())? {
-                Ordering::Less => Some(x),
-                Ordering::Greater => x.up_1(neg),
+            RoundingMode::Down | RoundingMode::Floor => Ok(x),
+            RoundingMode::Up | RoundingMode::Ceiling => up(x),
+            RoundingMode::Nearest => match cmp_half_helper(after_e, options.get_base()) {
+                Ordering::Less => Ok(x),
+                Ordering::Greater => up(x),
                 Ordering::Equal => {
                     if x.even() {
-                        Some(x)
+                        Ok(x)
                     } else {
-                        x.up_1(neg)
+                        up(x)
                     }
                 }
             },
+            RoundingMode::NearestTiesAway => match cmp_half_helper(after_e, options.get_base()) {
+                Ordering::Less => Ok(x),
+                Ordering::Greater | Ordering::Equal => up(x),
+            },
+            RoundingMode::NearestTiesTowardZero => {
+                match cmp_half_helper(after_e, options.get_base()) {
+                    Ordering::Less | Ordering::Equal => Ok(x),
+                    Ordering::Greater => up(x),
+                }
+            }
+            RoundingMode::Odd => {
+                if x.odd() {
+                    Ok(x)
+                } else {
+                    up(x)
+                }
+            }
+            RoundingMode::Exact => unreachable!(),
         }
     }
 }
@@ -167,8 +179,10 @@ impl FromSciString for Natural {
     /// exponents, but they may be used on their own. If the string represents a non-integer, the
     /// rounding mode specified in `options` is used to round to an integer.
     ///
-    /// If the string is unparseable, `None` is returned. `None` is also returned if the rounding
-    /// mode in options is `Exact`, but rounding is necessary.
+    /// If the string is unparseable or represents a negative number that doesn't round to zero, a
+    /// [`ParseNumberError`] describing the problem is returned. An error of kind
+    /// [`Inexact`](malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind::Inexact)
+    /// is returned if the rounding mode in options is `Exact`, but rounding is necessary.
     ///
     /// # Worst-case complexity
     /// $T(n, m) = O(m^n n \log m (\log n + \log\log m))$
@@ -180,13 +194,18 @@ impl FromSciString for Natural {
     /// # Examples
     /// ```
     /// use malachite_base::num::conversion::string::options::FromSciStringOptions;
+    /// use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
     /// use malachite_base::num::conversion::traits::FromSciString;
     /// use malachite_base::rounding_modes::RoundingMode;
     /// use malachite_nz::natural::Natural;
     ///
     /// assert_eq!(Natural::from_sci_string("123").unwrap(), 123);
     /// assert_eq!(Natural::from_sci_string("123.5").unwrap(), 124);
-    /// assert_eq!(Natural::from_sci_string("-123.5"), None);
+    /// assert_eq!(
+    ///     Natural::from_sci_string("-123.5").unwrap_err().kind(),
+    ///     ParseNumberErrorKind::SignNotAllowed
+    /// );
+    /// assert_eq!(Natural::from_sci_string("-0.1").unwrap(), 0);
     /// assert_eq!(Natural::from_sci_string("1.23e10").unwrap(), 12300000000u64);
     ///
     /// let mut options = FromSciStringOptions::default();
@@ -204,9 +223,19 @@ impl FromSciString for Natural {
     /// assert_eq!(Natural::from_sci_string_with_options("1e5", options).unwrap(), 1805);
     /// assert_eq!(Natural::from_sci_string_with_options("1e+5", options).unwrap(), 60466176);
     /// assert_eq!(Natural::from_sci_string_with_options("1e-5", options).unwrap(), 0);
+    ///
+    /// options = FromSciStringOptions::default();
+    /// options.set_rounding_mode(RoundingMode::Exact);
+    /// assert_eq!(
+    ///     Natural::from_sci_string_with_options("123.5", options).unwrap_err().kind(),
+    ///     ParseNumberErrorKind::Inexact
+    /// );
     /// ```
     #[inline]
-    fn from_sci_string_with_options(s: &str, options: FromSciStringOptions) -> Option<Natural> {
+    fn from_sci_string_with_options(
+        s: &str,
+        options: FromSciStringOptions,
+    ) -> Result<Natural, ParseNumberError> {
         from_sci_string_with_options_helper(s, options)
     }
 }
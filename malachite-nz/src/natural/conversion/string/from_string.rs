@@ -2,19 +2,24 @@ use crate::natural::Natural;
 use crate::platform::Limb;
 use malachite_base::num::arithmetic::traits::{ModPowerOf2, ShrRound};
 use malachite_base::num::basic::integers::PrimitiveInt;
-use malachite_base::num::conversion::string::from_string::digit_from_display_byte;
+use malachite_base::num::conversion::string::from_string::{
+    digit_from_display_byte, string_base_error,
+};
+use malachite_base::num::conversion::string::parse_error::{
+    ParseNumberError, ParseNumberErrorKind,
+};
 use malachite_base::num::conversion::traits::{Digits, ExactFrom, FromStringBase, WrappingFrom};
 use malachite_base::rounding_modes::RoundingMode;
 use std::str::FromStr;
 
 impl FromStr for Natural {
-    type Err = ();
+    type Err = ParseNumberError;
 
     /// Converts an string to a [`Natural`].
     ///
     /// If the string does not represent a valid [`Natural`], an `Err` is returned. To be valid,
     /// the string must be nonempty and only contain the [`char`]s `'0'` through `'9'`. Leading
-    /// zeros are allowed.
+    /// zeros are allowed. The [`ParseNumberError`] describes why the string is invalid.
     ///
     /// # Worst-case complexity
     /// $T(n) = O(n (\log n)^2 \log\log n)$
@@ -25,6 +30,7 @@ impl FromStr for Natural {
     ///
     /// # Examples
     /// ```
+    /// use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
     /// use malachite_nz::natural::Natural;
     /// use std::str::FromStr;
     ///
@@ -35,10 +41,14 @@ impl FromStr for Natural {
     /// assert!(Natural::from_str("").is_err());
     /// assert!(Natural::from_str("a").is_err());
     /// assert!(Natural::from_str("-5").is_err());
+    /// assert_eq!(
+    ///     Natural::from_str("12a4").unwrap_err().kind(),
+    ///     ParseNumberErrorKind::InvalidDigit(2)
+    /// );
     /// ```
     #[inline]
-    fn from_str(s: &str) -> Result<Natural, ()> {
-        Natural::from_string_base(10, s).ok_or(())
+    fn from_str(s: &str) -> Result<Natural, ParseNumberError> {
+        Natural::from_string_base(10, s)
     }
 }
 
@@ -155,13 +165,28 @@ fn from_hex_str(s: &str) -> Option<Natural> {
     }
 }
 
+fn from_general_str(base: u8, s: &str) -> Option<Natural> {
+    for b in s.bytes() {
+        let digit = digit_from_display_byte(b)?;
+        if digit >= base {
+            return None;
+        }
+    }
+    Natural::from_digits_desc(
+        &u8::wrapping_from(base),
+        s.bytes().map(|b| digit_from_display_byte(b).unwrap()),
+    )
+}
+
 impl FromStringBase for Natural {
     /// Converts an string, in a specified base, to a [`Natural`].
     ///
     /// If the string does not represent a valid [`Natural`], an `Err` is returned. To be valid,
     /// the string must be nonempty and only contain the [`char`]s `'0'` through `'9'`, `'a'`
     /// through `'z'`, and `'A'` through `'Z'`; and only characters that represent digits smaller
-    /// than the base are allowed. Leading zeros are always allowed.
+    /// than the base are allowed. Leading zeros are always allowed. If `base` is less than 2 or
+    /// greater than 36, an error of kind [`InvalidBase`](ParseNumberErrorKind::InvalidBase) is
+    /// returned.
     ///
     /// # Worst-case complexity
     /// $T(n) = O(n (\log n)^2 \log\log n)$
@@ -170,11 +195,9 @@ impl FromStringBase for Natural {
     ///
     /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
     ///
-    /// # Panics
-    /// Panics if `base` is less than 2 or greater than 36.
-    ///
     /// # Examples
     /// ```
+    /// use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
     /// use malachite_base::num::conversion::traits::{Digits, FromStringBase};
     /// use malachite_nz::natural::Natural;
     ///
@@ -184,34 +207,30 @@ impl FromStringBase for Natural {
     /// assert_eq!(Natural::from_string_base(16, "deadbeef").unwrap(), 3735928559u32);
     /// assert_eq!(Natural::from_string_base(16, "deAdBeEf").unwrap(), 3735928559u32);
     ///
-    /// assert!(Natural::from_string_base(10, "").is_none());
-    /// assert!(Natural::from_string_base(10, "a").is_none());
-    /// assert!(Natural::from_string_base(10, "-5").is_none());
-    /// assert!(Natural::from_string_base(2, "2").is_none());
+    /// let kind = |base, s| Natural::from_string_base(base, s).unwrap_err().kind();
+    /// assert_eq!(kind(10, ""), ParseNumberErrorKind::Empty);
+    /// assert_eq!(kind(10, "a"), ParseNumberErrorKind::InvalidDigit(0));
+    /// assert_eq!(kind(10, "-5"), ParseNumberErrorKind::SignNotAllowed);
+    /// assert_eq!(kind(2, "1012"), ParseNumberErrorKind::InvalidDigit(3));
+    /// assert_eq!(kind(37, "1"), ParseNumberErrorKind::InvalidBase(37));
     /// ```
     #[inline]
-    fn from_string_base(base: u8, s: &str) -> Option<Natural> {
-        assert!((2..=36).contains(&base), "base out of range");
-        if s.is_empty() {
+    fn from_string_base(base: u8, s: &str) -> Result<Natural, ParseNumberError> {
+        if !(2..=36).contains(&base) {
+            return Err(ParseNumberError::new(ParseNumberErrorKind::InvalidBase(
+                base,
+            )));
+        }
+        let x = if s.is_empty() {
             None
         } else {
             match base {
                 2 => from_binary_str(s),
                 8 => from_oct_str(s),
                 16 => from_hex_str(s),
-                _ => {
-                    for b in s.bytes() {
-                        let digit = digit_from_display_byte(b)?;
-                        if digit >= base {
-                            return None;
-                        }
-                    }
-                    Natural::from_digits_desc(
-                        &u8::wrapping_from(base),
-                        s.bytes().map(|b| digit_from_display_byte(b).unwrap()),
-                    )
-                }
+                _ => from_general_str(base, s),
             }
-        }
+        };
+        x.ok_or_else(|| string_base_error(s, base, false, false))
     }
 }
@@ -0,0 +1,54 @@
+use crate::natural::Natural;
+use malachite_base::num::conversion::string::options::FormatOptions;
+use malachite_base::num::conversion::string::to_formatted_string::format_digits;
+use malachite_base::num::conversion::traits::{ToFormattedString, ToStringBase};
+
+impl ToFormattedString for Natural {
+    /// Converts a [`Natural`] to a [`String`], using the specified [`FormatOptions`].
+    ///
+    /// The digits before the point are grouped and separated according to the options. Since a
+    /// [`Natural`] is an integer, it is never rounded; if the options specify a number of fraction
+    /// digits, that many zeros are written after the point.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`, plus
+    /// the number of fraction digits in `options`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::options::{DigitGrouping, FormatOptions};
+    /// use malachite_base::num::conversion::traits::ToFormattedString;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// let x = Natural::from_str("1234567890123").unwrap();
+    /// let mut options = FormatOptions::default();
+    /// assert_eq!(x.to_formatted_string(options), "1234567890123");
+    ///
+    /// options.set_digit_grouping(DigitGrouping::Uniform(3));
+    /// assert_eq!(x.to_formatted_string(options), "1,234,567,890,123");
+    ///
+    /// options.set_digit_grouping(DigitGrouping::Indian);
+    /// assert_eq!(x.to_formatted_string(options), "12,34,56,78,90,123");
+    ///
+    /// options.set_digit_grouping(DigitGrouping::Uniform(3));
+    /// options.set_group_separator(' ');
+    /// options.set_decimal_separator(',');
+    /// options.set_fraction_digits(2);
+    /// assert_eq!(x.to_formatted_string(options), "1 234 567 890 123,00");
+    ///
+    /// let mut options = FormatOptions::default();
+    /// options.set_base(16);
+    /// options.set_uppercase();
+    /// options.set_digit_grouping(DigitGrouping::Uniform(4));
+    /// options.set_group_separator('_');
+    /// assert_eq!(x.to_formatted_string(options), "11F_71FB_04CB");
+    /// ```
+    fn to_formatted_string(&self, options: FormatOptions) -> String {
+        format_digits(false, &self.to_string_base(options.get_base()), "", options)
+    }
+}
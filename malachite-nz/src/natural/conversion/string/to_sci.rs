@@ -8,13 +8,13 @@ use malachite_base::num::arithmetic::traits::{
 };
 use malachite_base::num::basic::traits::Zero;
 use malachite_base::num::conversion::string::options::{SciSizeOptions, ToSciOptions};
-use malachite_base::num::conversion::string::to_sci::write_exponent;
+use malachite_base::num::conversion::string::to_sci::{exp_options, pad_exp, write_exponent};
 use malachite_base::num::conversion::string::to_string::{
     digit_to_display_byte_lower, digit_to_display_byte_upper,
 };
 use malachite_base::num::conversion::traits::{Digits, ExactFrom, ToSci};
 use malachite_base::rounding_modes::RoundingMode;
-use std::fmt::{Display, Formatter, Write};
+use std::fmt::{Display, Formatter, LowerExp, UpperExp, Write};
 
 fn write_helper<T>(x: &T, f: &mut Formatter, options: ToSciOptions) -> std::fmt::Result
 where
@@ -237,3 +237,68 @@ impl ToSci for Natural {
         }
     }
 }
+
+impl LowerExp for Natural {
+    /// Formats a [`Natural`] in scientific notation, using a lowercase `'e'`.
+    ///
+    /// If a precision is specified, the significand is rounded to that many digits after the
+    /// decimal point, using [`Nearest`](RoundingMode::Nearest) rounding. Otherwise, the number is
+    /// written exactly, with trailing zeros removed from the significand. The width, fill,
+    /// alignment, sign, and zero-padding flags work as they do for primitive integers.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(format!("{:e}", Natural::ZERO), "0e0");
+    /// assert_eq!(format!("{:e}", Natural::from(123456u32)), "1.23456e5");
+    /// assert_eq!(format!("{:e}", Natural::from(1000u32)), "1e3");
+    /// assert_eq!(format!("{:.2e}", Natural::from(123456u32)), "1.23e5");
+    /// assert_eq!(format!("{:.2e}", Natural::from(1000u32)), "1.00e3");
+    /// assert_eq!(format!("{:.1e}", Natural::from(99u32)), "9.9e1");
+    /// assert_eq!(format!("{:.0e}", Natural::from(99u32)), "1e2");
+    /// assert_eq!(format!("{:>+12e}", Natural::from(123456u32)), "  +1.23456e5");
+    /// assert_eq!(format!("{:012e}", Natural::from(123456u32)), "0001.23456e5");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let s = self.to_sci_with_options(exp_options(f, true)).to_string();
+        pad_exp(f, true, &s, true)
+    }
+}
+
+impl UpperExp for Natural {
+    /// Formats a [`Natural`] in scientific notation, using an uppercase `'E'`.
+    ///
+    /// If a precision is specified, the significand is rounded to that many digits after the
+    /// decimal point, using [`Nearest`](RoundingMode::Nearest) rounding. Otherwise, the number is
+    /// written exactly, with trailing zeros removed from the significand. The width, fill,
+    /// alignment, sign, and zero-padding flags work as they do for primitive integers.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(format!("{:E}", Natural::from(123456u32)), "1.23456E5");
+    /// assert_eq!(format!("{:.3E}", Natural::from(123456u32)), "1.235E5");
+    /// assert_eq!(format!("{:<12E}|", Natural::from(1000u32)), "1E3         |");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let s = self.to_sci_with_options(exp_options(f, true)).to_string();
+        pad_exp(f, true, &s, false)
+    }
+}
@@ -264,6 +264,13 @@ impl Display for Natural {
     ///     "1000000000000"
     /// );
     /// assert_eq!(format!("{:05}", Natural::from(123u32)), "00123");
+    /// assert_eq!(format!("{:>6}", Natural::from(123u32)), "   123");
+    /// assert_eq!(format!("{:_<6}", Natural::from(123u32)), "123___");
+    /// assert_eq!(format!("{:+}", Natural::from(123u32)), "+123");
+    /// assert_eq!(
+    ///     format!("{:>+16}", Natural::from_str("1000000000000").unwrap()),
+    ///     "  +1000000000000"
+    /// );
     /// ```
     fn fmt(&self, f: &mut Formatter) -> Result {
         match self {
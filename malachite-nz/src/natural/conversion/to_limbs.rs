@@ -200,7 +200,7 @@ impl Natural {
         match *self {
             Natural::ZERO => Vec::new(),
             Natural(Small(small)) => vec![small],
-            Natural(Large(ref limbs)) => limbs.clone(),
+            Natural(Large(ref limbs)) => limbs.to_vec(),
         }
     }
 
@@ -276,7 +276,7 @@ impl Natural {
         match self {
             Natural::ZERO => Vec::new(),
             Natural(Small(small)) => vec![small],
-            Natural(Large(limbs)) => limbs,
+            Natural(Large(limbs)) => limbs.into_vec(),
         }
     }
 
@@ -318,7 +318,7 @@ impl Natural {
             Natural(Small(small)) => vec![small],
             Natural(Large(mut limbs)) => {
                 limbs.reverse();
-                limbs
+                limbs.into_vec()
             }
         }
     }
@@ -0,0 +1,536 @@
+use crate::integer::Integer;
+use crate::natural::scratch::Scratch;
+use crate::natural::InnerNatural::Large;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{AddMulAssign, SubMulAssign};
+use malachite_base::num::basic::traits::Zero;
+use std::ops::{Add, Mul, Sub};
+
+/// A type that [`LazyExpr`]s can be evaluated into; that is, [`Natural`] or [`Integer`].
+///
+/// The functions of this trait are the steps that evaluation is made of. They are used by the
+/// [`LazyExpr`] implementations and aren't meant to be called directly.
+pub trait LazyTarget: Sized + Zero {
+    #[doc(hidden)]
+    fn assign_ref(&mut self, x: &Self);
+
+    #[doc(hidden)]
+    fn assign_product(&mut self, x: &Self, y: &Self, scratch: &mut Scratch);
+
+    #[doc(hidden)]
+    fn add_ref(&mut self, x: &Self);
+
+    #[doc(hidden)]
+    fn sub_ref(&mut self, x: &Self);
+
+    #[doc(hidden)]
+    fn add_product(&mut self, x: &Self, y: &Self, scratch: &mut Scratch);
+
+    #[doc(hidden)]
+    fn sub_product(&mut self, x: &Self, y: &Self, scratch: &mut Scratch);
+}
+
+/// An arithmetic expression whose evaluation has been deferred.
+///
+/// Expressions are built by applying `+`, `-`, and `*` to [`Lazy`] references, and are made of
+/// terms that are either single values or products of two values. Nothing is computed until the
+/// expression is evaluated with [`evaluate`](LazyExpr::evaluate),
+/// [`evaluate_into`](LazyExpr::evaluate_into), or
+/// [`evaluate_into_with_scratch`](LazyExpr::evaluate_into_with_scratch). Evaluation then writes
+/// the result into a single destination. Products are computed in memory taken from a [`Scratch`]
+/// and then added to or subtracted from the destination, except for products with a factor that
+/// fits in a [`Limb`](crate#limbs), which are added or subtracted directly; so no temporary values
+/// are created.
+///
+/// The terms with a positive sign are evaluated before those with a negative sign, so that when
+/// evaluating into a [`Natural`], the partial results are never negative unless the final result
+/// is.
+pub trait LazyExpr: Copy {
+    type Target: LazyTarget;
+
+    // Adds the terms whose sign, after being negated if `negate` is `true`, is negative if
+    // `negative` is `true` and positive otherwise. If `assigned` is `false`, the first such term
+    // is assigned to `dest` instead of being added, and `assigned` is set to `true`.
+    #[doc(hidden)]
+    fn accumulate(
+        self,
+        dest: &mut Self::Target,
+        negate: bool,
+        negative: bool,
+        assigned: &mut bool,
+        scratch: &mut Scratch,
+    );
+
+    /// Evaluates a [`LazyExpr`], returning a new value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n, m) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum number of significant bits
+    /// of the values in the expression, and $m$ is the number of terms.
+    ///
+    /// # Panics
+    /// Panics if [`Target`](LazyExpr::Target) is [`Natural`] and the result is negative.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::lazy::LazyExpr;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let a = Natural::from(10u32);
+    /// let b = Natural::from(20u32);
+    /// let c = Natural::from(30u32);
+    /// assert_eq!((a.lazy() * &b + c.lazy() * &c - &a).evaluate(), 1090);
+    /// ```
+    fn evaluate(self) -> Self::Target {
+        let mut x = Self::Target::ZERO;
+        self.evaluate_into(&mut x);
+        x
+    }
+
+    /// Evaluates a [`LazyExpr`], writing the result into an existing value.
+    ///
+    /// The old value of `dest` is overwritten, and its memory is reused where possible.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n, m) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum number of significant bits
+    /// of the values in the expression, and $m$ is the number of terms.
+    ///
+    /// # Panics
+    /// Panics if [`Target`](LazyExpr::Target) is [`Natural`] and the result is negative.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::lazy::LazyExpr;
+    ///
+    /// let a = Integer::from(10);
+    /// let b = Integer::from(-20);
+    /// let mut x = Integer::from(5);
+    /// (a.lazy() * &b - &a - &b).evaluate_into(&mut x);
+    /// assert_eq!(x, -190);
+    /// ```
+    fn evaluate_into(self, dest: &mut Self::Target) {
+        self.evaluate_into_with_scratch(dest, &mut Scratch::new());
+    }
+
+    /// Evaluates a [`LazyExpr`], writing the result into an existing value and taking temporary
+    /// memory from a [`Scratch`].
+    ///
+    /// When the same expression shape is evaluated many times with the same [`Scratch`] and
+    /// destination, the products in the expression are computed without allocating once the
+    /// [`Scratch`] and the destination have grown large enough.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n, m) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum number of significant bits
+    /// of the values in the expression, and $m$ is the number of terms.
+    ///
+    /// # Panics
+    /// Panics if [`Target`](LazyExpr::Target) is [`Natural`] and the result is negative.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::lazy::LazyExpr;
+    /// use malachite_nz::natural::scratch::Scratch;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut scratch = Scratch::new();
+    /// let mut x = Natural::from(1u32);
+    /// let mut y = Natural::from(1u32);
+    /// // Fibonacci numbers: x' = x + y, y' = x
+    /// for _ in 0..100 {
+    ///     let mut sum = Natural::from(0u32);
+    ///     (x.lazy() + &y).evaluate_into_with_scratch(&mut sum, &mut scratch);
+    ///     y = std::mem::replace(&mut x, sum);
+    /// }
+    /// assert_eq!(x.to_string(), "927372692193078999176");
+    /// ```
+    fn evaluate_into_with_scratch(self, dest: &mut Self::Target, scratch: &mut Scratch) {
+        let mut assigned = false;
+        self.accumulate(dest, false, false, &mut assigned, scratch);
+        if !assigned {
+            *dest = Self::Target::ZERO;
+            assigned = true;
+        }
+        self.accumulate(dest, false, true, &mut assigned, scratch);
+    }
+}
+
+/// A reference to a [`Natural`] or [`Integer`] whose arithmetic operators build a [`LazyExpr`]
+/// instead of computing a result.
+///
+/// A `Lazy` can be created with `Lazy(&x)` or with `x.lazy()`. Once an expression contains a
+/// `Lazy`, plain references can be used for the remaining operands.
+///
+/// # Examples
+/// ```
+/// use malachite_nz::natural::lazy::{Lazy, LazyExpr};
+/// use malachite_nz::natural::Natural;
+///
+/// let a = Natural::from(3u32);
+/// let b = Natural::from(4u32);
+/// assert_eq!((Lazy(&a) * Lazy(&a) + b.lazy() * &b).evaluate(), 25);
+/// ```
+#[derive(Debug)]
+pub struct Lazy<'a, T>(pub &'a T);
+
+/// The product of two values, as a [`LazyExpr`].
+#[derive(Debug)]
+pub struct Product<'a, T>(&'a T, &'a T);
+
+/// The sum of two [`LazyExpr`]s, as a [`LazyExpr`].
+#[derive(Clone, Copy, Debug)]
+pub struct Sum<L, R>(L, R);
+
+/// The difference of two [`LazyExpr`]s, as a [`LazyExpr`].
+#[derive(Clone, Copy, Debug)]
+pub struct Difference<L, R>(L, R);
+
+// `derive` would require `T: Clone`, but only references are copied.
+impl<'a, T> Clone for Lazy<'a, T> {
+    fn clone(&self) -> Lazy<'a, T> {
+        *self
+    }
+}
+
+impl<'a, T> Copy for Lazy<'a, T> {}
+
+impl<'a, T> Clone for Product<'a, T> {
+    fn clone(&self) -> Product<'a, T> {
+        *self
+    }
+}
+
+impl<'a, T> Copy for Product<'a, T> {}
+
+impl<'a, T: LazyTarget> LazyExpr for Lazy<'a, T> {
+    type Target = T;
+
+    fn accumulate(
+        self,
+        dest: &mut T,
+        negate: bool,
+        negative: bool,
+        assigned: &mut bool,
+        _scratch: &mut Scratch,
+    ) {
+        if negate != negative {
+            return;
+        }
+        if negative {
+            dest.sub_ref(self.0);
+        } else if *assigned {
+            dest.add_ref(self.0);
+        } else {
+            dest.assign_ref(self.0);
+            *assigned = true;
+        }
+    }
+}
+
+impl<'a, T: LazyTarget> LazyExpr for Product<'a, T> {
+    type Target = T;
+
+    fn accumulate(
+        self,
+        dest: &mut T,
+        negate: bool,
+        negative: bool,
+        assigned: &mut bool,
+        scratch: &mut Scratch,
+    ) {
+        if negate != negative {
+            return;
+        }
+        if negative {
+            dest.sub_product(self.0, self.1, scratch);
+        } else if *assigned {
+            dest.add_product(self.0, self.1, scratch);
+        } else {
+            dest.assign_product(self.0, self.1, scratch);
+            *assigned = true;
+        }
+    }
+}
+
+impl<L: LazyExpr, R: LazyExpr<Target = L::Target>> LazyExpr for Sum<L, R> {
+    type Target = L::Target;
+
+    fn accumulate(
+        self,
+        dest: &mut L::Target,
+        negate: bool,
+        negative: bool,
+        assigned: &mut bool,
+        scratch: &mut Scratch,
+    ) {
+        self.0.accumulate(dest, negate, negative, assigned, scratch);
+        self.1.accumulate(dest, negate, negative, assigned, scratch);
+    }
+}
+
+impl<L: LazyExpr, R: LazyExpr<Target = L::Target>> LazyExpr for Difference<L, R> {
+    type Target = L::Target;
+
+    fn accumulate(
+        self,
+        dest: &mut L::Target,
+        negate: bool,
+        negative: bool,
+        assigned: &mut bool,
+        scratch: &mut Scratch,
+    ) {
+        self.0.accumulate(dest, negate, negative, assigned, scratch);
+        self.1
+            .accumulate(dest, !negate, negative, assigned, scratch);
+    }
+}
+
+impl<'a, T> Mul<Lazy<'a, T>> for Lazy<'a, T> {
+    type Output = Product<'a, T>;
+
+    /// Multiplies two [`Lazy`] references, without evaluating the product.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    #[inline]
+    fn mul(self, other: Lazy<'a, T>) -> Product<'a, T> {
+        Product(self.0, other.0)
+    }
+}
+
+impl<'a, T> Mul<&'a T> for Lazy<'a, T> {
+    type Output = Product<'a, T>;
+
+    /// Multiplies a [`Lazy`] reference by a reference, without evaluating the product.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    #[inline]
+    fn mul(self, other: &'a T) -> Product<'a, T> {
+        Product(self.0, other)
+    }
+}
+
+macro_rules! impl_add_and_sub_ref {
+    ([$($generics: tt)*], $node: ty, $t: ident) => {
+        impl<'b, $($generics)*> Add<&'b $t> for $node
+        where
+            $node: LazyExpr<Target = $t>,
+        {
+            type Output = Sum<$node, Lazy<'b, $t>>;
+
+            /// Adds a reference to a [`LazyExpr`], without evaluating the sum.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            #[inline]
+            fn add(self, other: &'b $t) -> Sum<$node, Lazy<'b, $t>> {
+                Sum(self, Lazy(other))
+            }
+        }
+
+        impl<'b, $($generics)*> Sub<&'b $t> for $node
+        where
+            $node: LazyExpr<Target = $t>,
+        {
+            type Output = Difference<$node, Lazy<'b, $t>>;
+
+            /// Subtracts a reference from a [`LazyExpr`], without evaluating the difference.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            #[inline]
+            fn sub(self, other: &'b $t) -> Difference<$node, Lazy<'b, $t>> {
+                Difference(self, Lazy(other))
+            }
+        }
+    };
+}
+
+macro_rules! impl_add_and_sub {
+    ([$($generics: tt)*], $node: ty) => {
+        impl<$($generics)*, E: LazyExpr<Target = <$node as LazyExpr>::Target>> Add<E> for $node {
+            type Output = Sum<$node, E>;
+
+            /// Adds two [`LazyExpr`]s, without evaluating the sum.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            #[inline]
+            fn add(self, other: E) -> Sum<$node, E> {
+                Sum(self, other)
+            }
+        }
+
+        impl<$($generics)*, E: LazyExpr<Target = <$node as LazyExpr>::Target>> Sub<E> for $node {
+            type Output = Difference<$node, E>;
+
+            /// Subtracts a [`LazyExpr`] from another, without evaluating the difference.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            #[inline]
+            fn sub(self, other: E) -> Difference<$node, E> {
+                Difference(self, other)
+            }
+        }
+
+        impl_add_and_sub_ref!([$($generics)*], $node, Natural);
+        impl_add_and_sub_ref!([$($generics)*], $node, Integer);
+    };
+}
+impl_add_and_sub!(['a, T: LazyTarget], Lazy<'a, T>);
+impl_add_and_sub!(['a, T: LazyTarget], Product<'a, T>);
+impl_add_and_sub!([L: LazyExpr, R: LazyExpr<Target = L::Target>], Sum<L, R>);
+impl_add_and_sub!([L: LazyExpr, R: LazyExpr<Target = L::Target>], Difference<L, R>);
+
+impl LazyTarget for Natural {
+    #[inline]
+    fn assign_ref(&mut self, x: &Natural) {
+        self.clone_from(x);
+    }
+
+    #[inline]
+    fn assign_product(&mut self, x: &Natural, y: &Natural, scratch: &mut Scratch) {
+        self.assign_product_with_scratch(x, y, scratch);
+    }
+
+    #[inline]
+    fn add_ref(&mut self, x: &Natural) {
+        *self += x;
+    }
+
+    #[inline]
+    fn sub_ref(&mut self, x: &Natural) {
+        *self -= x;
+    }
+
+    fn add_product(&mut self, x: &Natural, y: &Natural, scratch: &mut Scratch) {
+        if let (Natural(Large(_)), Natural(Large(_))) = (x, y) {
+            scratch.with_product(x, y, |product| {
+                *self += &product;
+                product
+            });
+        } else {
+            // A factor fits in a limb, so no temporary product is created.
+            self.add_mul_assign(x, y);
+        }
+    }
+
+    fn sub_product(&mut self, x: &Natural, y: &Natural, scratch: &mut Scratch) {
+        if let (Natural(Large(_)), Natural(Large(_))) = (x, y) {
+            scratch.with_product(x, y, |product| {
+                *self -= &product;
+                product
+            });
+        } else {
+            self.sub_mul_assign(x, y);
+        }
+    }
+}
+
+impl LazyTarget for Integer {
+    #[inline]
+    fn assign_ref(&mut self, x: &Integer) {
+        self.clone_from(x);
+    }
+
+    fn assign_product(&mut self, x: &Integer, y: &Integer, scratch: &mut Scratch) {
+        self.abs
+            .assign_product_with_scratch(&x.abs, &y.abs, scratch);
+        self.sign = x.sign == y.sign || self.abs == 0u32;
+    }
+
+    #[inline]
+    fn add_ref(&mut self, x: &Integer) {
+        *self += x;
+    }
+
+    #[inline]
+    fn sub_ref(&mut self, x: &Integer) {
+        *self -= x;
+    }
+
+    #[inline]
+    fn add_product(&mut self, x: &Integer, y: &Integer, scratch: &mut Scratch) {
+        self.add_signed_product(x, y, x.sign == y.sign, scratch);
+    }
+
+    #[inline]
+    fn sub_product(&mut self, x: &Integer, y: &Integer, scratch: &mut Scratch) {
+        self.add_signed_product(x, y, x.sign != y.sign, scratch);
+    }
+}
+
+impl Integer {
+    // Adds `|x| * |y|` to `self` if `sign` is `true`, and subtracts it otherwise.
+    fn add_signed_product(&mut self, x: &Integer, y: &Integer, sign: bool, scratch: &mut Scratch) {
+        if let (Natural(Large(_)), Natural(Large(_))) = (&x.abs, &y.abs) {
+            scratch.with_product(&x.abs, &y.abs, |abs| {
+                let product = Integer { sign, abs };
+                *self += &product;
+                product.abs
+            });
+        } else if sign == (x.sign == y.sign) {
+            // A factor fits in a limb, so no temporary product is created.
+            self.add_mul_assign(x, y);
+        } else {
+            self.sub_mul_assign(x, y);
+        }
+    }
+}
+
+impl Natural {
+    /// Wraps a reference to a [`Natural`] in a [`Lazy`], so that arithmetic on it builds a
+    /// [`LazyExpr`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::lazy::LazyExpr;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let a = Natural::from(6u32);
+    /// let b = Natural::from(7u32);
+    /// assert_eq!((a.lazy() * &b - &a).evaluate(), 36);
+    /// ```
+    #[inline]
+    pub const fn lazy(&self) -> Lazy<'_, Natural> {
+        Lazy(self)
+    }
+}
+
+impl Integer {
+    /// Wraps a reference to an [`Integer`] in a [`Lazy`], so that arithmetic on it builds a
+    /// [`LazyExpr`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::lazy::LazyExpr;
+    ///
+    /// let a = Integer::from(6);
+    /// let b = Integer::from(-7);
+    /// assert_eq!((a.lazy() * &b - &a).evaluate(), -48);
+    /// ```
+    #[inline]
+    pub const fn lazy(&self) -> Lazy<'_, Integer> {
+        Lazy(self)
+    }
+}
@@ -0,0 +1,264 @@
+use crate::natural::limb_vec::LimbVec::{Empty, Heap, One, Two};
+use crate::platform::Limb;
+use std::cmp::min;
+use std::fmt::{self, Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::{Deref, DerefMut};
+use std::slice;
+
+// The number of limbs that a `LimbVec` can hold without allocating.
+pub(crate) const INLINE_LIMB_COUNT: usize = 2;
+
+// The limbs of a `Natural` that doesn't fit in a single `Limb`.
+//
+// Up to `INLINE_LIMB_COUNT` limbs are stored inline; longer sequences spill to a `Vec` on the
+// heap. A `LimbVec` dereferences to a slice of its limbs, so the `limbs_*` functions can operate
+// on it directly, and it supports the subset of `Vec`'s API that the rest of the crate uses.
+//
+// Equality, hashing, and debug formatting only depend on the limbs, not on whether they're stored
+// inline.
+//
+// Each inline length has its own variant, instead of a variant with a length field, so that a
+// `LimbVec` is no larger than a `Vec`: the compiler stores the inline limbs where the `Vec`'s
+// pointer and length would be, and tells the variants apart using capacities that a `Vec` can't
+// have. `InnerNatural` uses another such capacity for `Small`, so a `Natural` is 24 bytes long on
+// 64-bit platforms, just as it would be if it stored all multi-limb values on the heap.
+#[derive(Clone)]
+pub(crate) enum LimbVec {
+    Heap(Vec<Limb>),
+    Empty,
+    One(Limb),
+    Two([Limb; INLINE_LIMB_COUNT]),
+}
+
+impl LimbVec {
+    // Creates a `LimbVec` containing a copy of `xs`.
+    //
+    // # Worst-case complexity
+    // $T(n) = O(n)$
+    //
+    // $M(n) = O(n)$
+    //
+    // where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+    pub(crate) fn from_slice(xs: &[Limb]) -> LimbVec {
+        match *xs {
+            [] => Empty,
+            [x] => One(x),
+            [x, y] => Two([x, y]),
+            _ => Heap(xs.to_vec()),
+        }
+    }
+
+    // Creates an inline `LimbVec` by passing a zeroed buffer of `INLINE_LIMB_COUNT` limbs to `f`,
+    // which writes the limbs and returns how many it wrote.
+    //
+    // # Worst-case complexity
+    // Constant time and additional memory, not counting the work done by `f`.
+    //
+    // # Panics
+    // Panics if `f` returns a value greater than `INLINE_LIMB_COUNT`.
+    pub(crate) fn from_inline_fn<F: FnOnce(&mut [Limb]) -> usize>(f: F) -> LimbVec {
+        let mut limbs = [0; INLINE_LIMB_COUNT];
+        let len = f(&mut limbs);
+        assert!(len <= INLINE_LIMB_COUNT);
+        LimbVec::from_slice(&limbs[..len])
+    }
+
+    // Returns whether the limbs are stored inline.
+    //
+    // # Worst-case complexity
+    // Constant time and additional memory.
+    pub(crate) const fn is_inline(&self) -> bool {
+        !matches!(self, Heap(_))
+    }
+
+    // Returns a mutable reference to the limbs as a `Vec`, moving them to the heap if they are
+    // stored inline.
+    //
+    // # Worst-case complexity
+    // Constant time and additional memory.
+    pub(crate) fn as_mut_vec(&mut self) -> &mut Vec<Limb> {
+        if self.is_inline() {
+            let mut xs = Vec::with_capacity(INLINE_LIMB_COUNT << 1);
+            xs.extend_from_slice(self);
+            *self = Heap(xs);
+        }
+        if let Heap(xs) = self {
+            xs
+        } else {
+            unreachable!()
+        }
+    }
+
+    // Moves the limbs inline if they are stored on the heap and there are few enough of them.
+    // Heap storage with more capacity than a spill from inline storage allocates is kept, since
+    // it was probably reserved on purpose; `shrink_to_fit` releases it.
+    //
+    // # Worst-case complexity
+    // Constant time and additional memory.
+    pub(crate) fn inline_if_small(&mut self) {
+        if let Heap(xs) = self {
+            if xs.len() <= INLINE_LIMB_COUNT && xs.capacity() <= INLINE_LIMB_COUNT << 1 {
+                *self = LimbVec::from_slice(xs);
+            }
+        }
+    }
+
+    // Returns the number of limbs the `LimbVec` can hold without allocating.
+    //
+    // # Worst-case complexity
+    // Constant time and additional memory.
+    pub(crate) fn capacity(&self) -> usize {
+        match self {
+            Heap(xs) => xs.capacity(),
+            _ => INLINE_LIMB_COUNT,
+        }
+    }
+
+    // Makes room for at least `additional` more limbs, moving the limbs to the heap if they don't
+    // fit inline.
+    //
+    // # Worst-case complexity
+    // $T(n) = O(n)$
+    //
+    // $M(n) = O(n)$
+    //
+    // where $T$ is time, $M$ is additional memory, and $n$ is `self.len() + additional`.
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        if self.len() + additional > self.capacity() {
+            self.as_mut_vec().reserve(additional);
+        }
+    }
+
+    // Releases unused capacity, moving the limbs inline if there are few enough of them.
+    //
+    // # Worst-case complexity
+    // $T(n) = O(n)$
+    //
+    // $M(n) = O(n)$
+    //
+    // where $T$ is time, $M$ is additional memory, and $n$ is `self.len()`.
+    pub(crate) fn shrink_to_fit(&mut self) {
+        if let Heap(xs) = self {
+            if xs.len() <= INLINE_LIMB_COUNT {
+                *self = LimbVec::from_slice(xs);
+            } else {
+                xs.shrink_to_fit();
+            }
+        }
+    }
+
+    // Converts the `LimbVec` into a `Vec` of limbs.
+    //
+    // # Worst-case complexity
+    // Constant time and additional memory.
+    pub(crate) fn into_vec(self) -> Vec<Limb> {
+        match self {
+            Heap(xs) => xs,
+            xs => xs.to_vec(),
+        }
+    }
+
+    // Appends a limb.
+    //
+    // # Worst-case complexity
+    // Constant time and additional memory, amortized.
+    pub(crate) fn push(&mut self, x: Limb) {
+        match *self {
+            Empty => *self = One(x),
+            One(y) => *self = Two([y, x]),
+            _ => self.as_mut_vec().push(x),
+        }
+    }
+
+    // Shortens the `LimbVec` to `new_len` limbs. Does nothing if `new_len` is at least the
+    // current length.
+    //
+    // # Worst-case complexity
+    // Constant time and additional memory.
+    pub(crate) fn truncate(&mut self, new_len: usize) {
+        match self {
+            Heap(xs) => xs.truncate(new_len),
+            xs => {
+                if new_len < xs.len() {
+                    *xs = LimbVec::from_slice(&xs[..new_len]);
+                }
+            }
+        }
+    }
+
+    // Resizes the `LimbVec` to `new_len` limbs, filling any new positions with `x`.
+    //
+    // # Worst-case complexity
+    // $T(n) = O(n)$
+    //
+    // $M(n) = O(n)$
+    //
+    // where $T$ is time, $M$ is additional memory, and $n$ is `new_len`.
+    pub(crate) fn resize(&mut self, new_len: usize, x: Limb) {
+        if self.is_inline() && new_len <= INLINE_LIMB_COUNT {
+            let mut limbs = [x; INLINE_LIMB_COUNT];
+            let old_len = min(self.len(), new_len);
+            limbs[..old_len].copy_from_slice(&self[..old_len]);
+            *self = LimbVec::from_slice(&limbs[..new_len]);
+        } else {
+            self.as_mut_vec().resize(new_len, x);
+        }
+    }
+}
+
+impl Deref for LimbVec {
+    type Target = [Limb];
+
+    fn deref(&self) -> &[Limb] {
+        match self {
+            Heap(xs) => xs,
+            Empty => &[],
+            One(x) => slice::from_ref(x),
+            Two(xs) => xs,
+        }
+    }
+}
+
+impl DerefMut for LimbVec {
+    fn deref_mut(&mut self) -> &mut [Limb] {
+        match self {
+            Heap(xs) => xs,
+            Empty => &mut [],
+            One(x) => slice::from_mut(x),
+            Two(xs) => xs,
+        }
+    }
+}
+
+impl PartialEq for LimbVec {
+    fn eq(&self, other: &LimbVec) -> bool {
+        **self == **other
+    }
+}
+
+impl Eq for LimbVec {}
+
+impl Hash for LimbVec {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
+
+impl Debug for LimbVec {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl From<Vec<Limb>> for LimbVec {
+    // Converts a `Vec` of limbs to a `LimbVec`, moving the limbs inline if there are few enough
+    // of them.
+    fn from(xs: Vec<Limb>) -> LimbVec {
+        if xs.len() <= INLINE_LIMB_COUNT {
+            LimbVec::from_slice(&xs)
+        } else {
+            Heap(xs)
+        }
+    }
+}
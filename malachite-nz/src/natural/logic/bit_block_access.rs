@@ -260,7 +260,7 @@ impl BitBlockAccess for Natural {
         match self {
             Natural(Small(small)) => Natural(Small(small.get_bits(start, end))),
             Natural(Large(limbs)) => {
-                Natural::from_owned_limbs_asc(limbs_vec_get_bits(limbs, start, end))
+                Natural::from_owned_limbs_asc(limbs_vec_get_bits(limbs.into_vec(), start, end))
             }
         }
     }
@@ -51,7 +51,7 @@ impl LowMask for Natural {
         if bits <= Limb::WIDTH {
             Natural(Small(Limb::low_mask(bits)))
         } else {
-            Natural(Large(limbs_low_mask(bits)))
+            Natural(Large(limbs_low_mask(bits).into()))
         }
     }
 }
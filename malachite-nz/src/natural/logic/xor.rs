@@ -235,7 +235,7 @@ impl Natural {
     fn xor_limb_ref(&self, other: Limb) -> Natural {
         Natural(match *self {
             Natural(Small(small)) => Small(small ^ other),
-            Natural(Large(ref limbs)) => Large(limbs_xor_limb(limbs, other)),
+            Natural(Large(ref limbs)) => Large(limbs_xor_limb(limbs, other).into()),
         })
     }
 
@@ -469,7 +469,7 @@ impl<'a> BitXorAssign<&'a Natural> for Natural {
             (_, Natural(Small(y))) => self.xor_assign_limb(*y),
             (Natural(Small(ref mut x)), _) => *self = other.xor_limb_ref(*x),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys))) => {
-                limbs_xor_in_place_left(xs, ys);
+                limbs_xor_in_place_left(xs.as_mut_vec(), ys);
                 self.trim();
             }
         }
@@ -1,3 +1,4 @@
+use crate::natural::limb_vec::LimbVec;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::platform::Limb;
 #[cfg(feature = "doc-images")]
@@ -11,9 +12,11 @@ use malachite_base::slices::slice_trailing_zeros;
 
 /// A natural (non-negative) integer.
 ///
-/// Any `Natural` small enough to fit into a [`Limb`](crate#limbs) is represented inline. Only
-/// `Natural`s outside this range incur the costs of heap-allocation. Here's a diagram of a slice
-/// of `Natural`s (using 32-bit limbs) containing the first 8 values of
+/// Any `Natural` small enough to fit into a [`Limb`](crate#limbs) is represented inline, and the
+/// limbs of any `Natural` small enough to fit into two [`Limb`](crate#limbs)s are stored inline as
+/// well, in the space that would otherwise hold a pointer to the heap. Only `Natural`s outside this
+/// range incur the costs of heap-allocation. Here's a diagram of a slice of `Natural`s (using
+/// 32-bit limbs) containing the first 8 values of
 /// [Sylvester's sequence](https://oeis.org/A000058):
 ///
 /// ![Natural memory layout][natural-mem-layout]
@@ -22,11 +25,6 @@ use malachite_base::slices::slice_trailing_zeros;
     embed_doc_image("natural-mem-layout", "images/natural-mem-layout.svg")
 )]
 #[derive(Clone, Eq, Hash, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-#[cfg_attr(
-    feature = "serde",
-    serde(try_from = "SerdeNatural", into = "SerdeNatural")
-)]
 pub struct Natural(pub(crate) InnerNatural);
 
 // We want to limit the visibility of the `Small` and `Large` constructors to within this crate. To
@@ -34,13 +32,9 @@ pub struct Natural(pub(crate) InnerNatural);
 #[derive(Clone, Eq, Hash, PartialEq)]
 pub(crate) enum InnerNatural {
     Small(Limb),
-    Large(Vec<Limb>),
+    Large(LimbVec),
 }
 
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "serde", serde(transparent))]
-pub(crate) struct SerdeNatural(String);
-
 impl Natural {
     // If a `Natural` is `Large` but is small enough to be `Small`, make it `Small`.
     fn demote_if_small(&mut self) {
@@ -53,13 +47,14 @@ impl Natural {
         }
     }
 
-    // If a `Natural` is `Small`, make it `Large`. Return a reference to the `Limb` vector.
+    // If a `Natural` is `Small`, make it `Large`. Return a reference to the `Limb` vector, moving
+    // the limbs to the heap if they are stored inline.
     pub(crate) fn promote_in_place(&mut self) -> &mut Vec<Limb> {
         if let Natural(Small(x)) = self {
-            *self = Natural(Large(vec![*x]));
+            *self = Natural(Large(LimbVec::Heap(vec![*x])));
         }
         if let Natural(Large(ref mut xs)) = self {
-            xs
+            xs.as_mut_vec()
         } else {
             unreachable!();
         }
@@ -72,6 +67,7 @@ impl Natural {
                 let len = limbs.len();
                 limbs.truncate(len - trailing_zero_count);
             }
+            limbs.inline_if_small();
         }
         self.demote_if_small();
     }
@@ -135,7 +131,17 @@ pub mod conversion;
 pub mod exhaustive;
 /// Traits for generating primes, primality testing, and factorization (TODO!)
 pub mod factorization;
+/// [`LazyExpr`](lazy::LazyExpr), for evaluating compound expressions of [`Natural`]s or
+/// [`Integer`](crate::integer::Integer)s without creating temporary values.
+pub mod lazy;
+pub(crate) mod limb_vec;
 /// Traits for logic and bit manipulation.
 pub mod logic;
+/// [`Modulus`](modular::Modulus) and [`Residue`](modular::Residue), for fast repeated arithmetic
+/// modulo a fixed [`Natural`].
+pub mod modular;
 /// Iterators that generate [`Natural`]s randomly.
 pub mod random;
+/// [`Scratch`](scratch::Scratch), for reusing memory across [`Natural`] operations, and functions
+/// for managing a [`Natural`]'s storage.
+pub mod scratch;
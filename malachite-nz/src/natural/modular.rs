@@ -0,0 +1,893 @@
+use crate::natural::arithmetic::add::limbs_slice_add_same_length_in_place_left;
+use crate::natural::arithmetic::div_exact::{
+    limbs_modular_invert, limbs_modular_invert_limb, limbs_modular_invert_scratch_len,
+};
+use crate::natural::arithmetic::mod_pow::{
+    limbs_redc, limbs_redc_limb, REDC_1_TO_REDC_N_THRESHOLD,
+};
+use crate::natural::arithmetic::mul::{
+    limbs_mul_same_length_to_out, limbs_mul_same_length_to_out_scratch_len, limbs_mul_to_out,
+    limbs_mul_to_out_scratch_len,
+};
+use crate::natural::arithmetic::square::{limbs_square_to_out, limbs_square_to_out_scratch_len};
+use crate::natural::arithmetic::sub::{
+    limbs_sub_same_length_in_place_left, limbs_sub_same_length_in_place_right,
+    limbs_sub_same_length_to_out,
+};
+use crate::natural::comparison::cmp::limbs_cmp_same_length;
+use crate::natural::logic::bit_access::limbs_get_bit;
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::arithmetic::traits::{
+    ModInverse, Parity, Pow, PowAssign, PowerOf2, Square, SquareAssign, WrappingNegAssign,
+};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::slices::slice_test_zero;
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Reduction {
+    // An odd modulus $m$ with $n$ limbs. Residues are stored as $xR \mod m$, where $R = B^n$.
+    // `inverse` holds $-m^{-1} \mod B$ if $n$ is small, and $m^{-1} \mod B^n$ otherwise, as
+    // expected by `limbs_redc_limb` and `limbs_redc` respectively.
+    Montgomery {
+        inverse: Vec<Limb>,
+        r_squared: Vec<Limb>,
+    },
+    // An even modulus $m$ with $n$ limbs. Residues are stored as they are, and products are
+    // reduced using `reciprocal`, which holds $\lfloor B^{2n}/m \rfloor$.
+    Barrett {
+        reciprocal: Vec<Limb>,
+    },
+}
+
+/// A modulus $m$ together with the data needed to multiply quickly modulo $m$.
+///
+/// [`Natural`]'s [`ModMul`](malachite_base::num::arithmetic::traits::ModMul) and
+/// [`ModPow`](malachite_base::num::arithmetic::traits::ModPow) reduce each result from scratch.
+/// A `Modulus` does the expensive precomputation once, and [`Residue`]s created from it stay in a
+/// representation that makes reduction cheap across any number of operations.
+///
+/// If $m$ is odd, residues are kept in Montgomery form, and the `Modulus` holds the Montgomery
+/// inverse $-m^{-1}$ and $R^2 \mod m$, where $R = 2^{nW}$, $n$ is the number of limbs of $m$, and
+/// $W$ is the width of a [`Limb`](crate#limbs). If $m$ is even, residues are kept as they are, and
+/// the `Modulus` holds the Barrett reciprocal $\lfloor R^2/m \rfloor$.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::arithmetic::traits::Pow;
+/// use malachite_nz::natural::modular::Modulus;
+/// use malachite_nz::natural::Natural;
+///
+/// let m = Modulus::new(Natural::from(1000000007u32));
+/// let x = m.residue(&Natural::from(123u32));
+/// let y = m.residue(&Natural::from(456u32));
+/// assert_eq!(Natural::from(&x * &y + &x), 56211);
+/// assert_eq!(Natural::from((&x).pow(1000000006)), 1);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Modulus {
+    m: Natural,
+    ms: Vec<Limb>,
+    // The representation of 1: $R \mod m$ in the Montgomery case, and $1 \mod m$ otherwise.
+    one: Vec<Limb>,
+    reduction: Reduction,
+}
+
+/// An element of $\Z/m\Z$, tied to the [`Modulus`] it was created from.
+///
+/// A `Residue` supports `+`, `-`, `*`, and unary `-`, along with
+/// [`Square`](malachite_base::num::arithmetic::traits::Square) and
+/// [`Pow`](malachite_base::num::arithmetic::traits::Pow). Combining `Residue`s from different
+/// moduli panics. To get the value back, convert the `Residue` to a [`Natural`].
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::arithmetic::traits::Square;
+/// use malachite_nz::natural::modular::Modulus;
+/// use malachite_nz::natural::Natural;
+///
+/// let m = Modulus::new(Natural::from(10u32));
+/// let x = m.residue(&Natural::from(7u32));
+/// assert_eq!(Natural::from(x.clone() + m.residue(&Natural::from(5u32))), 2);
+/// assert_eq!(Natural::from(-x.clone()), 3);
+/// assert_eq!(Natural::from(x.square()), 9);
+/// ```
+#[derive(Clone)]
+pub struct Residue<'a> {
+    modulus: &'a Modulus,
+    xs: Vec<Limb>,
+}
+
+// Given the limbs of $x$ and $m$, where $x < m^2$, $m$ has $n$ limbs, its most significant limb is
+// nonzero, and `xs` has $2n$ limbs, writes the $n$ limbs of $x \mod m$ to `out`. `reciprocal` must
+// hold the limbs of $\lfloor B^{2n}/m \rfloor$.
+//
+// # Worst-case complexity
+// $T(n) = O(n \log n \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `ms.len()`.
+//
+// This is Algorithm 14.42 from Menezes, van Oorschot, and Vanstone, _Handbook of Applied
+// Cryptography_.
+fn limbs_barrett_reduce(out: &mut [Limb], xs: &[Limb], ms: &[Limb], reciprocal: &[Limb]) {
+    let n = ms.len();
+    assert_eq!(xs.len(), n << 1);
+    // q_1 = floor(x / B^(n - 1)), q_3 = floor(q_1 * reciprocal / B^(n + 1))
+    let qs_1 = &xs[n - 1..];
+    let mut scratch = vec![0; limbs_mul_to_out_scratch_len(qs_1.len(), reciprocal.len())];
+    let mut qs_2 = vec![0; qs_1.len() + reciprocal.len()];
+    limbs_mul_to_out(&mut qs_2, qs_1, reciprocal, &mut scratch);
+    let qs_3 = &qs_2[n + 1..];
+    let mut scratch = vec![0; limbs_mul_to_out_scratch_len(qs_3.len(), n)];
+    let mut qms = vec![0; qs_3.len() + n];
+    limbs_mul_to_out(&mut qms, qs_3, ms, &mut scratch);
+    // r = (x - q_3 * m) mod B^(n + 1), which is known to be less than 3m.
+    let mut rs = vec![0; n + 1];
+    limbs_sub_same_length_to_out(&mut rs, &xs[..=n], &qms[..=n]);
+    while rs[n] != 0 || limbs_cmp_same_length(&rs[..n], ms) != Ordering::Less {
+        if limbs_sub_same_length_in_place_left(&mut rs[..n], ms) {
+            rs[n] -= 1;
+        }
+    }
+    out.copy_from_slice(&rs[..n]);
+}
+
+impl Modulus {
+    /// Creates a [`Modulus`] from a [`Natural`] $m$, precomputing the data used for reduction
+    /// modulo $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if $m$ is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::modular::Modulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(*Modulus::new(Natural::from(10u32)).modulus(), 10);
+    /// ```
+    pub fn new(m: Natural) -> Modulus {
+        assert_ne!(m, 0u32, "modulus cannot be zero");
+        let ms = m.to_limbs_asc();
+        let n = ms.len();
+        let bits = u64::exact_from(n) << Limb::LOG_WIDTH;
+        let padded = |x: Natural| {
+            let mut xs = x.into_limbs_asc();
+            xs.resize(n, 0);
+            xs
+        };
+        let montgomery = ms[0].odd();
+        let one = padded(if montgomery {
+            Natural::power_of_2(bits) % &m
+        } else {
+            Natural::ONE % &m
+        });
+        let reduction = if montgomery {
+            let inverse = if n < REDC_1_TO_REDC_N_THRESHOLD {
+                let mut inverse = limbs_modular_invert_limb(ms[0]);
+                inverse.wrapping_neg_assign();
+                vec![inverse]
+            } else {
+                let mut inverse = vec![0; n];
+                let mut scratch = vec![0; limbs_modular_invert_scratch_len(n)];
+                limbs_modular_invert(&mut inverse, &ms, &mut scratch);
+                inverse
+            };
+            Reduction::Montgomery {
+                inverse,
+                r_squared: padded(Natural::power_of_2(bits << 1) % &m),
+            }
+        } else {
+            Reduction::Barrett {
+                reciprocal: (Natural::power_of_2(bits << 1) / &m).into_limbs_asc(),
+            }
+        };
+        Modulus {
+            m,
+            ms,
+            one,
+            reduction,
+        }
+    }
+
+    /// Returns the modulus $m$ as a [`Natural`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::modular::Modulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(*Modulus::new(Natural::from(123u32)).modulus(), 123);
+    /// ```
+    pub const fn modulus(&self) -> &Natural {
+        &self.m
+    }
+
+    /// Creates the [`Residue`] of a [`Natural`] modulo $m$. The [`Natural`] does not need to be
+    /// reduced.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(x.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::modular::Modulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Modulus::new(Natural::from(10u32));
+    /// assert_eq!(Natural::from(m.residue(&Natural::from(123u32))), 3);
+    /// ```
+    pub fn residue(&self, x: &Natural) -> Residue<'_> {
+        let mut xs = (x % &self.m).into_limbs_asc();
+        xs.resize(self.ms.len(), 0);
+        if let Reduction::Montgomery { r_squared, .. } = &self.reduction {
+            let mut out = vec![0; xs.len()];
+            self.mul_limbs(&mut out, &xs, r_squared);
+            xs = out;
+        }
+        Residue { modulus: self, xs }
+    }
+
+    /// Returns the [`Residue`] 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::modular::Modulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(Modulus::new(Natural::from(10u32)).zero()), 0);
+    /// ```
+    pub fn zero(&self) -> Residue<'_> {
+        Residue {
+            modulus: self,
+            xs: vec![0; self.ms.len()],
+        }
+    }
+
+    /// Returns the [`Residue`] 1. If $m$ is 1, this is the same as 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::modular::Modulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(Modulus::new(Natural::from(10u32)).one()), 1);
+    /// assert_eq!(Natural::from(Modulus::new(Natural::from(1u32)).one()), 0);
+    /// ```
+    pub fn one(&self) -> Residue<'_> {
+        Residue {
+            modulus: self,
+            xs: self.one.clone(),
+        }
+    }
+
+    /// Inverts many [`Residue`]s at once, using Montgomery's trick: only a single modular
+    /// inversion is performed, along with $3(k-1)$ multiplications, where $k$ is the number of
+    /// [`Residue`]s.
+    ///
+    /// Returns `None` if any of the [`Residue`]s is not invertible, which happens when it is not
+    /// coprime to $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(kn \log n \log\log n + n^2)$
+    ///
+    /// $M(n, k) = O(kn + n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $k$ is
+    /// `xs.len()`.
+    ///
+    /// # Panics
+    /// Panics if any of the [`Residue`]s was created from a different [`Modulus`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::modular::Modulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Modulus::new(Natural::from(10u32));
+    /// let xs = [1u32, 3, 7, 9].map(|x| m.residue(&Natural::from(x)));
+    /// let inverses: Vec<Natural> = m
+    ///     .batch_invert(&xs)
+    ///     .unwrap()
+    ///     .into_iter()
+    ///     .map(Natural::from)
+    ///     .collect();
+    /// assert_eq!(inverses, &[1u32, 7, 3, 9]);
+    ///
+    /// let xs = [3u32, 4].map(|x| m.residue(&Natural::from(x)));
+    /// assert!(m.batch_invert(&xs).is_none());
+    /// ```
+    pub fn batch_invert<'a>(&'a self, xs: &[Residue<'a>]) -> Option<Vec<Residue<'a>>> {
+        if xs.is_empty() {
+            return Some(Vec::new());
+        }
+        for x in xs {
+            self.check_same(x.modulus);
+        }
+        // prefix_products[i] = xs[0] * xs[1] * ... * xs[i]
+        let mut prefix_products = Vec::with_capacity(xs.len());
+        prefix_products.push(xs[0].clone());
+        for x in &xs[1..] {
+            let product = prefix_products.last().unwrap() * x;
+            prefix_products.push(product);
+        }
+        let mut inverse = prefix_products.last().unwrap().invert()?;
+        let mut inverses = vec![self.zero(); xs.len()];
+        for i in (1..xs.len()).rev() {
+            inverses[i] = &inverse * &prefix_products[i - 1];
+            inverse *= &xs[i];
+        }
+        inverses[0] = inverse;
+        Some(inverses)
+    }
+
+    fn check_same(&self, other: &Modulus) {
+        assert!(
+            std::ptr::eq(self, other) || self.m == other.m,
+            "residues have different moduli"
+        );
+    }
+
+    // Reduces the $2n$-limb product `xs`, which is less than $m^2$ (or $mR$ in the Montgomery
+    // case), and writes the $n$ limbs of the result to `out`. `xs` is clobbered.
+    fn reduce_limbs(&self, out: &mut [Limb], xs: &mut [Limb]) {
+        match &self.reduction {
+            Reduction::Montgomery { inverse, .. } => {
+                if inverse.len() == 1 {
+                    limbs_redc_limb(out, xs, &self.ms, inverse[0]);
+                } else {
+                    limbs_redc(out, xs, &self.ms, inverse);
+                }
+                if limbs_cmp_same_length(out, &self.ms) != Ordering::Less {
+                    limbs_sub_same_length_in_place_left(out, &self.ms);
+                }
+            }
+            Reduction::Barrett { reciprocal } => {
+                limbs_barrett_reduce(out, xs, &self.ms, reciprocal);
+            }
+        }
+    }
+
+    fn mul_limbs(&self, out: &mut [Limb], xs: &[Limb], ys: &[Limb]) {
+        let n = self.ms.len();
+        let mut product = vec![0; n << 1];
+        let mut scratch = vec![0; limbs_mul_same_length_to_out_scratch_len(n)];
+        limbs_mul_same_length_to_out(&mut product, xs, ys, &mut scratch);
+        self.reduce_limbs(out, &mut product);
+    }
+
+    fn square_limbs(&self, out: &mut [Limb], xs: &[Limb]) {
+        let n = self.ms.len();
+        let mut product = vec![0; n << 1];
+        let mut scratch = vec![0; limbs_square_to_out_scratch_len(n)];
+        limbs_square_to_out(&mut product, xs, &mut scratch);
+        self.reduce_limbs(out, &mut product);
+    }
+}
+
+impl<'a> Residue<'a> {
+    /// Returns the [`Modulus`] that this [`Residue`] was created from.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::modular::Modulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Modulus::new(Natural::from(10u32));
+    /// assert_eq!(*m.residue(&Natural::from(3u32)).modulus().modulus(), 10);
+    /// ```
+    pub const fn modulus(&self) -> &'a Modulus {
+        self.modulus
+    }
+
+    /// Computes the multiplicative inverse of a [`Residue`].
+    ///
+    /// Returns `None` if the [`Residue`] is not coprime to $m$. In particular, 0 is never
+    /// invertible, even when $m$ is 1, as with
+    /// [`ConstantTimeResidue::invert`](crate::fixed::constant_time::ConstantTimeResidue::invert).
+    ///
+    /// Unlike the other [`Residue`] operations, this one does not work on the internal
+    /// representation: the [`Residue`] is converted to a [`Natural`], inverted with
+    /// [`ModInverse`](malachite_base::num::arithmetic::traits::ModInverse), and converted back.
+    /// When $m$ is odd, so that residues are kept in Montgomery form, this costs two extra
+    /// reductions on top of the extended GCD. When many residues need inverting,
+    /// [`Modulus::batch_invert`] shares a single inversion among all of them.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::modular::Modulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Modulus::new(Natural::from(10u32));
+    /// let x = m.residue(&Natural::from(3u32));
+    /// assert_eq!(Natural::from(x.invert().unwrap()), 7);
+    /// assert!(m.residue(&Natural::from(4u32)).invert().is_none());
+    ///
+    /// let m = Modulus::new(Natural::from(1u32));
+    /// assert!(m.residue(&Natural::from(0u32)).invert().is_none());
+    /// ```
+    pub fn invert(&self) -> Option<Residue<'a>> {
+        let x = Natural::from(self);
+        if x == 0u32 {
+            return None;
+        }
+        x.mod_inverse(&self.modulus.m)
+            .map(|inverse| self.modulus.residue(&inverse))
+    }
+}
+
+impl<'a, 'b> From<&'b Residue<'a>> for Natural {
+    /// Converts a [`Residue`] to a [`Natural`] in $[0, m)$, taking the [`Residue`] by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::modular::Modulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Modulus::new(Natural::from(10u32));
+    /// assert_eq!(Natural::from(&m.residue(&Natural::from(13u32))), 3);
+    /// ```
+    fn from(x: &'b Residue<'a>) -> Natural {
+        let modulus = x.modulus;
+        match modulus.reduction {
+            Reduction::Montgomery { .. } => {
+                let n = x.xs.len();
+                let mut xs = vec![0; n << 1];
+                xs[..n].copy_from_slice(&x.xs);
+                let mut out = vec![0; n];
+                modulus.reduce_limbs(&mut out, &mut xs);
+                Natural::from_owned_limbs_asc(out)
+            }
+            Reduction::Barrett { .. } => Natural::from_limbs_asc(&x.xs),
+        }
+    }
+}
+
+impl<'a> From<Residue<'a>> for Natural {
+    /// Converts a [`Residue`] to a [`Natural`] in $[0, m)$, taking the [`Residue`] by value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::modular::Modulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Modulus::new(Natural::from(10u32));
+    /// assert_eq!(Natural::from(m.residue(&Natural::from(13u32))), 3);
+    /// ```
+    fn from(x: Residue<'a>) -> Natural {
+        match x.modulus.reduction {
+            Reduction::Montgomery { .. } => Natural::from(&x),
+            Reduction::Barrett { .. } => Natural::from_owned_limbs_asc(x.xs),
+        }
+    }
+}
+
+impl<'a> PartialEq for Residue<'a> {
+    /// Determines whether two [`Residue`]s are equal.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the [`Residue`]s were created from different [`Modulus`]es.
+    fn eq(&self, other: &Residue<'a>) -> bool {
+        self.modulus.check_same(other.modulus);
+        self.xs == other.xs
+    }
+}
+
+impl<'a> Eq for Residue<'a> {}
+
+impl<'a> Display for Residue<'a> {
+    /// Writes the value of a [`Residue`], in $[0, m)$, to a [`Formatter`].
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&Natural::from(self), f)
+    }
+}
+
+impl<'a> Debug for Residue<'a> {
+    /// Writes the value of a [`Residue`], in $[0, m)$, to a [`Formatter`].
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(&Natural::from(self), f)
+    }
+}
+
+impl<'a> AddAssign<&Residue<'a>> for Residue<'a> {
+    /// Adds a [`Residue`] to a [`Residue`] in place, taking the [`Residue`] on the right-hand side
+    /// by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the [`Residue`]s were created from different [`Modulus`]es.
+    fn add_assign(&mut self, other: &Residue<'a>) {
+        self.modulus.check_same(other.modulus);
+        let ms = &self.modulus.ms;
+        if limbs_slice_add_same_length_in_place_left(&mut self.xs, &other.xs)
+            || limbs_cmp_same_length(&self.xs, ms) != Ordering::Less
+        {
+            limbs_sub_same_length_in_place_left(&mut self.xs, ms);
+        }
+    }
+}
+
+impl<'a> SubAssign<&Residue<'a>> for Residue<'a> {
+    /// Subtracts a [`Residue`] from a [`Residue`] in place, taking the [`Residue`] on the
+    /// right-hand side by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the [`Residue`]s were created from different [`Modulus`]es.
+    fn sub_assign(&mut self, other: &Residue<'a>) {
+        self.modulus.check_same(other.modulus);
+        if limbs_sub_same_length_in_place_left(&mut self.xs, &other.xs) {
+            limbs_slice_add_same_length_in_place_left(&mut self.xs, &self.modulus.ms);
+        }
+    }
+}
+
+impl<'a> MulAssign<&Residue<'a>> for Residue<'a> {
+    /// Multiplies a [`Residue`] by a [`Residue`] in place, taking the [`Residue`] on the
+    /// right-hand side by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the [`Residue`]s were created from different [`Modulus`]es.
+    fn mul_assign(&mut self, other: &Residue<'a>) {
+        self.modulus.check_same(other.modulus);
+        let xs = self.xs.clone();
+        self.modulus.mul_limbs(&mut self.xs, &xs, &other.xs);
+    }
+}
+
+macro_rules! impl_residue_op {
+    ($trait: ident, $fn: ident, $assign_trait: ident, $assign_fn: ident) => {
+        impl<'a> $assign_trait<Residue<'a>> for Residue<'a> {
+            /// Applies the operation in place, taking the [`Residue`] on the right-hand side by
+            /// value. See the implementation that takes it by reference.
+            #[inline]
+            fn $assign_fn(&mut self, other: Residue<'a>) {
+                $assign_trait::$assign_fn(self, &other);
+            }
+        }
+
+        impl<'a> $trait<Residue<'a>> for Residue<'a> {
+            type Output = Residue<'a>;
+
+            /// Applies the operation, taking both [`Residue`]s by value.
+            #[inline]
+            fn $fn(mut self, other: Residue<'a>) -> Residue<'a> {
+                $assign_trait::$assign_fn(&mut self, &other);
+                self
+            }
+        }
+
+        impl<'a, 'b> $trait<&'b Residue<'a>> for Residue<'a> {
+            type Output = Residue<'a>;
+
+            /// Applies the operation, taking the first [`Residue`] by value and the second by
+            /// reference.
+            #[inline]
+            fn $fn(mut self, other: &'b Residue<'a>) -> Residue<'a> {
+                $assign_trait::$assign_fn(&mut self, other);
+                self
+            }
+        }
+
+        impl<'a, 'b> $trait<Residue<'a>> for &'b Residue<'a> {
+            type Output = Residue<'a>;
+
+            /// Applies the operation, taking the first [`Residue`] by reference and the second by
+            /// value.
+            #[inline]
+            fn $fn(self, other: Residue<'a>) -> Residue<'a> {
+                let mut x = self.clone();
+                $assign_trait::$assign_fn(&mut x, &other);
+                x
+            }
+        }
+
+        impl<'a, 'b, 'c> $trait<&'c Residue<'a>> for &'b Residue<'a> {
+            type Output = Residue<'a>;
+
+            /// Applies the operation, taking both [`Residue`]s by reference.
+            #[inline]
+            fn $fn(self, other: &'c Residue<'a>) -> Residue<'a> {
+                let mut x = self.clone();
+                $assign_trait::$assign_fn(&mut x, other);
+                x
+            }
+        }
+    };
+}
+impl_residue_op!(Add, add, AddAssign, add_assign);
+impl_residue_op!(Sub, sub, SubAssign, sub_assign);
+impl_residue_op!(Mul, mul, MulAssign, mul_assign);
+
+impl<'a> Neg for Residue<'a> {
+    type Output = Residue<'a>;
+
+    /// Negates a [`Residue`], taking it by value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    fn neg(mut self) -> Residue<'a> {
+        if !slice_test_zero(&self.xs) {
+            limbs_sub_same_length_in_place_right(&self.modulus.ms, &mut self.xs);
+        }
+        self
+    }
+}
+
+impl<'a, 'b> Neg for &'b Residue<'a> {
+    type Output = Residue<'a>;
+
+    /// Negates a [`Residue`], taking it by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    #[inline]
+    fn neg(self) -> Residue<'a> {
+        -self.clone()
+    }
+}
+
+impl<'a> SquareAssign for Residue<'a> {
+    /// Squares a [`Residue`] in place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    fn square_assign(&mut self) {
+        let xs = self.xs.clone();
+        self.modulus.square_limbs(&mut self.xs, &xs);
+    }
+}
+
+impl<'a> Square for Residue<'a> {
+    type Output = Residue<'a>;
+
+    /// Squares a [`Residue`], taking it by value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    #[inline]
+    fn square(mut self) -> Residue<'a> {
+        self.square_assign();
+        self
+    }
+}
+
+impl<'a, 'b> Square for &'b Residue<'a> {
+    type Output = Residue<'a>;
+
+    /// Squares a [`Residue`], taking it by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    fn square(self) -> Residue<'a> {
+        let mut out = self.modulus.zero();
+        self.modulus.square_limbs(&mut out.xs, &self.xs);
+        out
+    }
+}
+
+impl<'a, 'b, 'c> Pow<&'c Natural> for &'b Residue<'a> {
+    type Output = Residue<'a>;
+
+    /// Raises a [`Residue`] to a [`Natural`] power, taking both by reference. $0^0$ is 1.
+    ///
+    /// The exponent is scanned from left to right, and all intermediate results stay in the
+    /// [`Residue`]'s representation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $m$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::natural::modular::Modulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Modulus::new(Natural::from(497u32));
+    /// let x = m.residue(&Natural::from(4u32));
+    /// assert_eq!(Natural::from((&x).pow(&Natural::from(13u32))), 445);
+    /// ```
+    fn pow(self, exp: &'c Natural) -> Residue<'a> {
+        let modulus = self.modulus;
+        let mut out = modulus.one();
+        if *exp == 0u32 {
+            return out;
+        }
+        let es = exp.to_limbs_asc();
+        let mut scratch = vec![0; self.xs.len()];
+        for i in (0..exp.significant_bits()).rev() {
+            scratch.copy_from_slice(&out.xs);
+            modulus.square_limbs(&mut out.xs, &scratch);
+            if limbs_get_bit(&es, i) {
+                scratch.copy_from_slice(&out.xs);
+                modulus.mul_limbs(&mut out.xs, &scratch, &self.xs);
+            }
+        }
+        out
+    }
+}
+
+impl<'a, 'b> Pow<&'b Natural> for Residue<'a> {
+    type Output = Residue<'a>;
+
+    /// Raises a [`Residue`] to a [`Natural`] power, taking the [`Residue`] by value and the
+    /// [`Natural`] by reference. $0^0$ is 1.
+    ///
+    /// See the implementation for `&Residue` for details.
+    #[inline]
+    fn pow(self, exp: &'b Natural) -> Residue<'a> {
+        (&self).pow(exp)
+    }
+}
+
+impl<'a, 'b> Pow<u64> for &'b Residue<'a> {
+    type Output = Residue<'a>;
+
+    /// Raises a [`Residue`] to a [`u64`] power, taking the [`Residue`] by reference. $0^0$ is 1.
+    ///
+    /// See the implementation that takes a [`Natural`] exponent for details.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::natural::modular::Modulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Modulus::new(Natural::from(10u32).pow(30));
+    /// let x = m.residue(&Natural::from(7u32));
+    /// assert_eq!(
+    ///     Natural::from((&x).pow(100)).to_string(),
+    ///     "933895331391691459636928060001"
+    /// );
+    /// ```
+    #[inline]
+    fn pow(self, exp: u64) -> Residue<'a> {
+        self.pow(&Natural::from(exp))
+    }
+}
+
+impl<'a> Pow<u64> for Residue<'a> {
+    type Output = Residue<'a>;
+
+    /// Raises a [`Residue`] to a [`u64`] power, taking the [`Residue`] by value. $0^0$ is 1.
+    ///
+    /// See the implementation that takes a [`Natural`] exponent for details.
+    #[inline]
+    fn pow(self, exp: u64) -> Residue<'a> {
+        (&self).pow(&Natural::from(exp))
+    }
+}
+
+impl<'a, 'b> PowAssign<&'b Natural> for Residue<'a> {
+    /// Raises a [`Residue`] to a [`Natural`] power in place. $0^0$ is 1.
+    ///
+    /// See the implementation of [`Pow`] for `&Residue` for details.
+    #[inline]
+    fn pow_assign(&mut self, exp: &'b Natural) {
+        *self = (&*self).pow(exp);
+    }
+}
+
+impl<'a> PowAssign<u64> for Residue<'a> {
+    /// Raises a [`Residue`] to a [`u64`] power in place. $0^0$ is 1.
+    ///
+    /// See the implementation of [`Pow`] for `&Residue` for details.
+    #[inline]
+    fn pow_assign(&mut self, exp: u64) {
+        *self = (&*self).pow(&Natural::from(exp));
+    }
+}
@@ -0,0 +1,440 @@
+use crate::natural::arithmetic::mul::{
+    limbs_mul_same_length_to_out_scratch_len, limbs_mul_to_out, limbs_mul_to_out_scratch_len,
+};
+use crate::natural::arithmetic::square::{limbs_square_to_out, limbs_square_to_out_scratch_len};
+use crate::natural::limb_vec::LimbVec::{self, Heap};
+use crate::natural::limb_vec::INLINE_LIMB_COUNT;
+use crate::natural::InnerNatural::{Large, Small};
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use std::mem::{swap, take};
+use std::slice;
+
+/// Memory that can be reused across [`Natural`] operations, so that tight loops don't have to
+/// allocate.
+///
+/// Functions like [`Natural::mul_with_scratch`] need temporary memory for the multiplication
+/// algorithms and for the result. Normally that memory is allocated and freed on every call; with
+/// a `Scratch`, it is taken from the `Scratch` instead, and grows only when an operation needs more
+/// than any previous one did. The result is swapped into the [`Natural`]'s own storage when
+/// possible, and the storage that it replaces goes back into the `Scratch`, so that repeatedly
+/// updating the same [`Natural`] settles into not allocating at all.
+///
+/// A `Scratch` holds no values between calls, and can be shared by any number of [`Natural`]s.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::arithmetic::traits::PowerOf2;
+/// use malachite_nz::natural::scratch::Scratch;
+/// use malachite_nz::natural::Natural;
+///
+/// let mut scratch = Scratch::with_capacity(1000);
+/// let mut x = Natural::power_of_2(300) - Natural::from(1u32);
+/// let y = Natural::power_of_2(300) + Natural::from(1u32);
+/// x.mul_with_scratch(&y, &mut scratch);
+/// assert_eq!(x, Natural::power_of_2(600) - Natural::from(1u32));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Scratch {
+    // The result of the most recent operation, before it's swapped into its destination.
+    out: Vec<Limb>,
+    // Workspace for the `limbs_*_to_out` functions.
+    work: Vec<Limb>,
+    // Storage for products that are added to or subtracted from another value.
+    product: Natural,
+}
+
+// Returns the number of limbs needed to hold `bits` bits.
+fn limb_count(bits: u64) -> usize {
+    usize::exact_from((bits >> Limb::LOG_WIDTH) + u64::from(bits & Limb::WIDTH_MASK != 0))
+}
+
+impl Scratch {
+    /// Creates an empty [`Scratch`]. The first operations that use it will allocate.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::scratch::Scratch;
+    ///
+    /// assert_eq!(Scratch::new().capacity(), 0);
+    /// ```
+    pub const fn new() -> Scratch {
+        Scratch {
+            out: Vec::new(),
+            work: Vec::new(),
+            product: Natural::ZERO,
+        }
+    }
+
+    /// Creates a [`Scratch`] with enough memory to multiply or square [`Natural`]s with up to
+    /// `bits` significant bits each without allocating.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bits`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::scratch::Scratch;
+    ///
+    /// assert!(Scratch::with_capacity(1000).capacity() >= 2000);
+    /// ```
+    pub fn with_capacity(bits: u64) -> Scratch {
+        let mut scratch = Scratch::new();
+        scratch.reserve(bits);
+        scratch
+    }
+
+    /// Returns the number of bits of products that the [`Scratch`] can hold without allocating.
+    /// The memory used by the multiplication algorithms themselves is not included.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::scratch::Scratch;
+    ///
+    /// assert_eq!(Scratch::new().capacity(), 0);
+    /// ```
+    pub fn capacity(&self) -> u64 {
+        u64::exact_from(self.out.capacity()) << Limb::LOG_WIDTH
+    }
+
+    /// Makes sure that the [`Scratch`] has enough memory to multiply or square [`Natural`]s with
+    /// up to `bits` significant bits each without allocating.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bits`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::scratch::Scratch;
+    ///
+    /// let mut scratch = Scratch::new();
+    /// scratch.reserve(1000);
+    /// assert!(scratch.capacity() >= 2000);
+    /// ```
+    pub fn reserve(&mut self, bits: u64) {
+        let len = limb_count(bits);
+        if len == 0 {
+            return;
+        }
+        reserve_len(&mut self.out, len << 1);
+        reserve_len(
+            &mut self.work,
+            limbs_mul_same_length_to_out_scratch_len(len).max(limbs_square_to_out_scratch_len(len)),
+        );
+    }
+
+    /// Frees all the memory held by the [`Scratch`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::scratch::Scratch;
+    ///
+    /// let mut scratch = Scratch::with_capacity(1000);
+    /// scratch.shrink_to_fit();
+    /// assert_eq!(scratch.capacity(), 0);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        *self = Scratch::new();
+    }
+
+    // Returns a zeroed output buffer of length `out_len` and a workspace of length `work_len`.
+    fn buffers(&mut self, out_len: usize, work_len: usize) -> (&mut [Limb], &mut [Limb]) {
+        self.out.clear();
+        self.out.resize(out_len, 0);
+        if self.work.len() < work_len {
+            self.work.resize(work_len, 0);
+        }
+        (&mut self.out, &mut self.work[..work_len])
+    }
+
+    // Writes the product of `xs` and `ys` to the output buffer.
+    fn mul(&mut self, xs: &[Limb], ys: &[Limb]) {
+        let (out, work) = self.buffers(
+            xs.len() + ys.len(),
+            limbs_mul_to_out_scratch_len(xs.len(), ys.len()),
+        );
+        limbs_mul_to_out(out, xs, ys, work);
+    }
+
+    // Computes `x * y` in storage kept by the `Scratch` and passes it to `f`, which gives it back
+    // so that the storage can be reused.
+    pub(crate) fn with_product<F: FnOnce(Natural) -> Natural>(
+        &mut self,
+        x: &Natural,
+        y: &Natural,
+        f: F,
+    ) {
+        let mut product = take(&mut self.product);
+        self.mul(limb_slice(x), limb_slice(y));
+        self.store(&mut product);
+        self.product = f(product);
+    }
+
+    // Moves the output buffer's limbs into `x`. If `x` has heap storage, the two buffers are
+    // swapped, so nothing is allocated or freed; otherwise the limbs are copied.
+    fn store(&mut self, x: &mut Natural) {
+        match x {
+            Natural(Large(Heap(xs))) => swap(xs, &mut self.out),
+            _ if self.out.len() <= INLINE_LIMB_COUNT => {
+                *x = Natural(Large(LimbVec::from_slice(&self.out)));
+            }
+            _ => *x = Natural(Large(Heap(self.out.clone()))),
+        }
+        x.trim();
+    }
+}
+
+fn reserve_len(xs: &mut Vec<Limb>, len: usize) {
+    if xs.capacity() < len {
+        xs.reserve(len - xs.len());
+    }
+}
+
+// Returns the limbs of `x`. Unlike `Natural::limbs`, a `Small` zero has one limb.
+fn limb_slice(x: &Natural) -> &[Limb] {
+    match x {
+        Natural(Small(x)) => slice::from_ref(x),
+        Natural(Large(xs)) => xs,
+    }
+}
+
+impl Natural {
+    /// Multiplies a [`Natural`] by another [`Natural`] in place, using memory from a [`Scratch`]
+    /// instead of allocating.
+    ///
+    /// $$
+    /// x \gets xy.
+    /// $$
+    ///
+    /// The result is the same as with `*=`. Once the [`Scratch`] and `self` have grown large enough
+    /// for the operands, repeated calls neither allocate nor free memory.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::natural::scratch::Scratch;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut scratch = Scratch::new();
+    /// let mut x = Natural::ONE;
+    /// for i in 1..=30u32 {
+    ///     x.mul_with_scratch(&Natural::from(i), &mut scratch);
+    /// }
+    /// assert_eq!(x.to_string(), "265252859812191058636308480000000");
+    /// ```
+    pub fn mul_with_scratch(&mut self, other: &Natural, scratch: &mut Scratch) {
+        if let (Natural(Small(_)), Natural(Small(_))) = (&*self, other) {
+            // The product fits inline, so nothing is allocated.
+            *self *= other;
+            return;
+        }
+        scratch.mul(limb_slice(self), limb_slice(other));
+        scratch.store(self);
+    }
+
+    // Sets `self` to `x * y`, using memory from `scratch` and reusing the storage of `self`.
+    pub(crate) fn assign_product_with_scratch(
+        &mut self,
+        x: &Natural,
+        y: &Natural,
+        scratch: &mut Scratch,
+    ) {
+        if let (Natural(Small(_)), Natural(Small(_))) = (x, y) {
+            *self = x * y;
+            return;
+        }
+        scratch.mul(limb_slice(x), limb_slice(y));
+        scratch.store(self);
+    }
+
+    /// Squares a [`Natural`] in place, using memory from a [`Scratch`] instead of allocating.
+    ///
+    /// $$
+    /// x \gets x^2.
+    /// $$
+    ///
+    /// The result is the same as with
+    /// [`square_assign`](malachite_base::num::arithmetic::traits::SquareAssign::square_assign).
+    /// Once the [`Scratch`] and `self` have grown large enough, repeated calls neither allocate nor
+    /// free memory.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::scratch::Scratch;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut scratch = Scratch::new();
+    /// let mut x = Natural::from(3u32);
+    /// for _ in 0..5 {
+    ///     x.square_with_scratch(&mut scratch);
+    /// }
+    /// assert_eq!(x.to_string(), "1853020188851841");
+    /// ```
+    pub fn square_with_scratch(&mut self, scratch: &mut Scratch) {
+        let xs = limb_slice(self);
+        let (out, work) = scratch.buffers(xs.len() << 1, limbs_square_to_out_scratch_len(xs.len()));
+        limbs_square_to_out(out, xs, work);
+        scratch.store(self);
+    }
+
+    /// Creates a [`Natural`] equal to zero, for code that reserves storage up front.
+    ///
+    /// Zero is stored in a single limb with no separate storage, so the result can hold only
+    /// [`Limb::WIDTH`](malachite_base::num::basic::integers::PrimitiveInt::WIDTH) bits without
+    /// allocating, whatever `bits` is. As with [`reserve`](Natural::reserve), storage can only be
+    /// reserved once a [`Natural`] is at least $2^W$, where $W$ is the width of a
+    /// [`Limb`](crate#limbs).
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowerOf2;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Natural::with_capacity(10000);
+    /// assert_eq!(x, 0u32);
+    ///
+    /// x += Natural::power_of_2(5000);
+    /// x.reserve(5000);
+    /// assert!(x.capacity() >= 10001);
+    /// ```
+    #[allow(unused_variables)]
+    pub const fn with_capacity(bits: u64) -> Natural {
+        Natural(Small(0))
+    }
+
+    /// Returns the number of bits that a [`Natural`] can hold without allocating.
+    ///
+    /// A [`Natural`] that fits in a [`Limb`](crate#limbs), or whose limbs fit inline, never
+    /// allocates; larger [`Natural`]s keep their heap storage until it is released by
+    /// [`shrink_to_fit`](Natural::shrink_to_fit).
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowerOf2;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!(Natural::from(3u32).capacity() >= 3);
+    /// let mut x = Natural::power_of_2(1000);
+    /// x.reserve(1000);
+    /// assert!(x.capacity() >= 2001);
+    /// ```
+    pub fn capacity(&self) -> u64 {
+        match self {
+            Natural(Small(_)) => Limb::WIDTH,
+            Natural(Large(xs)) => u64::exact_from(xs.capacity()) << Limb::LOG_WIDTH,
+        }
+    }
+
+    /// Reserves storage so that a [`Natural`] can grow by `additional` bits without allocating.
+    ///
+    /// A [`Natural`] less than $2^W$, where $W$ is the width of a [`Limb`](crate#limbs), is stored
+    /// in a single limb with no separate storage, so reserving does nothing to it.
+    ///
+    /// Otherwise, the storage is kept by operations that modify a [`Natural`] in place, such as
+    /// `+=` and `-=` with a reference, `<<=`, `>>=`, multiplying by a [`Limb`](crate#limbs) with
+    /// `*=`, and [`mul_with_scratch`](Natural::mul_with_scratch), even if the value becomes small
+    /// enough to be stored inline. Operations that compute a new value and replace the old one with
+    /// it, such as `*=` with a [`Natural`] of more than one limb, `square_assign`, and
+    /// `div_exact_assign`, don't keep it. The storage is released when the value drops below $2^W$
+    /// or when [`shrink_to_fit`](Natural::shrink_to_fit) is called.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.significant_bits() + additional`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowerOf2;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Natural::power_of_2(100);
+    /// x.reserve(10000);
+    /// assert!(x.capacity() >= 10101);
+    /// x <<= 5000;
+    /// assert_eq!(x, Natural::power_of_2(5100));
+    /// assert!(x.capacity() >= 10101);
+    ///
+    /// let mut x = Natural::from(3u32);
+    /// x.reserve(10000);
+    /// assert!(x.capacity() < 10000);
+    /// ```
+    pub fn reserve(&mut self, additional: u64) {
+        let len = limb_count(self.significant_bits() + additional);
+        if let Natural(Large(xs)) = self {
+            let additional = len - xs.len();
+            xs.reserve(additional);
+        }
+    }
+
+    /// Releases any storage that a [`Natural`] doesn't need, moving its limbs inline if there are
+    /// few enough of them.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowerOf2;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Natural::power_of_2(1000);
+    /// x.reserve(10000);
+    /// x.shrink_to_fit();
+    /// assert!(x.capacity() < 2000);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        if let Natural(Large(xs)) = self {
+            xs.shrink_to_fit();
+        }
+    }
+}
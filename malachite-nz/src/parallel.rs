@@ -0,0 +1,69 @@
+use crate::platform::Limb;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+// Whether the `parallel` feature is enabled. When it isn't, the helpers in this module run their
+// arguments sequentially, so callers don't need to be duplicated behind `cfg` attributes.
+pub(crate) const PARALLEL: bool = cfg!(feature = "parallel");
+
+// The minimum total number of limbs in the inputs of an FFT multiplication or squaring for which
+// its transforms and pointwise products are spread over multiple threads.
+pub(crate) const PARALLEL_FFT_THRESHOLD: usize = 20000;
+
+// The minimum total number of limbs in the inputs of an unbalanced Toom multiplication for which
+// the chunks of the longer input are multiplied on multiple threads.
+pub(crate) const PARALLEL_TOOM_THRESHOLD: usize = 20000;
+
+// The minimum length of the inputs of a balanced multiplication for which the top level of
+// Karatsuba's algorithm is used, with its three half-length products computed on separate threads.
+// Above `PARALLEL_FFT_THRESHOLD`, the FFT is parallelized instead.
+pub(crate) const PARALLEL_KARATSUBA_THRESHOLD: usize = 1000;
+
+// The minimum number of factors for which the two halves of `limbs_product` are computed on
+// separate threads.
+pub(crate) const PARALLEL_PRODUCT_THRESHOLD: usize = 2000;
+
+// The minimum number of limbs for which the two halves of a divide-and-conquer radix conversion are
+// computed on separate threads.
+pub(crate) const PARALLEL_DIGITS_THRESHOLD: usize = 10000;
+
+// Runs `a` and `b`, potentially in parallel if `parallel` is `true` and the `parallel` feature is
+// enabled, and returns both results. The results are the same either way.
+#[allow(unused_variables)]
+pub(crate) fn join<A, B, RA, RB>(parallel: bool, a: A, b: B) -> (RA, RB)
+where
+    A: FnOnce() -> RA + Send,
+    B: FnOnce() -> RB + Send,
+    RA: Send,
+    RB: Send,
+{
+    #[cfg(feature = "parallel")]
+    if parallel {
+        return rayon::join(a, b);
+    }
+    (a(), b())
+}
+
+// Calls `f` on every element of `items`, passing it a scratch buffer. When running sequentially,
+// `scratch` is reused for every element; when running in parallel, each thread gets its own zeroed
+// buffer with the same length as `scratch`. `f` must therefore not depend on the initial contents
+// of the scratch buffer.
+#[allow(unused_variables)]
+pub(crate) fn for_each_with_scratch<T: Send, F: Fn(&mut T, &mut [Limb]) + Sync>(
+    parallel: bool,
+    items: &mut [T],
+    scratch: &mut [Limb],
+    f: F,
+) {
+    #[cfg(feature = "parallel")]
+    if parallel {
+        let scratch_len = scratch.len();
+        items
+            .par_iter_mut()
+            .for_each_init(|| vec![0; scratch_len], |scratch, x| f(x, scratch));
+        return;
+    }
+    for x in items {
+        f(x, scratch);
+    }
+}
@@ -433,6 +433,8 @@ pub(crate) fn round_to_multiple_integer_filter_map(
                     RoundingMode::Ceiling
                 }
             || rm == RoundingMode::Nearest
+            || rm == RoundingMode::NearestTiesAway
+            || rm == RoundingMode::NearestTiesTowardZero
         {
             Some((x, y, rm))
         } else {
@@ -1428,7 +1430,12 @@ pub(crate) fn round_to_multiple_natural_filter_map(
     if x == y {
         Some((x, y, rm))
     } else if y == 0 {
-        if rm == RoundingMode::Down || rm == RoundingMode::Floor || rm == RoundingMode::Nearest {
+        if rm == RoundingMode::Down
+            || rm == RoundingMode::Floor
+            || rm == RoundingMode::Nearest
+            || rm == RoundingMode::NearestTiesAway
+            || rm == RoundingMode::NearestTiesTowardZero
+        {
             Some((x, y, rm))
         } else {
             None
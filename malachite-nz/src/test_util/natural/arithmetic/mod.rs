@@ -22,6 +22,7 @@ pub mod mul;
 pub mod neg;
 pub mod pow;
 pub mod primorial;
+pub mod remove_factor;
 pub mod root;
 pub mod sqrt;
 pub mod square;
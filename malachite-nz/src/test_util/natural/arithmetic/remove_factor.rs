@@ -0,0 +1,13 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{DivExactAssign, DivisibleBy};
+
+pub fn remove_factor_simple(mut x: Natural, p: &Natural) -> (Natural, u64) {
+    assert_ne!(x, 0u32);
+    assert!(*p > 1u32);
+    let mut multiplicity = 0;
+    while (&x).divisible_by(p) {
+        x.div_exact_assign(p);
+        multiplicity += 1;
+    }
+    (x, multiplicity)
+}
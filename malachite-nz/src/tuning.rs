@@ -0,0 +1,103 @@
+// Parsing and validation of the thresholds in a platform file generated by `cargo run --release
+// --features bin_build -- -g tuneup`. `build.rs` includes this file to check the file selected by
+// `MALACHITE_NZ_PLATFORM`, so it may only use `std`.
+
+use std::collections::BTreeMap;
+
+// The thresholds set by tuneup, in groups. Within each group, the thresholds choose between
+// successively faster algorithms for larger inputs, so they must be strictly increasing. Each
+// threshold is paired with the smallest input length accepted by the algorithm that it selects.
+pub const TUNED_THRESHOLDS: [&[(&str, usize)]; 2] = [
+    &[
+        ("MUL_TOOM22_THRESHOLD", 6),
+        ("MUL_TOOM33_THRESHOLD", 17),
+        ("MUL_TOOM44_THRESHOLD", 30),
+        ("MUL_TOOM6H_THRESHOLD", 42),
+        ("MUL_TOOM8H_THRESHOLD", 86),
+        ("MUL_FFT_THRESHOLD", 1),
+    ],
+    &[("DC_DIV_QR_THRESHOLD", 6), ("MU_DIV_QR_THRESHOLD", 74)],
+];
+
+fn tuned_threshold_names() -> impl Iterator<Item = &'static str> {
+    TUNED_THRESHOLDS
+        .iter()
+        .flat_map(|group| group.iter().map(|&(name, _)| name))
+}
+
+// Returns the values of the tuned thresholds in the source of a platform file. Each must be
+// defined exactly once, on a line of the form `pub const NAME: usize = VALUE;`.
+pub fn parse_thresholds(source: &str) -> Result<BTreeMap<&'static str, usize>, String> {
+    let mut thresholds = BTreeMap::new();
+    for (i, line) in source.lines().enumerate() {
+        let (name, rest) = match line
+            .trim()
+            .strip_prefix("pub const ")
+            .and_then(|rest| rest.split_once(": usize = "))
+        {
+            Some(definition) => definition,
+            None => continue,
+        };
+        let name = match tuned_threshold_names().find(|&tuned| tuned == name) {
+            Some(name) => name,
+            None => continue,
+        };
+        let value = match rest.split_once(';') {
+            Some((value, _)) => value.trim(),
+            None => return Err(format!("line {}: expected `;` after {name}", i + 1)),
+        };
+        let value = value
+            .replace('_', "")
+            .parse()
+            .map_err(|_| format!("line {}: {name} is not an integer: `{value}`", i + 1))?;
+        if thresholds.insert(name, value).is_some() {
+            return Err(format!("line {}: {name} is defined more than once", i + 1));
+        }
+    }
+    if let Some(name) = tuned_threshold_names().find(|name| !thresholds.contains_key(name)) {
+        return Err(format!("{name} is not defined"));
+    }
+    Ok(thresholds)
+}
+
+// Checks that the tuned thresholds are at least the minimum input lengths of their algorithms, and
+// that they are strictly increasing within each group.
+pub fn validate_thresholds(thresholds: &BTreeMap<&str, usize>) -> Result<(), String> {
+    for group in TUNED_THRESHOLDS {
+        let mut previous: Option<(&str, usize)> = None;
+        for &(name, min) in group {
+            let value = *thresholds
+                .get(name)
+                .ok_or_else(|| format!("{name} is not defined"))?;
+            if value < min {
+                return Err(format!(
+                    "{name} is {value}, but its algorithm needs inputs of length at least {min}"
+                ));
+            }
+            if let Some((previous_name, previous_value)) = previous {
+                if value <= previous_value {
+                    return Err(format!(
+                        "{name} is {value}, but it must be greater than {previous_name}, which is \
+                        {previous_value}"
+                    ));
+                }
+            }
+            previous = Some((name, value));
+        }
+    }
+    Ok(())
+}
+
+// Raises tuned thresholds as little as possible to make them valid, in the sense of
+// `validate_thresholds`. Measured thresholds can be out of order when neighboring algorithms are
+// about equally fast.
+pub fn make_thresholds_valid(thresholds: &mut BTreeMap<&str, usize>) {
+    for group in TUNED_THRESHOLDS {
+        let mut lower_bound = 0;
+        for &(name, min) in group {
+            let value = thresholds.get_mut(name).unwrap();
+            *value = (*value).max(min).max(lower_bound);
+            lower_bound = *value + 1;
+        }
+    }
+}
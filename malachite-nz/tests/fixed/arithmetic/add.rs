@@ -0,0 +1,89 @@
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::arithmetic::traits::{
+    CheckedAdd, OverflowingAdd, OverflowingSub, SaturatingAdd, SaturatingSub, WrappingAdd,
+    WrappingSub,
+};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_nz::fixed::{Int, Uint, I256, U256};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{integer_pair_gen, natural_pair_gen};
+
+#[test]
+fn test_add() {
+    assert_eq!(U256::from(123u32) + U256::from(456u32), U256::from(579u32));
+    assert_eq!(U256::MAX.overflowing_add(U256::ONE), (U256::ZERO, true));
+    assert_eq!(U256::MAX.checked_add(U256::ONE), None);
+    assert_eq!(U256::MAX.saturating_add(U256::ONE), U256::MAX);
+    assert_eq!(I256::from(-5) + I256::from(3), I256::from(-2));
+    assert_eq!(I256::MAX.overflowing_add(I256::ONE), (I256::MIN, true));
+    assert_eq!(I256::MIN.saturating_add(I256::NEGATIVE_ONE), I256::MIN);
+}
+
+#[test]
+fn test_sub() {
+    assert_eq!(U256::from(456u32) - U256::from(123u32), U256::from(333u32));
+    assert_eq!(U256::ZERO.overflowing_sub(U256::ONE), (U256::MAX, true));
+    assert_eq!(U256::ZERO.saturating_sub(U256::ONE), U256::ZERO);
+    assert_eq!(I256::from(3) - I256::from(5), I256::from(-2));
+    assert_eq!(I256::MIN.overflowing_sub(I256::ONE), (I256::MAX, true));
+    assert_eq!(I256::MAX.saturating_sub(I256::NEGATIVE_ONE), I256::MAX);
+}
+
+#[test]
+#[should_panic]
+fn add_fail() {
+    let _ = U256::MAX + U256::ONE;
+}
+
+#[test]
+#[should_panic]
+fn sub_fail() {
+    let _ = I256::MIN - I256::ONE;
+}
+
+#[test]
+fn add_properties() {
+    let modulus = Natural::ONE << U256::WIDTH;
+    natural_pair_gen().test_properties(|(x, y)| {
+        let (a, b) = (U256::wrapping_from(&x), U256::wrapping_from(&y));
+        let (sum, overflow) = a.overflowing_add(b);
+        let exact = Natural::from(a) + Natural::from(b);
+        assert_eq!(overflow, exact >= modulus);
+        assert_eq!(Natural::from(sum), exact % &modulus);
+        assert_eq!(a.wrapping_add(b), sum);
+        assert_eq!(b.wrapping_add(a), sum);
+        assert_eq!(sum.wrapping_sub(b), a);
+        assert_eq!(a.checked_add(b).is_none(), overflow);
+    });
+
+    integer_pair_gen().test_properties(|(x, y)| {
+        let (a, b) = (Int::<1>::wrapping_from(&x), Int::<1>::wrapping_from(&y));
+        let (sum, overflow) = a.overflowing_add(b);
+        let exact = Integer::from(a) + Integer::from(b);
+        assert_eq!(Integer::from(sum) != exact, overflow);
+        assert_eq!(Int::<1>::wrapping_from(&exact), sum);
+        let (difference, overflow) = a.overflowing_sub(b);
+        let exact = Integer::from(a) - Integer::from(b);
+        assert_eq!(Integer::from(difference) != exact, overflow);
+        assert_eq!(Int::<1>::wrapping_from(&exact), difference);
+    });
+}
+
+#[test]
+fn sub_properties() {
+    natural_pair_gen().test_properties(|(x, y)| {
+        let (a, b) = (Uint::<2>::wrapping_from(&x), Uint::<2>::wrapping_from(&y));
+        let (difference, overflow) = a.overflowing_sub(b);
+        assert_eq!(overflow, a < b);
+        if !overflow {
+            assert_eq!(
+                Natural::from(difference),
+                Natural::from(a) - Natural::from(b)
+            );
+        }
+        assert_eq!(difference.wrapping_add(b), a);
+        assert_eq!(a.saturating_sub(b) == Uint::ZERO, a <= b);
+    });
+}
@@ -0,0 +1,102 @@
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::arithmetic::traits::{
+    CheckedDiv, DivMod, DivRem, OverflowingDiv, WrappingDiv,
+};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_nz::fixed::{Int, Uint, I256, U256};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{integer_pair_gen, natural_pair_gen};
+
+#[test]
+fn test_div_mod() {
+    let test = |n: U256, d: U256, q: U256, r: U256| {
+        assert_eq!(n.div_mod(d), (q, r));
+        assert_eq!(n / d, q);
+        assert_eq!(n % d, r);
+    };
+    test(
+        U256::from(23u32),
+        U256::from(10u32),
+        U256::from(2u32),
+        U256::from(3u32),
+    );
+    test(U256::MAX, U256::MAX, U256::ONE, U256::ZERO);
+    test(U256::MAX, U256::ONE << 255, U256::ONE, U256::MAX >> 1);
+    test(U256::ONE << 200, U256::MAX, U256::ZERO, U256::ONE << 200);
+
+    assert_eq!(
+        I256::from(-23).div_mod(I256::from(10)),
+        (I256::from(-3), I256::from(7))
+    );
+    assert_eq!(
+        I256::from(-23).div_rem(I256::from(10)),
+        (I256::from(-2), I256::from(-3))
+    );
+    assert_eq!(
+        I256::MIN.overflowing_div(I256::NEGATIVE_ONE),
+        (I256::MIN, true)
+    );
+    assert_eq!(I256::MIN.wrapping_div(I256::NEGATIVE_ONE), I256::MIN);
+    assert_eq!(I256::MIN.checked_div(I256::NEGATIVE_ONE), None);
+    assert_eq!(I256::MIN % I256::NEGATIVE_ONE, I256::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn div_fail() {
+    let _ = U256::ONE / U256::ZERO;
+}
+
+#[test]
+#[should_panic]
+fn div_overflow_fail() {
+    let _ = I256::MIN / I256::NEGATIVE_ONE;
+}
+
+fn div_mod_properties_helper<const LIMBS: usize>(x: &Natural, y: &Natural) {
+    let (n, d) = (
+        Uint::<LIMBS>::wrapping_from(x),
+        Uint::<LIMBS>::wrapping_from(y),
+    );
+    if d == Uint::ZERO {
+        return;
+    }
+    let (q, r) = n.div_mod(d);
+    assert_eq!(
+        (Natural::from(q), Natural::from(r)),
+        Natural::from(n).div_mod(Natural::from(d))
+    );
+    assert_eq!(n.div_rem(d), (q, r));
+    assert_eq!(n.checked_div(d), Some(q));
+}
+
+#[test]
+fn div_mod_properties() {
+    natural_pair_gen().test_properties(|(x, y)| {
+        div_mod_properties_helper::<1>(&x, &y);
+        div_mod_properties_helper::<2>(&x, &y);
+        div_mod_properties_helper::<4>(&x, &y);
+        div_mod_properties_helper::<4>(&x, &(y >> 128u64));
+    });
+
+    integer_pair_gen().test_properties(|(x, y)| {
+        let (n, d) = (Int::<2>::wrapping_from(&x), Int::<2>::wrapping_from(&y));
+        if d == Int::ZERO {
+            return;
+        }
+        let (q, overflow) = n.overflowing_div(d);
+        let exact = Integer::from(n) / Integer::from(d);
+        assert_eq!(overflow, n == Int::MIN && d == Int::NEGATIVE_ONE);
+        assert_eq!(Int::<2>::wrapping_from(&exact), q);
+        assert_eq!(Integer::from(n % d), Integer::from(n) % Integer::from(d));
+        if !overflow {
+            let (q, r) = n.div_mod(d);
+            assert_eq!(
+                (Integer::from(q), Integer::from(r)),
+                Integer::from(n).div_mod(Integer::from(d))
+            );
+        }
+    });
+}
@@ -0,0 +1,51 @@
+use malachite_base::comparison::traits::Max;
+use malachite_base::num::arithmetic::traits::{ModAdd, ModMul, ModNeg, ModPow, ModSub};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_nz::fixed::{Uint, U256};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_triple_gen, natural_unsigned_pair_gen_var_4};
+
+#[test]
+fn test_mod_arithmetic() {
+    let m = U256::MAX - U256::from(188u32);
+    assert_eq!((m - U256::ONE).mod_add(U256::TWO, m), U256::ONE);
+    assert_eq!(U256::ONE.mod_sub(U256::TWO, m), m - U256::ONE);
+    assert_eq!(U256::ZERO.mod_neg(m), U256::ZERO);
+    assert_eq!((m - U256::ONE).mod_mul(m - U256::ONE, m), U256::ONE);
+    // 2^256 - 189 is prime, so Fermat's little theorem applies
+    assert_eq!(U256::from(3u32).mod_pow(m - U256::ONE, m), U256::ONE);
+    assert_eq!(U256::from(3u32).mod_pow(U256::ZERO, U256::ONE), U256::ZERO);
+}
+
+fn mod_arithmetic_properties_helper<const LIMBS: usize>(x: &Natural, y: &Natural, m: &Natural) {
+    let m = Uint::<LIMBS>::wrapping_from(m);
+    if m == Uint::ZERO {
+        return;
+    }
+    let x = Uint::<LIMBS>::wrapping_from(x) % m;
+    let y = Uint::<LIMBS>::wrapping_from(y) % m;
+    let (xn, yn, mn) = (Natural::from(x), Natural::from(y), Natural::from(m));
+    assert_eq!(Natural::from(x.mod_add(y, m)), (&xn).mod_add(&yn, &mn));
+    assert_eq!(Natural::from(x.mod_sub(y, m)), (&xn).mod_sub(&yn, &mn));
+    assert_eq!(Natural::from(x.mod_neg(m)), (&xn).mod_neg(&mn));
+    assert_eq!(Natural::from(x.mod_mul(y, m)), (&xn).mod_mul(&yn, &mn));
+}
+
+#[test]
+fn mod_arithmetic_properties() {
+    natural_triple_gen().test_properties(|(x, y, m)| {
+        mod_arithmetic_properties_helper::<1>(&x, &y, &m);
+        mod_arithmetic_properties_helper::<3>(&x, &y, &m);
+        mod_arithmetic_properties_helper::<4>(&x, &y, &(m >> 100u64));
+    });
+
+    natural_unsigned_pair_gen_var_4::<u64>().test_properties(|(x, e)| {
+        let m = U256::MAX >> 3u64;
+        let x = U256::wrapping_from(&x) % m;
+        assert_eq!(
+            Natural::from(x.mod_pow(U256::from(e), m)),
+            Natural::from(x).mod_pow(Natural::from(e), Natural::from(m))
+        );
+    });
+}
@@ -0,0 +1,68 @@
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::arithmetic::traits::{
+    CheckedMul, OverflowingMul, SaturatingMul, WrappingMul,
+};
+use malachite_base::num::basic::traits::{NegativeOne, One, Two, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_nz::fixed::{Int, Uint, I256, U256};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{integer_pair_gen, natural_pair_gen};
+
+#[test]
+fn test_mul() {
+    assert_eq!(
+        U256::from(123u32) * U256::from(456u32),
+        U256::from(56088u32)
+    );
+    assert_eq!(
+        (U256::ONE << 128).overflowing_mul(U256::ONE << 128),
+        (U256::ZERO, true)
+    );
+    assert_eq!(U256::MAX.wrapping_mul(U256::MAX), U256::ONE);
+    assert_eq!(U256::MAX.saturating_mul(U256::TWO), U256::MAX);
+    assert_eq!(I256::from(-123) * I256::from(456), I256::from(-56088));
+    assert_eq!(
+        I256::MIN.overflowing_mul(I256::NEGATIVE_ONE),
+        (I256::MIN, true)
+    );
+    assert_eq!((I256::MIN >> 1u64).checked_mul(I256::TWO), Some(I256::MIN));
+    assert_eq!(I256::MAX.saturating_mul(I256::from(-2)), I256::MIN);
+    assert_eq!(I256::ZERO * I256::MIN, I256::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn mul_fail() {
+    let _ = U256::MAX * U256::TWO;
+}
+
+#[test]
+fn mul_properties() {
+    let modulus = Natural::ONE << Uint::<3>::WIDTH;
+    natural_pair_gen().test_properties(|(x, y)| {
+        let (a, b) = (Uint::<3>::wrapping_from(&x), Uint::<3>::wrapping_from(&y));
+        let (product, overflow) = a.overflowing_mul(b);
+        let exact = Natural::from(a) * Natural::from(b);
+        assert_eq!(overflow, exact >= modulus);
+        assert_eq!(Natural::from(product), exact % &modulus);
+        assert_eq!(b.wrapping_mul(a), product);
+        assert_eq!(a.checked_mul(b).is_none(), overflow);
+        if overflow {
+            assert_eq!(a.saturating_mul(b), Uint::MAX);
+        }
+    });
+
+    integer_pair_gen().test_properties(|(x, y)| {
+        let (a, b) = (Int::<2>::wrapping_from(&x), Int::<2>::wrapping_from(&y));
+        let (product, overflow) = a.overflowing_mul(b);
+        let exact = Integer::from(a) * Integer::from(b);
+        assert_eq!(Integer::from(product) != exact, overflow);
+        assert_eq!(Int::<2>::wrapping_from(&exact), product);
+        assert_eq!(b.wrapping_mul(a), product);
+        if overflow {
+            let saturated = a.saturating_mul(b);
+            assert!(saturated == Int::MAX || saturated == Int::MIN);
+        }
+    });
+}
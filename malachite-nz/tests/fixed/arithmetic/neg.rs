@@ -0,0 +1,48 @@
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::arithmetic::traits::{
+    Abs, CheckedNeg, OverflowingAbs, OverflowingNeg, SaturatingAbs, SaturatingNeg, UnsignedAbs,
+    WrappingNeg,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_nz::fixed::{Int, Uint, I256, U256};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_gen;
+
+#[test]
+fn test_neg() {
+    assert_eq!(-I256::from(123), I256::from(-123));
+    assert_eq!(I256::MIN.overflowing_neg(), (I256::MIN, true));
+    assert_eq!(I256::MIN.saturating_neg(), I256::MAX);
+    assert_eq!(I256::MIN.overflowing_abs(), (I256::MIN, true));
+    assert_eq!(I256::MIN.saturating_abs(), I256::MAX);
+    assert_eq!(I256::MIN.unsigned_abs(), U256::ONE << 255);
+    assert_eq!(I256::from(-5).abs(), I256::from(5));
+    assert_eq!(U256::ONE.wrapping_neg(), U256::MAX);
+    assert_eq!(U256::ZERO.checked_neg(), Some(U256::ZERO));
+    assert_eq!(U256::ONE.checked_neg(), None);
+}
+
+#[test]
+#[should_panic]
+fn neg_fail() {
+    let _ = -I256::MIN;
+}
+
+#[test]
+fn neg_properties() {
+    integer_gen().test_properties(|x| {
+        let a = Int::<2>::wrapping_from(&x);
+        let (negative, overflow) = a.overflowing_neg();
+        assert_eq!(overflow, a == Int::MIN);
+        assert_eq!(Int::<2>::wrapping_from(&-Integer::from(a)), negative);
+        assert_eq!(negative.wrapping_neg(), a);
+        assert_eq!(
+            Natural::from(a.unsigned_abs()),
+            Integer::from(a).unsigned_abs()
+        );
+        let u = Uint::<2>::wrapping_from(a);
+        assert_eq!(u.wrapping_neg(), Uint::wrapping_from(negative));
+    });
+}
@@ -0,0 +1,20 @@
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_nz::fixed::{I256, U256};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{integer_pair_gen, natural_pair_gen};
+
+#[test]
+fn cmp_properties() {
+    natural_pair_gen().test_properties(|(x, y)| {
+        let (a, b) = (U256::wrapping_from(&x), U256::wrapping_from(&y));
+        assert_eq!(a.cmp(&b), Natural::from(a).cmp(&Natural::from(b)));
+        assert_eq!(b.cmp(&a), a.cmp(&b).reverse());
+    });
+
+    integer_pair_gen().test_properties(|(x, y)| {
+        let (a, b) = (I256::wrapping_from(&x), I256::wrapping_from(&y));
+        assert_eq!(a.cmp(&b), Integer::from(a).cmp(&Integer::from(b)));
+        assert_eq!(b.cmp(&a), a.cmp(&b).reverse());
+    });
+}
@@ -0,0 +1,121 @@
+use malachite_base::comparison::traits::Max;
+use malachite_base::num::arithmetic::traits::{ModInverse, ModMul, ModPow, Pow, Square};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_nz::fixed::constant_time::{take_trace, ConstantTimeModulus};
+use malachite_nz::fixed::{Uint, U256};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_triple_gen;
+
+#[test]
+fn test_constant_time_residue() {
+    // 2^256 - 189 is prime
+    let m = ConstantTimeModulus::new(U256::MAX - U256::from(188u32));
+    let x = m.residue(U256::from(3u32));
+    assert_eq!(U256::from(x.pow(m.modulus() - U256::ONE)), U256::ONE);
+    assert_eq!(U256::from(x.pow(U256::ZERO)), U256::ONE);
+    assert_eq!(U256::from(m.zero().pow(U256::ZERO)), U256::ONE);
+    assert_eq!(U256::from(m.zero().pow(U256::ONE)), U256::ZERO);
+    assert_eq!(U256::from(-m.one()), m.modulus() - U256::ONE);
+    assert_eq!(x.invert().unwrap() * x, m.one());
+    assert!(m.zero().invert().is_none());
+    assert_eq!(m.residue(U256::MAX), m.residue(U256::from(188u32)));
+
+    let m = ConstantTimeModulus::new(U256::ONE);
+    let x = m.residue(U256::from(5u32));
+    assert_eq!(x, m.zero());
+    assert_eq!(U256::from(x.pow(U256::ZERO)), U256::ZERO);
+    assert!(x.invert().is_none());
+
+    let m = ConstantTimeModulus::new(U256::from(15u32));
+    assert!(m.residue(U256::from(6u32)).invert().is_none());
+    assert_eq!(
+        U256::from(m.residue(U256::from(7u32)).invert().unwrap()),
+        U256::from(13u32)
+    );
+}
+
+#[test]
+#[should_panic]
+fn constant_time_modulus_fail() {
+    ConstantTimeModulus::new(U256::from(10u32));
+}
+
+#[test]
+#[should_panic]
+fn constant_time_add_fail() {
+    let m = ConstantTimeModulus::new(U256::from(11u32));
+    let n = ConstantTimeModulus::new(U256::from(13u32));
+    let _ = m.one() + n.one();
+}
+
+#[test]
+fn test_constant_time_trace() {
+    let m = ConstantTimeModulus::new(U256::MAX - U256::from(188u32));
+    take_trace();
+    let mut pow_traces = Vec::new();
+    for (x, e) in [
+        (U256::ZERO, U256::ZERO),
+        (U256::ONE, U256::ONE),
+        (U256::from(3u32), U256::MAX),
+        (U256::MAX, U256::from(1u32) << 200u64),
+    ] {
+        m.residue(x).pow(e);
+        pow_traces.push(take_trace());
+    }
+    assert!(pow_traces.windows(2).all(|w| w[0] == w[1]));
+
+    let mut invert_traces = Vec::new();
+    for x in [U256::ZERO, U256::ONE, U256::from(3u32), U256::MAX >> 1u64] {
+        m.residue(x).invert();
+        invert_traces.push(take_trace());
+    }
+    assert!(invert_traces.windows(2).all(|w| w[0] == w[1]));
+
+    // Non-invertible inputs take the same path too
+    let m = ConstantTimeModulus::new(U256::from(15u32));
+    m.residue(U256::from(5u32)).invert();
+    let trace_1 = take_trace();
+    m.residue(U256::from(7u32)).invert();
+    assert_eq!(trace_1, take_trace());
+}
+
+fn constant_time_properties_helper<const LIMBS: usize>(x: &Natural, y: &Natural, m: &Natural) {
+    let m = Uint::<LIMBS>::wrapping_from(m) | Uint::ONE;
+    let x = Uint::<LIMBS>::wrapping_from(x);
+    let y = Uint::<LIMBS>::wrapping_from(y);
+    let (xn, yn, mn) = (Natural::from(x), Natural::from(y), Natural::from(m));
+    let (xn, yn) = (xn % &mn, yn % &mn);
+    let modulus = ConstantTimeModulus::new(m);
+    let (xr, yr) = (modulus.residue(x), modulus.residue(y));
+    assert_eq!(Natural::from(Uint::from(xr)), xn);
+    assert_eq!(Natural::from(Uint::from(xr + yr)), (&xn + &yn) % &mn);
+    assert_eq!(Natural::from(Uint::from(xr - yr)), (&xn + &mn - &yn) % &mn);
+    assert_eq!(Natural::from(Uint::from(-xr)), (&mn - &xn) % &mn);
+    assert_eq!(Natural::from(Uint::from(xr * yr)), (&xn).mod_mul(&yn, &mn));
+    assert_eq!(xr.square(), xr * xr);
+    assert_eq!(
+        Natural::from(Uint::from(xr.pow(y))),
+        (&xn).mod_pow(Natural::from(y), &mn)
+    );
+    assert_eq!(
+        xr.invert()
+            .map(|inverse| Natural::from(Uint::from(inverse))),
+        if xn == 0u32 {
+            None
+        } else {
+            (&xn).mod_inverse(&mn)
+        }
+    );
+    assert_eq!(xr == yr, xn == yn);
+    take_trace();
+}
+
+#[test]
+fn constant_time_properties() {
+    natural_triple_gen().test_properties_with_limit(SMALL_LIMIT, |(x, y, m)| {
+        constant_time_properties_helper::<1>(&x, &y, &m);
+        constant_time_properties_helper::<2>(&x, &y, &m);
+    });
+}
@@ -0,0 +1,102 @@
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
+use malachite_base::num::conversion::traits::{
+    ConvertibleFrom, OverflowingFrom, SaturatingFrom, WrappingFrom,
+};
+use malachite_nz::fixed::{Int, Uint, I256, U256};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{integer_gen, natural_gen};
+use std::str::FromStr;
+
+#[test]
+fn test_from_natural() {
+    let two_256 = Natural::ONE << 256;
+    assert_eq!(
+        U256::try_from(&Natural::from(123u32)),
+        Ok(U256::from(123u32))
+    );
+    assert!(U256::try_from(&two_256).is_err());
+    assert_eq!(U256::wrapping_from(&two_256), U256::ZERO);
+    assert_eq!(U256::saturating_from(&two_256), U256::MAX);
+    assert_eq!(U256::overflowing_from(&two_256), (U256::ZERO, true));
+    assert!(!U256::convertible_from(&two_256));
+    assert_eq!(Natural::from(U256::MAX), two_256 - Natural::ONE);
+}
+
+#[test]
+fn test_from_integer() {
+    let two_255 = Integer::ONE << 255;
+    assert_eq!(I256::try_from(&-&two_255), Ok(I256::MIN));
+    assert!(I256::try_from(&two_255).is_err());
+    assert_eq!(I256::wrapping_from(&two_255), I256::MIN);
+    assert_eq!(I256::saturating_from(&two_255), I256::MAX);
+    assert_eq!(I256::overflowing_from(&two_255), (I256::MIN, true));
+    assert_eq!(Integer::from(I256::NEGATIVE_ONE), Integer::NEGATIVE_ONE);
+    assert_eq!(U256::wrapping_from(I256::NEGATIVE_ONE), U256::MAX);
+    assert!(!I256::convertible_from(U256::MAX));
+    assert!(!U256::convertible_from(I256::MIN));
+}
+
+#[test]
+fn test_to_string() {
+    assert_eq!(U256::from(123u32).to_string(), "123");
+    assert_eq!(format!("{:#x}", U256::from(255u32)), "0xff");
+    assert_eq!(format!("{:?}", U256::ZERO), "0");
+    assert_eq!(I256::from(-123).to_string(), "-123");
+    assert_eq!(format!("{:x}", I256::NEGATIVE_ONE), "f".repeat(64));
+    assert_eq!(U256::from_str("123"), Ok(U256::from(123u32)));
+    assert_eq!(I256::from_str("-123"), Ok(I256::from(-123)));
+    assert_eq!(
+        U256::from_str("-1").unwrap_err().kind(),
+        ParseNumberErrorKind::SignNotAllowed
+    );
+    assert_eq!(
+        U256::from_str(&(Natural::ONE << 256u64).to_string())
+            .unwrap_err()
+            .kind(),
+        ParseNumberErrorKind::Overflow
+    );
+    assert_eq!(
+        I256::from_str(&(Integer::ONE << 255u64).to_string())
+            .unwrap_err()
+            .kind(),
+        ParseNumberErrorKind::Overflow
+    );
+    assert_eq!(
+        I256::from_str(&(-(Integer::ONE << 255u64)).to_string()),
+        Ok(I256::MIN)
+    );
+}
+
+#[test]
+fn conversion_properties() {
+    natural_gen().test_properties(|x| {
+        let (a, overflow) = Uint::<2>::overflowing_from(&x);
+        assert_eq!(Uint::<2>::wrapping_from(&x), a);
+        assert_eq!(Uint::<2>::convertible_from(&x), !overflow);
+        assert_eq!(Uint::<2>::try_from(&x).is_ok(), !overflow);
+        if !overflow {
+            assert_eq!(Natural::from(a), x);
+        }
+        assert_eq!(Natural::from(a), x % (Natural::ONE << Uint::<2>::WIDTH));
+        assert_eq!(Uint::<2>::from_str(&a.to_string()), Ok(a));
+        assert_eq!(format!("{a:x}"), format!("{:x}", Natural::from(a)));
+    });
+
+    integer_gen().test_properties(|x| {
+        let (a, overflow) = Int::<2>::overflowing_from(&x);
+        assert_eq!(Int::<2>::wrapping_from(&x), a);
+        assert_eq!(Int::<2>::convertible_from(&x), !overflow);
+        assert_eq!(Integer::from(a) == x, !overflow);
+        let saturated = Int::<2>::saturating_from(&x);
+        if overflow {
+            assert!(saturated == Int::MIN || saturated == Int::MAX);
+        } else {
+            assert_eq!(saturated, a);
+        }
+        assert_eq!(Int::<2>::from_str(&a.to_string()), Ok(a));
+        assert_eq!(Int::wrapping_from(Uint::<2>::wrapping_from(a)), a);
+    });
+}
@@ -0,0 +1,72 @@
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+use malachite_nz::fixed::{Int, Uint, I256, U256};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{
+    integer_pair_gen, integer_unsigned_pair_gen_var_2, natural_pair_gen,
+    natural_unsigned_pair_gen_var_4,
+};
+
+#[test]
+fn test_logic() {
+    assert_eq!(U256::ONE << 300u64, U256::ZERO);
+    assert_eq!(U256::MAX >> 255u64, U256::ONE);
+    assert_eq!(I256::MIN >> 255u64, I256::NEGATIVE_ONE);
+    assert_eq!(I256::MIN >> 1000u64, I256::NEGATIVE_ONE);
+    assert_eq!(!I256::ZERO, I256::NEGATIVE_ONE);
+    assert_eq!(U256::MAX.significant_bits(), 256);
+    assert!(I256::NEGATIVE_ONE.get_bit(1000));
+    let mut x = U256::ZERO;
+    x.set_bit(255);
+    assert_eq!(x, U256::ONE << 255u64);
+    let mut x = I256::MAX;
+    x.set_bit(255);
+    assert_eq!(x, I256::NEGATIVE_ONE);
+}
+
+#[test]
+#[should_panic]
+fn set_bit_fail() {
+    let mut x = U256::ZERO;
+    x.set_bit(256);
+}
+
+#[test]
+fn logic_properties() {
+    natural_pair_gen().test_properties(|(x, y)| {
+        let (a, b) = (Uint::<3>::wrapping_from(&x), Uint::<3>::wrapping_from(&y));
+        let (xn, yn) = (Natural::from(a), Natural::from(b));
+        assert_eq!(Natural::from(a & b), &xn & &yn);
+        assert_eq!(Natural::from(a | b), &xn | &yn);
+        assert_eq!(Natural::from(a ^ b), &xn ^ &yn);
+        assert_eq!(a.significant_bits(), xn.significant_bits());
+    });
+
+    integer_pair_gen().test_properties(|(x, y)| {
+        let (a, b) = (Int::<3>::wrapping_from(&x), Int::<3>::wrapping_from(&y));
+        let (xi, yi) = (Integer::from(a), Integer::from(b));
+        assert_eq!(Integer::from(a & b), &xi & &yi);
+        assert_eq!(Integer::from(a | b), &xi | &yi);
+        assert_eq!(Integer::from(a ^ b), &xi ^ &yi);
+        assert_eq!(Integer::from(!a), !xi);
+    });
+
+    natural_unsigned_pair_gen_var_4::<u64>().test_properties(|(x, bits)| {
+        let a = Uint::<2>::wrapping_from(&x);
+        let xn = Natural::from(a);
+        assert_eq!(Natural::from(a >> bits), &xn >> bits);
+        assert_eq!(Uint::<2>::wrapping_from(&(&xn << bits)), a << bits);
+        assert_eq!(a.get_bit(bits), xn.get_bit(bits));
+    });
+
+    integer_unsigned_pair_gen_var_2::<u64>().test_properties(|(x, bits)| {
+        let a = Int::<2>::wrapping_from(&x);
+        let xi = Integer::from(a);
+        assert_eq!(Integer::from(a >> bits), &xi >> bits);
+        assert_eq!(Int::<2>::wrapping_from(&(&xi << bits)), a << bits);
+        assert_eq!(a.get_bit(bits), xi.get_bit(bits));
+    });
+}
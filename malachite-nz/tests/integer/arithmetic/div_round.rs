@@ -95,6 +95,21 @@ fn test_div_round() {
     test("0", "1", RoundingMode::Up, "0", Ordering::Equal);
     test("0", "1", RoundingMode::Ceiling, "0", Ordering::Equal);
     test("0", "1", RoundingMode::Nearest, "0", Ordering::Equal);
+    test(
+        "0",
+        "1",
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "1",
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Equal,
+    );
+    test("0", "1", RoundingMode::Odd, "0", Ordering::Equal);
     test("0", "1", RoundingMode::Exact, "0", Ordering::Equal);
 
     test("0", "123", RoundingMode::Down, "0", Ordering::Equal);
@@ -102,6 +117,21 @@ fn test_div_round() {
     test("0", "123", RoundingMode::Up, "0", Ordering::Equal);
     test("0", "123", RoundingMode::Ceiling, "0", Ordering::Equal);
     test("0", "123", RoundingMode::Nearest, "0", Ordering::Equal);
+    test(
+        "0",
+        "123",
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "123",
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Equal,
+    );
+    test("0", "123", RoundingMode::Odd, "0", Ordering::Equal);
     test("0", "123", RoundingMode::Exact, "0", Ordering::Equal);
 
     test("1", "1", RoundingMode::Down, "1", Ordering::Equal);
@@ -109,6 +139,21 @@ fn test_div_round() {
     test("1", "1", RoundingMode::Up, "1", Ordering::Equal);
     test("1", "1", RoundingMode::Ceiling, "1", Ordering::Equal);
     test("1", "1", RoundingMode::Nearest, "1", Ordering::Equal);
+    test(
+        "1",
+        "1",
+        RoundingMode::NearestTiesAway,
+        "1",
+        Ordering::Equal,
+    );
+    test(
+        "1",
+        "1",
+        RoundingMode::NearestTiesTowardZero,
+        "1",
+        Ordering::Equal,
+    );
+    test("1", "1", RoundingMode::Odd, "1", Ordering::Equal);
     test("1", "1", RoundingMode::Exact, "1", Ordering::Equal);
 
     test("123", "1", RoundingMode::Down, "123", Ordering::Equal);
@@ -116,6 +161,21 @@ fn test_div_round() {
     test("123", "1", RoundingMode::Up, "123", Ordering::Equal);
     test("123", "1", RoundingMode::Ceiling, "123", Ordering::Equal);
     test("123", "1", RoundingMode::Nearest, "123", Ordering::Equal);
+    test(
+        "123",
+        "1",
+        RoundingMode::NearestTiesAway,
+        "123",
+        Ordering::Equal,
+    );
+    test(
+        "123",
+        "1",
+        RoundingMode::NearestTiesTowardZero,
+        "123",
+        Ordering::Equal,
+    );
+    test("123", "1", RoundingMode::Odd, "123", Ordering::Equal);
     test("123", "1", RoundingMode::Exact, "123", Ordering::Equal);
 
     test("123", "2", RoundingMode::Down, "61", Ordering::Less);
@@ -123,18 +183,63 @@ fn test_div_round() {
     test("123", "2", RoundingMode::Up, "62", Ordering::Greater);
     test("123", "2", RoundingMode::Ceiling, "62", Ordering::Greater);
     test("123", "2", RoundingMode::Nearest, "62", Ordering::Greater);
+    test(
+        "123",
+        "2",
+        RoundingMode::NearestTiesAway,
+        "62",
+        Ordering::Greater,
+    );
+    test(
+        "123",
+        "2",
+        RoundingMode::NearestTiesTowardZero,
+        "61",
+        Ordering::Less,
+    );
+    test("123", "2", RoundingMode::Odd, "61", Ordering::Less);
 
     test("125", "2", RoundingMode::Down, "62", Ordering::Less);
     test("125", "2", RoundingMode::Floor, "62", Ordering::Less);
     test("125", "2", RoundingMode::Up, "63", Ordering::Greater);
     test("125", "2", RoundingMode::Ceiling, "63", Ordering::Greater);
     test("125", "2", RoundingMode::Nearest, "62", Ordering::Less);
+    test(
+        "125",
+        "2",
+        RoundingMode::NearestTiesAway,
+        "63",
+        Ordering::Greater,
+    );
+    test(
+        "125",
+        "2",
+        RoundingMode::NearestTiesTowardZero,
+        "62",
+        Ordering::Less,
+    );
+    test("125", "2", RoundingMode::Odd, "63", Ordering::Greater);
 
     test("123", "123", RoundingMode::Down, "1", Ordering::Equal);
     test("123", "123", RoundingMode::Floor, "1", Ordering::Equal);
     test("123", "123", RoundingMode::Up, "1", Ordering::Equal);
     test("123", "123", RoundingMode::Ceiling, "1", Ordering::Equal);
     test("123", "123", RoundingMode::Nearest, "1", Ordering::Equal);
+    test(
+        "123",
+        "123",
+        RoundingMode::NearestTiesAway,
+        "1",
+        Ordering::Equal,
+    );
+    test(
+        "123",
+        "123",
+        RoundingMode::NearestTiesTowardZero,
+        "1",
+        Ordering::Equal,
+    );
+    test("123", "123", RoundingMode::Odd, "1", Ordering::Equal);
     test("123", "123", RoundingMode::Exact, "1", Ordering::Equal);
 
     test("123", "456", RoundingMode::Down, "0", Ordering::Less);
@@ -142,6 +247,21 @@ fn test_div_round() {
     test("123", "456", RoundingMode::Up, "1", Ordering::Greater);
     test("123", "456", RoundingMode::Ceiling, "1", Ordering::Greater);
     test("123", "456", RoundingMode::Nearest, "0", Ordering::Less);
+    test(
+        "123",
+        "456",
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Less,
+    );
+    test(
+        "123",
+        "456",
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Less,
+    );
+    test("123", "456", RoundingMode::Odd, "1", Ordering::Greater);
 
     test(
         "1000000000000",
@@ -436,6 +556,21 @@ fn test_div_round() {
     test("0", "-1", RoundingMode::Up, "0", Ordering::Equal);
     test("0", "-1", RoundingMode::Ceiling, "0", Ordering::Equal);
     test("0", "-1", RoundingMode::Nearest, "0", Ordering::Equal);
+    test(
+        "0",
+        "-1",
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-1",
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Equal,
+    );
+    test("0", "-1", RoundingMode::Odd, "0", Ordering::Equal);
     test("0", "-1", RoundingMode::Exact, "0", Ordering::Equal);
 
     test("0", "-123", RoundingMode::Down, "0", Ordering::Equal);
@@ -443,6 +578,21 @@ fn test_div_round() {
     test("0", "-123", RoundingMode::Up, "0", Ordering::Equal);
     test("0", "-123", RoundingMode::Ceiling, "0", Ordering::Equal);
     test("0", "-123", RoundingMode::Nearest, "0", Ordering::Equal);
+    test(
+        "0",
+        "-123",
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-123",
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Equal,
+    );
+    test("0", "-123", RoundingMode::Odd, "0", Ordering::Equal);
     test("0", "-123", RoundingMode::Exact, "0", Ordering::Equal);
 
     test("1", "-1", RoundingMode::Down, "-1", Ordering::Equal);
@@ -450,6 +600,21 @@ fn test_div_round() {
     test("1", "-1", RoundingMode::Up, "-1", Ordering::Equal);
     test("1", "-1", RoundingMode::Ceiling, "-1", Ordering::Equal);
     test("1", "-1", RoundingMode::Nearest, "-1", Ordering::Equal);
+    test(
+        "1",
+        "-1",
+        RoundingMode::NearestTiesAway,
+        "-1",
+        Ordering::Equal,
+    );
+    test(
+        "1",
+        "-1",
+        RoundingMode::NearestTiesTowardZero,
+        "-1",
+        Ordering::Equal,
+    );
+    test("1", "-1", RoundingMode::Odd, "-1", Ordering::Equal);
     test("1", "-1", RoundingMode::Exact, "-1", Ordering::Equal);
 
     test("123", "-1", RoundingMode::Down, "-123", Ordering::Equal);
@@ -457,6 +622,21 @@ fn test_div_round() {
     test("123", "-1", RoundingMode::Up, "-123", Ordering::Equal);
     test("123", "-1", RoundingMode::Ceiling, "-123", Ordering::Equal);
     test("123", "-1", RoundingMode::Nearest, "-123", Ordering::Equal);
+    test(
+        "123",
+        "-1",
+        RoundingMode::NearestTiesAway,
+        "-123",
+        Ordering::Equal,
+    );
+    test(
+        "123",
+        "-1",
+        RoundingMode::NearestTiesTowardZero,
+        "-123",
+        Ordering::Equal,
+    );
+    test("123", "-1", RoundingMode::Odd, "-123", Ordering::Equal);
     test("123", "-1", RoundingMode::Exact, "-123", Ordering::Equal);
 
     test("123", "-2", RoundingMode::Down, "-61", Ordering::Greater);
@@ -464,18 +644,63 @@ fn test_div_round() {
     test("123", "-2", RoundingMode::Up, "-62", Ordering::Less);
     test("123", "-2", RoundingMode::Ceiling, "-61", Ordering::Greater);
     test("123", "-2", RoundingMode::Nearest, "-62", Ordering::Less);
+    test(
+        "123",
+        "-2",
+        RoundingMode::NearestTiesAway,
+        "-62",
+        Ordering::Less,
+    );
+    test(
+        "123",
+        "-2",
+        RoundingMode::NearestTiesTowardZero,
+        "-61",
+        Ordering::Greater,
+    );
+    test("123", "-2", RoundingMode::Odd, "-61", Ordering::Greater);
 
     test("125", "-2", RoundingMode::Down, "-62", Ordering::Greater);
     test("125", "-2", RoundingMode::Floor, "-63", Ordering::Less);
     test("125", "-2", RoundingMode::Up, "-63", Ordering::Less);
     test("125", "-2", RoundingMode::Ceiling, "-62", Ordering::Greater);
     test("125", "-2", RoundingMode::Nearest, "-62", Ordering::Greater);
+    test(
+        "125",
+        "-2",
+        RoundingMode::NearestTiesAway,
+        "-63",
+        Ordering::Less,
+    );
+    test(
+        "125",
+        "-2",
+        RoundingMode::NearestTiesTowardZero,
+        "-62",
+        Ordering::Greater,
+    );
+    test("125", "-2", RoundingMode::Odd, "-63", Ordering::Less);
 
     test("123", "-123", RoundingMode::Down, "-1", Ordering::Equal);
     test("123", "-123", RoundingMode::Floor, "-1", Ordering::Equal);
     test("123", "-123", RoundingMode::Up, "-1", Ordering::Equal);
     test("123", "-123", RoundingMode::Ceiling, "-1", Ordering::Equal);
     test("123", "-123", RoundingMode::Nearest, "-1", Ordering::Equal);
+    test(
+        "123",
+        "-123",
+        RoundingMode::NearestTiesAway,
+        "-1",
+        Ordering::Equal,
+    );
+    test(
+        "123",
+        "-123",
+        RoundingMode::NearestTiesTowardZero,
+        "-1",
+        Ordering::Equal,
+    );
+    test("123", "-123", RoundingMode::Odd, "-1", Ordering::Equal);
     test("123", "-123", RoundingMode::Exact, "-1", Ordering::Equal);
 
     test("123", "-456", RoundingMode::Down, "0", Ordering::Greater);
@@ -483,6 +708,21 @@ fn test_div_round() {
     test("123", "-456", RoundingMode::Up, "-1", Ordering::Less);
     test("123", "-456", RoundingMode::Ceiling, "0", Ordering::Greater);
     test("123", "-456", RoundingMode::Nearest, "0", Ordering::Greater);
+    test(
+        "123",
+        "-456",
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Greater,
+    );
+    test(
+        "123",
+        "-456",
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Greater,
+    );
+    test("123", "-456", RoundingMode::Odd, "-1", Ordering::Less);
 
     test(
         "1000000000000",
@@ -777,6 +1017,21 @@ fn test_div_round() {
     test("-1", "1", RoundingMode::Up, "-1", Ordering::Equal);
     test("-1", "1", RoundingMode::Ceiling, "-1", Ordering::Equal);
     test("-1", "1", RoundingMode::Nearest, "-1", Ordering::Equal);
+    test(
+        "-1",
+        "1",
+        RoundingMode::NearestTiesAway,
+        "-1",
+        Ordering::Equal,
+    );
+    test(
+        "-1",
+        "1",
+        RoundingMode::NearestTiesTowardZero,
+        "-1",
+        Ordering::Equal,
+    );
+    test("-1", "1", RoundingMode::Odd, "-1", Ordering::Equal);
     test("-1", "1", RoundingMode::Exact, "-1", Ordering::Equal);
 
     test("-123", "1", RoundingMode::Down, "-123", Ordering::Equal);
@@ -784,6 +1039,21 @@ fn test_div_round() {
     test("-123", "1", RoundingMode::Up, "-123", Ordering::Equal);
     test("-123", "1", RoundingMode::Ceiling, "-123", Ordering::Equal);
     test("-123", "1", RoundingMode::Nearest, "-123", Ordering::Equal);
+    test(
+        "-123",
+        "1",
+        RoundingMode::NearestTiesAway,
+        "-123",
+        Ordering::Equal,
+    );
+    test(
+        "-123",
+        "1",
+        RoundingMode::NearestTiesTowardZero,
+        "-123",
+        Ordering::Equal,
+    );
+    test("-123", "1", RoundingMode::Odd, "-123", Ordering::Equal);
     test("-123", "1", RoundingMode::Exact, "-123", Ordering::Equal);
 
     test("-123", "2", RoundingMode::Down, "-61", Ordering::Greater);
@@ -791,18 +1061,63 @@ fn test_div_round() {
     test("-123", "2", RoundingMode::Up, "-62", Ordering::Less);
     test("-123", "2", RoundingMode::Ceiling, "-61", Ordering::Greater);
     test("-123", "2", RoundingMode::Nearest, "-62", Ordering::Less);
+    test(
+        "-123",
+        "2",
+        RoundingMode::NearestTiesAway,
+        "-62",
+        Ordering::Less,
+    );
+    test(
+        "-123",
+        "2",
+        RoundingMode::NearestTiesTowardZero,
+        "-61",
+        Ordering::Greater,
+    );
+    test("-123", "2", RoundingMode::Odd, "-61", Ordering::Greater);
 
     test("-125", "2", RoundingMode::Down, "-62", Ordering::Greater);
     test("-125", "2", RoundingMode::Floor, "-63", Ordering::Less);
     test("-125", "2", RoundingMode::Up, "-63", Ordering::Less);
     test("-125", "2", RoundingMode::Ceiling, "-62", Ordering::Greater);
     test("-125", "2", RoundingMode::Nearest, "-62", Ordering::Greater);
+    test(
+        "-125",
+        "2",
+        RoundingMode::NearestTiesAway,
+        "-63",
+        Ordering::Less,
+    );
+    test(
+        "-125",
+        "2",
+        RoundingMode::NearestTiesTowardZero,
+        "-62",
+        Ordering::Greater,
+    );
+    test("-125", "2", RoundingMode::Odd, "-63", Ordering::Less);
 
     test("-123", "123", RoundingMode::Down, "-1", Ordering::Equal);
     test("-123", "123", RoundingMode::Floor, "-1", Ordering::Equal);
     test("-123", "123", RoundingMode::Up, "-1", Ordering::Equal);
     test("-123", "123", RoundingMode::Ceiling, "-1", Ordering::Equal);
     test("-123", "123", RoundingMode::Nearest, "-1", Ordering::Equal);
+    test(
+        "-123",
+        "123",
+        RoundingMode::NearestTiesAway,
+        "-1",
+        Ordering::Equal,
+    );
+    test(
+        "-123",
+        "123",
+        RoundingMode::NearestTiesTowardZero,
+        "-1",
+        Ordering::Equal,
+    );
+    test("-123", "123", RoundingMode::Odd, "-1", Ordering::Equal);
     test("-123", "123", RoundingMode::Exact, "-1", Ordering::Equal);
 
     test("-123", "456", RoundingMode::Down, "0", Ordering::Greater);
@@ -810,6 +1125,21 @@ fn test_div_round() {
     test("-123", "456", RoundingMode::Up, "-1", Ordering::Less);
     test("-123", "456", RoundingMode::Ceiling, "0", Ordering::Greater);
     test("-123", "456", RoundingMode::Nearest, "0", Ordering::Greater);
+    test(
+        "-123",
+        "456",
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Greater,
+    );
+    test(
+        "-123",
+        "456",
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Greater,
+    );
+    test("-123", "456", RoundingMode::Odd, "-1", Ordering::Less);
 
     test(
         "-1000000000000",
@@ -1104,6 +1434,21 @@ fn test_div_round() {
     test("-1", "-1", RoundingMode::Up, "1", Ordering::Equal);
     test("-1", "-1", RoundingMode::Ceiling, "1", Ordering::Equal);
     test("-1", "-1", RoundingMode::Nearest, "1", Ordering::Equal);
+    test(
+        "-1",
+        "-1",
+        RoundingMode::NearestTiesAway,
+        "1",
+        Ordering::Equal,
+    );
+    test(
+        "-1",
+        "-1",
+        RoundingMode::NearestTiesTowardZero,
+        "1",
+        Ordering::Equal,
+    );
+    test("-1", "-1", RoundingMode::Odd, "1", Ordering::Equal);
     test("-1", "-1", RoundingMode::Exact, "1", Ordering::Equal);
 
     test("-123", "-1", RoundingMode::Down, "123", Ordering::Equal);
@@ -1111,6 +1456,21 @@ fn test_div_round() {
     test("-123", "-1", RoundingMode::Up, "123", Ordering::Equal);
     test("-123", "-1", RoundingMode::Ceiling, "123", Ordering::Equal);
     test("-123", "-1", RoundingMode::Nearest, "123", Ordering::Equal);
+    test(
+        "-123",
+        "-1",
+        RoundingMode::NearestTiesAway,
+        "123",
+        Ordering::Equal,
+    );
+    test(
+        "-123",
+        "-1",
+        RoundingMode::NearestTiesTowardZero,
+        "123",
+        Ordering::Equal,
+    );
+    test("-123", "-1", RoundingMode::Odd, "123", Ordering::Equal);
     test("-123", "-1", RoundingMode::Exact, "123", Ordering::Equal);
 
     test("-123", "-2", RoundingMode::Down, "61", Ordering::Less);
@@ -1118,18 +1478,63 @@ fn test_div_round() {
     test("-123", "-2", RoundingMode::Up, "62", Ordering::Greater);
     test("-123", "-2", RoundingMode::Ceiling, "62", Ordering::Greater);
     test("-123", "-2", RoundingMode::Nearest, "62", Ordering::Greater);
+    test(
+        "-123",
+        "-2",
+        RoundingMode::NearestTiesAway,
+        "62",
+        Ordering::Greater,
+    );
+    test(
+        "-123",
+        "-2",
+        RoundingMode::NearestTiesTowardZero,
+        "61",
+        Ordering::Less,
+    );
+    test("-123", "-2", RoundingMode::Odd, "61", Ordering::Less);
 
     test("-125", "-2", RoundingMode::Down, "62", Ordering::Less);
     test("-125", "-2", RoundingMode::Floor, "62", Ordering::Less);
     test("-125", "-2", RoundingMode::Up, "63", Ordering::Greater);
     test("-125", "-2", RoundingMode::Ceiling, "63", Ordering::Greater);
     test("-125", "-2", RoundingMode::Nearest, "62", Ordering::Less);
+    test(
+        "-125",
+        "-2",
+        RoundingMode::NearestTiesAway,
+        "63",
+        Ordering::Greater,
+    );
+    test(
+        "-125",
+        "-2",
+        RoundingMode::NearestTiesTowardZero,
+        "62",
+        Ordering::Less,
+    );
+    test("-125", "-2", RoundingMode::Odd, "63", Ordering::Greater);
 
     test("-123", "-123", RoundingMode::Down, "1", Ordering::Equal);
     test("-123", "-123", RoundingMode::Floor, "1", Ordering::Equal);
     test("-123", "-123", RoundingMode::Up, "1", Ordering::Equal);
     test("-123", "-123", RoundingMode::Ceiling, "1", Ordering::Equal);
     test("-123", "-123", RoundingMode::Nearest, "1", Ordering::Equal);
+    test(
+        "-123",
+        "-123",
+        RoundingMode::NearestTiesAway,
+        "1",
+        Ordering::Equal,
+    );
+    test(
+        "-123",
+        "-123",
+        RoundingMode::NearestTiesTowardZero,
+        "1",
+        Ordering::Equal,
+    );
+    test("-123", "-123", RoundingMode::Odd, "1", Ordering::Equal);
     test("-123", "-123", RoundingMode::Exact, "1", Ordering::Equal);
 
     test("-123", "-456", RoundingMode::Down, "0", Ordering::Less);
@@ -1143,6 +1548,21 @@ fn test_div_round() {
         Ordering::Greater,
     );
     test("-123", "-456", RoundingMode::Nearest, "0", Ordering::Less);
+    test(
+        "-123",
+        "-456",
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Less,
+    );
+    test(
+        "-123",
+        "-456",
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Less,
+    );
+    test("-123", "-456", RoundingMode::Odd, "1", Ordering::Greater);
 
     test(
         "-1000000000000",
@@ -0,0 +1,87 @@
+use malachite_base::num::arithmetic::traits::UnsignedAbs;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::float::NiceFloat;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_pair_gen_var_1;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+#[test]
+fn test_div_to_f64_round() {
+    let test = |s, t, rm, out: f64, o| {
+        let x = Integer::from_str(s).unwrap();
+        let y = Integer::from_str(t).unwrap();
+        let (f, o_alt) = x.div_to_f64_round(&y, rm);
+        assert_eq!(NiceFloat(f), NiceFloat(out));
+        assert_eq!(o_alt, o);
+    };
+    test("0", "-1", RoundingMode::Exact, 0.0, Ordering::Equal);
+    test("-3", "4", RoundingMode::Exact, -0.75, Ordering::Equal);
+    test("-3", "-4", RoundingMode::Exact, 0.75, Ordering::Equal);
+    test(
+        "1",
+        "-3",
+        RoundingMode::Floor,
+        -0.33333333333333337,
+        Ordering::Less,
+    );
+    test(
+        "1",
+        "-3",
+        RoundingMode::Ceiling,
+        -0.3333333333333333,
+        Ordering::Greater,
+    );
+    test(
+        "-1",
+        "-3",
+        RoundingMode::Ceiling,
+        0.33333333333333337,
+        Ordering::Greater,
+    );
+    test(
+        "-2",
+        "3",
+        RoundingMode::Down,
+        -0.6666666666666666,
+        Ordering::Greater,
+    );
+    test(
+        "-2",
+        "3",
+        RoundingMode::Up,
+        -0.6666666666666667,
+        Ordering::Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn div_to_f64_round_fail() {
+    Integer::ONE.div_to_f64_round(&Integer::from(0), RoundingMode::Floor);
+}
+
+#[test]
+fn div_to_primitive_float_round_properties() {
+    integer_pair_gen_var_1().test_properties(|(x, y)| {
+        for rm in exhaustive_rounding_modes() {
+            if rm == RoundingMode::Exact {
+                continue;
+            }
+            let (f, o) = x.div_to_f64_round(&y, rm);
+            let (f_alt, o_alt) = (-&x).div_to_f64_round(&y, -rm);
+            assert_eq!(NiceFloat(f_alt), NiceFloat(if x == 0 { f } else { -f }));
+            assert_eq!(o_alt, if x == 0 { o } else { o.reverse() });
+            assert_eq!(NiceFloat(x.div_to_f64_round(&-&y, -rm).0), NiceFloat(f_alt));
+            if x >= 0 && y > 0 {
+                let (f_alt, o_alt) = (&x)
+                    .unsigned_abs()
+                    .div_to_f64_round(&(&y).unsigned_abs(), rm);
+                assert_eq!(NiceFloat(f_alt), NiceFloat(f));
+                assert_eq!(o_alt, o);
+            }
+        }
+    });
+}
@@ -0,0 +1,141 @@
+use malachite_base::num::arithmetic::traits::{RemoveFactor, RemoveFactorAssign, Valuation};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::strings::ToDebugString;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_pair_gen_var_3;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_remove_factor() {
+    let test = |s, t, out| {
+        let x = Integer::from_str(s).unwrap();
+        let p = Natural::from_str(t).unwrap();
+
+        let result = x.clone().remove_factor(p.clone());
+        assert!(result.0.is_valid());
+        assert_eq!(result.to_debug_string(), out);
+
+        let result = x.clone().remove_factor(&p);
+        assert!(result.0.is_valid());
+        assert_eq!(result.to_debug_string(), out);
+
+        let result = (&x).remove_factor(p.clone());
+        assert!(result.0.is_valid());
+        assert_eq!(result.to_debug_string(), out);
+
+        let result = (&x).remove_factor(&p);
+        assert!(result.0.is_valid());
+        assert_eq!(result.to_debug_string(), out);
+
+        let mut mut_x = x.clone();
+        let multiplicity = mut_x.remove_factor_assign(p.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!((mut_x, multiplicity).to_debug_string(), out);
+
+        let mut mut_x = x;
+        let multiplicity = mut_x.remove_factor_assign(&p);
+        assert!(mut_x.is_valid());
+        assert_eq!((mut_x, multiplicity).to_debug_string(), out);
+    };
+    test("1", "2", "(1, 0)");
+    test("-1", "2", "(-1, 0)");
+    test("360", "2", "(45, 3)");
+    test("-360", "2", "(-45, 3)");
+    test("-360", "6", "(-10, 2)");
+    test("-360", "7", "(-360, 0)");
+    test("-1000000000000", "10", "(-1, 12)");
+    test("-340282366920938463463374607431768211456", "2", "(-1, 128)");
+}
+
+#[test]
+fn remove_factor_fail() {
+    assert_panic!(Integer::ZERO.remove_factor(Natural::from(3u32)));
+    assert_panic!(Integer::from(-3).remove_factor(Natural::ONE));
+    assert_panic!(Integer::ZERO.remove_factor(&Natural::from(3u32)));
+    assert_panic!(Integer::from(-3).remove_factor(&Natural::ONE));
+    assert_panic!((&Integer::ZERO).remove_factor(Natural::from(3u32)));
+    assert_panic!((&Integer::from(-3)).remove_factor(Natural::ONE));
+    assert_panic!((&Integer::ZERO).remove_factor(&Natural::from(3u32)));
+    assert_panic!((&Integer::from(-3)).remove_factor(&Natural::ONE));
+    assert_panic!({
+        let mut x = Integer::ZERO;
+        x.remove_factor_assign(Natural::from(3u32))
+    });
+    assert_panic!({
+        let mut x = Integer::from(-3);
+        x.remove_factor_assign(&Natural::ONE)
+    });
+}
+
+#[test]
+fn test_valuation() {
+    let test = |s, t, out| {
+        let x = Integer::from_str(s).unwrap();
+        let p = Natural::from_str(t).unwrap();
+
+        assert_eq!(x.clone().valuation(p.clone()), out);
+        assert_eq!(x.clone().valuation(&p), out);
+        assert_eq!((&x).valuation(p.clone()), out);
+        assert_eq!((&x).valuation(&p), out);
+    };
+    test("1", "2", 0);
+    test("-360", "2", 3);
+    test("-360", "3", 2);
+    test("360", "7", 0);
+    test("-1000000000000", "10", 12);
+}
+
+#[test]
+fn valuation_fail() {
+    assert_panic!(Integer::ZERO.valuation(Natural::from(3u32)));
+    assert_panic!(Integer::from(-3).valuation(Natural::ONE));
+    assert_panic!(Integer::ZERO.valuation(&Natural::from(3u32)));
+    assert_panic!(Integer::from(-3).valuation(&Natural::ONE));
+    assert_panic!((&Integer::ZERO).valuation(Natural::from(3u32)));
+    assert_panic!((&Integer::from(-3)).valuation(Natural::ONE));
+    assert_panic!((&Integer::ZERO).valuation(&Natural::from(3u32)));
+    assert_panic!((&Integer::from(-3)).valuation(&Natural::ONE));
+}
+
+#[test]
+fn remove_factor_properties() {
+    natural_pair_gen_var_3().test_properties(|(n, p)| {
+        for x in [Integer::from(&n), -Integer::from(&n)] {
+            let (stripped, multiplicity) = (&x).remove_factor(&p);
+            assert!(stripped.is_valid());
+
+            let (stripped_alt, multiplicity_alt) = x.clone().remove_factor(p.clone());
+            assert!(stripped_alt.is_valid());
+            assert_eq!(stripped_alt, stripped);
+            assert_eq!(multiplicity_alt, multiplicity);
+
+            let (stripped_alt, multiplicity_alt) = x.clone().remove_factor(&p);
+            assert!(stripped_alt.is_valid());
+            assert_eq!(stripped_alt, stripped);
+            assert_eq!(multiplicity_alt, multiplicity);
+
+            let (stripped_alt, multiplicity_alt) = (&x).remove_factor(p.clone());
+            assert!(stripped_alt.is_valid());
+            assert_eq!(stripped_alt, stripped);
+            assert_eq!(multiplicity_alt, multiplicity);
+
+            let mut mut_x = x.clone();
+            assert_eq!(mut_x.remove_factor_assign(&p), multiplicity);
+            assert!(mut_x.is_valid());
+            assert_eq!(mut_x, stripped);
+
+            let mut mut_x = x.clone();
+            assert_eq!(mut_x.remove_factor_assign(p.clone()), multiplicity);
+            assert!(mut_x.is_valid());
+            assert_eq!(mut_x, stripped);
+
+            assert_eq!((&x).valuation(&p), multiplicity);
+            assert_eq!(stripped < 0, x < 0);
+            let (stripped_alt, multiplicity_alt) = (&n).remove_factor(&p);
+            assert_eq!(stripped.unsigned_abs_ref(), &stripped_alt);
+            assert_eq!(multiplicity_alt, multiplicity);
+        }
+    });
+}
@@ -47,6 +47,15 @@ fn test_round_to_multiple_of_power_of_2() {
     test("0", 0, RoundingMode::Floor, "0", Ordering::Equal);
     test("0", 0, RoundingMode::Ceiling, "0", Ordering::Equal);
     test("0", 0, RoundingMode::Nearest, "0", Ordering::Equal);
+    test("0", 0, RoundingMode::NearestTiesAway, "0", Ordering::Equal);
+    test(
+        "0",
+        0,
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Equal,
+    );
+    test("0", 0, RoundingMode::Odd, "0", Ordering::Equal);
     test("0", 0, RoundingMode::Exact, "0", Ordering::Equal);
 
     test("0", 10, RoundingMode::Down, "0", Ordering::Equal);
@@ -54,6 +63,15 @@ fn test_round_to_multiple_of_power_of_2() {
     test("0", 10, RoundingMode::Floor, "0", Ordering::Equal);
     test("0", 10, RoundingMode::Ceiling, "0", Ordering::Equal);
     test("0", 10, RoundingMode::Nearest, "0", Ordering::Equal);
+    test("0", 10, RoundingMode::NearestTiesAway, "0", Ordering::Equal);
+    test(
+        "0",
+        10,
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Equal,
+    );
+    test("0", 10, RoundingMode::Odd, "0", Ordering::Equal);
     test("0", 10, RoundingMode::Exact, "0", Ordering::Equal);
 
     test("123", 0, RoundingMode::Down, "123", Ordering::Equal);
@@ -61,6 +79,21 @@ fn test_round_to_multiple_of_power_of_2() {
     test("123", 0, RoundingMode::Floor, "123", Ordering::Equal);
     test("123", 0, RoundingMode::Ceiling, "123", Ordering::Equal);
     test("123", 0, RoundingMode::Nearest, "123", Ordering::Equal);
+    test(
+        "123",
+        0,
+        RoundingMode::NearestTiesAway,
+        "123",
+        Ordering::Equal,
+    );
+    test(
+        "123",
+        0,
+        RoundingMode::NearestTiesTowardZero,
+        "123",
+        Ordering::Equal,
+    );
+    test("123", 0, RoundingMode::Odd, "123", Ordering::Equal);
     test("123", 0, RoundingMode::Exact, "123", Ordering::Equal);
 
     test("245", 1, RoundingMode::Down, "244", Ordering::Less);
@@ -68,12 +101,42 @@ fn test_round_to_multiple_of_power_of_2() {
     test("245", 1, RoundingMode::Floor, "244", Ordering::Less);
     test("245", 1, RoundingMode::Ceiling, "246", Ordering::Greater);
     test("245", 1, RoundingMode::Nearest, "244", Ordering::Less);
+    test(
+        "245",
+        1,
+        RoundingMode::NearestTiesAway,
+        "246",
+        Ordering::Greater,
+    );
+    test(
+        "245",
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        "244",
+        Ordering::Less,
+    );
+    test("245", 1, RoundingMode::Odd, "246", Ordering::Greater);
 
     test("246", 1, RoundingMode::Down, "246", Ordering::Equal);
     test("246", 1, RoundingMode::Up, "246", Ordering::Equal);
     test("246", 1, RoundingMode::Floor, "246", Ordering::Equal);
     test("246", 1, RoundingMode::Ceiling, "246", Ordering::Equal);
     test("246", 1, RoundingMode::Nearest, "246", Ordering::Equal);
+    test(
+        "246",
+        1,
+        RoundingMode::NearestTiesAway,
+        "246",
+        Ordering::Equal,
+    );
+    test(
+        "246",
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        "246",
+        Ordering::Equal,
+    );
+    test("246", 1, RoundingMode::Odd, "246", Ordering::Equal);
     test("246", 1, RoundingMode::Exact, "246", Ordering::Equal);
 
     test("247", 1, RoundingMode::Down, "246", Ordering::Less);
@@ -81,18 +144,63 @@ fn test_round_to_multiple_of_power_of_2() {
     test("247", 1, RoundingMode::Floor, "246", Ordering::Less);
     test("247", 1, RoundingMode::Ceiling, "248", Ordering::Greater);
     test("247", 1, RoundingMode::Nearest, "248", Ordering::Greater);
+    test(
+        "247",
+        1,
+        RoundingMode::NearestTiesAway,
+        "248",
+        Ordering::Greater,
+    );
+    test(
+        "247",
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        "246",
+        Ordering::Less,
+    );
+    test("247", 1, RoundingMode::Odd, "246", Ordering::Less);
 
     test("491", 2, RoundingMode::Down, "488", Ordering::Less);
     test("491", 2, RoundingMode::Up, "492", Ordering::Greater);
     test("491", 2, RoundingMode::Floor, "488", Ordering::Less);
     test("491", 2, RoundingMode::Ceiling, "492", Ordering::Greater);
     test("491", 2, RoundingMode::Nearest, "492", Ordering::Greater);
+    test(
+        "491",
+        2,
+        RoundingMode::NearestTiesAway,
+        "492",
+        Ordering::Greater,
+    );
+    test(
+        "491",
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        "492",
+        Ordering::Greater,
+    );
+    test("491", 2, RoundingMode::Odd, "492", Ordering::Greater);
 
     test("492", 2, RoundingMode::Down, "492", Ordering::Equal);
     test("492", 2, RoundingMode::Up, "492", Ordering::Equal);
     test("492", 2, RoundingMode::Floor, "492", Ordering::Equal);
     test("492", 2, RoundingMode::Ceiling, "492", Ordering::Equal);
     test("492", 2, RoundingMode::Nearest, "492", Ordering::Equal);
+    test(
+        "492",
+        2,
+        RoundingMode::NearestTiesAway,
+        "492",
+        Ordering::Equal,
+    );
+    test(
+        "492",
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        "492",
+        Ordering::Equal,
+    );
+    test("492", 2, RoundingMode::Odd, "492", Ordering::Equal);
     test("492", 2, RoundingMode::Exact, "492", Ordering::Equal);
 
     test("493", 2, RoundingMode::Down, "492", Ordering::Less);
@@ -100,6 +208,21 @@ fn test_round_to_multiple_of_power_of_2() {
     test("493", 2, RoundingMode::Floor, "492", Ordering::Less);
     test("493", 2, RoundingMode::Ceiling, "496", Ordering::Greater);
     test("493", 2, RoundingMode::Nearest, "492", Ordering::Less);
+    test(
+        "493",
+        2,
+        RoundingMode::NearestTiesAway,
+        "492",
+        Ordering::Less,
+    );
+    test(
+        "493",
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        "492",
+        Ordering::Less,
+    );
+    test("493", 2, RoundingMode::Odd, "492", Ordering::Less);
 
     test(
         "4127195135",
@@ -1036,6 +1159,27 @@ fn test_round_to_multiple_of_power_of_2() {
         Ordering::Greater,
     );
     test("980657949", 72, RoundingMode::Nearest, "0", Ordering::Less);
+    test(
+        "980657949",
+        72,
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Less,
+    );
+    test(
+        "980657949",
+        72,
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Less,
+    );
+    test(
+        "980657949",
+        72,
+        RoundingMode::Odd,
+        "4722366482869645213696",
+        Ordering::Greater,
+    );
 
     test(
         "4294967295",
@@ -1157,12 +1301,48 @@ fn test_round_to_multiple_of_power_of_2() {
         Ordering::Greater,
     );
     test("4294967296", 33, RoundingMode::Nearest, "0", Ordering::Less);
+    test(
+        "4294967296",
+        33,
+        RoundingMode::NearestTiesAway,
+        "8589934592",
+        Ordering::Greater,
+    );
+    test(
+        "4294967296",
+        33,
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Less,
+    );
+    test(
+        "4294967296",
+        33,
+        RoundingMode::Odd,
+        "8589934592",
+        Ordering::Greater,
+    );
 
     test("-123", 0, RoundingMode::Down, "-123", Ordering::Equal);
     test("-123", 0, RoundingMode::Up, "-123", Ordering::Equal);
     test("-123", 0, RoundingMode::Floor, "-123", Ordering::Equal);
     test("-123", 0, RoundingMode::Ceiling, "-123", Ordering::Equal);
     test("-123", 0, RoundingMode::Nearest, "-123", Ordering::Equal);
+    test(
+        "-123",
+        0,
+        RoundingMode::NearestTiesAway,
+        "-123",
+        Ordering::Equal,
+    );
+    test(
+        "-123",
+        0,
+        RoundingMode::NearestTiesTowardZero,
+        "-123",
+        Ordering::Equal,
+    );
+    test("-123", 0, RoundingMode::Odd, "-123", Ordering::Equal);
     test("-123", 0, RoundingMode::Exact, "-123", Ordering::Equal);
 
     test("-245", 1, RoundingMode::Down, "-244", Ordering::Greater);
@@ -1170,12 +1350,42 @@ fn test_round_to_multiple_of_power_of_2() {
     test("-245", 1, RoundingMode::Floor, "-246", Ordering::Less);
     test("-245", 1, RoundingMode::Ceiling, "-244", Ordering::Greater);
     test("-245", 1, RoundingMode::Nearest, "-244", Ordering::Greater);
+    test(
+        "-245",
+        1,
+        RoundingMode::NearestTiesAway,
+        "-246",
+        Ordering::Less,
+    );
+    test(
+        "-245",
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        "-244",
+        Ordering::Greater,
+    );
+    test("-245", 1, RoundingMode::Odd, "-246", Ordering::Less);
 
     test("-246", 1, RoundingMode::Down, "-246", Ordering::Equal);
     test("-246", 1, RoundingMode::Up, "-246", Ordering::Equal);
     test("-246", 1, RoundingMode::Floor, "-246", Ordering::Equal);
     test("-246", 1, RoundingMode::Ceiling, "-246", Ordering::Equal);
     test("-246", 1, RoundingMode::Nearest, "-246", Ordering::Equal);
+    test(
+        "-246",
+        1,
+        RoundingMode::NearestTiesAway,
+        "-246",
+        Ordering::Equal,
+    );
+    test(
+        "-246",
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        "-246",
+        Ordering::Equal,
+    );
+    test("-246", 1, RoundingMode::Odd, "-246", Ordering::Equal);
     test("-246", 1, RoundingMode::Exact, "-246", Ordering::Equal);
 
     test("-247", 1, RoundingMode::Down, "-246", Ordering::Greater);
@@ -1183,18 +1393,63 @@ fn test_round_to_multiple_of_power_of_2() {
     test("-247", 1, RoundingMode::Floor, "-248", Ordering::Less);
     test("-247", 1, RoundingMode::Ceiling, "-246", Ordering::Greater);
     test("-247", 1, RoundingMode::Nearest, "-248", Ordering::Less);
+    test(
+        "-247",
+        1,
+        RoundingMode::NearestTiesAway,
+        "-248",
+        Ordering::Less,
+    );
+    test(
+        "-247",
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        "-246",
+        Ordering::Greater,
+    );
+    test("-247", 1, RoundingMode::Odd, "-246", Ordering::Greater);
 
     test("-491", 2, RoundingMode::Down, "-488", Ordering::Greater);
     test("-491", 2, RoundingMode::Up, "-492", Ordering::Less);
     test("-491", 2, RoundingMode::Floor, "-492", Ordering::Less);
     test("-491", 2, RoundingMode::Ceiling, "-488", Ordering::Greater);
     test("-491", 2, RoundingMode::Nearest, "-492", Ordering::Less);
+    test(
+        "-491",
+        2,
+        RoundingMode::NearestTiesAway,
+        "-492",
+        Ordering::Less,
+    );
+    test(
+        "-491",
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        "-492",
+        Ordering::Less,
+    );
+    test("-491", 2, RoundingMode::Odd, "-492", Ordering::Less);
 
     test("-492", 2, RoundingMode::Down, "-492", Ordering::Equal);
     test("-492", 2, RoundingMode::Up, "-492", Ordering::Equal);
     test("-492", 2, RoundingMode::Floor, "-492", Ordering::Equal);
     test("-492", 2, RoundingMode::Ceiling, "-492", Ordering::Equal);
     test("-492", 2, RoundingMode::Nearest, "-492", Ordering::Equal);
+    test(
+        "-492",
+        2,
+        RoundingMode::NearestTiesAway,
+        "-492",
+        Ordering::Equal,
+    );
+    test(
+        "-492",
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        "-492",
+        Ordering::Equal,
+    );
+    test("-492", 2, RoundingMode::Odd, "-492", Ordering::Equal);
     test("-492", 2, RoundingMode::Exact, "-492", Ordering::Equal);
 
     test("-493", 2, RoundingMode::Down, "-492", Ordering::Greater);
@@ -1202,6 +1457,21 @@ fn test_round_to_multiple_of_power_of_2() {
     test("-493", 2, RoundingMode::Floor, "-496", Ordering::Less);
     test("-493", 2, RoundingMode::Ceiling, "-492", Ordering::Greater);
     test("-493", 2, RoundingMode::Nearest, "-492", Ordering::Greater);
+    test(
+        "-493",
+        2,
+        RoundingMode::NearestTiesAway,
+        "-492",
+        Ordering::Greater,
+    );
+    test(
+        "-493",
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        "-492",
+        Ordering::Greater,
+    );
+    test("-493", 2, RoundingMode::Odd, "-492", Ordering::Greater);
 
     test(
         "-4127195135",
@@ -2434,20 +2704,27 @@ fn round_to_multiple_of_power_of_2_properties() {
             RoundingMode::Down => assert!(r.le_abs(&n)),
             RoundingMode::Up => assert!(r.ge_abs(&n)),
             RoundingMode::Exact => assert_eq!(r, n),
-            RoundingMode::Nearest => {
+            RoundingMode::Odd => assert!(r == n || r.get_bit(pow)),
+            RoundingMode::Nearest
+            | RoundingMode::NearestTiesAway
+            | RoundingMode::NearestTiesTowardZero => {
                 let k = Integer::power_of_2(pow);
                 let closest;
                 let second_closest;
                 if r <= n {
                     closest = &n - &r;
-                    second_closest = &r + k - n;
+                    second_closest = &r + k - &n;
                 } else {
                     closest = &r - &n;
-                    second_closest = n + k - &r;
+                    second_closest = &n + k - &r;
                 }
                 assert!(closest <= second_closest);
                 if closest == second_closest {
-                    assert!(!r.get_bit(pow));
+                    match rm {
+                        RoundingMode::Nearest => assert!(!r.get_bit(pow)),
+                        RoundingMode::NearestTiesAway => assert!(r.ge_abs(&n)),
+                        _ => assert!(r.le_abs(&n)),
+                    }
                 }
             }
         }
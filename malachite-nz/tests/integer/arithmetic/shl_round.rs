@@ -206,6 +206,15 @@ macro_rules! test_shl_round_signed_helper {
         test("0", 0, RoundingMode::Floor, "0", Ordering::Equal);
         test("0", 0, RoundingMode::Ceiling, "0", Ordering::Equal);
         test("0", 0, RoundingMode::Nearest, "0", Ordering::Equal);
+        test("0", 0, RoundingMode::NearestTiesAway, "0", Ordering::Equal);
+        test(
+            "0",
+            0,
+            RoundingMode::NearestTiesTowardZero,
+            "0",
+            Ordering::Equal,
+        );
+        test("0", 0, RoundingMode::Odd, "0", Ordering::Equal);
         test("0", 0, RoundingMode::Exact, "0", Ordering::Equal);
 
         test("0", -10, RoundingMode::Down, "0", Ordering::Equal);
@@ -213,6 +222,21 @@ macro_rules! test_shl_round_signed_helper {
         test("0", -10, RoundingMode::Floor, "0", Ordering::Equal);
         test("0", -10, RoundingMode::Ceiling, "0", Ordering::Equal);
         test("0", -10, RoundingMode::Nearest, "0", Ordering::Equal);
+        test(
+            "0",
+            -10,
+            RoundingMode::NearestTiesAway,
+            "0",
+            Ordering::Equal,
+        );
+        test(
+            "0",
+            -10,
+            RoundingMode::NearestTiesTowardZero,
+            "0",
+            Ordering::Equal,
+        );
+        test("0", -10, RoundingMode::Odd, "0", Ordering::Equal);
         test("0", -10, RoundingMode::Exact, "0", Ordering::Equal);
 
         test("123", 0, RoundingMode::Down, "123", Ordering::Equal);
@@ -220,6 +244,21 @@ macro_rules! test_shl_round_signed_helper {
         test("123", 0, RoundingMode::Floor, "123", Ordering::Equal);
         test("123", 0, RoundingMode::Ceiling, "123", Ordering::Equal);
         test("123", 0, RoundingMode::Nearest, "123", Ordering::Equal);
+        test(
+            "123",
+            0,
+            RoundingMode::NearestTiesAway,
+            "123",
+            Ordering::Equal,
+        );
+        test(
+            "123",
+            0,
+            RoundingMode::NearestTiesTowardZero,
+            "123",
+            Ordering::Equal,
+        );
+        test("123", 0, RoundingMode::Odd, "123", Ordering::Equal);
         test("123", 0, RoundingMode::Exact, "123", Ordering::Equal);
 
         test("245", -1, RoundingMode::Down, "122", Ordering::Less);
@@ -227,12 +266,42 @@ macro_rules! test_shl_round_signed_helper {
         test("245", -1, RoundingMode::Floor, "122", Ordering::Less);
         test("245", -1, RoundingMode::Ceiling, "123", Ordering::Greater);
         test("245", -1, RoundingMode::Nearest, "122", Ordering::Less);
+        test(
+            "245",
+            -1,
+            RoundingMode::NearestTiesAway,
+            "123",
+            Ordering::Greater,
+        );
+        test(
+            "245",
+            -1,
+            RoundingMode::NearestTiesTowardZero,
+            "122",
+            Ordering::Less,
+        );
+        test("245", -1, RoundingMode::Odd, "123", Ordering::Greater);
 
         test("246", -1, RoundingMode::Down, "123", Ordering::Equal);
         test("246", -1, RoundingMode::Up, "123", Ordering::Equal);
         test("246", -1, RoundingMode::Floor, "123", Ordering::Equal);
         test("246", -1, RoundingMode::Ceiling, "123", Ordering::Equal);
         test("246", -1, RoundingMode::Nearest, "123", Ordering::Equal);
+        test(
+            "246",
+            -1,
+            RoundingMode::NearestTiesAway,
+            "123",
+            Ordering::Equal,
+        );
+        test(
+            "246",
+            -1,
+            RoundingMode::NearestTiesTowardZero,
+            "123",
+            Ordering::Equal,
+        );
+        test("246", -1, RoundingMode::Odd, "123", Ordering::Equal);
         test("246", -1, RoundingMode::Exact, "123", Ordering::Equal);
 
         test("247", -1, RoundingMode::Down, "123", Ordering::Less);
@@ -240,18 +309,63 @@ macro_rules! test_shl_round_signed_helper {
         test("247", -1, RoundingMode::Floor, "123", Ordering::Less);
         test("247", -1, RoundingMode::Ceiling, "124", Ordering::Greater);
         test("247", -1, RoundingMode::Nearest, "124", Ordering::Greater);
+        test(
+            "247",
+            -1,
+            RoundingMode::NearestTiesAway,
+            "124",
+            Ordering::Greater,
+        );
+        test(
+            "247",
+            -1,
+            RoundingMode::NearestTiesTowardZero,
+            "123",
+            Ordering::Less,
+        );
+        test("247", -1, RoundingMode::Odd, "123", Ordering::Less);
 
         test("491", -2, RoundingMode::Down, "122", Ordering::Less);
         test("491", -2, RoundingMode::Up, "123", Ordering::Greater);
         test("491", -2, RoundingMode::Floor, "122", Ordering::Less);
         test("491", -2, RoundingMode::Ceiling, "123", Ordering::Greater);
         test("491", -2, RoundingMode::Nearest, "123", Ordering::Greater);
+        test(
+            "491",
+            -2,
+            RoundingMode::NearestTiesAway,
+            "123",
+            Ordering::Greater,
+        );
+        test(
+            "491",
+            -2,
+            RoundingMode::NearestTiesTowardZero,
+            "123",
+            Ordering::Greater,
+        );
+        test("491", -2, RoundingMode::Odd, "123", Ordering::Greater);
 
         test("492", -2, RoundingMode::Down, "123", Ordering::Equal);
         test("492", -2, RoundingMode::Up, "123", Ordering::Equal);
         test("492", -2, RoundingMode::Floor, "123", Ordering::Equal);
         test("492", -2, RoundingMode::Ceiling, "123", Ordering::Equal);
         test("492", -2, RoundingMode::Nearest, "123", Ordering::Equal);
+        test(
+            "492",
+            -2,
+            RoundingMode::NearestTiesAway,
+            "123",
+            Ordering::Equal,
+        );
+        test(
+            "492",
+            -2,
+            RoundingMode::NearestTiesTowardZero,
+            "123",
+            Ordering::Equal,
+        );
+        test("492", -2, RoundingMode::Odd, "123", Ordering::Equal);
         test("492", -2, RoundingMode::Exact, "123", Ordering::Equal);
 
         test("493", -2, RoundingMode::Down, "123", Ordering::Less);
@@ -259,6 +373,21 @@ macro_rules! test_shl_round_signed_helper {
         test("493", -2, RoundingMode::Floor, "123", Ordering::Less);
         test("493", -2, RoundingMode::Ceiling, "124", Ordering::Greater);
         test("493", -2, RoundingMode::Nearest, "123", Ordering::Less);
+        test(
+            "493",
+            -2,
+            RoundingMode::NearestTiesAway,
+            "123",
+            Ordering::Less,
+        );
+        test(
+            "493",
+            -2,
+            RoundingMode::NearestTiesTowardZero,
+            "123",
+            Ordering::Less,
+        );
+        test("493", -2, RoundingMode::Odd, "123", Ordering::Less);
 
         test("4127195135", -25, RoundingMode::Down, "122", Ordering::Less);
         test(
@@ -1154,6 +1283,21 @@ macro_rules! test_shl_round_signed_helper {
             Ordering::Greater,
         );
         test("980657949", -72, RoundingMode::Nearest, "0", Ordering::Less);
+        test(
+            "980657949",
+            -72,
+            RoundingMode::NearestTiesAway,
+            "0",
+            Ordering::Less,
+        );
+        test(
+            "980657949",
+            -72,
+            RoundingMode::NearestTiesTowardZero,
+            "0",
+            Ordering::Less,
+        );
+        test("980657949", -72, RoundingMode::Odd, "1", Ordering::Greater);
 
         test("4294967295", -31, RoundingMode::Down, "1", Ordering::Less);
         test("4294967295", -31, RoundingMode::Up, "2", Ordering::Greater);
@@ -1233,6 +1377,21 @@ macro_rules! test_shl_round_signed_helper {
         test("-123", 0, RoundingMode::Floor, "-123", Ordering::Equal);
         test("-123", 0, RoundingMode::Ceiling, "-123", Ordering::Equal);
         test("-123", 0, RoundingMode::Nearest, "-123", Ordering::Equal);
+        test(
+            "-123",
+            0,
+            RoundingMode::NearestTiesAway,
+            "-123",
+            Ordering::Equal,
+        );
+        test(
+            "-123",
+            0,
+            RoundingMode::NearestTiesTowardZero,
+            "-123",
+            Ordering::Equal,
+        );
+        test("-123", 0, RoundingMode::Odd, "-123", Ordering::Equal);
         test("-123", 0, RoundingMode::Exact, "-123", Ordering::Equal);
 
         test("-245", -1, RoundingMode::Down, "-122", Ordering::Greater);
@@ -1240,12 +1399,42 @@ macro_rules! test_shl_round_signed_helper {
         test("-245", -1, RoundingMode::Floor, "-123", Ordering::Less);
         test("-245", -1, RoundingMode::Ceiling, "-122", Ordering::Greater);
         test("-245", -1, RoundingMode::Nearest, "-122", Ordering::Greater);
+        test(
+            "-245",
+            -1,
+            RoundingMode::NearestTiesAway,
+            "-123",
+            Ordering::Less,
+        );
+        test(
+            "-245",
+            -1,
+            RoundingMode::NearestTiesTowardZero,
+            "-122",
+            Ordering::Greater,
+        );
+        test("-245", -1, RoundingMode::Odd, "-123", Ordering::Less);
 
         test("-246", -1, RoundingMode::Down, "-123", Ordering::Equal);
         test("-246", -1, RoundingMode::Up, "-123", Ordering::Equal);
         test("-246", -1, RoundingMode::Floor, "-123", Ordering::Equal);
         test("-246", -1, RoundingMode::Ceiling, "-123", Ordering::Equal);
         test("-246", -1, RoundingMode::Nearest, "-123", Ordering::Equal);
+        test(
+            "-246",
+            -1,
+            RoundingMode::NearestTiesAway,
+            "-123",
+            Ordering::Equal,
+        );
+        test(
+            "-246",
+            -1,
+            RoundingMode::NearestTiesTowardZero,
+            "-123",
+            Ordering::Equal,
+        );
+        test("-246", -1, RoundingMode::Odd, "-123", Ordering::Equal);
         test("-246", -1, RoundingMode::Exact, "-123", Ordering::Equal);
 
         test("-247", -1, RoundingMode::Down, "-123", Ordering::Greater);
@@ -1253,18 +1442,63 @@ macro_rules! test_shl_round_signed_helper {
         test("-247", -1, RoundingMode::Floor, "-124", Ordering::Less);
         test("-247", -1, RoundingMode::Ceiling, "-123", Ordering::Greater);
         test("-247", -1, RoundingMode::Nearest, "-124", Ordering::Less);
+        test(
+            "-247",
+            -1,
+            RoundingMode::NearestTiesAway,
+            "-124",
+            Ordering::Less,
+        );
+        test(
+            "-247",
+            -1,
+            RoundingMode::NearestTiesTowardZero,
+            "-123",
+            Ordering::Greater,
+        );
+        test("-247", -1, RoundingMode::Odd, "-123", Ordering::Greater);
 
         test("-491", -2, RoundingMode::Down, "-122", Ordering::Greater);
         test("-491", -2, RoundingMode::Up, "-123", Ordering::Less);
         test("-491", -2, RoundingMode::Floor, "-123", Ordering::Less);
         test("-491", -2, RoundingMode::Ceiling, "-122", Ordering::Greater);
         test("-491", -2, RoundingMode::Nearest, "-123", Ordering::Less);
+        test(
+            "-491",
+            -2,
+            RoundingMode::NearestTiesAway,
+            "-123",
+            Ordering::Less,
+        );
+        test(
+            "-491",
+            -2,
+            RoundingMode::NearestTiesTowardZero,
+            "-123",
+            Ordering::Less,
+        );
+        test("-491", -2, RoundingMode::Odd, "-123", Ordering::Less);
 
         test("-492", -2, RoundingMode::Down, "-123", Ordering::Equal);
         test("-492", -2, RoundingMode::Up, "-123", Ordering::Equal);
         test("-492", -2, RoundingMode::Floor, "-123", Ordering::Equal);
         test("-492", -2, RoundingMode::Ceiling, "-123", Ordering::Equal);
         test("-492", -2, RoundingMode::Nearest, "-123", Ordering::Equal);
+        test(
+            "-492",
+            -2,
+            RoundingMode::NearestTiesAway,
+            "-123",
+            Ordering::Equal,
+        );
+        test(
+            "-492",
+            -2,
+            RoundingMode::NearestTiesTowardZero,
+            "-123",
+            Ordering::Equal,
+        );
+        test("-492", -2, RoundingMode::Odd, "-123", Ordering::Equal);
         test("-492", -2, RoundingMode::Exact, "-123", Ordering::Equal);
 
         test("-493", -2, RoundingMode::Down, "-123", Ordering::Greater);
@@ -1272,6 +1506,21 @@ macro_rules! test_shl_round_signed_helper {
         test("-493", -2, RoundingMode::Floor, "-124", Ordering::Less);
         test("-493", -2, RoundingMode::Ceiling, "-123", Ordering::Greater);
         test("-493", -2, RoundingMode::Nearest, "-123", Ordering::Greater);
+        test(
+            "-493",
+            -2,
+            RoundingMode::NearestTiesAway,
+            "-123",
+            Ordering::Greater,
+        );
+        test(
+            "-493",
+            -2,
+            RoundingMode::NearestTiesTowardZero,
+            "-123",
+            Ordering::Greater,
+        );
+        test("-493", -2, RoundingMode::Odd, "-123", Ordering::Greater);
 
         test(
             "-4127195135",
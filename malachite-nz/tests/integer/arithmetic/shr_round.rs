@@ -50,6 +50,15 @@ macro_rules! test_shr_round_unsigned_helper {
         test("0", 0, RoundingMode::Floor, "0", Ordering::Equal);
         test("0", 0, RoundingMode::Ceiling, "0", Ordering::Equal);
         test("0", 0, RoundingMode::Nearest, "0", Ordering::Equal);
+        test("0", 0, RoundingMode::NearestTiesAway, "0", Ordering::Equal);
+        test(
+            "0",
+            0,
+            RoundingMode::NearestTiesTowardZero,
+            "0",
+            Ordering::Equal,
+        );
+        test("0", 0, RoundingMode::Odd, "0", Ordering::Equal);
         test("0", 0, RoundingMode::Exact, "0", Ordering::Equal);
 
         test("0", 10, RoundingMode::Down, "0", Ordering::Equal);
@@ -57,6 +66,15 @@ macro_rules! test_shr_round_unsigned_helper {
         test("0", 10, RoundingMode::Floor, "0", Ordering::Equal);
         test("0", 10, RoundingMode::Ceiling, "0", Ordering::Equal);
         test("0", 10, RoundingMode::Nearest, "0", Ordering::Equal);
+        test("0", 10, RoundingMode::NearestTiesAway, "0", Ordering::Equal);
+        test(
+            "0",
+            10,
+            RoundingMode::NearestTiesTowardZero,
+            "0",
+            Ordering::Equal,
+        );
+        test("0", 10, RoundingMode::Odd, "0", Ordering::Equal);
         test("0", 10, RoundingMode::Exact, "0", Ordering::Equal);
 
         test("123", 0, RoundingMode::Down, "123", Ordering::Equal);
@@ -64,6 +82,21 @@ macro_rules! test_shr_round_unsigned_helper {
         test("123", 0, RoundingMode::Floor, "123", Ordering::Equal);
         test("123", 0, RoundingMode::Ceiling, "123", Ordering::Equal);
         test("123", 0, RoundingMode::Nearest, "123", Ordering::Equal);
+        test(
+            "123",
+            0,
+            RoundingMode::NearestTiesAway,
+            "123",
+            Ordering::Equal,
+        );
+        test(
+            "123",
+            0,
+            RoundingMode::NearestTiesTowardZero,
+            "123",
+            Ordering::Equal,
+        );
+        test("123", 0, RoundingMode::Odd, "123", Ordering::Equal);
         test("123", 0, RoundingMode::Exact, "123", Ordering::Equal);
 
         test("245", 1, RoundingMode::Down, "122", Ordering::Less);
@@ -71,12 +104,42 @@ macro_rules! test_shr_round_unsigned_helper {
         test("245", 1, RoundingMode::Floor, "122", Ordering::Less);
         test("245", 1, RoundingMode::Ceiling, "123", Ordering::Greater);
         test("245", 1, RoundingMode::Nearest, "122", Ordering::Less);
+        test(
+            "245",
+            1,
+            RoundingMode::NearestTiesAway,
+            "123",
+            Ordering::Greater,
+        );
+        test(
+            "245",
+            1,
+            RoundingMode::NearestTiesTowardZero,
+            "122",
+            Ordering::Less,
+        );
+        test("245", 1, RoundingMode::Odd, "123", Ordering::Greater);
 
         test("246", 1, RoundingMode::Down, "123", Ordering::Equal);
         test("246", 1, RoundingMode::Up, "123", Ordering::Equal);
         test("246", 1, RoundingMode::Floor, "123", Ordering::Equal);
         test("246", 1, RoundingMode::Ceiling, "123", Ordering::Equal);
         test("246", 1, RoundingMode::Nearest, "123", Ordering::Equal);
+        test(
+            "246",
+            1,
+            RoundingMode::NearestTiesAway,
+            "123",
+            Ordering::Equal,
+        );
+        test(
+            "246",
+            1,
+            RoundingMode::NearestTiesTowardZero,
+            "123",
+            Ordering::Equal,
+        );
+        test("246", 1, RoundingMode::Odd, "123", Ordering::Equal);
         test("246", 1, RoundingMode::Exact, "123", Ordering::Equal);
 
         test("247", 1, RoundingMode::Down, "123", Ordering::Less);
@@ -84,18 +147,63 @@ macro_rules! test_shr_round_unsigned_helper {
         test("247", 1, RoundingMode::Floor, "123", Ordering::Less);
         test("247", 1, RoundingMode::Ceiling, "124", Ordering::Greater);
         test("247", 1, RoundingMode::Nearest, "124", Ordering::Greater);
+        test(
+            "247",
+            1,
+            RoundingMode::NearestTiesAway,
+            "124",
+            Ordering::Greater,
+        );
+        test(
+            "247",
+            1,
+            RoundingMode::NearestTiesTowardZero,
+            "123",
+            Ordering::Less,
+        );
+        test("247", 1, RoundingMode::Odd, "123", Ordering::Less);
 
         test("491", 2, RoundingMode::Down, "122", Ordering::Less);
         test("491", 2, RoundingMode::Up, "123", Ordering::Greater);
         test("491", 2, RoundingMode::Floor, "122", Ordering::Less);
         test("491", 2, RoundingMode::Ceiling, "123", Ordering::Greater);
         test("491", 2, RoundingMode::Nearest, "123", Ordering::Greater);
+        test(
+            "491",
+            2,
+            RoundingMode::NearestTiesAway,
+            "123",
+            Ordering::Greater,
+        );
+        test(
+            "491",
+            2,
+            RoundingMode::NearestTiesTowardZero,
+            "123",
+            Ordering::Greater,
+        );
+        test("491", 2, RoundingMode::Odd, "123", Ordering::Greater);
 
         test("492", 2, RoundingMode::Down, "123", Ordering::Equal);
         test("492", 2, RoundingMode::Up, "123", Ordering::Equal);
         test("492", 2, RoundingMode::Floor, "123", Ordering::Equal);
         test("492", 2, RoundingMode::Ceiling, "123", Ordering::Equal);
         test("492", 2, RoundingMode::Nearest, "123", Ordering::Equal);
+        test(
+            "492",
+            2,
+            RoundingMode::NearestTiesAway,
+            "123",
+            Ordering::Equal,
+        );
+        test(
+            "492",
+            2,
+            RoundingMode::NearestTiesTowardZero,
+            "123",
+            Ordering::Equal,
+        );
+        test("492", 2, RoundingMode::Odd, "123", Ordering::Equal);
         test("492", 2, RoundingMode::Exact, "123", Ordering::Equal);
 
         test("493", 2, RoundingMode::Down, "123", Ordering::Less);
@@ -103,6 +211,21 @@ macro_rules! test_shr_round_unsigned_helper {
         test("493", 2, RoundingMode::Floor, "123", Ordering::Less);
         test("493", 2, RoundingMode::Ceiling, "124", Ordering::Greater);
         test("493", 2, RoundingMode::Nearest, "123", Ordering::Less);
+        test(
+            "493",
+            2,
+            RoundingMode::NearestTiesAway,
+            "123",
+            Ordering::Less,
+        );
+        test(
+            "493",
+            2,
+            RoundingMode::NearestTiesTowardZero,
+            "123",
+            Ordering::Less,
+        );
+        test("493", 2, RoundingMode::Odd, "123", Ordering::Less);
 
         test("4127195135", 25, RoundingMode::Down, "122", Ordering::Less);
         test("4127195135", 25, RoundingMode::Up, "123", Ordering::Greater);
@@ -938,6 +1061,21 @@ macro_rules! test_shr_round_unsigned_helper {
             Ordering::Greater,
         );
         test("980657949", 72, RoundingMode::Nearest, "0", Ordering::Less);
+        test(
+            "980657949",
+            72,
+            RoundingMode::NearestTiesAway,
+            "0",
+            Ordering::Less,
+        );
+        test(
+            "980657949",
+            72,
+            RoundingMode::NearestTiesTowardZero,
+            "0",
+            Ordering::Less,
+        );
+        test("980657949", 72, RoundingMode::Odd, "1", Ordering::Greater);
 
         test("4294967295", 31, RoundingMode::Down, "1", Ordering::Less);
         test("4294967295", 31, RoundingMode::Up, "2", Ordering::Greater);
@@ -1005,12 +1143,42 @@ macro_rules! test_shr_round_unsigned_helper {
             Ordering::Greater,
         );
         test("4294967296", 33, RoundingMode::Nearest, "0", Ordering::Less);
+        test(
+            "4294967296",
+            33,
+            RoundingMode::NearestTiesAway,
+            "1",
+            Ordering::Greater,
+        );
+        test(
+            "4294967296",
+            33,
+            RoundingMode::NearestTiesTowardZero,
+            "0",
+            Ordering::Less,
+        );
+        test("4294967296", 33, RoundingMode::Odd, "1", Ordering::Greater);
 
         test("-123", 0, RoundingMode::Down, "-123", Ordering::Equal);
         test("-123", 0, RoundingMode::Up, "-123", Ordering::Equal);
         test("-123", 0, RoundingMode::Floor, "-123", Ordering::Equal);
         test("-123", 0, RoundingMode::Ceiling, "-123", Ordering::Equal);
         test("-123", 0, RoundingMode::Nearest, "-123", Ordering::Equal);
+        test(
+            "-123",
+            0,
+            RoundingMode::NearestTiesAway,
+            "-123",
+            Ordering::Equal,
+        );
+        test(
+            "-123",
+            0,
+            RoundingMode::NearestTiesTowardZero,
+            "-123",
+            Ordering::Equal,
+        );
+        test("-123", 0, RoundingMode::Odd, "-123", Ordering::Equal);
         test("-123", 0, RoundingMode::Exact, "-123", Ordering::Equal);
 
         test("-245", 1, RoundingMode::Down, "-122", Ordering::Greater);
@@ -1018,12 +1186,42 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-245", 1, RoundingMode::Floor, "-123", Ordering::Less);
         test("-245", 1, RoundingMode::Ceiling, "-122", Ordering::Greater);
         test("-245", 1, RoundingMode::Nearest, "-122", Ordering::Greater);
+        test(
+            "-245",
+            1,
+            RoundingMode::NearestTiesAway,
+            "-123",
+            Ordering::Less,
+        );
+        test(
+            "-245",
+            1,
+            RoundingMode::NearestTiesTowardZero,
+            "-122",
+            Ordering::Greater,
+        );
+        test("-245", 1, RoundingMode::Odd, "-123", Ordering::Less);
 
         test("-246", 1, RoundingMode::Down, "-123", Ordering::Equal);
         test("-246", 1, RoundingMode::Up, "-123", Ordering::Equal);
         test("-246", 1, RoundingMode::Floor, "-123", Ordering::Equal);
         test("-246", 1, RoundingMode::Ceiling, "-123", Ordering::Equal);
         test("-246", 1, RoundingMode::Nearest, "-123", Ordering::Equal);
+        test(
+            "-246",
+            1,
+            RoundingMode::NearestTiesAway,
+            "-123",
+            Ordering::Equal,
+        );
+        test(
+            "-246",
+            1,
+            RoundingMode::NearestTiesTowardZero,
+            "-123",
+            Ordering::Equal,
+        );
+        test("-246", 1, RoundingMode::Odd, "-123", Ordering::Equal);
         test("-246", 1, RoundingMode::Exact, "-123", Ordering::Equal);
 
         test("-247", 1, RoundingMode::Down, "-123", Ordering::Greater);
@@ -1031,18 +1229,63 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-247", 1, RoundingMode::Floor, "-124", Ordering::Less);
         test("-247", 1, RoundingMode::Ceiling, "-123", Ordering::Greater);
         test("-247", 1, RoundingMode::Nearest, "-124", Ordering::Less);
+        test(
+            "-247",
+            1,
+            RoundingMode::NearestTiesAway,
+            "-124",
+            Ordering::Less,
+        );
+        test(
+            "-247",
+            1,
+            RoundingMode::NearestTiesTowardZero,
+            "-123",
+            Ordering::Greater,
+        );
+        test("-247", 1, RoundingMode::Odd, "-123", Ordering::Greater);
 
         test("-491", 2, RoundingMode::Down, "-122", Ordering::Greater);
         test("-491", 2, RoundingMode::Up, "-123", Ordering::Less);
         test("-491", 2, RoundingMode::Floor, "-123", Ordering::Less);
         test("-491", 2, RoundingMode::Ceiling, "-122", Ordering::Greater);
         test("-491", 2, RoundingMode::Nearest, "-123", Ordering::Less);
+        test(
+            "-491",
+            2,
+            RoundingMode::NearestTiesAway,
+            "-123",
+            Ordering::Less,
+        );
+        test(
+            "-491",
+            2,
+            RoundingMode::NearestTiesTowardZero,
+            "-123",
+            Ordering::Less,
+        );
+        test("-491", 2, RoundingMode::Odd, "-123", Ordering::Less);
 
         test("-492", 2, RoundingMode::Down, "-123", Ordering::Equal);
         test("-492", 2, RoundingMode::Up, "-123", Ordering::Equal);
         test("-492", 2, RoundingMode::Floor, "-123", Ordering::Equal);
         test("-492", 2, RoundingMode::Ceiling, "-123", Ordering::Equal);
         test("-492", 2, RoundingMode::Nearest, "-123", Ordering::Equal);
+        test(
+            "-492",
+            2,
+            RoundingMode::NearestTiesAway,
+            "-123",
+            Ordering::Equal,
+        );
+        test(
+            "-492",
+            2,
+            RoundingMode::NearestTiesTowardZero,
+            "-123",
+            Ordering::Equal,
+        );
+        test("-492", 2, RoundingMode::Odd, "-123", Ordering::Equal);
         test("-492", 2, RoundingMode::Exact, "-123", Ordering::Equal);
 
         test("-493", 2, RoundingMode::Down, "-123", Ordering::Greater);
@@ -1050,6 +1293,21 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-493", 2, RoundingMode::Floor, "-124", Ordering::Less);
         test("-493", 2, RoundingMode::Ceiling, "-123", Ordering::Greater);
         test("-493", 2, RoundingMode::Nearest, "-123", Ordering::Greater);
+        test(
+            "-493",
+            2,
+            RoundingMode::NearestTiesAway,
+            "-123",
+            Ordering::Greater,
+        );
+        test(
+            "-493",
+            2,
+            RoundingMode::NearestTiesTowardZero,
+            "-123",
+            Ordering::Greater,
+        );
+        test("-493", 2, RoundingMode::Odd, "-123", Ordering::Greater);
 
         test(
             "-4127195135",
@@ -2122,6 +2380,15 @@ macro_rules! test_shr_round_signed_helper {
         test("0", 0, RoundingMode::Floor, "0", Ordering::Equal);
         test("0", 0, RoundingMode::Ceiling, "0", Ordering::Equal);
         test("0", 0, RoundingMode::Nearest, "0", Ordering::Equal);
+        test("0", 0, RoundingMode::NearestTiesAway, "0", Ordering::Equal);
+        test(
+            "0",
+            0,
+            RoundingMode::NearestTiesTowardZero,
+            "0",
+            Ordering::Equal,
+        );
+        test("0", 0, RoundingMode::Odd, "0", Ordering::Equal);
         test("0", 0, RoundingMode::Exact, "0", Ordering::Equal);
 
         test("0", 10, RoundingMode::Down, "0", Ordering::Equal);
@@ -2129,6 +2396,15 @@ macro_rules! test_shr_round_signed_helper {
         test("0", 10, RoundingMode::Floor, "0", Ordering::Equal);
         test("0", 10, RoundingMode::Ceiling, "0", Ordering::Equal);
         test("0", 10, RoundingMode::Nearest, "0", Ordering::Equal);
+        test("0", 10, RoundingMode::NearestTiesAway, "0", Ordering::Equal);
+        test(
+            "0",
+            10,
+            RoundingMode::NearestTiesTowardZero,
+            "0",
+            Ordering::Equal,
+        );
+        test("0", 10, RoundingMode::Odd, "0", Ordering::Equal);
         test("0", 10, RoundingMode::Exact, "0", Ordering::Equal);
 
         test("123", 0, RoundingMode::Down, "123", Ordering::Equal);
@@ -2136,6 +2412,21 @@ macro_rules! test_shr_round_signed_helper {
         test("123", 0, RoundingMode::Floor, "123", Ordering::Equal);
         test("123", 0, RoundingMode::Ceiling, "123", Ordering::Equal);
         test("123", 0, RoundingMode::Nearest, "123", Ordering::Equal);
+        test(
+            "123",
+            0,
+            RoundingMode::NearestTiesAway,
+            "123",
+            Ordering::Equal,
+        );
+        test(
+            "123",
+            0,
+            RoundingMode::NearestTiesTowardZero,
+            "123",
+            Ordering::Equal,
+        );
+        test("123", 0, RoundingMode::Odd, "123", Ordering::Equal);
         test("123", 0, RoundingMode::Exact, "123", Ordering::Equal);
 
         test("245", 1, RoundingMode::Down, "122", Ordering::Less);
@@ -2143,12 +2434,42 @@ macro_rules! test_shr_round_signed_helper {
         test("245", 1, RoundingMode::Floor, "122", Ordering::Less);
         test("245", 1, RoundingMode::Ceiling, "123", Ordering::Greater);
         test("245", 1, RoundingMode::Nearest, "122", Ordering::Less);
+        test(
+            "245",
+            1,
+            RoundingMode::NearestTiesAway,
+            "123",
+            Ordering::Greater,
+        );
+        test(
+            "245",
+            1,
+            RoundingMode::NearestTiesTowardZero,
+            "122",
+            Ordering::Less,
+        );
+        test("245", 1, RoundingMode::Odd, "123", Ordering::Greater);
 
         test("246", 1, RoundingMode::Down, "123", Ordering::Equal);
         test("246", 1, RoundingMode::Up, "123", Ordering::Equal);
         test("246", 1, RoundingMode::Floor, "123", Ordering::Equal);
         test("246", 1, RoundingMode::Ceiling, "123", Ordering::Equal);
         test("246", 1, RoundingMode::Nearest, "123", Ordering::Equal);
+        test(
+            "246",
+            1,
+            RoundingMode::NearestTiesAway,
+            "123",
+            Ordering::Equal,
+        );
+        test(
+            "246",
+            1,
+            RoundingMode::NearestTiesTowardZero,
+            "123",
+            Ordering::Equal,
+        );
+        test("246", 1, RoundingMode::Odd, "123", Ordering::Equal);
         test("246", 1, RoundingMode::Exact, "123", Ordering::Equal);
 
         test("247", 1, RoundingMode::Down, "123", Ordering::Less);
@@ -2156,18 +2477,63 @@ macro_rules! test_shr_round_signed_helper {
         test("247", 1, RoundingMode::Floor, "123", Ordering::Less);
         test("247", 1, RoundingMode::Ceiling, "124", Ordering::Greater);
         test("247", 1, RoundingMode::Nearest, "124", Ordering::Greater);
+        test(
+            "247",
+            1,
+            RoundingMode::NearestTiesAway,
+            "124",
+            Ordering::Greater,
+        );
+        test(
+            "247",
+            1,
+            RoundingMode::NearestTiesTowardZero,
+            "123",
+            Ordering::Less,
+        );
+        test("247", 1, RoundingMode::Odd, "123", Ordering::Less);
 
         test("491", 2, RoundingMode::Down, "122", Ordering::Less);
         test("491", 2, RoundingMode::Up, "123", Ordering::Greater);
         test("491", 2, RoundingMode::Floor, "122", Ordering::Less);
         test("491", 2, RoundingMode::Ceiling, "123", Ordering::Greater);
         test("491", 2, RoundingMode::Nearest, "123", Ordering::Greater);
+        test(
+            "491",
+            2,
+            RoundingMode::NearestTiesAway,
+            "123",
+            Ordering::Greater,
+        );
+        test(
+            "491",
+            2,
+            RoundingMode::NearestTiesTowardZero,
+            "123",
+            Ordering::Greater,
+        );
+        test("491", 2, RoundingMode::Odd, "123", Ordering::Greater);
 
         test("492", 2, RoundingMode::Down, "123", Ordering::Equal);
         test("492", 2, RoundingMode::Up, "123", Ordering::Equal);
         test("492", 2, RoundingMode::Floor, "123", Ordering::Equal);
         test("492", 2, RoundingMode::Ceiling, "123", Ordering::Equal);
         test("492", 2, RoundingMode::Nearest, "123", Ordering::Equal);
+        test(
+            "492",
+            2,
+            RoundingMode::NearestTiesAway,
+            "123",
+            Ordering::Equal,
+        );
+        test(
+            "492",
+            2,
+            RoundingMode::NearestTiesTowardZero,
+            "123",
+            Ordering::Equal,
+        );
+        test("492", 2, RoundingMode::Odd, "123", Ordering::Equal);
         test("492", 2, RoundingMode::Exact, "123", Ordering::Equal);
 
         test("493", 2, RoundingMode::Down, "123", Ordering::Less);
@@ -2175,6 +2541,21 @@ macro_rules! test_shr_round_signed_helper {
         test("493", 2, RoundingMode::Floor, "123", Ordering::Less);
         test("493", 2, RoundingMode::Ceiling, "124", Ordering::Greater);
         test("493", 2, RoundingMode::Nearest, "123", Ordering::Less);
+        test(
+            "493",
+            2,
+            RoundingMode::NearestTiesAway,
+            "123",
+            Ordering::Less,
+        );
+        test(
+            "493",
+            2,
+            RoundingMode::NearestTiesTowardZero,
+            "123",
+            Ordering::Less,
+        );
+        test("493", 2, RoundingMode::Odd, "123", Ordering::Less);
 
         test("4127195135", 25, RoundingMode::Down, "122", Ordering::Less);
         test("4127195135", 25, RoundingMode::Up, "123", Ordering::Greater);
@@ -3010,6 +3391,21 @@ macro_rules! test_shr_round_signed_helper {
             Ordering::Greater,
         );
         test("980657949", 72, RoundingMode::Nearest, "0", Ordering::Less);
+        test(
+            "980657949",
+            72,
+            RoundingMode::NearestTiesAway,
+            "0",
+            Ordering::Less,
+        );
+        test(
+            "980657949",
+            72,
+            RoundingMode::NearestTiesTowardZero,
+            "0",
+            Ordering::Less,
+        );
+        test("980657949", 72, RoundingMode::Odd, "1", Ordering::Greater);
 
         test("4294967295", 31, RoundingMode::Down, "1", Ordering::Less);
         test("4294967295", 31, RoundingMode::Up, "2", Ordering::Greater);
@@ -3077,12 +3473,42 @@ macro_rules! test_shr_round_signed_helper {
             Ordering::Greater,
         );
         test("4294967296", 33, RoundingMode::Nearest, "0", Ordering::Less);
+        test(
+            "4294967296",
+            33,
+            RoundingMode::NearestTiesAway,
+            "1",
+            Ordering::Greater,
+        );
+        test(
+            "4294967296",
+            33,
+            RoundingMode::NearestTiesTowardZero,
+            "0",
+            Ordering::Less,
+        );
+        test("4294967296", 33, RoundingMode::Odd, "1", Ordering::Greater);
 
         test("-123", 0, RoundingMode::Down, "-123", Ordering::Equal);
         test("-123", 0, RoundingMode::Up, "-123", Ordering::Equal);
         test("-123", 0, RoundingMode::Floor, "-123", Ordering::Equal);
         test("-123", 0, RoundingMode::Ceiling, "-123", Ordering::Equal);
         test("-123", 0, RoundingMode::Nearest, "-123", Ordering::Equal);
+        test(
+            "-123",
+            0,
+            RoundingMode::NearestTiesAway,
+            "-123",
+            Ordering::Equal,
+        );
+        test(
+            "-123",
+            0,
+            RoundingMode::NearestTiesTowardZero,
+            "-123",
+            Ordering::Equal,
+        );
+        test("-123", 0, RoundingMode::Odd, "-123", Ordering::Equal);
         test("-123", 0, RoundingMode::Exact, "-123", Ordering::Equal);
 
         test("-245", 1, RoundingMode::Down, "-122", Ordering::Greater);
@@ -3090,12 +3516,42 @@ macro_rules! test_shr_round_signed_helper {
         test("-245", 1, RoundingMode::Floor, "-123", Ordering::Less);
         test("-245", 1, RoundingMode::Ceiling, "-122", Ordering::Greater);
         test("-245", 1, RoundingMode::Nearest, "-122", Ordering::Greater);
+        test(
+            "-245",
+            1,
+            RoundingMode::NearestTiesAway,
+            "-123",
+            Ordering::Less,
+        );
+        test(
+            "-245",
+            1,
+            RoundingMode::NearestTiesTowardZero,
+            "-122",
+            Ordering::Greater,
+        );
+        test("-245", 1, RoundingMode::Odd, "-123", Ordering::Less);
 
         test("-246", 1, RoundingMode::Down, "-123", Ordering::Equal);
         test("-246", 1, RoundingMode::Up, "-123", Ordering::Equal);
         test("-246", 1, RoundingMode::Floor, "-123", Ordering::Equal);
         test("-246", 1, RoundingMode::Ceiling, "-123", Ordering::Equal);
         test("-246", 1, RoundingMode::Nearest, "-123", Ordering::Equal);
+        test(
+            "-246",
+            1,
+            RoundingMode::NearestTiesAway,
+            "-123",
+            Ordering::Equal,
+        );
+        test(
+            "-246",
+            1,
+            RoundingMode::NearestTiesTowardZero,
+            "-123",
+            Ordering::Equal,
+        );
+        test("-246", 1, RoundingMode::Odd, "-123", Ordering::Equal);
         test("-246", 1, RoundingMode::Exact, "-123", Ordering::Equal);
 
         test("-247", 1, RoundingMode::Down, "-123", Ordering::Greater);
@@ -3103,18 +3559,63 @@ macro_rules! test_shr_round_signed_helper {
         test("-247", 1, RoundingMode::Floor, "-124", Ordering::Less);
         test("-247", 1, RoundingMode::Ceiling, "-123", Ordering::Greater);
         test("-247", 1, RoundingMode::Nearest, "-124", Ordering::Less);
+        test(
+            "-247",
+            1,
+            RoundingMode::NearestTiesAway,
+            "-124",
+            Ordering::Less,
+        );
+        test(
+            "-247",
+            1,
+            RoundingMode::NearestTiesTowardZero,
+            "-123",
+            Ordering::Greater,
+        );
+        test("-247", 1, RoundingMode::Odd, "-123", Ordering::Greater);
 
         test("-491", 2, RoundingMode::Down, "-122", Ordering::Greater);
         test("-491", 2, RoundingMode::Up, "-123", Ordering::Less);
         test("-491", 2, RoundingMode::Floor, "-123", Ordering::Less);
         test("-491", 2, RoundingMode::Ceiling, "-122", Ordering::Greater);
         test("-491", 2, RoundingMode::Nearest, "-123", Ordering::Less);
+        test(
+            "-491",
+            2,
+            RoundingMode::NearestTiesAway,
+            "-123",
+            Ordering::Less,
+        );
+        test(
+            "-491",
+            2,
+            RoundingMode::NearestTiesTowardZero,
+            "-123",
+            Ordering::Less,
+        );
+        test("-491", 2, RoundingMode::Odd, "-123", Ordering::Less);
 
         test("-492", 2, RoundingMode::Down, "-123", Ordering::Equal);
         test("-492", 2, RoundingMode::Up, "-123", Ordering::Equal);
         test("-492", 2, RoundingMode::Floor, "-123", Ordering::Equal);
         test("-492", 2, RoundingMode::Ceiling, "-123", Ordering::Equal);
         test("-492", 2, RoundingMode::Nearest, "-123", Ordering::Equal);
+        test(
+            "-492",
+            2,
+            RoundingMode::NearestTiesAway,
+            "-123",
+            Ordering::Equal,
+        );
+        test(
+            "-492",
+            2,
+            RoundingMode::NearestTiesTowardZero,
+            "-123",
+            Ordering::Equal,
+        );
+        test("-492", 2, RoundingMode::Odd, "-123", Ordering::Equal);
         test("-492", 2, RoundingMode::Exact, "-123", Ordering::Equal);
 
         test("-493", 2, RoundingMode::Down, "-123", Ordering::Greater);
@@ -3122,6 +3623,21 @@ macro_rules! test_shr_round_signed_helper {
         test("-493", 2, RoundingMode::Floor, "-124", Ordering::Less);
         test("-493", 2, RoundingMode::Ceiling, "-123", Ordering::Greater);
         test("-493", 2, RoundingMode::Nearest, "-123", Ordering::Greater);
+        test(
+            "-493",
+            2,
+            RoundingMode::NearestTiesAway,
+            "-123",
+            Ordering::Greater,
+        );
+        test(
+            "-493",
+            2,
+            RoundingMode::NearestTiesTowardZero,
+            "-123",
+            Ordering::Greater,
+        );
+        test("-493", 2, RoundingMode::Odd, "-123", Ordering::Greater);
 
         test(
             "-4127195135",
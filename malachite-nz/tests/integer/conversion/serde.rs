@@ -46,12 +46,55 @@ fn test_serde() {
     );
 }
 
+#[test]
+fn test_serde_binary() {
+    let test = |n, out: &[u8]| {
+        assert_eq!(
+            bincode::serialize(&Integer::from_str(n).unwrap()).unwrap(),
+            out
+        );
+        assert_eq!(bincode::deserialize::<Integer>(out).unwrap().to_string(), n);
+    };
+    test("0", &[1, 0, 0, 0, 0, 0, 0, 0, 0]);
+    test("100", &[2, 0, 0, 0, 0, 0, 0, 0, 0, 100]);
+    test(
+        "1000000000000",
+        &[6, 0, 0, 0, 0, 0, 0, 0, 0, 0x00, 0x10, 0xa5, 0xd4, 0xe8],
+    );
+    test("-100", &[2, 0, 0, 0, 0, 0, 0, 0, 1, 100]);
+    test(
+        "-1000000000000",
+        &[6, 0, 0, 0, 0, 0, 0, 0, 1, 0x00, 0x10, 0xa5, 0xd4, 0xe8],
+    );
+    test(
+        "-18446744073709551616",
+        &[10, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+    );
+
+    // negative zero is accepted
+    assert_eq!(
+        bincode::deserialize::<Integer>(&[1, 0, 0, 0, 0, 0, 0, 0, 1]).unwrap(),
+        0
+    );
+    // the sign byte is required
+    assert!(bincode::deserialize::<Integer>(&[0, 0, 0, 0, 0, 0, 0, 0]).is_err());
+    assert!(bincode::deserialize::<Integer>(&[2, 0, 0, 0, 0, 0, 0, 0, 2, 100]).is_err());
+}
+
 #[test]
 fn serde_properties() {
     integer_gen().test_properties(|x| {
         let s = serde_json::to_string(&x).unwrap();
         assert_eq!(serde_json::from_str::<Integer>(&s).unwrap(), x);
         assert!(string_is_subset(&s, "\"-0123456789abcdefx"));
+
+        let bytes = bincode::serialize(&x).unwrap();
+        assert_eq!(bincode::deserialize::<Integer>(&bytes).unwrap(), x);
+        assert_eq!(bytes[8], u8::from(x < 0));
+        assert_eq!(
+            &bytes[9..],
+            &bincode::serialize(x.unsigned_abs_ref()).unwrap()[8..]
+        );
     });
 
     string_gen().test_properties(|s| {
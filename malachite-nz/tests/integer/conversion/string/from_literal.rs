@@ -0,0 +1,76 @@
+use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::string_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_gen;
+
+#[test]
+fn test_from_literal() {
+    let test_ok = |s, n| {
+        assert_eq!(Integer::from_literal(s).unwrap().to_string(), n);
+    };
+    test_ok("0", "0");
+    test_ok("-0", "0");
+    test_ok("+0", "0");
+    test_ok("123456", "123456");
+    test_ok("-1_000_000", "-1000000");
+    test_ok("-0x0", "0");
+    test_ok("-0xff", "-255");
+    test_ok("+0XFF", "255");
+    test_ok("-0xdead_BEEF", "-3735928559");
+    test_ok("-0o755", "-493");
+    test_ok("-0B1010_1010", "-170");
+    test_ok(
+        "-0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff",
+        "-340282366920938463463374607431768211455",
+    );
+
+    let test_err = |s, kind| {
+        assert_eq!(Integer::from_literal(s).unwrap_err().kind(), kind);
+    };
+    test_err("", ParseNumberErrorKind::Empty);
+    test_err("-", ParseNumberErrorKind::Empty);
+    test_err("-0x", ParseNumberErrorKind::Empty);
+    test_err("--1", ParseNumberErrorKind::InvalidDigit(1));
+    test_err("+-1", ParseNumberErrorKind::InvalidDigit(1));
+    test_err("-0x-1", ParseNumberErrorKind::InvalidDigit(3));
+    test_err("-_1", ParseNumberErrorKind::InvalidDigit(1));
+    test_err("-0o78", ParseNumberErrorKind::InvalidDigit(4));
+    test_err("-1_2_a", ParseNumberErrorKind::InvalidDigit(5));
+    test_err("-0x1i64", ParseNumberErrorKind::InvalidDigit(4));
+}
+
+#[test]
+fn from_literal_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 64);
+    string_gen().test_properties_with_config(&config, |s| {
+        let result = Integer::from_literal(&s);
+        if let Ok(n) = Natural::from_literal(&s) {
+            assert_eq!(result.unwrap(), n);
+        } else if let Some(abs_s) = s.strip_prefix('-') {
+            if let Ok(n) = Natural::from_literal(abs_s) {
+                if !abs_s.starts_with('+') {
+                    assert_eq!(result.unwrap(), -n);
+                }
+            }
+        } else {
+            assert!(result.is_err());
+        }
+    });
+
+    integer_gen().test_properties(|n| {
+        let abs_n = n.unsigned_abs_ref();
+        let sign = if n < 0 { "-" } else { "" };
+        for s in [
+            n.to_string(),
+            format!("{sign}{abs_n:#x}"),
+            format!("{sign}{abs_n:#X}"),
+            format!("{sign}{abs_n:#o}"),
+            format!("{sign}{abs_n:#b}"),
+        ] {
+            assert_eq!(Integer::from_literal(&s).unwrap(), n);
+        }
+    });
+}
@@ -1,5 +1,6 @@
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::conversion::string::options::FromSciStringOptions;
+use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
 use malachite_base::num::conversion::traits::{FromSciString, ToStringBase};
 use malachite_base::rounding_modes::RoundingMode;
 use malachite_base::test_util::generators::{
@@ -14,9 +15,9 @@ use std::str::FromStr;
 pub fn test_from_sci_string() {
     fn test(s: &str, out: Option<&'static str>) {
         let out = out.map(|s| Integer::from_str(s).unwrap());
-        assert_eq!(Integer::from_sci_string(s), out);
+        assert_eq!(Integer::from_sci_string(s).ok(), out);
         assert_eq!(
-            Integer::from_sci_string_with_options(s, FromSciStringOptions::default()),
+            Integer::from_sci_string_with_options(s, FromSciStringOptions::default()).ok(),
             out
         );
     }
@@ -113,18 +114,50 @@ pub fn test_from_sci_string() {
     test("0e-10000000000000000000000000000", None);
 }
 
+#[test]
+pub fn test_from_sci_string_error() {
+    fn test(s: &str, options: FromSciStringOptions, kind: ParseNumberErrorKind) {
+        assert_eq!(
+            Integer::from_sci_string_with_options(s, options)
+                .unwrap_err()
+                .kind(),
+            kind
+        );
+    }
+    let mut options = FromSciStringOptions::default();
+    test("", options, ParseNumberErrorKind::Empty);
+    test("1e", options, ParseNumberErrorKind::Empty);
+    test("1.2.3", options, ParseNumberErrorKind::InvalidDigit(3));
+    test("12a", options, ParseNumberErrorKind::InvalidDigit(2));
+    test("--1.5", options, ParseNumberErrorKind::InvalidDigit(1));
+    test("-1.5e+-2", options, ParseNumberErrorKind::InvalidDigit(6));
+    test(
+        "1e10000000000000000000000",
+        options,
+        ParseNumberErrorKind::Overflow,
+    );
+
+    options.set_base(16);
+    test("ffg", options, ParseNumberErrorKind::InvalidDigit(2));
+
+    options = FromSciStringOptions::default();
+    options.set_rounding_mode(RoundingMode::Exact);
+    test("1.5", options, ParseNumberErrorKind::Inexact);
+    test("15e-1", options, ParseNumberErrorKind::Inexact);
+}
+
 #[test]
 pub fn test_from_sci_string_with_options() {
     fn test(s: &str, options: FromSciStringOptions, out: Option<&str>) {
         let out = out.map(|s| Integer::from_str(s).unwrap());
-        assert_eq!(Integer::from_sci_string_with_options(s, options), out);
+        assert_eq!(Integer::from_sci_string_with_options(s, options).ok(), out);
     }
     fn test_i<T: PrimitiveInt>(s: &str, options: FromSciStringOptions, out: Option<T>)
     where
         Integer: From<T>,
     {
         let out = out.map(Integer::from);
-        assert_eq!(Integer::from_sci_string_with_options(s, options), out);
+        assert_eq!(Integer::from_sci_string_with_options(s, options).ok(), out);
     }
     // For tests with the default options, see `test_from_sci_string`
 
@@ -551,7 +584,7 @@ pub fn test_from_sci_string_with_options() {
 }
 
 fn from_sci_string_helper(s: &str) {
-    if let Some(x) = Integer::from_sci_string(s) {
+    if let Ok(x) = Integer::from_sci_string(s) {
         for c in ['.', 'e', 'E', '+'] {
             if s.contains(c) {
                 return;
@@ -580,7 +613,7 @@ fn from_sci_string_properties() {
 }
 
 fn from_sci_string_with_options_helper(s: &str, options: FromSciStringOptions) {
-    if let Some(x) = Integer::from_sci_string_with_options(s, options) {
+    if let Ok(x) = Integer::from_sci_string_with_options(s, options) {
         assert!(!s.ends_with('+'));
         assert!(!s.ends_with('-'));
         assert!(!s.contains("++"));
@@ -1,3 +1,4 @@
+use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
 use malachite_base::num::conversion::traits::{ExactFrom, FromStringBase, ToStringBase};
 use malachite_base::test_util::generators::common::GenConfig;
 use malachite_base::test_util::generators::exhaustive::valid_digit_chars;
@@ -144,7 +145,7 @@ fn test_from_string_base() {
     test_ok(36, "-helloworld", "-1767707668033969");
 
     let test_err = |base, s, rug_err| {
-        assert!(Integer::from_string_base(base, s).is_none());
+        assert!(Integer::from_string_base(base, s).is_err());
         assert!(BigInt::from_str_radix(s, u32::exact_from(base)).is_err());
         assert_eq!(
             rug::Integer::from_str_radix(s, i32::exact_from(base)).is_err(),
@@ -160,6 +161,21 @@ fn test_from_string_base() {
     test_err(35, "-", true);
 }
 
+#[test]
+fn test_from_string_base_error() {
+    let test = |base, s, kind| {
+        assert_eq!(Integer::from_string_base(base, s).unwrap_err().kind(), kind);
+    };
+    test(10, "", ParseNumberErrorKind::Empty);
+    test(10, "-", ParseNumberErrorKind::Empty);
+    test(10, "--5", ParseNumberErrorKind::InvalidDigit(1));
+    test(10, "+5", ParseNumberErrorKind::SignNotAllowed);
+    test(10, "-12A", ParseNumberErrorKind::InvalidDigit(3));
+    test(2, "-123", ParseNumberErrorKind::InvalidDigit(2));
+    test(0, "0", ParseNumberErrorKind::InvalidBase(0));
+    test(37, "-0", ParseNumberErrorKind::InvalidBase(37));
+}
+
 #[test]
 fn from_string_base_properties() {
     let mut config = GenConfig::new();
@@ -171,7 +187,7 @@ fn from_string_base_properties() {
             .entry(base)
             .or_insert_with(|| valid_digit_chars(base));
         assert_eq!(
-            Integer::from_string_base(base, &s).is_some(),
+            Integer::from_string_base(base, &s).is_ok(),
             !abs_s.is_empty() && abs_s.chars().all(|c| digits.contains(&c)),
         );
     });
@@ -0,0 +1,119 @@
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::conversion::string::options::{DigitGrouping, FormatOptions};
+use malachite_base::num::conversion::traits::{ToFormattedString, ToStringBase};
+use malachite_base::test_util::generators::signed_unsigned_pair_gen_var_5;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::{integer_gen, integer_unsigned_pair_gen_var_1};
+use std::str::FromStr;
+
+#[test]
+pub fn test_to_formatted_string() {
+    fn test(s: &str, options: FormatOptions, out: &str) {
+        let x = Integer::from_str(s).unwrap();
+        assert_eq!(x.to_formatted_string(options), out);
+    }
+    let mut options = FormatOptions::default();
+    test("0", options, "0");
+    test("123", options, "123");
+    test("-123", options, "-123");
+    test(
+        "-1000000000000000000000",
+        options,
+        "-1000000000000000000000",
+    );
+
+    options.set_digit_grouping(DigitGrouping::Uniform(3));
+    test("0", options, "0");
+    test("-123", options, "-123");
+    test("-1234", options, "-1,234");
+    test(
+        "1000000000000000000000",
+        options,
+        "1,000,000,000,000,000,000,000",
+    );
+    test(
+        "-1000000000000000000000",
+        options,
+        "-1,000,000,000,000,000,000,000",
+    );
+
+    options.set_digit_grouping(DigitGrouping::Indian);
+    test("-12345", options, "-12,345");
+    test(
+        "-1000000000000000000000",
+        options,
+        "-1,00,00,00,00,00,00,00,00,00,000",
+    );
+
+    options.set_digit_grouping(DigitGrouping::Uniform(3));
+    options.set_group_separator('.');
+    options.set_decimal_separator(',');
+    options.set_fraction_digits(2);
+    test(
+        "-1000000000000000000000",
+        options,
+        "-1.000.000.000.000.000.000.000,00",
+    );
+
+    let mut options = FormatOptions::default();
+    options.set_base(16);
+    options.set_uppercase();
+    options.set_digit_grouping(DigitGrouping::Uniform(4));
+    options.set_group_separator('_');
+    test(
+        "-1000000000000000000000",
+        options,
+        "-36_35C9_ADC5_DEA0_0000",
+    );
+}
+
+fn to_formatted_string_helper<T: PrimitiveSigned>()
+where
+    Integer: From<T>,
+{
+    signed_unsigned_pair_gen_var_5::<T, u8>().test_properties(|(x, base)| {
+        let mut options = FormatOptions::default();
+        options.set_base(base);
+        options.set_digit_grouping(DigitGrouping::Uniform(3));
+        assert_eq!(
+            Integer::from(x).to_formatted_string(options),
+            x.to_formatted_string(options)
+        );
+    });
+}
+
+#[test]
+fn to_formatted_string_properties() {
+    integer_unsigned_pair_gen_var_1().test_properties(|(x, base)| {
+        let mut options = FormatOptions::default();
+        options.set_base(base);
+        let s = x.to_formatted_string(options);
+        assert_eq!(s, x.to_string_base(base));
+        options.set_group_separator('_');
+        for grouping in
+            [DigitGrouping::Uniform(1), DigitGrouping::Uniform(3), DigitGrouping::Indian]
+        {
+            options.set_digit_grouping(grouping);
+            let s_grouped = x.to_formatted_string(options);
+            assert_eq!(s_grouped.replace('_', ""), s);
+            assert_eq!(
+                s_grouped.trim_start_matches('-'),
+                x.unsigned_abs_ref().to_formatted_string(options)
+            );
+        }
+    });
+
+    integer_gen().test_properties(|x| {
+        let mut options = FormatOptions::default();
+        options.set_digit_grouping(DigitGrouping::Indian);
+        options.set_fraction_digits(1);
+        let s = x.to_formatted_string(options);
+        assert_eq!(s.starts_with('-'), x < 0);
+        assert_eq!(
+            (-&x).to_formatted_string(options).trim_start_matches('-'),
+            s.trim_start_matches('-')
+        );
+    });
+
+    apply_fn_to_signeds!(to_formatted_string_helper);
+}
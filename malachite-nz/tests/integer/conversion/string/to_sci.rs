@@ -824,3 +824,59 @@ fn to_sci_with_options_properties() {
         );
     });
 }
+
+#[test]
+pub fn test_exp() {
+    fn test(s: &str, out: &str, out_p2: &str) {
+        let x = Integer::from_str(s).unwrap();
+        assert_eq!(format!("{x:e}"), out);
+        assert_eq!(format!("{x:E}"), out.to_uppercase());
+        assert_eq!(format!("{x:.2e}"), out_p2);
+    }
+    test("0", "0e0", "0.00e0");
+    test("123", "1.23e2", "1.23e2");
+    test("-1", "-1e0", "-1.00e0");
+    test("-1250", "-1.25e3", "-1.25e3");
+    test("-12355", "-1.2355e4", "-1.24e4");
+    test("-99999", "-9.9999e4", "-1.00e5");
+    test("-1000000000000000000000000", "-1e24", "-1.00e24");
+
+    let x = Integer::from(-123456);
+    assert_eq!(format!("{x:>12e}"), "  -1.23456e5");
+    assert_eq!(format!("{x:*<12e}"), "-1.23456e5**");
+    assert_eq!(format!("{x:012e}"), "-001.23456e5");
+    assert_eq!(format!("{x:^12.1E}"), "   -1.2E5   ");
+    assert_eq!(format!("{:+e}", Integer::from(5)), "+5e0");
+}
+
+#[test]
+fn exp_properties() {
+    integer_gen().test_properties(|x| {
+        let s = format!("{x:e}");
+        assert_eq!(format!("{x:E}"), s.to_uppercase());
+        assert_eq!(Integer::from_sci_string(&s).unwrap(), x);
+        assert_eq!(s.starts_with('-'), x < 0);
+        let abs = x.unsigned_abs_ref();
+        if x >= 0 {
+            assert_eq!(format!("{abs:e}"), s);
+        } else {
+            assert_eq!(format!("-{abs:e}"), s);
+        }
+        let s = format!("{x:.2e}");
+        let s_neg = format!("{:.2e}", -&x);
+        if x > 0 {
+            assert_eq!(s_neg, format!("-{s}"));
+        } else if x < 0 {
+            assert_eq!(s, format!("-{s_neg}"));
+        }
+    });
+
+    signed_gen::<SignedLimb>().test_properties(|x| {
+        let n = Integer::from(x);
+        assert_eq!(format!("{n:e}"), format!("{x:e}"));
+        assert_eq!(format!("{n:E}"), format!("{x:E}"));
+        assert_eq!(format!("{n:.3e}"), format!("{x:.3e}"));
+        assert_eq!(format!("{n:_>+20.1e}"), format!("{x:_>+20.1e}"));
+        assert_eq!(format!("{n:020E}"), format!("{x:020E}"));
+    });
+}
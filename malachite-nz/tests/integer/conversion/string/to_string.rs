@@ -159,6 +159,51 @@ fn to_string_properties() {
     });
 }
 
+#[test]
+pub fn test_fmt_flags() {
+    let x = Integer::from(-123);
+    assert_eq!(format!("{x:>8}"), "    -123");
+    assert_eq!(format!("{x:*<8}"), "-123****");
+    assert_eq!(format!("{x:^9}"), "  -123   ");
+    assert_eq!(format!("{x:08}"), "-0000123");
+    assert_eq!(format!("{x:>#12x}"), "       -0x7b");
+    assert_eq!(format!("{x:#012b}"), "-0b001111011");
+    assert_eq!(format!("{x:_^#10o}"), "__-0o173__");
+    let x = Integer::from(123);
+    assert_eq!(format!("{x:+}"), "+123");
+    assert_eq!(format!("{x:+08}"), "+0000123");
+    assert_eq!(format!("{x:<+8}|"), "+123    |");
+    assert_eq!(format!("{x:+#X}"), "+0x7B");
+    let x = Integer::from_str("-1000000000000000000000").unwrap();
+    assert_eq!(format!("{x:>24}"), " -1000000000000000000000");
+    assert_eq!(format!("{x:+>#24x}"), "+++-0x3635c9adc5dea00000");
+    assert_eq!(
+        format!("{:#^30}", BaseFmtWrapper::new(&x, 36)),
+        "#######-5v1j4f4ds79m9s########"
+    );
+}
+
+#[test]
+fn fmt_flags_properties() {
+    integer_unsigned_pair_gen_var_2().test_properties(|(x, width)| {
+        let y = BigInt::from(&x);
+        assert_eq!(format!("{x:>width$}"), format!("{y:>width$}"));
+        assert_eq!(format!("{x:*<+width$}"), format!("{y:*<+width$}"));
+        assert_eq!(format!("{x:^width$}"), format!("{y:^width$}"));
+        assert_eq!(format!("{x:+0width$}"), format!("{y:+0width$}"));
+        assert_eq!(format!("{x:>#width$b}"), format!("{y:>#width$b}"));
+        assert_eq!(format!("{x:<#width$o}"), format!("{y:<#width$o}"));
+        assert_eq!(format!("{x:^+#width$x}"), format!("{y:^+#width$x}"));
+        assert_eq!(format!("{x:#0width$X}"), format!("{y:#0width$X}"));
+    });
+
+    signed_unsigned_pair_gen_var_5::<SignedLimb, usize>().test_properties(|(x, width)| {
+        let n = Integer::from(x);
+        assert_eq!(format!("{n:_>+width$}"), format!("{x:_>+width$}"));
+        assert_eq!(format!("{n:^width$}"), format!("{x:^width$}"));
+    });
+}
+
 #[test]
 pub fn test_to_binary_string() {
     fn test(u: &str, out: &str, out_prefixed: &str) {
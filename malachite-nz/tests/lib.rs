@@ -45,6 +45,19 @@ extern crate malachite_nz;
 extern crate num;
 extern crate rug;
 
+pub mod fixed {
+    pub mod arithmetic {
+        pub mod add;
+        pub mod div;
+        pub mod mod_pow;
+        pub mod mul;
+        pub mod neg;
+    }
+    pub mod comparison;
+    pub mod constant_time;
+    pub mod conversion;
+    pub mod logic;
+}
 pub mod integer {
     pub mod arithmetic {
         pub mod abs;
@@ -55,6 +68,7 @@ pub mod integer {
         pub mod div_exact;
         pub mod div_mod;
         pub mod div_round;
+        pub mod div_to_primitive_float;
         pub mod divisible_by;
         pub mod divisible_by_power_of_2;
         pub mod eq_mod;
@@ -68,6 +82,7 @@ pub mod integer {
         pub mod parity;
         pub mod pow;
         pub mod power_of_2;
+        pub mod remove_factor;
         pub mod root;
         pub mod round_to_multiple;
         pub mod round_to_multiple_of_power_of_2;
@@ -117,8 +132,10 @@ pub mod integer {
         #[cfg(feature = "serde")]
         pub mod serde;
         pub mod string {
+            pub mod from_literal;
             pub mod from_sci_string;
             pub mod from_string;
+            pub mod to_formatted_string;
             pub mod to_sci;
             pub mod to_string;
         }
@@ -198,6 +215,7 @@ pub mod natural {
         pub mod div_exact;
         pub mod div_mod;
         pub mod div_round;
+        pub mod div_to_primitive_float;
         pub mod divisible_by;
         pub mod divisible_by_power_of_2;
         pub mod eq_mod;
@@ -240,6 +258,7 @@ pub mod natural {
         pub mod pow;
         pub mod power_of_2;
         pub mod primorial;
+        pub mod remove_factor;
         pub mod root;
         pub mod round_to_multiple;
         pub mod round_to_multiple_of_power_of_2;
@@ -295,8 +314,10 @@ pub mod natural {
         #[cfg(feature = "serde")]
         pub mod serde;
         pub mod string {
+            pub mod from_literal;
             pub mod from_sci_string;
             pub mod from_string;
+            pub mod to_formatted_string;
             pub mod to_sci;
             pub mod to_string;
         }
@@ -336,6 +357,8 @@ pub mod natural {
         pub mod trailing_zeros;
         pub mod xor;
     }
+    pub mod lazy;
+    pub mod modular;
     pub mod random {
         pub mod get_random_natural_with_bits;
         pub mod get_random_natural_with_up_to_bits;
@@ -355,4 +378,6 @@ pub mod natural {
         pub mod uniform_random_natural_inclusive_range;
         pub mod uniform_random_natural_range;
     }
+    pub mod scratch;
 }
+pub mod tuning;
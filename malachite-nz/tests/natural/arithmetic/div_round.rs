@@ -101,6 +101,21 @@ fn test_div_round() {
     test("0", "1", RoundingMode::Up, "0", Ordering::Equal);
     test("0", "1", RoundingMode::Ceiling, "0", Ordering::Equal);
     test("0", "1", RoundingMode::Nearest, "0", Ordering::Equal);
+    test(
+        "0",
+        "1",
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "1",
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Equal,
+    );
+    test("0", "1", RoundingMode::Odd, "0", Ordering::Equal);
     test("0", "1", RoundingMode::Exact, "0", Ordering::Equal);
 
     test("0", "123", RoundingMode::Down, "0", Ordering::Equal);
@@ -108,6 +123,21 @@ fn test_div_round() {
     test("0", "123", RoundingMode::Up, "0", Ordering::Equal);
     test("0", "123", RoundingMode::Ceiling, "0", Ordering::Equal);
     test("0", "123", RoundingMode::Nearest, "0", Ordering::Equal);
+    test(
+        "0",
+        "123",
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "123",
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Equal,
+    );
+    test("0", "123", RoundingMode::Odd, "0", Ordering::Equal);
     test("0", "123", RoundingMode::Exact, "0", Ordering::Equal);
 
     test("1", "1", RoundingMode::Down, "1", Ordering::Equal);
@@ -115,6 +145,21 @@ fn test_div_round() {
     test("1", "1", RoundingMode::Up, "1", Ordering::Equal);
     test("1", "1", RoundingMode::Ceiling, "1", Ordering::Equal);
     test("1", "1", RoundingMode::Nearest, "1", Ordering::Equal);
+    test(
+        "1",
+        "1",
+        RoundingMode::NearestTiesAway,
+        "1",
+        Ordering::Equal,
+    );
+    test(
+        "1",
+        "1",
+        RoundingMode::NearestTiesTowardZero,
+        "1",
+        Ordering::Equal,
+    );
+    test("1", "1", RoundingMode::Odd, "1", Ordering::Equal);
     test("1", "1", RoundingMode::Exact, "1", Ordering::Equal);
 
     test("123", "1", RoundingMode::Down, "123", Ordering::Equal);
@@ -122,6 +167,21 @@ fn test_div_round() {
     test("123", "1", RoundingMode::Up, "123", Ordering::Equal);
     test("123", "1", RoundingMode::Ceiling, "123", Ordering::Equal);
     test("123", "1", RoundingMode::Nearest, "123", Ordering::Equal);
+    test(
+        "123",
+        "1",
+        RoundingMode::NearestTiesAway,
+        "123",
+        Ordering::Equal,
+    );
+    test(
+        "123",
+        "1",
+        RoundingMode::NearestTiesTowardZero,
+        "123",
+        Ordering::Equal,
+    );
+    test("123", "1", RoundingMode::Odd, "123", Ordering::Equal);
     test("123", "1", RoundingMode::Exact, "123", Ordering::Equal);
 
     test("123", "2", RoundingMode::Down, "61", Ordering::Less);
@@ -129,18 +189,63 @@ fn test_div_round() {
     test("123", "2", RoundingMode::Up, "62", Ordering::Greater);
     test("123", "2", RoundingMode::Ceiling, "62", Ordering::Greater);
     test("123", "2", RoundingMode::Nearest, "62", Ordering::Greater);
+    test(
+        "123",
+        "2",
+        RoundingMode::NearestTiesAway,
+        "62",
+        Ordering::Greater,
+    );
+    test(
+        "123",
+        "2",
+        RoundingMode::NearestTiesTowardZero,
+        "61",
+        Ordering::Less,
+    );
+    test("123", "2", RoundingMode::Odd, "61", Ordering::Less);
 
     test("125", "2", RoundingMode::Down, "62", Ordering::Less);
     test("125", "2", RoundingMode::Floor, "62", Ordering::Less);
     test("125", "2", RoundingMode::Up, "63", Ordering::Greater);
     test("125", "2", RoundingMode::Ceiling, "63", Ordering::Greater);
     test("125", "2", RoundingMode::Nearest, "62", Ordering::Less);
+    test(
+        "125",
+        "2",
+        RoundingMode::NearestTiesAway,
+        "63",
+        Ordering::Greater,
+    );
+    test(
+        "125",
+        "2",
+        RoundingMode::NearestTiesTowardZero,
+        "62",
+        Ordering::Less,
+    );
+    test("125", "2", RoundingMode::Odd, "63", Ordering::Greater);
 
     test("123", "123", RoundingMode::Down, "1", Ordering::Equal);
     test("123", "123", RoundingMode::Floor, "1", Ordering::Equal);
     test("123", "123", RoundingMode::Up, "1", Ordering::Equal);
     test("123", "123", RoundingMode::Ceiling, "1", Ordering::Equal);
     test("123", "123", RoundingMode::Nearest, "1", Ordering::Equal);
+    test(
+        "123",
+        "123",
+        RoundingMode::NearestTiesAway,
+        "1",
+        Ordering::Equal,
+    );
+    test(
+        "123",
+        "123",
+        RoundingMode::NearestTiesTowardZero,
+        "1",
+        Ordering::Equal,
+    );
+    test("123", "123", RoundingMode::Odd, "1", Ordering::Equal);
     test("123", "123", RoundingMode::Exact, "1", Ordering::Equal);
 
     test("123", "456", RoundingMode::Down, "0", Ordering::Less);
@@ -148,6 +253,21 @@ fn test_div_round() {
     test("123", "456", RoundingMode::Up, "1", Ordering::Greater);
     test("123", "456", RoundingMode::Ceiling, "1", Ordering::Greater);
     test("123", "456", RoundingMode::Nearest, "0", Ordering::Less);
+    test(
+        "123",
+        "456",
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Less,
+    );
+    test(
+        "123",
+        "456",
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Less,
+    );
+    test("123", "456", RoundingMode::Odd, "1", Ordering::Greater);
 
     test(
         "1000000000000",
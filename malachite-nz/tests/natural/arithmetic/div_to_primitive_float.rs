@@ -0,0 +1,224 @@
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_pair_gen_var_5;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+// Compares $x/y$ to a finite, non-negative float.
+fn cmp_quotient<T: PrimitiveFloat>(x: &Natural, y: &Natural, f: T) -> Ordering {
+    if f == T::ZERO {
+        return if *x == 0 {
+            Ordering::Equal
+        } else {
+            Ordering::Greater
+        };
+    }
+    let (m, e) = f.integer_mantissa_and_exponent();
+    if e >= 0 {
+        x.cmp(&((y * Natural::from(m)) << e))
+    } else {
+        (x << e.unsigned_abs()).cmp(&(y * Natural::from(m)))
+    }
+}
+
+#[test]
+fn test_div_to_f64_round() {
+    let test = |s, t, rm, out: f64, o| {
+        let x = Natural::from_str(s).unwrap();
+        let y = Natural::from_str(t).unwrap();
+        let (f, o_alt) = x.div_to_f64_round(&y, rm);
+        assert_eq!(NiceFloat(f), NiceFloat(out));
+        assert_eq!(o_alt, o);
+    };
+    test("0", "1", RoundingMode::Exact, 0.0, Ordering::Equal);
+    test("0", "123", RoundingMode::Floor, 0.0, Ordering::Equal);
+    test("1", "1", RoundingMode::Exact, 1.0, Ordering::Equal);
+    test("3", "4", RoundingMode::Exact, 0.75, Ordering::Equal);
+    test(
+        "1",
+        "3",
+        RoundingMode::Floor,
+        0.3333333333333333,
+        Ordering::Less,
+    );
+    test(
+        "1",
+        "3",
+        RoundingMode::Ceiling,
+        0.33333333333333337,
+        Ordering::Greater,
+    );
+    test(
+        "1",
+        "3",
+        RoundingMode::Nearest,
+        0.3333333333333333,
+        Ordering::Less,
+    );
+    test(
+        "2",
+        "3",
+        RoundingMode::Nearest,
+        0.6666666666666666,
+        Ordering::Less,
+    );
+    test(
+        "2",
+        "3",
+        RoundingMode::Up,
+        0.6666666666666667,
+        Ordering::Greater,
+    );
+    test(
+        "22",
+        "7",
+        RoundingMode::Nearest,
+        3.142857142857143,
+        Ordering::Less,
+    );
+    // 2^53 + 1 is exactly between two floats
+    test(
+        "9007199254740993",
+        "1",
+        RoundingMode::Nearest,
+        9007199254740992.0,
+        Ordering::Less,
+    );
+    test(
+        "9007199254740993",
+        "1",
+        RoundingMode::NearestTiesAway,
+        9007199254740994.0,
+        Ordering::Greater,
+    );
+    test(
+        "9007199254740995",
+        "1",
+        RoundingMode::Nearest,
+        9007199254740996.0,
+        Ordering::Greater,
+    );
+    test(
+        "9007199254740995",
+        "1",
+        RoundingMode::Odd,
+        9007199254740994.0,
+        Ordering::Less,
+    );
+    test(
+        "1000000000000000000000000000000000000000000000000000000000000000000000000000001",
+        "1000000000000000000000000000000000000000000000000000000000000000000000000000000",
+        RoundingMode::Ceiling,
+        1.0000000000000002,
+        Ordering::Greater,
+    );
+    test(
+        "1000000000000000000000000000000000000000000000000000000000000000000000000000001",
+        "1000000000000000000000000000000000000000000000000000000000000000000000000000000",
+        RoundingMode::Nearest,
+        1.0,
+        Ordering::Less,
+    );
+
+    let big = Natural::from(10u32).pow(400);
+    let (f, o) = big.div_to_f64_round(&Natural::ONE, RoundingMode::Floor);
+    assert_eq!(NiceFloat(f), NiceFloat(f64::MAX_FINITE));
+    assert_eq!(o, Ordering::Less);
+    let (f, o) = big.div_to_f64_round(&Natural::ONE, RoundingMode::Ceiling);
+    assert_eq!(NiceFloat(f), NiceFloat(f64::INFINITY));
+    assert_eq!(o, Ordering::Greater);
+    let (f, o) = Natural::ONE.div_to_f64_round(&big, RoundingMode::Nearest);
+    assert_eq!(NiceFloat(f), NiceFloat(0.0));
+    assert_eq!(o, Ordering::Less);
+    let (f, o) = Natural::ONE.div_to_f64_round(&big, RoundingMode::Up);
+    assert_eq!(NiceFloat(f), NiceFloat(f64::MIN_POSITIVE_SUBNORMAL));
+    assert_eq!(o, Ordering::Greater);
+    // 1 / 2^1075 is exactly half of the smallest positive subnormal
+    let (f, o) = Natural::ONE.div_to_f64_round(&(Natural::ONE << 1075), RoundingMode::Nearest);
+    assert_eq!(NiceFloat(f), NiceFloat(0.0));
+    assert_eq!(o, Ordering::Less);
+    let (f, o) =
+        Natural::ONE.div_to_f64_round(&(Natural::ONE << 1075), RoundingMode::NearestTiesAway);
+    assert_eq!(NiceFloat(f), NiceFloat(f64::MIN_POSITIVE_SUBNORMAL));
+    assert_eq!(o, Ordering::Greater);
+    let (f, o) = Natural::ONE.div_to_f64_round(&(Natural::ONE << 1022), RoundingMode::Exact);
+    assert_eq!(NiceFloat(f), NiceFloat(f64::MIN_POSITIVE_NORMAL));
+    assert_eq!(o, Ordering::Equal);
+    let (f, o) = Natural::from(3u32).div_to_f64_round(&(Natural::ONE << 1074), RoundingMode::Exact);
+    assert_eq!(NiceFloat(f), NiceFloat(3.0 * f64::MIN_POSITIVE_SUBNORMAL));
+    assert_eq!(o, Ordering::Equal);
+}
+
+#[test]
+#[should_panic]
+fn div_to_f64_round_fail_1() {
+    Natural::ONE.div_to_f64_round(&Natural::from(0u32), RoundingMode::Floor);
+}
+
+#[test]
+#[should_panic]
+fn div_to_f64_round_fail_2() {
+    Natural::ONE.div_to_f64_round(&Natural::from(3u32), RoundingMode::Exact);
+}
+
+fn div_to_primitive_float_round_properties_helper<T: PrimitiveFloat>(
+    x: &Natural,
+    y: &Natural,
+    div: fn(&Natural, &Natural, RoundingMode) -> (T, Ordering),
+) {
+    let (floor, o_floor) = div(x, y, RoundingMode::Floor);
+    let (ceiling, o_ceiling) = div(x, y, RoundingMode::Ceiling);
+    assert_eq!(NiceFloat(div(x, y, RoundingMode::Down).0), NiceFloat(floor));
+    assert_eq!(NiceFloat(div(x, y, RoundingMode::Up).0), NiceFloat(ceiling));
+    assert_ne!(o_floor, Ordering::Greater);
+    assert_ne!(o_ceiling, Ordering::Less);
+    assert_eq!(cmp_quotient(x, y, floor), o_floor.reverse());
+    if ceiling.is_finite() {
+        assert_eq!(cmp_quotient(x, y, ceiling), o_ceiling.reverse());
+    }
+    if o_floor == Ordering::Equal {
+        assert_eq!(NiceFloat(floor), NiceFloat(ceiling));
+        assert_eq!(o_ceiling, Ordering::Equal);
+        assert_eq!(
+            NiceFloat(div(x, y, RoundingMode::Exact).0),
+            NiceFloat(floor)
+        );
+    } else if floor != T::MAX_FINITE {
+        assert_eq!(NiceFloat(floor.next_higher()), NiceFloat(ceiling));
+    }
+    for rm in exhaustive_rounding_modes() {
+        if rm == RoundingMode::Exact {
+            continue;
+        }
+        let (f, o) = div(x, y, rm);
+        assert!(NiceFloat(f) == NiceFloat(floor) || NiceFloat(f) == NiceFloat(ceiling));
+        if f.is_finite() {
+            assert_eq!(cmp_quotient(x, y, f), o.reverse());
+        }
+    }
+}
+
+#[test]
+fn div_to_primitive_float_round_properties() {
+    natural_pair_gen_var_5().test_properties(|(x, y)| {
+        div_to_primitive_float_round_properties_helper(&x, &y, Natural::div_to_f32_round);
+        div_to_primitive_float_round_properties_helper(&x, &y, Natural::div_to_f64_round);
+
+        if x.significant_bits() <= u32::WIDTH && y.significant_bits() <= u32::WIDTH {
+            // Dividing two exactly-representable floats is correctly rounded
+            let (f, _) = x.div_to_f64_round(&y, RoundingMode::Nearest);
+            assert_eq!(
+                NiceFloat(f),
+                NiceFloat(f64::from(u32::exact_from(&x)) / f64::from(u32::exact_from(&y)))
+            );
+        }
+    });
+}
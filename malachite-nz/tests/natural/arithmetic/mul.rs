@@ -2,6 +2,8 @@ use malachite_base::num::arithmetic::traits::{DivMod, Square};
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::random::random_primitive_ints;
+use malachite_base::random::EXAMPLE_SEED;
 use malachite_base::test_util::common::rle_decode;
 use malachite_base::test_util::generators::common::GenConfig;
 use malachite_base::test_util::generators::{
@@ -48,7 +50,8 @@ use malachite_nz::natural::arithmetic::mul::toom::{
 };
 use malachite_nz::natural::arithmetic::mul::{
     limbs_mul, limbs_mul_greater, limbs_mul_greater_to_out, limbs_mul_greater_to_out_basecase,
-    limbs_mul_greater_to_out_scratch_len, limbs_mul_same_length_to_out,
+    limbs_mul_greater_to_out_parallel_chunks, limbs_mul_greater_to_out_scratch_len,
+    limbs_mul_same_length_to_out, limbs_mul_same_length_to_out_parallel_karatsuba,
     limbs_mul_same_length_to_out_scratch_len, limbs_mul_to_out, limbs_mul_to_out_scratch_len,
 };
 use malachite_nz::natural::Natural;
@@ -12222,6 +12225,88 @@ fn test_mul() {
     );
 }
 
+// These inputs are large enough that, if the `parallel` feature is enabled, the multithreaded
+// code paths are used.
+#[test]
+fn test_large_mul_and_product() {
+    let mut limbs = random_primitive_ints::<Limb>(EXAMPLE_SEED);
+    let mut random_natural =
+        |len: usize| Natural::from_owned_limbs_asc(limbs.by_ref().take(len).collect());
+    for &(xs_len, ys_len) in &[(12000, 12000), (45000, 45000), (30000, 100), (25000, 400)] {
+        let x = random_natural(xs_len);
+        let y = random_natural(ys_len);
+        let product = &x * &y;
+        assert!(product.is_valid());
+        assert_eq!(
+            product,
+            Natural::exact_from(&(rug::Integer::from(&x) * rug::Integer::from(&y)))
+        );
+        let square = (&x).square();
+        assert!(square.is_valid());
+        assert_eq!(
+            square,
+            Natural::exact_from(&rug::Integer::from(&x).square())
+        );
+        if xs_len == ys_len {
+            // A small cutoff makes the FFT use recursive pointwise products.
+            let xs = x.to_limbs_asc();
+            let ys = y.to_limbs_asc();
+            let mut out = vec![0; xs_len << 1];
+            let mut scratch =
+                vec![0; limbs_mul_greater_to_out_fft_with_cutoff_scratch_len(xs_len, ys_len, 1)];
+            limbs_mul_greater_to_out_fft_with_cutoff(&mut out, &xs, &ys, 1, &mut scratch);
+            assert_eq!(Natural::from_owned_limbs_asc(out), product);
+            let mut out = vec![0; xs_len << 1];
+            let mut scratch = vec![0; limbs_square_to_out_fft_with_cutoff_scratch_len(xs_len, 1)];
+            limbs_square_to_out_fft_with_cutoff(&mut out, &xs, 1, &mut scratch);
+            assert_eq!(Natural::from_owned_limbs_asc(out), square);
+        }
+    }
+
+    let mut xs: Vec<Limb> = limbs.take(5000).collect();
+    let xs_old = xs.clone();
+    let mut out = vec![0; xs.len()];
+    let out_len = limbs_product(&mut out, &mut xs);
+    out.truncate(out_len);
+    let mut out_alt = vec![0; xs.len()];
+    let out_len = limbs_product_naive(&mut out_alt, &xs_old);
+    out_alt.truncate(out_len);
+    assert_eq!(out, out_alt);
+}
+
+// The multithreaded algorithms run sequentially if the `parallel` feature is disabled. Their
+// results are compared with those of basecase multiplication, which always runs on one thread.
+#[test]
+fn test_limbs_mul_parallel() {
+    let mut limbs = random_primitive_ints::<Limb>(EXAMPLE_SEED.fork("parallel"));
+    let mut random_limbs = |len: usize| -> Vec<Limb> { limbs.by_ref().take(len).collect() };
+    let test_same_length = |xs: &[Limb], ys: &[Limb]| {
+        let len = xs.len();
+        let mut out = vec![0; len << 1];
+        limbs_mul_greater_to_out_basecase(&mut out, xs, ys);
+        let mut out_alt = vec![0; len << 1];
+        limbs_mul_same_length_to_out_parallel_karatsuba(&mut out_alt, xs, ys);
+        assert_eq!(out_alt, out);
+        let mut out_alt = vec![0; len << 1];
+        let mut scratch = vec![0; limbs_mul_same_length_to_out_scratch_len(len)];
+        limbs_mul_same_length_to_out(&mut out_alt, xs, ys, &mut scratch);
+        assert_eq!(out_alt, out);
+    };
+    for len in [2, 3, 100, 999, 1000, 1001, 2500, 5001] {
+        test_same_length(&random_limbs(len), &random_limbs(len));
+        test_same_length(&vec![Limb::MAX; len], &vec![Limb::MAX; len]);
+    }
+    for (xs_len, ys_len) in [(4, 1), (100, 20), (1001, 250), (25000, 400)] {
+        let xs = random_limbs(xs_len);
+        let ys = random_limbs(ys_len);
+        let mut out = vec![0; xs_len + ys_len];
+        limbs_mul_greater_to_out_basecase(&mut out, &xs, &ys);
+        let mut out_alt = vec![0; xs_len + ys_len];
+        limbs_mul_greater_to_out_parallel_chunks(&mut out_alt, &xs, &ys);
+        assert_eq!(out_alt, out);
+    }
+}
+
 #[test]
 fn limbs_product_properties() {
     let mut config = GenConfig::new();
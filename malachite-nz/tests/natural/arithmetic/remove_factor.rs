@@ -0,0 +1,190 @@
+use malachite_base::num::arithmetic::traits::{
+    DivExact, DivisibleBy, Pow, RemoveFactor, RemoveFactorAssign, Valuation,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::strings::ToDebugString;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_gen_var_1, natural_pair_gen_var_3};
+use malachite_nz::test_util::natural::arithmetic::remove_factor::remove_factor_simple;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_remove_factor() {
+    let test = |s, t, out| {
+        let x = Natural::from_str(s).unwrap();
+        let p = Natural::from_str(t).unwrap();
+
+        let result = x.clone().remove_factor(p.clone());
+        assert!(result.0.is_valid());
+        assert_eq!(result.to_debug_string(), out);
+
+        let result = x.clone().remove_factor(&p);
+        assert!(result.0.is_valid());
+        assert_eq!(result.to_debug_string(), out);
+
+        let result = (&x).remove_factor(p.clone());
+        assert!(result.0.is_valid());
+        assert_eq!(result.to_debug_string(), out);
+
+        let result = (&x).remove_factor(&p);
+        assert!(result.0.is_valid());
+        assert_eq!(result.to_debug_string(), out);
+
+        let mut mut_x = x.clone();
+        let multiplicity = mut_x.remove_factor_assign(p.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!((mut_x, multiplicity).to_debug_string(), out);
+
+        let mut mut_x = x.clone();
+        let multiplicity = mut_x.remove_factor_assign(&p);
+        assert!(mut_x.is_valid());
+        assert_eq!((mut_x, multiplicity).to_debug_string(), out);
+
+        assert_eq!(remove_factor_simple(x, &p).to_debug_string(), out);
+    };
+    test("1", "2", "(1, 0)");
+    test("1", "10", "(1, 0)");
+    test("7", "7", "(1, 1)");
+    test("360", "2", "(45, 3)");
+    test("360", "3", "(40, 2)");
+    test("360", "5", "(72, 1)");
+    test("360", "6", "(10, 2)");
+    test("360", "7", "(360, 0)");
+    test("360", "4", "(90, 1)");
+    test("1024", "8", "(2, 3)");
+    test("1000000000000", "10", "(1, 12)");
+    test("1000000000000", "1000", "(1, 4)");
+    test("1000000000000", "100000", "(100, 2)");
+    test("3486784401", "3", "(1, 20)");
+    test("6973568802", "3", "(2, 20)");
+    test("1853020188851841", "3", "(1, 32)");
+    test("5559060566555523", "3", "(1, 33)");
+    test("340282366920938463463374607431768211456", "2", "(1, 128)");
+    test("340282366920938463463374607431768211456", "16", "(1, 32)");
+    test("340282366920938463463374607431768211456", "256", "(1, 16)");
+    test(
+        "340282366920938463463374607431768211456",
+        "1024",
+        "(256, 12)",
+    );
+    test(
+        "1000000000000000000000000000000000000000000000000000000000001",
+        "10",
+        "(1000000000000000000000000000000000000000000000000000000000001, 0)",
+    );
+
+    // p = 2^100 - 1; dividing p^40 by p^16 used to overflow a carry in debug builds
+    let p = Natural::from_str("1267650600228229401496703205375").unwrap();
+    let x = (&p).pow(40);
+    assert_eq!((&x).remove_factor(&p), (Natural::ONE, 40));
+    assert_eq!((&x).div_exact(&(&p).pow(16)), (&p).pow(24));
+    let y = x * Natural::from(3u32);
+    assert_eq!((&y).remove_factor(&p), (Natural::from(3u32), 40));
+    assert_eq!(remove_factor_simple(y, &p), (Natural::from(3u32), 40));
+}
+
+#[test]
+fn remove_factor_fail() {
+    assert_panic!(Natural::ZERO.remove_factor(Natural::from(3u32)));
+    assert_panic!(Natural::from(3u32).remove_factor(Natural::ZERO));
+    assert_panic!(Natural::from(3u32).remove_factor(Natural::ONE));
+    assert_panic!(Natural::ZERO.remove_factor(&Natural::from(3u32)));
+    assert_panic!(Natural::from(3u32).remove_factor(&Natural::ONE));
+    assert_panic!((&Natural::ZERO).remove_factor(Natural::from(3u32)));
+    assert_panic!((&Natural::from(3u32)).remove_factor(Natural::ONE));
+    assert_panic!((&Natural::ZERO).remove_factor(&Natural::from(3u32)));
+    assert_panic!((&Natural::from(3u32)).remove_factor(&Natural::ONE));
+    assert_panic!({
+        let mut x = Natural::ZERO;
+        x.remove_factor_assign(Natural::from(3u32))
+    });
+    assert_panic!({
+        let mut x = Natural::from(3u32);
+        x.remove_factor_assign(&Natural::ONE)
+    });
+}
+
+#[test]
+fn test_valuation() {
+    let test = |s, t, out| {
+        let x = Natural::from_str(s).unwrap();
+        let p = Natural::from_str(t).unwrap();
+
+        assert_eq!(x.clone().valuation(p.clone()), out);
+        assert_eq!(x.clone().valuation(&p), out);
+        assert_eq!((&x).valuation(p.clone()), out);
+        assert_eq!((&x).valuation(&p), out);
+    };
+    test("1", "2", 0);
+    test("360", "2", 3);
+    test("360", "3", 2);
+    test("360", "6", 2);
+    test("360", "7", 0);
+    test("1000000000000", "10", 12);
+    test("340282366920938463463374607431768211456", "4", 64);
+}
+
+#[test]
+fn valuation_fail() {
+    assert_panic!(Natural::ZERO.valuation(Natural::from(3u32)));
+    assert_panic!(Natural::from(3u32).valuation(Natural::ONE));
+    assert_panic!(Natural::ZERO.valuation(&Natural::from(3u32)));
+    assert_panic!(Natural::from(3u32).valuation(&Natural::ONE));
+    assert_panic!((&Natural::ZERO).valuation(Natural::from(3u32)));
+    assert_panic!((&Natural::from(3u32)).valuation(Natural::ONE));
+    assert_panic!((&Natural::ZERO).valuation(&Natural::from(3u32)));
+    assert_panic!((&Natural::from(3u32)).valuation(&Natural::ONE));
+}
+
+#[test]
+fn remove_factor_properties() {
+    natural_pair_gen_var_3().test_properties(|(x, p)| {
+        let (stripped, multiplicity) = (&x).remove_factor(&p);
+        assert!(stripped.is_valid());
+
+        let (stripped_alt, multiplicity_alt) = x.clone().remove_factor(p.clone());
+        assert!(stripped_alt.is_valid());
+        assert_eq!(stripped_alt, stripped);
+        assert_eq!(multiplicity_alt, multiplicity);
+
+        let (stripped_alt, multiplicity_alt) = x.clone().remove_factor(&p);
+        assert!(stripped_alt.is_valid());
+        assert_eq!(stripped_alt, stripped);
+        assert_eq!(multiplicity_alt, multiplicity);
+
+        let (stripped_alt, multiplicity_alt) = (&x).remove_factor(p.clone());
+        assert!(stripped_alt.is_valid());
+        assert_eq!(stripped_alt, stripped);
+        assert_eq!(multiplicity_alt, multiplicity);
+
+        let mut mut_x = x.clone();
+        assert_eq!(mut_x.remove_factor_assign(&p), multiplicity);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, stripped);
+
+        let mut mut_x = x.clone();
+        assert_eq!(mut_x.remove_factor_assign(p.clone()), multiplicity);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, stripped);
+
+        assert_eq!(
+            remove_factor_simple(x.clone(), &p),
+            (stripped.clone(), multiplicity)
+        );
+        assert_eq!((&x).valuation(&p), multiplicity);
+        assert_ne!(stripped, 0u32);
+        assert!(!(&stripped).divisible_by(&p));
+        assert_eq!(&stripped * (&p).pow(multiplicity), x);
+
+        let y = &x * (&p).pow(5);
+        assert_eq!((&y).remove_factor(&p), (stripped, multiplicity + 5));
+    });
+
+    natural_gen_var_1().test_properties(|p| {
+        assert_eq!(Natural::ONE.remove_factor(&p), (Natural::ONE, 0));
+        assert_eq!((&p).remove_factor(&p), (Natural::ONE, 1));
+        let x = (&p).pow(40);
+        assert_eq!(x.remove_factor(&p), (Natural::ONE, 40));
+    });
+}
@@ -55,6 +55,21 @@ fn test_round_to_multiple() {
     test("0", "1", RoundingMode::Up, "0", Ordering::Equal);
     test("0", "1", RoundingMode::Ceiling, "0", Ordering::Equal);
     test("0", "1", RoundingMode::Nearest, "0", Ordering::Equal);
+    test(
+        "0",
+        "1",
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "1",
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Equal,
+    );
+    test("0", "1", RoundingMode::Odd, "0", Ordering::Equal);
     test("0", "1", RoundingMode::Exact, "0", Ordering::Equal);
 
     test("0", "123", RoundingMode::Down, "0", Ordering::Equal);
@@ -62,6 +77,21 @@ fn test_round_to_multiple() {
     test("0", "123", RoundingMode::Up, "0", Ordering::Equal);
     test("0", "123", RoundingMode::Ceiling, "0", Ordering::Equal);
     test("0", "123", RoundingMode::Nearest, "0", Ordering::Equal);
+    test(
+        "0",
+        "123",
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "123",
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Equal,
+    );
+    test("0", "123", RoundingMode::Odd, "0", Ordering::Equal);
     test("0", "123", RoundingMode::Exact, "0", Ordering::Equal);
 
     test("1", "1", RoundingMode::Down, "1", Ordering::Equal);
@@ -69,6 +99,21 @@ fn test_round_to_multiple() {
     test("1", "1", RoundingMode::Up, "1", Ordering::Equal);
     test("1", "1", RoundingMode::Ceiling, "1", Ordering::Equal);
     test("1", "1", RoundingMode::Nearest, "1", Ordering::Equal);
+    test(
+        "1",
+        "1",
+        RoundingMode::NearestTiesAway,
+        "1",
+        Ordering::Equal,
+    );
+    test(
+        "1",
+        "1",
+        RoundingMode::NearestTiesTowardZero,
+        "1",
+        Ordering::Equal,
+    );
+    test("1", "1", RoundingMode::Odd, "1", Ordering::Equal);
     test("1", "1", RoundingMode::Exact, "1", Ordering::Equal);
 
     test("123", "1", RoundingMode::Down, "123", Ordering::Equal);
@@ -76,6 +121,21 @@ fn test_round_to_multiple() {
     test("123", "1", RoundingMode::Up, "123", Ordering::Equal);
     test("123", "1", RoundingMode::Ceiling, "123", Ordering::Equal);
     test("123", "1", RoundingMode::Nearest, "123", Ordering::Equal);
+    test(
+        "123",
+        "1",
+        RoundingMode::NearestTiesAway,
+        "123",
+        Ordering::Equal,
+    );
+    test(
+        "123",
+        "1",
+        RoundingMode::NearestTiesTowardZero,
+        "123",
+        Ordering::Equal,
+    );
+    test("123", "1", RoundingMode::Odd, "123", Ordering::Equal);
     test("123", "1", RoundingMode::Exact, "123", Ordering::Equal);
 
     test("123", "2", RoundingMode::Down, "122", Ordering::Less);
@@ -83,18 +143,63 @@ fn test_round_to_multiple() {
     test("123", "2", RoundingMode::Up, "124", Ordering::Greater);
     test("123", "2", RoundingMode::Ceiling, "124", Ordering::Greater);
     test("123", "2", RoundingMode::Nearest, "124", Ordering::Greater);
+    test(
+        "123",
+        "2",
+        RoundingMode::NearestTiesAway,
+        "124",
+        Ordering::Greater,
+    );
+    test(
+        "123",
+        "2",
+        RoundingMode::NearestTiesTowardZero,
+        "122",
+        Ordering::Less,
+    );
+    test("123", "2", RoundingMode::Odd, "122", Ordering::Less);
 
     test("125", "2", RoundingMode::Down, "124", Ordering::Less);
     test("125", "2", RoundingMode::Floor, "124", Ordering::Less);
     test("125", "2", RoundingMode::Up, "126", Ordering::Greater);
     test("125", "2", RoundingMode::Ceiling, "126", Ordering::Greater);
     test("125", "2", RoundingMode::Nearest, "124", Ordering::Less);
+    test(
+        "125",
+        "2",
+        RoundingMode::NearestTiesAway,
+        "126",
+        Ordering::Greater,
+    );
+    test(
+        "125",
+        "2",
+        RoundingMode::NearestTiesTowardZero,
+        "124",
+        Ordering::Less,
+    );
+    test("125", "2", RoundingMode::Odd, "126", Ordering::Greater);
 
     test("123", "123", RoundingMode::Down, "123", Ordering::Equal);
     test("123", "123", RoundingMode::Floor, "123", Ordering::Equal);
     test("123", "123", RoundingMode::Up, "123", Ordering::Equal);
     test("123", "123", RoundingMode::Ceiling, "123", Ordering::Equal);
     test("123", "123", RoundingMode::Nearest, "123", Ordering::Equal);
+    test(
+        "123",
+        "123",
+        RoundingMode::NearestTiesAway,
+        "123",
+        Ordering::Equal,
+    );
+    test(
+        "123",
+        "123",
+        RoundingMode::NearestTiesTowardZero,
+        "123",
+        Ordering::Equal,
+    );
+    test("123", "123", RoundingMode::Odd, "123", Ordering::Equal);
     test("123", "123", RoundingMode::Exact, "123", Ordering::Equal);
 
     test("123", "456", RoundingMode::Down, "0", Ordering::Less);
@@ -108,6 +213,21 @@ fn test_round_to_multiple() {
         Ordering::Greater,
     );
     test("123", "456", RoundingMode::Nearest, "0", Ordering::Less);
+    test(
+        "123",
+        "456",
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Less,
+    );
+    test(
+        "123",
+        "456",
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Less,
+    );
+    test("123", "456", RoundingMode::Odd, "456", Ordering::Greater);
 
     test(
         "1000000000000",
@@ -610,19 +730,30 @@ fn round_to_multiple_properties() {
                 RoundingMode::Floor | RoundingMode::Down => assert!(r <= x),
                 RoundingMode::Ceiling | RoundingMode::Up => assert!(r >= x),
                 RoundingMode::Exact => assert_eq!(r, x),
-                RoundingMode::Nearest => {
+                RoundingMode::Odd => {
+                    assert!(r == x || (&r).div_round(&y, RoundingMode::Exact).0.odd())
+                }
+                RoundingMode::Nearest
+                | RoundingMode::NearestTiesAway
+                | RoundingMode::NearestTiesTowardZero => {
                     let closest;
                     let second_closest;
                     if r <= x {
                         closest = &x - &r;
-                        second_closest = &r + &y - x;
+                        second_closest = &r + &y - &x;
                     } else {
                         closest = &r - &x;
-                        second_closest = x + &y - &r;
+                        second_closest = &x + &y - &r;
                     }
                     assert!(closest <= second_closest);
                     if closest == second_closest {
-                        assert!((&r).div_round(&y, RoundingMode::Exact).0.even());
+                        match rm {
+                            RoundingMode::Nearest => {
+                                assert!((&r).div_round(&y, RoundingMode::Exact).0.even())
+                            }
+                            RoundingMode::NearestTiesAway => assert!(r >= x),
+                            _ => assert!(r <= x),
+                        }
                     }
                 }
             }
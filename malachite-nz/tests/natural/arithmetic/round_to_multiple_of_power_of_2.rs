@@ -18,6 +18,11 @@ use malachite_nz::natural::arithmetic::round_to_multiple_of_power_of_2::{
     limbs_round_to_multiple_of_power_of_2_down_in_place,
     limbs_round_to_multiple_of_power_of_2_in_place, limbs_round_to_multiple_of_power_of_2_nearest,
     limbs_round_to_multiple_of_power_of_2_nearest_in_place,
+    limbs_round_to_multiple_of_power_of_2_nearest_ties_away,
+    limbs_round_to_multiple_of_power_of_2_nearest_ties_away_in_place,
+    limbs_round_to_multiple_of_power_of_2_nearest_ties_toward_zero,
+    limbs_round_to_multiple_of_power_of_2_nearest_ties_toward_zero_in_place,
+    limbs_round_to_multiple_of_power_of_2_odd, limbs_round_to_multiple_of_power_of_2_odd_in_place,
     limbs_round_to_multiple_of_power_of_2_up, limbs_round_to_multiple_of_power_of_2_up_in_place,
 };
 use malachite_nz::natural::Natural;
@@ -132,6 +137,120 @@ fn test_limbs_round_to_multiple_of_power_of_2_nearest() {
     test(&[u32::MAX, u32::MAX], 32, &[0, 0, 1], Ordering::Greater);
 }
 
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+fn test_limbs_round_to_multiple_of_power_of_2_nearest_ties_away() {
+    let test = |xs: &[Limb], pow: u64, out: &[Limb], o: Ordering| {
+        let (r, o_alt) = limbs_round_to_multiple_of_power_of_2_nearest_ties_away(xs, pow);
+        assert_eq!(r, out);
+        assert_eq!(o_alt, o);
+
+        let mut xs = xs.to_vec();
+        assert_eq!(
+            limbs_round_to_multiple_of_power_of_2_nearest_ties_away_in_place(&mut xs, pow),
+            o
+        );
+        assert_eq!(xs, out);
+    };
+    test(&[], 0, &[], Ordering::Equal);
+    test(&[], 1, &[], Ordering::Equal);
+    test(&[], 100, &[], Ordering::Equal);
+    test(&[0, 0, 0], 0, &[0, 0, 0], Ordering::Equal);
+    test(&[0, 0, 0], 1, &[0, 0, 0], Ordering::Equal);
+    test(&[0, 0, 0], 100, &[], Ordering::Equal);
+    test(&[1], 0, &[1], Ordering::Equal);
+    test(&[1], 1, &[2], Ordering::Greater);
+    test(&[3], 1, &[4], Ordering::Greater);
+    test(&[122, 456], 1, &[122, 456], Ordering::Equal);
+    test(&[123, 456], 0, &[123, 456], Ordering::Equal);
+    test(&[123, 456], 1, &[124, 456], Ordering::Greater);
+    test(&[123, 455], 1, &[124, 455], Ordering::Greater);
+    test(&[123, 456], 31, &[0, 456], Ordering::Less);
+    test(&[123, 456], 32, &[0, 456], Ordering::Less);
+    test(&[123, 456], 100, &[], Ordering::Less);
+    test(&[256, 456], 8, &[256, 456], Ordering::Equal);
+    test(&[u32::MAX, 1], 1, &[0, 2], Ordering::Greater);
+    test(&[u32::MAX, u32::MAX], 32, &[0, 0, 1], Ordering::Greater);
+    test(&[2147483648, 1], 32, &[0, 2], Ordering::Greater);
+    test(&[2147483648, 2], 32, &[0, 3], Ordering::Greater);
+    test(&[2147483648], 32, &[0, 1], Ordering::Greater);
+    test(&[5], 1, &[6], Ordering::Greater);
+}
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+fn test_limbs_round_to_multiple_of_power_of_2_nearest_ties_toward_zero() {
+    let test = |xs: &[Limb], pow: u64, out: &[Limb], o: Ordering| {
+        let (r, o_alt) = limbs_round_to_multiple_of_power_of_2_nearest_ties_toward_zero(xs, pow);
+        assert_eq!(r, out);
+        assert_eq!(o_alt, o);
+
+        let mut xs = xs.to_vec();
+        assert_eq!(
+            limbs_round_to_multiple_of_power_of_2_nearest_ties_toward_zero_in_place(&mut xs, pow),
+            o
+        );
+        assert_eq!(xs, out);
+    };
+    test(&[], 0, &[], Ordering::Equal);
+    test(&[], 1, &[], Ordering::Equal);
+    test(&[], 100, &[], Ordering::Equal);
+    test(&[0, 0, 0], 0, &[0, 0, 0], Ordering::Equal);
+    test(&[0, 0, 0], 1, &[0, 0, 0], Ordering::Equal);
+    test(&[0, 0, 0], 100, &[], Ordering::Equal);
+    test(&[1], 0, &[1], Ordering::Equal);
+    test(&[1], 1, &[0], Ordering::Less);
+    test(&[3], 1, &[2], Ordering::Less);
+    test(&[122, 456], 1, &[122, 456], Ordering::Equal);
+    test(&[123, 456], 0, &[123, 456], Ordering::Equal);
+    test(&[123, 456], 1, &[122, 456], Ordering::Less);
+    test(&[123, 455], 1, &[122, 455], Ordering::Less);
+    test(&[123, 456], 31, &[0, 456], Ordering::Less);
+    test(&[123, 456], 32, &[0, 456], Ordering::Less);
+    test(&[123, 456], 100, &[], Ordering::Less);
+    test(&[256, 456], 8, &[256, 456], Ordering::Equal);
+    test(&[u32::MAX, 1], 1, &[4294967294, 1], Ordering::Less);
+    test(&[u32::MAX, u32::MAX], 32, &[0, 0, 1], Ordering::Greater);
+    test(&[2147483648, 1], 32, &[0, 1], Ordering::Less);
+    test(&[2147483648, 2], 32, &[0, 2], Ordering::Less);
+    test(&[2147483648], 32, &[], Ordering::Less);
+    test(&[5], 1, &[4], Ordering::Less);
+}
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+fn test_limbs_round_to_multiple_of_power_of_2_odd() {
+    let test = |xs: &[Limb], pow: u64, out: &[Limb], o: Ordering| {
+        let (r, o_alt) = limbs_round_to_multiple_of_power_of_2_odd(xs, pow);
+        assert_eq!(r, out);
+        assert_eq!(o_alt, o);
+
+        let mut xs = xs.to_vec();
+        assert_eq!(
+            limbs_round_to_multiple_of_power_of_2_odd_in_place(&mut xs, pow),
+            o
+        );
+        assert_eq!(xs, out);
+    };
+    test(&[1], 0, &[1], Ordering::Equal);
+    test(&[1], 1, &[2], Ordering::Greater);
+    test(&[3], 1, &[2], Ordering::Less);
+    test(&[122, 456], 1, &[122, 456], Ordering::Equal);
+    test(&[123, 456], 0, &[123, 456], Ordering::Equal);
+    test(&[123, 456], 1, &[122, 456], Ordering::Less);
+    test(&[123, 455], 1, &[122, 455], Ordering::Less);
+    test(&[123, 456], 31, &[2147483648, 456], Ordering::Greater);
+    test(&[123, 456], 32, &[0, 457], Ordering::Greater);
+    test(&[123, 456], 100, &[0, 0, 0, 16], Ordering::Greater);
+    test(&[256, 456], 8, &[256, 456], Ordering::Equal);
+    test(&[u32::MAX, 1], 1, &[4294967294, 1], Ordering::Less);
+    test(&[u32::MAX, u32::MAX], 32, &[0, u32::MAX], Ordering::Less);
+    test(&[2147483648, 1], 32, &[0, 1], Ordering::Less);
+    test(&[2147483648, 2], 32, &[0, 3], Ordering::Greater);
+    test(&[2147483648], 32, &[0, 1], Ordering::Greater);
+    test(&[5], 1, &[6], Ordering::Greater);
+}
+
 #[cfg(feature = "32_bit_limbs")]
 #[test]
 fn test_limbs_round_to_multiple_of_power_of_2() {
@@ -254,6 +373,15 @@ fn test_round_to_multiple_of_power_of_2() {
     test("0", 0, RoundingMode::Floor, "0", Ordering::Equal);
     test("0", 0, RoundingMode::Ceiling, "0", Ordering::Equal);
     test("0", 0, RoundingMode::Nearest, "0", Ordering::Equal);
+    test("0", 0, RoundingMode::NearestTiesAway, "0", Ordering::Equal);
+    test(
+        "0",
+        0,
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Equal,
+    );
+    test("0", 0, RoundingMode::Odd, "0", Ordering::Equal);
     test("0", 0, RoundingMode::Exact, "0", Ordering::Equal);
 
     test("0", 10, RoundingMode::Down, "0", Ordering::Equal);
@@ -261,6 +389,15 @@ fn test_round_to_multiple_of_power_of_2() {
     test("0", 10, RoundingMode::Floor, "0", Ordering::Equal);
     test("0", 10, RoundingMode::Ceiling, "0", Ordering::Equal);
     test("0", 10, RoundingMode::Nearest, "0", Ordering::Equal);
+    test("0", 10, RoundingMode::NearestTiesAway, "0", Ordering::Equal);
+    test(
+        "0",
+        10,
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Equal,
+    );
+    test("0", 10, RoundingMode::Odd, "0", Ordering::Equal);
     test("0", 10, RoundingMode::Exact, "0", Ordering::Equal);
 
     test("123", 0, RoundingMode::Down, "123", Ordering::Equal);
@@ -268,6 +405,21 @@ fn test_round_to_multiple_of_power_of_2() {
     test("123", 0, RoundingMode::Floor, "123", Ordering::Equal);
     test("123", 0, RoundingMode::Ceiling, "123", Ordering::Equal);
     test("123", 0, RoundingMode::Nearest, "123", Ordering::Equal);
+    test(
+        "123",
+        0,
+        RoundingMode::NearestTiesAway,
+        "123",
+        Ordering::Equal,
+    );
+    test(
+        "123",
+        0,
+        RoundingMode::NearestTiesTowardZero,
+        "123",
+        Ordering::Equal,
+    );
+    test("123", 0, RoundingMode::Odd, "123", Ordering::Equal);
     test("123", 0, RoundingMode::Exact, "123", Ordering::Equal);
 
     test("245", 1, RoundingMode::Down, "244", Ordering::Less);
@@ -275,12 +427,42 @@ fn test_round_to_multiple_of_power_of_2() {
     test("245", 1, RoundingMode::Floor, "244", Ordering::Less);
     test("245", 1, RoundingMode::Ceiling, "246", Ordering::Greater);
     test("245", 1, RoundingMode::Nearest, "244", Ordering::Less);
+    test(
+        "245",
+        1,
+        RoundingMode::NearestTiesAway,
+        "246",
+        Ordering::Greater,
+    );
+    test(
+        "245",
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        "244",
+        Ordering::Less,
+    );
+    test("245", 1, RoundingMode::Odd, "246", Ordering::Greater);
 
     test("246", 1, RoundingMode::Down, "246", Ordering::Equal);
     test("246", 1, RoundingMode::Up, "246", Ordering::Equal);
     test("246", 1, RoundingMode::Floor, "246", Ordering::Equal);
     test("246", 1, RoundingMode::Ceiling, "246", Ordering::Equal);
     test("246", 1, RoundingMode::Nearest, "246", Ordering::Equal);
+    test(
+        "246",
+        1,
+        RoundingMode::NearestTiesAway,
+        "246",
+        Ordering::Equal,
+    );
+    test(
+        "246",
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        "246",
+        Ordering::Equal,
+    );
+    test("246", 1, RoundingMode::Odd, "246", Ordering::Equal);
     test("246", 1, RoundingMode::Exact, "246", Ordering::Equal);
 
     test("247", 1, RoundingMode::Down, "246", Ordering::Less);
@@ -288,18 +470,63 @@ fn test_round_to_multiple_of_power_of_2() {
     test("247", 1, RoundingMode::Floor, "246", Ordering::Less);
     test("247", 1, RoundingMode::Ceiling, "248", Ordering::Greater);
     test("247", 1, RoundingMode::Nearest, "248", Ordering::Greater);
+    test(
+        "247",
+        1,
+        RoundingMode::NearestTiesAway,
+        "248",
+        Ordering::Greater,
+    );
+    test(
+        "247",
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        "246",
+        Ordering::Less,
+    );
+    test("247", 1, RoundingMode::Odd, "246", Ordering::Less);
 
     test("491", 2, RoundingMode::Down, "488", Ordering::Less);
     test("491", 2, RoundingMode::Up, "492", Ordering::Greater);
     test("491", 2, RoundingMode::Floor, "488", Ordering::Less);
     test("491", 2, RoundingMode::Ceiling, "492", Ordering::Greater);
     test("491", 2, RoundingMode::Nearest, "492", Ordering::Greater);
+    test(
+        "491",
+        2,
+        RoundingMode::NearestTiesAway,
+        "492",
+        Ordering::Greater,
+    );
+    test(
+        "491",
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        "492",
+        Ordering::Greater,
+    );
+    test("491", 2, RoundingMode::Odd, "492", Ordering::Greater);
 
     test("492", 2, RoundingMode::Down, "492", Ordering::Equal);
     test("492", 2, RoundingMode::Up, "492", Ordering::Equal);
     test("492", 2, RoundingMode::Floor, "492", Ordering::Equal);
     test("492", 2, RoundingMode::Ceiling, "492", Ordering::Equal);
     test("492", 2, RoundingMode::Nearest, "492", Ordering::Equal);
+    test(
+        "492",
+        2,
+        RoundingMode::NearestTiesAway,
+        "492",
+        Ordering::Equal,
+    );
+    test(
+        "492",
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        "492",
+        Ordering::Equal,
+    );
+    test("492", 2, RoundingMode::Odd, "492", Ordering::Equal);
     test("492", 2, RoundingMode::Exact, "492", Ordering::Equal);
 
     test("493", 2, RoundingMode::Down, "492", Ordering::Less);
@@ -307,6 +534,21 @@ fn test_round_to_multiple_of_power_of_2() {
     test("493", 2, RoundingMode::Floor, "492", Ordering::Less);
     test("493", 2, RoundingMode::Ceiling, "496", Ordering::Greater);
     test("493", 2, RoundingMode::Nearest, "492", Ordering::Less);
+    test(
+        "493",
+        2,
+        RoundingMode::NearestTiesAway,
+        "492",
+        Ordering::Less,
+    );
+    test(
+        "493",
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        "492",
+        Ordering::Less,
+    );
+    test("493", 2, RoundingMode::Odd, "492", Ordering::Less);
 
     test(
         "4127195135",
@@ -1243,6 +1485,27 @@ fn test_round_to_multiple_of_power_of_2() {
         Ordering::Greater,
     );
     test("980657949", 72, RoundingMode::Nearest, "0", Ordering::Less);
+    test(
+        "980657949",
+        72,
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Less,
+    );
+    test(
+        "980657949",
+        72,
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Less,
+    );
+    test(
+        "980657949",
+        72,
+        RoundingMode::Odd,
+        "4722366482869645213696",
+        Ordering::Greater,
+    );
 
     test(
         "4294967295",
@@ -1364,6 +1627,27 @@ fn test_round_to_multiple_of_power_of_2() {
         Ordering::Greater,
     );
     test("4294967296", 33, RoundingMode::Nearest, "0", Ordering::Less);
+    test(
+        "4294967296",
+        33,
+        RoundingMode::NearestTiesAway,
+        "8589934592",
+        Ordering::Greater,
+    );
+    test(
+        "4294967296",
+        33,
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Less,
+    );
+    test(
+        "4294967296",
+        33,
+        RoundingMode::Odd,
+        "8589934592",
+        Ordering::Greater,
+    );
 }
 
 #[test]
@@ -1494,6 +1778,56 @@ fn limbs_round_to_multiple_of_power_of_2_nearest_properties() {
     });
 }
 
+#[test]
+fn limbs_round_to_multiple_of_power_of_2_nearest_ties_away_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_unsigned_pair_gen_var_16().test_properties_with_config(&config, |(xs, pow)| {
+        let (r, o) = limbs_round_to_multiple_of_power_of_2_nearest_ties_away(&xs, pow);
+        let r = Natural::from_owned_limbs_asc(r);
+        let x = Natural::from_owned_limbs_asc(xs);
+        let (r_alt, o_alt) =
+            (&x).round_to_multiple_of_power_of_2(pow, RoundingMode::NearestTiesAway);
+        assert_eq!(r_alt, r);
+        assert_eq!(o_alt, o);
+        assert_eq!(r.cmp(&x), o);
+    });
+}
+
+#[test]
+fn limbs_round_to_multiple_of_power_of_2_nearest_ties_toward_zero_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_unsigned_pair_gen_var_16().test_properties_with_config(&config, |(xs, pow)| {
+        let (r, o) = limbs_round_to_multiple_of_power_of_2_nearest_ties_toward_zero(&xs, pow);
+        let r = Natural::from_owned_limbs_asc(r);
+        let x = Natural::from_owned_limbs_asc(xs);
+        let (r_alt, o_alt) =
+            (&x).round_to_multiple_of_power_of_2(pow, RoundingMode::NearestTiesTowardZero);
+        assert_eq!(r_alt, r);
+        assert_eq!(o_alt, o);
+        assert_eq!(r.cmp(&x), o);
+    });
+}
+
+#[test]
+fn limbs_round_to_multiple_of_power_of_2_odd_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_unsigned_pair_gen_var_20().test_properties_with_config(&config, |(xs, pow)| {
+        let (r, o) = limbs_round_to_multiple_of_power_of_2_odd(&xs, pow);
+        let r = Natural::from_owned_limbs_asc(r);
+        let x = Natural::from_owned_limbs_asc(xs);
+        let (r_alt, o_alt) = (&x).round_to_multiple_of_power_of_2(pow, RoundingMode::Odd);
+        assert_eq!(r_alt, r);
+        assert_eq!(o_alt, o);
+        assert_eq!(r.cmp(&x), o);
+    });
+}
+
 #[test]
 fn limbs_round_to_multiple_of_power_of_2_properties() {
     let mut config = GenConfig::new();
@@ -1573,6 +1907,62 @@ fn limbs_round_to_multiple_of_power_of_2_nearest_in_place_properties() {
     );
 }
 
+#[test]
+fn limbs_round_to_multiple_of_power_of_2_nearest_ties_away_in_place_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_unsigned_pair_gen_var_16().test_properties_with_config(
+        &config,
+        |(mut xs, pow)| {
+            let old_xs = xs.clone();
+            let o = limbs_round_to_multiple_of_power_of_2_nearest_ties_away_in_place(&mut xs, pow);
+            let (n, o_alt) = Natural::from_owned_limbs_asc(old_xs)
+                .round_to_multiple_of_power_of_2(pow, RoundingMode::NearestTiesAway);
+            assert_eq!(Natural::from_owned_limbs_asc(xs), n);
+            assert_eq!(o_alt, o);
+        },
+    );
+}
+
+#[test]
+fn limbs_round_to_multiple_of_power_of_2_nearest_ties_toward_zero_in_place_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_unsigned_pair_gen_var_16().test_properties_with_config(
+        &config,
+        |(mut xs, pow)| {
+            let old_xs = xs.clone();
+            let o = limbs_round_to_multiple_of_power_of_2_nearest_ties_toward_zero_in_place(
+                &mut xs, pow,
+            );
+            let (n, o_alt) = Natural::from_owned_limbs_asc(old_xs)
+                .round_to_multiple_of_power_of_2(pow, RoundingMode::NearestTiesTowardZero);
+            assert_eq!(Natural::from_owned_limbs_asc(xs), n);
+            assert_eq!(o_alt, o);
+        },
+    );
+}
+
+#[test]
+fn limbs_round_to_multiple_of_power_of_2_odd_in_place_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_unsigned_pair_gen_var_20().test_properties_with_config(
+        &config,
+        |(mut xs, pow)| {
+            let old_xs = xs.clone();
+            let o = limbs_round_to_multiple_of_power_of_2_odd_in_place(&mut xs, pow);
+            let (n, o_alt) = Natural::from_owned_limbs_asc(old_xs)
+                .round_to_multiple_of_power_of_2(pow, RoundingMode::Odd);
+            assert_eq!(Natural::from_owned_limbs_asc(xs), n);
+            assert_eq!(o_alt, o);
+        },
+    );
+}
+
 #[test]
 fn limbs_round_to_multiple_of_power_of_2_in_place_properties() {
     let mut config = GenConfig::new();
@@ -1635,20 +2025,27 @@ fn round_to_multiple_of_power_of_2_properties() {
             RoundingMode::Floor | RoundingMode::Down => assert!(r <= n),
             RoundingMode::Ceiling | RoundingMode::Up => assert!(r >= n),
             RoundingMode::Exact => assert_eq!(r, n),
-            RoundingMode::Nearest => {
+            RoundingMode::Odd => assert!(r == n || r.get_bit(pow)),
+            RoundingMode::Nearest
+            | RoundingMode::NearestTiesAway
+            | RoundingMode::NearestTiesTowardZero => {
                 let k = Natural::power_of_2(pow);
                 let closest;
                 let second_closest;
                 if r <= n {
                     closest = &n - &r;
-                    second_closest = &r + k - n;
+                    second_closest = &r + k - &n;
                 } else {
                     closest = &r - &n;
-                    second_closest = n + k - &r;
+                    second_closest = &n + k - &r;
                 }
                 assert!(closest <= second_closest);
                 if closest == second_closest {
-                    assert!(!r.get_bit(pow));
+                    match rm {
+                        RoundingMode::Nearest => assert!(!r.get_bit(pow)),
+                        RoundingMode::NearestTiesAway => assert!(r >= n),
+                        _ => assert!(r <= n),
+                    }
                 }
             }
         }
@@ -41,6 +41,15 @@ macro_rules! test_shl_round_signed_helper {
         test("0", 0, RoundingMode::Floor, "0", Ordering::Equal);
         test("0", 0, RoundingMode::Ceiling, "0", Ordering::Equal);
         test("0", 0, RoundingMode::Nearest, "0", Ordering::Equal);
+        test("0", 0, RoundingMode::NearestTiesAway, "0", Ordering::Equal);
+        test(
+            "0",
+            0,
+            RoundingMode::NearestTiesTowardZero,
+            "0",
+            Ordering::Equal,
+        );
+        test("0", 0, RoundingMode::Odd, "0", Ordering::Equal);
         test("0", 0, RoundingMode::Exact, "0", Ordering::Equal);
 
         test("0", -10, RoundingMode::Down, "0", Ordering::Equal);
@@ -48,6 +57,21 @@ macro_rules! test_shl_round_signed_helper {
         test("0", -10, RoundingMode::Floor, "0", Ordering::Equal);
         test("0", -10, RoundingMode::Ceiling, "0", Ordering::Equal);
         test("0", -10, RoundingMode::Nearest, "0", Ordering::Equal);
+        test(
+            "0",
+            -10,
+            RoundingMode::NearestTiesAway,
+            "0",
+            Ordering::Equal,
+        );
+        test(
+            "0",
+            -10,
+            RoundingMode::NearestTiesTowardZero,
+            "0",
+            Ordering::Equal,
+        );
+        test("0", -10, RoundingMode::Odd, "0", Ordering::Equal);
         test("0", -10, RoundingMode::Exact, "0", Ordering::Equal);
 
         test("123", 0, RoundingMode::Down, "123", Ordering::Equal);
@@ -55,6 +79,21 @@ macro_rules! test_shl_round_signed_helper {
         test("123", 0, RoundingMode::Floor, "123", Ordering::Equal);
         test("123", 0, RoundingMode::Ceiling, "123", Ordering::Equal);
         test("123", 0, RoundingMode::Nearest, "123", Ordering::Equal);
+        test(
+            "123",
+            0,
+            RoundingMode::NearestTiesAway,
+            "123",
+            Ordering::Equal,
+        );
+        test(
+            "123",
+            0,
+            RoundingMode::NearestTiesTowardZero,
+            "123",
+            Ordering::Equal,
+        );
+        test("123", 0, RoundingMode::Odd, "123", Ordering::Equal);
         test("123", 0, RoundingMode::Exact, "123", Ordering::Equal);
 
         test("245", -1, RoundingMode::Down, "122", Ordering::Less);
@@ -62,12 +101,42 @@ macro_rules! test_shl_round_signed_helper {
         test("245", -1, RoundingMode::Floor, "122", Ordering::Less);
         test("245", -1, RoundingMode::Ceiling, "123", Ordering::Greater);
         test("245", -1, RoundingMode::Nearest, "122", Ordering::Less);
+        test(
+            "245",
+            -1,
+            RoundingMode::NearestTiesAway,
+            "123",
+            Ordering::Greater,
+        );
+        test(
+            "245",
+            -1,
+            RoundingMode::NearestTiesTowardZero,
+            "122",
+            Ordering::Less,
+        );
+        test("245", -1, RoundingMode::Odd, "123", Ordering::Greater);
 
         test("246", -1, RoundingMode::Down, "123", Ordering::Equal);
         test("246", -1, RoundingMode::Up, "123", Ordering::Equal);
         test("246", -1, RoundingMode::Floor, "123", Ordering::Equal);
         test("246", -1, RoundingMode::Ceiling, "123", Ordering::Equal);
         test("246", -1, RoundingMode::Nearest, "123", Ordering::Equal);
+        test(
+            "246",
+            -1,
+            RoundingMode::NearestTiesAway,
+            "123",
+            Ordering::Equal,
+        );
+        test(
+            "246",
+            -1,
+            RoundingMode::NearestTiesTowardZero,
+            "123",
+            Ordering::Equal,
+        );
+        test("246", -1, RoundingMode::Odd, "123", Ordering::Equal);
         test("246", -1, RoundingMode::Exact, "123", Ordering::Equal);
 
         test("247", -1, RoundingMode::Down, "123", Ordering::Less);
@@ -75,18 +144,63 @@ macro_rules! test_shl_round_signed_helper {
         test("247", -1, RoundingMode::Floor, "123", Ordering::Less);
         test("247", -1, RoundingMode::Ceiling, "124", Ordering::Greater);
         test("247", -1, RoundingMode::Nearest, "124", Ordering::Greater);
+        test(
+            "247",
+            -1,
+            RoundingMode::NearestTiesAway,
+            "124",
+            Ordering::Greater,
+        );
+        test(
+            "247",
+            -1,
+            RoundingMode::NearestTiesTowardZero,
+            "123",
+            Ordering::Less,
+        );
+        test("247", -1, RoundingMode::Odd, "123", Ordering::Less);
 
         test("491", -2, RoundingMode::Down, "122", Ordering::Less);
         test("491", -2, RoundingMode::Up, "123", Ordering::Greater);
         test("491", -2, RoundingMode::Floor, "122", Ordering::Less);
         test("491", -2, RoundingMode::Ceiling, "123", Ordering::Greater);
         test("491", -2, RoundingMode::Nearest, "123", Ordering::Greater);
+        test(
+            "491",
+            -2,
+            RoundingMode::NearestTiesAway,
+            "123",
+            Ordering::Greater,
+        );
+        test(
+            "491",
+            -2,
+            RoundingMode::NearestTiesTowardZero,
+            "123",
+            Ordering::Greater,
+        );
+        test("491", -2, RoundingMode::Odd, "123", Ordering::Greater);
 
         test("492", -2, RoundingMode::Down, "123", Ordering::Equal);
         test("492", -2, RoundingMode::Up, "123", Ordering::Equal);
         test("492", -2, RoundingMode::Floor, "123", Ordering::Equal);
         test("492", -2, RoundingMode::Ceiling, "123", Ordering::Equal);
         test("492", -2, RoundingMode::Nearest, "123", Ordering::Equal);
+        test(
+            "492",
+            -2,
+            RoundingMode::NearestTiesAway,
+            "123",
+            Ordering::Equal,
+        );
+        test(
+            "492",
+            -2,
+            RoundingMode::NearestTiesTowardZero,
+            "123",
+            Ordering::Equal,
+        );
+        test("492", -2, RoundingMode::Odd, "123", Ordering::Equal);
         test("492", -2, RoundingMode::Exact, "123", Ordering::Equal);
 
         test("493", -2, RoundingMode::Down, "123", Ordering::Less);
@@ -94,6 +208,21 @@ macro_rules! test_shl_round_signed_helper {
         test("493", -2, RoundingMode::Floor, "123", Ordering::Less);
         test("493", -2, RoundingMode::Ceiling, "124", Ordering::Greater);
         test("493", -2, RoundingMode::Nearest, "123", Ordering::Less);
+        test(
+            "493",
+            -2,
+            RoundingMode::NearestTiesAway,
+            "123",
+            Ordering::Less,
+        );
+        test(
+            "493",
+            -2,
+            RoundingMode::NearestTiesTowardZero,
+            "123",
+            Ordering::Less,
+        );
+        test("493", -2, RoundingMode::Odd, "123", Ordering::Less);
 
         test("4127195135", -25, RoundingMode::Down, "122", Ordering::Less);
         test(
@@ -989,6 +1118,21 @@ macro_rules! test_shl_round_signed_helper {
             Ordering::Greater,
         );
         test("980657949", -72, RoundingMode::Nearest, "0", Ordering::Less);
+        test(
+            "980657949",
+            -72,
+            RoundingMode::NearestTiesAway,
+            "0",
+            Ordering::Less,
+        );
+        test(
+            "980657949",
+            -72,
+            RoundingMode::NearestTiesTowardZero,
+            "0",
+            Ordering::Less,
+        );
+        test("980657949", -72, RoundingMode::Odd, "1", Ordering::Greater);
 
         test("4294967295", -31, RoundingMode::Down, "1", Ordering::Less);
         test("4294967295", -31, RoundingMode::Up, "2", Ordering::Greater);
@@ -15,9 +15,12 @@ use malachite_base::test_util::generators::{
     unsigned_vec_unsigned_pair_gen_var_20, unsigned_vec_unsigned_rounding_mode_triple_gen_var_2,
 };
 use malachite_nz::natural::arithmetic::shr_round::{
-    limbs_shr_exact, limbs_shr_round, limbs_shr_round_nearest, limbs_shr_round_up,
+    limbs_shr_exact, limbs_shr_round, limbs_shr_round_nearest, limbs_shr_round_nearest_ties_away,
+    limbs_shr_round_nearest_ties_toward_zero, limbs_shr_round_odd, limbs_shr_round_up,
     limbs_vec_shr_exact_in_place, limbs_vec_shr_round_in_place,
-    limbs_vec_shr_round_nearest_in_place, limbs_vec_shr_round_up_in_place,
+    limbs_vec_shr_round_nearest_in_place, limbs_vec_shr_round_nearest_ties_away_in_place,
+    limbs_vec_shr_round_nearest_ties_toward_zero_in_place, limbs_vec_shr_round_odd_in_place,
+    limbs_vec_shr_round_up_in_place,
 };
 use malachite_nz::natural::Natural;
 use malachite_nz::platform::Limb;
@@ -91,6 +94,118 @@ fn test_limbs_shr_round_nearest_and_limbs_vec_shr_round_nearest_in_place() {
     test(&[u32::MAX, u32::MAX], 32, &[0, 1], Ordering::Greater);
 }
 
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+fn test_limbs_shr_round_nearest_ties_away_and_limbs_vec_shr_round_nearest_ties_away_in_place() {
+    let test = |limbs: &[Limb], bits: u64, out: &[Limb], o: Ordering| {
+        let (s, o_alt) = limbs_shr_round_nearest_ties_away(limbs, bits);
+        assert_eq!(s, out);
+        assert_eq!(o_alt, o);
+
+        let mut limbs = limbs.to_vec();
+        assert_eq!(
+            limbs_vec_shr_round_nearest_ties_away_in_place(&mut limbs, bits),
+            o
+        );
+        assert_eq!(limbs, out);
+    };
+    test(&[], 0, &[], Ordering::Equal);
+    test(&[], 1, &[], Ordering::Equal);
+    test(&[], 100, &[], Ordering::Equal);
+    test(&[0, 0, 0], 0, &[0, 0, 0], Ordering::Equal);
+    test(&[0, 0, 0], 1, &[0, 0, 0], Ordering::Equal);
+    test(&[0, 0, 0], 100, &[], Ordering::Equal);
+    test(&[1], 0, &[1], Ordering::Equal);
+    test(&[1], 1, &[1], Ordering::Greater);
+    test(&[3], 1, &[2], Ordering::Greater);
+    test(&[122, 456], 1, &[61, 228], Ordering::Equal);
+    test(&[123, 456], 0, &[123, 456], Ordering::Equal);
+    test(&[123, 456], 1, &[62, 228], Ordering::Greater);
+    test(&[123, 455], 1, &[2147483710, 227], Ordering::Greater);
+    test(&[123, 456], 31, &[912, 0], Ordering::Less);
+    test(&[123, 456], 32, &[456], Ordering::Less);
+    test(&[123, 456], 100, &[], Ordering::Less);
+    test(&[256, 456], 8, &[3355443201, 1], Ordering::Equal);
+    test(&[u32::MAX, 1], 1, &[0, 1], Ordering::Greater);
+    test(&[u32::MAX, u32::MAX], 32, &[0, 1], Ordering::Greater);
+    test(&[2147483648, 1], 32, &[2], Ordering::Greater);
+    test(&[2147483648, 2], 32, &[3], Ordering::Greater);
+    test(&[2147483648], 32, &[1], Ordering::Greater);
+    test(&[5], 1, &[3], Ordering::Greater);
+}
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+fn test_limbs_shr_round_nearest_ties_toward_zero_and_limbs_vec_shr_round_nearest_ties_toward_zero_in_place(
+) {
+    let test = |limbs: &[Limb], bits: u64, out: &[Limb], o: Ordering| {
+        let (s, o_alt) = limbs_shr_round_nearest_ties_toward_zero(limbs, bits);
+        assert_eq!(s, out);
+        assert_eq!(o_alt, o);
+
+        let mut limbs = limbs.to_vec();
+        assert_eq!(
+            limbs_vec_shr_round_nearest_ties_toward_zero_in_place(&mut limbs, bits),
+            o
+        );
+        assert_eq!(limbs, out);
+    };
+    test(&[], 0, &[], Ordering::Equal);
+    test(&[], 1, &[], Ordering::Equal);
+    test(&[], 100, &[], Ordering::Equal);
+    test(&[0, 0, 0], 0, &[0, 0, 0], Ordering::Equal);
+    test(&[0, 0, 0], 1, &[0, 0, 0], Ordering::Equal);
+    test(&[0, 0, 0], 100, &[], Ordering::Equal);
+    test(&[1], 0, &[1], Ordering::Equal);
+    test(&[1], 1, &[0], Ordering::Less);
+    test(&[3], 1, &[1], Ordering::Less);
+    test(&[122, 456], 1, &[61, 228], Ordering::Equal);
+    test(&[123, 456], 0, &[123, 456], Ordering::Equal);
+    test(&[123, 456], 1, &[61, 228], Ordering::Less);
+    test(&[123, 455], 1, &[2147483709, 227], Ordering::Less);
+    test(&[123, 456], 31, &[912, 0], Ordering::Less);
+    test(&[123, 456], 32, &[456], Ordering::Less);
+    test(&[123, 456], 100, &[], Ordering::Less);
+    test(&[256, 456], 8, &[3355443201, 1], Ordering::Equal);
+    test(&[u32::MAX, 1], 1, &[u32::MAX, 0], Ordering::Less);
+    test(&[u32::MAX, u32::MAX], 32, &[0, 1], Ordering::Greater);
+    test(&[2147483648, 1], 32, &[1], Ordering::Less);
+    test(&[2147483648, 2], 32, &[2], Ordering::Less);
+    test(&[2147483648], 32, &[], Ordering::Less);
+    test(&[5], 1, &[2], Ordering::Less);
+}
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+fn test_limbs_shr_round_odd_and_limbs_vec_shr_round_odd_in_place() {
+    let test = |limbs: &[Limb], bits: u64, out: &[Limb], o: Ordering| {
+        let (s, o_alt) = limbs_shr_round_odd(limbs, bits);
+        assert_eq!(s, out);
+        assert_eq!(o_alt, o);
+
+        let mut limbs = limbs.to_vec();
+        assert_eq!(limbs_vec_shr_round_odd_in_place(&mut limbs, bits), o);
+        assert_eq!(limbs, out);
+    };
+    test(&[1], 0, &[1], Ordering::Equal);
+    test(&[1], 1, &[1], Ordering::Greater);
+    test(&[3], 1, &[1], Ordering::Less);
+    test(&[122, 456], 1, &[61, 228], Ordering::Equal);
+    test(&[123, 456], 0, &[123, 456], Ordering::Equal);
+    test(&[123, 456], 1, &[61, 228], Ordering::Less);
+    test(&[123, 455], 1, &[2147483709, 227], Ordering::Less);
+    test(&[123, 456], 31, &[913, 0], Ordering::Greater);
+    test(&[123, 456], 32, &[457], Ordering::Greater);
+    test(&[123, 456], 100, &[1], Ordering::Greater);
+    test(&[256, 456], 8, &[3355443201, 1], Ordering::Equal);
+    test(&[u32::MAX, 1], 1, &[u32::MAX, 0], Ordering::Less);
+    test(&[u32::MAX, u32::MAX], 32, &[u32::MAX], Ordering::Less);
+    test(&[2147483648, 1], 32, &[1], Ordering::Less);
+    test(&[2147483648, 2], 32, &[3], Ordering::Greater);
+    test(&[2147483648], 32, &[1], Ordering::Greater);
+    test(&[5], 1, &[3], Ordering::Greater);
+}
+
 #[cfg(feature = "32_bit_limbs")]
 #[test]
 fn test_limbs_shr_exact_and_limbs_vec_shr_exact_in_place() {
@@ -249,6 +364,15 @@ where
     test("0", 0, RoundingMode::Floor, "0", Ordering::Equal);
     test("0", 0, RoundingMode::Ceiling, "0", Ordering::Equal);
     test("0", 0, RoundingMode::Nearest, "0", Ordering::Equal);
+    test("0", 0, RoundingMode::NearestTiesAway, "0", Ordering::Equal);
+    test(
+        "0",
+        0,
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Equal,
+    );
+    test("0", 0, RoundingMode::Odd, "0", Ordering::Equal);
     test("0", 0, RoundingMode::Exact, "0", Ordering::Equal);
 
     test("0", 10, RoundingMode::Down, "0", Ordering::Equal);
@@ -256,6 +380,15 @@ where
     test("0", 10, RoundingMode::Floor, "0", Ordering::Equal);
     test("0", 10, RoundingMode::Ceiling, "0", Ordering::Equal);
     test("0", 10, RoundingMode::Nearest, "0", Ordering::Equal);
+    test("0", 10, RoundingMode::NearestTiesAway, "0", Ordering::Equal);
+    test(
+        "0",
+        10,
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Equal,
+    );
+    test("0", 10, RoundingMode::Odd, "0", Ordering::Equal);
     test("0", 10, RoundingMode::Exact, "0", Ordering::Equal);
 
     test("123", 0, RoundingMode::Down, "123", Ordering::Equal);
@@ -263,6 +396,21 @@ where
     test("123", 0, RoundingMode::Floor, "123", Ordering::Equal);
     test("123", 0, RoundingMode::Ceiling, "123", Ordering::Equal);
     test("123", 0, RoundingMode::Nearest, "123", Ordering::Equal);
+    test(
+        "123",
+        0,
+        RoundingMode::NearestTiesAway,
+        "123",
+        Ordering::Equal,
+    );
+    test(
+        "123",
+        0,
+        RoundingMode::NearestTiesTowardZero,
+        "123",
+        Ordering::Equal,
+    );
+    test("123", 0, RoundingMode::Odd, "123", Ordering::Equal);
     test("123", 0, RoundingMode::Exact, "123", Ordering::Equal);
 
     test("245", 1, RoundingMode::Down, "122", Ordering::Less);
@@ -270,12 +418,42 @@ where
     test("245", 1, RoundingMode::Floor, "122", Ordering::Less);
     test("245", 1, RoundingMode::Ceiling, "123", Ordering::Greater);
     test("245", 1, RoundingMode::Nearest, "122", Ordering::Less);
+    test(
+        "245",
+        1,
+        RoundingMode::NearestTiesAway,
+        "123",
+        Ordering::Greater,
+    );
+    test(
+        "245",
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        "122",
+        Ordering::Less,
+    );
+    test("245", 1, RoundingMode::Odd, "123", Ordering::Greater);
 
     test("246", 1, RoundingMode::Down, "123", Ordering::Equal);
     test("246", 1, RoundingMode::Up, "123", Ordering::Equal);
     test("246", 1, RoundingMode::Floor, "123", Ordering::Equal);
     test("246", 1, RoundingMode::Ceiling, "123", Ordering::Equal);
     test("246", 1, RoundingMode::Nearest, "123", Ordering::Equal);
+    test(
+        "246",
+        1,
+        RoundingMode::NearestTiesAway,
+        "123",
+        Ordering::Equal,
+    );
+    test(
+        "246",
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        "123",
+        Ordering::Equal,
+    );
+    test("246", 1, RoundingMode::Odd, "123", Ordering::Equal);
     test("246", 1, RoundingMode::Exact, "123", Ordering::Equal);
 
     test("247", 1, RoundingMode::Down, "123", Ordering::Less);
@@ -283,18 +461,63 @@ where
     test("247", 1, RoundingMode::Floor, "123", Ordering::Less);
     test("247", 1, RoundingMode::Ceiling, "124", Ordering::Greater);
     test("247", 1, RoundingMode::Nearest, "124", Ordering::Greater);
+    test(
+        "247",
+        1,
+        RoundingMode::NearestTiesAway,
+        "124",
+        Ordering::Greater,
+    );
+    test(
+        "247",
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        "123",
+        Ordering::Less,
+    );
+    test("247", 1, RoundingMode::Odd, "123", Ordering::Less);
 
     test("491", 2, RoundingMode::Down, "122", Ordering::Less);
     test("491", 2, RoundingMode::Up, "123", Ordering::Greater);
     test("491", 2, RoundingMode::Floor, "122", Ordering::Less);
     test("491", 2, RoundingMode::Ceiling, "123", Ordering::Greater);
     test("491", 2, RoundingMode::Nearest, "123", Ordering::Greater);
+    test(
+        "491",
+        2,
+        RoundingMode::NearestTiesAway,
+        "123",
+        Ordering::Greater,
+    );
+    test(
+        "491",
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        "123",
+        Ordering::Greater,
+    );
+    test("491", 2, RoundingMode::Odd, "123", Ordering::Greater);
 
     test("492", 2, RoundingMode::Down, "123", Ordering::Equal);
     test("492", 2, RoundingMode::Up, "123", Ordering::Equal);
     test("492", 2, RoundingMode::Floor, "123", Ordering::Equal);
     test("492", 2, RoundingMode::Ceiling, "123", Ordering::Equal);
     test("492", 2, RoundingMode::Nearest, "123", Ordering::Equal);
+    test(
+        "492",
+        2,
+        RoundingMode::NearestTiesAway,
+        "123",
+        Ordering::Equal,
+    );
+    test(
+        "492",
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        "123",
+        Ordering::Equal,
+    );
+    test("492", 2, RoundingMode::Odd, "123", Ordering::Equal);
     test("492", 2, RoundingMode::Exact, "123", Ordering::Equal);
 
     test("493", 2, RoundingMode::Down, "123", Ordering::Less);
@@ -302,6 +525,21 @@ where
     test("493", 2, RoundingMode::Floor, "123", Ordering::Less);
     test("493", 2, RoundingMode::Ceiling, "124", Ordering::Greater);
     test("493", 2, RoundingMode::Nearest, "123", Ordering::Less);
+    test(
+        "493",
+        2,
+        RoundingMode::NearestTiesAway,
+        "123",
+        Ordering::Less,
+    );
+    test(
+        "493",
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        "123",
+        Ordering::Less,
+    );
+    test("493", 2, RoundingMode::Odd, "123", Ordering::Less);
 
     test("4127195135", 25, RoundingMode::Down, "122", Ordering::Less);
     test("4127195135", 25, RoundingMode::Up, "123", Ordering::Greater);
@@ -1137,6 +1375,21 @@ where
         Ordering::Greater,
     );
     test("980657949", 72, RoundingMode::Nearest, "0", Ordering::Less);
+    test(
+        "980657949",
+        72,
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Less,
+    );
+    test(
+        "980657949",
+        72,
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Less,
+    );
+    test("980657949", 72, RoundingMode::Odd, "1", Ordering::Greater);
 
     test("4294967295", 31, RoundingMode::Down, "1", Ordering::Less);
     test("4294967295", 31, RoundingMode::Up, "2", Ordering::Greater);
@@ -1204,6 +1457,21 @@ where
         Ordering::Greater,
     );
     test("4294967296", 33, RoundingMode::Nearest, "0", Ordering::Less);
+    test(
+        "4294967296",
+        33,
+        RoundingMode::NearestTiesAway,
+        "1",
+        Ordering::Greater,
+    );
+    test(
+        "4294967296",
+        33,
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Less,
+    );
+    test("4294967296", 33, RoundingMode::Odd, "1", Ordering::Greater);
 }
 
 #[test]
@@ -1275,6 +1543,15 @@ where
     test("0", 0, RoundingMode::Floor, "0", Ordering::Equal);
     test("0", 0, RoundingMode::Ceiling, "0", Ordering::Equal);
     test("0", 0, RoundingMode::Nearest, "0", Ordering::Equal);
+    test("0", 0, RoundingMode::NearestTiesAway, "0", Ordering::Equal);
+    test(
+        "0",
+        0,
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Equal,
+    );
+    test("0", 0, RoundingMode::Odd, "0", Ordering::Equal);
     test("0", 0, RoundingMode::Exact, "0", Ordering::Equal);
 
     test("0", 10, RoundingMode::Down, "0", Ordering::Equal);
@@ -1282,6 +1559,15 @@ where
     test("0", 10, RoundingMode::Floor, "0", Ordering::Equal);
     test("0", 10, RoundingMode::Ceiling, "0", Ordering::Equal);
     test("0", 10, RoundingMode::Nearest, "0", Ordering::Equal);
+    test("0", 10, RoundingMode::NearestTiesAway, "0", Ordering::Equal);
+    test(
+        "0",
+        10,
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Equal,
+    );
+    test("0", 10, RoundingMode::Odd, "0", Ordering::Equal);
     test("0", 10, RoundingMode::Exact, "0", Ordering::Equal);
 
     test("123", 0, RoundingMode::Down, "123", Ordering::Equal);
@@ -1289,6 +1575,21 @@ where
     test("123", 0, RoundingMode::Floor, "123", Ordering::Equal);
     test("123", 0, RoundingMode::Ceiling, "123", Ordering::Equal);
     test("123", 0, RoundingMode::Nearest, "123", Ordering::Equal);
+    test(
+        "123",
+        0,
+        RoundingMode::NearestTiesAway,
+        "123",
+        Ordering::Equal,
+    );
+    test(
+        "123",
+        0,
+        RoundingMode::NearestTiesTowardZero,
+        "123",
+        Ordering::Equal,
+    );
+    test("123", 0, RoundingMode::Odd, "123", Ordering::Equal);
     test("123", 0, RoundingMode::Exact, "123", Ordering::Equal);
 
     test("245", 1, RoundingMode::Down, "122", Ordering::Less);
@@ -1296,12 +1597,42 @@ where
     test("245", 1, RoundingMode::Floor, "122", Ordering::Less);
     test("245", 1, RoundingMode::Ceiling, "123", Ordering::Greater);
     test("245", 1, RoundingMode::Nearest, "122", Ordering::Less);
+    test(
+        "245",
+        1,
+        RoundingMode::NearestTiesAway,
+        "123",
+        Ordering::Greater,
+    );
+    test(
+        "245",
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        "122",
+        Ordering::Less,
+    );
+    test("245", 1, RoundingMode::Odd, "123", Ordering::Greater);
 
     test("246", 1, RoundingMode::Down, "123", Ordering::Equal);
     test("246", 1, RoundingMode::Up, "123", Ordering::Equal);
     test("246", 1, RoundingMode::Floor, "123", Ordering::Equal);
     test("246", 1, RoundingMode::Ceiling, "123", Ordering::Equal);
     test("246", 1, RoundingMode::Nearest, "123", Ordering::Equal);
+    test(
+        "246",
+        1,
+        RoundingMode::NearestTiesAway,
+        "123",
+        Ordering::Equal,
+    );
+    test(
+        "246",
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        "123",
+        Ordering::Equal,
+    );
+    test("246", 1, RoundingMode::Odd, "123", Ordering::Equal);
     test("246", 1, RoundingMode::Exact, "123", Ordering::Equal);
 
     test("247", 1, RoundingMode::Down, "123", Ordering::Less);
@@ -1309,18 +1640,63 @@ where
     test("247", 1, RoundingMode::Floor, "123", Ordering::Less);
     test("247", 1, RoundingMode::Ceiling, "124", Ordering::Greater);
     test("247", 1, RoundingMode::Nearest, "124", Ordering::Greater);
+    test(
+        "247",
+        1,
+        RoundingMode::NearestTiesAway,
+        "124",
+        Ordering::Greater,
+    );
+    test(
+        "247",
+        1,
+        RoundingMode::NearestTiesTowardZero,
+        "123",
+        Ordering::Less,
+    );
+    test("247", 1, RoundingMode::Odd, "123", Ordering::Less);
 
     test("491", 2, RoundingMode::Down, "122", Ordering::Less);
     test("491", 2, RoundingMode::Up, "123", Ordering::Greater);
     test("491", 2, RoundingMode::Floor, "122", Ordering::Less);
     test("491", 2, RoundingMode::Ceiling, "123", Ordering::Greater);
     test("491", 2, RoundingMode::Nearest, "123", Ordering::Greater);
+    test(
+        "491",
+        2,
+        RoundingMode::NearestTiesAway,
+        "123",
+        Ordering::Greater,
+    );
+    test(
+        "491",
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        "123",
+        Ordering::Greater,
+    );
+    test("491", 2, RoundingMode::Odd, "123", Ordering::Greater);
 
     test("492", 2, RoundingMode::Down, "123", Ordering::Equal);
     test("492", 2, RoundingMode::Up, "123", Ordering::Equal);
     test("492", 2, RoundingMode::Floor, "123", Ordering::Equal);
     test("492", 2, RoundingMode::Ceiling, "123", Ordering::Equal);
     test("492", 2, RoundingMode::Nearest, "123", Ordering::Equal);
+    test(
+        "492",
+        2,
+        RoundingMode::NearestTiesAway,
+        "123",
+        Ordering::Equal,
+    );
+    test(
+        "492",
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        "123",
+        Ordering::Equal,
+    );
+    test("492", 2, RoundingMode::Odd, "123", Ordering::Equal);
     test("492", 2, RoundingMode::Exact, "123", Ordering::Equal);
 
     test("493", 2, RoundingMode::Down, "123", Ordering::Less);
@@ -1328,6 +1704,21 @@ where
     test("493", 2, RoundingMode::Floor, "123", Ordering::Less);
     test("493", 2, RoundingMode::Ceiling, "124", Ordering::Greater);
     test("493", 2, RoundingMode::Nearest, "123", Ordering::Less);
+    test(
+        "493",
+        2,
+        RoundingMode::NearestTiesAway,
+        "123",
+        Ordering::Less,
+    );
+    test(
+        "493",
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        "123",
+        Ordering::Less,
+    );
+    test("493", 2, RoundingMode::Odd, "123", Ordering::Less);
 
     test("4127195135", 25, RoundingMode::Down, "122", Ordering::Less);
     test("4127195135", 25, RoundingMode::Up, "123", Ordering::Greater);
@@ -2163,6 +2554,21 @@ where
         Ordering::Greater,
     );
     test("980657949", 72, RoundingMode::Nearest, "0", Ordering::Less);
+    test(
+        "980657949",
+        72,
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Less,
+    );
+    test(
+        "980657949",
+        72,
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Less,
+    );
+    test("980657949", 72, RoundingMode::Odd, "1", Ordering::Greater);
 
     test("4294967295", 31, RoundingMode::Down, "1", Ordering::Less);
     test("4294967295", 31, RoundingMode::Up, "2", Ordering::Greater);
@@ -2230,6 +2636,21 @@ where
         Ordering::Greater,
     );
     test("4294967296", 33, RoundingMode::Nearest, "0", Ordering::Less);
+    test(
+        "4294967296",
+        33,
+        RoundingMode::NearestTiesAway,
+        "1",
+        Ordering::Greater,
+    );
+    test(
+        "4294967296",
+        33,
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Less,
+    );
+    test("4294967296", 33, RoundingMode::Odd, "1", Ordering::Greater);
 
     test("0", -10, RoundingMode::Exact, "0", Ordering::Equal);
     test("123", -1, RoundingMode::Exact, "246", Ordering::Equal);
@@ -2381,6 +2802,48 @@ fn limbs_shr_round_nearest_properties() {
     });
 }
 
+#[test]
+fn limbs_shr_round_nearest_ties_away_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_unsigned_pair_gen_var_16().test_properties_with_config(&config, |(xs, bits)| {
+        let (s, o) = limbs_shr_round_nearest_ties_away(&xs, bits);
+        assert_eq!(
+            (Natural::from_owned_limbs_asc(s), o),
+            Natural::from_owned_limbs_asc(xs).shr_round(bits, RoundingMode::NearestTiesAway),
+        );
+    });
+}
+
+#[test]
+fn limbs_shr_round_nearest_ties_toward_zero_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_unsigned_pair_gen_var_16().test_properties_with_config(&config, |(xs, bits)| {
+        let (s, o) = limbs_shr_round_nearest_ties_toward_zero(&xs, bits);
+        assert_eq!(
+            (Natural::from_owned_limbs_asc(s), o),
+            Natural::from_owned_limbs_asc(xs).shr_round(bits, RoundingMode::NearestTiesTowardZero),
+        );
+    });
+}
+
+#[test]
+fn limbs_shr_round_odd_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_unsigned_pair_gen_var_20().test_properties_with_config(&config, |(xs, bits)| {
+        let (s, o) = limbs_shr_round_odd(&xs, bits);
+        assert_eq!(
+            (Natural::from_owned_limbs_asc(s), o),
+            Natural::from_owned_limbs_asc(xs).shr_round(bits, RoundingMode::Odd),
+        );
+    });
+}
+
 #[test]
 fn limbs_shr_exact_properties() {
     let mut config = GenConfig::new();
@@ -2465,6 +2928,60 @@ fn limbs_vec_shr_round_nearest_in_place_properties() {
     );
 }
 
+#[test]
+fn limbs_vec_shr_round_nearest_ties_away_in_place_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_unsigned_pair_gen_var_16().test_properties_with_config(
+        &config,
+        |(mut xs, bits)| {
+            let old_xs = xs.clone();
+            let o = limbs_vec_shr_round_nearest_ties_away_in_place(&mut xs, bits);
+            let (n, o_alt) = Natural::from_owned_limbs_asc(old_xs)
+                .shr_round(bits, RoundingMode::NearestTiesAway);
+            assert_eq!(Natural::from_owned_limbs_asc(xs), n);
+            assert_eq!(o, o_alt);
+        },
+    );
+}
+
+#[test]
+fn limbs_vec_shr_round_nearest_ties_toward_zero_in_place_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_unsigned_pair_gen_var_16().test_properties_with_config(
+        &config,
+        |(mut xs, bits)| {
+            let old_xs = xs.clone();
+            let o = limbs_vec_shr_round_nearest_ties_toward_zero_in_place(&mut xs, bits);
+            let (n, o_alt) = Natural::from_owned_limbs_asc(old_xs)
+                .shr_round(bits, RoundingMode::NearestTiesTowardZero);
+            assert_eq!(Natural::from_owned_limbs_asc(xs), n);
+            assert_eq!(o, o_alt);
+        },
+    );
+}
+
+#[test]
+fn limbs_vec_shr_round_odd_in_place_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_unsigned_pair_gen_var_20().test_properties_with_config(
+        &config,
+        |(mut xs, bits)| {
+            let old_xs = xs.clone();
+            let o = limbs_vec_shr_round_odd_in_place(&mut xs, bits);
+            let (n, o_alt) =
+                Natural::from_owned_limbs_asc(old_xs).shr_round(bits, RoundingMode::Odd);
+            assert_eq!(Natural::from_owned_limbs_asc(xs), n);
+            assert_eq!(o, o_alt);
+        },
+    );
+}
+
 #[test]
 fn limbs_vec_shr_exact_in_place_properties() {
     let mut config = GenConfig::new();
@@ -1,3 +1,7 @@
+use malachite_base::num::arithmetic::traits::DivRound;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
 use malachite_base::strings::string_is_subset;
 use malachite_base::test_util::generators::{string_gen, string_gen_var_8};
 use malachite_nz::integer::Integer;
@@ -32,6 +36,39 @@ fn test_serde() {
     );
 }
 
+#[test]
+fn test_serde_binary() {
+    let test = |n, out: &[u8]| {
+        assert_eq!(
+            bincode::serialize(&Natural::from_str(n).unwrap()).unwrap(),
+            out
+        );
+        assert_eq!(bincode::deserialize::<Natural>(out).unwrap().to_string(), n);
+    };
+    test("0", &[0, 0, 0, 0, 0, 0, 0, 0]);
+    test("100", &[1, 0, 0, 0, 0, 0, 0, 0, 100]);
+    test(
+        "1000000000000",
+        &[5, 0, 0, 0, 0, 0, 0, 0, 0x00, 0x10, 0xa5, 0xd4, 0xe8],
+    );
+    test(
+        "4294967295",
+        &[4, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, 0xff, 0xff],
+    );
+    test("4294967296", &[5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+    test(
+        "18446744073709551616",
+        &[9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+    );
+
+    // trailing zero bytes are accepted
+    assert_eq!(
+        bincode::deserialize::<Natural>(&[3, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0]).unwrap(),
+        100
+    );
+    assert!(bincode::deserialize::<Natural>(&[2, 0, 0, 0, 0, 0, 0, 0, 100]).is_err());
+}
+
 #[test]
 fn serde_properties() {
     natural_gen().test_properties(|x| {
@@ -39,6 +76,13 @@ fn serde_properties() {
         assert_eq!(serde_json::from_str::<Natural>(&s).unwrap(), x);
         assert_eq!(serde_json::from_str::<Integer>(&s).unwrap(), x);
         assert!(string_is_subset(&s, "\"0123456789abcdefx"));
+
+        let bytes = bincode::serialize(&x).unwrap();
+        assert_eq!(bincode::deserialize::<Natural>(&bytes).unwrap(), x);
+        assert_eq!(
+            u64::exact_from(bytes.len()),
+            8 + x.significant_bits().div_round(8, RoundingMode::Ceiling).0
+        );
     });
 
     string_gen().test_properties(|s| {
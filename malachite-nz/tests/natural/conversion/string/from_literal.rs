@@ -0,0 +1,116 @@
+use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
+use malachite_base::num::conversion::traits::FromStringBase;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::{string_gen, string_gen_var_3, string_gen_var_7};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen;
+use std::str::FromStr;
+
+fn separate_digits(s: &str) -> String {
+    let (prefix, digits) = if s.len() > 2 && s.as_bytes()[0] == b'0' && s.as_bytes()[1] > b'9' {
+        s.split_at(2)
+    } else {
+        ("", s)
+    };
+    let mut out = prefix.to_string();
+    for (i, c) in digits.chars().enumerate() {
+        if i != 0 {
+            out.push('_');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[test]
+fn test_from_literal() {
+    let test_ok = |s, n| {
+        assert_eq!(Natural::from_literal(s).unwrap().to_string(), n);
+    };
+    test_ok("0", "0");
+    test_ok("+0", "0");
+    test_ok("123456", "123456");
+    test_ok("1_000_000", "1000000");
+    test_ok("1__000__", "1000");
+    test_ok("0x0", "0");
+    test_ok("0xff", "255");
+    test_ok("0XFF", "255");
+    test_ok("0xdead_BEEF", "3735928559");
+    test_ok("+0x_ff", "255");
+    test_ok("0o755", "493");
+    test_ok("0O7_7", "63");
+    test_ok("0b1010", "10");
+    test_ok("0B_1111_0000", "240");
+    test_ok("0010", "10");
+    test_ok(
+        "0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff",
+        "340282366920938463463374607431768211455",
+    );
+    test_ok(
+        "1_000_000_000_000_000_000_000_000",
+        "1000000000000000000000000",
+    );
+
+    let test_err = |s, kind| {
+        assert_eq!(Natural::from_literal(s).unwrap_err().kind(), kind);
+    };
+    test_err("", ParseNumberErrorKind::Empty);
+    test_err("+", ParseNumberErrorKind::Empty);
+    test_err("0x", ParseNumberErrorKind::Empty);
+    test_err("0b__", ParseNumberErrorKind::Empty);
+    test_err("-1", ParseNumberErrorKind::SignNotAllowed);
+    test_err("-0x1", ParseNumberErrorKind::SignNotAllowed);
+    test_err("++1", ParseNumberErrorKind::InvalidDigit(1));
+    test_err("0x+1", ParseNumberErrorKind::InvalidDigit(2));
+    test_err("0b-1", ParseNumberErrorKind::InvalidDigit(2));
+    test_err("_1", ParseNumberErrorKind::InvalidDigit(0));
+    test_err("+_1", ParseNumberErrorKind::InvalidDigit(1));
+    test_err("12a", ParseNumberErrorKind::InvalidDigit(2));
+    test_err("0b1_012", ParseNumberErrorKind::InvalidDigit(6));
+    test_err("0o8", ParseNumberErrorKind::InvalidDigit(2));
+    test_err("0xffu8", ParseNumberErrorKind::InvalidDigit(4));
+    test_err("0z1", ParseNumberErrorKind::InvalidDigit(1));
+    test_err(" 1", ParseNumberErrorKind::InvalidDigit(0));
+    test_err("1.0", ParseNumberErrorKind::InvalidDigit(1));
+}
+
+#[test]
+fn from_literal_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 64);
+    string_gen().test_properties_with_config(&config, |s| {
+        if let Ok(n) = Natural::from_literal(&s) {
+            assert!(!s.starts_with('-'));
+            if !s.starts_with('+') {
+                assert_eq!(Natural::from_literal(&format!("+{s}")).unwrap(), n);
+                assert_eq!(Natural::from_literal(&separate_digits(&s)).unwrap(), n);
+            }
+        }
+    });
+
+    string_gen_var_3().test_properties(|s| {
+        let n = Natural::from_literal(&s).unwrap();
+        assert_eq!(Natural::from_str(&s).unwrap(), n);
+        assert_eq!(Natural::from_literal(&format!("+{s}")).unwrap(), n);
+        assert_eq!(Natural::from_literal(&separate_digits(&s)).unwrap(), n);
+    });
+
+    string_gen_var_7().test_properties(|s| {
+        let n = Natural::from_literal(&format!("0x{s}")).unwrap();
+        assert_eq!(Natural::from_string_base(16, &s).unwrap(), n);
+        assert_eq!(Natural::from_literal(&format!("0X{s}")).unwrap(), n);
+    });
+
+    natural_gen().test_properties(|n| {
+        for s in [
+            n.to_string(),
+            format!("{n:#x}"),
+            format!("{n:#X}"),
+            format!("{n:#o}"),
+            format!("{n:#b}"),
+        ] {
+            assert_eq!(Natural::from_literal(&s).unwrap(), n);
+            assert_eq!(Natural::from_literal(&separate_digits(&s)).unwrap(), n);
+        }
+    });
+}
@@ -1,6 +1,7 @@
 use malachite_base::num::basic::signeds::PrimitiveSigned;
 use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
 use malachite_base::num::conversion::string::options::FromSciStringOptions;
+use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
 use malachite_base::num::conversion::traits::{ExactFrom, FromSciString, ToStringBase};
 use malachite_base::rounding_modes::RoundingMode;
 use malachite_base::test_util::generators::{
@@ -15,9 +16,9 @@ use std::str::FromStr;
 pub fn test_from_sci_string() {
     fn test(s: &str, out: Option<&'static str>) {
         let out = out.map(|s| Natural::from_str(s).unwrap());
-        assert_eq!(Natural::from_sci_string(s), out);
+        assert_eq!(Natural::from_sci_string(s).ok(), out);
         assert_eq!(
-            Natural::from_sci_string_with_options(s, FromSciStringOptions::default()),
+            Natural::from_sci_string_with_options(s, FromSciStringOptions::default()).ok(),
             out
         );
     }
@@ -91,25 +92,57 @@ pub fn test_from_sci_string() {
     test("0e-10000000000000000000000000000", None);
 }
 
+#[test]
+pub fn test_from_sci_string_error() {
+    fn test(s: &str, options: FromSciStringOptions, kind: ParseNumberErrorKind) {
+        assert_eq!(
+            Natural::from_sci_string_with_options(s, options)
+                .unwrap_err()
+                .kind(),
+            kind
+        );
+    }
+    let mut options = FromSciStringOptions::default();
+    test("", options, ParseNumberErrorKind::Empty);
+    test("1e", options, ParseNumberErrorKind::Empty);
+    test("1.2.3", options, ParseNumberErrorKind::InvalidDigit(3));
+    test("12a", options, ParseNumberErrorKind::InvalidDigit(2));
+    test("-1.5", options, ParseNumberErrorKind::SignNotAllowed);
+    test("-6e-1", options, ParseNumberErrorKind::SignNotAllowed);
+    test(
+        "1e10000000000000000000000",
+        options,
+        ParseNumberErrorKind::Overflow,
+    );
+
+    options.set_base(16);
+    test("ffg", options, ParseNumberErrorKind::InvalidDigit(2));
+
+    options = FromSciStringOptions::default();
+    options.set_rounding_mode(RoundingMode::Exact);
+    test("1.5", options, ParseNumberErrorKind::Inexact);
+    test("15e-1", options, ParseNumberErrorKind::Inexact);
+}
+
 #[test]
 pub fn test_from_sci_string_with_options() {
     fn test(s: &str, options: FromSciStringOptions, out: Option<&str>) {
         let out = out.map(|s| Natural::from_str(s).unwrap());
-        assert_eq!(Natural::from_sci_string_with_options(s, options), out);
+        assert_eq!(Natural::from_sci_string_with_options(s, options).ok(), out);
     }
     fn test_u<T: PrimitiveUnsigned>(s: &str, options: FromSciStringOptions, out: Option<T>)
     where
         Natural: From<T>,
     {
         let out = out.map(Natural::from);
-        assert_eq!(Natural::from_sci_string_with_options(s, options), out);
+        assert_eq!(Natural::from_sci_string_with_options(s, options).ok(), out);
     }
     fn test_i<T: PrimitiveSigned>(s: &str, options: FromSciStringOptions, out: Option<T>)
     where
         Natural: TryFrom<T>,
     {
         let out = out.map(Natural::exact_from);
-        assert_eq!(Natural::from_sci_string_with_options(s, options), out);
+        assert_eq!(Natural::from_sci_string_with_options(s, options).ok(), out);
     }
     // For tests with the default options, see `test_from_sci_string`
 
@@ -376,7 +409,7 @@ pub fn test_from_sci_string_with_options() {
 }
 
 fn from_sci_string_helper(s: &str) {
-    if let Some(x) = Natural::from_sci_string(s) {
+    if let Ok(x) = Natural::from_sci_string(s) {
         assert!(x.is_valid());
         for c in ['.', 'e', 'E', '+'] {
             if s.contains(c) {
@@ -406,7 +439,7 @@ fn from_sci_string_properties() {
 }
 
 fn from_sci_string_with_options_helper(s: &str, options: FromSciStringOptions) {
-    if let Some(x) = Natural::from_sci_string_with_options(s, options) {
+    if let Ok(x) = Natural::from_sci_string_with_options(s, options) {
         assert!(x.is_valid());
         assert!(!s.ends_with('+'));
         assert!(!s.ends_with('-'));
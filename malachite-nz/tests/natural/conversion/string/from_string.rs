@@ -1,3 +1,4 @@
+use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
 use malachite_base::num::conversion::traits::{
     ExactFrom, FromStringBase, ToStringBase, WrappingFrom,
 };
@@ -117,7 +118,7 @@ fn test_from_string_base() {
     test_ok(36, "helloworld", "1767707668033969");
 
     let test_err = |base, s, rug_err| {
-        assert!(Natural::from_string_base(base, s).is_none());
+        assert!(Natural::from_string_base(base, s).is_err());
         assert!(BigUint::from_str_radix(s, u32::exact_from(base)).is_err());
         let rn = rug::Integer::from_str_radix(s, i32::exact_from(base));
         assert_eq!(rn.is_err() || rn.unwrap() < 0, rug_err);
@@ -135,15 +136,31 @@ fn test_from_string_base() {
 }
 
 #[test]
-#[should_panic]
-fn from_string_base_fail_1() {
-    Natural::from_string_base(1, "0");
-}
-
-#[test]
-#[should_panic]
-fn from_string_base_fail_2() {
-    Natural::from_string_base(0, "0");
+fn test_from_string_base_error() {
+    fn test(base: u8, s: &str, kind: ParseNumberErrorKind) {
+        assert_eq!(Natural::from_string_base(base, s).unwrap_err().kind(), kind);
+    }
+    test(10, "", ParseNumberErrorKind::Empty);
+    test(10, "-5", ParseNumberErrorKind::SignNotAllowed);
+    test(10, "+5", ParseNumberErrorKind::SignNotAllowed);
+    test(10, "12A", ParseNumberErrorKind::InvalidDigit(2));
+    test(2, "123", ParseNumberErrorKind::InvalidDigit(1));
+    test(
+        2,
+        &format!("{}2", "1".repeat(100)),
+        ParseNumberErrorKind::InvalidDigit(100),
+    );
+    test(8, "778", ParseNumberErrorKind::InvalidDigit(2));
+    test(16, "ffg", ParseNumberErrorKind::InvalidDigit(2));
+    test(
+        16,
+        &format!("{}g", "f".repeat(100)),
+        ParseNumberErrorKind::InvalidDigit(100),
+    );
+    test(36, " 10", ParseNumberErrorKind::InvalidDigit(0));
+    test(0, "0", ParseNumberErrorKind::InvalidBase(0));
+    test(1, "0", ParseNumberErrorKind::InvalidBase(1));
+    test(37, "0", ParseNumberErrorKind::InvalidBase(37));
 }
 
 fn from_string_base_helper(base: u8, s: &str) {
@@ -178,7 +195,7 @@ fn from_string_base_properties() {
             .entry(base)
             .or_insert_with(|| valid_digit_chars(u8::wrapping_from(base)));
         assert_eq!(
-            Natural::from_string_base(base, &s).is_some(),
+            Natural::from_string_base(base, &s).is_ok(),
             !s.is_empty() && s.chars().all(|c| digits.contains(&c)),
         );
     });
@@ -0,0 +1,99 @@
+use malachite_base::num::conversion::string::options::{DigitGrouping, FormatOptions};
+use malachite_base::num::conversion::traits::{ToFormattedString, ToStringBase};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_gen, natural_unsigned_pair_gen_var_3};
+use std::str::FromStr;
+
+#[test]
+pub fn test_to_formatted_string() {
+    fn test(s: &str, options: FormatOptions, out: &str) {
+        let x = Natural::from_str(s).unwrap();
+        assert_eq!(x.to_formatted_string(options), out);
+    }
+    let mut options = FormatOptions::default();
+    test("0", options, "0");
+    test("123", options, "123");
+    test("1000000000000000000000", options, "1000000000000000000000");
+
+    options.set_digit_grouping(DigitGrouping::Uniform(3));
+    test("0", options, "0");
+    test("123", options, "123");
+    test("1234", options, "1,234");
+    test(
+        "1000000000000000000000",
+        options,
+        "1,000,000,000,000,000,000,000",
+    );
+    test(
+        "340282366920938463463374607431768211456",
+        options,
+        "340,282,366,920,938,463,463,374,607,431,768,211,456",
+    );
+
+    options.set_digit_grouping(DigitGrouping::Indian);
+    test("12345", options, "12,345");
+    test(
+        "1000000000000000000000",
+        options,
+        "1,00,00,00,00,00,00,00,00,00,000",
+    );
+
+    options.set_digit_grouping(DigitGrouping::Uniform(3));
+    options.set_group_separator('\'');
+    options.set_decimal_separator(',');
+    options.set_fraction_digits(3);
+    test(
+        "1000000000000000000000",
+        options,
+        "1'000'000'000'000'000'000'000,000",
+    );
+
+    let mut options = FormatOptions::default();
+    options.set_base(16);
+    options.set_digit_grouping(DigitGrouping::Uniform(4));
+    options.set_group_separator('_');
+    test(
+        "340282366920938463463374607431768211455",
+        options,
+        "ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff",
+    );
+    options.set_uppercase();
+    test("1000000000000000000000", options, "36_35C9_ADC5_DEA0_0000");
+}
+
+#[test]
+fn to_formatted_string_properties() {
+    natural_unsigned_pair_gen_var_3().test_properties(|(x, base)| {
+        let mut options = FormatOptions::default();
+        options.set_base(base);
+        let s = x.to_string_base(base);
+        assert_eq!(x.to_formatted_string(options), s);
+        options.set_group_separator('_');
+        for grouping in
+            [DigitGrouping::Uniform(1), DigitGrouping::Uniform(3), DigitGrouping::Indian]
+        {
+            options.set_digit_grouping(grouping);
+            let s_grouped = x.to_formatted_string(options);
+            assert_eq!(s_grouped.replace('_', ""), s);
+            assert!(!s_grouped.starts_with('_'));
+            assert!(!s_grouped.contains("__"));
+        }
+        options.set_digit_grouping(DigitGrouping::None);
+        options.set_uppercase();
+        options.set_fraction_digits(2);
+        assert_eq!(
+            x.to_formatted_string(options),
+            x.to_string_base_upper(base) + ".00"
+        );
+    });
+
+    natural_gen().test_properties(|x| {
+        let mut options = FormatOptions::default();
+        options.set_digit_grouping(DigitGrouping::Uniform(3));
+        let s = x.to_formatted_string(options);
+        assert_eq!(s.replace(',', ""), x.to_string());
+        let groups: Vec<&str> = s.split(',').collect();
+        assert!((1..=3).contains(&groups[0].len()));
+        assert!(groups[1..].iter().all(|g| g.len() == 3));
+    });
+}
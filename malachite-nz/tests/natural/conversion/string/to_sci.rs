@@ -2,7 +2,7 @@ use malachite_base::num::arithmetic::traits::{FloorLogBase, Pow, PowerOf2, Round
 use malachite_base::num::conversion::string::options::{
     FromSciStringOptions, SciSizeOptions, ToSciOptions,
 };
-use malachite_base::num::conversion::traits::{FromSciString, ToSci};
+use malachite_base::num::conversion::traits::{ExactFrom, FromSciString, ToSci};
 use malachite_base::rounding_modes::RoundingMode;
 use malachite_base::strings::string_is_subset;
 use malachite_base::test_util::generators::{unsigned_gen, unsigned_to_sci_options_pair_gen_var_1};
@@ -457,3 +457,65 @@ fn to_sci_with_options_properties() {
         );
     });
 }
+
+#[test]
+pub fn test_exp() {
+    fn test(s: &str, out: &str, out_p2: &str) {
+        let x = Natural::from_str(s).unwrap();
+        assert_eq!(format!("{x:e}"), out);
+        assert_eq!(format!("{x:E}"), out.to_uppercase());
+        assert_eq!(format!("{x:.2e}"), out_p2);
+    }
+    test("0", "0e0", "0.00e0");
+    test("1", "1e0", "1.00e0");
+    test("10", "1e1", "1.00e1");
+    test("123", "1.23e2", "1.23e2");
+    test("1250", "1.25e3", "1.25e3");
+    test("12345", "1.2345e4", "1.23e4");
+    test("12355", "1.2355e4", "1.24e4");
+    test("99999", "9.9999e4", "1.00e5");
+    test("1000000000000000000000000", "1e24", "1.00e24");
+    test(
+        "340282366920938463463374607431768211455",
+        "3.40282366920938463463374607431768211455e38",
+        "3.40e38",
+    );
+
+    let x = Natural::from(123456u32);
+    assert_eq!(format!("{x:>12e}"), "   1.23456e5");
+    assert_eq!(format!("{x:*<12e}"), "1.23456e5***");
+    assert_eq!(format!("{x:^+12.1e}"), "   +1.2e5   ");
+    assert_eq!(format!("{x:012e}"), "0001.23456e5");
+    assert_eq!(format!("{x:+012.0E}"), "+000000001E5");
+}
+
+#[test]
+fn exp_properties() {
+    natural_gen().test_properties(|x| {
+        let s = format!("{x:e}");
+        assert_eq!(format!("{x:E}"), s.to_uppercase());
+        assert!(string_is_subset(&s, DECIMAL_SCI_STRING_CHARS));
+        assert!(x == 0 || !s.contains("0e"));
+        assert_eq!(Natural::from_sci_string(&s).unwrap(), x);
+        for p in 0..5 {
+            let s = format!("{x:.p$e}");
+            let i = s.find('e').unwrap();
+            assert_eq!(i, if p == 0 { 1 } else { p + 2 });
+            let mut options = ToSciOptions::default();
+            options.set_precision(u64::exact_from(p) + 1);
+            assert_eq!(
+                Natural::from_sci_string(&s).unwrap(),
+                Natural::from_sci_string(&x.to_sci_with_options(options).to_string()).unwrap()
+            );
+        }
+    });
+
+    unsigned_gen::<Limb>().test_properties(|x| {
+        let n = Natural::from(x);
+        assert_eq!(format!("{n:e}"), format!("{x:e}"));
+        assert_eq!(format!("{n:E}"), format!("{x:E}"));
+        assert_eq!(format!("{n:.3e}"), format!("{x:.3e}"));
+        assert_eq!(format!("{n:>+20.1e}"), format!("{x:>+20.1e}"));
+        assert_eq!(format!("{n:020E}"), format!("{x:020E}"));
+    });
+}
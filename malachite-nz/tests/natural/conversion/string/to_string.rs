@@ -2,6 +2,8 @@ use itertools::repeat_n;
 use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::string::to_string::BaseFmtWrapper as BaseBaseFmtWrapper;
 use malachite_base::num::conversion::traits::{FromStringBase, ToStringBase};
+use malachite_base::num::random::random_primitive_ints;
+use malachite_base::random::EXAMPLE_SEED;
 use malachite_base::strings::{
     string_is_subset, ToBinaryString, ToDebugString, ToLowerHexString, ToOctalString,
     ToUpperHexString,
@@ -79,6 +81,19 @@ pub fn test_to_string() {
     test_width("1000000000000000", 20, "00001000000000000000");
 }
 
+// These inputs are large enough that, if the `parallel` feature is enabled, the multithreaded
+// code paths are used.
+#[test]
+fn test_large_to_string_and_from_str() {
+    let mut limbs = random_primitive_ints::<Limb>(EXAMPLE_SEED);
+    for &len in &[12000, 30000] {
+        let x = Natural::from_owned_limbs_asc(limbs.by_ref().take(len).collect());
+        let s = x.to_string();
+        assert_eq!(rug::Integer::from(&x).to_string(), s);
+        assert_eq!(Natural::from_str(&s).unwrap(), x);
+    }
+}
+
 #[test]
 fn to_string_properties() {
     natural_gen().test_properties(|x| {
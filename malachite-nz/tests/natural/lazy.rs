@@ -0,0 +1,119 @@
+use malachite_base::num::arithmetic::traits::{AddMul, SubMul};
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::lazy::{Lazy, LazyExpr};
+use malachite_nz::natural::scratch::Scratch;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{integer_triple_gen, natural_triple_gen};
+use std::str::FromStr;
+
+#[test]
+fn test_lazy_natural() {
+    let test = |s, t, u, out| {
+        let a = Natural::from_str(s).unwrap();
+        let b = Natural::from_str(t).unwrap();
+        let c = Natural::from_str(u).unwrap();
+        let x = (a.lazy() * &b + c.lazy() * &c - &a).evaluate();
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+    };
+    test("0", "0", "0", "0");
+    test("1", "2", "3", "10");
+    test("10", "20", "30", "1090");
+    test(
+        "1000000000000",
+        "1000000000000",
+        "1000000000000",
+        "1999999999999000000000000",
+    );
+
+    let a = Natural::from(5u32);
+    let b = Natural::from(3u32);
+    // The positive terms are added first, so this doesn't panic
+    assert_eq!((b.lazy() - &a + &a).evaluate(), 3);
+    assert_eq!((a.lazy() - &a).evaluate(), 0);
+    assert_eq!((Lazy(&a) - (b.lazy() - &a)).evaluate(), 7);
+}
+
+#[test]
+#[should_panic]
+fn lazy_natural_fail() {
+    let a = Natural::from(5u32);
+    let b = Natural::from(3u32);
+    (b.lazy() - &a).evaluate();
+}
+
+#[test]
+fn test_lazy_integer() {
+    let test = |s, t, u, out| {
+        let a = Integer::from_str(s).unwrap();
+        let b = Integer::from_str(t).unwrap();
+        let c = Integer::from_str(u).unwrap();
+        let mut x = Integer::from(123);
+        (a.lazy() * &b - c.lazy() * &a + &c).evaluate_into(&mut x);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+    };
+    test("0", "0", "0", "0");
+    test("1", "2", "3", "2");
+    test("-10", "20", "30", "130");
+    test("10", "-20", "-30", "70");
+
+    let a = Integer::from(5);
+    assert_eq!((Lazy(&a) - (a.lazy() * &a)).evaluate(), -20);
+    assert_eq!((Integer::ZERO.lazy() - &a - &a).evaluate(), -10);
+}
+
+#[test]
+fn lazy_natural_properties() {
+    let mut scratch = Scratch::new();
+    let mut out = Natural::ZERO;
+    natural_triple_gen().test_properties(|(a, b, c)| {
+        let expected = (&a).add_mul(&b, &c);
+        let x = (a.lazy() + b.lazy() * &c).evaluate();
+        assert!(x.is_valid());
+        assert_eq!(x, expected);
+
+        (b.lazy() * &c + &a).evaluate_into_with_scratch(&mut out, &mut scratch);
+        assert!(out.is_valid());
+        assert_eq!(out, expected);
+
+        let x = (a.lazy() * &b + b.lazy() * &c - b.lazy() * &c).evaluate();
+        assert!(x.is_valid());
+        assert_eq!(x, &a * &b);
+
+        let x = (a.lazy() * &b + &c - &c - a.lazy() * &b).evaluate();
+        assert_eq!(x, 0u32);
+
+        (a.lazy() * &b + b.lazy() * &c + c.lazy() * &a - b.lazy() * &c)
+            .evaluate_into_with_scratch(&mut out, &mut scratch);
+        assert!(out.is_valid());
+        assert_eq!(out, &a * &b + &c * &a);
+    });
+}
+
+#[test]
+fn lazy_integer_properties() {
+    let mut scratch = Scratch::new();
+    let mut out = Integer::ZERO;
+    integer_triple_gen().test_properties(|(a, b, c)| {
+        let expected = (&a).sub_mul(&b, &c);
+        let x = (a.lazy() - b.lazy() * &c).evaluate();
+        assert!(x.is_valid());
+        assert_eq!(x, expected);
+
+        (Integer::ZERO.lazy() - b.lazy() * &c + &a)
+            .evaluate_into_with_scratch(&mut out, &mut scratch);
+        assert!(out.is_valid());
+        assert_eq!(out, expected);
+
+        let x = (a.lazy() * &b + c.lazy() * &c).evaluate();
+        assert!(x.is_valid());
+        assert_eq!(x, &a * &b + &c * &c);
+
+        (a.lazy() * &b - b.lazy() * &c + c.lazy() * &c - a.lazy() * &a)
+            .evaluate_into_with_scratch(&mut out, &mut scratch);
+        assert!(out.is_valid());
+        assert_eq!(out, &a * &b - &b * &c + &c * &c - &a * &a);
+    });
+}
@@ -0,0 +1,154 @@
+use malachite_base::num::arithmetic::traits::{
+    ModInverse, ModMul, ModNeg, ModPow, ModSquare, Pow, PowerOf2, Square,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::natural::modular::{Modulus, Residue};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{
+    natural_gen, natural_triple_gen_var_4, natural_unsigned_pair_gen_var_4, natural_vec_gen,
+};
+use std::str::FromStr;
+
+#[test]
+fn test_residue() {
+    let test = |m: &str, x: &str, y: &str, sum: &str, product: &str| {
+        let m = Modulus::new(Natural::from_str(m).unwrap());
+        let x = m.residue(&Natural::from_str(x).unwrap());
+        let y = m.residue(&Natural::from_str(y).unwrap());
+        assert_eq!(Natural::from(&x + &y).to_string(), sum);
+        assert_eq!(Natural::from(&x * &y).to_string(), product);
+        assert_eq!(Natural::from(&x - &y + &y), Natural::from(&x));
+    };
+    test("1", "5", "7", "0", "0");
+    test("10", "7", "5", "2", "5");
+    test("11", "7", "5", "1", "2");
+    test("1000000007", "123", "456", "579", "56088");
+    test(
+        "340282366920938463463374607431768211297",
+        "340282366920938463463374607431768211296",
+        "340282366920938463463374607431768211296",
+        "340282366920938463463374607431768211295",
+        "1",
+    );
+    test(
+        "340282366920938463463374607431768211456",
+        "1000000000000000000000",
+        "1000000000000000000000",
+        "2000000000000000000000",
+        "250405986282794344605403365464994742272",
+    );
+}
+
+#[test]
+fn test_pow_and_invert() {
+    let m = Modulus::new(Natural::from(1000000007u32));
+    let x = m.residue(&Natural::from(3u32));
+    assert_eq!(Natural::from((&x).pow(1000000006)), 1);
+    assert_eq!(Natural::from((&x).pow(0)), 1);
+    assert_eq!(Natural::from(m.zero().pow(0)), 1);
+    assert_eq!(Natural::from(x.invert().unwrap() * &x), 1);
+    assert!(m.zero().invert().is_none());
+
+    let m = Modulus::new(Natural::from(12u32));
+    assert!(m.residue(&Natural::from(4u32)).invert().is_none());
+    assert_eq!(
+        Natural::from(m.residue(&Natural::from(5u32)).invert().unwrap()),
+        5
+    );
+    assert_eq!(m.batch_invert(&[]), Some(Vec::new()));
+}
+
+#[test]
+#[should_panic]
+fn modulus_fail() {
+    Modulus::new(Natural::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn add_fail() {
+    let m_1 = Modulus::new(Natural::from(10u32));
+    let m_2 = Modulus::new(Natural::from(11u32));
+    let _ = m_1.one() + m_2.one();
+}
+
+// Moduli with at least 100 limbs use `limbs_redc` rather than `limbs_redc_limb`, so cover them
+// explicitly.
+#[test]
+fn test_large_modulus() {
+    for m in
+        [Natural::power_of_2(7001) - Natural::ONE, Natural::power_of_2(7001) - Natural::from(2u32)]
+    {
+        let modulus = Modulus::new(m.clone());
+        let x = Natural::power_of_2(6000) / Natural::from(7u32);
+        let y = Natural::power_of_2(7000) / Natural::from(3u32);
+        let rx = modulus.residue(&x);
+        let ry = modulus.residue(&y);
+        assert_eq!(Natural::from(&rx * &ry), (&x).mod_mul(&y, &m));
+        assert_eq!(Natural::from((&rx).square()), (&x).mod_square(&m));
+        assert_eq!(Natural::from(-&rx), (&x).mod_neg(&m));
+        assert_eq!(
+            Natural::from((&rx).pow(12345)),
+            (&x).mod_pow(Natural::from(12345u32), &m)
+        );
+    }
+}
+
+#[test]
+fn residue_properties() {
+    natural_triple_gen_var_4().test_properties(|(x, y, m)| {
+        let modulus = Modulus::new(m.clone());
+        let rx = modulus.residue(&x);
+        let ry = modulus.residue(&y);
+        let x = x % &m;
+        let y = y % &m;
+        assert_eq!(Natural::from(&rx), x);
+        assert_eq!(Natural::from(&rx + &ry), (&x + &y) % &m);
+        assert_eq!(Natural::from(&rx - &ry), (&x + &m - &y) % &m);
+        assert_eq!(Natural::from(-&rx), (&x).mod_neg(&m));
+        assert_eq!(Natural::from(&rx * &ry), (&x).mod_mul(&y, &m));
+        assert_eq!(Natural::from((&rx).square()), (&x).mod_square(&m));
+        assert_eq!(&rx * &rx, (&rx).square());
+        assert_eq!(
+            Natural::from((&rx).pow(&y)),
+            if m == 1 {
+                Natural::ZERO
+            } else {
+                (&x).mod_pow(&y, &m)
+            }
+        );
+        let inverse = if x == 0 { None } else { (&x).mod_inverse(&m) };
+        assert_eq!(rx.invert().map(Natural::from), inverse);
+    });
+
+    natural_unsigned_pair_gen_var_4::<u64>().test_properties(|(x, e)| {
+        let modulus = Modulus::new(Natural::from(1000000u32));
+        let rx = modulus.residue(&x);
+        let mut power = modulus.one();
+        for _ in 0..e {
+            power *= &rx;
+        }
+        assert_eq!((&rx).pow(e), power);
+    });
+
+    natural_gen().test_properties(|m| {
+        let modulus = Modulus::new(m + Natural::ONE);
+        assert_eq!(modulus.one() * modulus.one(), modulus.one());
+        assert_eq!(
+            Natural::from(modulus.one()),
+            Natural::ONE % modulus.modulus()
+        );
+    });
+}
+
+#[test]
+fn batch_invert_properties() {
+    for m in [Natural::from(1000000007u32), Natural::from(1000000u32)] {
+        let modulus = Modulus::new(m);
+        natural_vec_gen().test_properties(|xs| {
+            let xs: Vec<_> = xs.iter().map(|x| modulus.residue(x)).collect();
+            let inverses: Option<Vec<_>> = xs.iter().map(Residue::invert).collect();
+            assert_eq!(modulus.batch_invert(&xs), inverses);
+        });
+    }
+}
@@ -0,0 +1,155 @@
+use malachite_base::num::arithmetic::traits::{PowerOf2, Square};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_nz::natural::scratch::Scratch;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_gen, natural_pair_gen, natural_vec_gen};
+use std::str::FromStr;
+
+#[test]
+fn test_mul_with_scratch() {
+    let test = |s, t, out| {
+        let mut scratch = Scratch::new();
+        let mut x = Natural::from_str(s).unwrap();
+        x.mul_with_scratch(&Natural::from_str(t).unwrap(), &mut scratch);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+    };
+    test("0", "0", "0");
+    test("0", "123", "0");
+    test("123", "0", "0");
+    test("123", "456", "56088");
+    test(
+        "18446744073709551615",
+        "18446744073709551615",
+        "340282366920938463426481119284349108225",
+    );
+    test("1000000000000000000000000", "0", "0");
+    test("123456789000", "987654321000", "121932631112635269000000");
+    test(
+        "340282366920938463463374607431768211455",
+        "340282366920938463463374607431768211455",
+        "115792089237316195423570985008687907852589419931798687112530834793049593217025",
+    );
+}
+
+#[test]
+fn test_square_with_scratch() {
+    let test = |s, out| {
+        let mut scratch = Scratch::new();
+        let mut x = Natural::from_str(s).unwrap();
+        x.square_with_scratch(&mut scratch);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+    };
+    test("0", "0");
+    test("1", "1");
+    test("123", "15129");
+    test(
+        "18446744073709551616",
+        "340282366920938463463374607431768211456",
+    );
+}
+
+#[test]
+fn test_capacity() {
+    let mut scratch = Scratch::new();
+    assert_eq!(scratch.capacity(), 0);
+    scratch.reserve(10000);
+    assert!(scratch.capacity() >= 20000);
+    scratch.shrink_to_fit();
+    assert_eq!(scratch.capacity(), 0);
+
+    // Storage reserved for a large Natural survives it shrinking to an inline size
+    let mut x = Natural::power_of_2(1000);
+    x.reserve(10000);
+    assert!(x.capacity() >= 11001);
+    x >>= 900u64;
+    assert!(x.is_valid());
+    assert!(x.capacity() >= 11001);
+    x.shrink_to_fit();
+    assert!(x.capacity() < 11001);
+    assert_eq!(x, Natural::power_of_2(100));
+
+    let mut x = Natural::ONE;
+    x.reserve(10000);
+    assert!(x.is_valid());
+    assert_eq!(x.capacity(), Natural::ONE.capacity());
+    x.shrink_to_fit();
+    assert_eq!(x, 1u32);
+
+    let mut x = Natural::ZERO;
+    x.reserve(10000);
+    assert!(x.is_valid());
+    assert_eq!(x, 0u32);
+    assert_eq!(x.capacity(), Natural::ZERO.capacity());
+}
+
+#[test]
+fn test_with_capacity() {
+    let mut x = Natural::with_capacity(10000);
+    assert!(x.is_valid());
+    assert_eq!(x, 0u32);
+    x += Natural::power_of_2(100);
+    x.reserve(10000);
+    assert!(x.capacity() >= 10101);
+}
+
+#[test]
+fn mul_with_scratch_properties() {
+    natural_pair_gen().test_properties(|(x, y)| {
+        let mut scratch = Scratch::new();
+        let mut product = x.clone();
+        product.mul_with_scratch(&y, &mut scratch);
+        assert!(product.is_valid());
+        assert_eq!(product, &x * &y);
+
+        // Reusing the same scratch gives the same results
+        let mut product_alt = y.clone();
+        product_alt.mul_with_scratch(&x, &mut scratch);
+        assert!(product_alt.is_valid());
+        assert_eq!(product_alt, product);
+        product_alt.mul_with_scratch(&Natural::ZERO, &mut scratch);
+        assert_eq!(product_alt, 0u32);
+    });
+
+    natural_vec_gen().test_properties(|xs| {
+        let mut scratch = Scratch::new();
+        let mut product = Natural::ONE;
+        for x in &xs {
+            product.mul_with_scratch(x, &mut scratch);
+            assert!(product.is_valid());
+        }
+        assert_eq!(product, xs.iter().product::<Natural>());
+    });
+}
+
+#[test]
+fn square_with_scratch_properties() {
+    natural_gen().test_properties(|x| {
+        let mut scratch = Scratch::new();
+        let mut square = x.clone();
+        square.square_with_scratch(&mut scratch);
+        assert!(square.is_valid());
+        assert_eq!(square, (&x).square());
+        square.square_with_scratch(&mut scratch);
+        assert!(square.is_valid());
+        assert_eq!(square, (&x).square().square());
+    });
+}
+
+#[test]
+fn capacity_properties() {
+    natural_gen().test_properties(|mut x| {
+        assert!(x.capacity() >= x.significant_bits());
+        let old_x = x.clone();
+        x.reserve(1000);
+        assert_eq!(x, old_x);
+        if x.significant_bits() > 64 {
+            assert!(x.capacity() >= x.significant_bits() + 1000);
+        }
+        x.shrink_to_fit();
+        assert!(x.is_valid());
+        assert_eq!(x, old_x);
+    });
+}
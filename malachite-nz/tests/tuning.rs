@@ -0,0 +1,123 @@
+use malachite_nz::tuning::{make_thresholds_valid, parse_thresholds, validate_thresholds};
+use std::collections::BTreeMap;
+
+const PLATFORM_64: &str = include_str!("../src/platform_64.rs");
+
+const TUNED: &str = "\
+pub const MUL_TOOM22_THRESHOLD: usize = 20; // default: 20
+pub const MUL_TOOM33_THRESHOLD: usize = 39;
+pub const MUL_TOOM44_THRESHOLD: usize = 340;
+pub const MUL_TOOM6H_THRESHOLD: usize = 345;
+pub const MUL_TOOM8H_THRESHOLD: usize = 640;
+pub const MUL_TOOM32_TO_TOOM43_THRESHOLD: usize = 60;
+pub const MUL_FFT_THRESHOLD: usize = 1_500;
+pub const DC_DIV_QR_THRESHOLD: usize = 85;
+pub const MU_DIV_QR_THRESHOLD: usize = 2094;
+";
+
+fn check(source: &str) -> Result<(), String> {
+    parse_thresholds(source).and_then(|thresholds| validate_thresholds(&thresholds))
+}
+
+#[test]
+fn test_parse_thresholds() {
+    let thresholds = parse_thresholds(TUNED).unwrap();
+    assert_eq!(thresholds.len(), 8);
+    assert_eq!(thresholds["MUL_TOOM22_THRESHOLD"], 20);
+    assert_eq!(thresholds["MUL_FFT_THRESHOLD"], 1500);
+    assert_eq!(thresholds["MU_DIV_QR_THRESHOLD"], 2094);
+    assert!(!thresholds.contains_key("MUL_TOOM32_TO_TOOM43_THRESHOLD"));
+    assert_eq!(parse_thresholds(PLATFORM_64).unwrap().len(), 8);
+
+    let test_fail = |source: &str, message: &str| {
+        assert_eq!(parse_thresholds(source).unwrap_err(), message);
+    };
+    test_fail(
+        &TUNED.replace("= 39;", "= 3 * 13;"),
+        "line 2: MUL_TOOM33_THRESHOLD is not an integer: `3 * 13`",
+    );
+    test_fail(
+        &TUNED.replace("= 39;", "= 39"),
+        "line 2: expected `;` after MUL_TOOM33_THRESHOLD",
+    );
+    test_fail(
+        &format!("{TUNED}pub const MUL_FFT_THRESHOLD: usize = 1600;\n"),
+        "line 10: MUL_FFT_THRESHOLD is defined more than once",
+    );
+    test_fail(
+        &TUNED.replace(
+            "pub const DC_DIV_QR_THRESHOLD",
+            "pub const DC_DIV_Q_THRESHOLD",
+        ),
+        "DC_DIV_QR_THRESHOLD is not defined",
+    );
+}
+
+#[test]
+fn test_validate_thresholds() {
+    assert_eq!(check(TUNED), Ok(()));
+    assert_eq!(check(PLATFORM_64), Ok(()));
+    assert_eq!(
+        check(&TUNED.replace("= 39;", "= 20;")),
+        Err(
+            "MUL_TOOM33_THRESHOLD is 20, but it must be greater than MUL_TOOM22_THRESHOLD, which \
+            is 20"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        check(&TUNED.replace("= 2094;", "= 80;")),
+        Err(
+            "MU_DIV_QR_THRESHOLD is 80, but it must be greater than DC_DIV_QR_THRESHOLD, which is \
+            85"
+            .to_string()
+        )
+    );
+    assert_eq!(
+        check(&TUNED.replace("= 20; // default: 20", "= 3;")),
+        Err(
+            "MUL_TOOM22_THRESHOLD is 3, but its algorithm needs inputs of length at least 6"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        check(&TUNED.replace("= 640;", "= 80;")),
+        Err(
+            "MUL_TOOM8H_THRESHOLD is 80, but its algorithm needs inputs of length at least 86"
+                .to_string()
+        )
+    );
+}
+
+#[test]
+fn test_make_thresholds_valid() {
+    let test = |values: [usize; 8], out: [usize; 8]| {
+        let names = [
+            "MUL_TOOM22_THRESHOLD",
+            "MUL_TOOM33_THRESHOLD",
+            "MUL_TOOM44_THRESHOLD",
+            "MUL_TOOM6H_THRESHOLD",
+            "MUL_TOOM8H_THRESHOLD",
+            "MUL_FFT_THRESHOLD",
+            "DC_DIV_QR_THRESHOLD",
+            "MU_DIV_QR_THRESHOLD",
+        ];
+        let mut thresholds: BTreeMap<&str, usize> = names.into_iter().zip(values).collect();
+        make_thresholds_valid(&mut thresholds);
+        assert_eq!(validate_thresholds(&thresholds), Ok(()));
+        assert_eq!(names.map(|name| thresholds[name]), out);
+    };
+    test(
+        [20, 39, 340, 345, 640, 1500, 85, 2094],
+        [20, 39, 340, 345, 640, 1500, 85, 2094],
+    );
+    test(
+        [118, 101, 530, 738, 984, 5608, 7, 2243],
+        [118, 119, 530, 738, 984, 5608, 7, 2243],
+    );
+    test([0, 0, 0, 0, 0, 0, 0, 0], [6, 17, 30, 42, 86, 87, 6, 74]);
+    test(
+        [300, 300, 300, 300, 300, 300, 90, 90],
+        [300, 301, 302, 303, 304, 305, 90, 91],
+    );
+}
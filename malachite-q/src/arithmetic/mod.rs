@@ -42,6 +42,9 @@ pub mod log_base_2;
 /// and
 /// [`CheckedLogBasePowerOf2`](malachite_base::num::arithmetic::traits::CheckedLogBasePowerOf2).
 pub mod log_base_power_of_2;
+/// Implementations of [`Mod`](malachite_base::num::arithmetic::traits::Mod), a trait for reducing
+/// a [`Rational`](super::Rational) modulo a [`Natural`](malachite_nz::natural::Natural).
+pub mod mod_op;
 /// Multiplication of [`Rational`](super::Rational)s.
 pub mod mul;
 /// Negation of [`Rational`](super::Rational)s.
@@ -50,6 +53,8 @@ pub mod neg;
 /// [`NextPowerOf2Assign`](malachite_base::num::arithmetic::traits::NextPowerOf2Assign), traits for
 /// getting the next-highest power of 2.
 pub mod next_power_of_2;
+/// A function for finding the $p$-adic valuation of a [`Rational`](super::Rational).
+pub mod p_adic_valuation;
 /// Implementations of [`Pow`](malachite_base::num::arithmetic::traits::Pow) and
 /// [`PowAssign`](malachite_base::num::arithmetic::traits::PowAssign), traits for raising a number
 /// to a power.
@@ -0,0 +1,249 @@
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{Mod, ModInverse, ModMul, NegMod};
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::natural::Natural;
+
+fn mod_helper(
+    sign: bool,
+    numerator: &Natural,
+    denominator: &Natural,
+    m: &Natural,
+) -> Option<Natural> {
+    assert_ne!(*m, 0u32, "Cannot reduce modulo zero");
+    if *m == 1u32 {
+        return Some(Natural::ZERO);
+    }
+    let d = denominator % m;
+    if d == 0u32 {
+        return None;
+    }
+    let inverse = d.mod_inverse(m)?;
+    let n = if sign {
+        numerator % m
+    } else {
+        numerator.neg_mod(m)
+    };
+    Some(n.mod_mul(inverse, m))
+}
+
+impl Mod<Natural> for Rational {
+    type Output = Option<Natural>;
+
+    /// Reduces a [`Rational`] modulo a [`Natural`] $m$, taking both by value.
+    ///
+    /// A [`Rational`] $a/b$ is mapped to $ab^{-1} \mod m$, where $b^{-1}$ is the inverse of $b$
+    /// modulo $m$. This is the unique $r$ with $0 \leq r < m$ and $br \equiv a \mod m$. If $b$ and
+    /// $m$ are not coprime, $b$ has no inverse and `None` is returned.
+    ///
+    /// The map is compatible with addition and multiplication: if $x$ and $y$ are both reduced
+    /// successfully, then so are $x + y$ and $xy$, and their images are the sum and product of the
+    /// images of $x$ and $y$ modulo $m$.
+    ///
+    /// $$
+    /// f(a/b, m) = \\begin{cases}
+    ///     \operatorname{Some}(r) & \text{if} \\quad \gcd(b, m) = 1, \\\\
+    ///     \operatorname{None} & \textrm{otherwise},
+    /// \\end{cases}
+    /// $$
+    /// where $0 \leq r < m$ and $br \equiv a \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Mod;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Rational::from_signeds(1, 3).mod_op(Natural::from(7u32)),
+    ///     Some(Natural::from(5u32))
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(-1, 3).mod_op(Natural::from(7u32)),
+    ///     Some(Natural::from(2u32))
+    /// );
+    /// assert_eq!(Rational::from(10).mod_op(Natural::from(7u32)), Some(Natural::from(3u32)));
+    /// assert_eq!(Rational::from_signeds(1, 3).mod_op(Natural::from(6u32)), None);
+    /// ```
+    #[inline]
+    fn mod_op(self, m: Natural) -> Option<Natural> {
+        mod_helper(self.sign, &self.numerator, &self.denominator, &m)
+    }
+}
+
+impl<'a> Mod<&'a Natural> for Rational {
+    type Output = Option<Natural>;
+
+    /// Reduces a [`Rational`] modulo a [`Natural`] $m$, taking the [`Rational`] by value and the
+    /// [`Natural`] by reference.
+    ///
+    /// A [`Rational`] $a/b$ is mapped to $ab^{-1} \mod m$, where $b^{-1}$ is the inverse of $b$
+    /// modulo $m$. This is the unique $r$ with $0 \leq r < m$ and $br \equiv a \mod m$. If $b$ and
+    /// $m$ are not coprime, $b$ has no inverse and `None` is returned.
+    ///
+    /// The map is compatible with addition and multiplication: if $x$ and $y$ are both reduced
+    /// successfully, then so are $x + y$ and $xy$, and their images are the sum and product of the
+    /// images of $x$ and $y$ modulo $m$.
+    ///
+    /// $$
+    /// f(a/b, m) = \\begin{cases}
+    ///     \operatorname{Some}(r) & \text{if} \\quad \gcd(b, m) = 1, \\\\
+    ///     \operatorname{None} & \textrm{otherwise},
+    /// \\end{cases}
+    /// $$
+    /// where $0 \leq r < m$ and $br \equiv a \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Mod;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Rational::from_signeds(1, 3).mod_op(&Natural::from(7u32)),
+    ///     Some(Natural::from(5u32))
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(-1, 3).mod_op(&Natural::from(7u32)),
+    ///     Some(Natural::from(2u32))
+    /// );
+    /// assert_eq!(Rational::from(10).mod_op(&Natural::from(7u32)), Some(Natural::from(3u32)));
+    /// assert_eq!(Rational::from_signeds(1, 3).mod_op(&Natural::from(6u32)), None);
+    /// ```
+    #[inline]
+    fn mod_op(self, m: &'a Natural) -> Option<Natural> {
+        mod_helper(self.sign, &self.numerator, &self.denominator, m)
+    }
+}
+
+impl<'a> Mod<Natural> for &'a Rational {
+    type Output = Option<Natural>;
+
+    /// Reduces a [`Rational`] modulo a [`Natural`] $m$, taking the [`Rational`] by reference and
+    /// the [`Natural`] by value.
+    ///
+    /// A [`Rational`] $a/b$ is mapped to $ab^{-1} \mod m$, where $b^{-1}$ is the inverse of $b$
+    /// modulo $m$. This is the unique $r$ with $0 \leq r < m$ and $br \equiv a \mod m$. If $b$ and
+    /// $m$ are not coprime, $b$ has no inverse and `None` is returned.
+    ///
+    /// The map is compatible with addition and multiplication: if $x$ and $y$ are both reduced
+    /// successfully, then so are $x + y$ and $xy$, and their images are the sum and product of the
+    /// images of $x$ and $y$ modulo $m$.
+    ///
+    /// $$
+    /// f(a/b, m) = \\begin{cases}
+    ///     \operatorname{Some}(r) & \text{if} \\quad \gcd(b, m) = 1, \\\\
+    ///     \operatorname{None} & \textrm{otherwise},
+    /// \\end{cases}
+    /// $$
+    /// where $0 \leq r < m$ and $br \equiv a \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Mod;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(1, 3)).mod_op(Natural::from(7u32)),
+    ///     Some(Natural::from(5u32))
+    /// );
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-1, 3)).mod_op(Natural::from(7u32)),
+    ///     Some(Natural::from(2u32))
+    /// );
+    /// assert_eq!((&Rational::from(10)).mod_op(Natural::from(7u32)), Some(Natural::from(3u32)));
+    /// assert_eq!((&Rational::from_signeds(1, 3)).mod_op(Natural::from(6u32)), None);
+    /// ```
+    #[inline]
+    fn mod_op(self, m: Natural) -> Option<Natural> {
+        mod_helper(self.sign, &self.numerator, &self.denominator, &m)
+    }
+}
+
+impl<'a, 'b> Mod<&'b Natural> for &'a Rational {
+    type Output = Option<Natural>;
+
+    /// Reduces a [`Rational`] modulo a [`Natural`] $m$, taking both by reference.
+    ///
+    /// A [`Rational`] $a/b$ is mapped to $ab^{-1} \mod m$, where $b^{-1}$ is the inverse of $b$
+    /// modulo $m$. This is the unique $r$ with $0 \leq r < m$ and $br \equiv a \mod m$. If $b$ and
+    /// $m$ are not coprime, $b$ has no inverse and `None` is returned.
+    ///
+    /// The map is compatible with addition and multiplication: if $x$ and $y$ are both reduced
+    /// successfully, then so are $x + y$ and $xy$, and their images are the sum and product of the
+    /// images of $x$ and $y$ modulo $m$.
+    ///
+    /// $$
+    /// f(a/b, m) = \\begin{cases}
+    ///     \operatorname{Some}(r) & \text{if} \\quad \gcd(b, m) = 1, \\\\
+    ///     \operatorname{None} & \textrm{otherwise},
+    /// \\end{cases}
+    /// $$
+    /// where $0 \leq r < m$ and $br \equiv a \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Mod;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(1, 3)).mod_op(&Natural::from(7u32)),
+    ///     Some(Natural::from(5u32))
+    /// );
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-1, 3)).mod_op(&Natural::from(7u32)),
+    ///     Some(Natural::from(2u32))
+    /// );
+    /// assert_eq!((&Rational::from(10)).mod_op(&Natural::from(7u32)), Some(Natural::from(3u32)));
+    /// assert_eq!((&Rational::from_signeds(1, 3)).mod_op(&Natural::from(6u32)), None);
+    /// ```
+    #[inline]
+    fn mod_op(self, m: &'b Natural) -> Option<Natural> {
+        mod_helper(self.sign, &self.numerator, &self.denominator, m)
+    }
+}
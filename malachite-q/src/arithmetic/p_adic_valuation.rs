@@ -0,0 +1,42 @@
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::Valuation;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::natural::Natural;
+
+impl Rational {
+    /// Returns the $p$-adic valuation of a [`Rational`]; that is, the exponent of $p$ in its
+    /// factorization. The [`Rational`] is taken by reference.
+    ///
+    /// If the [`Rational`] is $a/b$ in lowest terms, the valuation is the multiplicity of $p$ as a
+    /// factor of $a$ minus its multiplicity as a factor of $b$. When $p$ is prime, at most one of
+    /// these multiplicities is nonzero.
+    ///
+    /// $f(x, p) = k$, where $k$ is the unique integer such that $x = p^k a/b$ for integers $a$ and
+    /// $b$ not divisible by $p$, if $p$ is prime.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Rational::from_signeds(-40, 9);
+    /// assert_eq!(x.p_adic_valuation(&Natural::from(2u32)), 3);
+    /// assert_eq!(x.p_adic_valuation(&Natural::from(3u32)), -2);
+    /// assert_eq!(x.p_adic_valuation(&Natural::from(7u32)), 0);
+    /// ```
+    pub fn p_adic_valuation(&self, p: &Natural) -> i64 {
+        assert_ne!(*self, 0u32, "Cannot take the valuation of zero");
+        i64::exact_from((&self.numerator).valuation(p))
+            - i64::exact_from((&self.denominator).valuation(p))
+    }
+}
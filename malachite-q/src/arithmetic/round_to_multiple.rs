@@ -33,6 +33,12 @@ impl RoundToMultiple<Rational> for Rational {
     /// \end{cases}
     /// $$
     ///
+    /// $f(x, y, \mathrm{NearestTiesAway})$ and $f(x, y, \mathrm{NearestTiesTowardZero})$ are
+    /// defined like $f(x, y, \mathrm{Nearest})$, except that ties are rounded away from $0$ and
+    /// towards $0$, respectively. $f(x, y, \mathrm{Odd})$ is $f(x, y, \mathrm{Down})$ if no
+    /// rounding is necessary, and otherwise whichever of $f(x, y, \mathrm{Floor})$ and $f(x, y,
+    /// \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+    ///
     /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \Z$.
     ///
     /// # Worst-case complexity
@@ -120,6 +126,12 @@ impl<'a> RoundToMultiple<&'a Rational> for Rational {
     /// \end{cases}
     /// $$
     ///
+    /// $f(x, y, \mathrm{NearestTiesAway})$ and $f(x, y, \mathrm{NearestTiesTowardZero})$ are
+    /// defined like $f(x, y, \mathrm{Nearest})$, except that ties are rounded away from $0$ and
+    /// towards $0$, respectively. $f(x, y, \mathrm{Odd})$ is $f(x, y, \mathrm{Down})$ if no
+    /// rounding is necessary, and otherwise whichever of $f(x, y, \mathrm{Floor})$ and $f(x, y,
+    /// \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+    ///
     /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \Z$.
     ///
     /// # Worst-case complexity
@@ -205,6 +217,12 @@ impl<'a> RoundToMultiple<Rational> for &'a Rational {
     /// \end{cases}
     /// $$
     ///
+    /// $f(x, y, \mathrm{NearestTiesAway})$ and $f(x, y, \mathrm{NearestTiesTowardZero})$ are
+    /// defined like $f(x, y, \mathrm{Nearest})$, except that ties are rounded away from $0$ and
+    /// towards $0$, respectively. $f(x, y, \mathrm{Odd})$ is $f(x, y, \mathrm{Down})$ if no
+    /// rounding is necessary, and otherwise whichever of $f(x, y, \mathrm{Floor})$ and $f(x, y,
+    /// \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+    ///
     /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \Z$.
     ///
     /// # Worst-case complexity
@@ -263,6 +281,8 @@ impl<'a> RoundToMultiple<Rational> for &'a Rational {
         if other == 0u32 {
             if rm == RoundingMode::Down
                 || rm == RoundingMode::Nearest
+                || rm == RoundingMode::NearestTiesAway
+                || rm == RoundingMode::NearestTiesTowardZero
                 || rm
                     == if *self >= 0u32 {
                         RoundingMode::Floor
@@ -320,6 +340,12 @@ impl<'a, 'b> RoundToMultiple<&'b Rational> for &'a Rational {
     /// \end{cases}
     /// $$
     ///
+    /// $f(x, y, \mathrm{NearestTiesAway})$ and $f(x, y, \mathrm{NearestTiesTowardZero})$ are
+    /// defined like $f(x, y, \mathrm{Nearest})$, except that ties are rounded away from $0$ and
+    /// towards $0$, respectively. $f(x, y, \mathrm{Odd})$ is $f(x, y, \mathrm{Down})$ if no
+    /// rounding is necessary, and otherwise whichever of $f(x, y, \mathrm{Floor})$ and $f(x, y,
+    /// \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+    ///
     /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \Z$.
     ///
     /// # Worst-case complexity
@@ -378,6 +404,8 @@ impl<'a, 'b> RoundToMultiple<&'b Rational> for &'a Rational {
         if *other == 0u32 {
             if rm == RoundingMode::Down
                 || rm == RoundingMode::Nearest
+                || rm == RoundingMode::NearestTiesAway
+                || rm == RoundingMode::NearestTiesTowardZero
                 || rm
                     == if *self >= 0u32 {
                         RoundingMode::Floor
@@ -489,6 +517,8 @@ impl RoundToMultipleAssign<Rational> for Rational {
         if other == 0u32 {
             if rm == RoundingMode::Down
                 || rm == RoundingMode::Nearest
+                || rm == RoundingMode::NearestTiesAway
+                || rm == RoundingMode::NearestTiesTowardZero
                 || rm
                     == if *self >= 0u32 {
                         RoundingMode::Floor
@@ -591,6 +621,8 @@ impl<'a> RoundToMultipleAssign<&'a Rational> for Rational {
         if *other == 0u32 {
             if rm == RoundingMode::Down
                 || rm == RoundingMode::Nearest
+                || rm == RoundingMode::NearestTiesAway
+                || rm == RoundingMode::NearestTiesTowardZero
                 || rm
                     == if *self >= 0u32 {
                         RoundingMode::Floor
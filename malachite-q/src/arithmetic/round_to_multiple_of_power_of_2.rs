@@ -39,6 +39,12 @@ impl RoundToMultipleOfPowerOf2<i64> for Rational {
     /// \end{cases}
     /// $$
     ///
+    /// $f(x, k, \mathrm{NearestTiesAway})$ and $f(x, k, \mathrm{NearestTiesTowardZero})$ are
+    /// defined like $f(x, k, \mathrm{Nearest})$, except that ties are rounded away from $0$ and
+    /// towards $0$, respectively. $f(x, k, \mathrm{Odd})$ is $f(x, k, \mathrm{Down})$ if no
+    /// rounding is necessary, and otherwise whichever of $f(x, k, \mathrm{Floor})$ and $f(x, k,
+    /// \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+    ///
     /// $f(x, k, \mathrm{Exact}) = 2^k q$, but panics if $q \notin \Z$.
     ///
     /// # Worst-case complexity
@@ -125,6 +131,12 @@ impl<'a> RoundToMultipleOfPowerOf2<i64> for &'a Rational {
     /// \end{cases}
     /// $$
     ///
+    /// $f(x, k, \mathrm{NearestTiesAway})$ and $f(x, k, \mathrm{NearestTiesTowardZero})$ are
+    /// defined like $f(x, k, \mathrm{Nearest})$, except that ties are rounded away from $0$ and
+    /// towards $0$, respectively. $f(x, k, \mathrm{Odd})$ is $f(x, k, \mathrm{Down})$ if no
+    /// rounding is necessary, and otherwise whichever of $f(x, k, \mathrm{Floor})$ and $f(x, k,
+    /// \mathrm{Ceiling})$ corresponds to an odd rounded quotient.
+    ///
     /// $f(x, k, \mathrm{Exact}) = 2^k q$, but panics if $q \notin \Z$.
     ///
     /// # Worst-case complexity
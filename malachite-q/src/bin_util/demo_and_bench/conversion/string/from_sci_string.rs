@@ -152,7 +152,9 @@ fn benchmark_rational_from_sci_string(
         limit,
         file_name,
         &string_len_bucketer(),
-        &mut [("Malachite", &mut |s| no_out!(Rational::from_sci_string(&s)))],
+        &mut [("Malachite", &mut |s| {
+            no_out!(Rational::from_sci_string(&s).ok())
+        })],
     );
 }
 
@@ -174,7 +176,7 @@ fn benchmark_rational_from_sci_string_with_options(
             no_out!({
                 let mut options = FromSciStringOptions::default();
                 options.set_base(base);
-                Rational::from_sci_string_with_options(&s, options)
+                Rational::from_sci_string_with_options(&s, options).ok()
             })
         })],
     );
@@ -195,7 +197,7 @@ fn benchmark_rational_from_sci_string_simplest(
         file_name,
         &string_len_bucketer(),
         &mut [("Malachite", &mut |s| {
-            no_out!(Rational::from_sci_string_simplest(&s))
+            no_out!(Rational::from_sci_string_simplest(&s).ok())
         })],
     );
 }
@@ -218,7 +220,7 @@ fn benchmark_rational_from_sci_string_simplest_with_options(
             no_out!({
                 let mut options = FromSciStringOptions::default();
                 options.set_base(base);
-                Rational::from_sci_string_simplest_with_options(&s, options)
+                Rational::from_sci_string_simplest_with_options(&s, options).ok()
             })
         })],
     );
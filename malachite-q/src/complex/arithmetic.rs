@@ -0,0 +1,1159 @@
+use crate::complex::ComplexRational;
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{
+    NegAssign, Pow, Reciprocal, ReciprocalAssign, Square,
+};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::logic::traits::SignificantBits;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+impl Add<ComplexRational> for ComplexRational {
+    type Output = ComplexRational;
+
+    /// Adds two [`ComplexRational`]s, taking both by value.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let x = ComplexRational::from_str("(1 2)").unwrap();
+    /// let y = ComplexRational::from_str("(1/2 -1)").unwrap();
+    /// assert_eq!((x + y).to_string(), "(3/2 1)");
+    /// ```
+    #[inline]
+    fn add(self, other: ComplexRational) -> ComplexRational {
+        &self + &other
+    }
+}
+
+impl<'a> Add<&'a ComplexRational> for ComplexRational {
+    type Output = ComplexRational;
+
+    /// Adds two [`ComplexRational`]s, taking the first by value and the second by reference.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let x = ComplexRational::from_str("(1 2)").unwrap();
+    /// let y = ComplexRational::from_str("(1/2 -1)").unwrap();
+    /// assert_eq!((x + &y).to_string(), "(3/2 1)");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a ComplexRational) -> ComplexRational {
+        &self + other
+    }
+}
+
+impl<'a> Add<ComplexRational> for &'a ComplexRational {
+    type Output = ComplexRational;
+
+    /// Adds two [`ComplexRational`]s, taking the first by reference and the second by value.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let x = ComplexRational::from_str("(1 2)").unwrap();
+    /// let y = ComplexRational::from_str("(1/2 -1)").unwrap();
+    /// assert_eq!((&x + y).to_string(), "(3/2 1)");
+    /// ```
+    #[inline]
+    fn add(self, other: ComplexRational) -> ComplexRational {
+        self + &other
+    }
+}
+
+impl<'a, 'b> Add<&'a ComplexRational> for &'b ComplexRational {
+    type Output = ComplexRational;
+
+    /// Adds two [`ComplexRational`]s, taking both by reference.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let x = ComplexRational::from_str("(1 2)").unwrap();
+    /// let y = ComplexRational::from_str("(1/2 -1)").unwrap();
+    /// assert_eq!((&x + &y).to_string(), "(3/2 1)");
+    /// ```
+    fn add(self, other: &'a ComplexRational) -> ComplexRational {
+        ComplexRational {
+            re: &self.re + &other.re,
+            im: &self.im + &other.im,
+        }
+    }
+}
+
+impl AddAssign<ComplexRational> for ComplexRational {
+    /// Adds a [`ComplexRational`] to a [`ComplexRational`] in place, taking the [`ComplexRational`] on the right-hand side by value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let mut x = ComplexRational::from_str("(1 2)").unwrap();
+    /// x += ComplexRational::from_str("(1/2 -1)").unwrap();
+    /// assert_eq!(x.to_string(), "(3/2 1)");
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: ComplexRational) {
+        *self = &*self + &other;
+    }
+}
+
+impl<'a> AddAssign<&'a ComplexRational> for ComplexRational {
+    /// Adds a [`ComplexRational`] to a [`ComplexRational`] in place, taking the [`ComplexRational`] on the right-hand side by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let mut x = ComplexRational::from_str("(1 2)").unwrap();
+    /// x += &ComplexRational::from_str("(1/2 -1)").unwrap();
+    /// assert_eq!(x.to_string(), "(3/2 1)");
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: &'a ComplexRational) {
+        *self = &*self + other;
+    }
+}
+
+impl Sub<ComplexRational> for ComplexRational {
+    type Output = ComplexRational;
+
+    /// Subtracts a [`ComplexRational`] by another [`ComplexRational`], taking both by value.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let x = ComplexRational::from_str("(1 2)").unwrap();
+    /// let y = ComplexRational::from_str("(1/2 -1)").unwrap();
+    /// assert_eq!((x - y).to_string(), "(1/2 3)");
+    /// ```
+    #[inline]
+    fn sub(self, other: ComplexRational) -> ComplexRational {
+        &self - &other
+    }
+}
+
+impl<'a> Sub<&'a ComplexRational> for ComplexRational {
+    type Output = ComplexRational;
+
+    /// Subtracts a [`ComplexRational`] by another [`ComplexRational`], taking the first by value and the second by reference.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let x = ComplexRational::from_str("(1 2)").unwrap();
+    /// let y = ComplexRational::from_str("(1/2 -1)").unwrap();
+    /// assert_eq!((x - &y).to_string(), "(1/2 3)");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'a ComplexRational) -> ComplexRational {
+        &self - other
+    }
+}
+
+impl<'a> Sub<ComplexRational> for &'a ComplexRational {
+    type Output = ComplexRational;
+
+    /// Subtracts a [`ComplexRational`] by another [`ComplexRational`], taking the first by reference and the second by value.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let x = ComplexRational::from_str("(1 2)").unwrap();
+    /// let y = ComplexRational::from_str("(1/2 -1)").unwrap();
+    /// assert_eq!((&x - y).to_string(), "(1/2 3)");
+    /// ```
+    #[inline]
+    fn sub(self, other: ComplexRational) -> ComplexRational {
+        self - &other
+    }
+}
+
+impl<'a, 'b> Sub<&'a ComplexRational> for &'b ComplexRational {
+    type Output = ComplexRational;
+
+    /// Subtracts a [`ComplexRational`] by another [`ComplexRational`], taking both by reference.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let x = ComplexRational::from_str("(1 2)").unwrap();
+    /// let y = ComplexRational::from_str("(1/2 -1)").unwrap();
+    /// assert_eq!((&x - &y).to_string(), "(1/2 3)");
+    /// ```
+    fn sub(self, other: &'a ComplexRational) -> ComplexRational {
+        ComplexRational {
+            re: &self.re - &other.re,
+            im: &self.im - &other.im,
+        }
+    }
+}
+
+impl SubAssign<ComplexRational> for ComplexRational {
+    /// Subtracts a [`ComplexRational`] by a [`ComplexRational`] in place, taking the [`ComplexRational`] on the right-hand side by value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let mut x = ComplexRational::from_str("(1 2)").unwrap();
+    /// x -= ComplexRational::from_str("(1/2 -1)").unwrap();
+    /// assert_eq!(x.to_string(), "(1/2 3)");
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: ComplexRational) {
+        *self = &*self - &other;
+    }
+}
+
+impl<'a> SubAssign<&'a ComplexRational> for ComplexRational {
+    /// Subtracts a [`ComplexRational`] by a [`ComplexRational`] in place, taking the [`ComplexRational`] on the right-hand side by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let mut x = ComplexRational::from_str("(1 2)").unwrap();
+    /// x -= &ComplexRational::from_str("(1/2 -1)").unwrap();
+    /// assert_eq!(x.to_string(), "(1/2 3)");
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: &'a ComplexRational) {
+        *self = &*self - other;
+    }
+}
+
+impl Mul<ComplexRational> for ComplexRational {
+    type Output = ComplexRational;
+
+    /// Multiplies two [`ComplexRational`]s, taking both by value.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let x = ComplexRational::from_str("(1 2)").unwrap();
+    /// let y = ComplexRational::from_str("(1/2 -1)").unwrap();
+    /// assert_eq!((x * y).to_string(), "(5/2 0)");
+    /// ```
+    #[inline]
+    fn mul(self, other: ComplexRational) -> ComplexRational {
+        &self * &other
+    }
+}
+
+impl<'a> Mul<&'a ComplexRational> for ComplexRational {
+    type Output = ComplexRational;
+
+    /// Multiplies two [`ComplexRational`]s, taking the first by value and the second by reference.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let x = ComplexRational::from_str("(1 2)").unwrap();
+    /// let y = ComplexRational::from_str("(1/2 -1)").unwrap();
+    /// assert_eq!((x * &y).to_string(), "(5/2 0)");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a ComplexRational) -> ComplexRational {
+        &self * other
+    }
+}
+
+impl<'a> Mul<ComplexRational> for &'a ComplexRational {
+    type Output = ComplexRational;
+
+    /// Multiplies two [`ComplexRational`]s, taking the first by reference and the second by value.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let x = ComplexRational::from_str("(1 2)").unwrap();
+    /// let y = ComplexRational::from_str("(1/2 -1)").unwrap();
+    /// assert_eq!((&x * y).to_string(), "(5/2 0)");
+    /// ```
+    #[inline]
+    fn mul(self, other: ComplexRational) -> ComplexRational {
+        self * &other
+    }
+}
+
+impl<'a, 'b> Mul<&'a ComplexRational> for &'b ComplexRational {
+    type Output = ComplexRational;
+
+    /// Multiplies two [`ComplexRational`]s, taking both by reference.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let x = ComplexRational::from_str("(1 2)").unwrap();
+    /// let y = ComplexRational::from_str("(1/2 -1)").unwrap();
+    /// assert_eq!((&x * &y).to_string(), "(5/2 0)");
+    /// ```
+    fn mul(self, other: &'a ComplexRational) -> ComplexRational {
+        ComplexRational {
+            re: &self.re * &other.re - &self.im * &other.im,
+            im: &self.re * &other.im + &self.im * &other.re,
+        }
+    }
+}
+
+impl MulAssign<ComplexRational> for ComplexRational {
+    /// Multiplies a [`ComplexRational`] by a [`ComplexRational`] in place, taking the [`ComplexRational`] on the right-hand side by value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let mut x = ComplexRational::from_str("(1 2)").unwrap();
+    /// x *= ComplexRational::from_str("(1/2 -1)").unwrap();
+    /// assert_eq!(x.to_string(), "(5/2 0)");
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: ComplexRational) {
+        *self = &*self * &other;
+    }
+}
+
+impl<'a> MulAssign<&'a ComplexRational> for ComplexRational {
+    /// Multiplies a [`ComplexRational`] by a [`ComplexRational`] in place, taking the [`ComplexRational`] on the right-hand side by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let mut x = ComplexRational::from_str("(1 2)").unwrap();
+    /// x *= &ComplexRational::from_str("(1/2 -1)").unwrap();
+    /// assert_eq!(x.to_string(), "(5/2 0)");
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: &'a ComplexRational) {
+        *self = &*self * other;
+    }
+}
+
+impl Div<ComplexRational> for ComplexRational {
+    type Output = ComplexRational;
+
+    /// Divides a [`ComplexRational`] by another [`ComplexRational`], taking both by value.
+    ///
+    /// $$
+    /// f(x, y) = \frac{x}{y}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let x = ComplexRational::from_str("(1 2)").unwrap();
+    /// let y = ComplexRational::from_str("(1/2 -1)").unwrap();
+    /// assert_eq!((x / y).to_string(), "(-6/5 8/5)");
+    /// ```
+    #[inline]
+    fn div(self, other: ComplexRational) -> ComplexRational {
+        &self / &other
+    }
+}
+
+impl<'a> Div<&'a ComplexRational> for ComplexRational {
+    type Output = ComplexRational;
+
+    /// Divides a [`ComplexRational`] by another [`ComplexRational`], taking the first by value and the second by reference.
+    ///
+    /// $$
+    /// f(x, y) = \frac{x}{y}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let x = ComplexRational::from_str("(1 2)").unwrap();
+    /// let y = ComplexRational::from_str("(1/2 -1)").unwrap();
+    /// assert_eq!((x / &y).to_string(), "(-6/5 8/5)");
+    /// ```
+    #[inline]
+    fn div(self, other: &'a ComplexRational) -> ComplexRational {
+        &self / other
+    }
+}
+
+impl<'a> Div<ComplexRational> for &'a ComplexRational {
+    type Output = ComplexRational;
+
+    /// Divides a [`ComplexRational`] by another [`ComplexRational`], taking the first by reference and the second by value.
+    ///
+    /// $$
+    /// f(x, y) = \frac{x}{y}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let x = ComplexRational::from_str("(1 2)").unwrap();
+    /// let y = ComplexRational::from_str("(1/2 -1)").unwrap();
+    /// assert_eq!((&x / y).to_string(), "(-6/5 8/5)");
+    /// ```
+    #[inline]
+    fn div(self, other: ComplexRational) -> ComplexRational {
+        self / &other
+    }
+}
+
+impl<'a, 'b> Div<&'a ComplexRational> for &'b ComplexRational {
+    type Output = ComplexRational;
+
+    /// Divides a [`ComplexRational`] by another [`ComplexRational`], taking both by reference.
+    ///
+    /// $$
+    /// f(x, y) = \frac{x}{y}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let x = ComplexRational::from_str("(1 2)").unwrap();
+    /// let y = ComplexRational::from_str("(1/2 -1)").unwrap();
+    /// assert_eq!((&x / &y).to_string(), "(-6/5 8/5)");
+    /// ```
+    fn div(self, other: &'a ComplexRational) -> ComplexRational {
+        let norm = other.norm();
+        assert_ne!(norm, 0u32, "division by zero");
+        ComplexRational {
+            re: (&self.re * &other.re + &self.im * &other.im) / &norm,
+            im: (&self.im * &other.re - &self.re * &other.im) / norm,
+        }
+    }
+}
+
+impl DivAssign<ComplexRational> for ComplexRational {
+    /// Divides a [`ComplexRational`] by a [`ComplexRational`] in place, taking the [`ComplexRational`] on the right-hand side by value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let mut x = ComplexRational::from_str("(1 2)").unwrap();
+    /// x /= ComplexRational::from_str("(1/2 -1)").unwrap();
+    /// assert_eq!(x.to_string(), "(-6/5 8/5)");
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: ComplexRational) {
+        *self = &*self / &other;
+    }
+}
+
+impl<'a> DivAssign<&'a ComplexRational> for ComplexRational {
+    /// Divides a [`ComplexRational`] by a [`ComplexRational`] in place, taking the [`ComplexRational`] on the right-hand side by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let mut x = ComplexRational::from_str("(1 2)").unwrap();
+    /// x /= &ComplexRational::from_str("(1/2 -1)").unwrap();
+    /// assert_eq!(x.to_string(), "(-6/5 8/5)");
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: &'a ComplexRational) {
+        *self = &*self / other;
+    }
+}
+
+impl ComplexRational {
+    /// Returns the complex conjugate of a [`ComplexRational`].
+    ///
+    /// $$
+    /// f(a+bi) = a-bi.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let z = ComplexRational::from_str("(1/2 -3)").unwrap();
+    /// assert_eq!(z.conj().to_string(), "(1/2 3)");
+    /// ```
+    pub fn conj(&self) -> ComplexRational {
+        ComplexRational {
+            re: self.re.clone(),
+            im: -&self.im,
+        }
+    }
+
+    /// Returns the norm of a [`ComplexRational`]; that is, the square of its absolute value.
+    ///
+    /// $$
+    /// f(a+bi) = a^2+b^2.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let z = ComplexRational::from_str("(1/2 -3)").unwrap();
+    /// assert_eq!(z.norm().to_string(), "37/4");
+    /// ```
+    pub fn norm(&self) -> Rational {
+        (&self.re).square() + (&self.im).square()
+    }
+
+    /// Returns the number of significant bits of a [`ComplexRational`]; that is, the sum of the
+    /// significant bits of its real and imaginary parts.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let z = ComplexRational::from_str("(1/2 -3)").unwrap();
+    /// assert_eq!(z.significant_bits(), 6);
+    /// ```
+    pub fn significant_bits(&self) -> u64 {
+        self.re.significant_bits() + self.im.significant_bits()
+    }
+}
+
+impl Neg for ComplexRational {
+    type Output = ComplexRational;
+
+    /// Negates a [`ComplexRational`], taking it by value.
+    ///
+    /// $$
+    /// f(x) = -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!((-ComplexRational::from_str("(1/2 -3)").unwrap()).to_string(), "(-1/2 3)");
+    /// ```
+    #[inline]
+    fn neg(mut self) -> ComplexRational {
+        self.neg_assign();
+        self
+    }
+}
+
+impl<'a> Neg for &'a ComplexRational {
+    type Output = ComplexRational;
+
+    /// Negates a [`ComplexRational`], taking it by reference.
+    ///
+    /// $$
+    /// f(x) = -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!((-&ComplexRational::from_str("(1/2 -3)").unwrap()).to_string(), "(-1/2 3)");
+    /// ```
+    fn neg(self) -> ComplexRational {
+        ComplexRational {
+            re: -&self.re,
+            im: -&self.im,
+        }
+    }
+}
+
+impl NegAssign for ComplexRational {
+    /// Negates a [`ComplexRational`] in place.
+    ///
+    /// $$
+    /// x \gets -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::NegAssign;
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let mut z = ComplexRational::from_str("(1/2 -3)").unwrap();
+    /// z.neg_assign();
+    /// assert_eq!(z.to_string(), "(-1/2 3)");
+    /// ```
+    fn neg_assign(&mut self) {
+        self.re.neg_assign();
+        self.im.neg_assign();
+    }
+}
+
+impl Reciprocal for ComplexRational {
+    type Output = ComplexRational;
+
+    /// Reciprocates a [`ComplexRational`], taking it by value.
+    ///
+    /// $$
+    /// f(x) = 1/x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Reciprocal;
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let z = ComplexRational::from_str("(1 2)").unwrap();
+    /// assert_eq!(z.reciprocal().to_string(), "(1/5 -2/5)");
+    /// ```
+    #[inline]
+    fn reciprocal(self) -> ComplexRational {
+        (&self).reciprocal()
+    }
+}
+
+impl<'a> Reciprocal for &'a ComplexRational {
+    type Output = ComplexRational;
+
+    /// Reciprocates a [`ComplexRational`], taking it by reference.
+    ///
+    /// $$
+    /// f(x) = 1/x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Reciprocal;
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let z = ComplexRational::from_str("(1 2)").unwrap();
+    /// assert_eq!((&z).reciprocal().to_string(), "(1/5 -2/5)");
+    /// ```
+    fn reciprocal(self) -> ComplexRational {
+        let norm = self.norm();
+        assert_ne!(norm, 0u32, "Cannot take reciprocal of zero");
+        ComplexRational {
+            re: &self.re / &norm,
+            im: -&self.im / norm,
+        }
+    }
+}
+
+impl ReciprocalAssign for ComplexRational {
+    /// Reciprocates a [`ComplexRational`] in place.
+    ///
+    /// $$
+    /// x \gets 1/x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ReciprocalAssign;
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let mut z = ComplexRational::from_str("(1 2)").unwrap();
+    /// z.reciprocal_assign();
+    /// assert_eq!(z.to_string(), "(1/5 -2/5)");
+    /// ```
+    #[inline]
+    fn reciprocal_assign(&mut self) {
+        *self = (&*self).reciprocal();
+    }
+}
+
+impl<'a> Pow<u64> for &'a ComplexRational {
+    type Output = ComplexRational;
+
+    /// Raises a [`ComplexRational`] to a power, taking the [`ComplexRational`] by reference.
+    ///
+    /// $f(x, n) = x^n$, using repeated squaring. $0^0$ is 1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(nm \log (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let z = ComplexRational::from_str("(1 1)").unwrap();
+    /// assert_eq!((&z).pow(2u64).to_string(), "(0 2)");
+    /// assert_eq!((&z).pow(8u64).to_string(), "(16 0)");
+    /// ```
+    fn pow(self, mut exp: u64) -> ComplexRational {
+        let mut result = ComplexRational::ONE;
+        let mut power = self.clone();
+        while exp != 0 {
+            if exp & 1 != 0 {
+                result *= &power;
+            }
+            exp >>= 1;
+            if exp != 0 {
+                power = &power * &power;
+            }
+        }
+        result
+    }
+}
+
+impl Pow<u64> for ComplexRational {
+    type Output = ComplexRational;
+
+    /// Raises a [`ComplexRational`] to a power, taking the [`ComplexRational`] by value.
+    ///
+    /// $f(x, n) = x^n$, using repeated squaring. $0^0$ is 1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(nm \log (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let z = ComplexRational::from_str("(1 1)").unwrap();
+    /// assert_eq!(z.pow(3u64).to_string(), "(-2 2)");
+    /// ```
+    #[inline]
+    fn pow(self, exp: u64) -> ComplexRational {
+        (&self).pow(exp)
+    }
+}
+
+impl<'a> Pow<i64> for &'a ComplexRational {
+    type Output = ComplexRational;
+
+    /// Raises a [`ComplexRational`] to an integer power, taking the [`ComplexRational`] by
+    /// reference.
+    ///
+    /// $f(x, n) = x^n$. $0^0$ is 1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(nm \log (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero and `exp` is negative.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let z = ComplexRational::from_str("(1 1)").unwrap();
+    /// assert_eq!((&z).pow(-2i64).to_string(), "(0 -1/2)");
+    /// ```
+    fn pow(self, exp: i64) -> ComplexRational {
+        let p = self.pow(exp.unsigned_abs());
+        if exp >= 0 {
+            p
+        } else {
+            p.reciprocal()
+        }
+    }
+}
+
+impl Pow<i64> for ComplexRational {
+    type Output = ComplexRational;
+
+    /// Raises a [`ComplexRational`] to an integer power, taking the [`ComplexRational`] by value.
+    ///
+    /// $f(x, n) = x^n$. $0^0$ is 1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(nm \log (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero and `exp` is negative.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// let z = ComplexRational::from_str("(1 1)").unwrap();
+    /// assert_eq!(z.pow(-1i64).to_string(), "(1/2 -1/2)");
+    /// ```
+    #[inline]
+    fn pow(self, exp: i64) -> ComplexRational {
+        (&self).pow(exp)
+    }
+}
@@ -0,0 +1,149 @@
+use crate::complex::ComplexRational;
+use crate::Rational;
+use malachite_base::num::basic::traits::Zero;
+use std::fmt::{Debug, Display, Formatter, Result};
+use std::str::FromStr;
+
+impl From<Rational> for ComplexRational {
+    /// Converts a [`Rational`] to a [`ComplexRational`] with a zero imaginary part, taking the
+    /// [`Rational`] by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(ComplexRational::from(Rational::from_signeds(22, 7)).to_string(), "(22/7 0)");
+    /// ```
+    #[inline]
+    fn from(x: Rational) -> ComplexRational {
+        ComplexRational {
+            re: x,
+            im: Rational::ZERO,
+        }
+    }
+}
+
+impl<'a> From<&'a Rational> for ComplexRational {
+    /// Converts a [`Rational`] to a [`ComplexRational`] with a zero imaginary part, taking the
+    /// [`Rational`] by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(ComplexRational::from(&Rational::from_signeds(22, 7)).to_string(), "(22/7 0)");
+    /// ```
+    #[inline]
+    fn from(x: &'a Rational) -> ComplexRational {
+        ComplexRational::from(x.clone())
+    }
+}
+
+impl FromStr for ComplexRational {
+    type Err = ();
+
+    /// Converts a string to a [`ComplexRational`].
+    ///
+    /// The string should have the form `(re im)`, where `re` and `im` are strings that can be
+    /// parsed as [`Rational`]s, separated by a single space. A string that can be parsed as a
+    /// [`Rational`] on its own is also accepted, and is converted to a real [`ComplexRational`].
+    /// This is the format produced by [`ComplexRational`]'s [`Display`] implementation.
+    ///
+    /// If the string does not have this form, an `Err` is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(ComplexRational::from_str("(1/2 -3)").unwrap().to_string(), "(1/2 -3)");
+    /// assert_eq!(ComplexRational::from_str("-22/7").unwrap().to_string(), "(-22/7 0)");
+    /// assert!(ComplexRational::from_str("(1/2,-3)").is_err());
+    /// assert!(ComplexRational::from_str("(1/2 -3").is_err());
+    /// ```
+    fn from_str(s: &str) -> std::result::Result<ComplexRational, ()> {
+        if let Some(s) = s.strip_prefix('(') {
+            let s = s.strip_suffix(')').ok_or(())?;
+            let (re, im) = s.split_once(' ').ok_or(())?;
+            Ok(ComplexRational {
+                re: Rational::from_str(re).map_err(|_| ())?,
+                im: Rational::from_str(im).map_err(|_| ())?,
+            })
+        } else {
+            Ok(ComplexRational::from(
+                Rational::from_str(s).map_err(|_| ())?,
+            ))
+        }
+    }
+}
+
+impl Display for ComplexRational {
+    /// Converts a [`ComplexRational`] to a [`String`], in the form `(re im)`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_q::complex::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(ComplexRational::ZERO.to_string(), "(0 0)");
+    /// assert_eq!(ComplexRational::I.to_string(), "(0 1)");
+    /// assert_eq!(
+    ///     ComplexRational::new(Rational::from_signeds(1, 2), Rational::from(-3)).to_string(),
+    ///     "(1/2 -3)"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "({} {})", self.re, self.im)
+    }
+}
+
+impl Debug for ComplexRational {
+    /// Converts a [`ComplexRational`] to a [`String`], in the form `(re im)`.
+    ///
+    /// This is the same as the [`Display::fmt`] implementation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    ///
+    /// assert_eq!(format!("{:?}", ComplexRational::I), "(0 1)");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
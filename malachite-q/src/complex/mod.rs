@@ -0,0 +1,130 @@
+use crate::Rational;
+use malachite_base::num::basic::traits::{One, Zero};
+
+/// A Gaussian rational: a complex number whose real and imaginary parts are [`Rational`]s.
+///
+/// Arithmetic on `ComplexRational`s is exact. A `ComplexRational` can be rounded to a
+/// `malachite_float::complex::Complex` when an approximation is needed.
+#[derive(Clone, Default, Hash, Eq, PartialEq)]
+pub struct ComplexRational {
+    pub(crate) re: Rational,
+    pub(crate) im: Rational,
+}
+
+impl ComplexRational {
+    /// The imaginary unit $i$.
+    pub const I: ComplexRational = ComplexRational {
+        re: Rational::ZERO,
+        im: Rational::ONE,
+    };
+
+    /// Creates a `ComplexRational` from its real and imaginary parts.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let z = ComplexRational::new(Rational::from_signeds(1, 2), Rational::from(-3));
+    /// assert_eq!(z.to_string(), "(1/2 -3)");
+    /// ```
+    pub const fn new(re: Rational, im: Rational) -> ComplexRational {
+        ComplexRational { re, im }
+    }
+
+    /// Returns a reference to the real part of a `ComplexRational`.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let z = ComplexRational::new(Rational::from_signeds(1, 2), Rational::from(-3));
+    /// assert_eq!(z.re().to_string(), "1/2");
+    /// ```
+    pub const fn re(&self) -> &Rational {
+        &self.re
+    }
+
+    /// Returns a reference to the imaginary part of a `ComplexRational`.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let z = ComplexRational::new(Rational::from_signeds(1, 2), Rational::from(-3));
+    /// assert_eq!(z.im().to_string(), "-3");
+    /// ```
+    pub const fn im(&self) -> &Rational {
+        &self.im
+    }
+
+    /// Takes a `ComplexRational` by value and returns its real and imaginary parts.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let (re, im) =
+    ///     ComplexRational::new(Rational::from_signeds(1, 2), Rational::from(-3)).into_re_im();
+    /// assert_eq!(re.to_string(), "1/2");
+    /// assert_eq!(im.to_string(), "-3");
+    /// ```
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_re_im(self) -> (Rational, Rational) {
+        (self.re, self.im)
+    }
+
+    /// Determines whether a `ComplexRational` is real; that is, whether its imaginary part is
+    /// zero.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_q::complex::ComplexRational;
+    ///
+    /// assert!(ComplexRational::ONE.is_real());
+    /// assert!(!ComplexRational::I.is_real());
+    /// ```
+    pub fn is_real(&self) -> bool {
+        self.im == 0u32
+    }
+}
+
+/// The constant 0.
+impl Zero for ComplexRational {
+    const ZERO: ComplexRational = ComplexRational {
+        re: Rational::ZERO,
+        im: Rational::ZERO,
+    };
+}
+
+/// The constant 1.
+impl One for ComplexRational {
+    const ONE: ComplexRational = ComplexRational {
+        re: Rational::ONE,
+        im: Rational::ZERO,
+    };
+}
+
+/// Arithmetic on [`ComplexRational`]s.
+pub mod arithmetic;
+/// Conversions between [`ComplexRational`]s and other types, including [`String`]s.
+pub mod conversion;
@@ -108,8 +108,9 @@ impl RoundingFrom<Rational> for Natural {
     /// value is less than, equal to, or greater than the original value.
     ///
     /// If the [`Rational`] is negative, then it will be rounded to zero when the
-    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) is `Ceiling`, `Down`, or
-    /// `Nearest`. Otherwise, this function will panic.
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) is `Ceiling`, `Down`,
+    /// `Nearest`, `NearestTiesAway`, or `NearestTiesTowardZero`. Otherwise, this function will
+    /// panic.
     ///
     /// # Worst-case complexity
     /// $T(n) = O(n \log n \log\log n)$
@@ -120,7 +121,8 @@ impl RoundingFrom<Rational> for Natural {
     ///
     /// # Panics
     /// Panics if the [`Rational`] is not an integer and `rm` is `Exact`, or if the [`Rational`] is
-    /// less than zero and `rm` is not `Down`, `Ceiling`, or `Nearest`.
+    /// less than zero and `rm` is not `Down`, `Ceiling`, `Nearest`, `NearestTiesAway`, or
+    /// `NearestTiesTowardZero`.
     ///
     /// # Examples
     /// ```
@@ -180,6 +182,8 @@ impl RoundingFrom<Rational> for Natural {
         } else if rm == RoundingMode::Down
             || rm == RoundingMode::Ceiling
             || rm == RoundingMode::Nearest
+            || rm == RoundingMode::NearestTiesAway
+            || rm == RoundingMode::NearestTiesTowardZero
         {
             (Natural::ZERO, Ordering::Greater)
         } else {
@@ -195,8 +199,9 @@ impl<'a> RoundingFrom<&'a Rational> for Natural {
     /// returned value is less than, equal to, or greater than the original value.
     ///
     /// If the [`Rational`] is negative, then it will be rounded to zero when the
-    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) is `Ceiling`, `Down`, or
-    /// `Nearest`. Otherwise, this function will panic.
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) is `Ceiling`, `Down`,
+    /// `Nearest`, `NearestTiesAway`, or `NearestTiesTowardZero`. Otherwise, this function will
+    /// panic.
     ///
     /// # Worst-case complexity
     /// $T(n) = O(n \log n \log\log n)$
@@ -207,7 +212,8 @@ impl<'a> RoundingFrom<&'a Rational> for Natural {
     ///
     /// # Panics
     /// Panics if the [`Rational`] is not an integer and `rm` is `Exact`, or if the [`Rational`] is
-    /// less than zero and `rm` is not `Down`, `Ceiling`, or `Nearest`.
+    /// less than zero and `rm` is not `Down`, `Ceiling`, `Nearest`, `NearestTiesAway`, or
+    /// `NearestTiesTowardZero`.
     ///
     /// # Examples
     /// ```
@@ -267,6 +273,8 @@ impl<'a> RoundingFrom<&'a Rational> for Natural {
         } else if rm == RoundingMode::Down
             || rm == RoundingMode::Ceiling
             || rm == RoundingMode::Nearest
+            || rm == RoundingMode::NearestTiesAway
+            || rm == RoundingMode::NearestTiesTowardZero
         {
             (Natural::ZERO, Ordering::Greater)
         } else {
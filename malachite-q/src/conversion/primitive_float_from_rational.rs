@@ -51,6 +51,13 @@ macro_rules! float_impls {
             ///   If the [`Rational`] is closer to zero than to any float (or if there is a tie
             ///   between zero and another float), then positive or negative zero is returned,
             ///   depending on the [`Rational`]'s sign.
+            /// - If the rounding mode is `NearestTiesAway` or `NearestTiesTowardZero`, the
+            ///   rounding proceeds as with `Nearest`, except that a [`Rational`] exactly between
+            ///   two floats is rounded away from or towards zero, respectively.
+            /// - If the rounding mode is `Odd`, then the float with the one least-significant bit
+            ///   in its representation is selected, unless the [`Rational`] is exactly
+            ///   representable. If the [`Rational`] is greater than the maximum finite float, then
+            ///   the maximum finite float is returned.
             ///
             /// # Worst-case complexity
             /// $T(n) = O(n \log n \log\log n)$
@@ -78,9 +85,12 @@ macro_rules! float_impls {
                             RoundingMode::Exact => {
                                 panic!("Value cannot be represented exactly as a float")
                             }
-                            RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                                ($f::MAX_FINITE, Ordering::Less)
-                            }
+                            RoundingMode::Floor
+                            | RoundingMode::Down
+                            | RoundingMode::Nearest
+                            | RoundingMode::NearestTiesAway
+                            | RoundingMode::NearestTiesTowardZero
+                            | RoundingMode::Odd => ($f::MAX_FINITE, Ordering::Less),
                             _ => ($f::INFINITY, Ordering::Greater),
                         }
                     } else if exponent >= $f::MIN_NORMAL_EXPONENT {
@@ -105,7 +115,10 @@ macro_rules! float_impls {
                                 }
                                 RoundingMode::Floor
                                 | RoundingMode::Down
-                                | RoundingMode::Nearest => ($f::MAX_FINITE, Ordering::Less),
+                                | RoundingMode::Nearest
+                                | RoundingMode::NearestTiesAway
+                                | RoundingMode::NearestTiesTowardZero
+                                | RoundingMode::Odd => ($f::MAX_FINITE, Ordering::Less),
                                 _ => ($f::INFINITY, Ordering::Greater),
                             }
                         } else {
@@ -140,7 +153,7 @@ macro_rules! float_impls {
                                 panic!("Value cannot be represented exactly as a float")
                             }
                             RoundingMode::Floor | RoundingMode::Down => (0.0, Ordering::Less),
-                            RoundingMode::Nearest => {
+                            RoundingMode::Nearest | RoundingMode::NearestTiesTowardZero => {
                                 if exponent == $f::MIN_EXPONENT - 1
                                     && !abs_is_neg_power_of_2(&value)
                                 {
@@ -149,6 +162,13 @@ macro_rules! float_impls {
                                     (0.0, Ordering::Less)
                                 }
                             }
+                            RoundingMode::NearestTiesAway => {
+                                if exponent == $f::MIN_EXPONENT - 1 {
+                                    ($f::MIN_POSITIVE_SUBNORMAL, Ordering::Greater)
+                                } else {
+                                    (0.0, Ordering::Less)
+                                }
+                            }
                             _ => ($f::MIN_POSITIVE_SUBNORMAL, Ordering::Greater),
                         }
                     };
@@ -263,6 +283,13 @@ macro_rules! float_impls {
             ///   If the [`Rational`] is closer to zero than to any float (or if there is a tie
             ///   between zero and another float), then positive or negative zero is returned,
             ///   depending on the [`Rational`]'s sign.
+            /// - If the rounding mode is `NearestTiesAway` or `NearestTiesTowardZero`, the
+            ///   rounding proceeds as with `Nearest`, except that a [`Rational`] exactly between
+            ///   two floats is rounded away from or towards zero, respectively.
+            /// - If the rounding mode is `Odd`, then the float with the one least-significant bit
+            ///   in its representation is selected, unless the [`Rational`] is exactly
+            ///   representable. If the [`Rational`] is greater than the maximum finite float, then
+            ///   the maximum finite float is returned.
             ///
             /// # Worst-case complexity
             /// $T(n) = O(n \log n \log\log n)$
@@ -289,9 +316,12 @@ macro_rules! float_impls {
                             RoundingMode::Exact => {
                                 panic!("Value cannot be represented exactly as a float")
                             }
-                            RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest => {
-                                ($f::MAX_FINITE, Ordering::Less)
-                            }
+                            RoundingMode::Floor
+                            | RoundingMode::Down
+                            | RoundingMode::Nearest
+                            | RoundingMode::NearestTiesAway
+                            | RoundingMode::NearestTiesTowardZero
+                            | RoundingMode::Odd => ($f::MAX_FINITE, Ordering::Less),
                             _ => ($f::INFINITY, Ordering::Greater),
                         }
                     } else if exponent >= $f::MIN_NORMAL_EXPONENT {
@@ -316,7 +346,10 @@ macro_rules! float_impls {
                                 }
                                 RoundingMode::Floor
                                 | RoundingMode::Down
-                                | RoundingMode::Nearest => ($f::MAX_FINITE, Ordering::Less),
+                                | RoundingMode::Nearest
+                                | RoundingMode::NearestTiesAway
+                                | RoundingMode::NearestTiesTowardZero
+                                | RoundingMode::Odd => ($f::MAX_FINITE, Ordering::Less),
                                 _ => ($f::INFINITY, Ordering::Greater),
                             }
                         } else {
@@ -351,7 +384,7 @@ macro_rules! float_impls {
                                 panic!("Value cannot be represented exactly as a float")
                             }
                             RoundingMode::Floor | RoundingMode::Down => (0.0, Ordering::Less),
-                            RoundingMode::Nearest => {
+                            RoundingMode::Nearest | RoundingMode::NearestTiesTowardZero => {
                                 if exponent == $f::MIN_EXPONENT - 1
                                     && !abs_is_neg_power_of_2(&value)
                                 {
@@ -360,6 +393,13 @@ macro_rules! float_impls {
                                     (0.0, Ordering::Less)
                                 }
                             }
+                            RoundingMode::NearestTiesAway => {
+                                if exponent == $f::MIN_EXPONENT - 1 {
+                                    ($f::MIN_POSITIVE_SUBNORMAL, Ordering::Greater)
+                                } else {
+                                    (0.0, Ordering::Less)
+                                }
+                            }
                             _ => ($f::MIN_POSITIVE_SUBNORMAL, Ordering::Greater),
                         }
                     };
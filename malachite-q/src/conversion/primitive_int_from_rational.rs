@@ -41,6 +41,8 @@ fn rounding_from_unsigned<'a, T: for<'b> TryFrom<&'b Natural> + Max + Named + Ze
         } else if rm == RoundingMode::Down
             || rm == RoundingMode::Floor
             || rm == RoundingMode::Nearest
+            || rm == RoundingMode::NearestTiesAway
+            || rm == RoundingMode::NearestTiesTowardZero
         {
             (T::MAX, Ordering::Less)
         } else {
@@ -51,7 +53,11 @@ fn rounding_from_unsigned<'a, T: for<'b> TryFrom<&'b Natural> + Max + Named + Ze
             );
         };
         out
-    } else if rm == RoundingMode::Down || rm == RoundingMode::Ceiling || rm == RoundingMode::Nearest
+    } else if rm == RoundingMode::Down
+        || rm == RoundingMode::Ceiling
+        || rm == RoundingMode::Nearest
+        || rm == RoundingMode::NearestTiesAway
+        || rm == RoundingMode::NearestTiesTowardZero
     {
         (T::ZERO, Ordering::Greater)
     } else {
@@ -137,7 +143,12 @@ where
 {
     let (i, o) = Integer::rounding_from(x, rm);
     if i > T::MAX {
-        if rm == RoundingMode::Down || rm == RoundingMode::Floor || rm == RoundingMode::Nearest {
+        if rm == RoundingMode::Down
+            || rm == RoundingMode::Floor
+            || rm == RoundingMode::Nearest
+            || rm == RoundingMode::NearestTiesAway
+            || rm == RoundingMode::NearestTiesTowardZero
+        {
             (T::MAX, Ordering::Less)
         } else {
             panic!(
@@ -147,7 +158,12 @@ where
             );
         }
     } else if i < T::MIN {
-        if rm == RoundingMode::Down || rm == RoundingMode::Ceiling || rm == RoundingMode::Nearest {
+        if rm == RoundingMode::Down
+            || rm == RoundingMode::Ceiling
+            || rm == RoundingMode::Nearest
+            || rm == RoundingMode::NearestTiesAway
+            || rm == RoundingMode::NearestTiesTowardZero
+        {
             (T::MIN, Ordering::Greater)
         } else {
             panic!(
@@ -200,11 +216,12 @@ macro_rules! impl_from_unsigned {
             /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode).
             ///
             /// If the [`Rational`] is negative, then it will be rounded to zero when `rm` is
-            /// `Ceiling`, `Down`, or `Nearest`. Otherwise, this function will panic.
+            /// `Ceiling`, `Down`, `Nearest`, `NearestTiesAway`, or `NearestTiesTowardZero`.
+            /// Otherwise, this function will panic.
             ///
             /// If the [`Rational`] is larger than the maximum value of the unsigned type, then it
-            /// will be rounded to the maximum value when `rm` is `Floor`, `Down`, or `Nearest`.
-            /// Otherwise, this function will panic.
+            /// will be rounded to the maximum value when `rm` is `Floor`, `Down`, `Nearest`,
+            /// `NearestTiesAway`, or `NearestTiesTowardZero`. Otherwise, this function will panic.
             ///
             /// # Worst-case complexity
             /// $T(n) = O(n \log n \log\log n)$
@@ -215,9 +232,10 @@ macro_rules! impl_from_unsigned {
             ///
             /// # Panics
             /// Panics if the [`Rational`] is not an integer and `rm` is `Exact`, if the
-            /// [`Rational`] is less than zero and `rm` is not `Down`, `Ceiling`, or
-            /// `Nearest`, or if the [`Rational`] is greater than `T::MAX` and `rm` is not
-            /// `Down`, `Floor`, or `Nearest`.
+            /// [`Rational`] is less than zero and `rm` is not `Down`, `Ceiling`, `Nearest`,
+            /// `NearestTiesAway`, or `NearestTiesTowardZero`, or if the [`Rational`] is greater
+            /// than `T::MAX` and `rm` is not `Down`, `Floor`, `Nearest`, `NearestTiesAway`, or
+            /// `NearestTiesTowardZero`.
             ///
             /// # Examples
             /// See [here](super::primitive_int_from_rational#rounding_from).
@@ -269,12 +287,12 @@ macro_rules! impl_from_signed {
             /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode).
             ///
             /// If the [`Rational`] is smaller than the minimum value of the unsigned type, then it
-            /// will be rounded to the minimum value when `rm` is `Ceiling`, `Down`, or `Nearest`.
-            /// Otherwise, this function will panic.
+            /// will be rounded to the minimum value when `rm` is `Ceiling`, `Down`, `Nearest`,
+            /// `NearestTiesAway`, or `NearestTiesTowardZero`. Otherwise, this function will panic.
             ///
             /// If the [`Rational`] is larger than the maximum value of the unsigned type, then it
-            /// will be rounded to the maximum value when `rm` is `Floor`, `Down`, or `Nearest`.
-            /// Otherwise, this function will panic.
+            /// will be rounded to the maximum value when `rm` is `Floor`, `Down`, `Nearest`,
+            /// `NearestTiesAway`, or `NearestTiesTowardZero`. Otherwise, this function will panic.
             ///
             /// # Worst-case complexity
             /// $T(n) = O(n \log n \log\log n)$
@@ -285,9 +303,10 @@ macro_rules! impl_from_signed {
             ///
             /// # Panics
             /// Panics if the [`Rational`] is not an integer and `rm` is `Exact`, if the
-            /// [`Rational`] is less than `T::MIN` and `rm` is not `Down`, `Ceiling`, or `Nearest`,
-            /// or if the [`Rational`] is greater than `T::MAX` and `rm` is not `Down`, `Floor`, or
-            /// `Nearest`.
+            /// [`Rational`] is less than `T::MIN` and `rm` is not `Down`, `Ceiling`, `Nearest`,
+            /// `NearestTiesAway`, or `NearestTiesTowardZero`, or if the [`Rational`] is greater
+            /// than `T::MAX` and `rm` is not `Down`, `Floor`, `Nearest`, `NearestTiesAway`, or
+            /// `NearestTiesTowardZero`.
             ///
             /// # Examples
             /// See [here](super::primitive_int_from_rational#rounding_from).
@@ -0,0 +1,72 @@
+use crate::Rational;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::string::parse_error::{
+    ParseNumberError, ParseNumberErrorKind,
+};
+use malachite_nz::natural::conversion::string::from_literal::natural_from_unsigned_literal;
+use malachite_nz::natural::Natural;
+
+impl Rational {
+    /// Converts a string in the style of a Rust or C integer literal, or a quotient of two such
+    /// literals, to a [`Rational`].
+    ///
+    /// The string may begin with a `'+'` or `'-'`, followed by a numerator and, optionally, a
+    /// `'/'` and a denominator. The numerator and denominator are each parsed like
+    /// [`Natural::from_literal`], without the sign: they may have their own radix prefixes
+    /// (`"0x"`, `"0o"`, or `"0b"`), and their digits may be separated by underscores. The
+    /// numerator and denominator do not need to be in lowest terms, but the denominator must be
+    /// nonzero.
+    ///
+    /// If the string is invalid, a [`ParseNumberError`] describing the problem is returned. The
+    /// byte offset of an invalid digit refers to the original string.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::from_literal("1_000").unwrap(), 1000);
+    /// assert_eq!(Rational::from_literal("-0x10/0b110").unwrap().to_string(), "-8/3");
+    /// assert_eq!(Rational::from_literal("+22/7").unwrap().to_string(), "22/7");
+    ///
+    /// let kind = |s| Rational::from_literal(s).unwrap_err().kind();
+    /// assert_eq!(kind("1/0x0"), ParseNumberErrorKind::ZeroDenominator);
+    /// assert_eq!(kind("1/"), ParseNumberErrorKind::Empty);
+    /// assert_eq!(kind("1/-2"), ParseNumberErrorKind::InvalidDigit(2));
+    /// assert_eq!(kind("-0x1g/2"), ParseNumberErrorKind::InvalidDigit(4));
+    /// ```
+    pub fn from_literal(s: &str) -> Result<Rational, ParseNumberError> {
+        let (abs_string, sign, offset) = match s.as_bytes().first() {
+            Some(b'+') => (&s[1..], true, 1),
+            Some(b'-') => (&s[1..], false, 1),
+            _ => (s, true, 0),
+        };
+        let numerator;
+        let denominator;
+        if let Some(slash_index) = abs_string.find('/') {
+            numerator = natural_from_unsigned_literal(&abs_string[..slash_index])
+                .map_err(|e| e.offset_by(offset))?;
+            denominator = natural_from_unsigned_literal(&abs_string[slash_index + 1..])
+                .map_err(|e| e.offset_by(offset + slash_index + 1))?;
+            if denominator == 0u32 {
+                return Err(ParseNumberError::new(ParseNumberErrorKind::ZeroDenominator));
+            }
+        } else {
+            numerator =
+                natural_from_unsigned_literal(abs_string).map_err(|e| e.offset_by(offset))?;
+            denominator = Natural::ONE;
+        }
+        Ok(Rational::from_sign_and_naturals(
+            sign,
+            numerator,
+            denominator,
+        ))
+    }
+}
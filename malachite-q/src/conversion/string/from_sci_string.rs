@@ -3,10 +3,41 @@ use crate::Rational;
 use malachite_base::num::arithmetic::traits::Pow;
 use malachite_base::num::conversion::string::from_sci_string::preprocess_sci_string;
 use malachite_base::num::conversion::string::options::FromSciStringOptions;
+use malachite_base::num::conversion::string::parse_error::ParseNumberError;
 use malachite_base::num::conversion::traits::FromSciString;
 use malachite_nz::integer::Integer;
 use malachite_nz::natural::conversion::string::from_sci_string::FromSciStringHelper;
 
+fn from_sci_string_with_options_helper(
+    s: &str,
+    options: FromSciStringOptions,
+) -> Result<Rational, ParseNumberError> {
+    let (s, exponent) = preprocess_sci_string(s, options)?;
+    // The digits are valid, so parsing can't fail
+    let x = Rational::from(Integer::parse_int(&s, options.get_base()).unwrap());
+    Ok(x * Rational::from(options.get_base()).pow(exponent))
+}
+
+fn from_sci_string_simplest_with_options_helper(
+    s: &str,
+    options: FromSciStringOptions,
+) -> Result<Rational, ParseNumberError> {
+    let (s, exponent) = preprocess_sci_string(s, options)?;
+    // The digits are valid, so parsing can't fail
+    let x = Rational::from(Integer::parse_int(&s, options.get_base()).unwrap());
+    let p = Rational::from(options.get_base()).pow(exponent);
+    let q = x * &p;
+    if exponent >= 0 {
+        Ok(q)
+    } else {
+        let epsilon = p >> 1;
+        Ok(Rational::simplest_rational_in_closed_interval(
+            &(&q - &epsilon),
+            &(q + epsilon),
+        ))
+    }
+}
+
 impl FromSciString for Rational {
     /// Converts a string, possibly in scientfic notation, to a [`Rational`].
     ///
@@ -27,7 +58,7 @@ impl FromSciString for Rational {
     ///
     /// Decimal (or other-base) points are allowed.
     ///
-    /// If the string is unparseable, `None` is returned.
+    /// If the string is unparseable, a [`ParseNumberError`] describing the problem is returned.
     ///
     /// This function is very literal; given `"0.333"`, it will return $333/1000$ rather than
     /// $1/3$. If you'd prefer that it return $1/3$, consider using
@@ -45,6 +76,7 @@ impl FromSciString for Rational {
     /// # Examples
     /// ```
     /// use malachite_base::num::conversion::string::options::FromSciStringOptions;
+    /// use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
     /// use malachite_base::num::conversion::traits::FromSciString;
     /// use malachite_base::rounding_modes::RoundingMode;
     /// use malachite_q::Rational;
@@ -55,6 +87,10 @@ impl FromSciString for Rational {
     /// assert_eq!(Rational::from_sci_string("0.333").unwrap().to_string(), "333/1000");
     /// assert_eq!(Rational::from_sci_string("1.2e5").unwrap(), 120000);
     /// assert_eq!(Rational::from_sci_string("1.2e-5").unwrap().to_string(), "3/250000");
+    /// assert_eq!(
+    ///     Rational::from_sci_string("1.2e-5x").unwrap_err().kind(),
+    ///     ParseNumberErrorKind::InvalidDigit(6)
+    /// );
     ///
     /// let mut options = FromSciStringOptions::default();
     /// options.set_base(16);
@@ -65,10 +101,11 @@ impl FromSciString for Rational {
     ///     "255/1048576"
     /// );
     /// ```
-    fn from_sci_string_with_options(s: &str, options: FromSciStringOptions) -> Option<Rational> {
-        let (s, exponent) = preprocess_sci_string(s, options)?;
-        let x = Rational::from(Integer::parse_int(&s, options.get_base())?);
-        Some(x * Rational::from(options.get_base()).pow(exponent))
+    fn from_sci_string_with_options(
+        s: &str,
+        options: FromSciStringOptions,
+    ) -> Result<Rational, ParseNumberError> {
+        from_sci_string_with_options_helper(s, options)
     }
 }
 
@@ -94,7 +131,7 @@ impl Rational {
     ///
     /// Decimal (or other-base) points are allowed.
     ///
-    /// If the string is unparseable, `None` is returned.
+    /// If the string is unparseable, a [`ParseNumberError`] describing the problem is returned.
     ///
     /// Here's a more precise description of the function's behavior. Suppose we are using base
     /// $b$, and the literal value of the string (as parsed by
@@ -150,20 +187,8 @@ impl Rational {
     pub fn from_sci_string_simplest_with_options(
         s: &str,
         options: FromSciStringOptions,
-    ) -> Option<Rational> {
-        let (s, exponent) = preprocess_sci_string(s, options)?;
-        let x = Rational::from(Integer::parse_int(&s, options.get_base())?);
-        let p = Rational::from(options.get_base()).pow(exponent);
-        let q = x * &p;
-        if exponent >= 0 {
-            Some(q)
-        } else {
-            let epsilon = p >> 1;
-            Some(Rational::simplest_rational_in_closed_interval(
-                &(&q - &epsilon),
-                &(q + epsilon),
-            ))
-        }
+    ) -> Result<Rational, ParseNumberError> {
+        from_sci_string_simplest_with_options_helper(s, options)
     }
 
     /// Converts a string, possibly in scientfic notation, to a [`Rational`]. This function finds
@@ -180,7 +205,7 @@ impl Rational {
     ///
     /// Decimal points are allowed.
     ///
-    /// If the string is unparseable, `None` is returned.
+    /// If the string is unparseable, a [`ParseNumberError`] describing the problem is returned.
     ///
     /// Here's a more precise description of the function's behavior. Suppose that the literal
     /// value of the string (as parsed by [`from_sci_string`](Rational::from_sci_string)) is $q$,
@@ -213,6 +238,7 @@ impl Rational {
     ///
     /// # Examples
     /// ```
+    /// use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
     /// use malachite_q::Rational;
     ///
     /// assert_eq!(Rational::from_sci_string_simplest("123").unwrap(), 123);
@@ -221,9 +247,13 @@ impl Rational {
     /// assert_eq!(Rational::from_sci_string_simplest("0.333").unwrap().to_string(), "1/3");
     /// assert_eq!(Rational::from_sci_string_simplest("1.2e5").unwrap(), 120000);
     /// assert_eq!(Rational::from_sci_string_simplest("1.2e-5").unwrap().to_string(), "1/80000");
+    /// assert_eq!(
+    ///     Rational::from_sci_string_simplest("").unwrap_err().kind(),
+    ///     ParseNumberErrorKind::Empty
+    /// );
     /// ```
     #[inline]
-    pub fn from_sci_string_simplest(s: &str) -> Option<Rational> {
+    pub fn from_sci_string_simplest(s: &str) -> Result<Rational, ParseNumberError> {
         Rational::from_sci_string_simplest_with_options(s, FromSciStringOptions::default())
     }
 }
@@ -1,16 +1,21 @@
 use crate::Rational;
 use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::string::parse_error::{
+    ParseNumberError, ParseNumberErrorKind,
+};
 use malachite_nz::natural::Natural;
 use std::str::FromStr;
 
 impl FromStr for Rational {
-    type Err = ();
+    type Err = ParseNumberError;
 
     /// Converts an string to a [`Rational`].
     ///
     /// If the string does not represent a valid [`Rational`], an `Err` is returned. The numerator
     /// and denominator do not need to be in lowest terms, but the denominator must be nonzero. A
-    /// negative sign is only allowed at the 0th position of the string.
+    /// negative sign is only allowed at the 0th position of the string. The [`ParseNumberError`]
+    /// describes why the string is invalid; the byte offsets of invalid digits are relative to the
+    /// start of the full string.
     ///
     /// # Worst-case complexity
     /// $T(n) = O(n (\log n)^2 \log\log n)$
@@ -21,6 +26,7 @@ impl FromStr for Rational {
     ///
     /// # Examples
     /// ```
+    /// use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
     /// use malachite_q::Rational;
     /// use std::str::FromStr;
     ///
@@ -44,24 +50,32 @@ impl FromStr for Rational {
     /// assert!(Rational::from_str("1/").is_err());
     /// assert!(Rational::from_str("--1").is_err());
     /// assert!(Rational::from_str("1/-2").is_err());
+    ///
+    /// let kind = |s| Rational::from_str(s).unwrap_err().kind();
+    /// assert_eq!(kind("1/0"), ParseNumberErrorKind::ZeroDenominator);
+    /// assert_eq!(kind("1/"), ParseNumberErrorKind::Empty);
+    /// assert_eq!(kind("-22/7a"), ParseNumberErrorKind::InvalidDigit(5));
+    /// assert_eq!(kind("1/-2"), ParseNumberErrorKind::SignNotAllowed);
     /// ```
     #[inline]
-    fn from_str(s: &str) -> Result<Rational, ()> {
-        let (abs_string, sign) = if let Some(abs_string) = s.strip_prefix('-') {
-            (abs_string, false)
+    fn from_str(s: &str) -> Result<Rational, ParseNumberError> {
+        let (abs_string, sign, offset) = if let Some(abs_string) = s.strip_prefix('-') {
+            (abs_string, false, 1)
         } else {
-            (s, true)
+            (s, true, 0)
         };
         let numerator;
         let denominator;
         if let Some(slash_index) = abs_string.find('/') {
-            numerator = Natural::from_str(&abs_string[..slash_index])?;
-            denominator = Natural::from_str(&abs_string[slash_index + 1..])?;
+            numerator =
+                Natural::from_str(&abs_string[..slash_index]).map_err(|e| e.offset_by(offset))?;
+            denominator = Natural::from_str(&abs_string[slash_index + 1..])
+                .map_err(|e| e.offset_by(offset + slash_index + 1))?;
             if denominator == 0u32 {
-                return Err(());
+                return Err(ParseNumberError::new(ParseNumberErrorKind::ZeroDenominator));
             }
         } else {
-            numerator = Natural::from_str(abs_string)?;
+            numerator = Natural::from_str(abs_string).map_err(|e| e.offset_by(offset))?;
             denominator = Natural::ONE;
         }
         Ok(Rational::from_sign_and_naturals(
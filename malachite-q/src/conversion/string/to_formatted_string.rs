@@ -0,0 +1,110 @@
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::conversion::string::options::FormatOptions;
+use malachite_base::num::conversion::string::to_formatted_string::format_digits;
+use malachite_base::num::conversion::traits::{
+    ExactFrom, RoundingFrom, ToFormattedString, ToStringBase,
+};
+use malachite_nz::integer::Integer;
+
+impl ToFormattedString for Rational {
+    /// Converts a [`Rational`] to a [`String`], using the specified [`FormatOptions`].
+    ///
+    /// The digits before the point are grouped and separated according to the options, and a
+    /// negative number is preceded by a `'-'`.
+    ///
+    /// If the options specify a number of fraction digits, the [`Rational`] is rounded to that
+    /// many digits after the point, using the options' rounding mode, and trailing zeros are
+    /// kept. If the rounded value is zero, no sign is written. Otherwise, the [`Rational`] is
+    /// written exactly, with as many digits after the point as its expansion in the base requires;
+    /// if the expansion doesn't terminate, it is rounded to the options' non-terminating fraction
+    /// digits instead, which by default are [`FormatOptions::NON_TERMINATING_FRACTION_DIGITS`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`, plus
+    /// the number of fraction digits in `options`.
+    ///
+    /// # Panics
+    /// Panics if the rounding mode is `Exact` and the [`Rational`] cannot be represented exactly
+    /// with the number of fraction digits that are shown, or if the fraction digits are
+    /// unspecified, the expansion of the [`Rational`] doesn't terminate, and the non-terminating
+    /// fraction digits are unspecified too.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::options::{DigitGrouping, FormatOptions};
+    /// use malachite_base::num::conversion::traits::ToFormattedString;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Rational::from_signeds(-123456789, 1000);
+    /// let mut options = FormatOptions::default();
+    /// assert_eq!(x.to_formatted_string(options), "-123456.789");
+    /// assert_eq!(
+    ///     Rational::from_signeds(2, 3).to_formatted_string(options),
+    ///     "0.66666666666666666667"
+    /// );
+    ///
+    /// options.set_digit_grouping(DigitGrouping::Uniform(3));
+    /// assert_eq!(x.to_formatted_string(options), "-123,456.789");
+    ///
+    /// options.set_fraction_digits(2);
+    /// assert_eq!(x.to_formatted_string(options), "-123,456.79");
+    ///
+    /// options.set_rounding_mode(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_formatted_string(options), "-123,456.78");
+    ///
+    /// options.set_fraction_digits(5);
+    /// assert_eq!(x.to_formatted_string(options), "-123,456.78900");
+    ///
+    /// let mut options = FormatOptions::default();
+    /// options.set_digit_grouping(DigitGrouping::Indian);
+    /// options.set_fraction_digits(4);
+    /// assert_eq!(
+    ///     Rational::from_signeds(1000000000, 3).to_formatted_string(options),
+    ///     "33,33,33,333.3333"
+    /// );
+    ///
+    /// options.set_group_separator('.');
+    /// options.set_decimal_separator(',');
+    /// options.set_digit_grouping(DigitGrouping::Uniform(3));
+    /// options.set_fraction_digits(0);
+    /// assert_eq!(
+    ///     Rational::from_signeds(2000000001, 2).to_formatted_string(options),
+    ///     "1.000.000.000"
+    /// );
+    /// assert_eq!(Rational::from_signeds(-1, 3).to_formatted_string(options), "0");
+    ///
+    /// let mut options = FormatOptions::default();
+    /// options.set_fraction_digits_exact_or(5);
+    /// assert_eq!(Rational::from_signeds(2, 3).to_formatted_string(options), "0.66667");
+    /// assert_eq!(Rational::from_signeds(1, 8).to_formatted_string(options), "0.125");
+    /// ```
+    fn to_formatted_string(&self, options: FormatOptions) -> String {
+        let base = options.get_base();
+        let fraction_digits = options.get_fraction_digits().unwrap_or_else(|| {
+            self.length_after_point_in_small_base(base)
+                .unwrap_or_else(|| {
+                    options
+                        .get_non_terminating_fraction_digits()
+                        .expect("Cannot format a non-terminating expansion exactly")
+                })
+        });
+        let scaled = Integer::rounding_from(
+            self * Rational::from(base).pow(fraction_digits),
+            options.get_rounding_mode(),
+        )
+        .0;
+        let mut digits = scaled.unsigned_abs_ref().to_string_base(base);
+        let point_index = usize::exact_from(fraction_digits);
+        if digits.len() <= point_index {
+            digits.insert_str(0, &"0".repeat(point_index + 1 - digits.len()));
+        }
+        let (int_digits, frac_digits) = digits.split_at(digits.len() - point_index);
+        format_digits(scaled < 0, int_digits, frac_digits, options)
+    }
+}
@@ -4,7 +4,7 @@ use malachite_base::num::arithmetic::traits::{
     Abs, CheckedLogBase2, DivExact, DivExactAssign, DivRound, DivisibleBy, Pow, Sign,
 };
 use malachite_base::num::conversion::string::options::{SciSizeOptions, ToSciOptions};
-use malachite_base::num::conversion::string::to_sci::write_exponent;
+use malachite_base::num::conversion::string::to_sci::{exp_options, pad_exp, write_exponent};
 use malachite_base::num::conversion::traits::{
     ExactFrom, IsInteger, RoundingFrom, ToSci, ToStringBase, WrappingFrom,
 };
@@ -12,7 +12,7 @@ use malachite_base::rounding_modes::RoundingMode;
 use malachite_nz::integer::Integer;
 use malachite_nz::natural::Natural;
 use std::cmp::{max, Ordering};
-use std::fmt::{Formatter, Write};
+use std::fmt::{Formatter, LowerExp, UpperExp, Write};
 
 const BASE_PRIME_FACTORS: [[(u8, u8); 3]; 37] = [
     [(0, 0), (0, 0), (0, 0)],
@@ -454,3 +454,75 @@ impl ToSci for Rational {
         }
     }
 }
+
+impl LowerExp for Rational {
+    /// Formats a [`Rational`] in scientific notation, using a lowercase `'e'`.
+    ///
+    /// If a precision is specified, the significand is rounded to that many digits after the
+    /// decimal point, using [`Nearest`](RoundingMode::Nearest) rounding. Otherwise, a number with
+    /// a terminating decimal expansion is written exactly, and any other number is rounded to 16
+    /// significant digits; in both cases, trailing zeros are removed from the significand. The
+    /// width, fill, alignment, sign, and zero-padding flags work as they do for primitive
+    /// floats.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), p)`, where `p` is the precision, if one is specified, or
+    /// `self.denominator` if the number is written exactly.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(format!("{:e}", Rational::from(1000)), "1e3");
+    /// assert_eq!(format!("{:e}", Rational::from_signeds(-1, 1024)), "-9.765625e-4");
+    /// assert_eq!(format!("{:e}", Rational::from_signeds(1, 3)), "3.333333333333333e-1");
+    /// assert_eq!(format!("{:.3e}", Rational::from_signeds(22, 7)), "3.143e0");
+    /// assert_eq!(format!("{:.2e}", Rational::from_signeds(1, 8)), "1.25e-1");
+    /// assert_eq!(format!("{:.3e}", Rational::from_signeds(1, 8)), "1.250e-1");
+    /// assert_eq!(format!("{:>+12.2e}", Rational::from_signeds(22, 7)), "     +3.14e0");
+    /// assert_eq!(format!("{:012.2e}", Rational::from_signeds(-22, 7)), "-000003.14e0");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let exact = f.precision().is_none() && self.length_after_point_in_small_base(10).is_some();
+        let s = self.to_sci_with_options(exp_options(f, exact)).to_string();
+        pad_exp(f, self.sign, s.strip_prefix('-').unwrap_or(&s), true)
+    }
+}
+
+impl UpperExp for Rational {
+    /// Formats a [`Rational`] in scientific notation, using an uppercase `'E'`.
+    ///
+    /// If a precision is specified, the significand is rounded to that many digits after the
+    /// decimal point, using [`Nearest`](RoundingMode::Nearest) rounding. Otherwise, a number with
+    /// a terminating decimal expansion is written exactly, and any other number is rounded to 16
+    /// significant digits; in both cases, trailing zeros are removed from the significand. The
+    /// width, fill, alignment, sign, and zero-padding flags work as they do for primitive
+    /// floats.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), p)`, where `p` is the precision, if one is specified, or
+    /// `self.denominator` if the number is written exactly.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(format!("{:E}", Rational::from_signeds(-1, 1024)), "-9.765625E-4");
+    /// assert_eq!(format!("{:.3E}", Rational::from_signeds(22, 7)), "3.143E0");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let exact = f.precision().is_none() && self.length_after_point_in_small_base(10).is_some();
+        let s = self.to_sci_with_options(exp_options(f, exact)).to_string();
+        pad_exp(f, self.sign, s.strip_prefix('-').unwrap_or(&s), false)
+    }
+}
@@ -1,5 +1,5 @@
 use crate::Rational;
-use std::fmt::{Debug, Display, Formatter, Result, Write};
+use std::fmt::{Debug, Display, Formatter, Result};
 
 impl Display for Rational {
     /// Converts a [`Rational`] to a [`String`].
@@ -20,18 +20,19 @@ impl Display for Rational {
     /// assert_eq!(Rational::ZERO.to_string(), "0");
     /// assert_eq!(Rational::from(123).to_string(), "123");
     /// assert_eq!(Rational::from_str("22/7").unwrap().to_string(), "22/7");
+    ///
+    /// assert_eq!(format!("{:>8}", Rational::from_signeds(-22, 7)), "   -22/7");
+    /// assert_eq!(format!("{:*<8}", Rational::from_signeds(22, 7)), "22/7****");
+    /// assert_eq!(format!("{:+}", Rational::from_signeds(22, 7)), "+22/7");
+    /// assert_eq!(format!("{:07}", Rational::from_signeds(-22, 7)), "-0022/7");
     /// ```
     fn fmt(&self, f: &mut Formatter) -> Result {
-        if !self.sign {
-            f.write_char('-')?;
-        }
-        let result = Display::fmt(&self.numerator, f);
-        if self.denominator != 1 {
-            f.write_char('/')?;
-            Display::fmt(&self.denominator, f)
+        let abs = if self.denominator == 1u32 {
+            self.numerator.to_string()
         } else {
-            result
-        }
+            format!("{}/{}", self.numerator, self.denominator)
+        };
+        f.pad_integral(self.sign, "", &abs)
     }
 }
 
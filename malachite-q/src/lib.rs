@@ -224,6 +224,9 @@ impl_named!(Rational);
 
 /// Traits for arithmetic.
 pub mod arithmetic;
+/// [`ComplexRational`](complex::ComplexRational)s: complex numbers with [`Rational`] real and
+/// imaginary parts.
+pub mod complex;
 /// Traits for comparing [`Rational`]s for equality or order.
 pub mod comparison;
 /// Traits for converting to and from [`Rational`]s, converting to and from strings, and extracting
@@ -457,6 +457,8 @@ pub(crate) fn round_to_multiple_rational_filter(t: &(Rational, Rational, Roundin
                     RoundingMode::Ceiling
                 })
             || rm == RoundingMode::Nearest
+            || rm == RoundingMode::NearestTiesAway
+            || rm == RoundingMode::NearestTiesTowardZero
     } else {
         rm != RoundingMode::Exact || (x / y).is_integer()
     }
@@ -572,7 +574,7 @@ pub fn exhaustive_rational_rounding_mode_pair_gen() -> It<(Rational, RoundingMod
 pub fn exhaustive_rational_rounding_mode_pair_gen_var_1() -> It<(Rational, RoundingMode)> {
     Box::new(
         lex_pairs(exhaustive_rationals(), exhaustive_rounding_modes()).filter(|(x, rm)| match rm {
-            RoundingMode::Floor | RoundingMode::Up => *x >= 0u32,
+            RoundingMode::Floor | RoundingMode::Up | RoundingMode::Odd => *x >= 0u32,
             RoundingMode::Exact => Natural::convertible_from(x),
             _ => true,
         }),
@@ -596,7 +598,7 @@ where
         lex_pairs(exhaustive_rationals(), exhaustive_rounding_modes()).filter(|(x, rm)| match rm {
             RoundingMode::Floor => *x >= T::MIN,
             RoundingMode::Ceiling => *x <= T::MAX,
-            RoundingMode::Up => *x >= T::MIN && *x <= T::MAX,
+            RoundingMode::Up | RoundingMode::Odd => *x >= T::MIN && *x <= T::MAX,
             RoundingMode::Exact => T::convertible_from(x),
             _ => true,
         }),
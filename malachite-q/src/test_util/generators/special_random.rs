@@ -1434,7 +1434,7 @@ pub fn special_random_rational_rounding_mode_pair_gen_var_1(
             &random_rounding_modes,
         )
         .filter(|(x, rm)| match rm {
-            RoundingMode::Floor | RoundingMode::Up => *x >= 0u32,
+            RoundingMode::Floor | RoundingMode::Up | RoundingMode::Odd => *x >= 0u32,
             RoundingMode::Exact => Natural::convertible_from(x),
             _ => true,
         }),
@@ -1487,7 +1487,7 @@ where
         .filter(|(x, rm)| match rm {
             RoundingMode::Floor => *x >= T::MIN,
             RoundingMode::Ceiling => *x <= T::MAX,
-            RoundingMode::Up => *x >= T::MIN && *x <= T::MAX,
+            RoundingMode::Up | RoundingMode::Odd => *x >= T::MIN && *x <= T::MAX,
             RoundingMode::Exact => T::convertible_from(x),
             _ => true,
         }),
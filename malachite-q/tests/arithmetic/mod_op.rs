@@ -0,0 +1,86 @@
+use malachite_base::num::arithmetic::traits::{CoprimeWith, DivisibleBy, Mod};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::strings::ToDebugString;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::test_util::generators::{rational_natural_pair_gen_var_3, rational_pair_gen};
+use malachite_q::Rational;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod() {
+    let test = |s, t, out| {
+        let x = Rational::from_str(s).unwrap();
+        let m = Natural::from_str(t).unwrap();
+
+        let r = x.clone().mod_op(m.clone());
+        assert_eq!(r.to_debug_string(), out);
+
+        let r = x.clone().mod_op(&m);
+        assert_eq!(r.to_debug_string(), out);
+
+        let r = (&x).mod_op(m.clone());
+        assert_eq!(r.to_debug_string(), out);
+
+        let r = (&x).mod_op(&m);
+        assert_eq!(r.to_debug_string(), out);
+    };
+    test("0", "1", "Some(0)");
+    test("0", "7", "Some(0)");
+    test("1/3", "1", "Some(0)");
+    test("1/3", "7", "Some(5)");
+    test("-1/3", "7", "Some(2)");
+    test("2/3", "7", "Some(3)");
+    test("10", "7", "Some(3)");
+    test("-10", "7", "Some(4)");
+    test("14", "7", "Some(0)");
+    test("1/3", "6", "None");
+    test("7/6", "4", "None");
+    test("22/7", "100", "Some(46)");
+    test("-22/7", "100", "Some(54)");
+    test("1/2", "1000000000000000000000", "None");
+    test(
+        "1/3",
+        "1000000000000000000000",
+        "Some(666666666666666666667)",
+    );
+}
+
+#[test]
+fn mod_fail() {
+    assert_panic!(Rational::from_signeds(1, 3).mod_op(Natural::ZERO));
+    assert_panic!(Rational::from_signeds(1, 3).mod_op(&Natural::ZERO));
+    assert_panic!((&Rational::from_signeds(1, 3)).mod_op(Natural::ZERO));
+    assert_panic!((&Rational::from_signeds(1, 3)).mod_op(&Natural::ZERO));
+}
+
+#[test]
+fn mod_properties() {
+    rational_natural_pair_gen_var_3().test_properties(|(x, m)| {
+        let r = (&x).mod_op(&m);
+        assert_eq!(x.clone().mod_op(m.clone()), r);
+        assert_eq!(x.clone().mod_op(&m), r);
+        assert_eq!((&x).mod_op(m.clone()), r);
+
+        assert_eq!(r.is_some(), x.denominator_ref().coprime_with(&m));
+        if let Some(r) = r {
+            assert!(r < m);
+            let numerator = if x >= 0u32 {
+                Integer::from(x.numerator_ref())
+            } else {
+                -Integer::from(x.numerator_ref())
+            };
+            let denominator = Integer::from(x.denominator_ref());
+            assert!((Integer::from(r) * denominator - numerator).divisible_by(Integer::from(m)));
+        }
+    });
+
+    rational_pair_gen().test_properties(|(x, y)| {
+        let m = Natural::from(1000000007u32);
+        if let (Some(r), Some(s)) = ((&x).mod_op(&m), (&y).mod_op(&m)) {
+            assert_eq!((&x + &y).mod_op(&m), Some((&r + &s) % &m));
+            assert_eq!((x * y).mod_op(&m), Some(r * s % m));
+        }
+    });
+}
@@ -0,0 +1,56 @@
+use malachite_base::num::arithmetic::traits::{Reciprocal, Valuation};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::IsInteger;
+use malachite_nz::natural::Natural;
+use malachite_q::test_util::generators::rational_natural_pair_gen_var_1;
+use malachite_q::Rational;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_p_adic_valuation() {
+    let test = |s, t, out| {
+        let x = Rational::from_str(s).unwrap();
+        let p = Natural::from_str(t).unwrap();
+        assert_eq!(x.p_adic_valuation(&p), out);
+    };
+    test("1", "2", 0);
+    test("-1", "3", 0);
+    test("8", "2", 3);
+    test("1/8", "2", -3);
+    test("-40/9", "2", 3);
+    test("-40/9", "3", -2);
+    test("-40/9", "5", 1);
+    test("-40/9", "7", 0);
+    test("-40/9", "6", 0);
+    test("-1/36", "6", -2);
+    test("22/7", "7", -1);
+    test("1/1000000000000", "10", -12);
+    test("340282366920938463463374607431768211456/3", "4", 64);
+}
+
+#[test]
+fn p_adic_valuation_fail() {
+    assert_panic!(Rational::ZERO.p_adic_valuation(&Natural::from(2u32)));
+    assert_panic!(Rational::ONE.p_adic_valuation(&Natural::ONE));
+    assert_panic!(Rational::ONE.p_adic_valuation(&Natural::ZERO));
+}
+
+#[test]
+fn p_adic_valuation_properties() {
+    rational_natural_pair_gen_var_1().test_properties(|(x, p)| {
+        if x == 0u32 {
+            return;
+        }
+        let v = x.p_adic_valuation(&p);
+        let numerator_valuation = i64::try_from(x.numerator_ref().valuation(&p)).unwrap();
+        let denominator_valuation = i64::try_from(x.denominator_ref().valuation(&p)).unwrap();
+        assert_eq!(v, numerator_valuation - denominator_valuation);
+        assert_eq!((-&x).p_adic_valuation(&p), v);
+        assert_eq!((&x).reciprocal().p_adic_valuation(&p), -v);
+        if (&x).is_integer() {
+            assert_eq!((&x * Rational::from(&p)).p_adic_valuation(&p), v + 1);
+        }
+        assert_eq!(Rational::from(&p).p_adic_valuation(&p), 1);
+    });
+}
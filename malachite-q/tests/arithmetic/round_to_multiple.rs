@@ -58,6 +58,21 @@ fn test_round_to_multiple() {
     test("0", "1", RoundingMode::Up, "0", Ordering::Equal);
     test("0", "1", RoundingMode::Ceiling, "0", Ordering::Equal);
     test("0", "1", RoundingMode::Nearest, "0", Ordering::Equal);
+    test(
+        "0",
+        "1",
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "1",
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Equal,
+    );
+    test("0", "1", RoundingMode::Odd, "0", Ordering::Equal);
     test("0", "1", RoundingMode::Exact, "0", Ordering::Equal);
 
     test("0", "22/7", RoundingMode::Down, "0", Ordering::Equal);
@@ -65,6 +80,21 @@ fn test_round_to_multiple() {
     test("0", "22/7", RoundingMode::Up, "0", Ordering::Equal);
     test("0", "22/7", RoundingMode::Ceiling, "0", Ordering::Equal);
     test("0", "22/7", RoundingMode::Nearest, "0", Ordering::Equal);
+    test(
+        "0",
+        "22/7",
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "22/7",
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Equal,
+    );
+    test("0", "22/7", RoundingMode::Odd, "0", Ordering::Equal);
     test("0", "22/7", RoundingMode::Exact, "0", Ordering::Equal);
 
     test("1/3", "1", RoundingMode::Down, "0", Ordering::Less);
@@ -72,12 +102,42 @@ fn test_round_to_multiple() {
     test("1/3", "1", RoundingMode::Up, "1", Ordering::Greater);
     test("1/3", "1", RoundingMode::Ceiling, "1", Ordering::Greater);
     test("1/3", "1", RoundingMode::Nearest, "0", Ordering::Less);
+    test(
+        "1/3",
+        "1",
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "1",
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Less,
+    );
+    test("1/3", "1", RoundingMode::Odd, "1", Ordering::Greater);
 
     test("1/3", "1/3", RoundingMode::Down, "1/3", Ordering::Equal);
     test("1/3", "1/3", RoundingMode::Floor, "1/3", Ordering::Equal);
     test("1/3", "1/3", RoundingMode::Up, "1/3", Ordering::Equal);
     test("1/3", "1/3", RoundingMode::Ceiling, "1/3", Ordering::Equal);
     test("1/3", "1/3", RoundingMode::Nearest, "1/3", Ordering::Equal);
+    test(
+        "1/3",
+        "1/3",
+        RoundingMode::NearestTiesAway,
+        "1/3",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "1/3",
+        RoundingMode::NearestTiesTowardZero,
+        "1/3",
+        Ordering::Equal,
+    );
+    test("1/3", "1/3", RoundingMode::Odd, "1/3", Ordering::Equal);
     test("1/3", "1/3", RoundingMode::Exact, "1/3", Ordering::Equal);
 
     test("1/3", "1/4", RoundingMode::Down, "1/4", Ordering::Less);
@@ -91,6 +151,21 @@ fn test_round_to_multiple() {
         Ordering::Greater,
     );
     test("1/3", "1/4", RoundingMode::Nearest, "1/4", Ordering::Less);
+    test(
+        "1/3",
+        "1/4",
+        RoundingMode::NearestTiesAway,
+        "1/4",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "1/4",
+        RoundingMode::NearestTiesTowardZero,
+        "1/4",
+        Ordering::Less,
+    );
+    test("1/3", "1/4", RoundingMode::Odd, "1/4", Ordering::Less);
 
     test(
         "884279719003555/281474976710656",
@@ -169,6 +244,21 @@ fn test_round_to_multiple() {
     test("-1/3", "1", RoundingMode::Up, "-1", Ordering::Less);
     test("-1/3", "1", RoundingMode::Ceiling, "0", Ordering::Greater);
     test("-1/3", "1", RoundingMode::Nearest, "0", Ordering::Greater);
+    test(
+        "-1/3",
+        "1",
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Greater,
+    );
+    test(
+        "-1/3",
+        "1",
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Greater,
+    );
+    test("-1/3", "1", RoundingMode::Odd, "-1", Ordering::Less);
 
     test("-1/3", "1/3", RoundingMode::Down, "-1/3", Ordering::Equal);
     test("-1/3", "1/3", RoundingMode::Floor, "-1/3", Ordering::Equal);
@@ -284,6 +374,21 @@ fn test_round_to_multiple() {
     test("0", "-1", RoundingMode::Up, "0", Ordering::Equal);
     test("0", "-1", RoundingMode::Ceiling, "0", Ordering::Equal);
     test("0", "-1", RoundingMode::Nearest, "0", Ordering::Equal);
+    test(
+        "0",
+        "-1",
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-1",
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Equal,
+    );
+    test("0", "-1", RoundingMode::Odd, "0", Ordering::Equal);
     test("0", "-1", RoundingMode::Exact, "0", Ordering::Equal);
 
     test("0", "-22/7", RoundingMode::Down, "0", Ordering::Equal);
@@ -291,6 +396,21 @@ fn test_round_to_multiple() {
     test("0", "-22/7", RoundingMode::Up, "0", Ordering::Equal);
     test("0", "-22/7", RoundingMode::Ceiling, "0", Ordering::Equal);
     test("0", "-22/7", RoundingMode::Nearest, "0", Ordering::Equal);
+    test(
+        "0",
+        "-22/7",
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-22/7",
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Equal,
+    );
+    test("0", "-22/7", RoundingMode::Odd, "0", Ordering::Equal);
     test("0", "-22/7", RoundingMode::Exact, "0", Ordering::Equal);
 
     test("1/3", "-1", RoundingMode::Down, "0", Ordering::Less);
@@ -298,12 +418,42 @@ fn test_round_to_multiple() {
     test("1/3", "-1", RoundingMode::Up, "1", Ordering::Greater);
     test("1/3", "-1", RoundingMode::Ceiling, "1", Ordering::Greater);
     test("1/3", "-1", RoundingMode::Nearest, "0", Ordering::Less);
+    test(
+        "1/3",
+        "-1",
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "-1",
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Less,
+    );
+    test("1/3", "-1", RoundingMode::Odd, "1", Ordering::Greater);
 
     test("1/3", "-1/3", RoundingMode::Down, "1/3", Ordering::Equal);
     test("1/3", "-1/3", RoundingMode::Floor, "1/3", Ordering::Equal);
     test("1/3", "-1/3", RoundingMode::Up, "1/3", Ordering::Equal);
     test("1/3", "-1/3", RoundingMode::Ceiling, "1/3", Ordering::Equal);
     test("1/3", "-1/3", RoundingMode::Nearest, "1/3", Ordering::Equal);
+    test(
+        "1/3",
+        "-1/3",
+        RoundingMode::NearestTiesAway,
+        "1/3",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "-1/3",
+        RoundingMode::NearestTiesTowardZero,
+        "1/3",
+        Ordering::Equal,
+    );
+    test("1/3", "-1/3", RoundingMode::Odd, "1/3", Ordering::Equal);
     test("1/3", "-1/3", RoundingMode::Exact, "1/3", Ordering::Equal);
 
     test("1/3", "-1/4", RoundingMode::Down, "1/4", Ordering::Less);
@@ -317,6 +467,21 @@ fn test_round_to_multiple() {
         Ordering::Greater,
     );
     test("1/3", "-1/4", RoundingMode::Nearest, "1/4", Ordering::Less);
+    test(
+        "1/3",
+        "-1/4",
+        RoundingMode::NearestTiesAway,
+        "1/4",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "-1/4",
+        RoundingMode::NearestTiesTowardZero,
+        "1/4",
+        Ordering::Less,
+    );
+    test("1/3", "-1/4", RoundingMode::Odd, "1/4", Ordering::Less);
 
     test(
         "884279719003555/281474976710656",
@@ -395,6 +560,21 @@ fn test_round_to_multiple() {
     test("-1/3", "-1", RoundingMode::Up, "-1", Ordering::Less);
     test("-1/3", "-1", RoundingMode::Ceiling, "0", Ordering::Greater);
     test("-1/3", "-1", RoundingMode::Nearest, "0", Ordering::Greater);
+    test(
+        "-1/3",
+        "-1",
+        RoundingMode::NearestTiesAway,
+        "0",
+        Ordering::Greater,
+    );
+    test(
+        "-1/3",
+        "-1",
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Greater,
+    );
+    test("-1/3", "-1", RoundingMode::Odd, "-1", Ordering::Less);
 
     test("-1/3", "-1/3", RoundingMode::Down, "-1/3", Ordering::Equal);
     test("-1/3", "-1/3", RoundingMode::Floor, "-1/3", Ordering::Equal);
@@ -734,7 +914,10 @@ fn round_to_multiple_properties() {
                 RoundingMode::Down => assert!(r.le_abs(&x)),
                 RoundingMode::Up => assert!(r.ge_abs(&x)),
                 RoundingMode::Exact => assert_eq!(r, x),
-                RoundingMode::Nearest => {
+                RoundingMode::Odd => assert!(r == x || Integer::exact_from(&r / &y).odd()),
+                RoundingMode::Nearest
+                | RoundingMode::NearestTiesAway
+                | RoundingMode::NearestTiesTowardZero => {
                     let closest;
                     let second_closest;
                     if r <= x {
@@ -742,11 +925,15 @@ fn round_to_multiple_properties() {
                         second_closest = &r + (&y).abs() - &x;
                     } else {
                         closest = &r - &x;
-                        second_closest = x + (&y).abs() - &r;
+                        second_closest = &x + (&y).abs() - &r;
                     }
                     assert!(closest <= second_closest);
                     if closest == second_closest {
-                        assert!(Integer::exact_from(r / y).even());
+                        match rm {
+                            RoundingMode::Nearest => assert!(Integer::exact_from(r / y).even()),
+                            RoundingMode::NearestTiesAway => assert!(r.ge_abs(&x)),
+                            _ => assert!(r.le_abs(&x)),
+                        }
                     }
                 }
             }
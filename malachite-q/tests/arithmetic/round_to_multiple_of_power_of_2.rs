@@ -44,6 +44,15 @@ fn test_round_to_multiple_of_power_of_2() {
     test("0", 0, RoundingMode::Floor, "0", Ordering::Equal);
     test("0", 0, RoundingMode::Ceiling, "0", Ordering::Equal);
     test("0", 0, RoundingMode::Nearest, "0", Ordering::Equal);
+    test("0", 0, RoundingMode::NearestTiesAway, "0", Ordering::Equal);
+    test(
+        "0",
+        0,
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Equal,
+    );
+    test("0", 0, RoundingMode::Odd, "0", Ordering::Equal);
     test("0", 0, RoundingMode::Exact, "0", Ordering::Equal);
 
     test("0", 10, RoundingMode::Down, "0", Ordering::Equal);
@@ -51,6 +60,15 @@ fn test_round_to_multiple_of_power_of_2() {
     test("0", 10, RoundingMode::Floor, "0", Ordering::Equal);
     test("0", 10, RoundingMode::Ceiling, "0", Ordering::Equal);
     test("0", 10, RoundingMode::Nearest, "0", Ordering::Equal);
+    test("0", 10, RoundingMode::NearestTiesAway, "0", Ordering::Equal);
+    test(
+        "0",
+        10,
+        RoundingMode::NearestTiesTowardZero,
+        "0",
+        Ordering::Equal,
+    );
+    test("0", 10, RoundingMode::Odd, "0", Ordering::Equal);
     test("0", 10, RoundingMode::Exact, "0", Ordering::Equal);
 
     test("123", 0, RoundingMode::Down, "123", Ordering::Equal);
@@ -58,6 +76,21 @@ fn test_round_to_multiple_of_power_of_2() {
     test("123", 0, RoundingMode::Floor, "123", Ordering::Equal);
     test("123", 0, RoundingMode::Ceiling, "123", Ordering::Equal);
     test("123", 0, RoundingMode::Nearest, "123", Ordering::Equal);
+    test(
+        "123",
+        0,
+        RoundingMode::NearestTiesAway,
+        "123",
+        Ordering::Equal,
+    );
+    test(
+        "123",
+        0,
+        RoundingMode::NearestTiesTowardZero,
+        "123",
+        Ordering::Equal,
+    );
+    test("123", 0, RoundingMode::Odd, "123", Ordering::Equal);
     test("123", 0, RoundingMode::Exact, "123", Ordering::Equal);
 
     test("123", 2, RoundingMode::Down, "120", Ordering::Less);
@@ -65,12 +98,42 @@ fn test_round_to_multiple_of_power_of_2() {
     test("123", 2, RoundingMode::Floor, "120", Ordering::Less);
     test("123", 2, RoundingMode::Ceiling, "124", Ordering::Greater);
     test("123", 2, RoundingMode::Nearest, "124", Ordering::Greater);
+    test(
+        "123",
+        2,
+        RoundingMode::NearestTiesAway,
+        "124",
+        Ordering::Greater,
+    );
+    test(
+        "123",
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        "124",
+        Ordering::Greater,
+    );
+    test("123", 2, RoundingMode::Odd, "124", Ordering::Greater);
 
     test("123", -2, RoundingMode::Down, "123", Ordering::Equal);
     test("123", -2, RoundingMode::Up, "123", Ordering::Equal);
     test("123", -2, RoundingMode::Floor, "123", Ordering::Equal);
     test("123", -2, RoundingMode::Ceiling, "123", Ordering::Equal);
     test("123", -2, RoundingMode::Nearest, "123", Ordering::Equal);
+    test(
+        "123",
+        -2,
+        RoundingMode::NearestTiesAway,
+        "123",
+        Ordering::Equal,
+    );
+    test(
+        "123",
+        -2,
+        RoundingMode::NearestTiesTowardZero,
+        "123",
+        Ordering::Equal,
+    );
+    test("123", -2, RoundingMode::Odd, "123", Ordering::Equal);
     test("123", -2, RoundingMode::Exact, "123", Ordering::Equal);
 
     test(
@@ -186,6 +249,21 @@ fn test_round_to_multiple_of_power_of_2() {
     test("-123", 0, RoundingMode::Floor, "-123", Ordering::Equal);
     test("-123", 0, RoundingMode::Ceiling, "-123", Ordering::Equal);
     test("-123", 0, RoundingMode::Nearest, "-123", Ordering::Equal);
+    test(
+        "-123",
+        0,
+        RoundingMode::NearestTiesAway,
+        "-123",
+        Ordering::Equal,
+    );
+    test(
+        "-123",
+        0,
+        RoundingMode::NearestTiesTowardZero,
+        "-123",
+        Ordering::Equal,
+    );
+    test("-123", 0, RoundingMode::Odd, "-123", Ordering::Equal);
     test("-123", 0, RoundingMode::Exact, "-123", Ordering::Equal);
 
     test("-123", 2, RoundingMode::Down, "-120", Ordering::Greater);
@@ -193,12 +271,42 @@ fn test_round_to_multiple_of_power_of_2() {
     test("-123", 2, RoundingMode::Floor, "-124", Ordering::Less);
     test("-123", 2, RoundingMode::Ceiling, "-120", Ordering::Greater);
     test("-123", 2, RoundingMode::Nearest, "-124", Ordering::Less);
+    test(
+        "-123",
+        2,
+        RoundingMode::NearestTiesAway,
+        "-124",
+        Ordering::Less,
+    );
+    test(
+        "-123",
+        2,
+        RoundingMode::NearestTiesTowardZero,
+        "-124",
+        Ordering::Less,
+    );
+    test("-123", 2, RoundingMode::Odd, "-124", Ordering::Less);
 
     test("-123", -2, RoundingMode::Down, "-123", Ordering::Equal);
     test("-123", -2, RoundingMode::Up, "-123", Ordering::Equal);
     test("-123", -2, RoundingMode::Floor, "-123", Ordering::Equal);
     test("-123", -2, RoundingMode::Ceiling, "-123", Ordering::Equal);
     test("-123", -2, RoundingMode::Nearest, "-123", Ordering::Equal);
+    test(
+        "-123",
+        -2,
+        RoundingMode::NearestTiesAway,
+        "-123",
+        Ordering::Equal,
+    );
+    test(
+        "-123",
+        -2,
+        RoundingMode::NearestTiesTowardZero,
+        "-123",
+        Ordering::Equal,
+    );
+    test("-123", -2, RoundingMode::Odd, "-123", Ordering::Equal);
     test("-123", -2, RoundingMode::Exact, "-123", Ordering::Equal);
 
     test(
@@ -435,20 +543,27 @@ fn round_to_multiple_of_power_of_2_properties() {
             RoundingMode::Down => assert!(r.le_abs(&n)),
             RoundingMode::Up => assert!(r.ge_abs(&n)),
             RoundingMode::Exact => assert_eq!(r, n),
-            RoundingMode::Nearest => {
+            RoundingMode::Odd => assert!(r == n || Integer::exact_from(&r >> pow).odd()),
+            RoundingMode::Nearest
+            | RoundingMode::NearestTiesAway
+            | RoundingMode::NearestTiesTowardZero => {
                 let k = Rational::power_of_2(pow);
                 let closest;
                 let second_closest;
                 if r <= n {
                     closest = &n - &r;
-                    second_closest = &r + k - n;
+                    second_closest = &r + k - &n;
                 } else {
                     closest = &r - &n;
-                    second_closest = n + k - &r;
+                    second_closest = &n + k - &r;
                 }
                 assert!(closest <= second_closest);
                 if closest == second_closest {
-                    assert!(Integer::exact_from(r >> pow).even());
+                    match rm {
+                        RoundingMode::Nearest => assert!(Integer::exact_from(r >> pow).even()),
+                        RoundingMode::NearestTiesAway => assert!(r.ge_abs(&n)),
+                        _ => assert!(r.le_abs(&n)),
+                    }
                 }
             }
         }
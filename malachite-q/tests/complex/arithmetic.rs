@@ -0,0 +1,164 @@
+use malachite_base::num::arithmetic::traits::{NegAssign, Pow, Reciprocal, ReciprocalAssign};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_q::complex::ComplexRational;
+use malachite_q::test_util::generators::{rational_pair_gen, rational_triple_gen};
+use malachite_q::Rational;
+use std::str::FromStr;
+
+fn parse(s: &str) -> ComplexRational {
+    ComplexRational::from_str(s).unwrap()
+}
+
+#[test]
+fn test_add_sub_mul_div() {
+    let test = |s, t, sum: &str, difference: &str, product: &str, quotient: &str| {
+        let x = parse(s);
+        let y = parse(t);
+
+        assert_eq!((x.clone() + y.clone()).to_string(), sum);
+        assert_eq!((x.clone() + &y).to_string(), sum);
+        assert_eq!((&x + y.clone()).to_string(), sum);
+        assert_eq!((&x + &y).to_string(), sum);
+        let mut z = x.clone();
+        z += &y;
+        assert_eq!(z.to_string(), sum);
+
+        assert_eq!((x.clone() - y.clone()).to_string(), difference);
+        assert_eq!((&x - &y).to_string(), difference);
+        let mut z = x.clone();
+        z -= y.clone();
+        assert_eq!(z.to_string(), difference);
+
+        assert_eq!((x.clone() * y.clone()).to_string(), product);
+        assert_eq!((&x * &y).to_string(), product);
+        let mut z = x.clone();
+        z *= &y;
+        assert_eq!(z.to_string(), product);
+
+        assert_eq!((x.clone() / y.clone()).to_string(), quotient);
+        assert_eq!((&x / &y).to_string(), quotient);
+        let mut z = x.clone();
+        z /= y;
+        assert_eq!(z.to_string(), quotient);
+    };
+    test("(0 0)", "(1 0)", "(1 0)", "(-1 0)", "(0 0)", "(0 0)");
+    test("(1 0)", "(0 1)", "(1 1)", "(1 -1)", "(0 1)", "(0 -1)");
+    test("(0 1)", "(0 1)", "(0 2)", "(0 0)", "(-1 0)", "(1 0)");
+    test(
+        "(1 2)",
+        "(3 4)",
+        "(4 6)",
+        "(-2 -2)",
+        "(-5 10)",
+        "(11/25 2/25)",
+    );
+    test(
+        "(1/2 -1/3)",
+        "(-2/5 7)",
+        "(1/10 20/3)",
+        "(9/10 -22/3)",
+        "(32/15 109/30)",
+        "(-190/3687 -505/7374)",
+    );
+}
+
+#[test]
+#[should_panic]
+fn div_fail() {
+    let _ = ComplexRational::ONE / ComplexRational::ZERO;
+}
+
+#[test]
+fn test_neg_conj_norm_reciprocal() {
+    let test = |s, neg: &str, conj: &str, norm: &str, reciprocal: &str| {
+        let x = parse(s);
+        assert_eq!((-x.clone()).to_string(), neg);
+        assert_eq!((-&x).to_string(), neg);
+        let mut y = x.clone();
+        y.neg_assign();
+        assert_eq!(y.to_string(), neg);
+
+        assert_eq!(x.conj().to_string(), conj);
+        assert_eq!(x.norm().to_string(), norm);
+
+        assert_eq!(x.clone().reciprocal().to_string(), reciprocal);
+        assert_eq!((&x).reciprocal().to_string(), reciprocal);
+        let mut y = x;
+        y.reciprocal_assign();
+        assert_eq!(y.to_string(), reciprocal);
+    };
+    test("(1 0)", "(-1 0)", "(1 0)", "1", "(1 0)");
+    test("(0 1)", "(0 -1)", "(0 -1)", "1", "(0 -1)");
+    test("(3 4)", "(-3 -4)", "(3 -4)", "25", "(3/25 -4/25)");
+    test(
+        "(-1/2 2/3)",
+        "(1/2 -2/3)",
+        "(-1/2 -2/3)",
+        "25/36",
+        "(-18/25 -24/25)",
+    );
+}
+
+#[test]
+fn test_pow() {
+    let test = |s, exp: i64, out: &str| {
+        let x = parse(s);
+        assert_eq!((&x).pow(exp).to_string(), out);
+        assert_eq!(x.clone().pow(exp).to_string(), out);
+        if exp >= 0 {
+            let exp = u64::try_from(exp).unwrap();
+            assert_eq!((&x).pow(exp).to_string(), out);
+            assert_eq!(x.pow(exp).to_string(), out);
+        }
+    };
+    test("(0 0)", 0, "(1 0)");
+    test("(0 0)", 3, "(0 0)");
+    test("(0 1)", 2, "(-1 0)");
+    test("(0 1)", 3, "(0 -1)");
+    test("(0 1)", -1, "(0 -1)");
+    test("(1 1)", 4, "(-4 0)");
+    test("(1 1)", -4, "(-1/4 0)");
+    test("(1/2 3/2)", 3, "(-13/4 -9/4)");
+}
+
+#[test]
+fn add_mul_properties() {
+    rational_triple_gen().test_properties(|(a, b, c)| {
+        let x = ComplexRational::new(a.clone(), b.clone());
+        let y = ComplexRational::new(b, c.clone());
+        let z = ComplexRational::new(c, a);
+
+        assert_eq!(&x + &y, &y + &x);
+        assert_eq!(&x * &y, &y * &x);
+        assert_eq!(&(&x + &y) - &y, x);
+        assert_eq!(&(&x * &y) * &z, &x * &(&y * &z));
+        assert_eq!(&x * &(&y + &z), &(&x * &y) + &(&x * &z));
+        assert_eq!((&x * &y).norm(), x.norm() * y.norm());
+        assert_eq!(&x * &x.conj(), ComplexRational::from(x.norm()));
+        if y != ComplexRational::ZERO {
+            assert_eq!(&(&x / &y) * &y, x);
+            assert_eq!(&x / &y, &x * &(&y).reciprocal());
+        }
+        assert_eq!(&x + &ComplexRational::ZERO, x);
+        assert_eq!(&x * &ComplexRational::ONE, x);
+        assert_eq!(-(-&x), x);
+    });
+}
+
+#[test]
+fn pow_properties() {
+    rational_pair_gen().test_properties(|(a, b)| {
+        let x = ComplexRational::new(a, b);
+        assert_eq!((&x).pow(0u64), ComplexRational::ONE);
+        assert_eq!((&x).pow(1u64), x);
+        assert_eq!((&x).pow(2u64), &x * &x);
+        assert_eq!((&x).pow(5u64), &(&x).pow(2u64) * &(&x).pow(3u64));
+        if x != ComplexRational::ZERO {
+            assert_eq!(&(&x).pow(-3i64) * &(&x).pow(3i64), ComplexRational::ONE);
+        }
+        assert_eq!(
+            ComplexRational::from(Rational::from(2)).pow(10u64),
+            ComplexRational::from(Rational::from(1024))
+        );
+    });
+}
@@ -0,0 +1,71 @@
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_q::complex::ComplexRational;
+use malachite_q::test_util::generators::rational_pair_gen;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_constants_and_accessors() {
+    assert_eq!(ComplexRational::ZERO.to_string(), "(0 0)");
+    assert_eq!(ComplexRational::ONE.to_string(), "(1 0)");
+    assert_eq!(ComplexRational::I.to_string(), "(0 1)");
+    assert_eq!(ComplexRational::default(), ComplexRational::ZERO);
+    assert!(ComplexRational::ONE.is_real());
+    assert!(!ComplexRational::I.is_real());
+
+    let z = ComplexRational::new(Rational::from_signeds(-1, 2), Rational::from(3));
+    assert_eq!(z.re(), &Rational::from_signeds(-1, 2));
+    assert_eq!(z.im(), &Rational::from(3));
+    assert_eq!(
+        z.into_re_im(),
+        (Rational::from_signeds(-1, 2), Rational::from(3))
+    );
+}
+
+#[test]
+fn test_from_rational() {
+    let test = |x: Rational, out: &str| {
+        assert_eq!(ComplexRational::from(&x).to_string(), out);
+        assert_eq!(ComplexRational::from(x).to_string(), out);
+    };
+    test(Rational::ZERO, "(0 0)");
+    test(Rational::from_signeds(22, 7), "(22/7 0)");
+    test(Rational::from(-5), "(-5 0)");
+}
+
+#[test]
+fn test_from_str() {
+    let test_ok = |s, out: &str| {
+        let z = ComplexRational::from_str(s).unwrap();
+        assert_eq!(z.to_string(), out);
+        assert_eq!(format!("{z:?}"), out);
+    };
+    test_ok("0", "(0 0)");
+    test_ok("-22/7", "(-22/7 0)");
+    test_ok("(0 0)", "(0 0)");
+    test_ok("(1/2 -3)", "(1/2 -3)");
+    test_ok("(-100 2/4)", "(-100 1/2)");
+
+    let test_err = |s| {
+        assert!(ComplexRational::from_str(s).is_err());
+    };
+    test_err("");
+    test_err("()");
+    test_err("(1)");
+    test_err("(1 2");
+    test_err("1 2)");
+    test_err("(1  2)");
+    test_err("(1,2)");
+    test_err("(1 2 3)");
+    test_err("1+2i");
+}
+
+#[test]
+fn to_string_properties() {
+    rational_pair_gen().test_properties(|(a, b)| {
+        let z = ComplexRational::new(a.clone(), b.clone());
+        let s = z.to_string();
+        assert_eq!(s, format!("({a} {b})"));
+        assert_eq!(ComplexRational::from_str(&s).unwrap(), z);
+    });
+}
@@ -86,6 +86,24 @@ fn test_f32_rounding_from_rational() {
         1.6777216e7,
         Ordering::Less,
     );
+    test(
+        "16777217",
+        RoundingMode::NearestTiesAway,
+        1.6777218e7,
+        Ordering::Greater,
+    );
+    test(
+        "16777217",
+        RoundingMode::NearestTiesTowardZero,
+        1.6777216e7,
+        Ordering::Less,
+    );
+    test(
+        "16777217",
+        RoundingMode::Odd,
+        1.6777218e7,
+        Ordering::Greater,
+    );
 
     test(
         "-16777217",
@@ -112,6 +130,19 @@ fn test_f32_rounding_from_rational() {
         -1.6777216e7,
         Ordering::Greater,
     );
+    test(
+        "-16777217",
+        RoundingMode::NearestTiesAway,
+        -1.6777218e7,
+        Ordering::Less,
+    );
+    test(
+        "-16777217",
+        RoundingMode::NearestTiesTowardZero,
+        -1.6777216e7,
+        Ordering::Greater,
+    );
+    test("-16777217", RoundingMode::Odd, -1.6777218e7, Ordering::Less);
 
     test(
         "33554432",
@@ -55,12 +55,47 @@ fn test_serde() {
     test("-22/7", "{\"s\":false,\"n\":\"0x16\",\"d\":\"0x7\"}");
 }
 
+#[test]
+fn test_serde_binary() {
+    let test = |n, out: &[u8]| {
+        assert_eq!(
+            bincode::serialize(&Rational::from_str(n).unwrap()).unwrap(),
+            out
+        );
+        assert_eq!(
+            bincode::deserialize::<Rational>(out).unwrap().to_string(),
+            n
+        );
+    };
+    test("0", &[1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1]);
+    test(
+        "22/7",
+        &[1, 1, 0, 0, 0, 0, 0, 0, 0, 22, 1, 0, 0, 0, 0, 0, 0, 0, 7],
+    );
+    test(
+        "-22/7",
+        &[0, 1, 0, 0, 0, 0, 0, 0, 0, 22, 1, 0, 0, 0, 0, 0, 0, 0, 7],
+    );
+    test(
+        "-1000000000000",
+        &[0, 5, 0, 0, 0, 0, 0, 0, 0, 0x00, 0x10, 0xa5, 0xd4, 0xe8, 1, 0, 0, 0, 0, 0, 0, 0, 1],
+    );
+}
+
 #[test]
 fn serde_properties() {
     rational_gen().test_properties(|x| {
         let s = serde_json::to_string(&x).unwrap();
         assert_eq!(serde_json::from_str::<Rational>(&s).unwrap(), x);
         assert!(string_is_subset(&s, "\",-/0123456789:abcdeflnrstux{}"));
+
+        let bytes = bincode::serialize(&x).unwrap();
+        assert_eq!(bincode::deserialize::<Rational>(&bytes).unwrap(), x);
+        let (numerator, denominator) = x.numerator_and_denominator_ref();
+        let mut expected = vec![u8::from(x >= 0u32)];
+        expected.extend(bincode::serialize(numerator).unwrap());
+        expected.extend(bincode::serialize(denominator).unwrap());
+        assert_eq!(bytes, expected);
     });
 
     string_gen().test_properties(|s| {
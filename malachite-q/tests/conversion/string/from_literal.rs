@@ -0,0 +1,74 @@
+use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::string_gen;
+use malachite_nz::integer::Integer;
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_from_literal() {
+    let test_ok = |s, n| {
+        assert_eq!(Rational::from_literal(s).unwrap().to_string(), n);
+    };
+    test_ok("0", "0");
+    test_ok("-0", "0");
+    test_ok("+0/5", "0");
+    test_ok("123456", "123456");
+    test_ok("-1_000", "-1000");
+    test_ok("22/7", "22/7");
+    test_ok("+22/7", "22/7");
+    test_ok("-6/4", "-3/2");
+    test_ok("0x10/0b110", "8/3");
+    test_ok("-0x10/0b110", "-8/3");
+    test_ok("0o1_0/0X_1_0", "1/2");
+    test_ok("1_000_000/3", "1000000/3");
+
+    let test_err = |s, kind| {
+        assert_eq!(Rational::from_literal(s).unwrap_err().kind(), kind);
+    };
+    test_err("", ParseNumberErrorKind::Empty);
+    test_err("-", ParseNumberErrorKind::Empty);
+    test_err("/2", ParseNumberErrorKind::Empty);
+    test_err("1/", ParseNumberErrorKind::Empty);
+    test_err("1/0x", ParseNumberErrorKind::Empty);
+    test_err("1/0", ParseNumberErrorKind::ZeroDenominator);
+    test_err("-1/0x_0", ParseNumberErrorKind::ZeroDenominator);
+    test_err("--1", ParseNumberErrorKind::InvalidDigit(1));
+    test_err("1/-2", ParseNumberErrorKind::InvalidDigit(2));
+    test_err("-1/+2", ParseNumberErrorKind::InvalidDigit(3));
+    test_err("1/2/3", ParseNumberErrorKind::InvalidDigit(3));
+    test_err("-0x1g/2", ParseNumberErrorKind::InvalidDigit(4));
+    test_err("1/0b12", ParseNumberErrorKind::InvalidDigit(5));
+    test_err("1.5", ParseNumberErrorKind::InvalidDigit(1));
+}
+
+#[test]
+fn from_literal_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 64);
+    string_gen().test_properties_with_config(&config, |s| {
+        if !s.contains('/') {
+            assert_eq!(
+                Rational::from_literal(&s).ok(),
+                Integer::from_literal(&s).ok().map(Rational::from)
+            );
+        }
+    });
+
+    rational_gen().test_properties(|x| {
+        let s = x.to_string();
+        assert_eq!(Rational::from_literal(&s).unwrap(), x);
+        assert_eq!(Rational::from_str(&s).unwrap(), x);
+        let sign = if x < 0u32 { "-" } else { "" };
+        let n = x.numerator_ref();
+        let d = x.denominator_ref();
+        for s in [
+            format!("{sign}{n:#x}/{d:#x}"),
+            format!("{sign}{n:#o}/{d:#b}"),
+            format!("{sign}{n}/{d:#X}"),
+        ] {
+            assert_eq!(Rational::from_literal(&s).unwrap(), x);
+        }
+    });
+}
@@ -2,6 +2,7 @@ use malachite_base::num::arithmetic::traits::{Abs, FloorLogBase};
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::conversion::string::from_sci_string::preprocess_sci_string;
 use malachite_base::num::conversion::string::options::{FromSciStringOptions, ToSciOptions};
+use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
 use malachite_base::num::conversion::traits::{ExactFrom, FromSciString, ToSci};
 use malachite_base::test_util::generators::{
     string_from_sci_string_options_pair_gen_var_2, string_from_sci_string_options_pair_gen_var_3,
@@ -17,9 +18,9 @@ use std::str::FromStr;
 pub fn test_from_sci_string() {
     fn test(s: &str, out: Option<&'static str>) {
         let out = out.map(|s| Rational::from_str(s).unwrap());
-        assert_eq!(Rational::from_sci_string(s), out);
+        assert_eq!(Rational::from_sci_string(s).ok(), out);
         assert_eq!(
-            Rational::from_sci_string_with_options(s, FromSciStringOptions::default()),
+            Rational::from_sci_string_with_options(s, FromSciStringOptions::default()).ok(),
             out
         );
     }
@@ -176,18 +177,37 @@ pub fn test_from_sci_string() {
     test("0e-10000000000000000000000000000", None);
 }
 
+#[test]
+pub fn test_from_sci_string_error() {
+    fn test(s: &str, kind: ParseNumberErrorKind) {
+        assert_eq!(Rational::from_sci_string(s).unwrap_err().kind(), kind);
+        assert_eq!(
+            Rational::from_sci_string_simplest(s).unwrap_err().kind(),
+            kind
+        );
+    }
+    test("", ParseNumberErrorKind::Empty);
+    test("-", ParseNumberErrorKind::Empty);
+    test("1.5e", ParseNumberErrorKind::Empty);
+    test("1/2", ParseNumberErrorKind::InvalidDigit(1));
+    test("--1.5", ParseNumberErrorKind::InvalidDigit(1));
+    test("1.5.", ParseNumberErrorKind::InvalidDigit(3));
+    test("1.5e2.5", ParseNumberErrorKind::InvalidDigit(5));
+    test("1e10000000000000000000000", ParseNumberErrorKind::Overflow);
+}
+
 #[test]
 pub fn test_from_sci_string_with_options() {
     fn test(s: &str, options: FromSciStringOptions, out: Option<&str>) {
         let out = out.map(|s| Rational::from_str(s).unwrap());
-        assert_eq!(Rational::from_sci_string_with_options(s, options), out);
+        assert_eq!(Rational::from_sci_string_with_options(s, options).ok(), out);
     }
     fn test_i<T: PrimitiveInt>(s: &str, options: FromSciStringOptions, out: Option<T>)
     where
         Rational: From<T>,
     {
         let out = out.map(Rational::from);
-        assert_eq!(Rational::from_sci_string_with_options(s, options), out);
+        assert_eq!(Rational::from_sci_string_with_options(s, options).ok(), out);
     }
     // For tests with the default options, see `test_from_sci_string`
 
@@ -450,9 +470,10 @@ pub fn test_from_sci_string_with_options() {
 pub fn test_from_sci_string_simplest() {
     fn test(s: &str, out: Option<&'static str>) {
         let out = out.map(|s| Rational::from_str(s).unwrap());
-        assert_eq!(Rational::from_sci_string_simplest(s), out);
+        assert_eq!(Rational::from_sci_string_simplest(s).ok(), out);
         assert_eq!(
-            Rational::from_sci_string_simplest_with_options(s, FromSciStringOptions::default()),
+            Rational::from_sci_string_simplest_with_options(s, FromSciStringOptions::default())
+                .ok(),
             out
         );
     }
@@ -593,7 +614,7 @@ pub fn test_from_sci_string_simplest_with_options() {
     fn test(s: &str, options: FromSciStringOptions, out: Option<&str>) {
         let out = out.map(|s| Rational::from_str(s).unwrap());
         assert_eq!(
-            Rational::from_sci_string_simplest_with_options(s, options),
+            Rational::from_sci_string_simplest_with_options(s, options).ok(),
             out
         );
     }
@@ -603,7 +624,7 @@ pub fn test_from_sci_string_simplest_with_options() {
     {
         let out = out.map(Rational::from);
         assert_eq!(
-            Rational::from_sci_string_simplest_with_options(s, options),
+            Rational::from_sci_string_simplest_with_options(s, options).ok(),
             out
         );
     }
@@ -857,7 +878,7 @@ pub fn test_from_sci_string_simplest_with_options() {
 }
 
 fn from_sci_string_helper(s: &str) {
-    if let Some(x) = Rational::from_sci_string(s) {
+    if let Ok(x) = Rational::from_sci_string(s) {
         assert!(x.is_valid());
         assert!(!s.ends_with('+'));
         assert!(!s.ends_with('-'));
@@ -888,7 +909,7 @@ fn from_sci_string_properties() {
 }
 
 fn from_sci_string_with_options_helper(s: &str, options: FromSciStringOptions) {
-    if let Some(x) = Rational::from_sci_string_with_options(s, options) {
+    if let Ok(x) = Rational::from_sci_string_with_options(s, options) {
         assert!(x.is_valid());
         assert!(!s.ends_with('+'));
         assert!(!s.ends_with('-'));
@@ -953,7 +974,7 @@ fn string_precision(s: &str, base: u8) -> u64 {
 fn from_sci_string_simplest_helper(s: &str) {
     let mut from_options = FromSciStringOptions::default();
     from_options.set_base(10);
-    if let Some(x) = Rational::from_sci_string_simplest(s) {
+    if let Ok(x) = Rational::from_sci_string_simplest(s) {
         assert!(x.is_valid());
         if x != 0u32 {
             let mut options = ToSciOptions::default();
@@ -1000,7 +1021,7 @@ fn from_sci_string_simplest_properties() {
 }
 
 fn from_sci_string_simplest_with_options_helper(s: &str, options: FromSciStringOptions) {
-    if let Some(x) = Rational::from_sci_string_simplest_with_options(s, options) {
+    if let Ok(x) = Rational::from_sci_string_simplest_with_options(s, options) {
         assert!(x.is_valid());
         assert!(!s.ends_with('+'));
         assert!(!s.ends_with('-'));
@@ -1,3 +1,4 @@
+use malachite_base::num::conversion::string::parse_error::ParseNumberErrorKind;
 use malachite_base::test_util::generators::common::GenConfig;
 use malachite_base::test_util::generators::string_gen;
 use malachite_nz::integer::Integer;
@@ -23,20 +24,27 @@ fn test_from_str() {
     test_ok("01/02", "1/2");
     test_ok("3/21", "1/7");
 
-    let test_err = |s, rug_err| {
-        assert!(Rational::from_str(s).is_err());
+    let test_err = |s, kind, rug_err| {
+        assert_eq!(Rational::from_str(s).unwrap_err().kind(), kind);
         assert!(BigRational::from_str(s).is_err());
         let rn = rug::Rational::from_str(s);
         assert_eq!(rn.is_err() || rn.unwrap() < 0, rug_err);
     };
-    test_err("12A", true);
-    test_err(" 10", false);
-    test_err("1.0", true);
-    test_err("$%^", true);
-    test_err("", true);
-    test_err("-", true);
-    test_err("1/0", true);
-    test_err("/1", true);
+    test_err("12A", ParseNumberErrorKind::InvalidDigit(2), true);
+    test_err(" 10", ParseNumberErrorKind::InvalidDigit(0), false);
+    test_err("1.0", ParseNumberErrorKind::InvalidDigit(1), true);
+    test_err("$%^", ParseNumberErrorKind::InvalidDigit(0), true);
+    test_err("", ParseNumberErrorKind::Empty, true);
+    test_err("-", ParseNumberErrorKind::Empty, true);
+    test_err("1/0", ParseNumberErrorKind::ZeroDenominator, true);
+    test_err("/1", ParseNumberErrorKind::Empty, true);
+    test_err("-12/3A", ParseNumberErrorKind::InvalidDigit(5), true);
+    test_err("--1", ParseNumberErrorKind::SignNotAllowed, true);
+    // num accepts a signed denominator
+    assert_eq!(
+        Rational::from_str("1/-2").unwrap_err().kind(),
+        ParseNumberErrorKind::SignNotAllowed
+    );
 }
 
 #[allow(unused_must_use)]
@@ -0,0 +1,199 @@
+use malachite_base::num::arithmetic::traits::{Abs, Pow};
+use malachite_base::num::conversion::string::options::{DigitGrouping, FormatOptions};
+use malachite_base::num::conversion::traits::{FromSciString, ToFormattedString};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::test_util::generators::integer_gen;
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::Rational;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+pub fn test_to_formatted_string() {
+    fn test(s: &str, options: FormatOptions, out: &str) {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(x.to_formatted_string(options), out);
+    }
+    let mut options = FormatOptions::default();
+    test("0", options, "0");
+    test("123", options, "123");
+    test("-123", options, "-123");
+    test("1/8", options, "0.125");
+    test("-1/8", options, "-0.125");
+    test("-123456789/1000", options, "-123456.789");
+    test("1/1024", options, "0.0009765625");
+    test("1/3", options, "0.33333333333333333333");
+    test("-2/3", options, "-0.66666666666666666667");
+    test("1/7", options, "0.14285714285714285714");
+    test("1000000/3", options, "333333.33333333333333333333");
+    options.set_rounding_mode(RoundingMode::Floor);
+    test("-2/3", options, "-0.66666666666666666667");
+    test("1/8", options, "0.125");
+    options.set_rounding_mode(RoundingMode::Down);
+    test("-2/3", options, "-0.66666666666666666666");
+    options.set_rounding_mode(RoundingMode::Nearest);
+
+    options.set_digit_grouping(DigitGrouping::Uniform(3));
+    test("-123456789/1000", options, "-123,456.789");
+    test(
+        "1000000000000000000001/10",
+        options,
+        "100,000,000,000,000,000,000.1",
+    );
+
+    options.set_fraction_digits(2);
+    test("0", options, "0.00");
+    test("1/3", options, "0.33");
+    test("2/3", options, "0.67");
+    test("-2/3", options, "-0.67");
+    test("-1/1000", options, "0.00");
+    test("1/200", options, "0.00");
+    test("3/200", options, "0.02");
+    test("-123456789/1000", options, "-123,456.79");
+    test("999999/1000", options, "1,000.00");
+
+    options.set_rounding_mode(RoundingMode::Floor);
+    test("2/3", options, "0.66");
+    test("-1/1000", options, "-0.01");
+    test("-123456789/1000", options, "-123,456.79");
+    options.set_rounding_mode(RoundingMode::Ceiling);
+    test("-123456789/1000", options, "-123,456.78");
+    test("1/1000", options, "0.01");
+    options.set_rounding_mode(RoundingMode::Down);
+    test("-2/3", options, "-0.66");
+    options.set_rounding_mode(RoundingMode::Up);
+    test("-1/1000", options, "-0.01");
+
+    options.set_rounding_mode(RoundingMode::Nearest);
+    options.set_fraction_digits(0);
+    test("5/2", options, "2");
+    test("7/2", options, "4");
+    test("-1/3", options, "0");
+    test("2000000001/2", options, "1,000,000,000");
+
+    options.set_fraction_digits(5);
+    test("-123456789/1000", options, "-123,456.78900");
+
+    let mut options = FormatOptions::default();
+    options.set_digit_grouping(DigitGrouping::Indian);
+    options.set_group_separator('.');
+    options.set_decimal_separator(',');
+    options.set_fraction_digits(4);
+    test("1000000000/3", options, "33.33.33.333,3333");
+
+    let mut options = FormatOptions::default();
+    options.set_base(16);
+    options.set_uppercase();
+    options.set_digit_grouping(DigitGrouping::Uniform(4));
+    options.set_group_separator('_');
+    test("-1048577/16", options, "-1_0000.1");
+    options.set_fraction_digits(3);
+    test("1/3", options, "0.555");
+
+    let mut options = FormatOptions::default();
+    options.set_base(3);
+    test("1/3", options, "0.1");
+    test("-5/9", options, "-0.12");
+    test("1/2", options, "0.11111111111111111111");
+
+    let mut options = FormatOptions::default();
+    options.set_fraction_digits_exact();
+    test("1/8", options, "0.125");
+    test("-123456789/1000", options, "-123456.789");
+    options.set_fraction_digits_exact_or(5);
+    test("1/8", options, "0.125");
+    test("2/3", options, "0.66667");
+    test("1/1024", options, "0.0009765625");
+    options.set_fraction_digits_exact_or(0);
+    test("2/3", options, "1");
+}
+
+#[test]
+fn to_formatted_string_fail() {
+    let mut options = FormatOptions::default();
+    options.set_rounding_mode(RoundingMode::Exact);
+    assert_panic!(Rational::from_signeds(1, 3).to_formatted_string(options));
+    let mut options = FormatOptions::default();
+    options.set_fraction_digits(1);
+    options.set_rounding_mode(RoundingMode::Exact);
+    assert_panic!(Rational::from_signeds(1, 4).to_formatted_string(options));
+    let mut options = FormatOptions::default();
+    options.set_fraction_digits_exact();
+    assert_panic!(Rational::from_signeds(1, 3).to_formatted_string(options));
+}
+
+#[test]
+fn to_formatted_string_properties() {
+    rational_gen().test_properties(|x| {
+        let mut options = FormatOptions::default();
+        for n in [0, 1, 5] {
+            options.set_fraction_digits(n);
+            let ulp = Rational::from(10u32).pow(-i64::try_from(n).unwrap());
+            options.set_rounding_mode(RoundingMode::Nearest);
+            let s = x.to_formatted_string(options);
+            let nearest = Rational::from_sci_string(&s).unwrap();
+            assert!((&nearest - &x).abs() * Rational::from(2u32) <= ulp);
+            assert_eq!(
+                s.find('.').map_or(0, |i| s.len() - i - 1),
+                usize::try_from(n).unwrap()
+            );
+
+            options.set_rounding_mode(RoundingMode::Floor);
+            let floor = Rational::from_sci_string(&x.to_formatted_string(options)).unwrap();
+            options.set_rounding_mode(RoundingMode::Ceiling);
+            let ceiling = Rational::from_sci_string(&x.to_formatted_string(options)).unwrap();
+            assert!(floor <= x);
+            assert!(ceiling >= x);
+            assert!(&ceiling - &floor <= ulp);
+
+            options.set_digit_grouping(DigitGrouping::Uniform(3));
+            assert_eq!(x.to_formatted_string(options).replace(',', ""), {
+                let mut o = options;
+                o.set_digit_grouping(DigitGrouping::None);
+                x.to_formatted_string(o)
+            });
+            options.set_digit_grouping(DigitGrouping::None);
+        }
+
+        let mut options = FormatOptions::default();
+        options.set_base(2);
+        options.set_fraction_digits(3);
+        let neg_s = (-&x).to_formatted_string(options);
+        let s = x.to_formatted_string(options);
+        if let Some(abs_s) = s.strip_prefix('-') {
+            assert_eq!(abs_s, neg_s);
+        } else if let Some(abs_neg_s) = neg_s.strip_prefix('-') {
+            assert_eq!(abs_neg_s, s);
+        } else {
+            assert_eq!(s, neg_s);
+        }
+    });
+
+    integer_gen().test_properties(|x| {
+        let mut options = FormatOptions::default();
+        options.set_digit_grouping(DigitGrouping::Uniform(3));
+        assert_eq!(
+            Rational::from(&x).to_formatted_string(options),
+            x.to_formatted_string(options)
+        );
+        options.set_fraction_digits(2);
+        assert_eq!(
+            Rational::from(&x).to_formatted_string(options),
+            x.to_formatted_string(options)
+        );
+    });
+
+    rational_gen().test_properties(|x| {
+        let mut options = FormatOptions::default();
+        let s = x.to_formatted_string(options);
+        if let Some(n) = x.length_after_point_in_small_base(10) {
+            assert_eq!(Rational::from_sci_string(&s).unwrap(), x);
+            options.set_fraction_digits(n);
+            options.set_rounding_mode(RoundingMode::Exact);
+            assert_eq!(x.to_formatted_string(options), s);
+        } else {
+            options.set_fraction_digits(FormatOptions::NON_TERMINATING_FRACTION_DIGITS);
+            assert_eq!(x.to_formatted_string(options), s);
+        }
+    });
+}
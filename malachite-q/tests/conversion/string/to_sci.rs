@@ -1012,3 +1012,64 @@ fn to_sci_with_options_properties() {
         );
     });
 }
+
+#[test]
+pub fn test_exp() {
+    fn test(s: &str, out: &str, out_p2: &str) {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(format!("{x:e}"), out);
+        assert_eq!(format!("{x:E}"), out.to_uppercase());
+        assert_eq!(format!("{x:.2e}"), out_p2);
+    }
+    test("0", "0e0", "0.00e0");
+    test("1", "1e0", "1.00e0");
+    test("-1000", "-1e3", "-1.00e3");
+    test("1/2", "5e-1", "5.00e-1");
+    test("-1/1024", "-9.765625e-4", "-9.77e-4");
+    test("1/3", "3.333333333333333e-1", "3.33e-1");
+    test("-2/3", "-6.666666666666667e-1", "-6.67e-1");
+    test("22/7", "3.142857142857143e0", "3.14e0");
+    test("999/1000", "9.99e-1", "9.99e-1");
+    test("9999/10000", "9.999e-1", "1.00e0");
+    test("1/1000000000000", "1e-12", "1.00e-12");
+    test(
+        "1000000000000000000000000/3",
+        "3.333333333333333e23",
+        "3.33e23",
+    );
+
+    let x = Rational::from_signeds(-22, 7);
+    assert_eq!(format!("{x:>12.3e}"), "    -3.143e0");
+    assert_eq!(format!("{x:*<12.3e}"), "-3.143e0****");
+    assert_eq!(format!("{x:012.3e}"), "-00003.143e0");
+    assert_eq!(format!("{:+.1E}", -x), "+3.1E0");
+}
+
+#[test]
+fn exp_properties() {
+    rational_gen().test_properties(|x| {
+        let s = format!("{x:e}");
+        assert_eq!(format!("{x:E}"), s.to_uppercase());
+        assert!(string_is_subset(&s, DECIMAL_SCI_STRING_CHARS));
+        assert_eq!(s.starts_with('-'), x < 0u32);
+        let i = s.find('e').unwrap();
+        assert!(i == 1 || i == 2 && s.starts_with('-') || s[..i].contains('.'));
+        if x.length_after_point_in_small_base(10).is_some() {
+            assert_eq!(Rational::from_sci_string(&s).unwrap(), x);
+        }
+        for p in 0..5 {
+            let s = format!("{x:.p$e}");
+            let mut options = ToSciOptions::default();
+            options.set_precision(u64::exact_from(p) + 1);
+            assert_eq!(
+                Rational::from_sci_string(&s).unwrap(),
+                Rational::from_sci_string(&x.to_sci_with_options(options).to_string()).unwrap()
+            );
+        }
+    });
+
+    integer_gen().test_properties(|x| {
+        assert_eq!(format!("{:e}", Rational::from(&x)), format!("{x:e}"));
+        assert_eq!(format!("{:.3E}", Rational::from(&x)), format!("{x:.3E}"));
+    });
+}
@@ -1,9 +1,10 @@
 use malachite_base::strings::string_is_subset;
 use malachite_base::strings::ToDebugString;
 use malachite_nz::test_util::generators::integer_gen;
-use malachite_q::test_util::generators::rational_gen;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_1};
 use malachite_q::Rational;
 use num::BigRational;
+use std::cmp::max;
 use std::str::FromStr;
 
 #[test]
@@ -32,6 +33,23 @@ pub fn test_to_string() {
     test("-22/7");
 }
 
+#[test]
+pub fn test_fmt_flags() {
+    let x = Rational::from_signeds(-22, 7);
+    assert_eq!(format!("{x:>8}"), "   -22/7");
+    assert_eq!(format!("{x:*<8}"), "-22/7***");
+    assert_eq!(format!("{x:^9}"), "  -22/7  ");
+    assert_eq!(format!("{x:08}"), "-00022/7");
+    assert_eq!(format!("{x:+}"), "-22/7");
+    let x = Rational::from_signeds(22, 7);
+    assert_eq!(format!("{x:+}"), "+22/7");
+    assert_eq!(format!("{x:+08}"), "+00022/7");
+    assert_eq!(format!("{x:<+8}|"), "+22/7   |");
+    let x = Rational::from(-123);
+    assert_eq!(format!("{x:>8}"), "    -123");
+    assert_eq!(format!("{x:08}"), "-0000123");
+}
+
 #[test]
 fn to_string_properties() {
     rational_gen().test_properties(|x| {
@@ -48,4 +66,20 @@ fn to_string_properties() {
     integer_gen().test_properties(|x| {
         assert_eq!(Rational::from(&x).to_string(), x.to_string());
     });
+
+    rational_unsigned_pair_gen_var_1::<usize>().test_properties(|(x, width)| {
+        let s = x.to_string();
+        let s_padded = format!("{x:>width$}");
+        assert_eq!(s_padded.trim_start(), s);
+        assert_eq!(s_padded.len(), max(s.len(), width));
+        assert_eq!(format!("{x:<width$}").trim_end(), s);
+        assert_eq!(format!("{x:^width$}").trim(), s);
+        let s_zero_padded = format!("{x:0width$}");
+        assert_eq!(s_zero_padded.len(), s_padded.len());
+        assert_eq!(Rational::from_str(&s_zero_padded).unwrap(), x);
+        assert_eq!(
+            Rational::from_str(format!("{x:+}").trim_start_matches('+')).unwrap(),
+            x
+        );
+    });
 }
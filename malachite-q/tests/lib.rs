@@ -58,9 +58,11 @@ pub mod arithmetic {
     pub mod log_base;
     pub mod log_base_2;
     pub mod log_base_power_of_2;
+    pub mod mod_op;
     pub mod mul;
     pub mod neg;
     pub mod next_power_of_2;
+    pub mod p_adic_valuation;
     pub mod pow;
     pub mod power_of_2;
     pub mod reciprocal;
@@ -103,6 +105,10 @@ pub mod comparison {
     pub mod partial_eq_primitive_float;
     pub mod partial_eq_primitive_int;
 }
+pub mod complex {
+    pub mod arithmetic;
+    pub mod conversion;
+}
 pub mod conversion {
     pub mod clone;
     pub mod continued_fraction {
@@ -133,8 +139,10 @@ pub mod conversion {
     pub mod sci_mantissa_and_exponent;
     pub mod serde;
     pub mod string {
+        pub mod from_literal;
         pub mod from_sci_string;
         pub mod from_string;
+        pub mod to_formatted_string;
         pub mod to_sci;
         pub mod to_string;
     }
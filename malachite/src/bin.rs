@@ -0,0 +1,422 @@
+//! An interactive calculator for arbitrary-precision numbers.
+//!
+//! Each line is either an expression, an assignment such as `x = 2^64 + 1`, or a command starting
+//! with `:`; type `:help` for a list of commands. See [`malachite::expression`] for the expression
+//! syntax. Every result is stored in the history, and can be referred to as `_` (the most recent
+//! result) or `_1`, `_2`, and so on.
+//!
+//! Exact results are shown as integers or fractions; results that involve irrational operations
+//! such as `sqrt` are computed as `Float`s with the working precision and rounding mode. When
+//! evaluating or formatting a result takes a long time, the time is printed.
+//!
+//! With no arguments, lines are read from standard input. Otherwise, each argument is executed as
+//! a line, and the process exits with a nonzero status if any of them fails.
+//!
+//! ```text
+//! $ malachite
+//! > (2^127 - 1) mod 10!
+//! _1 = 3427327
+//! > :precision 100
+//! > x = sqrt(2)
+//! x = 1.414213562373095048801688724209
+//! > :base 16
+//! > _1 * 2
+//! _3 = 6897fe
+//! ```
+
+use malachite::expression::{EvaluationOptions, Expression, ExpressionError, Value};
+use malachite::num::conversion::string::options::{SciSizeOptions, ToSciOptions};
+use malachite::num::conversion::traits::{IsInteger, ToSci, ToStringBase};
+use malachite::rounding_modes::RoundingMode;
+use malachite::{Integer, Rational};
+use std::collections::HashMap;
+use std::io::{stdin, stdout, BufRead, Write};
+use std::process::exit;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+// In automatic timing mode, times are only printed when evaluating and formatting a result takes
+// at least this long.
+const TIMING_THRESHOLD: Duration = Duration::from_millis(100);
+
+// The number of significant digits used when a value can't be shown completely in scientific
+// notation, such as 1/3 in base 10.
+const FALLBACK_DIGITS: u64 = 20;
+
+const HELP: &str = "\
+Expressions:
+  2^127 - 1, 100!, binomial(100, 50) / 3, sqrt(2), gcd(a, b), ...
+  x = <expression>         assign a variable
+  _, _1, _2, ...           refer to previous results
+Commands:
+  :help                    show this message
+  :quit                    exit
+  :vars                    list variables
+  :history                 list previous results
+  :settings                show the current settings
+  :base <2-36>             set the output base
+  :notation exact|sci      show exact values as integers and fractions, or in scientific notation
+  :digits <n>              show n significant digits in scientific notation
+  :scale <n>               show n digits after the point in scientific notation
+  :complete                show all digits in scientific notation, when possible
+  :threshold <n>           use negative exponents for values below base^n, where n < 0
+  :precision <bits>        set the precision of approximate values
+  :round <mode>            set the rounding mode: Down, Up, Floor, Ceiling, Nearest, ...
+  :timing auto|always|never
+  :limit <n>|none          abbreviate results longer than n characters";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Timing {
+    Auto,
+    Always,
+    Never,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Notation {
+    Exact,
+    Sci,
+}
+
+struct Session {
+    evaluation_options: EvaluationOptions,
+    sci_options: ToSciOptions,
+    notation: Notation,
+    timing: Timing,
+    limit: Option<usize>,
+    variables: HashMap<String, Value>,
+    history: Vec<String>,
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars
+        .next()
+        .map_or(false, |c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+// Renders an expression error, with carets under the part of the line that caused it.
+fn render_error(line: &str, offset: usize, e: &ExpressionError) -> String {
+    let span = e.span();
+    let start = offset + span.start;
+    let end = offset + span.end;
+    format!(
+        "error: {}\n  {}\n  {}{}",
+        e.kind(),
+        line,
+        " ".repeat(line[..start].chars().count()),
+        "^".repeat(line[start..end].chars().count().max(1))
+    )
+}
+
+fn parse_argument<T: FromStr>(command: &str, argument: Option<&str>) -> Result<T, String> {
+    argument
+        .and_then(|s| T::from_str(s).ok())
+        .ok_or_else(|| format!("invalid or missing argument to :{command}; see :help"))
+}
+
+impl Session {
+    fn new() -> Session {
+        Session {
+            evaluation_options: EvaluationOptions::default(),
+            sci_options: ToSciOptions::default(),
+            notation: Notation::Exact,
+            timing: Timing::Auto,
+            limit: Some(1000),
+            variables: HashMap::new(),
+            history: Vec::new(),
+        }
+    }
+
+    fn base(&self) -> u8 {
+        self.sci_options.get_base()
+    }
+
+    fn format_sci(&self, x: &Rational, mut options: ToSciOptions) -> String {
+        if !x.fmt_sci_valid(options) {
+            options.set_precision(FALLBACK_DIGITS);
+        }
+        x.to_sci_with_options(options).to_string()
+    }
+
+    fn format_value(&self, value: &Value) -> String {
+        let base = self.base();
+        match (value, self.notation) {
+            (Value::Exact(x), Notation::Exact) => {
+                if x.is_integer() {
+                    Integer::try_from(x).unwrap().to_string_base(base)
+                } else {
+                    let (n, d) = x.numerator_and_denominator_ref();
+                    format!(
+                        "{}{}/{}",
+                        if *x < 0u32 { "-" } else { "" },
+                        n.to_string_base(base),
+                        d.to_string_base(base)
+                    )
+                }
+            }
+            (Value::Exact(x), Notation::Sci) => self.format_sci(x, self.sci_options),
+            (Value::Approximate(x), notation) => match Rational::try_from(x) {
+                Ok(q) if notation == Notation::Sci || base != 10 => {
+                    let mut options = self.sci_options;
+                    if notation == Notation::Exact
+                        || options.get_size_options() == SciSizeOptions::Complete
+                    {
+                        // Show about as many digits as the precision justifies
+                        let prec = self.evaluation_options.get_precision() as f64;
+                        options.set_precision((prec / f64::from(base).log2()).ceil() as u64);
+                    }
+                    self.format_sci(&q, options)
+                }
+                _ => x.to_string(),
+            },
+        }
+    }
+
+    fn abbreviate(&self, s: String) -> String {
+        match self.limit {
+            Some(limit) if s.len() > limit => {
+                let half = limit >> 1;
+                format!(
+                    "{}...{} ({} characters)",
+                    &s[..half],
+                    &s[s.len() - half..],
+                    s.len()
+                )
+            }
+            _ => s,
+        }
+    }
+
+    fn evaluate(&mut self, line: &str, name: Option<&str>, offset: usize) -> Result<(), String> {
+        let source = &line[offset..];
+        let start = Instant::now();
+        let value = Expression::from_str(source)
+            .and_then(|e| e.evaluate_with_variables(self.evaluation_options, &self.variables))
+            .map_err(|e| render_error(line, offset, &e))?;
+        let evaluated = start.elapsed();
+        let start = Instant::now();
+        let s = self.format_value(&value);
+        let formatted = start.elapsed();
+        self.history.push(line.trim().to_string());
+        let index = format!("_{}", self.history.len());
+        self.variables.insert("_".to_string(), value.clone());
+        self.variables.insert(index.clone(), value.clone());
+        let name = match name {
+            Some(name) => {
+                self.variables.insert(name.to_string(), value);
+                name
+            }
+            None => &index,
+        };
+        println!("{} = {}", name, self.abbreviate(s));
+        if self.timing == Timing::Always
+            || self.timing == Timing::Auto && evaluated + formatted >= TIMING_THRESHOLD
+        {
+            println!("(evaluated in {evaluated:.3?}, formatted in {formatted:.3?})");
+        }
+        Ok(())
+    }
+
+    fn print_settings(&self) {
+        let sci = &self.sci_options;
+        println!("base: {}", sci.get_base());
+        println!(
+            "notation: {}",
+            match self.notation {
+                Notation::Exact => "exact",
+                Notation::Sci => "sci",
+            }
+        );
+        println!(
+            "size: {}",
+            match sci.get_size_options() {
+                SciSizeOptions::Complete => "complete".to_string(),
+                SciSizeOptions::Precision(p) => format!("{p} digits"),
+                SciSizeOptions::Scale(s) => format!("scale {s}"),
+            }
+        );
+        println!("threshold: {}", sci.get_neg_exp_threshold());
+        println!("precision: {}", self.evaluation_options.get_precision());
+        println!(
+            "rounding mode: {}",
+            self.evaluation_options.get_rounding_mode()
+        );
+        println!("timing: {:?}", self.timing);
+        match self.limit {
+            Some(limit) => println!("limit: {limit}"),
+            None => println!("limit: none"),
+        }
+    }
+
+    // Returns `false` if the session should end.
+    fn command(&mut self, line: &str) -> Result<bool, String> {
+        let mut words = line[1..].split_whitespace();
+        let command = words.next().unwrap_or("");
+        let argument = words.next();
+        if words.next().is_some() {
+            return Err(format!("too many arguments to :{command}"));
+        }
+        match command {
+            "help" => println!("{HELP}"),
+            "quit" | "exit" | "q" => return Ok(false),
+            "vars" => {
+                let mut names: Vec<_> = self
+                    .variables
+                    .keys()
+                    .filter(|name| !name.starts_with('_'))
+                    .collect();
+                names.sort();
+                for name in names {
+                    println!(
+                        "{} = {}",
+                        name,
+                        self.abbreviate(self.format_value(&self.variables[name]))
+                    );
+                }
+            }
+            "history" => {
+                for (i, line) in self.history.iter().enumerate() {
+                    println!("_{}: {}", i + 1, line);
+                }
+            }
+            "settings" => self.print_settings(),
+            "base" => {
+                let base: u8 = parse_argument(command, argument)?;
+                if !(2..=36).contains(&base) {
+                    return Err("the base must be between 2 and 36".to_string());
+                }
+                self.sci_options.set_base(base);
+            }
+            "notation" => {
+                self.notation = match argument {
+                    Some("exact") => Notation::Exact,
+                    Some("sci") => Notation::Sci,
+                    _ => return Err("expected :notation exact or :notation sci".to_string()),
+                }
+            }
+            "digits" => {
+                let digits: u64 = parse_argument(command, argument)?;
+                if digits == 0 {
+                    return Err("the number of digits must be positive".to_string());
+                }
+                self.sci_options.set_precision(digits);
+            }
+            "scale" => self
+                .sci_options
+                .set_scale(parse_argument(command, argument)?),
+            "complete" => self.sci_options.set_size_complete(),
+            "threshold" => {
+                let threshold: i64 = parse_argument(command, argument)?;
+                if threshold >= 0 {
+                    return Err("the threshold must be negative".to_string());
+                }
+                self.sci_options.set_neg_exp_threshold(threshold);
+            }
+            "precision" => {
+                let prec: u64 = parse_argument(command, argument)?;
+                if prec == 0 {
+                    return Err("the precision must be positive".to_string());
+                }
+                self.evaluation_options.set_precision(prec);
+            }
+            "round" => {
+                let rm: RoundingMode = parse_argument(command, argument)?;
+                if rm == RoundingMode::Exact {
+                    return Err("approximate values can't be rounded with Exact".to_string());
+                }
+                self.evaluation_options.set_rounding_mode(rm);
+                self.sci_options.set_rounding_mode(rm);
+            }
+            "timing" => {
+                self.timing = match argument {
+                    Some("auto") => Timing::Auto,
+                    Some("always") => Timing::Always,
+                    Some("never") => Timing::Never,
+                    _ => return Err("expected :timing auto, always, or never".to_string()),
+                }
+            }
+            "limit" => {
+                self.limit = match argument {
+                    Some("none") => None,
+                    _ => Some(parse_argument(command, argument)?),
+                }
+            }
+            _ => return Err(format!("unknown command :{command}; see :help")),
+        }
+        Ok(true)
+    }
+
+    // Returns `false` if the session should end.
+    fn execute(&mut self, line: &str) -> Result<bool, String> {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            Ok(true)
+        } else if trimmed.starts_with(':') {
+            self.command(trimmed)
+        } else {
+            match line.find('=') {
+                Some(i) if is_identifier(line[..i].trim()) => {
+                    let name = line[..i].trim();
+                    if name.starts_with('_') {
+                        return Err(format!(
+                            "can't assign to {name}; names starting with _ are reserved for \
+                            results"
+                        ));
+                    }
+                    if name == "mod" {
+                        return Err("can't assign to mod".to_string());
+                    }
+                    self.evaluate(line, Some(name), i + 1)?;
+                }
+                _ => self.evaluate(line, None, 0)?,
+            }
+            Ok(true)
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let mut session = Session::new();
+    if !args.is_empty() {
+        let mut success = true;
+        for arg in &args {
+            match session.execute(arg) {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(e) => {
+                    eprintln!("{e}");
+                    success = false;
+                }
+            }
+        }
+        exit(if success { 0 } else { 1 });
+    }
+    println!(
+        "malachite {}; type :help for help",
+        env!("CARGO_PKG_VERSION")
+    );
+    let stdin = stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("> ");
+        stdout().flush().unwrap();
+        match lines.next() {
+            Some(Ok(line)) => match session.execute(&line) {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(e) => eprintln!("{e}"),
+            },
+            Some(Err(e)) => {
+                eprintln!("error: {e}");
+                exit(1);
+            }
+            None => {
+                println!();
+                break;
+            }
+        }
+    }
+}
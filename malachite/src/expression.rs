@@ -0,0 +1,945 @@
+//! Parsing and evaluating arithmetic expressions, such as `"(2^127 - 1) mod 10!"`.
+//!
+//! An [`Expression`] is parsed from a string once and can then be evaluated any number of times,
+//! possibly with different [`EvaluationOptions`] and variables. Evaluation is exact whenever
+//! possible: numbers are [`Rational`]s, and integer operations are carried out on [`Natural`]s
+//! and [`Integer`]s. Only operations whose results are irrational, such as `sqrt(2)`, produce
+//! approximate [`Float`] values, which are correctly rounded to the working precision. Any
+//! operation with an approximate operand produces an approximate result, which is rounded again.
+//!
+//! # Syntax
+//! From lowest to highest precedence:
+//! - `a + b` and `a - b`;
+//! - `a * b`, `a / b`, and `a mod b` (or `a % b`), where `mod` takes two integers and gives a
+//!   result with the sign of `b`, like [`Mod`];
+//! - unary `-a` and `+a`;
+//! - `a ^ b`, which is right-associative and requires `b` to be an integer, so that
+//!   `-2 ^ 2 ^ 3` means $-(2^{(2^3)})$;
+//! - `a!`, the factorial of a non-negative integer.
+//!
+//! Numbers may be decimal, possibly with a fractional part and an exponent, as in `1.5e-3`, or
+//! Rust-style binary, octal, or hexadecimal literals, as in `0xff`. Digits may be separated by
+//! underscores. Variables are identifiers that are not followed by `(`. The following functions
+//! are available:
+//!
+//! | function           | result                                        | trait                   |
+//! |--------------------|-----------------------------------------------|-------------------------|
+//! | `abs(x)`           | $\|x\|$                                       | [`Abs`]                 |
+//! | `binomial(n, k)`   | $\binom{n}{k}$, for integers $n$ and $k\geq 0$ | [`BinomialCoefficient`] |
+//! | `ceiling(x)`       | $\lceil x \rceil$                             | [`Ceiling`]             |
+//! | `factorial(n)`     | $n!$                                          | [`Factorial`]           |
+//! | `floor(x)`         | $\lfloor x \rfloor$                           | [`Floor`]               |
+//! | `gcd(a, b)`        | $\gcd(a, b)$, for integers                    | [`Gcd`]                 |
+//! | `isqrt(n)`         | $\lfloor\sqrt{n}\rfloor$, for integers $n\geq 0$ | [`FloorSqrt`]        |
+//! | `lcm(a, b)`        | $\operatorname{lcm}(a, b)$, for integers      | [`Lcm`]                 |
+//! | `primorial(n)`     | $n\\#$                                        | [`Primorial`]           |
+//! | `sqrt(x)`          | $\sqrt{x}$, exact if $x$ is a square          | [`CheckedSqrt`]         |
+//!
+//! # Errors
+//! Parsing and evaluation errors are reported as [`ExpressionError`]s, which contain the byte
+//! range of the part of the string that caused the error.
+//!
+//! # Examples
+//! ```
+//! use malachite::expression::{EvaluationOptions, Expression, ExpressionErrorKind, Value};
+//! use malachite::Rational;
+//! use std::str::FromStr;
+//!
+//! let options = EvaluationOptions::default();
+//! let value = |s: &str| Expression::from_str(s).unwrap().evaluate(options).unwrap();
+//! assert_eq!(value("(2^127 - 1) mod 10!").to_string(), "3427327");
+//! assert_eq!(
+//!     value("binomial(100, 50) / 3").to_string(),
+//!     "33630448181854731111604165752"
+//! );
+//! assert_eq!(value("-2^-2"), Value::Exact(Rational::from_signeds(-1, 4)));
+//! assert_eq!(value("sqrt(16/9)").to_string(), "4/3");
+//! assert_eq!(value("sqrt(2)").to_string(), "1.4142135623730950488");
+//!
+//! let mut options = EvaluationOptions::default();
+//! options.set_precision(10);
+//! let sqrt_2 = Expression::from_str("sqrt(2)").unwrap();
+//! assert_eq!(sqrt_2.evaluate(options).unwrap().to_string(), "1.414");
+//!
+//! let error = Expression::from_str("1 + (2 * 3").unwrap_err();
+//! assert_eq!(*error.kind(), ExpressionErrorKind::UnexpectedEnd);
+//! assert_eq!(error.span(), 10..10);
+//!
+//! let error = Expression::from_str("5 / (3 - 3)")
+//!     .unwrap()
+//!     .evaluate(options)
+//!     .unwrap_err();
+//! assert_eq!(*error.kind(), ExpressionErrorKind::DivisionByZero);
+//! assert_eq!(error.span(), 4..11);
+//! assert_eq!(error.to_string(), "division by zero at bytes 4..11");
+//! ```
+
+use malachite_base::num::arithmetic::traits::{
+    Abs, BinomialCoefficient, Ceiling, CheckedSqrt, DivMod, Factorial, Floor, FloorSqrt, Gcd, Lcm,
+    Mod, Pow, Primorial, SqrtRem, UnsignedAbs,
+};
+use malachite_base::num::conversion::traits::{FromSciString, IsInteger};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::Float;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+use std::cmp::max;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+use std::str::FromStr;
+
+/// The reason that an expression couldn't be parsed or evaluated.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExpressionErrorKind {
+    /// A number literal is malformed, as in `"1.2.3"` or `"0xg"`.
+    InvalidNumber,
+    /// A character that can't begin a token was found.
+    UnexpectedCharacter(char),
+    /// The expression ended where an operand or a closing parenthesis was expected.
+    UnexpectedEnd,
+    /// A token was found where it isn't allowed, as in `"1 + * 2"` or `"(1))"`.
+    UnexpectedToken,
+    /// A function with the given name doesn't exist.
+    UnknownFunction(String),
+    /// A variable with the given name hasn't been defined.
+    UnknownVariable(String),
+    /// A function was called with the wrong number of arguments.
+    WrongArgumentCount { expected: usize, found: usize },
+    /// A number was divided by zero, or zero was raised to a negative power.
+    DivisionByZero,
+    /// An operation that requires an integer was given a non-integer.
+    NotAnInteger,
+    /// An operation that requires a non-negative number was given a negative number.
+    Negative,
+    /// An exponent or an argument of a factorial or primorial is too large.
+    TooLarge,
+}
+
+impl Display for ExpressionErrorKind {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ExpressionErrorKind::InvalidNumber => f.write_str("invalid number"),
+            ExpressionErrorKind::UnexpectedCharacter(c) => write!(f, "unexpected character {c:?}"),
+            ExpressionErrorKind::UnexpectedEnd => f.write_str("unexpected end of expression"),
+            ExpressionErrorKind::UnexpectedToken => f.write_str("unexpected token"),
+            ExpressionErrorKind::UnknownFunction(name) => write!(f, "unknown function {name:?}"),
+            ExpressionErrorKind::UnknownVariable(name) => write!(f, "unknown variable {name:?}"),
+            ExpressionErrorKind::WrongArgumentCount { expected, found } => {
+                write!(f, "expected {expected} argument(s), found {found}")
+            }
+            ExpressionErrorKind::DivisionByZero => f.write_str("division by zero"),
+            ExpressionErrorKind::NotAnInteger => f.write_str("expected an integer"),
+            ExpressionErrorKind::Negative => f.write_str("expected a non-negative number"),
+            ExpressionErrorKind::TooLarge => f.write_str("number too large"),
+        }
+    }
+}
+
+/// The error returned when an expression can't be parsed or evaluated.
+///
+/// The error contains an [`ExpressionErrorKind`] and the byte range of the part of the
+/// expression's string that caused it. The range is empty if the string ended unexpectedly.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExpressionError {
+    kind: ExpressionErrorKind,
+    span: Range<usize>,
+}
+
+impl ExpressionError {
+    const fn new(kind: ExpressionErrorKind, span: Range<usize>) -> ExpressionError {
+        ExpressionError { kind, span }
+    }
+
+    /// Returns the reason for the error.
+    pub const fn kind(&self) -> &ExpressionErrorKind {
+        &self.kind
+    }
+
+    /// Returns the byte range of the part of the expression's string that caused the error.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+impl Display for ExpressionError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{} at bytes {}..{}",
+            self.kind, self.span.start, self.span.end
+        )
+    }
+}
+
+impl std::error::Error for ExpressionError {}
+
+/// The result of evaluating an expression.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// An exact value.
+    Exact(Rational),
+    /// An approximate value, rounded to the working precision.
+    Approximate(Float),
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Value::Exact(x) => Display::fmt(x, f),
+            Value::Approximate(x) => Display::fmt(x, f),
+        }
+    }
+}
+
+impl From<Natural> for Value {
+    #[inline]
+    fn from(x: Natural) -> Value {
+        Value::Exact(Rational::from(x))
+    }
+}
+
+impl From<Integer> for Value {
+    #[inline]
+    fn from(x: Integer) -> Value {
+        Value::Exact(Rational::from(x))
+    }
+}
+
+impl From<Rational> for Value {
+    #[inline]
+    fn from(x: Rational) -> Value {
+        Value::Exact(x)
+    }
+}
+
+impl From<Float> for Value {
+    #[inline]
+    fn from(x: Float) -> Value {
+        Value::Approximate(x)
+    }
+}
+
+/// A `struct` determining how approximate values are computed when an [`Expression`] is
+/// evaluated.
+///
+/// - The precision is the number of significant bits of approximate values. It must be positive.
+///   The default precision is 64.
+///
+/// - The rounding mode determines how approximate values are rounded. It cannot be `Exact`. The
+///   default rounding mode is `Nearest`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EvaluationOptions {
+    prec: u64,
+    rounding_mode: RoundingMode,
+}
+
+impl Default for EvaluationOptions {
+    fn default() -> EvaluationOptions {
+        EvaluationOptions {
+            prec: 64,
+            rounding_mode: RoundingMode::Nearest,
+        }
+    }
+}
+
+impl EvaluationOptions {
+    /// Returns the precision of approximate values.
+    #[inline]
+    pub const fn get_precision(&self) -> u64 {
+        self.prec
+    }
+
+    /// Returns the rounding mode used for approximate values.
+    #[inline]
+    pub const fn get_rounding_mode(&self) -> RoundingMode {
+        self.rounding_mode
+    }
+
+    /// Sets the precision of approximate values.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    #[inline]
+    pub fn set_precision(&mut self, prec: u64) {
+        assert_ne!(prec, 0);
+        self.prec = prec;
+    }
+
+    /// Sets the rounding mode used for approximate values.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact`.
+    #[inline]
+    pub fn set_rounding_mode(&mut self, rm: RoundingMode) {
+        assert_ne!(rm, RoundingMode::Exact);
+        self.rounding_mode = rm;
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum TokenKind {
+    Number(Rational),
+    Identifier(String),
+    Symbol(char),
+    End,
+}
+
+#[derive(Clone, Debug)]
+struct Token {
+    kind: TokenKind,
+    span: Range<usize>,
+}
+
+fn is_identifier_char(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_'
+}
+
+// Scans a number literal starting at `start`, which must be an ASCII digit, and returns it
+// together with the index just past its end.
+fn scan_number(s: &str, start: usize) -> Result<(Rational, usize), ExpressionError> {
+    let bytes = s.as_bytes();
+    let mut end = start;
+    let radix_prefix = bytes[start] == b'0'
+        && matches!(
+            bytes.get(start + 1),
+            Some(b'x' | b'X' | b'o' | b'O' | b'b' | b'B')
+        );
+    let result = if radix_prefix {
+        while end < bytes.len() && is_identifier_char(bytes[end]) {
+            end += 1;
+        }
+        Natural::from_literal(&s[start..end])
+            .ok()
+            .map(Rational::from)
+    } else {
+        let digits_end = |mut i: usize| {
+            while i < bytes.len() && (bytes[i].is_ascii_digit() || bytes[i] == b'_') {
+                i += 1;
+            }
+            i
+        };
+        end = digits_end(start);
+        if bytes.get(end) == Some(&b'.') && bytes.get(end + 1).map_or(false, u8::is_ascii_digit) {
+            end = digits_end(end + 1);
+        }
+        if let Some(b'e' | b'E') = bytes.get(end) {
+            let exponent_start = match bytes.get(end + 1) {
+                Some(b'+' | b'-') => end + 2,
+                _ => end + 1,
+            };
+            if bytes.get(exponent_start).map_or(false, u8::is_ascii_digit) {
+                end = digits_end(exponent_start);
+            }
+        }
+        while end < bytes.len() && (is_identifier_char(bytes[end]) || bytes[end] == b'.') {
+            end += 1;
+        }
+        let digits: String = s[start..end].chars().filter(|&c| c != '_').collect();
+        Rational::from_sci_string(&digits).ok()
+    };
+    result
+        .map(|x| (x, end))
+        .ok_or_else(|| ExpressionError::new(ExpressionErrorKind::InvalidNumber, start..end))
+}
+
+fn tokenize(s: &str) -> Result<Vec<Token>, ExpressionError> {
+    let bytes = s.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        let start = i;
+        let kind = if b.is_ascii_whitespace() {
+            i += 1;
+            continue;
+        } else if b.is_ascii_digit() {
+            let (x, end) = scan_number(s, i)?;
+            i = end;
+            TokenKind::Number(x)
+        } else if b.is_ascii_alphabetic() || b == b'_' {
+            while i < bytes.len() && is_identifier_char(bytes[i]) {
+                i += 1;
+            }
+            TokenKind::Identifier(s[start..i].to_string())
+        } else if b"+-*/%^!(),".contains(&b) {
+            i += 1;
+            TokenKind::Symbol(char::from(b))
+        } else {
+            let c = s[i..].chars().next().unwrap();
+            return Err(ExpressionError::new(
+                ExpressionErrorKind::UnexpectedCharacter(c),
+                i..i + c.len_utf8(),
+            ));
+        };
+        tokens.push(Token {
+            kind,
+            span: start..i,
+        });
+    }
+    tokens.push(Token {
+        kind: TokenKind::End,
+        span: s.len()..s.len(),
+    });
+    Ok(tokens)
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum BinaryOperator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Mod,
+    Pow,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum NodeKind {
+    Number(Rational),
+    Variable(String),
+    Neg(Box<Node>),
+    Binary(BinaryOperator, Box<Node>, Box<Node>),
+    Factorial(Box<Node>),
+    Call(String, Vec<Node>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Node {
+    kind: NodeKind,
+    span: Range<usize>,
+}
+
+impl Node {
+    fn binary(op: BinaryOperator, left: Node, right: Node) -> Node {
+        Node {
+            span: left.span.start..right.span.end,
+            kind: NodeKind::Binary(op, Box::new(left), Box::new(right)),
+        }
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    index: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.index]
+    }
+
+    fn next(&mut self) -> Token {
+        let token = self.tokens[self.index].clone();
+        if token.kind != TokenKind::End {
+            self.index += 1;
+        }
+        token
+    }
+
+    fn unexpected(token: &Token) -> ExpressionError {
+        ExpressionError::new(
+            if token.kind == TokenKind::End {
+                ExpressionErrorKind::UnexpectedEnd
+            } else {
+                ExpressionErrorKind::UnexpectedToken
+            },
+            token.span.clone(),
+        )
+    }
+
+    fn parse_sum(&mut self) -> Result<Node, ExpressionError> {
+        let mut left = self.parse_product()?;
+        loop {
+            let op = match self.peek().kind {
+                TokenKind::Symbol('+') => BinaryOperator::Add,
+                TokenKind::Symbol('-') => BinaryOperator::Sub,
+                _ => return Ok(left),
+            };
+            self.next();
+            let right = self.parse_product()?;
+            left = Node::binary(op, left, right);
+        }
+    }
+
+    fn parse_product(&mut self) -> Result<Node, ExpressionError> {
+        let mut left = self.parse_unary()?;
+        loop {
+            let op = match &self.peek().kind {
+                TokenKind::Symbol('*') => BinaryOperator::Mul,
+                TokenKind::Symbol('/') => BinaryOperator::Div,
+                TokenKind::Symbol('%') => BinaryOperator::Mod,
+                TokenKind::Identifier(name) if name == "mod" => BinaryOperator::Mod,
+                _ => return Ok(left),
+            };
+            self.next();
+            let right = self.parse_unary()?;
+            left = Node::binary(op, left, right);
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<Node, ExpressionError> {
+        match self.peek().kind {
+            TokenKind::Symbol('-') => {
+                let start = self.next().span.start;
+                let operand = self.parse_unary()?;
+                Ok(Node {
+                    span: start..operand.span.end,
+                    kind: NodeKind::Neg(Box::new(operand)),
+                })
+            }
+            TokenKind::Symbol('+') => {
+                let start = self.next().span.start;
+                let mut operand = self.parse_unary()?;
+                operand.span.start = start;
+                Ok(operand)
+            }
+            _ => self.parse_power(),
+        }
+    }
+
+    fn parse_power(&mut self) -> Result<Node, ExpressionError> {
+        let base = self.parse_factorial()?;
+        if self.peek().kind == TokenKind::Symbol('^') {
+            self.next();
+            let exponent = self.parse_unary()?;
+            Ok(Node::binary(BinaryOperator::Pow, base, exponent))
+        } else {
+            Ok(base)
+        }
+    }
+
+    fn parse_factorial(&mut self) -> Result<Node, ExpressionError> {
+        let mut node = self.parse_primary()?;
+        while self.peek().kind == TokenKind::Symbol('!') {
+            let end = self.next().span.end;
+            node = Node {
+                span: node.span.start..end,
+                kind: NodeKind::Factorial(Box::new(node)),
+            };
+        }
+        Ok(node)
+    }
+
+    fn parse_primary(&mut self) -> Result<Node, ExpressionError> {
+        let token = self.next();
+        match token.kind {
+            TokenKind::Number(x) => Ok(Node {
+                kind: NodeKind::Number(x),
+                span: token.span,
+            }),
+            TokenKind::Identifier(name) => {
+                if self.peek().kind != TokenKind::Symbol('(') {
+                    return Ok(Node {
+                        kind: NodeKind::Variable(name),
+                        span: token.span,
+                    });
+                }
+                self.next();
+                let mut args = Vec::new();
+                if self.peek().kind != TokenKind::Symbol(')') {
+                    loop {
+                        args.push(self.parse_sum()?);
+                        if self.peek().kind == TokenKind::Symbol(',') {
+                            self.next();
+                        } else {
+                            break;
+                        }
+                    }
+                }
+                let end = self.expect_closing_parenthesis()?;
+                Ok(Node {
+                    kind: NodeKind::Call(name, args),
+                    span: token.span.start..end,
+                })
+            }
+            TokenKind::Symbol('(') => {
+                let mut node = self.parse_sum()?;
+                node.span = token.span.start..self.expect_closing_parenthesis()?;
+                Ok(node)
+            }
+            _ => Err(Parser::unexpected(&token)),
+        }
+    }
+
+    fn expect_closing_parenthesis(&mut self) -> Result<usize, ExpressionError> {
+        let token = self.next();
+        if token.kind == TokenKind::Symbol(')') {
+            Ok(token.span.end)
+        } else {
+            Err(Parser::unexpected(&token))
+        }
+    }
+}
+
+/// A parsed arithmetic expression.
+///
+/// See the [module-level documentation](self) for the syntax.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Expression {
+    root: Node,
+}
+
+impl FromStr for Expression {
+    type Err = ExpressionError;
+
+    /// Parses an [`Expression`].
+    ///
+    /// If the string is not a valid expression, an [`ExpressionError`] containing the byte range
+    /// of the first invalid token is returned. Unknown functions and variables are only detected
+    /// when the expression is evaluated.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::expression::{Expression, ExpressionErrorKind};
+    /// use std::str::FromStr;
+    ///
+    /// assert!(Expression::from_str("2^127 - 1").is_ok());
+    ///
+    /// let error = Expression::from_str("2 ^ * 3").unwrap_err();
+    /// assert_eq!(*error.kind(), ExpressionErrorKind::UnexpectedToken);
+    /// assert_eq!(error.span(), 4..5);
+    ///
+    /// let error = Expression::from_str("1.2.3 + 1").unwrap_err();
+    /// assert_eq!(*error.kind(), ExpressionErrorKind::InvalidNumber);
+    /// assert_eq!(error.span(), 0..5);
+    ///
+    /// let error = Expression::from_str("1 # 2").unwrap_err();
+    /// assert_eq!(*error.kind(), ExpressionErrorKind::UnexpectedCharacter('#'));
+    /// assert_eq!(error.span(), 2..3);
+    /// ```
+    fn from_str(s: &str) -> Result<Expression, ExpressionError> {
+        let mut parser = Parser {
+            tokens: tokenize(s)?,
+            index: 0,
+        };
+        let root = parser.parse_sum()?;
+        let token = parser.next();
+        if token.kind == TokenKind::End {
+            Ok(Expression { root })
+        } else {
+            Err(Parser::unexpected(&token))
+        }
+    }
+}
+
+// Computes the square root of a positive `Rational`, rounded to `prec` bits.
+//
+// The root is first computed with at least `prec` + 2 correct bits. If it is inexact, a sticky bit
+// is appended, so that rounding the result to `prec` bits gives the same result as rounding the
+// exact value.
+fn sqrt_rational_prec_round(x: &Rational, prec: u64, rm: RoundingMode) -> Float {
+    let (n, d) = x.numerator_and_denominator_ref();
+    // x >= 2 ^ (log - 1), so n * 4 ^ k / d >= 2 ^ (2 * (prec + 2))
+    let log = i128::from(n.significant_bits()) - i128::from(d.significant_bits());
+    let k = u64::try_from(max(0, (i128::from((prec + 2) << 1) - log + 2) >> 1)).unwrap();
+    let (q, r) = (n << (k << 1)).div_mod(d);
+    let (root, rem) = q.sqrt_rem();
+    let mut root = root << 1;
+    if r != 0u32 || rem != 0u32 {
+        root += Natural::from(1u32);
+    }
+    Float::from_rational_times_power_of_2_prec_round(
+        Rational::from(root),
+        -i64::try_from(k + 1).unwrap(),
+        prec,
+        rm,
+    )
+    .0
+}
+
+struct Evaluator<'a> {
+    options: EvaluationOptions,
+    variables: &'a HashMap<String, Value>,
+}
+
+// A value being operated on, together with the span of the expression that produced it.
+struct Operand {
+    x: Rational,
+    exact: bool,
+    span: Range<usize>,
+}
+
+impl Operand {
+    fn error(&self, kind: ExpressionErrorKind) -> ExpressionError {
+        ExpressionError::new(kind, self.span.clone())
+    }
+
+    fn integer(&self) -> Result<Integer, ExpressionError> {
+        if self.x.is_integer() {
+            Ok(Integer::try_from(&self.x).unwrap())
+        } else {
+            Err(self.error(ExpressionErrorKind::NotAnInteger))
+        }
+    }
+
+    fn natural(&self) -> Result<Natural, ExpressionError> {
+        let n = self.integer()?;
+        if n < 0u32 {
+            Err(self.error(ExpressionErrorKind::Negative))
+        } else {
+            Ok(n.unsigned_abs())
+        }
+    }
+
+    fn small_natural(&self) -> Result<u64, ExpressionError> {
+        u64::try_from(&self.natural()?).map_err(|_| self.error(ExpressionErrorKind::TooLarge))
+    }
+}
+
+impl<'a> Evaluator<'a> {
+    fn value(&self, x: Rational, exact: bool) -> Value {
+        if exact {
+            Value::Exact(x)
+        } else {
+            Value::Approximate(
+                Float::from_rational_prec_round(x, self.options.prec, self.options.rounding_mode).0,
+            )
+        }
+    }
+
+    fn operand(&self, node: &Node) -> Result<Operand, ExpressionError> {
+        let (x, exact) = match self.evaluate(node)? {
+            Value::Exact(x) => (x, true),
+            Value::Approximate(x) => (
+                Rational::try_from(&x).map_err(|_| {
+                    ExpressionError::new(ExpressionErrorKind::TooLarge, node.span.clone())
+                })?,
+                false,
+            ),
+        };
+        Ok(Operand {
+            x,
+            exact,
+            span: node.span.clone(),
+        })
+    }
+
+    fn evaluate(&self, node: &Node) -> Result<Value, ExpressionError> {
+        match &node.kind {
+            NodeKind::Number(x) => Ok(Value::Exact(x.clone())),
+            NodeKind::Variable(name) => self.variables.get(name).cloned().ok_or_else(|| {
+                ExpressionError::new(
+                    ExpressionErrorKind::UnknownVariable(name.clone()),
+                    node.span.clone(),
+                )
+            }),
+            NodeKind::Neg(operand) => {
+                let a = self.operand(operand)?;
+                Ok(self.value(-a.x, a.exact))
+            }
+            NodeKind::Factorial(operand) => {
+                let a = self.operand(operand)?;
+                Ok(self.value(
+                    Rational::from(Natural::factorial(a.small_natural()?)),
+                    a.exact,
+                ))
+            }
+            NodeKind::Binary(op, left, right) => {
+                let a = self.operand(left)?;
+                let b = self.operand(right)?;
+                let exact = a.exact && b.exact;
+                let x = match op {
+                    BinaryOperator::Add => a.x + b.x,
+                    BinaryOperator::Sub => a.x - b.x,
+                    BinaryOperator::Mul => a.x * b.x,
+                    BinaryOperator::Div => {
+                        if b.x == 0u32 {
+                            return Err(b.error(ExpressionErrorKind::DivisionByZero));
+                        }
+                        a.x / b.x
+                    }
+                    BinaryOperator::Mod => {
+                        let m = b.integer()?;
+                        if m == 0u32 {
+                            return Err(b.error(ExpressionErrorKind::DivisionByZero));
+                        }
+                        Rational::from(a.integer()?.mod_op(m))
+                    }
+                    BinaryOperator::Pow => {
+                        let exponent = i64::try_from(&b.integer()?)
+                            .map_err(|_| b.error(ExpressionErrorKind::TooLarge))?;
+                        if a.x == 0u32 && exponent < 0 {
+                            return Err(ExpressionError::new(
+                                ExpressionErrorKind::DivisionByZero,
+                                node.span.clone(),
+                            ));
+                        }
+                        a.x.pow(exponent)
+                    }
+                };
+                Ok(self.value(x, exact))
+            }
+            NodeKind::Call(name, args) => self.call(name, args, node.span.clone()),
+        }
+    }
+
+    fn call(
+        &self,
+        name: &str,
+        args: &[Node],
+        span: Range<usize>,
+    ) -> Result<Value, ExpressionError> {
+        let arity = match name {
+            "abs" | "ceiling" | "factorial" | "floor" | "isqrt" | "primorial" | "sqrt" => 1,
+            "binomial" | "gcd" | "lcm" => 2,
+            _ => {
+                return Err(ExpressionError::new(
+                    ExpressionErrorKind::UnknownFunction(name.to_string()),
+                    span,
+                ))
+            }
+        };
+        if args.len() != arity {
+            return Err(ExpressionError::new(
+                ExpressionErrorKind::WrongArgumentCount {
+                    expected: arity,
+                    found: args.len(),
+                },
+                span,
+            ));
+        }
+        let args = args
+            .iter()
+            .map(|arg| self.operand(arg))
+            .collect::<Result<Vec<_>, _>>()?;
+        let exact = args.iter().all(|arg| arg.exact);
+        let a = &args[0];
+        let x = match name {
+            "abs" => a.x.clone().abs(),
+            "binomial" => Rational::from(Integer::binomial_coefficient(
+                a.integer()?,
+                Integer::from(args[1].natural()?),
+            )),
+            "ceiling" => Rational::from(a.x.clone().ceiling()),
+            "factorial" => Rational::from(Natural::factorial(a.small_natural()?)),
+            "floor" => Rational::from(a.x.clone().floor()),
+            "gcd" => Rational::from(
+                a.integer()?
+                    .unsigned_abs()
+                    .gcd(args[1].integer()?.unsigned_abs()),
+            ),
+            "isqrt" => Rational::from(a.natural()?.floor_sqrt()),
+            "lcm" => Rational::from(
+                a.integer()?
+                    .unsigned_abs()
+                    .lcm(args[1].integer()?.unsigned_abs()),
+            ),
+            "primorial" => Rational::from(Natural::primorial(a.small_natural()?)),
+            "sqrt" => {
+                if a.x < 0u32 {
+                    return Err(a.error(ExpressionErrorKind::Negative));
+                }
+                match (&a.x).checked_sqrt() {
+                    Some(root) => root,
+                    None => {
+                        return Ok(Value::Approximate(sqrt_rational_prec_round(
+                            &a.x,
+                            self.options.prec,
+                            self.options.rounding_mode,
+                        )))
+                    }
+                }
+            }
+            _ => unreachable!(),
+        };
+        Ok(self.value(x, exact))
+    }
+}
+
+impl Expression {
+    /// Evaluates an [`Expression`] that contains no variables.
+    ///
+    /// Approximate values are computed using the precision and rounding mode given by `options`.
+    /// If the expression can't be evaluated, an [`ExpressionError`] containing the byte range of
+    /// the subexpression that caused the error is returned.
+    ///
+    /// # Worst-case complexity
+    /// The complexity depends on the operations in the expression.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::expression::{EvaluationOptions, Expression, ExpressionErrorKind, Value};
+    /// use malachite::num::arithmetic::traits::Primorial;
+    /// use malachite::rounding_modes::RoundingMode;
+    /// use malachite::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// let mut options = EvaluationOptions::default();
+    /// let evaluate = |s: &str, options| Expression::from_str(s).unwrap().evaluate(options);
+    /// assert_eq!(
+    ///     evaluate("primorial(50) - 1", options).unwrap(),
+    ///     Value::from(Natural::primorial(50) - Natural::from(1u32))
+    /// );
+    /// assert_eq!(evaluate("gcd(0x_ffff, 0o777) + 1.5e1", options).unwrap().to_string(), "16");
+    /// assert_eq!(evaluate("floor(22/7) * ceiling(-22/7)", options).unwrap().to_string(), "-9");
+    /// assert_eq!(evaluate("isqrt(10^20 + 1)", options).unwrap().to_string(), "10000000000");
+    ///
+    /// options.set_precision(20);
+    /// options.set_rounding_mode(RoundingMode::Floor);
+    /// assert_eq!(evaluate("sqrt(3) * 2", options).unwrap().to_string(), "3.4641");
+    ///
+    /// let error = evaluate("factorial(1/2)", options).unwrap_err();
+    /// assert_eq!(*error.kind(), ExpressionErrorKind::NotAnInteger);
+    /// assert_eq!(error.span(), 10..13);
+    ///
+    /// let error = evaluate("1 + x", options).unwrap_err();
+    /// assert_eq!(*error.kind(), ExpressionErrorKind::UnknownVariable("x".to_string()));
+    /// assert_eq!(error.span(), 4..5);
+    ///
+    /// let error = evaluate("binomial(5)", options).unwrap_err();
+    /// assert_eq!(
+    ///     *error.kind(),
+    ///     ExpressionErrorKind::WrongArgumentCount { expected: 2, found: 1 }
+    /// );
+    /// assert_eq!(error.span(), 0..11);
+    /// ```
+    #[inline]
+    pub fn evaluate(&self, options: EvaluationOptions) -> Result<Value, ExpressionError> {
+        self.evaluate_with_variables(options, &HashMap::new())
+    }
+
+    /// Evaluates an [`Expression`], looking up its variables in a map.
+    ///
+    /// Approximate values are computed using the precision and rounding mode given by `options`.
+    /// If the expression can't be evaluated, an [`ExpressionError`] containing the byte range of
+    /// the subexpression that caused the error is returned.
+    ///
+    /// # Worst-case complexity
+    /// The complexity depends on the operations in the expression.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::expression::{EvaluationOptions, Expression, Value};
+    /// use malachite::Rational;
+    /// use std::collections::HashMap;
+    /// use std::str::FromStr;
+    ///
+    /// let mut variables = HashMap::new();
+    /// variables.insert("x".to_string(), Value::from(Rational::from_signeds(1, 3)));
+    /// let expression = Expression::from_str("3 * x^2 - x").unwrap();
+    /// assert_eq!(
+    ///     expression
+    ///         .evaluate_with_variables(EvaluationOptions::default(), &variables)
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "0"
+    /// );
+    /// ```
+    pub fn evaluate_with_variables(
+        &self,
+        options: EvaluationOptions,
+        variables: &HashMap<String, Value>,
+    ) -> Result<Value, ExpressionError> {
+        Evaluator { options, variables }.evaluate(&self.root)
+    }
+}
@@ -0,0 +1,201 @@
+//! Finding integer relations among real numbers, and guessing the minimal polynomials of algebraic
+//! numbers.
+//!
+//! An integer relation among $x_1, \ldots, x_n$ is a nonzero vector of integers
+//! $(a_1, \ldots, a_n)$ with $\sum_i a_i x_i = 0$. Relations are found by LLL-reducing the rows of
+//! $$
+//! \begin{pmatrix}
+//!     1 & & & \lfloor Cx_1 \rceil \\\\
+//!     & \ddots & & \vdots \\\\
+//!     & & 1 & \lfloor Cx_n \rceil
+//! \end{pmatrix},
+//! $$
+//! where $C$ is about $2^p$ and $p$ is the precision of the inputs. A short vector in this
+//! lattice consists of small coefficients $a_i$ for which $\sum_i a_i x_i$ is small.
+//!
+//! Since the inputs are only approximations, no algorithm can prove that a relation holds. A
+//! relation is only reported if it is consistent with the precision of the inputs and its
+//! coefficients are small enough that it is unlikely to be a coincidence: with $n$ inputs of
+//! precision $p$, each coefficient may have at most $\lfloor 3p/(4n) \rfloor$ bits.
+//!
+//! [`Approximate`](malachite_base::num::arithmetic::traits::Approximate) and continued fractions
+//! handle relations between two numbers; the functions here are the general version.
+
+use crate::matrix::integer::IntegerMatrix;
+use crate::polynomial::integer::IntegerPolynomial;
+use malachite_base::num::arithmetic::traits::{Abs, Gcd, Pow};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::Float;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+// Looks for an integer relation among some nonzero `Rational`s, each of which approximates an
+// unknown real number with a relative error of at most 2^(-`prec`).
+fn find_relation(xs: &[Rational], prec: u64) -> Option<Vec<Integer>> {
+    let n = xs.len();
+    if n < 2 || prec == 0 {
+        return None;
+    }
+    // Scale so that the largest input has about `prec` bits
+    let exponent = xs
+        .iter()
+        .map(Rational::ceiling_log_base_2_abs)
+        .max()
+        .unwrap();
+    let shift = i64::exact_from(prec) - exponent;
+    let mut entries = Vec::with_capacity(n * (n + 1));
+    for (i, x) in xs.iter().enumerate() {
+        for j in 0..n {
+            entries.push(if i == j { Integer::ONE } else { Integer::ZERO });
+        }
+        entries.push(Integer::rounding_from(x << shift, RoundingMode::Nearest).0);
+    }
+    let reduced = IntegerMatrix::from_entries(n, n + 1, entries).lll_reduce_with_precision(
+        &Rational::from_signeds(99, 100),
+        prec + (u64::exact_from(n) << 1) + 10,
+    );
+    let mut relation = reduced.row(0)[..n].to_vec();
+    let max_bits = relation
+        .iter()
+        .map(SignificantBits::significant_bits)
+        .max()
+        .unwrap();
+    if max_bits * u64::exact_from(n) > (prec * 3) >> 2 {
+        return None;
+    }
+    let mut residual = Rational::ZERO;
+    let mut bound = Rational::ZERO;
+    for (a, x) in relation.iter().zip(xs.iter()) {
+        let term = Rational::from(a) * x;
+        bound += (&term).abs();
+        residual += term;
+    }
+    if residual.abs() > bound >> (prec - 1) {
+        return None;
+    }
+    let gcd = Integer::from(
+        relation
+            .iter()
+            .fold(Natural::ZERO, |gcd, a| gcd.gcd(a.unsigned_abs_ref())),
+    );
+    let first_nonzero = relation.iter().find(|a| **a != 0u32).unwrap();
+    let divisor = if *first_nonzero > 0u32 { gcd } else { -gcd };
+    for a in &mut relation {
+        *a /= &divisor;
+    }
+    Some(relation)
+}
+
+/// Looks for an integer relation among some [`Float`]s: a nonzero vector of small [`Integer`]s
+/// $a_i$ with $\sum_i a_i x_i = 0$.
+///
+/// Each [`Float`] is treated as an approximation of a real number, correct to its precision. If
+/// a relation is found, it is returned with coprime coefficients and with its first nonzero
+/// coefficient positive. If one of the inputs is zero, the relation that selects it is returned.
+/// See the [module-level documentation](self) for when a relation is considered found.
+///
+/// # Worst-case complexity
+/// $T(n, p) = O(n^6 p^3)$
+///
+/// $M(n, p) = O(n^3 p)$
+///
+/// where $T$ is time, $M$ is additional memory, $n$ is `xs.len()`, and $p$ is the maximum
+/// precision of the inputs.
+///
+/// # Panics
+/// Panics if any of the [`Float`]s is infinite or NaN.
+///
+/// # Examples
+/// ```
+/// use malachite::integer_relation::find_integer_relation;
+/// use malachite::Float;
+///
+/// let xs = [
+///     Float::from(2.0f64.ln()),
+///     Float::from(3.0f64.ln()),
+///     Float::from(6.0f64.ln()),
+/// ];
+/// assert_eq!(find_integer_relation(&xs).unwrap(), &[1, 1, -1]);
+///
+/// let xs = [Float::from(1.0), Float::from(std::f64::consts::PI)];
+/// assert_eq!(find_integer_relation(&xs), None);
+/// ```
+pub fn find_integer_relation(xs: &[Float]) -> Option<Vec<Integer>> {
+    assert!(
+        xs.iter().all(Float::is_finite),
+        "Float inputs must be finite"
+    );
+    if let Some(i) = xs.iter().position(|x| *x == 0u32) {
+        let mut relation = vec![Integer::ZERO; xs.len()];
+        relation[i] = Integer::ONE;
+        return Some(relation);
+    }
+    let prec = xs.iter().filter_map(Float::get_prec).min()?;
+    let xs: Vec<Rational> = xs.iter().map(Rational::exact_from).collect();
+    find_relation(&xs, prec)
+}
+
+/// Guesses the minimal polynomial of an algebraic number, given a [`Float`] approximation of it
+/// that is correct to its precision.
+///
+/// For each degree $d$ from 1 to `max_degree`, an integer relation among $1, x, \ldots, x^d$ is
+/// looked for using [`find_integer_relation`]'s criteria, with the precision reduced slightly to
+/// account for the error in the powers. The first relation found is returned as a primitive
+/// polynomial with a positive leading coefficient. If none is found, `None` is returned.
+///
+/// # Worst-case complexity
+/// $T(n, p) = O(n^7 p^3)$
+///
+/// $M(n, p) = O(n^3 p)$
+///
+/// where $T$ is time, $M$ is additional memory, $n$ is `max_degree`, and $p$ is the precision of
+/// `x`.
+///
+/// # Panics
+/// Panics if `x` is infinite or NaN.
+///
+/// # Examples
+/// ```
+/// use malachite::integer_relation::guess_minimal_polynomial;
+/// use malachite::Float;
+///
+/// let golden_ratio = Float::from((1.0 + 5.0f64.sqrt()) / 2.0);
+/// assert_eq!(
+///     guess_minimal_polynomial(&golden_ratio, 4).unwrap().to_string(),
+///     "x^2 - x - 1"
+/// );
+/// assert_eq!(
+///     guess_minimal_polynomial(&Float::from(2.0f64.cbrt()), 4).unwrap().to_string(),
+///     "x^3 - 2"
+/// );
+/// assert_eq!(
+///     guess_minimal_polynomial(&Float::from(0.75), 4).unwrap().to_string(),
+///     "4*x - 3"
+/// );
+/// assert_eq!(guess_minimal_polynomial(&Float::from(std::f64::consts::PI), 3), None);
+/// ```
+pub fn guess_minimal_polynomial(x: &Float, max_degree: u64) -> Option<IntegerPolynomial> {
+    assert!(x.is_finite(), "Float input must be finite");
+    if *x == 0u32 {
+        return Some(IntegerPolynomial::monomial(Integer::ONE, 1));
+    }
+    let prec = x.get_prec().unwrap();
+    let x = Rational::exact_from(x);
+    let mut powers = vec![Rational::ONE];
+    for degree in 1..=max_degree {
+        powers.push((&x).pow(degree));
+        // The error in x^k is about k times the error in x
+        let prec = match prec.checked_sub(degree.significant_bits()) {
+            Some(prec) => prec,
+            None => break,
+        };
+        if let Some(relation) = find_relation(&powers, prec) {
+            return Some(IntegerPolynomial::from_coefficients(relation).primitive_part());
+        }
+    }
+    None
+}
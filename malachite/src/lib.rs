@@ -13,3 +13,30 @@ pub use malachite_q::Rational;
 #[cfg(feature = "rationals")]
 #[cfg(feature = "floats")]
 pub use malachite_float::Float;
+
+#[cfg(feature = "naturals_and_integers")]
+#[cfg(feature = "rationals")]
+#[macro_use]
+mod ring_macros;
+
+/// Dense univariate polynomials over $\Z$, $\Q$, and $\Z/p\Z$.
+#[cfg(feature = "naturals_and_integers")]
+#[cfg(feature = "rationals")]
+pub mod polynomial;
+
+/// Dense matrices over $\Z$ and $\Q$.
+#[cfg(feature = "naturals_and_integers")]
+#[cfg(feature = "rationals")]
+pub mod matrix;
+
+/// Integer relations among real numbers, and minimal polynomials of algebraic numbers.
+#[cfg(feature = "naturals_and_integers")]
+#[cfg(feature = "rationals")]
+#[cfg(feature = "floats")]
+pub mod integer_relation;
+
+/// Parsing and evaluating arithmetic expressions over $\Q$, with approximate square roots.
+#[cfg(feature = "naturals_and_integers")]
+#[cfg(feature = "rationals")]
+#[cfg(feature = "floats")]
+pub mod expression;
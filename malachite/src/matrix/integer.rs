@@ -0,0 +1,963 @@
+use crate::matrix::rational::RationalMatrix;
+use crate::matrix::{mul_entries, write_rows};
+use crate::polynomial::large_primes;
+use malachite_base::num::arithmetic::traits::{
+    DivExact, DivMod, DivRem, DivisibleBy, ExtendedGcd, Gcd, Lcm, Mod, ModInverse, ModMul, ModNeg,
+    ModSub, Square,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+use std::cmp::min;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A dense matrix with [`Integer`] entries.
+///
+/// # Examples
+/// ```
+/// use malachite::matrix::integer::IntegerMatrix;
+/// use malachite::Integer;
+///
+/// let m = IntegerMatrix::from_rows(vec![
+///     vec![Integer::from(1), Integer::from(2), Integer::from(3)],
+///     vec![Integer::from(4), Integer::from(5), Integer::from(6)],
+/// ]);
+/// assert_eq!(m.rows(), 2);
+/// assert_eq!(m.cols(), 3);
+/// assert_eq!(m[(1, 2)], 6);
+/// assert_eq!(m.rank(), 2);
+/// ```
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct IntegerMatrix {
+    rows: usize,
+    cols: usize,
+    // The entries, in row-major order.
+    entries: Vec<Integer>,
+}
+
+fn swap_rows<T>(entries: &mut [T], cols: usize, i: usize, j: usize) {
+    if i != j {
+        for k in 0..cols {
+            entries.swap(i * cols + k, j * cols + k);
+        }
+    }
+}
+
+// Applies fraction-free Gaussian elimination (Bareiss's algorithm) to a `rows` x `cols` matrix,
+// choosing pivots only from the first `pivot_cols` columns. The result is in row echelon form,
+// and every entry is a minor of the original matrix, so the divisions are exact. Returns the
+// columns of the pivots and whether an odd number of row swaps was made.
+fn bareiss(
+    entries: &mut [Integer],
+    rows: usize,
+    cols: usize,
+    pivot_cols: usize,
+) -> (Vec<usize>, bool) {
+    let mut previous = Integer::ONE;
+    let mut pivots = Vec::new();
+    let mut odd_swaps = false;
+    for c in 0..pivot_cols {
+        let r = pivots.len();
+        if r == rows {
+            break;
+        }
+        let p = match (r..rows).find(|&i| entries[i * cols + c] != 0) {
+            Some(p) => p,
+            None => continue,
+        };
+        if p != r {
+            swap_rows(entries, cols, p, r);
+            odd_swaps = !odd_swaps;
+        }
+        let (top, bottom) = entries.split_at_mut((r + 1) * cols);
+        let pivot_row = &top[r * cols..];
+        let pivot = &pivot_row[c];
+        for row in bottom.chunks_mut(cols) {
+            let factor = std::mem::replace(&mut row[c], Integer::ZERO);
+            for (x, y) in row[c + 1..].iter_mut().zip(pivot_row[c + 1..].iter()) {
+                *x = (pivot * &*x - &factor * y).div_exact(&previous);
+            }
+        }
+        previous = pivot.clone();
+        pivots.push(c);
+    }
+    (pivots, odd_swaps)
+}
+
+impl IntegerMatrix {
+    /// Returns the zero matrix with the given dimensions.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `rows * cols`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    ///
+    /// assert_eq!(IntegerMatrix::zero(2, 3).to_string(), "[[0, 0, 0], [0, 0, 0]]");
+    /// ```
+    pub fn zero(rows: usize, cols: usize) -> IntegerMatrix {
+        IntegerMatrix {
+            rows,
+            cols,
+            entries: vec![Integer::ZERO; rows * cols],
+        }
+    }
+
+    /// Returns the $n \times n$ identity matrix.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n^2)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    ///
+    /// assert_eq!(IntegerMatrix::identity(2).to_string(), "[[1, 0], [0, 1]]");
+    /// ```
+    pub fn identity(n: usize) -> IntegerMatrix {
+        let mut m = IntegerMatrix::zero(n, n);
+        for i in 0..n {
+            m.entries[i * (n + 1)] = Integer::ONE;
+        }
+        m
+    }
+
+    /// Creates a matrix from its entries, given in row-major order.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if `entries.len()` is not `rows * cols`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    /// use malachite::Integer;
+    ///
+    /// let m = IntegerMatrix::from_entries(2, 1, vec![Integer::from(1), Integer::from(2)]);
+    /// assert_eq!(m.to_string(), "[[1], [2]]");
+    /// ```
+    pub fn from_entries(rows: usize, cols: usize, entries: Vec<Integer>) -> IntegerMatrix {
+        assert_eq!(entries.len(), rows * cols, "wrong number of entries");
+        IntegerMatrix {
+            rows,
+            cols,
+            entries,
+        }
+    }
+
+    /// Creates a matrix from a list of rows.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of entries.
+    ///
+    /// # Panics
+    /// Panics if the rows don't all have the same length.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    /// use malachite::Integer;
+    ///
+    /// let m = IntegerMatrix::from_rows(vec![
+    ///     vec![Integer::from(1), Integer::from(2)],
+    ///     vec![Integer::from(3), Integer::from(4)],
+    /// ]);
+    /// assert_eq!(m.to_string(), "[[1, 2], [3, 4]]");
+    /// ```
+    pub fn from_rows(rows: Vec<Vec<Integer>>) -> IntegerMatrix {
+        let cols = rows.first().map_or(0, Vec::len);
+        let row_count = rows.len();
+        let mut entries = Vec::with_capacity(row_count * cols);
+        for row in rows {
+            assert_eq!(row.len(), cols, "rows must all have the same length");
+            entries.extend(row);
+        }
+        IntegerMatrix {
+            rows: row_count,
+            cols,
+            entries,
+        }
+    }
+
+    /// Returns the number of rows of a matrix.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    ///
+    /// assert_eq!(IntegerMatrix::zero(2, 3).rows(), 2);
+    /// ```
+    pub const fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns of a matrix.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    ///
+    /// assert_eq!(IntegerMatrix::zero(2, 3).cols(), 3);
+    /// ```
+    pub const fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the entries of a matrix, in row-major order.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    ///
+    /// assert_eq!(IntegerMatrix::identity(2).entries(), &[1, 0, 0, 1]);
+    /// ```
+    pub fn entries(&self) -> &[Integer] {
+        &self.entries
+    }
+
+    /// Returns the entries of a matrix, in row-major order, taking the matrix by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    ///
+    /// assert_eq!(IntegerMatrix::identity(2).into_entries(), &[1, 0, 0, 1]);
+    /// ```
+    pub fn into_entries(self) -> Vec<Integer> {
+        self.entries
+    }
+
+    /// Returns a row of a matrix.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if `i` is not less than the number of rows.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    ///
+    /// assert_eq!(IntegerMatrix::identity(3).row(1), &[0, 1, 0]);
+    /// ```
+    pub fn row(&self, i: usize) -> &[Integer] {
+        assert!(i < self.rows, "row index out of range");
+        &self.entries[i * self.cols..(i + 1) * self.cols]
+    }
+
+    /// Returns the transpose of a matrix.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// entries.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    /// use malachite::Integer;
+    ///
+    /// let m = IntegerMatrix::from_rows(vec![vec![Integer::from(1), Integer::from(2)]]);
+    /// assert_eq!(m.transpose().to_string(), "[[1], [2]]");
+    /// ```
+    pub fn transpose(&self) -> IntegerMatrix {
+        let mut entries = Vec::with_capacity(self.entries.len());
+        for j in 0..self.cols {
+            for i in 0..self.rows {
+                entries.push(self.entries[i * self.cols + j].clone());
+            }
+        }
+        IntegerMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            entries,
+        }
+    }
+
+    fn assert_square(&self) {
+        assert_eq!(self.rows, self.cols, "matrix must be square");
+    }
+
+    /// Returns a row echelon form of a matrix, computed with fraction-free Gaussian elimination
+    /// (Bareiss's algorithm).
+    ///
+    /// Every entry of the result is a minor of the original matrix, up to sign, so the entries
+    /// stay small. For a square matrix, the last diagonal entry of the result is the determinant,
+    /// up to sign.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 (nm) \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(n^2 (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum dimension of the matrix, and
+    /// $m$ is the maximum bit length of its entries.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    /// use malachite::Integer;
+    ///
+    /// let m = IntegerMatrix::from_rows(vec![
+    ///     vec![Integer::from(2), Integer::from(3), Integer::from(1)],
+    ///     vec![Integer::from(4), Integer::from(1), Integer::from(5)],
+    ///     vec![Integer::from(6), Integer::from(4), Integer::from(6)],
+    /// ]);
+    /// assert_eq!(
+    ///     m.fraction_free_echelon_form().to_string(),
+    ///     "[[2, 3, 1], [0, -10, 6], [0, 0, 0]]"
+    /// );
+    /// ```
+    pub fn fraction_free_echelon_form(&self) -> IntegerMatrix {
+        let mut m = self.clone();
+        bareiss(&mut m.entries, m.rows, m.cols, m.cols);
+        m
+    }
+
+    /// Returns the rank of a matrix.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 (nm) \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(n^2 (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum dimension of the matrix, and
+    /// $m$ is the maximum bit length of its entries.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    /// use malachite::Integer;
+    ///
+    /// let m = IntegerMatrix::from_rows(vec![
+    ///     vec![Integer::from(1), Integer::from(2)],
+    ///     vec![Integer::from(2), Integer::from(4)],
+    /// ]);
+    /// assert_eq!(m.rank(), 1);
+    /// assert_eq!(IntegerMatrix::identity(3).rank(), 3);
+    /// ```
+    pub fn rank(&self) -> usize {
+        let mut entries = self.entries.clone();
+        bareiss(&mut entries, self.rows, self.cols, self.cols)
+            .0
+            .len()
+    }
+
+    fn determinant_mod_prime(&self, p: u64) -> u64 {
+        let n = self.rows;
+        let p_integer = Integer::from(p);
+        let mut a: Vec<u64> = self
+            .entries
+            .iter()
+            .map(|x| u64::exact_from(&x.mod_op(&p_integer)))
+            .collect();
+        let mut determinant = 1;
+        for c in 0..n {
+            let r = match (c..n).find(|&i| a[i * n + c] != 0) {
+                Some(r) => r,
+                None => return 0,
+            };
+            if r != c {
+                swap_rows(&mut a, n, r, c);
+                determinant = determinant.mod_neg(p);
+            }
+            let pivot = a[c * n + c];
+            determinant = determinant.mod_mul(pivot, p);
+            let inverse = pivot.mod_inverse(p).unwrap();
+            for i in c + 1..n {
+                let factor = a[i * n + c].mod_mul(inverse, p);
+                if factor == 0 {
+                    continue;
+                }
+                for j in c + 1..n {
+                    a[i * n + j] = a[i * n + j].mod_sub(factor.mod_mul(a[c * n + j], p), p);
+                }
+            }
+        }
+        determinant
+    }
+
+    /// Returns the determinant of a square matrix.
+    ///
+    /// The determinant is computed modulo primes less than $2^{62}$ until the product of the
+    /// primes exceeds twice Hadamard's bound, and the residues are combined using the Chinese
+    /// remainder theorem.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 (n\log n + nm) + n^2 (n\log n + nm)^2)$
+    ///
+    /// $M(n, m) = O(n^2 + n\log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the dimension of the matrix, and $m$ is
+    /// the maximum bit length of its entries.
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    /// use malachite::Integer;
+    ///
+    /// let m = IntegerMatrix::from_rows(vec![
+    ///     vec![Integer::from(2), Integer::from(-3), Integer::from(1)],
+    ///     vec![Integer::from(2), Integer::from(0), Integer::from(-1)],
+    ///     vec![Integer::from(1), Integer::from(4), Integer::from(5)],
+    /// ]);
+    /// assert_eq!(m.determinant(), 49);
+    /// assert_eq!(IntegerMatrix::zero(0, 0).determinant(), 1);
+    /// ```
+    pub fn determinant(&self) -> Integer {
+        self.assert_square();
+        // Hadamard's bound: the determinant's absolute value is at most the product of the rows'
+        // Euclidean norms.
+        let mut bound_bits = 0;
+        for i in 0..self.rows {
+            let mut norm_squared = Natural::ZERO;
+            for x in self.row(i) {
+                norm_squared += x.unsigned_abs_ref().square();
+            }
+            if norm_squared == 0u32 {
+                return Integer::ZERO;
+            }
+            bound_bits += (norm_squared.significant_bits() + 1) >> 1;
+        }
+        let mut modulus = Natural::ONE;
+        let mut determinant = Natural::ZERO;
+        for p in large_primes() {
+            let residue = self.determinant_mod_prime(p);
+            let p_natural = Natural::from(p);
+            let previous = u64::exact_from(&(&determinant % &p_natural));
+            let inverse = u64::exact_from(&(&modulus % &p_natural))
+                .mod_inverse(p)
+                .unwrap();
+            determinant +=
+                &modulus * Natural::from(residue.mod_sub(previous, p).mod_mul(inverse, p));
+            modulus *= p_natural;
+            if modulus.significant_bits() > bound_bits + 1 {
+                break;
+            }
+        }
+        if determinant > (&modulus >> 1u32) {
+            Integer::from(determinant) - Integer::from(modulus)
+        } else {
+            Integer::from(determinant)
+        }
+    }
+
+    /// Solves the system $AX = B$, where $A$ is `self`, which must be square and nonsingular.
+    /// Returns `None` if $A$ is singular.
+    ///
+    /// The augmented matrix is reduced with fraction-free elimination, and the solution is found
+    /// using fraction-free back-substitution, so the only rational arithmetic is the final
+    /// division by the determinant.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 (nm) \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(n^2 (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum dimension of the two
+    /// matrices, and $m$ is the maximum bit length of their entries.
+    ///
+    /// # Panics
+    /// Panics if `self` is not square, or if `rhs` has a different number of rows.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    /// use malachite::Integer;
+    ///
+    /// // 2x + y = 3, x + 3y = 5
+    /// let a = IntegerMatrix::from_rows(vec![
+    ///     vec![Integer::from(2), Integer::from(1)],
+    ///     vec![Integer::from(1), Integer::from(3)],
+    /// ]);
+    /// let b = IntegerMatrix::from_rows(vec![vec![Integer::from(3)], vec![Integer::from(5)]]);
+    /// assert_eq!(a.solve(&b).unwrap().to_string(), "[[4/5], [7/5]]");
+    ///
+    /// let singular = IntegerMatrix::from_rows(vec![
+    ///     vec![Integer::from(1), Integer::from(2)],
+    ///     vec![Integer::from(2), Integer::from(4)],
+    /// ]);
+    /// assert!(singular.solve(&b).is_none());
+    /// ```
+    pub fn solve(&self, rhs: &IntegerMatrix) -> Option<RationalMatrix> {
+        self.assert_square();
+        assert_eq!(self.rows, rhs.rows, "dimension mismatch");
+        let n = self.rows;
+        let k = rhs.cols;
+        let cols = n + k;
+        let mut entries = Vec::with_capacity(n * cols);
+        for i in 0..n {
+            entries.extend_from_slice(self.row(i));
+            entries.extend_from_slice(rhs.row(i));
+        }
+        if bareiss(&mut entries, n, cols, n).0.len() < n {
+            return None;
+        }
+        if n == 0 {
+            return Some(RationalMatrix::zero(0, k));
+        }
+        // The last pivot is the determinant of the row-permuted matrix. Multiplying the solution
+        // by it gives an integer matrix, which can be computed exactly.
+        let determinant = entries[n * cols - k - 1].clone();
+        let mut numerators = vec![Integer::ZERO; n * k];
+        for c in 0..k {
+            for i in (0..n).rev() {
+                let row = &entries[i * cols..(i + 1) * cols];
+                let mut x = &determinant * &row[n + c];
+                for j in i + 1..n {
+                    x -= &row[j] * &numerators[j * k + c];
+                }
+                numerators[i * k + c] = x.div_exact(&row[i]);
+            }
+        }
+        Some(RationalMatrix::from_entries(
+            n,
+            k,
+            numerators
+                .iter()
+                .map(|x| Rational::from_integers_ref(x, &determinant))
+                .collect(),
+        ))
+    }
+
+    /// Returns the inverse of a square matrix, or `None` if it is singular.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 (nm) \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(n^2 (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the dimension of the matrix, and $m$ is
+    /// the maximum bit length of its entries.
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    /// use malachite::Integer;
+    ///
+    /// let m = IntegerMatrix::from_rows(vec![
+    ///     vec![Integer::from(1), Integer::from(2)],
+    ///     vec![Integer::from(3), Integer::from(4)],
+    /// ]);
+    /// assert_eq!(m.inverse().unwrap().to_string(), "[[-2, 1], [3/2, -1/2]]");
+    /// ```
+    pub fn inverse(&self) -> Option<RationalMatrix> {
+        self.solve(&IntegerMatrix::identity(self.rows))
+    }
+
+    /// Returns a basis of the right nullspace of a matrix: the vectors $x$ with $Ax = 0$. The
+    /// basis vectors are the columns of the result, and each is primitive, meaning that the gcd of
+    /// its entries is 1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 (nm)^2)$
+    ///
+    /// $M(n, m) = O(n^2 (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum dimension of the matrix, and
+    /// $m$ is the maximum bit length of its entries.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    /// use malachite::Integer;
+    ///
+    /// let m = IntegerMatrix::from_rows(vec![
+    ///     vec![Integer::from(2), Integer::from(4), Integer::from(6)],
+    ///     vec![Integer::from(1), Integer::from(3), Integer::from(5)],
+    /// ]);
+    /// let n = m.nullspace();
+    /// assert_eq!(n.to_string(), "[[1], [-2], [1]]");
+    /// assert_eq!(m * n, IntegerMatrix::zero(2, 1));
+    /// ```
+    pub fn nullspace(&self) -> IntegerMatrix {
+        let basis = RationalMatrix::from(self.clone()).nullspace();
+        let (rows, cols) = (basis.rows(), basis.cols());
+        let mut entries = vec![Integer::ZERO; rows * cols];
+        for j in 0..cols {
+            let mut lcm = Natural::ONE;
+            for i in 0..rows {
+                lcm = lcm.lcm(basis[(i, j)].denominator_ref());
+            }
+            let mut content = Natural::ZERO;
+            for i in 0..rows {
+                let x = &basis[(i, j)];
+                let numerator = x.numerator_ref() * (&lcm / x.denominator_ref());
+                content = content.gcd(&numerator);
+                entries[i * cols + j] = Integer::from_sign_and_abs(*x >= 0u32, numerator);
+            }
+            let content = Integer::from(content);
+            for i in 0..rows {
+                entries[i * cols + j] = (&entries[i * cols + j]).div_exact(&content);
+            }
+        }
+        IntegerMatrix {
+            rows,
+            cols,
+            entries,
+        }
+    }
+
+    /// Returns the Hermite normal form of a matrix.
+    ///
+    /// The result $H$ is the unique matrix in row echelon form such that $H = UA$ for some
+    /// unimodular matrix $U$, the pivots are positive, and the entries above each pivot are
+    /// non-negative and less than it. Rows are combined using extended gcds.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 (nm)^2)$
+    ///
+    /// $M(n, m) = O(n^2 (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum dimension of the matrix, and
+    /// $m$ is the maximum bit length of its entries. Intermediate entries may grow further for
+    /// some inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    /// use malachite::Integer;
+    ///
+    /// let m = IntegerMatrix::from_rows(vec![
+    ///     vec![Integer::from(2), Integer::from(3), Integer::from(6)],
+    ///     vec![Integer::from(4), Integer::from(5), Integer::from(4)],
+    ///     vec![Integer::from(6), Integer::from(7), Integer::from(2)],
+    /// ]);
+    /// assert_eq!(
+    ///     m.hermite_normal_form().to_string(),
+    ///     "[[2, 0, -18], [0, 1, 8], [0, 0, 0]]"
+    /// );
+    /// ```
+    pub fn hermite_normal_form(&self) -> IntegerMatrix {
+        let (rows, cols) = (self.rows, self.cols);
+        let mut a = self.entries.clone();
+        let mut r = 0;
+        for c in 0..cols {
+            if r == rows {
+                break;
+            }
+            // Combine the rows at and below r so that only row r has a nonzero entry in column c
+            for i in r + 1..rows {
+                if a[i * cols + c] == 0 {
+                    continue;
+                }
+                if a[r * cols + c] == 0 {
+                    swap_rows(&mut a, cols, r, i);
+                    continue;
+                }
+                let (gcd, x, y) = (&a[r * cols + c]).extended_gcd(&a[i * cols + c]);
+                let gcd = Integer::from(gcd);
+                let u = (&a[r * cols + c]).div_exact(&gcd);
+                let v = (&a[i * cols + c]).div_exact(&gcd);
+                // The transformation [[x, y], [-v, u]] has determinant 1
+                for j in c..cols {
+                    let s = &a[r * cols + j];
+                    let t = &a[i * cols + j];
+                    let new_s = &x * s + &y * t;
+                    let new_t = &u * t - &v * s;
+                    a[r * cols + j] = new_s;
+                    a[i * cols + j] = new_t;
+                }
+            }
+            if a[r * cols + c] == 0 {
+                continue;
+            }
+            if a[r * cols + c] < 0 {
+                for x in &mut a[r * cols + c..(r + 1) * cols] {
+                    *x = -&*x;
+                }
+            }
+            // Reduce the entries above the pivot
+            let (top, bottom) = a.split_at_mut(r * cols);
+            let pivot_row = &bottom[..cols];
+            for row in top.chunks_mut(cols) {
+                let q = (&row[c]).div_mod(&pivot_row[c]).0;
+                if q != 0 {
+                    for (x, y) in row[c..].iter_mut().zip(pivot_row[c..].iter()) {
+                        *x -= &q * y;
+                    }
+                }
+            }
+            r += 1;
+        }
+        IntegerMatrix {
+            rows,
+            cols,
+            entries: a,
+        }
+    }
+
+    /// Returns the Smith normal form of a matrix.
+    ///
+    /// The result $S$ is the unique diagonal matrix, with the same dimensions as $A$, such that
+    /// $S = UAV$ for some unimodular matrices $U$ and $V$, the diagonal entries are non-negative,
+    /// and each diagonal entry divides the next. The nonzero diagonal entries are the invariant
+    /// factors of $A$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^4 (nm)^2)$
+    ///
+    /// $M(n, m) = O(n^2 (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum dimension of the matrix, and
+    /// $m$ is the maximum bit length of its entries. Intermediate entries may grow further for
+    /// some inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    /// use malachite::Integer;
+    ///
+    /// let m = IntegerMatrix::from_rows(vec![
+    ///     vec![Integer::from(2), Integer::from(4), Integer::from(4)],
+    ///     vec![Integer::from(-6), Integer::from(6), Integer::from(12)],
+    ///     vec![Integer::from(10), Integer::from(-4), Integer::from(-16)],
+    /// ]);
+    /// assert_eq!(
+    ///     m.smith_normal_form().to_string(),
+    ///     "[[2, 0, 0], [0, 6, 0], [0, 0, 12]]"
+    /// );
+    /// ```
+    pub fn smith_normal_form(&self) -> IntegerMatrix {
+        let (rows, cols) = (self.rows, self.cols);
+        let mut a = self.entries.clone();
+        let index = |i: usize, j: usize| i * cols + j;
+        let mut result = IntegerMatrix::zero(rows, cols);
+        'outer: for t in 0..min(rows, cols) {
+            loop {
+                // Move the nonzero entry with the smallest absolute value to (t, t)
+                let mut best: Option<(usize, usize)> = None;
+                for i in t..rows {
+                    for j in t..cols {
+                        let x = &a[index(i, j)];
+                        if *x != 0
+                            && best.map_or(true, |(k, l)| {
+                                x.unsigned_abs_ref() < a[index(k, l)].unsigned_abs_ref()
+                            })
+                        {
+                            best = Some((i, j));
+                        }
+                    }
+                }
+                let (i, j) = match best {
+                    Some(p) => p,
+                    None => break 'outer,
+                };
+                swap_rows(&mut a, cols, t, i);
+                for k in 0..rows {
+                    a.swap(index(k, t), index(k, j));
+                }
+                let pivot = a[index(t, t)].clone();
+                // Reduce the rest of row t and column t modulo the pivot
+                let mut cleared = true;
+                for i in t + 1..rows {
+                    let q = (&a[index(i, t)]).div_rem(&pivot).0;
+                    if q != 0 {
+                        for j in t..cols {
+                            let y = &q * &a[index(t, j)];
+                            a[index(i, j)] -= y;
+                        }
+                    }
+                    cleared &= a[index(i, t)] == 0;
+                }
+                for j in t + 1..cols {
+                    let q = (&a[index(t, j)]).div_rem(&pivot).0;
+                    if q != 0 {
+                        for i in t..rows {
+                            let y = &q * &a[index(i, t)];
+                            a[index(i, j)] -= y;
+                        }
+                    }
+                    cleared &= a[index(t, j)] == 0;
+                }
+                if !cleared {
+                    // A remainder is now smaller than the pivot
+                    continue;
+                }
+                // Make sure the pivot divides every remaining entry
+                match (t + 1..rows)
+                    .find(|&i| (t + 1..cols).any(|j| !(&a[index(i, j)]).divisible_by(&pivot)))
+                {
+                    Some(i) => {
+                        for j in t..cols {
+                            let y = a[index(i, j)].clone();
+                            a[index(t, j)] += y;
+                        }
+                    }
+                    None => break,
+                }
+            }
+            result.entries[index(t, t)] = Integer::from(a[index(t, t)].unsigned_abs_ref());
+        }
+        result
+    }
+
+    fn neg_ref(&self) -> IntegerMatrix {
+        IntegerMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            entries: self.entries.iter().map(|x| -x).collect(),
+        }
+    }
+
+    fn add_ref(&self, other: &IntegerMatrix) -> IntegerMatrix {
+        assert!(
+            self.rows == other.rows && self.cols == other.cols,
+            "dimension mismatch"
+        );
+        IntegerMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            entries: self
+                .entries
+                .iter()
+                .zip(other.entries.iter())
+                .map(|(x, y)| x + y)
+                .collect(),
+        }
+    }
+
+    fn sub_ref(&self, other: &IntegerMatrix) -> IntegerMatrix {
+        assert!(
+            self.rows == other.rows && self.cols == other.cols,
+            "dimension mismatch"
+        );
+        IntegerMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            entries: self
+                .entries
+                .iter()
+                .zip(other.entries.iter())
+                .map(|(x, y)| x - y)
+                .collect(),
+        }
+    }
+
+    fn mul_ref(&self, other: &IntegerMatrix) -> IntegerMatrix {
+        assert_eq!(self.cols, other.rows, "dimension mismatch");
+        IntegerMatrix {
+            rows: self.rows,
+            cols: other.cols,
+            entries: mul_entries(
+                &self.entries,
+                &other.entries,
+                self.rows,
+                self.cols,
+                other.cols,
+            ),
+        }
+    }
+}
+
+impl_ring_ops!(IntegerMatrix, "matrix", "matrices");
+
+impl Index<(usize, usize)> for IntegerMatrix {
+    type Output = Integer;
+
+    /// Gets a reference to the entry of a matrix at a given row and column.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if the row or column is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    ///
+    /// assert_eq!(IntegerMatrix::identity(2)[(1, 1)], 1);
+    /// ```
+    fn index(&self, (i, j): (usize, usize)) -> &Integer {
+        assert!(i < self.rows && j < self.cols, "index out of range");
+        &self.entries[i * self.cols + j]
+    }
+}
+
+impl IndexMut<(usize, usize)> for IntegerMatrix {
+    /// Gets a mutable reference to the entry of a matrix at a given row and column.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if the row or column is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    /// use malachite::Integer;
+    ///
+    /// let mut m = IntegerMatrix::zero(2, 2);
+    /// m[(0, 1)] = Integer::from(5);
+    /// assert_eq!(m.to_string(), "[[0, 5], [0, 0]]");
+    /// ```
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Integer {
+        assert!(i < self.rows && j < self.cols, "index out of range");
+        &mut self.entries[i * self.cols + j]
+    }
+}
+
+impl Display for IntegerMatrix {
+    /// Converts a matrix to a [`String`], as a list of rows.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    ///
+    /// assert_eq!(IntegerMatrix::identity(2).to_string(), "[[1, 0], [0, 1]]");
+    /// assert_eq!(IntegerMatrix::zero(2, 0).to_string(), "[[], []]");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write_rows(f, &self.entries, self.rows, self.cols)
+    }
+}
+
+impl Debug for IntegerMatrix {
+    /// Converts a matrix to a [`String`]; the same as [`Display::fmt`].
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,474 @@
+use crate::matrix::integer::IntegerMatrix;
+use malachite_base::num::arithmetic::traits::{Abs, DivExact, ExtendedGcd, Square};
+use malachite_base::num::basic::traits::{OneHalf, Zero};
+#[cfg(feature = "floats")]
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::conversion::traits::RoundingFrom;
+#[cfg(feature = "floats")]
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+#[cfg(feature = "floats")]
+use malachite_float::Float;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+use std::cmp::min;
+
+fn dot(xs: &[Integer], ys: &[Integer]) -> Integer {
+    let mut sum = Integer::ZERO;
+    for (x, y) in xs.iter().zip(ys.iter()) {
+        sum += x * y;
+    }
+    sum
+}
+
+fn assert_delta(delta: &Rational) {
+    assert!(
+        *delta > Rational::from_signeds(1, 4) && *delta < 1u32,
+        "delta must be greater than 1/4 and less than 1"
+    );
+}
+
+fn to_rows(m: &IntegerMatrix) -> Vec<Vec<Integer>> {
+    m.entries()
+        .chunks(m.cols().max(1))
+        .take(m.rows())
+        .map(<[Integer]>::to_vec)
+        .collect()
+}
+
+fn from_rows(rows: Vec<Vec<Integer>>, cols: usize) -> IntegerMatrix {
+    let row_count = rows.len();
+    IntegerMatrix::from_entries(row_count, cols, rows.into_iter().flatten().collect())
+}
+
+// Computes the squared norms of the Gram-Schmidt vectors of some rows, along with the
+// Gram-Schmidt coefficients: `mu[i][j]` is the coefficient of the `j`th Gram-Schmidt vector in
+// the `i`th row, for `j < i`. Returns `None` if the rows are linearly dependent.
+fn gram_schmidt(rows: &[Vec<Integer>]) -> Option<(Vec<Rational>, Vec<Vec<Rational>>)> {
+    let n = rows.len();
+    let mut norms: Vec<Rational> = Vec::with_capacity(n);
+    let mut mu = vec![vec![Rational::ZERO; n]; n];
+    for i in 0..n {
+        for j in 0..i {
+            let mut x = Rational::from(dot(&rows[i], &rows[j]));
+            for l in 0..j {
+                x -= &mu[j][l] * &mu[i][l] * &norms[l];
+            }
+            mu[i][j] = x / &norms[j];
+        }
+        let mut norm = Rational::from(dot(&rows[i], &rows[i]));
+        for j in 0..i {
+            norm -= (&mu[i][j]).square() * &norms[j];
+        }
+        if norm == 0u32 {
+            return None;
+        }
+        norms.push(norm);
+    }
+    Some((norms, mu))
+}
+
+// Subtracts the nearest integer to `mu[k][l]` times row `l` from row `k`.
+fn size_reduce(
+    rows: &mut [Vec<Integer>],
+    mu: &mut [Vec<Rational>],
+    k: usize,
+    l: usize,
+    round: &dyn Fn(Rational) -> Rational,
+) {
+    if (&mu[k][l]).abs() <= Rational::ONE_HALF {
+        return;
+    }
+    let q = Integer::rounding_from(&mu[k][l], RoundingMode::Nearest).0;
+    let row_l = rows[l].clone();
+    for (x, y) in rows[k].iter_mut().zip(row_l.iter()) {
+        *x -= &q * y;
+    }
+    let q = Rational::from(q);
+    mu[k][l] -= &q;
+    let (mu_head, mu_tail) = mu.split_at_mut(k);
+    for (x, y) in mu_tail[0][..l].iter_mut().zip(&mu_head[l][..l]) {
+        *x = round(&*x - &q * y);
+    }
+}
+
+// LLL-reduces some rows in place (Cohen, Algorithm 2.6.3), rounding every intermediate
+// Gram-Schmidt quantity with `round`. The rows are only ever changed by unimodular
+// transformations, so they span the same lattice even if the reduction fails. Returns `false` if
+// a Gram-Schmidt vector turns out to be zero, which means that the rows are linearly dependent
+// if `round` is exact, or if more than `max_swaps` swaps are needed.
+fn lll_in_place(
+    rows: &mut [Vec<Integer>],
+    delta: &Rational,
+    round: &dyn Fn(Rational) -> Rational,
+    max_swaps: Option<u64>,
+) -> bool {
+    let n = rows.len();
+    if n == 0 {
+        return true;
+    }
+    let mut norms = vec![Rational::ZERO; n];
+    let mut mu = vec![vec![Rational::ZERO; n]; n];
+    norms[0] = round(Rational::from(dot(&rows[0], &rows[0])));
+    if norms[0] == 0u32 {
+        return false;
+    }
+    let mut k = 1;
+    let mut k_max = 0;
+    let mut swaps = 0;
+    while k < n {
+        if k > k_max {
+            k_max = k;
+            for j in 0..k {
+                let mut x = Rational::from(dot(&rows[k], &rows[j]));
+                for l in 0..j {
+                    x = round(x - round(&mu[j][l] * &mu[k][l] * &norms[l]));
+                }
+                mu[k][j] = round(x / &norms[j]);
+            }
+            let mut x = Rational::from(dot(&rows[k], &rows[k]));
+            for j in 0..k {
+                x = round(x - round((&mu[k][j]).square() * &norms[j]));
+            }
+            norms[k] = x;
+        }
+        if norms[k] <= 0u32 {
+            return false;
+        }
+        size_reduce(rows, &mut mu, k, k - 1, round);
+        if norms[k] < round(delta - (&mu[k][k - 1]).square()) * &norms[k - 1] {
+            swaps += 1;
+            if max_swaps.map_or(false, |max| swaps > max) {
+                return false;
+            }
+            rows.swap(k, k - 1);
+            mu.swap(k, k - 1);
+            let m = mu[k - 1][k - 1].clone();
+            let norm = round(&norms[k] + round(m.clone().square() * &norms[k - 1]));
+            if norm == 0u32 {
+                return false;
+            }
+            mu[k][k - 1] = round(&m * &norms[k - 1] / &norm);
+            norms[k] = round(&norms[k - 1] * &norms[k] / &norm);
+            norms[k - 1] = norm;
+            for i in k + 1..=k_max {
+                let t = mu[i][k].clone();
+                mu[i][k] = round(&mu[i][k - 1] - &m * &t);
+                mu[i][k - 1] = round(t + &mu[k][k - 1] * &mu[i][k]);
+            }
+            if k > 1 {
+                k -= 1;
+            }
+        } else {
+            for l in (0..k - 1).rev() {
+                size_reduce(rows, &mut mu, k, l, round);
+            }
+            k += 1;
+        }
+    }
+    true
+}
+
+// Depth-first Schnorr-Euchner enumeration of the lattice spanned by the projections of rows
+// `start..end` orthogonally to the rows before `start`. `x` holds the coefficients chosen so far
+// for the rows after `j`; a vector is recorded in `best_x` whenever its squared norm is less than
+// `best`, which then shrinks. To skip the zero vector and halve the search, the last nonzero
+// coefficient is always positive.
+#[allow(clippy::too_many_arguments)]
+fn enumerate(
+    norms: &[Rational],
+    mu: &[Vec<Rational>],
+    start: usize,
+    j: usize,
+    partial: &Rational,
+    x: &mut [Integer],
+    best: &mut Rational,
+    best_x: &mut Option<Vec<Integer>>,
+) {
+    let end = start + x.len();
+    let higher_zero = x[j - start + 1..].iter().all(|x| *x == 0u32);
+    let mut center = Rational::ZERO;
+    for i in j + 1..end {
+        center -= &mu[i][j] * Rational::from(&x[i - start]);
+    }
+    let nearest = Integer::rounding_from(&center, RoundingMode::Nearest).0;
+    for upward in [true, false] {
+        if !upward && higher_zero {
+            break;
+        }
+        let mut t = if upward {
+            nearest.clone()
+        } else {
+            &nearest - Integer::from(1)
+        };
+        loop {
+            let norm = partial + &norms[j] * (Rational::from(&t) - &center).square();
+            if norm >= *best {
+                break;
+            }
+            x[j - start] = t.clone();
+            if j != start {
+                enumerate(norms, mu, start, j - 1, &norm, x, best, best_x);
+            } else if !higher_zero || t != 0u32 {
+                *best = norm;
+                *best_x = Some(x.to_vec());
+            }
+            if upward {
+                t += Integer::from(1);
+            } else {
+                t -= Integer::from(1);
+            }
+        }
+    }
+    x[j - start] = Integer::ZERO;
+}
+
+// Replaces rows `start..start + xs.len()` by another basis of the same lattice whose first row is
+// the combination of the old rows with coefficients `xs`, which must have gcd 1.
+fn insert_combination(rows: &mut [Vec<Integer>], start: usize, mut xs: Vec<Integer>) {
+    for i in (0..xs.len() - 1).rev() {
+        if xs[i + 1] == 0u32 {
+            continue;
+        }
+        let (gcd, s, t) = (&xs[i]).extended_gcd(&xs[i + 1]);
+        let gcd = Integer::from(gcd);
+        let u = (&xs[i]).div_exact(&gcd);
+        let v = (&xs[i + 1]).div_exact(&gcd);
+        // The transformation [[u, v], [-t, s]] has determinant 1, and maps the coefficients
+        // (xs[i], xs[i + 1]) to (gcd, 0)
+        let (a, b) = (&rows[start + i], &rows[start + i + 1]);
+        let new_a: Vec<Integer> = a
+            .iter()
+            .zip(b.iter())
+            .map(|(x, y)| &u * x + &v * y)
+            .collect();
+        let new_b: Vec<Integer> = a
+            .iter()
+            .zip(b.iter())
+            .map(|(x, y)| &s * y - &t * x)
+            .collect();
+        rows[start + i] = new_a;
+        rows[start + i + 1] = new_b;
+        xs[i] = gcd;
+        xs[i + 1] = Integer::ZERO;
+    }
+}
+
+impl IntegerMatrix {
+    /// LLL-reduces the lattice basis given by the rows of a matrix.
+    ///
+    /// The result spans the same lattice and satisfies the Lovász condition with parameter
+    /// $\delta$: if $b_i^*$ are its Gram-Schmidt vectors and $\mu_{i,j}$ its Gram-Schmidt
+    /// coefficients, then $|\mu_{i,j}| \leq 1/2$ for all $j < i$, and
+    /// $\|b_i^*\|^2 \geq (\delta - \mu_{i,i-1}^2)\|b_{i-1}^*\|^2$ for all $i > 0$. The Gram-Schmidt
+    /// data is kept exactly, as [`Rational`]s.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^6 m^3)$
+    ///
+    /// $M(n, m) = O(n^3 m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum dimension of the matrix, and
+    /// $m$ is the maximum bit length of its entries.
+    ///
+    /// # Panics
+    /// Panics if `delta` is not between 1/4 and 1, exclusive, or if the rows are linearly
+    /// dependent.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    /// use malachite::{Integer, Rational};
+    ///
+    /// let m = IntegerMatrix::from_rows(vec![
+    ///     vec![Integer::from(1), Integer::from(1), Integer::from(1)],
+    ///     vec![Integer::from(-1), Integer::from(0), Integer::from(2)],
+    ///     vec![Integer::from(3), Integer::from(5), Integer::from(6)],
+    /// ]);
+    /// let reduced = m.lll_reduce(&Rational::from_signeds(3, 4));
+    /// assert_eq!(reduced.to_string(), "[[0, 1, 0], [1, 0, 1], [-1, 0, 2]]");
+    /// assert!(reduced.is_lll_reduced(&Rational::from_signeds(3, 4)));
+    /// ```
+    pub fn lll_reduce(&self, delta: &Rational) -> IntegerMatrix {
+        assert_delta(delta);
+        let mut rows = to_rows(self);
+        assert!(
+            lll_in_place(&mut rows, delta, &|x| x, None),
+            "rows must be linearly independent"
+        );
+        from_rows(rows, self.cols())
+    }
+
+    /// LLL-reduces the lattice basis given by the rows of a matrix, keeping the Gram-Schmidt data
+    /// as [`Float`]s with a given precision.
+    ///
+    /// Most of the reduction is done with every Gram-Schmidt quantity rounded to `prec` bits,
+    /// which keeps the intermediate numbers small. Since rounding errors may leave the basis not
+    /// quite reduced, an exact pass finishes the job, so the result is the same kind of basis
+    /// that [`lll_reduce`](Self::lll_reduce) returns. The exact pass is cheap when `prec` is large
+    /// enough; a precision of around twice the dimension plus the bit length of the entries
+    /// usually suffices.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^6 m^3)$
+    ///
+    /// $M(n, m) = O(n^3 m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum dimension of the matrix, and
+    /// $m$ is the maximum bit length of its entries.
+    ///
+    /// # Panics
+    /// Panics if `delta` is not between 1/4 and 1, exclusive, if `prec` is zero, or if the rows
+    /// are linearly dependent.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    /// use malachite::{Integer, Rational};
+    ///
+    /// let m = IntegerMatrix::from_rows(vec![
+    ///     vec![Integer::from(1), Integer::from(1), Integer::from(1)],
+    ///     vec![Integer::from(-1), Integer::from(0), Integer::from(2)],
+    ///     vec![Integer::from(3), Integer::from(5), Integer::from(6)],
+    /// ]);
+    /// let delta = Rational::from_signeds(3, 4);
+    /// assert_eq!(m.lll_reduce_with_precision(&delta, 20), m.lll_reduce(&delta));
+    /// ```
+    #[cfg(feature = "floats")]
+    pub fn lll_reduce_with_precision(&self, delta: &Rational, prec: u64) -> IntegerMatrix {
+        assert_delta(delta);
+        assert_ne!(prec, 0, "precision must be positive");
+        let mut rows = to_rows(self);
+        let n = u64::exact_from(rows.len());
+        let bits = self
+            .entries()
+            .iter()
+            .map(SignificantBits::significant_bits)
+            .max()
+            .unwrap_or(0);
+        // With too little precision the floating-point pass may not terminate, so it gives up
+        // after many more swaps than an exact reduction could need
+        let max_swaps = (n * n * (bits + 1)) << 6;
+        lll_in_place(
+            &mut rows,
+            delta,
+            &|x| Rational::exact_from(&Float::from_rational_prec(x, prec).0),
+            Some(max_swaps),
+        );
+        assert!(
+            lll_in_place(&mut rows, delta, &|x| x, None),
+            "rows must be linearly independent"
+        );
+        from_rows(rows, self.cols())
+    }
+
+    /// Determines whether the rows of a matrix form an LLL-reduced basis with parameter $\delta$;
+    /// see [`lll_reduce`](Self::lll_reduce). Returns `false` if the rows are linearly dependent.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^5 m^2)$
+    ///
+    /// $M(n, m) = O(n^3 m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum dimension of the matrix, and
+    /// $m$ is the maximum bit length of its entries.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    /// use malachite::{Integer, Rational};
+    ///
+    /// let m = IntegerMatrix::from_rows(vec![
+    ///     vec![Integer::from(1), Integer::from(0)],
+    ///     vec![Integer::from(5), Integer::from(1)],
+    /// ]);
+    /// assert!(!m.is_lll_reduced(&Rational::from_signeds(3, 4)));
+    /// assert!(IntegerMatrix::identity(2).is_lll_reduced(&Rational::from_signeds(3, 4)));
+    /// ```
+    pub fn is_lll_reduced(&self, delta: &Rational) -> bool {
+        let (norms, mu) = match gram_schmidt(&to_rows(self)) {
+            Some(data) => data,
+            None => return false,
+        };
+        (1..norms.len()).all(|i| {
+            mu[i][..i].iter().all(|m| m.abs() <= Rational::ONE_HALF)
+                && norms[i] >= (delta - (&mu[i][i - 1]).square()) * &norms[i - 1]
+        })
+    }
+
+    /// BKZ-reduces the lattice basis given by the rows of a matrix.
+    ///
+    /// The basis is first LLL-reduced. Then, for each window of `block_size` consecutive rows, a
+    /// shortest nonzero vector of the window's lattice, projected orthogonally to the rows before
+    /// it, is found by enumeration. If it is shorter than $\sqrt\delta$ times the window's first
+    /// Gram-Schmidt vector, it is inserted at the start of the window and the basis is LLL-reduced
+    /// again. This repeats until no window changes. Larger blocks give shorter bases; a block size
+    /// equal to the number of rows gives a basis whose first row is a shortest nonzero vector of
+    /// the lattice.
+    ///
+    /// # Worst-case complexity
+    /// The enumeration takes time exponential in `block_size`, and the number of passes is not
+    /// bounded by a polynomial. Each LLL reduction has the complexity given for
+    /// [`lll_reduce`](Self::lll_reduce).
+    ///
+    /// # Panics
+    /// Panics if `block_size` is less than 2, if `delta` is not between 1/4 and 1, exclusive, or
+    /// if the rows are linearly dependent.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    /// use malachite::{Integer, Rational};
+    ///
+    /// // Look for small x_i with 51199x_1 + 58334x_2 + 27603x_3 + 60185x_4 = 0
+    /// let values = [51199, 58334, 27603, 60185];
+    /// let m = IntegerMatrix::from_rows(
+    ///     (0..4)
+    ///         .map(|i| {
+    ///             let mut row = vec![Integer::from(0); 5];
+    ///             row[i] = Integer::from(1);
+    ///             row[4] = Integer::from(values[i]);
+    ///             row
+    ///         })
+    ///         .collect(),
+    /// );
+    /// let lll = m.lll_reduce(&Rational::from_signeds(3, 4));
+    /// assert_eq!(lll.row(0), &[-6, 2, -4, 5, -13]);
+    /// let bkz = m.bkz_reduce(4, &Rational::from_signeds(99, 100));
+    /// assert_eq!(bkz.row(0), &[7, -11, -5, 7, -1]);
+    /// ```
+    pub fn bkz_reduce(&self, block_size: usize, delta: &Rational) -> IntegerMatrix {
+        assert!(block_size >= 2, "block size must be at least 2");
+        let mut rows = to_rows(&self.lll_reduce(delta));
+        let n = rows.len();
+        loop {
+            let mut changed = false;
+            for k in 0..n.saturating_sub(1) {
+                let end = min(k + block_size, n);
+                let (norms, mu) = gram_schmidt(&rows).unwrap();
+                let mut best = delta * &norms[k];
+                let mut best_x = None;
+                let mut x = vec![Integer::ZERO; end - k];
+                enumerate(
+                    &norms,
+                    &mu,
+                    k,
+                    end - 1,
+                    &Rational::ZERO,
+                    &mut x,
+                    &mut best,
+                    &mut best_x,
+                );
+                if let Some(xs) = best_x {
+                    insert_combination(&mut rows, k, xs);
+                    lll_in_place(&mut rows, delta, &|x| x, None);
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        from_rows(rows, self.cols())
+    }
+}
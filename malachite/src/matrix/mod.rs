@@ -0,0 +1,171 @@
+//! Dense matrices.
+//!
+//! Two entry types are supported:
+//! - [`IntegerMatrix`](integer::IntegerMatrix), with [`Integer`] entries;
+//! - [`RationalMatrix`](rational::RationalMatrix), with [`Rational`] entries.
+//!
+//! Matrices are stored in row-major order, and may have zero rows or columns.
+//!
+//! Multiplication uses the classical algorithm for small matrices, and Strassen's algorithm once
+//! every dimension reaches a threshold. Elimination over $\Z$ is fraction-free (Bareiss), so no
+//! gcds are needed; determinants of [`IntegerMatrix`](integer::IntegerMatrix)es are computed
+//! modulo enough primes to exceed Hadamard's bound and then combined using the Chinese remainder
+//! theorem. [`RationalMatrix`](rational::RationalMatrix) operations clear denominators row by row
+//! and reuse the integer algorithms where possible.
+//!
+//! The rows of an [`IntegerMatrix`](integer::IntegerMatrix) may also be treated as a lattice
+//! basis, which can be LLL- or BKZ-reduced.
+//!
+//! # Examples
+//! ```
+//! use malachite::matrix::integer::IntegerMatrix;
+//! use malachite::Integer;
+//!
+//! let m = IntegerMatrix::from_rows(vec![
+//!     vec![Integer::from(2), Integer::from(1)],
+//!     vec![Integer::from(4), Integer::from(3)],
+//! ]);
+//! assert_eq!(m.determinant(), 2);
+//! assert_eq!(m.inverse().unwrap().to_string(), "[[3/2, -1/2], [-2, 1]]");
+//! assert_eq!((&m * &m).to_string(), "[[8, 5], [20, 13]]");
+//! ```
+//!
+//! [`Integer`]: malachite_nz::integer::Integer
+//! [`Rational`]: malachite_q::Rational
+
+use malachite_base::num::basic::traits::Zero;
+use std::fmt::{self, Display, Formatter, Write};
+use std::ops::{Add, AddAssign, Mul, Sub};
+
+// Strassen's algorithm is used when all three dimensions of a product are at least this large.
+const STRASSEN_THRESHOLD: usize = 64;
+
+fn mul_classical<T>(xs: &[T], ys: &[T], n: usize, m: usize, k: usize) -> Vec<T>
+where
+    T: AddAssign<T> + Clone + PartialEq + Zero,
+    for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+    let mut out = vec![T::ZERO; n * k];
+    for (out_row, x_row) in out.chunks_mut(k.max(1)).zip(xs.chunks(m.max(1))).take(n) {
+        for (x, y_row) in x_row.iter().zip(ys.chunks(k.max(1))) {
+            if *x == T::ZERO {
+                continue;
+            }
+            for (z, y) in out_row.iter_mut().zip(y_row.iter()) {
+                *z += x * y;
+            }
+        }
+    }
+    out
+}
+
+// Copies the `block_rows` x `block_cols` block starting at (`row`, `col`) out of a matrix with
+// `cols` columns, padding with zeros where the block extends past the matrix.
+#[allow(clippy::too_many_arguments)]
+fn block<T: Clone + Zero>(
+    xs: &[T],
+    rows: usize,
+    cols: usize,
+    row: usize,
+    col: usize,
+    block_rows: usize,
+    block_cols: usize,
+) -> Vec<T> {
+    let mut out = Vec::with_capacity(block_rows * block_cols);
+    for i in row..row + block_rows {
+        for j in col..col + block_cols {
+            out.push(if i < rows && j < cols {
+                xs[i * cols + j].clone()
+            } else {
+                T::ZERO
+            });
+        }
+    }
+    out
+}
+
+fn add_entries<T>(xs: &[T], ys: &[T]) -> Vec<T>
+where
+    for<'a> &'a T: Add<&'a T, Output = T>,
+{
+    xs.iter().zip(ys.iter()).map(|(x, y)| x + y).collect()
+}
+
+fn sub_entries<T>(xs: &[T], ys: &[T]) -> Vec<T>
+where
+    for<'a> &'a T: Sub<&'a T, Output = T>,
+{
+    xs.iter().zip(ys.iter()).map(|(x, y)| x - y).collect()
+}
+
+// Multiplies an `n` x `m` matrix by an `m` x `k` matrix, both stored in row-major order.
+pub(crate) fn mul_entries<T>(xs: &[T], ys: &[T], n: usize, m: usize, k: usize) -> Vec<T>
+where
+    T: AddAssign<T> + Clone + PartialEq + Zero,
+    for<'a> &'a T: Add<&'a T, Output = T> + Sub<&'a T, Output = T> + Mul<&'a T, Output = T>,
+{
+    if n < STRASSEN_THRESHOLD || m < STRASSEN_THRESHOLD || k < STRASSEN_THRESHOLD {
+        return mul_classical(xs, ys, n, m, k);
+    }
+    // Split each dimension in half, padding odd dimensions with zeros.
+    let (n_2, m_2, k_2) = ((n + 1) >> 1, (m + 1) >> 1, (k + 1) >> 1);
+    let a = |i, j| block(xs, n, m, i * n_2, j * m_2, n_2, m_2);
+    let b = |i, j| block(ys, m, k, i * m_2, j * k_2, m_2, k_2);
+    let (a_11, a_12, a_21, a_22) = (a(0, 0), a(0, 1), a(1, 0), a(1, 1));
+    let (b_11, b_12, b_21, b_22) = (b(0, 0), b(0, 1), b(1, 0), b(1, 1));
+    let mul = |x: &[T], y: &[T]| mul_entries(x, y, n_2, m_2, k_2);
+    let p_1 = mul(&add_entries(&a_11, &a_22), &add_entries(&b_11, &b_22));
+    let p_2 = mul(&add_entries(&a_21, &a_22), &b_11);
+    let p_3 = mul(&a_11, &sub_entries(&b_12, &b_22));
+    let p_4 = mul(&a_22, &sub_entries(&b_21, &b_11));
+    let p_5 = mul(&add_entries(&a_11, &a_12), &b_22);
+    let p_6 = mul(&sub_entries(&a_21, &a_11), &add_entries(&b_11, &b_12));
+    let p_7 = mul(&sub_entries(&a_12, &a_22), &add_entries(&b_21, &b_22));
+    let c_11 = add_entries(&sub_entries(&add_entries(&p_1, &p_4), &p_5), &p_7);
+    let c_12 = add_entries(&p_3, &p_5);
+    let c_21 = add_entries(&p_2, &p_4);
+    let c_22 = add_entries(&add_entries(&sub_entries(&p_1, &p_2), &p_3), &p_6);
+    let mut out = Vec::with_capacity(n * k);
+    for i in 0..n {
+        for j in 0..k {
+            let c = match (i < n_2, j < k_2) {
+                (true, true) => &c_11,
+                (true, false) => &c_12,
+                (false, true) => &c_21,
+                (false, false) => &c_22,
+            };
+            out.push(c[(i % n_2) * k_2 + j % k_2].clone());
+        }
+    }
+    out
+}
+
+// Writes a matrix as a list of rows, for example "[[1, 2], [3, 4]]".
+pub(crate) fn write_rows<T: Display>(
+    f: &mut Formatter,
+    entries: &[T],
+    rows: usize,
+    cols: usize,
+) -> fmt::Result {
+    f.write_char('[')?;
+    for i in 0..rows {
+        if i != 0 {
+            f.write_str(", ")?;
+        }
+        f.write_char('[')?;
+        for (j, x) in entries[i * cols..(i + 1) * cols].iter().enumerate() {
+            if j != 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}", x)?;
+        }
+        f.write_char(']')?;
+    }
+    f.write_char(']')
+}
+
+/// Matrices with [`Integer`](malachite_nz::integer::Integer) entries.
+pub mod integer;
+mod lattice;
+/// Matrices with [`Rational`](malachite_q::Rational) entries.
+pub mod rational;
@@ -0,0 +1,703 @@
+use crate::matrix::integer::IntegerMatrix;
+use crate::matrix::{mul_entries, write_rows};
+use malachite_base::num::arithmetic::traits::Lcm;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A dense matrix with [`Rational`] entries.
+///
+/// # Examples
+/// ```
+/// use malachite::matrix::rational::RationalMatrix;
+/// use malachite::Rational;
+///
+/// let m = RationalMatrix::from_rows(vec![
+///     vec![Rational::from_signeds(1, 2), Rational::from(1)],
+///     vec![Rational::from(1), Rational::from(2)],
+/// ]);
+/// assert_eq!(m.determinant(), 0);
+/// assert_eq!(m.rank(), 1);
+/// ```
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct RationalMatrix {
+    rows: usize,
+    cols: usize,
+    // The entries, in row-major order.
+    entries: Vec<Rational>,
+}
+
+// Returns the lcm of the denominators of some `Rational`s.
+fn denominator_lcm<'a, I: Iterator<Item = &'a Rational>>(xs: I) -> Natural {
+    let mut lcm = Natural::ONE;
+    for x in xs {
+        if *x.denominator_ref() != 1u32 {
+            lcm = lcm.lcm(x.denominator_ref());
+        }
+    }
+    lcm
+}
+
+// Multiplies some `Rational`s by a multiple of all of their denominators.
+fn scale_to_integers(xs: &[Rational], multiple: &Natural) -> Vec<Integer> {
+    xs.iter()
+        .map(|x| {
+            Integer::from_sign_and_abs(
+                *x >= 0u32,
+                x.numerator_ref() * (multiple / x.denominator_ref()),
+            )
+        })
+        .collect()
+}
+
+impl RationalMatrix {
+    /// Returns the zero matrix with the given dimensions.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `rows * cols`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::rational::RationalMatrix;
+    ///
+    /// assert_eq!(RationalMatrix::zero(1, 2).to_string(), "[[0, 0]]");
+    /// ```
+    pub fn zero(rows: usize, cols: usize) -> RationalMatrix {
+        RationalMatrix {
+            rows,
+            cols,
+            entries: vec![Rational::ZERO; rows * cols],
+        }
+    }
+
+    /// Returns the $n \times n$ identity matrix.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n^2)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::rational::RationalMatrix;
+    ///
+    /// assert_eq!(RationalMatrix::identity(2).to_string(), "[[1, 0], [0, 1]]");
+    /// ```
+    pub fn identity(n: usize) -> RationalMatrix {
+        let mut m = RationalMatrix::zero(n, n);
+        for i in 0..n {
+            m.entries[i * (n + 1)] = Rational::ONE;
+        }
+        m
+    }
+
+    /// Creates a matrix from its entries, given in row-major order.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if `entries.len()` is not `rows * cols`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::rational::RationalMatrix;
+    /// use malachite::Rational;
+    ///
+    /// let m = RationalMatrix::from_entries(1, 2, vec![Rational::from(1), Rational::from_signeds(1, 2)]);
+    /// assert_eq!(m.to_string(), "[[1, 1/2]]");
+    /// ```
+    pub fn from_entries(rows: usize, cols: usize, entries: Vec<Rational>) -> RationalMatrix {
+        assert_eq!(entries.len(), rows * cols, "wrong number of entries");
+        RationalMatrix {
+            rows,
+            cols,
+            entries,
+        }
+    }
+
+    /// Creates a matrix from a list of rows.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of entries.
+    ///
+    /// # Panics
+    /// Panics if the rows don't all have the same length.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::rational::RationalMatrix;
+    /// use malachite::Rational;
+    ///
+    /// let m = RationalMatrix::from_rows(vec![
+    ///     vec![Rational::from(1), Rational::from_signeds(-1, 2)],
+    ///     vec![Rational::from(3), Rational::from(4)],
+    /// ]);
+    /// assert_eq!(m.to_string(), "[[1, -1/2], [3, 4]]");
+    /// ```
+    pub fn from_rows(rows: Vec<Vec<Rational>>) -> RationalMatrix {
+        let cols = rows.first().map_or(0, Vec::len);
+        let row_count = rows.len();
+        let mut entries = Vec::with_capacity(row_count * cols);
+        for row in rows {
+            assert_eq!(row.len(), cols, "rows must all have the same length");
+            entries.extend(row);
+        }
+        RationalMatrix {
+            rows: row_count,
+            cols,
+            entries,
+        }
+    }
+
+    /// Returns the number of rows of a matrix.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::rational::RationalMatrix;
+    ///
+    /// assert_eq!(RationalMatrix::zero(2, 3).rows(), 2);
+    /// ```
+    pub const fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns of a matrix.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::rational::RationalMatrix;
+    ///
+    /// assert_eq!(RationalMatrix::zero(2, 3).cols(), 3);
+    /// ```
+    pub const fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the entries of a matrix, in row-major order.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::rational::RationalMatrix;
+    ///
+    /// assert_eq!(RationalMatrix::identity(2).entries(), &[1, 0, 0, 1]);
+    /// ```
+    pub fn entries(&self) -> &[Rational] {
+        &self.entries
+    }
+
+    /// Returns the entries of a matrix, in row-major order, taking the matrix by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::rational::RationalMatrix;
+    ///
+    /// assert_eq!(RationalMatrix::identity(2).into_entries(), &[1, 0, 0, 1]);
+    /// ```
+    pub fn into_entries(self) -> Vec<Rational> {
+        self.entries
+    }
+
+    /// Returns a row of a matrix.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if `i` is not less than the number of rows.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::rational::RationalMatrix;
+    ///
+    /// assert_eq!(RationalMatrix::identity(3).row(1), &[0, 1, 0]);
+    /// ```
+    pub fn row(&self, i: usize) -> &[Rational] {
+        assert!(i < self.rows, "row index out of range");
+        &self.entries[i * self.cols..(i + 1) * self.cols]
+    }
+
+    /// Returns the transpose of a matrix.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// entries.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::rational::RationalMatrix;
+    /// use malachite::Rational;
+    ///
+    /// let m = RationalMatrix::from_rows(vec![vec![Rational::from(1), Rational::from_signeds(1, 2)]]);
+    /// assert_eq!(m.transpose().to_string(), "[[1], [1/2]]");
+    /// ```
+    pub fn transpose(&self) -> RationalMatrix {
+        let mut entries = Vec::with_capacity(self.entries.len());
+        for j in 0..self.cols {
+            for i in 0..self.rows {
+                entries.push(self.entries[i * self.cols + j].clone());
+            }
+        }
+        RationalMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            entries,
+        }
+    }
+
+    // Multiplies each row by the lcm of its denominators, returning the resulting `IntegerMatrix`
+    // and the multipliers.
+    fn clear_row_denominators(&self) -> (IntegerMatrix, Vec<Natural>) {
+        let mut entries = Vec::with_capacity(self.entries.len());
+        let mut multipliers = Vec::with_capacity(self.rows);
+        for i in 0..self.rows {
+            let row = self.row(i);
+            let lcm = denominator_lcm(row.iter());
+            entries.extend(scale_to_integers(row, &lcm));
+            multipliers.push(lcm);
+        }
+        (
+            IntegerMatrix::from_entries(self.rows, self.cols, entries),
+            multipliers,
+        )
+    }
+
+    /// Returns the rank of a matrix.
+    ///
+    /// The denominators of each row are cleared, and the rank of the resulting [`IntegerMatrix`]
+    /// is computed using fraction-free elimination.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 (nm) \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(n^2 (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum dimension of the matrix, and
+    /// $m$ is the maximum bit length of its entries.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::rational::RationalMatrix;
+    /// use malachite::Rational;
+    ///
+    /// let m = RationalMatrix::from_rows(vec![
+    ///     vec![Rational::from_signeds(1, 3), Rational::from(1)],
+    ///     vec![Rational::from(1), Rational::from(3)],
+    /// ]);
+    /// assert_eq!(m.rank(), 1);
+    /// ```
+    pub fn rank(&self) -> usize {
+        self.clear_row_denominators().0.rank()
+    }
+
+    /// Returns the determinant of a square matrix.
+    ///
+    /// The denominators of each row are cleared, and the determinant of the resulting
+    /// [`IntegerMatrix`] is computed using a multimodular algorithm.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 (n\log n + nm) + n^2 (n\log n + nm)^2)$
+    ///
+    /// $M(n, m) = O(n^2 + n\log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the dimension of the matrix, and $m$ is
+    /// the maximum bit length of its entries.
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::rational::RationalMatrix;
+    /// use malachite::Rational;
+    ///
+    /// let m = RationalMatrix::from_rows(vec![
+    ///     vec![Rational::from_signeds(1, 2), Rational::from_signeds(1, 3)],
+    ///     vec![Rational::from_signeds(1, 4), Rational::from_signeds(1, 5)],
+    /// ]);
+    /// assert_eq!(m.determinant(), Rational::from_signeds(1, 60));
+    /// ```
+    pub fn determinant(&self) -> Rational {
+        assert_eq!(self.rows, self.cols, "matrix must be square");
+        let (m, multipliers) = self.clear_row_denominators();
+        let mut denominator = Natural::ONE;
+        for multiplier in multipliers {
+            denominator *= multiplier;
+        }
+        Rational::from_integers(m.determinant(), Integer::from(denominator))
+    }
+
+    // Returns the reduced row echelon form of a matrix and the columns of its pivots.
+    fn reduced_row_echelon_form_with_pivots(&self) -> (RationalMatrix, Vec<usize>) {
+        let (rows, cols) = (self.rows, self.cols);
+        let mut a = self.entries.clone();
+        let mut pivots = Vec::new();
+        for c in 0..cols {
+            let r = pivots.len();
+            if r == rows {
+                break;
+            }
+            let p = match (r..rows).find(|&i| a[i * cols + c] != 0u32) {
+                Some(p) => p,
+                None => continue,
+            };
+            if p != r {
+                for j in c..cols {
+                    a.swap(p * cols + j, r * cols + j);
+                }
+            }
+            let pivot = a[r * cols + c].clone();
+            for x in &mut a[r * cols + c..(r + 1) * cols] {
+                *x /= &pivot;
+            }
+            let pivot_row = a[r * cols..(r + 1) * cols].to_vec();
+            for (i, row) in a.chunks_mut(cols).enumerate() {
+                if i == r || row[c] == 0u32 {
+                    continue;
+                }
+                let factor = row[c].clone();
+                for (x, y) in row[c..].iter_mut().zip(pivot_row[c..].iter()) {
+                    *x -= &factor * y;
+                }
+            }
+            pivots.push(c);
+        }
+        (
+            RationalMatrix {
+                rows,
+                cols,
+                entries: a,
+            },
+            pivots,
+        )
+    }
+
+    /// Returns the reduced row echelon form of a matrix, computed using Gauss-Jordan elimination.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 (nm)^2)$
+    ///
+    /// $M(n, m) = O(n^2 (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum dimension of the matrix, and
+    /// $m$ is the maximum bit length of its entries.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::rational::RationalMatrix;
+    /// use malachite::Rational;
+    ///
+    /// let m = RationalMatrix::from_rows(vec![
+    ///     vec![Rational::from(2), Rational::from(4), Rational::from(1)],
+    ///     vec![Rational::from(1), Rational::from(2), Rational::from(3)],
+    /// ]);
+    /// assert_eq!(
+    ///     m.reduced_row_echelon_form().to_string(),
+    ///     "[[1, 2, 0], [0, 0, 1]]"
+    /// );
+    /// ```
+    pub fn reduced_row_echelon_form(&self) -> RationalMatrix {
+        self.reduced_row_echelon_form_with_pivots().0
+    }
+
+    /// Solves the system $AX = B$, where $A$ is `self`, which must be square and nonsingular.
+    /// Returns `None` if $A$ is singular.
+    ///
+    /// The denominators of each row of the augmented matrix are cleared, and the resulting
+    /// integer system is solved using fraction-free elimination.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 (nm) \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(n^2 (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum dimension of the two
+    /// matrices, and $m$ is the maximum bit length of their entries.
+    ///
+    /// # Panics
+    /// Panics if `self` is not square, or if `rhs` has a different number of rows.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::rational::RationalMatrix;
+    /// use malachite::Rational;
+    ///
+    /// // x/2 + y = 1, x - y = 1/2
+    /// let a = RationalMatrix::from_rows(vec![
+    ///     vec![Rational::from_signeds(1, 2), Rational::from(1)],
+    ///     vec![Rational::from(1), Rational::from(-1)],
+    /// ]);
+    /// let b = RationalMatrix::from_rows(vec![
+    ///     vec![Rational::from(1)],
+    ///     vec![Rational::from_signeds(1, 2)],
+    /// ]);
+    /// assert_eq!(a.solve(&b).unwrap().to_string(), "[[1], [1/2]]");
+    /// ```
+    pub fn solve(&self, rhs: &RationalMatrix) -> Option<RationalMatrix> {
+        assert_eq!(self.rows, self.cols, "matrix must be square");
+        assert_eq!(self.rows, rhs.rows, "dimension mismatch");
+        let mut a = Vec::with_capacity(self.entries.len());
+        let mut b = Vec::with_capacity(rhs.entries.len());
+        for i in 0..self.rows {
+            let lcm = denominator_lcm(self.row(i).iter().chain(rhs.row(i).iter()));
+            a.extend(scale_to_integers(self.row(i), &lcm));
+            b.extend(scale_to_integers(rhs.row(i), &lcm));
+        }
+        IntegerMatrix::from_entries(self.rows, self.cols, a)
+            .solve(&IntegerMatrix::from_entries(rhs.rows, rhs.cols, b))
+    }
+
+    /// Returns the inverse of a square matrix, or `None` if it is singular.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 (nm) \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(n^2 (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the dimension of the matrix, and $m$ is
+    /// the maximum bit length of its entries.
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::rational::RationalMatrix;
+    /// use malachite::Rational;
+    ///
+    /// let m = RationalMatrix::from_rows(vec![
+    ///     vec![Rational::from_signeds(1, 2), Rational::from(0)],
+    ///     vec![Rational::from(1), Rational::from(3)],
+    /// ]);
+    /// assert_eq!(m.inverse().unwrap().to_string(), "[[2, 0], [-2/3, 1/3]]");
+    /// ```
+    pub fn inverse(&self) -> Option<RationalMatrix> {
+        self.solve(&RationalMatrix::identity(self.rows))
+    }
+
+    /// Returns a basis of the right nullspace of a matrix: the vectors $x$ with $Ax = 0$. The
+    /// basis vectors are the columns of the result. Each one has a 1 in the position of a
+    /// distinct non-pivot column of the reduced row echelon form, and zeros in the positions of
+    /// the other non-pivot columns.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 (nm)^2)$
+    ///
+    /// $M(n, m) = O(n^2 (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum dimension of the matrix, and
+    /// $m$ is the maximum bit length of its entries.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::rational::RationalMatrix;
+    /// use malachite::Rational;
+    ///
+    /// let m = RationalMatrix::from_rows(vec![vec![
+    ///     Rational::from(2),
+    ///     Rational::from(1),
+    ///     Rational::from_signeds(1, 2),
+    /// ]]);
+    /// assert_eq!(m.nullspace().to_string(), "[[-1/2, -1/4], [1, 0], [0, 1]]");
+    /// ```
+    pub fn nullspace(&self) -> RationalMatrix {
+        let (rref, pivots) = self.reduced_row_echelon_form_with_pivots();
+        let free: Vec<usize> = (0..self.cols).filter(|c| !pivots.contains(c)).collect();
+        let mut basis = RationalMatrix::zero(self.cols, free.len());
+        for (k, &f) in free.iter().enumerate() {
+            basis[(f, k)] = Rational::ONE;
+            for (r, &p) in pivots.iter().enumerate() {
+                basis[(p, k)] = -&rref[(r, f)];
+            }
+        }
+        basis
+    }
+
+    fn neg_ref(&self) -> RationalMatrix {
+        RationalMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            entries: self.entries.iter().map(|x| -x).collect(),
+        }
+    }
+
+    fn add_ref(&self, other: &RationalMatrix) -> RationalMatrix {
+        assert!(
+            self.rows == other.rows && self.cols == other.cols,
+            "dimension mismatch"
+        );
+        RationalMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            entries: self
+                .entries
+                .iter()
+                .zip(other.entries.iter())
+                .map(|(x, y)| x + y)
+                .collect(),
+        }
+    }
+
+    fn sub_ref(&self, other: &RationalMatrix) -> RationalMatrix {
+        assert!(
+            self.rows == other.rows && self.cols == other.cols,
+            "dimension mismatch"
+        );
+        RationalMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            entries: self
+                .entries
+                .iter()
+                .zip(other.entries.iter())
+                .map(|(x, y)| x - y)
+                .collect(),
+        }
+    }
+
+    fn mul_ref(&self, other: &RationalMatrix) -> RationalMatrix {
+        assert_eq!(self.cols, other.rows, "dimension mismatch");
+        RationalMatrix {
+            rows: self.rows,
+            cols: other.cols,
+            entries: mul_entries(
+                &self.entries,
+                &other.entries,
+                self.rows,
+                self.cols,
+                other.cols,
+            ),
+        }
+    }
+}
+
+impl_ring_ops!(RationalMatrix, "matrix", "matrices");
+
+impl From<IntegerMatrix> for RationalMatrix {
+    /// Converts an [`IntegerMatrix`] to a [`RationalMatrix`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of entries.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::integer::IntegerMatrix;
+    /// use malachite::matrix::rational::RationalMatrix;
+    ///
+    /// assert_eq!(
+    ///     RationalMatrix::from(IntegerMatrix::identity(2)),
+    ///     RationalMatrix::identity(2)
+    /// );
+    /// ```
+    fn from(m: IntegerMatrix) -> RationalMatrix {
+        let (rows, cols) = (m.rows(), m.cols());
+        RationalMatrix {
+            rows,
+            cols,
+            entries: m.into_entries().into_iter().map(Rational::from).collect(),
+        }
+    }
+}
+
+impl Index<(usize, usize)> for RationalMatrix {
+    type Output = Rational;
+
+    /// Gets a reference to the entry of a matrix at a given row and column.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if the row or column is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::rational::RationalMatrix;
+    ///
+    /// assert_eq!(RationalMatrix::identity(2)[(0, 1)], 0);
+    /// ```
+    fn index(&self, (i, j): (usize, usize)) -> &Rational {
+        assert!(i < self.rows && j < self.cols, "index out of range");
+        &self.entries[i * self.cols + j]
+    }
+}
+
+impl IndexMut<(usize, usize)> for RationalMatrix {
+    /// Gets a mutable reference to the entry of a matrix at a given row and column.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if the row or column is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::rational::RationalMatrix;
+    /// use malachite::Rational;
+    ///
+    /// let mut m = RationalMatrix::zero(1, 2);
+    /// m[(0, 0)] = Rational::from_signeds(2, 3);
+    /// assert_eq!(m.to_string(), "[[2/3, 0]]");
+    /// ```
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Rational {
+        assert!(i < self.rows && j < self.cols, "index out of range");
+        &mut self.entries[i * self.cols + j]
+    }
+}
+
+impl Display for RationalMatrix {
+    /// Converts a matrix to a [`String`], as a list of rows.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::matrix::rational::RationalMatrix;
+    /// use malachite::Rational;
+    ///
+    /// let m = RationalMatrix::from_rows(vec![vec![Rational::from_signeds(-1, 2)]]);
+    /// assert_eq!(m.to_string(), "[[-1/2]]");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write_rows(f, &self.entries, self.rows, self.cols)
+    }
+}
+
+impl Debug for RationalMatrix {
+    /// Converts a matrix to a [`String`]; the same as [`Display::fmt`].
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
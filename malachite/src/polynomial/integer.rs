@@ -0,0 +1,956 @@
+use crate::polynomial::modular::ModularPolynomial;
+use crate::polynomial::{large_primes, mul_integer_coefficients, write_terms};
+use malachite_base::num::arithmetic::traits::{
+    DivExact, DivRem, Gcd, Mod, ModInverse, ModMul, ModSub, Pow,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::max;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::mem::swap;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A dense univariate polynomial with [`Integer`] coefficients.
+///
+/// # Examples
+/// ```
+/// use malachite::polynomial::integer::IntegerPolynomial;
+/// use malachite::Integer;
+///
+/// // 2x^2 - 3
+/// let f = IntegerPolynomial::from_coefficients(vec![Integer::from(-3), 0.into(), 2.into()]);
+/// assert_eq!(f.to_string(), "2*x^2 - 3");
+/// assert_eq!(f.degree(), Some(2));
+/// assert_eq!(f.evaluate(&Integer::from(5)), 47);
+/// ```
+#[derive(Clone, Default, Eq, Hash, PartialEq)]
+pub struct IntegerPolynomial {
+    // The coefficients, starting with the constant term. The last coefficient is nonzero.
+    coefficients: Vec<Integer>,
+}
+
+impl IntegerPolynomial {
+    /// Creates a polynomial from its coefficients, starting with the constant term. Trailing zeros
+    /// are removed.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `coefficients.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::integer::IntegerPolynomial;
+    /// use malachite::Integer;
+    ///
+    /// let f = IntegerPolynomial::from_coefficients(vec![Integer::from(1), 2.into(), 0.into()]);
+    /// assert_eq!(f.to_string(), "2*x + 1");
+    /// assert_eq!(IntegerPolynomial::from_coefficients(vec![Integer::from(0)]).to_string(), "0");
+    /// ```
+    pub fn from_coefficients(mut coefficients: Vec<Integer>) -> IntegerPolynomial {
+        while coefficients.last() == Some(&Integer::ZERO) {
+            coefficients.pop();
+        }
+        IntegerPolynomial { coefficients }
+    }
+
+    /// Returns the polynomial $cx^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `degree`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::integer::IntegerPolynomial;
+    /// use malachite::Integer;
+    ///
+    /// assert_eq!(IntegerPolynomial::monomial(Integer::from(-3), 2).to_string(), "-3*x^2");
+    /// ```
+    pub fn monomial(c: Integer, degree: u64) -> IntegerPolynomial {
+        let mut coefficients = vec![Integer::ZERO; usize::exact_from(degree)];
+        coefficients.push(c);
+        IntegerPolynomial::from_coefficients(coefficients)
+    }
+
+    /// Returns the coefficients of a polynomial, starting with the constant term. The last
+    /// coefficient, if any, is nonzero.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::integer::IntegerPolynomial;
+    /// use malachite::Integer;
+    ///
+    /// let f = IntegerPolynomial::from_coefficients(vec![Integer::from(1), 2.into()]);
+    /// assert_eq!(f.coefficients(), &[1, 2]);
+    /// ```
+    pub fn coefficients(&self) -> &[Integer] {
+        &self.coefficients
+    }
+
+    /// Returns the coefficients of a polynomial, starting with the constant term, taking the
+    /// polynomial by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::integer::IntegerPolynomial;
+    /// use malachite::Integer;
+    ///
+    /// let f = IntegerPolynomial::from_coefficients(vec![Integer::from(1), 2.into()]);
+    /// assert_eq!(f.into_coefficients(), &[1, 2]);
+    /// ```
+    pub fn into_coefficients(self) -> Vec<Integer> {
+        self.coefficients
+    }
+
+    /// Returns the degree of a polynomial, or `None` if it is zero.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::num::basic::traits::Zero;
+    /// use malachite::polynomial::integer::IntegerPolynomial;
+    /// use malachite::Integer;
+    ///
+    /// assert_eq!(IntegerPolynomial::ZERO.degree(), None);
+    /// assert_eq!(IntegerPolynomial::from(Integer::from(5)).degree(), Some(0));
+    /// assert_eq!(IntegerPolynomial::monomial(Integer::from(5), 3).degree(), Some(3));
+    /// ```
+    pub fn degree(&self) -> Option<u64> {
+        self.coefficients
+            .len()
+            .checked_sub(1)
+            .map(u64::exact_from)
+    }
+
+    /// Returns the leading coefficient of a polynomial, or `None` if it is zero.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::num::basic::traits::Zero;
+    /// use malachite::polynomial::integer::IntegerPolynomial;
+    /// use malachite::Integer;
+    ///
+    /// assert_eq!(IntegerPolynomial::ZERO.leading_coefficient(), None);
+    /// assert_eq!(
+    ///     IntegerPolynomial::monomial(Integer::from(5), 3).leading_coefficient(),
+    ///     Some(&Integer::from(5))
+    /// );
+    /// ```
+    pub fn leading_coefficient(&self) -> Option<&Integer> {
+        self.coefficients.last()
+    }
+
+    fn degree_usize(&self) -> usize {
+        self.coefficients.len() - 1
+    }
+
+    /// Evaluates a polynomial at a point, using Horner's method.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(nm \log (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the length of the polynomial, and $m$
+    /// is the maximum of the bit lengths of `x` and of the coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::integer::IntegerPolynomial;
+    /// use malachite::Integer;
+    ///
+    /// // x^3 - 2x + 1
+    /// let f = IntegerPolynomial::from_coefficients(vec![
+    ///     Integer::from(1),
+    ///     Integer::from(-2),
+    ///     Integer::from(0),
+    ///     Integer::from(1),
+    /// ]);
+    /// assert_eq!(f.evaluate(&Integer::from(0)), 1);
+    /// assert_eq!(f.evaluate(&Integer::from(1)), 0);
+    /// assert_eq!(f.evaluate(&Integer::from(-3)), -20);
+    /// ```
+    pub fn evaluate(&self, x: &Integer) -> Integer {
+        let mut result = Integer::ZERO;
+        for c in self.coefficients.iter().rev() {
+            result *= x;
+            result += c;
+        }
+        result
+    }
+
+    /// Composes two polynomials, returning $f(g(x))$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^2m \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(n^2m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum length of the two
+    /// polynomials, and $m$ is the maximum bit length of their coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::integer::IntegerPolynomial;
+    /// use malachite::Integer;
+    ///
+    /// // x^2 - 1 and 2x + 1
+    /// let f = IntegerPolynomial::from_coefficients(vec![Integer::from(-1), 0.into(), 1.into()]);
+    /// let g = IntegerPolynomial::from_coefficients(vec![Integer::from(1), 2.into()]);
+    /// assert_eq!(f.compose(&g).to_string(), "4*x^2 + 4*x");
+    /// assert_eq!(g.compose(&f).to_string(), "2*x^2 - 1");
+    /// ```
+    pub fn compose(&self, other: &IntegerPolynomial) -> IntegerPolynomial {
+        let mut result = IntegerPolynomial::ZERO;
+        for c in self.coefficients.iter().rev() {
+            result = result.mul_ref(other);
+            result = result.add_ref(&IntegerPolynomial::from(c.clone()));
+        }
+        result
+    }
+
+    /// Returns the derivative of a polynomial.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n(m + \log n))$
+    ///
+    /// $M(n, m) = O(n(m + \log n))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the length of the polynomial, and $m$
+    /// is the maximum bit length of its coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::integer::IntegerPolynomial;
+    /// use malachite::Integer;
+    ///
+    /// // x^3 - 2x + 1
+    /// let f = IntegerPolynomial::from_coefficients(vec![
+    ///     Integer::from(1),
+    ///     Integer::from(-2),
+    ///     Integer::from(0),
+    ///     Integer::from(1),
+    /// ]);
+    /// assert_eq!(f.derivative().to_string(), "3*x^2 - 2");
+    /// ```
+    pub fn derivative(&self) -> IntegerPolynomial {
+        IntegerPolynomial::from_coefficients(
+            self.coefficients
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, c)| c * Integer::from(i))
+                .collect(),
+        )
+    }
+
+    /// Returns the content of a polynomial: the non-negative gcd of its coefficients. The content
+    /// of the zero polynomial is zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm^2)$
+    ///
+    /// $M(m) = O(m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the length of the polynomial, and $m$
+    /// is the maximum bit length of its coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::integer::IntegerPolynomial;
+    /// use malachite::Integer;
+    ///
+    /// let f = IntegerPolynomial::from_coefficients(vec![Integer::from(6), (-4).into(), 10.into()]);
+    /// assert_eq!(f.content(), 2);
+    /// ```
+    pub fn content(&self) -> Natural {
+        let mut content = Natural::ZERO;
+        for c in &self.coefficients {
+            content = content.gcd(c.unsigned_abs_ref());
+            if content == 1u32 {
+                break;
+            }
+        }
+        content
+    }
+
+    /// Returns the primitive part of a polynomial: the polynomial divided by its content, with the
+    /// sign chosen so that the leading coefficient is positive. The primitive part of the zero
+    /// polynomial is zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm^2)$
+    ///
+    /// $M(n, m) = O(nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the length of the polynomial, and $m$
+    /// is the maximum bit length of its coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::integer::IntegerPolynomial;
+    /// use malachite::Integer;
+    ///
+    /// let f = IntegerPolynomial::from_coefficients(vec![Integer::from(6), 4.into(), (-10).into()]);
+    /// assert_eq!(f.primitive_part().to_string(), "5*x^2 - 2*x - 3");
+    /// ```
+    pub fn primitive_part(&self) -> IntegerPolynomial {
+        match self.leading_coefficient() {
+            None => IntegerPolynomial::ZERO,
+            Some(lc) => {
+                let content = Integer::from(self.content());
+                self.div_exact_scalar(&if *lc > 0 { content } else { -content })
+            }
+        }
+    }
+
+    fn div_exact_scalar(&self, c: &Integer) -> IntegerPolynomial {
+        if *c == 1 {
+            return self.clone();
+        }
+        IntegerPolynomial {
+            coefficients: self
+                .coefficients
+                .iter()
+                .map(|x| x.clone().div_exact(c))
+                .collect(),
+        }
+    }
+
+    fn mul_scalar(&self, c: &Integer) -> IntegerPolynomial {
+        IntegerPolynomial::from_coefficients(self.coefficients.iter().map(|x| x * c).collect())
+    }
+
+    /// Computes the pseudo-quotient and pseudo-remainder of two polynomials.
+    ///
+    /// If $a$ has degree $m$ and $b$ has degree $n \leq m$ and leading coefficient $\ell$, this
+    /// returns $(q, r)$ such that $\ell^{m-n+1}a = qb + r$ and $r$ has a smaller degree than $b$.
+    /// No division of coefficients is needed. If $a$ has a smaller degree than $b$, $(0, a)$ is
+    /// returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3m)$
+    ///
+    /// $M(n, m) = O(n(nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the length of `self`, and $m$ is the
+    /// maximum bit length of the coefficients of both polynomials.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::integer::IntegerPolynomial;
+    /// use malachite::Integer;
+    ///
+    /// // x^2 + 1 and 2x + 1
+    /// let f = IntegerPolynomial::from_coefficients(vec![Integer::from(1), 0.into(), 1.into()]);
+    /// let g = IntegerPolynomial::from_coefficients(vec![Integer::from(1), 2.into()]);
+    /// let (q, r) = f.pseudo_div_rem(&g);
+    /// assert_eq!(q.to_string(), "2*x - 1");
+    /// assert_eq!(r.to_string(), "5");
+    /// ```
+    pub fn pseudo_div_rem(
+        &self,
+        other: &IntegerPolynomial,
+    ) -> (IntegerPolynomial, IntegerPolynomial) {
+        let lc = other.leading_coefficient().expect("division by zero");
+        let n = other.coefficients.len();
+        if self.coefficients.len() < n {
+            return (IntegerPolynomial::ZERO, self.clone());
+        }
+        let mut remainder = self.coefficients.clone();
+        let mut quotient = vec![Integer::ZERO; remainder.len() - n + 1];
+        for i in (0..quotient.len()).rev() {
+            // lc^k * self = quotient * other + remainder holds before each step, with k
+            // incremented by each step.
+            let c = remainder.pop().unwrap();
+            for x in remainder.iter_mut().chain(quotient[i + 1..].iter_mut()) {
+                *x *= lc;
+            }
+            if c != 0 {
+                for (x, y) in remainder[i..].iter_mut().zip(other.coefficients.iter()) {
+                    *x -= &c * y;
+                }
+            }
+            quotient[i] = c;
+        }
+        (
+            IntegerPolynomial::from_coefficients(quotient),
+            IntegerPolynomial::from_coefficients(remainder),
+        )
+    }
+
+    // Divides `self` by `other`, returning `None` if `other` doesn't divide `self` in $\Z[x]$.
+    fn checked_div_exact(&self, other: &IntegerPolynomial) -> Option<IntegerPolynomial> {
+        let lc = other.leading_coefficient().expect("division by zero");
+        let n = other.coefficients.len();
+        if self.coefficients.len() < n {
+            return if self.coefficients.is_empty() {
+                Some(IntegerPolynomial::ZERO)
+            } else {
+                None
+            };
+        }
+        let mut remainder = self.coefficients.clone();
+        let mut quotient = vec![Integer::ZERO; remainder.len() - n + 1];
+        for i in (0..quotient.len()).rev() {
+            let (c, r) = remainder.pop().unwrap().div_rem(lc);
+            if r != 0 {
+                return None;
+            }
+            if c != 0 {
+                for (x, y) in remainder[i..].iter_mut().zip(other.coefficients.iter()) {
+                    *x -= &c * y;
+                }
+            }
+            quotient[i] = c;
+        }
+        if remainder.iter().any(|x| *x != 0) {
+            None
+        } else {
+            Some(IntegerPolynomial::from_coefficients(quotient))
+        }
+    }
+
+    fn div_exact_ref(&self, other: &IntegerPolynomial) -> IntegerPolynomial {
+        self.checked_div_exact(other)
+            .expect("polynomial division is not exact")
+    }
+
+    // Returns `self` or its negative, whichever has a positive leading coefficient.
+    fn normalize_sign(self) -> IntegerPolynomial {
+        match self.leading_coefficient() {
+            Some(lc) if *lc < 0 => -self,
+            _ => self,
+        }
+    }
+
+    /// Returns the greatest common divisor of two polynomials, with a positive leading
+    /// coefficient. The gcd of two zero polynomials is zero.
+    ///
+    /// A modular algorithm is used: the gcd is computed modulo several primes less than $2^{62}$,
+    /// and the images are combined using the Chinese remainder theorem until the lifted result
+    /// divides both inputs.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3(n + m)^2)$
+    ///
+    /// $M(n, m) = O(n(n + m))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum length of the two
+    /// polynomials, and $m$ is the maximum bit length of their coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::integer::IntegerPolynomial;
+    /// use malachite::Integer;
+    ///
+    /// // 2x^2 - 2 and 4x^2 + 8x + 4
+    /// let f = IntegerPolynomial::from_coefficients(vec![Integer::from(-2), 0.into(), 2.into()]);
+    /// let g = IntegerPolynomial::from_coefficients(vec![Integer::from(4), 8.into(), 4.into()]);
+    /// assert_eq!(f.gcd(&g).to_string(), "2*x + 2");
+    /// ```
+    pub fn gcd(&self, other: &IntegerPolynomial) -> IntegerPolynomial {
+        if self.coefficients.is_empty() {
+            return other.clone().normalize_sign();
+        } else if other.coefficients.is_empty() {
+            return self.clone().normalize_sign();
+        }
+        let content = Integer::from(self.content().gcd(other.content()));
+        let a = self.primitive_part();
+        let b = other.primitive_part();
+        let a_lc = a.leading_coefficient().unwrap().unsigned_abs_ref();
+        let b_lc = b.leading_coefficient().unwrap().unsigned_abs_ref();
+        let lc_gcd = a_lc.gcd(b_lc);
+        let mut modulus = Natural::ONE;
+        let mut image: Vec<Natural> = Vec::new();
+        let mut previous: Option<IntegerPolynomial> = None;
+        for p in large_primes() {
+            let p = Natural::from(p);
+            let a_p = ModularPolynomial::from_integer_polynomial(p.clone(), &a);
+            let b_p = ModularPolynomial::from_integer_polynomial(p.clone(), &b);
+            if a_p.degree() != a.degree() || b_p.degree() != b.degree() {
+                // p divides one of the leading coefficients
+                continue;
+            }
+            let g_p = a_p.gcd(&b_p);
+            let degree = usize::exact_from(g_p.degree().unwrap());
+            if degree == 0 {
+                return IntegerPolynomial::from(content);
+            }
+            let scale = (&lc_gcd).mod_op(&p);
+            let g_p: Vec<Natural> = g_p
+                .coefficients()
+                .iter()
+                .map(|c| c.mod_mul(&scale, &p))
+                .collect();
+            if image.is_empty() || degree + 1 < image.len() {
+                // Either this is the first prime, or every previous prime was unlucky
+                image = g_p;
+                modulus = p;
+                previous = None;
+                continue;
+            } else if degree + 1 > image.len() {
+                // This prime is unlucky
+                continue;
+            }
+            let inverse = (&modulus).mod_op(&p).mod_inverse(&p).unwrap();
+            for (x, y) in image.iter_mut().zip(g_p) {
+                let t = y.mod_sub(&*x % &p, &p).mod_mul(&inverse, &p);
+                *x += &modulus * t;
+            }
+            modulus *= &p;
+            let half = &modulus >> 1u32;
+            let lifted = IntegerPolynomial::from_coefficients(
+                image
+                    .iter()
+                    .map(|x| {
+                        if *x > half {
+                            Integer::from(x) - Integer::from(&modulus)
+                        } else {
+                            Integer::from(x)
+                        }
+                    })
+                    .collect(),
+            );
+            if previous.as_ref() == Some(&lifted) {
+                let candidate = lifted.primitive_part();
+                if a.checked_div_exact(&candidate).is_some()
+                    && b.checked_div_exact(&candidate).is_some()
+                {
+                    return candidate.mul_scalar(&content);
+                }
+            }
+            previous = Some(lifted);
+        }
+        unreachable!()
+    }
+
+    /// Returns the greatest common divisor of two polynomials, with a positive leading
+    /// coefficient, computed using the subresultant algorithm. The result is the same as that of
+    /// [`gcd`](IntegerPolynomial::gcd), which is usually faster.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^4(n + m)^2)$
+    ///
+    /// $M(n, m) = O(n(n + m))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum length of the two
+    /// polynomials, and $m$ is the maximum bit length of their coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::integer::IntegerPolynomial;
+    /// use malachite::Integer;
+    ///
+    /// // 2x^2 - 2 and 4x^2 + 8x + 4
+    /// let f = IntegerPolynomial::from_coefficients(vec![Integer::from(-2), 0.into(), 2.into()]);
+    /// let g = IntegerPolynomial::from_coefficients(vec![Integer::from(4), 8.into(), 4.into()]);
+    /// assert_eq!(f.subresultant_gcd(&g).to_string(), "2*x + 2");
+    /// ```
+    pub fn subresultant_gcd(&self, other: &IntegerPolynomial) -> IntegerPolynomial {
+        if self.coefficients.is_empty() {
+            return other.clone().normalize_sign();
+        } else if other.coefficients.is_empty() {
+            return self.clone().normalize_sign();
+        }
+        let content = Integer::from(self.content().gcd(other.content()));
+        let mut a = self.primitive_part();
+        let mut b = other.primitive_part();
+        if a.coefficients.len() < b.coefficients.len() {
+            swap(&mut a, &mut b);
+        }
+        let mut g = Integer::ONE;
+        let mut h = Integer::ONE;
+        loop {
+            let delta = u64::exact_from(a.degree_usize() - b.degree_usize());
+            let r = a.pseudo_div_rem(&b).1;
+            match r.degree() {
+                None => return b.primitive_part().mul_scalar(&content),
+                Some(0) => return IntegerPolynomial::from(content),
+                _ => {}
+            }
+            a = b;
+            b = r.div_exact_scalar(&(&g * (&h).pow(delta)));
+            g = a.leading_coefficient().unwrap().clone();
+            if delta != 0 {
+                h = (&g).pow(delta).div_exact((&h).pow(delta - 1));
+            }
+        }
+    }
+
+    /// Returns the resultant of two polynomials. The resultant is zero if and only if the
+    /// polynomials have a nonconstant common factor, or if either is zero.
+    ///
+    /// The subresultant algorithm is used, so all intermediate divisions are exact.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^4(n + m)^2)$
+    ///
+    /// $M(n, m) = O(n(n + m))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum length of the two
+    /// polynomials, and $m$ is the maximum bit length of their coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::integer::IntegerPolynomial;
+    /// use malachite::Integer;
+    ///
+    /// // x^2 + 1 and 2x + 1
+    /// let f = IntegerPolynomial::from_coefficients(vec![Integer::from(1), 0.into(), 1.into()]);
+    /// let g = IntegerPolynomial::from_coefficients(vec![Integer::from(1), 2.into()]);
+    /// assert_eq!(f.resultant(&g), 5);
+    /// assert_eq!(g.resultant(&f), 5);
+    ///
+    /// // x^2 - 1 and x - 1
+    /// let f = IntegerPolynomial::from_coefficients(vec![Integer::from(-1), 0.into(), 1.into()]);
+    /// let g = IntegerPolynomial::from_coefficients(vec![Integer::from(-1), 1.into()]);
+    /// assert_eq!(f.resultant(&g), 0);
+    /// ```
+    pub fn resultant(&self, other: &IntegerPolynomial) -> Integer {
+        if self.coefficients.is_empty() || other.coefficients.is_empty() {
+            return Integer::ZERO;
+        }
+        let mut a = self;
+        let mut b = other;
+        let mut negate = false;
+        if a.coefficients.len() < b.coefficients.len() {
+            swap(&mut a, &mut b);
+            negate = a.degree_usize() & b.degree_usize() & 1 != 0;
+        }
+        let a_degree = u64::exact_from(a.degree_usize());
+        if b.degree_usize() == 0 {
+            let result = b.coefficients[0].clone().pow(a_degree);
+            return if negate { -result } else { result };
+        }
+        let b_degree = u64::exact_from(b.degree_usize());
+        let a_content = Integer::from(a.content());
+        let b_content = Integer::from(b.content());
+        let t = (&a_content).pow(b_degree) * (&b_content).pow(a_degree);
+        let mut a = a.div_exact_scalar(&a_content);
+        let mut b = b.div_exact_scalar(&b_content);
+        let mut g = Integer::ONE;
+        let mut h = Integer::ONE;
+        loop {
+            let delta = u64::exact_from(a.degree_usize() - b.degree_usize());
+            if a.degree_usize() & b.degree_usize() & 1 != 0 {
+                negate = !negate;
+            }
+            let r = a.pseudo_div_rem(&b).1;
+            if r.coefficients.is_empty() {
+                return Integer::ZERO;
+            }
+            a = b;
+            b = r.div_exact_scalar(&(&g * (&h).pow(delta)));
+            g = a.leading_coefficient().unwrap().clone();
+            if delta != 0 {
+                h = (&g).pow(delta).div_exact((&h).pow(delta - 1));
+            }
+            if b.degree_usize() == 0 {
+                break;
+            }
+        }
+        let a_degree = u64::exact_from(a.degree_usize());
+        let h = b.coefficients[0]
+            .clone()
+            .pow(a_degree)
+            .div_exact(h.pow(a_degree - 1));
+        let result = t * h;
+        if negate {
+            -result
+        } else {
+            result
+        }
+    }
+
+    /// Returns the square-free factorization of a polynomial.
+    ///
+    /// The result is a constant $c$ and a list of pairs $(f_i, e_i)$, sorted by $e_i$, such that
+    /// the $f_i$ are primitive, nonconstant, square-free, pairwise coprime, and have positive
+    /// leading coefficients, the $e_i$ are distinct, and $f = c \prod_i f_i^{e_i}$. The constant
+    /// $c$ is the content of $f$, with the sign of $f$'s leading coefficient. Yun's algorithm is
+    /// used.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^4(n + m)^2)$
+    ///
+    /// $M(n, m) = O(n(n + m))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the length of the polynomial, and $m$
+    /// is the maximum bit length of its coefficients.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::integer::IntegerPolynomial;
+    /// use malachite::Integer;
+    ///
+    /// // -2 x^2 (x + 1)^3 = -2x^5 - 6x^4 - 6x^3 - 2x^2
+    /// let f = IntegerPolynomial::from_coefficients(vec![
+    ///     Integer::from(0),
+    ///     Integer::from(0),
+    ///     Integer::from(-2),
+    ///     Integer::from(-6),
+    ///     Integer::from(-6),
+    ///     Integer::from(-2),
+    /// ]);
+    /// let (c, factors) = f.square_free_factorization();
+    /// assert_eq!(c, -2);
+    /// assert_eq!(
+    ///     factors
+    ///         .iter()
+    ///         .map(|(f, e)| (f.to_string(), *e))
+    ///         .collect::<Vec<_>>(),
+    ///     &[("x".to_string(), 2), ("x + 1".to_string(), 3)]
+    /// );
+    /// ```
+    pub fn square_free_factorization(&self) -> (Integer, Vec<(IntegerPolynomial, u64)>) {
+        let lc = self
+            .leading_coefficient()
+            .expect("cannot factor the zero polynomial");
+        let content = Integer::from(self.content());
+        let unit = if *lc > 0 { content } else { -content };
+        let f = self.div_exact_scalar(&unit);
+        let mut factors = Vec::new();
+        let derivative = f.derivative();
+        let g = f.gcd(&derivative);
+        let mut c = f.div_exact_ref(&g);
+        let mut d = derivative.div_exact_ref(&g).sub_ref(&c.derivative());
+        let mut i = 1;
+        while c.degree_usize() != 0 {
+            let a = c.gcd(&d);
+            c = c.div_exact_ref(&a);
+            d = d.div_exact_ref(&a).sub_ref(&c.derivative());
+            if a.degree_usize() != 0 {
+                factors.push((a, i));
+            }
+            i += 1;
+        }
+        (unit, factors)
+    }
+
+    fn neg_ref(&self) -> IntegerPolynomial {
+        IntegerPolynomial {
+            coefficients: self.coefficients.iter().map(|c| -c).collect(),
+        }
+    }
+
+    fn add_or_sub_ref(&self, other: &IntegerPolynomial, sub: bool) -> IntegerPolynomial {
+        let len = max(self.coefficients.len(), other.coefficients.len());
+        IntegerPolynomial::from_coefficients(
+            (0..len)
+                .map(
+                    |i| match (self.coefficients.get(i), other.coefficients.get(i)) {
+                        (Some(x), Some(y)) => {
+                            if sub {
+                                x - y
+                            } else {
+                                x + y
+                            }
+                        }
+                        (Some(x), None) => x.clone(),
+                        (None, Some(y)) => {
+                            if sub {
+                                -y
+                            } else {
+                                y.clone()
+                            }
+                        }
+                        (None, None) => unreachable!(),
+                    },
+                )
+                .collect(),
+        )
+    }
+
+    fn add_ref(&self, other: &IntegerPolynomial) -> IntegerPolynomial {
+        self.add_or_sub_ref(other, false)
+    }
+
+    fn sub_ref(&self, other: &IntegerPolynomial) -> IntegerPolynomial {
+        self.add_or_sub_ref(other, true)
+    }
+
+    fn mul_ref(&self, other: &IntegerPolynomial) -> IntegerPolynomial {
+        if self.coefficients.is_empty() || other.coefficients.is_empty() {
+            IntegerPolynomial::ZERO
+        } else {
+            IntegerPolynomial::from_coefficients(mul_integer_coefficients(
+                &self.coefficients,
+                &other.coefficients,
+            ))
+        }
+    }
+
+    fn div_rem_ref(&self, other: &IntegerPolynomial) -> (IntegerPolynomial, IntegerPolynomial) {
+        let lc = other.leading_coefficient().expect("division by zero");
+        assert!(
+            *lc.unsigned_abs_ref() == 1u32,
+            "the divisor's leading coefficient must be 1 or -1"
+        );
+        let n = other.coefficients.len();
+        if self.coefficients.len() < n {
+            return (IntegerPolynomial::ZERO, self.clone());
+        }
+        let mut remainder = self.coefficients.clone();
+        let mut quotient = vec![Integer::ZERO; remainder.len() - n + 1];
+        for i in (0..quotient.len()).rev() {
+            // Since lc is 1 or -1, it is its own inverse
+            let c = remainder.pop().unwrap() * lc;
+            if c != 0 {
+                for (x, y) in remainder[i..].iter_mut().zip(other.coefficients.iter()) {
+                    *x -= &c * y;
+                }
+            }
+            quotient[i] = c;
+        }
+        (
+            IntegerPolynomial::from_coefficients(quotient),
+            IntegerPolynomial::from_coefficients(remainder),
+        )
+    }
+}
+
+impl Zero for IntegerPolynomial {
+    /// The zero polynomial, which has no coefficients.
+    const ZERO: IntegerPolynomial = IntegerPolynomial {
+        coefficients: Vec::new(),
+    };
+}
+
+impl From<Integer> for IntegerPolynomial {
+    /// Converts an [`Integer`] to a constant polynomial.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::integer::IntegerPolynomial;
+    /// use malachite::Integer;
+    ///
+    /// assert_eq!(IntegerPolynomial::from(Integer::from(-3)).to_string(), "-3");
+    /// ```
+    fn from(c: Integer) -> IntegerPolynomial {
+        IntegerPolynomial::from_coefficients(vec![c])
+    }
+}
+
+impl_ring_ops!(IntegerPolynomial, "polynomial", "polynomials");
+
+impl<'a, 'b> DivRem<&'b IntegerPolynomial> for &'a IntegerPolynomial {
+    type DivOutput = IntegerPolynomial;
+    type RemOutput = IntegerPolynomial;
+
+    /// Divides a polynomial by another polynomial whose leading coefficient is 1 or $-1$, taking
+    /// both by reference and returning the quotient and remainder. The remainder has a smaller
+    /// degree than the divisor.
+    ///
+    /// Division by other polynomials can't be done in $\Z[x]$ in general; see
+    /// [`pseudo_div_rem`](IntegerPolynomial::pseudo_div_rem), or use
+    /// [`RationalPolynomial`](crate::polynomial::rational::RationalPolynomial).
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^2(n + m))$
+    ///
+    /// $M(n, m) = O(n(n + m))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the length of `self`, and $m$ is the
+    /// maximum bit length of the coefficients of both polynomials.
+    ///
+    /// # Panics
+    /// Panics if the leading coefficient of `other` is not 1 or $-1$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::num::arithmetic::traits::DivRem;
+    /// use malachite::polynomial::integer::IntegerPolynomial;
+    /// use malachite::Integer;
+    ///
+    /// // x^3 + 2 and x - 1
+    /// let f = IntegerPolynomial::from_coefficients(vec![Integer::from(2), 0.into(), 0.into(), 1.into()]);
+    /// let g = IntegerPolynomial::from_coefficients(vec![Integer::from(-1), 1.into()]);
+    /// let (q, r) = (&f).div_rem(&g);
+    /// assert_eq!(q.to_string(), "x^2 + x + 1");
+    /// assert_eq!(r.to_string(), "3");
+    /// ```
+    #[inline]
+    fn div_rem(self, other: &'b IntegerPolynomial) -> (IntegerPolynomial, IntegerPolynomial) {
+        self.div_rem_ref(other)
+    }
+}
+
+impl DivRem<IntegerPolynomial> for IntegerPolynomial {
+    type DivOutput = IntegerPolynomial;
+    type RemOutput = IntegerPolynomial;
+
+    /// Divides a polynomial by another polynomial whose leading coefficient is 1 or $-1$, taking
+    /// both by value and returning the quotient and remainder.
+    ///
+    /// See [here](IntegerPolynomial#impl-DivRem<%26IntegerPolynomial>-for-%26IntegerPolynomial)
+    /// for more details.
+    #[inline]
+    fn div_rem(self, other: IntegerPolynomial) -> (IntegerPolynomial, IntegerPolynomial) {
+        self.div_rem_ref(&other)
+    }
+}
+
+impl Display for IntegerPolynomial {
+    /// Converts a polynomial to a [`String`], starting with the highest-degree term.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::integer::IntegerPolynomial;
+    /// use malachite::Integer;
+    ///
+    /// let f = IntegerPolynomial::from_coefficients(vec![
+    ///     Integer::from(-1),
+    ///     Integer::from(0),
+    ///     Integer::from(-1),
+    ///     Integer::from(3),
+    /// ]);
+    /// assert_eq!(f.to_string(), "3*x^3 - x^2 - 1");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write_terms(
+            f,
+            self.coefficients
+                .iter()
+                .enumerate()
+                .rev()
+                .filter(|(_, c)| **c != 0)
+                .map(|(i, c)| (u64::exact_from(i), *c < 0, c.unsigned_abs_ref().to_string())),
+        )
+    }
+}
+
+impl Debug for IntegerPolynomial {
+    /// Converts a polynomial to a [`String`]; the same as [`Display::fmt`].
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
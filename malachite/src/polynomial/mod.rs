@@ -0,0 +1,213 @@
+//! Dense univariate polynomials.
+//!
+//! Three coefficient rings are supported:
+//! - [`IntegerPolynomial`](integer::IntegerPolynomial), over $\Z$;
+//! - [`RationalPolynomial`](rational::RationalPolynomial), over $\Q$;
+//! - [`ModularPolynomial`](modular::ModularPolynomial), over $\Z/p\Z$ for a prime $p$.
+//!
+//! Polynomials are stored as vectors of coefficients, starting with the constant term, and are
+//! always trimmed so that the last coefficient is nonzero. The zero polynomial has no coefficients
+//! and no degree.
+//!
+//! Multiplication uses Kronecker substitution: the coefficients of each factor are packed into a
+//! single [`Natural`] or [`Integer`], with enough space between them that the coefficients of
+//! the product can't overlap, and the two numbers are multiplied using the usual [`Natural`]
+//! multiplication, which switches to FFT multiplication for large inputs. The product's
+//! coefficients are then unpacked. Rational polynomials are multiplied by clearing denominators
+//! first.
+//!
+//! # Examples
+//! ```
+//! use malachite::polynomial::integer::IntegerPolynomial;
+//! use malachite::Integer;
+//!
+//! // x^2 - 1
+//! let p = IntegerPolynomial::from_coefficients(vec![Integer::from(-1), 0.into(), 1.into()]);
+//! // x^2 + 2x + 1
+//! let q = IntegerPolynomial::from_coefficients(vec![Integer::from(1), 2.into(), 1.into()]);
+//! assert_eq!((&p * &q).to_string(), "x^4 + 2*x^3 - 2*x - 1");
+//! assert_eq!(p.gcd(&q).to_string(), "x + 1");
+//! assert_eq!(p.resultant(&q), 0);
+//! ```
+
+use malachite_base::num::arithmetic::traits::{ModPow, ModSquare, PowerOf2, ShrRound};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::{ExactFrom, WrappingFrom};
+use malachite_base::num::logic::traits::{BitBlockAccess, SignificantBits};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use std::cmp::min;
+use std::fmt::{self, Formatter, Write};
+
+// Packs `xs` into a single `Natural`, with the `i`th value starting at bit `i * width`. Each value
+// must have at most `width` significant bits.
+fn pack<'a, I: Iterator<Item = &'a Natural>>(xs: I, len: usize, width: u64) -> Natural {
+    let total_bits = width * u64::exact_from(len);
+    let mut out = vec![
+        0;
+        usize::exact_from(
+            total_bits
+                .shr_round(Limb::LOG_WIDTH, RoundingMode::Ceiling)
+                .0
+        ) + 2
+    ];
+    for (i, x) in xs.enumerate() {
+        let offset = width * u64::exact_from(i);
+        let start = usize::exact_from(offset >> Limb::LOG_WIDTH);
+        let shift = offset & Limb::WIDTH_MASK;
+        for (index, limb) in (start..).zip(x.limbs()) {
+            out[index] |= limb << shift;
+            if shift != 0 {
+                out[index + 1] |= limb >> (Limb::WIDTH - shift);
+            }
+        }
+    }
+    Natural::from_owned_limbs_asc(out)
+}
+
+// Splits `x` into `len` blocks of `width` bits each, starting with the least significant block.
+fn unpack(x: &Natural, len: usize, width: u64) -> Vec<Natural> {
+    (0..len)
+        .map(|i| {
+            let start = width * u64::exact_from(i);
+            x.get_bits(start, start + width)
+        })
+        .collect()
+}
+
+fn max_significant_bits<'a, I: Iterator<Item = &'a Natural>>(xs: I) -> u64 {
+    xs.map(SignificantBits::significant_bits).max().unwrap_or(0)
+}
+
+// Multiplies two polynomials with non-negative coefficients using Kronecker substitution. Neither
+// input may be empty.
+pub(crate) fn mul_natural_coefficients(xs: &[Natural], ys: &[Natural]) -> Vec<Natural> {
+    let len = xs.len() + ys.len() - 1;
+    let width = max_significant_bits(xs.iter())
+        + max_significant_bits(ys.iter())
+        + u64::wrapping_from(min(xs.len(), ys.len())).significant_bits();
+    let product = pack(xs.iter(), xs.len(), width) * pack(ys.iter(), ys.len(), width);
+    unpack(&product, len, width)
+}
+
+// Multiplies two polynomials with `Integer` coefficients using Kronecker substitution. Each factor
+// is packed as the difference of its positive and negative parts, and the product is unpacked
+// into balanced digits in $[-2^{w-1}, 2^{w-1})$. Neither input may be empty.
+pub(crate) fn mul_integer_coefficients(xs: &[Integer], ys: &[Integer]) -> Vec<Integer> {
+    let len = xs.len() + ys.len() - 1;
+    let width = max_significant_bits(xs.iter().map(Integer::unsigned_abs_ref))
+        + max_significant_bits(ys.iter().map(Integer::unsigned_abs_ref))
+        + u64::wrapping_from(min(xs.len(), ys.len())).significant_bits()
+        + 1;
+    let zero = Natural::ZERO;
+    let pack_signed = |zs: &[Integer]| {
+        let positive = pack(
+            zs.iter()
+                .map(|z| if *z > 0 { z.unsigned_abs_ref() } else { &zero }),
+            zs.len(),
+            width,
+        );
+        let negative = pack(
+            zs.iter()
+                .map(|z| if *z < 0 { z.unsigned_abs_ref() } else { &zero }),
+            zs.len(),
+            width,
+        );
+        Integer::from(positive) - Integer::from(negative)
+    };
+    let product = pack_signed(xs) * pack_signed(ys);
+    let sign = product >= 0;
+    let half = Natural::power_of_2(width - 1);
+    let base = Integer::from(Natural::power_of_2(width));
+    let mut carry = false;
+    unpack(product.unsigned_abs_ref(), len, width)
+        .into_iter()
+        .map(|mut digit| {
+            if carry {
+                digit += Natural::ONE;
+            }
+            carry = digit >= half;
+            let mut c = Integer::from(digit);
+            if carry {
+                c -= &base;
+            }
+            if sign {
+                c
+            } else {
+                -c
+            }
+        })
+        .collect()
+}
+
+// Writes a polynomial given its nonzero terms in order of decreasing degree. Each term is a degree,
+// whether the coefficient is negative, and the absolute value of the coefficient.
+pub(crate) fn write_terms<I: Iterator<Item = (u64, bool, String)>>(
+    f: &mut Formatter,
+    terms: I,
+) -> fmt::Result {
+    let mut first = true;
+    for (degree, negative, abs) in terms {
+        if first {
+            if negative {
+                f.write_char('-')?;
+            }
+        } else {
+            f.write_str(if negative { " - " } else { " + " })?;
+        }
+        first = false;
+        if degree == 0 {
+            f.write_str(&abs)?;
+            continue;
+        }
+        if abs != "1" {
+            write!(f, "{}*", abs)?;
+        }
+        f.write_char('x')?;
+        if degree > 1 {
+            write!(f, "^{}", degree)?;
+        }
+    }
+    if first {
+        f.write_char('0')?;
+    }
+    Ok(())
+}
+
+// A deterministic Miller-Rabin test, valid for all odd `n` greater than 37.
+fn is_prime_u64(n: u64) -> bool {
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    'outer: for a in [2u64, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        let mut x = a.mod_pow(d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = x.mod_square(n);
+            if x == n - 1 {
+                continue 'outer;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+// Generates the primes less than $2^{62}$, in descending order. These are used by the modular
+// algorithms.
+pub(crate) fn large_primes() -> impl Iterator<Item = u64> {
+    (0..)
+        .map(|i: u64| (1 << 62) - 1 - (i << 1))
+        .filter(|&n| is_prime_u64(n))
+}
+
+/// Polynomials with [`Integer`] coefficients.
+pub mod integer;
+/// Polynomials with coefficients in $\Z/p\Z$, for a prime $p$.
+pub mod modular;
+/// Polynomials with [`Rational`](malachite_q::Rational) coefficients.
+pub mod rational;
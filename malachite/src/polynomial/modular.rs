@@ -0,0 +1,818 @@
+use crate::polynomial::integer::IntegerPolynomial;
+use crate::polynomial::{mul_natural_coefficients, write_terms};
+use malachite_base::num::arithmetic::traits::{
+    DivRem, Mod, ModAdd, ModInverse, ModMul, ModNeg, ModPow, ModSub, ModSubAssign,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A dense univariate polynomial with coefficients in $\Z/p\Z$, where $p$ is prime.
+///
+/// Each polynomial carries its modulus, and the coefficients are stored as [`Natural`]s reduced
+/// modulo $p$. Combining polynomials with different moduli panics. The modulus is assumed to be
+/// prime, but this is not checked; if it is composite, operations that need to invert a leading
+/// coefficient may panic.
+///
+/// # Examples
+/// ```
+/// use malachite::polynomial::modular::ModularPolynomial;
+/// use malachite::Natural;
+///
+/// let p = Natural::from(7u32);
+/// // x^2 + 1 and x + 3
+/// let f = ModularPolynomial::from_coefficients(p.clone(), vec![1u32.into(), 0u32.into(), 1u32.into()]);
+/// let g = ModularPolynomial::from_coefficients(p, vec![3u32.into(), 1u32.into()]);
+/// assert_eq!((&f * &g).to_string(), "x^3 + 3*x^2 + x + 3");
+/// assert_eq!(f.evaluate(&Natural::from(4u32)), 3);
+/// ```
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct ModularPolynomial {
+    modulus: Natural,
+    // The coefficients, starting with the constant term, each reduced modulo `modulus`. The last
+    // coefficient is nonzero.
+    coefficients: Vec<Natural>,
+}
+
+impl ModularPolynomial {
+    fn new_trimmed(modulus: Natural, mut coefficients: Vec<Natural>) -> ModularPolynomial {
+        while coefficients.last() == Some(&Natural::ZERO) {
+            coefficients.pop();
+        }
+        ModularPolynomial {
+            modulus,
+            coefficients,
+        }
+    }
+
+    fn with_coefficients(&self, coefficients: Vec<Natural>) -> ModularPolynomial {
+        ModularPolynomial::new_trimmed(self.modulus.clone(), coefficients)
+    }
+
+    fn check_modulus(&self, other: &ModularPolynomial) {
+        assert_eq!(
+            self.modulus, other.modulus,
+            "polynomials must have the same modulus"
+        );
+    }
+
+    /// Returns the zero polynomial modulo $p$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if `modulus` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::modular::ModularPolynomial;
+    /// use malachite::Natural;
+    ///
+    /// assert_eq!(ModularPolynomial::zero(Natural::from(5u32)).to_string(), "0");
+    /// ```
+    pub fn zero(modulus: Natural) -> ModularPolynomial {
+        assert!(modulus > 1u32, "modulus must be at least 2");
+        ModularPolynomial {
+            modulus,
+            coefficients: Vec::new(),
+        }
+    }
+
+    /// Creates a polynomial modulo $p$ from its coefficients, starting with the constant term.
+    /// The coefficients are reduced modulo $p$, and trailing zeros are removed.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Panics
+    /// Panics if `modulus` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::modular::ModularPolynomial;
+    /// use malachite::Natural;
+    ///
+    /// let f = ModularPolynomial::from_coefficients(
+    ///     Natural::from(5u32),
+    ///     vec![Natural::from(7u32), Natural::from(3u32), Natural::from(10u32)],
+    /// );
+    /// assert_eq!(f.to_string(), "3*x + 2");
+    /// ```
+    pub fn from_coefficients(modulus: Natural, coefficients: Vec<Natural>) -> ModularPolynomial {
+        assert!(modulus > 1u32, "modulus must be at least 2");
+        let coefficients = coefficients
+            .into_iter()
+            .map(|c| c.mod_op(&modulus))
+            .collect();
+        ModularPolynomial::new_trimmed(modulus, coefficients)
+    }
+
+    /// Reduces an [`IntegerPolynomial`] modulo $p$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Panics
+    /// Panics if `modulus` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::integer::IntegerPolynomial;
+    /// use malachite::polynomial::modular::ModularPolynomial;
+    /// use malachite::{Integer, Natural};
+    ///
+    /// let f = IntegerPolynomial::from_coefficients(vec![Integer::from(-1), Integer::from(5)]);
+    /// assert_eq!(
+    ///     ModularPolynomial::from_integer_polynomial(Natural::from(3u32), &f).to_string(),
+    ///     "2*x + 2"
+    /// );
+    /// ```
+    pub fn from_integer_polynomial(modulus: Natural, f: &IntegerPolynomial) -> ModularPolynomial {
+        assert!(modulus > 1u32, "modulus must be at least 2");
+        let m = Integer::from(&modulus);
+        let coefficients = f
+            .coefficients()
+            .iter()
+            .map(|c| Natural::exact_from(c.mod_op(&m)))
+            .collect();
+        ModularPolynomial::new_trimmed(modulus, coefficients)
+    }
+
+    /// Returns the polynomial $cx^k$ modulo $p$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `degree`.
+    ///
+    /// # Panics
+    /// Panics if `modulus` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::modular::ModularPolynomial;
+    /// use malachite::Natural;
+    ///
+    /// assert_eq!(
+    ///     ModularPolynomial::monomial(Natural::from(5u32), Natural::from(8u32), 3).to_string(),
+    ///     "3*x^3"
+    /// );
+    /// ```
+    pub fn monomial(modulus: Natural, c: Natural, degree: u64) -> ModularPolynomial {
+        let mut coefficients = vec![Natural::ZERO; usize::exact_from(degree)];
+        coefficients.push(c);
+        ModularPolynomial::from_coefficients(modulus, coefficients)
+    }
+
+    /// Returns the modulus $p$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::modular::ModularPolynomial;
+    /// use malachite::Natural;
+    ///
+    /// assert_eq!(*ModularPolynomial::zero(Natural::from(5u32)).modulus(), 5);
+    /// ```
+    pub const fn modulus(&self) -> &Natural {
+        &self.modulus
+    }
+
+    /// Returns the coefficients of a polynomial, starting with the constant term. The last
+    /// coefficient, if any, is nonzero.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::modular::ModularPolynomial;
+    /// use malachite::Natural;
+    ///
+    /// let f = ModularPolynomial::from_coefficients(
+    ///     Natural::from(5u32),
+    ///     vec![Natural::from(1u32), Natural::from(6u32)],
+    /// );
+    /// assert_eq!(f.coefficients(), &[1u32, 1]);
+    /// ```
+    pub fn coefficients(&self) -> &[Natural] {
+        &self.coefficients
+    }
+
+    /// Returns the coefficients of a polynomial, starting with the constant term, taking the
+    /// polynomial by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::modular::ModularPolynomial;
+    /// use malachite::Natural;
+    ///
+    /// let f = ModularPolynomial::from_coefficients(
+    ///     Natural::from(5u32),
+    ///     vec![Natural::from(1u32), Natural::from(6u32)],
+    /// );
+    /// assert_eq!(f.into_coefficients(), &[1u32, 1]);
+    /// ```
+    pub fn into_coefficients(self) -> Vec<Natural> {
+        self.coefficients
+    }
+
+    /// Returns the degree of a polynomial, or `None` if it is zero.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::num::basic::traits::One;
+    /// use malachite::polynomial::modular::ModularPolynomial;
+    /// use malachite::Natural;
+    ///
+    /// let p = Natural::from(5u32);
+    /// assert_eq!(ModularPolynomial::zero(p.clone()).degree(), None);
+    /// assert_eq!(ModularPolynomial::monomial(p, Natural::ONE, 4).degree(), Some(4));
+    /// ```
+    pub fn degree(&self) -> Option<u64> {
+        self.coefficients
+            .len()
+            .checked_sub(1)
+            .map(u64::exact_from)
+    }
+
+    /// Returns the leading coefficient of a polynomial, or `None` if it is zero.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::modular::ModularPolynomial;
+    /// use malachite::Natural;
+    ///
+    /// let p = Natural::from(5u32);
+    /// assert_eq!(ModularPolynomial::zero(p.clone()).leading_coefficient(), None);
+    /// assert_eq!(
+    ///     ModularPolynomial::monomial(p, Natural::from(3u32), 4).leading_coefficient(),
+    ///     Some(&Natural::from(3u32))
+    /// );
+    /// ```
+    pub fn leading_coefficient(&self) -> Option<&Natural> {
+        self.coefficients.last()
+    }
+
+    /// Divides a polynomial by its leading coefficient.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log m \log\log m)$
+    ///
+    /// $M(m) = O(m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the length of the polynomial, and $m$
+    /// is `self.modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::modular::ModularPolynomial;
+    /// use malachite::Natural;
+    ///
+    /// let f = ModularPolynomial::from_coefficients(
+    ///     Natural::from(7u32),
+    ///     vec![Natural::from(1u32), Natural::from(2u32)],
+    /// );
+    /// assert_eq!(f.make_monic().to_string(), "x + 4");
+    /// ```
+    pub fn make_monic(&self) -> ModularPolynomial {
+        let inverse = self.leading_coefficient_inverse();
+        self.mul_scalar(&inverse)
+    }
+
+    fn leading_coefficient_inverse(&self) -> Natural {
+        self.leading_coefficient()
+            .expect("polynomial must be nonzero")
+            .mod_inverse(&self.modulus)
+            .expect("leading coefficient is not invertible")
+    }
+
+    fn mul_scalar(&self, c: &Natural) -> ModularPolynomial {
+        self.with_coefficients(
+            self.coefficients
+                .iter()
+                .map(|x| x.mod_mul(c, &self.modulus))
+                .collect(),
+        )
+    }
+
+    fn is_one(&self) -> bool {
+        self.coefficients.len() == 1 && self.coefficients[0] == 1u32
+    }
+
+    /// Evaluates a polynomial at a point, using Horner's method. The point is reduced modulo $p$
+    /// first.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log m \log\log m)$
+    ///
+    /// $M(m) = O(m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the length of the polynomial, and $m$
+    /// is `self.modulus().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::modular::ModularPolynomial;
+    /// use malachite::Natural;
+    ///
+    /// // x^2 + 1
+    /// let f = ModularPolynomial::from_coefficients(
+    ///     Natural::from(5u32),
+    ///     vec![Natural::from(1u32), Natural::from(0u32), Natural::from(1u32)],
+    /// );
+    /// assert_eq!(f.evaluate(&Natural::from(2u32)), 0);
+    /// assert_eq!(f.evaluate(&Natural::from(13u32)), 0);
+    /// assert_eq!(f.evaluate(&Natural::from(1u32)), 2);
+    /// ```
+    pub fn evaluate(&self, x: &Natural) -> Natural {
+        let x = x.mod_op(&self.modulus);
+        let mut result = Natural::ZERO;
+        for c in self.coefficients.iter().rev() {
+            result = result.mod_mul(&x, &self.modulus).mod_add(c, &self.modulus);
+        }
+        result
+    }
+
+    /// Composes two polynomials, returning $f(g(x))$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^2m \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(nm \log (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the length of the longer polynomial,
+    /// and $m$ is `self.modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the polynomials have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::num::basic::traits::One;
+    /// use malachite::polynomial::modular::ModularPolynomial;
+    /// use malachite::Natural;
+    ///
+    /// let p = Natural::from(5u32);
+    /// // x^2 + 1 and x + 1
+    /// let f = ModularPolynomial::from_coefficients(
+    ///     p.clone(),
+    ///     vec![Natural::from(1u32), Natural::from(0u32), Natural::from(1u32)],
+    /// );
+    /// let g = ModularPolynomial::from_coefficients(p, vec![Natural::ONE, Natural::ONE]);
+    /// assert_eq!(f.compose(&g).to_string(), "x^2 + 2*x + 2");
+    /// ```
+    pub fn compose(&self, other: &ModularPolynomial) -> ModularPolynomial {
+        self.check_modulus(other);
+        let mut result = ModularPolynomial::zero(self.modulus.clone());
+        for c in self.coefficients.iter().rev() {
+            result = result.mul_ref(other);
+            let c = ModularPolynomial::new_trimmed(self.modulus.clone(), vec![c.clone()]);
+            result = result.add_ref(&c);
+        }
+        result
+    }
+
+    /// Returns the derivative of a polynomial.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log m \log\log m)$
+    ///
+    /// $M(m) = O(m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the length of the polynomial, and $m$
+    /// is `self.modulus().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::num::basic::traits::One;
+    /// use malachite::polynomial::modular::ModularPolynomial;
+    /// use malachite::Natural;
+    ///
+    /// // x^3 + x^2
+    /// let f = ModularPolynomial::from_coefficients(
+    ///     Natural::from(3u32),
+    ///     vec![Natural::from(0u32), Natural::from(0u32), Natural::ONE, Natural::ONE],
+    /// );
+    /// assert_eq!(f.derivative().to_string(), "2*x");
+    /// ```
+    pub fn derivative(&self) -> ModularPolynomial {
+        self.with_coefficients(
+            self.coefficients
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, c)| {
+                    Natural::from(i)
+                        .mod_op(&self.modulus)
+                        .mod_mul(c, &self.modulus)
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the monic greatest common divisor of two polynomials, computed with the Euclidean
+    /// algorithm. The gcd of two zero polynomials is zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^2m \log m \log\log m)$
+    ///
+    /// $M(n, m) = O(n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the length of the longer polynomial,
+    /// and $m$ is `self.modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the polynomials have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::num::basic::traits::One;
+    /// use malachite::polynomial::modular::ModularPolynomial;
+    /// use malachite::Natural;
+    ///
+    /// let p = Natural::from(7u32);
+    /// // x^2 - 1 and 2x + 2
+    /// let f = ModularPolynomial::from_coefficients(
+    ///     p.clone(),
+    ///     vec![Natural::from(6u32), Natural::from(0u32), Natural::ONE],
+    /// );
+    /// let g = ModularPolynomial::from_coefficients(p, vec![Natural::from(2u32), Natural::from(2u32)]);
+    /// assert_eq!(f.gcd(&g).to_string(), "x + 1");
+    /// ```
+    pub fn gcd(&self, other: &ModularPolynomial) -> ModularPolynomial {
+        self.check_modulus(other);
+        let mut a = self.clone();
+        let mut b = other.clone();
+        while b.degree().is_some() {
+            let r = a.div_rem_ref(&b).1;
+            a = b;
+            b = r;
+        }
+        if a.degree().is_some() {
+            a.make_monic()
+        } else {
+            a
+        }
+    }
+
+    /// Returns the resultant of two polynomials. The resultant is zero if and only if the
+    /// polynomials have a common factor, or if either is zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^2m \log m \log\log m)$
+    ///
+    /// $M(n, m) = O(n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the length of the longer polynomial,
+    /// and $m$ is `self.modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the polynomials have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::num::basic::traits::One;
+    /// use malachite::polynomial::modular::ModularPolynomial;
+    /// use malachite::Natural;
+    ///
+    /// let p = Natural::from(7u32);
+    /// // x^2 + 1 and x + 3
+    /// let f = ModularPolynomial::from_coefficients(
+    ///     p.clone(),
+    ///     vec![Natural::ONE, Natural::from(0u32), Natural::ONE],
+    /// );
+    /// let g = ModularPolynomial::from_coefficients(p, vec![Natural::from(3u32), Natural::ONE]);
+    /// assert_eq!(f.resultant(&g), 3);
+    /// ```
+    pub fn resultant(&self, other: &ModularPolynomial) -> Natural {
+        self.check_modulus(other);
+        let m = &self.modulus;
+        if self.degree().is_none() || other.degree().is_none() {
+            return Natural::ZERO;
+        }
+        let mut a = self.clone();
+        let mut b = other.clone();
+        let mut result = Natural::ONE;
+        loop {
+            let a_degree = a.degree().unwrap();
+            let b_degree = b.degree().unwrap();
+            let b_lc = b.leading_coefficient().unwrap();
+            if b_degree == 0 {
+                return result.mod_mul(b_lc.mod_pow(Natural::from(a_degree), m), m);
+            }
+            // res(a, b) = (-1)^(deg(a) deg(b)) lc(b)^(deg(a) - deg(r)) res(b, r)
+            let r = a.div_rem_ref(&b).1;
+            let r_degree = if let Some(d) = r.degree() {
+                d
+            } else {
+                return Natural::ZERO;
+            };
+            if a_degree & b_degree & 1 != 0 {
+                result = result.mod_neg(m);
+            }
+            result = result.mod_mul(b_lc.mod_pow(Natural::from(a_degree - r_degree), m), m);
+            a = b;
+            b = r;
+        }
+    }
+
+    // Returns $g$ such that $g(x)^p = f(x)$, given that $f' = 0$. Since $c^p = c$ for every
+    // $c \in \Z/p\Z$, this just takes every $p$th coefficient.
+    fn pth_root(&self) -> ModularPolynomial {
+        let step = usize::exact_from(&self.modulus);
+        self.with_coefficients(self.coefficients.iter().step_by(step).cloned().collect())
+    }
+
+    // Musser's algorithm for the square-free factorization of a monic polynomial over a finite
+    // field. The returned factors are monic and nonconstant, but may share multiplicities.
+    fn square_free_factors(&self, factors: &mut Vec<(ModularPolynomial, u64)>, scale: u64) {
+        if self.degree().unwrap() == 0 {
+            return;
+        }
+        let derivative = self.derivative();
+        let mut c = if derivative.degree().is_none() {
+            self.clone()
+        } else {
+            let mut c = self.gcd(&derivative);
+            let mut w = self.div_rem_ref(&c).0;
+            let mut i = 1;
+            while !w.is_one() {
+                let y = w.gcd(&c);
+                let factor = w.div_rem_ref(&y).0;
+                if factor.degree().unwrap() != 0 {
+                    factors.push((factor, i * scale));
+                }
+                i += 1;
+                c = c.div_rem_ref(&y).0;
+                w = y;
+            }
+            c
+        };
+        if !c.is_one() {
+            c = c.pth_root();
+            c.square_free_factors(factors, scale * u64::exact_from(&self.modulus));
+        }
+    }
+
+    /// Returns the square-free factorization of a polynomial.
+    ///
+    /// The result is the leading coefficient $c$ of $f$ and a list of pairs $(f_i, e_i)$, sorted
+    /// by $e_i$, such that the $f_i$ are monic, nonconstant, square-free, and pairwise coprime, the
+    /// $e_i$ are distinct, and $f = c \prod_i f_i^{e_i}$. Musser's algorithm is used, which
+    /// accounts for factors whose multiplicities are divisible by $p$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3m \log m \log\log m)$
+    ///
+    /// $M(n, m) = O(n^2 + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the length of the polynomial, and $m$
+    /// is `self.modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::modular::ModularPolynomial;
+    /// use malachite::Natural;
+    ///
+    /// // 2x^4 + 2x^3 modulo 3, which is 2 x^3 (x + 1)
+    /// let f = ModularPolynomial::from_coefficients(
+    ///     Natural::from(3u32),
+    ///     vec![
+    ///         Natural::from(0u32),
+    ///         Natural::from(0u32),
+    ///         Natural::from(0u32),
+    ///         Natural::from(2u32),
+    ///         Natural::from(2u32),
+    ///     ],
+    /// );
+    /// let (c, factors) = f.square_free_factorization();
+    /// assert_eq!(c, 2);
+    /// assert_eq!(
+    ///     factors
+    ///         .iter()
+    ///         .map(|(f, e)| (f.to_string(), *e))
+    ///         .collect::<Vec<_>>(),
+    ///     &[("x + 1".to_string(), 1), ("x".to_string(), 3)]
+    /// );
+    /// ```
+    pub fn square_free_factorization(&self) -> (Natural, Vec<(ModularPolynomial, u64)>) {
+        let c = self
+            .leading_coefficient()
+            .expect("cannot factor the zero polynomial")
+            .clone();
+        let mut factors = Vec::new();
+        self.make_monic().square_free_factors(&mut factors, 1);
+        factors.sort_by_key(|&(_, e)| e);
+        let mut merged: Vec<(ModularPolynomial, u64)> = Vec::with_capacity(factors.len());
+        for (f, e) in factors {
+            match merged.last_mut() {
+                Some((g, last_e)) if *last_e == e => *g = g.mul_ref(&f),
+                _ => merged.push((f, e)),
+            }
+        }
+        (c, merged)
+    }
+
+    fn neg_ref(&self) -> ModularPolynomial {
+        self.with_coefficients(
+            self.coefficients
+                .iter()
+                .map(|c| c.mod_neg(&self.modulus))
+                .collect(),
+        )
+    }
+
+    fn add_or_sub_ref(&self, other: &ModularPolynomial, sub: bool) -> ModularPolynomial {
+        self.check_modulus(other);
+        let m = &self.modulus;
+        let len = std::cmp::max(self.coefficients.len(), other.coefficients.len());
+        let zero = Natural::ZERO;
+        let coefficients = (0..len)
+            .map(|i| {
+                let x = self.coefficients.get(i).unwrap_or(&zero);
+                let y = other.coefficients.get(i).unwrap_or(&zero);
+                if sub {
+                    x.mod_sub(y, m)
+                } else {
+                    x.mod_add(y, m)
+                }
+            })
+            .collect();
+        self.with_coefficients(coefficients)
+    }
+
+    fn add_ref(&self, other: &ModularPolynomial) -> ModularPolynomial {
+        self.add_or_sub_ref(other, false)
+    }
+
+    fn sub_ref(&self, other: &ModularPolynomial) -> ModularPolynomial {
+        self.add_or_sub_ref(other, true)
+    }
+
+    fn mul_ref(&self, other: &ModularPolynomial) -> ModularPolynomial {
+        self.check_modulus(other);
+        if self.coefficients.is_empty() || other.coefficients.is_empty() {
+            return ModularPolynomial::zero(self.modulus.clone());
+        }
+        let coefficients = mul_natural_coefficients(&self.coefficients, &other.coefficients)
+            .into_iter()
+            .map(|c| c.mod_op(&self.modulus))
+            .collect();
+        self.with_coefficients(coefficients)
+    }
+
+    fn div_rem_ref(&self, other: &ModularPolynomial) -> (ModularPolynomial, ModularPolynomial) {
+        self.check_modulus(other);
+        let m = &self.modulus;
+        let inverse = other.leading_coefficient_inverse();
+        let n = other.coefficients.len();
+        if self.coefficients.len() < n {
+            return (ModularPolynomial::zero(m.clone()), self.clone());
+        }
+        let mut remainder = self.coefficients.clone();
+        let mut quotient = vec![Natural::ZERO; remainder.len() - n + 1];
+        for i in (0..quotient.len()).rev() {
+            let c = (&remainder[i + n - 1]).mod_mul(&inverse, m);
+            if c != 0u32 {
+                for (r, y) in remainder[i..].iter_mut().zip(other.coefficients.iter()) {
+                    r.mod_sub_assign((&c).mod_mul(y, m), m);
+                }
+            }
+            quotient[i] = c;
+        }
+        remainder.truncate(n - 1);
+        (
+            self.with_coefficients(quotient),
+            self.with_coefficients(remainder),
+        )
+    }
+}
+
+impl_ring_ops!(ModularPolynomial, "polynomial", "polynomials");
+
+impl<'a, 'b> DivRem<&'b ModularPolynomial> for &'a ModularPolynomial {
+    type DivOutput = ModularPolynomial;
+    type RemOutput = ModularPolynomial;
+
+    /// Divides a polynomial by another polynomial, taking both by reference and returning the
+    /// quotient and remainder. The remainder has a smaller degree than the divisor.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^2m \log m \log\log m)$
+    ///
+    /// $M(n, m) = O(n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the length of `self`, and $m$ is
+    /// `self.modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero or if the polynomials have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::num::arithmetic::traits::DivRem;
+    /// use malachite::num::basic::traits::One;
+    /// use malachite::polynomial::modular::ModularPolynomial;
+    /// use malachite::Natural;
+    ///
+    /// let p = Natural::from(7u32);
+    /// // x^2 + 1 and 2x + 1
+    /// let f = ModularPolynomial::from_coefficients(
+    ///     p.clone(),
+    ///     vec![Natural::ONE, Natural::from(0u32), Natural::ONE],
+    /// );
+    /// let g = ModularPolynomial::from_coefficients(p, vec![Natural::ONE, Natural::from(2u32)]);
+    /// let (q, r) = (&f).div_rem(&g);
+    /// assert_eq!(q.to_string(), "4*x + 5");
+    /// assert_eq!(r.to_string(), "3");
+    /// ```
+    #[inline]
+    fn div_rem(self, other: &'b ModularPolynomial) -> (ModularPolynomial, ModularPolynomial) {
+        self.div_rem_ref(other)
+    }
+}
+
+impl DivRem<ModularPolynomial> for ModularPolynomial {
+    type DivOutput = ModularPolynomial;
+    type RemOutput = ModularPolynomial;
+
+    /// Divides a polynomial by another polynomial, taking both by value and returning the
+    /// quotient and remainder. The remainder has a smaller degree than the divisor.
+    ///
+    /// See [here](ModularPolynomial#impl-DivRem<%26ModularPolynomial>-for-%26ModularPolynomial)
+    /// for more details.
+    #[inline]
+    fn div_rem(self, other: ModularPolynomial) -> (ModularPolynomial, ModularPolynomial) {
+        self.div_rem_ref(&other)
+    }
+}
+
+impl Display for ModularPolynomial {
+    /// Converts a polynomial to a [`String`], starting with the highest-degree term. The modulus
+    /// is not included.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::num::basic::traits::One;
+    /// use malachite::polynomial::modular::ModularPolynomial;
+    /// use malachite::Natural;
+    ///
+    /// let f = ModularPolynomial::from_coefficients(
+    ///     Natural::from(7u32),
+    ///     vec![Natural::from(3u32), Natural::ONE, Natural::from(0u32), Natural::from(5u32)],
+    /// );
+    /// assert_eq!(f.to_string(), "5*x^3 + x + 3");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write_terms(
+            f,
+            self.coefficients
+                .iter()
+                .enumerate()
+                .rev()
+                .filter(|(_, c)| **c != 0u32)
+                .map(|(i, c)| (u64::exact_from(i), false, c.to_string())),
+        )
+    }
+}
+
+impl Debug for ModularPolynomial {
+    /// Converts a polynomial to a [`String`]; the same as [`Display::fmt`].
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,688 @@
+use crate::polynomial::integer::IntegerPolynomial;
+use crate::polynomial::{mul_integer_coefficients, write_terms};
+use malachite_base::num::arithmetic::traits::{Abs, DivRem, Lcm, Pow};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+use std::cmp::max;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A dense univariate polynomial with [`Rational`] coefficients.
+///
+/// # Examples
+/// ```
+/// use malachite::polynomial::rational::RationalPolynomial;
+/// use malachite::Rational;
+///
+/// // x^2/2 - 1/3
+/// let f = RationalPolynomial::from_coefficients(vec![
+///     Rational::from_signeds(-1, 3),
+///     Rational::from(0),
+///     Rational::from_signeds(1, 2),
+/// ]);
+/// assert_eq!(f.to_string(), "1/2*x^2 - 1/3");
+/// assert_eq!(f.evaluate(&Rational::from(2)), Rational::from_signeds(5, 3));
+/// ```
+#[derive(Clone, Default, Eq, Hash, PartialEq)]
+pub struct RationalPolynomial {
+    // The coefficients, starting with the constant term. The last coefficient is nonzero.
+    coefficients: Vec<Rational>,
+}
+
+impl RationalPolynomial {
+    /// Creates a polynomial from its coefficients, starting with the constant term. Trailing zeros
+    /// are removed.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `coefficients.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::rational::RationalPolynomial;
+    /// use malachite::Rational;
+    ///
+    /// let f = RationalPolynomial::from_coefficients(vec![
+    ///     Rational::from(1),
+    ///     Rational::from_signeds(-2, 3),
+    ///     Rational::from(0),
+    /// ]);
+    /// assert_eq!(f.to_string(), "-2/3*x + 1");
+    /// ```
+    pub fn from_coefficients(mut coefficients: Vec<Rational>) -> RationalPolynomial {
+        while coefficients.last() == Some(&Rational::ZERO) {
+            coefficients.pop();
+        }
+        RationalPolynomial { coefficients }
+    }
+
+    /// Returns the polynomial $cx^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `degree`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::rational::RationalPolynomial;
+    /// use malachite::Rational;
+    ///
+    /// assert_eq!(
+    ///     RationalPolynomial::monomial(Rational::from_signeds(1, 2), 3).to_string(),
+    ///     "1/2*x^3"
+    /// );
+    /// ```
+    pub fn monomial(c: Rational, degree: u64) -> RationalPolynomial {
+        let mut coefficients = vec![Rational::ZERO; usize::exact_from(degree)];
+        coefficients.push(c);
+        RationalPolynomial::from_coefficients(coefficients)
+    }
+
+    /// Returns the coefficients of a polynomial, starting with the constant term. The last
+    /// coefficient, if any, is nonzero.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::rational::RationalPolynomial;
+    /// use malachite::Rational;
+    ///
+    /// let f = RationalPolynomial::from_coefficients(vec![Rational::from(1), Rational::from(2)]);
+    /// assert_eq!(f.coefficients(), &[1, 2]);
+    /// ```
+    pub fn coefficients(&self) -> &[Rational] {
+        &self.coefficients
+    }
+
+    /// Returns the coefficients of a polynomial, starting with the constant term, taking the
+    /// polynomial by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::rational::RationalPolynomial;
+    /// use malachite::Rational;
+    ///
+    /// let f = RationalPolynomial::from_coefficients(vec![Rational::from(1), Rational::from(2)]);
+    /// assert_eq!(f.into_coefficients(), &[1, 2]);
+    /// ```
+    pub fn into_coefficients(self) -> Vec<Rational> {
+        self.coefficients
+    }
+
+    /// Returns the degree of a polynomial, or `None` if it is zero.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::num::basic::traits::Zero;
+    /// use malachite::polynomial::rational::RationalPolynomial;
+    /// use malachite::Rational;
+    ///
+    /// assert_eq!(RationalPolynomial::ZERO.degree(), None);
+    /// assert_eq!(RationalPolynomial::monomial(Rational::from(5), 3).degree(), Some(3));
+    /// ```
+    pub fn degree(&self) -> Option<u64> {
+        self.coefficients
+            .len()
+            .checked_sub(1)
+            .map(u64::exact_from)
+    }
+
+    /// Returns the leading coefficient of a polynomial, or `None` if it is zero.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::num::basic::traits::Zero;
+    /// use malachite::polynomial::rational::RationalPolynomial;
+    /// use malachite::Rational;
+    ///
+    /// assert_eq!(RationalPolynomial::ZERO.leading_coefficient(), None);
+    /// assert_eq!(
+    ///     RationalPolynomial::monomial(Rational::from(5), 3).leading_coefficient(),
+    ///     Some(&Rational::from(5))
+    /// );
+    /// ```
+    pub fn leading_coefficient(&self) -> Option<&Rational> {
+        self.coefficients.last()
+    }
+
+    /// Divides a polynomial by its leading coefficient.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log m \log\log m)$
+    ///
+    /// $M(m) = O(m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the length of the polynomial, and $m$
+    /// is the maximum bit length of its coefficients.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::rational::RationalPolynomial;
+    /// use malachite::Rational;
+    ///
+    /// let f = RationalPolynomial::from_coefficients(vec![Rational::from(1), Rational::from(-3)]);
+    /// assert_eq!(f.make_monic().to_string(), "x - 1/3");
+    /// ```
+    pub fn make_monic(&self) -> RationalPolynomial {
+        let lc = self
+            .leading_coefficient()
+            .expect("polynomial must be nonzero");
+        if *lc == 1u32 {
+            return self.clone();
+        }
+        RationalPolynomial {
+            coefficients: self.coefficients.iter().map(|c| c / lc).collect(),
+        }
+    }
+
+    // Returns an `IntegerPolynomial` $P$ and a positive `Natural` $L$ such that `self` is $P/L$.
+    // $L$ is the lcm of the coefficients' denominators.
+    fn clear_denominators(&self) -> (IntegerPolynomial, Natural) {
+        let mut lcm = Natural::ONE;
+        for c in &self.coefficients {
+            if *c.denominator_ref() != 1u32 {
+                lcm = lcm.lcm(c.denominator_ref());
+            }
+        }
+        let coefficients = self
+            .coefficients
+            .iter()
+            .map(|c| {
+                Integer::from_sign_and_abs(
+                    *c >= 0u32,
+                    c.numerator_ref() * (&lcm / c.denominator_ref()),
+                )
+            })
+            .collect();
+        (IntegerPolynomial::from_coefficients(coefficients), lcm)
+    }
+
+    /// Evaluates a polynomial at a point, using Horner's method.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^2m \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(nm \log (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the length of the polynomial, and $m$
+    /// is the maximum of the bit lengths of `x` and of the coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::rational::RationalPolynomial;
+    /// use malachite::Rational;
+    ///
+    /// // x^2 - 1/4
+    /// let f = RationalPolynomial::from_coefficients(vec![
+    ///     Rational::from_signeds(-1, 4),
+    ///     Rational::from(0),
+    ///     Rational::from(1),
+    /// ]);
+    /// assert_eq!(f.evaluate(&Rational::from_signeds(1, 2)), 0);
+    /// assert_eq!(f.evaluate(&Rational::from(1)), Rational::from_signeds(3, 4));
+    /// ```
+    pub fn evaluate(&self, x: &Rational) -> Rational {
+        let mut result = Rational::ZERO;
+        for c in self.coefficients.iter().rev() {
+            result *= x;
+            result += c;
+        }
+        result
+    }
+
+    /// Composes two polynomials, returning $f(g(x))$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^2m \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(n^2m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum length of the two
+    /// polynomials, and $m$ is the maximum bit length of their coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::rational::RationalPolynomial;
+    /// use malachite::Rational;
+    ///
+    /// // x^2 and x/2 + 1
+    /// let f = RationalPolynomial::monomial(Rational::from(1), 2);
+    /// let g = RationalPolynomial::from_coefficients(vec![
+    ///     Rational::from(1),
+    ///     Rational::from_signeds(1, 2),
+    /// ]);
+    /// assert_eq!(f.compose(&g).to_string(), "1/4*x^2 + x + 1");
+    /// ```
+    pub fn compose(&self, other: &RationalPolynomial) -> RationalPolynomial {
+        let mut result = RationalPolynomial::ZERO;
+        for c in self.coefficients.iter().rev() {
+            result = result.mul_ref(other);
+            result = result.add_ref(&RationalPolynomial::from(c.clone()));
+        }
+        result
+    }
+
+    /// Returns the derivative of a polynomial.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n(m + \log n)^2)$
+    ///
+    /// $M(n, m) = O(n(m + \log n))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the length of the polynomial, and $m$
+    /// is the maximum bit length of its coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::rational::RationalPolynomial;
+    /// use malachite::Rational;
+    ///
+    /// let f = RationalPolynomial::from_coefficients(vec![
+    ///     Rational::from(5),
+    ///     Rational::from(0),
+    ///     Rational::from_signeds(1, 4),
+    /// ]);
+    /// assert_eq!(f.derivative().to_string(), "1/2*x");
+    /// ```
+    pub fn derivative(&self) -> RationalPolynomial {
+        RationalPolynomial::from_coefficients(
+            self.coefficients
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, c)| c * Rational::from(i))
+                .collect(),
+        )
+    }
+
+    /// Returns the monic greatest common divisor of two polynomials. The gcd of two zero
+    /// polynomials is zero.
+    ///
+    /// The denominators are cleared, and the gcd of the resulting [`IntegerPolynomial`]s is
+    /// computed with a modular algorithm.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3(n + m)^2)$
+    ///
+    /// $M(n, m) = O(n(n + m))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum length of the two
+    /// polynomials, and $m$ is the maximum bit length of their coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::rational::RationalPolynomial;
+    /// use malachite::Rational;
+    ///
+    /// // x^2 - 1/4 and 2x + 1
+    /// let f = RationalPolynomial::from_coefficients(vec![
+    ///     Rational::from_signeds(-1, 4),
+    ///     Rational::from(0),
+    ///     Rational::from(1),
+    /// ]);
+    /// let g = RationalPolynomial::from_coefficients(vec![Rational::from(1), Rational::from(2)]);
+    /// assert_eq!(f.gcd(&g).to_string(), "x + 1/2");
+    /// ```
+    pub fn gcd(&self, other: &RationalPolynomial) -> RationalPolynomial {
+        let gcd = self
+            .clear_denominators()
+            .0
+            .gcd(&other.clear_denominators().0);
+        if gcd.degree().is_none() {
+            RationalPolynomial::ZERO
+        } else {
+            RationalPolynomial::from(gcd).make_monic()
+        }
+    }
+
+    /// Returns the resultant of two polynomials. The resultant is zero if and only if the
+    /// polynomials have a nonconstant common factor, or if either is zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^4(n + m)^2)$
+    ///
+    /// $M(n, m) = O(n(n + m))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum length of the two
+    /// polynomials, and $m$ is the maximum bit length of their coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::rational::RationalPolynomial;
+    /// use malachite::Rational;
+    ///
+    /// // x^2 + 1 and x/2 + 1
+    /// let f = RationalPolynomial::from_coefficients(vec![
+    ///     Rational::from(1),
+    ///     Rational::from(0),
+    ///     Rational::from(1),
+    /// ]);
+    /// let g = RationalPolynomial::from_coefficients(vec![
+    ///     Rational::from(1),
+    ///     Rational::from_signeds(1, 2),
+    /// ]);
+    /// assert_eq!(f.resultant(&g), Rational::from_signeds(5, 4));
+    /// ```
+    pub fn resultant(&self, other: &RationalPolynomial) -> Rational {
+        let (a, a_lcm) = self.clear_denominators();
+        let (b, b_lcm) = other.clear_denominators();
+        let (a_degree, b_degree) = match (a.degree(), b.degree()) {
+            (Some(a_degree), Some(b_degree)) => (a_degree, b_degree),
+            _ => return Rational::ZERO,
+        };
+        // res(A / a, B / b) = res(A, B) / (a^deg(B) b^deg(A))
+        Rational::from_integers(
+            a.resultant(&b),
+            Integer::from(a_lcm.pow(b_degree) * b_lcm.pow(a_degree)),
+        )
+    }
+
+    /// Returns the square-free factorization of a polynomial.
+    ///
+    /// The result is the leading coefficient $c$ of $f$ and a list of pairs $(f_i, e_i)$, sorted
+    /// by $e_i$, such that the $f_i$ are monic, nonconstant, square-free, and pairwise coprime,
+    /// the $e_i$ are distinct, and $f = c \prod_i f_i^{e_i}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^4(n + m)^2)$
+    ///
+    /// $M(n, m) = O(n(n + m))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the length of the polynomial, and $m$
+    /// is the maximum bit length of its coefficients.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::rational::RationalPolynomial;
+    /// use malachite::Rational;
+    ///
+    /// // (x - 1/2)^2 / 3 = x^2/3 - x/3 + 1/12
+    /// let f = RationalPolynomial::from_coefficients(vec![
+    ///     Rational::from_signeds(1, 12),
+    ///     Rational::from_signeds(-1, 3),
+    ///     Rational::from_signeds(1, 3),
+    /// ]);
+    /// let (c, factors) = f.square_free_factorization();
+    /// assert_eq!(c, Rational::from_signeds(1, 3));
+    /// assert_eq!(
+    ///     factors
+    ///         .iter()
+    ///         .map(|(f, e)| (f.to_string(), *e))
+    ///         .collect::<Vec<_>>(),
+    ///     &[("x - 1/2".to_string(), 2)]
+    /// );
+    /// ```
+    pub fn square_free_factorization(&self) -> (Rational, Vec<(RationalPolynomial, u64)>) {
+        let lc = self
+            .leading_coefficient()
+            .expect("cannot factor the zero polynomial")
+            .clone();
+        let factors = self
+            .clear_denominators()
+            .0
+            .square_free_factorization()
+            .1
+            .into_iter()
+            .map(|(f, e)| (RationalPolynomial::from(f).make_monic(), e))
+            .collect();
+        (lc, factors)
+    }
+
+    fn neg_ref(&self) -> RationalPolynomial {
+        RationalPolynomial {
+            coefficients: self.coefficients.iter().map(|c| -c).collect(),
+        }
+    }
+
+    fn add_or_sub_ref(&self, other: &RationalPolynomial, sub: bool) -> RationalPolynomial {
+        let len = max(self.coefficients.len(), other.coefficients.len());
+        RationalPolynomial::from_coefficients(
+            (0..len)
+                .map(
+                    |i| match (self.coefficients.get(i), other.coefficients.get(i)) {
+                        (Some(x), Some(y)) => {
+                            if sub {
+                                x - y
+                            } else {
+                                x + y
+                            }
+                        }
+                        (Some(x), None) => x.clone(),
+                        (None, Some(y)) => {
+                            if sub {
+                                -y
+                            } else {
+                                y.clone()
+                            }
+                        }
+                        (None, None) => unreachable!(),
+                    },
+                )
+                .collect(),
+        )
+    }
+
+    fn add_ref(&self, other: &RationalPolynomial) -> RationalPolynomial {
+        self.add_or_sub_ref(other, false)
+    }
+
+    fn sub_ref(&self, other: &RationalPolynomial) -> RationalPolynomial {
+        self.add_or_sub_ref(other, true)
+    }
+
+    fn mul_ref(&self, other: &RationalPolynomial) -> RationalPolynomial {
+        if self.coefficients.is_empty() || other.coefficients.is_empty() {
+            return RationalPolynomial::ZERO;
+        }
+        let (a, a_lcm) = self.clear_denominators();
+        let (b, b_lcm) = other.clear_denominators();
+        let denominator = Integer::from(a_lcm * b_lcm);
+        RationalPolynomial::from_coefficients(
+            mul_integer_coefficients(a.coefficients(), b.coefficients())
+                .into_iter()
+                .map(|c| Rational::from_integers_ref(&c, &denominator))
+                .collect(),
+        )
+    }
+
+    fn div_rem_ref(&self, other: &RationalPolynomial) -> (RationalPolynomial, RationalPolynomial) {
+        let lc = other.leading_coefficient().expect("division by zero");
+        let n = other.coefficients.len();
+        if self.coefficients.len() < n {
+            return (RationalPolynomial::ZERO, self.clone());
+        }
+        let mut remainder = self.coefficients.clone();
+        let mut quotient = vec![Rational::ZERO; remainder.len() - n + 1];
+        for i in (0..quotient.len()).rev() {
+            let c = remainder.pop().unwrap() / lc;
+            if c != 0u32 {
+                for (x, y) in remainder[i..].iter_mut().zip(other.coefficients.iter()) {
+                    *x -= &c * y;
+                }
+            }
+            quotient[i] = c;
+        }
+        (
+            RationalPolynomial::from_coefficients(quotient),
+            RationalPolynomial::from_coefficients(remainder),
+        )
+    }
+}
+
+impl Zero for RationalPolynomial {
+    /// The zero polynomial, which has no coefficients.
+    const ZERO: RationalPolynomial = RationalPolynomial {
+        coefficients: Vec::new(),
+    };
+}
+
+impl From<Rational> for RationalPolynomial {
+    /// Converts a [`Rational`] to a constant polynomial.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::rational::RationalPolynomial;
+    /// use malachite::Rational;
+    ///
+    /// assert_eq!(
+    ///     RationalPolynomial::from(Rational::from_signeds(-3, 2)).to_string(),
+    ///     "-3/2"
+    /// );
+    /// ```
+    fn from(c: Rational) -> RationalPolynomial {
+        RationalPolynomial::from_coefficients(vec![c])
+    }
+}
+
+impl From<IntegerPolynomial> for RationalPolynomial {
+    /// Converts an [`IntegerPolynomial`] to a [`RationalPolynomial`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the length of the polynomial.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::integer::IntegerPolynomial;
+    /// use malachite::polynomial::rational::RationalPolynomial;
+    /// use malachite::Integer;
+    ///
+    /// let f = IntegerPolynomial::from_coefficients(vec![Integer::from(-1), Integer::from(2)]);
+    /// assert_eq!(RationalPolynomial::from(f).to_string(), "2*x - 1");
+    /// ```
+    fn from(f: IntegerPolynomial) -> RationalPolynomial {
+        RationalPolynomial {
+            coefficients: f
+                .into_coefficients()
+                .into_iter()
+                .map(Rational::from)
+                .collect(),
+        }
+    }
+}
+
+impl_ring_ops!(RationalPolynomial, "polynomial", "polynomials");
+
+impl<'a, 'b> DivRem<&'b RationalPolynomial> for &'a RationalPolynomial {
+    type DivOutput = RationalPolynomial;
+    type RemOutput = RationalPolynomial;
+
+    /// Divides a polynomial by another polynomial, taking both by reference and returning the
+    /// quotient and remainder. The remainder has a smaller degree than the divisor.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^2(n + m)^2)$
+    ///
+    /// $M(n, m) = O(n(n + m))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the length of `self`, and $m$ is the
+    /// maximum bit length of the coefficients of both polynomials.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::num::arithmetic::traits::DivRem;
+    /// use malachite::polynomial::rational::RationalPolynomial;
+    /// use malachite::Rational;
+    ///
+    /// // x^2 + 1 and 2x + 1
+    /// let f = RationalPolynomial::from_coefficients(vec![
+    ///     Rational::from(1),
+    ///     Rational::from(0),
+    ///     Rational::from(1),
+    /// ]);
+    /// let g = RationalPolynomial::from_coefficients(vec![Rational::from(1), Rational::from(2)]);
+    /// let (q, r) = (&f).div_rem(&g);
+    /// assert_eq!(q.to_string(), "1/2*x - 1/4");
+    /// assert_eq!(r.to_string(), "5/4");
+    /// ```
+    #[inline]
+    fn div_rem(self, other: &'b RationalPolynomial) -> (RationalPolynomial, RationalPolynomial) {
+        self.div_rem_ref(other)
+    }
+}
+
+impl DivRem<RationalPolynomial> for RationalPolynomial {
+    type DivOutput = RationalPolynomial;
+    type RemOutput = RationalPolynomial;
+
+    /// Divides a polynomial by another polynomial, taking both by value and returning the
+    /// quotient and remainder. The remainder has a smaller degree than the divisor.
+    ///
+    /// See [here](RationalPolynomial#impl-DivRem<%26RationalPolynomial>-for-%26RationalPolynomial)
+    /// for more details.
+    #[inline]
+    fn div_rem(self, other: RationalPolynomial) -> (RationalPolynomial, RationalPolynomial) {
+        self.div_rem_ref(&other)
+    }
+}
+
+impl Display for RationalPolynomial {
+    /// Converts a polynomial to a [`String`], starting with the highest-degree term.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite::polynomial::rational::RationalPolynomial;
+    /// use malachite::Rational;
+    ///
+    /// let f = RationalPolynomial::from_coefficients(vec![
+    ///     Rational::from_signeds(-1, 2),
+    ///     Rational::from(0),
+    ///     Rational::from(1),
+    ///     Rational::from_signeds(-2, 3),
+    /// ]);
+    /// assert_eq!(f.to_string(), "-2/3*x^3 + x^2 - 1/2");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write_terms(
+            f,
+            self.coefficients
+                .iter()
+                .enumerate()
+                .rev()
+                .filter(|(_, c)| **c != 0u32)
+                .map(|(i, c)| (u64::exact_from(i), *c < 0u32, c.abs().to_string())),
+        )
+    }
+}
+
+impl Debug for RationalPolynomial {
+    /// Converts a polynomial to a [`String`]; the same as [`Display::fmt`].
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
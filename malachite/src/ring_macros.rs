@@ -0,0 +1,150 @@
+// Implements `Neg`, `Add`, `Sub`, and `Mul`, along with their assigning variants, in terms of the
+// type's `neg_ref`, `add_ref`, `sub_ref`, and `mul_ref` functions. The nouns are used in the
+// generated doc comments.
+macro_rules! impl_ring_ops {
+    ($t: ident, $noun: literal, $nouns: literal) => {
+        impl Neg for $t {
+            type Output = $t;
+
+            #[doc = concat!("Negates a ", $noun, ", taking it by value.")]
+            #[inline]
+            fn neg(self) -> $t {
+                self.neg_ref()
+            }
+        }
+
+        impl<'a> Neg for &'a $t {
+            type Output = $t;
+
+            #[doc = concat!("Negates a ", $noun, ", taking it by reference.")]
+            #[inline]
+            fn neg(self) -> $t {
+                self.neg_ref()
+            }
+        }
+
+        impl_ring_ops!(
+            @binary $t,
+            $noun,
+            $nouns,
+            Add,
+            add,
+            AddAssign,
+            add_assign,
+            add_ref,
+            "Adds",
+            "to"
+        );
+        impl_ring_ops!(
+            @binary $t,
+            $noun,
+            $nouns,
+            Sub,
+            sub,
+            SubAssign,
+            sub_assign,
+            sub_ref,
+            "Subtracts",
+            "from"
+        );
+        impl_ring_ops!(
+            @binary $t,
+            $noun,
+            $nouns,
+            Mul,
+            mul,
+            MulAssign,
+            mul_assign,
+            mul_ref,
+            "Multiplies",
+            "by"
+        );
+    };
+    (@binary $t: ident, $noun: literal, $nouns: literal, $trait: ident, $fn: ident,
+        $assign_trait: ident, $assign_fn: ident, $ref_fn: ident, $verb: literal,
+        $preposition: literal) => {
+        impl $trait<$t> for $t {
+            type Output = $t;
+
+            #[doc = concat!($verb, " two ", $nouns, ", taking both by value.")]
+            #[inline]
+            fn $fn(self, other: $t) -> $t {
+                self.$ref_fn(&other)
+            }
+        }
+
+        impl<'a> $trait<&'a $t> for $t {
+            type Output = $t;
+
+            #[doc = concat!(
+                $verb,
+                " two ",
+                $nouns,
+                ", taking the first by value and the second by reference."
+            )]
+            #[inline]
+            fn $fn(self, other: &'a $t) -> $t {
+                self.$ref_fn(other)
+            }
+        }
+
+        impl<'a> $trait<$t> for &'a $t {
+            type Output = $t;
+
+            #[doc = concat!(
+                $verb,
+                " two ",
+                $nouns,
+                ", taking the first by reference and the second by value."
+            )]
+            #[inline]
+            fn $fn(self, other: $t) -> $t {
+                self.$ref_fn(&other)
+            }
+        }
+
+        impl<'a, 'b> $trait<&'a $t> for &'b $t {
+            type Output = $t;
+
+            #[doc = concat!($verb, " two ", $nouns, ", taking both by reference.")]
+            #[inline]
+            fn $fn(self, other: &'a $t) -> $t {
+                self.$ref_fn(other)
+            }
+        }
+
+        impl $assign_trait<$t> for $t {
+            #[doc = concat!(
+                $verb,
+                " a ",
+                $noun,
+                " ",
+                $preposition,
+                " a ",
+                $noun,
+                " in place, taking the second by value."
+            )]
+            #[inline]
+            fn $assign_fn(&mut self, other: $t) {
+                *self = self.$ref_fn(&other);
+            }
+        }
+
+        impl<'a> $assign_trait<&'a $t> for $t {
+            #[doc = concat!(
+                $verb,
+                " a ",
+                $noun,
+                " ",
+                $preposition,
+                " a ",
+                $noun,
+                " in place, taking the second by reference."
+            )]
+            #[inline]
+            fn $assign_fn(&mut self, other: &'a $t) {
+                *self = self.$ref_fn(other);
+            }
+        }
+    };
+}
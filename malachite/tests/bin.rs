@@ -0,0 +1,63 @@
+use std::process::{Command, Output};
+
+fn run(lines: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_malachite"))
+        .arg(":timing never")
+        .args(lines)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn test_history_numbering() {
+    // Assignments are results too, so they take a history number
+    let output = run(&[
+        "(2^127 - 1) mod 10!",
+        ":precision 100",
+        "x = sqrt(2)",
+        ":base 16",
+        "_1 * 2",
+        "_2 - x",
+        ":history",
+    ]);
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "_1 = 3427327\n\
+        x = 1.414213562373095048801688724209\n\
+        _3 = 6897fe\n\
+        _4 = 0\n\
+        _1: (2^127 - 1) mod 10!\n\
+        _2: x = sqrt(2)\n\
+        _3: _1 * 2\n\
+        _4: _2 - x\n"
+    );
+}
+
+#[test]
+fn test_underscore() {
+    let output = run(&["2 + 3", "_ * _", "_1 + _2", "y = _", "_ - y"]);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "_1 = 5\n_2 = 25\n_3 = 30\ny = 30\n_5 = 0\n");
+}
+
+#[test]
+fn test_errors() {
+    // Failed lines don't take a history number, and make the exit status nonzero
+    let output = run(&["1 +", "_1", "1 / (2 - 2)", "4", ":base 1", "_1 = 2", ":history"]);
+    assert!(!output.status.success());
+    assert_eq!(stdout(&output), "_1 = 4\n_1: 4\n");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(
+        stderr,
+        "error: unexpected end of expression\n  1 +\n     ^\n\
+        error: unknown variable \"_1\"\n  _1\n  ^^\n\
+        error: division by zero\n  1 / (2 - 2)\n      ^^^^^^^\n\
+        the base must be between 2 and 36\n\
+        can't assign to _1; names starting with _ are reserved for results\n"
+    );
+}
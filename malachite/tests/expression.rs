@@ -0,0 +1,180 @@
+use malachite::expression::{EvaluationOptions, Expression, ExpressionErrorKind, Value};
+use malachite::rounding_modes::RoundingMode;
+use malachite::Rational;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::str::FromStr;
+
+fn evaluate(s: &str) -> Value {
+    Expression::from_str(s)
+        .unwrap()
+        .evaluate(EvaluationOptions::default())
+        .unwrap()
+}
+
+#[test]
+fn test_evaluate() {
+    let test = |s, out| {
+        let value = evaluate(s);
+        assert!(matches!(value, Value::Exact(_)));
+        assert_eq!(value.to_string(), out);
+    };
+    test("0", "0");
+    test("  7 ", "7");
+    test("1 + 2 * 3", "7");
+    test("(1 + 2) * 3", "9");
+    test("2 - 3 - 4", "-5");
+    test("2 ^ 3 ^ 2", "512");
+    test("-2 ^ 2", "-4");
+    test("(-2) ^ 2", "4");
+    test("--3", "3");
+    test("+-+3", "-3");
+    test("2 ^ -2", "1/4");
+    test("0 ^ 0", "1");
+    test("3!!", "720");
+    test("0!", "1");
+    test("-7 mod 3", "2");
+    test("7 % -3", "-2");
+    test("1.5e-3", "3/2000");
+    test("1_000 + 0b1_0 + 0o17 + 0xFF", "1272");
+    test("abs(-1/2) + floor(-1/2) + ceiling(-1/2)", "-1/2");
+    test("binomial(-3, 2)", "6");
+    test("binomial(5, 7)", "0");
+    test("gcd(0, 0) + lcm(0, 5)", "0");
+    test("isqrt(0) + isqrt(99)", "9");
+    test("primorial(0) + factorial(0)", "2");
+    test("sqrt(0) + sqrt(1/4)", "1/2");
+}
+
+#[test]
+fn test_evaluate_approximate() {
+    let test = |s, prec, rm, out| {
+        let mut options = EvaluationOptions::default();
+        options.set_precision(prec);
+        options.set_rounding_mode(rm);
+        let value = Expression::from_str(s).unwrap().evaluate(options).unwrap();
+        assert!(matches!(value, Value::Approximate(_)));
+        assert_eq!(value.to_string(), out);
+    };
+    test("sqrt(2)", 10, RoundingMode::Nearest, "1.414");
+    test("sqrt(2)", 1, RoundingMode::Nearest, "1.0");
+    test("sqrt(2)", 1, RoundingMode::Ceiling, "2.0");
+    test("-sqrt(2)", 10, RoundingMode::Floor, "-1.414");
+    // Any approximate operand makes the result approximate
+    test("sqrt(2) - sqrt(2)", 10, RoundingMode::Nearest, "0.0");
+}
+
+#[test]
+fn test_evaluate_with_variables() {
+    let mut variables = HashMap::new();
+    variables.insert("x".to_string(), Value::from(Rational::from_signeds(1, 3)));
+    variables.insert("_1".to_string(), Value::from(Rational::from(10)));
+    let test = |s, out| {
+        assert_eq!(
+            Expression::from_str(s)
+                .unwrap()
+                .evaluate_with_variables(EvaluationOptions::default(), &variables)
+                .unwrap()
+                .to_string(),
+            out
+        );
+    };
+    test("3 * x^2 - x", "0");
+    test("_1 * x", "10/3");
+    test("x^-1 + _1", "13");
+}
+
+#[test]
+fn test_parse_errors() {
+    let test = |s, kind: ExpressionErrorKind, span: Range<usize>| {
+        let error = Expression::from_str(s).unwrap_err();
+        assert_eq!(*error.kind(), kind);
+        assert_eq!(error.span(), span);
+        assert_eq!(
+            error.to_string(),
+            format!("{} at bytes {}..{}", kind, span.start, span.end)
+        );
+    };
+    // An empty expression ends where an operand is expected
+    test("", ExpressionErrorKind::UnexpectedEnd, 0..0);
+    test("   ", ExpressionErrorKind::UnexpectedEnd, 3..3);
+    test("1 +", ExpressionErrorKind::UnexpectedEnd, 3..3);
+    test("1 + (2 * 3", ExpressionErrorKind::UnexpectedEnd, 10..10);
+    test("gcd(1, 2", ExpressionErrorKind::UnexpectedEnd, 8..8);
+    test("1 + * 2", ExpressionErrorKind::UnexpectedToken, 4..5);
+    test("(1))", ExpressionErrorKind::UnexpectedToken, 3..4);
+    test("()", ExpressionErrorKind::UnexpectedToken, 1..2);
+    test("1 2", ExpressionErrorKind::UnexpectedToken, 2..3);
+    test("gcd(1,)", ExpressionErrorKind::UnexpectedToken, 6..7);
+    test("2 ^ * 3", ExpressionErrorKind::UnexpectedToken, 4..5);
+    test("1.2.3 + 1", ExpressionErrorKind::InvalidNumber, 0..5);
+    test("1 + 0xg", ExpressionErrorKind::InvalidNumber, 4..7);
+    test("12abc", ExpressionErrorKind::InvalidNumber, 0..5);
+    test("1e", ExpressionErrorKind::InvalidNumber, 0..2);
+    test("1 # 2", ExpressionErrorKind::UnexpectedCharacter('#'), 2..3);
+    // Spans are byte ranges, so a multi-byte character spans several bytes
+    test("1 + π", ExpressionErrorKind::UnexpectedCharacter('π'), 4..6);
+}
+
+#[test]
+fn test_evaluation_errors() {
+    let test = |s, kind: ExpressionErrorKind, span: Range<usize>| {
+        let error = Expression::from_str(s)
+            .unwrap()
+            .evaluate(EvaluationOptions::default())
+            .unwrap_err();
+        assert_eq!(*error.kind(), kind);
+        assert_eq!(error.span(), span);
+    };
+    test("5 / (3 - 3)", ExpressionErrorKind::DivisionByZero, 4..11);
+    test("5 mod 0", ExpressionErrorKind::DivisionByZero, 6..7);
+    test("1 + 0 ^ -1", ExpressionErrorKind::DivisionByZero, 4..10);
+    test("1/2 mod 3", ExpressionErrorKind::NotAnInteger, 0..3);
+    test("2 ^ (1/2)", ExpressionErrorKind::NotAnInteger, 4..9);
+    test("(1/2)!", ExpressionErrorKind::NotAnInteger, 0..5);
+    test("(-1)!", ExpressionErrorKind::Negative, 0..4);
+    test("isqrt(-4)", ExpressionErrorKind::Negative, 6..8);
+    test("sqrt(-2)", ExpressionErrorKind::Negative, 5..7);
+    test("factorial(2^64)", ExpressionErrorKind::TooLarge, 10..14);
+    test("2 ^ 2^64", ExpressionErrorKind::TooLarge, 4..8);
+    test(
+        "1 + foo(2)",
+        ExpressionErrorKind::UnknownFunction("foo".to_string()),
+        4..10,
+    );
+    test(
+        "2 * y",
+        ExpressionErrorKind::UnknownVariable("y".to_string()),
+        4..5,
+    );
+    test(
+        "gcd(1, 2, 3)",
+        ExpressionErrorKind::WrongArgumentCount {
+            expected: 2,
+            found: 3,
+        },
+        0..12,
+    );
+    test(
+        "sqrt()",
+        ExpressionErrorKind::WrongArgumentCount {
+            expected: 1,
+            found: 0,
+        },
+        0..6,
+    );
+    // The first error, in evaluation order, is reported
+    test("1/0 + 2/0", ExpressionErrorKind::DivisionByZero, 2..3);
+}
+
+#[test]
+#[should_panic]
+fn set_precision_fail() {
+    EvaluationOptions::default().set_precision(0);
+}
+
+#[test]
+#[should_panic]
+fn set_rounding_mode_fail() {
+    EvaluationOptions::default().set_rounding_mode(RoundingMode::Exact);
+}
@@ -0,0 +1,81 @@
+use malachite::integer_relation::{find_integer_relation, guess_minimal_polynomial};
+use malachite::num::basic::traits::{Infinity, NaN, Zero};
+use malachite::Float;
+
+#[test]
+fn test_find_integer_relation() {
+    let test = |xs: &[f64], out: Option<&[i32]>| {
+        let xs: Vec<Float> = xs.iter().map(|&x| Float::from(x)).collect();
+        assert_eq!(
+            find_integer_relation(&xs),
+            out.map(|out| out.iter().map(|&a| a.into()).collect())
+        );
+    };
+    // No inputs, or a single nonzero input, have no relation
+    test(&[], None);
+    test(&[1.5], None);
+    // A zero input gives the relation that selects it
+    test(&[0.0], Some(&[1]));
+    test(&[1.0, 0.0, 2.0], Some(&[0, 1, 0]));
+    test(&[1.0, 2.0], Some(&[2, -1]));
+    test(&[-3.0, 2.0], Some(&[2, 3]));
+    test(
+        &[2.0f64.ln(), 3.0f64.ln(), 6.0f64.ln()],
+        Some(&[1, 1, -1]),
+    );
+    test(&[1.0, std::f64::consts::PI], None);
+}
+
+#[test]
+fn test_find_integer_relation_precision_exhausted() {
+    // ln 2, ln 3, and ln 6 have a relation, but with 1 bit of precision it can't be found
+    let xs: Vec<Float> = [2.0f64, 3.0, 6.0]
+        .iter()
+        .map(|x| Float::from_primitive_float_prec(x.ln(), 1).0)
+        .collect();
+    assert_eq!(find_integer_relation(&xs), None);
+}
+
+#[test]
+fn test_guess_minimal_polynomial() {
+    let test = |x: Float, max_degree: u64, out: Option<&str>| {
+        assert_eq!(
+            guess_minimal_polynomial(&x, max_degree).map(|f| f.to_string()),
+            out.map(ToString::to_string)
+        );
+    };
+    test(Float::ZERO, 0, Some("x"));
+    test(Float::ZERO, 3, Some("x"));
+    test(Float::from(0.75), 0, None);
+    test(Float::from(0.75), 1, Some("4*x - 3"));
+    test(Float::from(-2.0), 4, Some("x + 2"));
+    test(Float::from(2.0f64.sqrt()), 1, None);
+    test(Float::from(2.0f64.sqrt()), 2, Some("x^2 - 2"));
+    test(Float::from(2.0f64.cbrt()), 4, Some("x^3 - 2"));
+    test(Float::from(std::f64::consts::PI), 3, None);
+}
+
+#[test]
+fn test_guess_minimal_polynomial_precision_exhausted() {
+    // With only 1 bit, the precision runs out before any polynomial can be found
+    let golden_ratio = Float::from_primitive_float_prec((1.0 + 5.0f64.sqrt()) / 2.0, 1).0;
+    assert_eq!(guess_minimal_polynomial(&golden_ratio, 100), None);
+}
+
+#[test]
+#[should_panic]
+fn find_integer_relation_fail_infinite() {
+    find_integer_relation(&[Float::from(1.0), Float::INFINITY]);
+}
+
+#[test]
+#[should_panic]
+fn find_integer_relation_fail_nan() {
+    find_integer_relation(&[Float::NAN, Float::from(1.0)]);
+}
+
+#[test]
+#[should_panic]
+fn guess_minimal_polynomial_fail() {
+    guess_minimal_polynomial(&Float::NAN, 2);
+}
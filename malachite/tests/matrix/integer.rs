@@ -0,0 +1,121 @@
+use malachite::matrix::integer::IntegerMatrix;
+use malachite::matrix::rational::RationalMatrix;
+use malachite::Integer;
+
+fn matrix(rows: &[&[i32]]) -> IntegerMatrix {
+    IntegerMatrix::from_rows(
+        rows.iter()
+            .map(|row| row.iter().map(|&x| Integer::from(x)).collect())
+            .collect(),
+    )
+}
+
+#[test]
+fn test_empty_matrices() {
+    let empty = IntegerMatrix::zero(0, 0);
+    assert_eq!(empty, IntegerMatrix::from_rows(Vec::new()));
+    assert_eq!(empty, IntegerMatrix::identity(0));
+    assert_eq!(empty.to_string(), "[]");
+    assert_eq!(empty.rank(), 0);
+    assert_eq!(empty.determinant(), 1);
+    assert_eq!(empty.inverse(), Some(RationalMatrix::zero(0, 0)));
+    assert_eq!(empty.transpose(), empty);
+    assert_eq!(empty.hermite_normal_form(), empty);
+    assert_eq!(empty.smith_normal_form(), empty);
+    assert_eq!(&empty * &empty, empty);
+
+    let no_cols = IntegerMatrix::zero(2, 0);
+    assert_eq!(no_cols.to_string(), "[[], []]");
+    assert_eq!(no_cols.rank(), 0);
+    assert_eq!(no_cols.transpose(), IntegerMatrix::zero(0, 2));
+    assert_eq!(no_cols.nullspace(), IntegerMatrix::zero(0, 0));
+    // A 2x0 matrix times a 0x3 matrix is the 2x3 zero matrix
+    assert_eq!(&no_cols * &IntegerMatrix::zero(0, 3), IntegerMatrix::zero(2, 3));
+
+    // The nullspace of a 0x2 matrix is everything
+    assert_eq!(IntegerMatrix::zero(0, 2).nullspace(), IntegerMatrix::identity(2));
+}
+
+#[test]
+fn test_singular_matrices() {
+    let test = |m: IntegerMatrix, rank: usize| {
+        assert_eq!(m.rank(), rank);
+        assert_eq!(m.determinant(), 0);
+        assert_eq!(m.inverse(), None);
+        assert_eq!(m.solve(&IntegerMatrix::identity(m.rows())), None);
+        let n = m.nullspace();
+        assert_eq!(n.cols(), m.cols() - rank);
+        assert_eq!(&m * &n, IntegerMatrix::zero(m.rows(), n.cols()));
+        assert_eq!(m.smith_normal_form().rank(), rank);
+        assert_eq!(m.hermite_normal_form().rank(), rank);
+    };
+    test(IntegerMatrix::zero(1, 1), 0);
+    test(IntegerMatrix::zero(3, 3), 0);
+    test(matrix(&[&[1, 2], &[2, 4]]), 1);
+    test(matrix(&[&[2, 3, 1], &[4, 1, 5], &[6, 4, 6]]), 2);
+    test(matrix(&[&[0, 0, 0], &[1, 2, 3], &[4, 5, 6]]), 2);
+}
+
+#[test]
+fn test_nonsingular_matrices() {
+    let test = |m: IntegerMatrix, determinant: i32| {
+        let n = m.rows();
+        assert_eq!(m.rank(), n);
+        assert_eq!(m.determinant(), determinant);
+        assert_eq!(m.transpose().determinant(), determinant);
+        let inverse = m.inverse().unwrap();
+        assert_eq!(
+            RationalMatrix::from(m.clone()) * inverse,
+            RationalMatrix::identity(n)
+        );
+        assert_eq!(m.nullspace(), IntegerMatrix::zero(n, 0));
+    };
+    test(IntegerMatrix::identity(1), 1);
+    test(matrix(&[&[-7]]), -7);
+    test(matrix(&[&[1, 2], &[3, 4]]), -2);
+    // Needs a row swap
+    test(matrix(&[&[0, 1], &[1, 0]]), -1);
+    test(matrix(&[&[2, -3, 1], &[2, 0, -1], &[1, 4, 5]]), 49);
+}
+
+#[test]
+fn test_normal_forms() {
+    let m = matrix(&[&[2, 4, 4], &[-6, 6, 12], &[10, -4, -16]]);
+    assert_eq!(m.smith_normal_form(), matrix(&[&[2, 0, 0], &[0, 6, 0], &[0, 0, 12]]));
+    let m = matrix(&[&[0, 0], &[0, 5]]);
+    assert_eq!(m.hermite_normal_form(), matrix(&[&[0, 5], &[0, 0]]));
+    assert_eq!(m.smith_normal_form(), matrix(&[&[5, 0], &[0, 0]]));
+    let m = matrix(&[&[-3]]);
+    assert_eq!(m.hermite_normal_form(), matrix(&[&[3]]));
+    assert_eq!(m.smith_normal_form(), matrix(&[&[3]]));
+}
+
+#[test]
+#[should_panic]
+fn from_rows_fail() {
+    matrix(&[&[1, 2], &[3]]);
+}
+
+#[test]
+#[should_panic]
+fn from_entries_fail() {
+    IntegerMatrix::from_entries(2, 2, vec![Integer::from(1)]);
+}
+
+#[test]
+#[should_panic]
+fn determinant_fail() {
+    IntegerMatrix::zero(2, 3).determinant();
+}
+
+#[test]
+#[should_panic]
+fn solve_fail() {
+    IntegerMatrix::identity(2).solve(&IntegerMatrix::zero(3, 1));
+}
+
+#[test]
+#[should_panic]
+fn mul_fail() {
+    IntegerMatrix::zero(2, 3) * IntegerMatrix::zero(2, 3);
+}
@@ -0,0 +1,116 @@
+use malachite::matrix::integer::IntegerMatrix;
+use malachite::{Integer, Rational};
+
+fn matrix(rows: &[&[i32]]) -> IntegerMatrix {
+    IntegerMatrix::from_rows(
+        rows.iter()
+            .map(|row| row.iter().map(|&x| Integer::from(x)).collect())
+            .collect(),
+    )
+}
+
+fn delta() -> Rational {
+    Rational::from_signeds(3, 4)
+}
+
+#[test]
+fn test_lll_reduce() {
+    let test = |m: IntegerMatrix, out: &str| {
+        let reduced = m.lll_reduce(&delta());
+        assert_eq!(reduced.to_string(), out);
+        assert!(reduced.is_lll_reduced(&delta()));
+        // A unimodular change of basis preserves the lattice's volume
+        assert_eq!(
+            (&reduced * &reduced.transpose()).determinant(),
+            (&m * &m.transpose()).determinant()
+        );
+        #[cfg(feature = "floats")]
+        assert_eq!(m.lll_reduce_with_precision(&delta(), 20), reduced);
+    };
+    // An empty basis and a single vector are already reduced
+    test(IntegerMatrix::zero(0, 3), "[]");
+    test(matrix(&[&[3, 4]]), "[[3, 4]]");
+    test(IntegerMatrix::identity(3), "[[1, 0, 0], [0, 1, 0], [0, 0, 1]]");
+    test(matrix(&[&[1, 0], &[5, 1]]), "[[1, 0], [0, 1]]");
+    test(
+        matrix(&[&[1, 1, 1], &[-1, 0, 2], &[3, 5, 6]]),
+        "[[0, 1, 0], [1, 0, 1], [-1, 0, 2]]",
+    );
+}
+
+#[test]
+fn test_is_lll_reduced() {
+    assert!(IntegerMatrix::zero(0, 0).is_lll_reduced(&delta()));
+    assert!(!matrix(&[&[1, 0], &[5, 1]]).is_lll_reduced(&delta()));
+    // Dependent rows are never reduced
+    assert!(!matrix(&[&[1, 2], &[2, 4]]).is_lll_reduced(&delta()));
+    assert!(!matrix(&[&[0, 0]]).is_lll_reduced(&delta()));
+    assert!(!matrix(&[&[1, 0], &[1, 0]]).is_lll_reduced(&delta()));
+}
+
+#[test]
+fn test_bkz_reduce() {
+    let m = matrix(&[&[1, 0], &[5, 1]]);
+    assert_eq!(m.bkz_reduce(2, &delta()), m.lll_reduce(&delta()));
+    // A block size larger than the dimension is allowed
+    let m = matrix(&[&[1, 1, 1], &[-1, 0, 2], &[3, 5, 6]]);
+    let reduced = m.bkz_reduce(10, &Rational::from_signeds(99, 100));
+    assert!(reduced.is_lll_reduced(&Rational::from_signeds(99, 100)));
+    assert_eq!(reduced.row(0).iter().filter(|x| **x != 0).count(), 1);
+}
+
+#[test]
+#[should_panic]
+fn lll_reduce_fail_dependent() {
+    matrix(&[&[1, 2, 3], &[2, 4, 6]]).lll_reduce(&delta());
+}
+
+#[test]
+#[should_panic]
+fn lll_reduce_fail_zero_row() {
+    matrix(&[&[1, 2], &[0, 0]]).lll_reduce(&delta());
+}
+
+#[test]
+#[should_panic]
+fn lll_reduce_fail_more_rows_than_columns() {
+    matrix(&[&[1, 0], &[0, 1], &[1, 1]]).lll_reduce(&delta());
+}
+
+#[cfg(feature = "floats")]
+#[test]
+#[should_panic]
+fn lll_reduce_with_precision_fail_dependent() {
+    matrix(&[&[1, 2, 3], &[2, 4, 6]]).lll_reduce_with_precision(&delta(), 20);
+}
+
+#[cfg(feature = "floats")]
+#[test]
+#[should_panic]
+fn lll_reduce_with_precision_fail_zero_precision() {
+    IntegerMatrix::identity(2).lll_reduce_with_precision(&delta(), 0);
+}
+
+#[test]
+#[should_panic]
+fn lll_reduce_fail_delta_too_small() {
+    IntegerMatrix::identity(2).lll_reduce(&Rational::from_signeds(1, 4));
+}
+
+#[test]
+#[should_panic]
+fn lll_reduce_fail_delta_too_large() {
+    IntegerMatrix::identity(2).lll_reduce(&Rational::from(1));
+}
+
+#[test]
+#[should_panic]
+fn bkz_reduce_fail_dependent() {
+    matrix(&[&[1, 2], &[2, 4]]).bkz_reduce(2, &delta());
+}
+
+#[test]
+#[should_panic]
+fn bkz_reduce_fail_block_size() {
+    IntegerMatrix::identity(2).bkz_reduce(1, &delta());
+}
@@ -0,0 +1,77 @@
+use malachite::matrix::integer::IntegerMatrix;
+use malachite::matrix::rational::RationalMatrix;
+use malachite::Rational;
+
+fn matrix(rows: &[&[(i64, i64)]]) -> RationalMatrix {
+    RationalMatrix::from_rows(
+        rows.iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&(n, d)| Rational::from_signeds(n, d))
+                    .collect()
+            })
+            .collect(),
+    )
+}
+
+#[test]
+fn test_empty_matrices() {
+    let empty = RationalMatrix::zero(0, 0);
+    assert_eq!(empty, RationalMatrix::from_rows(Vec::new()));
+    assert_eq!(empty, RationalMatrix::from(IntegerMatrix::zero(0, 0)));
+    assert_eq!(empty.rank(), 0);
+    assert_eq!(empty.determinant(), 1);
+    assert_eq!(empty.inverse(), Some(empty.clone()));
+    assert_eq!(empty.reduced_row_echelon_form(), empty);
+    assert_eq!(
+        empty.solve(&RationalMatrix::zero(0, 2)),
+        Some(RationalMatrix::zero(0, 2))
+    );
+    assert_eq!(RationalMatrix::zero(0, 3).nullspace(), RationalMatrix::identity(3));
+    assert_eq!(RationalMatrix::zero(3, 0).nullspace(), RationalMatrix::zero(0, 0));
+}
+
+#[test]
+fn test_singular_matrices() {
+    let test = |m: RationalMatrix, rank: usize| {
+        assert_eq!(m.rank(), rank);
+        assert_eq!(m.determinant(), 0);
+        assert_eq!(m.inverse(), None);
+        let n = m.nullspace();
+        assert_eq!(n.cols(), m.cols() - rank);
+        assert_eq!(&m * &n, RationalMatrix::zero(m.rows(), n.cols()));
+        assert_eq!(m.reduced_row_echelon_form().rank(), rank);
+    };
+    test(RationalMatrix::zero(2, 2), 0);
+    test(matrix(&[&[(1, 2), (1, 3)], &[(3, 2), (1, 1)]]), 1);
+    test(matrix(&[&[(0, 1), (1, 1)], &[(0, 1), (2, 5)]]), 1);
+}
+
+#[test]
+fn test_solve() {
+    // x/2 + y = 1, x - y/3 = 2
+    let a = matrix(&[&[(1, 2), (1, 1)], &[(1, 1), (-1, 3)]]);
+    let b = matrix(&[&[(1, 1)], &[(2, 1)]]);
+    let x = a.solve(&b).unwrap();
+    assert_eq!(&a * &x, b);
+    assert_eq!(
+        a.reduced_row_echelon_form(),
+        RationalMatrix::identity(2)
+    );
+    // A singular system has no unique solution, even if it's consistent
+    let a = matrix(&[&[(1, 1), (1, 1)], &[(2, 1), (2, 1)]]);
+    let b = matrix(&[&[(1, 1)], &[(2, 1)]]);
+    assert_eq!(a.solve(&b), None);
+}
+
+#[test]
+#[should_panic]
+fn determinant_fail() {
+    RationalMatrix::zero(1, 2).determinant();
+}
+
+#[test]
+#[should_panic]
+fn inverse_fail() {
+    RationalMatrix::zero(2, 1).inverse();
+}
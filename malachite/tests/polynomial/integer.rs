@@ -0,0 +1,149 @@
+use malachite::num::arithmetic::traits::DivRem;
+use malachite::num::basic::traits::{One, Zero};
+use malachite::polynomial::integer::IntegerPolynomial;
+use malachite::Integer;
+
+fn poly(cs: &[i32]) -> IntegerPolynomial {
+    IntegerPolynomial::from_coefficients(cs.iter().map(|&c| Integer::from(c)).collect())
+}
+
+#[test]
+fn test_from_coefficients() {
+    let test = |cs: &[i32], out: &str, degree: Option<u64>| {
+        let f = poly(cs);
+        assert_eq!(f.to_string(), out);
+        assert_eq!(f.degree(), degree);
+        assert_eq!(f.leading_coefficient().is_none(), degree.is_none());
+    };
+    test(&[], "0", None);
+    test(&[0], "0", None);
+    test(&[0, 0, 0], "0", None);
+    test(&[5], "5", Some(0));
+    test(&[0, 1], "x", Some(1));
+    test(&[-1, 0, 1, 0, 0], "x^2 - 1", Some(2));
+    test(&[0, 0, -3], "-3*x^2", Some(2));
+    assert_eq!(IntegerPolynomial::ZERO, poly(&[0, 0]));
+    assert_eq!(IntegerPolynomial::monomial(Integer::ZERO, 5), IntegerPolynomial::ZERO);
+}
+
+#[test]
+fn test_zero_polynomial() {
+    let zero = IntegerPolynomial::ZERO;
+    let f = poly(&[1, -2, 3]);
+    assert_eq!(zero.evaluate(&Integer::from(10)), 0);
+    assert_eq!(zero.derivative(), zero);
+    assert_eq!(zero.compose(&f), zero);
+    assert_eq!(f.compose(&zero), poly(&[1]));
+    assert_eq!(zero.content(), 0u32);
+    assert_eq!(zero.primitive_part(), zero);
+    assert_eq!(&f + &zero, f);
+    assert_eq!(&f - &f, zero);
+    assert_eq!(&f * &zero, zero);
+    assert_eq!(-&zero, zero);
+
+    assert_eq!(zero.gcd(&zero), zero);
+    assert_eq!(zero.subresultant_gcd(&zero), zero);
+    // The gcd with zero is the other polynomial, with a positive leading coefficient
+    assert_eq!(zero.gcd(&-&f), f);
+    assert_eq!((-&f).subresultant_gcd(&zero), f);
+    assert_eq!(zero.resultant(&f), 0);
+    assert_eq!(f.resultant(&zero), 0);
+
+    let (q, r) = zero.pseudo_div_rem(&f);
+    assert_eq!(q, zero);
+    assert_eq!(r, zero);
+    let (q, r) = (&zero).div_rem(&poly(&[1, 1]));
+    assert_eq!(q, zero);
+    assert_eq!(r, zero);
+}
+
+#[test]
+fn test_constant_polynomials() {
+    let c = poly(&[6]);
+    let f = poly(&[2, 0, 4]);
+    assert_eq!(c.derivative(), IntegerPolynomial::ZERO);
+    assert_eq!(c.gcd(&f).to_string(), "2");
+    assert_eq!(c.subresultant_gcd(&f).to_string(), "2");
+    // res(c, f) = c^deg(f)
+    assert_eq!(c.resultant(&f), 36);
+    assert_eq!(f.resultant(&c), 36);
+    let (unit, factors) = poly(&[-6]).square_free_factorization();
+    assert_eq!(unit, -6);
+    assert!(factors.is_empty());
+    let (q, r) = (&c).div_rem(&poly(&[-1, 1]));
+    assert_eq!(q, IntegerPolynomial::ZERO);
+    assert_eq!(r, c);
+    let (q, r) = (&f).div_rem(&IntegerPolynomial::from(-Integer::ONE));
+    assert_eq!(q, -&f);
+    assert_eq!(r, IntegerPolynomial::ZERO);
+}
+
+#[test]
+fn test_gcd_and_resultant() {
+    let test = |xs: &[i32], ys: &[i32], gcd: &str, resultant: i32| {
+        let f = poly(xs);
+        let g = poly(ys);
+        assert_eq!(f.gcd(&g).to_string(), gcd);
+        assert_eq!(g.gcd(&f).to_string(), gcd);
+        assert_eq!(f.subresultant_gcd(&g).to_string(), gcd);
+        assert_eq!(f.resultant(&g), resultant);
+    };
+    test(&[-1, 0, 1], &[-1, 1], "x - 1", 0);
+    test(&[1, 0, 1], &[1, 2], "1", 5);
+    test(&[-2, 0, 2], &[4, 8, 4], "2*x + 2", 0);
+    test(&[0, 0, 1], &[0, 1], "x", 0);
+    test(&[1, 1], &[2, 1], "1", 1);
+}
+
+#[test]
+fn test_square_free_factorization() {
+    let test = |cs: &[i32], unit: i32, out: &[(&str, u64)]| {
+        let f = poly(cs);
+        let (c, factors) = f.square_free_factorization();
+        assert_eq!(c, unit);
+        assert_eq!(
+            factors
+                .iter()
+                .map(|(g, e)| (g.to_string(), *e))
+                .collect::<Vec<_>>(),
+            out.iter()
+                .map(|&(g, e)| (g.to_string(), e))
+                .collect::<Vec<_>>()
+        );
+        let mut product = IntegerPolynomial::from(Integer::from(unit));
+        for (g, e) in factors {
+            for _ in 0..e {
+                product *= &g;
+            }
+        }
+        assert_eq!(product, f);
+    };
+    test(&[0, 1], 1, &[("x", 1)]);
+    test(&[0, 0, 0, -2], -2, &[("x", 3)]);
+    test(&[0, 0, -2, -6, -6, -2], -2, &[("x", 2), ("x + 1", 3)]);
+    test(&[1, 2, 1], 1, &[("x + 1", 2)]);
+}
+
+#[test]
+#[should_panic]
+fn square_free_factorization_fail() {
+    IntegerPolynomial::ZERO.square_free_factorization();
+}
+
+#[test]
+#[should_panic]
+fn pseudo_div_rem_fail() {
+    poly(&[1, 2]).pseudo_div_rem(&IntegerPolynomial::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn div_rem_fail_zero() {
+    poly(&[1, 2]).div_rem(IntegerPolynomial::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn div_rem_fail_not_monic() {
+    poly(&[1, 2]).div_rem(poly(&[1, 2]));
+}
@@ -0,0 +1,121 @@
+use malachite::num::arithmetic::traits::DivRem;
+use malachite::num::basic::traits::{One, Zero};
+use malachite::polynomial::integer::IntegerPolynomial;
+use malachite::polynomial::modular::ModularPolynomial;
+use malachite::{Integer, Natural};
+
+fn poly(p: u32, cs: &[u32]) -> ModularPolynomial {
+    ModularPolynomial::from_coefficients(
+        Natural::from(p),
+        cs.iter().map(|&c| Natural::from(c)).collect(),
+    )
+}
+
+#[test]
+fn test_from_coefficients() {
+    let test = |p: u32, cs: &[u32], out: &str, degree: Option<u64>| {
+        let f = poly(p, cs);
+        assert_eq!(f.to_string(), out);
+        assert_eq!(f.degree(), degree);
+        assert_eq!(*f.modulus(), p);
+    };
+    test(5, &[], "0", None);
+    // Coefficients that are multiples of the modulus vanish
+    test(5, &[5, 10, 0], "0", None);
+    test(5, &[7, 3, 10], "3*x + 2", Some(1));
+    test(2, &[1, 1, 1], "x^2 + x + 1", Some(2));
+    assert_eq!(poly(7, &[0, 7]), ModularPolynomial::zero(Natural::from(7u32)));
+    assert_eq!(
+        ModularPolynomial::monomial(Natural::from(3u32), Natural::from(6u32), 2),
+        ModularPolynomial::zero(Natural::from(3u32))
+    );
+    let f = IntegerPolynomial::from_coefficients(vec![Integer::from(-1), Integer::from(3)]);
+    assert_eq!(
+        ModularPolynomial::from_integer_polynomial(Natural::from(3u32), &f).to_string(),
+        "2"
+    );
+}
+
+#[test]
+fn test_zero_polynomial() {
+    let zero = ModularPolynomial::zero(Natural::from(7u32));
+    let f = poly(7, &[3, 0, 2]);
+    assert_eq!(zero.evaluate(&Natural::from(4u32)), 0);
+    assert_eq!(zero.derivative(), zero);
+    assert_eq!(&f - &f, zero);
+    assert_eq!(&f * &zero, zero);
+    assert_eq!(zero.gcd(&zero), zero);
+    // The gcd with zero is the other polynomial, made monic
+    assert_eq!(zero.gcd(&f).to_string(), "x^2 + 5");
+    assert_eq!(f.gcd(&zero).to_string(), "x^2 + 5");
+    assert_eq!(zero.resultant(&f), 0);
+    assert_eq!(f.resultant(&zero), 0);
+    let (q, r) = (&zero).div_rem(&f);
+    assert_eq!(q, zero);
+    assert_eq!(r, zero);
+}
+
+#[test]
+fn test_derivative_vanishing() {
+    // The derivative of x^p is zero modulo p
+    let f = poly(5, &[1, 0, 0, 0, 0, 1]);
+    assert_eq!(f.derivative(), ModularPolynomial::zero(Natural::from(5u32)));
+    // x^5 + 1 = (x + 1)^5 mod 5
+    let (c, factors) = f.square_free_factorization();
+    assert_eq!(c, 1);
+    assert_eq!(
+        factors
+            .iter()
+            .map(|(g, e)| (g.to_string(), *e))
+            .collect::<Vec<_>>(),
+        &[("x + 1".to_string(), 5)]
+    );
+}
+
+#[test]
+fn test_div_rem() {
+    let f = poly(7, &[3, 1, 0, 5]);
+    let g = poly(7, &[2, 3]);
+    let (q, r) = (&f).div_rem(&g);
+    assert!(r.degree() < g.degree());
+    assert_eq!(&q * &g + &r, f);
+    let (q, r) = (&g).div_rem(&f);
+    assert_eq!(q, ModularPolynomial::zero(Natural::from(7u32)));
+    assert_eq!(r, g);
+}
+
+#[test]
+#[should_panic]
+fn zero_fail() {
+    ModularPolynomial::zero(Natural::ONE);
+}
+
+#[test]
+#[should_panic]
+fn from_coefficients_fail() {
+    ModularPolynomial::from_coefficients(Natural::ZERO, vec![Natural::ONE]);
+}
+
+#[test]
+#[should_panic]
+fn make_monic_fail() {
+    ModularPolynomial::zero(Natural::from(5u32)).make_monic();
+}
+
+#[test]
+#[should_panic]
+fn square_free_factorization_fail() {
+    ModularPolynomial::zero(Natural::from(5u32)).square_free_factorization();
+}
+
+#[test]
+#[should_panic]
+fn div_rem_fail_zero() {
+    poly(5, &[1, 2]).div_rem(ModularPolynomial::zero(Natural::from(5u32)));
+}
+
+#[test]
+#[should_panic]
+fn gcd_fail_different_moduli() {
+    poly(5, &[1, 2]).gcd(&poly(7, &[1, 2]));
+}
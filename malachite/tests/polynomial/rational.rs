@@ -0,0 +1,109 @@
+use malachite::num::arithmetic::traits::DivRem;
+use malachite::num::basic::traits::Zero;
+use malachite::polynomial::integer::IntegerPolynomial;
+use malachite::polynomial::rational::RationalPolynomial;
+use malachite::{Integer, Rational};
+
+fn poly(cs: &[(i64, i64)]) -> RationalPolynomial {
+    RationalPolynomial::from_coefficients(
+        cs.iter()
+            .map(|&(n, d)| Rational::from_signeds(n, d))
+            .collect(),
+    )
+}
+
+#[test]
+fn test_from_coefficients() {
+    let test = |cs: &[(i64, i64)], out: &str, degree: Option<u64>| {
+        let f = poly(cs);
+        assert_eq!(f.to_string(), out);
+        assert_eq!(f.degree(), degree);
+    };
+    test(&[], "0", None);
+    test(&[(0, 1), (0, 3)], "0", None);
+    test(&[(1, 2)], "1/2", Some(0));
+    test(&[(1, 1), (-2, 3), (0, 1)], "-2/3*x + 1", Some(1));
+    assert_eq!(
+        RationalPolynomial::from(IntegerPolynomial::ZERO),
+        RationalPolynomial::ZERO
+    );
+    assert_eq!(
+        RationalPolynomial::from(Rational::ZERO),
+        RationalPolynomial::ZERO
+    );
+}
+
+#[test]
+fn test_zero_polynomial() {
+    let zero = RationalPolynomial::ZERO;
+    let f = poly(&[(1, 2), (0, 1), (3, 1)]);
+    assert_eq!(zero.evaluate(&Rational::from_signeds(1, 3)), 0);
+    assert_eq!(zero.derivative(), zero);
+    assert_eq!(zero.compose(&f), zero);
+    assert_eq!(&f * &zero, zero);
+    assert_eq!(&f - &f, zero);
+    assert_eq!(zero.gcd(&zero), zero);
+    // The gcd with zero is the other polynomial, made monic
+    assert_eq!(zero.gcd(&f).to_string(), "x^2 + 1/6");
+    assert_eq!(f.gcd(&zero).to_string(), "x^2 + 1/6");
+    assert_eq!(zero.resultant(&f), 0);
+    assert_eq!(f.resultant(&zero), 0);
+    let (q, r) = (&zero).div_rem(&f);
+    assert_eq!(q, zero);
+    assert_eq!(r, zero);
+}
+
+#[test]
+fn test_div_rem() {
+    let test = |xs: &[(i64, i64)], ys: &[(i64, i64)], q_out: &str, r_out: &str| {
+        let f = poly(xs);
+        let g = poly(ys);
+        let (q, r) = (&f).div_rem(&g);
+        assert_eq!(q.to_string(), q_out);
+        assert_eq!(r.to_string(), r_out);
+        assert!(r.degree() < g.degree());
+        assert_eq!(&q * &g + &r, f);
+    };
+    test(&[(1, 1), (0, 1), (1, 1)], &[(1, 1), (2, 1)], "1/2*x - 1/4", "5/4");
+    test(&[(1, 1), (2, 1)], &[(1, 1), (0, 1), (1, 1)], "0", "2*x + 1");
+    test(&[(1, 1), (2, 1)], &[(3, 2)], "4/3*x + 2/3", "0");
+}
+
+#[test]
+fn test_square_free_factorization() {
+    // 1/3 (x - 1)^2 (x + 2)
+    let f = RationalPolynomial::from(IntegerPolynomial::from_coefficients(
+        [2, -3, 0, 1].iter().map(|&c| Integer::from(c)).collect(),
+    )) * poly(&[(1, 3)]);
+    let (c, factors) = f.square_free_factorization();
+    assert_eq!(c, Rational::from_signeds(1, 3));
+    assert_eq!(
+        factors
+            .iter()
+            .map(|(g, e)| (g.to_string(), *e))
+            .collect::<Vec<_>>(),
+        &[("x + 2".to_string(), 1), ("x - 1".to_string(), 2)]
+    );
+
+    let (c, factors) = poly(&[(-5, 7)]).square_free_factorization();
+    assert_eq!(c, Rational::from_signeds(-5, 7));
+    assert!(factors.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn make_monic_fail() {
+    RationalPolynomial::ZERO.make_monic();
+}
+
+#[test]
+#[should_panic]
+fn square_free_factorization_fail() {
+    RationalPolynomial::ZERO.square_free_factorization();
+}
+
+#[test]
+#[should_panic]
+fn div_rem_fail() {
+    poly(&[(1, 2)]).div_rem(RationalPolynomial::ZERO);
+}